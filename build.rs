@@ -0,0 +1,10 @@
+// Generates the gRPC service from `proto/` (see `src/grpc.rs`)
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Use the bundled protoc so builds don't need one installed
+    std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    println!("cargo:rerun-if-changed=proto");
+    tonic_build::configure()
+        .build_client(false)
+        .compile_protos(&["proto/hockey/v1/stats.proto"], &["proto"])?;
+    Ok(())
+}
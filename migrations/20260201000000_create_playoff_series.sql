@@ -0,0 +1,28 @@
+-- Playoff series: best-of-N matchups between two teams within a season
+-- Series score is derived from the finished matches linked via match.series_id
+
+CREATE TABLE playoff_series (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  season_id INTEGER NOT NULL,
+  round INTEGER NOT NULL,
+  home_team_id INTEGER NOT NULL,
+  away_team_id INTEGER NOT NULL,
+  home_seed INTEGER,
+  away_seed INTEGER,
+  best_of INTEGER NOT NULL DEFAULT 7,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (season_id) REFERENCES season(id) ON DELETE CASCADE,
+  FOREIGN KEY (home_team_id) REFERENCES team(id) ON DELETE RESTRICT,
+  FOREIGN KEY (away_team_id) REFERENCES team(id) ON DELETE RESTRICT,
+  CHECK (home_team_id != away_team_id),
+  CHECK (round >= 1),
+  CHECK (best_of IN (1, 3, 5, 7))
+) STRICT;
+
+CREATE INDEX idx_playoff_series_season_id ON playoff_series(season_id);
+
+-- Matches can optionally belong to a series; removing the series keeps the games
+ALTER TABLE match ADD COLUMN series_id INTEGER REFERENCES playoff_series(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_match_series_id ON match(series_id);
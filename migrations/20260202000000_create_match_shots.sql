@@ -0,0 +1,19 @@
+-- Shots on goal per team and period (1-3 regulation, 4 overtime)
+-- A missing row means shots were not recorded for that period
+
+CREATE TABLE match_shots (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  match_id INTEGER NOT NULL,
+  team_id INTEGER NOT NULL,
+  period INTEGER NOT NULL,
+  shots INTEGER NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (match_id) REFERENCES match(id) ON DELETE CASCADE,
+  FOREIGN KEY (team_id) REFERENCES team(id) ON DELETE RESTRICT,
+  UNIQUE (match_id, team_id, period),
+  CHECK (period >= 1 AND period <= 4),
+  CHECK (shots >= 0)
+) STRICT;
+
+CREATE INDEX idx_match_shots_match_id ON match_shots(match_id);
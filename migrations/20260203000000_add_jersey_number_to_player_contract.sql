@@ -0,0 +1,8 @@
+-- Jersey number worn by a player within a team participation (roster)
+-- Numbers are unique per roster; players without a number are unrestricted
+
+ALTER TABLE player_contract ADD COLUMN jersey_number INTEGER CHECK (jersey_number >= 0 AND jersey_number <= 99);
+
+CREATE UNIQUE INDEX idx_player_contract_jersey_number
+  ON player_contract(team_participation_id, jersey_number)
+  WHERE jersey_number IS NOT NULL;
@@ -0,0 +1,19 @@
+-- Login audit: one row per sign-in attempt, successful or not
+-- user_id is NULL when the email did not match any user
+
+CREATE TABLE login_audit (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  user_id INTEGER,
+  email TEXT NOT NULL,
+  success INTEGER NOT NULL,
+  failure_reason TEXT,
+  ip_address TEXT,
+  user_agent TEXT,
+  new_ip INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+  CHECK (success IN (0, 1)),
+  CHECK (new_ip IN (0, 1))
+) STRICT;
+
+CREATE INDEX idx_login_audit_user_created ON login_audit(user_id, created_at);
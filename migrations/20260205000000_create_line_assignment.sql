@@ -0,0 +1,22 @@
+-- Line combinations: forward lines, defense pairs and goalie depth chart
+-- Each roster entry holds at most one slot; each slot holds at most one player
+-- Forward slots: 1 = LW, 2 = C, 3 = RW; defense slots: 1 = LD, 2 = RD; goalie slot: 1
+
+CREATE TABLE line_assignment (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  team_participation_id INTEGER NOT NULL,
+  player_contract_id INTEGER NOT NULL UNIQUE,
+  unit TEXT NOT NULL,
+  line_number INTEGER NOT NULL,
+  slot INTEGER NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (team_participation_id) REFERENCES team_participation(id) ON DELETE CASCADE,
+  FOREIGN KEY (player_contract_id) REFERENCES player_contract(id) ON DELETE CASCADE,
+  UNIQUE (team_participation_id, unit, line_number, slot),
+  CHECK (unit IN ('forward', 'defense', 'goalie')),
+  CHECK (line_number >= 1),
+  CHECK (slot >= 1)
+) STRICT;
+
+CREATE INDEX idx_line_assignment_team_participation_id ON line_assignment(team_participation_id);
@@ -0,0 +1,14 @@
+-- Players dressed for a match, picked from their team's season roster
+-- A team without rows has no lineup recorded for that match
+
+CREATE TABLE match_lineup (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  match_id INTEGER NOT NULL,
+  player_contract_id INTEGER NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (match_id) REFERENCES match(id) ON DELETE CASCADE,
+  FOREIGN KEY (player_contract_id) REFERENCES player_contract(id) ON DELETE CASCADE,
+  UNIQUE (match_id, player_contract_id)
+) STRICT;
+
+CREATE INDEX idx_match_lineup_match_id ON match_lineup(match_id);
@@ -0,0 +1,31 @@
+-- Stable external identifiers for records exchanged between instances
+-- An entity can carry several aliases (its own exported id plus ids it was
+-- imported under); merge imports resolve records through this table first
+
+CREATE TABLE external_alias (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  entity_type TEXT NOT NULL,
+  external_id TEXT NOT NULL,
+  entity_id INTEGER NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  UNIQUE (entity_type, external_id),
+  CHECK (entity_type IN ('team', 'player', 'match'))
+) STRICT;
+
+CREATE INDEX idx_external_alias_entity ON external_alias(entity_type, entity_id);
+
+-- Aliases are polymorphic, so clean them up with triggers instead of foreign keys
+CREATE TRIGGER external_alias_team_deleted AFTER DELETE ON team
+BEGIN
+  DELETE FROM external_alias WHERE entity_type = 'team' AND entity_id = OLD.id;
+END;
+
+CREATE TRIGGER external_alias_player_deleted AFTER DELETE ON player
+BEGIN
+  DELETE FROM external_alias WHERE entity_type = 'player' AND entity_id = OLD.id;
+END;
+
+CREATE TRIGGER external_alias_match_deleted AFTER DELETE ON match
+BEGIN
+  DELETE FROM external_alias WHERE entity_type = 'match' AND entity_id = OLD.id;
+END;
@@ -0,0 +1,26 @@
+-- Referee registry and match officials
+-- Roles follow the four-official system: up to two referees and two linesmen
+
+CREATE TABLE referee (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  name TEXT NOT NULL,
+  country_id INTEGER,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (country_id) REFERENCES country(id) ON DELETE SET NULL
+) STRICT;
+
+CREATE TABLE match_official (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  match_id INTEGER NOT NULL,
+  referee_id INTEGER NOT NULL,
+  role TEXT NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (match_id) REFERENCES match(id) ON DELETE CASCADE,
+  FOREIGN KEY (referee_id) REFERENCES referee(id) ON DELETE CASCADE,
+  UNIQUE (match_id, referee_id),
+  CHECK (role IN ('referee', 'linesman'))
+) STRICT;
+
+CREATE INDEX idx_match_official_match_id ON match_official(match_id);
+CREATE INDEX idx_match_official_referee_id ON match_official(referee_id);
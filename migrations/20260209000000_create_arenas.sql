@@ -0,0 +1,30 @@
+-- Arenas replace the free-text match venue
+-- Each distinct venue string becomes an arena and matches are re-pointed to it
+
+CREATE TABLE arena (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  name TEXT NOT NULL,
+  city TEXT,
+  country_id INTEGER,
+  capacity INTEGER,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (country_id) REFERENCES country(id) ON DELETE SET NULL,
+  CHECK (capacity IS NULL OR capacity > 0)
+) STRICT;
+
+INSERT INTO arena (name)
+SELECT DISTINCT TRIM(venue)
+FROM match
+WHERE venue IS NOT NULL AND TRIM(venue) != ''
+ORDER BY TRIM(venue);
+
+ALTER TABLE match ADD COLUMN arena_id INTEGER REFERENCES arena(id) ON DELETE SET NULL;
+
+UPDATE match
+SET arena_id = (SELECT a.id FROM arena a WHERE a.name = TRIM(match.venue))
+WHERE venue IS NOT NULL;
+
+ALTER TABLE match DROP COLUMN venue;
+
+CREATE INDEX idx_match_arena_id ON match(arena_id);
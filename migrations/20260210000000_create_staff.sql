@@ -0,0 +1,27 @@
+-- Team staff (coaches and general managers)
+-- Staff are contracted to team participations, the same way players are
+
+CREATE TABLE staff_member (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  name TEXT NOT NULL,
+  country_id INTEGER,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (country_id) REFERENCES country(id) ON DELETE SET NULL
+) STRICT;
+
+CREATE TABLE staff_contract (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  team_participation_id INTEGER NOT NULL,
+  staff_member_id INTEGER NOT NULL,
+  role TEXT NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (team_participation_id) REFERENCES team_participation(id) ON DELETE CASCADE,
+  FOREIGN KEY (staff_member_id) REFERENCES staff_member(id) ON DELETE CASCADE,
+  UNIQUE (team_participation_id, staff_member_id, role),
+  CHECK (role IN ('head_coach', 'assistant_coach', 'general_manager'))
+) STRICT;
+
+CREATE INDEX idx_staff_contract_team_participation_id ON staff_contract(team_participation_id);
+CREATE INDEX idx_staff_contract_staff_member_id ON staff_contract(staff_member_id);
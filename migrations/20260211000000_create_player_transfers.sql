@@ -0,0 +1,23 @@
+-- Mid-season player transfers
+-- A transfer ends the player's contract with one team participation and starts
+-- one with another participation of the same season. Contract dates bound the
+-- matches a player can dress for; NULL means the whole season.
+
+ALTER TABLE player_contract ADD COLUMN joined_on TEXT;
+ALTER TABLE player_contract ADD COLUMN left_on TEXT;
+
+CREATE TABLE player_transfer (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  player_id INTEGER NOT NULL,
+  from_participation_id INTEGER NOT NULL,
+  to_participation_id INTEGER NOT NULL,
+  transfer_date TEXT NOT NULL,
+  note TEXT,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (player_id) REFERENCES player(id) ON DELETE CASCADE,
+  FOREIGN KEY (from_participation_id) REFERENCES team_participation(id) ON DELETE CASCADE,
+  FOREIGN KEY (to_participation_id) REFERENCES team_participation(id) ON DELETE CASCADE,
+  CHECK (from_participation_id != to_participation_id)
+) STRICT;
+
+CREATE INDEX idx_player_transfer_player_id ON player_transfer(player_id);
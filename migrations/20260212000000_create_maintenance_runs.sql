@@ -0,0 +1,13 @@
+-- Results of the scheduled database maintenance job
+-- One row per task (checkpoint, optimize, backup) per run
+
+CREATE TABLE maintenance_run (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  task TEXT NOT NULL CHECK (task IN ('checkpoint', 'optimize', 'backup')),
+  success INTEGER NOT NULL,
+  detail TEXT,
+  duration_ms INTEGER NOT NULL,
+  started_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+) STRICT;
+
+CREATE INDEX idx_maintenance_run_started_at ON maintenance_run(started_at);
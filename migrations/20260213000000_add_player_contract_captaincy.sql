@@ -0,0 +1,9 @@
+-- Team captain ('C') and alternate captains ('A') of a team participation
+-- A team has at most one captain and two alternates; the alternate limit is
+-- checked by the application, the captain is also unique in the database.
+
+ALTER TABLE player_contract ADD COLUMN captaincy TEXT CHECK (captaincy IN ('C', 'A'));
+
+CREATE UNIQUE INDEX idx_player_contract_captain
+  ON player_contract(team_participation_id)
+  WHERE captaincy = 'C';
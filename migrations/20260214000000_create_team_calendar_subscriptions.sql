@@ -0,0 +1,15 @@
+-- Calendar subscriptions for a team's matches
+-- Each subscription has its own secret token, so a leaked feed URL can be
+-- revoked without affecting the team's other subscribers.
+
+CREATE TABLE team_calendar_subscription (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  team_id INTEGER NOT NULL,
+  token TEXT NOT NULL UNIQUE,
+  label TEXT,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  last_used_at TEXT,
+  FOREIGN KEY (team_id) REFERENCES team(id) ON DELETE CASCADE
+) STRICT;
+
+CREATE INDEX idx_team_calendar_subscription_team_id ON team_calendar_subscription(team_id);
@@ -0,0 +1,3 @@
+-- Spectators at a match; unknown for most historical results
+
+ALTER TABLE match ADD COLUMN attendance INTEGER CHECK (attendance IS NULL OR attendance >= 0);
@@ -0,0 +1,16 @@
+-- Fan predictions (pick'em): each user picks the winner of upcoming matches
+-- Picks are scored once the match is finished, so no points are stored here
+
+CREATE TABLE match_prediction (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  user_id INTEGER NOT NULL,
+  match_id INTEGER NOT NULL,
+  predicted_winner TEXT NOT NULL CHECK (predicted_winner IN ('home', 'away')),
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+  FOREIGN KEY (match_id) REFERENCES match(id) ON DELETE CASCADE,
+  UNIQUE (user_id, match_id)
+) STRICT;
+
+CREATE INDEX idx_match_prediction_match_id ON match_prediction(match_id);
@@ -0,0 +1,5 @@
+-- Stage of competition a match belongs to; exhibition games don't count
+-- towards standings or player statistics
+
+ALTER TABLE match ADD COLUMN game_type TEXT NOT NULL DEFAULT 'group'
+    CHECK (game_type IN ('group', 'playoff', 'relegation', 'exhibition'));
@@ -0,0 +1,19 @@
+-- Groups (Group A/B, divisions) a season's teams are split into
+-- Standings are computed per group from the group-stage matches of its teams
+
+CREATE TABLE season_group (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  season_id INTEGER NOT NULL,
+  name TEXT NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (season_id) REFERENCES season(id) ON DELETE CASCADE,
+  UNIQUE (season_id, name)
+) STRICT;
+
+CREATE INDEX idx_season_group_season_id ON season_group(season_id);
+
+-- A team belongs to at most one group per season; deleting the group unassigns it
+ALTER TABLE team_participation ADD COLUMN group_id INTEGER REFERENCES season_group(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_team_participation_group_id ON team_participation(group_id);
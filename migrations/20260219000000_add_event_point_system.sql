@@ -0,0 +1,6 @@
+-- How standings award points in an event's seasons:
+-- 'classic' is 2-1-0 (win, tie, loss), 'iihf' is 3-2-1-0 (regulation win,
+-- overtime/shootout win, overtime/shootout loss, regulation loss)
+
+ALTER TABLE event ADD COLUMN point_system TEXT NOT NULL DEFAULT 'classic'
+    CHECK (point_system IN ('classic', 'iihf'));
@@ -0,0 +1,22 @@
+-- Field-level change history for matches, their goals and players
+-- changes holds a JSON array of {field, old, new}; entity_id has no foreign
+-- key so history outlives deleted goals. match_id groups goal changes under
+-- their match.
+
+CREATE TABLE change_history (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  entity_type TEXT NOT NULL,
+  entity_id INTEGER NOT NULL,
+  match_id INTEGER,
+  user_id INTEGER,
+  action TEXT NOT NULL,
+  changes TEXT NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (match_id) REFERENCES match(id) ON DELETE CASCADE,
+  FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE SET NULL,
+  CHECK (entity_type IN ('match', 'score_event', 'player')),
+  CHECK (action IN ('create', 'update', 'delete'))
+) STRICT;
+
+CREATE INDEX idx_change_history_entity ON change_history(entity_type, entity_id);
+CREATE INDEX idx_change_history_match ON change_history(match_id);
@@ -0,0 +1,6 @@
+-- Roster rules for every team of an event; NULL means no limit
+-- Imports are players whose country differs from their team's country
+
+ALTER TABLE event ADD COLUMN roster_max_players INTEGER CHECK (roster_max_players >= 0);
+ALTER TABLE event ADD COLUMN roster_min_goalies INTEGER CHECK (roster_min_goalies >= 0);
+ALTER TABLE event ADD COLUMN roster_max_imports INTEGER CHECK (roster_max_imports >= 0);
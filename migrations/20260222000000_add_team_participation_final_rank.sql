@@ -0,0 +1,8 @@
+-- Final placement of a team in a season (1 = gold, 2 = silver, 3 = bronze)
+-- Each place is held by at most one team of the season
+
+ALTER TABLE team_participation ADD COLUMN final_rank INTEGER CHECK (final_rank BETWEEN 1 AND 16);
+
+CREATE UNIQUE INDEX idx_team_participation_season_final_rank
+  ON team_participation(season_id, final_rank)
+  WHERE final_rank IS NOT NULL;
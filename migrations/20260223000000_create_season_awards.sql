@@ -0,0 +1,19 @@
+-- Individual awards of a season (MVP, best players by position, All-Star Team)
+-- Single-winner awards and the size of the All-Star Team are checked in the
+-- business layer
+
+CREATE TABLE season_award (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  season_id INTEGER NOT NULL,
+  player_id INTEGER NOT NULL,
+  award TEXT NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (season_id) REFERENCES season(id) ON DELETE CASCADE,
+  FOREIGN KEY (player_id) REFERENCES player(id) ON DELETE CASCADE,
+  UNIQUE (season_id, award, player_id),
+  CHECK (award IN ('mvp', 'best_forward', 'best_defenseman', 'best_goalie', 'all_star'))
+) STRICT;
+
+CREATE INDEX idx_season_award_season_id ON season_award(season_id);
+CREATE INDEX idx_season_award_player_id ON season_award(player_id);
@@ -0,0 +1,21 @@
+-- Player milestones detected when goals are saved (hat tricks, 4+ point
+-- games, 100th career goal). Rows are recomputed by the business layer, so
+-- each milestone is stored once per player and match.
+
+CREATE TABLE milestone (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  player_id INTEGER NOT NULL,
+  match_id INTEGER NOT NULL,
+  kind TEXT NOT NULL,
+  -- Goals, points or career goal count the milestone was reached with
+  value INTEGER NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (player_id) REFERENCES player(id) ON DELETE CASCADE,
+  FOREIGN KEY (match_id) REFERENCES match(id) ON DELETE CASCADE,
+  UNIQUE (player_id, match_id, kind),
+  CHECK (kind IN ('hat_trick', 'four_point_game', 'hundredth_goal'))
+) STRICT;
+
+CREATE INDEX idx_milestone_player_id ON milestone(player_id);
+CREATE INDEX idx_milestone_match_id ON milestone(match_id);
+CREATE INDEX idx_milestone_created_at ON milestone(created_at);
@@ -0,0 +1,8 @@
+-- Instance-wide settings edited on the management settings page
+-- (e.g. default filters of list views). A missing key means "not set".
+
+CREATE TABLE app_setting (
+  key TEXT PRIMARY KEY NOT NULL,
+  value TEXT NOT NULL,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+) STRICT;
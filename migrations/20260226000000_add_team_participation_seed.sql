@@ -0,0 +1,8 @@
+-- Playoff seed of a team in a season (1 = top seed)
+-- Each seed is held by at most one team of the season
+
+ALTER TABLE team_participation ADD COLUMN seed INTEGER CHECK (seed BETWEEN 1 AND 16);
+
+CREATE UNIQUE INDEX idx_team_participation_season_seed
+  ON team_participation(season_id, seed)
+  WHERE seed IS NOT NULL;
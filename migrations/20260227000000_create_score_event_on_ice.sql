@@ -0,0 +1,17 @@
+-- Players on the ice for a goal, per team, used for plus/minus
+-- A goal without rows has no on-ice players recorded
+
+CREATE TABLE score_event_on_ice (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  score_event_id INTEGER NOT NULL,
+  player_id INTEGER NOT NULL,
+  team_id INTEGER NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (score_event_id) REFERENCES score_event(id) ON DELETE CASCADE,
+  FOREIGN KEY (player_id) REFERENCES player(id) ON DELETE CASCADE,
+  FOREIGN KEY (team_id) REFERENCES team(id) ON DELETE CASCADE,
+  UNIQUE (score_event_id, player_id)
+) STRICT;
+
+CREATE INDEX idx_score_event_on_ice_score_event_id ON score_event_on_ice(score_event_id);
+CREATE INDEX idx_score_event_on_ice_player_id ON score_event_on_ice(player_id);
@@ -0,0 +1,10 @@
+-- Custom reports saved from the report builder
+-- The definition is JSON naming whitelisted fields only (see service::reports::builder)
+
+CREATE TABLE saved_report (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  name TEXT NOT NULL,
+  definition TEXT NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+) STRICT;
@@ -0,0 +1,19 @@
+-- Faceoffs won and lost by a player in a match (usually centers)
+-- A team without rows has no faceoffs recorded for that match
+
+CREATE TABLE match_faceoffs (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  match_id INTEGER NOT NULL,
+  player_id INTEGER NOT NULL,
+  team_id INTEGER NOT NULL,
+  won INTEGER NOT NULL CHECK (won BETWEEN 0 AND 99),
+  lost INTEGER NOT NULL CHECK (lost BETWEEN 0 AND 99),
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  FOREIGN KEY (match_id) REFERENCES match(id) ON DELETE CASCADE,
+  FOREIGN KEY (player_id) REFERENCES player(id) ON DELETE CASCADE,
+  FOREIGN KEY (team_id) REFERENCES team(id) ON DELETE CASCADE,
+  UNIQUE (match_id, player_id)
+) STRICT;
+
+CREATE INDEX idx_match_faceoffs_match_id ON match_faceoffs(match_id);
+CREATE INDEX idx_match_faceoffs_player_id ON match_faceoffs(player_id);
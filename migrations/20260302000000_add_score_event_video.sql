@@ -0,0 +1,6 @@
+-- Optional video link for a goal, e.g. a highlight clip
+-- video_timestamp is the offset into the video in seconds
+
+ALTER TABLE score_event ADD COLUMN video_url TEXT;
+ALTER TABLE score_event ADD COLUMN video_timestamp INTEGER
+  CHECK (video_timestamp IS NULL OR video_timestamp >= 0);
@@ -0,0 +1,40 @@
+-- URL slugs for teams, players and events
+-- Slugs are generated from the name by the application (which also fills in
+-- missing ones at startup) and can be edited; they are unique per entity type
+
+ALTER TABLE team ADD COLUMN slug TEXT;
+ALTER TABLE player ADD COLUMN slug TEXT;
+ALTER TABLE event ADD COLUMN slug TEXT;
+
+CREATE UNIQUE INDEX idx_team_slug ON team(slug) WHERE slug IS NOT NULL;
+CREATE UNIQUE INDEX idx_player_slug ON player(slug) WHERE slug IS NOT NULL;
+CREATE UNIQUE INDEX idx_event_slug ON event(slug) WHERE slug IS NOT NULL;
+
+-- Previous slugs, so links from before a rename redirect to the current slug
+CREATE TABLE slug_redirect (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  entity_type TEXT NOT NULL,
+  slug TEXT NOT NULL,
+  entity_id INTEGER NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  UNIQUE (entity_type, slug),
+  CHECK (entity_type IN ('team', 'player', 'event'))
+) STRICT;
+
+CREATE INDEX idx_slug_redirect_entity ON slug_redirect(entity_type, entity_id);
+
+-- Redirects are polymorphic, so clean them up with triggers instead of foreign keys
+CREATE TRIGGER slug_redirect_team_deleted AFTER DELETE ON team
+BEGIN
+  DELETE FROM slug_redirect WHERE entity_type = 'team' AND entity_id = OLD.id;
+END;
+
+CREATE TRIGGER slug_redirect_player_deleted AFTER DELETE ON player
+BEGIN
+  DELETE FROM slug_redirect WHERE entity_type = 'player' AND entity_id = OLD.id;
+END;
+
+CREATE TRIGGER slug_redirect_event_deleted AFTER DELETE ON event
+BEGIN
+  DELETE FROM slug_redirect WHERE entity_type = 'event' AND entity_id = OLD.id;
+END;
@@ -0,0 +1,14 @@
+-- Entries of the in-app "What's new" panel, edited under Management
+-- Each user sees entries created after their account that are newer than
+-- the last entry they dismissed (users.changelog_seen_id).
+
+CREATE TABLE changelog_entry (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  version TEXT NOT NULL,
+  title TEXT NOT NULL,
+  body TEXT NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+) STRICT;
+
+ALTER TABLE users ADD COLUMN changelog_seen_id INTEGER NOT NULL DEFAULT 0;
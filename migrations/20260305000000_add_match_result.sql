@@ -0,0 +1,27 @@
+-- Result of a finished match: the winning side and whether it was decided
+-- in regulation, overtime or a shootout (from the period of the last goal),
+-- or a draw. NULL until the match is finished. Kept up to date by the match
+-- and score event services (see service::matches::refresh_match_result).
+
+ALTER TABLE match ADD COLUMN result TEXT CHECK (result IN (
+  'home_regulation', 'home_overtime', 'home_shootout',
+  'away_regulation', 'away_overtime', 'away_shootout',
+  'draw'
+));
+
+UPDATE match SET result = (
+  SELECT CASE
+    WHEN totals.home = totals.away THEN 'draw'
+    ELSE CASE WHEN totals.home > totals.away THEN 'home_' ELSE 'away_' END
+      || CASE totals.last_period WHEN 5 THEN 'shootout' WHEN 4 THEN 'overtime' ELSE 'regulation' END
+  END
+  FROM (
+    SELECT
+      m.home_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id) AS home,
+      m.away_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.away_team_id) AS away,
+      (SELECT MAX(se.period) FROM score_event se WHERE se.match_id = m.id) AS last_period
+    FROM match m
+    WHERE m.id = match.id
+  ) totals
+)
+WHERE status = 'finished';
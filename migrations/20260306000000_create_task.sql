@@ -0,0 +1,21 @@
+-- Lightweight to-do list for coordinating archival work ("verify roster for
+-- SWE 1998"). A task may point at a team, player, event, season or match;
+-- the reference is not a foreign key, so deleting the record keeps the task.
+
+CREATE TABLE task (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  title TEXT NOT NULL,
+  notes TEXT,
+  entity_type TEXT CHECK (entity_type IN ('team', 'player', 'event', 'season', 'match')),
+  entity_id INTEGER,
+  assignee_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+  created_by INTEGER REFERENCES users(id) ON DELETE SET NULL,
+  due_date TEXT,
+  completed_at TEXT,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  CHECK ((entity_type IS NULL) = (entity_id IS NULL))
+) STRICT;
+
+CREATE INDEX idx_task_assignee_open ON task(assignee_id, completed_at);
+CREATE INDEX idx_task_entity ON task(entity_type, entity_id);
@@ -0,0 +1,30 @@
+-- Internal discussion next to matches, players and seasons. Replies point at
+-- the top-level comment of their thread. Like tasks, the record reference is
+-- not a foreign key.
+
+CREATE TABLE comment (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  entity_type TEXT NOT NULL CHECK (entity_type IN ('match', 'player', 'season')),
+  entity_id INTEGER NOT NULL,
+  parent_id INTEGER REFERENCES comment(id) ON DELETE CASCADE,
+  author_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+  body TEXT NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+) STRICT;
+
+CREATE INDEX idx_comment_entity ON comment(entity_type, entity_id);
+CREATE INDEX idx_comment_parent ON comment(parent_id);
+
+-- In-app notifications, currently created for @mentions in comments
+CREATE TABLE notification (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  comment_id INTEGER NOT NULL REFERENCES comment(id) ON DELETE CASCADE,
+  actor_id INTEGER REFERENCES users(id) ON DELETE SET NULL,
+  read_at TEXT,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  UNIQUE (user_id, comment_id)
+) STRICT;
+
+CREATE INDEX idx_notification_user_unread ON notification(user_id, read_at);
@@ -0,0 +1,13 @@
+-- Outgoing chat webhooks (Slack, Discord). Each webhook chooses which
+-- notifications it receives: final results and player milestones.
+
+CREATE TABLE chat_webhook (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  name TEXT NOT NULL,
+  kind TEXT NOT NULL CHECK (kind IN ('slack', 'discord')),
+  url TEXT NOT NULL,
+  notify_results INTEGER NOT NULL DEFAULT 1 CHECK (notify_results IN (0, 1)),
+  notify_milestones INTEGER NOT NULL DEFAULT 1 CHECK (notify_milestones IN (0, 1)),
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+) STRICT;
@@ -0,0 +1,32 @@
+-- Generic outgoing webhooks for syncing external systems. A subscription
+-- chooses the kinds of records it follows; every create, update or delete
+-- of such a record queues one delivery per subscription. Deliveries are
+-- signed with the subscription secret and retried with backoff.
+
+CREATE TABLE webhook_subscription (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  url TEXT NOT NULL,
+  secret TEXT NOT NULL,
+  on_matches INTEGER NOT NULL DEFAULT 1 CHECK (on_matches IN (0, 1)),
+  on_score_events INTEGER NOT NULL DEFAULT 1 CHECK (on_score_events IN (0, 1)),
+  on_players INTEGER NOT NULL DEFAULT 1 CHECK (on_players IN (0, 1)),
+  active INTEGER NOT NULL DEFAULT 1 CHECK (active IN (0, 1)),
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+) STRICT;
+
+CREATE TABLE webhook_delivery (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  subscription_id INTEGER NOT NULL REFERENCES webhook_subscription(id) ON DELETE CASCADE,
+  event TEXT NOT NULL,
+  payload TEXT NOT NULL,
+  status TEXT NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'delivered', 'failed')),
+  attempts INTEGER NOT NULL DEFAULT 0,
+  next_attempt_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  last_error TEXT,
+  delivered_at TEXT,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+) STRICT;
+
+CREATE INDEX idx_webhook_delivery_due ON webhook_delivery(status, next_attempt_at);
+CREATE INDEX idx_webhook_delivery_subscription ON webhook_delivery(subscription_id, id);
@@ -0,0 +1,16 @@
+-- API keys let scripts call the JSON API as a user without signing in.
+-- Only a SHA-256 hash of the key is stored; the key itself is shown once
+-- when created. Revoked keys are kept for reference.
+
+CREATE TABLE api_key (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  name TEXT NOT NULL,
+  key_prefix TEXT NOT NULL,
+  key_hash TEXT NOT NULL UNIQUE,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  last_used_at TEXT,
+  revoked_at TEXT
+) STRICT;
+
+CREATE INDEX idx_api_key_user_id ON api_key(user_id);
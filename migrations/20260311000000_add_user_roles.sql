@@ -0,0 +1,9 @@
+-- User management: roles, deactivation and forced password changes.
+-- Everybody could do everything before, so existing users become admins.
+-- Only admins manage users; editors can change all hockey data.
+
+ALTER TABLE users ADD COLUMN role TEXT NOT NULL DEFAULT 'admin' CHECK (role IN ('admin', 'editor'));
+ALTER TABLE users ADD COLUMN active INTEGER NOT NULL DEFAULT 1 CHECK (active IN (0, 1));
+-- Set when an admin creates the account or resets its password; the user
+-- has to choose a new password before doing anything else
+ALTER TABLE users ADD COLUMN must_change_password INTEGER NOT NULL DEFAULT 0 CHECK (must_change_password IN (0, 1));
@@ -0,0 +1,22 @@
+-- Self-service registration: people sign up, confirm their email with a
+-- link and, if the instance requires it, wait for an admin to approve them.
+-- Registered users stay inactive until then.
+
+-- NULL until the address is confirmed; earlier users were created by hand
+ALTER TABLE users ADD COLUMN email_verified_at TEXT;
+UPDATE users SET email_verified_at = created_at;
+-- Email confirmed, but an admin has yet to approve the account
+ALTER TABLE users ADD COLUMN approval_pending INTEGER NOT NULL DEFAULT 0 CHECK (approval_pending IN (0, 1));
+
+-- Only a SHA-256 hash of each token is stored; the token itself is in the
+-- emailed link. Tokens expire and can be used once.
+CREATE TABLE email_verification_token (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  token_hash TEXT NOT NULL UNIQUE,
+  expires_at TEXT NOT NULL,
+  used_at TEXT,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+) STRICT;
+
+CREATE INDEX idx_email_verification_token_user_id ON email_verification_token(user_id);
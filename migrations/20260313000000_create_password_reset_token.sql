@@ -0,0 +1,15 @@
+-- "Forgot password": a one-time link emailed to the user lets them choose a
+-- new password without signing in.
+
+-- Only a SHA-256 hash of each token is stored; the token itself is in the
+-- emailed link. Tokens expire, work once and are invalidated by a newer one.
+CREATE TABLE password_reset_token (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  token_hash TEXT NOT NULL UNIQUE,
+  expires_at TEXT NOT NULL,
+  used_at TEXT,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+) STRICT;
+
+CREATE INDEX idx_password_reset_token_user_id ON password_reset_token(user_id);
@@ -0,0 +1,16 @@
+-- Sign-in with Google or GitHub: external accounts linked to local users.
+-- A user has at most one linked account per provider.
+
+CREATE TABLE user_identity (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  user_id INTEGER NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  provider TEXT NOT NULL CHECK (provider IN ('google', 'github')),
+  -- Stable account ID at the provider (OIDC `sub`, GitHub user ID)
+  subject TEXT NOT NULL,
+  -- Email reported by the provider when the account was linked
+  email TEXT,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  last_used_at TEXT,
+  UNIQUE (provider, subject),
+  UNIQUE (user_id, provider)
+) STRICT;
@@ -0,0 +1,6 @@
+-- Account lockout: consecutive failed password sign-ins since the last
+-- successful one, and until when the account refuses password sign-ins.
+-- Reaching the configured threshold sets locked_until and starts counting anew.
+
+ALTER TABLE users ADD COLUMN failed_logins INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE users ADD COLUMN locked_until TEXT;
@@ -0,0 +1,4 @@
+-- Scoped API keys for read-only integrations: a space-separated list such as
+-- "matches:read stats:read". NULL keeps full access as the key's user.
+
+ALTER TABLE api_key ADD COLUMN scopes TEXT;
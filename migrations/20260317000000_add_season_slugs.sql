@@ -0,0 +1,49 @@
+-- URL slugs for seasons, used by the public site
+-- Generated from the display name, or the event name and year without one
+
+ALTER TABLE season ADD COLUMN slug TEXT;
+
+CREATE UNIQUE INDEX idx_season_slug ON season(slug) WHERE slug IS NOT NULL;
+
+-- Allow season redirects; SQLite cannot change a CHECK constraint in place
+DROP TRIGGER slug_redirect_team_deleted;
+DROP TRIGGER slug_redirect_player_deleted;
+DROP TRIGGER slug_redirect_event_deleted;
+
+CREATE TABLE slug_redirect_new (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  entity_type TEXT NOT NULL,
+  slug TEXT NOT NULL,
+  entity_id INTEGER NOT NULL,
+  created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  UNIQUE (entity_type, slug),
+  CHECK (entity_type IN ('team', 'player', 'event', 'season'))
+) STRICT;
+
+INSERT INTO slug_redirect_new (id, entity_type, slug, entity_id, created_at)
+SELECT id, entity_type, slug, entity_id, created_at FROM slug_redirect;
+
+DROP TABLE slug_redirect;
+ALTER TABLE slug_redirect_new RENAME TO slug_redirect;
+
+CREATE INDEX idx_slug_redirect_entity ON slug_redirect(entity_type, entity_id);
+
+CREATE TRIGGER slug_redirect_team_deleted AFTER DELETE ON team
+BEGIN
+  DELETE FROM slug_redirect WHERE entity_type = 'team' AND entity_id = OLD.id;
+END;
+
+CREATE TRIGGER slug_redirect_player_deleted AFTER DELETE ON player
+BEGIN
+  DELETE FROM slug_redirect WHERE entity_type = 'player' AND entity_id = OLD.id;
+END;
+
+CREATE TRIGGER slug_redirect_event_deleted AFTER DELETE ON event
+BEGIN
+  DELETE FROM slug_redirect WHERE entity_type = 'event' AND entity_id = OLD.id;
+END;
+
+CREATE TRIGGER slug_redirect_season_deleted AFTER DELETE ON season
+BEGIN
+  DELETE FROM slug_redirect WHERE entity_type = 'season' AND entity_id = OLD.id;
+END;
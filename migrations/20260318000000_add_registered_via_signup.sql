@@ -0,0 +1,10 @@
+-- Only accounts created by the sign-up form may be renewed or purged while
+-- unconfirmed; accounts made by hand or the CLI must never match, even when
+-- deactivated
+ALTER TABLE users ADD COLUMN registered_via_signup INTEGER NOT NULL DEFAULT 0 CHECK (registered_via_signup IN (0, 1));
+UPDATE users SET registered_via_signup = 1
+WHERE id IN (SELECT user_id FROM email_verification_token);
+
+-- Users created by the CLI since the last backfill never confirmed an email
+UPDATE users SET email_verified_at = created_at
+WHERE email_verified_at IS NULL AND registered_via_signup = 0;
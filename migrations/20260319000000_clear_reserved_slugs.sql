@@ -0,0 +1,6 @@
+-- Slugs that collide with static routes such as /teams/new can never be
+-- reached; clear them so startup generates them again with a suffix
+UPDATE team SET slug = NULL WHERE slug IN ('export', 'import', 'list', 'new', 'streaks');
+UPDATE player SET slug = NULL WHERE slug IN ('export', 'import', 'list', 'new', 'streaks');
+UPDATE event SET slug = NULL WHERE slug IN ('export', 'import', 'list', 'new', 'streaks');
+UPDATE season SET slug = NULL WHERE slug IN ('export', 'import', 'list', 'new', 'streaks');
@@ -0,0 +1,18 @@
+-- Shots and goals of a team in each match with shots recorded, shared by the
+-- season cards and the team season stats; exhibition games are left out and
+-- goals include the unidentified ones
+CREATE VIEW team_match_shooting AS
+SELECT
+  ms.match_id,
+  ms.team_id,
+  m.season_id,
+  SUM(ms.shots) AS shots,
+  (SELECT COUNT(*) FROM score_event se
+   WHERE se.match_id = ms.match_id AND se.team_id = ms.team_id)
+  + CASE WHEN m.home_team_id = ms.team_id
+         THEN m.home_score_unidentified
+         ELSE m.away_score_unidentified END AS goals
+FROM match_shots ms
+INNER JOIN match m ON ms.match_id = m.id
+WHERE m.game_type != 'exhibition'
+GROUP BY ms.match_id, ms.team_id;
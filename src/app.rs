@@ -0,0 +1,1004 @@
+//! Application router: every route with its middleware
+//!
+//! Shared by the server binary and `test_support`, so tests exercise the same
+//! routes and layers as production.
+
+use axum::{
+    extract::{Path, Request, State},
+    http::Method,
+    middleware,
+    response::Html,
+    routing::{get, post},
+    Extension, Router,
+};
+use tower_http::{
+    compression::CompressionLayer,
+    cors::{Any, CorsLayer},
+    trace::TraceLayer,
+};
+
+use crate::app_state::AppState;
+use crate::auth::{
+    self, demo_read_only, rate_limit_api, rate_limit_login, require_admin, require_auth,
+    LoginRateLimiter, CHANGE_PASSWORD_PATH,
+};
+use crate::i18n::{self, TranslationContext};
+use crate::views::{self, layout::admin_layout, pages::dashboard::dashboard_page};
+use crate::{assets, routes, service};
+
+/// Build the application router with all routes and middleware
+pub fn router(state: AppState) -> Router {
+    // Create rate limiter for login endpoint
+    let login_rate_limiter = LoginRateLimiter::new();
+
+    // Public routes (no authentication required)
+    let public_routes = Router::new()
+        .route("/auth/login", get(routes::auth::login_get))
+        .route(
+            "/auth/login",
+            post(routes::auth::login_post).layer(middleware::from_fn_with_state(
+                login_rate_limiter,
+                rate_limit_login,
+            )),
+        )
+        .route("/auth/register", get(routes::registration::register_get))
+        .route(
+            "/auth/register",
+            post(routes::registration::register_post).layer(middleware::from_fn_with_state(
+                LoginRateLimiter::new(),
+                rate_limit_login,
+            )),
+        )
+        .route("/auth/verify/:token", get(routes::registration::verify_get))
+        .route(
+            "/auth/forgot-password",
+            get(routes::password_reset::forgot_password_get),
+        )
+        .route(
+            "/auth/forgot-password",
+            post(routes::password_reset::forgot_password_post).layer(
+                middleware::from_fn_with_state(LoginRateLimiter::new(), rate_limit_login),
+            ),
+        )
+        .route(
+            "/auth/reset-password/:token",
+            get(routes::password_reset::reset_password_get),
+        )
+        .route(
+            "/auth/reset-password/:token",
+            post(routes::password_reset::reset_password_post).layer(
+                middleware::from_fn_with_state(LoginRateLimiter::new(), rate_limit_login),
+            ),
+        )
+        .route("/auth/oauth/:provider", get(routes::oauth::oauth_start))
+        .route(
+            "/auth/oauth/:provider/callback",
+            get(routes::oauth::oauth_callback),
+        )
+        .route("/auth/demo", post(routes::auth::demo_login_post))
+        .route("/auth/logout", post(routes::auth::logout_post))
+        .route("/locale/:code", get(routes::locale::set_locale))
+        .route(
+            "/calendar/:token",
+            get(routes::calendar_subscriptions::team_calendar_feed),
+        );
+
+    // Protected routes (authentication required)
+    let mut protected_routes = Router::new()
+        .route("/", get(root_handler))
+        .route("/auth/keepalive", get(routes::auth::keepalive_get))
+        .route("/profile", get(routes::profile::profile_get))
+        .route(CHANGE_PASSWORD_PATH, get(routes::profile::password_get))
+        .route(CHANGE_PASSWORD_PATH, post(routes::profile::password_post))
+        .route("/profile/api-keys", post(routes::profile::api_key_create))
+        .route(
+            "/profile/api-keys/:id/revoke",
+            post(routes::profile::api_key_revoke),
+        )
+        .route(
+            "/profile/identities/:provider",
+            post(routes::oauth::identity_link),
+        )
+        .route(
+            "/profile/identities/:provider/unlink",
+            post(routes::oauth::identity_unlink),
+        )
+        .route("/dashboard/stats", get(dashboard_stats_get))
+        .route("/management", get(routes::management::management_get))
+        .route("/management/status", get(routes::status::status_get))
+        .route(
+            "/management/data-quality",
+            get(routes::management::data_quality_get),
+        )
+        .route(
+            "/management/match-import",
+            get(routes::match_import::match_import_get),
+        )
+        .route(
+            "/management/match-import",
+            post(routes::match_import::match_import_post),
+        )
+        .route("/comments", get(routes::comments::comments_list_partial))
+        .route("/comments", post(routes::comments::comment_create))
+        .route(
+            "/comments/:id/delete",
+            post(routes::comments::comment_delete),
+        )
+        .route(
+            "/notifications",
+            get(routes::notifications::notifications_get),
+        )
+        .route(
+            "/notifications/unread-count",
+            get(routes::notifications::notifications_badge_get),
+        )
+        .route(
+            "/notifications/:id/open",
+            get(routes::notifications::notification_open),
+        )
+        .route(
+            "/notifications/read-all",
+            post(routes::notifications::notifications_read_all),
+        )
+        .route("/tasks", get(routes::tasks::tasks_get))
+        .route("/tasks/list", get(routes::tasks::tasks_list_partial))
+        .route("/tasks/new", get(routes::tasks::task_create_form))
+        .route("/tasks", post(routes::tasks::task_create))
+        .route("/tasks/:id/edit", get(routes::tasks::task_edit_form))
+        .route("/tasks/:id", post(routes::tasks::task_update))
+        .route("/tasks/:id/complete", post(routes::tasks::task_complete))
+        .route("/tasks/:id/delete", post(routes::tasks::task_delete))
+        .route("/whats-new", get(routes::changelog::whats_new_get))
+        .route(
+            "/whats-new/dismiss",
+            post(routes::changelog::whats_new_dismiss),
+        )
+        .route(
+            "/reports/stats-reconciliation",
+            get(routes::reports::stats_reconciliation_get),
+        )
+        .route("/reports/duplicates", get(routes::reports::duplicates_get))
+        .route(
+            "/reports/unidentified-goals",
+            get(routes::reports::unidentified_goals_get),
+        )
+        .route("/reports/builder", get(routes::reports::report_builder_get))
+        .route(
+            "/reports/builder/export",
+            get(routes::reports::report_builder_export),
+        )
+        .route("/reports/saved", post(routes::reports::saved_report_create))
+        .route("/reports/saved/:id", get(routes::reports::saved_report_get))
+        .route(
+            "/reports/saved/:id/delete",
+            post(routes::reports::saved_report_delete),
+        )
+        .route("/graphql", get(routes::graphql::graphql_get))
+        .route("/graphql", post(routes::graphql::graphql_post))
+        .route("/countries", get(routes::countries::countries_get))
+        .route("/api/countries", get(routes::countries::countries_list_api))
+        .route(
+            "/api/countries/:id/toggle",
+            post(routes::countries::country_toggle_enabled),
+        )
+        .route("/events", get(routes::events::events_get))
+        .route("/events/list", get(routes::events::events_list_partial))
+        .route("/events/:id", get(routes::events::event_detail))
+        .route("/events/new", get(routes::events::event_create_form))
+        .route("/events", post(routes::events::event_create))
+        .route("/events/:id/edit", get(routes::events::event_edit_form))
+        .route("/events/:id", post(routes::events::event_update))
+        .route("/events/:id/delete", post(routes::events::event_delete))
+        .route(
+            "/events/:id/roster-rules/edit",
+            get(routes::events::roster_rules_edit_form),
+        )
+        .route(
+            "/events/:id/roster-rules",
+            post(routes::events::roster_rules_update),
+        )
+        .route(
+            "/events/:event_id/seasons/new",
+            get(routes::seasons::event_season_create_form),
+        )
+        .route("/teams", get(routes::teams::teams_get))
+        .route("/teams/list", get(routes::teams::teams_list_partial))
+        .route("/teams/export", get(routes::teams::teams_export))
+        .route("/api/teams/export", get(routes::teams::teams_export))
+        .route("/teams/new", get(routes::teams::team_create_form))
+        .route("/teams/import", get(routes::teams::team_import_form))
+        .route(
+            "/teams/import",
+            post(routes::teams::team_import).layer(axum::extract::DefaultBodyLimit::max(
+                routes::teams::TEAM_IMPORT_MAX_BYTES,
+            )),
+        )
+        .route("/teams", post(routes::teams::team_create))
+        .route("/teams/:id", get(routes::teams::team_detail))
+        .route("/teams/:id/edit", get(routes::teams::team_edit_form))
+        .route("/teams/:id", post(routes::teams::team_update))
+        .route("/teams/:id/delete", post(routes::teams::team_delete))
+        .route("/teams/:id/export", get(routes::teams::team_export))
+        .route("/api/teams/:id/stats", get(routes::teams::team_stats_api))
+        .route(
+            "/api/matches/import",
+            post(routes::match_import::match_import_api),
+        )
+        .route(
+            "/api/matches/:id/score-events",
+            get(routes::matches::score_events_api),
+        )
+        .route(
+            "/api/score-events",
+            get(routes::matches::score_events_feed_api),
+        )
+        .route(
+            "/teams/:id/roster-diff",
+            get(routes::teams::team_roster_diff_get),
+        )
+        .route("/teams/:id/splits", get(routes::teams::team_splits_get))
+        .route("/teams/:id/merge", get(routes::teams::team_merge_get))
+        .route("/teams/:id/merge", post(routes::teams::team_merge_post))
+        .route(
+            "/teams/:id/calendar-subscriptions",
+            post(routes::calendar_subscriptions::calendar_subscription_create),
+        )
+        .route(
+            "/teams/:id/calendar-subscriptions/:subscription_id/revoke",
+            post(routes::calendar_subscriptions::calendar_subscription_revoke),
+        )
+        .route("/arenas", get(routes::arenas::arenas_get))
+        .route("/arenas/list", get(routes::arenas::arenas_list_partial))
+        .route("/arenas/new", get(routes::arenas::arena_create_form))
+        .route("/arenas", post(routes::arenas::arena_create))
+        .route("/arenas/:id", get(routes::arenas::arena_detail))
+        .route("/arenas/:id/edit", get(routes::arenas::arena_edit_form))
+        .route("/arenas/:id", post(routes::arenas::arena_update))
+        .route("/arenas/:id/delete", post(routes::arenas::arena_delete))
+        .route("/referees", get(routes::referees::referees_get))
+        .route(
+            "/referees/list",
+            get(routes::referees::referees_list_partial),
+        )
+        .route("/referees/new", get(routes::referees::referee_create_form))
+        .route("/referees", post(routes::referees::referee_create))
+        .route(
+            "/referees/:id/edit",
+            get(routes::referees::referee_edit_form),
+        )
+        .route("/referees/:id", post(routes::referees::referee_update))
+        .route(
+            "/referees/:id/delete",
+            post(routes::referees::referee_delete),
+        )
+        .route("/staff", get(routes::staff::staff_get))
+        .route("/staff/list", get(routes::staff::staff_list_partial))
+        .route("/staff/new", get(routes::staff::staff_member_create_form))
+        .route("/staff", post(routes::staff::staff_member_create))
+        .route(
+            "/staff/:id/edit",
+            get(routes::staff::staff_member_edit_form),
+        )
+        .route("/staff/:id", post(routes::staff::staff_member_update))
+        .route(
+            "/staff/:id/delete",
+            post(routes::staff::staff_member_delete),
+        )
+        .route(
+            "/team-participations/new",
+            get(routes::team_participations::team_participation_create_form),
+        )
+        .route(
+            "/team-participations",
+            post(routes::team_participations::team_participation_create),
+        )
+        .route("/players", get(routes::players::players_get))
+        .route("/players/list", get(routes::players::players_list_partial))
+        .route("/players/export", get(routes::players::players_export))
+        .route("/api/players/export", get(routes::players::players_export))
+        .route("/players/new", get(routes::players::player_create_form))
+        .route("/players/import", get(routes::players::player_import_form))
+        .route("/players/import", post(routes::players::player_import))
+        .route("/players/streaks", get(routes::leaders::streak_leaders_get))
+        .route("/players", post(routes::players::player_create))
+        .route("/players/:id", get(routes::players::player_detail))
+        .route("/players/:id/edit", get(routes::players::player_edit_form))
+        .route("/players/:id", post(routes::players::player_update))
+        .route("/players/:id/delete", post(routes::players::player_delete))
+        .route(
+            "/players/:id/history",
+            get(routes::change_history::player_history_get),
+        )
+        .route(
+            "/players/:id/scoring",
+            get(routes::players::player_scoring_get),
+        )
+        .route(
+            "/players/:id/splits",
+            get(routes::players::player_splits_get),
+        )
+        .route(
+            "/players/:id/scoring/list",
+            get(routes::players::player_scoring_list_partial),
+        )
+        .route(
+            "/players/:id/event-stats/new",
+            get(routes::players::event_stats_create_form),
+        )
+        .route(
+            "/players/:id/event-stats",
+            post(routes::players::event_stats_create),
+        )
+        .route(
+            "/players/:player_id/event-stats/:id/edit",
+            get(routes::players::event_stats_edit_form),
+        )
+        .route(
+            "/players/:player_id/event-stats/:id",
+            post(routes::players::event_stats_update),
+        )
+        .route(
+            "/players/:player_id/event-stats/:id/delete",
+            post(routes::players::event_stats_delete),
+        )
+        // Property change routes
+        .route(
+            "/players/:id/property-changes/new",
+            get(routes::players::property_change_create_form),
+        )
+        .route(
+            "/players/:id/property-changes",
+            post(routes::players::property_change_create),
+        )
+        .route(
+            "/players/:player_id/property-changes/:id/edit",
+            get(routes::players::property_change_edit_form),
+        )
+        .route(
+            "/players/:player_id/property-changes/:id",
+            post(routes::players::property_change_update),
+        )
+        .route(
+            "/players/:player_id/property-changes/:id/delete",
+            post(routes::players::property_change_delete),
+        )
+        .route("/seasons", get(routes::seasons::seasons_get))
+        .route("/seasons/list", get(routes::seasons::seasons_list_partial))
+        .route("/seasons/new", get(routes::seasons::season_create_form))
+        .route("/seasons", post(routes::seasons::season_create))
+        .route("/seasons/:id", get(routes::seasons::season_detail))
+        .route("/seasons/:id/edit", get(routes::seasons::season_edit_form))
+        .route("/seasons/:id", post(routes::seasons::season_update))
+        .route("/seasons/:id/delete", post(routes::seasons::season_delete))
+        .route(
+            "/seasons/:id/clone",
+            get(routes::seasons::season_clone_form),
+        )
+        .route("/seasons/:id/clone", post(routes::seasons::season_clone))
+        .route(
+            "/seasons/:id/export/xlsx",
+            get(routes::season_export::season_export_xlsx),
+        )
+        .route(
+            "/seasons/:season_id/teams/add",
+            get(routes::seasons::season_add_team_form),
+        )
+        .route(
+            "/seasons/:season_id/teams",
+            post(routes::seasons::season_add_team),
+        )
+        .route(
+            "/seasons/:season_id/playoffs/new",
+            get(routes::playoffs::series_create_form),
+        )
+        .route(
+            "/seasons/:season_id/playoffs",
+            post(routes::playoffs::series_create),
+        )
+        .route(
+            "/seasons/:season_id/playoffs/generate",
+            post(routes::playoffs::bracket_generate),
+        )
+        .route(
+            "/seasons/:season_id/groups/new",
+            get(routes::season_groups::group_create_form),
+        )
+        .route(
+            "/seasons/:season_id/groups",
+            post(routes::season_groups::group_create),
+        )
+        .route(
+            "/season-groups/:id/delete",
+            post(routes::season_groups::group_delete),
+        )
+        .route(
+            "/seasons/:season_id/awards/new",
+            get(routes::awards::award_create_form),
+        )
+        .route(
+            "/seasons/:season_id/awards",
+            post(routes::awards::award_create),
+        )
+        .route(
+            "/season-awards/:id/delete",
+            post(routes::awards::award_delete),
+        )
+        .route(
+            "/seasons/:id/leaders",
+            get(routes::leaders::season_leaders_get),
+        )
+        .route(
+            "/api/seasons/:id/leaders",
+            get(routes::leaders::season_leaders_api),
+        )
+        .route(
+            "/api/seasons/:id/period-distribution",
+            get(routes::seasons::season_period_distribution_api),
+        )
+        .route(
+            "/team-participations/:id/group",
+            post(routes::season_groups::team_group_assign),
+        )
+        .route(
+            "/team-participations/:id/placement",
+            post(routes::placements::team_placement_set),
+        )
+        .route(
+            "/team-participations/:id/seed",
+            post(routes::placements::team_seed_set),
+        )
+        .route(
+            "/playoffs/:id/games/new",
+            get(routes::playoffs::series_game_create_form),
+        )
+        .route(
+            "/playoffs/:id/games",
+            post(routes::playoffs::series_game_create),
+        )
+        .route(
+            "/playoffs/:id/delete",
+            post(routes::playoffs::series_delete),
+        )
+        .route(
+            "/team-participations/:id/delete",
+            post(routes::seasons::team_participation_delete),
+        )
+        .route(
+            "/team-participations/:id/roster",
+            get(routes::player_contracts::roster_get),
+        )
+        .route(
+            "/team-participations/:id/roster/import",
+            get(routes::player_contracts::roster_import_form),
+        )
+        .route(
+            "/team-participations/:id/roster/import/review",
+            post(routes::player_contracts::roster_import_review),
+        )
+        .route(
+            "/team-participations/:id/roster/import",
+            post(routes::player_contracts::roster_import),
+        )
+        .route(
+            "/team-participations/:id/roster/add-player",
+            get(routes::player_contracts::roster_add_player_form),
+        )
+        .route(
+            "/team-participations/:id/roster",
+            post(routes::player_contracts::roster_add_player),
+        )
+        .route(
+            "/team-participations/:id/staff/add",
+            get(routes::staff::roster_add_staff_form),
+        )
+        .route(
+            "/team-participations/:id/staff",
+            post(routes::staff::roster_add_staff),
+        )
+        .route(
+            "/team-participations/:id/lines/assign",
+            post(routes::lines::lines_assign),
+        )
+        .route(
+            "/team-participations/:id/lines/unassign",
+            post(routes::lines::lines_unassign),
+        )
+        .route(
+            "/player-contracts/:id/edit",
+            get(routes::player_contracts::player_contract_edit_form),
+        )
+        .route(
+            "/player-contracts/:id",
+            post(routes::player_contracts::player_contract_update),
+        )
+        .route(
+            "/player-contracts/:id/delete",
+            post(routes::player_contracts::player_contract_delete),
+        )
+        .route(
+            "/player-contracts/:id/transfer",
+            get(routes::transfers::transfer_form),
+        )
+        .route(
+            "/player-contracts/:id/transfer",
+            post(routes::transfers::transfer_create),
+        )
+        .route(
+            "/staff-contracts/:id/delete",
+            post(routes::staff::staff_contract_delete),
+        )
+        .route("/matches", get(routes::matches::matches_get))
+        .route("/matches/list", get(routes::matches::matches_list_partial))
+        .route("/matches/export", get(routes::matches::matches_export))
+        .route("/api/matches/export", get(routes::matches::matches_export))
+        .route(
+            "/matches/score-events/export",
+            get(routes::matches::score_events_export),
+        )
+        .route(
+            "/api/score-events/export",
+            get(routes::matches::score_events_export),
+        )
+        .route(
+            "/matches/teams-for-season",
+            get(routes::matches::teams_for_season),
+        )
+        .route("/matches/new", get(routes::matches::match_create_form))
+        .route(
+            "/matches/quick-create/season",
+            get(routes::matches::quick_create_season_form),
+        )
+        .route(
+            "/matches/quick-create/season",
+            post(routes::matches::quick_create_season),
+        )
+        .route(
+            "/matches/quick-create/team",
+            get(routes::matches::quick_create_team_form),
+        )
+        .route(
+            "/matches/quick-create/team",
+            post(routes::matches::quick_create_team),
+        )
+        .route("/matches", post(routes::matches::match_create))
+        .route("/matches/:id", get(routes::matches::match_detail))
+        .route(
+            "/matches/:id/events",
+            get(routes::matches::match_goal_events),
+        )
+        .route("/matches/:id/console", get(routes::matches::match_console))
+        .route("/matches/:id/edit", get(routes::matches::match_edit_form))
+        .route("/matches/:id", post(routes::matches::match_update))
+        .route("/matches/:id/delete", post(routes::matches::match_delete))
+        .route(
+            "/matches/:id/card.svg",
+            get(routes::matches::match_share_card_get),
+        )
+        .route(
+            "/matches/:id/history",
+            get(routes::change_history::match_history_get),
+        )
+        .route(
+            "/matches/:id/shots/edit",
+            get(routes::matches::match_shots_edit_form),
+        )
+        .route(
+            "/matches/:id/shots",
+            post(routes::matches::match_shots_update),
+        )
+        .route(
+            "/matches/:id/lineup/:team_id/edit",
+            get(routes::matches::match_lineup_edit_form),
+        )
+        .route(
+            "/matches/:id/lineup/:team_id",
+            post(routes::matches::match_lineup_update),
+        )
+        .route(
+            "/matches/:id/faceoffs/:team_id/edit",
+            get(routes::matches::match_faceoffs_edit_form),
+        )
+        .route(
+            "/matches/:id/faceoffs/:team_id",
+            post(routes::matches::match_faceoffs_update),
+        )
+        .route(
+            "/matches/:id/officials/edit",
+            get(routes::matches::match_officials_edit_form),
+        )
+        .route(
+            "/matches/:id/officials",
+            post(routes::matches::match_officials_update),
+        )
+        .route(
+            "/matches/:match_id/score-events/new",
+            get(routes::matches::score_event_create_form),
+        )
+        .route(
+            "/matches/:match_id/score-events",
+            post(routes::matches::score_event_create),
+        )
+        .route(
+            "/matches/score-events/:id/edit",
+            get(routes::matches::score_event_edit_form),
+        )
+        .route(
+            "/matches/score-events/:id",
+            post(routes::matches::score_event_update),
+        )
+        .route(
+            "/matches/score-events/:id/delete",
+            post(routes::matches::score_event_delete),
+        )
+        .route(
+            "/matches/score-events/:id/on-ice/edit",
+            get(routes::matches::score_event_on_ice_edit_form),
+        )
+        .route(
+            "/matches/score-events/:id/on-ice",
+            post(routes::matches::score_event_on_ice_update),
+        );
+
+    // Match predictions (pick'em) are optional
+    if state.predictions_enabled {
+        protected_routes = protected_routes
+            .route("/predictions", get(routes::predictions::predictions_get))
+            .route(
+                "/predictions/leaderboard",
+                get(routes::predictions::predictions_leaderboard_get),
+            )
+            .route(
+                "/predictions/:match_id",
+                post(routes::predictions::prediction_save),
+            );
+    }
+
+    // Backups, snapshots, instance transfer, webhooks and settings expose
+    // users, sessions and secrets, so only admins reach them; the "What's new"
+    // editor publishes to every user, so it is limited to admins as well
+    let admin_routes = Router::new()
+        .route(
+            "/management/whats-new",
+            get(routes::changelog::changelog_get),
+        )
+        .route(
+            "/management/whats-new/list",
+            get(routes::changelog::changelog_list_partial),
+        )
+        .route(
+            "/management/whats-new/new",
+            get(routes::changelog::changelog_create_form),
+        )
+        .route(
+            "/management/whats-new",
+            post(routes::changelog::changelog_create),
+        )
+        .route(
+            "/management/whats-new/:id/edit",
+            get(routes::changelog::changelog_edit_form),
+        )
+        .route(
+            "/management/whats-new/:id",
+            post(routes::changelog::changelog_update),
+        )
+        .route(
+            "/management/whats-new/:id/delete",
+            post(routes::changelog::changelog_delete),
+        )
+        .route(
+            "/management/instance-transfer",
+            get(routes::instance_transfer::instance_transfer_get),
+        )
+        .route(
+            "/management/instance-transfer",
+            post(routes::instance_transfer::instance_import).layer(
+                axum::extract::DefaultBodyLimit::max(
+                    routes::instance_transfer::INSTANCE_IMPORT_MAX_BYTES,
+                ),
+            ),
+        )
+        .route(
+            "/management/instance-transfer/export",
+            get(routes::instance_transfer::instance_export),
+        )
+        .route(
+            "/management/slow-queries",
+            get(routes::slow_queries::slow_queries_get),
+        )
+        .route(
+            "/management/slow-queries/clear",
+            post(routes::slow_queries::slow_queries_clear),
+        )
+        .route(
+            "/management/status/maintenance",
+            post(routes::status::status_run_maintenance),
+        )
+        .route("/management/backups", get(routes::backups::backups_get))
+        .route("/management/backups", post(routes::backups::backup_run))
+        .route(
+            "/management/backups/:name",
+            get(routes::backups::backup_download),
+        )
+        .route(
+            "/management/status/snapshot",
+            get(routes::status::status_snapshot),
+        )
+        .route("/management/webhooks", get(routes::webhooks::webhooks_get))
+        .route(
+            "/management/webhooks",
+            post(routes::webhooks::webhook_create),
+        )
+        .route(
+            "/management/webhooks/:id",
+            post(routes::webhooks::webhook_update),
+        )
+        .route(
+            "/management/webhooks/:id/delete",
+            post(routes::webhooks::webhook_delete),
+        )
+        .route(
+            "/management/webhooks/deliveries/:id/retry",
+            post(routes::webhooks::webhook_delivery_retry),
+        )
+        .route("/management/settings", get(routes::settings::settings_get))
+        .route(
+            "/management/settings",
+            post(routes::settings::settings_update),
+        )
+        .route(
+            "/management/settings/chat-webhooks",
+            post(routes::settings::chat_webhook_create),
+        )
+        .route(
+            "/management/settings/chat-webhooks/:id",
+            post(routes::settings::chat_webhook_update),
+        )
+        .route(
+            "/management/settings/chat-webhooks/:id/delete",
+            post(routes::settings::chat_webhook_delete),
+        )
+        .route(
+            "/management/settings/chat-webhooks/:id/test",
+            post(routes::settings::chat_webhook_test),
+        )
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_admin));
+
+    // User management is limited to admins
+    let user_routes = Router::new()
+        .route("/users", get(routes::users::users_get))
+        .route("/users", post(routes::users::user_create))
+        .route("/users/activity", get(routes::users::users_activity_get))
+        .route(
+            "/users/registration",
+            post(routes::users::registration_settings_update),
+        )
+        .route("/users/:id/role", post(routes::users::user_role_update))
+        .route("/users/:id/active", post(routes::users::user_active_update))
+        .route(
+            "/users/:id/reset-password",
+            post(routes::users::user_password_reset),
+        )
+        .route("/users/:id/unlock", post(routes::users::user_unlock))
+        .route_layer(middleware::from_fn_with_state(state.clone(), require_admin));
+
+    let protected_routes = protected_routes
+        .merge(admin_routes)
+        .merge(user_routes)
+        .layer(middleware::from_fn_with_state(
+            state.api_rate_limiter.clone(),
+            rate_limit_api,
+        ))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            demo_read_only,
+        ))
+        .layer(middleware::from_fn_with_state(state.clone(), require_auth));
+
+    // Public scoreboard JSON (no auth, rate limited)
+    let public_api_routes = Router::new()
+        .route(
+            "/public/api/live",
+            get(routes::public_api::live_matches_get),
+        )
+        .route(
+            "/public/api/matches/today",
+            get(routes::public_api::today_matches_get),
+        )
+        .route(
+            "/public/api/matches/:id/live",
+            get(routes::live_updates::match_live_ws),
+        )
+        .layer(middleware::from_fn_with_state(
+            state.api_rate_limiter.clone(),
+            rate_limit_api,
+        ));
+
+    // Public read-only site (no auth)
+    let public_site_routes = Router::new()
+        .route("/public", get(routes::public_site::public_home_get))
+        .route(
+            "/public/seasons/:season",
+            get(routes::public_site::public_season_get),
+        )
+        .route(
+            "/public/seasons/:season/teams/:team",
+            get(routes::public_site::public_roster_get),
+        )
+        .route(
+            "/public/seasons/:season/players",
+            get(routes::public_site::public_players_get),
+        )
+        .route("/sitemap.xml", get(routes::sitemap::sitemap_get));
+
+    // Embeddable widgets (no auth, readable from any origin)
+    let widget_routes = Router::new()
+        .route(
+            "/widgets/season/:id/standings",
+            get(routes::widgets::standings_widget_get),
+        )
+        .route(
+            "/widgets/season/:id/schedule",
+            get(routes::widgets::schedule_widget_get),
+        )
+        .route(
+            "/widgets/scoreboard",
+            get(routes::widgets::scoreboard_widget_get),
+        )
+        .layer(
+            CorsLayer::new()
+                .allow_origin(Any)
+                .allow_methods([Method::GET]),
+        );
+
+    // Health check (no auth)
+    let health_routes = Router::new()
+        .route("/health", get(health_handler))
+        .route("/liveness", get(health_handler))
+        .route("/readiness", get(readiness_handler));
+
+    // Static assets routes (embedded or from filesystem in dev mode)
+    let static_routes = Router::new().route("/static/*path", get(static_asset_handler));
+
+    // Build the complete application
+    Router::new()
+        .merge(public_routes)
+        .merge(protected_routes)
+        .merge(public_api_routes)
+        .merge(public_site_routes)
+        .merge(widget_routes)
+        .merge(health_routes)
+        .merge(static_routes)
+        .with_state(state.clone())
+        .layer(middleware::from_fn_with_state(
+            state.trusted_proxies,
+            auth::audit::resolve_client_ip,
+        ))
+        .layer(middleware::from_fn(
+            i18n::middleware::translation_context_middleware,
+        ))
+        .layer(middleware::from_fn(assets::preload_hints))
+        .layer(CompressionLayer::new().gzip(true))
+        .layer(TraceLayer::new_for_http())
+}
+
+/// Most open tasks shown on the dashboard
+const DASHBOARD_TASKS_LIMIT: i64 = 5;
+
+async fn root_handler(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    request: Request,
+) -> Html<String> {
+    // Extract session from request extensions (added by require_auth middleware)
+    let session = request
+        .extensions()
+        .get::<auth::Session>()
+        .expect("Session should be available in protected route")
+        .clone();
+
+    // Fetch dashboard stats
+    let stats = service::dashboard::get_dashboard_stats(&state.db)
+        .await
+        .unwrap_or_default();
+
+    let recent_activity = service::dashboard::get_recent_activity(&state.db)
+        .await
+        .unwrap_or_default();
+
+    let milestones = service::milestones::get_recent_milestones(&state.db, 10)
+        .await
+        .unwrap_or_default();
+
+    let leaders = match load_dashboard_leaders(&state).await {
+        Ok(leaders) => leaders,
+        Err(e) => {
+            tracing::warn!("Failed to load dashboard leaders: {}", e);
+            None
+        }
+    };
+
+    let my_tasks = match service::tasks::get_open_tasks_for_user(
+        &state.db,
+        session.user_id,
+        DASHBOARD_TASKS_LIMIT,
+    )
+    .await
+    {
+        Ok(tasks) => tasks,
+        Err(e) => {
+            tracing::warn!("Failed to load dashboard tasks: {}", e);
+            Vec::new()
+        }
+    };
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+
+    let content = dashboard_page(
+        &t,
+        &stats,
+        &recent_activity,
+        &milestones,
+        leaders.as_ref(),
+        &my_tasks,
+        &today,
+        state.predictions_enabled,
+    );
+    let html = admin_layout("Dashboard", &session, "/", &t, content);
+
+    Html(html.into_string())
+}
+
+/// Current season with its leaders, `None` without seasons
+async fn load_dashboard_leaders(
+    state: &AppState,
+) -> Result<
+    Option<(
+        service::seasons::SeasonEntity,
+        service::leaders::SeasonLeadersEntity,
+    )>,
+    sqlx::Error,
+> {
+    let Some(season_id) = service::seasons::get_current_season_id(&state.db).await? else {
+        return Ok(None);
+    };
+    let Some(season) = service::seasons::get_season_by_id(&state.db, season_id).await? else {
+        return Ok(None);
+    };
+    let leaders = service::leaders::get_season_leaders(
+        &state.db,
+        season_id,
+        views::pages::leaders::PANEL_LEADERS_LIMIT,
+    )
+    .await?;
+
+    Ok(Some((season, leaders)))
+}
+
+/// GET /dashboard/stats - Returns dashboard stats partial for HTMX updates
+async fn dashboard_stats_get(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> Html<String> {
+    let stats = service::dashboard::get_dashboard_stats(&state.db)
+        .await
+        .unwrap_or_default();
+
+    Html(views::pages::dashboard::dashboard_stats_partial(&t, &stats).into_string())
+}
+
+async fn health_handler() -> &'static str {
+    "OK"
+}
+
+async fn readiness_handler(State(state): State<AppState>) -> impl axum::response::IntoResponse {
+    match sqlx::query("SELECT 1").execute(&state.db).await {
+        Ok(_) => (axum::http::StatusCode::OK, "OK"),
+        Err(e) => {
+            tracing::error!("Readiness check failed: {}", e);
+            (axum::http::StatusCode::SERVICE_UNAVAILABLE, "Unavailable")
+        }
+    }
+}
+
+async fn static_asset_handler(Path(path): Path<String>) -> impl axum::response::IntoResponse {
+    assets::serve_static_asset(&path).await
+}
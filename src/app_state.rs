@@ -1,6 +1,17 @@
 use sqlx::SqlitePool;
 
-use crate::auth::SessionStore;
+use crate::auth::audit::TrustedProxies;
+use crate::auth::lockout::LockoutPolicy;
+use crate::auth::oauth::OAuthProviders;
+use crate::auth::{ApiRateLimiter, SessionStore};
+use crate::chat_notifications::ChatNotifier;
+use crate::graphql::{self, HockeySchema};
+use crate::live_updates::LiveUpdates;
+use crate::mailer::Mailer;
+use crate::maintenance::MaintenanceConfig;
+use crate::service::season_stats::SeasonStatsCache;
+use crate::slow_queries::SlowQueryLog;
+use crate::webhooks::WebhookDispatcher;
 
 #[derive(Clone)]
 pub struct AppState {
@@ -8,6 +19,41 @@ pub struct AppState {
     pub sessions: SessionStore,
     pub session_secret: String,
     pub is_production: bool,
+    /// Email users when they sign in from a new IP address
+    pub login_notify_new_ip: bool,
+    /// Lock accounts after repeated wrong passwords
+    pub lockout: LockoutPolicy,
+    /// Email of the read-only demo account; `None` when demo mode is off
+    pub demo_user_email: Option<String>,
+    /// Schedule of the nightly database maintenance job
+    pub maintenance: MaintenanceConfig,
+    /// Recent statements slower than `slow_query_ms`
+    pub slow_queries: SlowQueryLog,
+    /// Slow query threshold in milliseconds; 0 when logging is off
+    pub slow_query_ms: u64,
+    /// Whether the match predictions (pick'em) pages are mounted
+    pub predictions_enabled: bool,
+    /// Per-season player stats behind the percentile rankings
+    pub season_stats: SeasonStatsCache,
+    /// Posts results and milestones to the configured chat webhooks
+    pub chat: ChatNotifier,
+    /// Queues signed webhook deliveries of record changes
+    pub webhooks: WebhookDispatcher,
+    /// Score and status changes pushed to open match pages
+    pub live: LiveUpdates,
+    /// Read-only GraphQL schema served at `/graphql`
+    pub graphql: HockeySchema,
+    /// Per-client quota of the JSON API
+    pub api_rate_limiter: ApiRateLimiter,
+    /// Proxies whose forwarded client addresses are believed
+    pub trusted_proxies: TrustedProxies,
+    /// Sends registration emails; logs them when SMTP is not configured
+    pub mailer: Mailer,
+    /// Base of absolute links (emails, feeds, sitemap), from configuration
+    /// rather than request headers
+    pub public_url: String,
+    /// Google / GitHub sign-in; no providers unless configured
+    pub oauth: OAuthProviders,
 }
 
 impl AppState {
@@ -18,10 +64,94 @@ impl AppState {
         is_production: bool,
     ) -> Self {
         Self {
+            graphql: graphql::build_schema(db.clone()),
             db,
             sessions,
             session_secret,
             is_production,
+            login_notify_new_ip: false,
+            lockout: LockoutPolicy::default(),
+            demo_user_email: None,
+            maintenance: MaintenanceConfig::default(),
+            slow_queries: SlowQueryLog::new(),
+            slow_query_ms: 0,
+            predictions_enabled: false,
+            season_stats: SeasonStatsCache::new(),
+            chat: ChatNotifier::new(),
+            webhooks: WebhookDispatcher::new(),
+            live: LiveUpdates::new(),
+            api_rate_limiter: ApiRateLimiter::new(),
+            trusted_proxies: TrustedProxies::default(),
+            mailer: Mailer::log_only(),
+            public_url: "http://localhost:8080".to_string(),
+            oauth: OAuthProviders::default(),
         }
     }
+
+    /// Enable or disable new sign-in address notifications
+    pub fn with_login_notifications(mut self, enabled: bool) -> Self {
+        self.login_notify_new_ip = enabled;
+        self
+    }
+
+    /// Set when repeated wrong passwords lock an account
+    pub fn with_lockout(mut self, policy: LockoutPolicy) -> Self {
+        self.lockout = policy;
+        self
+    }
+
+    /// Enable demo mode with the given shared account
+    pub fn with_demo_user(mut self, email: Option<String>) -> Self {
+        self.demo_user_email = email;
+        self
+    }
+
+    /// Set the database maintenance schedule shown on the status page
+    pub fn with_maintenance(mut self, maintenance: MaintenanceConfig) -> Self {
+        self.maintenance = maintenance;
+        self
+    }
+
+    /// Share the slow query log filled by the tracing layer
+    pub fn with_slow_queries(mut self, log: SlowQueryLog, threshold_ms: u64) -> Self {
+        self.slow_queries = log;
+        self.slow_query_ms = threshold_ms;
+        self
+    }
+
+    /// Enable or disable match predictions
+    pub fn with_predictions(mut self, enabled: bool) -> Self {
+        self.predictions_enabled = enabled;
+        self
+    }
+
+    /// Set the JSON API quota of each client
+    pub fn with_api_rate_limit(mut self, per_minute: u32, burst: u32) -> Self {
+        self.api_rate_limiter = ApiRateLimiter::with_quota(per_minute, burst);
+        self
+    }
+
+    /// Believe forwarded client addresses from these proxies only
+    pub fn with_trusted_proxies(mut self, proxies: TrustedProxies) -> Self {
+        self.trusted_proxies = proxies;
+        self
+    }
+
+    /// Send emails with the given mailer
+    pub fn with_mailer(mut self, mailer: Mailer) -> Self {
+        self.mailer = mailer;
+        self
+    }
+
+    /// Build absolute links on this base address
+    pub fn with_public_url(mut self, url: String) -> Self {
+        self.public_url = url;
+        self
+    }
+
+    /// Offer sign-in with the configured external providers
+    pub fn with_oauth(mut self, oauth: OAuthProviders) -> Self {
+        self.oauth = oauth;
+        self
+    }
 }
@@ -1,10 +1,32 @@
 use axum::{
     body::Body,
+    extract::Request,
     http::{header, HeaderValue, Response, StatusCode},
+    middleware::Next,
     response::IntoResponse,
 };
 use rust_embed::RustEmbed;
 
+/// Stylesheets needed before the first paint of any page
+pub const CRITICAL_STYLES: &[&str] = &[
+    "css/theme.css",
+    "css/reset.css",
+    "css/layout.css",
+    "css/components.css",
+];
+
+/// HTMX library, loaded from the CDN
+pub const HTMX_SCRIPT_URL: &str = "https://unpkg.com/htmx.org@2.0.4";
+
+/// HTMX server-sent events extension (`hx-ext="sse"`), loaded by the pages using it
+pub const HTMX_SSE_SCRIPT_URL: &str = "https://unpkg.com/htmx-ext-sse@2.2.2/sse.js";
+
+/// Origin serving country flag images
+///
+/// Flags are fetched per country rather than from a local sprite, so the
+/// origin is preconnected instead of preloading individual images.
+pub const FLAG_ORIGIN: &str = "https://flagcdn.com";
+
 /// Embedded static assets (CSS, JS, images, flags)
 ///
 /// In development mode with debug-embed feature:
@@ -125,6 +147,71 @@ async fn serve_upload_file(path: &str) -> Response<Body> {
     }
 }
 
+/// Fingerprinted URL of a static asset, e.g. `/static/css/theme.css?v=1a2b3c4d`
+///
+/// The version is taken from the embedded file's content hash, so the URL
+/// changes whenever the file does and the year-long cache stays safe. Assets
+/// that are not embedded keep their plain URL.
+pub fn asset_url(path: &str) -> String {
+    let path = path.trim_start_matches('/');
+    match Assets::get(path) {
+        Some(content) => format!(
+            "/static/{}?v={}",
+            path,
+            hex::encode(&content.metadata.sha256_hash()[..4])
+        ),
+        None => format!("/static/{}", path),
+    }
+}
+
+/// Critical assets as `(url, as)` pairs for `<link rel="preload">`
+pub fn preload_assets() -> Vec<(String, &'static str)> {
+    let mut assets: Vec<(String, &'static str)> = CRITICAL_STYLES
+        .iter()
+        .map(|path| (asset_url(path), "style"))
+        .collect();
+    assets.push((HTMX_SCRIPT_URL.to_string(), "script"));
+    assets
+}
+
+/// `Link` header value announcing the critical assets
+///
+/// Reverse proxies and CDNs that support early hints turn this header into a
+/// `103 Early Hints` response, so the browser starts fetching while the page
+/// is still being rendered.
+pub fn preload_link_header() -> String {
+    let mut links: Vec<String> = preload_assets()
+        .into_iter()
+        .map(|(url, kind)| format!("<{}>; rel=preload; as={}", url, kind))
+        .collect();
+    links.push(format!("<{}>; rel=preconnect", FLAG_ORIGIN));
+    links.join(", ")
+}
+
+/// Middleware adding the preload `Link` header to full HTML pages
+///
+/// HTMX partials are skipped, their assets are already loaded, and so are the
+/// widgets, which inline their styles and load nothing else.
+pub async fn preload_hints(request: Request, next: Next) -> axum::response::Response {
+    let is_htmx = request.headers().contains_key("hx-request");
+    let is_widget = request.uri().path().starts_with("/widgets/");
+    let mut response = next.run(request).await;
+
+    let is_html = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("text/html"));
+
+    if is_html && !is_htmx && !is_widget {
+        if let Ok(value) = HeaderValue::from_str(&preload_link_header()) {
+            response.headers_mut().insert(header::LINK, value);
+        }
+    }
+
+    response
+}
+
 /// Helper to get asset content as string (useful for inlining CSS/JS)
 #[allow(dead_code)]
 pub fn get_asset_string(path: &str) -> Option<String> {
@@ -184,6 +271,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_asset_url_is_fingerprinted() {
+        let url = asset_url("css/theme.css");
+        assert!(url.starts_with("/static/css/theme.css?v="));
+        assert_eq!(url, asset_url("/css/theme.css"));
+
+        // Assets outside the embedded folder keep their plain URL
+        assert_eq!(asset_url("js/missing.js"), "/static/js/missing.js");
+    }
+
+    #[test]
+    fn test_preload_link_header() {
+        let link = preload_link_header();
+        assert!(link.contains(&format!(
+            "<{}>; rel=preload; as=style",
+            asset_url("css/theme.css")
+        )));
+        assert!(link.contains(&format!("<{}>; rel=preload; as=script", HTMX_SCRIPT_URL)));
+        assert!(link.contains("rel=preconnect"));
+    }
+
     #[tokio::test]
     async fn test_mime_type_detection() {
         // Test that different file types get correct MIME types
@@ -0,0 +1,368 @@
+use axum::http::Method;
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+
+use super::Session;
+
+/// Request header carrying an API key
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Prefix of every generated key, so leaked keys are easy to recognize
+const KEY_PREFIX: &str = "hk_";
+
+/// Characters of a key kept in clear to tell keys apart in the list
+const VISIBLE_PREFIX_LEN: usize = 11;
+
+/// Read-only part of the JSON API a scoped key may call
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiScope {
+    /// Match exports and score events
+    MatchesRead,
+    /// Team stats, season leaders and period distribution
+    StatsRead,
+    /// Team export
+    TeamsRead,
+    /// Player export
+    PlayersRead,
+}
+
+impl ApiScope {
+    pub const ALL: [ApiScope; 4] = [
+        ApiScope::MatchesRead,
+        ApiScope::StatsRead,
+        ApiScope::TeamsRead,
+        ApiScope::PlayersRead,
+    ];
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "matches:read" => Some(Self::MatchesRead),
+            "stats:read" => Some(Self::StatsRead),
+            "teams:read" => Some(Self::TeamsRead),
+            "players:read" => Some(Self::PlayersRead),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MatchesRead => "matches:read",
+            Self::StatsRead => "stats:read",
+            Self::TeamsRead => "teams:read",
+            Self::PlayersRead => "players:read",
+        }
+    }
+
+    /// Whether reading `path` falls under the scope
+    fn covers(&self, path: &str) -> bool {
+        let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+        match self {
+            Self::MatchesRead => matches!(
+                segments.as_slice(),
+                ["api", "matches", "export"]
+                    | ["api", "matches", _, "score-events"]
+                    | ["api", "score-events"]
+                    | ["api", "score-events", "export"]
+            ),
+            Self::StatsRead => matches!(
+                segments.as_slice(),
+                ["api", "teams", _, "stats"]
+                    | ["api", "seasons", _, "leaders" | "period-distribution"]
+            ),
+            Self::TeamsRead => segments == ["api", "teams", "export"],
+            Self::PlayersRead => segments == ["api", "players", "export"],
+        }
+    }
+}
+
+/// Stored form of a scope list; `None` for a key with full access
+fn join_scopes(scopes: &[ApiScope]) -> Option<String> {
+    (!scopes.is_empty()).then(|| {
+        scopes
+            .iter()
+            .map(ApiScope::as_str)
+            .collect::<Vec<_>>()
+            .join(" ")
+    })
+}
+
+/// Scopes of a stored list; unknown names grant nothing
+fn parse_scopes(scopes: &str) -> Vec<ApiScope> {
+    scopes
+        .split_whitespace()
+        .filter_map(ApiScope::from_str)
+        .collect()
+}
+
+/// A user's API key as listed on the profile page; the key itself is not stored
+#[derive(Debug, Clone)]
+pub struct ApiKeyEntry {
+    pub id: i64,
+    pub name: String,
+    /// First characters of the key, e.g. `hk_3f9a1c0b`
+    pub key_prefix: String,
+    /// Space-separated scopes; `None` for full access as the user
+    pub scopes: Option<String>,
+    pub created_at: String,
+    pub last_used_at: Option<String>,
+}
+
+impl ApiKeyEntry {
+    /// Scopes of a read-only key; `None` for full access
+    pub fn scopes(&self) -> Option<Vec<ApiScope>> {
+        self.scopes.as_deref().map(parse_scopes)
+    }
+}
+
+/// An API key that passed authentication
+#[derive(Debug, Clone)]
+pub struct ApiKeyAuth {
+    /// Request-only session of the key's user
+    pub session: Session,
+    /// `None` for full access as the user
+    pub scopes: Option<Vec<ApiScope>>,
+}
+
+impl ApiKeyAuth {
+    /// Whether the key may make this request; scoped keys only read
+    pub fn allows(&self, method: &Method, path: &str) -> bool {
+        match &self.scopes {
+            None => true,
+            Some(scopes) => {
+                (method == Method::GET || method == Method::HEAD)
+                    && scopes.iter().any(|scope| scope.covers(path))
+            }
+        }
+    }
+}
+
+/// Hex SHA-256 of a key, as stored in the database
+///
+/// Keys are long random strings, so a fast unsalted hash is enough to make
+/// a leaked table useless.
+fn hash_key(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+/// Create a key for a user; returns its ID and the key, which is shown only once
+///
+/// With `scopes` the key only reads those parts of the API; without, it
+/// acts as the user.
+pub async fn create_api_key(
+    db: &SqlitePool,
+    user_id: i64,
+    name: &str,
+    scopes: &[ApiScope],
+) -> Result<(i64, String), sqlx::Error> {
+    let key = format!(
+        "{}{}{}",
+        KEY_PREFIX,
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    );
+    let key_prefix = &key[..VISIBLE_PREFIX_LEN];
+    let key_hash = hash_key(&key);
+    let scopes = join_scopes(scopes);
+
+    let result = sqlx::query!(
+        "INSERT INTO api_key (user_id, name, key_prefix, key_hash, scopes) VALUES (?, ?, ?, ?, ?)",
+        user_id,
+        name,
+        key_prefix,
+        key_hash,
+        scopes
+    )
+    .execute(db)
+    .await?;
+
+    Ok((result.last_insert_rowid(), key))
+}
+
+/// Active keys of a user, newest first
+pub async fn get_user_api_keys(
+    db: &SqlitePool,
+    user_id: i64,
+) -> Result<Vec<ApiKeyEntry>, sqlx::Error> {
+    sqlx::query_as!(
+        ApiKeyEntry,
+        r#"
+        SELECT id as "id!", name, key_prefix, scopes, created_at, last_used_at
+        FROM api_key
+        WHERE user_id = ? AND revoked_at IS NULL
+        ORDER BY id DESC
+        "#,
+        user_id
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Revoke a key of a user; requests with it are rejected from now on
+pub async fn revoke_api_key(db: &SqlitePool, user_id: i64, id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE api_key SET revoked_at = CURRENT_TIMESTAMP
+        WHERE id = ? AND user_id = ? AND revoked_at IS NULL
+        "#,
+        id,
+        user_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Resolve an active key of an active user to a session and record the use
+///
+/// The session exists only for the request: its ID names the key (so the API
+/// rate limit counts per key) and its CSRF token is random, so HTML forms
+/// cannot be submitted with a key.
+pub async fn authenticate_api_key(
+    db: &SqlitePool,
+    key: &str,
+) -> Result<Option<ApiKeyAuth>, sqlx::Error> {
+    let key_hash = hash_key(key);
+    let row = sqlx::query!(
+        r#"
+        SELECT k.id as "id!", k.scopes, u.id as "user_id!", u.email, u.name
+        FROM api_key k
+        INNER JOIN users u ON k.user_id = u.id
+        WHERE k.key_hash = ? AND k.revoked_at IS NULL AND u.active = 1
+        "#,
+        key_hash
+    )
+    .fetch_optional(db)
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+
+    sqlx::query!(
+        "UPDATE api_key SET last_used_at = CURRENT_TIMESTAMP WHERE id = ?",
+        row.id
+    )
+    .execute(db)
+    .await?;
+
+    let now = Utc::now();
+    Ok(Some(ApiKeyAuth {
+        session: Session {
+            id: format!("api-key:{}", row.id),
+            user_id: row.user_id,
+            user_name: row.name.unwrap_or_else(|| row.email.clone()),
+            user_email: row.email,
+            created_at: now,
+            expires_at: now,
+            absolute_expires_at: now,
+            csrf_token: uuid::Uuid::new_v4().to_string(),
+        },
+        scopes: row.scopes.as_deref().map(parse_scopes),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn create_user(pool: &SqlitePool) -> i64 {
+        sqlx::query(
+            "INSERT INTO users (email, password_hash, name) VALUES ('bot@x.cz', 'x', 'Bot')",
+        )
+        .execute(pool)
+        .await
+        .unwrap()
+        .last_insert_rowid()
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_api_key_lifecycle(pool: SqlitePool) {
+        let user_id = create_user(&pool).await;
+        let (id, key) = create_api_key(&pool, user_id, "Import script", &[])
+            .await
+            .unwrap();
+        assert!(key.starts_with(KEY_PREFIX));
+        assert_eq!(key.len(), KEY_PREFIX.len() + 64);
+
+        let keys = get_user_api_keys(&pool, user_id).await.unwrap();
+        assert_eq!(keys.len(), 1);
+        assert_eq!(keys[0].key_prefix, &key[..VISIBLE_PREFIX_LEN]);
+        assert!(keys[0].last_used_at.is_none());
+
+        // Only the hash is stored
+        let stored: String = sqlx::query_scalar("SELECT key_hash FROM api_key WHERE id = ?")
+            .bind(id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_ne!(stored, key);
+
+        let auth = authenticate_api_key(&pool, &key).await.unwrap().unwrap();
+        assert!(auth.scopes.is_none());
+        assert!(auth.allows(&Method::POST, "/api/matches/import"));
+        let session = auth.session;
+        assert_eq!(session.user_id, user_id);
+        assert_eq!(session.user_name, "Bot");
+        assert_eq!(session.id, format!("api-key:{}", id));
+        assert!(get_user_api_keys(&pool, user_id).await.unwrap()[0]
+            .last_used_at
+            .is_some());
+
+        assert!(authenticate_api_key(&pool, "hk_wrong")
+            .await
+            .unwrap()
+            .is_none());
+
+        // Other users cannot revoke the key
+        assert!(!revoke_api_key(&pool, user_id + 1, id).await.unwrap());
+        assert!(revoke_api_key(&pool, user_id, id).await.unwrap());
+        assert!(authenticate_api_key(&pool, &key).await.unwrap().is_none());
+        assert!(get_user_api_keys(&pool, user_id).await.unwrap().is_empty());
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_scoped_api_key(pool: SqlitePool) {
+        let user_id = create_user(&pool).await;
+        let (_, key) = create_api_key(
+            &pool,
+            user_id,
+            "Scoreboard",
+            &[ApiScope::MatchesRead, ApiScope::StatsRead],
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            get_user_api_keys(&pool, user_id).await.unwrap()[0].scopes(),
+            Some(vec![ApiScope::MatchesRead, ApiScope::StatsRead])
+        );
+
+        let auth = authenticate_api_key(&pool, &key).await.unwrap().unwrap();
+        assert!(auth.allows(&Method::GET, "/api/score-events"));
+        assert!(auth.allows(&Method::GET, "/api/matches/7/score-events"));
+        assert!(auth.allows(&Method::GET, "/api/seasons/3/leaders"));
+        assert!(auth.allows(&Method::GET, "/api/teams/2/stats"));
+        // Read-only, and only within the scopes
+        assert!(!auth.allows(&Method::POST, "/api/matches/import"));
+        assert!(!auth.allows(&Method::GET, "/api/players/export"));
+        assert!(!auth.allows(&Method::GET, "/api/teams/export"));
+        assert!(!auth.allows(&Method::GET, "/graphql"));
+    }
+
+    #[test]
+    fn test_scope_names() {
+        for scope in ApiScope::ALL {
+            assert_eq!(ApiScope::from_str(scope.as_str()), Some(scope));
+        }
+        assert_eq!(
+            parse_scopes("stats:read admin:write  players:read"),
+            vec![ApiScope::StatsRead, ApiScope::PlayersRead]
+        );
+        assert_eq!(join_scopes(&[]), None);
+        assert_eq!(
+            join_scopes(&[ApiScope::TeamsRead, ApiScope::MatchesRead]).as_deref(),
+            Some("teams:read matches:read")
+        );
+    }
+}
@@ -0,0 +1,391 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::Arc;
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::HeaderMap,
+    middleware::Next,
+    response::Response,
+};
+use sqlx::SqlitePool;
+
+/// Maximum stored length of a user agent string
+const MAX_USER_AGENT_LEN: usize = 512;
+
+/// Why a login attempt was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginFailure {
+    /// No user with the submitted email exists
+    UnknownUser,
+    /// The user exists but the password did not match
+    InvalidPassword,
+    /// The password matched but the account is not active
+    Deactivated,
+    /// Signed in at Google or GitHub with an account no user has linked
+    UnlinkedAccount,
+    /// Too many wrong passwords; the password was not checked
+    LockedOut,
+}
+
+impl LoginFailure {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LoginFailure::UnknownUser => "unknown_user",
+            LoginFailure::InvalidPassword => "invalid_password",
+            LoginFailure::Deactivated => "deactivated",
+            LoginFailure::UnlinkedAccount => "unlinked_account",
+            LoginFailure::LockedOut => "locked_out",
+        }
+    }
+}
+
+/// A single sign-in attempt to be written to the audit log
+#[derive(Debug, Clone)]
+pub struct LoginAttempt {
+    pub user_id: Option<i64>,
+    pub email: String,
+    pub failure: Option<LoginFailure>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+impl LoginAttempt {
+    pub fn succeeded(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Stored audit log entry, as shown on the profile page
+#[derive(Debug, Clone)]
+pub struct LoginAuditEntry {
+    #[allow(dead_code)]
+    pub id: i64,
+    pub success: bool,
+    pub failure_reason: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub new_ip: bool,
+    pub created_at: String,
+}
+
+/// Stored audit log entry of any user, as shown to admins
+#[derive(Debug, Clone)]
+pub struct LoginActivityEntry {
+    pub id: i64,
+    pub user_id: Option<i64>,
+    /// Submitted email, kept for attempts that matched no user
+    pub email: String,
+    pub success: bool,
+    pub failure_reason: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub new_ip: bool,
+    pub created_at: String,
+}
+
+/// Write a login attempt to the audit log
+pub async fn record_login_attempt(
+    db: &SqlitePool,
+    attempt: &LoginAttempt,
+    new_ip: bool,
+) -> Result<i64, sqlx::Error> {
+    let success = attempt.succeeded();
+    let failure_reason = attempt.failure.map(|f| f.as_str());
+
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO login_audit (user_id, email, success, failure_reason, ip_address, user_agent, new_ip)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#,
+        attempt.user_id,
+        attempt.email,
+        success,
+        failure_reason,
+        attempt.ip_address,
+        attempt.user_agent,
+        new_ip
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Get the most recent login attempts of a user, newest first
+pub async fn get_recent_logins(
+    db: &SqlitePool,
+    user_id: i64,
+    limit: i64,
+) -> Result<Vec<LoginAuditEntry>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        LoginAuditEntry,
+        r#"
+        SELECT id as "id!", success as "success: bool", failure_reason, ip_address, user_agent,
+               new_ip as "new_ip: bool", created_at
+        FROM login_audit
+        WHERE user_id = ?
+        ORDER BY created_at DESC, id DESC
+        LIMIT ?
+        "#,
+        user_id,
+        limit
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// The most recent sign-in attempts of all users, newest first
+pub async fn get_recent_activity(
+    db: &SqlitePool,
+    failed_only: bool,
+    limit: i64,
+) -> Result<Vec<LoginActivityEntry>, sqlx::Error> {
+    sqlx::query_as!(
+        LoginActivityEntry,
+        r#"
+        SELECT id as "id!", user_id, email, success as "success: bool", failure_reason,
+               ip_address, user_agent, new_ip as "new_ip: bool", created_at
+        FROM login_audit
+        WHERE success = 0 OR NOT ?
+        ORDER BY created_at DESC, id DESC
+        LIMIT ?
+        "#,
+        failed_only,
+        limit
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Whether a successful sign-in from `ip_address` would be the first from that address
+///
+/// A user's very first sign-in is not considered new, so accounts do not
+/// trigger a notification the moment they are created.
+pub async fn is_new_ip_for_user(
+    db: &SqlitePool,
+    user_id: i64,
+    ip_address: &str,
+) -> Result<bool, sqlx::Error> {
+    let row = sqlx::query!(
+        r#"
+        SELECT
+            COUNT(*) as "total!: i64",
+            COALESCE(SUM(ip_address = ?), 0) as "from_ip!: i64"
+        FROM login_audit
+        WHERE user_id = ? AND success = 1
+        "#,
+        ip_address,
+        user_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(row.total > 0 && row.from_ip == 0)
+}
+
+/// Reverse proxies whose `X-Forwarded-For` and `X-Real-IP` headers are believed
+///
+/// Anyone can send those headers, so they only count when the connection
+/// itself comes from one of these addresses.
+#[derive(Debug, Clone)]
+pub struct TrustedProxies(Arc<[IpAddr]>);
+
+impl TrustedProxies {
+    pub fn new(proxies: Vec<IpAddr>) -> Self {
+        Self(proxies.into())
+    }
+
+    /// Loopback only, for a proxy on the same host
+    pub fn loopback() -> Self {
+        Self::new(vec![
+            IpAddr::from(Ipv4Addr::LOCALHOST),
+            IpAddr::from(Ipv6Addr::LOCALHOST),
+        ])
+    }
+
+    fn contains(&self, ip: &IpAddr) -> bool {
+        self.0.contains(ip)
+    }
+
+    /// Resolve the client IP address of a request
+    ///
+    /// Without a trusted proxy in front the socket address is the client. Behind
+    /// one, `X-Forwarded-For` is read from the right, because every proxy
+    /// appends the address it saw: the first entry not added by a trusted proxy
+    /// is the client, and anything left of it may be forged.
+    pub fn client_ip(
+        &self,
+        headers: &HeaderMap,
+        connect_info: Option<&ConnectInfo<SocketAddr>>,
+    ) -> Option<String> {
+        let peer = connect_info.map(|ConnectInfo(addr)| addr.ip())?;
+        if !self.contains(&peer) {
+            return Some(peer.to_string());
+        }
+
+        let header_ip = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+
+        let client = match header_ip("x-forwarded-for") {
+            Some(forwarded) => forwarded
+                .rsplit(',')
+                .map(|entry| entry.trim().parse::<IpAddr>())
+                .find(|entry| !matches!(entry, Ok(ip) if self.contains(ip)))
+                .and_then(Result::ok),
+            None => header_ip("x-real-ip").and_then(|ip| ip.trim().parse().ok()),
+        };
+
+        Some(client.unwrap_or(peer).to_string())
+    }
+}
+
+impl Default for TrustedProxies {
+    fn default() -> Self {
+        Self::loopback()
+    }
+}
+
+/// Client IP address of a request, resolved once by `resolve_client_ip`
+#[derive(Debug, Clone, Default)]
+pub struct ClientIp(pub Option<String>);
+
+/// Middleware storing the `ClientIp` of every request for handlers and rate limiters
+pub async fn resolve_client_ip(
+    State(proxies): State<TrustedProxies>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let connect_info = request.extensions().get::<ConnectInfo<SocketAddr>>();
+    let client_ip = ClientIp(proxies.client_ip(request.headers(), connect_info));
+    request.extensions_mut().insert(client_ip);
+    next.run(request).await
+}
+
+/// Extract the user agent header, truncated to a sane length
+pub fn user_agent(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(axum::http::header::USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .filter(|ua| !ua.is_empty())
+        .map(|ua| ua.chars().take(MAX_USER_AGENT_LEN).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(failure: Option<LoginFailure>, ip: &str) -> LoginAttempt {
+        LoginAttempt {
+            user_id: Some(1),
+            email: "test@example.com".to_string(),
+            failure,
+            ip_address: Some(ip.to_string()),
+            user_agent: Some("test-agent".to_string()),
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations", fixtures("users"))]
+    async fn test_record_and_list_logins(pool: SqlitePool) {
+        record_login_attempt(&pool, &attempt(None, "10.0.0.1"), false)
+            .await
+            .unwrap();
+        record_login_attempt(
+            &pool,
+            &attempt(Some(LoginFailure::InvalidPassword), "10.0.0.2"),
+            false,
+        )
+        .await
+        .unwrap();
+
+        let logins = get_recent_logins(&pool, 1, 10).await.unwrap();
+        assert_eq!(logins.len(), 2);
+        // Newest first
+        assert!(!logins[0].success);
+        assert_eq!(
+            logins[0].failure_reason.as_deref(),
+            Some("invalid_password")
+        );
+        assert!(logins[1].success);
+        assert_eq!(logins[1].user_agent.as_deref(), Some("test-agent"));
+
+        assert_eq!(get_recent_logins(&pool, 1, 1).await.unwrap().len(), 1);
+
+        let mut unknown = attempt(Some(LoginFailure::UnknownUser), "10.0.0.3");
+        unknown.user_id = None;
+        unknown.email = "nobody@example.com".to_string();
+        record_login_attempt(&pool, &unknown, false).await.unwrap();
+
+        let activity = get_recent_activity(&pool, false, 10).await.unwrap();
+        assert_eq!(activity.len(), 3);
+        assert_eq!(activity[0].email, "nobody@example.com");
+        assert_eq!(activity[0].user_id, None);
+        let failed = get_recent_activity(&pool, true, 10).await.unwrap();
+        assert_eq!(failed.len(), 2);
+        assert!(failed.iter().all(|entry| !entry.success));
+    }
+
+    #[sqlx::test(migrations = "./migrations", fixtures("users"))]
+    async fn test_is_new_ip_for_user(pool: SqlitePool) {
+        // First sign-in ever is not flagged
+        assert!(!is_new_ip_for_user(&pool, 1, "10.0.0.1").await.unwrap());
+
+        record_login_attempt(&pool, &attempt(None, "10.0.0.1"), false)
+            .await
+            .unwrap();
+        // Failed attempts do not make an address known
+        record_login_attempt(
+            &pool,
+            &attempt(Some(LoginFailure::InvalidPassword), "10.0.0.2"),
+            false,
+        )
+        .await
+        .unwrap();
+
+        assert!(!is_new_ip_for_user(&pool, 1, "10.0.0.1").await.unwrap());
+        assert!(is_new_ip_for_user(&pool, 1, "10.0.0.2").await.unwrap());
+    }
+
+    #[test]
+    fn test_client_ip_trusts_only_configured_proxies() {
+        let proxies = TrustedProxies::new(vec!["10.0.0.1".parse().unwrap()]);
+        let direct = ConnectInfo(SocketAddr::from(([192, 168, 1, 5], 4000)));
+        let proxied = ConnectInfo(SocketAddr::from(([10, 0, 0, 1], 4000)));
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-real-ip", "203.0.113.9".parse().unwrap());
+        assert_eq!(
+            proxies.client_ip(&headers, Some(&proxied)).as_deref(),
+            Some("203.0.113.9")
+        );
+
+        // The leftmost entry comes from the client and may be forged
+        headers.insert(
+            "x-forwarded-for",
+            "1.2.3.4, 198.51.100.7, 10.0.0.1".parse().unwrap(),
+        );
+        assert_eq!(
+            proxies.client_ip(&headers, Some(&proxied)).as_deref(),
+            Some("198.51.100.7")
+        );
+
+        // Headers of clients connecting directly are ignored
+        assert_eq!(
+            proxies.client_ip(&headers, Some(&direct)).as_deref(),
+            Some("192.168.1.5")
+        );
+
+        headers.insert("x-forwarded-for", "not-an-ip".parse().unwrap());
+        assert_eq!(
+            proxies.client_ip(&headers, Some(&proxied)).as_deref(),
+            Some("10.0.0.1")
+        );
+
+        assert_eq!(proxies.client_ip(&headers, None), None);
+    }
+}
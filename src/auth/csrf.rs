@@ -50,6 +50,7 @@ mod tests {
             user_name: "Test User".to_string(),
             created_at: Utc::now(),
             expires_at: Utc::now() + Duration::days(7),
+            absolute_expires_at: Utc::now() + Duration::days(30),
             csrf_token,
         }
     }
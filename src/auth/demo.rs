@@ -0,0 +1,207 @@
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderMap, HeaderName, Method},
+    middleware::Next,
+    response::{Html, IntoResponse, Redirect, Response},
+};
+use sqlx::SqlitePool;
+
+use super::session::Session;
+use crate::app_state::AppState;
+use crate::i18n::TranslationContext;
+use crate::views::components::toast::toast_warning;
+
+/// Display name of the shared demo account
+pub const DEMO_USER_NAME: &str = "Demo User";
+
+/// Password hash that no password can match, so the demo account can only be
+/// entered through the demo sign-in button
+const DEMO_PASSWORD_HASH: &str = "!demo-account-no-password";
+
+/// Create the demo account if it does not exist yet and return its ID
+///
+/// Fails when the address belongs to an account that is not an editor, so
+/// `DEMO_USER_EMAIL` can never hand visitors an admin account.
+pub async fn ensure_demo_user(db: &SqlitePool, email: &str) -> Result<i64, anyhow::Error> {
+    sqlx::query!(
+        "INSERT OR IGNORE INTO users (email, name, password_hash, role) VALUES (?, ?, ?, 'editor')",
+        email,
+        DEMO_USER_NAME,
+        DEMO_PASSWORD_HASH
+    )
+    .execute(db)
+    .await?;
+
+    let user = sqlx::query!(
+        r#"SELECT id as "id!", role FROM users WHERE email = ?"#,
+        email
+    )
+    .fetch_one(db)
+    .await?;
+    if user.role != "editor" {
+        anyhow::bail!(
+            "DEMO_USER_EMAIL {} belongs to an account with the {} role; \
+             demo mode needs an editor account",
+            email,
+            user.role
+        );
+    }
+
+    Ok(user.id)
+}
+
+/// Paths whose POST requests only read data
+const READ_ONLY_POST_PATHS: &[&str] = &["/graphql"];
+
+/// Whether a request can change data
+fn is_mutation(method: &Method, path: &str) -> bool {
+    !matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+        && !READ_ONLY_POST_PATHS.contains(&path)
+}
+
+/// Middleware that makes the demo account read-only
+///
+/// Must run after `require_auth`. Mutating requests from the demo account are
+/// answered with a "demo mode" toast instead of reaching the handler; HTMX
+/// requests keep the page as is, plain form posts are sent back where they
+/// came from.
+pub async fn demo_read_only(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(demo_email) = state.demo_user_email.as_deref() else {
+        return next.run(request).await;
+    };
+
+    let is_demo_session = request
+        .extensions()
+        .get::<Session>()
+        .is_some_and(|session| session.user_email == demo_email);
+
+    if !is_demo_session || !is_mutation(request.method(), request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    tracing::debug!(
+        "Blocked {} {} in demo mode",
+        request.method(),
+        request.uri().path()
+    );
+
+    demo_blocked_response(request.headers(), request.extensions().get())
+}
+
+fn demo_blocked_response(headers: &HeaderMap, t: Option<&TranslationContext>) -> Response {
+    if !headers.contains_key("hx-request") {
+        let back = headers
+            .get(header::REFERER)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("/");
+        return Redirect::to(back).into_response();
+    }
+
+    let message = t
+        .map(|t| t.messages.demo_mode_read_only().to_string())
+        .unwrap_or_else(|| "Demo mode: changes are not saved".to_string());
+
+    // Leave the current page untouched and only run the toast script
+    let mut response_headers = HeaderMap::new();
+    response_headers.insert(
+        HeaderName::from_static("hx-retarget"),
+        "body".parse().expect("Valid header value"),
+    );
+    response_headers.insert(
+        HeaderName::from_static("hx-reswap"),
+        "beforeend".parse().expect("Valid header value"),
+    );
+
+    (
+        response_headers,
+        Html(toast_warning(&message).into_string()),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_mutation() {
+        assert!(is_mutation(&Method::POST, "/teams"));
+        assert!(is_mutation(&Method::DELETE, "/teams"));
+        assert!(!is_mutation(&Method::GET, "/teams"));
+        assert!(!is_mutation(&Method::HEAD, "/teams"));
+        assert!(!is_mutation(&Method::POST, "/graphql"));
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_ensure_demo_user_is_idempotent(pool: SqlitePool) {
+        let first = ensure_demo_user(&pool, "demo@example.com").await.unwrap();
+        let second = ensure_demo_user(&pool, "demo@example.com").await.unwrap();
+        assert_eq!(first, second);
+
+        // The placeholder hash must never verify
+        assert!(!crate::auth::verify_password("", DEMO_PASSWORD_HASH).unwrap_or(false));
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_ensure_demo_user_refuses_admin(pool: SqlitePool) {
+        sqlx::query(
+            "INSERT INTO users (email, name, password_hash, role) VALUES ('boss@example.com', 'Boss', 'hash', 'admin')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        assert!(ensure_demo_user(&pool, "boss@example.com").await.is_err());
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_demo_session_cannot_mutate(pool: SqlitePool) {
+        use crate::auth::{require_auth, SessionStore};
+        use crate::test_support::{create_test_session, session_cookie, TEST_SESSION_SECRET};
+        use axum::{middleware, routing::get, Router};
+        use axum_test::TestServer;
+
+        let session = create_test_session(&pool).await;
+        let state = AppState::new(
+            pool.clone(),
+            SessionStore::new(pool),
+            TEST_SESSION_SECRET.to_string(),
+            false,
+        )
+        .with_demo_user(Some(session.user_email.clone()));
+
+        let app = Router::new()
+            .route(
+                "/probe",
+                get(|| async { "read" }).post(|| async { "written" }),
+            )
+            .layer(middleware::from_fn_with_state(
+                state.clone(),
+                demo_read_only,
+            ))
+            .layer(middleware::from_fn_with_state(state.clone(), require_auth))
+            .with_state(state)
+            .layer(middleware::from_fn(
+                crate::i18n::middleware::translation_context_middleware,
+            ));
+        let server = TestServer::new(app).unwrap();
+
+        let read = server
+            .get("/probe")
+            .add_cookie(session_cookie(&session))
+            .await;
+        assert_eq!(read.text(), "read");
+
+        let write = server
+            .post("/probe")
+            .add_header("hx-request", "true")
+            .add_cookie(session_cookie(&session))
+            .await;
+        assert_ne!(write.text(), "written");
+        assert!(write.headers().get("hx-reswap").is_some());
+    }
+}
@@ -1,3 +1,3 @@
 -- Test user fixture for session tests
-INSERT INTO users (id, email, name, password_hash)
-VALUES (1, 'test@example.com', 'Test User', 'test_hash');
+INSERT INTO users (id, email, name, password_hash, email_verified_at)
+VALUES (1, 'test@example.com', 'Test User', 'test_hash', CURRENT_TIMESTAMP);
@@ -0,0 +1,161 @@
+use sqlx::SqlitePool;
+
+/// Failed password sign-ins in a row that lock an account by default
+pub const DEFAULT_LOCKOUT_THRESHOLD: u32 = 5;
+
+/// Minutes a locked account refuses password sign-ins by default
+pub const DEFAULT_LOCKOUT_MINUTES: i64 = 15;
+
+/// When repeated wrong passwords lock an account, and for how long
+///
+/// Only password sign-ins count and are refused; Google / GitHub sign-in and
+/// password reset links keep working, and a reset lifts the lockout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LockoutPolicy {
+    /// Failed sign-ins in a row that lock the account; 0 disables lockout
+    pub threshold: u32,
+    /// How long the account stays locked
+    pub minutes: i64,
+}
+
+impl Default for LockoutPolicy {
+    fn default() -> Self {
+        Self {
+            threshold: DEFAULT_LOCKOUT_THRESHOLD,
+            minutes: DEFAULT_LOCKOUT_MINUTES,
+        }
+    }
+}
+
+impl LockoutPolicy {
+    pub fn enabled(&self) -> bool {
+        self.threshold > 0
+    }
+}
+
+/// Whether the user's account is currently locked
+pub async fn is_locked(db: &SqlitePool, user_id: i64) -> Result<bool, sqlx::Error> {
+    let locked = sqlx::query_scalar!(
+        r#"SELECT COALESCE(locked_until > datetime('now'), 0) as "locked!: bool" FROM users WHERE id = ?"#,
+        user_id
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(locked.unwrap_or(false))
+}
+
+/// Count a wrong password; returns whether this failure locked the account
+pub async fn record_failed_login(
+    db: &SqlitePool,
+    user_id: i64,
+    policy: &LockoutPolicy,
+) -> Result<bool, sqlx::Error> {
+    if !policy.enabled() {
+        return Ok(false);
+    }
+
+    let mut tx = db.begin().await?;
+    let failed = sqlx::query_scalar!(
+        "UPDATE users SET failed_logins = failed_logins + 1 WHERE id = ? RETURNING failed_logins",
+        user_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let locked = failed.is_some_and(|failed| failed >= i64::from(policy.threshold));
+    if locked {
+        let duration = format!("+{} minutes", policy.minutes);
+        sqlx::query!(
+            "UPDATE users SET failed_logins = 0, locked_until = datetime('now', ?) WHERE id = ?",
+            duration,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+
+    Ok(locked)
+}
+
+/// Forget earlier wrong passwords after a successful sign-in
+pub async fn clear_failed_logins(db: &SqlitePool, user_id: i64) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        "UPDATE users SET failed_logins = 0 WHERE id = ? AND failed_logins > 0",
+        user_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+/// Lift a lockout before it runs out
+pub async fn unlock_user(db: &SqlitePool, user_id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        "UPDATE users SET failed_logins = 0, locked_until = NULL WHERE id = ?",
+        user_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::users::{self, UserRole};
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_lockout(pool: SqlitePool) {
+        let id = users::create_user(&pool, "coach@x.cz", "Coach", UserRole::Editor, "hash")
+            .await
+            .unwrap();
+        let policy = LockoutPolicy {
+            threshold: 3,
+            minutes: 15,
+        };
+
+        // A successful sign-in resets the count
+        assert!(!record_failed_login(&pool, id, &policy).await.unwrap());
+        assert!(!record_failed_login(&pool, id, &policy).await.unwrap());
+        clear_failed_logins(&pool, id).await.unwrap();
+        assert!(!record_failed_login(&pool, id, &policy).await.unwrap());
+        assert!(!record_failed_login(&pool, id, &policy).await.unwrap());
+        assert!(!is_locked(&pool, id).await.unwrap());
+
+        assert!(record_failed_login(&pool, id, &policy).await.unwrap());
+        assert!(is_locked(&pool, id).await.unwrap());
+        assert!(
+            users::get_user_by_id(&pool, id)
+                .await
+                .unwrap()
+                .unwrap()
+                .locked
+        );
+
+        // Lockouts run out
+        sqlx::query("UPDATE users SET locked_until = datetime('now', '-1 minutes')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(!is_locked(&pool, id).await.unwrap());
+
+        for _ in 0..3 {
+            record_failed_login(&pool, id, &policy).await.unwrap();
+        }
+        assert!(unlock_user(&pool, id).await.unwrap());
+        assert!(!is_locked(&pool, id).await.unwrap());
+
+        let disabled = LockoutPolicy {
+            threshold: 0,
+            minutes: 15,
+        };
+        for _ in 0..5 {
+            assert!(!record_failed_login(&pool, id, &disabled).await.unwrap());
+        }
+        assert!(!is_locked(&pool, id).await.unwrap());
+    }
+}
@@ -6,12 +6,18 @@ use axum::{
 };
 use axum_extra::extract::CookieJar;
 
+use super::api_keys::{authenticate_api_key, API_KEY_HEADER};
+use super::rate_limit::is_api_path;
 use super::session::Session;
 use super::signing::verify_signed_session_id;
 use crate::app_state::AppState;
+use crate::service::users::{self, UserRole};
 
 pub const SESSION_COOKIE_NAME: &str = "hockey_session";
 
+/// Page where users replace a temporary password
+pub const CHANGE_PASSWORD_PATH: &str = "/profile/password";
+
 /// Middleware that requires authentication
 /// Redirects to /auth/login if not authenticated
 pub async fn require_auth(
@@ -20,6 +26,30 @@ pub async fn require_auth(
     mut request: Request,
     next: Next,
 ) -> Result<Response, Response> {
+    // Scripts authenticate JSON API calls with an API key instead of a session
+    if let Some(key) = request.headers().get(API_KEY_HEADER) {
+        if !is_api_path(request.uri().path()) {
+            return Err((StatusCode::FORBIDDEN, "API keys only work with the API").into_response());
+        }
+        let key = key.to_str().unwrap_or_default();
+        return match authenticate_api_key(&state.db, key).await {
+            Ok(Some(auth)) if !auth.allows(request.method(), request.uri().path()) => Err((
+                StatusCode::FORBIDDEN,
+                "The API key's scopes do not allow this request",
+            )
+                .into_response()),
+            Ok(Some(auth)) => {
+                request.extensions_mut().insert(auth.session);
+                Ok(next.run(request).await)
+            }
+            Ok(None) => Err((StatusCode::UNAUTHORIZED, "Invalid API key").into_response()),
+            Err(e) => {
+                tracing::error!("Failed to check API key: {}", e);
+                Err(StatusCode::INTERNAL_SERVER_ERROR.into_response())
+            }
+        };
+    }
+
     // Get session cookie (contains signed session ID: "session_id.signature")
     let signed_session_id = jar
         .get(SESSION_COOKIE_NAME)
@@ -32,9 +62,25 @@ pub async fn require_auth(
         {
             // Validate session
             if let Some(session) = state.sessions.validate_session(&session_id).await {
-                // Refresh session expiry on each request
-                if let Err(e) = state.sessions.refresh_session(&session_id).await {
-                    tracing::error!("Failed to refresh session {}: {}", session_id, e);
+                // Refresh session expiry on each request (sliding renewal)
+                let session = match state.sessions.refresh_session(&session_id).await {
+                    Ok(Some(refreshed)) => refreshed,
+                    Ok(None) => session,
+                    Err(e) => {
+                        tracing::error!("Failed to refresh session {}: {}", session_id, e);
+                        session
+                    }
+                };
+
+                // Users with a temporary password have to replace it first
+                if request.uri().path() != CHANGE_PASSWORD_PATH
+                    && !is_api_path(request.uri().path())
+                {
+                    match users::must_change_password(&state.db, session.user_id).await {
+                        Ok(true) => return Err(Redirect::to(CHANGE_PASSWORD_PATH).into_response()),
+                        Ok(false) => {}
+                        Err(e) => tracing::error!("Failed to check password status: {}", e),
+                    }
                 }
 
                 // Add session to request extensions
@@ -50,6 +96,29 @@ pub async fn require_auth(
     Err(Redirect::to("/auth/login").into_response())
 }
 
+/// Middleware that lets only admins through; runs after `require_auth`
+///
+/// Editors get 403. The role is read from the database on every request, so
+/// a demotion takes effect immediately.
+pub async fn require_admin(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, Response> {
+    let Some(user_id) = request.extensions().get::<Session>().map(|s| s.user_id) else {
+        return Err(Redirect::to("/auth/login").into_response());
+    };
+
+    match users::get_user_by_id(&state.db, user_id).await {
+        Ok(Some(user)) if user.role() == Some(UserRole::Admin) => Ok(next.run(request).await),
+        Ok(_) => Err((StatusCode::FORBIDDEN, "Only admins can do this").into_response()),
+        Err(e) => {
+            tracing::error!("Failed to check user role: {}", e);
+            Err(StatusCode::INTERNAL_SERVER_ERROR.into_response())
+        }
+    }
+}
+
 /// Middleware that optionally extracts session if present
 /// Does not redirect - just adds session to extensions if available
 #[allow(dead_code)]
@@ -69,9 +138,14 @@ pub async fn optional_auth(
             verify_signed_session_id(&signed_session_id, &state.session_secret)
         {
             if let Some(session) = state.sessions.validate_session(&session_id).await {
-                if let Err(e) = state.sessions.refresh_session(&session_id).await {
-                    tracing::error!("Failed to refresh session {}: {}", session_id, e);
-                }
+                let session = match state.sessions.refresh_session(&session_id).await {
+                    Ok(Some(refreshed)) => refreshed,
+                    Ok(None) => session,
+                    Err(e) => {
+                        tracing::error!("Failed to refresh session {}: {}", session_id, e);
+                        session
+                    }
+                };
                 request.extensions_mut().insert(session);
             }
         }
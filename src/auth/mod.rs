@@ -1,14 +1,20 @@
+pub mod api_keys;
+pub mod audit;
 pub mod csrf;
+pub mod demo;
+pub mod lockout;
 pub mod middleware;
+pub mod oauth;
 pub mod password;
 pub mod rate_limit;
 pub mod session;
 pub mod signing;
 
 pub use csrf::validate_csrf_token;
-pub use middleware::{require_auth, SESSION_COOKIE_NAME};
+pub use demo::demo_read_only;
+pub use middleware::{require_admin, require_auth, CHANGE_PASSWORD_PATH, SESSION_COOKIE_NAME};
 pub use password::verify_password;
-pub use rate_limit::{rate_limit_login, LoginRateLimiter};
+pub use rate_limit::{rate_limit_api, rate_limit_login, ApiRateLimiter, LoginRateLimiter};
 pub use signing::{sign_session_id, verify_signed_session_id};
 
 // Re-export these for potential future use
@@ -16,4 +22,4 @@ pub use signing::{sign_session_id, verify_signed_session_id};
 pub use middleware::{get_session, optional_auth};
 #[allow(unused_imports)]
 pub use password::hash_password;
-pub use session::{Session, SessionStore};
+pub use session::{Session, SessionConfig, SessionStore};
@@ -0,0 +1,468 @@
+//! Sign-in with Google (OpenID Connect) or GitHub (OAuth 2)
+//!
+//! Both use the authorization code flow with PKCE. The `state` parameter,
+//! the PKCE verifier and, when an account is being linked, the local user ID
+//! travel in a short-lived signed cookie, so nothing is stored server-side
+//! until the provider sends the user back. The cookie is `SameSite=Lax`
+//! because the callback is a navigation coming from the provider's site.
+
+use std::time::Duration;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+
+use super::signing::{sign_session_id, verify_signed_session_id};
+
+/// Cookie holding the sign-in attempt in progress
+pub const OAUTH_STATE_COOKIE: &str = "oauth_state";
+
+/// Seconds the user has to finish signing in at the provider
+pub const OAUTH_STATE_MAX_AGE_SECS: i64 = 600;
+
+/// How long a provider may take to answer a token or profile request
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// External sign-in providers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OAuthProvider {
+    Google,
+    GitHub,
+}
+
+impl OAuthProvider {
+    pub const ALL: [OAuthProvider; 2] = [OAuthProvider::Google, OAuthProvider::GitHub];
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "google" => Some(Self::Google),
+            "github" => Some(Self::GitHub),
+            _ => None,
+        }
+    }
+
+    /// Identifier used in URLs and the `user_identity` table
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Google => "google",
+            Self::GitHub => "github",
+        }
+    }
+
+    /// Name shown on buttons
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Google => "Google",
+            Self::GitHub => "GitHub",
+        }
+    }
+
+    fn authorize_endpoint(&self) -> &'static str {
+        match self {
+            Self::Google => "https://accounts.google.com/o/oauth2/v2/auth",
+            Self::GitHub => "https://github.com/login/oauth/authorize",
+        }
+    }
+
+    fn token_endpoint(&self) -> &'static str {
+        match self {
+            Self::Google => "https://oauth2.googleapis.com/token",
+            Self::GitHub => "https://github.com/login/oauth/access_token",
+        }
+    }
+
+    fn scope(&self) -> &'static str {
+        match self {
+            Self::Google => "openid email profile",
+            Self::GitHub => "read:user user:email",
+        }
+    }
+}
+
+/// Credentials of the app registered with a provider
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OAuthClient {
+    pub client_id: String,
+    pub client_secret: String,
+}
+
+/// Account at a provider, as reported after sign-in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalIdentity {
+    /// Stable account ID at the provider
+    pub subject: String,
+    pub email: Option<String>,
+    /// The provider has confirmed the user owns `email`
+    pub email_verified: bool,
+    pub name: Option<String>,
+}
+
+/// Sign-in attempt in progress, kept in the `oauth_state` cookie
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OAuthLogin {
+    pub provider: OAuthProvider,
+    /// Random `state` parameter the provider must send back
+    pub state: String,
+    /// PKCE code verifier
+    pub verifier: String,
+    /// User linking an account from their profile; `None` when signing in
+    pub link_user_id: Option<i64>,
+    /// Unix time the attempt started
+    pub issued_at: i64,
+}
+
+impl OAuthLogin {
+    pub fn new(provider: OAuthProvider, link_user_id: Option<i64>) -> Self {
+        Self {
+            provider,
+            state: uuid::Uuid::new_v4().simple().to_string(),
+            verifier: format!(
+                "{}{}",
+                uuid::Uuid::new_v4().simple(),
+                uuid::Uuid::new_v4().simple()
+            ),
+            link_user_id,
+            issued_at: chrono::Utc::now().timestamp(),
+        }
+    }
+
+    /// PKCE S256 challenge of the verifier
+    pub fn code_challenge(&self) -> String {
+        URL_SAFE_NO_PAD.encode(Sha256::digest(self.verifier.as_bytes()))
+    }
+
+    /// Signed cookie value
+    pub fn to_cookie_value(&self, secret: &str) -> String {
+        let payload = format!(
+            "oauth:{}:{}:{}:{}:{}",
+            self.provider.as_str(),
+            self.state,
+            self.verifier,
+            self.link_user_id.unwrap_or(0),
+            self.issued_at
+        );
+        sign_session_id(&payload, secret)
+    }
+
+    /// Attempt from a cookie value; `None` if tampered with or older than
+    /// `OAUTH_STATE_MAX_AGE_SECS` at unix time `now`
+    pub fn from_cookie_value(value: &str, secret: &str, now: i64) -> Option<Self> {
+        let payload = verify_signed_session_id(value, secret)?;
+        let mut parts = payload.split(':');
+        if parts.next()? != "oauth" {
+            return None;
+        }
+        let provider = OAuthProvider::from_str(parts.next()?)?;
+        let state = parts.next()?.to_string();
+        let verifier = parts.next()?.to_string();
+        let link_user_id = parts.next()?.parse::<i64>().ok()?;
+        let issued_at = parts.next()?.parse::<i64>().ok()?;
+        if parts.next().is_some() || now - issued_at > OAUTH_STATE_MAX_AGE_SECS {
+            return None;
+        }
+
+        Some(Self {
+            provider,
+            state,
+            verifier,
+            link_user_id: (link_user_id > 0).then_some(link_user_id),
+            issued_at,
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: Option<String>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GoogleUserInfo {
+    sub: String,
+    email: Option<String>,
+    #[serde(default)]
+    email_verified: bool,
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubUser {
+    id: i64,
+    login: String,
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitHubEmail {
+    email: String,
+    primary: bool,
+    verified: bool,
+}
+
+/// Identity from GitHub's user and email endpoints; the primary address
+/// counts as verified only if GitHub says so
+fn github_identity(user: GitHubUser, emails: Vec<GitHubEmail>) -> ExternalIdentity {
+    let primary = emails.into_iter().find(|email| email.primary);
+    ExternalIdentity {
+        subject: user.id.to_string(),
+        email_verified: primary.as_ref().is_some_and(|email| email.verified),
+        email: primary.map(|email| email.email),
+        name: user
+            .name
+            .filter(|name| !name.is_empty())
+            .or(Some(user.login)),
+    }
+}
+
+/// The configured providers and the HTTP client talking to them
+#[derive(Clone)]
+pub struct OAuthProviders {
+    http: reqwest::Client,
+    google: Option<OAuthClient>,
+    github: Option<OAuthClient>,
+}
+
+impl Default for OAuthProviders {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
+}
+
+impl OAuthProviders {
+    pub fn new(google: Option<OAuthClient>, github: Option<OAuthClient>) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent("hockey")
+            .build()
+            .unwrap_or_default();
+        Self {
+            http,
+            google,
+            github,
+        }
+    }
+
+    /// Credentials of a provider, if it is configured
+    pub fn client(&self, provider: OAuthProvider) -> Option<&OAuthClient> {
+        match provider {
+            OAuthProvider::Google => self.google.as_ref(),
+            OAuthProvider::GitHub => self.github.as_ref(),
+        }
+    }
+
+    /// Providers offered on the sign-in and profile pages
+    pub fn enabled(&self) -> Vec<OAuthProvider> {
+        OAuthProvider::ALL
+            .into_iter()
+            .filter(|provider| self.client(*provider).is_some())
+            .collect()
+    }
+
+    /// Provider page the user is sent to; `None` if the provider is not configured
+    pub fn authorization_url(&self, login: &OAuthLogin, redirect_uri: &str) -> Option<String> {
+        let client = self.client(login.provider)?;
+        Some(format!(
+            "{}?response_type=code&client_id={}&redirect_uri={}&scope={}&state={}&code_challenge={}&code_challenge_method=S256",
+            login.provider.authorize_endpoint(),
+            urlencoding::encode(&client.client_id),
+            urlencoding::encode(redirect_uri),
+            urlencoding::encode(login.provider.scope()),
+            login.state,
+            login.code_challenge()
+        ))
+    }
+
+    /// Exchange the authorization code and read the signed-in account
+    pub async fn fetch_identity(
+        &self,
+        login: &OAuthLogin,
+        code: &str,
+        redirect_uri: &str,
+    ) -> anyhow::Result<ExternalIdentity> {
+        let provider = login.provider;
+        let client = self
+            .client(provider)
+            .ok_or_else(|| anyhow::anyhow!("{} sign-in is not configured", provider.label()))?;
+
+        let token: TokenResponse = self
+            .http
+            .post(provider.token_endpoint())
+            .header(reqwest::header::ACCEPT, "application/json")
+            .form(&[
+                ("grant_type", "authorization_code"),
+                ("code", code),
+                ("redirect_uri", redirect_uri),
+                ("client_id", &client.client_id),
+                ("client_secret", &client.client_secret),
+                ("code_verifier", &login.verifier),
+            ])
+            .send()
+            .await?
+            .json()
+            .await?;
+        let access_token = token.access_token.ok_or_else(|| {
+            anyhow::anyhow!(
+                "{} refused the code: {}",
+                provider.label(),
+                token.error.as_deref().unwrap_or("no access token")
+            )
+        })?;
+
+        match provider {
+            OAuthProvider::Google => {
+                let info: GoogleUserInfo = self
+                    .http
+                    .get("https://openidconnect.googleapis.com/v1/userinfo")
+                    .bearer_auth(&access_token)
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json()
+                    .await?;
+                Ok(ExternalIdentity {
+                    subject: info.sub,
+                    email: info.email,
+                    email_verified: info.email_verified,
+                    name: info.name,
+                })
+            }
+            OAuthProvider::GitHub => {
+                let user: GitHubUser = self
+                    .github_get("https://api.github.com/user", &access_token)
+                    .await?;
+                let emails: Vec<GitHubEmail> = self
+                    .github_get("https://api.github.com/user/emails", &access_token)
+                    .await?;
+                Ok(github_identity(user, emails))
+            }
+        }
+    }
+
+    async fn github_get<T: serde::de::DeserializeOwned>(
+        &self,
+        url: &str,
+        access_token: &str,
+    ) -> anyhow::Result<T> {
+        Ok(self
+            .http
+            .get(url)
+            .header(reqwest::header::ACCEPT, "application/vnd.github+json")
+            .bearer_auth(access_token)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "test-secret-that-is-long-enough-for-signing";
+
+    #[test]
+    fn test_state_cookie_round_trip() {
+        let login = OAuthLogin::new(OAuthProvider::GitHub, Some(7));
+        let value = login.to_cookie_value(SECRET);
+        assert_eq!(
+            OAuthLogin::from_cookie_value(&value, SECRET, login.issued_at + 60),
+            Some(login.clone())
+        );
+
+        // Expired, tampered with or signed with another secret
+        assert_eq!(
+            OAuthLogin::from_cookie_value(&value, SECRET, login.issued_at + 601),
+            None
+        );
+        let tampered = value.replacen(":7:", ":1:", 1);
+        assert_eq!(
+            OAuthLogin::from_cookie_value(&tampered, SECRET, login.issued_at),
+            None
+        );
+        assert_eq!(
+            OAuthLogin::from_cookie_value(&value, "another-secret", login.issued_at),
+            None
+        );
+
+        let sign_in = OAuthLogin::new(OAuthProvider::Google, None);
+        let value = sign_in.to_cookie_value(SECRET);
+        let parsed = OAuthLogin::from_cookie_value(&value, SECRET, sign_in.issued_at).unwrap();
+        assert_eq!(parsed.link_user_id, None);
+    }
+
+    #[test]
+    fn test_authorization_url() {
+        let providers = OAuthProviders::new(
+            Some(OAuthClient {
+                client_id: "client id".to_string(),
+                client_secret: "secret".to_string(),
+            }),
+            None,
+        );
+        assert_eq!(providers.enabled(), vec![OAuthProvider::Google]);
+
+        let login = OAuthLogin::new(OAuthProvider::Google, None);
+        let url = providers
+            .authorization_url(
+                &login,
+                "https://hockey.example.com/auth/oauth/google/callback",
+            )
+            .unwrap();
+        assert!(url.starts_with("https://accounts.google.com/o/oauth2/v2/auth?"));
+        assert!(url.contains("client_id=client%20id"));
+        assert!(url.contains("scope=openid%20email%20profile"));
+        assert!(url.contains(&format!("state={}", login.state)));
+        assert!(url.contains(&format!("code_challenge={}", login.code_challenge())));
+        assert!(!url.contains("secret"));
+
+        let github = OAuthLogin::new(OAuthProvider::GitHub, None);
+        assert!(providers.authorization_url(&github, "/").is_none());
+    }
+
+    #[test]
+    fn test_code_challenge() {
+        // Example from RFC 7636, appendix B
+        let login = OAuthLogin {
+            verifier: "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk".to_string(),
+            ..OAuthLogin::new(OAuthProvider::Google, None)
+        };
+        assert_eq!(
+            login.code_challenge(),
+            "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+        );
+    }
+
+    #[test]
+    fn test_github_identity() {
+        let user = GitHubUser {
+            id: 42,
+            login: "octocat".to_string(),
+            name: None,
+        };
+        let emails = vec![
+            GitHubEmail {
+                email: "old@example.com".to_string(),
+                primary: false,
+                verified: true,
+            },
+            GitHubEmail {
+                email: "octo@example.com".to_string(),
+                primary: true,
+                verified: false,
+            },
+        ];
+        assert_eq!(
+            github_identity(user, emails),
+            ExternalIdentity {
+                subject: "42".to_string(),
+                email: Some("octo@example.com".to_string()),
+                email_verified: false,
+                name: Some("octocat".to_string()),
+            }
+        );
+    }
+}
@@ -1,26 +1,262 @@
-use bcrypt::{hash, verify, DEFAULT_COST};
+use std::sync::OnceLock;
 
-/// Hash a password using bcrypt
-#[allow(dead_code)]
-pub fn hash_password(password: &str) -> Result<String, bcrypt::BcryptError> {
-    hash(password, DEFAULT_COST)
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+use password_hash::rand_core::OsRng;
+
+/// Distinct characters a chosen password needs at least
+const MIN_DISTINCT_CHARS: usize = 5;
+
+/// Frequently leaked passwords long enough to pass the length check
+const COMMON_PASSWORDS: &[&str] = &[
+    "password",
+    "password1",
+    "password12",
+    "password123",
+    "passw0rd",
+    "12345678",
+    "123456789",
+    "1234567890",
+    "0987654321",
+    "qwertyui",
+    "qwertyuiop",
+    "qwerty123",
+    "1q2w3e4r",
+    "1qaz2wsx",
+    "zaq12wsx",
+    "asdfghjk",
+    "asdfasdf",
+    "iloveyou",
+    "sunshine",
+    "princess",
+    "football",
+    "baseball",
+    "welcome1",
+    "trustno1",
+    "letmein1",
+    "superman",
+    "starwars",
+    "whatever",
+    "computer",
+    "internet",
+    "abc12345",
+    "admin123",
+    "changeme",
+    "hockey12",
+    "hockey123",
+    "heslo123",
+    "hokej123",
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum PasswordError {
+    #[error("bcrypt: {0}")]
+    Bcrypt(#[from] bcrypt::BcryptError),
+    #[error("argon2: {0}")]
+    Argon2(#[from] argon2::password_hash::Error),
+}
+
+/// Argon2id cost of new password hashes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HashingParams {
+    /// Memory per hash in KiB
+    pub memory_kib: u32,
+    /// Passes over the memory
+    pub iterations: u32,
+    /// Lanes hashed in parallel
+    pub parallelism: u32,
+}
+
+impl Default for HashingParams {
+    /// The OWASP recommendation the argon2 crate defaults to
+    fn default() -> Self {
+        Self {
+            memory_kib: Params::DEFAULT_M_COST,
+            iterations: Params::DEFAULT_T_COST,
+            parallelism: Params::DEFAULT_P_COST,
+        }
+    }
+}
+
+impl HashingParams {
+    /// Argon2 parameters, or why the combination is invalid
+    pub fn params(&self) -> Result<Params, argon2::Error> {
+        Params::new(self.memory_kib, self.iterations, self.parallelism, None)
+    }
+
+    fn hasher(&self) -> Result<Argon2<'static>, PasswordError> {
+        let params = self.params().map_err(argon2::password_hash::Error::from)?;
+        Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+    }
+
+    /// Hash a password with these parameters
+    pub fn hash(&self, password: &str) -> Result<String, PasswordError> {
+        let salt = SaltString::generate(&mut OsRng);
+        Ok(self
+            .hasher()?
+            .hash_password(password.as_bytes(), &salt)?
+            .to_string())
+    }
+
+    /// Whether a stored hash was made differently, e.g. with bcrypt or other costs
+    pub fn needs_rehash(&self, hash: &str) -> bool {
+        let Ok(parsed) = PasswordHash::new(hash) else {
+            return true;
+        };
+        let Ok(params) = Params::try_from(&parsed) else {
+            return true;
+        };
+
+        parsed.algorithm != Algorithm::Argon2id.ident()
+            || parsed.version != Some(Version::V0x13.into())
+            || params.m_cost() != self.memory_kib
+            || params.t_cost() != self.iterations
+            || params.p_cost() != self.parallelism
+    }
+}
+
+static HASHING_PARAMS: OnceLock<HashingParams> = OnceLock::new();
+
+/// Set the cost of new hashes once at startup; later calls are ignored
+pub fn configure_hashing(params: HashingParams) {
+    if HASHING_PARAMS.set(params).is_err() {
+        tracing::warn!("Password hashing parameters were already set");
+    }
+}
+
+/// Cost of new hashes; the defaults unless configured
+pub fn hashing_params() -> HashingParams {
+    *HASHING_PARAMS.get_or_init(HashingParams::default)
+}
+
+/// Hash a password using Argon2id with the configured parameters
+pub fn hash_password(password: &str) -> Result<String, PasswordError> {
+    hashing_params().hash(password)
+}
+
+/// Verify a password against an Argon2 or an older bcrypt hash
+pub fn verify_password(password: &str, hash: &str) -> Result<bool, PasswordError> {
+    if !hash.starts_with("$argon2") {
+        return Ok(bcrypt::verify(password, hash)?);
+    }
+
+    let parsed = PasswordHash::new(hash)?;
+    match Argon2::default().verify_password(password.as_bytes(), &parsed) {
+        Ok(()) => Ok(true),
+        Err(argon2::password_hash::Error::Password) => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Whether a verified hash should be replaced by one with the configured parameters
+pub fn needs_rehash(hash: &str) -> bool {
+    hashing_params().needs_rehash(hash)
+}
+
+/// Why a chosen password is too easy to guess
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasswordWeakness {
+    /// On the list of frequently leaked passwords
+    Common,
+    /// Few distinct characters, or a run like `abcdefgh`
+    Simple,
 }
 
-/// Verify a password against a hash
-pub fn verify_password(password: &str, hash: &str) -> Result<bool, bcrypt::BcryptError> {
-    verify(password, hash)
+/// Check a password a user chose beyond its length
+pub fn check_password_strength(password: &str) -> Result<(), PasswordWeakness> {
+    let lowercase = password.to_lowercase();
+    if COMMON_PASSWORDS.contains(&lowercase.as_str()) {
+        return Err(PasswordWeakness::Common);
+    }
+
+    let chars: Vec<char> = lowercase.chars().collect();
+    let mut distinct = chars.clone();
+    distinct.sort_unstable();
+    distinct.dedup();
+    let steps: Vec<i64> = chars
+        .windows(2)
+        .map(|pair| pair[1] as i64 - pair[0] as i64)
+        .collect();
+    let is_run = steps.iter().all(|step| *step == 1) || steps.iter().all(|step| *step == -1);
+    if distinct.len() < MIN_DISTINCT_CHARS || is_run {
+        return Err(PasswordWeakness::Simple);
+    }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Cheap parameters so the tests stay fast
+    const TEST_PARAMS: HashingParams = HashingParams {
+        memory_kib: 1024,
+        iterations: 1,
+        parallelism: 1,
+    };
+
     #[test]
     fn test_hash_and_verify() {
         let password = "test_password_123";
-        let hash = hash_password(password).unwrap();
+        let hash = TEST_PARAMS.hash(password).unwrap();
+        assert!(hash.starts_with("$argon2id$"));
 
         assert!(verify_password(password, &hash).unwrap());
         assert!(!verify_password("wrong_password", &hash).unwrap());
     }
+
+    #[test]
+    fn test_bcrypt_hashes_still_verify_and_need_rehash() {
+        let hash = bcrypt::hash("old_password", 4).unwrap();
+        assert!(verify_password("old_password", &hash).unwrap());
+        assert!(!verify_password("wrong_password", &hash).unwrap());
+        assert!(TEST_PARAMS.needs_rehash(&hash));
+    }
+
+    #[test]
+    fn test_needs_rehash_when_params_change() {
+        let hash = TEST_PARAMS.hash("test_password_123").unwrap();
+        assert!(!TEST_PARAMS.needs_rehash(&hash));
+
+        let stronger = HashingParams {
+            iterations: 2,
+            ..TEST_PARAMS
+        };
+        assert!(stronger.needs_rehash(&hash));
+        // Old hashes keep working until they are replaced
+        assert!(verify_password("test_password_123", &hash).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_params() {
+        let invalid = HashingParams {
+            memory_kib: 1,
+            ..TEST_PARAMS
+        };
+        assert!(invalid.params().is_err());
+        assert!(invalid.hash("test_password_123").is_err());
+    }
+
+    #[test]
+    fn test_password_strength() {
+        assert_eq!(check_password_strength("correct horse battery"), Ok(()));
+        assert_eq!(check_password_strength("Tr0ub4dor&3"), Ok(()));
+        assert_eq!(
+            check_password_strength("Password123"),
+            Err(PasswordWeakness::Common)
+        );
+        assert_eq!(
+            check_password_strength("aaaabbbb"),
+            Err(PasswordWeakness::Simple)
+        );
+        assert_eq!(
+            check_password_strength("abcdefgh"),
+            Err(PasswordWeakness::Simple)
+        );
+        assert_eq!(
+            check_password_strength("98765432"),
+            Err(PasswordWeakness::Simple)
+        );
+    }
 }
@@ -1,22 +1,29 @@
 use axum::{
     extract::{Request, State},
-    http::StatusCode,
+    http::{header, StatusCode},
     middleware::Next,
     response::{Html, IntoResponse, Response},
 };
 use governor::{
     clock::{Clock, DefaultClock},
-    state::{InMemoryState, NotKeyed},
+    state::keyed::DefaultKeyedStateStore,
     Quota, RateLimiter,
 };
 use std::num::NonZeroU32;
 use std::sync::Arc;
 
+use super::audit::ClientIp;
+use super::Session;
+
+/// Tracked clients after which idle ones are forgotten
+const MAX_TRACKED_CLIENTS: usize = 10_000;
+
 /// Rate limiter for login endpoint
-/// Prevents brute force attacks by limiting login attempts
+/// Prevents brute force attacks by limiting login attempts of each client IP
+/// address, so one client cannot lock everyone else out
 #[derive(Clone)]
 pub struct LoginRateLimiter {
-    limiter: Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock>>,
+    limiter: Arc<RateLimiter<String, DefaultKeyedStateStore<String>, DefaultClock>>,
 }
 
 impl LoginRateLimiter {
@@ -27,14 +34,18 @@ impl LoginRateLimiter {
             Quota::per_minute(NonZeroU32::new(5).unwrap()).allow_burst(NonZeroU32::new(5).unwrap());
 
         Self {
-            limiter: Arc::new(RateLimiter::direct(quota)),
+            limiter: Arc::new(RateLimiter::keyed(quota)),
         }
     }
 
-    /// Check if the request should be rate limited
+    /// Check if an attempt of the client should be rate limited
     /// Returns None if allowed, Some(retry_after_secs) if rate limited
-    pub fn check(&self) -> Option<u64> {
-        match self.limiter.check() {
+    pub fn check(&self, client: &str) -> Option<u64> {
+        if self.limiter.len() > MAX_TRACKED_CLIENTS {
+            self.limiter.retain_recent();
+        }
+
+        match self.limiter.check_key(&client.to_string()) {
             Ok(_) => None,
             Err(not_until) => {
                 let wait_time = not_until.wait_time_from(DefaultClock::default().now());
@@ -57,7 +68,12 @@ pub async fn rate_limit_login(
     request: Request,
     next: Next,
 ) -> Response {
-    if let Some(retry_after) = limiter.check() {
+    let client = match request.extensions().get::<ClientIp>() {
+        Some(ClientIp(Some(ip))) => ip.clone(),
+        _ => "unknown".to_string(),
+    };
+
+    if let Some(retry_after) = limiter.check(&client) {
         let error_html = format!(
             r#"<div class="p-4 bg-red-100 border border-red-400 text-red-700 rounded">
                 <p><strong>Too many login attempts.</strong></p>
@@ -72,6 +88,103 @@ pub async fn rate_limit_login(
     next.run(request).await
 }
 
+/// Default sustained API requests per minute of one client
+pub const DEFAULT_API_RATE_PER_MINUTE: u32 = 120;
+
+/// Default API requests a client may send at once before being slowed down
+pub const DEFAULT_API_RATE_BURST: u32 = 30;
+
+/// Rate limiter for the JSON API
+/// Every client gets its own quota: signed-in users per session, anonymous
+/// clients per IP address
+#[derive(Clone)]
+pub struct ApiRateLimiter {
+    limiter: Arc<RateLimiter<String, DefaultKeyedStateStore<String>, DefaultClock>>,
+}
+
+impl ApiRateLimiter {
+    pub fn new() -> Self {
+        Self::with_quota(DEFAULT_API_RATE_PER_MINUTE, DEFAULT_API_RATE_BURST)
+    }
+
+    /// Allow `per_minute` requests per minute with bursts of `burst`; zeros count as one
+    pub fn with_quota(per_minute: u32, burst: u32) -> Self {
+        let quota = Quota::per_minute(NonZeroU32::new(per_minute).unwrap_or(NonZeroU32::MIN))
+            .allow_burst(NonZeroU32::new(burst).unwrap_or(NonZeroU32::MIN));
+
+        Self {
+            limiter: Arc::new(RateLimiter::keyed(quota)),
+        }
+    }
+
+    /// Check if a request of the client should be rate limited
+    /// Returns None if allowed, Some(retry_after_secs) if rate limited
+    pub fn check(&self, client: &str) -> Option<u64> {
+        if self.limiter.len() > MAX_TRACKED_CLIENTS {
+            self.limiter.retain_recent();
+        }
+
+        match self.limiter.check_key(&client.to_string()) {
+            Ok(_) => None,
+            Err(not_until) => {
+                let wait_time = not_until.wait_time_from(DefaultClock::default().now());
+                Some(wait_time.as_secs() + 1) // Add 1 to round up
+            }
+        }
+    }
+}
+
+impl Default for ApiRateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Whether a path belongs to the JSON API
+pub(crate) fn is_api_path(path: &str) -> bool {
+    path.starts_with("/api/") || path.starts_with("/public/api/") || path == "/graphql"
+}
+
+/// Quota key of the client sending a request
+///
+/// Runs after `require_auth` on protected routes, so signed-in clients are
+/// keyed by their session token (or API key); everyone else by the IP
+/// address `resolve_client_ip` found.
+fn client_key(request: &Request) -> String {
+    if let Some(session) = request.extensions().get::<Session>() {
+        return format!("session:{}", session.id);
+    }
+
+    match request.extensions().get::<ClientIp>() {
+        Some(ClientIp(Some(ip))) => format!("ip:{}", ip),
+        _ => "unknown".to_string(),
+    }
+}
+
+/// Middleware function for rate limiting API requests
+/// Returns 429 Too Many Requests with a `Retry-After` header if rate limit exceeded;
+/// requests outside the JSON API pass through untouched
+pub async fn rate_limit_api(
+    State(limiter): State<ApiRateLimiter>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !is_api_path(request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    if let Some(retry_after) = limiter.check(&client_key(&request)) {
+        return (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(header::RETRY_AFTER, retry_after.to_string())],
+            "Too many requests",
+        )
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,7 +195,10 @@ mod tests {
 
         // First 5 requests should be allowed
         for _ in 0..5 {
-            assert!(limiter.check().is_none(), "Request should be allowed");
+            assert!(
+                limiter.check("10.0.0.1").is_none(),
+                "Request should be allowed"
+            );
         }
     }
 
@@ -92,12 +208,94 @@ mod tests {
 
         // Exhaust the quota
         for _ in 0..5 {
-            limiter.check();
+            limiter.check("10.0.0.1");
         }
 
         // Next request should be blocked
-        let result = limiter.check();
+        let result = limiter.check("10.0.0.1");
         assert!(result.is_some(), "Request should be rate limited");
         assert!(result.unwrap() > 0, "Should have a retry-after time");
+
+        // Other clients can still sign in
+        assert!(limiter.check("10.0.0.2").is_none());
+    }
+
+    #[test]
+    fn test_api_rate_limiter_blocks_after_burst() {
+        let limiter = ApiRateLimiter::new();
+
+        for _ in 0..30 {
+            assert!(
+                limiter.check("ip:10.0.0.1").is_none(),
+                "Request should be allowed"
+            );
+        }
+        assert!(
+            limiter.check("ip:10.0.0.1").is_some(),
+            "Request should be rate limited"
+        );
+    }
+
+    #[test]
+    fn test_api_rate_limiter_keeps_clients_apart() {
+        let limiter = ApiRateLimiter::with_quota(60, 2);
+
+        assert!(limiter.check("session:a").is_none());
+        assert!(limiter.check("session:a").is_none());
+        let retry_after = limiter.check("session:a");
+        assert!(matches!(retry_after, Some(secs) if (1..=2).contains(&secs)));
+
+        // Another client still has its full burst
+        assert!(limiter.check("ip:10.0.0.2").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_forwarded_headers_do_not_reset_quota() {
+        use super::super::audit::{resolve_client_ip, TrustedProxies};
+        use axum::{body::Body, extract::ConnectInfo, middleware, routing::get, Router};
+        use std::net::SocketAddr;
+        use tower::ServiceExt;
+
+        let app = Router::new()
+            .route("/public/api/live", get(|| async { "[]" }))
+            .layer(middleware::from_fn_with_state(
+                ApiRateLimiter::with_quota(60, 2),
+                rate_limit_api,
+            ))
+            .layer(middleware::from_fn_with_state(
+                TrustedProxies::loopback(),
+                resolve_client_ip,
+            ));
+
+        let mut statuses = Vec::new();
+        for i in 0..3 {
+            let mut request = Request::builder()
+                .uri("/public/api/live")
+                .header("x-forwarded-for", format!("198.51.100.{}", i))
+                .body(Body::empty())
+                .unwrap();
+            request
+                .extensions_mut()
+                .insert(ConnectInfo(SocketAddr::from(([203, 0, 113, 5], 4000))));
+            statuses.push(app.clone().oneshot(request).await.unwrap().status());
+        }
+
+        assert_eq!(
+            statuses,
+            [
+                StatusCode::OK,
+                StatusCode::OK,
+                StatusCode::TOO_MANY_REQUESTS
+            ]
+        );
+    }
+
+    #[test]
+    fn test_is_api_path() {
+        assert!(is_api_path("/api/teams/1/stats"));
+        assert!(is_api_path("/public/api/live"));
+        assert!(is_api_path("/graphql"));
+        assert!(!is_api_path("/matches"));
+        assert!(!is_api_path("/apiary"));
     }
 }
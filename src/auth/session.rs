@@ -5,21 +5,26 @@ use uuid::Uuid;
 #[derive(Debug, Clone)]
 pub struct Session {
     pub id: String,
-    #[allow(dead_code)]
     pub user_id: i64,
     pub user_email: String,
     pub user_name: String,
     #[allow(dead_code)]
     pub created_at: DateTime<Utc>,
     pub expires_at: DateTime<Utc>,
+    /// Hard expiry measured from sign-in; activity cannot extend past this
+    pub absolute_expires_at: DateTime<Utc>,
     #[allow(dead_code)]
     pub csrf_token: String,
 }
 
 impl Session {
-    pub fn new(user_id: i64, user_email: String, user_name: String) -> Self {
+    pub fn new(
+        user_id: i64,
+        user_email: String,
+        user_name: String,
+        config: &SessionConfig,
+    ) -> Self {
         let now = Utc::now();
-        let expires_at = now + Duration::days(7); // 7 day session
 
         Self {
             id: Uuid::new_v4().to_string(),
@@ -27,18 +32,45 @@ impl Session {
             user_email,
             user_name,
             created_at: now,
-            expires_at,
+            expires_at: config.expiry_from(now, now),
+            absolute_expires_at: now + config.max_lifetime,
             csrf_token: Uuid::new_v4().to_string(),
         }
     }
 
     pub fn is_expired(&self) -> bool {
-        Utc::now() > self.expires_at
+        let now = Utc::now();
+        now > self.expires_at || now > self.absolute_expires_at
     }
 
     #[allow(dead_code)]
-    pub fn refresh_expiry(&mut self) {
-        self.expires_at = Utc::now() + Duration::days(7);
+    pub fn refresh_expiry(&mut self, config: &SessionConfig) {
+        self.expires_at = config.expiry_from(self.created_at, Utc::now());
+    }
+}
+
+/// Session lifetime policy
+#[derive(Debug, Clone, Copy)]
+pub struct SessionConfig {
+    /// Inactivity period after which the session expires (renewed on every request)
+    pub idle_timeout: Duration,
+    /// Maximum session age since sign-in, regardless of activity
+    pub max_lifetime: Duration,
+}
+
+impl Default for SessionConfig {
+    fn default() -> Self {
+        Self {
+            idle_timeout: Duration::days(7),
+            max_lifetime: Duration::days(30),
+        }
+    }
+}
+
+impl SessionConfig {
+    /// Expiry for a session created at `created_at` with its last activity at `now`
+    pub fn expiry_from(&self, created_at: DateTime<Utc>, now: DateTime<Utc>) -> DateTime<Utc> {
+        (now + self.idle_timeout).min(created_at + self.max_lifetime)
     }
 }
 
@@ -46,11 +78,21 @@ impl Session {
 #[derive(Debug, Clone)]
 pub struct SessionStore {
     db: SqlitePool,
+    config: SessionConfig,
 }
 
 impl SessionStore {
+    #[allow(dead_code)]
     pub fn new(db: SqlitePool) -> Self {
-        Self { db }
+        Self::with_config(db, SessionConfig::default())
+    }
+
+    pub fn with_config(db: SqlitePool, config: SessionConfig) -> Self {
+        Self { db, config }
+    }
+
+    pub fn config(&self) -> &SessionConfig {
+        &self.config
     }
 
     /// Create a new session
@@ -60,7 +102,7 @@ impl SessionStore {
         email: String,
         name: String,
     ) -> Result<Session, sqlx::Error> {
-        let session = Session::new(user_id, email, name);
+        let session = Session::new(user_id, email, name, &self.config);
 
         // Store in database
         sqlx::query!(
@@ -112,6 +154,7 @@ impl SessionStore {
             csrf_token: row.csrf_token,
             created_at,
             expires_at,
+            absolute_expires_at: created_at + self.config.max_lifetime,
         })
     }
 
@@ -130,7 +173,7 @@ impl SessionStore {
         Some(session)
     }
 
-    /// Refresh session expiry
+    /// Refresh session expiry (sliding renewal, capped at the absolute lifetime)
     pub async fn refresh_session(&self, session_id: &str) -> Result<Option<Session>, sqlx::Error> {
         let session = match self.get_session(session_id).await {
             Some(s) => s,
@@ -141,7 +184,7 @@ impl SessionStore {
             return Ok(None);
         }
 
-        let new_expires_at = Utc::now() + Duration::days(7);
+        let new_expires_at = self.config.expiry_from(session.created_at, Utc::now());
 
         sqlx::query!(
             r#"
@@ -271,4 +314,60 @@ mod tests {
         let invalid = store.validate_session("invalid-id").await;
         assert!(invalid.is_none());
     }
+
+    #[sqlx::test(migrations = "./migrations", fixtures("users"))]
+    async fn test_session_sliding_renewal_capped_by_lifetime(pool: SqlitePool) {
+        let store = SessionStore::with_config(
+            pool.clone(),
+            SessionConfig {
+                idle_timeout: Duration::minutes(30),
+                max_lifetime: Duration::hours(8),
+            },
+        );
+        let session = store
+            .create_session(1, "test@example.com".to_string(), "Test User".to_string())
+            .await
+            .expect("Failed to create session");
+        assert!(session.expires_at <= session.created_at + Duration::minutes(30));
+
+        // Pretend the session was created 7h50m ago
+        let created_at = Utc::now() - Duration::minutes(470);
+        sqlx::query!(
+            "UPDATE sessions SET created_at = ?1 WHERE id = ?2",
+            created_at,
+            session.id
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let refreshed = store
+            .refresh_session(&session.id)
+            .await
+            .unwrap()
+            .expect("Session should still be valid");
+        assert_eq!(refreshed.expires_at, created_at + Duration::hours(8));
+        assert_eq!(refreshed.absolute_expires_at, refreshed.expires_at);
+    }
+
+    #[sqlx::test(migrations = "./migrations", fixtures("users"))]
+    async fn test_session_expires_after_absolute_lifetime(pool: SqlitePool) {
+        let store = SessionStore::new(pool.clone());
+        let session = store
+            .create_session(1, "test@example.com".to_string(), "Test User".to_string())
+            .await
+            .expect("Failed to create session");
+
+        let created_at = Utc::now() - Duration::days(31);
+        sqlx::query!(
+            "UPDATE sessions SET created_at = ?1 WHERE id = ?2",
+            created_at,
+            session.id
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        assert!(store.validate_session(&session.id).await.is_none());
+    }
 }
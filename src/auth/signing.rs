@@ -65,7 +65,7 @@ fn compute_signature(session_id: &str, secret: &str) -> String {
 }
 
 /// Constant-time string comparison to prevent timing attacks
-fn constant_time_compare(a: &str, b: &str) -> bool {
+pub(crate) fn constant_time_compare(a: &str, b: &str) -> bool {
     if a.len() != b.len() {
         return false;
     }
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use bcrypt::{hash, DEFAULT_COST};
+use hockey::auth::hash_password;
 use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
 use std::io::{self, Write};
 
@@ -31,12 +31,25 @@ async fn main() -> Result<()> {
     // Check for command-line arguments (non-interactive mode)
     let args: Vec<String> = std::env::args().collect();
     let force_recreate = args.contains(&"--force".to_string());
+    let seed_demo_data = args.contains(&"--seed".to_string());
+    let positional: Vec<&str> = args
+        .iter()
+        .skip(1)
+        .map(String::as_str)
+        .filter(|arg| !arg.starts_with("--"))
+        .collect();
+
+    // The demo dataset needs the full schema, even before the server's first start
+    if seed_demo_data {
+        println!("Running database migrations...");
+        sqlx::migrate!("./migrations").run(&pool).await?;
+    }
 
-    let (email, name, password) = if args.len() >= 4 {
+    let (email, name, password) = if positional.len() >= 3 {
         // Non-interactive mode: use command-line arguments
-        // Usage: create_admin <email> <name> <password> [--force]
+        // Usage: create_admin <email> <name> <password> [--force] [--seed]
         println!("Using non-interactive mode with provided arguments\n");
-        (args[1].as_str(), args[2].as_str(), args[3].as_str())
+        (positional[0], positional[1], positional[2])
     } else {
         // Interactive mode: prompt for input
         print!("Enter admin email: ");
@@ -64,9 +77,9 @@ async fn main() -> Result<()> {
         )
     };
 
-    // Hash password
+    // Hash password; the server rehashes it at sign-in if configured differently
     println!("\nHashing password...");
-    let password_hash = hash(password, DEFAULT_COST)?;
+    let password_hash = hash_password(password)?;
 
     // Delete existing user if --force flag is set
     if force_recreate {
@@ -80,7 +93,7 @@ async fn main() -> Result<()> {
     // Insert user
     println!("Creating user...");
     sqlx::query(
-        "INSERT INTO users (email, password_hash, name, created_at, updated_at) VALUES (?, ?, ?, datetime('now'), datetime('now'))"
+        "INSERT INTO users (email, password_hash, name, email_verified_at, created_at, updated_at) VALUES (?, ?, ?, datetime('now'), datetime('now'), datetime('now'))"
     )
     .bind(email)
     .bind(&password_hash)
@@ -91,6 +104,23 @@ async fn main() -> Result<()> {
     println!("\n✅ Admin user created successfully!");
     println!("Email: {}", email);
     println!("Name: {}", name);
+
+    if seed_demo_data {
+        println!("\nSeeding demo data...");
+        match hockey::seed::seed_demo_data(&pool).await? {
+            Some(summary) => println!(
+                "✅ Seeded {} teams, {} players, {} events, {} seasons, {} matches and {} goals",
+                summary.teams,
+                summary.players,
+                summary.events,
+                summary.seasons,
+                summary.matches,
+                summary.score_events
+            ),
+            None => println!("Database already has data, demo seed skipped"),
+        }
+    }
+
     println!("\nYou can now log in at http://localhost:8080/auth/login");
 
     Ok(())
@@ -0,0 +1,54 @@
+use anyhow::{bail, Result};
+use sqlx::sqlite::SqliteConnectOptions;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    println!("=== Hockey Management - Restore Database Snapshot ===\n");
+
+    // Load .env file
+    let _ = dotenvy::dotenv();
+
+    // Usage: restore_snapshot <snapshot.db> [--yes]
+    let args: Vec<String> = std::env::args().collect();
+    let skip_confirmation = args.contains(&"--yes".to_string());
+    let Some(snapshot) = args.iter().skip(1).find(|arg| !arg.starts_with("--")) else {
+        bail!("Usage: restore_snapshot <snapshot.db> [--yes]");
+    };
+    let snapshot = PathBuf::from(snapshot);
+
+    // Get database URL
+    let database_url =
+        std::env::var("DATABASE_URL").unwrap_or_else(|_| "sqlite:./hockey.db".to_string());
+    let options = database_url.parse::<SqliteConnectOptions>()?;
+    let database = options.get_filename().to_path_buf();
+
+    println!("Snapshot: {}", snapshot.display());
+    println!("Database: {}", database.display());
+    println!("\nThe server must be stopped while the database is replaced.");
+
+    if !skip_confirmation {
+        print!("Replace the database with the snapshot? [y/N]: ");
+        io::stdout().flush()?;
+        let mut answer = String::new();
+        io::stdin().read_line(&mut answer)?;
+        if !matches!(answer.trim(), "y" | "Y" | "yes") {
+            println!("Cancelled, nothing was changed.");
+            return Ok(());
+        }
+    }
+
+    println!("\nChecking snapshot and restoring...");
+    let previous = hockey::maintenance::restore_snapshot(&snapshot, &database)
+        .await
+        .map_err(anyhow::Error::msg)?;
+
+    println!("\n✅ Database restored successfully!");
+    if let Some(previous) = previous {
+        println!("Previous database kept as: {}", previous.display());
+    }
+    println!("\nStart the server to apply any newer migrations to the restored data.");
+
+    Ok(())
+}
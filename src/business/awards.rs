@@ -0,0 +1,119 @@
+use sqlx::SqlitePool;
+
+use crate::service::awards::{self, AwardType};
+
+/// Business logic validation errors for season awards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AwardValidationError {
+    /// Not one of the known awards
+    InvalidAward,
+    /// The player already holds this award in the season
+    AlreadyAwarded,
+    /// A single-winner award already has its winner
+    AwardTaken,
+    /// The All-Star Team already has all its players
+    AllStarTeamFull,
+}
+
+impl AwardValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            AwardValidationError::InvalidAward => "Invalid award",
+            AwardValidationError::AlreadyAwarded => "This player already has this award",
+            AwardValidationError::AwardTaken => {
+                "This award already has a winner this season; remove it first"
+            }
+            AwardValidationError::AllStarTeamFull => "The All-Star Team already has six players",
+        }
+    }
+}
+
+/// Gives a season award to a player with validation
+///
+/// # Returns
+/// * `Ok(i64)` - ID of created award
+/// * `Err(Ok(AwardValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn create_award_validated(
+    db: &SqlitePool,
+    season_id: i64,
+    award: &str,
+    player_id: i64,
+) -> Result<i64, Result<AwardValidationError, sqlx::Error>> {
+    let award = AwardType::from_str(award).ok_or(Ok(AwardValidationError::InvalidAward))?;
+
+    if awards::player_has_award(db, season_id, award, player_id)
+        .await
+        .map_err(Err)?
+    {
+        return Err(Ok(AwardValidationError::AlreadyAwarded));
+    }
+
+    let winners = awards::count_award_winners(db, season_id, award)
+        .await
+        .map_err(Err)?;
+    if winners >= award.max_winners() {
+        return Err(Ok(match award {
+            AwardType::AllStar => AwardValidationError::AllStarTeamFull,
+            _ => AwardValidationError::AwardTaken,
+        }));
+    }
+
+    awards::create_award(db, season_id, award, player_id)
+        .await
+        .map_err(Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures(
+            path = "../service/fixtures",
+            scripts("events", "seasons", "teams", "team_participations")
+        )
+    )]
+    async fn test_create_award_validated(pool: SqlitePool) {
+        sqlx::query(
+            "INSERT INTO player (id, name, country_id) VALUES (1, 'One', 34), (2, 'Two', 34)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        create_award_validated(&pool, 1, "mvp", 1).await.unwrap();
+        assert_eq!(
+            create_award_validated(&pool, 1, "mvp", 1)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            AwardValidationError::AlreadyAwarded
+        );
+        assert_eq!(
+            create_award_validated(&pool, 1, "mvp", 2)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            AwardValidationError::AwardTaken
+        );
+        assert_eq!(
+            create_award_validated(&pool, 1, "rookie", 2)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            AwardValidationError::InvalidAward
+        );
+        // Another season has its own MVP
+        create_award_validated(&pool, 2, "mvp", 2).await.unwrap();
+        // The All-Star Team takes several players
+        create_award_validated(&pool, 1, "all_star", 1)
+            .await
+            .unwrap();
+        create_award_validated(&pool, 1, "all_star", 2)
+            .await
+            .unwrap();
+    }
+}
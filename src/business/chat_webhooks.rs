@@ -0,0 +1,121 @@
+use sqlx::SqlitePool;
+
+use crate::service::chat_webhooks::{self, ChatWebhookKind, CreateChatWebhookEntity};
+
+/// Longest accepted webhook name
+const MAX_NAME_LENGTH: usize = 100;
+
+/// Business logic validation errors for chat webhooks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatWebhookValidationError {
+    /// Name is empty or too long
+    InvalidName,
+    /// Chat service is not Slack or Discord
+    InvalidKind,
+    /// URL is not an absolute HTTPS URL
+    InvalidUrl,
+}
+
+impl ChatWebhookValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            ChatWebhookValidationError::InvalidName => "Name must be 1 to 100 characters",
+            ChatWebhookValidationError::InvalidKind => "Choose Slack or Discord",
+            ChatWebhookValidationError::InvalidUrl => {
+                "Webhook URL must be an https:// address from the chat service"
+            }
+        }
+    }
+}
+
+/// Whether a URL can receive webhook posts: absolute, HTTPS and with a host
+fn is_valid_webhook_url(url: &str) -> bool {
+    reqwest::Url::parse(url)
+        .is_ok_and(|url| url.scheme() == "https" && url.host_str().is_some_and(|h| !h.is_empty()))
+}
+
+/// Creates a chat webhook with validation
+///
+/// # Returns
+/// * `Ok(i64)` - ID of the created webhook
+/// * `Err(Ok(ChatWebhookValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn create_chat_webhook_validated(
+    db: &SqlitePool,
+    name: &str,
+    kind: &str,
+    url: &str,
+    notify_results: bool,
+    notify_milestones: bool,
+) -> Result<i64, Result<ChatWebhookValidationError, sqlx::Error>> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(Ok(ChatWebhookValidationError::InvalidName));
+    }
+    let Some(kind) = ChatWebhookKind::from_str(kind) else {
+        return Err(Ok(ChatWebhookValidationError::InvalidKind));
+    };
+    let url = url.trim();
+    if !is_valid_webhook_url(url) {
+        return Err(Ok(ChatWebhookValidationError::InvalidUrl));
+    }
+
+    chat_webhooks::create_chat_webhook(
+        db,
+        &CreateChatWebhookEntity {
+            name: name.to_string(),
+            kind,
+            url: url.to_string(),
+            notify_results,
+            notify_milestones,
+        },
+    )
+    .await
+    .map_err(Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_webhook_url() {
+        assert!(is_valid_webhook_url(
+            "https://hooks.slack.com/services/T000/B000/XXXX"
+        ));
+        assert!(is_valid_webhook_url(
+            "https://discord.com/api/webhooks/123/abc"
+        ));
+        assert!(!is_valid_webhook_url(
+            "http://hooks.slack.com/services/T000"
+        ));
+        assert!(!is_valid_webhook_url("hooks.slack.com/services/T000"));
+        assert!(!is_valid_webhook_url("javascript:alert(1)"));
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_create_chat_webhook_validated(pool: SqlitePool) {
+        let url = "https://hooks.slack.com/services/T000/B000/XXXX";
+        let result = create_chat_webhook_validated(&pool, " ", "slack", url, true, true).await;
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            ChatWebhookValidationError::InvalidName
+        );
+        let result = create_chat_webhook_validated(&pool, "News", "teams", url, true, true).await;
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            ChatWebhookValidationError::InvalidKind
+        );
+
+        let id = create_chat_webhook_validated(&pool, " News ", "slack", url, true, false)
+            .await
+            .unwrap();
+        let webhook = chat_webhooks::get_chat_webhook_by_id(&pool, id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(webhook.name, "News");
+        assert!(webhook.notify_results && !webhook.notify_milestones);
+    }
+}
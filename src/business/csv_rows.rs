@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+/// Why a CSV upload cannot be read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvRowsError {
+    /// File is not readable as CSV
+    InvalidFile,
+    /// Header row does not name every required column
+    MissingColumn,
+    /// File has no data rows
+    Empty,
+    /// File has more data rows than allowed
+    TooManyRows,
+}
+
+/// A data row of an uploaded CSV, keyed by the columns its header names
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CsvRow {
+    /// Line in the file, counting the header as line 1
+    pub line: usize,
+    values: HashMap<&'static str, String>,
+}
+
+impl CsvRow {
+    /// Trimmed value of a column, empty when the file has no such column
+    pub fn get(&self, column: &str) -> String {
+        self.values.get(column).cloned().unwrap_or_default()
+    }
+}
+
+/// Read the rows of an uploaded CSV without touching the database
+///
+/// The first row names the columns; `column_of` maps a header to the column
+/// it stands for, headers it does not know are ignored. Semicolons are
+/// accepted as separators, as spreadsheet programs in many locales write them.
+/// Blank rows are skipped.
+pub fn read_csv_rows(
+    data: &[u8],
+    column_of: impl Fn(&str) -> Option<&'static str>,
+    required: &[&str],
+    max_rows: usize,
+) -> Result<Vec<CsvRow>, CsvRowsError> {
+    let first_line = data.split(|b| *b == b'\n').next().unwrap_or_default();
+    let delimiter = if first_line.contains(&b';') && !first_line.contains(&b',') {
+        b';'
+    } else {
+        b','
+    };
+
+    let mut reader = csv::ReaderBuilder::new()
+        .delimiter(delimiter)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .from_reader(data);
+
+    let columns: Vec<Option<&'static str>> = reader
+        .headers()
+        .map_err(|_| CsvRowsError::InvalidFile)?
+        .iter()
+        .map(|header| column_of(header.trim().to_lowercase().as_str()))
+        .collect();
+    if !required
+        .iter()
+        .all(|column| columns.contains(&Some(*column)))
+    {
+        return Err(CsvRowsError::MissingColumn);
+    }
+
+    // A record's position includes the blank lines the reader skipped before
+    // it, so lines are counted up to its first character to match what
+    // spreadsheets show
+    let line_at = |offset: usize| {
+        let offset = offset.min(data.len());
+        let start = data[offset..]
+            .iter()
+            .position(|b| !matches!(b, b'\n' | b'\r'))
+            .map_or(data.len(), |skipped| offset + skipped);
+        data[..start].iter().filter(|b| **b == b'\n').count() + 1
+    };
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|_| CsvRowsError::InvalidFile)?;
+        if record.iter().all(str::is_empty) {
+            continue;
+        }
+        if rows.len() == max_rows {
+            return Err(CsvRowsError::TooManyRows);
+        }
+
+        let mut values = HashMap::new();
+        for (column, value) in columns.iter().zip(record.iter()) {
+            if let Some(column) = column {
+                // The first of repeated columns wins
+                values.entry(*column).or_insert_with(|| value.to_string());
+            }
+        }
+        rows.push(CsvRow {
+            line: line_at(record.position().map_or(0, |p| p.byte() as usize)),
+            values,
+        });
+    }
+
+    if rows.is_empty() {
+        return Err(CsvRowsError::Empty);
+    }
+
+    Ok(rows)
+}
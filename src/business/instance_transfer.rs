@@ -0,0 +1,129 @@
+use std::collections::{BTreeSet, HashMap};
+
+use sqlx::SqlitePool;
+
+use crate::service::instance_transfer::{
+    self, InstanceFile, InstanceImportSummary, INSTANCE_EXPORT_FORMAT, INSTANCE_EXPORT_VERSION,
+};
+use crate::service::team_history::{self, ImportMode};
+
+/// Business logic validation errors for instance imports
+#[derive(Debug, Clone)]
+pub enum InstanceImportError {
+    /// File is not valid JSON or not an instance export
+    InvalidFile,
+    /// File was written by a newer, unknown format version
+    UnsupportedVersion,
+    /// File references a country code this instance does not know
+    UnknownCountry,
+    /// A roster or score event references a player missing from the file
+    UnknownPlayer,
+    /// An event, team or player has an empty name
+    MissingName,
+}
+
+impl InstanceImportError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            InstanceImportError::InvalidFile => "The file is not a valid instance export",
+            InstanceImportError::UnsupportedVersion => {
+                "The file was exported by a newer version and cannot be imported"
+            }
+            InstanceImportError::UnknownCountry => "The file references an unknown country code",
+            InstanceImportError::UnknownPlayer => "The file references a player that is not listed",
+            InstanceImportError::MissingName => {
+                "The file contains an event, team or player without a name"
+            }
+        }
+    }
+}
+
+/// Parse an instance export without touching the database
+pub fn parse_instance_file(data: &[u8]) -> Result<InstanceFile, InstanceImportError> {
+    let file: InstanceFile =
+        serde_json::from_slice(data).map_err(|_| InstanceImportError::InvalidFile)?;
+
+    if file.format != INSTANCE_EXPORT_FORMAT {
+        return Err(InstanceImportError::InvalidFile);
+    }
+
+    if file.version > INSTANCE_EXPORT_VERSION {
+        return Err(InstanceImportError::UnsupportedVersion);
+    }
+
+    if !file.has_names() {
+        return Err(InstanceImportError::MissingName);
+    }
+
+    let listed: BTreeSet<i64> = file.players.iter().map(|p| p.key).collect();
+    if !file.referenced_player_keys().is_subset(&listed) {
+        return Err(InstanceImportError::UnknownPlayer);
+    }
+
+    Ok(file)
+}
+
+/// Imports an instance export with validation
+///
+/// # Returns
+/// * `Ok(InstanceImportSummary)` - What was created, updated or matched
+/// * `Err(Ok(InstanceImportError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn import_instance_validated(
+    db: &SqlitePool,
+    data: &[u8],
+    mode: ImportMode,
+) -> Result<InstanceImportSummary, Result<InstanceImportError, sqlx::Error>> {
+    let file = parse_instance_file(data).map_err(Ok)?;
+
+    let mut countries = HashMap::new();
+    for code in file.country_codes() {
+        match team_history::find_country_by_code(db, code).await {
+            Ok(Some(id)) => {
+                countries.insert(code.to_string(), id);
+            }
+            Ok(None) => return Err(Ok(InstanceImportError::UnknownCountry)),
+            Err(e) => return Err(Err(e)),
+        }
+    }
+
+    instance_transfer::import_instance(db, &file, &countries, mode)
+        .await
+        .map_err(Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rejects_invalid_files() {
+        assert!(matches!(
+            parse_instance_file(b"not json"),
+            Err(InstanceImportError::InvalidFile)
+        ));
+        assert!(matches!(
+            parse_instance_file(br#"{"format":"hockey-team-history","version":1}"#),
+            Err(InstanceImportError::InvalidFile)
+        ));
+        assert!(matches!(
+            parse_instance_file(br#"{"format":"hockey-instance","version":99}"#),
+            Err(InstanceImportError::UnsupportedVersion)
+        ));
+        assert!(matches!(
+            parse_instance_file(
+                br#"{"format":"hockey-instance","version":1,"events":[{"name":" "}]}"#
+            ),
+            Err(InstanceImportError::MissingName)
+        ));
+        assert!(matches!(
+            parse_instance_file(
+                br#"{"format":"hockey-instance","version":1,"events":[{"name":"E","seasons":[
+                    {"year":2024,"participations":[{"team":{"name":"T"},"roster":[{"player":5}]}]}]}]}"#
+            ),
+            Err(InstanceImportError::UnknownPlayer)
+        ));
+        assert!(parse_instance_file(br#"{"format":"hockey-instance","version":1}"#).is_ok());
+    }
+}
@@ -0,0 +1,64 @@
+use sqlx::SqlitePool;
+
+use crate::service::lines::{self, LineUnit};
+use crate::service::player_contracts;
+
+/// Business logic validation errors for line combination operations
+#[derive(Debug, Clone)]
+pub enum LineValidationError {
+    /// Unit is not forward, defense or goalie
+    InvalidUnit,
+    /// Line or slot number is outside the unit's chart
+    InvalidSlot,
+    /// Player is not on this team participation's roster
+    PlayerNotInRoster,
+}
+
+impl LineValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            LineValidationError::InvalidUnit => "Unknown line unit",
+            LineValidationError::InvalidSlot => "This position does not exist on the lines chart",
+            LineValidationError::PlayerNotInRoster => "Player is not on this roster",
+        }
+    }
+}
+
+/// Places a roster player on the lines chart with validation
+///
+/// # Returns
+/// * `Ok(())` - Player was placed (swapping with the previous occupant)
+/// * `Err(Ok(LineValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn assign_player_validated(
+    db: &SqlitePool,
+    team_participation_id: i64,
+    player_contract_id: i64,
+    unit: &str,
+    line_number: i64,
+    slot: i64,
+) -> Result<(), Result<LineValidationError, sqlx::Error>> {
+    let unit = LineUnit::from_str(unit).ok_or(Ok(LineValidationError::InvalidUnit))?;
+
+    if !unit.is_valid_slot(line_number, slot) {
+        return Err(Ok(LineValidationError::InvalidSlot));
+    }
+
+    match player_contracts::get_player_contract(db, player_contract_id).await {
+        Ok(Some(contract)) if contract.team_participation_id == team_participation_id => {}
+        Ok(_) => return Err(Ok(LineValidationError::PlayerNotInRoster)),
+        Err(e) => return Err(Err(e)),
+    }
+
+    lines::assign_player_to_slot(
+        db,
+        team_participation_id,
+        player_contract_id,
+        unit,
+        line_number,
+        slot,
+    )
+    .await
+    .map_err(Err)
+}
@@ -0,0 +1,240 @@
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+
+use crate::business::csv_rows::{read_csv_rows, CsvRowsError};
+use crate::service::match_import::{self, MatchImportPlan, MatchImportRow};
+
+/// Most match rows accepted in one import
+pub const MATCH_IMPORT_MAX_ROWS: usize = 1000;
+
+/// Business logic validation errors for match imports
+#[derive(Debug, Clone)]
+pub enum MatchImportError {
+    /// File is neither a CSV file nor a JSON list of matches
+    InvalidFile,
+    /// CSV header row does not name every column
+    MissingColumns,
+    /// Import has no match rows
+    Empty,
+    /// Import has more rows than allowed
+    TooManyRows,
+}
+
+impl MatchImportError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            MatchImportError::InvalidFile => {
+                "The file is neither a valid CSV file nor a JSON list of matches"
+            }
+            MatchImportError::MissingColumns => {
+                "The first row must name the season, home team, away team, date, home score and away score columns"
+            }
+            MatchImportError::Empty => "The import does not contain any matches",
+            MatchImportError::TooManyRows => "An import cannot contain more than 1000 matches",
+        }
+    }
+}
+
+/// Result of an import whose rows could be read
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MatchImportOutcome {
+    /// Every row was valid; IDs of the created matches in row order
+    Created(Vec<i64>),
+    /// Some rows are invalid and nothing was created
+    Rejected(MatchImportPlan),
+}
+
+/// A value given either as text or as a number in JSON
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TextOrNumber {
+    Text(String),
+    Number(i64),
+}
+
+impl TextOrNumber {
+    fn into_text(self) -> String {
+        match self {
+            TextOrNumber::Text(text) => text,
+            TextOrNumber::Number(number) => number.to_string(),
+        }
+    }
+}
+
+/// A match of a JSON import
+///
+/// The season is its ID or label, teams are names or external aliases.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchImportRecord {
+    pub season: TextOrNumber,
+    pub home_team: String,
+    pub away_team: String,
+    pub date: String,
+    pub home_score: TextOrNumber,
+    pub away_score: TextOrNumber,
+}
+
+impl MatchImportRecord {
+    fn into_row(self, line: usize) -> MatchImportRow {
+        MatchImportRow {
+            line,
+            season: self.season.into_text(),
+            home_team: self.home_team,
+            away_team: self.away_team,
+            date: self.date,
+            home_score: self.home_score.into_text(),
+            away_score: self.away_score.into_text(),
+        }
+    }
+}
+
+/// Column of the match file a header names
+fn column_of(header: &str) -> Option<&'static str> {
+    match header {
+        "season" | "season_id" | "season id" => Some("season"),
+        "home" | "home team" | "home_team" => Some("home_team"),
+        "away" | "away team" | "away_team" => Some("away_team"),
+        "date" | "match date" | "match_date" => Some("date"),
+        "home score" | "home_score" | "home goals" => Some("home_score"),
+        "away score" | "away_score" | "away goals" => Some("away_score"),
+        _ => None,
+    }
+}
+
+/// Rows of a JSON import, numbered from 1
+pub fn records_to_rows(
+    records: Vec<MatchImportRecord>,
+) -> Result<Vec<MatchImportRow>, MatchImportError> {
+    if records.is_empty() {
+        return Err(MatchImportError::Empty);
+    }
+    if records.len() > MATCH_IMPORT_MAX_ROWS {
+        return Err(MatchImportError::TooManyRows);
+    }
+
+    Ok(records
+        .into_iter()
+        .enumerate()
+        .map(|(index, record)| record.into_row(index + 1))
+        .collect())
+}
+
+/// Parse an uploaded match file without touching the database
+///
+/// A file starting with `[` is read as a JSON list of matches, anything else
+/// as a CSV whose first row names the columns.
+pub fn parse_match_file(data: &[u8]) -> Result<Vec<MatchImportRow>, MatchImportError> {
+    if data.trim_ascii_start().starts_with(b"[") {
+        let records: Vec<MatchImportRecord> =
+            serde_json::from_slice(data).map_err(|_| MatchImportError::InvalidFile)?;
+        return records_to_rows(records);
+    }
+
+    let rows = read_csv_rows(
+        data,
+        column_of,
+        &[
+            "season",
+            "home_team",
+            "away_team",
+            "date",
+            "home_score",
+            "away_score",
+        ],
+        MATCH_IMPORT_MAX_ROWS,
+    )
+    .map_err(|error| match error {
+        CsvRowsError::InvalidFile => MatchImportError::InvalidFile,
+        CsvRowsError::MissingColumn => MatchImportError::MissingColumns,
+        CsvRowsError::Empty => MatchImportError::Empty,
+        CsvRowsError::TooManyRows => MatchImportError::TooManyRows,
+    })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| MatchImportRow {
+            line: row.line,
+            season: row.get("season"),
+            home_team: row.get("home_team"),
+            away_team: row.get("away_team"),
+            date: row.get("date"),
+            home_score: row.get("home_score"),
+            away_score: row.get("away_score"),
+        })
+        .collect())
+}
+
+/// Imports match rows, creating all of them or none
+///
+/// # Returns
+/// * `Ok(MatchImportOutcome)` - The created matches, or the rows that are invalid
+/// * `Err(sqlx::Error)` - If database operation fails
+pub async fn import_matches(
+    db: &SqlitePool,
+    rows: Vec<MatchImportRow>,
+) -> Result<MatchImportOutcome, sqlx::Error> {
+    let plan = match_import::plan_match_import(db, rows).await?;
+    if plan.has_errors() {
+        return Ok(MatchImportOutcome::Rejected(plan));
+    }
+
+    let ids = match_import::apply_match_import(db, &plan).await?;
+    Ok(MatchImportOutcome::Created(ids))
+}
+
+/// Imports an uploaded match file with validation
+///
+/// # Returns
+/// * `Ok(MatchImportOutcome)` - The created matches, or the rows that are invalid
+/// * `Err(Ok(MatchImportError))` - If the file cannot be read
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn import_match_file_validated(
+    db: &SqlitePool,
+    data: &[u8],
+) -> Result<MatchImportOutcome, Result<MatchImportError, sqlx::Error>> {
+    let rows = parse_match_file(data).map_err(Ok)?;
+
+    import_matches(db, rows).await.map_err(Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_match_file() {
+        let csv = parse_match_file(
+            b"Season;Home;Away;Date;Home score;Away score\n2024 World Cup;Team USA;Team Canada;2024-09-20;3;2\n",
+        )
+        .unwrap();
+        let json = parse_match_file(
+            br#" [{"season": "2024 World Cup", "home_team": "Team USA", "away_team": "Team Canada",
+                   "date": "2024-09-20", "home_score": 3, "away_score": "2"}]"#,
+        )
+        .unwrap();
+        assert_eq!(csv[0].line, 2);
+        assert_eq!(json[0].line, 1);
+        assert_eq!(
+            csv[0],
+            MatchImportRow {
+                line: 2,
+                ..json[0].clone()
+            }
+        );
+        assert_eq!(json[0].home_score, "3");
+
+        assert!(matches!(
+            parse_match_file(b"season,home,away,date\n1,A,B,2024-01-01\n"),
+            Err(MatchImportError::MissingColumns)
+        ));
+        assert!(matches!(
+            parse_match_file(b"[{\"season\": 1}]"),
+            Err(MatchImportError::InvalidFile)
+        ));
+        assert!(matches!(
+            parse_match_file(b"[]"),
+            Err(MatchImportError::Empty)
+        ));
+    }
+}
@@ -1,6 +1,15 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
 use sqlx::SqlitePool;
 
-use crate::service::matches::{self, CreateMatchEntity, UpdateMatchEntity};
+use crate::service::matches::{
+    self, CreateMatchEntity, EmptyNetIssue, GameType, GoalFlags, MatchEntity, ScoreEventEntity,
+    UpdateMatchEntity, SHOOTOUT_PERIOD,
+};
+
+/// Upper bound for attendance, well above the largest outdoor games
+const MAX_ATTENDANCE: i64 = 200_000;
 
 /// Business logic validation errors for match operations
 #[derive(Debug, Clone)]
@@ -9,6 +18,10 @@ pub enum MatchValidationError {
     SameTeams,
     /// Score values are negative
     NegativeScores,
+    /// Attendance is negative or implausibly large
+    InvalidAttendance,
+    /// Game type is not one of the known classifications
+    InvalidGameType,
     /// Teams don't participate in the selected season
     TeamsNotInSeason,
     /// Database error during validation
@@ -21,6 +34,8 @@ impl MatchValidationError {
         match self {
             MatchValidationError::SameTeams => "Home and away teams must be different",
             MatchValidationError::NegativeScores => "Scores cannot be negative",
+            MatchValidationError::InvalidAttendance => "Attendance must be between 0 and 200,000",
+            MatchValidationError::InvalidGameType => "Invalid game type",
             MatchValidationError::TeamsNotInSeason => {
                 "Both teams must participate in the selected season"
             }
@@ -38,10 +53,13 @@ impl MatchValidationError {
 /// * `away_team_id` - Away team ID
 /// * `home_score_unidentified` - Home team score
 /// * `away_score_unidentified` - Away team score
+/// * `attendance` - Number of spectators, if known
+/// * `game_type` - Match classification (group, playoff, ...)
 ///
 /// # Returns
 /// * `Ok(())` - If validation passes
 /// * `Err(MatchValidationError)` - If validation fails
+#[allow(clippy::too_many_arguments)]
 async fn validate_match_form(
     db: &SqlitePool,
     season_id: i64,
@@ -49,6 +67,8 @@ async fn validate_match_form(
     away_team_id: i64,
     home_score_unidentified: i32,
     away_score_unidentified: i32,
+    attendance: Option<i64>,
+    game_type: &str,
 ) -> Result<(), MatchValidationError> {
     // Validate teams are different
     if home_team_id == away_team_id {
@@ -60,6 +80,14 @@ async fn validate_match_form(
         return Err(MatchValidationError::NegativeScores);
     }
 
+    if attendance.is_some_and(|a| !(0..=MAX_ATTENDANCE).contains(&a)) {
+        return Err(MatchValidationError::InvalidAttendance);
+    }
+
+    if GameType::from_str(game_type).is_none() {
+        return Err(MatchValidationError::InvalidGameType);
+    }
+
     // Validate teams participate in season
     match matches::validate_teams_in_season(db, season_id, home_team_id, away_team_id).await {
         Ok(true) => Ok(()),
@@ -93,6 +121,8 @@ pub async fn create_match_validated(
         entity.away_team_id,
         entity.home_score_unidentified,
         entity.away_score_unidentified,
+        entity.attendance,
+        &entity.game_type,
     )
     .await
     .map_err(Ok)?;
@@ -125,6 +155,8 @@ pub async fn update_match_validated(
         entity.away_team_id,
         entity.home_score_unidentified,
         entity.away_score_unidentified,
+        entity.attendance,
+        &entity.game_type,
     )
     .await
     .map_err(Ok)?;
@@ -132,3 +164,240 @@ pub async fn update_match_validated(
     // Update match
     matches::update_match(db, id, entity).await.map_err(Err)
 }
+
+/// Flags the game-winning goal and inconsistent empty-net goals
+///
+/// The game-winning goal is the winner's goal that put them one ahead of the
+/// loser's final total. It is only derived for finished matches where all of
+/// the winner's goals are recorded and their order is known; a match decided
+/// in a shootout has none. An empty-net goal is flagged when it was scored in
+/// a shootout, or when the scoring team was behind at the time, which is only
+/// checked when every goal of the match is recorded.
+///
+/// # Returns
+/// Flags keyed by score event ID, only for goals with something to show
+pub fn derive_goal_flags(
+    match_info: &MatchEntity,
+    events: &[ScoreEventEntity],
+) -> HashMap<i64, GoalFlags> {
+    let mut flags: HashMap<i64, GoalFlags> = HashMap::new();
+
+    let team_goals = |team_id: i64| events.iter().filter(|e| e.team_id == team_id).count() as i32;
+    let home_total = team_goals(match_info.home_team_id) + match_info.home_score_unidentified;
+    let away_total = team_goals(match_info.away_team_id) + match_info.away_score_unidentified;
+
+    let winner = match home_total.cmp(&away_total) {
+        Ordering::Greater => Some((
+            match_info.home_team_id,
+            match_info.home_score_unidentified,
+            away_total,
+        )),
+        Ordering::Less => Some((
+            match_info.away_team_id,
+            match_info.away_score_unidentified,
+            home_total,
+        )),
+        Ordering::Equal => None,
+    };
+    if let Some((winner_id, 0, loser_total)) = winner.filter(|_| match_info.status == "finished") {
+        let mut winner_goals: Vec<&ScoreEventEntity> =
+            events.iter().filter(|e| e.team_id == winner_id).collect();
+        winner_goals.sort_by_key(|e| (e.period, game_time(e)));
+        if let Some(goal) = winner_goals.get(loser_total as usize) {
+            if goal.period != SHOOTOUT_PERIOD && has_known_order(goal, events) {
+                flags.entry(goal.id).or_default().game_winning = true;
+            }
+        }
+    }
+
+    let all_recorded =
+        match_info.home_score_unidentified == 0 && match_info.away_score_unidentified == 0;
+    for goal in events
+        .iter()
+        .filter(|e| e.goal_type.as_deref() == Some("empty_net"))
+    {
+        let issue = if goal.period == SHOOTOUT_PERIOD {
+            Some(EmptyNetIssue::Shootout)
+        } else if all_recorded && has_known_order(goal, events) {
+            let scored_before = |team_id: i64| {
+                events
+                    .iter()
+                    .filter(|e| e.team_id == team_id && is_before(e, goal))
+                    .count()
+            };
+            let opponent_id = if goal.team_id == match_info.home_team_id {
+                match_info.away_team_id
+            } else {
+                match_info.home_team_id
+            };
+            (scored_before(goal.team_id) < scored_before(opponent_id))
+                .then_some(EmptyNetIssue::ScoredWhileTrailing)
+        } else {
+            None
+        };
+        if issue.is_some() {
+            flags.entry(goal.id).or_default().empty_net_issue = issue;
+        }
+    }
+
+    flags
+}
+
+/// Game time of a goal within its period, if recorded
+fn game_time(event: &ScoreEventEntity) -> Option<(i32, i32)> {
+    Some((event.time_minutes?, event.time_seconds.unwrap_or(0)))
+}
+
+/// Whether a goal can be placed among the other goals of its period
+fn has_known_order(goal: &ScoreEventEntity, events: &[ScoreEventEntity]) -> bool {
+    events
+        .iter()
+        .filter(|e| e.period == goal.period)
+        .all(|e| game_time(e).is_some())
+}
+
+/// Whether a goal was scored before another one, both with known order
+fn is_before(event: &ScoreEventEntity, other: &ScoreEventEntity) -> bool {
+    (event.period, game_time(event)) < (other.period, game_time(other))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finished_match(home_unidentified: i32, away_unidentified: i32) -> MatchEntity {
+        MatchEntity {
+            id: 1,
+            season_id: 1,
+            season_name: None,
+            event_name: None,
+            home_team_id: 1,
+            home_team_name: "Home".to_string(),
+            home_team_country_iso2: None,
+            away_team_id: 2,
+            away_team_name: "Away".to_string(),
+            away_team_country_iso2: None,
+            home_score_unidentified: home_unidentified,
+            away_score_unidentified: away_unidentified,
+            home_score_identified: 0,
+            away_score_identified: 0,
+            match_date: None,
+            status: "finished".to_string(),
+            arena_id: None,
+            arena_name: None,
+            arena_city: None,
+            attendance: None,
+            game_type: "group".to_string(),
+            result: None,
+        }
+    }
+
+    fn goal(id: i64, team_id: i64, period: i32, minutes: Option<i32>) -> ScoreEventEntity {
+        ScoreEventEntity {
+            id,
+            match_id: 1,
+            team_id,
+            team_name: String::new(),
+            scorer_id: None,
+            scorer_name: None,
+            assist1_id: None,
+            assist1_name: None,
+            assist2_id: None,
+            assist2_name: None,
+            period,
+            time_minutes: minutes,
+            time_seconds: minutes.map(|_| 0),
+            goal_type: None,
+            scorer_captaincy: None,
+            assist1_captaincy: None,
+            assist2_captaincy: None,
+            video_url: None,
+            video_timestamp: None,
+        }
+    }
+
+    fn empty_net(mut event: ScoreEventEntity) -> ScoreEventEntity {
+        event.goal_type = Some("empty_net".to_string());
+        event
+    }
+
+    fn game_winning_ids(flags: &HashMap<i64, GoalFlags>) -> Vec<i64> {
+        let mut ids: Vec<i64> = flags
+            .iter()
+            .filter(|(_, f)| f.game_winning)
+            .map(|(id, _)| *id)
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn test_game_winning_goal() {
+        // Home wins 3-1; their second goal in game order is the winner
+        let events = vec![
+            goal(1, 1, 1, Some(5)),
+            goal(2, 2, 2, Some(3)),
+            goal(3, 1, 3, Some(15)),
+            goal(4, 1, 2, Some(10)),
+        ];
+        let flags = derive_goal_flags(&finished_match(0, 0), &events);
+        assert_eq!(game_winning_ids(&flags), vec![4]);
+
+        // Unrecorded goals of the loser still count towards their total
+        let flags = derive_goal_flags(&finished_match(0, 1), &events);
+        assert_eq!(game_winning_ids(&flags), vec![3]);
+
+        // Unrecorded goals of the winner leave the order unknown
+        let flags = derive_goal_flags(&finished_match(1, 0), &events);
+        assert!(game_winning_ids(&flags).is_empty());
+
+        // Missing times in the deciding period leave the order unknown
+        let mut untimed = events.clone();
+        untimed[1].time_minutes = None;
+        untimed[1].period = 2;
+        let flags = derive_goal_flags(&finished_match(0, 0), &untimed);
+        assert!(game_winning_ids(&flags).is_empty());
+
+        // Ties, shootouts and unfinished matches have no game-winning goal
+        let flags = derive_goal_flags(&finished_match(0, 2), &events);
+        assert!(game_winning_ids(&flags).is_empty());
+        let shootout = vec![
+            goal(1, 1, 1, Some(5)),
+            goal(2, 2, 2, None),
+            goal(3, 1, 5, None),
+        ];
+        let flags = derive_goal_flags(&finished_match(0, 0), &shootout);
+        assert!(game_winning_ids(&flags).is_empty());
+        let mut scheduled = finished_match(0, 0);
+        scheduled.status = "in_progress".to_string();
+        assert!(game_winning_ids(&derive_goal_flags(&scheduled, &events)).is_empty());
+    }
+
+    #[test]
+    fn test_empty_net_consistency() {
+        let events = vec![
+            goal(1, 1, 1, Some(5)),
+            empty_net(goal(2, 1, 3, Some(19))),
+            empty_net(goal(3, 2, 2, Some(1))),
+            empty_net(goal(4, 2, 5, None)),
+        ];
+        let flags = derive_goal_flags(&finished_match(0, 0), &events);
+        assert_eq!(flags.get(&2).and_then(|f| f.empty_net_issue), None);
+        assert_eq!(
+            flags.get(&3).and_then(|f| f.empty_net_issue),
+            Some(EmptyNetIssue::ScoredWhileTrailing)
+        );
+        assert_eq!(
+            flags.get(&4).and_then(|f| f.empty_net_issue),
+            Some(EmptyNetIssue::Shootout)
+        );
+
+        // With unrecorded goals the score at the time is unknown
+        let flags = derive_goal_flags(&finished_match(1, 0), &events);
+        assert_eq!(flags.get(&3).and_then(|f| f.empty_net_issue), None);
+        assert_eq!(
+            flags.get(&4).and_then(|f| f.empty_net_issue),
+            Some(EmptyNetIssue::Shootout)
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use sqlx::SqlitePool;
+
+use crate::service::milestones::{
+    self, MatchPlayerPointsEntity, MilestoneKind, NewMilestone, BIG_GAME_POINTS,
+    CAREER_GOAL_MILESTONE, HAT_TRICK_GOALS,
+};
+
+/// Single-match milestones reached by the players of a match
+pub fn detect_match_milestones(points: &[MatchPlayerPointsEntity]) -> Vec<NewMilestone> {
+    let mut detected = Vec::new();
+    for player in points {
+        if player.goals >= HAT_TRICK_GOALS {
+            detected.push(NewMilestone {
+                player_id: player.player_id,
+                kind: MilestoneKind::HatTrick,
+                value: player.goals,
+            });
+        }
+        if player.points() >= BIG_GAME_POINTS {
+            detected.push(NewMilestone {
+                player_id: player.player_id,
+                kind: MilestoneKind::FourPointGame,
+                value: player.points(),
+            });
+        }
+    }
+    detected
+}
+
+/// Re-detect milestones after a goal of a match was created, edited or deleted
+///
+/// `previous_player_ids` are the players credited on the goal before the
+/// change, so a removed scorer's career milestone is corrected as well.
+/// Returns the ids of newly reached milestones.
+pub async fn refresh_match_milestones(
+    db: &SqlitePool,
+    match_id: i64,
+    previous_player_ids: &[i64],
+) -> Result<Vec<i64>, sqlx::Error> {
+    let points = milestones::get_match_player_points(db, match_id).await?;
+    let mut reached =
+        milestones::replace_match_milestones(db, match_id, &detect_match_milestones(&points))
+            .await?;
+
+    let mut scorers: Vec<i64> = points
+        .iter()
+        .filter(|p| p.goals > 0)
+        .map(|p| p.player_id)
+        .chain(previous_player_ids.iter().copied())
+        .collect();
+    scorers.sort_unstable();
+    scorers.dedup();
+
+    for player_id in scorers {
+        let milestone_match =
+            milestones::get_career_goal_match(db, player_id, CAREER_GOAL_MILESTONE).await?;
+        reached
+            .extend(milestones::set_career_goal_milestone(db, player_id, milestone_match).await?);
+    }
+
+    Ok(reached)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_match_milestones() {
+        let points = [
+            MatchPlayerPointsEntity {
+                player_id: 1,
+                goals: 3,
+                assists: 1,
+            },
+            MatchPlayerPointsEntity {
+                player_id: 2,
+                goals: 0,
+                assists: 3,
+            },
+        ];
+
+        assert_eq!(
+            detect_match_milestones(&points),
+            vec![
+                NewMilestone {
+                    player_id: 1,
+                    kind: MilestoneKind::HatTrick,
+                    value: 3,
+                },
+                NewMilestone {
+                    player_id: 1,
+                    kind: MilestoneKind::FourPointGame,
+                    value: 4,
+                },
+            ]
+        );
+    }
+}
@@ -1,2 +1,28 @@
+pub mod awards;
+pub mod chat_webhooks;
+pub mod csv_rows;
+pub mod instance_transfer;
+pub mod lines;
+pub mod match_import;
 pub mod matches;
+pub mod milestones;
+pub mod password_reset;
+pub mod placements;
+pub mod player_contracts;
+pub mod player_import;
 pub mod players;
+pub mod playoffs;
+pub mod referees;
+pub mod registration;
+pub mod reports;
+pub mod roster_import;
+pub mod roster_rules;
+pub mod season_clone;
+pub mod season_groups;
+pub mod slugs;
+pub mod team_history;
+pub mod team_merge;
+pub mod transfers;
+pub mod user_identities;
+pub mod users;
+pub mod webhooks;
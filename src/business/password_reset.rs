@@ -0,0 +1,114 @@
+use sqlx::SqlitePool;
+
+use crate::business::users::{hash, validate_new_password, UserValidationError};
+use crate::service::password_reset::{self, ResetTokenStatus};
+
+/// Issues a reset token for the active user with this email
+///
+/// # Returns
+/// * `Ok(Some(token))` - Token for the emailed link
+/// * `Ok(None)` - No active user has this email; nothing should be sent
+/// * `Err(sqlx::Error)` - If database operation fails
+pub async fn request_password_reset(
+    db: &SqlitePool,
+    email: &str,
+) -> Result<Option<String>, sqlx::Error> {
+    let Some(user_id) = password_reset::get_resettable_user_id(db, email.trim()).await? else {
+        return Ok(None);
+    };
+
+    password_reset::create_reset_token(db, user_id)
+        .await
+        .map(Some)
+}
+
+/// Sets a new password with an emailed reset token
+///
+/// The token is checked before the password is hashed, so requests with
+/// made-up tokens cost no hashing.
+///
+/// # Returns
+/// * `Ok(ResetTokenStatus)` - The token's state; the password changed if it was valid
+/// * `Err(Ok(UserValidationError))` - If the new password is rejected
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn reset_password_validated(
+    db: &SqlitePool,
+    token: &str,
+    password: &str,
+    confirm_password: &str,
+) -> Result<ResetTokenStatus, Result<UserValidationError, sqlx::Error>> {
+    let status = password_reset::check_reset_token(db, token)
+        .await
+        .map_err(Err)?;
+    if !matches!(status, ResetTokenStatus::Valid { .. }) {
+        return Ok(status);
+    }
+
+    validate_new_password(password, confirm_password).map_err(Ok)?;
+
+    let password_hash = hash(password).map_err(Err)?;
+    password_reset::reset_password(db, token, &password_hash)
+        .await
+        .map_err(Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::password::verify_password;
+    use crate::service::users::{self, UserRole};
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_password_reset(pool: SqlitePool) {
+        assert_eq!(
+            request_password_reset(&pool, "nobody@x.cz").await.unwrap(),
+            None
+        );
+
+        let id = users::create_user(&pool, "coach@x.cz", "Coach", UserRole::Editor, "hash")
+            .await
+            .unwrap();
+        let token = request_password_reset(&pool, " Coach@x.cz ")
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Unknown and expired links are refused before the password is looked at
+        assert_eq!(
+            reset_password_validated(&pool, "made-up", "new-password", "other")
+                .await
+                .unwrap(),
+            ResetTokenStatus::Invalid
+        );
+        sqlx::query("UPDATE password_reset_token SET expires_at = datetime('now', '-1 minutes')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            reset_password_validated(&pool, &token, "new-password", "other")
+                .await
+                .unwrap(),
+            ResetTokenStatus::Expired
+        );
+        let token = request_password_reset(&pool, "coach@x.cz")
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            reset_password_validated(&pool, &token, "new-password", "new-passw0rd")
+                .await
+                .unwrap_err()
+                .unwrap(),
+            UserValidationError::PasswordMismatch
+        );
+        assert_eq!(
+            reset_password_validated(&pool, &token, "new-password", "new-password")
+                .await
+                .unwrap(),
+            ResetTokenStatus::Valid { user_id: id }
+        );
+        let hash = users::get_password_hash(&pool, id).await.unwrap().unwrap();
+        assert!(verify_password("new-password", &hash).unwrap());
+    }
+}
@@ -0,0 +1,90 @@
+use sqlx::SqlitePool;
+
+use crate::service::placements::{self, MAX_FINAL_RANK, MAX_SEED};
+
+/// Business logic validation errors for final placements
+#[derive(Debug, Clone)]
+pub enum PlacementValidationError {
+    /// Placement outside 1 to `MAX_FINAL_RANK`
+    OutOfRange,
+    /// Another team of the season already holds this placement
+    RankTaken,
+    /// Seed outside 1 to `MAX_SEED`
+    SeedOutOfRange,
+    /// Another team of the season already holds this seed
+    SeedTaken,
+}
+
+impl PlacementValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            PlacementValidationError::OutOfRange => "Placement must be between 1st and 16th",
+            PlacementValidationError::RankTaken => {
+                "Another team of this season already has this placement"
+            }
+            PlacementValidationError::SeedOutOfRange => "Seed must be between 1 and 16",
+            PlacementValidationError::SeedTaken => {
+                "Another team of this season already has this seed"
+            }
+        }
+    }
+}
+
+/// Sets the final placement of a team participation with validation
+///
+/// # Returns
+/// * `Ok(bool)` - Whether the team participation exists
+/// * `Err(Ok(PlacementValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn set_final_rank_validated(
+    db: &SqlitePool,
+    participation_id: i64,
+    final_rank: Option<i64>,
+) -> Result<bool, Result<PlacementValidationError, sqlx::Error>> {
+    if let Some(rank) = final_rank {
+        if !(1..=MAX_FINAL_RANK).contains(&rank) {
+            return Err(Ok(PlacementValidationError::OutOfRange));
+        }
+
+        if placements::is_final_rank_taken(db, participation_id, rank)
+            .await
+            .map_err(Err)?
+        {
+            return Err(Ok(PlacementValidationError::RankTaken));
+        }
+    }
+
+    placements::set_final_rank(db, participation_id, final_rank)
+        .await
+        .map_err(Err)
+}
+
+/// Sets the playoff seed of a team participation with validation
+///
+/// # Returns
+/// * `Ok(bool)` - Whether the team participation exists
+/// * `Err(Ok(PlacementValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn set_seed_validated(
+    db: &SqlitePool,
+    participation_id: i64,
+    seed: Option<i64>,
+) -> Result<bool, Result<PlacementValidationError, sqlx::Error>> {
+    if let Some(seed) = seed {
+        if !(1..=MAX_SEED).contains(&seed) {
+            return Err(Ok(PlacementValidationError::SeedOutOfRange));
+        }
+
+        if placements::is_seed_taken(db, participation_id, seed)
+            .await
+            .map_err(Err)?
+        {
+            return Err(Ok(PlacementValidationError::SeedTaken));
+        }
+    }
+
+    placements::set_seed(db, participation_id, seed)
+        .await
+        .map_err(Err)
+}
@@ -0,0 +1,86 @@
+use sqlx::SqlitePool;
+
+use crate::service::player_contracts::{self, Captaincy, PlayerContractEntity};
+
+/// Business logic validation errors for roster entries
+#[derive(Debug, Clone)]
+pub enum CaptaincyValidationError {
+    /// Captaincy is not `C`, `A` or empty
+    InvalidCaptaincy,
+    /// Another player of the team is already captain
+    CaptainTaken,
+    /// The team already has the maximum number of alternates
+    TooManyAlternates,
+    /// Player has been transferred away from this team
+    TransferredOut,
+}
+
+impl CaptaincyValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            CaptaincyValidationError::InvalidCaptaincy => "Unknown captaincy",
+            CaptaincyValidationError::CaptainTaken => "This team already has a captain",
+            CaptaincyValidationError::TooManyAlternates => {
+                "This team already has two alternate captains"
+            }
+            CaptaincyValidationError::TransferredOut => {
+                "A player transferred away from the team cannot wear a letter"
+            }
+        }
+    }
+}
+
+/// Parses a submitted captaincy; an empty value clears it
+pub fn parse_captaincy(value: Option<&str>) -> Result<Option<Captaincy>, CaptaincyValidationError> {
+    match value.map(str::trim).filter(|v| !v.is_empty()) {
+        None => Ok(None),
+        Some(value) => Captaincy::from_str(value)
+            .map(Some)
+            .ok_or(CaptaincyValidationError::InvalidCaptaincy),
+    }
+}
+
+/// Sets the captaincy of a roster entry with validation
+///
+/// A team has one captain and at most two alternates.
+///
+/// # Returns
+/// * `Ok(())` - Captaincy was updated
+/// * `Err(Ok(CaptaincyValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn update_captaincy_validated(
+    db: &SqlitePool,
+    contract: &PlayerContractEntity,
+    captaincy: Option<Captaincy>,
+) -> Result<(), Result<CaptaincyValidationError, sqlx::Error>> {
+    if let Some(captaincy) = captaincy {
+        if contract.left_on.is_some() {
+            return Err(Ok(CaptaincyValidationError::TransferredOut));
+        }
+
+        let others = player_contracts::count_captaincy(
+            db,
+            contract.team_participation_id,
+            captaincy,
+            Some(contract.id),
+        )
+        .await
+        .map_err(Err)?;
+
+        match captaincy {
+            Captaincy::Captain if others > 0 => {
+                return Err(Ok(CaptaincyValidationError::CaptainTaken));
+            }
+            Captaincy::Alternate if others >= Captaincy::MAX_ALTERNATES => {
+                return Err(Ok(CaptaincyValidationError::TooManyAlternates));
+            }
+            _ => {}
+        }
+    }
+
+    player_contracts::update_captaincy(db, contract.id, captaincy)
+        .await
+        .map(|_| ())
+        .map_err(Err)
+}
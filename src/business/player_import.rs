@@ -0,0 +1,120 @@
+use sqlx::SqlitePool;
+
+use crate::business::csv_rows::{read_csv_rows, CsvRowsError};
+use crate::service::player_import::{self, PlayerCsvRow, PlayerImportReport};
+
+/// Most player rows accepted in one file
+pub const PLAYER_IMPORT_MAX_ROWS: usize = 1000;
+
+/// Business logic validation errors for player CSV imports
+#[derive(Debug, Clone)]
+pub enum PlayerImportError {
+    /// File is not readable as CSV
+    InvalidFile,
+    /// Header row has no name column
+    MissingNameColumn,
+    /// File has no player rows
+    Empty,
+    /// File has more rows than allowed
+    TooManyRows,
+}
+
+impl PlayerImportError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            PlayerImportError::InvalidFile => "The file is not a valid CSV file",
+            PlayerImportError::MissingNameColumn => {
+                "The first row must name the columns, including a name column"
+            }
+            PlayerImportError::Empty => "The file does not contain any players",
+            PlayerImportError::TooManyRows => "The file cannot contain more than 1000 players",
+        }
+    }
+}
+
+/// Column of the player file a header names
+fn column_of(header: &str) -> Option<&'static str> {
+    match header {
+        "name" | "player" | "player name" => Some("name"),
+        "country" | "country code" | "country_code" | "nationality" => Some("country"),
+        "birth date" | "birth_date" | "birthdate" | "date of birth" | "born" => Some("birth_date"),
+        "position" | "pos" => Some("position"),
+        _ => None,
+    }
+}
+
+/// Parse a player CSV without touching the database
+///
+/// The first row names the columns: a name column is required, country,
+/// birth date and position columns are optional and other columns are ignored.
+pub fn parse_player_csv(data: &[u8]) -> Result<Vec<PlayerCsvRow>, PlayerImportError> {
+    let rows =
+        read_csv_rows(data, column_of, &["name"], PLAYER_IMPORT_MAX_ROWS).map_err(|error| {
+            match error {
+                CsvRowsError::InvalidFile => PlayerImportError::InvalidFile,
+                CsvRowsError::MissingColumn => PlayerImportError::MissingNameColumn,
+                CsvRowsError::Empty => PlayerImportError::Empty,
+                CsvRowsError::TooManyRows => PlayerImportError::TooManyRows,
+            }
+        })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| PlayerCsvRow {
+            line: row.line,
+            name: row.get("name"),
+            country: row.get("country"),
+            birth_date: row.get("birth_date"),
+            position: row.get("position"),
+        })
+        .collect())
+}
+
+/// Imports a player CSV with validation
+///
+/// Problems with single rows do not fail the import; they are listed in the
+/// report next to the rows that were created.
+///
+/// # Returns
+/// * `Ok(PlayerImportReport)` - What happened to every row
+/// * `Err(Ok(PlayerImportError))` - If the file cannot be read
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn import_players_validated(
+    db: &SqlitePool,
+    data: &[u8],
+) -> Result<PlayerImportReport, Result<PlayerImportError, sqlx::Error>> {
+    let rows = parse_player_csv(data).map_err(Ok)?;
+
+    player_import::import_players(db, rows).await.map_err(Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_player_csv() {
+        let rows = parse_player_csv(
+            b"Name,Nationality,Date of birth,Pos,Club\nJaromir Jagr,CZE,15.2.1972,RW,Kladno\nPatrik Elias,CZE\n",
+        )
+        .unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(
+            rows[0],
+            PlayerCsvRow {
+                line: 2,
+                name: "Jaromir Jagr".to_string(),
+                country: "CZE".to_string(),
+                birth_date: "15.2.1972".to_string(),
+                position: "RW".to_string(),
+            }
+        );
+        assert_eq!((rows[1].line, rows[1].birth_date.as_str()), (3, ""));
+
+        assert!(matches!(
+            parse_player_csv(b"country;position\nCZE;C\n"),
+            Err(PlayerImportError::MissingNameColumn)
+        ));
+    }
+}
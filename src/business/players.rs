@@ -1,12 +1,19 @@
 use sqlx::SqlitePool;
 
+use crate::business::slugs;
 use crate::common::pagination::{PagedResult, SortOrder};
 use crate::routes::players::forms::PlayerFormData;
+use crate::service::awards::{self, PlayerAwardEntity};
+use crate::service::milestones::{self, MilestoneEntity};
 use crate::service::players::{
     self, CreatePlayerEntity, PlayerDetailEntity, PlayerEntity, PlayerEventStatsEntity,
     PlayerScoringEventEntity, PlayerScoringFilters, PlayerSeasonStats, PropertyChangeEntity,
     ScoringEventSortField, UpdatePlayerEntity,
 };
+use crate::service::season_stats::{self, PlayerSeasonPercentiles, SeasonStatsCache};
+use crate::service::slugs::SlugEntity;
+use crate::service::streaks::{self, PlayerStreaksEntity};
+use crate::service::transfers::{self, TransferEntity};
 use crate::validation::{validate_height_cm, validate_name, validate_weight_kg};
 
 /// Bundled player detail page data
@@ -23,6 +30,14 @@ pub struct PlayerDetailPageData {
     pub event_stats: Vec<PlayerEventStatsEntity>,
     /// Property changes (career timeline)
     pub property_changes: Vec<PropertyChangeEntity>,
+    /// Mid-season moves between teams, most recent first
+    pub transfers: Vec<TransferEntity>,
+    /// Season awards, most recent season first
+    pub awards: Vec<PlayerAwardEntity>,
+    /// Hat tricks, big games and career goal milestones
+    pub milestones: Vec<MilestoneEntity>,
+    /// Current and longest point and goal streaks
+    pub streaks: PlayerStreaksEntity,
 }
 
 /// Fetches all data needed for the player detail page
@@ -85,14 +100,85 @@ pub async fn get_player_detail_page_data(
         }
     };
 
+    // Fetch transfers (return empty vec on error to maintain partial functionality)
+    let transfers = match transfers::get_player_transfers(db, player_id).await {
+        Ok(transfers) => transfers,
+        Err(e) => {
+            tracing::warn!("Failed to load transfers for player {}: {}", player_id, e);
+            Vec::new()
+        }
+    };
+
+    // Fetch awards (return empty vec on error to maintain partial functionality)
+    let awards = match awards::get_player_awards(db, player_id).await {
+        Ok(awards) => awards,
+        Err(e) => {
+            tracing::warn!("Failed to load awards for player {}: {}", player_id, e);
+            Vec::new()
+        }
+    };
+
+    // Fetch milestones (return empty vec on error to maintain partial functionality)
+    let milestones = match milestones::get_player_milestones(db, player_id).await {
+        Ok(milestones) => milestones,
+        Err(e) => {
+            tracing::warn!("Failed to load milestones for player {}: {}", player_id, e);
+            Vec::new()
+        }
+    };
+
+    // Fetch streaks (no streaks on error to maintain partial functionality)
+    let streaks = match streaks::get_player_streaks(db, player_id).await {
+        Ok(streaks) => streaks,
+        Err(e) => {
+            tracing::warn!("Failed to load streaks for player {}: {}", player_id, e);
+            PlayerStreaksEntity::default()
+        }
+    };
+
     Ok(Some(PlayerDetailPageData {
         detail,
         season_stats,
         event_stats,
         property_changes,
+        transfers,
+        awards,
+        milestones,
+        streaks,
     }))
 }
 
+/// Seasons, most recent first, for which percentile rankings are shown
+const PERCENTILE_SEASONS: usize = 3;
+
+/// Ranks a player within their most recent seasons against the cached season stats
+///
+/// Seasons that fail to load are skipped, so the page still renders.
+pub async fn get_player_season_percentiles(
+    db: &SqlitePool,
+    cache: &SeasonStatsCache,
+    player_id: i64,
+    season_stats: &[PlayerSeasonStats],
+) -> Vec<PlayerSeasonPercentiles> {
+    let mut result = Vec::new();
+    for season in season_stats.iter().take(PERCENTILE_SEASONS) {
+        let stats = match cache.get(db, season.season_id).await {
+            Ok(stats) => stats,
+            Err(e) => {
+                tracing::warn!("Failed to load stats of season {}: {}", season.season_id, e);
+                continue;
+            }
+        };
+        if let Some(percentiles) = season_stats::player_percentiles(&stats, player_id) {
+            result.push(PlayerSeasonPercentiles {
+                season: season.clone(),
+                percentiles,
+            });
+        }
+    }
+    result
+}
+
 /// Bundled player scoring page data
 ///
 /// This struct encapsulates all the data needed to render a player scoring page,
@@ -202,6 +288,8 @@ pub enum PlayerValidationError {
     InvalidHeight(&'static str),
     /// Player weight validation failed
     InvalidWeight(&'static str),
+    /// Slug is malformed or already used by another player
+    InvalidSlug(&'static str),
 }
 
 impl PlayerValidationError {
@@ -212,6 +300,7 @@ impl PlayerValidationError {
             PlayerValidationError::MissingCountryId => "Please select a country",
             PlayerValidationError::InvalidHeight(msg) => msg,
             PlayerValidationError::InvalidWeight(msg) => msg,
+            PlayerValidationError::InvalidSlug(msg) => msg,
         }
     }
 }
@@ -328,6 +417,18 @@ pub async fn update_player_validated(
     let validated_weight =
         validate_weight_kg(form_data.weight_kg).map_err(PlayerValidationError::InvalidWeight)?;
 
+    // Validate slug
+    let slug =
+        match slugs::validate_requested_slug(db, SlugEntity::Player, id, form_data.slug.as_deref())
+            .await
+        {
+            Ok(slug) => slug,
+            Err(Ok(error)) => {
+                return Err(PlayerValidationError::InvalidSlug(error.message()).into())
+            }
+            Err(Err(e)) => return Err(PlayerError::Database(e)),
+        };
+
     // Update player
     players::update_player(
         db,
@@ -342,6 +443,7 @@ pub async fn update_player_validated(
             weight_kg: validated_weight,
             position: form_data.position.clone(),
             shoots: form_data.shoots.clone(),
+            slug,
         },
     )
     .await
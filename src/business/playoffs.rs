@@ -0,0 +1,261 @@
+use sqlx::SqlitePool;
+
+use crate::service::matches;
+use crate::service::placements::{self, SeededTeamEntity};
+use crate::service::playoffs::{self, CreatePlayoffSeriesEntity};
+
+/// Business logic validation errors for playoff series operations
+#[derive(Debug, Clone)]
+pub enum PlayoffValidationError {
+    /// Both sides of the series are the same team
+    SameTeams,
+    /// Series length is not one of 1, 3, 5 or 7
+    InvalidBestOf,
+    /// Round number is lower than 1
+    InvalidRound,
+    /// Teams don't participate in the series' season
+    TeamsNotInSeason,
+    /// Series does not exist
+    SeriesNotFound,
+    /// Series is already decided or all games are scheduled
+    SeriesComplete,
+    /// The season already has playoff series, so no bracket is generated
+    BracketExists,
+    /// Fewer than two teams of the season are seeded
+    NotEnoughSeeds,
+    /// Database error during validation
+    DatabaseError,
+}
+
+impl PlayoffValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            PlayoffValidationError::SameTeams => "Series teams must be different",
+            PlayoffValidationError::InvalidBestOf => "Series must be best of 1, 3, 5 or 7",
+            PlayoffValidationError::InvalidRound => "Round must be 1 or higher",
+            PlayoffValidationError::TeamsNotInSeason => {
+                "Both teams must participate in the selected season"
+            }
+            PlayoffValidationError::SeriesNotFound => "Series not found",
+            PlayoffValidationError::SeriesComplete => "No more games can be added to this series",
+            PlayoffValidationError::BracketExists => {
+                "Remove the existing series before generating the bracket"
+            }
+            PlayoffValidationError::NotEnoughSeeds => {
+                "Seed at least two teams of the season to generate the bracket"
+            }
+            PlayoffValidationError::DatabaseError => "Failed to validate series",
+        }
+    }
+}
+
+/// Creates a new playoff series with validation
+///
+/// # Returns
+/// * `Ok(i64)` - ID of created series
+/// * `Err(Ok(PlayoffValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn create_series_validated(
+    db: &SqlitePool,
+    entity: CreatePlayoffSeriesEntity,
+) -> Result<i64, Result<PlayoffValidationError, sqlx::Error>> {
+    if entity.home_team_id == entity.away_team_id {
+        return Err(Ok(PlayoffValidationError::SameTeams));
+    }
+
+    if ![1, 3, 5, 7].contains(&entity.best_of) {
+        return Err(Ok(PlayoffValidationError::InvalidBestOf));
+    }
+
+    if entity.round < 1 {
+        return Err(Ok(PlayoffValidationError::InvalidRound));
+    }
+
+    match matches::validate_teams_in_season(
+        db,
+        entity.season_id,
+        entity.home_team_id,
+        entity.away_team_id,
+    )
+    .await
+    {
+        Ok(true) => {}
+        Ok(false) => return Err(Ok(PlayoffValidationError::TeamsNotInSeason)),
+        Err(e) => {
+            tracing::error!("Failed to validate teams in season: {}", e);
+            return Err(Ok(PlayoffValidationError::DatabaseError));
+        }
+    }
+
+    // Seeds not given explicitly come from the teams' season seeding
+    let mut entity = entity;
+    if entity.home_seed.is_none() || entity.away_seed.is_none() {
+        let seeded = placements::get_seeded_teams(db, entity.season_id)
+            .await
+            .map_err(Err)?;
+        let seed_of = |team_id: i64| {
+            seeded
+                .iter()
+                .find(|team| team.team_id == team_id)
+                .map(|team| team.seed)
+        };
+        entity.home_seed = entity.home_seed.or(seed_of(entity.home_team_id));
+        entity.away_seed = entity.away_seed.or(seed_of(entity.away_team_id));
+    }
+
+    playoffs::create_series(db, entity).await.map_err(Err)
+}
+
+/// First-round matchups of a bracket, top seed against bottom seed
+///
+/// The bracket is sized to the next power of two; the top seeds get a bye
+/// for the missing places, e.g. with six teams seeds 1 and 2 skip the first
+/// round and the pairs are 3-6 and 4-5. Teams must be ordered by seed.
+pub fn first_round_pairings(
+    seeded: &[SeededTeamEntity],
+) -> Vec<(&SeededTeamEntity, &SeededTeamEntity)> {
+    let byes = seeded.len().next_power_of_two() - seeded.len();
+    let playing = &seeded[byes..];
+
+    (0..playing.len() / 2)
+        .map(|i| (&playing[i], &playing[playing.len() - 1 - i]))
+        .collect()
+}
+
+/// Generates round 1 of the season's bracket from the team seeds
+///
+/// # Returns
+/// * `Ok(usize)` - Number of created series
+/// * `Err(Ok(PlayoffValidationError))` - If the season has series already or too few seeds
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn generate_bracket_validated(
+    db: &SqlitePool,
+    season_id: i64,
+    best_of: i64,
+) -> Result<usize, Result<PlayoffValidationError, sqlx::Error>> {
+    if ![1, 3, 5, 7].contains(&best_of) {
+        return Err(Ok(PlayoffValidationError::InvalidBestOf));
+    }
+
+    let existing = playoffs::get_series_for_season(db, season_id)
+        .await
+        .map_err(Err)?;
+    if !existing.is_empty() {
+        return Err(Ok(PlayoffValidationError::BracketExists));
+    }
+
+    let seeded = placements::get_seeded_teams(db, season_id)
+        .await
+        .map_err(Err)?;
+    if seeded.len() < 2 {
+        return Err(Ok(PlayoffValidationError::NotEnoughSeeds));
+    }
+
+    let pairings = first_round_pairings(&seeded);
+    for (home, away) in &pairings {
+        playoffs::create_series(
+            db,
+            CreatePlayoffSeriesEntity {
+                season_id,
+                round: 1,
+                home_team_id: home.team_id,
+                away_team_id: away.team_id,
+                home_seed: Some(home.seed),
+                away_seed: Some(away.seed),
+                best_of,
+            },
+        )
+        .await
+        .map_err(Err)?;
+    }
+
+    Ok(pairings.len())
+}
+
+/// Schedules the next game of a series, refusing once the series is decided
+///
+/// # Returns
+/// * `Ok(i64)` - ID of the created match
+/// * `Err(Ok(PlayoffValidationError))` - If the series is missing or complete
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn add_series_game_validated(
+    db: &SqlitePool,
+    series_id: i64,
+    match_date: Option<String>,
+) -> Result<i64, Result<PlayoffValidationError, sqlx::Error>> {
+    let series = match playoffs::get_series_by_id(db, series_id).await {
+        Ok(Some(series)) => series,
+        Ok(None) => return Err(Ok(PlayoffValidationError::SeriesNotFound)),
+        Err(e) => return Err(Err(e)),
+    };
+
+    if !series.can_add_game() {
+        return Err(Ok(PlayoffValidationError::SeriesComplete));
+    }
+
+    playoffs::create_series_game(db, &series, match_date)
+        .await
+        .map_err(Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded(count: i64) -> Vec<SeededTeamEntity> {
+        (1..=count)
+            .map(|seed| SeededTeamEntity {
+                team_id: seed * 10,
+                seed,
+            })
+            .collect()
+    }
+
+    fn seed_pairs(teams: &[SeededTeamEntity]) -> Vec<(i64, i64)> {
+        first_round_pairings(teams)
+            .into_iter()
+            .map(|(home, away)| (home.seed, away.seed))
+            .collect()
+    }
+
+    #[test]
+    fn test_first_round_pairings() {
+        assert_eq!(seed_pairs(&seeded(2)), vec![(1, 2)]);
+        assert_eq!(seed_pairs(&seeded(4)), vec![(1, 4), (2, 3)]);
+        assert_eq!(seed_pairs(&seeded(6)), vec![(3, 6), (4, 5)]);
+        assert_eq!(seed_pairs(&seeded(5)), vec![(4, 5)]);
+        assert_eq!(seed_pairs(&seeded(8)), vec![(1, 8), (2, 7), (3, 6), (4, 5)]);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures(
+            path = "../service/fixtures",
+            scripts("events", "seasons", "teams", "team_participations")
+        )
+    )]
+    async fn test_generate_bracket_validated(pool: SqlitePool) {
+        assert!(matches!(
+            generate_bracket_validated(&pool, 1, 7).await,
+            Err(Ok(PlayoffValidationError::NotEnoughSeeds))
+        ));
+
+        placements::set_seed(&pool, 1, Some(2)).await.unwrap();
+        placements::set_seed(&pool, 2, Some(1)).await.unwrap();
+        assert_eq!(generate_bracket_validated(&pool, 1, 7).await.unwrap(), 1);
+
+        let series = playoffs::get_series_for_season(&pool, 1).await.unwrap();
+        assert_eq!(series.len(), 1);
+        assert_eq!((series[0].home_team_id, series[0].away_team_id), (2, 1));
+        assert_eq!(
+            (series[0].home_seed, series[0].away_seed),
+            (Some(1), Some(2))
+        );
+
+        assert!(matches!(
+            generate_bracket_validated(&pool, 1, 7).await,
+            Err(Ok(PlayoffValidationError::BracketExists))
+        ));
+    }
+}
@@ -0,0 +1,65 @@
+use sqlx::SqlitePool;
+
+use crate::service::referees::{self, OfficialRole};
+
+/// Business logic validation errors for match official assignments
+#[derive(Debug, Clone)]
+pub enum OfficialsValidationError {
+    /// The same official was picked for two slots
+    DuplicateOfficial,
+    /// More officials of one role than a match allows
+    TooManyInRole,
+    /// Selected official is not in the registry
+    UnknownReferee,
+}
+
+impl OfficialsValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            OfficialsValidationError::DuplicateOfficial => {
+                "The same official cannot be assigned twice in one match"
+            }
+            OfficialsValidationError::TooManyInRole => {
+                "A match has at most two referees and two linesmen"
+            }
+            OfficialsValidationError::UnknownReferee => "Selected official does not exist",
+        }
+    }
+}
+
+/// Replaces the officials of a match with validation
+///
+/// # Returns
+/// * `Ok(())` - Officials were saved
+/// * `Err(Ok(OfficialsValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn set_match_officials_validated(
+    db: &SqlitePool,
+    match_id: i64,
+    officials: &[(i64, OfficialRole)],
+) -> Result<(), Result<OfficialsValidationError, sqlx::Error>> {
+    for (i, (referee_id, _)) in officials.iter().enumerate() {
+        if officials[..i].iter().any(|(other, _)| other == referee_id) {
+            return Err(Ok(OfficialsValidationError::DuplicateOfficial));
+        }
+    }
+
+    for role in [OfficialRole::Referee, OfficialRole::Linesman] {
+        if officials.iter().filter(|(_, r)| *r == role).count() > role.max_per_match() {
+            return Err(Ok(OfficialsValidationError::TooManyInRole));
+        }
+    }
+
+    for (referee_id, _) in officials {
+        match referees::get_referee_by_id(db, *referee_id).await {
+            Ok(Some(_)) => {}
+            Ok(None) => return Err(Ok(OfficialsValidationError::UnknownReferee)),
+            Err(e) => return Err(Err(e)),
+        }
+    }
+
+    referees::set_match_officials(db, match_id, officials)
+        .await
+        .map_err(Err)
+}
@@ -0,0 +1,190 @@
+use sqlx::SqlitePool;
+
+use crate::business::users::{
+    hash, is_valid_email, validate_new_password, UserValidationError, MAX_NAME_LENGTH,
+};
+use crate::service::{registration, users};
+
+/// What a valid sign-up led to
+///
+/// Both cases end in an email, so the sign-up form never reveals whether an
+/// address already has an account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Registration {
+    /// New or still unconfirmed account; `token` goes into the verification link
+    Pending { user_id: i64, token: String },
+    /// The address belongs to a confirmed account
+    AlreadyRegistered,
+}
+
+/// Signs a visitor up as an inactive editor awaiting email confirmation
+///
+/// Signing up again before confirming issues a fresh link for the existing
+/// registration, which is how expired links are renewed. The name and password
+/// of the first sign-up are kept: whoever follows the link activates the
+/// account the address was first registered with, never one whose password
+/// someone else swapped in meanwhile.
+///
+/// # Returns
+/// * `Ok(Registration)` - Which email to send
+/// * `Err(Ok(UserValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn register_validated(
+    db: &SqlitePool,
+    email: &str,
+    name: &str,
+    password: &str,
+    confirm_password: &str,
+) -> Result<Registration, Result<UserValidationError, sqlx::Error>> {
+    let email = email.trim();
+    if !is_valid_email(email) {
+        return Err(Ok(UserValidationError::InvalidEmail));
+    }
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(Ok(UserValidationError::InvalidName));
+    }
+    validate_new_password(password, confirm_password).map_err(Ok)?;
+
+    let unverified = registration::get_unverified_user_id(db, email)
+        .await
+        .map_err(Err)?;
+    if unverified.is_none() && users::email_exists(db, email).await.map_err(Err)? {
+        return Ok(Registration::AlreadyRegistered);
+    }
+
+    let user_id = match unverified {
+        Some(user_id) => user_id,
+        None => {
+            let password_hash = hash(password).map_err(Err)?;
+            registration::create_registration(db, email, name, &password_hash)
+                .await
+                .map_err(Err)?
+        }
+    };
+    let token = registration::create_verification_token(db, user_id)
+        .await
+        .map_err(Err)?;
+
+    Ok(Registration::Pending { user_id, token })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::password::verify_password;
+    use crate::service::registration::VerificationOutcome;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_register_validated(pool: SqlitePool) {
+        assert_eq!(
+            register_validated(&pool, "fan@x.cz", "Fan", "secret123", "secret124")
+                .await
+                .unwrap_err()
+                .unwrap(),
+            UserValidationError::PasswordMismatch
+        );
+
+        let Registration::Pending { user_id, token } =
+            register_validated(&pool, " fan@x.cz ", "Fan", "secret123", "secret123")
+                .await
+                .unwrap()
+        else {
+            panic!("expected a pending registration");
+        };
+
+        // Signing up again renews the link of the same account but keeps
+        // the name and password of the first sign-up
+        let Registration::Pending {
+            user_id: again,
+            token: renewed,
+        } = register_validated(&pool, "fan@x.cz", "Mallory", "hijack123", "hijack123")
+            .await
+            .unwrap()
+        else {
+            panic!("expected a pending registration");
+        };
+        assert_eq!(again, user_id);
+        assert_eq!(
+            registration::verify_email(&pool, &token, false)
+                .await
+                .unwrap(),
+            VerificationOutcome::Invalid
+        );
+        assert_eq!(
+            registration::verify_email(&pool, &renewed, false)
+                .await
+                .unwrap(),
+            VerificationOutcome::Activated
+        );
+
+        assert_eq!(
+            register_validated(&pool, "FAN@x.cz", "Fan", "secret123", "secret123")
+                .await
+                .unwrap(),
+            Registration::AlreadyRegistered
+        );
+        let user = users::get_user_by_id(&pool, user_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(user.active);
+        assert_eq!(user.name.as_deref(), Some("Fan"));
+        let password_hash: String =
+            sqlx::query_scalar("SELECT password_hash FROM users WHERE id = ?")
+                .bind(user_id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert!(verify_password("secret123", &password_hash).unwrap());
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_register_cannot_take_over_deactivated_account(pool: SqlitePool) {
+        // A CLI-created admin without a confirmed email, later deactivated
+        let user_id: i64 = sqlx::query_scalar(
+            "INSERT INTO users (email, name, password_hash, role, active) VALUES ('boss@x.cz', 'Boss', 'original', 'admin', 0) RETURNING id",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO email_verification_token (user_id, token_hash, expires_at) VALUES (?, 'old', datetime('now', '-30 days'))",
+        )
+        .bind(user_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        assert_eq!(
+            register_validated(&pool, "boss@x.cz", "Mallory", "secret123", "secret123")
+                .await
+                .unwrap(),
+            Registration::AlreadyRegistered
+        );
+        let password_hash: String =
+            sqlx::query_scalar("SELECT password_hash FROM users WHERE id = ?")
+                .bind(user_id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(password_hash, "original");
+        let user = users::get_user_by_id(&pool, user_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!user.active);
+        assert_eq!(user.name.as_deref(), Some("Boss"));
+
+        assert_eq!(
+            registration::purge_unverified_registrations(&pool)
+                .await
+                .unwrap(),
+            0
+        );
+        assert!(users::get_user_by_id(&pool, user_id)
+            .await
+            .unwrap()
+            .is_some());
+    }
+}
@@ -0,0 +1,202 @@
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+
+use crate::service::matches::GameType;
+use crate::service::reports::{self, ReportDefinition};
+
+/// Longest name of a saved report
+pub const MAX_REPORT_NAME_LENGTH: usize = 100;
+
+/// Business logic validation errors for custom reports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReportValidationError {
+    /// Neither a dimension nor a measure is selected
+    NoColumns,
+    /// A dimension is not available for the report's source
+    UnknownDimension,
+    /// A measure is not available for the report's source
+    UnknownMeasure,
+    /// The sort field is not one of the selected fields
+    InvalidSort,
+    /// The game type filter is not a known game type
+    InvalidGameType,
+    /// A date filter is not a YYYY-MM-DD date
+    InvalidDate,
+    /// The saved report has no name
+    NameRequired,
+    /// The saved report name exceeds `MAX_REPORT_NAME_LENGTH`
+    NameTooLong,
+}
+
+impl ReportValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            ReportValidationError::NoColumns => "Select at least one dimension or measure",
+            ReportValidationError::UnknownDimension => {
+                "A selected dimension is not available for this source"
+            }
+            ReportValidationError::UnknownMeasure => {
+                "A selected measure is not available for this source"
+            }
+            ReportValidationError::InvalidSort => "Sort by one of the selected fields",
+            ReportValidationError::InvalidGameType => "Unknown game type",
+            ReportValidationError::InvalidDate => "Dates must be in YYYY-MM-DD format",
+            ReportValidationError::NameRequired => "Report name is required",
+            ReportValidationError::NameTooLong => "Report name must be at most 100 characters",
+        }
+    }
+}
+
+/// Checks that a report only uses fields whitelisted for its source
+pub fn validate_report_definition(
+    definition: &ReportDefinition,
+) -> Result<(), ReportValidationError> {
+    let source = definition.source;
+
+    if !definition.has_columns() {
+        return Err(ReportValidationError::NoColumns);
+    }
+    if definition
+        .dimensions
+        .iter()
+        .any(|key| source.dimension(key).is_none())
+    {
+        return Err(ReportValidationError::UnknownDimension);
+    }
+    if definition
+        .measures
+        .iter()
+        .any(|key| source.measure(key).is_none())
+    {
+        return Err(ReportValidationError::UnknownMeasure);
+    }
+    if let Some(sort) = &definition.sort {
+        if !definition.dimensions.contains(sort) && !definition.measures.contains(sort) {
+            return Err(ReportValidationError::InvalidSort);
+        }
+    }
+
+    let filters = &definition.filters;
+    if let Some(game_type) = &filters.game_type {
+        if GameType::from_str(game_type).is_none() {
+            return Err(ReportValidationError::InvalidGameType);
+        }
+    }
+    for date in [&filters.date_from, &filters.date_to].into_iter().flatten() {
+        if NaiveDate::parse_from_str(date, "%Y-%m-%d").is_err() {
+            return Err(ReportValidationError::InvalidDate);
+        }
+    }
+
+    Ok(())
+}
+
+/// Runs a report after validating its definition
+///
+/// # Returns
+/// * `Ok(ReportResult)` - Report rows, at most `limit` of them
+/// * `Err(Ok(ReportValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn run_report_validated(
+    db: &SqlitePool,
+    definition: &ReportDefinition,
+    limit: i64,
+) -> Result<reports::ReportResult, Result<ReportValidationError, sqlx::Error>> {
+    validate_report_definition(definition).map_err(Ok)?;
+
+    reports::run_report(db, definition, limit)
+        .await
+        .map_err(Err)
+}
+
+/// Saves a report under a name with validation
+///
+/// # Returns
+/// * `Ok(i64)` - ID of the saved report
+/// * `Err(Ok(ReportValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn save_report_validated(
+    db: &SqlitePool,
+    name: &str,
+    definition: &ReportDefinition,
+) -> Result<i64, Result<ReportValidationError, sqlx::Error>> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(Ok(ReportValidationError::NameRequired));
+    }
+    if name.chars().count() > MAX_REPORT_NAME_LENGTH {
+        return Err(Ok(ReportValidationError::NameTooLong));
+    }
+    validate_report_definition(definition).map_err(Ok)?;
+
+    reports::create_saved_report(db, name, definition)
+        .await
+        .map_err(Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::reports::{ReportFilters, ReportSource};
+
+    fn definition(dimensions: &[&str], measures: &[&str]) -> ReportDefinition {
+        ReportDefinition {
+            source: ReportSource::Goals,
+            dimensions: dimensions.iter().map(|d| d.to_string()).collect(),
+            measures: measures.iter().map(|m| m.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_report_definition() {
+        assert_eq!(
+            validate_report_definition(&definition(&["team"], &["goals"])),
+            Ok(())
+        );
+        assert_eq!(
+            validate_report_definition(&definition(&[], &[])),
+            Err(ReportValidationError::NoColumns)
+        );
+        // Roster fields are not available for goals
+        assert_eq!(
+            validate_report_definition(&definition(&["position"], &["goals"])),
+            Err(ReportValidationError::UnknownDimension)
+        );
+        assert_eq!(
+            validate_report_definition(&definition(&["team"], &["team.name"])),
+            Err(ReportValidationError::UnknownMeasure)
+        );
+
+        let mut sorted = definition(&["team"], &["goals"]);
+        sorted.sort = Some("scorers".to_string());
+        assert_eq!(
+            validate_report_definition(&sorted),
+            Err(ReportValidationError::InvalidSort)
+        );
+
+        let mut filtered = definition(&["team"], &[]);
+        filtered.filters = ReportFilters {
+            date_from: Some("2024-13-01".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            validate_report_definition(&filtered),
+            Err(ReportValidationError::InvalidDate)
+        );
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_save_report_validated(pool: SqlitePool) {
+        let report = definition(&["season"], &["goals"]);
+
+        assert!(matches!(
+            save_report_validated(&pool, "  ", &report).await,
+            Err(Ok(ReportValidationError::NameRequired))
+        ));
+        assert!(save_report_validated(&pool, "Goals per season", &report)
+            .await
+            .is_ok());
+    }
+}
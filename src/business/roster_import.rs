@@ -0,0 +1,182 @@
+use sqlx::SqlitePool;
+
+use crate::business::csv_rows::{read_csv_rows, CsvRowsError};
+use crate::business::roster_rules::RosterRuleViolation;
+use crate::service::player_contracts;
+use crate::service::roster_import::{self, RosterCsvRow, RosterImportPlan, RosterImportSummary};
+use crate::service::roster_rules;
+
+/// Most player rows accepted in one roster file
+pub const ROSTER_IMPORT_MAX_ROWS: usize = 200;
+
+/// Business logic validation errors for roster CSV imports
+#[derive(Debug, Clone)]
+pub enum RosterImportError {
+    /// File is not readable as CSV
+    InvalidFile,
+    /// Header row has no name column
+    MissingNameColumn,
+    /// File has no player rows
+    Empty,
+    /// File has more rows than allowed
+    TooManyRows,
+    /// Team participation does not exist
+    ParticipationNotFound,
+    /// The imported players would exceed the event's roster size
+    RosterRule(RosterRuleViolation),
+}
+
+impl RosterImportError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            RosterImportError::InvalidFile => "The file is not a valid CSV file",
+            RosterImportError::MissingNameColumn => {
+                "The first row must name the columns, including a name column"
+            }
+            RosterImportError::Empty => "The file does not contain any players",
+            RosterImportError::TooManyRows => "The file cannot contain more than 200 players",
+            RosterImportError::ParticipationNotFound => "Team participation not found",
+            RosterImportError::RosterRule(violation) => violation.message(),
+        }
+    }
+}
+
+/// Column of the roster file a header names
+fn column_of(header: &str) -> Option<&'static str> {
+    match header {
+        "name" | "player" | "player name" => Some("name"),
+        "number" | "jersey" | "jersey number" | "jersey_number" | "#" | "no" => Some("number"),
+        "country" | "country code" | "country_code" | "nationality" => Some("country"),
+        _ => None,
+    }
+}
+
+/// Parse a roster CSV without touching the database
+///
+/// The first row names the columns: a name column is required, number and
+/// country columns are optional and other columns are ignored.
+pub fn parse_roster_csv(data: &[u8]) -> Result<Vec<RosterCsvRow>, RosterImportError> {
+    let rows =
+        read_csv_rows(data, column_of, &["name"], ROSTER_IMPORT_MAX_ROWS).map_err(|error| {
+            match error {
+                CsvRowsError::InvalidFile => RosterImportError::InvalidFile,
+                CsvRowsError::MissingColumn => RosterImportError::MissingNameColumn,
+                CsvRowsError::Empty => RosterImportError::Empty,
+                CsvRowsError::TooManyRows => RosterImportError::TooManyRows,
+            }
+        })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| RosterCsvRow {
+            line: row.line,
+            name: row.get("name"),
+            number: row.get("number"),
+            country: row.get("country"),
+        })
+        .collect())
+}
+
+/// Plans a roster import with validation, for the review step
+///
+/// # Returns
+/// * `Ok(RosterImportPlan)` - What importing each row would do
+/// * `Err(Ok(RosterImportError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn plan_roster_import_validated(
+    db: &SqlitePool,
+    team_participation_id: i64,
+    data: &[u8],
+) -> Result<RosterImportPlan, Result<RosterImportError, sqlx::Error>> {
+    let rows = parse_roster_csv(data).map_err(Ok)?;
+
+    if player_contracts::get_team_participation_context(db, team_participation_id)
+        .await
+        .map_err(Err)?
+        .is_none()
+    {
+        return Err(Ok(RosterImportError::ParticipationNotFound));
+    }
+
+    let plan = roster_import::plan_roster_import(db, team_participation_id, rows)
+        .await
+        .map_err(Err)?;
+
+    // The maximum roster size applies to the whole import; the import player
+    // limit is left to the roster page, which reports rosters over it
+    if let Some(max_players) =
+        roster_rules::get_participation_roster_rules(db, team_participation_id)
+            .await
+            .map_err(Err)?
+            .and_then(|rules| rules.max_players)
+    {
+        let current = roster_rules::get_roster_composition(db, team_participation_id)
+            .await
+            .map_err(Err)?;
+        let added = (plan.matched() + plan.created()) as i64;
+        if added > 0 && current.players + added > max_players {
+            return Err(Ok(RosterImportError::RosterRule(
+                RosterRuleViolation::TooManyPlayers,
+            )));
+        }
+    }
+
+    Ok(plan)
+}
+
+/// Imports a roster CSV with validation
+///
+/// The file is planned again, so the import reflects the roster at the time
+/// it is confirmed rather than when it was reviewed.
+///
+/// # Returns
+/// * `Ok(RosterImportSummary)` - Players added and created
+/// * `Err(Ok(RosterImportError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn import_roster_validated(
+    db: &SqlitePool,
+    team_participation_id: i64,
+    data: &[u8],
+) -> Result<RosterImportSummary, Result<RosterImportError, sqlx::Error>> {
+    let plan = plan_roster_import_validated(db, team_participation_id, data).await?;
+
+    roster_import::apply_roster_import(db, team_participation_id, &plan)
+        .await
+        .map_err(Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roster_csv() {
+        let rows = parse_roster_csv(
+            "\u{feff}Jersey;Player;Position\n97;Connor McDavid;C\n\n ; Leon Draisaitl ;C\n"
+                .as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].name, "Connor McDavid");
+        assert_eq!(rows[0].number, "97");
+        assert_eq!(rows[0].country, "");
+        assert_eq!((rows[1].line, rows[1].name.as_str()), (4, "Leon Draisaitl"));
+        assert_eq!(rows[1].number, "");
+
+        assert!(matches!(
+            parse_roster_csv(b"number,position\n97,C\n"),
+            Err(RosterImportError::MissingNameColumn)
+        ));
+        assert!(matches!(
+            parse_roster_csv(b"name,number\n"),
+            Err(RosterImportError::Empty)
+        ));
+
+        let many = format!("name\n{}", "Player\n".repeat(ROSTER_IMPORT_MAX_ROWS + 1));
+        assert!(matches!(
+            parse_roster_csv(many.as_bytes()),
+            Err(RosterImportError::TooManyRows)
+        ));
+    }
+}
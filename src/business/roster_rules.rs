@@ -0,0 +1,186 @@
+use sqlx::SqlitePool;
+
+use crate::service::roster_rules::{self, RosterRule, RosterRulesEntity};
+
+/// A roster rule that adding a player would break
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RosterRuleViolation {
+    /// The roster already has the maximum number of players
+    TooManyPlayers,
+    /// The roster already has the maximum number of import players
+    TooManyImports,
+}
+
+impl RosterRuleViolation {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            RosterRuleViolation::TooManyPlayers => {
+                "The roster already has the maximum number of players allowed by the event"
+            }
+            RosterRuleViolation::TooManyImports => {
+                "The roster already has the maximum number of import players allowed by the event"
+            }
+        }
+    }
+}
+
+/// Validation errors for editing an event's roster rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RosterRulesValidationError {
+    /// A limit is negative
+    NegativeLimit,
+    /// More goalies are required than players allowed
+    GoaliesExceedPlayers,
+}
+
+impl RosterRulesValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            RosterRulesValidationError::NegativeLimit => "Limits cannot be negative",
+            RosterRulesValidationError::GoaliesExceedPlayers => {
+                "The minimum number of goalies cannot exceed the maximum number of players"
+            }
+        }
+    }
+}
+
+/// Checks whether a player can join a roster under its event's rules
+///
+/// Only maximums are enforced; a missing goalie is reported on the roster
+/// page instead, since adding a player never removes one.
+pub async fn check_player_addition(
+    db: &SqlitePool,
+    team_participation_id: i64,
+    player_id: i64,
+) -> Result<Option<RosterRuleViolation>, sqlx::Error> {
+    let Some(rules) =
+        roster_rules::get_participation_roster_rules(db, team_participation_id).await?
+    else {
+        return Ok(None);
+    };
+    if rules.max_players.is_none() && rules.max_imports.is_none() {
+        return Ok(None);
+    }
+
+    let Some(candidate) =
+        roster_rules::get_player_composition(db, team_participation_id, player_id).await?
+    else {
+        return Ok(None);
+    };
+    let current = roster_rules::get_roster_composition(db, team_participation_id).await?;
+
+    let broken = rules
+        .checks(&current.with(&candidate))
+        .into_iter()
+        // Only the rules the new player adds to; a roster already over a limit
+        // still accepts players that don't count towards it
+        .find(|check| {
+            !check.is_met()
+                && match check.rule {
+                    RosterRule::MaxPlayers => true,
+                    RosterRule::MaxImports => candidate.imports > 0,
+                    RosterRule::MinGoalies => false,
+                }
+        });
+
+    Ok(broken.map(|check| match check.rule {
+        RosterRule::MaxImports => RosterRuleViolation::TooManyImports,
+        _ => RosterRuleViolation::TooManyPlayers,
+    }))
+}
+
+/// Updates an event's roster rules with validation
+///
+/// # Returns
+/// * `Ok(bool)` - Whether the event exists
+/// * `Err(Ok(RosterRulesValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn update_roster_rules_validated(
+    db: &SqlitePool,
+    event_id: i64,
+    rules: &RosterRulesEntity,
+) -> Result<bool, Result<RosterRulesValidationError, sqlx::Error>> {
+    if [rules.max_players, rules.min_goalies, rules.max_imports]
+        .into_iter()
+        .flatten()
+        .any(|limit| limit < 0)
+    {
+        return Err(Ok(RosterRulesValidationError::NegativeLimit));
+    }
+
+    if let (Some(max_players), Some(min_goalies)) = (rules.max_players, rules.min_goalies) {
+        if min_goalies > max_players {
+            return Err(Ok(RosterRulesValidationError::GoaliesExceedPlayers));
+        }
+    }
+
+    roster_rules::update_event_roster_rules(db, event_id, rules)
+        .await
+        .map_err(Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures(
+            path = "../service/fixtures",
+            scripts("events", "seasons", "teams", "team_participations")
+        )
+    )]
+    async fn test_check_player_addition(pool: SqlitePool) {
+        sqlx::query(
+            "INSERT INTO player (id, name, country_id) VALUES
+                (1, 'Home Player', 34),
+                (2, 'Import Player', 187),
+                (3, 'Second Import', 153)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO player_contract (team_participation_id, player_id) VALUES (1, 2)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        // No rules, anything goes
+        assert_eq!(check_player_addition(&pool, 1, 3).await.unwrap(), None);
+
+        let rules = RosterRulesEntity {
+            max_players: Some(2),
+            min_goalies: None,
+            max_imports: Some(1),
+        };
+        update_roster_rules_validated(&pool, 1, &rules)
+            .await
+            .unwrap();
+        assert_eq!(check_player_addition(&pool, 1, 1).await.unwrap(), None);
+        assert_eq!(
+            check_player_addition(&pool, 1, 3).await.unwrap(),
+            Some(RosterRuleViolation::TooManyImports)
+        );
+
+        sqlx::query("INSERT INTO player_contract (team_participation_id, player_id) VALUES (1, 1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            check_player_addition(&pool, 1, 3).await.unwrap(),
+            Some(RosterRuleViolation::TooManyPlayers)
+        );
+
+        let invalid = RosterRulesEntity {
+            max_players: Some(1),
+            min_goalies: Some(2),
+            max_imports: None,
+        };
+        assert!(matches!(
+            update_roster_rules_validated(&pool, 1, &invalid).await,
+            Err(Ok(RosterRulesValidationError::GoaliesExceedPlayers))
+        ));
+    }
+}
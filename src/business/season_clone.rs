@@ -0,0 +1,128 @@
+use sqlx::SqlitePool;
+
+use crate::service::season_clone::{self, CloneSeasonEntity};
+use crate::service::seasons;
+
+/// Business logic validation errors for cloning seasons
+#[derive(Debug, Clone)]
+pub enum SeasonCloneValidationError {
+    /// Year is outside the accepted range
+    InvalidYear,
+    /// Display name exceeds the maximum length
+    DisplayNameTooLong,
+    /// Season to clone does not exist
+    SeasonNotFound,
+    /// The event already has a season in that year
+    YearTaken,
+}
+
+impl SeasonCloneValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            SeasonCloneValidationError::InvalidYear => "Year must be between 1900 and 2100",
+            SeasonCloneValidationError::DisplayNameTooLong => {
+                "Display name cannot exceed 255 characters"
+            }
+            SeasonCloneValidationError::SeasonNotFound => "Season not found",
+            SeasonCloneValidationError::YearTaken => "The event already has a season in this year",
+        }
+    }
+}
+
+/// Display name for the clone: the source name with its year replaced
+///
+/// Split years like "2024/25" move as a whole. Names that do not mention the
+/// year cannot be adapted and are left empty, so the clone falls back to the
+/// event name and year.
+pub fn next_display_name(
+    display_name: Option<&str>,
+    from_year: i64,
+    to_year: i64,
+) -> Option<String> {
+    let display_name = display_name?;
+    for separator in ["/", "-", "–"] {
+        let split_year = |year: i64| format!("{}{}{:02}", year, separator, (year + 1) % 100);
+        if display_name.contains(&split_year(from_year)) {
+            return Some(display_name.replace(&split_year(from_year), &split_year(to_year)));
+        }
+    }
+
+    let from_year = from_year.to_string();
+    display_name
+        .contains(&from_year)
+        .then(|| display_name.replace(&from_year, &to_year.to_string()))
+}
+
+/// Clones a season with validation
+///
+/// # Returns
+/// * `Ok(i64)` - ID of the new season
+/// * `Err(Ok(SeasonCloneValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn clone_season_validated(
+    db: &SqlitePool,
+    season_id: i64,
+    year: i64,
+    display_name: Option<&str>,
+    copy_rosters: bool,
+) -> Result<i64, Result<SeasonCloneValidationError, sqlx::Error>> {
+    if !(1900..=2100).contains(&year) {
+        return Err(Ok(SeasonCloneValidationError::InvalidYear));
+    }
+
+    let display_name = display_name.map(str::trim).filter(|n| !n.is_empty());
+    if display_name.is_some_and(|n| n.len() > 255) {
+        return Err(Ok(SeasonCloneValidationError::DisplayNameTooLong));
+    }
+
+    let Some(source) = seasons::get_season_by_id(db, season_id)
+        .await
+        .map_err(Err)?
+    else {
+        return Err(Ok(SeasonCloneValidationError::SeasonNotFound));
+    };
+
+    if season_clone::season_year_exists(db, source.event_id, year)
+        .await
+        .map_err(Err)?
+    {
+        return Err(Ok(SeasonCloneValidationError::YearTaken));
+    }
+
+    season_clone::clone_season(
+        db,
+        season_id,
+        CloneSeasonEntity {
+            year,
+            display_name: display_name.map(str::to_string),
+            copy_rosters,
+        },
+    )
+    .await
+    .map_err(Err)?
+    .ok_or(Ok(SeasonCloneValidationError::SeasonNotFound))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_display_name() {
+        assert_eq!(
+            next_display_name(Some("2024 World Championship"), 2024, 2025),
+            Some("2025 World Championship".to_string())
+        );
+        assert_eq!(
+            next_display_name(Some("Extraliga 2024/25"), 2024, 2025),
+            Some("Extraliga 2025/26".to_string())
+        );
+        assert_eq!(
+            next_display_name(Some("NHL 2099-00"), 2099, 2100),
+            Some("NHL 2100-01".to_string())
+        );
+        assert_eq!(next_display_name(Some("Summer Cup"), 2024, 2025), None);
+        assert_eq!(next_display_name(None, 2024, 2025), None);
+    }
+}
@@ -0,0 +1,50 @@
+use sqlx::SqlitePool;
+
+use crate::service::season_groups;
+use crate::validation::validate_name;
+
+/// Business logic validation errors for season group operations
+#[derive(Debug, Clone)]
+pub enum SeasonGroupValidationError {
+    /// Name is empty or too long
+    InvalidName(&'static str),
+    /// The season already has a group with this name
+    DuplicateName,
+}
+
+impl SeasonGroupValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            SeasonGroupValidationError::InvalidName(message) => message,
+            SeasonGroupValidationError::DuplicateName => {
+                "This season already has a group with that name"
+            }
+        }
+    }
+}
+
+/// Creates a group in a season with validation
+///
+/// # Returns
+/// * `Ok(i64)` - ID of created group
+/// * `Err(Ok(SeasonGroupValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn create_group_validated(
+    db: &SqlitePool,
+    season_id: i64,
+    name: &str,
+) -> Result<i64, Result<SeasonGroupValidationError, sqlx::Error>> {
+    let name = validate_name(name).map_err(|e| Ok(SeasonGroupValidationError::InvalidName(e)))?;
+
+    if season_groups::group_name_exists(db, season_id, &name)
+        .await
+        .map_err(Err)?
+    {
+        return Err(Ok(SeasonGroupValidationError::DuplicateName));
+    }
+
+    season_groups::create_group(db, season_id, &name)
+        .await
+        .map_err(Err)
+}
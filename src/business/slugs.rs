@@ -0,0 +1,49 @@
+use sqlx::SqlitePool;
+
+use crate::service::slugs::{self, SlugEntity};
+use crate::validation::validate_slug;
+
+/// Business logic validation errors for hand-edited slugs
+#[derive(Debug, Clone)]
+pub enum SlugValidationError {
+    /// Slug format validation failed
+    Invalid(&'static str),
+    /// Another record of the same type already uses the slug
+    Taken,
+}
+
+impl SlugValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            SlugValidationError::Invalid(msg) => msg,
+            SlugValidationError::Taken => "This slug is already used by another record",
+        }
+    }
+}
+
+/// Validates the slug entered in an edit form
+///
+/// # Returns
+/// * `Ok(Option<String>)` - Normalized slug, `None` when left empty
+/// * `Err(Ok(SlugValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn validate_requested_slug(
+    db: &SqlitePool,
+    entity: SlugEntity,
+    id: i64,
+    slug: Option<&str>,
+) -> Result<Option<String>, Result<SlugValidationError, sqlx::Error>> {
+    let slug = validate_slug(slug).map_err(|msg| Ok(SlugValidationError::Invalid(msg)))?;
+
+    if let Some(slug) = &slug {
+        if slugs::is_slug_taken(db, entity, slug, Some(id))
+            .await
+            .map_err(Err)?
+        {
+            return Err(Ok(SlugValidationError::Taken));
+        }
+    }
+
+    Ok(slug)
+}
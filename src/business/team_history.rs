@@ -0,0 +1,135 @@
+use std::collections::{BTreeSet, HashMap};
+
+use sqlx::SqlitePool;
+
+use crate::service::team_history::{
+    self, ImportMode, TeamHistoryFile, TeamImportSummary, TEAM_HISTORY_FORMAT, TEAM_HISTORY_VERSION,
+};
+
+/// Business logic validation errors for team history imports
+#[derive(Debug, Clone)]
+pub enum TeamImportError {
+    /// File is not valid JSON or not a team history file
+    InvalidFile,
+    /// File was written by a newer, unknown format version
+    UnsupportedVersion,
+    /// File references a country code this instance does not know
+    UnknownCountry,
+    /// A roster or score event references a player missing from the file
+    UnknownPlayer,
+    /// Team name is empty
+    MissingTeamName,
+}
+
+impl TeamImportError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            TeamImportError::InvalidFile => "The file is not a valid team history export",
+            TeamImportError::UnsupportedVersion => {
+                "The file was exported by a newer version and cannot be imported"
+            }
+            TeamImportError::UnknownCountry => "The file references an unknown country code",
+            TeamImportError::UnknownPlayer => "The file references a player that is not listed",
+            TeamImportError::MissingTeamName => "The file does not contain a team name",
+        }
+    }
+}
+
+/// Parse a team history file without touching the database
+pub fn parse_team_history(data: &[u8]) -> Result<TeamHistoryFile, TeamImportError> {
+    let file: TeamHistoryFile =
+        serde_json::from_slice(data).map_err(|_| TeamImportError::InvalidFile)?;
+
+    if file.format != TEAM_HISTORY_FORMAT {
+        return Err(TeamImportError::InvalidFile);
+    }
+
+    if file.version > TEAM_HISTORY_VERSION {
+        return Err(TeamImportError::UnsupportedVersion);
+    }
+
+    if file.team.name.trim().is_empty() {
+        return Err(TeamImportError::MissingTeamName);
+    }
+
+    let listed: BTreeSet<i64> = file.players.iter().map(|p| p.key).collect();
+    if !file.referenced_player_keys().is_subset(&listed) {
+        return Err(TeamImportError::UnknownPlayer);
+    }
+
+    Ok(file)
+}
+
+/// Imports a team history file with validation
+///
+/// # Returns
+/// * `Ok(TeamImportSummary)` - What was created or matched
+/// * `Err(Ok(TeamImportError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn import_team_history_validated(
+    db: &SqlitePool,
+    data: &[u8],
+    mode: ImportMode,
+) -> Result<TeamImportSummary, Result<TeamImportError, sqlx::Error>> {
+    let file = parse_team_history(data).map_err(Ok)?;
+
+    let mut countries = HashMap::new();
+    for code in file.country_codes() {
+        match team_history::find_country_by_code(db, code).await {
+            Ok(Some(id)) => {
+                countries.insert(code.to_string(), id);
+            }
+            Ok(None) => return Err(Ok(TeamImportError::UnknownCountry)),
+            Err(e) => return Err(Err(e)),
+        }
+    }
+
+    team_history::import_team_history(db, &file, &countries, mode)
+        .await
+        .map_err(Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rejects_invalid_files() {
+        assert!(matches!(
+            parse_team_history(b"not json"),
+            Err(TeamImportError::InvalidFile)
+        ));
+        assert!(matches!(
+            parse_team_history(br#"{"format":"other","version":1,"team":{"name":"X"}}"#),
+            Err(TeamImportError::InvalidFile)
+        ));
+        assert!(matches!(
+            parse_team_history(
+                br#"{"format":"hockey-team-history","version":99,"team":{"name":"X"}}"#
+            ),
+            Err(TeamImportError::UnsupportedVersion)
+        ));
+        assert!(matches!(
+            parse_team_history(
+                br#"{"format":"hockey-team-history","version":1,"team":{"name":"X"},
+                    "participations":[{"season":{"event":"E","year":2024},"roster":[{"player":5}]}]}"#
+            ),
+            Err(TeamImportError::UnknownPlayer)
+        ));
+        assert!(parse_team_history(
+            br#"{"format":"hockey-team-history","version":1,"team":{"name":"X"}}"#
+        )
+        .is_ok());
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_import_rejects_unknown_country(pool: SqlitePool) {
+        let data =
+            br#"{"format":"hockey-team-history","version":1,"team":{"name":"X","country":"ZZZ"}}"#;
+        assert!(matches!(
+            import_team_history_validated(&pool, data, ImportMode::AddMissing).await,
+            Err(Ok(TeamImportError::UnknownCountry))
+        ));
+    }
+}
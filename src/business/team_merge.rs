@@ -0,0 +1,62 @@
+use sqlx::SqlitePool;
+
+use crate::service::{team_merge, teams};
+
+/// Business logic validation errors for merging teams
+#[derive(Debug, Clone)]
+pub enum TeamMergeValidationError {
+    /// A team cannot be merged into itself
+    SameTeam,
+    /// Canonical team does not exist
+    CanonicalNotFound,
+    /// Both teams took part in the same season or played each other
+    Conflicts,
+}
+
+impl TeamMergeValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            TeamMergeValidationError::SameTeam => "A team cannot be merged into itself",
+            TeamMergeValidationError::CanonicalNotFound => "The team to merge into was not found",
+            TeamMergeValidationError::Conflicts => {
+                "The teams share a season or played each other, resolve the conflicts first"
+            }
+        }
+    }
+}
+
+/// Merges the duplicate team into the canonical one after checking for conflicts
+///
+/// # Returns
+/// * `Ok(())` - If the duplicate was merged and deleted
+/// * `Err(Ok(TeamMergeValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn merge_teams_validated(
+    db: &SqlitePool,
+    canonical_id: i64,
+    duplicate_id: i64,
+) -> Result<(), Result<TeamMergeValidationError, sqlx::Error>> {
+    if canonical_id == duplicate_id {
+        return Err(Ok(TeamMergeValidationError::SameTeam));
+    }
+
+    if teams::get_team_by_id(db, canonical_id)
+        .await
+        .map_err(Err)?
+        .is_none()
+    {
+        return Err(Ok(TeamMergeValidationError::CanonicalNotFound));
+    }
+
+    let preview = team_merge::get_merge_preview(db, canonical_id, duplicate_id)
+        .await
+        .map_err(Err)?;
+    if preview.has_conflicts() {
+        return Err(Ok(TeamMergeValidationError::Conflicts));
+    }
+
+    team_merge::merge_teams(db, canonical_id, duplicate_id)
+        .await
+        .map_err(Err)
+}
@@ -0,0 +1,109 @@
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+
+use crate::business::roster_rules::{check_player_addition, RosterRuleViolation};
+use crate::service::transfers;
+
+/// Business logic validation errors for player transfers
+#[derive(Debug, Clone)]
+pub enum TransferValidationError {
+    /// Transfer date is not a valid YYYY-MM-DD date
+    InvalidDate,
+    /// Roster entry to transfer from does not exist
+    ContractNotFound,
+    /// Player has already been transferred away from this roster
+    AlreadyTransferred,
+    /// Destination is not another team of the same season
+    InvalidDestination,
+    /// Transfer date is before the player joined the current team
+    DateBeforeJoining,
+    /// Note exceeds the maximum length
+    NoteTooLong,
+    /// The destination roster is full under its event's roster rules
+    RosterRule(RosterRuleViolation),
+}
+
+impl TransferValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            TransferValidationError::InvalidDate => "Invalid date format. Use YYYY-MM-DD",
+            TransferValidationError::ContractNotFound => "Roster entry not found",
+            TransferValidationError::AlreadyTransferred => {
+                "The player has already been transferred away from this team"
+            }
+            TransferValidationError::InvalidDestination => {
+                "The destination must be another team in the same season"
+            }
+            TransferValidationError::DateBeforeJoining => {
+                "The transfer date cannot be before the player joined the team"
+            }
+            TransferValidationError::NoteTooLong => "Note cannot exceed 500 characters",
+            TransferValidationError::RosterRule(violation) => violation.message(),
+        }
+    }
+}
+
+/// Records a transfer with validation
+///
+/// # Returns
+/// * `Ok(i64)` - ID of the recorded transfer
+/// * `Err(Ok(TransferValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn record_transfer_validated(
+    db: &SqlitePool,
+    player_contract_id: i64,
+    to_participation_id: i64,
+    transfer_date: &str,
+    note: Option<&str>,
+) -> Result<i64, Result<TransferValidationError, sqlx::Error>> {
+    let transfer_date = transfer_date.trim();
+    if NaiveDate::parse_from_str(transfer_date, "%Y-%m-%d").is_err() {
+        return Err(Ok(TransferValidationError::InvalidDate));
+    }
+
+    let note = note.map(str::trim).filter(|n| !n.is_empty());
+    if note.is_some_and(|n| n.len() > 500) {
+        return Err(Ok(TransferValidationError::NoteTooLong));
+    }
+
+    let source = match transfers::get_transfer_source(db, player_contract_id).await {
+        Ok(Some(source)) => source,
+        Ok(None) => return Err(Ok(TransferValidationError::ContractNotFound)),
+        Err(e) => return Err(Err(e)),
+    };
+
+    if source.left_on.is_some() {
+        return Err(Ok(TransferValidationError::AlreadyTransferred));
+    }
+
+    // ISO dates compare correctly as strings
+    if source
+        .joined_on
+        .as_deref()
+        .is_some_and(|joined| transfer_date < joined)
+    {
+        return Err(Ok(TransferValidationError::DateBeforeJoining));
+    }
+
+    let destinations = transfers::get_transfer_destinations(db, source.team_participation_id)
+        .await
+        .map_err(Err)?;
+    if !destinations
+        .iter()
+        .any(|(id, _)| *id == to_participation_id)
+    {
+        return Err(Ok(TransferValidationError::InvalidDestination));
+    }
+
+    if let Some(violation) = check_player_addition(db, to_participation_id, source.player_id)
+        .await
+        .map_err(Err)?
+    {
+        return Err(Ok(TransferValidationError::RosterRule(violation)));
+    }
+
+    transfers::record_transfer(db, &source, to_participation_id, transfer_date, note)
+        .await
+        .map_err(Err)
+}
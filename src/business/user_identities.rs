@@ -0,0 +1,235 @@
+use sqlx::SqlitePool;
+
+use crate::auth::oauth::{ExternalIdentity, OAuthProvider};
+use crate::service::{user_identities, users};
+
+/// Why signing in with or linking an external account failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentityError {
+    /// No user has this account linked or signs in with its verified email
+    NoAccount,
+    /// The user this account belongs to is inactive
+    Inactive,
+    /// The account is linked to a different user
+    LinkedElsewhere,
+    /// The user already has another account of this provider linked
+    ProviderTaken,
+}
+
+/// Finds the user signing in with an external account
+///
+/// Accounts not linked yet are linked to the active user with the same
+/// email, provided the provider has verified it; nobody else can sign in.
+///
+/// # Returns
+/// * `Ok(user_id)` - The user to sign in
+/// * `Err(Ok(IdentityError))` - If the account may not sign in
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn sign_in_with_identity(
+    db: &SqlitePool,
+    provider: OAuthProvider,
+    identity: &ExternalIdentity,
+) -> Result<i64, Result<IdentityError, sqlx::Error>> {
+    let provider_name = provider.as_str();
+    let linked = user_identities::get_linked_user_id(db, provider_name, &identity.subject)
+        .await
+        .map_err(Err)?;
+
+    let user_id = match linked {
+        Some(user_id) => {
+            let active = users::get_user_by_id(db, user_id)
+                .await
+                .map_err(Err)?
+                .is_some_and(|user| user.active);
+            if !active {
+                return Err(Ok(IdentityError::Inactive));
+            }
+            user_id
+        }
+        None => {
+            let email = identity
+                .email
+                .as_deref()
+                .filter(|_| identity.email_verified)
+                .ok_or(Ok(IdentityError::NoAccount))?;
+            let user_id = user_identities::get_active_user_id_by_email(db, email)
+                .await
+                .map_err(Err)?
+                .ok_or(Ok(IdentityError::NoAccount))?;
+            // Never replace an account the user linked on purpose
+            if user_identities::has_provider(db, user_id, provider_name)
+                .await
+                .map_err(Err)?
+            {
+                return Err(Ok(IdentityError::NoAccount));
+            }
+            user_identities::link_identity(
+                db,
+                user_id,
+                provider_name,
+                &identity.subject,
+                Some(email),
+            )
+            .await
+            .map_err(Err)?;
+            user_id
+        }
+    };
+
+    user_identities::record_identity_use(db, provider_name, &identity.subject)
+        .await
+        .map_err(Err)?;
+
+    Ok(user_id)
+}
+
+/// Links an external account to a signed-in user
+///
+/// # Returns
+/// * `Ok(())` - If linked, or already linked to this user
+/// * `Err(Ok(IdentityError))` - If the account or provider is taken
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn link_identity_validated(
+    db: &SqlitePool,
+    user_id: i64,
+    provider: OAuthProvider,
+    identity: &ExternalIdentity,
+) -> Result<(), Result<IdentityError, sqlx::Error>> {
+    let provider_name = provider.as_str();
+    match user_identities::get_linked_user_id(db, provider_name, &identity.subject)
+        .await
+        .map_err(Err)?
+    {
+        Some(linked) if linked == user_id => return Ok(()),
+        Some(_) => return Err(Ok(IdentityError::LinkedElsewhere)),
+        None => {}
+    }
+    if user_identities::has_provider(db, user_id, provider_name)
+        .await
+        .map_err(Err)?
+    {
+        return Err(Ok(IdentityError::ProviderTaken));
+    }
+
+    user_identities::link_identity(
+        db,
+        user_id,
+        provider_name,
+        &identity.subject,
+        identity.email.as_deref(),
+    )
+    .await
+    .map_err(Err)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::users::UserRole;
+
+    fn identity(subject: &str, email: &str, verified: bool) -> ExternalIdentity {
+        ExternalIdentity {
+            subject: subject.to_string(),
+            email: Some(email.to_string()),
+            email_verified: verified,
+            name: None,
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_sign_in_with_identity(pool: SqlitePool) {
+        let id = users::create_user(&pool, "coach@x.cz", "Coach", UserRole::Editor, "hash")
+            .await
+            .unwrap();
+        let google = OAuthProvider::Google;
+
+        // Unverified or unknown emails do not match anyone
+        assert_eq!(
+            sign_in_with_identity(&pool, google, &identity("g1", "coach@x.cz", false))
+                .await
+                .unwrap_err()
+                .unwrap(),
+            IdentityError::NoAccount
+        );
+        assert_eq!(
+            sign_in_with_identity(&pool, google, &identity("g1", "fan@x.cz", true))
+                .await
+                .unwrap_err()
+                .unwrap(),
+            IdentityError::NoAccount
+        );
+
+        // A verified email links the account, which then works on its own
+        assert_eq!(
+            sign_in_with_identity(&pool, google, &identity("g1", "COACH@x.cz", true))
+                .await
+                .unwrap(),
+            id
+        );
+        assert_eq!(
+            sign_in_with_identity(&pool, google, &identity("g1", "new@x.cz", false))
+                .await
+                .unwrap(),
+            id
+        );
+        // A second Google account with the same email is not linked on top
+        assert_eq!(
+            sign_in_with_identity(&pool, google, &identity("g2", "coach@x.cz", true))
+                .await
+                .unwrap_err()
+                .unwrap(),
+            IdentityError::NoAccount
+        );
+
+        users::set_user_active(&pool, id, false).await.unwrap();
+        assert_eq!(
+            sign_in_with_identity(&pool, google, &identity("g1", "coach@x.cz", true))
+                .await
+                .unwrap_err()
+                .unwrap(),
+            IdentityError::Inactive
+        );
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_link_identity_validated(pool: SqlitePool) {
+        let coach = users::create_user(&pool, "coach@x.cz", "Coach", UserRole::Editor, "hash")
+            .await
+            .unwrap();
+        let scout = users::create_user(&pool, "scout@x.cz", "Scout", UserRole::Editor, "hash")
+            .await
+            .unwrap();
+        let github = OAuthProvider::GitHub;
+
+        // Linking does not need a matching email
+        let account = identity("7", "someone@example.com", false);
+        link_identity_validated(&pool, coach, github, &account)
+            .await
+            .unwrap();
+        link_identity_validated(&pool, coach, github, &account)
+            .await
+            .unwrap();
+        assert_eq!(
+            link_identity_validated(&pool, scout, github, &account)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            IdentityError::LinkedElsewhere
+        );
+        assert_eq!(
+            link_identity_validated(&pool, coach, github, &identity("8", "a@b.cz", true))
+                .await
+                .unwrap_err()
+                .unwrap(),
+            IdentityError::ProviderTaken
+        );
+        assert_eq!(
+            sign_in_with_identity(&pool, github, &account)
+                .await
+                .unwrap(),
+            coach
+        );
+    }
+}
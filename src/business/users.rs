@@ -0,0 +1,405 @@
+use sqlx::SqlitePool;
+
+use crate::auth::password::{
+    check_password_strength, hash_password, verify_password, PasswordWeakness,
+};
+use crate::service::users::{self, UserRole};
+
+/// Longest accepted email address
+const MAX_EMAIL_LENGTH: usize = 254;
+
+/// Longest accepted display name
+pub(crate) const MAX_NAME_LENGTH: usize = 100;
+
+/// Shortest password a user may choose
+pub const MIN_PASSWORD_LENGTH: usize = 8;
+
+/// Characters of a generated temporary password
+const TEMPORARY_PASSWORD_LENGTH: usize = 16;
+
+/// Business logic validation errors for user management
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UserValidationError {
+    /// Email is empty, too long or not an address
+    InvalidEmail,
+    /// Another user already signs in with this email
+    DuplicateEmail,
+    /// Name is empty or too long
+    InvalidName,
+    /// Role is not admin or editor
+    InvalidRole,
+    /// User does not exist
+    NotFound,
+    /// Admins cannot demote or deactivate themselves
+    OwnAccount,
+    /// The change would leave no active admin
+    LastAdmin,
+    /// Current password did not match
+    WrongPassword,
+    /// New password is too short
+    PasswordTooShort,
+    /// New password is one of the most common ones
+    PasswordTooCommon,
+    /// New password repeats a few characters or runs like `abcdefgh`
+    PasswordTooSimple,
+    /// New password and its confirmation differ
+    PasswordMismatch,
+}
+
+impl UserValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            UserValidationError::InvalidEmail => "Enter a valid email address",
+            UserValidationError::DuplicateEmail => "A user with this email already exists",
+            UserValidationError::InvalidName => "Name must be 1 to 100 characters",
+            UserValidationError::InvalidRole => "Choose admin or editor",
+            UserValidationError::NotFound => "User not found",
+            UserValidationError::OwnAccount => "You cannot demote or deactivate yourself",
+            UserValidationError::LastAdmin => "At least one active admin must remain",
+            UserValidationError::WrongPassword => "Current password is incorrect",
+            UserValidationError::PasswordTooShort => "Password must be at least 8 characters",
+            UserValidationError::PasswordTooCommon => {
+                "This password is too common. Choose one that is harder to guess"
+            }
+            UserValidationError::PasswordTooSimple => {
+                "Password is too simple. Avoid repeated characters and runs like abcd or 1234"
+            }
+            UserValidationError::PasswordMismatch => "Passwords do not match",
+        }
+    }
+}
+
+/// Whether a string looks like an email address: `local@domain` without spaces
+pub(crate) fn is_valid_email(email: &str) -> bool {
+    if email.is_empty() || email.len() > MAX_EMAIL_LENGTH || email.contains(char::is_whitespace) {
+        return false;
+    }
+    email
+        .split_once('@')
+        .is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.'))
+}
+
+/// Check a password the user chose and its confirmation
+///
+/// Used when signing up, resetting and changing a password.
+pub(crate) fn validate_new_password(
+    password: &str,
+    confirm_password: &str,
+) -> Result<(), UserValidationError> {
+    if password.chars().count() < MIN_PASSWORD_LENGTH {
+        return Err(UserValidationError::PasswordTooShort);
+    }
+    match check_password_strength(password) {
+        Ok(()) => {}
+        Err(PasswordWeakness::Common) => return Err(UserValidationError::PasswordTooCommon),
+        Err(PasswordWeakness::Simple) => return Err(UserValidationError::PasswordTooSimple),
+    }
+    if password != confirm_password {
+        return Err(UserValidationError::PasswordMismatch);
+    }
+
+    Ok(())
+}
+
+/// Random password handed to a new user or after a reset
+fn generate_temporary_password() -> String {
+    let mut password = uuid::Uuid::new_v4().simple().to_string();
+    password.truncate(TEMPORARY_PASSWORD_LENGTH);
+    password
+}
+
+/// Argon2 hash of a password; hashing failures surface as database errors
+pub(crate) fn hash(password: &str) -> Result<String, sqlx::Error> {
+    hash_password(password).map_err(|e| sqlx::Error::Encode(Box::new(e)))
+}
+
+/// Creates a user with a temporary password
+///
+/// The user has to replace the password at the first sign-in.
+///
+/// # Returns
+/// * `Ok((i64, String))` - ID of the created user and the temporary password
+/// * `Err(Ok(UserValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn create_user_validated(
+    db: &SqlitePool,
+    email: &str,
+    name: &str,
+    role: &str,
+) -> Result<(i64, String), Result<UserValidationError, sqlx::Error>> {
+    let email = email.trim();
+    if !is_valid_email(email) {
+        return Err(Ok(UserValidationError::InvalidEmail));
+    }
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(Ok(UserValidationError::InvalidName));
+    }
+    let Some(role) = UserRole::from_str(role) else {
+        return Err(Ok(UserValidationError::InvalidRole));
+    };
+    if users::email_exists(db, email).await.map_err(Err)? {
+        return Err(Ok(UserValidationError::DuplicateEmail));
+    }
+
+    let password = generate_temporary_password();
+    let password_hash = hash(&password).map_err(Err)?;
+    let id = users::create_user(db, email, name, role, &password_hash)
+        .await
+        .map_err(Err)?;
+
+    Ok((id, password))
+}
+
+/// Check that changing `user_id` away from an active admin keeps one behind
+async fn ensure_admin_remains(
+    db: &SqlitePool,
+    actor_id: i64,
+    user_id: i64,
+) -> Result<(), Result<UserValidationError, sqlx::Error>> {
+    if actor_id == user_id {
+        return Err(Ok(UserValidationError::OwnAccount));
+    }
+    let user = users::get_user_by_id(db, user_id)
+        .await
+        .map_err(Err)?
+        .ok_or(Ok(UserValidationError::NotFound))?;
+    if user.is_admin() && user.active && users::count_active_admins(db).await.map_err(Err)? <= 1 {
+        return Err(Ok(UserValidationError::LastAdmin));
+    }
+
+    Ok(())
+}
+
+/// Changes a user's role; admins cannot demote themselves or the last admin
+pub async fn set_user_role_validated(
+    db: &SqlitePool,
+    actor_id: i64,
+    user_id: i64,
+    role: &str,
+) -> Result<(), Result<UserValidationError, sqlx::Error>> {
+    let Some(role) = UserRole::from_str(role) else {
+        return Err(Ok(UserValidationError::InvalidRole));
+    };
+    if role != UserRole::Admin {
+        ensure_admin_remains(db, actor_id, user_id).await?;
+    }
+
+    if users::set_user_role(db, user_id, role).await.map_err(Err)? {
+        Ok(())
+    } else {
+        Err(Ok(UserValidationError::NotFound))
+    }
+}
+
+/// Deactivates or reactivates a user; deactivated users are signed out
+pub async fn set_user_active_validated(
+    db: &SqlitePool,
+    actor_id: i64,
+    user_id: i64,
+    active: bool,
+) -> Result<(), Result<UserValidationError, sqlx::Error>> {
+    if !active {
+        ensure_admin_remains(db, actor_id, user_id).await?;
+    }
+
+    if users::set_user_active(db, user_id, active)
+        .await
+        .map_err(Err)?
+    {
+        Ok(())
+    } else {
+        Err(Ok(UserValidationError::NotFound))
+    }
+}
+
+/// Replaces a user's password with a temporary one and signs them out
+///
+/// # Returns
+/// * `Ok(String)` - The temporary password to hand over
+pub async fn reset_user_password(
+    db: &SqlitePool,
+    user_id: i64,
+) -> Result<String, Result<UserValidationError, sqlx::Error>> {
+    let password = generate_temporary_password();
+    let password_hash = hash(&password).map_err(Err)?;
+
+    if users::reset_user_password(db, user_id, &password_hash)
+        .await
+        .map_err(Err)?
+    {
+        Ok(password)
+    } else {
+        Err(Ok(UserValidationError::NotFound))
+    }
+}
+
+/// Changes the signed-in user's own password after checking the current one
+///
+/// Other sessions of the user are signed out; `session_id` stays signed in.
+pub async fn change_own_password(
+    db: &SqlitePool,
+    user_id: i64,
+    session_id: &str,
+    current_password: &str,
+    new_password: &str,
+    confirm_password: &str,
+) -> Result<(), Result<UserValidationError, sqlx::Error>> {
+    let current_hash = users::get_password_hash(db, user_id)
+        .await
+        .map_err(Err)?
+        .ok_or(Ok(UserValidationError::NotFound))?;
+    if !verify_password(current_password, &current_hash).unwrap_or(false) {
+        return Err(Ok(UserValidationError::WrongPassword));
+    }
+    validate_new_password(new_password, confirm_password).map_err(Ok)?;
+
+    let password_hash = hash(new_password).map_err(Err)?;
+    users::change_password(db, user_id, &password_hash, session_id)
+        .await
+        .map_err(Err)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_email() {
+        assert!(is_valid_email("coach@club.cz"));
+        assert!(!is_valid_email("coach"));
+        assert!(!is_valid_email("@club.cz"));
+        assert!(!is_valid_email("coach@club"));
+        assert!(!is_valid_email("co ach@club.cz"));
+    }
+
+    #[test]
+    fn test_validate_new_password() {
+        assert_eq!(
+            validate_new_password("blue-puck-42", "blue-puck-42"),
+            Ok(())
+        );
+        assert_eq!(
+            validate_new_password("short", "short"),
+            Err(UserValidationError::PasswordTooShort)
+        );
+        assert_eq!(
+            validate_new_password("Password1", "Password1"),
+            Err(UserValidationError::PasswordTooCommon)
+        );
+        assert_eq!(
+            validate_new_password("12341234", "12341234"),
+            Err(UserValidationError::PasswordTooSimple)
+        );
+        assert_eq!(
+            validate_new_password("blue-puck-42", "blue-puck-43"),
+            Err(UserValidationError::PasswordMismatch)
+        );
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_user_management_rules(pool: SqlitePool) {
+        assert_eq!(
+            create_user_validated(&pool, "nope", "Nope", "editor")
+                .await
+                .unwrap_err()
+                .unwrap(),
+            UserValidationError::InvalidEmail
+        );
+        assert_eq!(
+            create_user_validated(&pool, "a@x.cz", "A", "owner")
+                .await
+                .unwrap_err()
+                .unwrap(),
+            UserValidationError::InvalidRole
+        );
+
+        let (admin_id, password) = create_user_validated(&pool, " admin@x.cz ", "Admin", "admin")
+            .await
+            .unwrap();
+        assert_eq!(password.len(), TEMPORARY_PASSWORD_LENGTH);
+        assert_eq!(
+            create_user_validated(&pool, "ADMIN@x.cz", "Other", "editor")
+                .await
+                .unwrap_err()
+                .unwrap(),
+            UserValidationError::DuplicateEmail
+        );
+        let (editor_id, _) = create_user_validated(&pool, "editor@x.cz", "Editor", "editor")
+            .await
+            .unwrap();
+
+        // Admins cannot lock themselves out, nor can the last admin be removed
+        assert_eq!(
+            set_user_active_validated(&pool, admin_id, admin_id, false)
+                .await
+                .unwrap_err()
+                .unwrap(),
+            UserValidationError::OwnAccount
+        );
+        assert_eq!(
+            set_user_role_validated(&pool, editor_id, admin_id, "editor")
+                .await
+                .unwrap_err()
+                .unwrap(),
+            UserValidationError::LastAdmin
+        );
+        set_user_role_validated(&pool, admin_id, editor_id, "admin")
+            .await
+            .unwrap();
+        set_user_role_validated(&pool, editor_id, admin_id, "editor")
+            .await
+            .unwrap();
+        set_user_active_validated(&pool, editor_id, admin_id, false)
+            .await
+            .unwrap();
+        assert!(
+            !users::get_user_by_id(&pool, admin_id)
+                .await
+                .unwrap()
+                .unwrap()
+                .active
+        );
+
+        assert_eq!(
+            change_own_password(
+                &pool,
+                admin_id,
+                "current",
+                "wrong",
+                "new-password",
+                "new-password"
+            )
+            .await
+            .unwrap_err()
+            .unwrap(),
+            UserValidationError::WrongPassword
+        );
+        assert_eq!(
+            change_own_password(&pool, admin_id, "current", &password, "short", "short")
+                .await
+                .unwrap_err()
+                .unwrap(),
+            UserValidationError::PasswordTooShort
+        );
+        change_own_password(
+            &pool,
+            admin_id,
+            "current",
+            &password,
+            "new-password",
+            "new-password",
+        )
+        .await
+        .unwrap();
+        assert!(!users::must_change_password(&pool, admin_id).await.unwrap());
+
+        assert_eq!(
+            reset_user_password(&pool, 999).await.unwrap_err().unwrap(),
+            UserValidationError::NotFound
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use sqlx::SqlitePool;
+
+use crate::service::webhooks::{self, CreateWebhookSubscriptionEntity};
+
+/// Business logic validation errors for webhook subscriptions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebhookValidationError {
+    /// URL is not an absolute HTTP(S) URL
+    InvalidUrl,
+    /// Neither matches, goals nor players are followed
+    NothingFollowed,
+}
+
+impl WebhookValidationError {
+    /// Get user-friendly error message
+    pub fn message(&self) -> &'static str {
+        match self {
+            WebhookValidationError::InvalidUrl => {
+                "Webhook URL must be an http:// or https:// address"
+            }
+            WebhookValidationError::NothingFollowed => {
+                "Choose at least one of matches, goals or players"
+            }
+        }
+    }
+}
+
+/// Whether a URL can receive deliveries: absolute HTTP(S) with a host
+fn is_valid_subscription_url(url: &str) -> bool {
+    reqwest::Url::parse(url).is_ok_and(|url| {
+        matches!(url.scheme(), "http" | "https") && url.host_str().is_some_and(|h| !h.is_empty())
+    })
+}
+
+/// Creates a webhook subscription with validation
+///
+/// # Returns
+/// * `Ok(i64)` - ID of the created subscription
+/// * `Err(Ok(WebhookValidationError))` - If validation fails
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn create_webhook_subscription_validated(
+    db: &SqlitePool,
+    url: &str,
+    on_matches: bool,
+    on_score_events: bool,
+    on_players: bool,
+) -> Result<i64, Result<WebhookValidationError, sqlx::Error>> {
+    let url = url.trim();
+    if !is_valid_subscription_url(url) {
+        return Err(Ok(WebhookValidationError::InvalidUrl));
+    }
+    if !(on_matches || on_score_events || on_players) {
+        return Err(Ok(WebhookValidationError::NothingFollowed));
+    }
+
+    webhooks::create_webhook_subscription(
+        db,
+        &CreateWebhookSubscriptionEntity {
+            url: url.to_string(),
+            on_matches,
+            on_score_events,
+            on_players,
+        },
+    )
+    .await
+    .map_err(Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_valid_subscription_url() {
+        assert!(is_valid_subscription_url("https://example.com/hooks"));
+        assert!(is_valid_subscription_url(
+            "http://sync.internal:8080/hockey"
+        ));
+        assert!(!is_valid_subscription_url("ftp://example.com/hooks"));
+        assert!(!is_valid_subscription_url("example.com/hooks"));
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_create_webhook_subscription_validated(pool: SqlitePool) {
+        let result =
+            create_webhook_subscription_validated(&pool, "not a url", true, true, true).await;
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            WebhookValidationError::InvalidUrl
+        );
+        let result = create_webhook_subscription_validated(
+            &pool,
+            "https://example.com",
+            false,
+            false,
+            false,
+        )
+        .await;
+        assert_eq!(
+            result.unwrap_err().unwrap(),
+            WebhookValidationError::NothingFollowed
+        );
+
+        let id = create_webhook_subscription_validated(
+            &pool,
+            " https://example.com/hooks ",
+            false,
+            true,
+            false,
+        )
+        .await
+        .unwrap();
+        let subscription = webhooks::get_webhook_subscription_by_id(&pool, id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(subscription.url, "https://example.com/hooks");
+        assert!(subscription.on_score_events && !subscription.on_matches);
+    }
+}
@@ -0,0 +1,218 @@
+//! Chat messages for final results and milestones
+//!
+//! Messages are posted in the background so a slow or unreachable chat
+//! service never delays saving a match; failures are only logged. Webhooks
+//! are configured on the settings page (see `service::chat_webhooks`).
+
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use sqlx::SqlitePool;
+
+use crate::i18n::TranslationContext;
+use crate::service::chat_webhooks::{self, ChatNotification, ChatWebhookEntity, ChatWebhookKind};
+use crate::service::matches::{self, Decision, MatchEntity, MatchResult};
+use crate::service::milestones::{self, MilestoneEntity};
+use crate::views::pages::milestones::{milestone_icon, milestone_label};
+
+/// How long a chat service may take to accept a message
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Posts messages to the configured chat webhooks
+#[derive(Clone)]
+pub struct ChatNotifier {
+    http: reqwest::Client,
+}
+
+impl Default for ChatNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChatNotifier {
+    pub fn new() -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self { http }
+    }
+
+    /// Post one message and wait for the chat service to accept it
+    pub async fn send(&self, webhook: &ChatWebhookEntity, text: &str) -> Result<(), String> {
+        let kind = webhook
+            .kind()
+            .ok_or_else(|| format!("unknown webhook kind '{}'", webhook.kind))?;
+        let response = self
+            .http
+            .post(&webhook.url)
+            .json(&payload(kind, text))
+            .send()
+            .await
+            .map_err(|e| e.without_url().to_string())?;
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(format!("chat service answered {}", response.status()))
+        }
+    }
+
+    /// Post a message to every webhook that receives the notification
+    async fn broadcast(&self, db: &SqlitePool, notification: ChatNotification, text: &str) {
+        let webhooks = match chat_webhooks::get_chat_webhooks_for(db, notification).await {
+            Ok(webhooks) => webhooks,
+            Err(e) => {
+                tracing::warn!("Failed to load chat webhooks: {}", e);
+                return;
+            }
+        };
+        for webhook in webhooks {
+            if let Err(e) = self.send(&webhook, text).await {
+                tracing::warn!("Chat webhook '{}' failed: {}", webhook.name, e);
+            }
+        }
+    }
+
+    /// Announce the final result of a match in the background
+    pub fn notify_match_result(&self, db: &SqlitePool, t: &TranslationContext, match_id: i64) {
+        let (notifier, db, t) = (self.clone(), db.clone(), t.clone());
+        tokio::spawn(async move {
+            match matches::get_match_by_id(&db, match_id).await {
+                Ok(Some(game)) => {
+                    let text = result_message(&t, &game);
+                    notifier
+                        .broadcast(&db, ChatNotification::MatchResult, &text)
+                        .await;
+                }
+                Ok(None) => {}
+                Err(e) => tracing::warn!("Failed to load match {} for chat: {}", match_id, e),
+            }
+        });
+    }
+
+    /// Announce newly reached milestones in the background
+    pub fn notify_milestones(&self, db: &SqlitePool, t: &TranslationContext, ids: Vec<i64>) {
+        if ids.is_empty() {
+            return;
+        }
+        let (notifier, db, t) = (self.clone(), db.clone(), t.clone());
+        tokio::spawn(async move {
+            for id in ids {
+                match milestones::get_milestone_by_id(&db, id).await {
+                    Ok(Some(milestone)) => {
+                        let text = milestone_message(&t, &milestone);
+                        notifier
+                            .broadcast(&db, ChatNotification::Milestone, &text)
+                            .await;
+                    }
+                    Ok(None) => {}
+                    Err(e) => tracing::warn!("Failed to load milestone {} for chat: {}", id, e),
+                }
+            }
+        });
+    }
+}
+
+/// Request body of a webhook message
+///
+/// Discord mentions are disabled so names cannot ping `@everyone`.
+pub fn payload(kind: ChatWebhookKind, text: &str) -> Value {
+    match kind {
+        ChatWebhookKind::Slack => json!({ "text": text }),
+        ChatWebhookKind::Discord => json!({
+            "content": text,
+            "allowed_mentions": { "parse": [] },
+        }),
+    }
+}
+
+/// e.g. "🏒 Final: Canada 3:2 Latvia OT – 2025 World Championship"
+pub fn result_message(t: &TranslationContext, game: &MatchEntity) -> String {
+    let decision = game
+        .result
+        .as_deref()
+        .and_then(MatchResult::from_str)
+        .and_then(|result| result.decision());
+    let suffix = match decision {
+        Some(Decision::Overtime) => " OT",
+        Some(Decision::Shootout) => " SO",
+        _ => "",
+    };
+    let mut text = format!(
+        "🏒 {}: {} {}:{} {}{}",
+        t.messages.chat_final_result(),
+        game.home_team_name,
+        game.home_score_total(),
+        game.away_score_total(),
+        game.away_team_name,
+        suffix
+    );
+    if let Some(season) = game.season_name.as_ref().or(game.event_name.as_ref()) {
+        text.push_str(&format!(" – {}", season));
+    }
+    text
+}
+
+/// e.g. "🎩 Nico Gerber: Hat trick (3 goals) – Switzerland vs Latvia"
+pub fn milestone_message(t: &TranslationContext, milestone: &MilestoneEntity) -> String {
+    format!(
+        "{} {}: {} – {} vs {}",
+        milestone_icon(milestone.kind()),
+        milestone.player_name,
+        milestone_label(t, milestone),
+        milestone.home_team_name,
+        milestone.away_team_name
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Locale;
+
+    #[test]
+    fn test_payload() {
+        assert_eq!(
+            payload(ChatWebhookKind::Slack, "Final"),
+            json!({ "text": "Final" })
+        );
+        assert_eq!(
+            payload(ChatWebhookKind::Discord, "Final")["content"],
+            "Final"
+        );
+    }
+
+    #[test]
+    fn test_result_message() {
+        let t = TranslationContext::new(Locale::English);
+        let game = MatchEntity {
+            id: 1,
+            season_id: 1,
+            season_name: Some("2025 World Championship".to_string()),
+            event_name: Some("World Championship".to_string()),
+            home_team_id: 1,
+            home_team_name: "Canada".to_string(),
+            home_team_country_iso2: None,
+            away_team_id: 2,
+            away_team_name: "Latvia".to_string(),
+            away_team_country_iso2: None,
+            home_score_unidentified: 1,
+            away_score_unidentified: 0,
+            home_score_identified: 2,
+            away_score_identified: 2,
+            match_date: None,
+            status: "finished".to_string(),
+            arena_id: None,
+            arena_name: None,
+            arena_city: None,
+            attendance: None,
+            game_type: "group".to_string(),
+            result: Some("home_overtime".to_string()),
+        };
+        assert_eq!(
+            result_message(&t, &game),
+            "🏒 Final: Canada 3:2 Latvia OT – 2025 World Championship"
+        );
+    }
+}
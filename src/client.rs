@@ -0,0 +1,440 @@
+//! Typed HTTP client for the JSON API
+//!
+//! Enabled with the `client` feature. Request and response types are the
+//! ones the server serializes, so the two cannot drift apart. The client
+//! either signs in with a regular user account and keeps the session cookie
+//! for later requests, or sends an API key created on the profile page with
+//! every request.
+//!
+//! ```rust,ignore
+//! use hockey::client::HockeyClient;
+//! use hockey::service::countries::CountryFilters;
+//!
+//! let client = HockeyClient::new("https://hockey.example.com")?;
+//! client.login("admin@example.com", "secret").await?;
+//! let countries = client
+//!     .countries(&CountryFilters {
+//!         iihf_only: true,
+//!         ..Default::default()
+//!     })
+//!     .await?;
+//! ```
+//!
+//! With an API key no sign-in is needed:
+//!
+//! ```rust,ignore
+//! let client = HockeyClient::with_api_key("https://hockey.example.com", "hk_...")?;
+//! let leaders = client.season_leaders(3, Some(5)).await?;
+//! ```
+
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{redirect, Response, StatusCode};
+use serde::de::DeserializeOwned;
+
+use crate::auth::api_keys::API_KEY_HEADER;
+use crate::business::match_import::MatchImportRecord;
+use crate::routes::match_import::{MatchImportCreatedEntity, MatchImportRejectedEntity};
+use crate::routes::matches::ScoreEventsFeedEntity;
+use crate::service::countries::{CountryEntity, CountryFilters, CountryToggleEntity};
+use crate::service::leaders::SeasonLeadersEntity;
+use crate::service::live_scores::ScoreboardMatch;
+use crate::service::matches::{MatchFilters, ScoreEventEntity};
+use crate::service::period_distribution::PeriodDistributionEntity;
+use crate::service::players::PlayerFilters;
+use crate::service::team_stats::TeamSeasonStatsEntity;
+use crate::service::teams::TeamFilters;
+
+/// Errors returned by [`HockeyClient`]
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    /// The request could not be sent or the response body not decoded
+    #[error("request failed: {0}")]
+    Http(#[from] reqwest::Error),
+    /// The base URL cannot be joined with an endpoint path
+    #[error("invalid URL: {0}")]
+    InvalidUrl(String),
+    /// Sign-in was rejected
+    #[error("invalid email or password")]
+    InvalidCredentials,
+    /// The endpoint requires a signed-in session or a valid API key
+    #[error("not signed in")]
+    Unauthorized,
+    /// The API key's scopes do not cover the endpoint
+    #[error("not allowed")]
+    Forbidden,
+    /// The server answered with an unexpected status
+    #[error("unexpected status {status}: {body}")]
+    Status { status: StatusCode, body: String },
+    /// A GraphQL query was answered with errors
+    #[error("GraphQL errors: {}", .0.join("; "))]
+    GraphQl(Vec<String>),
+}
+
+/// Body of a GraphQL response; `data` is decoded by the caller
+#[derive(Debug, serde::Deserialize)]
+struct GraphQlResponse {
+    #[serde(default)]
+    data: serde_json::Value,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct GraphQlError {
+    message: String,
+}
+
+/// Client for one server, sharing a cookie jar across requests
+#[derive(Debug, Clone)]
+pub struct HockeyClient {
+    base_url: reqwest::Url,
+    http: reqwest::Client,
+}
+
+impl HockeyClient {
+    /// Create a client for the server at `base_url` (e.g. `http://localhost:8080`)
+    pub fn new(base_url: &str) -> Result<Self, ClientError> {
+        Self::build(base_url, HeaderMap::new())
+    }
+
+    /// Create a client that authenticates every request with an API key
+    pub fn with_api_key(base_url: &str, api_key: &str) -> Result<Self, ClientError> {
+        let mut value =
+            HeaderValue::from_str(api_key).map_err(|_| ClientError::InvalidCredentials)?;
+        value.set_sensitive(true);
+        let mut headers = HeaderMap::new();
+        headers.insert(API_KEY_HEADER, value);
+        Self::build(base_url, headers)
+    }
+
+    fn build(base_url: &str, headers: HeaderMap) -> Result<Self, ClientError> {
+        let base_url =
+            reqwest::Url::parse(base_url).map_err(|e| ClientError::InvalidUrl(e.to_string()))?;
+        // Redirects are not followed: the server answers unauthenticated
+        // requests and successful sign-ins with a redirect
+        let http = reqwest::Client::builder()
+            .cookie_store(true)
+            .default_headers(headers)
+            .redirect(redirect::Policy::none())
+            .build()?;
+
+        Ok(Self { base_url, http })
+    }
+
+    fn url(&self, path: &str) -> Result<reqwest::Url, ClientError> {
+        self.base_url
+            .join(path)
+            .map_err(|e| ClientError::InvalidUrl(e.to_string()))
+    }
+
+    /// Sign in; the session cookie is used by every later request
+    pub async fn login(&self, email: &str, password: &str) -> Result<(), ClientError> {
+        let response = self
+            .http
+            .post(self.url("/auth/login")?)
+            .form(&[("email", email), ("password", password)])
+            .send()
+            .await?;
+
+        // A successful sign-in redirects to the dashboard, a failed one
+        // renders the login form again
+        if response.status().is_redirection() {
+            Ok(())
+        } else if response.status().is_success() {
+            Err(ClientError::InvalidCredentials)
+        } else {
+            Err(status_error(response).await)
+        }
+    }
+
+    /// GET /health - Whether the server is up
+    pub async fn health(&self) -> Result<bool, ClientError> {
+        self.probe("/health").await
+    }
+
+    /// GET /readiness - Whether the server can reach its database
+    pub async fn readiness(&self) -> Result<bool, ClientError> {
+        self.probe("/readiness").await
+    }
+
+    /// GET /public/api/live - Matches in progress, no sign-in needed
+    pub async fn live_matches(&self) -> Result<Vec<ScoreboardMatch>, ClientError> {
+        let response = self.http.get(self.url("/public/api/live")?).send().await?;
+        json(response).await
+    }
+
+    /// GET /public/api/matches/today - Matches of the server's current day, no sign-in needed
+    pub async fn today_matches(&self) -> Result<Vec<ScoreboardMatch>, ClientError> {
+        let response = self
+            .http
+            .get(self.url("/public/api/matches/today")?)
+            .send()
+            .await?;
+        json(response).await
+    }
+
+    /// POST /graphql - Run a query and decode its `data` into `T`
+    ///
+    /// Errors reported by the server fail as [`ClientError::GraphQl`].
+    pub async fn graphql<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<T, ClientError> {
+        let response = self
+            .http
+            .post(self.url("/graphql")?)
+            .json(&serde_json::json!({ "query": query, "variables": variables }))
+            .send()
+            .await?;
+
+        let body: GraphQlResponse = json(response).await?;
+        if !body.errors.is_empty() {
+            return Err(ClientError::GraphQl(
+                body.errors.into_iter().map(|e| e.message).collect(),
+            ));
+        }
+        serde_json::from_value(body.data)
+            .map_err(|e| ClientError::GraphQl(vec![format!("unexpected data: {}", e)]))
+    }
+
+    /// GET /api/countries - Countries matching the filters
+    pub async fn countries(
+        &self,
+        filters: &CountryFilters,
+    ) -> Result<Vec<CountryEntity>, ClientError> {
+        let response = self
+            .http
+            .get(self.url("/api/countries")?)
+            .query(filters)
+            .send()
+            .await?;
+
+        json(response).await
+    }
+
+    /// POST /api/countries/{id}/toggle - Enable or disable a country
+    ///
+    /// Returns `None` when the country does not exist.
+    pub async fn toggle_country(
+        &self,
+        id: i64,
+    ) -> Result<Option<CountryToggleEntity>, ClientError> {
+        let response = self
+            .http
+            .post(self.url(&format!("/api/countries/{}/toggle", id))?)
+            .send()
+            .await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        json(response).await.map(Some)
+    }
+
+    /// GET /api/teams/{id}/stats - Totals of a team in each season it played
+    ///
+    /// Returns `None` when the team does not exist.
+    pub async fn team_stats(
+        &self,
+        id: i64,
+    ) -> Result<Option<Vec<TeamSeasonStatsEntity>>, ClientError> {
+        self.get_optional(&format!("/api/teams/{}/stats", id), &[])
+            .await
+    }
+
+    /// GET /api/seasons/{id}/leaders - Goal, assist and point leaders of a season
+    ///
+    /// `limit` players per category, the server's default when `None`.
+    /// Returns `None` when the season does not exist.
+    pub async fn season_leaders(
+        &self,
+        id: i64,
+        limit: Option<usize>,
+    ) -> Result<Option<SeasonLeadersEntity>, ClientError> {
+        let query: Vec<(&str, String)> = limit
+            .map(|limit| ("limit", limit.to_string()))
+            .into_iter()
+            .collect();
+        self.get_optional(&format!("/api/seasons/{}/leaders", id), &query)
+            .await
+    }
+
+    /// GET /api/seasons/{id}/period-distribution - Goals per period of a season
+    ///
+    /// Returns `None` when the season does not exist.
+    pub async fn season_period_distribution(
+        &self,
+        id: i64,
+    ) -> Result<Option<PeriodDistributionEntity>, ClientError> {
+        self.get_optional(&format!("/api/seasons/{}/period-distribution", id), &[])
+            .await
+    }
+
+    /// GET /api/matches/{id}/score-events - Goals of a match
+    ///
+    /// Returns `None` when the match does not exist.
+    pub async fn match_score_events(
+        &self,
+        id: i64,
+    ) -> Result<Option<Vec<ScoreEventEntity>>, ClientError> {
+        self.get_optional(&format!("/api/matches/{}/score-events", id), &[])
+            .await
+    }
+
+    /// GET /api/score-events - A page of goals in insertion order
+    ///
+    /// Pass the `next_cursor` of a page as `after` to read the next one;
+    /// `match_id` limits the feed to one match.
+    pub async fn score_events(
+        &self,
+        match_id: Option<i64>,
+        after: Option<&str>,
+        limit: Option<usize>,
+    ) -> Result<ScoreEventsFeedEntity, ClientError> {
+        let mut query = Vec::new();
+        if let Some(match_id) = match_id {
+            query.push(("match_id", match_id.to_string()));
+        }
+        if let Some(after) = after {
+            query.push(("after", after.to_string()));
+        }
+        if let Some(limit) = limit {
+            query.push(("limit", limit.to_string()));
+        }
+        let response = self
+            .http
+            .get(self.url("/api/score-events")?)
+            .query(&query)
+            .send()
+            .await?;
+
+        json(response).await
+    }
+
+    /// POST /api/matches/import - Create finished matches, all or none
+    ///
+    /// Returns the rejected rows as `Err` when nothing was created.
+    pub async fn import_matches(
+        &self,
+        records: &[MatchImportRecord],
+    ) -> Result<Result<MatchImportCreatedEntity, MatchImportRejectedEntity>, ClientError> {
+        let response = self
+            .http
+            .post(self.url("/api/matches/import")?)
+            .json(records)
+            .send()
+            .await?;
+
+        if response.status() == StatusCode::UNPROCESSABLE_ENTITY {
+            return Ok(Err(response.json().await?));
+        }
+        json(response).await.map(Ok)
+    }
+
+    /// GET /api/teams/export - Teams matching the filters as CSV
+    pub async fn export_teams(&self, filters: &TeamFilters) -> Result<String, ClientError> {
+        self.csv("/api/teams/export", filters).await
+    }
+
+    /// GET /api/players/export - Players matching the filters as CSV
+    pub async fn export_players(&self, filters: &PlayerFilters) -> Result<String, ClientError> {
+        self.csv("/api/players/export", filters).await
+    }
+
+    /// GET /api/matches/export - Matches matching the filters as CSV
+    pub async fn export_matches(&self, filters: &MatchFilters) -> Result<String, ClientError> {
+        self.csv("/api/matches/export", &match_filter_query(filters))
+            .await
+    }
+
+    /// GET /api/score-events/export - Goals of the matches matching the filters as CSV
+    pub async fn export_score_events(&self, filters: &MatchFilters) -> Result<String, ClientError> {
+        self.csv("/api/score-events/export", &match_filter_query(filters))
+            .await
+    }
+
+    /// GET a JSON resource, `None` when the server answers 404
+    async fn get_optional<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<Option<T>, ClientError> {
+        let response = self.http.get(self.url(path)?).query(query).send().await?;
+
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        json(response).await.map(Some)
+    }
+
+    /// GET a CSV export; an export the server aborts partway fails as [`ClientError::Http`]
+    async fn csv<Q: serde::Serialize + ?Sized>(
+        &self,
+        path: &str,
+        query: &Q,
+    ) -> Result<String, ClientError> {
+        let response = self.http.get(self.url(path)?).query(query).send().await?;
+
+        if response.status().is_success() {
+            Ok(response.text().await?)
+        } else {
+            Err(status_error(response).await)
+        }
+    }
+
+    async fn probe(&self, path: &str) -> Result<bool, ClientError> {
+        let response = self.http.get(self.url(path)?).send().await?;
+        Ok(response.status().is_success())
+    }
+}
+
+/// Query parameters of the match list for a set of filters
+fn match_filter_query(filters: &MatchFilters) -> Vec<(&'static str, String)> {
+    let mut query = Vec::new();
+    let mut push = |name, value: Option<String>| {
+        if let Some(value) = value {
+            query.push((name, value));
+        }
+    };
+    push("season_id", filters.season_id.map(|id| id.to_string()));
+    push("team_id", filters.team_id.map(|id| id.to_string()));
+    push("status", filters.status.clone());
+    push("date_from", filters.date_from.clone());
+    push("date_to", filters.date_to.clone());
+    push("coverage", filters.coverage.map(|c| c.as_str().to_string()));
+    push("arena_id", filters.arena_id.map(|id| id.to_string()));
+    push(
+        "game_type",
+        filters.game_type.map(|g| g.as_str().to_string()),
+    );
+    push("group_id", filters.group_id.map(|id| id.to_string()));
+    push(
+        "date_unknown",
+        filters.date_unknown.then(|| "true".to_string()),
+    );
+    query
+}
+
+/// Decode a JSON response, mapping failures to [`ClientError`]
+async fn json<T: DeserializeOwned>(response: Response) -> Result<T, ClientError> {
+    if response.status().is_success() {
+        Ok(response.json().await?)
+    } else {
+        Err(status_error(response).await)
+    }
+}
+
+async fn status_error(response: Response) -> ClientError {
+    let status = response.status();
+    if status.is_redirection() || status == StatusCode::UNAUTHORIZED {
+        return ClientError::Unauthorized;
+    }
+    if status == StatusCode::FORBIDDEN {
+        return ClientError::Forbidden;
+    }
+    ClientError::Status {
+        status,
+        body: response.text().await.unwrap_or_default(),
+    }
+}
@@ -0,0 +1,208 @@
+//! Streaming CSV downloads
+//!
+//! Exports write their rows into a [`CsvSink`] while the query result is
+//! still being read, and [`csv_download`] sends them to the client as they
+//! come. Neither side holds the whole export in memory: the sink hands over
+//! a chunk every [`CSV_CHUNK_ROWS`] rows and waits while the client is slower
+//! than the database.
+//!
+//! Cells that a spreadsheet would read as a formula are written with a
+//! leading `'`, see [`guard_formula`].
+
+use std::borrow::Cow;
+use std::future::Future;
+use std::io;
+
+use axum::{
+    body::Body,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use tokio::sync::mpsc;
+
+/// Rows collected before a chunk is sent to the client
+pub const CSV_CHUNK_ROWS: usize = 500;
+
+/// Chunks waiting for the client before the export pauses
+const CSV_BUFFERED_CHUNKS: usize = 4;
+
+/// A chunk of the download, or the error that cut it short
+type CsvChunk = Result<Vec<u8>, io::Error>;
+
+/// Destination of the rows of a streamed CSV export
+pub struct CsvSink {
+    tx: mpsc::Sender<CsvChunk>,
+    writer: csv::Writer<Vec<u8>>,
+    pending: usize,
+}
+
+impl CsvSink {
+    fn new(tx: mpsc::Sender<CsvChunk>) -> Self {
+        Self {
+            tx,
+            writer: csv::Writer::from_writer(Vec::new()),
+            pending: 0,
+        }
+    }
+
+    /// Write one row, sending the collected chunk when it is full
+    ///
+    /// Every cell goes through [`guard_formula`] first. Returns `false` once the client has gone away, so the export can stop
+    /// reading rows nobody will receive.
+    pub async fn write<I, T>(&mut self, record: I) -> bool
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u8]>,
+    {
+        let record = record
+            .into_iter()
+            .map(|field| guard_formula(field.as_ref()).into_owned());
+        if let Err(e) = self.writer.write_record(record) {
+            tracing::error!("Failed to write CSV row: {}", e);
+            return false;
+        }
+        self.pending += 1;
+        if self.pending < CSV_CHUNK_ROWS {
+            return true;
+        }
+        self.flush().await
+    }
+
+    async fn flush(&mut self) -> bool {
+        self.pending = 0;
+        let writer = std::mem::replace(&mut self.writer, csv::Writer::from_writer(Vec::new()));
+        let chunk = match writer.into_inner() {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                tracing::error!("Failed to write CSV chunk: {}", e);
+                return false;
+            }
+        };
+        chunk.is_empty() || self.tx.send(Ok(chunk)).await.is_ok()
+    }
+}
+
+/// Neutralise a cell that a spreadsheet would evaluate as a formula
+///
+/// Values starting with `=`, `+`, `-` or `@` get a leading `'`, so names
+/// and notes typed by users cannot run as formulas when the export is
+/// opened. Plain numbers such as `-2` are left alone.
+fn guard_formula(field: &[u8]) -> Cow<'_, [u8]> {
+    let is_formula = matches!(field.first(), Some(b'=' | b'+' | b'-' | b'@'))
+        && std::str::from_utf8(field).map_or(true, |s| s.parse::<f64>().is_err());
+    if is_formula {
+        let mut guarded = Vec::with_capacity(field.len() + 1);
+        guarded.push(b'\'');
+        guarded.extend_from_slice(field);
+        Cow::Owned(guarded)
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// Respond with a CSV file whose rows `export` writes while it is downloaded
+///
+/// The export runs in its own task. When it fails midway the status line has
+/// been sent already, so the failure is logged and the body ends with an
+/// error: the connection is aborted and the client sees an incomplete
+/// download instead of a file that merely looks shorter.
+pub fn csv_download<F, Fut>(filename: &str, export: F) -> Response
+where
+    F: FnOnce(CsvSink) -> Fut + Send + 'static,
+    Fut: Future<Output = Result<CsvSink, sqlx::Error>> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel(CSV_BUFFERED_CHUNKS);
+    let failed = tx.clone();
+    tokio::spawn(async move {
+        match export(CsvSink::new(tx)).await {
+            Ok(mut sink) => {
+                sink.flush().await;
+            }
+            Err(e) => {
+                tracing::error!("CSV export failed: {}", e);
+                let _ = failed.send(Err(io::Error::other(e))).await;
+            }
+        }
+    });
+
+    let chunks = futures::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|chunk| (chunk, rx))
+    });
+
+    (
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            ),
+        ],
+        Body::from_stream(chunks),
+    )
+        .into_response()
+}
+
+/// Run an export into memory and return the CSV it wrote
+#[cfg(test)]
+pub(crate) async fn collect_csv<F, Fut>(export: F) -> String
+where
+    F: FnOnce(CsvSink) -> Fut,
+    Fut: Future<Output = Result<CsvSink, sqlx::Error>>,
+{
+    let (tx, mut rx) = mpsc::channel::<CsvChunk>(1);
+    let collect = async move {
+        let mut data = Vec::new();
+        while let Some(chunk) = rx.recv().await {
+            data.extend(chunk.unwrap());
+        }
+        data
+    };
+    let export = async move {
+        let mut sink = export(CsvSink::new(tx)).await.unwrap();
+        sink.flush().await;
+    };
+
+    let (data, ()) = tokio::join!(collect, export);
+    String::from_utf8(data).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_formula_cells_are_guarded() {
+        let csv = collect_csv(|mut sink| async move {
+            sink.write([
+                "=1+1", "+SUM(A1)", "-2+3", "@cmd", "-2", "+5", "3.5", "a=b", "",
+            ])
+            .await;
+            Ok(sink)
+        })
+        .await;
+        assert_eq!(csv, "'=1+1,'+SUM(A1),'-2+3,'@cmd,-2,+5,3.5,a=b,\n");
+    }
+
+    #[tokio::test]
+    async fn test_failed_export_aborts_download() {
+        let complete = csv_download("ok.csv", |mut sink| async move {
+            sink.write(["id", "name"]).await;
+            Ok(sink)
+        });
+        let body = axum::body::to_bytes(complete.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], b"id,name\n");
+
+        // Rows already sent are not enough: the body itself must fail
+        let failed = csv_download("failed.csv", |mut sink| async move {
+            for id in 0..CSV_CHUNK_ROWS + 1 {
+                sink.write([id.to_string()]).await;
+            }
+            Err(sqlx::Error::RowNotFound)
+        });
+        assert!(axum::body::to_bytes(failed.into_body(), usize::MAX)
+            .await
+            .is_err());
+    }
+}
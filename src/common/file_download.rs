@@ -0,0 +1,49 @@
+//! Downloads of files on disk, such as database snapshots and backups
+
+use axum::{
+    body::Body,
+    http::{header, HeaderMap},
+    response::{IntoResponse, Response},
+};
+
+/// Read size when streaming a file
+const CHUNK_BYTES: usize = 64 * 1024;
+
+/// Content type of SQLite database files
+pub const SQLITE_CONTENT_TYPE: &str = "application/vnd.sqlite3";
+
+/// Respond with an open file as a download, streamed in chunks
+pub async fn file_download(file: tokio::fs::File, filename: &str, content_type: &str) -> Response {
+    let length = file.metadata().await.map(|m| m.len()).ok();
+
+    let chunks = futures::stream::unfold(file, |mut file| async move {
+        let mut buffer = vec![0; CHUNK_BYTES];
+        match tokio::io::AsyncReadExt::read(&mut file, &mut buffer).await {
+            Ok(0) => None,
+            Ok(read) => {
+                buffer.truncate(read);
+                Some((Ok::<_, std::io::Error>(buffer), file))
+            }
+            Err(e) => Some((Err(e), file)),
+        }
+    });
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CONTENT_TYPE,
+        content_type
+            .parse()
+            .expect("Content type should be a valid header value"),
+    );
+    headers.insert(
+        header::CONTENT_DISPOSITION,
+        format!("attachment; filename=\"{}\"", filename)
+            .parse()
+            .expect("File name should be a valid header value"),
+    );
+    if let Some(length) = length {
+        headers.insert(header::CONTENT_LENGTH, length.into());
+    }
+
+    (headers, Body::from_stream(chunks)).into_response()
+}
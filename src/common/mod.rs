@@ -1 +1,4 @@
+pub mod csv_export;
+pub mod file_download;
 pub mod pagination;
+pub mod xlsx;
@@ -8,6 +8,8 @@ pub struct PagedResult<T> {
     pub total_pages: usize,
     pub has_next: bool,
     pub has_previous: bool,
+    /// Opaque cursor of the next page when the result was read after a cursor
+    pub next_cursor: Option<String>,
 }
 
 impl<T> PagedResult<T> {
@@ -24,16 +26,160 @@ impl<T> PagedResult<T> {
             total_pages,
             has_next,
             has_previous,
+            next_cursor: None,
         }
     }
+
+    /// Set the cursor a client passes back to continue after the last item
+    pub fn with_next_cursor(mut self, cursor: Option<Cursor>) -> Self {
+        self.next_cursor = cursor.map(|cursor| cursor.encode());
+        self.has_next = self.next_cursor.is_some();
+        self
+    }
+}
+
+/// Position in a list, handed to clients as an opaque token
+///
+/// Unlike page numbers, reading after a cursor needs no OFFSET and neither
+/// skips nor repeats rows when new ones are inserted between requests. Lists
+/// ordered by a column carry that column's value of the last row as well, with
+/// the row id breaking ties.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cursor {
+    /// Id of the last row already returned
+    pub after_id: i64,
+    /// Sort key of the last row; `None` when ordered by id alone or the key is NULL
+    pub after_key: Option<String>,
+}
+
+impl Cursor {
+    const PREFIX: &'static str = "v1:";
+
+    pub fn after(id: i64) -> Self {
+        Self {
+            after_id: id,
+            after_key: None,
+        }
+    }
+
+    pub fn after_key(key: Option<String>, id: i64) -> Self {
+        Self {
+            after_id: id,
+            after_key: key,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let token = match &self.after_key {
+            Some(key) => format!("{}{}:{}", Self::PREFIX, self.after_id, key),
+            None => format!("{}{}", Self::PREFIX, self.after_id),
+        };
+        hex::encode(token)
+    }
+
+    /// Parse a token made by [`Cursor::encode`]; `None` when it is malformed
+    pub fn decode(token: &str) -> Option<Self> {
+        let bytes = hex::decode(token).ok()?;
+        let text = std::str::from_utf8(&bytes)
+            .ok()?
+            .strip_prefix(Self::PREFIX)?;
+        let (id, key) = match text.split_once(':') {
+            Some((id, key)) => (id, Some(key.to_string())),
+            None => (text, None),
+        };
+        Some(Self {
+            after_id: id.parse().ok()?,
+            after_key: key,
+        })
+    }
 }
 
 /// Base trait for sortable fields
-#[allow(dead_code)]
-pub trait SortableField {
+pub trait SortableField: Sized {
     fn from_str(s: &str) -> Self;
     fn to_sql(&self) -> &'static str;
     fn as_str(&self) -> &'static str;
+
+    /// Whether the column can be NULL; such rows sort last in either direction
+    fn nulls_last(&self) -> bool {
+        false
+    }
+}
+
+/// Maximum number of tie-breaking sort keys kept in the query string
+pub const MAX_SECONDARY_SORT_KEYS: usize = 2;
+
+/// Parse secondary sort keys from a `field:order,field:order` query string value
+///
+/// Keys matching the primary field or repeating an earlier key are dropped,
+/// so the resulting list can be appended to ORDER BY as-is.
+pub fn parse_sort_keys<F: SortableField>(s: &str, primary: &F) -> Vec<(F, SortOrder)> {
+    let mut keys: Vec<(F, SortOrder)> = Vec::new();
+
+    for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (field, order) = part.split_once(':').unwrap_or((part, "asc"));
+        let field = F::from_str(field);
+
+        let duplicate = field.as_str() == primary.as_str()
+            || keys.iter().any(|(f, _)| f.as_str() == field.as_str());
+        if !duplicate && keys.len() < MAX_SECONDARY_SORT_KEYS {
+            keys.push((field, SortOrder::from_str(order)));
+        }
+    }
+
+    keys
+}
+
+/// Serialize secondary sort keys back into their query string form
+pub fn format_sort_keys<F: SortableField>(keys: &[(F, SortOrder)]) -> String {
+    keys.iter()
+        .map(|(field, order)| format!("{}:{}", field.as_str(), order.as_str()))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Compute the secondary keys after the user sorts by `field`
+///
+/// The previous primary key is demoted to the first tie-breaker, so clicking
+/// "date" and then "status" sorts by status, then date.
+pub fn rerank_sort_keys<F: SortableField + Clone>(
+    field: &F,
+    current_field: &F,
+    current_order: &SortOrder,
+    current_then: &[(F, SortOrder)],
+) -> Vec<(F, SortOrder)> {
+    if field.as_str() == current_field.as_str() {
+        return current_then.to_vec();
+    }
+
+    std::iter::once((current_field.clone(), current_order.clone()))
+        .chain(current_then.iter().cloned())
+        .filter(|(f, _)| f.as_str() != field.as_str())
+        .take(MAX_SECONDARY_SORT_KEYS)
+        .collect()
+}
+
+/// Append `column ORDER` for a sort key, keeping NULLs last for nullable fields
+pub fn push_sort_key<F: SortableField>(
+    query: &mut sqlx::QueryBuilder<'_, sqlx::Sqlite>,
+    field: &F,
+    order: &SortOrder,
+) {
+    query.push(field.to_sql()).push(" ").push(order.to_sql());
+    if field.nulls_last() {
+        query.push(" NULLS LAST");
+    }
+}
+
+/// Append `, column ORDER` for each secondary key to an ORDER BY clause
+pub fn push_secondary_sort<F: SortableField>(
+    query: &mut sqlx::QueryBuilder<'_, sqlx::Sqlite>,
+    keys: &[(F, SortOrder)],
+) {
+    for (field, order) in keys {
+        query.push(", ");
+        push_sort_key(query, field, order);
+    }
 }
 
 /// Sort order (ascending/descending)
@@ -72,3 +218,86 @@ impl SortOrder {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum TestField {
+        Date,
+        Status,
+        Event,
+    }
+
+    impl SortableField for TestField {
+        fn from_str(s: &str) -> Self {
+            match s {
+                "status" => Self::Status,
+                "event" => Self::Event,
+                _ => Self::Date,
+            }
+        }
+
+        fn to_sql(&self) -> &'static str {
+            self.as_str()
+        }
+
+        fn as_str(&self) -> &'static str {
+            match self {
+                Self::Date => "date",
+                Self::Status => "status",
+                Self::Event => "event",
+            }
+        }
+    }
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = Cursor::after(42);
+        assert_eq!(Cursor::decode(&cursor.encode()), Some(cursor));
+        let cursor = Cursor::after_key(Some("2024-02-18 19:30:00".to_string()), 7);
+        assert_eq!(Cursor::decode(&cursor.encode()), Some(cursor));
+        assert_eq!(Cursor::decode("42"), None);
+        assert_eq!(Cursor::decode("not a cursor"), None);
+        assert_eq!(Cursor::decode(&hex::encode("v2:42")), None);
+    }
+
+    #[test]
+    fn test_with_next_cursor() {
+        let page = PagedResult::new(vec![1, 2], 5, 1, 2).with_next_cursor(Some(Cursor::after(2)));
+        assert!(page.has_next);
+        assert_eq!(page.next_cursor, Some(Cursor::after(2).encode()));
+
+        let last = PagedResult::new(vec![5], 5, 3, 2).with_next_cursor(None);
+        assert!(!last.has_next);
+        assert!(last.next_cursor.is_none());
+    }
+
+    #[test]
+    fn test_parse_sort_keys_skips_primary_and_duplicates() {
+        let keys = parse_sort_keys("date:desc,event,status:desc,event:desc", &TestField::Status);
+        assert_eq!(
+            keys,
+            vec![
+                (TestField::Date, SortOrder::Desc),
+                (TestField::Event, SortOrder::Asc)
+            ]
+        );
+        assert_eq!(format_sort_keys(&keys), "date:desc,event:asc");
+    }
+
+    #[test]
+    fn test_rerank_sort_keys_demotes_previous_primary() {
+        let then = rerank_sort_keys(&TestField::Status, &TestField::Date, &SortOrder::Desc, &[]);
+        assert_eq!(then, vec![(TestField::Date, SortOrder::Desc)]);
+
+        // Re-sorting by a secondary key removes it from the tie-breakers
+        let then = rerank_sort_keys(&TestField::Date, &TestField::Status, &SortOrder::Asc, &then);
+        assert_eq!(then, vec![(TestField::Status, SortOrder::Asc)]);
+
+        // Toggling the primary key keeps the tie-breakers untouched
+        let then = rerank_sort_keys(&TestField::Date, &TestField::Date, &SortOrder::Asc, &then);
+        assert_eq!(then, vec![(TestField::Status, SortOrder::Asc)]);
+    }
+}
@@ -0,0 +1,152 @@
+//! Excel workbook downloads
+//!
+//! Reports are handed over as [`XlsxSheet`]s of text and number cells, so
+//! spreadsheet programs open them with proper columns and numbers that can be
+//! summed and sorted, unlike a CSV opened with the wrong locale.
+
+use axum::{
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+/// A cell of an exported sheet
+#[derive(Debug, Clone, PartialEq)]
+pub enum XlsxCell {
+    Text(String),
+    Number(i64),
+    Empty,
+}
+
+impl From<String> for XlsxCell {
+    fn from(text: String) -> Self {
+        XlsxCell::Text(text)
+    }
+}
+
+impl From<&str> for XlsxCell {
+    fn from(text: &str) -> Self {
+        XlsxCell::Text(text.to_string())
+    }
+}
+
+impl From<i64> for XlsxCell {
+    fn from(number: i64) -> Self {
+        XlsxCell::Number(number)
+    }
+}
+
+impl<T: Into<XlsxCell>> From<Option<T>> for XlsxCell {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(XlsxCell::Empty, Into::into)
+    }
+}
+
+/// A worksheet: a bold header row followed by data rows
+#[derive(Debug, Clone, PartialEq)]
+pub struct XlsxSheet {
+    /// Tab name, at most 31 characters
+    pub name: String,
+    pub headers: Vec<String>,
+    pub rows: Vec<Vec<XlsxCell>>,
+}
+
+/// Write sheets into an XLSX file, in order
+///
+/// The header row stays visible while scrolling and columns are sized to
+/// their content.
+pub fn write_workbook(sheets: &[XlsxSheet]) -> Result<Vec<u8>, XlsxError> {
+    let mut workbook = Workbook::new();
+    let bold = Format::new().set_bold();
+
+    for sheet in sheets {
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(&sheet.name)?;
+
+        for (col, title) in sheet.headers.iter().enumerate() {
+            worksheet.write_string_with_format(0, col as u16, title, &bold)?;
+        }
+        for (index, cells) in sheet.rows.iter().enumerate() {
+            let row = index as u32 + 1;
+            for (col, cell) in cells.iter().enumerate() {
+                match cell {
+                    XlsxCell::Text(text) => {
+                        worksheet.write_string(row, col as u16, text)?;
+                    }
+                    XlsxCell::Number(number) => {
+                        worksheet.write_number(row, col as u16, *number as f64)?;
+                    }
+                    XlsxCell::Empty => {}
+                }
+            }
+        }
+
+        worksheet.set_freeze_panes(1, 0)?;
+        worksheet.autofit();
+    }
+
+    workbook.save_to_buffer()
+}
+
+/// Respond with the sheets as an XLSX file download
+pub fn xlsx_download(filename: &str, sheets: &[XlsxSheet]) -> Response {
+    match write_workbook(sheets) {
+        Ok(data) => (
+            [
+                (
+                    header::CONTENT_TYPE,
+                    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet".to_string(),
+                ),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}\"", filename),
+                ),
+            ],
+            data,
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to write XLSX file: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to create the spreadsheet",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_workbook() {
+        let sheets = [
+            XlsxSheet {
+                name: "Standings".to_string(),
+                headers: vec!["Team".to_string(), "Pts".to_string()],
+                rows: vec![
+                    vec!["Team Canada".into(), 6.into()],
+                    vec!["Team USA".into(), XlsxCell::from(None::<i64>)],
+                ],
+            },
+            XlsxSheet {
+                name: "Schedule".to_string(),
+                headers: vec!["Date".to_string()],
+                rows: Vec::new(),
+            },
+        ];
+
+        let data = write_workbook(&sheets).unwrap();
+        // XLSX files are ZIP archives
+        assert!(data.starts_with(b"PK"));
+
+        let invalid = [XlsxSheet {
+            name: "Standings [A]".to_string(),
+            headers: Vec::new(),
+            rows: Vec::new(),
+        }];
+        assert!(write_workbook(&invalid).is_err());
+    }
+}
@@ -1,4 +1,14 @@
 use std::env;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::auth::lockout::{LockoutPolicy, DEFAULT_LOCKOUT_MINUTES, DEFAULT_LOCKOUT_THRESHOLD};
+use crate::auth::oauth::{OAuthClient, OAuthProviders};
+use crate::auth::password::HashingParams;
+use crate::auth::rate_limit::{DEFAULT_API_RATE_BURST, DEFAULT_API_RATE_PER_MINUTE};
+use crate::auth::SessionConfig;
+use crate::mailer::DEFAULT_MAIL_FROM;
+use crate::maintenance::MaintenanceConfig;
+use crate::server::ServerConfig;
 
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -7,6 +17,67 @@ pub struct Config {
     pub environment: Environment,
     pub port: u16,
     pub db_max_connections: u32,
+    /// Minutes of inactivity before a session expires
+    pub session_idle_timeout_minutes: i64,
+    /// Hours after sign-in before a session expires regardless of activity
+    pub session_max_lifetime_hours: i64,
+    /// Notify users when they sign in from an address not seen before
+    pub login_notify_new_ip: bool,
+    /// Wrong passwords in a row that lock an account; 0 disables lockout
+    pub login_lockout_threshold: u32,
+    /// Minutes a locked account refuses password sign-ins
+    pub login_lockout_minutes: i64,
+    /// Argon2id memory per password hash in KiB
+    pub password_hash_memory_kib: u32,
+    /// Argon2id passes over the memory
+    pub password_hash_iterations: u32,
+    /// Argon2id lanes hashed in parallel
+    pub password_hash_parallelism: u32,
+    /// Public demo: offer a shared read-only account on the login page
+    pub demo_mode: bool,
+    /// Email of the shared demo account
+    pub demo_user_email: String,
+    /// Accept HTTP/2 (h2c prior knowledge, or via a TLS-terminating proxy) alongside HTTP/1.1
+    pub http2_enabled: bool,
+    /// Seconds an idle connection is kept open; 0 disables keep-alive
+    pub keep_alive_secs: u64,
+    /// Requests processed at once across all connections; 0 means unlimited
+    pub max_concurrent_requests: usize,
+    /// Streams a single HTTP/2 connection may have open at once
+    pub http2_max_concurrent_streams: u32,
+    /// Hour of the day (UTC, 0-23) the nightly database maintenance runs
+    pub maintenance_hour: u32,
+    /// Directory for nightly `VACUUM INTO` backups; unset disables backups
+    pub backup_dir: Option<String>,
+    /// Nightly backups to keep; 0 keeps all of them
+    pub backup_keep: usize,
+    /// Hours between backups (a divisor of 24), counted from the maintenance hour
+    pub backup_interval_hours: u32,
+    /// Statements taking at least this many milliseconds are logged as slow; 0 disables
+    pub slow_query_ms: u64,
+    /// Let signed-in users pick winners of upcoming matches (pick'em)
+    pub predictions_enabled: bool,
+    /// Port of the gRPC stats service; unset disables it
+    pub grpc_port: Option<u16>,
+    /// Bearer token every gRPC call must present
+    pub grpc_token: Option<String>,
+    /// Sustained JSON API requests per minute of one session or IP address
+    pub api_rate_limit_per_minute: u32,
+    /// JSON API requests one client may send at once
+    pub api_rate_limit_burst: u32,
+    /// Reverse proxies whose `X-Forwarded-For` / `X-Real-IP` headers are believed
+    pub trusted_proxies: Vec<IpAddr>,
+    /// SMTP server for outgoing email; emails are only logged without it
+    pub smtp_url: Option<String>,
+    /// Sender address of outgoing email
+    pub mail_from: String,
+    /// Address the site is reached at, e.g. `https://hockey.example.com`;
+    /// absolute links are built from it, never from request headers
+    pub public_url: Option<String>,
+    /// App credentials for "Sign in with Google"; unset hides the button
+    pub google_oauth: Option<OAuthClient>,
+    /// App credentials for "Sign in with GitHub"; unset hides the button
+    pub github_oauth: Option<OAuthClient>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -82,15 +153,297 @@ impl Config {
             .and_then(|s| s.parse().ok())
             .unwrap_or(5);
 
+        let session_idle_timeout_minutes = env::var("SESSION_IDLE_TIMEOUT_MINUTES")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|minutes: &i64| *minutes > 0)
+            .unwrap_or(7 * 24 * 60);
+
+        let session_max_lifetime_hours = env::var("SESSION_MAX_LIFETIME_HOURS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|hours: &i64| *hours > 0)
+            .unwrap_or(30 * 24);
+
+        let login_notify_new_ip = env::var("LOGIN_NOTIFY_NEW_IP")
+            .map(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"))
+            .unwrap_or(false);
+
+        let login_lockout_threshold = env::var("LOGIN_LOCKOUT_THRESHOLD")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(DEFAULT_LOCKOUT_THRESHOLD);
+
+        let login_lockout_minutes = env::var("LOGIN_LOCKOUT_MINUTES")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|minutes: &i64| *minutes > 0)
+            .unwrap_or(DEFAULT_LOCKOUT_MINUTES);
+
+        let default_hashing = HashingParams::default();
+        let hashing = HashingParams {
+            memory_kib: env::var("PASSWORD_HASH_MEMORY_KIB")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(default_hashing.memory_kib),
+            iterations: env::var("PASSWORD_HASH_ITERATIONS")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(default_hashing.iterations),
+            parallelism: env::var("PASSWORD_HASH_PARALLELISM")
+                .ok()
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(default_hashing.parallelism),
+        };
+        if let Err(e) = hashing.params() {
+            anyhow::bail!(
+                "Invalid password hashing parameters ({}): PASSWORD_HASH_MEMORY_KIB must be \
+                 at least 8 times PASSWORD_HASH_PARALLELISM, and the counts at least 1",
+                e
+            );
+        }
+
+        let demo_mode = env::var("DEMO_MODE")
+            .map(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"))
+            .unwrap_or(false);
+
+        let demo_user_email =
+            env::var("DEMO_USER_EMAIL").unwrap_or_else(|_| "demo@hockey.local".to_string());
+
+        let http2_enabled = env::var("HTTP2_ENABLED")
+            .map(|v| !matches!(v.to_lowercase().as_str(), "0" | "false" | "no"))
+            .unwrap_or(true);
+
+        let keep_alive_secs = env::var("KEEP_ALIVE_SECS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(75);
+
+        let max_concurrent_requests = env::var("MAX_CONCURRENT_REQUESTS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0);
+
+        let http2_max_concurrent_streams = env::var("HTTP2_MAX_CONCURRENT_STREAMS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|streams: &u32| *streams > 0)
+            .unwrap_or(200);
+
+        let maintenance_hour = env::var("MAINTENANCE_HOUR")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|hour: &u32| *hour < 24)
+            .unwrap_or(3);
+
+        let backup_dir = env::var("BACKUP_DIR").ok().filter(|dir| !dir.is_empty());
+
+        let backup_keep = env::var("BACKUP_KEEP")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(7);
+
+        let backup_interval_hours = env::var("BACKUP_INTERVAL_HOURS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|hours: &u32| *hours > 0 && 24 % *hours == 0)
+            .unwrap_or(24);
+
+        let slow_query_ms = env::var("SLOW_QUERY_MS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(100);
+
+        let predictions_enabled = env::var("PREDICTIONS_ENABLED")
+            .map(|v| matches!(v.to_lowercase().as_str(), "1" | "true" | "yes"))
+            .unwrap_or(false);
+
+        let grpc_port = env::var("GRPC_PORT")
+            .ok()
+            .filter(|port| !port.is_empty())
+            .map(|port| port.parse::<u16>())
+            .transpose()?;
+
+        let grpc_token = env::var("GRPC_TOKEN")
+            .ok()
+            .filter(|token| !token.is_empty());
+        if grpc_port.is_some() && !matches!(&grpc_token, Some(token) if token.len() >= 32) {
+            anyhow::bail!(
+                "GRPC_TOKEN of at least 32 characters is required when GRPC_PORT is set\n\
+                 Generate one with: openssl rand -hex 32"
+            );
+        }
+
+        let api_rate_limit_per_minute = env::var("API_RATE_LIMIT_PER_MINUTE")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|requests: &u32| *requests > 0)
+            .unwrap_or(DEFAULT_API_RATE_PER_MINUTE);
+
+        let api_rate_limit_burst = env::var("API_RATE_LIMIT_BURST")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .filter(|requests: &u32| *requests > 0)
+            .unwrap_or(DEFAULT_API_RATE_BURST);
+
+        let trusted_proxies = match env::var("TRUSTED_PROXIES").ok().filter(|v| !v.is_empty()) {
+            Some(proxies) => proxies
+                .split(',')
+                .map(str::trim)
+                .filter(|proxy| !proxy.is_empty())
+                .map(|proxy| {
+                    proxy.parse().map_err(|_| {
+                        anyhow::anyhow!("TRUSTED_PROXIES contains an invalid IP address: {}", proxy)
+                    })
+                })
+                .collect::<Result<Vec<IpAddr>, _>>()?,
+            None => vec![
+                IpAddr::from(Ipv4Addr::LOCALHOST),
+                IpAddr::from(Ipv6Addr::LOCALHOST),
+            ],
+        };
+
+        let smtp_url = env::var("SMTP_URL").ok().filter(|url| !url.is_empty());
+
+        let mail_from = env::var("MAIL_FROM")
+            .ok()
+            .filter(|from| !from.is_empty())
+            .unwrap_or_else(|| DEFAULT_MAIL_FROM.to_string());
+
+        let public_url = env::var("PUBLIC_URL")
+            .ok()
+            .map(|url| url.trim().trim_end_matches('/').to_string())
+            .filter(|url| !url.is_empty());
+        if let Some(url) = &public_url {
+            let is_http_origin = reqwest::Url::parse(url)
+                .is_ok_and(|u| matches!(u.scheme(), "http" | "https") && u.host().is_some());
+            if !is_http_origin {
+                anyhow::bail!(
+                    "PUBLIC_URL must be an http(s) address such as https://hockey.example.com"
+                );
+            }
+        }
+        if smtp_url.is_some() && public_url.is_none() {
+            anyhow::bail!(
+                "PUBLIC_URL is required when SMTP_URL is set\n\
+                 Emailed links are built from it, e.g. PUBLIC_URL=https://hockey.example.com"
+            );
+        }
+
+        let google_oauth = oauth_client("GOOGLE")?;
+        let github_oauth = oauth_client("GITHUB")?;
+        if environment == Environment::Production
+            && (google_oauth.is_some() || github_oauth.is_some())
+            && public_url.is_none()
+        {
+            anyhow::bail!(
+                "PUBLIC_URL is required for external sign-in in production\n\
+                 The provider callback URL is built from it, e.g. PUBLIC_URL=https://hockey.example.com"
+            );
+        }
+
         Ok(Config {
             database_url,
             session_secret,
             environment,
             port,
             db_max_connections,
+            session_idle_timeout_minutes,
+            session_max_lifetime_hours,
+            login_notify_new_ip,
+            login_lockout_threshold,
+            login_lockout_minutes,
+            password_hash_memory_kib: hashing.memory_kib,
+            password_hash_iterations: hashing.iterations,
+            password_hash_parallelism: hashing.parallelism,
+            demo_mode,
+            demo_user_email,
+            http2_enabled,
+            keep_alive_secs,
+            max_concurrent_requests,
+            http2_max_concurrent_streams,
+            maintenance_hour,
+            backup_dir,
+            backup_keep,
+            backup_interval_hours,
+            slow_query_ms,
+            predictions_enabled,
+            grpc_port,
+            grpc_token,
+            api_rate_limit_per_minute,
+            api_rate_limit_burst,
+            trusted_proxies,
+            smtp_url,
+            mail_from,
+            public_url,
+            google_oauth,
+            github_oauth,
         })
     }
 
+    /// External sign-in providers with credentials configured
+    pub fn oauth_providers(&self) -> OAuthProviders {
+        OAuthProviders::new(self.google_oauth.clone(), self.github_oauth.clone())
+    }
+
+    /// Account lockout after repeated wrong passwords
+    pub fn lockout_policy(&self) -> LockoutPolicy {
+        LockoutPolicy {
+            threshold: self.login_lockout_threshold,
+            minutes: self.login_lockout_minutes,
+        }
+    }
+
+    /// Argon2id cost of new password hashes
+    pub fn hashing_params(&self) -> HashingParams {
+        HashingParams {
+            memory_kib: self.password_hash_memory_kib,
+            iterations: self.password_hash_iterations,
+            parallelism: self.password_hash_parallelism,
+        }
+    }
+
+    /// Session lifetime policy derived from the configured timeouts
+    pub fn session_config(&self) -> SessionConfig {
+        SessionConfig {
+            idle_timeout: chrono::Duration::minutes(self.session_idle_timeout_minutes),
+            max_lifetime: chrono::Duration::hours(self.session_max_lifetime_hours),
+        }
+    }
+
+    /// Connection settings for the HTTP server
+    pub fn server_config(&self) -> ServerConfig {
+        ServerConfig {
+            http2: self.http2_enabled,
+            keep_alive: (self.keep_alive_secs > 0)
+                .then(|| std::time::Duration::from_secs(self.keep_alive_secs)),
+            http2_max_concurrent_streams: self.http2_max_concurrent_streams,
+        }
+    }
+
+    /// Schedule of the nightly database maintenance job
+    pub fn maintenance_config(&self) -> MaintenanceConfig {
+        MaintenanceConfig {
+            hour: self.maintenance_hour,
+            backup_dir: self.backup_dir.as_ref().map(std::path::PathBuf::from),
+            backup_keep: self.backup_keep,
+            backup_interval_hours: self.backup_interval_hours,
+        }
+    }
+
+    /// Base of absolute links: `PUBLIC_URL`, or this machine's port when it
+    /// is not set
+    pub fn public_base_url(&self) -> String {
+        self.public_url
+            .clone()
+            .unwrap_or_else(|| format!("http://localhost:{}", self.port))
+    }
+
+    /// Email of the demo account when demo mode is enabled
+    pub fn demo_user(&self) -> Option<&str> {
+        self.demo_mode.then_some(self.demo_user_email.as_str())
+    }
+
     #[allow(dead_code)]
     pub fn is_production(&self) -> bool {
         self.environment == Environment::Production
@@ -102,6 +455,26 @@ impl Config {
     }
 }
 
+/// Credentials from `{PREFIX}_CLIENT_ID` and `{PREFIX}_CLIENT_SECRET`, which
+/// must be set together
+fn oauth_client(prefix: &str) -> Result<Option<OAuthClient>, anyhow::Error> {
+    let id_var = format!("{}_CLIENT_ID", prefix);
+    let secret_var = format!("{}_CLIENT_SECRET", prefix);
+    let client_id = env::var(&id_var).ok().filter(|id| !id.is_empty());
+    let client_secret = env::var(&secret_var)
+        .ok()
+        .filter(|secret| !secret.is_empty());
+
+    match (client_id, client_secret) {
+        (Some(client_id), Some(client_secret)) => Ok(Some(OAuthClient {
+            client_id,
+            client_secret,
+        })),
+        (None, None) => Ok(None),
+        _ => anyhow::bail!("{} and {} must be set together", id_var, secret_var),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,6 +487,38 @@ mod tests {
         env::remove_var("DATABASE_URL");
         env::remove_var("PORT");
         env::remove_var("DB_MAX_CONNECTIONS");
+        env::remove_var("SESSION_IDLE_TIMEOUT_MINUTES");
+        env::remove_var("SESSION_MAX_LIFETIME_HOURS");
+        env::remove_var("LOGIN_NOTIFY_NEW_IP");
+        env::remove_var("LOGIN_LOCKOUT_THRESHOLD");
+        env::remove_var("LOGIN_LOCKOUT_MINUTES");
+        env::remove_var("PASSWORD_HASH_MEMORY_KIB");
+        env::remove_var("PASSWORD_HASH_ITERATIONS");
+        env::remove_var("PASSWORD_HASH_PARALLELISM");
+        env::remove_var("DEMO_MODE");
+        env::remove_var("DEMO_USER_EMAIL");
+        env::remove_var("HTTP2_ENABLED");
+        env::remove_var("KEEP_ALIVE_SECS");
+        env::remove_var("MAX_CONCURRENT_REQUESTS");
+        env::remove_var("HTTP2_MAX_CONCURRENT_STREAMS");
+        env::remove_var("MAINTENANCE_HOUR");
+        env::remove_var("BACKUP_DIR");
+        env::remove_var("BACKUP_KEEP");
+        env::remove_var("BACKUP_INTERVAL_HOURS");
+        env::remove_var("SLOW_QUERY_MS");
+        env::remove_var("PREDICTIONS_ENABLED");
+        env::remove_var("GRPC_PORT");
+        env::remove_var("GRPC_TOKEN");
+        env::remove_var("API_RATE_LIMIT_PER_MINUTE");
+        env::remove_var("API_RATE_LIMIT_BURST");
+        env::remove_var("TRUSTED_PROXIES");
+        env::remove_var("SMTP_URL");
+        env::remove_var("MAIL_FROM");
+        env::remove_var("PUBLIC_URL");
+        env::remove_var("GOOGLE_CLIENT_ID");
+        env::remove_var("GOOGLE_CLIENT_SECRET");
+        env::remove_var("GITHUB_CLIENT_ID");
+        env::remove_var("GITHUB_CLIENT_SECRET");
     }
 
     #[test]
@@ -251,4 +656,329 @@ mod tests {
         let config = result.unwrap();
         assert_eq!(config.db_max_connections, 5);
     }
+
+    #[test]
+    #[serial]
+    fn test_default_session_timeouts() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(config.session_idle_timeout_minutes, 7 * 24 * 60);
+        assert_eq!(config.session_max_lifetime_hours, 30 * 24);
+    }
+
+    #[test]
+    #[serial]
+    fn test_custom_session_timeouts() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+        env::set_var("SESSION_IDLE_TIMEOUT_MINUTES", "30");
+        env::set_var("SESSION_MAX_LIFETIME_HOURS", "12");
+
+        let config = Config::from_env_vars().unwrap();
+        let session_config = config.session_config();
+        assert_eq!(session_config.idle_timeout, chrono::Duration::minutes(30));
+        assert_eq!(session_config.max_lifetime, chrono::Duration::hours(12));
+    }
+
+    #[test]
+    #[serial]
+    fn test_invalid_session_timeouts_use_defaults() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+        env::set_var("SESSION_IDLE_TIMEOUT_MINUTES", "0");
+        env::set_var("SESSION_MAX_LIFETIME_HOURS", "forever");
+
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(config.session_idle_timeout_minutes, 7 * 24 * 60);
+        assert_eq!(config.session_max_lifetime_hours, 30 * 24);
+    }
+
+    #[test]
+    #[serial]
+    fn test_login_notify_new_ip() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+        assert!(!Config::from_env_vars().unwrap().login_notify_new_ip);
+
+        env::set_var("LOGIN_NOTIFY_NEW_IP", "true");
+        assert!(Config::from_env_vars().unwrap().login_notify_new_ip);
+    }
+
+    #[test]
+    #[serial]
+    fn test_login_lockout() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+        assert_eq!(
+            Config::from_env_vars().unwrap().lockout_policy(),
+            LockoutPolicy::default()
+        );
+
+        env::set_var("LOGIN_LOCKOUT_THRESHOLD", "0");
+        env::set_var("LOGIN_LOCKOUT_MINUTES", "-5");
+        let policy = Config::from_env_vars().unwrap().lockout_policy();
+        assert!(!policy.enabled());
+        assert_eq!(policy.minutes, DEFAULT_LOCKOUT_MINUTES);
+
+        env::set_var("LOGIN_LOCKOUT_THRESHOLD", "10");
+        env::set_var("LOGIN_LOCKOUT_MINUTES", "60");
+        assert_eq!(
+            Config::from_env_vars().unwrap().lockout_policy(),
+            LockoutPolicy {
+                threshold: 10,
+                minutes: 60
+            }
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn test_password_hashing() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+        assert_eq!(
+            Config::from_env_vars().unwrap().hashing_params(),
+            HashingParams::default()
+        );
+
+        env::set_var("PASSWORD_HASH_MEMORY_KIB", "65536");
+        env::set_var("PASSWORD_HASH_ITERATIONS", "3");
+        env::set_var("PASSWORD_HASH_PARALLELISM", "4");
+        assert_eq!(
+            Config::from_env_vars().unwrap().hashing_params(),
+            HashingParams {
+                memory_kib: 65536,
+                iterations: 3,
+                parallelism: 4
+            }
+        );
+
+        env::set_var("PASSWORD_HASH_MEMORY_KIB", "16");
+        assert!(Config::from_env_vars().is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_demo_mode() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+        assert_eq!(Config::from_env_vars().unwrap().demo_user(), None);
+
+        env::set_var("DEMO_MODE", "true");
+        env::set_var("DEMO_USER_EMAIL", "guest@example.com");
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(config.demo_user(), Some("guest@example.com"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_default_server_settings() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+
+        let config = Config::from_env_vars().unwrap();
+        assert!(config.http2_enabled);
+        assert_eq!(config.max_concurrent_requests, 0);
+
+        let server = config.server_config();
+        assert_eq!(server.keep_alive, Some(std::time::Duration::from_secs(75)));
+        assert_eq!(server.http2_max_concurrent_streams, 200);
+    }
+
+    #[test]
+    #[serial]
+    fn test_custom_server_settings() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+        env::set_var("HTTP2_ENABLED", "false");
+        env::set_var("KEEP_ALIVE_SECS", "0");
+        env::set_var("MAX_CONCURRENT_REQUESTS", "64");
+        env::set_var("HTTP2_MAX_CONCURRENT_STREAMS", "0");
+
+        let config = Config::from_env_vars().unwrap();
+        assert!(!config.http2_enabled);
+        assert_eq!(config.max_concurrent_requests, 64);
+
+        let server = config.server_config();
+        assert!(!server.http2);
+        assert!(server.keep_alive.is_none());
+        // Zero streams would make HTTP/2 unusable, so the default is kept
+        assert_eq!(server.http2_max_concurrent_streams, 200);
+    }
+
+    #[test]
+    #[serial]
+    fn test_maintenance_settings() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+
+        let maintenance = Config::from_env_vars().unwrap().maintenance_config();
+        assert_eq!(maintenance.hour, 3);
+        assert!(maintenance.backup_dir.is_none());
+        assert_eq!(maintenance.backup_keep, 7);
+        assert_eq!(maintenance.backup_interval_hours, 24);
+
+        env::set_var("MAINTENANCE_HOUR", "24");
+        env::set_var("BACKUP_DIR", "/var/backups/hockey");
+        env::set_var("BACKUP_KEEP", "14");
+        env::set_var("BACKUP_INTERVAL_HOURS", "6");
+
+        let maintenance = Config::from_env_vars().unwrap().maintenance_config();
+        // Out-of-range hours fall back to the default
+        assert_eq!(maintenance.hour, 3);
+        assert_eq!(
+            maintenance.backup_dir,
+            Some(std::path::PathBuf::from("/var/backups/hockey"))
+        );
+        assert_eq!(maintenance.backup_keep, 14);
+        assert_eq!(maintenance.backup_interval_hours, 6);
+
+        // Intervals that do not divide a day fall back to daily
+        env::set_var("BACKUP_INTERVAL_HOURS", "5");
+        let maintenance = Config::from_env_vars().unwrap().maintenance_config();
+        assert_eq!(maintenance.backup_interval_hours, 24);
+    }
+
+    #[test]
+    #[serial]
+    fn test_slow_query_threshold() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+        assert_eq!(Config::from_env_vars().unwrap().slow_query_ms, 100);
+
+        env::set_var("SLOW_QUERY_MS", "0");
+        assert_eq!(Config::from_env_vars().unwrap().slow_query_ms, 0);
+
+        env::set_var("SLOW_QUERY_MS", "fast");
+        assert_eq!(Config::from_env_vars().unwrap().slow_query_ms, 100);
+    }
+
+    #[test]
+    #[serial]
+    fn test_predictions_disabled_by_default() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+        assert!(!Config::from_env_vars().unwrap().predictions_enabled);
+
+        env::set_var("PREDICTIONS_ENABLED", "true");
+        assert!(Config::from_env_vars().unwrap().predictions_enabled);
+    }
+
+    #[test]
+    #[serial]
+    fn test_grpc_requires_token() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+        assert_eq!(Config::from_env_vars().unwrap().grpc_port, None);
+
+        env::set_var("GRPC_PORT", "50051");
+        assert!(Config::from_env_vars().is_err());
+
+        env::set_var("GRPC_TOKEN", "a".repeat(32));
+        assert_eq!(Config::from_env_vars().unwrap().grpc_port, Some(50051));
+    }
+
+    #[test]
+    #[serial]
+    fn test_api_rate_limit() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(config.api_rate_limit_per_minute, 120);
+        assert_eq!(config.api_rate_limit_burst, 30);
+
+        env::set_var("API_RATE_LIMIT_PER_MINUTE", "600");
+        env::set_var("API_RATE_LIMIT_BURST", "0");
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(config.api_rate_limit_per_minute, 600);
+        assert_eq!(config.api_rate_limit_burst, 30);
+    }
+
+    #[test]
+    #[serial]
+    fn test_trusted_proxies() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(
+            config.trusted_proxies,
+            vec![
+                IpAddr::from(Ipv4Addr::LOCALHOST),
+                IpAddr::from(Ipv6Addr::LOCALHOST)
+            ]
+        );
+
+        env::set_var("TRUSTED_PROXIES", "10.0.0.1, fd00::1");
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(
+            config.trusted_proxies,
+            vec![
+                "10.0.0.1".parse::<IpAddr>().unwrap(),
+                "fd00::1".parse::<IpAddr>().unwrap()
+            ]
+        );
+
+        env::set_var("TRUSTED_PROXIES", "10.0.0.0/8");
+        assert!(Config::from_env_vars().is_err());
+    }
+
+    #[test]
+    #[serial]
+    fn test_mail_settings() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(config.smtp_url, None);
+        assert_eq!(config.mail_from, DEFAULT_MAIL_FROM);
+
+        assert_eq!(config.public_base_url(), "http://localhost:8080");
+
+        env::set_var("SMTP_URL", "smtp://localhost:1025");
+        env::set_var("MAIL_FROM", "Club <club@example.com>");
+        assert!(Config::from_env_vars().is_err());
+
+        env::set_var("PUBLIC_URL", "hockey.example.com");
+        assert!(Config::from_env_vars().is_err());
+
+        env::set_var("PUBLIC_URL", "https://hockey.example.com/");
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(config.smtp_url.as_deref(), Some("smtp://localhost:1025"));
+        assert_eq!(config.mail_from, "Club <club@example.com>");
+        assert_eq!(config.public_base_url(), "https://hockey.example.com");
+    }
+
+    #[test]
+    #[serial]
+    fn test_oauth_providers() {
+        clear_env();
+        env::set_var("ENVIRONMENT", "development");
+        let config = Config::from_env_vars().unwrap();
+        assert!(config.oauth_providers().enabled().is_empty());
+
+        env::set_var("GITHUB_CLIENT_ID", "github-id");
+        let error = Config::from_env_vars().unwrap_err().to_string();
+        assert!(error.contains("GITHUB_CLIENT_SECRET"));
+
+        env::set_var("GITHUB_CLIENT_SECRET", "github-secret");
+        let config = Config::from_env_vars().unwrap();
+        assert_eq!(
+            config.github_oauth,
+            Some(OAuthClient {
+                client_id: "github-id".to_string(),
+                client_secret: "github-secret".to_string(),
+            })
+        );
+        assert_eq!(config.google_oauth, None);
+
+        // Production builds the callback URL from PUBLIC_URL only
+        env::set_var("ENVIRONMENT", "production");
+        env::set_var("SESSION_SECRET", "a".repeat(32));
+        let error = Config::from_env_vars().unwrap_err().to_string();
+        assert!(error.contains("PUBLIC_URL"));
+
+        env::set_var("PUBLIC_URL", "https://hockey.example.com");
+        assert!(Config::from_env_vars().is_ok());
+    }
 }
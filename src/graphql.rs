@@ -0,0 +1,651 @@
+//! Read-only GraphQL API over the backend services
+//!
+//! Served at `/graphql` (see `routes::graphql`) for reports that need nested
+//! data, e.g. a season's matches with their goals and scorers, in one
+//! request. Objects wrap the service entities and load their relations on
+//! demand; depth and complexity limits keep a single query from fanning out
+//! into an unbounded number of lookups.
+
+use async_graphql::{Context, EmptyMutation, EmptySubscription, Object, Result, Schema};
+use sqlx::SqlitePool;
+
+use crate::common::pagination::SortOrder;
+use crate::service::events::{self, EventEntity, EventFilters};
+use crate::service::matches::{self, MatchEntity, MatchFilters, ScoreEventEntity};
+use crate::service::player_contracts::{self, PlayerInRoster};
+use crate::service::players::{self, PlayerEntity, PlayerFilters};
+use crate::service::seasons::{self, SeasonEntity, SeasonFilters};
+use crate::service::team_participations::{self, TeamParticipationEntity};
+use crate::service::teams::{self, TeamEntity, TeamFilters};
+
+pub type HockeySchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+/// Page size of top-level lists when none is requested
+const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// Largest page a top-level list returns
+const MAX_PAGE_SIZE: usize = 200;
+
+/// Upper bound of nested lists such as a season's matches
+const NESTED_LIMIT: usize = 10_000;
+
+/// Deepest nesting a query may use
+const MAX_DEPTH: usize = 10;
+
+/// Fields a single query may select in total
+const MAX_COMPLEXITY: usize = 5_000;
+
+/// Build the schema with the database pool its resolvers read from
+pub fn build_schema(db: SqlitePool) -> HockeySchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(db)
+        .limit_depth(MAX_DEPTH)
+        .limit_complexity(MAX_COMPLEXITY)
+        .finish()
+}
+
+fn db<'a>(ctx: &Context<'a>) -> &'a SqlitePool {
+    ctx.data_unchecked::<SqlitePool>()
+}
+
+/// Log a database error and hide its details from the client
+fn db_error(e: sqlx::Error) -> async_graphql::Error {
+    tracing::error!("GraphQL query failed: {}", e);
+    async_graphql::Error::new("Database error")
+}
+
+/// 1-based page and page size, clamped to `MAX_PAGE_SIZE`
+fn paging(page: Option<i32>, page_size: Option<i32>) -> (usize, usize) {
+    let page = page.unwrap_or(1).max(1) as usize;
+    let page_size = page_size
+        .map(|size| size.clamp(1, MAX_PAGE_SIZE as i32) as usize)
+        .unwrap_or(DEFAULT_PAGE_SIZE);
+    (page, page_size)
+}
+
+async fn season_matches(
+    db: &SqlitePool,
+    season_id: Option<i64>,
+    team_id: Option<i64>,
+    status: Option<String>,
+    page: usize,
+    page_size: usize,
+) -> Result<Vec<Match>> {
+    let filters = MatchFilters {
+        season_id,
+        team_id,
+        status,
+        date_from: None,
+        date_to: None,
+        coverage: None,
+        arena_id: None,
+        game_type: None,
+        group_id: None,
+        date_unknown: false,
+    };
+    let result = matches::get_matches(
+        db,
+        &filters,
+        &matches::SortField::Date,
+        &SortOrder::Asc,
+        &[],
+        page,
+        page_size,
+        None,
+    )
+    .await
+    .map_err(db_error)?;
+    Ok(result.items.into_iter().map(Match).collect())
+}
+
+async fn team(db: &SqlitePool, id: i64) -> Result<Option<Team>> {
+    Ok(teams::get_team_by_id(db, id)
+        .await
+        .map_err(db_error)?
+        .map(Team))
+}
+
+async fn player(db: &SqlitePool, id: Option<i64>) -> Result<Option<Player>> {
+    let Some(id) = id else {
+        return Ok(None);
+    };
+    Ok(players::get_player_by_id(db, id)
+        .await
+        .map_err(db_error)?
+        .map(Player))
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// Events by name, e.g. "World Championship"
+    async fn events(
+        &self,
+        ctx: &Context<'_>,
+        name: Option<String>,
+        page: Option<i32>,
+        page_size: Option<i32>,
+    ) -> Result<Vec<Event>> {
+        let (page, page_size) = paging(page, page_size);
+        let filters = EventFilters {
+            name,
+            country_id: None,
+        };
+        let result = events::get_events(db(ctx), &filters, page, page_size)
+            .await
+            .map_err(db_error)?;
+        Ok(result.items.into_iter().map(Event).collect())
+    }
+
+    async fn event(&self, ctx: &Context<'_>, id: i64) -> Result<Option<Event>> {
+        Ok(events::get_event_by_id(db(ctx), id)
+            .await
+            .map_err(db_error)?
+            .map(Event))
+    }
+
+    /// Seasons, newest first
+    async fn seasons(
+        &self,
+        ctx: &Context<'_>,
+        event_id: Option<i64>,
+        year: Option<i64>,
+        page: Option<i32>,
+        page_size: Option<i32>,
+    ) -> Result<Vec<Season>> {
+        let (page, page_size) = paging(page, page_size);
+        let filters = SeasonFilters { event_id, year };
+        let result = seasons::get_seasons(
+            db(ctx),
+            &filters,
+            &seasons::SortField::Year,
+            &SortOrder::Desc,
+            page,
+            page_size,
+        )
+        .await
+        .map_err(db_error)?;
+        Ok(result.items.into_iter().map(Season).collect())
+    }
+
+    async fn season(&self, ctx: &Context<'_>, id: i64) -> Result<Option<Season>> {
+        Ok(seasons::get_season_by_id(db(ctx), id)
+            .await
+            .map_err(db_error)?
+            .map(Season))
+    }
+
+    /// Teams by name
+    async fn teams(
+        &self,
+        ctx: &Context<'_>,
+        name: Option<String>,
+        page: Option<i32>,
+        page_size: Option<i32>,
+    ) -> Result<Vec<Team>> {
+        let (page, page_size) = paging(page, page_size);
+        let filters = TeamFilters {
+            name,
+            country_id: None,
+        };
+        let result = teams::get_teams(
+            db(ctx),
+            &filters,
+            &teams::SortField::Name,
+            &SortOrder::Asc,
+            page,
+            page_size,
+        )
+        .await
+        .map_err(db_error)?;
+        Ok(result.items.into_iter().map(Team).collect())
+    }
+
+    async fn team(&self, ctx: &Context<'_>, id: i64) -> Result<Option<Team>> {
+        team(db(ctx), id).await
+    }
+
+    /// Players by name
+    async fn players(
+        &self,
+        ctx: &Context<'_>,
+        name: Option<String>,
+        page: Option<i32>,
+        page_size: Option<i32>,
+    ) -> Result<Vec<Player>> {
+        let (page, page_size) = paging(page, page_size);
+        let filters = PlayerFilters {
+            name,
+            country_id: None,
+        };
+        let result = players::get_players(
+            db(ctx),
+            &filters,
+            &players::SortField::Name,
+            &SortOrder::Asc,
+            page,
+            page_size,
+        )
+        .await
+        .map_err(db_error)?;
+        Ok(result.items.into_iter().map(Player).collect())
+    }
+
+    async fn player(&self, ctx: &Context<'_>, id: i64) -> Result<Option<Player>> {
+        player(db(ctx), Some(id)).await
+    }
+
+    /// Matches in date order; `team_id` matches either side
+    async fn matches(
+        &self,
+        ctx: &Context<'_>,
+        season_id: Option<i64>,
+        team_id: Option<i64>,
+        status: Option<String>,
+        page: Option<i32>,
+        page_size: Option<i32>,
+    ) -> Result<Vec<Match>> {
+        let (page, page_size) = paging(page, page_size);
+        season_matches(db(ctx), season_id, team_id, status, page, page_size).await
+    }
+
+    #[graphql(name = "match")]
+    async fn game(&self, ctx: &Context<'_>, id: i64) -> Result<Option<Match>> {
+        Ok(matches::get_match_by_id(db(ctx), id)
+            .await
+            .map_err(db_error)?
+            .map(Match))
+    }
+}
+
+pub struct Event(EventEntity);
+
+#[Object]
+impl Event {
+    async fn id(&self) -> i64 {
+        self.0.id
+    }
+
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    async fn slug(&self) -> Option<&str> {
+        self.0.slug.as_deref()
+    }
+
+    /// Country code (ISO 3166-1 alpha-2) of the event's default country
+    async fn country_code(&self) -> Option<&str> {
+        self.0.country_iso2_code.as_deref()
+    }
+
+    async fn point_system(&self) -> &str {
+        &self.0.point_system
+    }
+
+    /// Seasons of the event, newest first
+    async fn seasons(&self, ctx: &Context<'_>) -> Result<Vec<Season>> {
+        let filters = SeasonFilters {
+            event_id: Some(self.0.id),
+            year: None,
+        };
+        let result = seasons::get_seasons(
+            db(ctx),
+            &filters,
+            &seasons::SortField::Year,
+            &SortOrder::Desc,
+            1,
+            NESTED_LIMIT,
+        )
+        .await
+        .map_err(db_error)?;
+        Ok(result.items.into_iter().map(Season).collect())
+    }
+}
+
+pub struct Season(SeasonEntity);
+
+#[Object]
+impl Season {
+    async fn id(&self) -> i64 {
+        self.0.id
+    }
+
+    async fn year(&self) -> i64 {
+        self.0.year
+    }
+
+    /// Display name, falling back to the year
+    async fn name(&self) -> String {
+        self.0
+            .display_name
+            .clone()
+            .unwrap_or_else(|| self.0.year.to_string())
+    }
+
+    async fn event(&self, ctx: &Context<'_>) -> Result<Option<Event>> {
+        Ok(events::get_event_by_id(db(ctx), self.0.event_id)
+            .await
+            .map_err(db_error)?
+            .map(Event))
+    }
+
+    /// Participating teams by name
+    async fn teams(&self, ctx: &Context<'_>) -> Result<Vec<SeasonTeam>> {
+        Ok(
+            team_participations::get_teams_for_season(db(ctx), self.0.id)
+                .await
+                .map_err(db_error)?
+                .into_iter()
+                .map(SeasonTeam)
+                .collect(),
+        )
+    }
+
+    /// Matches of the season in date order
+    async fn matches(&self, ctx: &Context<'_>, status: Option<String>) -> Result<Vec<Match>> {
+        season_matches(db(ctx), Some(self.0.id), None, status, 1, NESTED_LIMIT).await
+    }
+}
+
+/// A team's participation in a season
+pub struct SeasonTeam(TeamParticipationEntity);
+
+#[Object]
+impl SeasonTeam {
+    async fn team(&self, ctx: &Context<'_>) -> Result<Option<Team>> {
+        team(db(ctx), self.0.team_id).await
+    }
+
+    /// Final placement in the season (1 = gold)
+    async fn final_rank(&self) -> Option<i64> {
+        self.0.final_rank
+    }
+
+    /// Playoff seed (1 = top seed)
+    async fn seed(&self) -> Option<i64> {
+        self.0.seed
+    }
+
+    /// Players on the team's roster for the season
+    async fn roster(&self, ctx: &Context<'_>) -> Result<Vec<RosterPlayer>> {
+        Ok(player_contracts::get_roster(db(ctx), self.0.id)
+            .await
+            .map_err(db_error)?
+            .into_iter()
+            .map(RosterPlayer)
+            .collect())
+    }
+}
+
+pub struct RosterPlayer(PlayerInRoster);
+
+#[Object]
+impl RosterPlayer {
+    async fn player(&self, ctx: &Context<'_>) -> Result<Option<Player>> {
+        player(db(ctx), Some(self.0.player_id)).await
+    }
+
+    async fn jersey_number(&self) -> Option<i64> {
+        self.0.jersey_number
+    }
+
+    /// "C" for the captain, "A" for an alternate
+    async fn captaincy(&self) -> Option<&str> {
+        self.0.captaincy.as_deref()
+    }
+
+    async fn joined_on(&self) -> Option<&str> {
+        self.0.joined_on.as_deref()
+    }
+
+    async fn left_on(&self) -> Option<&str> {
+        self.0.left_on.as_deref()
+    }
+}
+
+pub struct Team(TeamEntity);
+
+#[Object]
+impl Team {
+    async fn id(&self) -> i64 {
+        self.0.id
+    }
+
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    async fn slug(&self) -> Option<&str> {
+        self.0.slug.as_deref()
+    }
+
+    /// Country code (ISO 3166-1 alpha-2)
+    async fn country_code(&self) -> Option<&str> {
+        self.0.country_iso2_code.as_deref()
+    }
+
+    /// Matches of the team in date order, optionally of one season
+    async fn matches(&self, ctx: &Context<'_>, season_id: Option<i64>) -> Result<Vec<Match>> {
+        season_matches(db(ctx), season_id, Some(self.0.id), None, 1, NESTED_LIMIT).await
+    }
+}
+
+pub struct Player(PlayerEntity);
+
+#[Object]
+impl Player {
+    async fn id(&self) -> i64 {
+        self.0.id
+    }
+
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    async fn slug(&self) -> Option<&str> {
+        self.0.slug.as_deref()
+    }
+
+    /// Country code (ISO 3166-1 alpha-2)
+    async fn country_code(&self) -> &str {
+        &self.0.country_iso2_code
+    }
+
+    async fn birth_date(&self) -> Option<&str> {
+        self.0.birth_date.as_deref()
+    }
+
+    async fn birth_place(&self) -> Option<&str> {
+        self.0.birth_place.as_deref()
+    }
+
+    async fn height_cm(&self) -> Option<i64> {
+        self.0.height_cm
+    }
+
+    async fn weight_kg(&self) -> Option<i64> {
+        self.0.weight_kg
+    }
+
+    async fn position(&self) -> Option<&str> {
+        self.0.position.as_deref()
+    }
+
+    async fn shoots(&self) -> Option<&str> {
+        self.0.shoots.as_deref()
+    }
+}
+
+pub struct Match(MatchEntity);
+
+#[Object]
+impl Match {
+    async fn id(&self) -> i64 {
+        self.0.id
+    }
+
+    async fn season(&self, ctx: &Context<'_>) -> Result<Option<Season>> {
+        Ok(seasons::get_season_by_id(db(ctx), self.0.season_id)
+            .await
+            .map_err(db_error)?
+            .map(Season))
+    }
+
+    async fn home_team(&self, ctx: &Context<'_>) -> Result<Option<Team>> {
+        team(db(ctx), self.0.home_team_id).await
+    }
+
+    async fn away_team(&self, ctx: &Context<'_>) -> Result<Option<Team>> {
+        team(db(ctx), self.0.away_team_id).await
+    }
+
+    /// Final home score, identified and unidentified goals together
+    async fn home_score(&self) -> i32 {
+        self.0.home_score_total()
+    }
+
+    async fn away_score(&self) -> i32 {
+        self.0.away_score_total()
+    }
+
+    /// Home goals without a score event
+    async fn home_score_unidentified(&self) -> i32 {
+        self.0.home_score_unidentified
+    }
+
+    async fn away_score_unidentified(&self) -> i32 {
+        self.0.away_score_unidentified
+    }
+
+    async fn date(&self) -> Option<&str> {
+        self.0.match_date.as_deref()
+    }
+
+    /// `scheduled`, `in_progress`, `finished` or `cancelled`
+    async fn status(&self) -> &str {
+        &self.0.status
+    }
+
+    async fn game_type(&self) -> &str {
+        &self.0.game_type
+    }
+
+    async fn arena(&self) -> Option<&str> {
+        self.0.arena_name.as_deref()
+    }
+
+    async fn attendance(&self) -> Option<i64> {
+        self.0.attendance
+    }
+
+    /// Identified goals in game order
+    async fn score_events(&self, ctx: &Context<'_>) -> Result<Vec<ScoreEvent>> {
+        Ok(matches::get_score_events(db(ctx), self.0.id)
+            .await
+            .map_err(db_error)?
+            .into_iter()
+            .map(ScoreEvent)
+            .collect())
+    }
+}
+
+pub struct ScoreEvent(ScoreEventEntity);
+
+#[Object]
+impl ScoreEvent {
+    async fn id(&self) -> i64 {
+        self.0.id
+    }
+
+    async fn team(&self, ctx: &Context<'_>) -> Result<Option<Team>> {
+        team(db(ctx), self.0.team_id).await
+    }
+
+    async fn scorer(&self, ctx: &Context<'_>) -> Result<Option<Player>> {
+        player(db(ctx), self.0.scorer_id).await
+    }
+
+    async fn assist1(&self, ctx: &Context<'_>) -> Result<Option<Player>> {
+        player(db(ctx), self.0.assist1_id).await
+    }
+
+    async fn assist2(&self, ctx: &Context<'_>) -> Result<Option<Player>> {
+        player(db(ctx), self.0.assist2_id).await
+    }
+
+    /// 1-3 regulation, 4 overtime, 5 shootout
+    async fn period(&self) -> i32 {
+        self.0.period
+    }
+
+    async fn time_minutes(&self) -> Option<i32> {
+        self.0.time_minutes
+    }
+
+    async fn time_seconds(&self) -> Option<i32> {
+        self.0.time_seconds
+    }
+
+    async fn goal_type(&self) -> Option<&str> {
+        self.0.goal_type.as_deref()
+    }
+
+    async fn video_url(&self) -> Option<String> {
+        self.0.video_link()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures(
+            path = "service/fixtures",
+            scripts("events", "seasons", "teams", "team_participations")
+        )
+    )]
+    async fn test_nested_query(pool: SqlitePool) {
+        matches::create_match(
+            &pool,
+            matches::CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: 3,
+                away_score_unidentified: 1,
+                match_date: Some("2024-05-10".to_string()),
+                status: "finished".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+        let schema = build_schema(pool);
+        let response = schema
+            .execute(
+                "{ season(id: 1) { year matches { homeScore awayScore homeTeam { id } scoreEvents { id } } } }",
+            )
+            .await;
+        assert!(response.errors.is_empty(), "{:?}", response.errors);
+        let data = response.data.into_json().unwrap();
+        let game = &data["season"]["matches"][0];
+        assert_eq!(game["homeScore"], 3);
+        assert_eq!(game["awayScore"], 1);
+        assert_eq!(game["homeTeam"]["id"], 1);
+        assert!(game["scoreEvents"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_paging() {
+        assert_eq!(paging(None, None), (1, DEFAULT_PAGE_SIZE));
+        assert_eq!(paging(Some(0), Some(10_000)), (1, MAX_PAGE_SIZE));
+        assert_eq!(paging(Some(3), Some(20)), (3, 20));
+    }
+}
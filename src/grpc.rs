@@ -0,0 +1,355 @@
+//! gRPC stats service for internal tooling
+//!
+//! Mirrors the matches, standings and player stats pages with the typed
+//! messages of `proto/hockey/v1/stats.proto`, so tools written in other
+//! languages can generate a client. Listens on its own port when `GRPC_PORT`
+//! is set; every call must carry `authorization: Bearer <GRPC_TOKEN>`.
+
+use std::net::SocketAddr;
+
+use sqlx::SqlitePool;
+use tonic::{service::Interceptor, Request, Response, Status};
+
+use crate::auth::signing::constant_time_compare;
+use crate::common::pagination::SortOrder;
+use crate::service::matches::{self, MatchEntity, MatchFilters, ScoreEventEntity};
+use crate::service::players;
+use crate::service::standings::{self, TeamStandingEntity};
+
+pub mod proto {
+    tonic::include_proto!("hockey.v1");
+}
+
+use proto::stats_server::{Stats, StatsServer};
+
+/// Page size of `ListMatches` when none is requested
+const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// Largest page `ListMatches` returns
+const MAX_PAGE_SIZE: usize = 200;
+
+pub struct StatsService {
+    db: SqlitePool,
+}
+
+impl StatsService {
+    pub fn new(db: SqlitePool) -> Self {
+        Self { db }
+    }
+}
+
+/// Log a database error and hide its details from the caller
+fn db_error(e: sqlx::Error) -> Status {
+    tracing::error!("gRPC call failed: {}", e);
+    Status::internal("Database error")
+}
+
+/// 1-based page and page size, clamped to `MAX_PAGE_SIZE`
+fn paging(page: u32, page_size: u32) -> (usize, usize) {
+    let page = (page as usize).max(1);
+    let page_size = match page_size as usize {
+        0 => DEFAULT_PAGE_SIZE,
+        size => size.min(MAX_PAGE_SIZE),
+    };
+    (page, page_size)
+}
+
+impl From<MatchEntity> for proto::Match {
+    fn from(game: MatchEntity) -> Self {
+        Self {
+            id: game.id,
+            season_id: game.season_id,
+            home_score: game.home_score_total(),
+            away_score: game.away_score_total(),
+            season_name: game.season_name,
+            event_name: game.event_name,
+            home_team_id: game.home_team_id,
+            home_team_name: game.home_team_name,
+            away_team_id: game.away_team_id,
+            away_team_name: game.away_team_name,
+            home_score_unidentified: game.home_score_unidentified,
+            away_score_unidentified: game.away_score_unidentified,
+            date: game.match_date,
+            status: game.status,
+            game_type: game.game_type,
+            arena: game.arena_name,
+            attendance: game.attendance,
+            score_events: Vec::new(),
+        }
+    }
+}
+
+impl From<ScoreEventEntity> for proto::ScoreEvent {
+    fn from(event: ScoreEventEntity) -> Self {
+        Self {
+            id: event.id,
+            team_id: event.team_id,
+            scorer_id: event.scorer_id,
+            scorer_name: event.scorer_name,
+            assist1_id: event.assist1_id,
+            assist1_name: event.assist1_name,
+            assist2_id: event.assist2_id,
+            assist2_name: event.assist2_name,
+            period: event.period,
+            time_minutes: event.time_minutes,
+            time_seconds: event.time_seconds,
+            goal_type: event.goal_type,
+        }
+    }
+}
+
+impl From<TeamStandingEntity> for proto::TeamStanding {
+    fn from(standing: TeamStandingEntity) -> Self {
+        Self {
+            points: standing.points(),
+            team_id: standing.team_id,
+            team_name: standing.team_name,
+            group_id: standing.group_id,
+            games: standing.games,
+            wins: standing.wins,
+            overtime_wins: standing.overtime_wins,
+            ties: standing.ties,
+            overtime_losses: standing.overtime_losses,
+            losses: standing.losses,
+            goals_for: standing.goals_for,
+            goals_against: standing.goals_against,
+        }
+    }
+}
+
+impl From<players::PlayerSeasonStats> for proto::PlayerSeasonStats {
+    fn from(stats: players::PlayerSeasonStats) -> Self {
+        Self {
+            season_id: stats.season_id,
+            season_year: stats.season_year,
+            season_display_name: stats.season_display_name,
+            event_id: stats.event_id,
+            event_name: stats.event_name,
+            goals: stats.goals,
+            assists: stats.assists,
+            points: stats.points,
+            plus_minus: stats.plus_minus,
+            faceoffs_won: stats.faceoffs_won,
+            faceoffs_lost: stats.faceoffs_lost,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl Stats for StatsService {
+    async fn list_matches(
+        &self,
+        request: Request<proto::ListMatchesRequest>,
+    ) -> Result<Response<proto::ListMatchesResponse>, Status> {
+        let request = request.into_inner();
+        let (page, page_size) = paging(request.page, request.page_size);
+        let filters = MatchFilters {
+            season_id: request.season_id,
+            team_id: request.team_id,
+            status: request.status,
+            date_from: None,
+            date_to: None,
+            coverage: None,
+            arena_id: None,
+            game_type: None,
+            group_id: None,
+            date_unknown: false,
+        };
+        let result = matches::get_matches(
+            &self.db,
+            &filters,
+            &matches::SortField::Date,
+            &SortOrder::Asc,
+            &[],
+            page,
+            page_size,
+            None,
+        )
+        .await
+        .map_err(db_error)?;
+
+        Ok(Response::new(proto::ListMatchesResponse {
+            total: result.total as u64,
+            total_pages: result.total_pages as u64,
+            matches: result.items.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    async fn get_match(
+        &self,
+        request: Request<proto::GetMatchRequest>,
+    ) -> Result<Response<proto::Match>, Status> {
+        let id = request.into_inner().id;
+        let game = matches::get_match_by_id(&self.db, id)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| Status::not_found("Match not found"))?;
+        let score_events = matches::get_score_events(&self.db, id)
+            .await
+            .map_err(db_error)?;
+
+        let mut game = proto::Match::from(game);
+        game.score_events = score_events.into_iter().map(Into::into).collect();
+        Ok(Response::new(game))
+    }
+
+    async fn get_standings(
+        &self,
+        request: Request<proto::GetStandingsRequest>,
+    ) -> Result<Response<proto::GetStandingsResponse>, Status> {
+        let standings = standings::get_season_standings(&self.db, request.into_inner().season_id)
+            .await
+            .map_err(db_error)?;
+
+        Ok(Response::new(proto::GetStandingsResponse {
+            standings: standings.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    async fn get_player_stats(
+        &self,
+        request: Request<proto::GetPlayerStatsRequest>,
+    ) -> Result<Response<proto::GetPlayerStatsResponse>, Status> {
+        let player_id = request.into_inner().player_id;
+        let player = players::get_player_by_id(&self.db, player_id)
+            .await
+            .map_err(db_error)?
+            .ok_or_else(|| Status::not_found("Player not found"))?;
+        let seasons = players::get_player_season_stats(&self.db, player_id)
+            .await
+            .map_err(db_error)?;
+
+        Ok(Response::new(proto::GetPlayerStatsResponse {
+            player_id,
+            player_name: player.name,
+            seasons: seasons.into_iter().map(Into::into).collect(),
+        }))
+    }
+}
+
+/// Rejects calls without `authorization: Bearer <token>`
+#[derive(Clone)]
+struct TokenCheck {
+    token: String,
+}
+
+impl Interceptor for TokenCheck {
+    fn call(&mut self, request: Request<()>) -> Result<Request<()>, Status> {
+        let provided = request
+            .metadata()
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+        match provided {
+            Some(provided) if constant_time_compare(provided, &self.token) => Ok(request),
+            _ => Err(Status::unauthenticated("Invalid or missing token")),
+        }
+    }
+}
+
+/// Start the gRPC server on `addr`
+pub fn spawn(db: SqlitePool, addr: SocketAddr, token: String) {
+    let service = StatsServer::with_interceptor(StatsService::new(db), TokenCheck { token });
+    tokio::spawn(async move {
+        if let Err(e) = tonic::transport::Server::builder()
+            .add_service(service)
+            .serve(addr)
+            .await
+        {
+            tracing::error!("gRPC server stopped: {}", e);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_check() {
+        let mut check = TokenCheck {
+            token: "secret".to_string(),
+        };
+        assert!(check.call(Request::new(())).is_err());
+
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("authorization", "Bearer wrong".parse().unwrap());
+        assert!(check.call(request).is_err());
+
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("authorization", "Bearer secret".parse().unwrap());
+        assert!(check.call(request).is_ok());
+    }
+
+    #[test]
+    fn test_paging() {
+        assert_eq!(paging(0, 0), (1, DEFAULT_PAGE_SIZE));
+        assert_eq!(paging(2, 10_000), (2, MAX_PAGE_SIZE));
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures(
+            path = "service/fixtures",
+            scripts("events", "seasons", "teams", "team_participations")
+        )
+    )]
+    async fn test_match_calls(pool: SqlitePool) {
+        let id = matches::create_match(
+            &pool,
+            matches::CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: 2,
+                away_score_unidentified: 3,
+                match_date: Some("2024-05-10".to_string()),
+                status: "finished".to_string(),
+                arena_id: None,
+                attendance: Some(8000),
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        let service = StatsService::new(pool);
+
+        let list = service
+            .list_matches(Request::new(proto::ListMatchesRequest {
+                season_id: Some(1),
+                ..Default::default()
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(list.total, 1);
+        assert_eq!(list.matches[0].id, id);
+
+        let game = service
+            .get_match(Request::new(proto::GetMatchRequest { id }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!((game.home_score, game.away_score), (2, 3));
+        assert_eq!(game.attendance, Some(8000));
+
+        let missing = service
+            .get_match(Request::new(proto::GetMatchRequest { id: id + 1 }))
+            .await
+            .unwrap_err();
+        assert_eq!(missing.code(), tonic::Code::NotFound);
+
+        let standings = service
+            .get_standings(Request::new(proto::GetStandingsRequest { season_id: 1 }))
+            .await
+            .unwrap()
+            .into_inner()
+            .standings;
+        assert_eq!(standings[0].team_id, 2);
+        assert_eq!(standings[0].wins, 1);
+    }
+}
@@ -5,14 +5,37 @@ nav-players = Hráči
 nav-events = Události
 nav-seasons = Sezóny
 nav-matches = Zápasy
+nav-referees = Rozhodčí
+nav-staff = Realizační tým
+nav-arenas = Stadiony
 nav-management = Správa
 nav-countries = Země
+nav-tasks = Úkoly
+nav-notifications = Oznámení
 
 # User menu
 user-logout = Odhlásit se
 user-language = Jazyk
 user-signed-in-as = Přihlášen jako
 
+# Profile
+profile-title = Profil
+profile-description = Údaje o vašem účtu a nedávná přihlášení
+profile-name = Jméno
+profile-email = E-mail
+profile-recent-logins = Nedávná přihlášení
+profile-recent-logins-hint = Poslední pokusy o přihlášení k vašemu účtu. Pokud některý nepoznáváte, kontaktujte administrátora.
+profile-login-time = Čas
+profile-login-result = Výsledek
+profile-login-ip = IP adresa
+profile-login-user-agent = Prohlížeč
+profile-login-success = Přihlášeno
+profile-login-failed = Neúspěšné
+profile-login-wrong-password = Špatné heslo
+profile-login-new-ip = Nová adresa
+profile-no-logins-title = Žádná přihlášení
+profile-no-logins-message = Zde se zobrazí pokusy o přihlášení.
+
 # Dashboard
 dashboard-title = Přehled
 dashboard-subtitle = Vítejte v aplikaci pro správu hokeje. Toto je hlavní přehled, kde můžete vidět souhrn vašich hokejových dat.
@@ -20,6 +43,9 @@ dashboard-stats-total-teams = Celkem týmů
 dashboard-stats-active-players = Aktivních hráčů
 dashboard-stats-ongoing-seasons = Probíhajících sezón
 dashboard-stats-total-events = Celkem událostí
+dashboard-stats-identified-goals = Identifikované góly
+dashboard-this-week = tento týden
+dashboard-last-week = Minulý týden
 dashboard-recent-activity = Nedávná aktivita
 dashboard-quick-actions = Rychlé akce
 dashboard-no-activity = Žádná nedávná aktivita k zobrazení.
@@ -70,6 +96,85 @@ teams-back-to-list = Zpět na Týmy
 teams-add-to-season = Přidat do sezóny
 teams-no-participations = Žádné účasti v sezónách
 teams-no-participations-help = Tento tým ještě nebyl přidán do žádné sezóny. Přidejte tým do sezóny pomocí tlačítka výše.
+teams-head-coach = Hlavní trenér
+teams-export = Exportovat historii
+teams-import = Importovat tým
+teams-import-title = Import historie týmu
+teams-import-help = Nahrajte soubor s historií týmu exportovaný z jiné instance. Ve výchozím režimu se existující týmy, události, sezóny a hráči spárují podle názvu a použijí se znovu a již existující zápasy se přeskočí. Režim sloučení navíc rozpozná dříve importované záznamy podle jejich externích ID a aktualizuje je na hodnoty ze souboru.
+teams-import-file = Soubor s historií (.json)
+teams-import-submit = Importovat
+teams-import-done = Historie týmu importována
+teams-import-team-created = Vytvořen nový tým
+teams-import-team-matched = Sloučeno do existujícího týmu
+teams-import-mode = Režim importu
+teams-import-mode-add = Pouze doplnit chybějící záznamy (párovat podle názvu)
+teams-import-mode-merge = Sloučit podle externího ID (aktualizovat existující záznamy)
+teams-import-entity = Záznamy
+teams-import-created = Vytvořeno
+teams-import-updated = Aktualizováno
+teams-import-unchanged = Beze změny
+teams-import-row-teams = Týmy
+teams-import-row-events = Události
+teams-import-row-seasons = Sezóny
+teams-import-row-participations = Účasti v sezónách
+teams-import-row-players = Hráči
+teams-import-row-contracts = Záznamy soupisky
+teams-import-row-matches = Zápasy
+teams-import-row-goals = Góly
+teams-import-open-team = Otevřít tým
+
+# Arenas
+arenas-title = Stadiony
+arenas-description = Správa stadionů, na kterých se hrají zápasy.
+arenas-create = + Nový stadion
+arenas-entity = stadion
+arenas-name-label = Název stadionu
+arenas-name-placeholder = Zadejte název stadionu...
+arenas-search-placeholder = Název stadionu nebo město...
+arenas-city = Město
+arenas-city-placeholder = Zadejte město...
+arenas-capacity = Kapacita
+arenas-create-title = Vytvořit stadion
+arenas-create-submit = Vytvořit stadion
+arenas-edit-title = Upravit stadion
+arenas-created = Stadion byl úspěšně vytvořen
+arenas-updated = Stadion byl úspěšně upraven
+arenas-deleted = Stadion byl úspěšně smazán
+arenas-matches = Zápasy
+arenas-back-to-list = Zpět na stadiony
+
+# Referees
+referees-title = Rozhodčí
+referees-description = Správa hlavních rozhodčích a čárových sudích, kteří řídí zápasy.
+referees-create = + Nový rozhodčí
+referees-entity = rozhodčí
+referees-name-label = Jméno
+referees-name-placeholder = Zadejte jméno rozhodčího...
+referees-create-title = Vytvořit rozhodčího
+referees-create-submit = Vytvořit rozhodčího
+referees-edit-title = Upravit rozhodčího
+referees-created = Rozhodčí byl úspěšně vytvořen
+referees-updated = Rozhodčí byl úspěšně upraven
+referees-deleted = Rozhodčí byl úspěšně smazán
+referees-matches = Zápasy
+
+# Staff
+staff-title = Realizační tým
+staff-description = Správa trenérů a generálních manažerů s kontrakty u týmů.
+staff-create = + Nový člen týmu
+staff-entity = člen realizačního týmu
+staff-name-label = Jméno
+staff-name-placeholder = Zadejte jméno člena týmu...
+staff-create-title = Vytvořit člena realizačního týmu
+staff-create-submit = Vytvořit člena
+staff-edit-title = Upravit člena realizačního týmu
+staff-created = Člen realizačního týmu byl úspěšně vytvořen
+staff-updated = Člen realizačního týmu byl úspěšně upraven
+staff-deleted = Člen realizačního týmu byl úspěšně smazán
+staff-contracts = Kontrakty
+staff-role-head-coach = Hlavní trenér
+staff-role-assistant-coach = Asistent trenéra
+staff-role-general-manager = Generální manažer
 
 # Players
 players-title = Hráči
@@ -191,6 +296,21 @@ seasons-remove-team = Odebrat tým
 seasons-confirm-remove-team-1 = Opravdu chcete odebrat
 seasons-confirm-remove-team-2 = z této sezóny?
 
+# Playoffs
+playoffs-title = Play-off
+playoffs-add-series = Přidat sérii
+playoffs-create-title = Vytvořit sérii play-off
+playoffs-empty = Pro tuto sezónu zatím nebyly vytvořeny žádné série play-off
+playoffs-round = Kolo
+playoffs-best-of = Na vítězství z
+playoffs-higher-seed = Výše nasazený (domácí led)
+playoffs-lower-seed = Níže nasazený
+playoffs-seed = Nasazení
+playoffs-add-game = Přidat zápas
+playoffs-confirm-delete = Opravdu chcete smazat tuto sérii? Její zápasy zůstanou zachovány jako běžné zápasy.
+playoffs-generate-from-seeds = Vytvořit podle nasazení
+playoffs-generate-hint = Vytvoří 1. kolo podle nasazení týmů níže: nejvýše nasazený proti nejníže nasazenému, nejvýše nasazené týmy postupují bez boje, pokud pavouk není plný.
+
 # Matches
 matches-title = Zápasy
 matches-description = Správa a zobrazení všech hokejových zápasů a her.
@@ -210,10 +330,16 @@ matches-filter-by-team = Filtrovat podle týmu
 matches-filter-by-status = Filtrovat podle stavu
 matches-filter-date-from = Datum od
 matches-filter-date-to = Datum do
+matches-filter-date-unknown = Pouze zápasy s neznámým datem
+matches-date-unknown = Datum neznámé
 matches-status-scheduled = Naplánovaný
 matches-status-in-progress = Probíhá
 matches-status-finished = Ukončený
 matches-status-cancelled = Zrušený
+matches-status-not-cancelled = Vše kromě zrušených
+matches-defaults-applied = Zobrazeny výchozí filtry z nastavení.
+matches-show-all = Zobrazit všechny zápasy
+matches-share-image = Obrázek ke sdílení
 matches-home-team = Domácí tým
 matches-away-team = Hostující tým
 matches-date = Datum
@@ -250,7 +376,8 @@ matches-edit-submit = Uložit změny
 matches-view = Zobrazit zápas
 matches-vs = vs
 matches-date-time-label = Datum a čas
-matches-location-placeholder = Zadejte místo konání
+matches-no-arena = Bez stadionu
+matches-attendance = Návštěva
 matches-notes = Poznámky
 matches-notes-placeholder = Další poznámky k zápasu
 matches-back-to-list = Zpět na zápasy
@@ -263,15 +390,64 @@ matches-power-play = Přesilová hra
 matches-short-handed = Oslabení
 matches-penalty-shot = Trestné střílení
 matches-empty-net = Prázdná branka
+matches-include-goalies = Včetně brankářů
+matches-scoring-group-other = Ostatní hráči
+matches-quick-create-new = Nový
+matches-quick-create-select-season = Nejprve vyberte ročník; nový tým bude přidán do ročníku zápasu
+matches-quick-create-team-hint = Tým bude přidán do ročníku vybraného pro zápas.
 matches-own-goal = Vlastní gól
 matches-overtime = Prodloužení
 matches-shootout = Nájezdy
+matches-goals = Góly
+matches-shots-on-goal = Střely na branku
+matches-shooting-percentage = Úspěšnost střelby
+matches-shots-edit = Upravit střely
+matches-shots-edit-title = Střely na branku
+matches-shots-hint = Zadejte střely na branku za každou třetinu. Pokud střely nebyly zaznamenány, nechte pole prázdné.
+matches-shots-empty = Pro tento zápas nejsou zaznamenány žádné střely na branku.
+matches-shots-games-recorded = zápasů se zaznamenanými střelami
+matches-lineups = Sestavy
+matches-lineup-edit = Upravit sestavu
+matches-lineup-edit-title = Nastoupivší sestava
+matches-lineup-hint = Zaškrtněte hráče, kteří v zápase nastoupili. Po uložení sestavy lze jako střelce a asistenty vybrat pouze nastoupivší hráče.
+matches-lineup-empty = Sestava není zaznamenána
+matches-lineup-no-roster = Tento tým nemá pro sezónu zápasu soupisku. Nejprve přidejte hráče na stránce soupisky.
+matches-lineup-dressed = nastoupilo
+matches-lineup-select-all = Vybrat vše
+matches-officials = Rozhodčí
+matches-officials-edit = Upravit rozhodčí
+matches-officials-edit-title = Rozhodčí zápasu
+matches-officials-hint = Přiřaďte až dva hlavní rozhodčí a dva čárové sudí. Nové rozhodčí přidáte na stránce Rozhodčí.
+matches-officials-empty = Rozhodčí nejsou přiřazeni
+matches-staff = Realizační týmy
+matches-staff-empty = Žádný realizační tým
+matches-officials-referee = Hlavní rozhodčí
+matches-officials-linesman = Čárový sudí
+matches-officials-none = — Žádný —
+matches-officials-no-referees = Zatím nejsou evidováni žádní rozhodčí.
 matches-filter-season = Sezóna
 matches-filter-team = Tým
 matches-filter-status = Status
 matches-all-seasons = Všechny sezóny
 matches-all-teams = Všechny týmy
 matches-all-statuses = Všechny statusy
+matches-filter-coverage = Pokrytí gólů
+matches-all-coverage = Všechny úrovně pokrytí
+matches-filter-arena = Stadion
+matches-all-arenas = Všechny stadiony
+matches-game-type = Typ zápasu
+matches-all-game-types = Všechny typy zápasů
+matches-filter-group = Skupina
+matches-all-groups = Všechny skupiny
+matches-game-type-group = Základní skupina
+matches-game-type-playoff = Play-off
+matches-game-type-relegation = Baráž
+matches-game-type-exhibition = Přátelský zápas
+matches-coverage-full = Plně identifikováno
+matches-coverage-partial = Částečně identifikováno
+matches-coverage-aggregate = Pouze souhrn
+matches-coverage-none = Bez gólů
+matches-coverage-hint = Kolik gólů má zaznamenaného střelce
 
 # Management / Countries
 management-title = Správa
@@ -289,6 +465,74 @@ management-countries-empty-search = Zkuste upravit vyhledávací podmínky.
 management-countries-empty-message = Zde jsou zobrazeny všechny země ze systémové databáze.
 management-loading = Načítání
 
+# Reports
+reports-reconciliation-title = Kontrola statistik
+reports-reconciliation-description = Porovnání ručně zadaných kariérních statistik s hodnotami odvozenými ze zaznamenaných gólů.
+reports-reconciliation-card-description = Najděte hráče, jejichž ruční statistiky nesouhlasí se zaznamenanými góly a asistencemi
+reports-reconciliation-empty-title = Žádné nesrovnalosti
+reports-reconciliation-empty-message = Ruční statistiky a zaznamenané góly se shodují u všech hráčů.
+reports-show-discrepancies = Pouze nesrovnalosti
+reports-show-all = Zobrazit vše
+reports-manual = Ručně
+reports-derived = Ze zaznamenaných gólů
+reports-status-matching = Shoda
+reports-status-unidentified = Neidentifikovaný zbytek
+reports-status-conflict = Konflikt
+reports-status-missing-manual = Chybí ruční záznam
+reports-fix-manual = Upravit ruční statistiky
+reports-fix-derived = Zkontrolovat góly
+# System status
+status-title = Stav systému
+status-description = Úložiště databáze a výsledky noční údržby (checkpoint WAL, statistiky pro plánovač dotazů a zálohy).
+status-card-description = Velikost databáze, plán údržby a výsledky záloh
+status-run-now = Spustit údržbu nyní
+status-run-succeeded = Údržba dokončena
+status-run-failed = Údržba skončila s chybami
+status-journal-mode = Režim žurnálu
+status-database-size = Velikost databáze
+status-free-space = Uvolnitelné místo
+status-next-run = Příští plánované spuštění
+status-backups = Zálohy
+status-backups-kept = uchovávaných
+status-backups-disabled = Vypnuto (nastavte BACKUP_DIR)
+status-recent-runs = Poslední běhy
+status-no-runs-title = Údržba zatím neproběhla
+status-no-runs-message = Úloha běží každou noc; tlačítkem „Spustit údržbu nyní“ ji spustíte okamžitě.
+status-run-started = Zahájeno
+status-run-task = Úloha
+status-run-result = Výsledek
+status-run-duration = Trvání
+status-run-detail = Podrobnosti
+status-task-checkpoint = Checkpoint WAL
+status-task-optimize = Optimalizace
+status-task-backup = Záloha
+status-result-ok = OK
+status-result-failed = Chyba
+status-download-snapshot = Stáhnout databázi
+status-snapshot-failed = Snímek databáze se nepodařilo vytvořit
+# Slow queries
+slow-queries-title = Pomalé dotazy
+slow-queries-description = Databázové příkazy, které od spuštění serveru trvaly déle než nastavený limit. Pomáhají rozhodnout, kam přidat indexy.
+slow-queries-card-description = Najděte databázové příkazy, které potřebují index
+slow-queries-threshold = Limit:
+slow-queries-disabled = Zaznamenávání pomalých dotazů je vypnuté (SLOW_QUERY_MS=0).
+slow-queries-clear = Vymazat
+slow-queries-cleared = Záznam pomalých dotazů byl vymazán
+slow-queries-empty-title = Žádné pomalé dotazy
+slow-queries-empty-message = Od spuštění serveru nebo posledního vymazání žádný příkaz nepřekročil limit.
+slow-queries-by-statement = Podle příkazu
+slow-queries-recent = Poslední
+slow-queries-statement = Příkaz
+slow-queries-count = Počet
+slow-queries-avg = Průměr
+slow-queries-max = Maximum
+slow-queries-last-route = Naposledy
+slow-queries-time = Čas (UTC)
+slow-queries-route = Požadavek
+slow-queries-duration = Trvání
+slow-queries-rows = Řádky
+slow-queries-background = Úloha na pozadí
+
 # Sign In
 signin-title = Přihlaste se do svého účtu
 signin-subtitle = Přístup do systému správy hokejové databáze
@@ -298,6 +542,11 @@ signin-button = Přihlásit se
 signin-signing-in = Přihlašování...
 signin-error-invalid-credentials = Neplatný e-mail nebo heslo
 signin-error-general = Něco se pokazilo. Zkuste to prosím znovu.
+signin-error-deactivated = Tento účet byl deaktivován. Požádejte správce o jeho opětovnou aktivaci.
+signin-error-unverified = Před přihlášením potvrďte svůj e-mail odkazem, který jsme vám poslali.
+signin-error-awaiting-approval = Váš účet čeká na schválení správcem.
+signin-demo-button = Vyzkoušet demo
+signin-demo-hint = Jen se rozhlížíte? Přihlaste se do demo účtu jen pro čtení, heslo není potřeba.
 
 # Common
 common-save = Uložit
@@ -338,6 +587,8 @@ common-remove = Odebrat
 form-name = Název
 form-country = Země
 form-photo = Fotografie
+form-slug = Adresa (slug)
+form-slug-help = Část adresy stránky. Ponechte prázdné pro vytvoření z názvu; odkazy se starou adresou se přesměrují.
 label-team = Tým
 label-season = Sezóna
 placeholder-select-team = Vyberte tým...
@@ -374,10 +625,19 @@ error-player-not-found = Hráč nenalezen
 error-event-not-found = Událost nenalezena
 error-season-not-found = Sezóna nenalezena
 error-team-not-found = Tým nenalezen
+error-referee-not-found = Rozhodčí nenalezen
+error-arena-not-found = Stadion nenalezen
 error-match-not-found = Zápas nenalezen
 error-score-event-not-found = Událost skóre nenalezena
 error-player-contract-not-found = Kontrakt hráče nenalezen
+error-staff-member-not-found = Člen realizačního týmu nenalezen
+error-staff-contract-not-found = Kontrakt člena realizačního týmu nenalezen
+error-failed-to-load-staff = Nepodařilo se načíst realizační tým
+error-failed-to-load-staff-member = Nepodařilo se načíst člena realizačního týmu
+error-failed-to-delete-staff-member = Nepodařilo se smazat člena realizačního týmu
 error-team-participation-not-found = Účast týmu nenalezena
+error-playoff-series-not-found = Série play-off nenalezena
+error-season-group-not-found = Skupina nenalezena
 error-statistics-not-found = Statistiky nenalezeny
 
 # Error messages - Failed to load
@@ -389,6 +649,11 @@ error-failed-to-load-seasons = Nepodařilo se načíst sezóny
 error-failed-to-load-season = Nepodařilo se načíst sezónu
 error-failed-to-load-teams = Nepodařilo se načíst týmy
 error-failed-to-load-team = Nepodařilo se načíst tým
+error-failed-to-load-referees = Nepodařilo se načíst rozhodčí
+error-failed-to-load-referee = Nepodařilo se načíst rozhodčího
+error-failed-to-load-officials = Nepodařilo se načíst rozhodčí zápasu
+error-failed-to-load-arenas = Nepodařilo se načíst stadiony
+error-failed-to-load-arena = Nepodařilo se načíst stadion
 error-failed-to-load-matches = Nepodařilo se načíst zápasy
 error-failed-to-load-match = Nepodařilo se načíst zápas
 error-failed-to-load-match-detail = Nepodařilo se načíst detail zápasu
@@ -397,15 +662,33 @@ error-failed-to-load-score-event = Nepodařilo se načíst událost skóre
 error-failed-to-load-player-scoring-data = Nepodařilo se načíst data bodování hráče
 error-failed-to-load-scoring-events = Nepodařilo se načíst události bodování
 error-failed-to-load-statistics = Nepodařilo se načíst statistiky
+error-failed-to-load-playoff-series = Nepodařilo se načíst sérii play-off
+error-failed-to-load-report = Nepodařilo se načíst přehled
+error-failed-to-load-status = Nepodařilo se načíst stav systému
+error-failed-to-load-logins = Nepodařilo se načíst historii přihlášení
+error-failed-to-load-lines = Nepodařilo se načíst formace
 
 # Error messages - Failed to delete
 error-failed-to-delete-player = Nepodařilo se smazat hráče
 error-failed-to-delete-event = Nepodařilo se smazat událost
 error-failed-to-delete-season = Nepodařilo se smazat sezónu
 error-failed-to-delete-team = Nepodařilo se smazat tým
+error-failed-to-delete-referee = Nepodařilo se smazat rozhodčího
+error-failed-to-delete-arena = Nepodařilo se smazat stadion
 error-failed-to-delete-match = Nepodařilo se smazat zápas
 error-failed-to-delete-score-event = Nepodařilo se smazat událost skóre
 error-failed-to-delete-statistics = Nepodařilo se smazat statistiky
+error-failed-to-delete-playoff-series = Nepodařilo se smazat sérii play-off
+error-failed-to-delete-season-group = Nepodařilo se smazat skupinu
+error-failed-to-assign-season-group = Nepodařilo se změnit skupinu týmu
+error-failed-to-load-history = Nepodařilo se načíst historii změn
+error-failed-to-set-placement = Nepodařilo se změnit umístění týmu
+error-season-award-not-found = Ocenění nebylo nalezeno
+error-failed-to-delete-season-award = Nepodařilo se odebrat ocenění
+error-failed-to-set-seed = Nepodařilo se změnit nasazení týmu
+error-failed-to-generate-bracket = Nepodařilo se vytvořit pavouka play-off
+error-invalid-settings = Neplatná hodnota nastavení
+error-failed-to-save-settings = Nepodařilo se uložit nastavení
 
 # Error messages - Failed to save/update
 error-failed-to-save-statistics = Nepodařilo se uložit statistiky
@@ -492,6 +775,7 @@ players-event-stats-save-changes = Uložit změny
 
 # Player Property Changes
 player-property-change-timeline = Kariérní Timeline
+player-transfers-title = Přestupy
 player-property-change-add = Přidat změnu
 player-property-change-edit = Upravit změnu
 player-property-change-delete = Smazat změnu
@@ -522,3 +806,911 @@ player-property-change-season-help = Volitelně propojte tuto změnu s konkrétn
 
 player-property-change-no-changes = Žádné události v kariéře
 player-property-change-no-changes-help = Sledujte důležité milníky jako změny pozice, přestupy a kariérní události
+
+# Demo mode
+demo-mode-read-only = Demo režim: změny se neukládají
+
+# Session expiry
+session-expiry-warning = Vaše relace brzy vyprší kvůli nečinnosti. Chcete-li pokračovat v práci, zůstaňte přihlášeni.
+session-stay-signed-in = Zůstat přihlášen
+
+# Roster
+roster-jersey-number = Číslo dresu
+roster-jersey-number-short = #
+roster-jersey-number-help = Nepovinné. Musí být v rámci soupisky jedinečné (0-99).
+roster-captaincy = Kapitánství
+roster-captaincy-none = Žádné
+roster-captain = Kapitán
+roster-alternate-captain = Asistent kapitána
+roster-captaincy-help = Tým má jednoho kapitána (C) a nejvýše dva asistenty (A).
+roster-edit-player-title = Upravit hráče na soupisce
+roster-lines-title = Formace
+roster-lines-hint = Přetáhněte hráče ze soupisky do útočných formací, obranných dvojic a pořadí brankářů. Přetažením na obsazené místo se hráči prohodí.
+roster-lines-unassigned = Mimo formace
+roster-lines-all-assigned = Všichni hráči soupisky jsou ve formacích
+roster-lines-forwards = Útočníci
+roster-lines-defense = Obránci
+roster-lines-goalies = Brankáři
+roster-lines-line = Formace
+roster-lines-pair = Dvojice
+roster-lines-empty-slot = Přetáhněte hráče sem
+roster-lines-remove = Odebrat z formace
+roster-staff-title = Realizační tým
+roster-staff-add = + Přidat člena
+roster-staff-add-title = Přidat člena realizačního týmu
+roster-staff-member = Člen týmu
+roster-staff-select-member = -- Vyberte člena týmu --
+roster-staff-role = Role
+roster-staff-none-registered = Zatím nejsou registrováni žádní členové. Nejprve je přidejte na stránce Realizační tým.
+roster-staff-empty = K tomuto týmu zatím není přiřazen žádný realizační tým
+roster-staff-remove = Odebrat
+roster-staff-remove-title = Odebrat člena týmu
+roster-staff-confirm-remove = Odebrat tohoto člena z realizačního týmu?
+roster-joined = Přišel
+roster-left = Odešel
+roster-transfer = Přestup
+roster-transfer-title = Přestup hráče
+roster-transfer-destination = Nový tým
+roster-transfer-select-destination = -- Vyberte tým --
+roster-transfer-date = Datum přestupu
+roster-transfer-note = Poznámka
+roster-transfer-no-destinations = V této sezóně nehraje žádný jiný tým.
+
+# Team calendar subscriptions
+calendar-subscriptions-title = Odběry kalendáře
+calendar-subscriptions-description = Soukromé adresy kalendáře, které si fanoušci přidají do své kalendářové aplikace a uvidí v ní zápasy týmu. Každou adresu lze zrušit samostatně.
+calendar-subscriptions-empty = Zatím žádné odběry kalendáře
+calendar-subscription-label = Popisek
+calendar-subscription-label-placeholder = Popisek, např. Zpravodaj fanklubu (nepovinné)
+calendar-subscription-url = Adresa kalendáře
+calendar-subscription-created-at = Vytvořeno
+calendar-subscription-last-used = Naposledy staženo
+calendar-subscription-never-used = Nikdy
+calendar-subscription-create = Vytvořit adresu
+calendar-subscription-subscribe = Odebírat
+calendar-subscription-revoke = Zrušit
+calendar-subscription-confirm-revoke = Kalendáře přihlášené přes tuto adresu přestanou dostávat aktualizace. Tuto akci nelze vrátit.
+calendar-subscription-created = Odběr kalendáře vytvořen
+calendar-subscription-revoked = Odběr kalendáře zrušen
+
+# Attendance
+attendance-title = Návštěvnost
+attendance-empty = Zatím není zaznamenána žádná návštěva
+attendance-matches = Zápasy s návštěvou
+attendance-total = Celkem
+attendance-average = Průměr
+attendance-overall = Celkem za vše
+attendance-arena = Stadion
+attendance-season = Sezóna
+
+# Match predictions
+predictions-title = Tipovačka
+predictions-description = Tipněte si vítěze nadcházejících zápasů. Tip lze měnit až do začátku zápasu a vyhodnotí se po jeho skončení.
+predictions-leaderboard = Žebříček
+predictions-leaderboard-description = Tipující seřazení podle správných tipů na odehrané zápasy sezóny. Nerozhodný konečný výsledek se počítá jako chybný tip.
+predictions-back = Zpět na tipovačku
+predictions-empty-title = Žádné nadcházející zápasy
+predictions-empty-message = Momentálně nejsou naplánované žádné zápasy k tipování.
+predictions-leaderboard-empty-title = Zatím žádné vyhodnocené tipy
+predictions-leaderboard-empty-message = Tipy se vyhodnotí po skončení zápasů.
+predictions-match = Zápas
+predictions-your-pick = Váš tip
+predictions-predictor = Tipující
+predictions-correct = Správně
+predictions-picks = Tipy
+predictions-accuracy = Úspěšnost
+predictions-saved = Tip uložen
+predictions-closed = Tipování na tento zápas je uzavřeno
+
+# Season groups and standings
+groups-title = Skupiny
+groups-add = Přidat skupinu
+groups-create-title = Vytvořit skupinu
+groups-name = Název skupiny
+groups-name-placeholder = např. Skupina A
+groups-empty = Sezóna není rozdělena do skupin.
+groups-all-teams = Všechny týmy
+groups-none = Bez skupiny
+groups-view-matches = Zápasy skupiny
+groups-confirm-delete = Opravdu chcete smazat tuto skupinu? Její týmy zůstanou v sezóně bez skupiny.
+standings-title = Tabulka
+standings-hint = Pouze odehrané zápasy základní skupiny.
+standings-team = Tým
+standings-games = Z
+standings-wins = V
+standings-ties = R
+standings-losses = P
+standings-goals = Skóre
+standings-goal-difference = +/-
+standings-points = B
+
+# Point systems
+events-point-system = Bodový systém
+events-point-system-classic = 2-1-0 (výhra, remíza, prohra)
+events-point-system-iihf = 3-2-1-0 (IIHF, prodloužení a nájezdy)
+standings-points-classic = Výhra má hodnotu 2 body, remíza 1 bod.
+standings-points-iihf = Výhra v základní hrací době má hodnotu 3 body, výhra v prodloužení nebo nájezdech 2 body a prohra v prodloužení nebo nájezdech 1 bod.
+standings-overtime-wins = VP
+standings-overtime-losses = PP
+
+# Change history
+history-title = Historie
+history-view = Historie
+history-empty = Zatím nebyly zaznamenány žádné změny.
+history-back-to-match = Zpět na zápas
+history-unknown-user = Neznámý uživatel
+history-field = Pole
+history-before = Před
+history-after = Po
+history-match-updated = Zápas upraven
+history-goal-created = Gól přidán
+history-goal-updated = Gól upraven
+history-goal-deleted = Gól smazán
+history-player-updated = Hráč upraven
+history-field-season = Sezóna
+history-field-home-team = Domácí tým
+history-field-away-team = Hostující tým
+history-field-home-score = Neidentifikované góly domácích
+history-field-away-score = Neidentifikované góly hostů
+history-field-match-date = Datum
+history-field-status = Stav
+history-field-game-type = Typ zápasu
+history-field-arena = Stadion
+history-field-attendance = Návštěvnost
+history-field-team = Tým
+history-field-scorer = Střelec
+history-field-first-assist = První asistence
+history-field-second-assist = Druhá asistence
+history-field-period = Třetina
+history-field-time = Čas
+history-field-goal-type = Typ gólu
+history-field-name = Jméno
+history-field-country = Země
+history-field-birth-date = Datum narození
+history-field-birth-place = Místo narození
+history-field-height = Výška (cm)
+history-field-weight = Váha (kg)
+history-field-position = Pozice
+history-field-shoots = Hůl
+history-field-photo = Fotografie
+history-field-video = Video
+
+# Roster rules
+roster-rules-title = Pravidla soupisky
+roster-rules-edit = Upravit pravidla soupisky
+roster-rules-updated = Pravidla soupisky byla úspěšně upravena
+roster-rules-no-limit = Bez omezení
+roster-rules-max-players = Maximální počet hráčů
+roster-rules-min-goalies = Minimální počet brankářů
+roster-rules-max-imports = Maximální počet zahraničních hráčů
+roster-rules-hint = Pravidla platí pro všechny týmy události. Zahraniční hráči jsou hráči z jiné země, než je země jejich týmu. Nad maximální počet nelze hráče na soupisku přidat ani přestoupit.
+roster-rules-compliant = Soupiska splňuje pravidla události
+roster-rules-not-compliant = Soupiska nesplňuje pravidla události
+
+# Placements and medals
+placements-final-placement = Umístění
+placements-none = Neumístěn
+placements-not-seeded = Nenasazen
+placements-medal-table = Medailové pořadí
+placements-medal-table-hint = Medaile získané jednotlivými týmy ve všech ročnících události. Konečná umístění se zadávají na stránce ročníku.
+placements-no-medals = Zatím nebyla zadána žádná konečná umístění
+placements-gold = Zlato
+placements-silver = Stříbro
+placements-bronze = Bronz
+placements-total = Celkem
+placements-medals = Medaile
+
+# Season awards
+awards-title = Ocenění
+awards-add = Přidat ocenění
+awards-empty = V této sezóně zatím nebylo uděleno žádné ocenění
+awards-award = Ocenění
+awards-player = Hráč
+awards-no-candidates = Nejprve přidejte hráče na soupisky sezóny
+awards-mvp = Nejužitečnější hráč
+awards-best-forward = Nejlepší útočník
+awards-best-defenseman = Nejlepší obránce
+awards-best-goalie = Nejlepší brankář
+awards-all-star = All-Star tým
+awards-confirm-delete = Opravdu chcete toto ocenění odebrat?
+awards-player-title = Ocenění
+
+# Milestones
+milestones-title = Milníky
+milestones-recent = Nedávné milníky
+milestones-hat-trick = Hattrick
+milestones-four-point-game = Zápas se 4+ body
+milestones-hundredth-goal = 100. gól v kariéře
+milestones-goals = gólů
+milestones-points = bodů
+
+# Settings
+settings-title = Nastavení
+settings-card-description = Výchozí filtry a další nastavení celé aplikace
+settings-match-defaults = Seznam zápasů
+settings-match-defaults-hint = Filtry použité při otevření seznamu zápasů bez filtrů v adrese, např. z postranního menu.
+settings-current-season = Aktuální sezóna (nejnovější)
+settings-saved = Nastavení uloženo
+
+# Leaders
+leaders-title = Nejlepší hráči
+leaders-view-all = Všichni hráči
+leaders-empty = V této sezóně zatím nebyly zaznamenány žádné góly.
+leaders-goals = Góly
+leaders-assists = Asistence
+leaders-points = Body
+leaders-goals-short = G
+leaders-assists-short = A
+leaders-points-short = B
+
+# Team season statistics
+team-stats-title = Statistiky sezón
+team-stats-season = Sezóna
+team-stats-goals-for = VG
+team-stats-goals-against = OG
+team-stats-power-play-goals = GP
+team-stats-short-handed-goals = GO
+team-stats-shots = Střely
+team-stats-shooting-percentage = Úsp%
+
+# Season rankings
+players-season-rankings = Pořadí v sezónách
+players-season-rankings-hint = Kde se hráč řadí mezi všemi hráči svých posledních sezón. Přátelské zápasy se nepočítají.
+players-season-rankings-players = hráčů
+players-season-rankings-top = Top
+players-season-rankings-goals = v gólech
+players-season-rankings-assists = v asistencích
+players-season-rankings-points = v bodech
+players-season-rankings-points-per-game = v bodech na zápas
+
+# On-ice players
+matches-on-ice = Na ledě
+matches-on-ice-title = Hráči na ledě
+matches-on-ice-hint = Zaškrtněte hráče, kteří byli na ledě při vstřelení gólu. Góly v přesilovce, z trestného střílení a z nájezdů se do plus/minus nezapočítávají.
+matches-on-ice-no-players = Pro tento tým nejsou k dispozici žádní hráči.
+player-scoring-plus-minus = Plus/minus
+error-failed-to-save-on-ice = Nepodařilo se uložit hráče na ledě
+
+# Roster comparison
+roster-diff-title = Porovnání soupisek
+roster-diff-open = Porovnat soupisky
+roster-diff-from = Dřívější sezóna
+roster-diff-to = Pozdější sezóna
+roster-diff-compare = Porovnat
+roster-diff-pick-seasons = Vyberte dvě různé sezóny k porovnání soupisek.
+roster-diff-added = Příchody
+roster-diff-departed = Odchody
+roster-diff-retained = Zůstali
+roster-diff-none = Nikdo
+roster-diff-came-from = z týmu
+roster-diff-went-to = nyní v týmu
+
+# Report builder
+report-builder-title = Tvorba sestav
+report-builder-card-description = Sestavujte vlastní přehledy gólů, zápasů a soupisek, ukládejte je a exportujte do CSV
+report-builder-description = Vyberte, co se má počítat, podle čeho seskupit a které řádky zahrnout. Výsledky lze uložit na později nebo stáhnout jako CSV.
+report-builder-new = Nová sestava
+report-builder-saved = Uložené sestavy:
+report-builder-source = Zdroj
+report-builder-source-goals = Góly
+report-builder-source-matches = Zápasy
+report-builder-source-rosters = Smlouvy na soupiskách
+report-builder-dimensions = Seskupit podle
+report-builder-measures = Hodnoty
+report-builder-season = Sezóna
+report-builder-team = Tým
+report-builder-game-type = Typ zápasu
+report-builder-date-from = Od data
+report-builder-date-to = Do data
+report-builder-any = Libovolný
+report-builder-sort = Řadit podle
+report-builder-sort-default = Pořadí seskupení
+report-builder-run = Spustit sestavu
+report-builder-pick-fields = Vyberte alespoň jedno pole pro seskupení nebo hodnotu a spusťte sestavu.
+report-builder-no-rows = Sestavě neodpovídají žádné řádky.
+report-builder-truncated = Zobrazeny jsou jen první řádky. Celý výsledek získáte exportem do CSV.
+report-builder-name = Název sestavy
+report-builder-save = Uložit sestavu
+report-builder-export-csv = Exportovat CSV
+report-builder-confirm-delete = Opravdu chcete smazat tuto uloženou sestavu?
+report-field-season = Sezóna
+report-field-event = Soutěž
+report-field-team = Tým
+report-field-scorer = Střelec
+report-field-period = Třetina
+report-field-goal-type = Typ gólu
+report-field-game-type = Typ zápasu
+report-field-month = Měsíc
+report-field-home-team = Domácí tým
+report-field-away-team = Hostující tým
+report-field-arena = Stadion
+report-field-status = Stav
+report-field-position = Pozice
+report-field-country = Země
+report-field-goals = Góly
+report-field-matches = Zápasy
+report-field-scorers = Různých střelců
+report-field-assisted-goals = Góly s asistencí
+report-field-total-attendance = Celková návštěvnost
+report-field-avg-attendance = Průměrná návštěvnost
+report-field-players = Hráči
+report-field-contracts = Smlouvy
+error-report-not-found = Sestava nenalezena
+error-failed-to-save-report = Nepodařilo se uložit sestavu
+error-failed-to-delete-report = Nepodařilo se smazat sestavu
+
+# Faceoffs
+matches-faceoffs = Vhazování
+matches-faceoffs-edit = Upravit vhazování
+matches-faceoffs-edit-title = Vhazování
+matches-faceoffs-hint = Zadejte vyhraná a prohraná vhazování každého hráče, který je vhazoval, obvykle centrů. Prázdné řádky se neukládají.
+matches-faceoffs-empty = Vhazování nejsou zaznamenána
+matches-faceoffs-player = Hráč
+matches-faceoffs-won = Vyhraná
+matches-faceoffs-lost = Prohraná
+matches-faceoffs-won-short = V
+matches-faceoffs-lost-short = P
+matches-faceoffs-percentage = Vh%
+player-scoring-faceoff-percentage = Úspěšnost vhazování
+team-stats-faceoff-percentage = Vh%
+
+# Goal videos
+matches-video-url = Odkaz na video
+matches-video-timestamp = Čas ve videu
+matches-video-play = Přehrát gól
+
+# Goal flags
+matches-game-winning-goal = Vítězný gól
+matches-game-winning-goal-short = VG
+matches-empty-net-in-shootout = Nájezdy se zahrávají proti brankáři, tento gól proto nemůže být do prázdné branky
+matches-empty-net-while-trailing = Střílející tým v tu chvíli prohrával, soupeř tedy neměl důvod odvolat brankáře
+
+# What's new
+changelog-title = Co je nového
+changelog-description = Záznamy, které se po aktualizaci jednou zobrazí každému uživateli, aby zapisovatelé věděli o nových funkcích.
+changelog-card-description = Pište poznámky k vydání, které uživatelé uvidí po aktualizaci
+changelog-whats-new = Co je nového
+changelog-dismiss = Rozumím
+changelog-create = Nový záznam
+changelog-edit = Upravit záznam
+changelog-version = Verze
+changelog-entry-title = Nadpis
+changelog-body = Text
+changelog-body-help = Prostý text; zalomení řádků se zachovají.
+changelog-created = Vytvořeno
+changelog-empty-title = Zatím žádné záznamy
+changelog-empty-message = Přidejte záznam a dejte uživatelům vědět, co se v této verzi změnilo.
+changelog-delete-title = Smazat záznam
+changelog-delete-confirm = Opravdu chcete tento záznam smazat? Uživatelům, kteří ho ještě neviděli, se již nezobrazí.
+changelog-created-toast = Záznam vytvořen
+changelog-updated-toast = Záznam upraven
+changelog-deleted-toast = Záznam smazán
+changelog-not-found = Záznam nenalezen
+changelog-load-failed = Nepodařilo se načíst záznamy
+changelog-save-failed = Záznam se nepodařilo uložit
+changelog-delete-failed = Záznam se nepodařilo smazat
+
+# Match results
+matches-result-overtime = PP
+matches-result-shootout = SN
+
+# Connection
+connection-offline = Jste offline. Změny se neuloží, dokud se připojení neobnoví.
+connection-server-unavailable = Server je nedostupný. Automaticky se zkouší znovu…
+connection-restored = Připojení obnoveno
+connection-retry-now = Zkusit znovu
+
+# Team form
+team-form-title = Forma
+team-form-hint = Posledních pět odehraných zápasů, nejnovější vpravo
+team-form-win = V
+team-form-overtime-win = VP
+team-form-draw = R
+team-form-overtime-loss = PP
+team-form-loss = P
+team-form-versus = vs
+
+# Home/away splits
+splits-title = Doma a venku
+splits-view = Doma/venku
+splits-home = Doma
+splits-away = Venku
+splits-venue = Prostředí
+splits-total = Celkem v kariéře
+splits-team-hint = Odehrané zápasy bez exhibic. Výhry a prohry zahrnují rozhodnutí v prodloužení a nájezdech.
+splits-player-hint = Góly a asistence podle toho, zda tým hráče hrál doma nebo venku. Exhibiční zápasy se nepočítají.
+splits-team-empty = Tento tým zatím nemá žádné odehrané zápasy.
+splits-player-empty = Tento hráč zatím nemá žádné góly ani asistence.
+splits-load-failed = Nepodařilo se načíst statistiky doma a venku
+
+# Tasks
+tasks-title = Úkoly
+tasks-description = Koordinujte archivní práci: připojte úkoly k týmům, hráčům, událostem, sezónám a zápasům, přiřaďte je a nastavte termíny.
+tasks-create = Nový úkol
+tasks-edit = Upravit úkol
+tasks-task = Úkol
+tasks-title-placeholder = např. Ověřit soupisku SWE 1998
+tasks-notes = Poznámky
+tasks-assignee = Řešitel
+tasks-unassigned = Nepřiřazeno
+tasks-due-date = Termín
+tasks-overdue = Po termínu
+tasks-attached-to = Připojeno k
+tasks-entity-deleted = Smazaný záznam
+tasks-scope-mine = Moje
+tasks-scope-open = Všechny otevřené
+tasks-scope-completed = Dokončené
+tasks-scope-all = Všechny
+tasks-complete = Označit jako hotové
+tasks-reopen = Znovu otevřít
+tasks-empty-title = Žádné úkoly
+tasks-empty-message = Není co dělat. Vytvořte úkol a koordinujte práci s ostatními.
+tasks-my-open = Moje otevřené úkoly
+tasks-view-all = Zobrazit všechny úkoly
+tasks-none-assigned = Nemáte přiřazené žádné otevřené úkoly.
+tasks-delete-title = Smazat úkol
+tasks-delete-confirm = Opravdu chcete tento úkol smazat?
+tasks-created-toast = Úkol vytvořen
+tasks-updated-toast = Úkol upraven
+tasks-completed-toast = Úkol označen jako hotový
+tasks-reopened-toast = Úkol znovu otevřen
+tasks-deleted-toast = Úkol smazán
+tasks-load-failed = Nepodařilo se načíst úkoly
+tasks-save-failed = Nepodařilo se uložit úkol
+tasks-delete-failed = Nepodařilo se smazat úkol
+tasks-not-found = Úkol nenalezen
+tasks-entity-not-found = Záznam, ke kterému má být úkol připojen, nebyl nalezen
+
+# Goal types
+goal-types-title = Typy gólů
+goal-types-hint = Identifikované góly podle herní situace. Neidentifikované góly a přípravné zápasy se nezapočítávají.
+goal-types-empty = Zatím žádné identifikované góly.
+goal-types-team = Tým
+goal-types-total = Celkem
+goal-types-unspecified = Typ nezaznamenán
+goal-types-even-strength-short = 5/5
+goal-types-power-play-short = PP
+goal-types-short-handed-short = OS
+goal-types-penalty-shot-short = TS
+goal-types-empty-net-short = PB
+
+# Comments
+comments-title = Komentáře
+comments-hint = Interní diskuse pro badatele. Zmiňte kolegu pomocí @ a části jeho e-mailu před @ (např. @jana.novakova), aby dostal oznámení.
+comments-empty = Zatím žádné komentáře.
+comments-placeholder = Napište komentář…
+comments-reply-placeholder = Napište odpověď…
+comments-markdown-help = Podporován je Markdown: **tučně**, *kurzíva*, [odkazy](https://…), seznamy a `kód`.
+comments-post = Přidat komentář
+comments-reply = Odpovědět
+comments-deleted-user = Smazaný uživatel
+comments-delete-title = Smazat komentář
+comments-delete-confirm = Smazat tento komentář i všechny odpovědi na něj?
+comments-posted-toast = Komentář přidán
+comments-deleted-toast = Komentář smazán
+comments-load-failed = Nepodařilo se načíst komentáře
+comments-save-failed = Nepodařilo se přidat komentář
+comments-delete-failed = Nepodařilo se smazat komentář
+comments-not-found = Komentář nenalezen
+
+# Notifications
+notifications-title = Oznámení
+notifications-empty = Nemáte žádná oznámení.
+notifications-mentioned-you = vás zmínil(a) v komentáři
+notifications-mark-all-read = Označit vše jako přečtené
+notifications-load-failed = Nepodařilo se načíst oznámení
+
+# Period distribution
+period-distribution-title = Góly podle třetin
+period-distribution-hint = Identifikované góly podle třetin. Nájezdy, neidentifikované góly a přátelská utkání se nepočítají.
+period-distribution-empty = Zatím žádné identifikované góly.
+period-distribution-goals = Góly
+period-distribution-home-away = Domácí:Hosté
+period-distribution-share = Podíl
+period-distribution-per-match = Na zápas
+
+# Duplicate report
+reports-duplicates-title = Duplicity
+reports-duplicates-description = Hráči, týmy a stadiony s velmi podobnými názvy bez ohledu na diakritiku, interpunkci a pořadí slov. Hráči narození v různých letech, týmy různých zemí a stadiony v různých městech se nepárují.
+reports-duplicates-card-description = Najít pravděpodobné duplicity hráčů, týmů a stadionů
+reports-duplicates-empty-title = Žádné duplicity
+reports-duplicates-empty-message = Žádní dva hráči, týmy ani stadiony nemají dostatečně podobné názvy.
+reports-duplicates-players = Hráči
+reports-duplicates-teams = Týmy
+reports-duplicates-arenas = Stadiony
+reports-duplicates-record = Záznam
+reports-duplicates-possible-duplicate = Možná duplicita
+reports-duplicates-similarity = Podobnost
+reports-duplicates-exact = Stejný název
+reports-duplicates-short-name = Zkratka
+reports-duplicates-birth-year = Narozen
+
+# Streaks
+streaks-title = Bodové série
+streaks-description = Po sobě jdoucí zápasy s bodem nebo gólem v pořadí podle data. Přátelská utkání a zápasy bez data se nepočítají a neidentifikované góly se nikomu nepřipisují.
+streaks-empty = Zatím žádné série.
+streaks-points = Bodová série
+streaks-goals = Gólová série
+streaks-longest = nejdelší
+streaks-games = Zápasy
+streaks-current-points = Aktuální bodové série
+streaks-longest-points = Nejdelší bodové série
+streaks-current-goals = Aktuální gólové série
+streaks-longest-goals = Nejdelší gólové série
+streaks-leaderboard-link = Žebříček sérií →
+streaks-load-failed = Nepodařilo se spočítat série
+
+# Data quality
+data-quality-title = Kvalita dat
+data-quality-description = Záznamy, které jsou neúplné nebo vypadají chybně. Každý řádek odkazuje na místo, kde je lze opravit.
+data-quality-card-description = Najít zápasy bez data, góly bez střelce, týmy bez názvu, duplicitní hráče a prázdné soupisky
+data-quality-empty-title = Není co opravovat
+data-quality-empty-message = Nebyly nalezeny žádné problémy s kvalitou dat.
+data-quality-undated-matches = Zápasy bez data
+data-quality-unscored-goals = Góly bez střelce
+data-quality-unnamed-teams = Týmy bez názvu
+data-quality-duplicate-players = Možní duplicitní hráči
+data-quality-empty-rosters = Soupisky bez hráčů
+data-quality-fix = Opravit
+data-quality-view-all = Zobrazit vše →
+data-quality-truncated = Zobrazeny jsou jen první záznamy.
+
+# Unidentified goals report
+reports-unidentified-title = Neidentifikované góly
+reports-unidentified-description = Zápasy, jejichž skóre stále obsahuje góly bez zaznamenaného střelce, času a třetiny, seřazené od nejvíce gólů. Zrušené zápasy nejsou uvedeny.
+reports-unidentified-card-description = Určit, které zápasy potřebují identifikaci gólů
+reports-unidentified-empty-title = Žádné neidentifikované góly
+reports-unidentified-empty-message = Všechny góly vybraných zápasů jsou identifikovány.
+reports-unidentified-goals = Neidentifikované góly
+reports-unidentified-matches = Zápasy
+reports-unidentified-match = Zápas
+reports-unidentified-identified = Identifikováno
+reports-unidentified-by-season = Podle sezóny
+reports-unidentified-by-team = Podle týmu
+reports-unidentified-identify = Identifikovat góly
+
+# Team merge
+team-merge-open = Sloučit
+team-merge-title = Sloučit tým
+team-merge-description = Přesune všechny účasti, soupisky, zápasy a góly tohoto týmu k jinému týmu a tento tým smaže. Jeho staré odkazy povedou na druhý tým.
+team-merge-into = Sloučit do
+team-merge-select-team = Vyberte tým
+team-merge-preview = Náhled
+team-merge-participations = Účasti v sezónách
+team-merge-matches = Zápasy
+team-merge-score-events = Góly
+team-merge-conflicts = Týmy nelze sloučit, dokud nebudou vyřešeny tyto konflikty.
+team-merge-shared-seasons = Oba týmy se účastnily těchto sezón:
+team-merge-head-to-head = Zápasy a série play-off mezi týmy
+team-merge-action = Sloučit
+team-merge-confirm = Tento tým bude smazán a vše, co je k němu zaznamenáno, bude patřit druhému týmu. Tuto akci nelze vrátit.
+team-merge-failed = Sloučení týmů se nezdařilo
+
+# Clone season
+seasons-clone = Klonovat
+seasons-clone-title = Klonovat sezónu
+seasons-clone-description = Vytvoří další sezónu této soutěže se stejnými skupinami a týmy.
+seasons-clone-teams = Týmy
+seasons-clone-copy-rosters = Kopírovat soupisky
+seasons-clone-not-copied = Zápasy, nasazení, konečná umístění, play-off a ocenění se nekopírují. Hráči přestoupivší během sezóny nebudou na soupiskách.
+seasons-clone-submit = Klonovat sezónu
+seasons-clone-failed = Klonování sezóny se nezdařilo
+
+# Roster import
+roster-import-open = Import CSV
+roster-import-title = Import soupisky
+roster-import-help = Nahrajte soubor CSV, jehož první řádek pojmenovává sloupce. Sloupec se jménem je povinný, sloupce s číslem a zemí (kód ISO nebo IOC) jsou volitelné. Hráči se párují podle jména a nenalezení hráči se vytvoří se zemí z řádku nebo se zemí týmu.
+roster-import-file = Soubor CSV
+roster-import-review = Zkontrolovat
+roster-import-matched = Spárováno
+roster-import-new = Nový hráč
+roster-import-skipped = Přeskočeno
+roster-import-on-roster = Již na soupisce
+roster-import-submit = Importovat hráče
+
+# Player import
+players-import = Import CSV
+players-import-title = Import hráčů
+players-import-help = Nahrajte soubor CSV, jehož první řádek pojmenovává sloupce: jméno, země (kód ISO nebo IOC), datum narození (RRRR-MM-DD nebo DD.MM.RRRR) a pozice (Forward, Defense, Goalie nebo zkratka jako C, LW či D). Již existující hráči se vypíší a znovu se nevytvoří.
+players-import-file = Soubor CSV
+players-import-submit = Importovat
+players-import-done = Import dokončen
+players-import-line = Řádek
+players-import-result = Výsledek
+players-import-created = Vytvořen
+players-import-duplicates = Již existující
+players-import-duplicate-of = Již existuje jako
+players-import-invalid = Neplatné
+
+# Match import
+match-import-title = Import zápasů
+match-import-card-description = Hromadné vytvoření odehraných zápasů ze souboru CSV nebo JSON
+match-import-description = Nahrajte soubor CSV nebo JSON s jedním odehraným zápasem na řádek: sezóna (ID nebo název), domácí a hostující tým (název nebo externí alias týmu, který se sezóny účastní), datum a konečné skóre. Pokud není platný každý řádek, neimportuje se nic. Skóre se uloží jako neidentifikované góly.
+match-import-api-hint = Stejný seznam ve formátu JSON lze odeslat na
+match-import-file = Soubor CSV nebo JSON
+match-import-submit = Importovat
+match-import-created = Vytvořené zápasy
+match-import-view-matches = Zobrazit zápasy
+match-import-rejected = Nic nebylo importováno. Opravte tyto řádky a nahrajte soubor znovu.
+match-import-line = Řádek
+match-import-season = Sezóna
+match-import-match = Zápas
+match-import-error = Problém
+
+# List CSV export
+list-export-csv = Exportovat CSV
+matches-export-goals = Exportovat góly (CSV)
+
+# Season workbook export
+season-export-xlsx = Exportovat XLSX
+season-export-rank = #
+season-export-group = Skupina
+season-export-scorers = Kanadské bodování
+season-export-player = Hráč
+season-export-teams = Týmy
+season-export-schedule = Rozpis zápasů
+season-export-home-score = Góly domácích
+season-export-away-score = Góly hostů
+season-export-arena = Stadion
+
+# Instance transfer
+instance-transfer-title = Přenos dat
+instance-transfer-card-description = Přesun všech dat do jiné instance jako jeden soubor JSON
+instance-transfer-description = Exportujte všechny hráče, týmy, události, sezóny, soupisky a zápasy této instance do jednoho souboru JSON nebo importujte takový soubor z jiné instance. Záznamy se párují podle názvu, takže interní ID se mezi instancemi mohou lišit.
+instance-transfer-export = Export
+instance-transfer-export-help = Soubor obsahuje všechny hráče, týmy, události s jejich sezónami, soupisky sezón, zápasy a góly.
+instance-transfer-download = Stáhnout JSON
+instance-transfer-export-failed = Export dat se nezdařil
+instance-transfer-import = Import
+instance-transfer-import-help = Nahrajte soubor exportovaný z jiné instance. Celý soubor se importuje najednou; pokud některý záznam selže, nic se nezmění.
+instance-transfer-file = Soubor exportu (.json)
+instance-transfer-submit = Importovat
+instance-transfer-done = Data importována
+
+# Backups
+backups-title = Zálohy
+backups-card-description = Plán záloh, jejich uchovávání a stažení záložních souborů
+backups-description = Zhuštěné kopie databáze vytvořené příkazem VACUUM INTO při noční údržbě a s BACKUP_INTERVAL_HOURS i mezi nimi. Staré zálohy nad limit uchovávání se po každé záloze smažou.
+backups-run-now = Zálohovat nyní
+backups-run-succeeded = Záloha vytvořena
+backups-run-failed = Záloha selhala, podrobnosti najdete na stránce Stav systému
+backups-disabled-title = Zálohy jsou vypnuté
+backups-disabled-message = Nastavte BACKUP_DIR na adresář, kam se mají zálohy ukládat, a restartujte aplikaci.
+backups-directory = Adresář
+backups-interval = Interval
+backups-keep = Uchovávané zálohy
+backups-keep-all = Všechny
+backups-next = Další záloha
+backups-empty-title = Zatím žádné zálohy
+backups-empty-message = Zálohy se zde objeví po příštím plánovaném běhu nebo ruční záloze.
+backups-file = Soubor
+backups-created = Vytvořeno
+backups-size = Velikost
+backups-download = Stáhnout
+backups-not-found = Záloha nenalezena
+
+# Public site
+public-site-name = Hokej
+public-sign-in = Přihlásit se
+public-seasons-title = Sezóny
+public-seasons-description = Rozpisy, tabulky, soupisky a statistiky hráčů všech sezón.
+public-no-seasons-title = Zatím žádné sezóny
+public-no-seasons-message = Sezóny se zde zobrazí, jakmile budou vytvořeny.
+public-season = Sezóna
+public-event = Soutěž
+public-teams = Týmy
+public-played = Odehráno
+public-back-to-seasons = Všechny sezóny
+public-back-to-season = Zpět na sezónu
+public-player-stats = Statistiky hráčů
+public-schedule = Rozpis zápasů
+public-no-matches = Zatím nejsou naplánovány žádné zápasy.
+public-player = Hráč
+public-no-roster = Soupiska je zatím prázdná.
+public-no-points = Zatím nebyly zaznamenány žádné body.
+public-not-found-title = Stránka nenalezena
+public-not-found-message = Tato sezóna nebo tým neexistuje.
+
+# Widgets
+widgets-view-full = Podrobnosti
+widgets-scoreboard-title = Dnešní zápasy
+widgets-no-matches-today = Dnes se nehrají žádné zápasy.
+
+# Chat webhooks
+chat-final-result = Konečný výsledek
+chat-webhooks-title = Oznámení do chatu
+chat-webhooks-hint = Posílejte konečné výsledky a milníky hráčů do kanálů ve Slacku nebo na Discordu přes příchozí webhooky.
+chat-webhooks-empty = Zatím nejsou nastaveny žádné webhooky.
+chat-webhooks-name = Název
+chat-webhooks-service = Služba
+chat-webhooks-url = URL webhooku
+chat-webhooks-notifications = Oznámení
+chat-webhooks-results = Konečné výsledky
+chat-webhooks-milestones = Milníky
+chat-webhooks-add = Přidat webhook
+chat-webhooks-send-test = Odeslat test
+chat-webhooks-test-message = Testovací zpráva ze správy hokejových soutěží
+chat-webhooks-test-sent = Testovací zpráva odeslána
+chat-webhooks-test-failed = Služba chatu testovací zprávu nepřijala
+chat-webhooks-confirm-delete = Výsledky a milníky se do tohoto kanálu přestanou posílat.
+chat-webhooks-deleted = Webhook smazán
+
+# Webhooks
+webhooks-title = Webhooky
+webhooks-card-description = Upozorňujte externí systémy na změny zápasů, gólů a hráčů
+webhooks-description = Každé vytvoření, úprava nebo smazání sledovaného záznamu se odešle jako JSON na adresu odběru. Ověřte hlavičku X-Hockey-Signature: sha256= následované hexadecimálním HMAC-SHA256 z hodnoty X-Hockey-Timestamp, tečky a těla požadavku s tajným klíčem. Neúspěšná doručení se opakují s prodlužujícím se odstupem.
+webhooks-empty-title = Zatím žádné webhooky
+webhooks-empty-message = Přidejte níže adresu, na kterou se budou změny posílat.
+webhooks-url = URL
+webhooks-secret = Tajný klíč
+webhooks-follows = Posílá
+webhooks-matches = Zápasy
+webhooks-score-events = Góly
+webhooks-players = Hráči
+webhooks-active = Aktivní
+webhooks-add = Přidat webhook
+webhooks-created = Webhook přidán
+webhooks-saved = Webhook uložen
+webhooks-save-failed = Webhook se nepodařilo uložit
+webhooks-deleted = Webhook smazán
+webhooks-confirm-delete = Odběr i jeho záznam doručení budou odstraněny.
+webhooks-deliveries-title = Poslední doručení
+webhooks-deliveries-empty = Zatím nebylo nic odesláno.
+webhooks-event = Událost
+webhooks-status = Stav
+webhooks-attempts = Pokusy
+webhooks-created-at = Zařazeno
+webhooks-status-pending = Čeká
+webhooks-status-delivered = Doručeno
+webhooks-status-failed = Selhalo
+webhooks-retry = Zkusit znovu
+webhooks-retry-queued = Doručení znovu zařazeno
+
+# Live console
+matches-console = Živá konzole
+matches-console-back = Zpět na zápas
+matches-console-keys = Mezerník spustit/zastavit čas · N další třetina · H / A gól · Shift+H / Shift+A trestné střílení · Enter uložit · Esc opustit formulář
+matches-console-clock-toggle = Spustit / zastavit
+matches-console-next-period = Další třetina
+matches-console-reset-clock = Vynulovat čas
+matches-console-goal = Gól
+matches-console-save-goal = Uložit gól
+
+# API keys
+profile-api-keys = API klíče
+profile-api-keys-hint = Skripty posílají klíč v hlavičce X-Api-Key a volají tak JSON API (/api/…, /graphql) vaším jménem. Nepoužívané klíče zrušte.
+profile-api-keys-empty = Zatím žádné API klíče
+profile-api-key-name = Název
+profile-api-key-name-placeholder = Název, např. Noční import
+profile-api-key-prefix = Klíč
+profile-api-key-created-at = Vytvořeno
+profile-api-key-last-used = Naposledy použito
+profile-api-key-never-used = Nikdy
+profile-api-key-create = Vytvořit API klíč
+profile-api-key-new = Zkopírujte si nový klíč hned, znovu už zobrazen nebude:
+profile-api-key-revoke = Zrušit
+profile-api-key-confirm-revoke = Skripty používající tento klíč přestanou fungovat. Tuto akci nelze vrátit.
+profile-api-key-created = API klíč vytvořen
+profile-api-key-revoked = API klíč zrušen
+profile-api-key-name-required = Zadejte název klíče
+
+# Users
+users-title = Uživatelé
+users-description = Všichni, kdo se mohou přihlásit. Editoři spravují hokejová data, správci navíc i uživatele.
+users-card-description = Přidávejte uživatele, měňte jejich roli, deaktivujte účty a resetujte hesla
+users-name = Jméno
+users-email = E-mail
+users-role = Role
+users-role-admin = Správce
+users-role-editor = Editor
+users-status = Stav
+users-active = Aktivní
+users-inactive = Deaktivovaný
+users-password-pending = Čeká na změnu hesla
+users-last-login = Poslední přihlášení
+users-never-signed-in = Nikdy
+users-you = vy
+users-create = Přidat uživatele
+users-temporary-password = Předejte toto dočasné heslo hned, znovu se nezobrazí. Patří uživateli
+users-deactivate = Deaktivovat
+users-reactivate = Znovu aktivovat
+users-confirm-deactivate = Uživatel bude odhlášen a nepřihlásí se, dokud jej znovu neaktivujete. Jeho API klíče přestanou fungovat.
+users-reset-password = Resetovat heslo
+users-confirm-reset-password = Uživatel bude odhlášen a dostane dočasné heslo, které musí při dalším přihlášení změnit.
+users-created = Uživatel přidán
+users-updated = Uživatel upraven
+users-password-was-reset = Heslo resetováno
+password-change = Změnit heslo
+password-change-title = Změna hesla
+password-change-description = Zvolte nové heslo o délce alespoň 8 znaků, které není běžně používané. Ostatní přihlášení budou ukončena.
+password-change-required = Přihlásili jste se dočasným heslem. Pro pokračování si zvolte vlastní heslo.
+password-current = Současné heslo
+password-new = Nové heslo
+password-confirm = Potvrzení nového hesla
+password-change-submit = Změnit heslo
+
+# Registration
+register-prompt = Ještě nemáte účet?
+register-link = Zaregistrujte se
+register-title = Registrace
+register-name = Jméno
+register-button = Zaregistrovat se
+register-back-to-signin = Zpět na přihlášení
+register-email-subject = Potvrďte svou e-mailovou adresu
+register-email-body = Děkujeme za registraci. Otevřete tento odkaz a potvrďte svou e-mailovou adresu:
+register-email-expiry = Odkaz platí 24 hodin. Pokud jste se neregistrovali, tento e-mail ignorujte.
+register-email-existing = Někdo se pokusil zaregistrovat s touto e-mailovou adresou, ale účet s ní již existuje. Přihlaste se, nebo použijte „Zapomněli jste heslo?“ na přihlašovací stránce. Pokud jste to nebyli vy, tento e-mail ignorujte.
+register-error-email = Potvrzovací e-mail se nepodařilo odeslat. Zkuste to prosím později.
+register-check-inbox-title = Zkontrolujte svou schránku
+register-check-inbox = Na vaši e-mailovou adresu jsme poslali potvrzovací odkaz. Otevřete jej do 24 hodin a dokončete registraci.
+register-verify-title = Potvrzení e-mailu
+register-verified = Vaše e-mailová adresa je potvrzena. Nyní se můžete přihlásit.
+register-awaiting-approval = Vaše e-mailová adresa je potvrzena. Než se budete moci přihlásit, musí váš účet schválit správce.
+register-link-expired = Platnost odkazu vypršela. Zaregistrujte se znovu se stejnou e-mailovou adresou a získáte nový.
+register-link-invalid = Odkaz je neplatný nebo již byl použit.
+users-registration = Registrace
+users-registration-hint = Umožní návštěvníkům vytvořit si účet z přihlašovací stránky. Nové účty jsou editoři a musí nejprve potvrdit svou e-mailovou adresu.
+users-registration-enabled = Povolit registraci
+users-registration-requires-approval = Nové účty schvaluje správce
+users-email-unverified = E-mail nepotvrzen
+users-approval-pending = Čeká na schválení
+users-approve = Schválit
+
+# Password reset
+reset-forgot-link = Zapomněli jste heslo?
+reset-request-title = Obnovení hesla
+reset-request-hint = Zadejte e-mailovou adresu, kterou se přihlašujete, a pošleme vám odkaz pro nastavení nového hesla.
+reset-request-button = Poslat odkaz
+reset-check-inbox = Pokud k této e-mailové adrese existuje účet, poslali jsme na ni odkaz pro obnovení hesla. Odkaz platí 60 minut.
+reset-email-subject = Obnovení hesla
+reset-email-body = Někdo požádal o obnovení hesla k vašemu účtu. Nové heslo si zvolíte po otevření tohoto odkazu:
+reset-email-expiry = Odkaz platí 60 minut a lze jej použít jen jednou. Pokud jste o obnovení nežádali, tento e-mail ignorujte; vaše heslo zůstane beze změny.
+reset-title = Zvolte nové heslo
+reset-button = Nastavit heslo
+reset-done = Vaše heslo bylo změněno a všechna vaše přihlášení byla ukončena. Přihlaste se novým heslem.
+reset-link-expired = Platnost odkazu vypršela. O nový požádejte z přihlašovací stránky.
+reset-link-invalid = Odkaz je neplatný nebo již byl použit.
+
+# External sign-in
+signin-with = Přihlásit se přes
+signin-oauth-failed = Přihlášení u poskytovatele se nezdařilo. Zkuste to prosím znovu.
+signin-oauth-no-account = K tomuto přihlášení není propojen žádný účet. Přihlaste se heslem a propojte jej na stránce profilu, nebo požádejte správce, aby vás přidal se stejnou e-mailovou adresou.
+identities-title = Propojené účty
+identities-hint = Přihlašujte se těmito účty místo hesla.
+identities-not-linked = Nepropojeno
+identities-link = Propojit
+identities-unlink = Odpojit
+identities-linked-elsewhere = Tento účet je již propojen s jiným uživatelem.
+identities-provider-taken = Účet tohoto poskytovatele již máte propojený. Nejprve jej odpojte.
+
+# Account lockout
+signin-error-locked = Příliš mnoho neúspěšných pokusů o přihlášení. Účet je dočasně zablokován; zkuste to později nebo si obnovte heslo.
+profile-login-locked = Účet zablokován
+users-locked = Zablokován
+users-unlock = Odblokovat
+users-unlocked = Účet odblokován
+users-activity = Aktivita přihlašování
+users-activity-description = Poslední pokusy o přihlášení ke všem účtům, včetně pokusů s neznámou e-mailovou adresou.
+users-activity-all = Všechny pokusy
+users-activity-failed-only = Jen neúspěšné
+users-activity-email = E-mail
+users-activity-unknown-user = Neznámý účet
+users-activity-inactive = Neaktivní účet
+users-activity-unlinked = Nepropojený externí účet
+users-activity-empty-title = Žádné pokusy o přihlášení
+users-activity-empty-message = Pokusy o přihlášení se zobrazí zde.
+
+# API key scopes
+profile-api-key-access = Přístup
+profile-api-key-full-access = Plný přístup
+profile-api-key-scopes = Přístup jen pro čtení
+profile-api-key-scopes-hint = Pro integrace: zaškrtněte, co smí klíč číst. Takový klíč nemůže nic měnit a dosáhne jen na odpovídající endpointy /api/…, ne na /graphql. Nechte vše nezaškrtnuté pro klíč s plným přístupem vaším jménem.
+api-scope-matches-read = Zápasy a góly
+api-scope-stats-read = Statistiky týmů a sezón
+api-scope-teams-read = Export týmů
+api-scope-players-read = Export hráčů
+
+# New sign-in address
+new-ip-email-subject = Nové přihlášení k vašemu účtu
+new-ip-email-body = K vašemu účtu se právě někdo přihlásil z adresy, ze které se dosud nepřihlašoval.
+new-ip-email-advice = Pokud jste to byli vy, nemusíte nic dělat. Jinak si ihned změňte heslo a dejte vědět administrátorovi.
@@ -5,14 +5,37 @@ nav-players = Players
 nav-events = Events
 nav-seasons = Seasons
 nav-matches = Matches
+nav-referees = Referees
+nav-staff = Staff
+nav-arenas = Arenas
 nav-management = Management
 nav-countries = Countries
+nav-tasks = Tasks
+nav-notifications = Notifications
 
 # User menu
 user-logout = Logout
 user-language = Language
 user-signed-in-as = Signed in as
 
+# Profile
+profile-title = Profile
+profile-description = Your account details and recent sign-in activity
+profile-name = Name
+profile-email = Email
+profile-recent-logins = Recent sign-ins
+profile-recent-logins-hint = Latest sign-in attempts for your account. Contact an administrator if you do not recognize one.
+profile-login-time = Time
+profile-login-result = Result
+profile-login-ip = IP address
+profile-login-user-agent = Browser
+profile-login-success = Signed in
+profile-login-failed = Failed
+profile-login-wrong-password = Wrong password
+profile-login-new-ip = New address
+profile-no-logins-title = No sign-ins recorded
+profile-no-logins-message = Sign-in attempts will appear here.
+
 # Dashboard
 dashboard-title = Dashboard
 dashboard-subtitle = Welcome to the Hockey Management Application. This is the main dashboard where you can see an overview of your hockey management data.
@@ -20,6 +43,9 @@ dashboard-stats-total-teams = Total Teams
 dashboard-stats-active-players = Active Players
 dashboard-stats-ongoing-seasons = Ongoing Seasons
 dashboard-stats-total-events = Total Events
+dashboard-stats-identified-goals = Identified Goals
+dashboard-this-week = this week
+dashboard-last-week = Last week
 dashboard-recent-activity = Recent Activity
 dashboard-quick-actions = Quick Actions
 dashboard-no-activity = No recent activity to display.
@@ -70,6 +96,85 @@ teams-back-to-list = Back to Teams
 teams-add-to-season = Add to Season
 teams-no-participations = No Season Participations
 teams-no-participations-help = This team hasn't been added to any seasons yet. Add this team to a season using the button above.
+teams-head-coach = Head coach
+teams-export = Export History
+teams-import = Import Team
+teams-import-title = Import Team History
+teams-import-help = Upload a team history file exported from another instance. By default existing teams, events, seasons and players are matched by name and reused, and matches already present are skipped. Merge mode also recognises records imported earlier by their external IDs and updates them to the file's values.
+teams-import-file = History file (.json)
+teams-import-submit = Import
+teams-import-done = Team history imported
+teams-import-team-created = New team created
+teams-import-team-matched = Merged into existing team
+teams-import-mode = Import mode
+teams-import-mode-add = Add missing records only (match by name)
+teams-import-mode-merge = Merge by external ID (update existing records)
+teams-import-entity = Records
+teams-import-created = Created
+teams-import-updated = Updated
+teams-import-unchanged = Unchanged
+teams-import-row-teams = Teams
+teams-import-row-events = Events
+teams-import-row-seasons = Seasons
+teams-import-row-participations = Season participations
+teams-import-row-players = Players
+teams-import-row-contracts = Roster entries
+teams-import-row-matches = Matches
+teams-import-row-goals = Goals
+teams-import-open-team = Open Team
+
+# Arenas
+arenas-title = Arenas
+arenas-description = Manage the arenas where matches are played.
+arenas-create = + New Arena
+arenas-entity = arena
+arenas-name-label = Arena Name
+arenas-name-placeholder = Enter arena name...
+arenas-search-placeholder = Arena name or city...
+arenas-city = City
+arenas-city-placeholder = Enter city...
+arenas-capacity = Capacity
+arenas-create-title = Create Arena
+arenas-create-submit = Create Arena
+arenas-edit-title = Edit Arena
+arenas-created = Arena created successfully
+arenas-updated = Arena updated successfully
+arenas-deleted = Arena deleted successfully
+arenas-matches = Matches
+arenas-back-to-list = Back to Arenas
+
+# Referees
+referees-title = Referees
+referees-description = Manage the referees and linesmen who officiate matches.
+referees-create = + New Referee
+referees-entity = referee
+referees-name-label = Name
+referees-name-placeholder = Enter official's name...
+referees-create-title = Create Referee
+referees-create-submit = Create Referee
+referees-edit-title = Edit Referee
+referees-created = Referee created successfully
+referees-updated = Referee updated successfully
+referees-deleted = Referee deleted successfully
+referees-matches = Matches
+
+# Staff
+staff-title = Staff
+staff-description = Manage coaches and general managers contracted to teams.
+staff-create = + New Staff Member
+staff-entity = staff member
+staff-name-label = Name
+staff-name-placeholder = Enter staff member's name...
+staff-create-title = Create Staff Member
+staff-create-submit = Create Staff Member
+staff-edit-title = Edit Staff Member
+staff-created = Staff member created successfully
+staff-updated = Staff member updated successfully
+staff-deleted = Staff member deleted successfully
+staff-contracts = Contracts
+staff-role-head-coach = Head coach
+staff-role-assistant-coach = Assistant coach
+staff-role-general-manager = General manager
 
 # Players
 players-title = Players
@@ -191,6 +296,21 @@ seasons-remove-team = Remove Team
 seasons-confirm-remove-team-1 = Are you sure you want to remove
 seasons-confirm-remove-team-2 = from this season?
 
+# Playoffs
+playoffs-title = Playoffs
+playoffs-add-series = Add Series
+playoffs-create-title = Create Playoff Series
+playoffs-empty = No playoff series have been set up for this season yet
+playoffs-round = Round
+playoffs-best-of = Best of
+playoffs-higher-seed = Higher seed (home ice)
+playoffs-lower-seed = Lower seed
+playoffs-seed = Seed
+playoffs-add-game = Add Game
+playoffs-confirm-delete = Are you sure you want to delete this series? Its games will be kept as regular matches.
+playoffs-generate-from-seeds = Generate from seeds
+playoffs-generate-hint = Creates round 1 from the seeds set on the teams below: top seed against bottom seed, with byes for the top seeds when the bracket is not full.
+
 # Matches
 matches-title = Matches
 matches-description = Manage and view all hockey matches and games.
@@ -210,10 +330,16 @@ matches-filter-by-team = Filter by team
 matches-filter-by-status = Filter by status
 matches-filter-date-from = Date from
 matches-filter-date-to = Date to
+matches-filter-date-unknown = Only matches with unknown date
+matches-date-unknown = Date unknown
 matches-status-scheduled = Scheduled
 matches-status-in-progress = In Progress
 matches-status-finished = Finished
 matches-status-cancelled = Cancelled
+matches-status-not-cancelled = All except cancelled
+matches-defaults-applied = Showing the default filters from the settings.
+matches-show-all = Show all matches
+matches-share-image = Share image
 matches-home-team = Home Team
 matches-away-team = Away Team
 matches-date = Date
@@ -250,7 +376,8 @@ matches-edit-submit = Save Changes
 matches-view = View Match
 matches-vs = vs
 matches-date-time-label = Date and Time
-matches-location-placeholder = Enter venue/location
+matches-no-arena = No arena
+matches-attendance = Attendance
 matches-notes = Notes
 matches-notes-placeholder = Additional notes about the match
 matches-back-to-list = Back to Matches
@@ -263,15 +390,64 @@ matches-power-play = Power Play
 matches-short-handed = Short-Handed
 matches-penalty-shot = Penalty Shot
 matches-empty-net = Empty Net
+matches-include-goalies = Include goalies
+matches-scoring-group-other = Other players
+matches-quick-create-new = New
+matches-quick-create-select-season = Select a season first; the new team joins the match's season
+matches-quick-create-team-hint = The team is added to the season selected for the match.
 matches-own-goal = Own Goal
 matches-overtime = Overtime
 matches-shootout = Shootout
+matches-goals = Goals
+matches-shots-on-goal = Shots on goal
+matches-shooting-percentage = Shooting %
+matches-shots-edit = Edit shots
+matches-shots-edit-title = Shots on goal
+matches-shots-hint = Enter shots on goal per period. Leave a field empty if shots were not recorded.
+matches-shots-empty = No shots on goal recorded for this match.
+matches-shots-games-recorded = matches with shots recorded
+matches-lineups = Lineups
+matches-lineup-edit = Edit lineup
+matches-lineup-edit-title = Dressed lineup
+matches-lineup-hint = Check the players who dressed for this game. Once a lineup is saved, only dressed players can be picked as goal scorers and assists.
+matches-lineup-empty = No lineup recorded
+matches-lineup-no-roster = This team has no roster for the match's season. Add players on the roster page first.
+matches-lineup-dressed = dressed
+matches-lineup-select-all = Select all
+matches-officials = Officials
+matches-officials-edit = Edit officials
+matches-officials-edit-title = Match officials
+matches-officials-hint = Assign up to two referees and two linesmen. New officials are added on the Referees page.
+matches-officials-empty = No officials assigned
+matches-staff = Bench Staff
+matches-staff-empty = No staff contracted
+matches-officials-referee = Referee
+matches-officials-linesman = Linesman
+matches-officials-none = — None —
+matches-officials-no-referees = No referees registered yet.
 matches-filter-season = Season
 matches-filter-team = Team
 matches-filter-status = Status
 matches-all-seasons = All seasons
 matches-all-teams = All teams
 matches-all-statuses = All statuses
+matches-filter-coverage = Goal coverage
+matches-all-coverage = All coverage levels
+matches-filter-arena = Arena
+matches-all-arenas = All arenas
+matches-game-type = Game Type
+matches-all-game-types = All game types
+matches-filter-group = Group
+matches-all-groups = All groups
+matches-game-type-group = Group stage
+matches-game-type-playoff = Playoff
+matches-game-type-relegation = Relegation
+matches-game-type-exhibition = Exhibition
+matches-coverage-full = Fully identified
+matches-coverage-partial = Partially identified
+matches-coverage-aggregate = Aggregate only
+matches-coverage-none = No goals
+matches-coverage-hint = How many of the goals have a recorded scorer
 
 # Management / Countries
 management-title = Management
@@ -289,6 +465,74 @@ management-countries-empty-search = Try adjusting your search terms.
 management-countries-empty-message = All countries from the system database are shown here.
 management-loading = Loading
 
+# Reports
+reports-reconciliation-title = Stats Reconciliation
+reports-reconciliation-description = Compare manually entered career stats with totals derived from recorded score events.
+reports-reconciliation-card-description = Find players whose manual stats disagree with tracked goals and assists
+reports-reconciliation-empty-title = No discrepancies
+reports-reconciliation-empty-message = Manual stats and score events agree for every player.
+reports-show-discrepancies = Discrepancies only
+reports-show-all = Show all
+reports-manual = Manual
+reports-derived = From score events
+reports-status-matching = Matching
+reports-status-unidentified = Unidentified remainder
+reports-status-conflict = Conflict
+reports-status-missing-manual = Missing manual entry
+reports-fix-manual = Edit manual stats
+reports-fix-derived = Review score events
+# System status
+status-title = System Status
+status-description = Database storage and results of the nightly maintenance job (WAL checkpoint, query planner statistics and backups).
+status-card-description = Database size, maintenance schedule and backup results
+status-run-now = Run maintenance now
+status-run-succeeded = Maintenance finished
+status-run-failed = Maintenance finished with errors
+status-journal-mode = Journal mode
+status-database-size = Database size
+status-free-space = Reclaimable space
+status-next-run = Next scheduled run
+status-backups = Backups
+status-backups-kept = kept
+status-backups-disabled = Disabled (set BACKUP_DIR)
+status-recent-runs = Recent runs
+status-no-runs-title = No maintenance runs yet
+status-no-runs-message = The job runs nightly; use "Run maintenance now" to run it immediately.
+status-run-started = Started
+status-run-task = Task
+status-run-result = Result
+status-run-duration = Duration
+status-run-detail = Detail
+status-task-checkpoint = WAL checkpoint
+status-task-optimize = Optimize
+status-task-backup = Backup
+status-result-ok = OK
+status-result-failed = Failed
+status-download-snapshot = Download Database
+status-snapshot-failed = Failed to create a database snapshot
+# Slow queries
+slow-queries-title = Slow Queries
+slow-queries-description = Database statements that took longer than the slow query threshold since the server started, to guide indexing work.
+slow-queries-card-description = Find database statements that need an index
+slow-queries-threshold = Threshold:
+slow-queries-disabled = Slow query logging is disabled (SLOW_QUERY_MS=0).
+slow-queries-clear = Clear
+slow-queries-cleared = Slow query log cleared
+slow-queries-empty-title = No slow queries
+slow-queries-empty-message = No statement has exceeded the threshold since the server started or the log was cleared.
+slow-queries-by-statement = By statement
+slow-queries-recent = Recent
+slow-queries-statement = Statement
+slow-queries-count = Count
+slow-queries-avg = Average
+slow-queries-max = Max
+slow-queries-last-route = Last seen
+slow-queries-time = Time (UTC)
+slow-queries-route = Request
+slow-queries-duration = Duration
+slow-queries-rows = Rows
+slow-queries-background = Background job
+
 # Sign In
 signin-title = Sign in to your account
 signin-subtitle = Access the Hockey Database Management System
@@ -298,6 +542,11 @@ signin-button = Sign in
 signin-signing-in = Signing in...
 signin-error-invalid-credentials = Invalid email or password
 signin-error-general = Something went wrong. Please try again.
+signin-error-deactivated = This account has been deactivated. Ask an admin to reactivate it.
+signin-error-unverified = Confirm your email with the link we sent you before signing in.
+signin-error-awaiting-approval = Your account is waiting for an admin to approve it.
+signin-demo-button = Explore the demo
+signin-demo-hint = Just looking around? Sign in to the read-only demo account, no password needed.
 
 # Common
 common-save = Save
@@ -338,6 +587,8 @@ common-remove = Remove
 form-name = Name
 form-country = Country
 form-photo = Photo
+form-slug = URL slug
+form-slug-help = Part of the page address. Leave empty to generate it from the name; links using an old slug are redirected.
 label-team = Team
 label-season = Season
 placeholder-select-team = Choose a team...
@@ -374,10 +625,19 @@ error-player-not-found = Player not found
 error-event-not-found = Event not found
 error-season-not-found = Season not found
 error-team-not-found = Team not found
+error-referee-not-found = Referee not found
+error-arena-not-found = Arena not found
 error-match-not-found = Match not found
 error-score-event-not-found = Score event not found
 error-player-contract-not-found = Player contract not found
+error-staff-member-not-found = Staff member not found
+error-staff-contract-not-found = Staff contract not found
+error-failed-to-load-staff = Failed to load staff
+error-failed-to-load-staff-member = Failed to load staff member
+error-failed-to-delete-staff-member = Failed to delete staff member
 error-team-participation-not-found = Team participation not found
+error-playoff-series-not-found = Playoff series not found
+error-season-group-not-found = Group not found
 error-statistics-not-found = Statistics not found
 
 # Error messages - Failed to load
@@ -389,6 +649,11 @@ error-failed-to-load-seasons = Failed to load seasons
 error-failed-to-load-season = Failed to load season
 error-failed-to-load-teams = Failed to load teams
 error-failed-to-load-team = Failed to load team
+error-failed-to-load-referees = Failed to load referees
+error-failed-to-load-referee = Failed to load referee
+error-failed-to-load-officials = Failed to load match officials
+error-failed-to-load-arenas = Failed to load arenas
+error-failed-to-load-arena = Failed to load arena
 error-failed-to-load-matches = Failed to load matches
 error-failed-to-load-match = Failed to load match
 error-failed-to-load-match-detail = Failed to load match detail
@@ -397,15 +662,33 @@ error-failed-to-load-score-event = Failed to load score event
 error-failed-to-load-player-scoring-data = Failed to load player scoring data
 error-failed-to-load-scoring-events = Failed to load scoring events
 error-failed-to-load-statistics = Failed to load statistics
+error-failed-to-load-playoff-series = Failed to load playoff series
+error-failed-to-load-report = Failed to load report
+error-failed-to-load-status = Failed to load system status
+error-failed-to-load-logins = Failed to load sign-in history
+error-failed-to-load-lines = Failed to load lines
 
 # Error messages - Failed to delete
 error-failed-to-delete-player = Failed to delete player
 error-failed-to-delete-event = Failed to delete event
 error-failed-to-delete-season = Failed to delete season
 error-failed-to-delete-team = Failed to delete team
+error-failed-to-delete-referee = Failed to delete referee
+error-failed-to-delete-arena = Failed to delete arena
 error-failed-to-delete-match = Failed to delete match
 error-failed-to-delete-score-event = Failed to delete score event
 error-failed-to-delete-statistics = Failed to delete statistics
+error-failed-to-delete-playoff-series = Failed to delete playoff series
+error-failed-to-delete-season-group = Failed to delete group
+error-failed-to-assign-season-group = Failed to change the team's group
+error-failed-to-load-history = Failed to load change history
+error-failed-to-set-placement = Failed to change the team's placement
+error-season-award-not-found = Award not found
+error-failed-to-delete-season-award = Failed to remove award
+error-failed-to-set-seed = Failed to change the team's seed
+error-failed-to-generate-bracket = Failed to generate the playoff bracket
+error-invalid-settings = Invalid settings value
+error-failed-to-save-settings = Failed to save settings
 
 # Error messages - Failed to save/update
 error-failed-to-save-statistics = Failed to save statistics
@@ -492,6 +775,7 @@ players-event-stats-save-changes = Save Changes
 
 # Player Property Changes
 player-property-change-timeline = Career Timeline
+player-transfers-title = Transfers
 player-property-change-add = Add Change
 player-property-change-edit = Edit Change
 player-property-change-delete = Delete Change
@@ -522,3 +806,911 @@ player-property-change-season-help = Optionally link this change to a specific s
 
 player-property-change-no-changes = No Career Timeline Events
 player-property-change-no-changes-help = Track important milestones like position changes, trades, and career events
+
+# Demo mode
+demo-mode-read-only = Demo mode: changes are not saved
+
+# Session expiry
+session-expiry-warning = Your session is about to expire due to inactivity. Stay signed in to keep working.
+session-stay-signed-in = Stay signed in
+
+# Roster
+roster-jersey-number = Jersey number
+roster-jersey-number-short = #
+roster-jersey-number-help = Optional. Must be unique within this roster (0-99).
+roster-captaincy = Captaincy
+roster-captaincy-none = None
+roster-captain = Captain
+roster-alternate-captain = Alternate captain
+roster-captaincy-help = A team has one captain (C) and up to two alternates (A).
+roster-edit-player-title = Edit Roster Player
+roster-lines-title = Lines
+roster-lines-hint = Drag players from the roster onto forward lines, defense pairs and the goalie depth chart. Dropping onto a taken spot swaps the two players.
+roster-lines-unassigned = Not on a line
+roster-lines-all-assigned = Every rostered player is on a line
+roster-lines-forwards = Forwards
+roster-lines-defense = Defense
+roster-lines-goalies = Goalies
+roster-lines-line = Line
+roster-lines-pair = Pair
+roster-lines-empty-slot = Drop player here
+roster-lines-remove = Remove from line
+roster-staff-title = Staff
+roster-staff-add = + Add Staff
+roster-staff-add-title = Add Staff to Team
+roster-staff-member = Staff member
+roster-staff-select-member = -- Select a staff member --
+roster-staff-role = Role
+roster-staff-none-registered = No staff members registered yet. Add them on the Staff page first.
+roster-staff-empty = No staff contracted to this team yet
+roster-staff-remove = Remove
+roster-staff-remove-title = Remove Staff Member
+roster-staff-confirm-remove = Remove this staff member from the team?
+roster-joined = Joined
+roster-left = Left
+roster-transfer = Transfer
+roster-transfer-title = Transfer Player
+roster-transfer-destination = New team
+roster-transfer-select-destination = -- Select a team --
+roster-transfer-date = Transfer date
+roster-transfer-note = Note
+roster-transfer-no-destinations = No other team takes part in this season.
+
+# Team calendar subscriptions
+calendar-subscriptions-title = Calendar Subscriptions
+calendar-subscriptions-description = Private feed URLs fans can add to their calendar app to follow this team's matches. Each URL can be revoked on its own.
+calendar-subscriptions-empty = No calendar subscriptions yet
+calendar-subscription-label = Label
+calendar-subscription-label-placeholder = Label, e.g. Fan club newsletter (optional)
+calendar-subscription-url = Feed URL
+calendar-subscription-created-at = Created
+calendar-subscription-last-used = Last fetched
+calendar-subscription-never-used = Never
+calendar-subscription-create = Create feed URL
+calendar-subscription-subscribe = Subscribe
+calendar-subscription-revoke = Revoke
+calendar-subscription-confirm-revoke = Calendars subscribed with this URL will stop receiving updates. This cannot be undone.
+calendar-subscription-created = Calendar subscription created
+calendar-subscription-revoked = Calendar subscription revoked
+
+# Attendance
+attendance-title = Attendance
+attendance-empty = No attendance recorded yet
+attendance-matches = Matches with attendance
+attendance-total = Total
+attendance-average = Average
+attendance-overall = Overall
+attendance-arena = Arena
+attendance-season = Season
+
+# Match predictions
+predictions-title = Predictions
+predictions-description = Pick the winner of upcoming matches. Picks can be changed until a match starts and are scored once it is finished.
+predictions-leaderboard = Leaderboard
+predictions-leaderboard-description = Predictors ranked by correct picks on finished matches of the season. A tied final score counts as a miss for every pick.
+predictions-back = Back to Predictions
+predictions-empty-title = No upcoming matches
+predictions-empty-message = There are no scheduled matches to predict right now.
+predictions-leaderboard-empty-title = No scored picks yet
+predictions-leaderboard-empty-message = Picks are scored once their matches are finished.
+predictions-match = Match
+predictions-your-pick = Your pick
+predictions-predictor = Predictor
+predictions-correct = Correct
+predictions-picks = Picks
+predictions-accuracy = Accuracy
+predictions-saved = Pick saved
+predictions-closed = Picks are closed for this match
+
+# Season groups and standings
+groups-title = Groups
+groups-add = Add Group
+groups-create-title = Create Group
+groups-name = Group name
+groups-name-placeholder = e.g. Group A
+groups-empty = This season is not divided into groups.
+groups-all-teams = All teams
+groups-none = No group
+groups-view-matches = Group matches
+groups-confirm-delete = Are you sure you want to delete this group? Its teams stay in the season without a group.
+standings-title = Standings
+standings-hint = Finished group-stage matches only.
+standings-team = Team
+standings-games = GP
+standings-wins = W
+standings-ties = T
+standings-losses = L
+standings-goals = Goals
+standings-goal-difference = +/-
+standings-points = Pts
+
+# Point systems
+events-point-system = Point System
+events-point-system-classic = 2-1-0 (win, tie, loss)
+events-point-system-iihf = 3-2-1-0 (IIHF, overtime and shootouts)
+standings-points-classic = A win is worth 2 points, a tie 1 point.
+standings-points-iihf = A regulation win is worth 3 points, an overtime or shootout win 2 points and an overtime or shootout loss 1 point.
+standings-overtime-wins = OTW
+standings-overtime-losses = OTL
+
+# Change history
+history-title = History
+history-view = History
+history-empty = No changes have been recorded yet.
+history-back-to-match = Back to Match
+history-unknown-user = Unknown user
+history-field = Field
+history-before = Before
+history-after = After
+history-match-updated = Match updated
+history-goal-created = Goal added
+history-goal-updated = Goal updated
+history-goal-deleted = Goal deleted
+history-player-updated = Player updated
+history-field-season = Season
+history-field-home-team = Home team
+history-field-away-team = Away team
+history-field-home-score = Home unidentified goals
+history-field-away-score = Away unidentified goals
+history-field-match-date = Date
+history-field-status = Status
+history-field-game-type = Game type
+history-field-arena = Arena
+history-field-attendance = Attendance
+history-field-team = Team
+history-field-scorer = Scorer
+history-field-first-assist = First assist
+history-field-second-assist = Second assist
+history-field-period = Period
+history-field-time = Time
+history-field-goal-type = Goal type
+history-field-name = Name
+history-field-country = Country
+history-field-birth-date = Birth date
+history-field-birth-place = Birth place
+history-field-height = Height (cm)
+history-field-weight = Weight (kg)
+history-field-position = Position
+history-field-shoots = Shoots
+history-field-photo = Photo
+history-field-video = Video
+
+# Roster rules
+roster-rules-title = Roster Rules
+roster-rules-edit = Edit Roster Rules
+roster-rules-updated = Roster rules updated successfully
+roster-rules-no-limit = No limit
+roster-rules-max-players = Maximum players
+roster-rules-min-goalies = Minimum goalies
+roster-rules-max-imports = Maximum import players
+roster-rules-hint = The rules apply to every team of the event. Import players are players from a country other than their team's. Players cannot be added or transferred to a roster beyond a maximum.
+roster-rules-compliant = The roster meets the event's roster rules
+roster-rules-not-compliant = The roster does not meet the event's roster rules
+
+# Placements and medals
+placements-final-placement = Placement
+placements-none = Not placed
+placements-not-seeded = Not seeded
+placements-medal-table = Medal Table
+placements-medal-table-hint = Medals won by each team across all seasons of the event. Final placements are set on the season page.
+placements-no-medals = No final placements recorded yet
+placements-gold = Gold
+placements-silver = Silver
+placements-bronze = Bronze
+placements-total = Total
+placements-medals = Medals
+
+# Season awards
+awards-title = Awards
+awards-add = Add award
+awards-empty = No awards have been given this season yet
+awards-award = Award
+awards-player = Player
+awards-no-candidates = Add players to the season's rosters first
+awards-mvp = Most Valuable Player
+awards-best-forward = Best Forward
+awards-best-defenseman = Best Defenseman
+awards-best-goalie = Best Goalie
+awards-all-star = All-Star Team
+awards-confirm-delete = Are you sure you want to remove this award?
+awards-player-title = Awards
+
+# Milestones
+milestones-title = Milestones
+milestones-recent = Recent Milestones
+milestones-hat-trick = Hat trick
+milestones-four-point-game = 4+ point game
+milestones-hundredth-goal = 100th career goal
+milestones-goals = goals
+milestones-points = points
+
+# Settings
+settings-title = Settings
+settings-card-description = Default filters and other instance-wide preferences
+settings-match-defaults = Matches list
+settings-match-defaults-hint = Filters applied when the matches list is opened without any filter in the address, e.g. from the sidebar.
+settings-current-season = Current season (most recent)
+settings-saved = Settings saved
+
+# Leaders
+leaders-title = Leaders
+leaders-view-all = All leaders
+leaders-empty = No goals have been recorded in this season yet.
+leaders-goals = Goals
+leaders-assists = Assists
+leaders-points = Points
+leaders-goals-short = G
+leaders-assists-short = A
+leaders-points-short = P
+
+# Team season statistics
+team-stats-title = Season Statistics
+team-stats-season = Season
+team-stats-goals-for = GF
+team-stats-goals-against = GA
+team-stats-power-play-goals = PPG
+team-stats-short-handed-goals = SHG
+team-stats-shots = SOG
+team-stats-shooting-percentage = SH%
+
+# Season rankings
+players-season-rankings = Season Rankings
+players-season-rankings-hint = Where the player ranks among all players of their recent seasons. Exhibition games are not counted.
+players-season-rankings-players = players
+players-season-rankings-top = Top
+players-season-rankings-goals = in goals
+players-season-rankings-assists = in assists
+players-season-rankings-points = in points
+players-season-rankings-points-per-game = in points per game
+
+# On-ice players
+matches-on-ice = On ice
+matches-on-ice-title = Players on ice
+matches-on-ice-hint = Check the players who were on the ice when the goal was scored. Power-play, penalty-shot and shootout goals do not count towards plus/minus.
+matches-on-ice-no-players = No players available for this team.
+player-scoring-plus-minus = Plus/Minus
+error-failed-to-save-on-ice = Failed to save players on ice
+
+# Roster comparison
+roster-diff-title = Roster Comparison
+roster-diff-open = Compare Rosters
+roster-diff-from = Earlier season
+roster-diff-to = Later season
+roster-diff-compare = Compare
+roster-diff-pick-seasons = Pick two different seasons to compare the rosters.
+roster-diff-added = Added
+roster-diff-departed = Departed
+roster-diff-retained = Retained
+roster-diff-none = Nobody
+roster-diff-came-from = from
+roster-diff-went-to = now with
+
+# Report builder
+report-builder-title = Report Builder
+report-builder-card-description = Compose custom reports over goals, matches and rosters, save them and export to CSV
+report-builder-description = Pick what to count, how to group it and which rows to include. Results can be saved for later or downloaded as CSV.
+report-builder-new = New Report
+report-builder-saved = Saved reports:
+report-builder-source = Source
+report-builder-source-goals = Goals
+report-builder-source-matches = Matches
+report-builder-source-rosters = Roster contracts
+report-builder-dimensions = Group by
+report-builder-measures = Measures
+report-builder-season = Season
+report-builder-team = Team
+report-builder-game-type = Game type
+report-builder-date-from = From date
+report-builder-date-to = To date
+report-builder-any = Any
+report-builder-sort = Sort by
+report-builder-sort-default = Grouping order
+report-builder-run = Run Report
+report-builder-pick-fields = Select at least one field to group by or measure, then run the report.
+report-builder-no-rows = No rows match this report.
+report-builder-truncated = Only the first rows are shown. Export to CSV for the full result.
+report-builder-name = Report name
+report-builder-save = Save Report
+report-builder-export-csv = Export CSV
+report-builder-confirm-delete = Are you sure you want to delete this saved report?
+report-field-season = Season
+report-field-event = Competition
+report-field-team = Team
+report-field-scorer = Scorer
+report-field-period = Period
+report-field-goal-type = Goal type
+report-field-game-type = Game type
+report-field-month = Month
+report-field-home-team = Home team
+report-field-away-team = Away team
+report-field-arena = Arena
+report-field-status = Status
+report-field-position = Position
+report-field-country = Country
+report-field-goals = Goals
+report-field-matches = Matches
+report-field-scorers = Different scorers
+report-field-assisted-goals = Assisted goals
+report-field-total-attendance = Total attendance
+report-field-avg-attendance = Average attendance
+report-field-players = Players
+report-field-contracts = Contracts
+error-report-not-found = Report not found
+error-failed-to-save-report = Failed to save report
+error-failed-to-delete-report = Failed to delete report
+
+# Faceoffs
+matches-faceoffs = Faceoffs
+matches-faceoffs-edit = Edit faceoffs
+matches-faceoffs-edit-title = Faceoffs
+matches-faceoffs-hint = Enter faceoffs won and lost by each player who took them, usually the centers. Rows left empty are not saved.
+matches-faceoffs-empty = No faceoffs recorded
+matches-faceoffs-player = Player
+matches-faceoffs-won = Won
+matches-faceoffs-lost = Lost
+matches-faceoffs-won-short = W
+matches-faceoffs-lost-short = L
+matches-faceoffs-percentage = FO%
+player-scoring-faceoff-percentage = Faceoffs Won
+team-stats-faceoff-percentage = FO%
+
+# Goal videos
+matches-video-url = Video URL
+matches-video-timestamp = Video time
+matches-video-play = Watch the goal
+
+# Goal flags
+matches-game-winning-goal = Game-winning goal
+matches-game-winning-goal-short = GWG
+matches-empty-net-in-shootout = Shootout attempts are taken against a goaltender, so this goal cannot be an empty-net goal
+matches-empty-net-while-trailing = The scoring team was behind at the time, so the opponent had no reason to pull their goaltender
+
+# What's new
+changelog-title = What's New
+changelog-description = Entries shown once to every user after an upgrade, so scorekeepers learn about new features.
+changelog-card-description = Write the release notes users see after an upgrade
+changelog-whats-new = What's new
+changelog-dismiss = Got it
+changelog-create = New Entry
+changelog-edit = Edit Entry
+changelog-version = Version
+changelog-entry-title = Title
+changelog-body = Text
+changelog-body-help = Plain text; line breaks are kept.
+changelog-created = Created
+changelog-empty-title = No entries yet
+changelog-empty-message = Add an entry to tell users what changed in this version.
+changelog-delete-title = Delete Entry
+changelog-delete-confirm = Are you sure you want to delete this entry? Users who have not seen it yet will not be shown it.
+changelog-created-toast = Entry created
+changelog-updated-toast = Entry updated
+changelog-deleted-toast = Entry deleted
+changelog-not-found = Entry not found
+changelog-load-failed = Failed to load entries
+changelog-save-failed = Failed to save entry
+changelog-delete-failed = Failed to delete entry
+
+# Match results
+matches-result-overtime = OT
+matches-result-shootout = SO
+
+# Connection
+connection-offline = You are offline. Changes will not be saved until the connection is back.
+connection-server-unavailable = The server cannot be reached. Retrying automatically…
+connection-restored = Connection restored
+connection-retry-now = Retry now
+
+# Team form
+team-form-title = Form
+team-form-hint = Last five finished matches, most recent on the right
+team-form-win = W
+team-form-overtime-win = OTW
+team-form-draw = D
+team-form-overtime-loss = OTL
+team-form-loss = L
+team-form-versus = vs
+
+# Home/away splits
+splits-title = Home/Away Splits
+splits-view = Home/Away
+splits-home = Home
+splits-away = Away
+splits-venue = Venue
+splits-total = Career total
+splits-team-hint = Finished matches without exhibition games. Wins and losses include overtime and shootout decisions.
+splits-player-hint = Goals and assists split by whether the player's team played at home or away. Exhibition games are not counted.
+splits-team-empty = This team has no finished matches yet.
+splits-player-empty = This player has no goals or assists yet.
+splits-load-failed = Failed to load home/away splits
+
+# Tasks
+tasks-title = Tasks
+tasks-description = Coordinate archival work: attach to-dos to teams, players, events, seasons and matches, assign them and set due dates.
+tasks-create = New task
+tasks-edit = Edit task
+tasks-task = Task
+tasks-title-placeholder = e.g. Verify roster for SWE 1998
+tasks-notes = Notes
+tasks-assignee = Assignee
+tasks-unassigned = Unassigned
+tasks-due-date = Due date
+tasks-overdue = Overdue
+tasks-attached-to = Attached to
+tasks-entity-deleted = Deleted record
+tasks-scope-mine = Assigned to me
+tasks-scope-open = All open
+tasks-scope-completed = Completed
+tasks-scope-all = All
+tasks-complete = Mark as done
+tasks-reopen = Reopen
+tasks-empty-title = No tasks
+tasks-empty-message = Nothing to do here. Create a task to coordinate work with others.
+tasks-my-open = My open tasks
+tasks-view-all = View all tasks
+tasks-none-assigned = No open tasks are assigned to you.
+tasks-delete-title = Delete task
+tasks-delete-confirm = Are you sure you want to delete this task?
+tasks-created-toast = Task created
+tasks-updated-toast = Task updated
+tasks-completed-toast = Task marked as done
+tasks-reopened-toast = Task reopened
+tasks-deleted-toast = Task deleted
+tasks-load-failed = Failed to load tasks
+tasks-save-failed = Failed to save task
+tasks-delete-failed = Failed to delete task
+tasks-not-found = Task not found
+tasks-entity-not-found = The record to attach the task to was not found
+
+# Goal types
+goal-types-title = Goal Types
+goal-types-hint = Identified goals by situation. Unidentified goals and exhibition games are not counted.
+goal-types-empty = No identified goals yet.
+goal-types-team = Team
+goal-types-total = Total
+goal-types-unspecified = Type not recorded
+goal-types-even-strength-short = EV
+goal-types-power-play-short = PP
+goal-types-short-handed-short = SH
+goal-types-penalty-shot-short = PS
+goal-types-empty-net-short = EN
+
+# Comments
+comments-title = Comments
+comments-hint = Internal discussion for researchers. Mention someone with @ and the part of their email before the @ (e.g. @jane.doe) to notify them.
+comments-empty = No comments yet.
+comments-placeholder = Write a comment…
+comments-reply-placeholder = Write a reply…
+comments-markdown-help = Markdown is supported: **bold**, *italic*, [links](https://…), lists and `code`.
+comments-post = Post comment
+comments-reply = Reply
+comments-deleted-user = Deleted user
+comments-delete-title = Delete comment
+comments-delete-confirm = Delete this comment and all replies to it?
+comments-posted-toast = Comment posted
+comments-deleted-toast = Comment deleted
+comments-load-failed = Failed to load comments
+comments-save-failed = Failed to post comment
+comments-delete-failed = Failed to delete comment
+comments-not-found = Comment not found
+
+# Notifications
+notifications-title = Notifications
+notifications-empty = You have no notifications.
+notifications-mentioned-you = mentioned you in a comment
+notifications-mark-all-read = Mark all as read
+notifications-load-failed = Failed to load notifications
+
+# Period distribution
+period-distribution-title = Goals per Period
+period-distribution-hint = Identified goals by period. Shootouts, unidentified goals and exhibition games are not counted.
+period-distribution-empty = No identified goals yet.
+period-distribution-goals = Goals
+period-distribution-home-away = Home:Away
+period-distribution-share = Share
+period-distribution-per-match = Per match
+
+# Duplicate report
+reports-duplicates-title = Duplicates
+reports-duplicates-description = Players, teams and arenas with very similar names, ignoring accents, punctuation and word order. Players born in different years, teams of different countries and arenas in different cities are never paired.
+reports-duplicates-card-description = Find probable duplicate players, teams and arenas
+reports-duplicates-empty-title = No duplicates found
+reports-duplicates-empty-message = No two players, teams or arenas have similar enough names.
+reports-duplicates-players = Players
+reports-duplicates-teams = Teams
+reports-duplicates-arenas = Arenas
+reports-duplicates-record = Record
+reports-duplicates-possible-duplicate = Possible duplicate
+reports-duplicates-similarity = Similarity
+reports-duplicates-exact = Same name
+reports-duplicates-short-name = Short name
+reports-duplicates-birth-year = Born
+
+# Streaks
+streaks-title = Scoring Streaks
+streaks-description = Consecutive games with a point or a goal, in date order. Exhibition games and undated matches are not counted, and unidentified goals credit nobody.
+streaks-empty = No streaks yet.
+streaks-points = Point streak
+streaks-goals = Goal streak
+streaks-longest = longest
+streaks-games = Games
+streaks-current-points = Current point streaks
+streaks-longest-points = Longest point streaks
+streaks-current-goals = Current goal streaks
+streaks-longest-goals = Longest goal streaks
+streaks-leaderboard-link = Streaks leaderboard →
+streaks-load-failed = Failed to compute streaks
+
+# Data quality
+data-quality-title = Data Quality
+data-quality-description = Records that are incomplete or look wrong. Each row links to the place where it can be fixed.
+data-quality-card-description = Find undated matches, goals without scorers, unnamed teams, duplicate players and empty rosters
+data-quality-empty-title = Nothing to fix
+data-quality-empty-message = No data quality issues were found.
+data-quality-undated-matches = Matches without a date
+data-quality-unscored-goals = Goals without a scorer
+data-quality-unnamed-teams = Teams without a name
+data-quality-duplicate-players = Possible duplicate players
+data-quality-empty-rosters = Rosters without players
+data-quality-fix = Fix
+data-quality-view-all = View all →
+data-quality-truncated = Only the first records are listed.
+
+# Unidentified goals report
+reports-unidentified-title = Unidentified Goals
+reports-unidentified-description = Matches whose score still includes goals without a recorded scorer, time and period, most goals first. Cancelled matches are not listed.
+reports-unidentified-card-description = Prioritize which matches need goal identification work
+reports-unidentified-empty-title = No unidentified goals
+reports-unidentified-empty-message = Every goal of the selected matches has been identified.
+reports-unidentified-goals = Unidentified goals
+reports-unidentified-matches = Matches
+reports-unidentified-match = Match
+reports-unidentified-identified = Identified
+reports-unidentified-by-season = By season
+reports-unidentified-by-team = By team
+reports-unidentified-identify = Identify goals
+
+# Team merge
+team-merge-open = Merge
+team-merge-title = Merge team
+team-merge-description = Move all participations, rosters, matches and score events of this team to another team, then delete this team. Its old links will lead to the other team.
+team-merge-into = Merge into
+team-merge-select-team = Select team
+team-merge-preview = Preview
+team-merge-participations = Season participations
+team-merge-matches = Matches
+team-merge-score-events = Score events
+team-merge-conflicts = The teams cannot be merged until these conflicts are resolved.
+team-merge-shared-seasons = Both teams took part in these seasons:
+team-merge-head-to-head = Matches and playoff series between the teams
+team-merge-action = Merge
+team-merge-confirm = This team will be deleted and everything recorded for it will belong to the other team. This cannot be undone.
+team-merge-failed = Failed to merge teams
+
+# Clone season
+seasons-clone = Clone
+seasons-clone-title = Clone Season
+seasons-clone-description = Create the next season of this event with the same groups and teams.
+seasons-clone-teams = Teams
+seasons-clone-copy-rosters = Copy rosters
+seasons-clone-not-copied = Matches, seeds, final ranks, playoffs and awards are not copied. Players transferred away during the season are left off the rosters.
+seasons-clone-submit = Clone Season
+seasons-clone-failed = Failed to clone season
+
+# Roster import
+roster-import-open = Import CSV
+roster-import-title = Import Roster
+roster-import-help = Upload a CSV file whose first row names the columns. A name column is required; number and country (ISO or IOC code) columns are optional. Players are matched by name, and players not found are created with the row's country or the team's country.
+roster-import-file = CSV file
+roster-import-review = Review
+roster-import-matched = Matched
+roster-import-new = New player
+roster-import-skipped = Skipped
+roster-import-on-roster = Already on the roster
+roster-import-submit = Import players
+
+# Player import
+players-import = Import CSV
+players-import-title = Import Players
+players-import-help = Upload a CSV file whose first row names the columns: name, country (ISO or IOC code), birth date (YYYY-MM-DD or DD.MM.YYYY) and position (Forward, Defense, Goalie or an abbreviation such as C, LW or D). Players that already exist are reported and not created again.
+players-import-file = CSV file
+players-import-submit = Import
+players-import-done = Import finished
+players-import-line = Line
+players-import-result = Result
+players-import-created = Created
+players-import-duplicates = Already existing
+players-import-duplicate-of = Already exists as
+players-import-invalid = Invalid
+
+# Match import
+match-import-title = Import Matches
+match-import-card-description = Create finished matches in bulk from a CSV or JSON file
+match-import-description = Upload a CSV or JSON file with one finished match per row: the season (ID or name), home and away team (name or external alias of a team taking part in the season), date and final score. Nothing is imported unless every row is valid. Scores are recorded as unidentified goals.
+match-import-api-hint = The same JSON list can be sent to
+match-import-file = CSV or JSON file
+match-import-submit = Import
+match-import-created = Matches created
+match-import-view-matches = View matches
+match-import-rejected = Nothing was imported. Fix these rows and upload the file again.
+match-import-line = Line
+match-import-season = Season
+match-import-match = Match
+match-import-error = Problem
+
+# List CSV export
+list-export-csv = Export CSV
+matches-export-goals = Export goals CSV
+
+# Season workbook export
+season-export-xlsx = Export XLSX
+season-export-rank = #
+season-export-group = Group
+season-export-scorers = Scorers
+season-export-player = Player
+season-export-teams = Teams
+season-export-schedule = Schedule
+season-export-home-score = Home goals
+season-export-away-score = Away goals
+season-export-arena = Arena
+
+# Instance transfer
+instance-transfer-title = Data Transfer
+instance-transfer-card-description = Move all data to another instance as a single JSON file
+instance-transfer-description = Export every player, team, event, season, roster and match of this instance into one JSON file, or import such a file from another instance. Records are matched by name, so internal IDs may differ between instances.
+instance-transfer-export = Export
+instance-transfer-export-help = The file contains all players, teams, events with their seasons, season rosters, matches and goals.
+instance-transfer-download = Download JSON
+instance-transfer-export-failed = Failed to export data
+instance-transfer-import = Import
+instance-transfer-import-help = Upload a file exported from another instance. The whole file is imported at once; if any record fails, nothing is changed.
+instance-transfer-file = Export file (.json)
+instance-transfer-submit = Import
+instance-transfer-done = Data imported
+
+# Backups
+backups-title = Backups
+backups-card-description = Backup schedule, retention and downloads of backup files
+backups-description = Compacted copies of the database written with VACUUM INTO by the nightly maintenance job and, with BACKUP_INTERVAL_HOURS, in between. Old backups beyond the retention limit are deleted after each backup.
+backups-run-now = Back Up Now
+backups-run-succeeded = Backup created
+backups-run-failed = Backup failed, see the System Status page for details
+backups-disabled-title = Backups are disabled
+backups-disabled-message = Set BACKUP_DIR to the directory where backups should be written and restart the application.
+backups-directory = Directory
+backups-interval = Interval
+backups-keep = Backups kept
+backups-keep-all = All
+backups-next = Next backup
+backups-empty-title = No backups yet
+backups-empty-message = Backups appear here after the next scheduled run or a manual backup.
+backups-file = File
+backups-created = Created
+backups-size = Size
+backups-download = Download
+backups-not-found = Backup not found
+
+# Public site
+public-site-name = Hockey
+public-sign-in = Sign in
+public-seasons-title = Seasons
+public-seasons-description = Schedules, standings, rosters and player statistics of every season.
+public-no-seasons-title = No seasons yet
+public-no-seasons-message = Seasons appear here once they are created.
+public-season = Season
+public-event = Event
+public-teams = Teams
+public-played = Played
+public-back-to-seasons = All seasons
+public-back-to-season = Back to season
+public-player-stats = Player statistics
+public-schedule = Schedule
+public-no-matches = No matches scheduled yet.
+public-player = Player
+public-no-roster = No players on the roster yet.
+public-no-points = No points recorded yet.
+public-not-found-title = Page not found
+public-not-found-message = This season or team does not exist.
+
+# Widgets
+widgets-view-full = Full details
+widgets-scoreboard-title = Today's matches
+widgets-no-matches-today = No matches today.
+
+# Chat webhooks
+chat-final-result = Final
+chat-webhooks-title = Chat notifications
+chat-webhooks-hint = Post final results and player milestones to Slack or Discord channels through incoming webhooks.
+chat-webhooks-empty = No chat webhooks yet.
+chat-webhooks-name = Name
+chat-webhooks-service = Service
+chat-webhooks-url = Webhook URL
+chat-webhooks-notifications = Notifications
+chat-webhooks-results = Final results
+chat-webhooks-milestones = Milestones
+chat-webhooks-add = Add webhook
+chat-webhooks-send-test = Send test
+chat-webhooks-test-message = Test message from the hockey manager
+chat-webhooks-test-sent = Test message sent
+chat-webhooks-test-failed = The chat service did not accept the test message
+chat-webhooks-confirm-delete = Results and milestones will no longer be posted to this channel.
+chat-webhooks-deleted = Webhook deleted
+
+# Webhooks
+webhooks-title = Webhooks
+webhooks-card-description = Notify external systems when matches, goals or players change
+webhooks-description = Every create, update or delete of a followed record is posted as JSON to the subscription URL. Verify the X-Hockey-Signature header: sha256= followed by the hex HMAC-SHA256 of the X-Hockey-Timestamp value, a dot and the request body, keyed with the secret. Failed deliveries are retried with increasing delays.
+webhooks-empty-title = No webhooks yet
+webhooks-empty-message = Add a URL below to start receiving changes.
+webhooks-url = URL
+webhooks-secret = Secret
+webhooks-follows = Sends
+webhooks-matches = Matches
+webhooks-score-events = Goals
+webhooks-players = Players
+webhooks-active = Active
+webhooks-add = Add webhook
+webhooks-created = Webhook added
+webhooks-saved = Webhook saved
+webhooks-save-failed = Failed to save the webhook
+webhooks-deleted = Webhook deleted
+webhooks-confirm-delete = The subscription and its delivery log will be removed.
+webhooks-deliveries-title = Recent deliveries
+webhooks-deliveries-empty = Nothing has been sent yet.
+webhooks-event = Event
+webhooks-status = Status
+webhooks-attempts = Attempts
+webhooks-created-at = Queued
+webhooks-status-pending = Pending
+webhooks-status-delivered = Delivered
+webhooks-status-failed = Failed
+webhooks-retry = Retry
+webhooks-retry-queued = Delivery queued again
+
+# Live console
+matches-console = Live console
+matches-console-back = Back to match
+matches-console-keys = Space start/stop clock · N next period · H / A goal · Shift+H / Shift+A penalty shot · Enter save · Esc leave form
+matches-console-clock-toggle = Start / stop
+matches-console-next-period = Next period
+matches-console-reset-clock = Reset clock
+matches-console-goal = Goal
+matches-console-save-goal = Save goal
+
+# API keys
+profile-api-keys = API keys
+profile-api-keys-hint = Scripts send a key in the X-Api-Key header to call the JSON API (/api/…, /graphql) as you. Revoke keys you no longer use.
+profile-api-keys-empty = No API keys yet
+profile-api-key-name = Name
+profile-api-key-name-placeholder = Name, e.g. Nightly import
+profile-api-key-prefix = Key
+profile-api-key-created-at = Created
+profile-api-key-last-used = Last used
+profile-api-key-never-used = Never
+profile-api-key-create = Create API key
+profile-api-key-new = Copy the new key now, it will not be shown again:
+profile-api-key-revoke = Revoke
+profile-api-key-confirm-revoke = Scripts using this key will stop working. This cannot be undone.
+profile-api-key-created = API key created
+profile-api-key-revoked = API key revoked
+profile-api-key-name-required = Enter a name for the key
+
+# Users
+users-title = Users
+users-description = Everyone who can sign in. Editors manage hockey data; admins can also manage users.
+users-card-description = Add users, change their role, deactivate accounts and reset passwords
+users-name = Name
+users-email = Email
+users-role = Role
+users-role-admin = Admin
+users-role-editor = Editor
+users-status = Status
+users-active = Active
+users-inactive = Deactivated
+users-password-pending = Password change pending
+users-last-login = Last sign-in
+users-never-signed-in = Never
+users-you = you
+users-create = Add user
+users-temporary-password = Hand over this temporary password now, it will not be shown again. It is for
+users-deactivate = Deactivate
+users-reactivate = Reactivate
+users-confirm-deactivate = The user is signed out and cannot sign in until reactivated. Their API keys stop working.
+users-reset-password = Reset password
+users-confirm-reset-password = The user is signed out and gets a temporary password they must change at the next sign-in.
+users-created = User added
+users-updated = User updated
+users-password-was-reset = Password reset
+password-change = Change password
+password-change-title = Change password
+password-change-description = Choose a new password of at least 8 characters that is not a common one. Your other sessions will be signed out.
+password-change-required = You signed in with a temporary password. Choose your own password to continue.
+password-current = Current password
+password-new = New password
+password-confirm = Confirm new password
+password-change-submit = Change password
+
+# Registration
+register-prompt = No account yet?
+register-link = Sign up
+register-title = Sign up
+register-name = Name
+register-button = Sign up
+register-back-to-signin = Back to sign in
+register-email-subject = Confirm your email address
+register-email-body = Thanks for signing up. Open this link to confirm your email address:
+register-email-expiry = The link works for 24 hours. If you did not sign up, ignore this email.
+register-email-existing = Someone tried to sign up with this email address, but it already has an account. Sign in instead, or use "Forgot your password?" on the sign-in page. If it was not you, ignore this email.
+register-error-email = We could not send the confirmation email. Please try again later.
+register-check-inbox-title = Check your inbox
+register-check-inbox = We sent a confirmation link to your email address. Open it within 24 hours to finish signing up.
+register-verify-title = Email confirmation
+register-verified = Your email address is confirmed. You can sign in now.
+register-awaiting-approval = Your email address is confirmed. An admin has to approve your account before you can sign in.
+register-link-expired = This link has expired. Sign up again with the same email address to get a new one.
+register-link-invalid = This link is invalid or has already been used.
+users-registration = Sign-up
+users-registration-hint = Let visitors create accounts from the sign-in page. New accounts are editors and must confirm their email address first.
+users-registration-enabled = Allow sign-up
+users-registration-requires-approval = An admin approves new accounts
+users-email-unverified = Email not confirmed
+users-approval-pending = Awaiting approval
+users-approve = Approve
+
+# Password reset
+reset-forgot-link = Forgot your password?
+reset-request-title = Reset password
+reset-request-hint = Enter the email address you sign in with and we will send you a link to choose a new password.
+reset-request-button = Send reset link
+reset-check-inbox = If an account uses this email address, we sent it a link to reset the password. The link works for 60 minutes.
+reset-email-subject = Reset your password
+reset-email-body = Someone asked to reset the password of your account. Open this link to choose a new one:
+reset-email-expiry = The link works for 60 minutes and only once. If you did not ask for it, ignore this email; your password stays the same.
+reset-title = Choose a new password
+reset-button = Set password
+reset-done = Your password has been changed and all your sessions were signed out. Sign in with the new password.
+reset-link-expired = This link has expired. Ask for a new one from the sign-in page.
+reset-link-invalid = This link is invalid or has already been used.
+
+# External sign-in
+signin-with = Sign in with
+signin-oauth-failed = Signing in at the provider did not work. Please try again.
+signin-oauth-no-account = No account is linked to this login. Sign in with your password and link it on your profile page, or ask an admin to add you with the same email address.
+identities-title = Linked accounts
+identities-hint = Sign in with these accounts instead of your password.
+identities-not-linked = Not linked
+identities-link = Link
+identities-unlink = Unlink
+identities-linked-elsewhere = This account is already linked to another user.
+identities-provider-taken = You already have an account of this provider linked. Unlink it first.
+
+# Account lockout
+signin-error-locked = Too many failed sign-in attempts. The account is locked for a while; try again later or reset your password.
+profile-login-locked = Account locked
+users-locked = Locked
+users-unlock = Unlock
+users-unlocked = Account unlocked
+users-activity = Sign-in activity
+users-activity-description = Latest sign-in attempts of all accounts, including attempts with unknown email addresses.
+users-activity-all = All attempts
+users-activity-failed-only = Failed only
+users-activity-email = Email
+users-activity-unknown-user = Unknown account
+users-activity-inactive = Inactive account
+users-activity-unlinked = Unlinked external account
+users-activity-empty-title = No sign-in attempts
+users-activity-empty-message = Sign-in attempts will appear here.
+
+# API key scopes
+profile-api-key-access = Access
+profile-api-key-full-access = Full access
+profile-api-key-scopes = Read-only access
+profile-api-key-scopes-hint = For integrations: check what the key may read. Such a key can change nothing and reaches only the matching /api/… endpoints, not /graphql. Leave everything unchecked for a key with full access as you.
+api-scope-matches-read = Matches and score events
+api-scope-stats-read = Team and season stats
+api-scope-teams-read = Team export
+api-scope-players-read = Player export
+
+# New sign-in address
+new-ip-email-subject = New sign-in to your account
+new-ip-email-body = Your account was just signed in to from an address it has not used before.
+new-ip-email-advice = If this was you, there is nothing to do. Otherwise change your password right away and let an administrator know.
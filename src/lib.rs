@@ -0,0 +1,36 @@
+//! Hockey management application
+//!
+//! The server binary (`src/main.rs`) wires these modules together; they are
+//! exposed as a library so integration tests can drive the real router
+//! through `test_support`.
+
+// Enums parse with `from_str(&str) -> Option<Self>` (or a default) rather than `FromStr`
+#![allow(clippy::should_implement_trait)]
+
+pub mod app;
+pub mod app_state;
+pub mod assets;
+pub mod auth;
+pub mod business;
+pub mod chat_notifications;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod common;
+pub mod config;
+pub mod error;
+pub mod graphql;
+pub mod grpc;
+pub mod i18n;
+pub mod live_updates;
+pub mod mailer;
+pub mod maintenance;
+pub mod routes;
+pub mod seed;
+pub mod server;
+pub mod service;
+pub mod slow_queries;
+pub mod test_support;
+pub mod utils;
+pub mod validation;
+pub mod views;
+pub mod webhooks;
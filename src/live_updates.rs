@@ -0,0 +1,150 @@
+//! Live score updates pushed to open browsers
+//!
+//! Handlers publish after a goal or a match was saved; every WebSocket
+//! connection (see `routes::live_updates`) forwards the updates of its
+//! match. Updates are not stored: a client that reconnects starts again from
+//! the current state sent on connect.
+
+use serde::Serialize;
+use sqlx::SqlitePool;
+use tokio::sync::broadcast;
+
+use crate::service::matches::{self, MatchEntity};
+
+/// Updates buffered per connection before a slow client starts missing some
+const CHANNEL_CAPACITY: usize = 256;
+
+/// What changed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LiveUpdateKind {
+    /// Current state, sent when a client connects
+    Snapshot,
+    /// A goal was added, edited or removed
+    ScoreEvent,
+    /// Match details such as the status or unidentified goals changed
+    Match,
+    /// The match was deleted
+    Deleted,
+}
+
+/// Score and status of a match after a change
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LiveUpdate {
+    pub match_id: i64,
+    pub kind: LiveUpdateKind,
+    /// `None` once the match is deleted
+    pub status: Option<String>,
+    pub home_score: Option<i32>,
+    pub away_score: Option<i32>,
+}
+
+impl LiveUpdate {
+    pub fn new(kind: LiveUpdateKind, game: &MatchEntity) -> Self {
+        Self {
+            match_id: game.id,
+            kind,
+            status: Some(game.status.clone()),
+            home_score: Some(game.home_score_total()),
+            away_score: Some(game.away_score_total()),
+        }
+    }
+
+    pub fn deleted(match_id: i64) -> Self {
+        Self {
+            match_id,
+            kind: LiveUpdateKind::Deleted,
+            status: None,
+            home_score: None,
+            away_score: None,
+        }
+    }
+}
+
+/// Fan-out of live updates to the open connections
+#[derive(Clone)]
+pub struct LiveUpdates {
+    sender: broadcast::Sender<LiveUpdate>,
+}
+
+impl Default for LiveUpdates {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LiveUpdates {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self { sender }
+    }
+
+    /// Receive every update published from now on
+    pub fn subscribe(&self) -> broadcast::Receiver<LiveUpdate> {
+        self.sender.subscribe()
+    }
+
+    /// Send an update to all connections; nobody listening is fine
+    pub fn send(&self, update: LiveUpdate) {
+        let _ = self.sender.send(update);
+    }
+
+    /// Announce the current score and status of a match after a change
+    pub async fn publish(&self, db: &SqlitePool, match_id: i64, kind: LiveUpdateKind) {
+        if self.sender.receiver_count() == 0 {
+            return;
+        }
+        match matches::get_match_by_id(db, match_id).await {
+            Ok(Some(game)) => self.send(LiveUpdate::new(kind, &game)),
+            Ok(None) => self.send(LiveUpdate::deleted(match_id)),
+            Err(e) => tracing::warn!("Failed to load match {} for live update: {}", match_id, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures(
+            path = "service/fixtures",
+            scripts("events", "seasons", "teams", "team_participations")
+        )
+    )]
+    async fn test_publish(pool: SqlitePool) {
+        let match_id = matches::create_match(
+            &pool,
+            matches::CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: 2,
+                away_score_unidentified: 1,
+                match_date: None,
+                status: "in_progress".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+        let live = LiveUpdates::new();
+        let mut receiver = live.subscribe();
+        live.publish(&pool, match_id, LiveUpdateKind::Match).await;
+        let update = receiver.recv().await.unwrap();
+        assert_eq!(update.kind, LiveUpdateKind::Match);
+        assert_eq!(update.status.as_deref(), Some("in_progress"));
+        assert_eq!((update.home_score, update.away_score), (Some(2), Some(1)));
+
+        matches::delete_match(&pool, match_id).await.unwrap();
+        live.publish(&pool, match_id, LiveUpdateKind::Match).await;
+        assert_eq!(
+            receiver.recv().await.unwrap(),
+            LiveUpdate::deleted(match_id)
+        );
+    }
+}
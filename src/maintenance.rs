@@ -0,0 +1,620 @@
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use chrono::{DateTime, Duration, Utc};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::SqlitePool;
+
+use crate::service::maintenance::{self, MaintenanceTask};
+
+/// Prefix and extension of backup files written by the job
+const BACKUP_PREFIX: &str = "hockey-";
+const BACKUP_EXTENSION: &str = ".db";
+
+/// Prefix of the temporary files written for snapshot downloads
+const SNAPSHOT_PREFIX: &str = "hockey-snapshot-";
+
+/// Database maintenance schedule
+#[derive(Debug, Clone)]
+pub struct MaintenanceConfig {
+    /// Hour of the day (UTC) the nightly run starts
+    pub hour: u32,
+    /// Directory for `VACUUM INTO` backups; `None` disables backups
+    pub backup_dir: Option<PathBuf>,
+    /// Backups to keep in `backup_dir`; 0 keeps all of them
+    pub backup_keep: usize,
+    /// Hours between backups, a divisor of 24; slots are aligned to `hour`,
+    /// where the backup runs as part of the nightly job
+    pub backup_interval_hours: u32,
+}
+
+impl Default for MaintenanceConfig {
+    fn default() -> Self {
+        Self {
+            hour: 3,
+            backup_dir: None,
+            backup_keep: 7,
+            backup_interval_hours: 24,
+        }
+    }
+}
+
+impl MaintenanceConfig {
+    /// When the next nightly run starts, strictly after `now`
+    pub fn next_run(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let today = now
+            .date_naive()
+            .and_hms_opt(self.hour, 0, 0)
+            .expect("Maintenance hour is validated to 0-23")
+            .and_utc();
+
+        if today > now {
+            today
+        } else {
+            today + Duration::days(1)
+        }
+    }
+
+    /// When the next backup is due, strictly after `now`; `None` when
+    /// backups are disabled
+    ///
+    /// Every backup slot that coincides with [`next_run`](Self::next_run) is
+    /// covered by the nightly job itself.
+    pub fn next_backup(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.backup_dir.as_ref()?;
+
+        let interval = Duration::hours(i64::from(self.backup_interval_hours.clamp(1, 24)));
+        // The previous nightly run is a slot; step forward from it
+        let mut slot = self.next_run(now) - Duration::days(1);
+        while slot <= now {
+            slot += interval;
+        }
+        Some(slot)
+    }
+}
+
+/// A backup file in the backup directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BackupFile {
+    pub name: String,
+    pub size_bytes: u64,
+    pub modified_at: DateTime<Utc>,
+}
+
+/// Result of one task in a maintenance run
+#[derive(Debug, Clone)]
+pub struct TaskOutcome {
+    pub task: MaintenanceTask,
+    pub success: bool,
+}
+
+/// Run the nightly job in the background
+pub fn spawn(db: SqlitePool, config: MaintenanceConfig) {
+    tokio::spawn(async move {
+        loop {
+            let now = Utc::now();
+            let next_run = config.next_run(now);
+            let wake = config
+                .next_backup(now)
+                .filter(|backup| *backup < next_run)
+                .unwrap_or(next_run);
+            let wait = (wake - now).to_std().unwrap_or(std::time::Duration::ZERO);
+            tokio::time::sleep(wait).await;
+
+            // Backup slots between nightly runs only back up
+            if wake < next_run {
+                if let Some(outcome) = run_backup(&db, &config).await {
+                    tracing::info!(
+                        "Scheduled backup finished: {}",
+                        if outcome.success { "ok" } else { "failed" }
+                    );
+                }
+                continue;
+            }
+
+            let outcomes = run(&db, &config).await;
+            let summary = outcomes
+                .iter()
+                .map(|o| {
+                    format!(
+                        "{} {}",
+                        o.task.as_str(),
+                        if o.success { "ok" } else { "failed" }
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            tracing::info!("Database maintenance finished: {}", summary);
+        }
+    });
+}
+
+/// Checkpoint, optimize and (when configured) back up the database
+///
+/// Each task is recorded in `maintenance_run`; a failing task does not stop
+/// the ones after it.
+pub async fn run(db: &SqlitePool, config: &MaintenanceConfig) -> Vec<TaskOutcome> {
+    let mut outcomes = Vec::new();
+
+    let started = Instant::now();
+    let checkpoint = match maintenance::checkpoint_wal(db).await {
+        Ok(result) if result.log_frames < 0 => Ok("Not in WAL mode".to_string()),
+        Ok(result) if result.busy => Err(format!(
+            "Busy: {} of {} frames checkpointed",
+            result.checkpointed_frames, result.log_frames
+        )),
+        Ok(result) => Ok(format!(
+            "{} frames checkpointed",
+            result.checkpointed_frames
+        )),
+        Err(e) => Err(e.to_string()),
+    };
+    outcomes.push(record(db, MaintenanceTask::Checkpoint, checkpoint, started).await);
+
+    let started = Instant::now();
+    let optimize = maintenance::optimize(db)
+        .await
+        .map(|_| String::new())
+        .map_err(|e| e.to_string());
+    outcomes.push(record(db, MaintenanceTask::Optimize, optimize, started).await);
+
+    outcomes.extend(run_backup(db, config).await);
+
+    outcomes
+}
+
+/// Back up the database and prune old backups, when a backup directory is set
+///
+/// The result is recorded in `maintenance_run` like the nightly tasks.
+pub async fn run_backup(db: &SqlitePool, config: &MaintenanceConfig) -> Option<TaskOutcome> {
+    let dir = config.backup_dir.as_ref()?;
+    let started = Instant::now();
+    let backup = backup(db, dir, config.backup_keep).await;
+    Some(record(db, MaintenanceTask::Backup, backup, started).await)
+}
+
+/// Backups in `dir`, newest first
+pub fn list_backups(dir: &Path) -> std::io::Result<Vec<BackupFile>> {
+    let mut backups = Vec::new();
+    for path in backup_paths(dir)? {
+        let metadata = std::fs::metadata(&path)?;
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        backups.push(BackupFile {
+            name: name.to_string(),
+            size_bytes: metadata.len(),
+            modified_at: metadata.modified().map(DateTime::<Utc>::from)?,
+        });
+    }
+
+    // Timestamped names sort chronologically
+    backups.sort_by(|a, b| b.name.cmp(&a.name));
+    Ok(backups)
+}
+
+/// Path of the backup called `name` in `dir`
+///
+/// `None` unless `name` is a plain backup file name, so request parameters
+/// cannot point outside the backup directory.
+pub fn backup_path(dir: &Path, name: &str) -> Option<PathBuf> {
+    let valid = is_backup_name(name)
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && !name.contains("..");
+    valid.then(|| dir.join(name))
+}
+
+fn is_backup_name(name: &str) -> bool {
+    name.starts_with(BACKUP_PREFIX) && name.ends_with(BACKUP_EXTENSION)
+}
+
+/// Backup files directly in `dir`, unsorted
+fn backup_paths(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    Ok(std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(is_backup_name)
+        })
+        .collect())
+}
+
+/// Log and store a task result
+async fn record(
+    db: &SqlitePool,
+    task: MaintenanceTask,
+    result: Result<String, String>,
+    started: Instant,
+) -> TaskOutcome {
+    let duration_ms = started.elapsed().as_millis() as i64;
+    let (success, detail) = match result {
+        Ok(detail) => (true, Some(detail).filter(|d| !d.is_empty())),
+        Err(error) => {
+            tracing::error!("Maintenance task {} failed: {}", task.as_str(), error);
+            (false, Some(error))
+        }
+    };
+
+    if let Err(e) = maintenance::record_run(db, task, success, detail.as_deref(), duration_ms).await
+    {
+        tracing::error!("Failed to record maintenance run: {}", e);
+    }
+
+    TaskOutcome { task, success }
+}
+
+/// Write a timestamped backup into `dir` and prune old ones
+async fn backup(db: &SqlitePool, dir: &Path, keep: usize) -> Result<String, String> {
+    std::fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+
+    let name = format!(
+        "{}{}{}",
+        BACKUP_PREFIX,
+        Utc::now().format("%Y%m%d-%H%M%S"),
+        BACKUP_EXTENSION
+    );
+    let path = dir.join(&name);
+    let path_str = path
+        .to_str()
+        .ok_or_else(|| format!("{}: not a valid UTF-8 path", path.display()))?;
+
+    maintenance::vacuum_into(db, path_str)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let size = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+    let removed = prune_backups(dir, keep).map_err(|e| e.to_string())?;
+
+    Ok(if removed > 0 {
+        format!(
+            "{} ({} bytes), {} old backup(s) removed",
+            name, size, removed
+        )
+    } else {
+        format!("{} ({} bytes)", name, size)
+    })
+}
+
+/// Delete all but the newest `keep` backups, returning how many were removed
+fn prune_backups(dir: &Path, keep: usize) -> std::io::Result<usize> {
+    if keep == 0 {
+        return Ok(0);
+    }
+
+    // Timestamped names sort chronologically
+    let mut backups = backup_paths(dir)?;
+    backups.sort();
+
+    let excess = backups.len().saturating_sub(keep);
+    for path in &backups[..excess] {
+        std::fs::remove_file(path)?;
+    }
+
+    Ok(excess)
+}
+
+/// Write a consistent copy of the database and open it for reading
+///
+/// The copy is made with `VACUUM INTO` in the temp directory and unlinked
+/// right after opening, so it disappears once the file is dropped, even when
+/// a download is cancelled halfway.
+pub async fn snapshot(db: &SqlitePool) -> Result<tokio::fs::File, String> {
+    let path = std::env::temp_dir().join(format!(
+        "{}{}{}",
+        SNAPSHOT_PREFIX,
+        uuid::Uuid::new_v4(),
+        BACKUP_EXTENSION
+    ));
+    let path_str = path
+        .to_str()
+        .ok_or_else(|| format!("{}: not a valid UTF-8 path", path.display()))?;
+
+    maintenance::vacuum_into(db, path_str)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let file = tokio::fs::File::open(&path).await;
+    if let Err(e) = tokio::fs::remove_file(&path).await {
+        tracing::warn!("Failed to remove snapshot {}: {}", path.display(), e);
+    }
+    file.map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Replace the database file at `database` with a snapshot or backup
+///
+/// Meant for the `restore_snapshot` command while the server is stopped. The
+/// snapshot must pass an integrity check and contain the migrations table
+/// before anything is touched. The current database is kept next to it as
+/// `<name>.pre-restore-<timestamp>` (returned when there was one), and its
+/// `-wal`/`-shm` files are removed so SQLite does not replay them onto the
+/// restored copy.
+///
+/// Writes still in the WAL are checkpointed into the database file before it
+/// is copied, so the kept copy is complete. When that fails, e.g. because the
+/// database is damaged, the WAL is moved next to the copy instead.
+pub async fn restore_snapshot(snapshot: &Path, database: &Path) -> Result<Option<PathBuf>, String> {
+    check_snapshot(snapshot)
+        .await
+        .map_err(|e| format!("{}: not a usable snapshot: {}", snapshot.display(), e))?;
+
+    let with_suffix = |path: &Path, suffix: &str| {
+        let mut name = path.as_os_str().to_owned();
+        name.push(suffix);
+        PathBuf::from(name)
+    };
+
+    let previous = if database.exists() {
+        let checkpointed = match checkpoint(database).await {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::warn!("Failed to checkpoint {}: {}", database.display(), e);
+                false
+            }
+        };
+        let previous = with_suffix(
+            database,
+            &format!(".pre-restore-{}", Utc::now().format("%Y%m%d-%H%M%S")),
+        );
+        std::fs::copy(database, &previous).map_err(|e| format!("{}: {}", previous.display(), e))?;
+        let wal = with_suffix(database, "-wal");
+        if !checkpointed && wal.exists() {
+            let kept = with_suffix(&previous, "-wal");
+            std::fs::rename(&wal, &kept).map_err(|e| format!("{}: {}", kept.display(), e))?;
+        }
+        Some(previous)
+    } else {
+        None
+    };
+
+    for suffix in ["-wal", "-shm"] {
+        let path = with_suffix(database, suffix);
+        if path.exists() {
+            std::fs::remove_file(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        }
+    }
+
+    std::fs::copy(snapshot, database).map_err(|e| format!("{}: {}", database.display(), e))?;
+    Ok(previous)
+}
+
+/// Copy every write in the WAL of `database` into the database file and empty the WAL
+async fn checkpoint(database: &Path) -> Result<(), sqlx::Error> {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(SqliteConnectOptions::new().filename(database))
+        .await?;
+
+    // The first column is 1 when another connection kept the checkpoint
+    // from finishing
+    let result = sqlx::query_as::<_, (i64, i64, i64)>("PRAGMA wal_checkpoint(TRUNCATE)")
+        .fetch_one(&pool)
+        .await
+        .and_then(|(busy, _, _)| match busy {
+            0 => Ok(()),
+            _ => Err(sqlx::Error::Protocol("database is busy".to_string())),
+        });
+
+    pool.close().await;
+    result
+}
+
+/// Open a snapshot read-only and make sure it is an intact database of this app
+async fn check_snapshot(snapshot: &Path) -> Result<(), sqlx::Error> {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(
+            SqliteConnectOptions::new()
+                .filename(snapshot)
+                .read_only(true),
+        )
+        .await?;
+
+    let result = async {
+        let integrity: String = sqlx::query_scalar("PRAGMA integrity_check")
+            .fetch_one(&pool)
+            .await?;
+        if integrity != "ok" {
+            return Err(sqlx::Error::Protocol(format!(
+                "integrity check failed: {}",
+                integrity
+            )));
+        }
+        sqlx::query("SELECT version FROM _sqlx_migrations LIMIT 1")
+            .execute(&pool)
+            .await?;
+        Ok(())
+    }
+    .await;
+
+    pool.close().await;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 14, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_next_run() {
+        let config = MaintenanceConfig::default();
+        assert_eq!(config.next_run(at(1, 30)), at(3, 0));
+        assert_eq!(config.next_run(at(3, 0)), at(3, 0) + Duration::days(1));
+        assert_eq!(config.next_run(at(22, 0)), at(3, 0) + Duration::days(1));
+    }
+
+    #[test]
+    fn test_next_backup() {
+        let mut config = MaintenanceConfig::default();
+        assert_eq!(config.next_backup(at(1, 30)), None);
+
+        config.backup_dir = Some(PathBuf::from("/backups"));
+        assert_eq!(config.next_backup(at(1, 30)), Some(at(3, 0)));
+
+        // Every 6 hours from the maintenance hour: 03, 09, 15, 21
+        config.backup_interval_hours = 6;
+        assert_eq!(config.next_backup(at(1, 30)), Some(at(3, 0)));
+        assert_eq!(config.next_backup(at(3, 0)), Some(at(9, 0)));
+        assert_eq!(config.next_backup(at(16, 0)), Some(at(21, 0)));
+        assert_eq!(
+            config.next_backup(at(22, 0)),
+            Some(at(3, 0) + Duration::days(1))
+        );
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_run_backs_up_and_prunes(pool: SqlitePool) {
+        let dir = std::env::temp_dir().join(format!("hockey-backups-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        for old in ["hockey-20200101-000000.db", "hockey-20200102-000000.db"] {
+            std::fs::write(dir.join(old), b"old").unwrap();
+        }
+        std::fs::write(dir.join("notes.txt"), b"keep me").unwrap();
+
+        let config = MaintenanceConfig {
+            hour: 3,
+            backup_dir: Some(dir.clone()),
+            backup_keep: 2,
+            ..Default::default()
+        };
+        let outcomes = run(&pool, &config).await;
+        assert_eq!(outcomes.len(), 3);
+        assert!(outcomes.iter().all(|o| o.success), "{:?}", outcomes);
+
+        let mut names: Vec<String> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().into_string().unwrap())
+            .collect();
+        names.sort();
+        assert_eq!(names.len(), 3);
+        assert_eq!(names[0], "hockey-20200102-000000.db");
+        assert_eq!(names[2], "notes.txt");
+
+        let backups = list_backups(&dir).unwrap();
+        assert_eq!(backups.len(), 2);
+        assert_eq!(backups[0].name, names[1]);
+        assert_eq!(backups[1].name, "hockey-20200102-000000.db");
+        assert_eq!(backups[1].size_bytes, 3);
+
+        assert_eq!(
+            backup_path(&dir, "hockey-20200102-000000.db"),
+            Some(dir.join("hockey-20200102-000000.db"))
+        );
+        assert_eq!(backup_path(&dir, "notes.txt"), None);
+        assert_eq!(backup_path(&dir, "hockey-../../etc/passwd.db"), None);
+
+        let runs = maintenance::get_recent_runs(&pool, 10).await.unwrap();
+        assert_eq!(runs.len(), 3);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_snapshot_and_restore(pool: SqlitePool) {
+        let mut file = snapshot(&pool).await.unwrap();
+        let mut data = Vec::new();
+        tokio::io::AsyncReadExt::read_to_end(&mut file, &mut data)
+            .await
+            .unwrap();
+        assert!(data.starts_with(b"SQLite format 3\0"));
+
+        let dir = std::env::temp_dir().join(format!("hockey-restore-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let snapshot_path = dir.join("snapshot.db");
+        std::fs::write(&snapshot_path, &data).unwrap();
+        let database = dir.join("hockey.db");
+        std::fs::write(&database, b"old database").unwrap();
+        std::fs::write(dir.join("hockey.db-wal"), b"stale").unwrap();
+
+        let previous = restore_snapshot(&snapshot_path, &database)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(std::fs::read(&previous).unwrap(), b"old database");
+        assert_eq!(std::fs::read(&database).unwrap(), data);
+        assert!(!dir.join("hockey.db-wal").exists());
+
+        // Anything but an intact database of this app is refused
+        let garbage = dir.join("garbage.db");
+        std::fs::write(&garbage, b"not a database").unwrap();
+        assert!(restore_snapshot(&garbage, &database).await.is_err());
+        assert_eq!(std::fs::read(&database).unwrap(), data);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_restore_keeps_uncheckpointed_writes(pool: SqlitePool) {
+        let dir = std::env::temp_dir().join(format!("hockey-restore-wal-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let snapshot_path = dir.join("snapshot.db");
+        let mut file = snapshot(&pool).await.unwrap();
+        let mut data = Vec::new();
+        tokio::io::AsyncReadExt::read_to_end(&mut file, &mut data)
+            .await
+            .unwrap();
+        std::fs::write(&snapshot_path, &data).unwrap();
+
+        // A server that stopped without checkpointing leaves its last writes
+        // in the WAL only
+        let live = dir.join("live.db");
+        let live_pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(
+                SqliteConnectOptions::new()
+                    .filename(&live)
+                    .create_if_missing(true)
+                    .journal_mode(sqlx::sqlite::SqliteJournalMode::Wal),
+            )
+            .await
+            .unwrap();
+        for statement in [
+            "PRAGMA wal_autocheckpoint = 0",
+            "CREATE TABLE note (body TEXT NOT NULL)",
+            "INSERT INTO note (body) VALUES ('written before the restore')",
+        ] {
+            sqlx::query(statement).execute(&live_pool).await.unwrap();
+        }
+        let database = dir.join("hockey.db");
+        std::fs::copy(&live, &database).unwrap();
+        std::fs::copy(dir.join("live.db-wal"), dir.join("hockey.db-wal")).unwrap();
+        live_pool.close().await;
+
+        let previous = restore_snapshot(&snapshot_path, &database)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(std::fs::read(&database).unwrap(), data);
+        assert!(!dir.join("hockey.db-wal").exists());
+
+        let previous_pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(
+                SqliteConnectOptions::new()
+                    .filename(&previous)
+                    .read_only(true),
+            )
+            .await
+            .unwrap();
+        let notes: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM note")
+            .fetch_one(&previous_pool)
+            .await
+            .unwrap();
+        assert_eq!(notes, 1);
+        previous_pool.close().await;
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
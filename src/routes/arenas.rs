@@ -0,0 +1,511 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::{
+    arenas::{self, ArenaFilters, CreateArenaEntity, SortField, SortOrder, UpdateArenaEntity},
+    countries, matches,
+};
+use crate::validation::{validate_arena_capacity, validate_name};
+use crate::views::{
+    components::{error::error_message, htmx::htmx_reload_table},
+    layout::admin_layout,
+    pages::arena_detail::arena_detail_page,
+    pages::arenas::{arena_create_modal, arena_edit_modal, arena_list_content, arenas_page},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct ArenasQuery {
+    #[serde(default = "default_page")]
+    page: usize,
+    #[serde(default = "default_page_size")]
+    page_size: usize,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    name: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    country_id: Option<i64>,
+    #[serde(default = "default_sort")]
+    sort: String,
+    #[serde(default = "default_order")]
+    order: String,
+}
+
+fn default_page() -> usize {
+    1
+}
+
+fn default_page_size() -> usize {
+    20
+}
+
+fn default_sort() -> String {
+    "name".to_string()
+}
+
+fn default_order() -> String {
+    "asc".to_string()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateArenaForm {
+    name: String,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    city: Option<String>,
+    country_id: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    capacity: Option<i64>,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateArenaForm {
+    name: String,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    city: Option<String>,
+    country_id: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    capacity: Option<i64>,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteArenaForm {
+    csrf_token: String,
+}
+
+/// Validate the name and capacity shared by the create and edit forms
+fn validate_arena_form(
+    name: &str,
+    capacity: Option<i64>,
+) -> Result<(String, Option<i64>), &'static str> {
+    let name = validate_name(name)?;
+    let capacity = validate_arena_capacity(capacity)?;
+    Ok((name, capacity))
+}
+
+/// GET /arenas - Arenas list page
+pub async fn arenas_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<ArenasQuery>,
+) -> impl IntoResponse {
+    // Build filters
+    let filters = ArenaFilters {
+        name: query.name.clone(),
+        country_id: query.country_id,
+    };
+
+    // Parse sort parameters
+    let sort_field = SortField::from_str(&query.sort);
+    let sort_order = SortOrder::from_str(&query.order);
+
+    // Get arenas
+    let result = match arenas::get_arenas(
+        &state.db,
+        &filters,
+        &sort_field,
+        &sort_order,
+        query.page,
+        query.page_size,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            tracing::error!("Failed to fetch arenas: {}", e);
+            return Html(
+                admin_layout(
+                    "Arenas",
+                    &session,
+                    "/arenas",
+                    &t,
+                    crate::views::components::error::error_message(
+                        &t,
+                        t.messages.error_failed_to_load_arenas(),
+                    ),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    // Get countries for filter
+    let countries = match countries::get_countries_simple(&state.db).await {
+        Ok(countries) => countries,
+        Err(e) => {
+            tracing::warn!("Failed to load countries for filter dropdown: {}", e);
+            Vec::new()
+        }
+    };
+
+    let content = arenas_page(
+        &session,
+        &t,
+        &result,
+        &filters,
+        &sort_field,
+        &sort_order,
+        &countries,
+    );
+    Html(admin_layout("Arenas", &session, "/arenas", &t, content).into_string())
+}
+
+/// GET /arenas/list - HTMX endpoint for table updates
+pub async fn arenas_list_partial(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<ArenasQuery>,
+) -> impl IntoResponse {
+    let filters = ArenaFilters {
+        name: query.name.clone(),
+        country_id: query.country_id,
+    };
+
+    // Parse sort parameters
+    let sort_field = SortField::from_str(&query.sort);
+    let sort_order = SortOrder::from_str(&query.order);
+
+    let result = match arenas::get_arenas(
+        &state.db,
+        &filters,
+        &sort_field,
+        &sort_order,
+        query.page,
+        query.page_size,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            tracing::error!("Failed to fetch arenas: {}", e);
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_arenas(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    Html(
+        arena_list_content(&session, &t, &result, &filters, &sort_field, &sort_order).into_string(),
+    )
+}
+
+/// GET /arenas/new - Show create modal
+pub async fn arena_create_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+) -> impl IntoResponse {
+    Html(arena_create_modal(&session, &t, None).into_string())
+}
+
+/// POST /arenas - Create new arena
+pub async fn arena_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<CreateArenaForm>,
+) -> impl IntoResponse {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    // Validation
+    let (name, capacity) = match validate_arena_form(&form.name, form.capacity) {
+        Ok(v) => v,
+        Err(error) => {
+            return Html(arena_create_modal(&session, &t, Some(error)).into_string())
+                .into_response()
+        }
+    };
+
+    // Create arena
+    match arenas::create_arena(
+        &state.db,
+        CreateArenaEntity {
+            name: name.to_string(),
+            city: form.city.map(|c| c.trim().to_string()),
+            country_id: form.country_id,
+            capacity,
+        },
+    )
+    .await
+    {
+        Ok(_) => {
+            use axum::http::header::{HeaderMap, HeaderName};
+
+            // Return HTMX response to close modal and reload table
+            // Trigger entity-created event for dashboard stats update
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-trigger"),
+                "entity-created".parse().unwrap(),
+            );
+            headers.insert(
+                HeaderName::from_static("hx-toast-success"),
+                t.messages.arenas_created().to_string().parse().unwrap(),
+            );
+            (headers, htmx_reload_table("/arenas/list", "arenas-table")).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to create arena: {}", e);
+            Html(arena_create_modal(&session, &t, Some("Failed to create arena")).into_string())
+                .into_response()
+        }
+    }
+}
+
+/// GET /arenas/{id} - Arena detail page
+pub async fn arena_detail(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let arena = match arenas::get_arena_by_id(&state.db, id).await {
+        Ok(Some(arena)) => arena,
+        Ok(None) => {
+            return Html(
+                admin_layout(
+                    "Arena Not Found",
+                    &session,
+                    "/arenas",
+                    &t,
+                    error_message(&t, t.messages.error_arena_not_found()),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch arena detail: {}", e);
+            return Html(
+                admin_layout(
+                    "Error",
+                    &session,
+                    "/arenas",
+                    &t,
+                    error_message(&t, t.messages.error_failed_to_load_arena()),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let attendance = match matches::get_arena_attendance(&state.db, id).await {
+        Ok(attendance) => attendance,
+        Err(e) => {
+            tracing::warn!("Failed to load attendance for arena {}: {}", id, e);
+            Vec::new()
+        }
+    };
+
+    let content = arena_detail_page(&t, &arena, &attendance);
+    Html(admin_layout("Arena Detail", &session, "/arenas", &t, content).into_string())
+}
+
+/// GET /arenas/{id}/edit - Show edit modal
+pub async fn arena_edit_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let arena = match arenas::get_arena_by_id(&state.db, id).await {
+        Ok(Some(arena)) => arena,
+        Ok(None) => {
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_arena_not_found(),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch arena: {}", e);
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_arena(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    Html(arena_edit_modal(&session, &t, &arena, None).into_string())
+}
+
+/// POST /arenas/{id} - Update arena
+pub async fn arena_update(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<UpdateArenaForm>,
+) -> impl IntoResponse {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    // Validation
+    let (name, capacity) = match validate_arena_form(&form.name, form.capacity) {
+        Ok(v) => v,
+        Err(error) => {
+            let arena = match arenas::get_arena_by_id(&state.db, id).await {
+                Ok(Some(arena)) => arena,
+                Ok(None) => {
+                    return Html(
+                        error_message(&t, t.messages.error_arena_not_found()).into_string(),
+                    )
+                    .into_response();
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "Database error fetching arena {} for form re-render: {}",
+                        id,
+                        e
+                    );
+                    return Html(
+                        error_message(&t, t.messages.error_failed_to_load_arena()).into_string(),
+                    )
+                    .into_response();
+                }
+            };
+            return Html(arena_edit_modal(&session, &t, &arena, Some(error)).into_string())
+                .into_response();
+        }
+    };
+
+    // Update arena
+    match arenas::update_arena(
+        &state.db,
+        id,
+        UpdateArenaEntity {
+            name: name.to_string(),
+            city: form.city.map(|c| c.trim().to_string()),
+            country_id: form.country_id,
+            capacity,
+        },
+    )
+    .await
+    {
+        Ok(true) => {
+            // Return HTMX response to close modal and reload table
+            use axum::http::header::{HeaderMap, HeaderName};
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-toast-success"),
+                t.messages.arenas_updated().to_string().parse().unwrap(),
+            );
+            (headers, htmx_reload_table("/arenas/list", "arenas-table")).into_response()
+        }
+        Ok(false) => Html(error_message(&t, t.messages.error_arena_not_found()).into_string())
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to update arena: {}", e);
+            let arena = match arenas::get_arena_by_id(&state.db, id).await {
+                Ok(Some(arena)) => arena,
+                Ok(None) => {
+                    return Html(
+                        error_message(&t, t.messages.error_arena_not_found()).into_string(),
+                    )
+                    .into_response();
+                }
+                Err(e2) => {
+                    tracing::error!(
+                        "Database error fetching arena {} for form re-render: {}",
+                        id,
+                        e2
+                    );
+                    return Html(
+                        error_message(&t, t.messages.error_failed_to_load_arena()).into_string(),
+                    )
+                    .into_response();
+                }
+            };
+            Html(
+                arena_edit_modal(&session, &t, &arena, Some("Failed to update arena"))
+                    .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
+
+/// POST /arenas/{id}/delete - Delete arena
+pub async fn arena_delete(
+    Extension(session): Extension<Session>,
+    State(state): State<AppState>,
+    Extension(t): Extension<TranslationContext>,
+    Path(id): Path<i64>,
+    Query(query): Query<ArenasQuery>,
+    Form(form): Form<DeleteArenaForm>,
+) -> impl IntoResponse {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match arenas::delete_arena(&state.db, id).await {
+        Ok(true) => {
+            // Build URL to reload table with current filters and sorting
+            let mut reload_url = format!(
+                "/arenas/list?page={}&page_size={}&sort={}&order={}",
+                query.page, query.page_size, query.sort, query.order
+            );
+
+            if let Some(name) = &query.name {
+                reload_url.push_str(&format!("&name={}", urlencoding::encode(name)));
+            }
+
+            if let Some(country_id) = query.country_id {
+                reload_url.push_str(&format!("&country_id={}", country_id));
+            }
+
+            // Return HTMX response to reload table with filters
+            use axum::http::header::{HeaderMap, HeaderName};
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-toast-success"),
+                t.messages.arenas_deleted().to_string().parse().unwrap(),
+            );
+            (
+                headers,
+                Html(format!(
+                    "<div hx-get=\"{}\" hx-target=\"#arenas-table\" hx-trigger=\"load\" hx-swap=\"outerHTML\"></div>",
+                    reload_url
+                )),
+            )
+                .into_response()
+        }
+        Ok(false) => Html(
+            crate::views::components::error::error_message(&t, t.messages.error_arena_not_found())
+                .into_string(),
+        )
+        .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to delete arena: {}", e);
+            Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_delete_arena(),
+                )
+                .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use axum::{
     extract::{Extension, State},
+    http::{HeaderMap, StatusCode},
     response::{Html, IntoResponse, Redirect},
     Form,
 };
@@ -11,11 +12,17 @@ use serde::Deserialize;
 use sqlx::Row;
 
 use crate::app_state::AppState;
+use crate::auth::audit::{self, ClientIp, LoginAttempt, LoginFailure};
+use crate::auth::demo::{self, DEMO_USER_NAME};
+use crate::auth::lockout;
+use crate::auth::password::{hash_password, needs_rehash};
 use crate::auth::{
-    sign_session_id, verify_password, verify_signed_session_id, SESSION_COOKIE_NAME,
+    sign_session_id, verify_password, verify_signed_session_id, Session, SESSION_COOKIE_NAME,
 };
 use crate::i18n::TranslationContext;
-use crate::views::pages::auth::login_page;
+use crate::routes::registration::registration_settings;
+use crate::service::users;
+use crate::views::pages::auth::{login_page, LoginOptions};
 
 #[derive(Debug, Deserialize)]
 pub struct LoginForm {
@@ -24,8 +31,12 @@ pub struct LoginForm {
 }
 
 /// GET /auth/login - Show login page
-pub async fn login_get(Extension(t): Extension<TranslationContext>) -> Html<String> {
-    let html = login_page(&t, None);
+pub async fn login_get(
+    State(state): State<AppState>,
+    Extension(t): Extension<TranslationContext>,
+) -> Html<String> {
+    let options = login_options(&state).await;
+    let html = login_page(&t, &options, None);
     Html(html.into_string())
 }
 
@@ -33,34 +44,53 @@ pub async fn login_get(Extension(t): Extension<TranslationContext>) -> Html<Stri
 pub async fn login_post(
     State(state): State<AppState>,
     Extension(t): Extension<TranslationContext>,
+    Extension(ClientIp(client_ip)): Extension<ClientIp>,
+    headers: HeaderMap,
     jar: CookieJar,
     Form(form): Form<LoginForm>,
 ) -> Result<impl IntoResponse, impl IntoResponse> {
+    let options = login_options(&state).await;
+    let mut attempt = LoginAttempt {
+        user_id: None,
+        email: form.email.clone(),
+        failure: None,
+        ip_address: client_ip,
+        user_agent: audit::user_agent(&headers),
+    };
+
     // Verify user credentials using raw query
-    let user_row = sqlx::query("SELECT id, email, password_hash, name FROM users WHERE email = ?")
-        .bind(&form.email)
-        .fetch_optional(&state.db)
-        .await
-        .map_err(|e| {
-            tracing::error!("Database error during login: {}", e);
-            (
-                jar.clone(),
-                Html(
-                    login_page(&t, Some(t.messages.signin_error_general().to_string()))
+    let user_row =
+        sqlx::query("SELECT id, email, password_hash, name, active, email_verified_at IS NULL AS unverified, approval_pending FROM users WHERE email = ? COLLATE NOCASE")
+            .bind(&form.email)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(|e| {
+                tracing::error!("Database error during login: {}", e);
+                (
+                    jar.clone(),
+                    Html(
+                        login_page(
+                            &t,
+                            &options,
+                            Some(t.messages.signin_error_general().to_string()),
+                        )
                         .into_string(),
-                ),
-            )
-        })?;
+                    ),
+                )
+            })?;
 
     // Check if user exists
     let user_row = match user_row {
         Some(row) => row,
         None => {
+            attempt.failure = Some(LoginFailure::UnknownUser);
+            record_attempt(&state, &t, &attempt).await;
             return Err((
                 jar,
                 Html(
                     login_page(
                         &t,
+                        &options,
                         Some(t.messages.signin_error_invalid_credentials().to_string()),
                     )
                     .into_string(),
@@ -74,26 +104,85 @@ pub async fn login_post(
     let user_email: String = user_row.get("email");
     let user_name: String = user_row.get("name");
     let password_hash: String = user_row.get("password_hash");
+    attempt.user_id = Some(user_id);
+
+    // Locked accounts refuse every password, right or wrong, until the lockout
+    // runs out, so guessing cannot continue in the meantime
+    let locked = lockout::is_locked(&state.db, user_id)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::error!("Failed to check lockout of {}: {}", user_email, e);
+            false
+        });
+    if locked {
+        attempt.failure = Some(LoginFailure::LockedOut);
+        record_attempt(&state, &t, &attempt).await;
+        return Err((
+            jar,
+            Html(
+                login_page(
+                    &t,
+                    &options,
+                    Some(t.messages.signin_error_locked().to_string()),
+                )
+                .into_string(),
+            ),
+        ));
+    }
 
     // Verify password
     let password_valid = verify_password(&form.password, &password_hash).map_err(|e| {
         tracing::error!("Password verification error: {}", e);
         (
             jar.clone(),
-            Html(login_page(&t, Some(t.messages.signin_error_general().to_string())).into_string()),
-        )
-    })?;
-
-    if !password_valid {
-        return Err((
-            jar,
             Html(
                 login_page(
                     &t,
-                    Some(t.messages.signin_error_invalid_credentials().to_string()),
+                    &options,
+                    Some(t.messages.signin_error_general().to_string()),
                 )
                 .into_string(),
             ),
+        )
+    })?;
+
+    if !password_valid {
+        attempt.failure = Some(LoginFailure::InvalidPassword);
+        record_attempt(&state, &t, &attempt).await;
+        let locked = lockout::record_failed_login(&state.db, user_id, &state.lockout)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::error!("Failed to count failed login of {}: {}", user_email, e);
+                false
+            });
+        let message = if locked {
+            tracing::warn!("Account {} locked after repeated failed logins", user_email);
+            t.messages.signin_error_locked().to_string()
+        } else {
+            t.messages.signin_error_invalid_credentials().to_string()
+        };
+        return Err((
+            jar,
+            Html(login_page(&t, &options, Some(message)).into_string()),
+        ));
+    }
+
+    // Inactive users (deactivated, unconfirmed or unapproved sign-ups) keep
+    // their data but cannot sign in
+    let active: bool = user_row.get("active");
+    if !active {
+        let message = if user_row.get::<bool, _>("unverified") {
+            t.messages.signin_error_unverified().to_string()
+        } else if user_row.get::<bool, _>("approval_pending") {
+            t.messages.signin_error_awaiting_approval().to_string()
+        } else {
+            t.messages.signin_error_deactivated().to_string()
+        };
+        attempt.failure = Some(LoginFailure::Deactivated);
+        record_attempt(&state, &t, &attempt).await;
+        return Err((
+            jar,
+            Html(login_page(&t, &options, Some(message)).into_string()),
         ));
     }
 
@@ -109,31 +198,214 @@ pub async fn login_post(
             return Err((
                 jar,
                 Html(
-                    login_page(&t, Some(t.messages.signin_error_general().to_string()))
-                        .into_string(),
+                    login_page(
+                        &t,
+                        &options,
+                        Some(t.messages.signin_error_general().to_string()),
+                    )
+                    .into_string(),
                 ),
             ));
         }
     };
 
     tracing::info!("User {} logged in successfully", user_email);
+    record_attempt(&state, &t, &attempt).await;
+    if let Err(e) = lockout::clear_failed_logins(&state.db, user_id).await {
+        tracing::error!("Failed to reset failed logins of {}: {}", user_email, e);
+    }
+    rehash_password(&state, user_id, &user_email, &form.password, &password_hash).await;
+
+    let jar = jar.add(session_cookie(&state, &session));
+
+    // Redirect to dashboard
+    Ok((jar, Redirect::to("/")))
+}
+
+/// Store a new hash of a just verified password if the stored one was made
+/// with bcrypt or other Argon2 parameters than configured now
+///
+/// Failures are logged; the old hash keeps working.
+async fn rehash_password(
+    state: &AppState,
+    user_id: i64,
+    email: &str,
+    password: &str,
+    password_hash: &str,
+) {
+    if !needs_rehash(password_hash) {
+        return;
+    }
+
+    let result = match hash_password(password) {
+        Ok(new_hash) => {
+            users::upgrade_password_hash(&state.db, user_id, password_hash, &new_hash).await
+        }
+        Err(e) => Err(sqlx::Error::Encode(Box::new(e))),
+    };
+    match result {
+        Ok(_) => tracing::info!("Rehashed password of {}", email),
+        Err(e) => tracing::error!("Failed to rehash password of {}: {}", email, e),
+    }
+}
+
+/// What the sign-in page offers: demo account, sign-up and external providers
+pub(crate) async fn login_options(state: &AppState) -> LoginOptions {
+    LoginOptions {
+        demo_mode: state.demo_user_email.is_some(),
+        registration_open: registration_settings(state).await.enabled,
+        oauth_providers: state.oauth.enabled(),
+    }
+}
 
-    // Sign the session ID for the cookie
+/// Session cookie carrying the signed session ID
+pub(crate) fn session_cookie(state: &AppState, session: &Session) -> Cookie<'static> {
     let signed_session_id = sign_session_id(&session.id, &state.session_secret);
 
-    // Set session cookie with signed session ID
-    let session_cookie = Cookie::build((SESSION_COOKIE_NAME, signed_session_id))
+    Cookie::build((SESSION_COOKIE_NAME, signed_session_id))
         .path("/")
         .http_only(true)
         .secure(state.is_production)
         .same_site(SameSite::Strict)
-        .max_age(time::Duration::days(7))
-        .build();
+        .max_age(time::Duration::seconds(
+            state.sessions.config().max_lifetime.num_seconds(),
+        ))
+        .build()
+}
 
-    let jar = jar.add(session_cookie);
+/// POST /auth/demo - Sign in to the shared read-only demo account
+pub async fn demo_login_post(
+    State(state): State<AppState>,
+    Extension(t): Extension<TranslationContext>,
+    Extension(ClientIp(client_ip)): Extension<ClientIp>,
+    headers: HeaderMap,
+    jar: CookieJar,
+) -> axum::response::Response {
+    let Some(demo_email) = state.demo_user_email.clone() else {
+        return Redirect::to("/auth/login").into_response();
+    };
 
-    // Redirect to dashboard
-    Ok((jar, Redirect::to("/")))
+    let session = match demo::ensure_demo_user(&state.db, &demo_email).await {
+        Ok(user_id) => state
+            .sessions
+            .create_session(user_id, demo_email.clone(), DEMO_USER_NAME.to_string())
+            .await
+            .map_err(anyhow::Error::from),
+        Err(e) => Err(e),
+    };
+
+    let session = match session {
+        Ok(session) => session,
+        Err(e) => {
+            tracing::error!("Failed to create demo session: {}", e);
+            return Html(
+                login_page(
+                    &t,
+                    &login_options(&state).await,
+                    Some(t.messages.signin_error_general().to_string()),
+                )
+                .into_string(),
+            )
+            .into_response();
+        }
+    };
+
+    let attempt = LoginAttempt {
+        user_id: Some(session.user_id),
+        email: demo_email,
+        failure: None,
+        ip_address: client_ip,
+        user_agent: audit::user_agent(&headers),
+    };
+    // Demo visitors come from everywhere; never flag them as new addresses
+    if let Err(e) = audit::record_login_attempt(&state.db, &attempt, false).await {
+        tracing::error!("Failed to record demo login: {}", e);
+    }
+
+    (jar.add(session_cookie(&state, &session)), Redirect::to("/")).into_response()
+}
+
+/// Write a login attempt to the audit log and flag sign-ins from new addresses
+///
+/// Audit failures are logged but never block the sign-in itself.
+pub(crate) async fn record_attempt(
+    state: &AppState,
+    t: &TranslationContext,
+    attempt: &LoginAttempt,
+) {
+    let new_ip = match (attempt.succeeded(), attempt.user_id, &attempt.ip_address) {
+        (true, Some(user_id), Some(ip)) => audit::is_new_ip_for_user(&state.db, user_id, ip)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::error!("Failed to check sign-in address history: {}", e);
+                false
+            }),
+        _ => false,
+    };
+
+    if let Err(e) = audit::record_login_attempt(&state.db, attempt, new_ip).await {
+        tracing::error!(
+            "Failed to record login attempt for {}: {}",
+            attempt.email,
+            e
+        );
+    }
+
+    if let (true, Some(user_id), Some(ip)) = (
+        new_ip && state.login_notify_new_ip,
+        attempt.user_id,
+        &attempt.ip_address,
+    ) {
+        notify_new_ip_sign_in(state, t, user_id, ip, attempt.user_agent.as_deref());
+    }
+}
+
+/// Email a user about a sign-in from an address they have not used before
+///
+/// Sent in the background so a slow mail server does not hold up the sign-in.
+fn notify_new_ip_sign_in(
+    state: &AppState,
+    t: &TranslationContext,
+    user_id: i64,
+    ip_address: &str,
+    user_agent: Option<&str>,
+) {
+    tracing::warn!(
+        "New sign-in for user {} from unrecognized address {}",
+        user_id,
+        ip_address
+    );
+
+    let subject = t.messages.new_ip_email_subject().to_string();
+    let body = format!(
+        "{}\n\n{}: {}\n{}: {}\n\n{}",
+        t.messages.new_ip_email_body(),
+        t.messages.profile_login_ip(),
+        ip_address,
+        t.messages.profile_login_user_agent(),
+        user_agent.unwrap_or("-"),
+        t.messages.new_ip_email_advice()
+    );
+    let db = state.db.clone();
+    let mailer = state.mailer.clone();
+    tokio::spawn(async move {
+        // External sign-ins may not carry an email, so the account's own is used
+        let email = match users::get_user_by_id(&db, user_id).await {
+            Ok(Some(user)) => user.email,
+            Ok(None) => return,
+            Err(e) => {
+                tracing::error!("Failed to load user {} for sign-in email: {}", user_id, e);
+                return;
+            }
+        };
+        if let Err(e) = mailer.send(&email, &subject, body).await {
+            tracing::error!(
+                "Failed to send new sign-in address email to {}: {}",
+                email,
+                e
+            );
+        }
+    });
 }
 
 /// POST /auth/logout - Handle logout
@@ -158,3 +430,10 @@ pub async fn logout_post(State(state): State<AppState>, jar: CookieJar) -> impl
     // Redirect to login
     (jar, Redirect::to("/auth/login"))
 }
+
+/// GET /auth/keepalive - Extend the session from the expiry warning banner
+///
+/// The auth middleware already renewed the session for this request.
+pub async fn keepalive_get() -> StatusCode {
+    StatusCode::NO_CONTENT
+}
@@ -0,0 +1,153 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::business;
+use crate::i18n::TranslationContext;
+use crate::service::awards;
+use crate::views::components::error::error_message;
+use crate::views::pages::awards::award_create_modal;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateAwardForm {
+    award: String,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    player_id: Option<i64>,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteAwardForm {
+    csrf_token: String,
+}
+
+/// HTMX redirect back to the season detail page (awards are managed there)
+fn redirect_to_season(season_id: i64) -> axum::response::Response {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("hx-redirect"),
+        format!("/seasons/{}", season_id)
+            .parse()
+            .expect("Valid redirect URL should parse"),
+    );
+    (headers, Html("".to_string())).into_response()
+}
+
+/// Render the create modal, loading the season's candidates
+async fn render_create_modal(
+    state: &AppState,
+    session: &Session,
+    t: &TranslationContext,
+    season_id: i64,
+    error: Option<&str>,
+) -> axum::response::Response {
+    let candidates = match awards::get_season_award_candidates(&state.db, season_id).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            tracing::error!("Failed to load award candidates: {}", e);
+            Vec::new()
+        }
+    };
+
+    Html(award_create_modal(session, t, season_id, &candidates, error).into_string())
+        .into_response()
+}
+
+/// GET /seasons/{season_id}/awards/new - Show create award modal
+pub async fn award_create_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(season_id): Path<i64>,
+) -> axum::response::Response {
+    render_create_modal(&state, &session, &t, season_id, None).await
+}
+
+/// POST /seasons/{season_id}/awards - Give an award to a player
+pub async fn award_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(season_id): Path<i64>,
+    Form(form): Form<CreateAwardForm>,
+) -> axum::response::Response {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let Some(player_id) = form.player_id else {
+        return render_create_modal(&state, &session, &t, season_id, Some("Player is required"))
+            .await;
+    };
+
+    match business::awards::create_award_validated(&state.db, season_id, &form.award, player_id)
+        .await
+    {
+        Ok(_) => redirect_to_season(season_id),
+        Err(Ok(validation_error)) => {
+            render_create_modal(
+                &state,
+                &session,
+                &t,
+                season_id,
+                Some(validation_error.message()),
+            )
+            .await
+        }
+        Err(Err(e)) => {
+            tracing::error!("Failed to create season award: {}", e);
+            render_create_modal(&state, &session, &t, season_id, Some("Failed to add award")).await
+        }
+    }
+}
+
+/// POST /season-awards/{id}/delete - Remove an award
+pub async fn award_delete(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<DeleteAwardForm>,
+) -> axum::response::Response {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let season_id = match awards::get_award_by_id(&state.db, id).await {
+        Ok(Some(award)) => award.season_id,
+        Ok(None) => {
+            return Html(
+                error_message(&t, t.messages.error_season_award_not_found()).into_string(),
+            )
+            .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch season award: {}", e);
+            return Html(
+                error_message(&t, t.messages.error_failed_to_delete_season_award()).into_string(),
+            )
+            .into_response();
+        }
+    };
+
+    match awards::delete_award(&state.db, id).await {
+        Ok(true) => redirect_to_season(season_id),
+        Ok(false) => {
+            Html(error_message(&t, t.messages.error_season_award_not_found()).into_string())
+                .into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to delete season award: {}", e);
+            Html(error_message(&t, t.messages.error_failed_to_delete_season_award()).into_string())
+                .into_response()
+        }
+    }
+}
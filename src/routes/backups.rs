@@ -0,0 +1,112 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, HeaderName, StatusCode},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::common::file_download::{file_download, SQLITE_CONTENT_TYPE};
+use crate::i18n::TranslationContext;
+use crate::maintenance::{self, BackupFile};
+use crate::views::{
+    layout::admin_layout,
+    pages::backups::{backup_list, backups_page},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct RunBackupForm {
+    csrf_token: String,
+}
+
+/// Backups in the configured directory; empty when backups are disabled
+fn load_backups(state: &AppState) -> Vec<BackupFile> {
+    let Some(dir) = &state.maintenance.backup_dir else {
+        return Vec::new();
+    };
+    match maintenance::list_backups(dir) {
+        Ok(backups) => backups,
+        Err(e) => {
+            // The directory only appears with the first backup
+            if e.kind() != std::io::ErrorKind::NotFound {
+                tracing::warn!("Failed to list backups in {}: {}", dir.display(), e);
+            }
+            Vec::new()
+        }
+    }
+}
+
+/// GET /management/backups - Backup schedule and the backups on disk
+pub async fn backups_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let backups = load_backups(&state);
+    let next_backup = state.maintenance.next_backup(Utc::now());
+    let content = backups_page(&session, &t, &state.maintenance, next_backup, &backups);
+    Html(admin_layout("Backups", &session, "/management", &t, content).into_string())
+}
+
+/// POST /management/backups - Back up the database now
+pub async fn backup_run(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<RunBackupForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let outcome = maintenance::run_backup(&state.db, &state.maintenance).await;
+
+    let mut headers = HeaderMap::new();
+    let (header, message) = if outcome.is_some_and(|outcome| outcome.success) {
+        ("hx-toast-success", t.messages.backups_run_succeeded())
+    } else {
+        ("hx-toast-error", t.messages.backups_run_failed())
+    };
+    headers.insert(
+        HeaderName::from_static(header),
+        message
+            .to_string()
+            .parse()
+            .expect("Toast message should be a valid header value"),
+    );
+
+    (
+        headers,
+        Html(backup_list(&t, &load_backups(&state)).into_string()),
+    )
+        .into_response()
+}
+
+/// GET /management/backups/:name - Download a backup file
+pub async fn backup_download(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> axum::response::Response {
+    let path = state
+        .maintenance
+        .backup_dir
+        .as_deref()
+        .and_then(|dir| maintenance::backup_path(dir, &name));
+    let file = match path {
+        Some(path) => tokio::fs::File::open(&path).await.ok(),
+        None => None,
+    };
+
+    match file {
+        Some(file) => file_download(file, &name, SQLITE_CONTENT_TYPE).await,
+        None => (
+            StatusCode::NOT_FOUND,
+            t.messages.backups_not_found().to_string(),
+        )
+            .into_response(),
+    }
+}
@@ -0,0 +1,207 @@
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap, HeaderName, StatusCode},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::calendar_subscriptions;
+use crate::views::{
+    calendar::team_calendar, components::error::error_message,
+    pages::calendar_subscriptions::calendar_subscriptions_section,
+};
+
+/// Maximum length of a subscription label
+const LABEL_MAX_LENGTH: usize = 100;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateCalendarSubscriptionForm {
+    csrf_token: String,
+    #[serde(default)]
+    label: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RevokeCalendarSubscriptionForm {
+    csrf_token: String,
+}
+
+/// Re-render the subscriptions section of a team with an optional toast
+async fn subscriptions_response(
+    state: &AppState,
+    session: &Session,
+    t: &TranslationContext,
+    team_id: i64,
+    error: Option<&str>,
+    toast: Option<String>,
+) -> axum::response::Response {
+    let subscriptions = match calendar_subscriptions::get_team_subscriptions(&state.db, team_id)
+        .await
+    {
+        Ok(subscriptions) => subscriptions,
+        Err(e) => {
+            tracing::error!("Failed to load calendar subscriptions: {}", e);
+            return Html(error_message(t, t.messages.error_failed_to_load_team()).into_string())
+                .into_response();
+        }
+    };
+
+    let mut response_headers = HeaderMap::new();
+    if let Some(toast) = toast {
+        response_headers.insert(
+            HeaderName::from_static("hx-toast-success"),
+            toast
+                .parse()
+                .expect("Toast message should be a valid header value"),
+        );
+    }
+
+    (
+        response_headers,
+        Html(
+            calendar_subscriptions_section(
+                session,
+                t,
+                team_id,
+                &subscriptions,
+                &state.public_url,
+                error,
+            )
+            .into_string(),
+        ),
+    )
+        .into_response()
+}
+
+/// POST /teams/{id}/calendar-subscriptions - Create a calendar feed URL
+pub async fn calendar_subscription_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(team_id): Path<i64>,
+    Form(form): Form<CreateCalendarSubscriptionForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let label = form.label.trim();
+    if label.chars().count() > LABEL_MAX_LENGTH {
+        return subscriptions_response(
+            &state,
+            &session,
+            &t,
+            team_id,
+            Some("Label cannot exceed 100 characters"),
+            None,
+        )
+        .await;
+    }
+    let label = Some(label).filter(|l| !l.is_empty());
+
+    match calendar_subscriptions::create_subscription(&state.db, team_id, label).await {
+        Ok(_) => {
+            subscriptions_response(
+                &state,
+                &session,
+                &t,
+                team_id,
+                None,
+                Some(t.messages.calendar_subscription_created().to_string()),
+            )
+            .await
+        }
+        Err(e) => {
+            tracing::error!("Failed to create calendar subscription: {}", e);
+            subscriptions_response(
+                &state,
+                &session,
+                &t,
+                team_id,
+                Some("Failed to create subscription. Please try again."),
+                None,
+            )
+            .await
+        }
+    }
+}
+
+/// POST /teams/{id}/calendar-subscriptions/{subscription_id}/revoke - Revoke a feed URL
+pub async fn calendar_subscription_revoke(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path((team_id, subscription_id)): Path<(i64, i64)>,
+    Form(form): Form<RevokeCalendarSubscriptionForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match calendar_subscriptions::revoke_subscription(&state.db, team_id, subscription_id).await {
+        Ok(_) => {
+            subscriptions_response(
+                &state,
+                &session,
+                &t,
+                team_id,
+                None,
+                Some(t.messages.calendar_subscription_revoked().to_string()),
+            )
+            .await
+        }
+        Err(e) => {
+            tracing::error!("Failed to revoke calendar subscription: {}", e);
+            subscriptions_response(
+                &state,
+                &session,
+                &t,
+                team_id,
+                Some("Failed to revoke subscription. Please try again."),
+                None,
+            )
+            .await
+        }
+    }
+}
+
+/// GET /calendar/{token}.ics - Public iCalendar feed of a team's matches
+pub async fn team_calendar_feed(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> axum::response::Response {
+    let token = token.strip_suffix(".ics").unwrap_or(&token);
+
+    let (team_id, team_name) =
+        match calendar_subscriptions::use_subscription_token(&state.db, token).await {
+            Ok(Some(team)) => team,
+            Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+            Err(e) => {
+                tracing::error!("Failed to resolve calendar token: {}", e);
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        };
+
+    match calendar_subscriptions::get_team_calendar_matches(&state.db, team_id).await {
+        Ok(matches) => (
+            [
+                (header::CONTENT_TYPE, "text/calendar; charset=utf-8"),
+                (header::CACHE_CONTROL, "private, max-age=900"),
+            ],
+            team_calendar(&team_name, &matches),
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!(
+                "Failed to load calendar matches for team {}: {}",
+                team_id,
+                e
+            );
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
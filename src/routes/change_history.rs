@@ -0,0 +1,146 @@
+use axum::{
+    extract::{Path, State},
+    response::{Html, IntoResponse},
+    Extension,
+};
+use sqlx::SqlitePool;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::change_history::{self, NewChangeEntity};
+use crate::service::{matches, players};
+use crate::views::{
+    components::error::error_message,
+    layout::admin_layout,
+    pages::change_history::{match_history_page, player_history_page},
+};
+
+/// Write a change to the history after a successful edit
+///
+/// The edit itself has already been saved, so a failure is only logged.
+pub async fn record_history(db: &SqlitePool, change: NewChangeEntity) {
+    let (entity_type, entity_id) = (change.entity_type, change.entity_id);
+    if let Err(e) = change_history::record_change(db, change).await {
+        tracing::warn!(
+            "Failed to record history for {} {}: {}",
+            entity_type.as_str(),
+            entity_id,
+            e
+        );
+    }
+}
+
+/// GET /matches/{id}/history - Changes to a match and its goals
+pub async fn match_history_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let match_entity = match matches::get_match_by_id(&state.db, id).await {
+        Ok(Some(match_entity)) => match_entity,
+        Ok(None) => {
+            return Html(
+                admin_layout(
+                    "Match Not Found",
+                    &session,
+                    "/matches",
+                    &t,
+                    error_message(&t, t.messages.error_match_not_found()),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch match: {}", e);
+            return Html(
+                admin_layout(
+                    "Error",
+                    &session,
+                    "/matches",
+                    &t,
+                    error_message(&t, t.messages.error_failed_to_load_match()),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let history = match change_history::get_match_history(&state.db, id).await {
+        Ok(history) => history,
+        Err(e) => {
+            tracing::error!("Failed to fetch match history: {}", e);
+            return Html(
+                admin_layout(
+                    "Error",
+                    &session,
+                    "/matches",
+                    &t,
+                    error_message(&t, t.messages.error_failed_to_load_history()),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let content = match_history_page(&t, &match_entity, &history);
+    Html(admin_layout("Match History", &session, "/matches", &t, content).into_string())
+}
+
+/// GET /players/{id}/history - Changes to a player
+pub async fn player_history_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let player = match players::get_player_by_id(&state.db, id).await {
+        Ok(Some(player)) => player,
+        Ok(None) => {
+            return Html(
+                admin_layout(
+                    "Player Not Found",
+                    &session,
+                    "/players",
+                    &t,
+                    error_message(&t, t.messages.error_player_not_found()),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch player: {}", e);
+            return Html(
+                admin_layout(
+                    "Error",
+                    &session,
+                    "/players",
+                    &t,
+                    error_message(&t, t.messages.error_failed_to_load_player()),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let history = match change_history::get_player_history(&state.db, id).await {
+        Ok(history) => history,
+        Err(e) => {
+            tracing::error!("Failed to fetch player history: {}", e);
+            return Html(
+                admin_layout(
+                    "Error",
+                    &session,
+                    "/players",
+                    &t,
+                    error_message(&t, t.messages.error_failed_to_load_history()),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let content = player_history_page(&t, &player, &history);
+    Html(admin_layout("Player History", &session, "/players", &t, content).into_string())
+}
@@ -0,0 +1,298 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::changelog::{
+    self, ChangelogEntryEntity, CreateChangelogEntryEntity, UpdateChangelogEntryEntity,
+};
+use crate::validation::{validate_changelog_body, validate_changelog_version, validate_name};
+use crate::views::{
+    components::{error::error_message, htmx::htmx_reload_table},
+    layout::admin_layout,
+    pages::changelog::{
+        changelog_create_modal, changelog_edit_modal, changelog_list, changelog_page,
+        whats_new_panel,
+    },
+};
+
+/// Most entries shown in the "What's new" panel at once
+const PANEL_LIMIT: i64 = 10;
+
+#[derive(Debug, Deserialize)]
+pub struct ChangelogEntryForm {
+    version: String,
+    title: String,
+    body: String,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CsrfForm {
+    csrf_token: String,
+}
+
+/// Validate the fields shared by the create and edit forms
+fn validate_entry_form(
+    form: &ChangelogEntryForm,
+) -> Result<(String, String, String), &'static str> {
+    let version = validate_changelog_version(&form.version)?;
+    let title = validate_name(&form.title)?;
+    let body = validate_changelog_body(&form.body)?;
+    Ok((version, title, body))
+}
+
+fn toast_headers(message: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("hx-toast-success"),
+        message
+            .parse()
+            .expect("Toast message should be a valid header value"),
+    );
+    headers
+}
+
+/// GET /management/whats-new - Edit the "What's new" entries
+pub async fn changelog_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let content = match changelog::get_changelog_entries(&state.db).await {
+        Ok(entries) => changelog_page(&session, &t, &entries),
+        Err(e) => {
+            tracing::error!("Failed to fetch changelog entries: {}", e);
+            error_message(&t, t.messages.changelog_load_failed())
+        }
+    };
+    Html(admin_layout("What's New", &session, "/management", &t, content).into_string())
+}
+
+/// GET /management/whats-new/list - HTMX endpoint for the entries table
+pub async fn changelog_list_partial(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    match changelog::get_changelog_entries(&state.db).await {
+        Ok(entries) => Html(changelog_list(&session, &t, &entries).into_string()),
+        Err(e) => {
+            tracing::error!("Failed to fetch changelog entries: {}", e);
+            Html(error_message(&t, t.messages.changelog_load_failed()).into_string())
+        }
+    }
+}
+
+/// GET /management/whats-new/new - Show create modal
+pub async fn changelog_create_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+) -> impl IntoResponse {
+    Html(changelog_create_modal(&session, &t, env!("CARGO_PKG_VERSION"), None).into_string())
+}
+
+/// POST /management/whats-new - Create entry
+pub async fn changelog_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<ChangelogEntryForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let (version, title, body) = match validate_entry_form(&form) {
+        Ok(v) => v,
+        Err(error) => {
+            return Html(
+                changelog_create_modal(&session, &t, &form.version, Some(error)).into_string(),
+            )
+            .into_response()
+        }
+    };
+
+    match changelog::create_changelog_entry(
+        &state.db,
+        CreateChangelogEntryEntity {
+            version,
+            title,
+            body,
+        },
+    )
+    .await
+    {
+        Ok(_) => (
+            toast_headers(&t.messages.changelog_created_toast().to_string()),
+            htmx_reload_table("/management/whats-new/list", "changelog-entries"),
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to create changelog entry: {}", e);
+            Html(
+                changelog_create_modal(
+                    &session,
+                    &t,
+                    &form.version,
+                    Some(&t.messages.changelog_save_failed().to_string()),
+                )
+                .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
+
+/// GET /management/whats-new/{id}/edit - Show edit modal
+pub async fn changelog_edit_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    match changelog::get_changelog_entry_by_id(&state.db, id).await {
+        Ok(Some(entry)) => Html(changelog_edit_modal(&session, &t, &entry, None).into_string()),
+        Ok(None) => Html(error_message(&t, t.messages.changelog_not_found()).into_string()),
+        Err(e) => {
+            tracing::error!("Failed to fetch changelog entry {}: {}", id, e);
+            Html(error_message(&t, t.messages.changelog_load_failed()).into_string())
+        }
+    }
+}
+
+/// POST /management/whats-new/{id} - Update entry
+pub async fn changelog_update(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<ChangelogEntryForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    // Re-render the submitted values on errors
+    let submitted = |form: &ChangelogEntryForm| ChangelogEntryEntity {
+        id,
+        version: form.version.clone(),
+        title: form.title.clone(),
+        body: form.body.clone(),
+        created_at: String::new(),
+    };
+
+    let (version, title, body) = match validate_entry_form(&form) {
+        Ok(v) => v,
+        Err(error) => {
+            return Html(
+                changelog_edit_modal(&session, &t, &submitted(&form), Some(error)).into_string(),
+            )
+            .into_response()
+        }
+    };
+
+    match changelog::update_changelog_entry(
+        &state.db,
+        id,
+        UpdateChangelogEntryEntity {
+            version,
+            title,
+            body,
+        },
+    )
+    .await
+    {
+        Ok(true) => (
+            toast_headers(&t.messages.changelog_updated_toast().to_string()),
+            htmx_reload_table("/management/whats-new/list", "changelog-entries"),
+        )
+            .into_response(),
+        Ok(false) => {
+            Html(error_message(&t, t.messages.changelog_not_found()).into_string()).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to update changelog entry {}: {}", id, e);
+            Html(
+                changelog_edit_modal(
+                    &session,
+                    &t,
+                    &submitted(&form),
+                    Some(&t.messages.changelog_save_failed().to_string()),
+                )
+                .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
+
+/// POST /management/whats-new/{id}/delete - Delete entry
+pub async fn changelog_delete(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<CsrfForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match changelog::delete_changelog_entry(&state.db, id).await {
+        Ok(true) => (
+            toast_headers(&t.messages.changelog_deleted_toast().to_string()),
+            htmx_reload_table("/management/whats-new/list", "changelog-entries"),
+        )
+            .into_response(),
+        Ok(false) => {
+            Html(error_message(&t, t.messages.changelog_not_found()).into_string()).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to delete changelog entry {}: {}", id, e);
+            Html(error_message(&t, t.messages.changelog_delete_failed()).into_string())
+                .into_response()
+        }
+    }
+}
+
+/// GET /whats-new - "What's new" panel, empty when everything was seen
+pub async fn whats_new_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> Html<String> {
+    match changelog::get_unseen_changelog_entries(&state.db, session.user_id, PANEL_LIMIT).await {
+        Ok(entries) if !entries.is_empty() => {
+            Html(whats_new_panel(&session, &t, &entries).into_string())
+        }
+        Ok(_) => Html(String::new()),
+        Err(e) => {
+            // The panel is informational, so the page works without it
+            tracing::warn!("Failed to fetch unseen changelog entries: {}", e);
+            Html(String::new())
+        }
+    }
+}
+
+/// POST /whats-new/dismiss - Mark the current entries as seen
+pub async fn whats_new_dismiss(
+    Extension(session): Extension<Session>,
+    State(state): State<AppState>,
+    Form(form): Form<CsrfForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    if let Err(e) = changelog::mark_changelog_seen(&state.db, session.user_id).await {
+        tracing::error!("Failed to mark changelog as seen: {}", e);
+    }
+    Html(String::new()).into_response()
+}
@@ -0,0 +1,170 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::comments::{self, CommentEntityType, CreateCommentEntity};
+use crate::validation::validate_comment_body;
+use crate::views::{
+    components::error::error_message,
+    pages::comments::{comment_threads, COMMENTS_CHANGED_EVENT},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct CommentsQuery {
+    entity_type: String,
+    entity_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommentForm {
+    entity_type: String,
+    entity_id: i64,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    parent_id: Option<i64>,
+    body: String,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CsrfForm {
+    csrf_token: String,
+}
+
+fn toast_header(name: &'static str, message: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static(name),
+        message
+            .parse()
+            .expect("Toast message should be a valid header value"),
+    );
+    headers
+}
+
+/// Success toast plus the event reloading the comment thread
+fn comments_changed_headers(message: &str) -> HeaderMap {
+    let mut headers = toast_header("hx-toast-success", message);
+    headers.insert(
+        HeaderName::from_static("hx-trigger"),
+        COMMENTS_CHANGED_EVENT
+            .parse()
+            .expect("Event name should be a valid header value"),
+    );
+    headers
+}
+
+/// GET /comments - HTMX endpoint for the comment threads of a record
+pub async fn comments_list_partial(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<CommentsQuery>,
+) -> impl IntoResponse {
+    let Some(entity_type) = CommentEntityType::from_str(&query.entity_type) else {
+        return Html(error_message(&t, t.messages.comments_load_failed()).into_string());
+    };
+
+    match comments::get_comment_threads(&state.db, entity_type, query.entity_id).await {
+        Ok(threads) => Html(
+            comment_threads(&session, &t, entity_type, query.entity_id, &threads).into_string(),
+        ),
+        Err(e) => {
+            tracing::error!("Failed to fetch comments: {}", e);
+            Html(error_message(&t, t.messages.comments_load_failed()).into_string())
+        }
+    }
+}
+
+/// POST /comments - Post a comment or reply
+///
+/// The forms do not swap anything; errors are reported as toasts and a
+/// successful post reloads the thread.
+pub async fn comment_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<CommentForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let Some(entity_type) = CommentEntityType::from_str(&form.entity_type) else {
+        return toast_header(
+            "hx-toast-error",
+            &t.messages.comments_save_failed().to_string(),
+        )
+        .into_response();
+    };
+    let body = match validate_comment_body(&form.body) {
+        Ok(body) => body,
+        Err(error) => return toast_header("hx-toast-error", error).into_response(),
+    };
+
+    match comments::create_comment(
+        &state.db,
+        CreateCommentEntity {
+            entity_type,
+            entity_id: form.entity_id,
+            parent_id: form.parent_id,
+            author_id: session.user_id,
+            body,
+        },
+    )
+    .await
+    {
+        Ok(Some(_)) => comments_changed_headers(&t.messages.comments_posted_toast().to_string())
+            .into_response(),
+        Ok(None) => toast_header(
+            "hx-toast-error",
+            &t.messages.comments_not_found().to_string(),
+        )
+        .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to create comment: {}", e);
+            toast_header(
+                "hx-toast-error",
+                &t.messages.comments_save_failed().to_string(),
+            )
+            .into_response()
+        }
+    }
+}
+
+/// POST /comments/{id}/delete - Delete one of the user's own comments
+pub async fn comment_delete(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<CsrfForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match comments::delete_comment(&state.db, id, session.user_id).await {
+        Ok(true) => comments_changed_headers(&t.messages.comments_deleted_toast().to_string())
+            .into_response(),
+        Ok(false) => toast_header(
+            "hx-toast-error",
+            &t.messages.comments_not_found().to_string(),
+        )
+        .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to delete comment {}: {}", id, e);
+            toast_header(
+                "hx-toast-error",
+                &t.messages.comments_delete_failed().to_string(),
+            )
+            .into_response()
+        }
+    }
+}
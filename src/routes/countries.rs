@@ -8,7 +8,7 @@ use serde::Deserialize;
 use crate::app_state::AppState;
 use crate::auth::session::Session;
 use crate::i18n::TranslationContext;
-use crate::service::countries::{self, CountryFilters};
+use crate::service::countries::{self, CountryFilters, CountryToggleEntity};
 use crate::views::{layout::admin_layout, pages::countries::countries_page};
 
 #[derive(Debug, Deserialize)]
@@ -60,10 +60,7 @@ pub async fn country_toggle_enabled(
     Path(id): Path<i64>,
 ) -> impl IntoResponse {
     match countries::toggle_country_enabled(&state.db, id).await {
-        Ok(Some(new_status)) => Json(serde_json::json!({
-            "enabled": new_status
-        }))
-        .into_response(),
+        Ok(Some(enabled)) => Json(CountryToggleEntity { enabled }).into_response(),
         Ok(None) => (axum::http::StatusCode::NOT_FOUND, "Country not found").into_response(),
         Err(e) => {
             tracing::error!("Failed to toggle country enabled status: {}", e);
@@ -1,6 +1,6 @@
 use axum::{
     extract::{Path, Query, State},
-    response::{Html, IntoResponse},
+    response::{Html, IntoResponse, Response},
     Extension, Form,
 };
 use serde::Deserialize;
@@ -11,12 +11,16 @@ use crate::i18n::TranslationContext;
 use crate::service::{
     countries,
     events::{self, CreateEventEntity, EventFilters, UpdateEventEntity},
+    placements,
+    roster_rules::{self, RosterRulesEntity},
+    slugs::SlugEntity,
+    standings::PointSystem,
 };
 use crate::validation::validate_name;
 use crate::views::{
     components::{error::error_message, htmx::htmx_reload_table},
     layout::admin_layout,
-    pages::event_detail::event_detail_page,
+    pages::event_detail::{event_detail_page, roster_rules_modal},
     pages::events::{event_create_modal, event_edit_modal, event_list_content, events_page},
 };
 
@@ -40,11 +44,17 @@ fn default_page_size() -> usize {
     20
 }
 
+fn default_point_system() -> String {
+    PointSystem::default().as_str().to_string()
+}
+
 #[derive(Debug, Deserialize)]
 pub struct CreateEventForm {
     name: String,
     #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
     country_id: Option<i64>,
+    #[serde(default = "default_point_system")]
+    point_system: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -52,6 +62,20 @@ pub struct UpdateEventForm {
     name: String,
     #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
     country_id: Option<i64>,
+    #[serde(default = "default_point_system")]
+    point_system: String,
+    #[serde(default)]
+    slug: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RosterRulesForm {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    max_players: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    min_goalies: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    max_imports: Option<i64>,
 }
 
 /// GET /events - Events list page
@@ -129,14 +153,23 @@ pub async fn events_list_partial(
     Html(event_list_content(&t, &result, &filters).into_string())
 }
 
-/// GET /events/{id} - Event detail page
+/// GET /events/{id} - Event detail page, also reachable by the event's slug
 pub async fn event_detail(
     Extension(session): Extension<Session>,
     Extension(t): Extension<TranslationContext>,
     State(state): State<AppState>,
-    Path(id): Path<i64>,
-) -> impl IntoResponse {
-    let detail = match events::get_event_detail(&state.db, id).await {
+    Path(key): Path<String>,
+) -> Response {
+    let resolved =
+        crate::routes::slugs::resolve_entity_path(&state.db, SlugEntity::Event, "/events", &key)
+            .await;
+    let detail = match resolved {
+        Ok(Some(id)) => events::get_event_detail(&state.db, id).await,
+        Ok(None) => Ok(None),
+        Err(Ok(redirect)) => return redirect,
+        Err(Err(e)) => Err(e),
+    };
+    let detail = match detail {
         Ok(Some(detail)) => detail,
         Ok(None) => {
             return Html(
@@ -151,7 +184,8 @@ pub async fn event_detail(
                     ),
                 )
                 .into_string(),
-            );
+            )
+            .into_response();
         }
         Err(e) => {
             tracing::error!("Failed to fetch event detail: {}", e);
@@ -167,12 +201,24 @@ pub async fn event_detail(
                     ),
                 )
                 .into_string(),
-            );
+            )
+            .into_response();
         }
     };
 
-    let content = event_detail_page(&t, &detail);
+    let id = detail.event_info.id;
+
+    let medal_table = match placements::get_event_medal_table(&state.db, id).await {
+        Ok(medal_table) => medal_table,
+        Err(e) => {
+            tracing::warn!("Failed to load medal table for event {}: {}", id, e);
+            Vec::new()
+        }
+    };
+
+    let content = event_detail_page(&t, &detail, &medal_table);
     Html(admin_layout("Event Detail", &session, "/events", &t, content).into_string())
+        .into_response()
 }
 
 /// GET /events/new - Show create modal
@@ -197,7 +243,11 @@ pub async fn event_create(
     Form(form): Form<CreateEventForm>,
 ) -> impl IntoResponse {
     // Validation
-    let name = match validate_name(&form.name) {
+    let name = match validate_name(&form.name).and_then(|name| {
+        PointSystem::from_str(&form.point_system)
+            .map(|_| name)
+            .ok_or("Invalid point system")
+    }) {
         Ok(n) => n,
         Err(error) => {
             let countries = match countries::get_countries_simple(&state.db).await {
@@ -218,6 +268,7 @@ pub async fn event_create(
         CreateEventEntity {
             name: name.to_string(),
             country_id: form.country_id,
+            point_system: form.point_system,
         },
     )
     .await
@@ -300,8 +351,30 @@ pub async fn event_update(
     Form(form): Form<UpdateEventForm>,
 ) -> impl IntoResponse {
     // Validation
-    let name = match validate_name(&form.name) {
-        Ok(n) => n,
+    let validated = match validate_name(&form.name).and_then(|name| {
+        PointSystem::from_str(&form.point_system)
+            .map(|_| name)
+            .ok_or("Invalid point system")
+    }) {
+        Ok(name) => crate::business::slugs::validate_requested_slug(
+            &state.db,
+            SlugEntity::Event,
+            id,
+            form.slug.as_deref(),
+        )
+        .await
+        .map(|slug| (name, slug))
+        .map_err(|error| match error {
+            Ok(error) => error.message(),
+            Err(e) => {
+                tracing::error!("Failed to check slug of event {}: {}", id, e);
+                "Failed to check the slug"
+            }
+        }),
+        Err(error) => Err(error),
+    };
+    let (name, slug) = match validated {
+        Ok(validated) => validated,
         Err(error) => {
             let event = match events::get_event_by_id(&state.db, id).await {
                 Ok(Some(event)) => event,
@@ -342,6 +415,8 @@ pub async fn event_update(
         UpdateEventEntity {
             name: name.to_string(),
             country_id: form.country_id,
+            point_system: form.point_system,
+            slug,
         },
     )
     .await
@@ -431,3 +506,70 @@ pub async fn event_delete(
         }
     }
 }
+
+/// GET /events/{id}/roster-rules/edit - Show roster rules modal
+pub async fn roster_rules_edit_form(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    match roster_rules::get_event_roster_rules(&state.db, id).await {
+        Ok(Some(rules)) => Html(roster_rules_modal(&t, id, &rules, None).into_string()),
+        Ok(None) => Html(error_message(&t, t.messages.error_event_not_found()).into_string()),
+        Err(e) => {
+            tracing::error!("Failed to fetch roster rules: {}", e);
+            Html(error_message(&t, t.messages.error_failed_to_load_event()).into_string())
+        }
+    }
+}
+
+/// POST /events/{id}/roster-rules - Update roster rules
+pub async fn roster_rules_update(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<RosterRulesForm>,
+) -> impl IntoResponse {
+    let rules = RosterRulesEntity {
+        max_players: form.max_players,
+        min_goalies: form.min_goalies,
+        max_imports: form.max_imports,
+    };
+
+    match crate::business::roster_rules::update_roster_rules_validated(&state.db, id, &rules).await
+    {
+        Ok(true) => {
+            use axum::http::header::{HeaderMap, HeaderName};
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-redirect"),
+                format!("/events/{}", id)
+                    .parse()
+                    .expect("Valid redirect URL should parse"),
+            );
+            headers.insert(
+                HeaderName::from_static("hx-toast-success"),
+                t.messages
+                    .roster_rules_updated()
+                    .to_string()
+                    .parse()
+                    .unwrap(),
+            );
+            (headers, Html("".to_string())).into_response()
+        }
+        Ok(false) => Html(error_message(&t, t.messages.error_event_not_found()).into_string())
+            .into_response(),
+        Err(Ok(validation_error)) => {
+            Html(roster_rules_modal(&t, id, &rules, Some(validation_error.message())).into_string())
+                .into_response()
+        }
+        Err(Err(e)) => {
+            tracing::error!("Failed to update roster rules: {}", e);
+            Html(
+                roster_rules_modal(&t, id, &rules, Some("Failed to update roster rules"))
+                    .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! GraphQL endpoint for signed-in users
+//!
+//! The schema (see [`crate::graphql`]) has no mutations, so the endpoint is
+//! open to the read-only demo account as well.
+
+use axum::{extract::State, response::Html, Json};
+
+use crate::app_state::AppState;
+
+/// POST /graphql - Run a GraphQL query
+pub async fn graphql_post(
+    State(state): State<AppState>,
+    Json(request): Json<async_graphql::Request>,
+) -> Json<async_graphql::Response> {
+    Json(state.graphql.execute(request).await)
+}
+
+/// GET /graphql - GraphiQL explorer for writing queries
+pub async fn graphql_get() -> Html<String> {
+    Html(
+        async_graphql::http::GraphiQLSource::build()
+            .endpoint("/graphql")
+            .title("Hockey GraphQL")
+            .finish(),
+    )
+}
@@ -0,0 +1,119 @@
+use axum::{
+    extract::{Multipart, State},
+    http::header,
+    response::{Html, IntoResponse},
+    Extension,
+};
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::business::instance_transfer;
+use crate::i18n::TranslationContext;
+use crate::service::{instance_transfer::export_instance, team_history::ImportMode};
+use crate::views::{
+    components::error::error_message,
+    layout::admin_layout,
+    pages::instance_transfer::{instance_import_result, instance_transfer_page},
+};
+
+/// Upload limit for instance exports, which hold every record of an instance
+pub const INSTANCE_IMPORT_MAX_BYTES: usize = 100 * 1024 * 1024;
+
+/// GET /management/instance-transfer - Export and import of all data
+pub async fn instance_transfer_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+) -> Html<String> {
+    let content = instance_transfer_page(&session, &t);
+    Html(admin_layout("Data Transfer", &session, "/management", &t, content).into_string())
+}
+
+/// GET /management/instance-transfer/export - Download every record as a portable JSON file
+pub async fn instance_export(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let file = match export_instance(&state.db).await {
+        Ok(file) => file,
+        Err(e) => {
+            tracing::error!("Failed to export instance: {}", e);
+            return Html(
+                error_message(&t, t.messages.instance_transfer_export_failed()).into_string(),
+            )
+            .into_response();
+        }
+    };
+
+    let body = match serde_json::to_string(&file) {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::error!("Failed to serialize instance export: {}", e);
+            return Html(
+                error_message(&t, t.messages.instance_transfer_export_failed()).into_string(),
+            )
+            .into_response();
+        }
+    };
+
+    let filename = format!(
+        "hockey-export-{}.json",
+        chrono::Utc::now().format("%Y-%m-%d")
+    );
+    (
+        [
+            (header::CONTENT_TYPE, "application/json".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            ),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+/// POST /management/instance-transfer - Import an uploaded instance export
+pub async fn instance_import(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    let mut csrf_token = String::new();
+    let mut mode = ImportMode::AddMissing;
+    let mut data = Vec::new();
+
+    while let Ok(Some(field)) = multipart.next_field().await {
+        match field.name().unwrap_or("") {
+            "csrf_token" => csrf_token = field.text().await.unwrap_or_default(),
+            "mode" => mode = ImportMode::from_str(&field.text().await.unwrap_or_default()),
+            "file" => match field.bytes().await {
+                Ok(bytes) => data = bytes.to_vec(),
+                Err(e) => {
+                    tracing::warn!("Failed to read instance upload: {}", e);
+                    return Html(
+                        instance_import_result(&t, Err("Failed to read uploaded file"))
+                            .into_string(),
+                    )
+                    .into_response();
+                }
+            },
+            _ => {}
+        }
+    }
+
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let result = match instance_transfer::import_instance_validated(&state.db, &data, mode).await {
+        Ok(summary) => instance_import_result(&t, Ok(&summary)),
+        Err(Ok(validation_error)) => instance_import_result(&t, Err(validation_error.message())),
+        Err(Err(e)) => {
+            tracing::error!("Failed to import instance: {}", e);
+            instance_import_result(&t, Err("Failed to import data"))
+        }
+    };
+    Html(result.into_string()).into_response()
+}
@@ -0,0 +1,127 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::{Html, IntoResponse, Json},
+    Extension,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::leaders::{self, DEFAULT_LEADERS_LIMIT};
+use crate::service::seasons;
+use crate::service::streaks;
+use crate::views::components::error::error_message;
+use crate::views::{
+    layout::admin_layout,
+    pages::leaders::{season_leaders_page, PAGE_LEADERS_LIMIT},
+    pages::streaks::{streak_leaders_page, STREAK_LEADERS_LIMIT},
+};
+
+/// Upper bound of the API `limit` parameter
+const MAX_LEADERS_LIMIT: usize = 100;
+
+#[derive(Debug, Deserialize)]
+pub struct LeadersQuery {
+    limit: Option<usize>,
+}
+
+/// GET /seasons/{id}/leaders - Goal, assist and point leaders of a season
+pub async fn season_leaders_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let season = match seasons::get_season_by_id(&state.db, id).await {
+        Ok(Some(season)) => season,
+        Ok(None) => {
+            return Html(
+                admin_layout(
+                    "Season Not Found",
+                    &session,
+                    "/seasons",
+                    &t,
+                    error_message(&t, t.messages.error_season_not_found()),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch season: {}", e);
+            return Html(
+                admin_layout(
+                    "Error",
+                    &session,
+                    "/seasons",
+                    &t,
+                    error_message(&t, t.messages.error_failed_to_load_season()),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let leaders = match leaders::get_season_leaders(&state.db, id, PAGE_LEADERS_LIMIT).await {
+        Ok(leaders) => leaders,
+        Err(e) => {
+            tracing::warn!("Failed to load leaders for season {}: {}", id, e);
+            Default::default()
+        }
+    };
+
+    let content = season_leaders_page(&t, &season, &leaders);
+    Html(admin_layout("Season Leaders", &session, "/seasons", &t, content).into_string())
+}
+
+/// GET /api/seasons/:id/leaders - JSON leaderboards, `limit` players per category
+pub async fn season_leaders_api(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Query(query): Query<LeadersQuery>,
+) -> impl IntoResponse {
+    match seasons::get_season_by_id(&state.db, id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return (axum::http::StatusCode::NOT_FOUND, "Season not found").into_response(),
+        Err(e) => {
+            tracing::error!("Failed to fetch season: {}", e);
+            return (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to fetch season",
+            )
+                .into_response();
+        }
+    }
+
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_LEADERS_LIMIT)
+        .clamp(1, MAX_LEADERS_LIMIT);
+    match leaders::get_season_leaders(&state.db, id, limit).await {
+        Ok(leaders) => Json(leaders).into_response(),
+        Err(e) => {
+            tracing::error!("Failed to fetch season leaders: {}", e);
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to fetch season leaders",
+            )
+                .into_response()
+        }
+    }
+}
+
+/// GET /players/streaks - Longest current and all-time point and goal streaks
+pub async fn streak_leaders_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let content = match streaks::get_streak_leaders(&state.db, STREAK_LEADERS_LIMIT).await {
+        Ok(leaders) => streak_leaders_page(&t, &leaders),
+        Err(e) => {
+            tracing::error!("Failed to compute streak leaders: {}", e);
+            error_message(&t, t.messages.streaks_load_failed())
+        }
+    };
+    Html(admin_layout("Streaks", &session, "/players", &t, content).into_string())
+}
@@ -0,0 +1,120 @@
+use axum::{
+    extract::{Path, State},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::business;
+use crate::i18n::TranslationContext;
+use crate::service::{lines, player_contracts};
+use crate::views::pages::roster::line_editor;
+
+#[derive(Debug, Deserialize)]
+pub struct AssignLineForm {
+    player_contract_id: i64,
+    unit: String,
+    line_number: i64,
+    slot: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UnassignLineForm {
+    player_contract_id: i64,
+}
+
+/// Re-render the line editor partial after a change
+async fn render_line_editor(
+    state: &AppState,
+    t: &TranslationContext,
+    team_participation_id: i64,
+    error: Option<&str>,
+) -> Html<String> {
+    let roster = match player_contracts::get_roster(&state.db, team_participation_id).await {
+        Ok(roster) => roster,
+        Err(e) => {
+            tracing::error!(
+                "Failed to fetch roster for team participation {}: {}",
+                team_participation_id,
+                e
+            );
+            return Html(
+                crate::views::components::error::error_message(
+                    t,
+                    t.messages.error_failed_to_load_roster(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let assignments = match lines::get_line_assignments(&state.db, team_participation_id).await {
+        Ok(assignments) => assignments,
+        Err(e) => {
+            tracing::error!(
+                "Failed to fetch lines for team participation {}: {}",
+                team_participation_id,
+                e
+            );
+            return Html(
+                crate::views::components::error::error_message(
+                    t,
+                    t.messages.error_failed_to_load_lines(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    Html(line_editor(t, team_participation_id, &roster, &assignments, error).into_string())
+}
+
+/// POST /team-participations/{id}/lines/assign - Place a player on the lines chart
+pub async fn lines_assign(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(team_participation_id): Path<i64>,
+    Form(form): Form<AssignLineForm>,
+) -> impl IntoResponse {
+    let error = match business::lines::assign_player_validated(
+        &state.db,
+        team_participation_id,
+        form.player_contract_id,
+        &form.unit,
+        form.line_number,
+        form.slot,
+    )
+    .await
+    {
+        Ok(()) => None,
+        Err(Ok(validation_error)) => Some(validation_error.message()),
+        Err(Err(e)) => {
+            tracing::error!("Failed to save line assignment: {}", e);
+            Some("Failed to save lines. Please try again.")
+        }
+    };
+
+    render_line_editor(&state, &t, team_participation_id, error).await
+}
+
+/// POST /team-participations/{id}/lines/unassign - Take a player off the lines chart
+pub async fn lines_unassign(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(team_participation_id): Path<i64>,
+    Form(form): Form<UnassignLineForm>,
+) -> impl IntoResponse {
+    let error =
+        match lines::unassign_player(&state.db, team_participation_id, form.player_contract_id)
+            .await
+        {
+            Ok(_) => None,
+            Err(e) => {
+                tracing::error!("Failed to remove line assignment: {}", e);
+                Some("Failed to save lines. Please try again.")
+            }
+        };
+
+    render_line_editor(&state, &t, team_participation_id, error).await
+}
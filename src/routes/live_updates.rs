@@ -0,0 +1,82 @@
+//! WebSocket of a match's live score
+//!
+//! Public like the scoreboard JSON: the connection only receives scores and
+//! statuses, which the public site shows anyway.
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, State,
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::app_state::AppState;
+use crate::live_updates::{LiveUpdate, LiveUpdateKind};
+use crate::service::matches;
+
+/// GET /public/api/matches/:id/live - Score and status updates of a match
+///
+/// Sends the current state right after connecting, then one JSON message per
+/// change until the match is deleted or the client disconnects.
+pub async fn match_live_ws(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    ws: WebSocketUpgrade,
+) -> Response {
+    // Subscribe before loading, so a change saved in between is sent after
+    // the snapshot instead of being lost
+    let updates = state.live.subscribe();
+    let snapshot = match matches::get_match_by_id(&state.db, id).await {
+        Ok(Some(game)) => LiveUpdate::new(LiveUpdateKind::Snapshot, &game),
+        Ok(None) => return (StatusCode::NOT_FOUND, "Match not found").into_response(),
+        Err(e) => {
+            tracing::error!("Failed to load match {} for live updates: {}", id, e);
+            return (StatusCode::INTERNAL_SERVER_ERROR, "Failed to load match").into_response();
+        }
+    };
+
+    ws.on_upgrade(move |socket| forward_updates(socket, updates, snapshot))
+}
+
+async fn forward_updates(
+    mut socket: WebSocket,
+    mut updates: broadcast::Receiver<LiveUpdate>,
+    snapshot: LiveUpdate,
+) {
+    let match_id = snapshot.match_id;
+    if send(&mut socket, &snapshot).await.is_err() {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            update = updates.recv() => match update {
+                Ok(update) if update.match_id == match_id => {
+                    let deleted = update.kind == LiveUpdateKind::Deleted;
+                    if send(&mut socket, &update).await.is_err() || deleted {
+                        break;
+                    }
+                }
+                Ok(_) => {}
+                // Missed updates only delay the client until the next one
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::debug!("Live updates of match {} skipped {} messages", match_id, skipped);
+                }
+                Err(RecvError::Closed) => break,
+            },
+            message = socket.recv() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Pings are answered by the WebSocket layer; clients send nothing else
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+async fn send(socket: &mut WebSocket, update: &LiveUpdate) -> Result<(), axum::Error> {
+    let text = serde_json::to_string(update).unwrap_or_default();
+    socket.send(Message::Text(text)).await
+}
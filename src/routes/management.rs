@@ -1,7 +1,13 @@
+use crate::app_state::AppState;
 use crate::auth::session::Session;
 use crate::i18n::TranslationContext;
-use crate::views::{layout::admin_layout, pages::management::management_page};
-use axum::{response::Html, Extension};
+use crate::service::data_quality;
+use crate::views::{
+    components::error::error_message,
+    layout::admin_layout,
+    pages::{data_quality::data_quality_page, management::management_page},
+};
+use axum::{extract::State, response::Html, Extension};
 
 /// GET /management - Management hub page
 pub async fn management_get(
@@ -11,3 +17,19 @@ pub async fn management_get(
     let content = management_page(&t);
     Html(admin_layout("Management", &session, "/management", &t, content).into_string())
 }
+
+/// GET /management/data-quality - Incomplete and suspicious records with links to fix them
+pub async fn data_quality_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> Html<String> {
+    let content = match data_quality::get_data_quality_report(&state.db).await {
+        Ok(report) => data_quality_page(&t, &report),
+        Err(e) => {
+            tracing::error!("Failed to build data quality report: {}", e);
+            error_message(&t, t.messages.error_failed_to_load_report())
+        }
+    };
+    Html(admin_layout("Data Quality", &session, "/management", &t, content).into_string())
+}
@@ -0,0 +1,160 @@
+use axum::{
+    extract::{Multipart, State},
+    http::StatusCode,
+    response::{Html, IntoResponse},
+    Extension, Json,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::business::match_import::{self, MatchImportOutcome, MatchImportRecord};
+use crate::i18n::TranslationContext;
+use crate::service::change_history::{match_snapshot, HistoryAction, HistoryEntityType};
+use crate::service::matches;
+use crate::views::{
+    layout::admin_layout,
+    pages::match_import::{match_import_page, match_import_result},
+};
+use crate::webhooks::EntityChange;
+
+/// Response of the match import API when matches were created
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchImportCreatedEntity {
+    pub created: Vec<i64>,
+}
+
+/// A row the match import API rejected
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchImportRowErrorEntity {
+    /// Position of the match in the request, counting from 1
+    pub line: usize,
+    pub message: String,
+}
+
+/// Response of the match import API when nothing was created
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchImportRejectedEntity {
+    pub errors: Vec<MatchImportRowErrorEntity>,
+}
+
+/// Queue a `match.created` webhook for every imported match
+async fn announce_created_matches(state: &AppState, ids: &[i64]) {
+    for &id in ids {
+        if let Ok(Some(created)) = matches::get_match_by_id(&state.db, id).await {
+            state
+                .webhooks
+                .entity_changed(
+                    &state.db,
+                    EntityChange {
+                        entity_type: HistoryEntityType::Match,
+                        action: HistoryAction::Create,
+                        entity_id: id,
+                        match_id: Some(id),
+                        snapshot: Some(match_snapshot(&created)),
+                    },
+                )
+                .await;
+        }
+    }
+}
+
+/// POST /api/matches/import - Create finished matches from a JSON list, all or none
+pub async fn match_import_api(
+    State(state): State<AppState>,
+    Json(records): Json<Vec<MatchImportRecord>>,
+) -> impl IntoResponse {
+    let rows = match match_import::records_to_rows(records) {
+        Ok(rows) => rows,
+        Err(error) => return (StatusCode::BAD_REQUEST, error.message()).into_response(),
+    };
+
+    match match_import::import_matches(&state.db, rows).await {
+        Ok(MatchImportOutcome::Created(created)) => {
+            announce_created_matches(&state, &created).await;
+            (
+                StatusCode::CREATED,
+                Json(MatchImportCreatedEntity { created }),
+            )
+                .into_response()
+        }
+        Ok(MatchImportOutcome::Rejected(plan)) => (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(MatchImportRejectedEntity {
+                errors: plan
+                    .errors()
+                    .map(|(row, error)| MatchImportRowErrorEntity {
+                        line: row.line,
+                        message: error.message().to_string(),
+                    })
+                    .collect(),
+            }),
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to import matches: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to import matches",
+            )
+                .into_response()
+        }
+    }
+}
+
+/// GET /management/match-import - Bulk match import page
+pub async fn match_import_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+) -> Html<String> {
+    let content = match_import_page(&session, &t);
+    Html(admin_layout("Import Matches", &session, "/management", &t, content).into_string())
+}
+
+/// POST /management/match-import - Import an uploaded CSV or JSON match file
+pub async fn match_import_post(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    let mut csrf_token = String::new();
+    let mut data = Vec::new();
+
+    while let Ok(Some(field)) = multipart.next_field().await {
+        match field.name().unwrap_or("") {
+            "csrf_token" => csrf_token = field.text().await.unwrap_or_default(),
+            "file" => match field.bytes().await {
+                Ok(bytes) => data = bytes.to_vec(),
+                Err(e) => {
+                    tracing::warn!("Failed to read match upload: {}", e);
+                    return Html(
+                        match_import_result(&t, Err("Failed to read uploaded file")).into_string(),
+                    )
+                    .into_response();
+                }
+            },
+            _ => {}
+        }
+    }
+
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let result = match match_import::import_match_file_validated(&state.db, &data).await {
+        Ok(outcome) => {
+            if let MatchImportOutcome::Created(created) = &outcome {
+                announce_created_matches(&state, created).await;
+            }
+            match_import_result(&t, Ok(&outcome))
+        }
+        Err(Ok(validation_error)) => match_import_result(&t, Err(validation_error.message())),
+        Err(Err(e)) => {
+            tracing::error!("Failed to import matches: {}", e);
+            match_import_result(&t, Err("Failed to import matches"))
+        }
+    };
+    Html(result.into_string()).into_response()
+}
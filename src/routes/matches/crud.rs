@@ -7,13 +7,21 @@ use axum::{
 use serde::Deserialize;
 
 use crate::app_state::AppState;
+use crate::auth::Session;
 use crate::business;
 use crate::i18n::TranslationContext;
+use crate::live_updates::{LiveUpdate, LiveUpdateKind};
+use crate::routes::change_history::record_history;
+use crate::service::arenas;
+use crate::service::change_history::{
+    diff_snapshots, match_snapshot, HistoryAction, HistoryEntityType, NewChangeEntity,
+};
 use crate::service::matches::{self, CreateMatchEntity, UpdateMatchEntity};
 use crate::views::{
     components::htmx::htmx_reload_table,
     pages::matches::{match_create_modal, match_edit_modal},
 };
+use crate::webhooks::EntityChange;
 
 #[derive(Debug, Deserialize)]
 pub struct CreateMatchForm {
@@ -27,8 +35,12 @@ pub struct CreateMatchForm {
     #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
     match_date: Option<String>,
     status: String,
-    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
-    venue: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    arena_id: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    attendance: Option<i64>,
+    #[serde(default = "default_game_type")]
+    game_type: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -43,8 +55,16 @@ pub struct UpdateMatchForm {
     #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
     match_date: Option<String>,
     status: String,
-    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
-    venue: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    arena_id: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    attendance: Option<i64>,
+    #[serde(default = "default_game_type")]
+    game_type: String,
+}
+
+fn default_game_type() -> String {
+    "group".to_string()
 }
 
 /// GET /matches/new - Show create modal
@@ -61,7 +81,15 @@ pub async fn match_create_form(
         }
     };
 
-    Html(match_create_modal(&t, None, &seasons, &[]).into_string())
+    let arenas = match arenas::get_arenas_simple(&state.db).await {
+        Ok(arenas) => arenas,
+        Err(e) => {
+            tracing::warn!("Failed to load arenas for dropdown: {}", e);
+            Vec::new()
+        }
+    };
+
+    Html(match_create_modal(&t, None, &seasons, &[], &arenas).into_string())
 }
 
 /// POST /matches - Create new match
@@ -89,6 +117,15 @@ pub async fn match_create(
             Vec::new()
         }
     };
+    let arenas = match arenas::get_arenas_simple(&state.db).await {
+        Ok(arenas) => arenas,
+        Err(e) => {
+            tracing::warn!("Failed to load arenas for dropdown: {}", e);
+            Vec::new()
+        }
+    };
+
+    let finished = form.status == "finished";
 
     // Create match with business layer validation
     match business::matches::create_match_validated(
@@ -101,14 +138,35 @@ pub async fn match_create(
             away_score_unidentified: form.away_score_unidentified,
             match_date: form.match_date,
             status: form.status,
-            venue: form.venue,
+            arena_id: form.arena_id,
+            attendance: form.attendance,
+            game_type: form.game_type,
         },
     )
     .await
     {
-        Ok(_) => {
+        Ok(id) => {
             use axum::http::header::{HeaderMap, HeaderName};
 
+            if let Ok(Some(created)) = matches::get_match_by_id(&state.db, id).await {
+                state
+                    .webhooks
+                    .entity_changed(
+                        &state.db,
+                        EntityChange {
+                            entity_type: HistoryEntityType::Match,
+                            action: HistoryAction::Create,
+                            entity_id: id,
+                            match_id: Some(id),
+                            snapshot: Some(match_snapshot(&created)),
+                        },
+                    )
+                    .await;
+            }
+            if finished {
+                state.chat.notify_match_result(&state.db, &t, id);
+            }
+
             // Return HTMX response to close modal and reload table
             // Trigger entity-created event for dashboard stats update
             let mut headers = HeaderMap::new();
@@ -125,8 +183,14 @@ pub async fn match_create(
         Err(Ok(validation_error)) => {
             // Validation error
             Html(
-                match_create_modal(&t, Some(validation_error.message()), &seasons, &teams)
-                    .into_string(),
+                match_create_modal(
+                    &t,
+                    Some(validation_error.message()),
+                    &seasons,
+                    &teams,
+                    &arenas,
+                )
+                .into_string(),
             )
             .into_response()
         }
@@ -134,8 +198,14 @@ pub async fn match_create(
             // Database error
             tracing::error!("Failed to create match: {}", e);
             Html(
-                match_create_modal(&t, Some("Failed to create match"), &seasons, &teams)
-                    .into_string(),
+                match_create_modal(
+                    &t,
+                    Some("Failed to create match"),
+                    &seasons,
+                    &teams,
+                    &arenas,
+                )
+                .into_string(),
             )
             .into_response()
         }
@@ -190,12 +260,20 @@ pub async fn match_edit_form(
             Vec::new()
         }
     };
+    let arenas = match arenas::get_arenas_simple(&state.db).await {
+        Ok(arenas) => arenas,
+        Err(e) => {
+            tracing::warn!("Failed to load arenas for dropdown: {}", e);
+            Vec::new()
+        }
+    };
 
-    Html(match_edit_modal(&t, &match_entity, None, &seasons, &teams).into_string())
+    Html(match_edit_modal(&t, &match_entity, None, &seasons, &teams, &arenas).into_string())
 }
 
 /// POST /matches/{id} - Update match
 pub async fn match_update(
+    Extension(session): Extension<Session>,
     Extension(t): Extension<TranslationContext>,
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -249,6 +327,13 @@ pub async fn match_update(
             Vec::new()
         }
     };
+    let arenas = match arenas::get_arenas_simple(&state.db).await {
+        Ok(arenas) => arenas,
+        Err(e) => {
+            tracing::warn!("Failed to load arenas for dropdown: {}", e);
+            Vec::new()
+        }
+    };
 
     // Update match with business layer validation
     match business::matches::update_match_validated(
@@ -262,12 +347,51 @@ pub async fn match_update(
             away_score_unidentified: form.away_score_unidentified,
             match_date: form.match_date,
             status: form.status,
-            venue: form.venue,
+            arena_id: form.arena_id,
+            attendance: form.attendance,
+            game_type: form.game_type,
         },
     )
     .await
     {
         Ok(true) => {
+            if let Ok(Some(updated)) = matches::get_match_by_id(&state.db, id).await {
+                record_history(
+                    &state.db,
+                    NewChangeEntity {
+                        entity_type: HistoryEntityType::Match,
+                        entity_id: id,
+                        match_id: Some(id),
+                        user_id: Some(session.user_id),
+                        action: HistoryAction::Update,
+                        changes: diff_snapshots(
+                            Some(&match_snapshot(&match_entity)),
+                            Some(&match_snapshot(&updated)),
+                        ),
+                    },
+                )
+                .await;
+                state
+                    .webhooks
+                    .entity_changed(
+                        &state.db,
+                        EntityChange {
+                            entity_type: HistoryEntityType::Match,
+                            action: HistoryAction::Update,
+                            entity_id: id,
+                            match_id: Some(id),
+                            snapshot: Some(match_snapshot(&updated)),
+                        },
+                    )
+                    .await;
+                if match_entity.status != "finished" && updated.status == "finished" {
+                    state.chat.notify_match_result(&state.db, &t, id);
+                }
+                state
+                    .live
+                    .send(LiveUpdate::new(LiveUpdateKind::Match, &updated));
+            }
+
             // Redirect back to match detail page using HX-Redirect header
             let mut headers = HeaderMap::new();
             headers.insert(
@@ -283,8 +407,15 @@ pub async fn match_update(
             (headers, Html("".to_string())).into_response()
         }
         Ok(false) => Html(
-            match_edit_modal(&t, &match_entity, Some("Match not found"), &seasons, &teams)
-                .into_string(),
+            match_edit_modal(
+                &t,
+                &match_entity,
+                Some("Match not found"),
+                &seasons,
+                &teams,
+                &arenas,
+            )
+            .into_string(),
         )
         .into_response(),
         Err(Ok(validation_error)) => {
@@ -296,6 +427,7 @@ pub async fn match_update(
                     Some(validation_error.message()),
                     &seasons,
                     &teams,
+                    &arenas,
                 )
                 .into_string(),
             )
@@ -311,6 +443,7 @@ pub async fn match_update(
                     Some("Failed to update match"),
                     &seasons,
                     &teams,
+                    &arenas,
                 )
                 .into_string(),
             )
@@ -327,6 +460,21 @@ pub async fn match_delete(
 ) -> impl IntoResponse {
     match matches::delete_match(&state.db, id).await {
         Ok(true) => {
+            state.live.send(LiveUpdate::deleted(id));
+            state
+                .webhooks
+                .entity_changed(
+                    &state.db,
+                    EntityChange {
+                        entity_type: HistoryEntityType::Match,
+                        action: HistoryAction::Delete,
+                        entity_id: id,
+                        match_id: Some(id),
+                        snapshot: None,
+                    },
+                )
+                .await;
+
             // Redirect to matches list using HTMX redirect header
             let mut headers = HeaderMap::new();
             headers.insert(
@@ -1,14 +1,28 @@
+use std::convert::Infallible;
+
 use axum::{
     extract::{Path, State},
-    response::{Html, IntoResponse},
+    http::{header, StatusCode},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        Html, IntoResponse,
+    },
     Extension,
 };
+use futures::Stream;
+use maud::Markup;
+use tokio::sync::broadcast::error::RecvError;
 
 use crate::app_state::AppState;
 use crate::auth::Session;
+use crate::business;
 use crate::i18n::TranslationContext;
+use crate::live_updates::LiveUpdateKind;
 use crate::service::matches;
-use crate::views::{layout::admin_layout, pages::matches::match_detail_page};
+use crate::views::{
+    layout::{admin_layout, base_layout},
+    pages::matches::{match_console_page, match_detail_page, match_goals, match_share_card},
+};
 
 /// GET /matches/{id} - Match detail page
 pub async fn match_detail(
@@ -18,7 +32,7 @@ pub async fn match_detail(
     Path(id): Path<i64>,
 ) -> impl IntoResponse {
     // Get match detail
-    let match_detail = match matches::get_match_detail(&state.db, id).await {
+    let mut match_detail = match matches::get_match_detail(&state.db, id).await {
         Ok(Some(detail)) => detail,
         Ok(None) => {
             return Html(
@@ -53,6 +67,145 @@ pub async fn match_detail(
         }
     };
 
+    match_detail.goal_flags =
+        business::matches::derive_goal_flags(&match_detail.match_info, &match_detail.score_events);
+
     let content = match_detail_page(&t, &match_detail);
     Html(admin_layout("Match Detail", &session, "/matches", &t, content).into_string())
 }
+
+/// GET /matches/{id}/console - Full-screen live console for scoring a match
+pub async fn match_console(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let match_info = match matches::get_match_by_id(&state.db, id).await {
+        Ok(Some(match_info)) => match_info,
+        Ok(None) => {
+            return Html(
+                admin_layout(
+                    "Match Not Found",
+                    &session,
+                    "/matches",
+                    &t,
+                    crate::views::components::error::error_message(
+                        &t,
+                        t.messages.error_match_not_found(),
+                    ),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch match: {}", e);
+            return Html(
+                admin_layout(
+                    "Error",
+                    &session,
+                    "/matches",
+                    &t,
+                    crate::views::components::error::error_message(
+                        &t,
+                        t.messages.error_failed_to_load_match(),
+                    ),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let home_players =
+        matches::get_scoring_players(&state.db, &match_info, match_info.home_team_id, &[])
+            .await
+            .unwrap_or_default();
+    let away_players =
+        matches::get_scoring_players(&state.db, &match_info, match_info.away_team_id, &[])
+            .await
+            .unwrap_or_default();
+
+    let content = match_console_page(&t, &match_info, &home_players, &away_players);
+    Html(base_layout(&t.messages.matches_console().to_string(), content).into_string())
+}
+
+/// GET /matches/{id}/events - Goal lists pushed while the detail page is open
+///
+/// Sends a `goals` event with the re-rendered list whenever a goal of the
+/// match is added, edited or removed; the stream ends when the match is
+/// deleted.
+pub async fn match_goal_events(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let updates = state.live.subscribe();
+    let stream = futures::stream::unfold(
+        (updates, state, t),
+        move |(mut updates, state, t)| async move {
+            loop {
+                match updates.recv().await {
+                    Ok(update) if update.match_id != id => continue,
+                    Ok(update) if update.kind == LiveUpdateKind::Deleted => return None,
+                    Ok(update) if update.kind != LiveUpdateKind::ScoreEvent => continue,
+                    // Missed updates may have been goals of this match
+                    Ok(_) | Err(RecvError::Lagged(_)) => {}
+                    Err(RecvError::Closed) => return None,
+                }
+                if let Some(goals) = render_match_goals(&state, &t, id).await {
+                    let event = Event::default().event("goals").data(goals.into_string());
+                    return Some((Ok(event), (updates, state, t)));
+                }
+            }
+        },
+    );
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+/// Current goal list of a match; `None` when it cannot be loaded
+async fn render_match_goals(state: &AppState, t: &TranslationContext, id: i64) -> Option<Markup> {
+    let mut detail = match matches::get_match_detail(&state.db, id).await {
+        Ok(detail) => detail?,
+        Err(e) => {
+            tracing::warn!(
+                "Failed to load goals of match {} for the event stream: {}",
+                id,
+                e
+            );
+            return None;
+        }
+    };
+    detail.goal_flags =
+        business::matches::derive_goal_flags(&detail.match_info, &detail.score_events);
+    Some(match_goals(t, &detail))
+}
+
+/// GET /matches/{id}/card.svg - Match result card as an image for sharing
+pub async fn match_share_card_get(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> axum::response::Response {
+    match matches::get_match_detail(&state.db, id).await {
+        Ok(Some(detail)) => (
+            [
+                (
+                    header::CONTENT_TYPE,
+                    "image/svg+xml; charset=utf-8".to_string(),
+                ),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("inline; filename=\"match-{}.svg\"", id),
+                ),
+            ],
+            match_share_card(&t, &detail).into_string(),
+        )
+            .into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!("Failed to fetch match detail: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+
+use crate::app_state::AppState;
+use crate::i18n::TranslationContext;
+use crate::service::matches::{self, MatchFaceoffsEntity};
+use crate::views::pages::matches::match_faceoffs_modal;
+
+use super::lineups::side_name;
+
+/// Read `won_{player_id}` and `lost_{player_id}` fields into
+/// `(player_id, won, lost)` entries
+///
+/// A player with only one of the two filled in gets 0 for the other; players
+/// with neither are left out.
+fn faceoff_entries(fields: &[(String, String)]) -> Result<Vec<(i64, i32, i32)>, &'static str> {
+    let mut entries: Vec<(i64, Option<i32>, Option<i32>)> = Vec::new();
+
+    for (name, value) in fields {
+        let value = value.trim();
+        if value.is_empty() {
+            continue;
+        }
+        let (won, player_id) = match name.split_once('_') {
+            Some(("won", id)) => (true, id),
+            Some(("lost", id)) => (false, id),
+            _ => continue,
+        };
+        let Ok(player_id) = player_id.parse::<i64>() else {
+            continue;
+        };
+        let count = value
+            .parse::<i32>()
+            .map_err(|_| "Faceoffs must be a number")?;
+        crate::validation::validate_faceoffs(Some(count))?;
+
+        let index = match entries.iter().position(|(id, _, _)| *id == player_id) {
+            Some(index) => index,
+            None => {
+                entries.push((player_id, None, None));
+                entries.len() - 1
+            }
+        };
+        if won {
+            entries[index].1 = Some(count);
+        } else {
+            entries[index].2 = Some(count);
+        }
+    }
+
+    Ok(entries
+        .into_iter()
+        .map(|(id, won, lost)| (id, won.unwrap_or(0), lost.unwrap_or(0)))
+        .collect())
+}
+
+/// GET /matches/{id}/faceoffs/{team_id}/edit - Show edit faceoffs modal
+pub async fn match_faceoffs_edit_form(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path((id, team_id)): Path<(i64, i64)>,
+) -> impl IntoResponse {
+    let match_info = match matches::get_match_by_id(&state.db, id).await {
+        Ok(Some(m)) => m,
+        Ok(None) => {
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_match_not_found(),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch match: {}", e);
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_match(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let Some(team_name) = side_name(&match_info, team_id) else {
+        return Html(
+            crate::views::components::error::error_message(&t, t.messages.error_team_not_found())
+                .into_string(),
+        );
+    };
+
+    let faceoffs: Vec<MatchFaceoffsEntity> = matches::get_match_faceoffs(&state.db, id)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|f| f.team_id == team_id)
+        .collect();
+    let keep: Vec<i64> = faceoffs.iter().map(|f| f.player_id).collect();
+    let players = matches::get_scoring_players(&state.db, &match_info, team_id, &keep)
+        .await
+        .unwrap_or_default();
+
+    Html(
+        match_faceoffs_modal(
+            &t,
+            None,
+            &match_info,
+            team_id,
+            team_name,
+            &players,
+            &faceoffs,
+        )
+        .into_string(),
+    )
+}
+
+/// POST /matches/{id}/faceoffs/{team_id} - Save a team's faceoffs
+pub async fn match_faceoffs_update(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path((id, team_id)): Path<(i64, i64)>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> impl IntoResponse {
+    let match_info = matches::get_match_by_id(&state.db, id).await.ok().flatten();
+
+    let Some(match_info) = match_info else {
+        return Html(
+            crate::views::components::error::error_message(&t, t.messages.error_match_not_found())
+                .into_string(),
+        )
+        .into_response();
+    };
+
+    let Some(team_name) = side_name(&match_info, team_id) else {
+        return Html(
+            crate::views::components::error::error_message(&t, t.messages.error_team_not_found())
+                .into_string(),
+        )
+        .into_response();
+    };
+
+    let result = match faceoff_entries(&fields) {
+        Ok(entries) => matches::save_team_faceoffs(&state.db, id, team_id, &entries)
+            .await
+            .map_err(|e| {
+                tracing::error!("Failed to save faceoffs for match {}: {}", id, e);
+                "Failed to save faceoffs"
+            }),
+        Err(error) => Err(error),
+    };
+
+    match result {
+        Ok(_) => {
+            // Redirect back to match detail page using HX-Redirect header
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-redirect"),
+                format!("/matches/{}", id)
+                    .parse()
+                    .expect("Valid redirect URL should parse"),
+            );
+            (headers, Html("".to_string())).into_response()
+        }
+        Err(error) => {
+            let faceoffs: Vec<MatchFaceoffsEntity> = matches::get_match_faceoffs(&state.db, id)
+                .await
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|f| f.team_id == team_id)
+                .collect();
+            let keep: Vec<i64> = faceoffs.iter().map(|f| f.player_id).collect();
+            let players = matches::get_scoring_players(&state.db, &match_info, team_id, &keep)
+                .await
+                .unwrap_or_default();
+            Html(
+                match_faceoffs_modal(
+                    &t,
+                    Some(error),
+                    &match_info,
+                    team_id,
+                    team_name,
+                    &players,
+                    &faceoffs,
+                )
+                .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
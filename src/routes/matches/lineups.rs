@@ -0,0 +1,142 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+
+use crate::app_state::AppState;
+use crate::i18n::TranslationContext;
+use crate::service::matches::{self, MatchEntity};
+use crate::views::pages::matches::match_lineup_modal;
+
+/// Team name of one side of a match, `None` when the team did not play in it
+pub(super) fn side_name(match_info: &MatchEntity, team_id: i64) -> Option<&str> {
+    if team_id == match_info.home_team_id {
+        Some(&match_info.home_team_name)
+    } else if team_id == match_info.away_team_id {
+        Some(&match_info.away_team_name)
+    } else {
+        None
+    }
+}
+
+/// GET /matches/{id}/lineup/{team_id}/edit - Show edit lineup modal
+pub async fn match_lineup_edit_form(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path((id, team_id)): Path<(i64, i64)>,
+) -> impl IntoResponse {
+    let match_info = match matches::get_match_by_id(&state.db, id).await {
+        Ok(Some(m)) => m,
+        Ok(None) => {
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_match_not_found(),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch match: {}", e);
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_match(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let Some(team_name) = side_name(&match_info, team_id) else {
+        return Html(
+            crate::views::components::error::error_message(&t, t.messages.error_team_not_found())
+                .into_string(),
+        );
+    };
+
+    let candidates = match matches::get_lineup_candidates(&state.db, id, team_id).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            tracing::error!("Failed to fetch lineup for match {}: {}", id, e);
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_roster(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    Html(match_lineup_modal(&t, None, &match_info, team_id, team_name, &candidates).into_string())
+}
+
+/// POST /matches/{id}/lineup/{team_id} - Save a team's dressed lineup
+///
+/// The form posts one `player_contract_id` per checked player.
+pub async fn match_lineup_update(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path((id, team_id)): Path<(i64, i64)>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> impl IntoResponse {
+    let match_info = matches::get_match_by_id(&state.db, id).await.ok().flatten();
+
+    let Some(match_info) = match_info else {
+        return Html(
+            crate::views::components::error::error_message(&t, t.messages.error_match_not_found())
+                .into_string(),
+        )
+        .into_response();
+    };
+
+    if side_name(&match_info, team_id).is_none() {
+        return Html(
+            crate::views::components::error::error_message(&t, t.messages.error_team_not_found())
+                .into_string(),
+        )
+        .into_response();
+    }
+
+    let player_contract_ids: Vec<i64> = fields
+        .iter()
+        .filter(|(name, _)| name == "player_contract_id")
+        .filter_map(|(_, value)| value.parse().ok())
+        .collect();
+
+    match matches::save_team_lineup(&state.db, id, team_id, &player_contract_ids).await {
+        Ok(_) => {
+            // Redirect back to match detail page using HX-Redirect header
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-redirect"),
+                format!("/matches/{}", id)
+                    .parse()
+                    .expect("Valid redirect URL should parse"),
+            );
+            (headers, Html("".to_string())).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to save lineup for match {}: {}", id, e);
+            let candidates = matches::get_lineup_candidates(&state.db, id, team_id)
+                .await
+                .unwrap_or_default();
+            let team_name = side_name(&match_info, team_id).unwrap_or_default();
+            Html(
+                match_lineup_modal(
+                    &t,
+                    Some("Failed to save lineup"),
+                    &match_info,
+                    team_id,
+                    team_name,
+                    &candidates,
+                )
+                .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
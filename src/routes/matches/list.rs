@@ -1,6 +1,6 @@
 use axum::{
-    extract::{Query, State},
-    response::{Html, IntoResponse},
+    extract::{Query, RawQuery, State},
+    response::{Html, IntoResponse, Response},
     Extension,
 };
 use maud::html;
@@ -8,9 +8,12 @@ use serde::Deserialize;
 
 use crate::app_state::AppState;
 use crate::auth::Session;
-use crate::common::pagination::SortOrder;
+use crate::common::csv_export::csv_download;
+use crate::common::pagination::{parse_sort_keys, Cursor, SortOrder};
 use crate::i18n::TranslationContext;
-use crate::service::matches::{self, MatchFilters, SortField};
+use crate::service::matches::{self, GameType, MatchFilters, ScoreCoverage, SortField};
+use crate::service::settings::{self, DefaultSeason};
+use crate::service::{arenas, season_groups, seasons};
 use crate::views::{
     layout::admin_layout,
     pages::matches::{match_list_content, matches_page},
@@ -32,10 +35,26 @@ pub struct MatchesQuery {
     date_from: Option<String>,
     #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
     date_to: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    coverage: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    arena_id: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    game_type: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    group_id: Option<i64>,
+    #[serde(default)]
+    date_unknown: bool,
     #[serde(default = "default_sort")]
     sort: String,
     #[serde(default = "default_order")]
     order: String,
+    /// Tie-breaking sort keys, e.g. `date:desc,event:asc`
+    #[serde(default)]
+    then: String,
+    /// `next_cursor` of the previous page, when `page` was reached by "Next"
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    after: Option<String>,
 }
 
 fn default_page() -> usize {
@@ -59,25 +78,69 @@ pub struct TeamsForSeasonQuery {
     season_id: i64,
 }
 
+/// Fill the season and status filters from the defaults on the settings page
+///
+/// Returns whether any default was applied.
+async fn apply_list_defaults(state: &AppState, query: &mut MatchesQuery) -> bool {
+    let defaults = match settings::get_match_list_defaults(&state.db).await {
+        Ok(defaults) => defaults,
+        Err(e) => {
+            tracing::warn!("Failed to load match list defaults: {}", e);
+            return false;
+        }
+    };
+
+    query.season_id = match defaults.season {
+        Some(DefaultSeason::Current) => seasons::get_current_season_id(&state.db)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::warn!("Failed to load current season: {}", e);
+                None
+            }),
+        Some(DefaultSeason::Season(id)) => Some(id),
+        None => None,
+    };
+    query.status = defaults.status.clone();
+
+    !defaults.is_empty()
+}
+
+/// Filters of the match list the query selects
+fn match_filters(query: &MatchesQuery) -> MatchFilters {
+    MatchFilters {
+        season_id: query.season_id,
+        team_id: query.team_id,
+        status: query.status.clone(),
+        date_from: query.date_from.clone(),
+        date_to: query.date_to.clone(),
+        coverage: query.coverage.as_deref().and_then(ScoreCoverage::from_str),
+        arena_id: query.arena_id,
+        game_type: query.game_type.as_deref().and_then(GameType::from_str),
+        group_id: query.group_id,
+        date_unknown: query.date_unknown,
+    }
+}
+
 /// GET /matches - Matches list page
+///
+/// Opened without query parameters, the list starts on the default filters.
 pub async fn matches_get(
     Extension(session): Extension<Session>,
     Extension(t): Extension<TranslationContext>,
     State(state): State<AppState>,
-    Query(query): Query<MatchesQuery>,
+    RawQuery(raw_query): RawQuery,
+    Query(mut query): Query<MatchesQuery>,
 ) -> impl IntoResponse {
+    let defaults_applied = raw_query.as_deref().is_none_or(str::is_empty)
+        && apply_list_defaults(&state, &mut query).await;
+
     // Build filters
-    let filters = MatchFilters {
-        season_id: query.season_id,
-        team_id: query.team_id,
-        status: query.status.clone(),
-        date_from: query.date_from.clone(),
-        date_to: query.date_to.clone(),
-    };
+    let filters = match_filters(&query);
 
     // Parse sort parameters
     let sort_field = SortField::from_str(&query.sort);
     let sort_order = SortOrder::from_str(&query.order);
+    let then_by = parse_sort_keys(&query.then, &sort_field);
 
     // Get matches
     let result = match matches::get_matches(
@@ -85,8 +148,10 @@ pub async fn matches_get(
         &filters,
         &sort_field,
         &sort_order,
+        &then_by,
         query.page,
         query.page_size,
+        query.after.as_deref().and_then(Cursor::decode).as_ref(),
     )
     .await
     {
@@ -112,6 +177,12 @@ pub async fn matches_get(
     // Get filter data
     let seasons = matches::get_seasons(&state.db).await.unwrap_or_default();
     let teams = matches::get_teams(&state.db).await.unwrap_or_default();
+    let arenas = arenas::get_arenas_simple(&state.db)
+        .await
+        .unwrap_or_default();
+    let groups = season_groups::get_groups_simple(&state.db)
+        .await
+        .unwrap_or_default();
 
     let content = matches_page(
         &t,
@@ -119,8 +190,12 @@ pub async fn matches_get(
         &filters,
         &sort_field,
         &sort_order,
+        &then_by,
         &seasons,
         &teams,
+        &arenas,
+        &groups,
+        defaults_applied,
     );
     Html(admin_layout("Matches", &session, "/matches", &t, content).into_string())
 }
@@ -131,25 +206,22 @@ pub async fn matches_list_partial(
     State(state): State<AppState>,
     Query(query): Query<MatchesQuery>,
 ) -> impl IntoResponse {
-    let filters = MatchFilters {
-        season_id: query.season_id,
-        team_id: query.team_id,
-        status: query.status.clone(),
-        date_from: query.date_from.clone(),
-        date_to: query.date_to.clone(),
-    };
+    let filters = match_filters(&query);
 
     // Parse sort parameters
     let sort_field = SortField::from_str(&query.sort);
     let sort_order = SortOrder::from_str(&query.order);
+    let then_by = parse_sort_keys(&query.then, &sort_field);
 
     let result = match matches::get_matches(
         &state.db,
         &filters,
         &sort_field,
         &sort_order,
+        &then_by,
         query.page,
         query.page_size,
+        query.after.as_deref().and_then(Cursor::decode).as_ref(),
     )
     .await
     {
@@ -166,7 +238,57 @@ pub async fn matches_list_partial(
         }
     };
 
-    Html(match_list_content(&t, &result, &filters, &sort_field, &sort_order).into_string())
+    Html(
+        match_list_content(&t, &result, &filters, &sort_field, &sort_order, &then_by).into_string(),
+    )
+}
+
+/// GET /matches/export, /api/matches/export - Matches of the current list as CSV
+pub async fn matches_export(
+    State(state): State<AppState>,
+    Query(query): Query<MatchesQuery>,
+) -> Response {
+    let filters = match_filters(&query);
+    let sort_field = SortField::from_str(&query.sort);
+    let sort_order = SortOrder::from_str(&query.order);
+    let then_by = parse_sort_keys(&query.then, &sort_field);
+
+    csv_download("matches.csv", move |mut sink| async move {
+        matches::export_matches_csv(
+            &state.db,
+            &filters,
+            &sort_field,
+            &sort_order,
+            &then_by,
+            &mut sink,
+        )
+        .await?;
+        Ok(sink)
+    })
+}
+
+/// GET /matches/score-events/export, /api/score-events/export - Goals of the current match list as CSV
+pub async fn score_events_export(
+    State(state): State<AppState>,
+    Query(query): Query<MatchesQuery>,
+) -> Response {
+    let filters = match_filters(&query);
+    let sort_field = SortField::from_str(&query.sort);
+    let sort_order = SortOrder::from_str(&query.order);
+    let then_by = parse_sort_keys(&query.then, &sort_field);
+
+    csv_download("goals.csv", move |mut sink| async move {
+        matches::export_score_events_csv(
+            &state.db,
+            &filters,
+            &sort_field,
+            &sort_order,
+            &then_by,
+            &mut sink,
+        )
+        .await?;
+        Ok(sink)
+    })
 }
 
 /// GET /matches/teams-for-season - HTMX endpoint to get teams for a selected season
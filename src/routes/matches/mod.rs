@@ -1,9 +1,21 @@
 mod crud;
 mod detail;
+mod faceoffs;
+mod lineups;
 mod list;
+mod officials;
+mod on_ice;
+mod quick_create;
 mod scoring;
+mod shots;
 
 pub use self::crud::*;
 pub use self::detail::*;
+pub use self::faceoffs::*;
+pub use self::lineups::*;
 pub use self::list::*;
+pub use self::officials::*;
+pub use self::on_ice::*;
+pub use self::quick_create::*;
 pub use self::scoring::*;
+pub use self::shots::*;
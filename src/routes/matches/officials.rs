@@ -0,0 +1,171 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::business::referees::set_match_officials_validated;
+use crate::i18n::TranslationContext;
+use crate::service::matches::{self, MatchEntity};
+use crate::service::referees::{self, OfficialRole};
+use crate::views::pages::matches::match_officials_modal;
+
+#[derive(Debug, Deserialize)]
+pub struct MatchOfficialsForm {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    referee_1: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    referee_2: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    linesman_1: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    linesman_2: Option<i64>,
+}
+
+impl MatchOfficialsForm {
+    /// Filled slots in form order
+    fn officials(&self) -> Vec<(i64, OfficialRole)> {
+        [
+            (self.referee_1, OfficialRole::Referee),
+            (self.referee_2, OfficialRole::Referee),
+            (self.linesman_1, OfficialRole::Linesman),
+            (self.linesman_2, OfficialRole::Linesman),
+        ]
+        .into_iter()
+        .filter_map(|(id, role)| id.map(|id| (id, role)))
+        .collect()
+    }
+}
+
+/// Render the officials modal, loading the referee registry for the dropdowns
+async fn officials_modal(
+    state: &AppState,
+    t: &TranslationContext,
+    error: Option<&str>,
+    match_info: &MatchEntity,
+    selected: &[(i64, OfficialRole)],
+) -> Html<String> {
+    let referee_options = match referees::get_referees_simple(&state.db).await {
+        Ok(options) => options,
+        Err(e) => {
+            tracing::error!("Failed to fetch referees: {}", e);
+            return Html(
+                crate::views::components::error::error_message(
+                    t,
+                    t.messages.error_failed_to_load_referees(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    Html(match_officials_modal(t, error, match_info, &referee_options, selected).into_string())
+}
+
+/// GET /matches/{id}/officials/edit - Show edit officials modal
+pub async fn match_officials_edit_form(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let match_info = match matches::get_match_by_id(&state.db, id).await {
+        Ok(Some(m)) => m,
+        Ok(None) => {
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_match_not_found(),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch match: {}", e);
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_match(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let selected: Vec<(i64, OfficialRole)> =
+        match referees::get_match_officials(&state.db, id).await {
+            Ok(officials) => officials
+                .iter()
+                .filter_map(|o| o.role().map(|role| (o.referee_id, role)))
+                .collect(),
+            Err(e) => {
+                tracing::error!("Failed to fetch officials for match {}: {}", id, e);
+                return Html(
+                    crate::views::components::error::error_message(
+                        &t,
+                        t.messages.error_failed_to_load_officials(),
+                    )
+                    .into_string(),
+                );
+            }
+        };
+
+    officials_modal(&state, &t, None, &match_info, &selected).await
+}
+
+/// POST /matches/{id}/officials - Save the officials of a match
+pub async fn match_officials_update(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<MatchOfficialsForm>,
+) -> impl IntoResponse {
+    let match_info = matches::get_match_by_id(&state.db, id).await.ok().flatten();
+
+    let Some(match_info) = match_info else {
+        return Html(
+            crate::views::components::error::error_message(&t, t.messages.error_match_not_found())
+                .into_string(),
+        )
+        .into_response();
+    };
+
+    let officials = form.officials();
+
+    match set_match_officials_validated(&state.db, id, &officials).await {
+        Ok(_) => {
+            // Redirect back to match detail page using HX-Redirect header
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-redirect"),
+                format!("/matches/{}", id)
+                    .parse()
+                    .expect("Valid redirect URL should parse"),
+            );
+            (headers, Html("".to_string())).into_response()
+        }
+        Err(Ok(validation_error)) => officials_modal(
+            &state,
+            &t,
+            Some(validation_error.message()),
+            &match_info,
+            &officials,
+        )
+        .await
+        .into_response(),
+        Err(Err(e)) => {
+            tracing::error!("Failed to save officials for match {}: {}", id, e);
+            officials_modal(
+                &state,
+                &t,
+                Some("Failed to save officials"),
+                &match_info,
+                &officials,
+            )
+            .await
+            .into_response()
+        }
+    }
+}
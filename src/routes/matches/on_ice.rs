@@ -0,0 +1,190 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+
+use crate::app_state::AppState;
+use crate::i18n::TranslationContext;
+use crate::service::matches::{self, MatchEntity, ScoreEventEntity};
+use crate::views::pages::matches::on_ice_modal;
+
+/// Render the on-ice modal with both teams' players
+async fn render_on_ice_modal(
+    state: &AppState,
+    t: &TranslationContext,
+    error: Option<&str>,
+    score_event: &ScoreEventEntity,
+    match_info: &MatchEntity,
+    on_ice: &[i64],
+) -> String {
+    // Keep already recorded players selectable even when not dressed
+    let mut keep = score_event.credited_player_ids();
+    keep.extend_from_slice(on_ice);
+    let home_players =
+        matches::get_scoring_players(&state.db, match_info, match_info.home_team_id, &keep)
+            .await
+            .unwrap_or_default();
+    let away_players =
+        matches::get_scoring_players(&state.db, match_info, match_info.away_team_id, &keep)
+            .await
+            .unwrap_or_default();
+
+    on_ice_modal(
+        t,
+        error,
+        score_event,
+        match_info,
+        &home_players,
+        &away_players,
+        on_ice,
+    )
+    .into_string()
+}
+
+/// GET /matches/score-events/{id}/on-ice/edit - Show players on ice modal
+pub async fn score_event_on_ice_edit_form(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let score_event = match matches::get_score_event_by_id(&state.db, id).await {
+        Ok(Some(se)) => se,
+        Ok(None) => {
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_score_event_not_found(),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch score event: {}", e);
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_score_event(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let match_info = match matches::get_match_by_id(&state.db, score_event.match_id).await {
+        Ok(Some(m)) => m,
+        Ok(None) => {
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_match_not_found(),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch match: {}", e);
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_match(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let on_ice = matches::get_on_ice_player_ids(&state.db, id)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to load players on ice for goal {}: {}", id, e);
+            Vec::new()
+        });
+
+    Html(render_on_ice_modal(&state, &t, None, &score_event, &match_info, &on_ice).await)
+}
+
+/// POST /matches/score-events/{id}/on-ice - Save the players on ice for a goal
+///
+/// The form posts one `home_player_id` or `away_player_id` per checked player.
+pub async fn score_event_on_ice_update(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> impl IntoResponse {
+    let score_event = matches::get_score_event_by_id(&state.db, id)
+        .await
+        .ok()
+        .flatten();
+    let Some(score_event) = score_event else {
+        return Html(
+            crate::views::components::error::error_message(
+                &t,
+                t.messages.error_score_event_not_found(),
+            )
+            .into_string(),
+        )
+        .into_response();
+    };
+
+    let match_info = matches::get_match_by_id(&state.db, score_event.match_id)
+        .await
+        .ok()
+        .flatten();
+    let Some(match_info) = match_info else {
+        return Html(
+            crate::views::components::error::error_message(&t, t.messages.error_match_not_found())
+                .into_string(),
+        )
+        .into_response();
+    };
+
+    let player_ids = |field: &str| -> Vec<i64> {
+        fields
+            .iter()
+            .filter(|(name, _)| name == field)
+            .filter_map(|(_, value)| value.parse().ok())
+            .collect()
+    };
+    let home = player_ids("home_player_id");
+    let away = player_ids("away_player_id");
+
+    let saved = async {
+        matches::save_on_ice_players(&state.db, id, match_info.home_team_id, &home).await?;
+        matches::save_on_ice_players(&state.db, id, match_info.away_team_id, &away).await
+    };
+
+    match saved.await {
+        Ok(_) => {
+            state.season_stats.clear();
+
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-redirect"),
+                format!("/matches/{}", match_info.id)
+                    .parse()
+                    .expect("Valid redirect URL should parse"),
+            );
+            (headers, Html("".to_string())).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to save players on ice for goal {}: {}", id, e);
+            let on_ice: Vec<i64> = home.iter().chain(away.iter()).copied().collect();
+            let message = t.messages.error_failed_to_save_on_ice().to_string();
+            Html(
+                render_on_ice_modal(
+                    &state,
+                    &t,
+                    Some(&message),
+                    &score_event,
+                    &match_info,
+                    &on_ice,
+                )
+                .await,
+            )
+            .into_response()
+        }
+    }
+}
@@ -0,0 +1,279 @@
+use axum::{
+    extract::{Query, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::matches;
+use crate::service::seasons::{self, CreateSeasonEntity};
+use crate::service::team_participations::{self, CreateTeamParticipationEntity};
+use crate::service::teams::{self, CreateTeamEntity};
+use crate::validation::validate_name;
+use crate::views::pages::matches::{
+    match_season_select, quick_create_result, quick_create_season_modal, quick_create_team_modal,
+    MatchSide,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct QuickCreateSeasonForm {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    event_id: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    year: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    display_name: Option<String>,
+    csrf_token: String,
+}
+
+/// Current state of the create match modal, sent along by the "+ New" buttons
+#[derive(Debug, Deserialize)]
+pub struct QuickCreateTeamQuery {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    season_id: Option<i64>,
+    #[serde(default)]
+    side: String,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    home_team_id: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    away_team_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuickCreateTeamForm {
+    season_id: i64,
+    side: String,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    home_team_id: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    away_team_id: Option<i64>,
+    name: String,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    country_id: Option<i64>,
+    csrf_token: String,
+}
+
+/// Toast instead of a modal, leaving the page as it is
+fn toast_error(message: String) -> axum::response::Response {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("hx-toast-error"),
+        message
+            .parse()
+            .expect("Toast message should be a valid header value"),
+    );
+    headers.insert(
+        HeaderName::from_static("hx-reswap"),
+        "none".parse().unwrap(),
+    );
+    (headers, Html("".to_string())).into_response()
+}
+
+/// GET /matches/quick-create/season - Show quick-create season modal
+pub async fn quick_create_season_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let events = seasons::get_events(&state.db).await.unwrap_or_default();
+
+    Html(quick_create_season_modal(&session, &t, None, &events).into_string())
+}
+
+/// POST /matches/quick-create/season - Create a season and select it in the match modal
+pub async fn quick_create_season(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<QuickCreateSeasonForm>,
+) -> impl IntoResponse {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let events = seasons::get_events(&state.db).await.unwrap_or_default();
+    let error = |message: &str| {
+        Html(quick_create_season_modal(&session, &t, Some(message), &events).into_string())
+            .into_response()
+    };
+
+    let Some(event_id) = form.event_id else {
+        return error("Please select an event");
+    };
+    let Some(year) = form.year.filter(|year| (1900..=2100).contains(year)) else {
+        return error("Year must be between 1900 and 2100");
+    };
+    let display_name = form
+        .display_name
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty());
+    if display_name.as_ref().is_some_and(|name| name.len() > 255) {
+        return error("Display name cannot exceed 255 characters");
+    }
+
+    let season_id = match seasons::create_season(
+        &state.db,
+        CreateSeasonEntity {
+            year,
+            display_name,
+            event_id,
+            country_id: None,
+        },
+    )
+    .await
+    {
+        Ok(season_id) => season_id,
+        Err(e) => {
+            tracing::error!("Failed to create season: {}", e);
+            return error("Failed to create season");
+        }
+    };
+
+    let all_seasons = matches::get_seasons(&state.db).await.unwrap_or_default();
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("hx-toast-success"),
+        t.messages.seasons_created().to_string().parse().unwrap(),
+    );
+    // A new season has no teams yet, so the team dropdowns are emptied
+    (
+        headers,
+        Html(
+            quick_create_result(
+                &t,
+                Some(match_season_select(&t, &all_seasons, Some(season_id), true)),
+                &[],
+                None,
+                None,
+            )
+            .into_string(),
+        ),
+    )
+        .into_response()
+}
+
+/// GET /matches/quick-create/team - Show quick-create team modal
+pub async fn quick_create_team_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    Query(query): Query<QuickCreateTeamQuery>,
+) -> impl IntoResponse {
+    let Some(season_id) = query.season_id else {
+        return toast_error(t.messages.matches_quick_create_select_season().to_string());
+    };
+    let side = MatchSide::from_str(&query.side).unwrap_or(MatchSide::Home);
+
+    Html(
+        quick_create_team_modal(
+            &session,
+            &t,
+            None,
+            season_id,
+            side,
+            query.home_team_id,
+            query.away_team_id,
+        )
+        .into_string(),
+    )
+    .into_response()
+}
+
+/// POST /matches/quick-create/team - Create a team, add it to the match's
+/// season and select it in the match modal
+pub async fn quick_create_team(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<QuickCreateTeamForm>,
+) -> impl IntoResponse {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let side = MatchSide::from_str(&form.side).unwrap_or(MatchSide::Home);
+    let error = |message: &str| {
+        Html(
+            quick_create_team_modal(
+                &session,
+                &t,
+                Some(message),
+                form.season_id,
+                side,
+                form.home_team_id,
+                form.away_team_id,
+            )
+            .into_string(),
+        )
+        .into_response()
+    };
+
+    let name = match validate_name(&form.name) {
+        Ok(name) => name,
+        Err(message) => return error(message),
+    };
+
+    let season = match seasons::get_season_by_id(&state.db, form.season_id).await {
+        Ok(Some(season)) => season,
+        Ok(None) => return error(&t.messages.error_season_not_found().to_string()),
+        Err(e) => {
+            tracing::error!("Failed to fetch season: {}", e);
+            return error(&t.messages.error_failed_to_load_season().to_string());
+        }
+    };
+
+    let team_id = match teams::create_team(
+        &state.db,
+        CreateTeamEntity {
+            name,
+            country_id: form.country_id,
+        },
+    )
+    .await
+    {
+        Ok(team_id) => team_id,
+        Err(e) => {
+            tracing::error!("Failed to create team: {}", e);
+            return error("Failed to create team");
+        }
+    };
+
+    if let Err(e) = team_participations::add_team_to_season(
+        &state.db,
+        CreateTeamParticipationEntity {
+            team_id,
+            season_id: season.id,
+            event_id: season.event_id,
+        },
+    )
+    .await
+    {
+        tracing::error!("Failed to add team to season: {}", e);
+        return error("Failed to add team to season");
+    }
+
+    let teams = matches::get_teams_for_season(&state.db, season.id)
+        .await
+        .unwrap_or_default();
+    let (home_team_id, away_team_id) = match side {
+        MatchSide::Home => (Some(team_id), form.away_team_id),
+        MatchSide::Away => (form.home_team_id, Some(team_id)),
+    };
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("hx-toast-success"),
+        t.messages.teams_created().to_string().parse().unwrap(),
+    );
+    (
+        headers,
+        Html(quick_create_result(&t, None, &teams, home_team_id, away_team_id).into_string()),
+    )
+        .into_response()
+}
@@ -1,15 +1,50 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::{HeaderMap, HeaderName},
     response::{Html, IntoResponse},
-    Extension, Form,
+    Extension, Form, Json,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::business;
+use crate::common::pagination::Cursor;
 use crate::i18n::TranslationContext;
-use crate::service::matches::{self, CreateScoreEventEntity, UpdateScoreEventEntity};
+use crate::live_updates::LiveUpdateKind;
+use crate::routes::change_history::record_history;
+use crate::service::change_history::{
+    diff_snapshots, score_event_snapshot, HistoryAction, HistoryEntityType, NewChangeEntity,
+};
+use crate::service::matches::{
+    self, CreateScoreEventEntity, ScoreEventEntity, UpdateScoreEventEntity,
+};
 use crate::views::pages::matches::{score_event_create_modal, score_event_edit_modal};
+use crate::webhooks::EntityChange;
+
+/// Goals returned per request when `limit` is not given
+const DEFAULT_SCORE_EVENTS_LIMIT: usize = 50;
+
+/// Upper bound of the API `limit` parameter
+const MAX_SCORE_EVENTS_LIMIT: usize = 500;
+
+#[derive(Debug, Deserialize)]
+pub struct ScoreEventsFeedQuery {
+    match_id: Option<i64>,
+    /// `next_cursor` of the previous response
+    after: Option<String>,
+    limit: Option<usize>,
+}
+
+/// A page of the score event feed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreEventsFeedEntity {
+    pub items: Vec<ScoreEventEntity>,
+    /// Goals in the feed, across every page
+    pub total: usize,
+    /// Pass as `after` to read the next page; `None` on the last page
+    pub next_cursor: Option<String>,
+}
 
 #[derive(Debug, Deserialize)]
 pub struct CreateScoreEventForm {
@@ -27,6 +62,13 @@ pub struct CreateScoreEventForm {
     time_seconds: Option<i32>,
     #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
     goal_type: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    video_url: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    video_timestamp: Option<String>,
+    /// Page of the match to go back to after saving, e.g. the live console
+    #[serde(default)]
+    return_to: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -45,6 +87,34 @@ pub struct UpdateScoreEventForm {
     time_seconds: Option<i32>,
     #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
     goal_type: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    video_url: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    video_timestamp: Option<String>,
+}
+
+/// Re-detect milestones, drop the cached season stats and push the new score
+/// to open match pages after a goal was saved
+///
+/// Newly reached milestones are announced in chat. The goal itself has
+/// already been saved, so a failure is only logged.
+async fn refresh_goal_derived_data(
+    state: &AppState,
+    t: &TranslationContext,
+    match_id: i64,
+    previous_player_ids: &[i64],
+) {
+    state.season_stats.clear();
+    match business::milestones::refresh_match_milestones(&state.db, match_id, previous_player_ids)
+        .await
+    {
+        Ok(reached) => state.chat.notify_milestones(&state.db, t, reached),
+        Err(e) => tracing::warn!("Failed to detect milestones for match {}: {}", match_id, e),
+    }
+    state
+        .live
+        .publish(&state.db, match_id, LiveUpdateKind::ScoreEvent)
+        .await;
 }
 
 /// GET /matches/{match_id}/score-events/new - Show create score event modal
@@ -78,12 +148,14 @@ pub async fn score_event_create_form(
     };
 
     // Get players for both teams
-    let home_players = matches::get_players_for_team(&state.db, match_info.home_team_id)
-        .await
-        .unwrap_or_default();
-    let away_players = matches::get_players_for_team(&state.db, match_info.away_team_id)
-        .await
-        .unwrap_or_default();
+    let home_players =
+        matches::get_scoring_players(&state.db, &match_info, match_info.home_team_id, &[])
+            .await
+            .unwrap_or_default();
+    let away_players =
+        matches::get_scoring_players(&state.db, &match_info, match_info.away_team_id, &[])
+            .await
+            .unwrap_or_default();
 
     Html(
         score_event_create_modal(&t, None, &match_info, &home_players, &away_players).into_string(),
@@ -92,6 +164,7 @@ pub async fn score_event_create_form(
 
 /// POST /matches/{match_id}/score-events - Create new score event
 pub async fn score_event_create(
+    Extension(session): Extension<Session>,
     Extension(t): Extension<TranslationContext>,
     State(state): State<AppState>,
     Path(match_id): Path<i64>,
@@ -111,25 +184,43 @@ pub async fn score_event_create(
         .into_response();
     };
 
-    let home_players = matches::get_players_for_team(&state.db, match_info.home_team_id)
-        .await
-        .unwrap_or_default();
-    let away_players = matches::get_players_for_team(&state.db, match_info.away_team_id)
-        .await
-        .unwrap_or_default();
+    let home_players =
+        matches::get_scoring_players(&state.db, &match_info, match_info.home_team_id, &[])
+            .await
+            .unwrap_or_default();
+    let away_players =
+        matches::get_scoring_players(&state.db, &match_info, match_info.away_team_id, &[])
+            .await
+            .unwrap_or_default();
 
     // Validation
-    if let Err(error) = crate::validation::validate_score_event_time(
+    let validated = crate::validation::validate_score_event_time(
         form.period,
         form.time_minutes,
         form.time_seconds,
-    ) {
-        return Html(
-            score_event_create_modal(&t, Some(error), &match_info, &home_players, &away_players)
-                .into_string(),
+    )
+    .and_then(|_| {
+        crate::validation::validate_video_link(
+            form.video_url.as_deref(),
+            form.video_timestamp.as_deref(),
         )
-        .into_response();
-    }
+    });
+    let (video_url, video_timestamp) = match validated {
+        Ok(video) => video,
+        Err(error) => {
+            return Html(
+                score_event_create_modal(
+                    &t,
+                    Some(error),
+                    &match_info,
+                    &home_players,
+                    &away_players,
+                )
+                .into_string(),
+            )
+            .into_response();
+        }
+    };
 
     // Create score event
     match matches::create_score_event(
@@ -144,19 +235,51 @@ pub async fn score_event_create(
             time_minutes: form.time_minutes,
             time_seconds: form.time_seconds,
             goal_type: form.goal_type,
+            video_url,
+            video_timestamp,
         },
     )
     .await
     {
-        Ok(_) => {
-            // Redirect back to match detail page using HX-Redirect header
+        Ok(id) => {
+            if let Ok(Some(created)) = matches::get_score_event_by_id(&state.db, id).await {
+                record_history(
+                    &state.db,
+                    NewChangeEntity {
+                        entity_type: HistoryEntityType::ScoreEvent,
+                        entity_id: id,
+                        match_id: Some(match_id),
+                        user_id: Some(session.user_id),
+                        action: HistoryAction::Create,
+                        changes: diff_snapshots(None, Some(&score_event_snapshot(&created))),
+                    },
+                )
+                .await;
+                state
+                    .webhooks
+                    .entity_changed(
+                        &state.db,
+                        EntityChange {
+                            entity_type: HistoryEntityType::ScoreEvent,
+                            action: HistoryAction::Create,
+                            entity_id: id,
+                            match_id: Some(match_id),
+                            snapshot: Some(score_event_snapshot(&created)),
+                        },
+                    )
+                    .await;
+            }
+            refresh_goal_derived_data(&state, &t, match_id, &[]).await;
+
+            // Redirect back to the match page the goal was entered from
+            let match_url = format!("/matches/{}", match_id);
+            let redirect_url = form
+                .return_to
+                .filter(|url| url.starts_with(&format!("{}/", match_url)))
+                .and_then(|url| url.parse().ok())
+                .unwrap_or_else(|| match_url.parse().expect("Valid redirect URL should parse"));
             let mut headers = HeaderMap::new();
-            headers.insert(
-                HeaderName::from_static("hx-redirect"),
-                format!("/matches/{}", match_id)
-                    .parse()
-                    .expect("Valid redirect URL should parse"),
-            );
+            headers.insert(HeaderName::from_static("hx-redirect"), redirect_url);
             (headers, Html("".to_string())).into_response()
         }
         Err(e) => {
@@ -230,12 +353,15 @@ pub async fn score_event_edit_form(
     };
 
     // Get players for both teams
-    let home_players = matches::get_players_for_team(&state.db, match_info.home_team_id)
-        .await
-        .unwrap_or_default();
-    let away_players = matches::get_players_for_team(&state.db, match_info.away_team_id)
-        .await
-        .unwrap_or_default();
+    let credited = score_event.credited_player_ids();
+    let home_players =
+        matches::get_scoring_players(&state.db, &match_info, match_info.home_team_id, &credited)
+            .await
+            .unwrap_or_default();
+    let away_players =
+        matches::get_scoring_players(&state.db, &match_info, match_info.away_team_id, &credited)
+            .await
+            .unwrap_or_default();
 
     Html(
         score_event_edit_modal(
@@ -252,6 +378,7 @@ pub async fn score_event_edit_form(
 
 /// POST /matches/score-events/{id} - Update score event
 pub async fn score_event_update(
+    Extension(session): Extension<Session>,
     Extension(t): Extension<TranslationContext>,
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -288,32 +415,45 @@ pub async fn score_event_update(
         .into_response();
     };
 
-    let home_players = matches::get_players_for_team(&state.db, match_info.home_team_id)
-        .await
-        .unwrap_or_default();
-    let away_players = matches::get_players_for_team(&state.db, match_info.away_team_id)
-        .await
-        .unwrap_or_default();
+    let credited = score_event.credited_player_ids();
+    let home_players =
+        matches::get_scoring_players(&state.db, &match_info, match_info.home_team_id, &credited)
+            .await
+            .unwrap_or_default();
+    let away_players =
+        matches::get_scoring_players(&state.db, &match_info, match_info.away_team_id, &credited)
+            .await
+            .unwrap_or_default();
 
     // Validation
-    if let Err(error) = crate::validation::validate_score_event_time(
+    let validated = crate::validation::validate_score_event_time(
         form.period,
         form.time_minutes,
         form.time_seconds,
-    ) {
-        return Html(
-            score_event_edit_modal(
-                &t,
-                Some(error),
-                &score_event,
-                &match_info,
-                &home_players,
-                &away_players,
-            )
-            .into_string(),
+    )
+    .and_then(|_| {
+        crate::validation::validate_video_link(
+            form.video_url.as_deref(),
+            form.video_timestamp.as_deref(),
         )
-        .into_response();
-    }
+    });
+    let (video_url, video_timestamp) = match validated {
+        Ok(video) => video,
+        Err(error) => {
+            return Html(
+                score_event_edit_modal(
+                    &t,
+                    Some(error),
+                    &score_event,
+                    &match_info,
+                    &home_players,
+                    &away_players,
+                )
+                .into_string(),
+            )
+            .into_response();
+        }
+    };
 
     // Update score event
     match matches::update_score_event(
@@ -328,11 +468,45 @@ pub async fn score_event_update(
             time_minutes: form.time_minutes,
             time_seconds: form.time_seconds,
             goal_type: form.goal_type,
+            video_url,
+            video_timestamp,
         },
     )
     .await
     {
         Ok(true) => {
+            if let Ok(Some(updated)) = matches::get_score_event_by_id(&state.db, id).await {
+                record_history(
+                    &state.db,
+                    NewChangeEntity {
+                        entity_type: HistoryEntityType::ScoreEvent,
+                        entity_id: id,
+                        match_id: Some(match_id),
+                        user_id: Some(session.user_id),
+                        action: HistoryAction::Update,
+                        changes: diff_snapshots(
+                            Some(&score_event_snapshot(&score_event)),
+                            Some(&score_event_snapshot(&updated)),
+                        ),
+                    },
+                )
+                .await;
+                state
+                    .webhooks
+                    .entity_changed(
+                        &state.db,
+                        EntityChange {
+                            entity_type: HistoryEntityType::ScoreEvent,
+                            action: HistoryAction::Update,
+                            entity_id: id,
+                            match_id: Some(match_id),
+                            snapshot: Some(score_event_snapshot(&updated)),
+                        },
+                    )
+                    .await;
+            }
+            refresh_goal_derived_data(&state, &t, match_id, &credited).await;
+
             // Redirect back to match detail page using HX-Redirect header
             let mut headers = HeaderMap::new();
             headers.insert(
@@ -375,13 +549,14 @@ pub async fn score_event_update(
 
 /// POST /matches/score-events/{id}/delete - Delete score event
 pub async fn score_event_delete(
+    Extension(session): Extension<Session>,
     Extension(t): Extension<TranslationContext>,
     State(state): State<AppState>,
     Path(id): Path<i64>,
 ) -> impl IntoResponse {
-    // Get match_id before deleting
-    let match_id = match matches::get_score_event_by_id(&state.db, id).await {
-        Ok(Some(se)) => se.match_id,
+    // Get the score event before deleting (for the redirect and its history)
+    let score_event = match matches::get_score_event_by_id(&state.db, id).await {
+        Ok(Some(se)) => se,
         Ok(None) => {
             return Html(
                 crate::views::components::error::error_message(
@@ -405,8 +580,38 @@ pub async fn score_event_delete(
         }
     };
 
+    let match_id = score_event.match_id;
+
     match matches::delete_score_event(&state.db, id).await {
         Ok(true) => {
+            record_history(
+                &state.db,
+                NewChangeEntity {
+                    entity_type: HistoryEntityType::ScoreEvent,
+                    entity_id: id,
+                    match_id: Some(match_id),
+                    user_id: Some(session.user_id),
+                    action: HistoryAction::Delete,
+                    changes: diff_snapshots(Some(&score_event_snapshot(&score_event)), None),
+                },
+            )
+            .await;
+            state
+                .webhooks
+                .entity_changed(
+                    &state.db,
+                    EntityChange {
+                        entity_type: HistoryEntityType::ScoreEvent,
+                        action: HistoryAction::Delete,
+                        entity_id: id,
+                        match_id: Some(match_id),
+                        snapshot: None,
+                    },
+                )
+                .await;
+            refresh_goal_derived_data(&state, &t, match_id, &score_event.credited_player_ids())
+                .await;
+
             // Redirect back to match detail page using HX-Redirect header
             let mut headers = HeaderMap::new();
             headers.insert(
@@ -438,3 +643,72 @@ pub async fn score_event_delete(
         }
     }
 }
+
+/// GET /api/matches/{match_id}/score-events - JSON goals of a match, with video links
+pub async fn score_events_api(
+    State(state): State<AppState>,
+    Path(match_id): Path<i64>,
+) -> impl IntoResponse {
+    match matches::get_match_by_id(&state.db, match_id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return (axum::http::StatusCode::NOT_FOUND, "Match not found").into_response(),
+        Err(e) => {
+            tracing::error!("Failed to fetch match: {}", e);
+            return (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to fetch match",
+            )
+                .into_response();
+        }
+    }
+
+    match matches::get_score_events(&state.db, match_id).await {
+        Ok(events) => Json(events).into_response(),
+        Err(e) => {
+            tracing::error!("Failed to fetch score events: {}", e);
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to fetch score events",
+            )
+                .into_response()
+        }
+    }
+}
+
+/// GET /api/score-events - JSON goals in insertion order, paged by cursor
+///
+/// Pass the `next_cursor` of a response as `after` to read the next page;
+/// it is `null` on the last page.
+pub async fn score_events_feed_api(
+    State(state): State<AppState>,
+    Query(query): Query<ScoreEventsFeedQuery>,
+) -> impl IntoResponse {
+    let after = match query.after.as_deref().filter(|after| !after.is_empty()) {
+        Some(token) => match Cursor::decode(token) {
+            Some(cursor) => Some(cursor),
+            None => return (axum::http::StatusCode::BAD_REQUEST, "Invalid cursor").into_response(),
+        },
+        None => None,
+    };
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_SCORE_EVENTS_LIMIT)
+        .clamp(1, MAX_SCORE_EVENTS_LIMIT);
+
+    match matches::get_score_events_after(&state.db, query.match_id, after, limit).await {
+        Ok(page) => Json(ScoreEventsFeedEntity {
+            items: page.items,
+            total: page.total,
+            next_cursor: page.next_cursor,
+        })
+        .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to fetch score events: {}", e);
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to fetch score events",
+            )
+                .into_response()
+        }
+    }
+}
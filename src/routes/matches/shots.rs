@@ -0,0 +1,146 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::i18n::TranslationContext;
+use crate::service::matches::{self, MatchEntity, MatchShotsEntity};
+use crate::views::pages::matches::match_shots_modal;
+
+#[derive(Debug, Deserialize)]
+pub struct MatchShotsForm {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i32")]
+    home_1: Option<i32>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i32")]
+    home_2: Option<i32>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i32")]
+    home_3: Option<i32>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i32")]
+    home_4: Option<i32>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i32")]
+    away_1: Option<i32>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i32")]
+    away_2: Option<i32>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i32")]
+    away_3: Option<i32>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i32")]
+    away_4: Option<i32>,
+}
+
+impl MatchShotsForm {
+    /// Convert filled-in fields to shots entries; empty fields are skipped
+    fn to_entities(&self, match_info: &MatchEntity) -> Vec<MatchShotsEntity> {
+        let home = [self.home_1, self.home_2, self.home_3, self.home_4];
+        let away = [self.away_1, self.away_2, self.away_3, self.away_4];
+
+        [
+            (match_info.home_team_id, home),
+            (match_info.away_team_id, away),
+        ]
+        .into_iter()
+        .flat_map(|(team_id, periods)| {
+            periods
+                .into_iter()
+                .zip(1..)
+                .filter_map(move |(shots, period)| {
+                    shots.map(|shots| MatchShotsEntity {
+                        team_id,
+                        period,
+                        shots,
+                    })
+                })
+        })
+        .collect()
+    }
+}
+
+/// GET /matches/{id}/shots/edit - Show edit shots on goal modal
+pub async fn match_shots_edit_form(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let match_info = match matches::get_match_by_id(&state.db, id).await {
+        Ok(Some(m)) => m,
+        Ok(None) => {
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_match_not_found(),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch match: {}", e);
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_match(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let shots = matches::get_match_shots(&state.db, id)
+        .await
+        .unwrap_or_default();
+
+    Html(match_shots_modal(&t, None, &match_info, &shots).into_string())
+}
+
+/// POST /matches/{id}/shots - Save shots on goal
+pub async fn match_shots_update(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<MatchShotsForm>,
+) -> impl IntoResponse {
+    let match_info = matches::get_match_by_id(&state.db, id).await.ok().flatten();
+
+    let Some(match_info) = match_info else {
+        return Html(
+            crate::views::components::error::error_message(&t, t.messages.error_match_not_found())
+                .into_string(),
+        )
+        .into_response();
+    };
+
+    let shots = form.to_entities(&match_info);
+
+    // Validation
+    if let Some(error) = shots
+        .iter()
+        .find_map(|s| crate::validation::validate_shots_on_goal(Some(s.shots)).err())
+    {
+        return Html(match_shots_modal(&t, Some(error), &match_info, &shots).into_string())
+            .into_response();
+    }
+
+    match matches::save_match_shots(&state.db, id, &shots).await {
+        Ok(_) => {
+            // Redirect back to match detail page using HX-Redirect header
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-redirect"),
+                format!("/matches/{}", id)
+                    .parse()
+                    .expect("Valid redirect URL should parse"),
+            );
+            (headers, Html("".to_string())).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to save shots on goal: {}", e);
+            Html(
+                match_shots_modal(&t, Some("Failed to save shots"), &match_info, &shots)
+                    .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
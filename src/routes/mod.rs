@@ -1,11 +1,49 @@
+pub mod arenas;
 pub mod auth;
+pub mod awards;
+pub mod backups;
+pub mod calendar_subscriptions;
+pub mod change_history;
+pub mod changelog;
+pub mod comments;
 pub mod countries;
 pub mod events;
+pub mod graphql;
+pub mod instance_transfer;
+pub mod leaders;
+pub mod lines;
+pub mod live_updates;
 pub mod locale;
 pub mod management;
+pub mod match_import;
 pub mod matches;
+pub mod notifications;
+pub mod oauth;
+pub mod password_reset;
+pub mod placements;
 pub mod player_contracts;
 pub mod players;
+pub mod playoffs;
+pub mod predictions;
+pub mod profile;
+pub mod public_api;
+pub mod public_site;
+pub mod referees;
+pub mod registration;
+pub mod reports;
+pub mod season_export;
+pub mod season_groups;
 pub mod seasons;
+pub mod settings;
+pub mod sitemap;
+pub mod slow_queries;
+pub mod slugs;
+pub mod staff;
+pub mod status;
+pub mod tasks;
 pub mod team_participations;
 pub mod teams;
+pub mod transfers;
+pub mod users;
+pub mod webhooks;
+pub mod widgets;
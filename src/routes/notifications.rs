@@ -0,0 +1,93 @@
+use axum::{
+    extract::{Path, State},
+    response::{Html, IntoResponse, Redirect},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::notifications;
+use crate::views::{
+    components::error::error_message,
+    layout::admin_layout,
+    pages::notifications::{notifications_badge, notifications_page},
+};
+
+/// Most notifications listed at once
+const NOTIFICATIONS_LIMIT: i64 = 100;
+
+#[derive(Debug, Deserialize)]
+pub struct CsrfForm {
+    csrf_token: String,
+}
+
+/// GET /notifications - The current user's notifications
+pub async fn notifications_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let content =
+        match notifications::get_notifications(&state.db, session.user_id, NOTIFICATIONS_LIMIT)
+            .await
+        {
+            Ok(notifications) => notifications_page(&session, &t, &notifications),
+            Err(e) => {
+                tracing::error!("Failed to fetch notifications: {}", e);
+                error_message(&t, t.messages.notifications_load_failed())
+            }
+        };
+    Html(admin_layout("Notifications", &session, "/notifications", &t, content).into_string())
+}
+
+/// GET /notifications/unread-count - Badge for the navigation link
+pub async fn notifications_badge_get(
+    Extension(session): Extension<Session>,
+    State(state): State<AppState>,
+) -> Html<String> {
+    match notifications::count_unread_notifications(&state.db, session.user_id).await {
+        Ok(unread) => Html(notifications_badge(unread).into_string()),
+        Err(e) => {
+            // The badge is informational, so the page works without it
+            tracing::warn!("Failed to count unread notifications: {}", e);
+            Html(String::new())
+        }
+    }
+}
+
+/// GET /notifications/{id}/open - Mark a notification read and go to the comment
+pub async fn notification_open(
+    Extension(session): Extension<Session>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Redirect {
+    match notifications::mark_notification_read(&state.db, id, session.user_id).await {
+        Ok(Some(notification)) => match notification.url() {
+            Some(url) => Redirect::to(&url),
+            None => Redirect::to("/notifications"),
+        },
+        Ok(None) => Redirect::to("/notifications"),
+        Err(e) => {
+            tracing::error!("Failed to mark notification {} as read: {}", id, e);
+            Redirect::to("/notifications")
+        }
+    }
+}
+
+/// POST /notifications/read-all - Mark all notifications as read
+pub async fn notifications_read_all(
+    Extension(session): Extension<Session>,
+    State(state): State<AppState>,
+    Form(form): Form<CsrfForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    if let Err(e) = notifications::mark_all_notifications_read(&state.db, session.user_id).await {
+        tracing::error!("Failed to mark notifications as read: {}", e);
+    }
+    Redirect::to("/notifications").into_response()
+}
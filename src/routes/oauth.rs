@@ -0,0 +1,271 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{Html, IntoResponse, Redirect},
+    Extension, Form,
+};
+use axum_extra::extract::{
+    cookie::{Cookie, SameSite},
+    CookieJar,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::audit::{self, ClientIp, LoginAttempt, LoginFailure};
+use crate::auth::oauth::{OAuthLogin, OAuthProvider, OAUTH_STATE_COOKIE, OAUTH_STATE_MAX_AGE_SECS};
+use crate::auth::Session;
+use crate::business::user_identities::{
+    link_identity_validated, sign_in_with_identity, IdentityError,
+};
+use crate::i18n::TranslationContext;
+use crate::routes::auth::{login_options, record_attempt, session_cookie};
+use crate::service::{user_identities, users};
+use crate::views::pages::auth::{auth_notice_page, continue_page, login_page};
+
+#[derive(Debug, Deserialize)]
+pub struct CallbackQuery {
+    code: Option<String>,
+    state: Option<String>,
+    /// Set instead of `code` when the user cancelled at the provider
+    error: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IdentityForm {
+    csrf_token: String,
+}
+
+/// Where the provider sends the user back; must match the app registration
+fn redirect_uri(state: &AppState, provider: OAuthProvider) -> String {
+    format!(
+        "{}/auth/oauth/{}/callback",
+        state.public_url,
+        provider.as_str()
+    )
+}
+
+/// Cookie carrying the sign-in attempt to the callback
+fn state_cookie(state: &AppState, login: &OAuthLogin) -> Cookie<'static> {
+    Cookie::build((
+        OAUTH_STATE_COOKIE,
+        login.to_cookie_value(&state.session_secret),
+    ))
+    .path("/auth/oauth")
+    .http_only(true)
+    .secure(state.is_production)
+    .same_site(SameSite::Lax)
+    .max_age(time::Duration::seconds(OAUTH_STATE_MAX_AGE_SECS))
+    .build()
+}
+
+/// Send the user to the provider, remembering the attempt in a cookie
+fn start(
+    state: &AppState,
+    jar: CookieJar,
+    provider: OAuthProvider,
+    link_user_id: Option<i64>,
+) -> axum::response::Response {
+    let login = OAuthLogin::new(provider, link_user_id);
+    let Some(url) = state
+        .oauth
+        .authorization_url(&login, &redirect_uri(state, provider))
+    else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    (jar.add(state_cookie(state, &login)), Redirect::to(&url)).into_response()
+}
+
+/// GET /auth/oauth/{provider} - Sign in with Google or GitHub
+pub async fn oauth_start(
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+    jar: CookieJar,
+) -> axum::response::Response {
+    match OAuthProvider::from_str(&provider) {
+        Some(provider) => start(&state, jar, provider, None),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// POST /profile/identities/{provider} - Link a Google or GitHub account
+pub async fn identity_link(
+    Extension(session): Extension<Session>,
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+    jar: CookieJar,
+    Form(form): Form<IdentityForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match OAuthProvider::from_str(&provider) {
+        Some(provider) => start(&state, jar, provider, Some(session.user_id)),
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+/// POST /profile/identities/{provider}/unlink - Remove a linked account
+pub async fn identity_unlink(
+    Extension(session): Extension<Session>,
+    State(state): State<AppState>,
+    Path(provider): Path<String>,
+    Form(form): Form<IdentityForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    if let Err(e) = user_identities::unlink_identity(&state.db, session.user_id, &provider).await {
+        tracing::error!("Failed to unlink {} account: {}", provider, e);
+    }
+
+    Redirect::to("/profile").into_response()
+}
+
+/// Message for an external account that cannot be used
+fn identity_error_message(t: &TranslationContext, error: IdentityError) -> String {
+    match error {
+        IdentityError::NoAccount => t.messages.signin_oauth_no_account().to_string(),
+        IdentityError::Inactive => t.messages.signin_error_deactivated().to_string(),
+        IdentityError::LinkedElsewhere => t.messages.identities_linked_elsewhere().to_string(),
+        IdentityError::ProviderTaken => t.messages.identities_provider_taken().to_string(),
+    }
+}
+
+/// GET /auth/oauth/{provider}/callback - The provider sends the user back
+pub async fn oauth_callback(
+    State(state): State<AppState>,
+    Extension(t): Extension<TranslationContext>,
+    Path(provider): Path<String>,
+    Query(query): Query<CallbackQuery>,
+    Extension(ClientIp(client_ip)): Extension<ClientIp>,
+    headers: HeaderMap,
+    jar: CookieJar,
+) -> axum::response::Response {
+    let options = login_options(&state).await;
+    let cookie = jar.get(OAUTH_STATE_COOKIE).map(|c| c.value().to_string());
+    let jar = jar.remove(
+        Cookie::build(OAUTH_STATE_COOKIE)
+            .path("/auth/oauth")
+            .build(),
+    );
+    let sign_in_error = |jar: CookieJar, message: String| {
+        (
+            jar,
+            Html(login_page(&t, &options, Some(message)).into_string()),
+        )
+            .into_response()
+    };
+
+    // The attempt must have started in this browser and not be stale
+    let login = cookie.and_then(|value| {
+        OAuthLogin::from_cookie_value(
+            &value,
+            &state.session_secret,
+            chrono::Utc::now().timestamp(),
+        )
+    });
+    let login = match (login, OAuthProvider::from_str(&provider)) {
+        (Some(login), Some(provider))
+            if login.provider == provider && query.state.as_deref() == Some(&login.state) =>
+        {
+            login
+        }
+        _ => return sign_in_error(jar, t.messages.signin_oauth_failed().to_string()),
+    };
+    let Some(code) = query.code.filter(|_| query.error.is_none()) else {
+        return sign_in_error(jar, t.messages.signin_oauth_failed().to_string());
+    };
+
+    let identity = match state
+        .oauth
+        .fetch_identity(&login, &code, &redirect_uri(&state, login.provider))
+        .await
+    {
+        Ok(identity) => identity,
+        Err(e) => {
+            tracing::warn!("{} sign-in failed: {}", login.provider.label(), e);
+            return sign_in_error(jar, t.messages.signin_oauth_failed().to_string());
+        }
+    };
+
+    // Linking from the profile page; the session cookie is not sent on this
+    // cross-site navigation, so the user comes from the signed state cookie
+    if let Some(user_id) = login.link_user_id {
+        let message = match link_identity_validated(&state.db, user_id, login.provider, &identity)
+            .await
+        {
+            Ok(()) => return (jar, Html(continue_page("/profile").into_string())).into_response(),
+            Err(Ok(error)) => identity_error_message(&t, error),
+            Err(Err(e)) => {
+                tracing::error!("Failed to link {} account: {}", login.provider.label(), e);
+                t.messages.signin_error_general().to_string()
+            }
+        };
+        let title = t.messages.identities_title().to_string();
+        return (
+            jar,
+            Html(auth_notice_page(&t, &title, &message).into_string()),
+        )
+            .into_response();
+    }
+
+    let mut attempt = LoginAttempt {
+        user_id: None,
+        email: identity
+            .email
+            .clone()
+            .unwrap_or_else(|| format!("{} account {}", login.provider.label(), identity.subject)),
+        failure: None,
+        ip_address: client_ip,
+        user_agent: audit::user_agent(&headers),
+    };
+
+    let user = match sign_in_with_identity(&state.db, login.provider, &identity).await {
+        Ok(user_id) => users::get_user_by_id(&state.db, user_id).await,
+        Err(Ok(error)) => {
+            attempt.failure = Some(match error {
+                IdentityError::Inactive => LoginFailure::Deactivated,
+                _ => LoginFailure::UnlinkedAccount,
+            });
+            record_attempt(&state, &t, &attempt).await;
+            return sign_in_error(jar, identity_error_message(&t, error));
+        }
+        Err(Err(e)) => Err(e),
+    };
+    let session = match user {
+        Ok(Some(user)) => {
+            attempt.user_id = Some(user.id);
+            attempt.email = user.email.clone();
+            let name = user.name.unwrap_or_else(|| user.email.clone());
+            state
+                .sessions
+                .create_session(user.id, user.email, name)
+                .await
+        }
+        Ok(None) => Err(sqlx::Error::RowNotFound),
+        Err(e) => Err(e),
+    };
+    let session = match session {
+        Ok(session) => session,
+        Err(e) => {
+            tracing::error!("Failed to sign in with {}: {}", login.provider.label(), e);
+            return sign_in_error(jar, t.messages.signin_error_general().to_string());
+        }
+    };
+
+    tracing::info!(
+        "User {} logged in with {}",
+        attempt.email,
+        login.provider.label()
+    );
+    record_attempt(&state, &t, &attempt).await;
+
+    (
+        jar.add(session_cookie(&state, &session)),
+        Html(continue_page("/").into_string()),
+    )
+        .into_response()
+}
@@ -0,0 +1,135 @@
+use axum::{
+    extract::{Path, State},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::business::password_reset::{request_password_reset, reset_password_validated};
+use crate::i18n::TranslationContext;
+use crate::service::password_reset::{self, ResetTokenStatus};
+use crate::views::pages::auth::{auth_notice_page, forgot_password_page, reset_password_page};
+
+#[derive(Debug, Deserialize)]
+pub struct ForgotPasswordForm {
+    #[serde(default)]
+    email: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResetPasswordForm {
+    #[serde(default)]
+    password: String,
+    #[serde(default)]
+    confirm_password: String,
+}
+
+fn notice(t: &TranslationContext, message: &str) -> axum::response::Response {
+    Html(auth_notice_page(t, &t.messages.reset_request_title().to_string(), message).into_string())
+        .into_response()
+}
+
+/// Notice shown for a link that cannot be used
+fn unusable_link(t: &TranslationContext, status: ResetTokenStatus) -> axum::response::Response {
+    match status {
+        ResetTokenStatus::Expired => notice(t, &t.messages.reset_link_expired().to_string()),
+        _ => notice(t, &t.messages.reset_link_invalid().to_string()),
+    }
+}
+
+/// GET /auth/forgot-password - Ask for a password reset link
+pub async fn forgot_password_get(Extension(t): Extension<TranslationContext>) -> Html<String> {
+    Html(forgot_password_page(&t).into_string())
+}
+
+/// POST /auth/forgot-password - Email a reset link to an active account
+///
+/// The response is the same whether or not the address has an account, and
+/// send failures are only logged, so the form does not reveal who signs in.
+pub async fn forgot_password_post(
+    State(state): State<AppState>,
+    Extension(t): Extension<TranslationContext>,
+    Form(form): Form<ForgotPasswordForm>,
+) -> axum::response::Response {
+    let email = form.email.trim();
+    let check_inbox = || notice(&t, &t.messages.reset_check_inbox().to_string());
+
+    // The shared demo account's password is not the visitors' to change
+    if state
+        .demo_user_email
+        .as_deref()
+        .is_some_and(|demo| demo.eq_ignore_ascii_case(email))
+    {
+        return check_inbox();
+    }
+
+    let token = match request_password_reset(&state.db, email).await {
+        Ok(Some(token)) => token,
+        Ok(None) => return check_inbox(),
+        Err(e) => {
+            tracing::error!("Failed to issue password reset for {}: {}", email, e);
+            return notice(&t, &t.messages.signin_error_general().to_string());
+        }
+    };
+
+    let link = format!("{}/auth/reset-password/{}", state.public_url, token);
+    let body = format!(
+        "{}\n\n{}\n\n{}",
+        t.messages.reset_email_body(),
+        link,
+        t.messages.reset_email_expiry()
+    );
+    if let Err(e) = state
+        .mailer
+        .send(email, &t.messages.reset_email_subject().to_string(), body)
+        .await
+    {
+        tracing::error!("Failed to send password reset email to {}: {}", email, e);
+    }
+
+    check_inbox()
+}
+
+/// GET /auth/reset-password/{token} - New password form from the emailed link
+pub async fn reset_password_get(
+    State(state): State<AppState>,
+    Extension(t): Extension<TranslationContext>,
+    Path(token): Path<String>,
+) -> axum::response::Response {
+    match password_reset::check_reset_token(&state.db, &token).await {
+        Ok(ResetTokenStatus::Valid { .. }) => {
+            Html(reset_password_page(&t, &token, None).into_string()).into_response()
+        }
+        Ok(status) => unusable_link(&t, status),
+        Err(e) => {
+            tracing::error!("Failed to check password reset token: {}", e);
+            notice(&t, &t.messages.signin_error_general().to_string())
+        }
+    }
+}
+
+/// POST /auth/reset-password/{token} - Set the new password and sign out everywhere
+pub async fn reset_password_post(
+    State(state): State<AppState>,
+    Extension(t): Extension<TranslationContext>,
+    Path(token): Path<String>,
+    Form(form): Form<ResetPasswordForm>,
+) -> axum::response::Response {
+    match reset_password_validated(&state.db, &token, &form.password, &form.confirm_password).await
+    {
+        Ok(ResetTokenStatus::Valid { user_id }) => {
+            tracing::info!("User {} reset their password", user_id);
+            notice(&t, &t.messages.reset_done().to_string())
+        }
+        Ok(status) => unusable_link(&t, status),
+        Err(Ok(error)) => {
+            Html(reset_password_page(&t, &token, Some(error.message())).into_string())
+                .into_response()
+        }
+        Err(Err(e)) => {
+            tracing::error!("Failed to reset password: {}", e);
+            notice(&t, &t.messages.signin_error_general().to_string())
+        }
+    }
+}
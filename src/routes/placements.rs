@@ -0,0 +1,134 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::business;
+use crate::i18n::TranslationContext;
+use crate::service::team_participations;
+use crate::views::components::error::error_message;
+
+#[derive(Debug, Deserialize)]
+pub struct PlacementForm {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    final_rank: Option<i64>,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SeedForm {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    seed: Option<i64>,
+    csrf_token: String,
+}
+
+/// HTMX redirect back to the season detail page (teams are managed there)
+fn redirect_to_season(season_id: i64) -> axum::response::Response {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("hx-redirect"),
+        format!("/seasons/{}", season_id)
+            .parse()
+            .expect("Valid redirect URL should parse"),
+    );
+    (headers, Html("".to_string())).into_response()
+}
+
+/// POST /team-participations/{id}/placement - Set a team's final placement in its season
+pub async fn team_placement_set(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<PlacementForm>,
+) -> axum::response::Response {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let season_id = match team_participations::get_season_id_for_participation(&state.db, id).await
+    {
+        Ok(Some(season_id)) => season_id,
+        Ok(None) => {
+            return Html(
+                error_message(&t, t.messages.error_team_participation_not_found()).into_string(),
+            )
+            .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch team participation: {}", e);
+            return Html(
+                error_message(&t, t.messages.error_failed_to_set_placement()).into_string(),
+            )
+            .into_response();
+        }
+    };
+
+    match business::placements::set_final_rank_validated(&state.db, id, form.final_rank).await {
+        Ok(true) => redirect_to_season(season_id),
+        Ok(false) => {
+            Html(error_message(&t, t.messages.error_team_participation_not_found()).into_string())
+                .into_response()
+        }
+        Err(Ok(validation_error)) => {
+            Html(error_message(&t, validation_error.message()).into_string()).into_response()
+        }
+        Err(Err(e)) => {
+            tracing::error!("Failed to set final placement: {}", e);
+            Html(error_message(&t, t.messages.error_failed_to_set_placement()).into_string())
+                .into_response()
+        }
+    }
+}
+
+/// POST /team-participations/{id}/seed - Set a team's playoff seed in its season
+pub async fn team_seed_set(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<SeedForm>,
+) -> axum::response::Response {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let season_id = match team_participations::get_season_id_for_participation(&state.db, id).await
+    {
+        Ok(Some(season_id)) => season_id,
+        Ok(None) => {
+            return Html(
+                error_message(&t, t.messages.error_team_participation_not_found()).into_string(),
+            )
+            .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch team participation: {}", e);
+            return Html(error_message(&t, t.messages.error_failed_to_set_seed()).into_string())
+                .into_response();
+        }
+    };
+
+    match business::placements::set_seed_validated(&state.db, id, form.seed).await {
+        Ok(true) => redirect_to_season(season_id),
+        Ok(false) => {
+            Html(error_message(&t, t.messages.error_team_participation_not_found()).into_string())
+                .into_response()
+        }
+        Err(Ok(validation_error)) => {
+            Html(error_message(&t, validation_error.message()).into_string()).into_response()
+        }
+        Err(Err(e)) => {
+            tracing::error!("Failed to set seed: {}", e);
+            Html(error_message(&t, t.messages.error_failed_to_set_seed()).into_string())
+                .into_response()
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Multipart, Path, State},
     http::{HeaderMap, HeaderName},
     response::{Html, IntoResponse},
     Extension, Form,
@@ -8,16 +8,43 @@ use serde::Deserialize;
 
 use crate::app_state::AppState;
 use crate::auth::Session;
+use crate::business;
 use crate::i18n::TranslationContext;
-use crate::service::player_contracts;
+use crate::service::roster_rules::{self, RosterRuleCheck};
+use crate::service::{lines, player_contracts, staff};
 use crate::views::{
     layout::admin_layout,
-    pages::roster::{add_player_modal, roster_page},
+    pages::roster::{
+        add_player_modal, edit_player_contract_modal, roster_import_modal,
+        roster_import_review_modal, roster_page,
+    },
 };
 
 #[derive(Debug, Deserialize)]
 pub struct AddPlayerForm {
     player_id: i64,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    jersey_number: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct EditPlayerContractForm {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    jersey_number: Option<i64>,
+    #[serde(default)]
+    captaincy: Option<String>,
+}
+
+/// HTMX redirect back to the roster page
+pub(crate) fn redirect_to_roster(team_participation_id: i64) -> axum::response::Response {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("hx-redirect"),
+        format!("/team-participations/{}/roster", team_participation_id)
+            .parse()
+            .expect("Valid redirect URL should parse"),
+    );
+    (headers, Html("".to_string())).into_response()
 }
 
 /// GET /team-participations/{id}/roster - Roster management page
@@ -95,10 +122,64 @@ pub async fn roster_get(
         }
     };
 
-    let content = roster_page(&t, &context, &roster);
+    let assignments = match lines::get_line_assignments(&state.db, team_participation_id).await {
+        Ok(assignments) => assignments,
+        Err(e) => {
+            tracing::warn!(
+                "Failed to load lines for team participation {}: {}",
+                team_participation_id,
+                e
+            );
+            Vec::new()
+        }
+    };
+
+    let staff = match staff::get_participation_staff(&state.db, team_participation_id).await {
+        Ok(staff) => staff,
+        Err(e) => {
+            tracing::warn!(
+                "Failed to load staff for team participation {}: {}",
+                team_participation_id,
+                e
+            );
+            Vec::new()
+        }
+    };
+
+    let rule_checks = match load_rule_checks(&state.db, team_participation_id).await {
+        Ok(checks) => checks,
+        Err(e) => {
+            tracing::warn!(
+                "Failed to check roster rules for team participation {}: {}",
+                team_participation_id,
+                e
+            );
+            Vec::new()
+        }
+    };
+
+    let content = roster_page(&t, &context, &roster, &assignments, &staff, &rule_checks);
     Html(admin_layout("Roster Management", &session, "/seasons", &t, content).into_string())
 }
 
+/// The event's roster rules compared with the current roster
+async fn load_rule_checks(
+    db: &sqlx::SqlitePool,
+    team_participation_id: i64,
+) -> Result<Vec<RosterRuleCheck>, sqlx::Error> {
+    let Some(rules) =
+        roster_rules::get_participation_roster_rules(db, team_participation_id).await?
+    else {
+        return Ok(Vec::new());
+    };
+    if rules.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let composition = roster_rules::get_roster_composition(db, team_participation_id).await?;
+    Ok(rules.checks(&composition))
+}
+
 /// GET /team-participations/{id}/roster/add-player - Form/modal to add player
 pub async fn roster_add_player_form(
     Extension(t): Extension<TranslationContext>,
@@ -174,21 +255,102 @@ pub async fn roster_add_player(
         _ => {}
     }
 
-    // Add player to roster
-    match player_contracts::add_player_to_roster(&state.db, team_participation_id, form.player_id)
+    // Validate jersey number (range and uniqueness within the roster)
+    let jersey_number = match crate::validation::validate_jersey_number(form.jersey_number) {
+        Ok(number) => number,
+        Err(error) => {
+            return Html(
+                add_player_modal(&t, team_participation_id, Some(error), &available_players)
+                    .into_string(),
+            )
+            .into_response();
+        }
+    };
+
+    if let Some(number) = jersey_number {
+        match player_contracts::is_jersey_number_taken(
+            &state.db,
+            team_participation_id,
+            number,
+            None,
+        )
         .await
+        {
+            Ok(true) => {
+                return Html(
+                    add_player_modal(
+                        &t,
+                        team_participation_id,
+                        Some("This jersey number is already taken in the roster"),
+                        &available_players,
+                    )
+                    .into_string(),
+                )
+                .into_response();
+            }
+            Err(e) => {
+                tracing::error!("Failed to check jersey number: {}", e);
+                return Html(
+                    add_player_modal(
+                        &t,
+                        team_participation_id,
+                        Some("Failed to check jersey number"),
+                        &available_players,
+                    )
+                    .into_string(),
+                )
+                .into_response();
+            }
+            _ => {}
+        }
+    }
+
+    // Enforce the event's roster rules
+    match business::roster_rules::check_player_addition(
+        &state.db,
+        team_participation_id,
+        form.player_id,
+    )
+    .await
     {
-        Ok(_) => {
-            // Return HTMX redirect to roster page
-            let mut headers = HeaderMap::new();
-            headers.insert(
-                HeaderName::from_static("hx-redirect"),
-                format!("/team-participations/{}/roster", team_participation_id)
-                    .parse()
-                    .expect("Valid redirect URL should parse"),
-            );
-            (headers, Html("".to_string())).into_response()
+        Ok(Some(violation)) => {
+            return Html(
+                add_player_modal(
+                    &t,
+                    team_participation_id,
+                    Some(violation.message()),
+                    &available_players,
+                )
+                .into_string(),
+            )
+            .into_response();
         }
+        Err(e) => {
+            tracing::error!("Failed to check roster rules: {}", e);
+            return Html(
+                add_player_modal(
+                    &t,
+                    team_participation_id,
+                    Some("Failed to check roster rules"),
+                    &available_players,
+                )
+                .into_string(),
+            )
+            .into_response();
+        }
+        Ok(None) => {}
+    }
+
+    // Add player to roster
+    match player_contracts::add_player_to_roster(
+        &state.db,
+        team_participation_id,
+        form.player_id,
+        jersey_number,
+    )
+    .await
+    {
+        Ok(_) => redirect_to_roster(team_participation_id),
         Err(e) => {
             tracing::error!("Failed to add player to roster: {}", e);
             Html(
@@ -205,6 +367,327 @@ pub async fn roster_add_player(
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct RosterImportForm {
+    csv: String,
+    csrf_token: String,
+}
+
+/// GET /team-participations/{id}/roster/import - Form/modal to upload a roster CSV
+pub async fn roster_import_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    Path(team_participation_id): Path<i64>,
+) -> impl IntoResponse {
+    Html(roster_import_modal(&session, &t, team_participation_id, None).into_string())
+}
+
+/// POST /team-participations/{id}/roster/import/review - Show what importing the uploaded CSV would do
+pub async fn roster_import_review(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(team_participation_id): Path<i64>,
+    mut multipart: Multipart,
+) -> axum::response::Response {
+    let mut csrf_token = String::new();
+    let mut data = Vec::new();
+
+    while let Ok(Some(field)) = multipart.next_field().await {
+        match field.name().unwrap_or("") {
+            "csrf_token" => csrf_token = field.text().await.unwrap_or_default(),
+            "file" => match field.bytes().await {
+                Ok(bytes) => data = bytes.to_vec(),
+                Err(e) => {
+                    tracing::warn!("Failed to read roster upload: {}", e);
+                    return Html(
+                        roster_import_modal(
+                            &session,
+                            &t,
+                            team_participation_id,
+                            Some("Failed to read uploaded file"),
+                        )
+                        .into_string(),
+                    )
+                    .into_response();
+                }
+            },
+            _ => {}
+        }
+    }
+
+    if let Err(response) = crate::auth::validate_csrf_token(&csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let Ok(csv) = String::from_utf8(data) else {
+        return Html(
+            roster_import_modal(
+                &session,
+                &t,
+                team_participation_id,
+                Some(business::roster_import::RosterImportError::InvalidFile.message()),
+            )
+            .into_string(),
+        )
+        .into_response();
+    };
+
+    match business::roster_import::plan_roster_import_validated(
+        &state.db,
+        team_participation_id,
+        csv.as_bytes(),
+    )
+    .await
+    {
+        Ok(plan) => Html(
+            roster_import_review_modal(&session, &t, team_participation_id, &csv, &plan)
+                .into_string(),
+        )
+        .into_response(),
+        Err(Ok(validation_error)) => Html(
+            roster_import_modal(
+                &session,
+                &t,
+                team_participation_id,
+                Some(validation_error.message()),
+            )
+            .into_string(),
+        )
+        .into_response(),
+        Err(Err(e)) => {
+            tracing::error!("Failed to plan roster import: {}", e);
+            Html(
+                roster_import_modal(
+                    &session,
+                    &t,
+                    team_participation_id,
+                    Some("Failed to read the roster. Please try again."),
+                )
+                .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
+
+/// POST /team-participations/{id}/roster/import - Import the reviewed roster CSV
+pub async fn roster_import(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(team_participation_id): Path<i64>,
+    Form(form): Form<RosterImportForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match business::roster_import::import_roster_validated(
+        &state.db,
+        team_participation_id,
+        form.csv.as_bytes(),
+    )
+    .await
+    {
+        Ok(summary) => {
+            tracing::info!(
+                "Imported {} players ({} new) into team participation {}",
+                summary.added,
+                summary.created,
+                team_participation_id
+            );
+            redirect_to_roster(team_participation_id)
+        }
+        Err(Ok(validation_error)) => Html(
+            roster_import_modal(
+                &session,
+                &t,
+                team_participation_id,
+                Some(validation_error.message()),
+            )
+            .into_string(),
+        )
+        .into_response(),
+        Err(Err(e)) => {
+            tracing::error!("Failed to import roster: {}", e);
+            Html(
+                roster_import_modal(
+                    &session,
+                    &t,
+                    team_participation_id,
+                    Some("Failed to import the roster. Please try again."),
+                )
+                .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
+
+/// GET /player-contracts/{id}/edit - Form/modal to edit a roster entry
+pub async fn player_contract_edit_form(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(player_contract_id): Path<i64>,
+) -> impl IntoResponse {
+    match player_contracts::get_player_contract(&state.db, player_contract_id).await {
+        Ok(Some(contract)) => Html(edit_player_contract_modal(&t, &contract, None).into_string()),
+        Ok(None) => Html(
+            crate::views::components::error::error_message(
+                &t,
+                t.messages.error_player_contract_not_found(),
+            )
+            .into_string(),
+        ),
+        Err(e) => {
+            tracing::error!("Failed to fetch player contract: {}", e);
+            Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_roster(),
+                )
+                .into_string(),
+            )
+        }
+    }
+}
+
+/// POST /player-contracts/{id} - Update a roster entry
+pub async fn player_contract_update(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(player_contract_id): Path<i64>,
+    Form(form): Form<EditPlayerContractForm>,
+) -> axum::response::Response {
+    let contract = match player_contracts::get_player_contract(&state.db, player_contract_id).await
+    {
+        Ok(Some(contract)) => contract,
+        Ok(None) => {
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_player_contract_not_found(),
+                )
+                .into_string(),
+            )
+            .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch player contract: {}", e);
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_roster(),
+                )
+                .into_string(),
+            )
+            .into_response();
+        }
+    };
+
+    // Re-render with the submitted value on error
+    let submitted = player_contracts::PlayerContractEntity {
+        jersey_number: form.jersey_number,
+        captaincy: form.captaincy.clone().filter(|c| !c.is_empty()),
+        ..contract.clone()
+    };
+
+    let captaincy = match business::player_contracts::parse_captaincy(form.captaincy.as_deref()) {
+        Ok(captaincy) => captaincy,
+        Err(error) => {
+            return Html(
+                edit_player_contract_modal(&t, &submitted, Some(error.message())).into_string(),
+            )
+            .into_response();
+        }
+    };
+
+    let jersey_number = match crate::validation::validate_jersey_number(form.jersey_number) {
+        Ok(number) => number,
+        Err(error) => {
+            return Html(edit_player_contract_modal(&t, &submitted, Some(error)).into_string())
+                .into_response();
+        }
+    };
+
+    if let Some(number) = jersey_number {
+        match player_contracts::is_jersey_number_taken(
+            &state.db,
+            contract.team_participation_id,
+            number,
+            Some(contract.id),
+        )
+        .await
+        {
+            Ok(true) => {
+                return Html(
+                    edit_player_contract_modal(
+                        &t,
+                        &submitted,
+                        Some("This jersey number is already taken in the roster"),
+                    )
+                    .into_string(),
+                )
+                .into_response();
+            }
+            Err(e) => {
+                tracing::error!("Failed to check jersey number: {}", e);
+                return Html(
+                    edit_player_contract_modal(
+                        &t,
+                        &submitted,
+                        Some("Failed to check jersey number"),
+                    )
+                    .into_string(),
+                )
+                .into_response();
+            }
+            _ => {}
+        }
+    }
+
+    match business::player_contracts::update_captaincy_validated(&state.db, &contract, captaincy)
+        .await
+    {
+        Ok(()) => {}
+        Err(Ok(error)) => {
+            return Html(
+                edit_player_contract_modal(&t, &submitted, Some(error.message())).into_string(),
+            )
+            .into_response();
+        }
+        Err(Err(e)) => {
+            tracing::error!("Failed to update captaincy: {}", e);
+            return Html(
+                edit_player_contract_modal(
+                    &t,
+                    &submitted,
+                    Some("Failed to update player. Please try again."),
+                )
+                .into_string(),
+            )
+            .into_response();
+        }
+    }
+
+    match player_contracts::update_jersey_number(&state.db, contract.id, jersey_number).await {
+        Ok(_) => redirect_to_roster(contract.team_participation_id),
+        Err(e) => {
+            tracing::error!("Failed to update player contract: {}", e);
+            Html(
+                edit_player_contract_modal(
+                    &t,
+                    &submitted,
+                    Some("Failed to update player. Please try again."),
+                )
+                .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
+
 /// POST /player-contracts/{id}/delete - Remove player from roster
 pub async fn player_contract_delete(
     Extension(t): Extension<TranslationContext>,
@@ -243,17 +726,7 @@ pub async fn player_contract_delete(
     };
 
     match player_contracts::remove_player_from_roster(&state.db, player_contract_id).await {
-        Ok(true) => {
-            // Return HTMX redirect to roster page
-            let mut headers = HeaderMap::new();
-            headers.insert(
-                HeaderName::from_static("hx-redirect"),
-                format!("/team-participations/{}/roster", team_participation_id)
-                    .parse()
-                    .expect("Valid redirect URL should parse"),
-            );
-            (headers, Html("".to_string())).into_response()
-        }
+        Ok(true) => redirect_to_roster(team_participation_id),
         Ok(false) => Html(
             crate::views::components::error::error_message(
                 &t,
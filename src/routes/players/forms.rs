@@ -16,6 +16,8 @@ pub struct PlayerFormData {
     pub weight_kg: Option<i64>,
     pub position: Option<String>,
     pub shoots: Option<String>,
+    /// URL slug, only present in the edit form
+    pub slug: Option<String>,
     pub csrf_token: String,
 }
 
@@ -102,6 +104,9 @@ pub async fn parse_player_form(
                     Some(text)
                 };
             }
+            "slug" => {
+                form_data.slug = Some(field.text().await.unwrap_or_default());
+            }
             "csrf_token" => {
                 form_data.csrf_token = field.text().await.unwrap_or_default();
             }
@@ -1,16 +1,22 @@
 use axum::{
     extract::{Multipart, Path, Query, State},
-    response::{Html, IntoResponse},
+    response::{Html, IntoResponse, Response},
     Extension,
 };
 use serde::Deserialize;
 
 use crate::app_state::AppState;
 use crate::auth::Session;
+use crate::common::csv_export::csv_download;
 use crate::i18n::TranslationContext;
+use crate::routes::change_history::record_history;
 use crate::service::{
+    change_history::{
+        diff_snapshots, player_snapshot, HistoryAction, HistoryEntityType, NewChangeEntity,
+    },
     countries,
     players::{self, PlayerFilters, SortField, SortOrder},
+    slugs::SlugEntity,
 };
 use crate::views::{
     components::{
@@ -21,6 +27,7 @@ use crate::views::{
     pages::player_detail::player_detail_page,
     pages::players::{player_create_modal, player_edit_modal, player_list_content, players_page},
 };
+use crate::webhooks::EntityChange;
 
 #[derive(Debug, Deserialize)]
 pub struct PlayersQuery {
@@ -167,6 +174,25 @@ pub async fn players_list_partial(
     )
 }
 
+/// GET /players/export, /api/players/export - Players of the current list as CSV
+pub async fn players_export(
+    State(state): State<AppState>,
+    Query(query): Query<PlayersQuery>,
+) -> Response {
+    let filters = PlayerFilters {
+        name: query.name,
+        country_id: query.country_id,
+    };
+    let sort_field = SortField::from_str(&query.sort);
+    let sort_order = SortOrder::from_str(&query.order);
+
+    csv_download("players.csv", move |mut sink| async move {
+        players::export_players_csv(&state.db, &filters, &sort_field, &sort_order, &mut sink)
+            .await?;
+        Ok(sink)
+    })
+}
+
 /// GET /players/new - Show create modal
 pub async fn player_create_form(
     Extension(session): Extension<Session>,
@@ -230,7 +256,23 @@ pub async fn player_create(
     match crate::business::players::create_player_validated(&state.db, &form_data, final_photo_path)
         .await
     {
-        Ok(_) => {
+        Ok(id) => {
+            if let Ok(Some(created)) = players::get_player_by_id(&state.db, id).await {
+                state
+                    .webhooks
+                    .entity_changed(
+                        &state.db,
+                        EntityChange {
+                            entity_type: HistoryEntityType::Player,
+                            action: HistoryAction::Create,
+                            entity_id: id,
+                            match_id: None,
+                            snapshot: Some(player_snapshot(&created)),
+                        },
+                    )
+                    .await;
+            }
+
             // Return HTMX response to close modal and reload table
             // Trigger entity-created event for dashboard stats update
             let mut headers = HeaderMap::new();
@@ -376,6 +418,37 @@ pub async fn player_update(
     .await
     {
         Ok(true) => {
+            if let Ok(Some(updated)) = players::get_player_by_id(&state.db, id).await {
+                record_history(
+                    &state.db,
+                    NewChangeEntity {
+                        entity_type: HistoryEntityType::Player,
+                        entity_id: id,
+                        match_id: None,
+                        user_id: Some(session.user_id),
+                        action: HistoryAction::Update,
+                        changes: diff_snapshots(
+                            Some(&player_snapshot(&current_player)),
+                            Some(&player_snapshot(&updated)),
+                        ),
+                    },
+                )
+                .await;
+                state
+                    .webhooks
+                    .entity_changed(
+                        &state.db,
+                        EntityChange {
+                            entity_type: HistoryEntityType::Player,
+                            action: HistoryAction::Update,
+                            entity_id: id,
+                            match_id: None,
+                            snapshot: Some(player_snapshot(&updated)),
+                        },
+                    )
+                    .await;
+            }
+
             // Return HTMX response to close modal and reload page to show updated data
             use axum::http::header::{HeaderMap, HeaderName};
             let mut headers = HeaderMap::new();
@@ -449,6 +522,20 @@ pub async fn player_delete(
     }
     match players::delete_player(&state.db, id).await {
         Ok(true) => {
+            state
+                .webhooks
+                .entity_changed(
+                    &state.db,
+                    EntityChange {
+                        entity_type: HistoryEntityType::Player,
+                        action: HistoryAction::Delete,
+                        entity_id: id,
+                        match_id: None,
+                        snapshot: None,
+                    },
+                )
+                .await;
+
             // Reload the table content after successful delete
             let filters = PlayerFilters {
                 name: query.name.clone(),
@@ -516,16 +603,24 @@ pub async fn player_delete(
     }
 }
 
-/// GET /players/{id} - Player detail page
+/// GET /players/{id} - Player detail page, also reachable by the player's slug
 pub async fn player_detail(
     Extension(session): Extension<Session>,
     Extension(t): Extension<TranslationContext>,
     State(state): State<AppState>,
-    Path(id): Path<i64>,
-) -> impl IntoResponse {
+    Path(key): Path<String>,
+) -> Response {
     // Fetch all player detail page data from business layer
-    let page_data = match crate::business::players::get_player_detail_page_data(&state.db, id).await
-    {
+    let resolved =
+        crate::routes::slugs::resolve_entity_path(&state.db, SlugEntity::Player, "/players", &key)
+            .await;
+    let page_data = match resolved {
+        Ok(Some(id)) => crate::business::players::get_player_detail_page_data(&state.db, id).await,
+        Ok(None) => Ok(None),
+        Err(Ok(redirect)) => return redirect,
+        Err(Err(e)) => Err(e),
+    };
+    let page_data = match page_data {
         Ok(Some(data)) => data,
         Ok(None) => {
             return Html(
@@ -540,7 +635,8 @@ pub async fn player_detail(
                     ),
                 )
                 .into_string(),
-            );
+            )
+            .into_response();
         }
         Err(e) => {
             tracing::error!("Failed to fetch player detail: {}", e);
@@ -556,17 +652,34 @@ pub async fn player_detail(
                     ),
                 )
                 .into_string(),
-            );
+            )
+            .into_response();
         }
     };
 
+    let id = page_data.detail.player_info.id;
+
+    let percentiles = crate::business::players::get_player_season_percentiles(
+        &state.db,
+        &state.season_stats,
+        id,
+        &page_data.season_stats,
+    )
+    .await;
+
     let content = player_detail_page(
         &session,
         &t,
         &page_data.detail,
         &page_data.season_stats,
+        &percentiles,
         &page_data.event_stats,
         &page_data.property_changes,
+        &page_data.transfers,
+        &page_data.awards,
+        &page_data.milestones,
+        &page_data.streaks,
     );
     Html(admin_layout("Player Detail", &session, "/players", &t, content).into_string())
+        .into_response()
 }
@@ -0,0 +1,113 @@
+use axum::{
+    extract::{Multipart, State},
+    response::{Html, IntoResponse},
+    Extension,
+};
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::change_history::{player_snapshot, HistoryAction, HistoryEntityType};
+use crate::service::player_import::{PlayerImportReport, PlayerRowOutcome};
+use crate::service::players;
+use crate::views::pages::players::{player_import_modal, player_import_result_modal};
+use crate::webhooks::EntityChange;
+
+/// Queue a `player.created` webhook for every player the import created
+async fn announce_created_players(state: &AppState, report: &PlayerImportReport) {
+    for row in &report.rows {
+        let PlayerRowOutcome::Created { player_id } = row.outcome else {
+            continue;
+        };
+        if let Ok(Some(created)) = players::get_player_by_id(&state.db, player_id).await {
+            state
+                .webhooks
+                .entity_changed(
+                    &state.db,
+                    EntityChange {
+                        entity_type: HistoryEntityType::Player,
+                        action: HistoryAction::Create,
+                        entity_id: player_id,
+                        match_id: None,
+                        snapshot: Some(player_snapshot(&created)),
+                    },
+                )
+                .await;
+        }
+    }
+}
+
+/// GET /players/import - Form/modal to upload a player CSV
+pub async fn player_import_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+) -> impl IntoResponse {
+    Html(player_import_modal(&session, &t, None).into_string())
+}
+
+/// POST /players/import - Create players from an uploaded CSV
+pub async fn player_import(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    let mut csrf_token = String::new();
+    let mut data = Vec::new();
+
+    while let Ok(Some(field)) = multipart.next_field().await {
+        match field.name().unwrap_or("") {
+            "csrf_token" => csrf_token = field.text().await.unwrap_or_default(),
+            "file" => match field.bytes().await {
+                Ok(bytes) => data = bytes.to_vec(),
+                Err(e) => {
+                    tracing::warn!("Failed to read player upload: {}", e);
+                    return Html(
+                        player_import_modal(&session, &t, Some("Failed to read uploaded file"))
+                            .into_string(),
+                    )
+                    .into_response();
+                }
+            },
+            _ => {}
+        }
+    }
+
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match crate::business::player_import::import_players_validated(&state.db, &data).await {
+        Ok(report) => {
+            use axum::http::header::{HeaderMap, HeaderName};
+
+            tracing::info!(
+                "Imported players: {} created, {} duplicates, {} invalid",
+                report.created(),
+                report.duplicates(),
+                report.invalid()
+            );
+            announce_created_players(&state, &report).await;
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-trigger"),
+                "entity-created".parse().unwrap(),
+            );
+            (
+                headers,
+                Html(player_import_result_modal(&t, &report).into_string()),
+            )
+                .into_response()
+        }
+        Err(Ok(validation_error)) => {
+            Html(player_import_modal(&session, &t, Some(validation_error.message())).into_string())
+                .into_response()
+        }
+        Err(Err(e)) => {
+            tracing::error!("Failed to import players: {}", e);
+            Html(player_import_modal(&session, &t, Some("Failed to import players")).into_string())
+                .into_response()
+        }
+    }
+}
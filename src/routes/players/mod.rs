@@ -2,18 +2,26 @@
 mod event_stats_handlers;
 pub mod forms;
 mod handlers;
+mod import_handlers;
 mod property_change_handlers;
 mod scoring;
+mod splits;
 
 // Re-export all route handlers from handlers module
 pub use handlers::{
     player_create, player_create_form, player_delete, player_detail, player_edit_form,
-    player_update, players_get, players_list_partial,
+    player_update, players_export, players_get, players_list_partial,
 };
 
+// Re-export import route handlers
+pub use import_handlers::{player_import, player_import_form};
+
 // Re-export scoring route handlers
 pub use scoring::{player_scoring_get, player_scoring_list_partial};
 
+// Re-export splits route handlers
+pub use splits::player_splits_get;
+
 // Re-export event stats handlers
 pub use event_stats_handlers::{
     event_stats_create, event_stats_create_form, event_stats_delete, event_stats_edit_form,
@@ -0,0 +1,59 @@
+use axum::{
+    extract::{Path, State},
+    response::{Html, IntoResponse},
+    Extension,
+};
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::{players, splits};
+use crate::views::{
+    components::error::error_message, layout::admin_layout, pages::splits::player_splits_page,
+};
+
+/// GET /players/{id}/splits - Home and away scoring of the player per season
+pub async fn player_splits_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(player_id): Path<i64>,
+) -> impl IntoResponse {
+    let player = match players::get_player_by_id(&state.db, player_id).await {
+        Ok(Some(player)) => player,
+        Ok(None) => {
+            return Html(
+                admin_layout(
+                    "Player Not Found",
+                    &session,
+                    "/players",
+                    &t,
+                    error_message(&t, t.messages.error_player_not_found()),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch player: {}", e);
+            return Html(
+                admin_layout(
+                    "Error",
+                    &session,
+                    "/players",
+                    &t,
+                    error_message(&t, t.messages.error_failed_to_load_player()),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let content = match splits::get_player_splits(&state.db, player_id).await {
+        Ok(splits) => player_splits_page(&t, &player, &splits),
+        Err(e) => {
+            tracing::error!("Failed to load splits for player {}: {}", player_id, e);
+            error_message(&t, t.messages.splits_load_failed())
+        }
+    };
+    Html(admin_layout("Home/Away Splits", &session, "/players", &t, content).into_string())
+}
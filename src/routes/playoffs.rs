@@ -0,0 +1,288 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::business;
+use crate::i18n::TranslationContext;
+use crate::service::matches;
+use crate::service::playoffs::{self, CreatePlayoffSeriesEntity};
+use crate::views::components::error::error_message;
+use crate::views::pages::playoffs::{series_create_modal, series_game_modal};
+
+#[derive(Debug, Deserialize)]
+pub struct CreateSeriesForm {
+    round: i64,
+    best_of: i64,
+    home_team_id: i64,
+    away_team_id: i64,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    home_seed: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    away_seed: Option<i64>,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GenerateBracketForm {
+    best_of: i64,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateSeriesGameForm {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    match_date: Option<String>,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteSeriesForm {
+    csrf_token: String,
+}
+
+/// HTMX redirect back to the season detail page (bracket is rendered there)
+fn redirect_to_season(season_id: i64) -> axum::response::Response {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("hx-redirect"),
+        format!("/seasons/{}", season_id)
+            .parse()
+            .expect("Valid redirect URL should parse"),
+    );
+    (headers, Html("".to_string())).into_response()
+}
+
+/// GET /seasons/{season_id}/playoffs/new - Show create series modal
+pub async fn series_create_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(season_id): Path<i64>,
+) -> impl IntoResponse {
+    let teams = match matches::get_teams_for_season(&state.db, season_id).await {
+        Ok(teams) => teams,
+        Err(e) => {
+            tracing::warn!(
+                "Failed to load teams for season {} dropdown: {}",
+                season_id,
+                e
+            );
+            Vec::new()
+        }
+    };
+
+    Html(series_create_modal(&session, &t, season_id, None, &teams).into_string())
+}
+
+/// POST /seasons/{season_id}/playoffs - Create a playoff series
+pub async fn series_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(season_id): Path<i64>,
+    Form(form): Form<CreateSeriesForm>,
+) -> axum::response::Response {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let teams = match matches::get_teams_for_season(&state.db, season_id).await {
+        Ok(teams) => teams,
+        Err(e) => {
+            tracing::warn!(
+                "Failed to load teams for season {} dropdown: {}",
+                season_id,
+                e
+            );
+            Vec::new()
+        }
+    };
+
+    match business::playoffs::create_series_validated(
+        &state.db,
+        CreatePlayoffSeriesEntity {
+            season_id,
+            round: form.round,
+            home_team_id: form.home_team_id,
+            away_team_id: form.away_team_id,
+            home_seed: form.home_seed,
+            away_seed: form.away_seed,
+            best_of: form.best_of,
+        },
+    )
+    .await
+    {
+        Ok(_) => redirect_to_season(season_id),
+        Err(Ok(validation_error)) => Html(
+            series_create_modal(
+                &session,
+                &t,
+                season_id,
+                Some(validation_error.message()),
+                &teams,
+            )
+            .into_string(),
+        )
+        .into_response(),
+        Err(Err(e)) => {
+            tracing::error!("Failed to create playoff series: {}", e);
+            Html(
+                series_create_modal(
+                    &session,
+                    &t,
+                    season_id,
+                    Some("Failed to create series"),
+                    &teams,
+                )
+                .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
+
+/// POST /seasons/{season_id}/playoffs/generate - Create round 1 from the team seeds
+pub async fn bracket_generate(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(season_id): Path<i64>,
+    Form(form): Form<GenerateBracketForm>,
+) -> axum::response::Response {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match business::playoffs::generate_bracket_validated(&state.db, season_id, form.best_of).await {
+        Ok(_) => redirect_to_season(season_id),
+        Err(Ok(validation_error)) => {
+            Html(error_message(&t, validation_error.message()).into_string()).into_response()
+        }
+        Err(Err(e)) => {
+            tracing::error!("Failed to generate playoff bracket: {}", e);
+            Html(error_message(&t, t.messages.error_failed_to_generate_bracket()).into_string())
+                .into_response()
+        }
+    }
+}
+
+/// GET /playoffs/{id}/games/new - Show modal to schedule the next series game
+pub async fn series_game_create_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    match playoffs::get_series_by_id(&state.db, id).await {
+        Ok(Some(series)) => Html(series_game_modal(&session, &t, &series, None).into_string()),
+        Ok(None) => {
+            Html(error_message(&t, t.messages.error_playoff_series_not_found()).into_string())
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch playoff series: {}", e);
+            Html(error_message(&t, t.messages.error_failed_to_load_playoff_series()).into_string())
+        }
+    }
+}
+
+/// POST /playoffs/{id}/games - Schedule the next series game
+pub async fn series_game_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<CreateSeriesGameForm>,
+) -> axum::response::Response {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let series = match playoffs::get_series_by_id(&state.db, id).await {
+        Ok(Some(series)) => series,
+        Ok(None) => {
+            return Html(
+                error_message(&t, t.messages.error_playoff_series_not_found()).into_string(),
+            )
+            .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch playoff series: {}", e);
+            return Html(
+                error_message(&t, t.messages.error_failed_to_load_playoff_series()).into_string(),
+            )
+            .into_response();
+        }
+    };
+
+    match business::playoffs::add_series_game_validated(&state.db, id, form.match_date).await {
+        Ok(_) => redirect_to_season(series.season_id),
+        Err(Ok(validation_error)) => Html(
+            series_game_modal(&session, &t, &series, Some(validation_error.message()))
+                .into_string(),
+        )
+        .into_response(),
+        Err(Err(e)) => {
+            tracing::error!("Failed to create series game: {}", e);
+            Html(
+                series_game_modal(&session, &t, &series, Some("Failed to create game"))
+                    .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
+
+/// POST /playoffs/{id}/delete - Delete a playoff series (games are kept)
+pub async fn series_delete(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<DeleteSeriesForm>,
+) -> axum::response::Response {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let season_id = match playoffs::get_series_by_id(&state.db, id).await {
+        Ok(Some(series)) => series.season_id,
+        Ok(None) => {
+            return Html(
+                error_message(&t, t.messages.error_playoff_series_not_found()).into_string(),
+            )
+            .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch playoff series: {}", e);
+            return Html(
+                error_message(&t, t.messages.error_failed_to_load_playoff_series()).into_string(),
+            )
+            .into_response();
+        }
+    };
+
+    match playoffs::delete_series(&state.db, id).await {
+        Ok(true) => redirect_to_season(season_id),
+        Ok(false) => {
+            Html(error_message(&t, t.messages.error_playoff_series_not_found()).into_string())
+                .into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to delete playoff series: {}", e);
+            Html(
+                error_message(&t, t.messages.error_failed_to_delete_playoff_series()).into_string(),
+            )
+            .into_response()
+        }
+    }
+}
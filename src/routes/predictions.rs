@@ -0,0 +1,179 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, HeaderName, StatusCode},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::{
+    matches,
+    predictions::{self, PredictedWinner},
+};
+use crate::views::{
+    components::error::error_message,
+    layout::admin_layout,
+    pages::predictions::{leaderboard_page, prediction_row, predictions_page},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct PredictionsQuery {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    season_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PredictionForm {
+    csrf_token: String,
+    winner: String,
+}
+
+/// GET /predictions - Upcoming matches to pick winners for
+pub async fn predictions_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<PredictionsQuery>,
+) -> impl IntoResponse {
+    let seasons = match matches::get_seasons(&state.db).await {
+        Ok(seasons) => seasons,
+        Err(e) => {
+            tracing::warn!("Failed to load seasons for dropdown: {}", e);
+            Vec::new()
+        }
+    };
+
+    let content = match predictions::get_upcoming_matches(
+        &state.db,
+        session.user_id,
+        query.season_id,
+    )
+    .await
+    {
+        Ok(upcoming) => predictions_page(&session, &t, &seasons, query.season_id, &upcoming),
+        Err(e) => {
+            tracing::error!("Failed to load upcoming matches for predictions: {}", e);
+            error_message(&t, t.messages.error_failed_to_load_matches())
+        }
+    };
+
+    Html(admin_layout("Predictions", &session, "/predictions", &t, content).into_string())
+}
+
+/// POST /predictions/{match_id} - Save the user's pick for a match
+pub async fn prediction_save(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(match_id): Path<i64>,
+    Form(form): Form<PredictionForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let Some(winner) = PredictedWinner::from_str(&form.winner) else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+
+    let saved =
+        match predictions::save_prediction(&state.db, session.user_id, match_id, winner).await {
+            Ok(saved) => saved,
+            Err(e) => {
+                tracing::error!("Failed to save prediction for match {}: {}", match_id, e);
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        };
+
+    let mut headers = HeaderMap::new();
+    if !saved {
+        // The match started (or was removed) since the page was loaded
+        headers.insert(
+            HeaderName::from_static("hx-toast-error"),
+            t.messages
+                .predictions_closed()
+                .to_string()
+                .parse()
+                .expect("Toast message should be a valid header value"),
+        );
+        headers.insert(
+            HeaderName::from_static("hx-reswap"),
+            "none".parse().unwrap(),
+        );
+        return (headers, Html(String::new())).into_response();
+    }
+
+    match predictions::get_upcoming_match(&state.db, session.user_id, match_id).await {
+        Ok(Some(upcoming)) => {
+            headers.insert(
+                HeaderName::from_static("hx-toast-success"),
+                t.messages
+                    .predictions_saved()
+                    .to_string()
+                    .parse()
+                    .expect("Toast message should be a valid header value"),
+            );
+            (
+                headers,
+                Html(prediction_row(&session, &upcoming).into_string()),
+            )
+                .into_response()
+        }
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!("Failed to reload prediction for match {}: {}", match_id, e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// GET /predictions/leaderboard - Predictors ranked by correct picks in a season
+pub async fn predictions_leaderboard_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<PredictionsQuery>,
+) -> impl IntoResponse {
+    let seasons = match matches::get_seasons(&state.db).await {
+        Ok(seasons) => seasons,
+        Err(e) => {
+            tracing::warn!("Failed to load seasons for dropdown: {}", e);
+            Vec::new()
+        }
+    };
+
+    // Default to the most recent season
+    let season_id = query
+        .season_id
+        .or_else(|| seasons.first().map(|(id, _)| *id));
+
+    let standings = match season_id {
+        Some(season_id) => match predictions::get_leaderboard(&state.db, season_id).await {
+            Ok(standings) => standings,
+            Err(e) => {
+                tracing::error!(
+                    "Failed to load prediction leaderboard for season {}: {}",
+                    season_id,
+                    e
+                );
+                Vec::new()
+            }
+        },
+        None => Vec::new(),
+    };
+
+    let content = leaderboard_page(&session, &t, &seasons, season_id, &standings);
+    Html(
+        admin_layout(
+            "Prediction Leaderboard",
+            &session,
+            "/predictions",
+            &t,
+            content,
+        )
+        .into_string(),
+    )
+}
@@ -0,0 +1,317 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse, Redirect},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::api_keys::{self, ApiScope};
+use crate::auth::{audit, Session};
+use crate::business::users as users_business;
+use crate::i18n::TranslationContext;
+use crate::service::{user_identities, users};
+use crate::views::components::error::error_message;
+use crate::views::{
+    layout::admin_layout,
+    pages::profile::{api_keys_section, profile_page},
+    pages::users::change_password_page,
+};
+
+/// Number of sign-in attempts listed on the profile page
+const RECENT_LOGINS_LIMIT: i64 = 20;
+
+/// Maximum length of an API key name
+const API_KEY_NAME_MAX_LENGTH: usize = 100;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateApiKeyForm {
+    csrf_token: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default, rename = "matches:read")]
+    matches_read: bool,
+    #[serde(default, rename = "stats:read")]
+    stats_read: bool,
+    #[serde(default, rename = "teams:read")]
+    teams_read: bool,
+    #[serde(default, rename = "players:read")]
+    players_read: bool,
+}
+
+impl CreateApiKeyForm {
+    /// Checked scopes; none checked creates a key with full access
+    fn scopes(&self) -> Vec<ApiScope> {
+        [
+            (self.matches_read, ApiScope::MatchesRead),
+            (self.stats_read, ApiScope::StatsRead),
+            (self.teams_read, ApiScope::TeamsRead),
+            (self.players_read, ApiScope::PlayersRead),
+        ]
+        .into_iter()
+        .filter_map(|(checked, scope)| checked.then_some(scope))
+        .collect()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RevokeApiKeyForm {
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChangePasswordForm {
+    csrf_token: String,
+    current_password: String,
+    new_password: String,
+    confirm_password: String,
+}
+
+/// GET /profile - Current user's profile with recent sign-ins
+pub async fn profile_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let logins =
+        match audit::get_recent_logins(&state.db, session.user_id, RECENT_LOGINS_LIMIT).await {
+            Ok(logins) => logins,
+            Err(e) => {
+                tracing::error!("Failed to load sign-in history: {}", e);
+                return Html(
+                    admin_layout(
+                        "Profile",
+                        &session,
+                        "/profile",
+                        &t,
+                        crate::views::components::error::error_message(
+                            &t,
+                            t.messages.error_failed_to_load_logins(),
+                        ),
+                    )
+                    .into_string(),
+                );
+            }
+        };
+
+    let api_keys = api_keys::get_user_api_keys(&state.db, session.user_id)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::error!("Failed to load API keys: {}", e);
+            Vec::new()
+        });
+
+    let identities = user_identities::get_user_identities(&state.db, session.user_id)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::error!("Failed to load linked accounts: {}", e);
+            Vec::new()
+        });
+
+    let content = profile_page(
+        &t,
+        &session,
+        &logins,
+        &api_keys,
+        &state.oauth.enabled(),
+        &identities,
+    );
+    Html(admin_layout("Profile", &session, "/profile", &t, content).into_string())
+}
+
+/// Re-render the API keys section with an optional new key, error or toast
+async fn api_keys_response(
+    state: &AppState,
+    session: &Session,
+    t: &TranslationContext,
+    new_key: Option<&str>,
+    error: Option<&str>,
+    toast: Option<String>,
+) -> axum::response::Response {
+    let keys = match api_keys::get_user_api_keys(&state.db, session.user_id).await {
+        Ok(keys) => keys,
+        Err(e) => {
+            tracing::error!("Failed to load API keys: {}", e);
+            return Html(error_message(t, t.messages.error_server_error()).into_string())
+                .into_response();
+        }
+    };
+
+    let mut headers = HeaderMap::new();
+    if let Some(toast) = toast {
+        headers.insert(
+            HeaderName::from_static("hx-toast-success"),
+            toast
+                .parse()
+                .expect("Toast message should be a valid header value"),
+        );
+    }
+
+    (
+        headers,
+        Html(api_keys_section(t, session, &keys, new_key, error).into_string()),
+    )
+        .into_response()
+}
+
+/// POST /profile/api-keys - Create an API key for the current user, optionally
+/// limited to reading some parts of the API
+pub async fn api_key_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<CreateApiKeyForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let name = form.name.trim();
+    if name.is_empty() {
+        let error = t.messages.profile_api_key_name_required().to_string();
+        return api_keys_response(&state, &session, &t, None, Some(&error), None).await;
+    }
+    if name.chars().count() > API_KEY_NAME_MAX_LENGTH {
+        return api_keys_response(
+            &state,
+            &session,
+            &t,
+            None,
+            Some("Name cannot exceed 100 characters"),
+            None,
+        )
+        .await;
+    }
+
+    match api_keys::create_api_key(&state.db, session.user_id, name, &form.scopes()).await {
+        Ok((_, key)) => {
+            api_keys_response(
+                &state,
+                &session,
+                &t,
+                Some(&key),
+                None,
+                Some(t.messages.profile_api_key_created().to_string()),
+            )
+            .await
+        }
+        Err(e) => {
+            tracing::error!("Failed to create API key: {}", e);
+            api_keys_response(
+                &state,
+                &session,
+                &t,
+                None,
+                Some("Failed to create API key. Please try again."),
+                None,
+            )
+            .await
+        }
+    }
+}
+
+/// POST /profile/api-keys/{id}/revoke - Revoke an API key of the current user
+pub async fn api_key_revoke(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<RevokeApiKeyForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match api_keys::revoke_api_key(&state.db, session.user_id, id).await {
+        Ok(_) => {
+            api_keys_response(
+                &state,
+                &session,
+                &t,
+                None,
+                None,
+                Some(t.messages.profile_api_key_revoked().to_string()),
+            )
+            .await
+        }
+        Err(e) => {
+            tracing::error!("Failed to revoke API key: {}", e);
+            api_keys_response(
+                &state,
+                &session,
+                &t,
+                None,
+                Some("Failed to revoke API key. Please try again."),
+                None,
+            )
+            .await
+        }
+    }
+}
+
+/// Render the change password page, noting whether the change is required
+async fn change_password_response(
+    state: &AppState,
+    session: &Session,
+    t: &TranslationContext,
+    error: Option<&str>,
+) -> axum::response::Response {
+    let required = users::must_change_password(&state.db, session.user_id)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::error!("Failed to check password status: {}", e);
+            false
+        });
+
+    let content = change_password_page(t, session, required, error);
+    Html(admin_layout("Change password", session, "/profile", t, content).into_string())
+        .into_response()
+}
+
+/// GET /profile/password - Change the current user's password
+pub async fn password_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> axum::response::Response {
+    change_password_response(&state, &session, &t, None).await
+}
+
+/// POST /profile/password - Replace the current user's password
+pub async fn password_post(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<ChangePasswordForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match users_business::change_own_password(
+        &state.db,
+        session.user_id,
+        &session.id,
+        &form.current_password,
+        &form.new_password,
+        &form.confirm_password,
+    )
+    .await
+    {
+        Ok(()) => Redirect::to("/profile").into_response(),
+        Err(Ok(error)) => {
+            change_password_response(&state, &session, &t, Some(error.message())).await
+        }
+        Err(Err(e)) => {
+            tracing::error!("Failed to change password: {}", e);
+            change_password_response(
+                &state,
+                &session,
+                &t,
+                Some("Failed to change password. Please try again."),
+            )
+            .await
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Unauthenticated JSON for scoreboard apps
+//!
+//! Responses are small and may be cached briefly by browsers and proxies, so
+//! scoreboards polling these endpoints rarely reach the database.
+
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::app_state::AppState;
+use crate::service::live_scores::{self, ScoreboardMatch};
+
+/// Cache lifetime of the live scores, in seconds
+const LIVE_MAX_AGE: u32 = 15;
+
+/// Cache lifetime of the day's matches, in seconds
+const TODAY_MAX_AGE: u32 = 60;
+
+fn scoreboard_response(
+    matches: Result<Vec<ScoreboardMatch>, sqlx::Error>,
+    max_age: u32,
+) -> axum::response::Response {
+    match matches {
+        Ok(matches) => (
+            [(
+                header::CACHE_CONTROL,
+                format!("public, max-age={}", max_age),
+            )],
+            Json(matches),
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to fetch scoreboard matches: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                [(header::CACHE_CONTROL, "no-store")],
+                "Failed to fetch matches",
+            )
+                .into_response()
+        }
+    }
+}
+
+/// GET /public/api/live - Matches in progress
+pub async fn live_matches_get(State(state): State<AppState>) -> impl IntoResponse {
+    scoreboard_response(live_scores::get_live_matches(&state.db).await, LIVE_MAX_AGE)
+}
+
+/// GET /public/api/matches/today - All matches of the server's current day
+pub async fn today_matches_get(State(state): State<AppState>) -> impl IntoResponse {
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    scoreboard_response(
+        live_scores::get_matches_on_date(&state.db, &today).await,
+        TODAY_MAX_AGE,
+    )
+}
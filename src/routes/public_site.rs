@@ -0,0 +1,212 @@
+//! Unauthenticated read-only pages of seasons
+//!
+//! Pages change only when results are entered, so they may be cached briefly
+//! by browsers and proxies. Seasons and teams are addressed by slug; numeric
+//! IDs and slugs given up after a rename redirect permanently to the current
+//! path.
+
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode, Uri},
+    response::{Html, IntoResponse, Redirect, Response},
+    Extension,
+};
+use maud::Markup;
+
+use crate::app_state::AppState;
+use crate::i18n::TranslationContext;
+use crate::service::public_site::PublicSeasonPaths;
+use crate::service::seasons::{self, SeasonEntity};
+use crate::service::slugs::{self, SlugEntity};
+use crate::service::{
+    leaders, public_site, season_export, season_groups, standings, team_participations,
+};
+use crate::views::components::error::error_message;
+use crate::views::layout::public_layout;
+use crate::views::pages::public_site::{
+    public_home_page, public_not_found, public_players_page, public_roster_page,
+    public_season_page, season_name,
+};
+
+/// Cache lifetime of the public pages, in seconds
+const PUBLIC_MAX_AGE: u32 = 60;
+
+fn public_page(t: &TranslationContext, title: &str, content: Markup) -> Response {
+    (
+        [(
+            header::CACHE_CONTROL,
+            format!("public, max-age={}", PUBLIC_MAX_AGE),
+        )],
+        Html(public_layout(title, t, content).into_string()),
+    )
+        .into_response()
+}
+
+fn not_found(t: &TranslationContext) -> Response {
+    (
+        StatusCode::NOT_FOUND,
+        Html(public_layout("Not Found", t, public_not_found(t)).into_string()),
+    )
+        .into_response()
+}
+
+fn load_failed(t: &TranslationContext, e: sqlx::Error) -> Response {
+    tracing::error!("Failed to load public page: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        [(header::CACHE_CONTROL, "no-store")],
+        Html(
+            public_layout(
+                "Error",
+                t,
+                error_message(t, t.messages.error_failed_to_load_season()),
+            )
+            .into_string(),
+        ),
+    )
+        .into_response()
+}
+
+/// Record ID behind a path segment, or the response to send instead
+async fn resolve_key(
+    state: &AppState,
+    t: &TranslationContext,
+    entity: SlugEntity,
+    key: &str,
+) -> Result<i64, Response> {
+    match slugs::resolve_key(&state.db, entity, key).await {
+        Ok(Some(id)) => Ok(id),
+        Ok(None) => Err(not_found(t)),
+        Err(e) => Err(load_failed(t, e)),
+    }
+}
+
+/// Season behind a path segment with the paths of its pages, or the response to send instead
+async fn load_season(
+    state: &AppState,
+    t: &TranslationContext,
+    key: &str,
+) -> Result<(SeasonEntity, PublicSeasonPaths), Response> {
+    let season_id = resolve_key(state, t, SlugEntity::Season, key).await?;
+    let season = match seasons::get_season_by_id(&state.db, season_id).await {
+        Ok(Some(season)) => season,
+        Ok(None) => return Err(not_found(t)),
+        Err(e) => return Err(load_failed(t, e)),
+    };
+    match public_site::get_public_season_paths(&state.db, season_id).await {
+        Ok(paths) => Ok((season, paths)),
+        Err(e) => Err(load_failed(t, e)),
+    }
+}
+
+/// Permanent redirect when a page was requested by anything but its current path
+fn redirect_to_current(uri: &Uri, path: String) -> Option<Response> {
+    (uri.path() != path).then(|| Redirect::permanent(&path).into_response())
+}
+
+/// GET /public - Every season
+pub async fn public_home_get(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> Response {
+    match public_site::get_public_seasons(&state.db).await {
+        Ok(seasons) => public_page(&t, "Seasons", public_home_page(&t, &seasons)),
+        Err(e) => load_failed(&t, e),
+    }
+}
+
+/// GET /public/seasons/:season - Standings and schedule of a season
+pub async fn public_season_get(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    uri: Uri,
+    Path(key): Path<String>,
+) -> Response {
+    let (season, paths) = match load_season(&state, &t, &key).await {
+        Ok(loaded) => loaded,
+        Err(response) => return response,
+    };
+    if let Some(redirect) = redirect_to_current(&uri, paths.season()) {
+        return redirect;
+    }
+    let season_id = season.id;
+    let data = async {
+        Ok::<_, sqlx::Error>((
+            season_groups::get_season_groups(&state.db, season_id).await?,
+            standings::get_season_standings(&state.db, season_id).await?,
+            season_export::get_season_schedule(&state.db, season_id).await?,
+        ))
+    };
+    match data.await {
+        Ok((groups, standings, schedule)) => public_page(
+            &t,
+            &season_name(&season),
+            public_season_page(&t, &season, &paths, &groups, &standings, &schedule),
+        ),
+        Err(e) => load_failed(&t, e),
+    }
+}
+
+/// GET /public/seasons/:season/teams/:team - Roster of a team in a season
+pub async fn public_roster_get(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    uri: Uri,
+    Path((season_key, team_key)): Path<(String, String)>,
+) -> Response {
+    let (season, paths) = match load_season(&state, &t, &season_key).await {
+        Ok(loaded) => loaded,
+        Err(response) => return response,
+    };
+    let team_id = match resolve_key(&state, &t, SlugEntity::Team, &team_key).await {
+        Ok(team_id) => team_id,
+        Err(response) => return response,
+    };
+    let season_id = season.id;
+    let team = match team_participations::get_teams_for_season(&state.db, season_id).await {
+        Ok(teams) => teams.into_iter().find(|team| team.team_id == team_id),
+        Err(e) => return load_failed(&t, e),
+    };
+    let Some(team) = team else {
+        return not_found(&t);
+    };
+    if let Some(redirect) = redirect_to_current(&uri, paths.roster(team_id)) {
+        return redirect;
+    }
+    match public_site::get_public_roster(&state.db, season_id, team_id).await {
+        Ok(roster) => public_page(
+            &t,
+            &team.team_name,
+            public_roster_page(&t, &season, &paths, &team, &roster),
+        ),
+        Err(e) => load_failed(&t, e),
+    }
+}
+
+/// GET /public/seasons/:season/players - Scoring of every player in a season
+pub async fn public_players_get(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    uri: Uri,
+    Path(key): Path<String>,
+) -> Response {
+    let (season, paths) = match load_season(&state, &t, &key).await {
+        Ok(loaded) => loaded,
+        Err(response) => return response,
+    };
+    if let Some(redirect) = redirect_to_current(&uri, paths.players()) {
+        return redirect;
+    }
+    match leaders::get_season_player_points(&state.db, season.id).await {
+        Ok(players) => {
+            let players =
+                leaders::SeasonLeadersEntity::from_players(&players, players.len()).points;
+            public_page(
+                &t,
+                &season_name(&season),
+                public_players_page(&t, &season, &paths, &players),
+            )
+        }
+        Err(e) => load_failed(&t, e),
+    }
+}
@@ -0,0 +1,456 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::{
+    countries,
+    referees::{
+        self, CreateRefereeEntity, RefereeFilters, SortField, SortOrder, UpdateRefereeEntity,
+    },
+};
+use crate::validation::validate_name;
+use crate::views::{
+    components::{error::error_message, htmx::htmx_reload_table},
+    layout::admin_layout,
+    pages::referees::{
+        referee_create_modal, referee_edit_modal, referee_list_content, referees_page,
+    },
+};
+
+#[derive(Debug, Deserialize)]
+pub struct RefereesQuery {
+    #[serde(default = "default_page")]
+    page: usize,
+    #[serde(default = "default_page_size")]
+    page_size: usize,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    name: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    country_id: Option<i64>,
+    #[serde(default = "default_sort")]
+    sort: String,
+    #[serde(default = "default_order")]
+    order: String,
+}
+
+fn default_page() -> usize {
+    1
+}
+
+fn default_page_size() -> usize {
+    20
+}
+
+fn default_sort() -> String {
+    "name".to_string()
+}
+
+fn default_order() -> String {
+    "asc".to_string()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateRefereeForm {
+    name: String,
+    country_id: Option<i64>,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateRefereeForm {
+    name: String,
+    country_id: Option<i64>,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteRefereeForm {
+    csrf_token: String,
+}
+
+/// GET /referees - Referees list page
+pub async fn referees_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<RefereesQuery>,
+) -> impl IntoResponse {
+    // Build filters
+    let filters = RefereeFilters {
+        name: query.name.clone(),
+        country_id: query.country_id,
+    };
+
+    // Parse sort parameters
+    let sort_field = SortField::from_str(&query.sort);
+    let sort_order = SortOrder::from_str(&query.order);
+
+    // Get referees
+    let result = match referees::get_referees(
+        &state.db,
+        &filters,
+        &sort_field,
+        &sort_order,
+        query.page,
+        query.page_size,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            tracing::error!("Failed to fetch referees: {}", e);
+            return Html(
+                admin_layout(
+                    "Referees",
+                    &session,
+                    "/referees",
+                    &t,
+                    crate::views::components::error::error_message(
+                        &t,
+                        t.messages.error_failed_to_load_referees(),
+                    ),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    // Get countries for filter
+    let countries = match countries::get_countries_simple(&state.db).await {
+        Ok(countries) => countries,
+        Err(e) => {
+            tracing::warn!("Failed to load countries for filter dropdown: {}", e);
+            Vec::new()
+        }
+    };
+
+    let content = referees_page(
+        &session,
+        &t,
+        &result,
+        &filters,
+        &sort_field,
+        &sort_order,
+        &countries,
+    );
+    Html(admin_layout("Referees", &session, "/referees", &t, content).into_string())
+}
+
+/// GET /referees/list - HTMX endpoint for table updates
+pub async fn referees_list_partial(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<RefereesQuery>,
+) -> impl IntoResponse {
+    let filters = RefereeFilters {
+        name: query.name.clone(),
+        country_id: query.country_id,
+    };
+
+    // Parse sort parameters
+    let sort_field = SortField::from_str(&query.sort);
+    let sort_order = SortOrder::from_str(&query.order);
+
+    let result = match referees::get_referees(
+        &state.db,
+        &filters,
+        &sort_field,
+        &sort_order,
+        query.page,
+        query.page_size,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            tracing::error!("Failed to fetch referees: {}", e);
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_referees(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    Html(
+        referee_list_content(&session, &t, &result, &filters, &sort_field, &sort_order)
+            .into_string(),
+    )
+}
+
+/// GET /referees/new - Show create modal
+pub async fn referee_create_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+) -> impl IntoResponse {
+    Html(referee_create_modal(&session, &t, None).into_string())
+}
+
+/// POST /referees - Create new referee
+pub async fn referee_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<CreateRefereeForm>,
+) -> impl IntoResponse {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    // Validation
+    let name = match validate_name(&form.name) {
+        Ok(n) => n,
+        Err(error) => {
+            return Html(referee_create_modal(&session, &t, Some(error)).into_string())
+                .into_response()
+        }
+    };
+
+    // Create referee
+    match referees::create_referee(
+        &state.db,
+        CreateRefereeEntity {
+            name: name.to_string(),
+            country_id: form.country_id,
+        },
+    )
+    .await
+    {
+        Ok(_) => {
+            use axum::http::header::{HeaderMap, HeaderName};
+
+            // Return HTMX response to close modal and reload table
+            // Trigger entity-created event for dashboard stats update
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-trigger"),
+                "entity-created".parse().unwrap(),
+            );
+            headers.insert(
+                HeaderName::from_static("hx-toast-success"),
+                t.messages.referees_created().to_string().parse().unwrap(),
+            );
+            (
+                headers,
+                htmx_reload_table("/referees/list", "referees-table"),
+            )
+                .into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to create referee: {}", e);
+            Html(referee_create_modal(&session, &t, Some("Failed to create referee")).into_string())
+                .into_response()
+        }
+    }
+}
+
+/// GET /referees/{id}/edit - Show edit modal
+pub async fn referee_edit_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let referee = match referees::get_referee_by_id(&state.db, id).await {
+        Ok(Some(referee)) => referee,
+        Ok(None) => {
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_referee_not_found(),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch referee: {}", e);
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_referee(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    Html(referee_edit_modal(&session, &t, &referee, None).into_string())
+}
+
+/// POST /referees/{id} - Update referee
+pub async fn referee_update(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<UpdateRefereeForm>,
+) -> impl IntoResponse {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    // Validation
+    let name = match validate_name(&form.name) {
+        Ok(n) => n,
+        Err(error) => {
+            let referee = match referees::get_referee_by_id(&state.db, id).await {
+                Ok(Some(referee)) => referee,
+                Ok(None) => {
+                    return Html(
+                        error_message(&t, t.messages.error_referee_not_found()).into_string(),
+                    )
+                    .into_response();
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "Database error fetching referee {} for form re-render: {}",
+                        id,
+                        e
+                    );
+                    return Html(
+                        error_message(&t, t.messages.error_failed_to_load_referee()).into_string(),
+                    )
+                    .into_response();
+                }
+            };
+            return Html(referee_edit_modal(&session, &t, &referee, Some(error)).into_string())
+                .into_response();
+        }
+    };
+
+    // Update referee
+    match referees::update_referee(
+        &state.db,
+        id,
+        UpdateRefereeEntity {
+            name: name.to_string(),
+            country_id: form.country_id,
+        },
+    )
+    .await
+    {
+        Ok(true) => {
+            // Return HTMX response to close modal and reload table
+            use axum::http::header::{HeaderMap, HeaderName};
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-toast-success"),
+                t.messages.referees_updated().to_string().parse().unwrap(),
+            );
+            (
+                headers,
+                htmx_reload_table("/referees/list", "referees-table"),
+            )
+                .into_response()
+        }
+        Ok(false) => Html(error_message(&t, t.messages.error_referee_not_found()).into_string())
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to update referee: {}", e);
+            let referee = match referees::get_referee_by_id(&state.db, id).await {
+                Ok(Some(referee)) => referee,
+                Ok(None) => {
+                    return Html(
+                        error_message(&t, t.messages.error_referee_not_found()).into_string(),
+                    )
+                    .into_response();
+                }
+                Err(e2) => {
+                    tracing::error!(
+                        "Database error fetching referee {} for form re-render: {}",
+                        id,
+                        e2
+                    );
+                    return Html(
+                        error_message(&t, t.messages.error_failed_to_load_referee()).into_string(),
+                    )
+                    .into_response();
+                }
+            };
+            Html(
+                referee_edit_modal(&session, &t, &referee, Some("Failed to update referee"))
+                    .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
+
+/// POST /referees/{id}/delete - Delete referee
+pub async fn referee_delete(
+    Extension(session): Extension<Session>,
+    State(state): State<AppState>,
+    Extension(t): Extension<TranslationContext>,
+    Path(id): Path<i64>,
+    Query(query): Query<RefereesQuery>,
+    Form(form): Form<DeleteRefereeForm>,
+) -> impl IntoResponse {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match referees::delete_referee(&state.db, id).await {
+        Ok(true) => {
+            // Build URL to reload table with current filters and sorting
+            let mut reload_url = format!(
+                "/referees/list?page={}&page_size={}&sort={}&order={}",
+                query.page, query.page_size, query.sort, query.order
+            );
+
+            if let Some(name) = &query.name {
+                reload_url.push_str(&format!("&name={}", urlencoding::encode(name)));
+            }
+
+            if let Some(country_id) = query.country_id {
+                reload_url.push_str(&format!("&country_id={}", country_id));
+            }
+
+            // Return HTMX response to reload table with filters
+            use axum::http::header::{HeaderMap, HeaderName};
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-toast-success"),
+                t.messages.referees_deleted().to_string().parse().unwrap(),
+            );
+            (
+                headers,
+                Html(format!(
+                    "<div hx-get=\"{}\" hx-target=\"#referees-table\" hx-trigger=\"load\" hx-swap=\"outerHTML\"></div>",
+                    reload_url
+                )),
+            )
+                .into_response()
+        }
+        Ok(false) => Html(
+            crate::views::components::error::error_message(
+                &t,
+                t.messages.error_referee_not_found(),
+            )
+            .into_string(),
+        )
+        .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to delete referee: {}", e);
+            Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_delete_referee(),
+                )
+                .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use axum::{
+    extract::{Path, State},
+    response::{Html, IntoResponse, Redirect},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::business::registration::{register_validated, Registration};
+use crate::i18n::TranslationContext;
+use crate::service::registration::{self, VerificationOutcome};
+use crate::service::settings::{self, RegistrationSettings};
+use crate::views::pages::auth::{auth_notice_page, register_page};
+
+#[derive(Debug, Deserialize)]
+pub struct RegisterForm {
+    #[serde(default)]
+    email: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    password: String,
+    #[serde(default)]
+    confirm_password: String,
+}
+
+/// Registration switches; the closed defaults when they cannot be read
+pub(crate) async fn registration_settings(state: &AppState) -> RegistrationSettings {
+    settings::get_registration_settings(&state.db)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::error!("Failed to load registration settings: {}", e);
+            RegistrationSettings::default()
+        })
+}
+
+/// GET /auth/register - Sign-up form, while registration is open
+pub async fn register_get(
+    State(state): State<AppState>,
+    Extension(t): Extension<TranslationContext>,
+) -> axum::response::Response {
+    if !registration_settings(&state).await.enabled {
+        return Redirect::to("/auth/login").into_response();
+    }
+
+    Html(register_page(&t, "", "", None).into_string()).into_response()
+}
+
+/// POST /auth/register - Create the account and email the verification link
+pub async fn register_post(
+    State(state): State<AppState>,
+    Extension(t): Extension<TranslationContext>,
+    Form(form): Form<RegisterForm>,
+) -> axum::response::Response {
+    if !registration_settings(&state).await.enabled {
+        return Redirect::to("/auth/login").into_response();
+    }
+
+    let form_error = |message: &str| {
+        Html(register_page(&t, form.email.trim(), form.name.trim(), Some(message)).into_string())
+            .into_response()
+    };
+
+    let result = register_validated(
+        &state.db,
+        &form.email,
+        &form.name,
+        &form.password,
+        &form.confirm_password,
+    )
+    .await;
+
+    let email = form.email.trim();
+    let (subject, body) = match result {
+        Ok(Registration::Pending { token, .. }) => {
+            let link = format!("{}/auth/verify/{}", state.public_url, token);
+            (
+                t.messages.register_email_subject().to_string(),
+                format!(
+                    "{}\n\n{}\n\n{}",
+                    t.messages.register_email_body(),
+                    link,
+                    t.messages.register_email_expiry()
+                ),
+            )
+        }
+        Ok(Registration::AlreadyRegistered) => (
+            t.messages.register_email_subject().to_string(),
+            t.messages.register_email_existing().to_string(),
+        ),
+        Err(Ok(error)) => return form_error(error.message()),
+        Err(Err(e)) => {
+            tracing::error!("Failed to register {}: {}", email, e);
+            return form_error(&t.messages.signin_error_general().to_string());
+        }
+    };
+
+    if let Err(e) = state.mailer.send(email, &subject, body).await {
+        tracing::error!("Failed to send registration email to {}: {}", email, e);
+        return form_error(&t.messages.register_error_email().to_string());
+    }
+
+    Html(
+        auth_notice_page(
+            &t,
+            &t.messages.register_check_inbox_title().to_string(),
+            &t.messages.register_check_inbox().to_string(),
+        )
+        .into_string(),
+    )
+    .into_response()
+}
+
+/// GET /auth/verify/{token} - Confirm an email address from the emailed link
+pub async fn verify_get(
+    State(state): State<AppState>,
+    Extension(t): Extension<TranslationContext>,
+    Path(token): Path<String>,
+) -> axum::response::Response {
+    let requires_approval = registration_settings(&state).await.requires_approval;
+
+    let message = match registration::verify_email(&state.db, &token, requires_approval).await {
+        Ok(VerificationOutcome::Activated) => t.messages.register_verified().to_string(),
+        Ok(VerificationOutcome::AwaitingApproval) => {
+            t.messages.register_awaiting_approval().to_string()
+        }
+        Ok(VerificationOutcome::Expired) => t.messages.register_link_expired().to_string(),
+        Ok(VerificationOutcome::Invalid) => t.messages.register_link_invalid().to_string(),
+        Err(e) => {
+            tracing::error!("Failed to verify email: {}", e);
+            t.messages.signin_error_general().to_string()
+        }
+    };
+
+    Html(
+        auth_notice_page(
+            &t,
+            &t.messages.register_verify_title().to_string(),
+            &message,
+        )
+        .into_string(),
+    )
+    .into_response()
+}
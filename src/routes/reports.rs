@@ -0,0 +1,310 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::business;
+use crate::common::csv_export::csv_download;
+use crate::i18n::TranslationContext;
+use crate::service::matches;
+use crate::service::reports::{
+    self, ReportDefinition, SavedReportEntity, UnidentifiedGoalsFilters, MAX_REPORT_ROWS,
+};
+use crate::views::components::error::error_message;
+use crate::views::{
+    layout::admin_layout,
+    pages::reports::{
+        duplicates_page, report_builder_page, report_field_label, stats_reconciliation_page,
+        unidentified_goals_page,
+    },
+};
+
+/// Rows shown on the report builder page; CSV export goes up to `MAX_REPORT_ROWS`
+const PAGE_REPORT_ROWS: i64 = 500;
+
+#[derive(Debug, Deserialize)]
+pub struct ReconciliationQuery {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    show: Option<String>,
+}
+
+/// GET /reports/stats-reconciliation - Manual vs. derived player stats report
+pub async fn stats_reconciliation_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<ReconciliationQuery>,
+) -> impl IntoResponse {
+    let show_all = query.show.as_deref() == Some("all");
+
+    let rows = match reports::get_stats_reconciliation(&state.db, !show_all).await {
+        Ok(rows) => rows,
+        Err(e) => {
+            tracing::error!("Failed to build stats reconciliation report: {}", e);
+            return Html(
+                admin_layout(
+                    "Stats Reconciliation",
+                    &session,
+                    "/management",
+                    &t,
+                    error_message(&t, t.messages.error_failed_to_load_report()),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let content = stats_reconciliation_page(&t, &rows, show_all);
+    Html(admin_layout("Stats Reconciliation", &session, "/management", &t, content).into_string())
+}
+
+/// GET /reports/duplicates - Probable duplicate players, teams and arenas
+pub async fn duplicates_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let content = match reports::get_duplicate_report(&state.db).await {
+        Ok(report) => duplicates_page(&t, &report),
+        Err(e) => {
+            tracing::error!("Failed to build duplicate report: {}", e);
+            error_message(&t, t.messages.error_failed_to_load_report())
+        }
+    };
+    Html(admin_layout("Duplicates", &session, "/management", &t, content).into_string())
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UnidentifiedGoalsQuery {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    season_id: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    team_id: Option<i64>,
+}
+
+/// GET /reports/unidentified-goals - Matches still carrying unidentified goals
+pub async fn unidentified_goals_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<UnidentifiedGoalsQuery>,
+) -> impl IntoResponse {
+    let filters = UnidentifiedGoalsFilters {
+        season_id: query.season_id,
+        team_id: query.team_id,
+    };
+    let seasons = matches::get_seasons(&state.db).await.unwrap_or_default();
+    let teams = matches::get_teams(&state.db).await.unwrap_or_default();
+
+    let content = match reports::get_unidentified_goals_report(&state.db, &filters).await {
+        Ok(report) => unidentified_goals_page(&t, &report, &filters, &seasons, &teams),
+        Err(e) => {
+            tracing::error!("Failed to build unidentified goals report: {}", e);
+            error_message(&t, t.messages.error_failed_to_load_report())
+        }
+    };
+    Html(admin_layout("Unidentified Goals", &session, "/management", &t, content).into_string())
+}
+
+/// Render the report builder, running the report when it has any columns
+async fn render_report_builder(
+    state: &AppState,
+    session: &Session,
+    t: &TranslationContext,
+    definition: &ReportDefinition,
+    saved: Option<&SavedReportEntity>,
+) -> Html<String> {
+    let saved_reports = reports::get_saved_reports(&state.db)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to load saved reports: {}", e);
+            Vec::new()
+        });
+    let seasons = matches::get_seasons(&state.db).await.unwrap_or_default();
+    let teams = matches::get_teams(&state.db).await.unwrap_or_default();
+
+    let mut error = None;
+    let mut result = None;
+    if definition.has_columns() {
+        match business::reports::run_report_validated(&state.db, definition, PAGE_REPORT_ROWS).await
+        {
+            Ok(rows) => result = Some(rows),
+            Err(Ok(validation_error)) => error = Some(validation_error.message().to_string()),
+            Err(Err(e)) => {
+                tracing::error!("Failed to run report: {}", e);
+                error = Some(t.messages.error_failed_to_load_report().to_string());
+            }
+        }
+    }
+
+    let content = report_builder_page(
+        session,
+        t,
+        definition,
+        result.as_ref(),
+        error.as_deref(),
+        &saved_reports,
+        saved,
+        &seasons,
+        &teams,
+    );
+    Html(admin_layout("Report Builder", session, "/management", t, content).into_string())
+}
+
+/// GET /reports/builder - Compose a report from whitelisted fields
+///
+/// The report is described by query fields (`source`, repeated `dimension`
+/// and `measure`, filters and `sort`), so built reports can be bookmarked.
+pub async fn report_builder_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(fields): Query<Vec<(String, String)>>,
+) -> impl IntoResponse {
+    let definition = ReportDefinition::from_form_fields(&fields);
+    render_report_builder(&state, &session, &t, &definition, None).await
+}
+
+/// GET /reports/builder/export - Download a report as CSV
+pub async fn report_builder_export(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(fields): Query<Vec<(String, String)>>,
+) -> impl IntoResponse {
+    let definition = ReportDefinition::from_form_fields(&fields);
+
+    match business::reports::run_report_validated(&state.db, &definition, MAX_REPORT_ROWS).await {
+        Ok(result) => {
+            let headers: Vec<String> = result
+                .columns
+                .iter()
+                .map(|key| report_field_label(&t, key))
+                .collect();
+            let filename = format!("report-{}.csv", definition.source.as_str());
+            csv_download(&filename, move |mut sink| async move {
+                result.write_csv(&headers, &mut sink).await;
+                Ok(sink)
+            })
+        }
+        Err(Ok(validation_error)) => (
+            axum::http::StatusCode::BAD_REQUEST,
+            validation_error.message(),
+        )
+            .into_response(),
+        Err(Err(e)) => {
+            tracing::error!("Failed to export report: {}", e);
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                t.messages.error_failed_to_load_report().to_string(),
+            )
+                .into_response()
+        }
+    }
+}
+
+/// POST /reports/saved - Save the report built on the page under a name
+///
+/// The form repeats the builder's fields next to `name`; errors are shown
+/// next to the form.
+pub async fn saved_report_create(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> impl IntoResponse {
+    let definition = ReportDefinition::from_form_fields(&fields);
+    let name = fields
+        .iter()
+        .find(|(name, _)| name == "name")
+        .map(|(_, value)| value.as_str())
+        .unwrap_or_default();
+
+    match business::reports::save_report_validated(&state.db, name, &definition).await {
+        Ok(id) => {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-redirect"),
+                format!("/reports/saved/{}", id)
+                    .parse()
+                    .expect("Valid redirect URL should parse"),
+            );
+            (headers, Html("".to_string())).into_response()
+        }
+        Err(Ok(validation_error)) => {
+            Html(error_message(&t, validation_error.message()).into_string()).into_response()
+        }
+        Err(Err(e)) => {
+            tracing::error!("Failed to save report: {}", e);
+            Html(error_message(&t, t.messages.error_failed_to_save_report()).into_string())
+                .into_response()
+        }
+    }
+}
+
+/// GET /reports/saved/{id} - Run a saved report
+pub async fn saved_report_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    match reports::get_saved_report(&state.db, id).await {
+        Ok(Some(saved)) => {
+            render_report_builder(&state, &session, &t, &saved.definition, Some(&saved)).await
+        }
+        Ok(None) => Html(
+            admin_layout(
+                "Report Not Found",
+                &session,
+                "/management",
+                &t,
+                error_message(&t, t.messages.error_report_not_found()),
+            )
+            .into_string(),
+        ),
+        Err(e) => {
+            tracing::error!("Failed to fetch saved report: {}", e);
+            Html(
+                admin_layout(
+                    "Error",
+                    &session,
+                    "/management",
+                    &t,
+                    error_message(&t, t.messages.error_failed_to_load_report()),
+                )
+                .into_string(),
+            )
+        }
+    }
+}
+
+/// POST /reports/saved/{id}/delete - Delete a saved report
+pub async fn saved_report_delete(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    match reports::delete_saved_report(&state.db, id).await {
+        Ok(true) => {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-redirect"),
+                "/reports/builder"
+                    .parse()
+                    .expect("Valid redirect URL should parse"),
+            );
+            (headers, Html("".to_string())).into_response()
+        }
+        Ok(false) => Html(error_message(&t, t.messages.error_report_not_found()).into_string())
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to delete saved report: {}", e);
+            Html(error_message(&t, t.messages.error_failed_to_delete_report()).into_string())
+                .into_response()
+        }
+    }
+}
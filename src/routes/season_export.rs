@@ -0,0 +1,217 @@
+use axum::{
+    extract::{Path, State},
+    response::{Html, IntoResponse},
+    Extension,
+};
+
+use crate::app_state::AppState;
+use crate::common::xlsx::{xlsx_download, XlsxCell, XlsxSheet};
+use crate::i18n::TranslationContext;
+use crate::service::leaders::{self, LeaderEntity, SeasonLeadersEntity};
+use crate::service::matches::GameType;
+use crate::service::season_export::{self, ScheduleMatchEntity};
+use crate::service::season_groups::{self, SeasonGroupEntity};
+use crate::service::seasons;
+use crate::service::standings::{self, TeamStandingEntity};
+use crate::views::components::error::error_message;
+use crate::views::pages::matches::{game_type_label, status_filter_label};
+
+/// GET /seasons/:id/export/xlsx - Standings, scorers and schedule as an Excel workbook
+pub async fn season_export_xlsx(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let failed = || Html(error_message(&t, t.messages.error_failed_to_load_season()).into_string());
+
+    match seasons::get_season_by_id(&state.db, id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => {
+            return Html(error_message(&t, t.messages.error_season_not_found()).into_string())
+                .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to load season {} for export: {}", id, e);
+            return failed().into_response();
+        }
+    }
+
+    let data = async {
+        let standings = standings::get_season_standings(&state.db, id).await?;
+        let groups = season_groups::get_season_groups(&state.db, id).await?;
+        let players = leaders::get_season_player_points(&state.db, id).await?;
+        let schedule = season_export::get_season_schedule(&state.db, id).await?;
+        Ok::<_, sqlx::Error>((standings, groups, players, schedule))
+    };
+    let (standings, groups, players, schedule) = match data.await {
+        Ok(data) => data,
+        Err(e) => {
+            tracing::error!("Failed to load season {} for export: {}", id, e);
+            return failed().into_response();
+        }
+    };
+
+    // Every player with a point, best first
+    let scorers = SeasonLeadersEntity::from_players(&players, players.len()).points;
+
+    let sheets = [
+        standings_sheet(&t, &standings, &groups),
+        scorers_sheet(&t, &scorers),
+        schedule_sheet(&t, &schedule),
+    ];
+    xlsx_download(&format!("season-{}.xlsx", id), &sheets).into_response()
+}
+
+/// Standings table, group by group when the season has groups
+fn standings_sheet(
+    t: &TranslationContext,
+    standings: &[TeamStandingEntity],
+    groups: &[SeasonGroupEntity],
+) -> XlsxSheet {
+    let mut headers = vec![t.messages.season_export_rank().to_string()];
+    if !groups.is_empty() {
+        headers.push(t.messages.season_export_group().to_string());
+    }
+    headers.extend(
+        [
+            t.messages.standings_team(),
+            t.messages.standings_games(),
+            t.messages.standings_wins(),
+            t.messages.standings_overtime_wins(),
+            t.messages.standings_ties(),
+            t.messages.standings_overtime_losses(),
+            t.messages.standings_losses(),
+            t.messages.team_stats_goals_for(),
+            t.messages.team_stats_goals_against(),
+            t.messages.standings_goal_difference(),
+            t.messages.standings_points(),
+        ]
+        .iter()
+        .map(ToString::to_string),
+    );
+
+    // Groups in their listed order, teams without a group last
+    let mut sections: Vec<(Option<&str>, Vec<&TeamStandingEntity>)> = groups
+        .iter()
+        .map(|group| {
+            let teams = standings
+                .iter()
+                .filter(|team| team.group_id == Some(group.id))
+                .collect();
+            (Some(group.name.as_str()), teams)
+        })
+        .collect();
+    sections.push((
+        None,
+        standings
+            .iter()
+            .filter(|team| !groups.iter().any(|g| team.group_id == Some(g.id)))
+            .collect(),
+    ));
+
+    let mut rows = Vec::new();
+    for (group_name, teams) in sections {
+        for (rank, team) in teams.into_iter().enumerate() {
+            let mut row = vec![XlsxCell::Number(rank as i64 + 1)];
+            if !groups.is_empty() {
+                row.push(group_name.into());
+            }
+            row.extend([
+                team.team_name.as_str().into(),
+                team.games.into(),
+                team.wins.into(),
+                team.overtime_wins.into(),
+                team.ties.into(),
+                team.overtime_losses.into(),
+                team.losses.into(),
+                team.goals_for.into(),
+                team.goals_against.into(),
+                team.goal_difference().into(),
+                team.points().into(),
+            ]);
+            rows.push(row);
+        }
+    }
+
+    XlsxSheet {
+        name: t.messages.standings_title().to_string(),
+        headers,
+        rows,
+    }
+}
+
+/// Scoring leaderboard by points
+fn scorers_sheet(t: &TranslationContext, scorers: &[LeaderEntity]) -> XlsxSheet {
+    XlsxSheet {
+        name: t.messages.season_export_scorers().to_string(),
+        headers: [
+            t.messages.season_export_rank(),
+            t.messages.season_export_player(),
+            t.messages.season_export_teams(),
+            t.messages.leaders_goals_short(),
+            t.messages.leaders_assists_short(),
+            t.messages.leaders_points_short(),
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect(),
+        rows: scorers
+            .iter()
+            .enumerate()
+            .map(|(rank, player)| {
+                vec![
+                    XlsxCell::Number(rank as i64 + 1),
+                    player.player_name.as_str().into(),
+                    player.team_names.as_str().into(),
+                    player.goals.into(),
+                    player.assists.into(),
+                    player.points.into(),
+                ]
+            })
+            .collect(),
+    }
+}
+
+/// Every match of the season with its score once it has started
+fn schedule_sheet(t: &TranslationContext, schedule: &[ScheduleMatchEntity]) -> XlsxSheet {
+    XlsxSheet {
+        name: t.messages.season_export_schedule().to_string(),
+        headers: [
+            t.messages.matches_date(),
+            t.messages.matches_home_team(),
+            t.messages.matches_away_team(),
+            t.messages.season_export_home_score(),
+            t.messages.season_export_away_score(),
+            t.messages.matches_status(),
+            t.messages.matches_game_type(),
+            t.messages.season_export_arena(),
+        ]
+        .iter()
+        .map(ToString::to_string)
+        .collect(),
+        rows: schedule
+            .iter()
+            .map(|m| {
+                let score = |goals: i64| match m.status.as_str() {
+                    "scheduled" | "cancelled" => XlsxCell::Empty,
+                    _ => XlsxCell::Number(goals),
+                };
+                vec![
+                    m.match_date
+                        .as_deref()
+                        .map(|date| date.replace('T', " "))
+                        .into(),
+                    m.home_team_name.as_str().into(),
+                    m.away_team_name.as_str().into(),
+                    score(m.home_score),
+                    score(m.away_score),
+                    status_filter_label(t, &m.status).into(),
+                    GameType::from_str(&m.game_type)
+                        .map(|game_type| game_type_label(t, game_type))
+                        .into(),
+                    m.arena_name.as_deref().into(),
+                ]
+            })
+            .collect(),
+    }
+}
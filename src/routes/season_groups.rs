@@ -0,0 +1,174 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::business;
+use crate::i18n::TranslationContext;
+use crate::service::{season_groups, team_participations};
+use crate::views::components::error::error_message;
+use crate::views::pages::season_groups::group_create_modal;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateGroupForm {
+    name: String,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteGroupForm {
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AssignGroupForm {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    group_id: Option<i64>,
+    csrf_token: String,
+}
+
+/// HTMX redirect back to the season detail page (groups are managed there)
+fn redirect_to_season(season_id: i64) -> axum::response::Response {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("hx-redirect"),
+        format!("/seasons/{}", season_id)
+            .parse()
+            .expect("Valid redirect URL should parse"),
+    );
+    (headers, Html("".to_string())).into_response()
+}
+
+/// GET /seasons/{season_id}/groups/new - Show create group modal
+pub async fn group_create_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    Path(season_id): Path<i64>,
+) -> impl IntoResponse {
+    Html(group_create_modal(&session, &t, season_id, None).into_string())
+}
+
+/// POST /seasons/{season_id}/groups - Create a group
+pub async fn group_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(season_id): Path<i64>,
+    Form(form): Form<CreateGroupForm>,
+) -> axum::response::Response {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match business::season_groups::create_group_validated(&state.db, season_id, &form.name).await {
+        Ok(_) => redirect_to_season(season_id),
+        Err(Ok(validation_error)) => Html(
+            group_create_modal(&session, &t, season_id, Some(validation_error.message()))
+                .into_string(),
+        )
+        .into_response(),
+        Err(Err(e)) => {
+            tracing::error!("Failed to create season group: {}", e);
+            Html(
+                group_create_modal(&session, &t, season_id, Some("Failed to create group"))
+                    .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
+
+/// POST /season-groups/{id}/delete - Delete a group (its teams stay in the season)
+pub async fn group_delete(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<DeleteGroupForm>,
+) -> axum::response::Response {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let season_id = match season_groups::get_group_by_id(&state.db, id).await {
+        Ok(Some(group)) => group.season_id,
+        Ok(None) => {
+            return Html(
+                error_message(&t, t.messages.error_season_group_not_found()).into_string(),
+            )
+            .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch season group: {}", e);
+            return Html(
+                error_message(&t, t.messages.error_failed_to_delete_season_group()).into_string(),
+            )
+            .into_response();
+        }
+    };
+
+    match season_groups::delete_group(&state.db, id).await {
+        Ok(true) => redirect_to_season(season_id),
+        Ok(false) => {
+            Html(error_message(&t, t.messages.error_season_group_not_found()).into_string())
+                .into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to delete season group: {}", e);
+            Html(error_message(&t, t.messages.error_failed_to_delete_season_group()).into_string())
+                .into_response()
+        }
+    }
+}
+
+/// POST /team-participations/{id}/group - Move a team into a group of its season
+pub async fn team_group_assign(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<AssignGroupForm>,
+) -> axum::response::Response {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let season_id = match team_participations::get_season_id_for_participation(&state.db, id).await
+    {
+        Ok(Some(season_id)) => season_id,
+        Ok(None) => {
+            return Html(
+                error_message(&t, t.messages.error_team_participation_not_found()).into_string(),
+            )
+            .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch team participation: {}", e);
+            return Html(
+                error_message(&t, t.messages.error_failed_to_assign_season_group()).into_string(),
+            )
+            .into_response();
+        }
+    };
+
+    match season_groups::assign_team_to_group(&state.db, id, form.group_id).await {
+        Ok(true) => redirect_to_season(season_id),
+        Ok(false) => {
+            Html(error_message(&t, t.messages.error_season_group_not_found()).into_string())
+                .into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to assign team to season group: {}", e);
+            Html(error_message(&t, t.messages.error_failed_to_assign_season_group()).into_string())
+                .into_response()
+        }
+    }
+}
@@ -2,13 +2,16 @@ use axum::{
     extract::{Path, Query, State},
     http::{HeaderMap, HeaderName},
     response::{Html, IntoResponse},
-    Extension, Form,
+    Extension, Form, Json,
 };
 use serde::Deserialize;
 
 use crate::app_state::AppState;
 use crate::auth::Session;
+use crate::business::season_clone::{clone_season_validated, next_display_name};
 use crate::i18n::TranslationContext;
+use crate::service::period_distribution::{self, PeriodDistributionEntity};
+use crate::service::season_clone;
 use crate::service::team_participations::{self, CreateTeamParticipationEntity};
 use crate::service::{
     countries,
@@ -18,7 +21,10 @@ use crate::views::{
     components::{error::error_message, htmx::htmx_reload_table},
     layout::admin_layout,
     pages::season_detail::{add_team_modal, season_detail_page},
-    pages::seasons::{season_create_modal, season_edit_modal, season_list_content, seasons_page},
+    pages::seasons::{
+        season_clone_modal, season_create_modal, season_edit_modal, season_list_content,
+        seasons_page,
+    },
 };
 
 #[derive(Debug, Deserialize)]
@@ -91,6 +97,12 @@ pub struct DeleteTeamParticipationForm {
     csrf_token: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SeasonDetailQuery {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    group_id: Option<i64>,
+}
+
 /// GET /seasons - Seasons list page
 pub async fn seasons_get(
     Extension(session): Extension<Session>,
@@ -550,6 +562,126 @@ pub async fn season_update(
     }
 }
 
+#[derive(Debug, Deserialize)]
+pub struct CloneSeasonForm {
+    year: i64,
+    display_name: Option<String>,
+    #[serde(default)]
+    copy_rosters: Option<String>,
+    csrf_token: String,
+}
+
+/// GET /seasons/{id}/clone - Show clone season modal, proposing the next year
+pub async fn season_clone_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let season = match seasons::get_season_by_id(&state.db, id).await {
+        Ok(Some(season)) => season,
+        Ok(None) => {
+            return Html(error_message(&t, t.messages.error_season_not_found()).into_string());
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch season: {}", e);
+            return Html(error_message(&t, t.messages.error_failed_to_load_season()).into_string());
+        }
+    };
+
+    let summary = season_clone::get_clone_summary(&state.db, id)
+        .await
+        .unwrap_or_default();
+    let year = season.year + 1;
+    let display_name = next_display_name(season.display_name.as_deref(), season.year, year);
+
+    Html(
+        season_clone_modal(
+            &session,
+            &t,
+            &season,
+            &summary,
+            year,
+            display_name.as_deref(),
+            true,
+            None,
+        )
+        .into_string(),
+    )
+}
+
+/// POST /seasons/{id}/clone - Create the next season from this one and open it
+pub async fn season_clone(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<CloneSeasonForm>,
+) -> impl IntoResponse {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let copy_rosters = form.copy_rosters.is_some();
+    let result = clone_season_validated(
+        &state.db,
+        id,
+        form.year,
+        form.display_name.as_deref(),
+        copy_rosters,
+    )
+    .await;
+
+    let error = match result {
+        Ok(new_id) => {
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-redirect"),
+                format!("/seasons/{}", new_id)
+                    .parse()
+                    .expect("Valid redirect URL should parse"),
+            );
+            return (headers, Html("".to_string())).into_response();
+        }
+        Err(Ok(validation_error)) => validation_error.message().to_string(),
+        Err(Err(e)) => {
+            tracing::error!("Failed to clone season {}: {}", id, e);
+            t.messages.seasons_clone_failed().to_string()
+        }
+    };
+
+    let season = match seasons::get_season_by_id(&state.db, id).await {
+        Ok(Some(season)) => season,
+        Ok(None) => {
+            return Html(error_message(&t, t.messages.error_season_not_found()).into_string())
+                .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch season: {}", e);
+            return Html(error_message(&t, t.messages.error_failed_to_load_season()).into_string())
+                .into_response();
+        }
+    };
+    let summary = season_clone::get_clone_summary(&state.db, id)
+        .await
+        .unwrap_or_default();
+
+    Html(
+        season_clone_modal(
+            &session,
+            &t,
+            &season,
+            &summary,
+            form.year,
+            form.display_name.as_deref(),
+            copy_rosters,
+            Some(&error),
+        )
+        .into_string(),
+    )
+    .into_response()
+}
+
 /// POST /seasons/{id}/delete - Delete season
 pub async fn season_delete(
     Extension(session): Extension<Session>,
@@ -638,6 +770,7 @@ pub async fn season_detail(
     Extension(t): Extension<TranslationContext>,
     State(state): State<AppState>,
     Path(id): Path<i64>,
+    Query(query): Query<SeasonDetailQuery>,
 ) -> impl IntoResponse {
     let detail = match seasons::get_season_detail(&state.db, id).await {
         Ok(Some(detail)) => detail,
@@ -674,10 +807,156 @@ pub async fn season_detail(
         }
     };
 
-    let content = season_detail_page(&session, &t, &detail);
+    let groups = match crate::service::season_groups::get_season_groups(&state.db, id).await {
+        Ok(groups) => groups,
+        Err(e) => {
+            tracing::warn!("Failed to load groups for season {}: {}", id, e);
+            Vec::new()
+        }
+    };
+    // Ignore a stale group filter (e.g. the group was deleted)
+    let selected_group = query
+        .group_id
+        .filter(|group_id| groups.iter().any(|g| g.id == *group_id));
+
+    let standings = match crate::service::standings::get_season_standings(&state.db, id).await {
+        Ok(standings) => standings,
+        Err(e) => {
+            tracing::warn!("Failed to load standings for season {}: {}", id, e);
+            Vec::new()
+        }
+    };
+
+    let form = match crate::service::team_form::get_season_form(&state.db, id).await {
+        Ok(form) => form,
+        Err(e) => {
+            tracing::warn!("Failed to load team form for season {}: {}", id, e);
+            Default::default()
+        }
+    };
+
+    let series = match crate::service::playoffs::get_series_for_season(&state.db, id).await {
+        Ok(series) => series,
+        Err(e) => {
+            tracing::warn!("Failed to load playoff series for season {}: {}", id, e);
+            Vec::new()
+        }
+    };
+
+    let shooting = match crate::service::matches::get_season_team_shooting(&state.db, id).await {
+        Ok(shooting) => shooting,
+        Err(e) => {
+            tracing::warn!("Failed to load team shooting for season {}: {}", id, e);
+            Vec::new()
+        }
+    };
+
+    let attendance = match crate::service::matches::get_season_attendance(&state.db, id).await {
+        Ok(attendance) => attendance,
+        Err(e) => {
+            tracing::warn!("Failed to load attendance for season {}: {}", id, e);
+            Vec::new()
+        }
+    };
+
+    let goal_types = match crate::service::goal_types::get_season_goal_types(&state.db, id).await {
+        Ok(goal_types) => goal_types,
+        Err(e) => {
+            tracing::warn!("Failed to load goal types for season {}: {}", id, e);
+            Vec::new()
+        }
+    };
+
+    let period_distribution =
+        match period_distribution::get_season_period_distribution(&state.db, id).await {
+            Ok(distribution) => distribution,
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to load period distribution for season {}: {}",
+                    id,
+                    e
+                );
+                PeriodDistributionEntity {
+                    season_id: id,
+                    matches: 0,
+                    total_goals: 0,
+                    periods: Vec::new(),
+                }
+            }
+        };
+
+    let awards = match crate::service::awards::get_season_awards(&state.db, id).await {
+        Ok(awards) => awards,
+        Err(e) => {
+            tracing::warn!("Failed to load awards for season {}: {}", id, e);
+            Vec::new()
+        }
+    };
+
+    let leaders = match crate::service::leaders::get_season_leaders(
+        &state.db,
+        id,
+        crate::views::pages::leaders::PANEL_LEADERS_LIMIT,
+    )
+    .await
+    {
+        Ok(leaders) => leaders,
+        Err(e) => {
+            tracing::warn!("Failed to load leaders for season {}: {}", id, e);
+            Default::default()
+        }
+    };
+
+    let content = season_detail_page(
+        &session,
+        &t,
+        &detail,
+        &groups,
+        selected_group,
+        &standings,
+        &form,
+        &series,
+        &shooting,
+        &attendance,
+        &goal_types,
+        &period_distribution,
+        &awards,
+        &leaders,
+    );
     Html(admin_layout("Season Detail", &session, "/seasons", &t, content).into_string())
 }
 
+/// GET /api/seasons/:id/period-distribution - JSON goals per period of a season
+pub async fn season_period_distribution_api(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    match seasons::get_season_by_id(&state.db, id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return (axum::http::StatusCode::NOT_FOUND, "Season not found").into_response(),
+        Err(e) => {
+            tracing::error!("Failed to fetch season: {}", e);
+            return (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to fetch season",
+            )
+                .into_response();
+        }
+    }
+
+    match period_distribution::get_season_period_distribution(&state.db, id).await {
+        Ok(distribution) => Json(distribution).into_response(),
+        Err(e) => {
+            tracing::error!("Failed to fetch period distribution: {}", e);
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to fetch period distribution",
+            )
+                .into_response()
+        }
+    }
+}
+
 /// GET /seasons/{season_id}/teams/add - Show add team modal
 pub async fn season_add_team_form(
     Extension(session): Extension<Session>,
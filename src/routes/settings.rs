@@ -0,0 +1,314 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::business::chat_webhooks::create_chat_webhook_validated;
+use crate::i18n::TranslationContext;
+use crate::service::chat_webhooks;
+use crate::service::matches::{self, STATUS_FILTERS};
+use crate::service::settings::{self, DefaultSeason, MatchListDefaults};
+use crate::views::{
+    layout::admin_layout,
+    pages::settings::{chat_webhooks_section, settings_page},
+};
+
+#[derive(Debug, Deserialize)]
+pub struct SettingsForm {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    matches_default_season: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    matches_default_status: Option<String>,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChatWebhookForm {
+    name: String,
+    kind: String,
+    url: String,
+    #[serde(default)]
+    notify_results: bool,
+    #[serde(default)]
+    notify_milestones: bool,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChatWebhookNotificationsForm {
+    #[serde(default)]
+    notify_results: bool,
+    #[serde(default)]
+    notify_milestones: bool,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CsrfForm {
+    csrf_token: String,
+}
+
+/// Toast response without swapping the form
+fn toast(kind: &'static str, message: String) -> axum::response::Response {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static(kind),
+        message
+            .parse()
+            .expect("Toast message should be a valid header value"),
+    );
+    headers.insert(
+        HeaderName::from_static("hx-reswap"),
+        "none".parse().expect("Valid swap value should parse"),
+    );
+    (headers, Html("".to_string())).into_response()
+}
+
+/// GET /management/settings - Instance-wide settings
+pub async fn settings_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let match_defaults = match settings::get_match_list_defaults(&state.db).await {
+        Ok(defaults) => defaults,
+        Err(e) => {
+            tracing::warn!("Failed to load match list defaults: {}", e);
+            MatchListDefaults::default()
+        }
+    };
+    let seasons = matches::get_seasons(&state.db).await.unwrap_or_default();
+    let webhooks = chat_webhooks::get_chat_webhooks(&state.db)
+        .await
+        .unwrap_or_default();
+
+    let content = settings_page(&session, &t, &match_defaults, &seasons, &webhooks);
+    Html(admin_layout("Settings", &session, "/management", &t, content).into_string())
+}
+
+/// POST /management/settings - Save the settings
+pub async fn settings_update(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<SettingsForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let season = match form.matches_default_season.as_deref() {
+        Some(value) => match DefaultSeason::from_str(value) {
+            Some(season) => Some(season),
+            None => {
+                return toast(
+                    "hx-toast-error",
+                    t.messages.error_invalid_settings().to_string(),
+                )
+            }
+        },
+        None => None,
+    };
+    if form
+        .matches_default_status
+        .as_deref()
+        .is_some_and(|status| !STATUS_FILTERS.contains(&status))
+    {
+        return toast(
+            "hx-toast-error",
+            t.messages.error_invalid_settings().to_string(),
+        );
+    }
+
+    let defaults = MatchListDefaults {
+        season,
+        status: form.matches_default_status,
+    };
+    match settings::update_match_list_defaults(&state.db, &defaults).await {
+        Ok(()) => toast("hx-toast-success", t.messages.settings_saved().to_string()),
+        Err(e) => {
+            tracing::error!("Failed to save settings: {}", e);
+            toast(
+                "hx-toast-error",
+                t.messages.error_failed_to_save_settings().to_string(),
+            )
+        }
+    }
+}
+
+/// Chat webhooks section after a change, with an optional form error
+async fn render_chat_webhooks(
+    state: &AppState,
+    session: &Session,
+    t: &TranslationContext,
+    error: Option<&str>,
+) -> axum::response::Response {
+    match chat_webhooks::get_chat_webhooks(&state.db).await {
+        Ok(webhooks) => {
+            Html(chat_webhooks_section(session, t, &webhooks, error).into_string()).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to load chat webhooks: {}", e);
+            toast(
+                "hx-toast-error",
+                t.messages.error_failed_to_save_settings().to_string(),
+            )
+        }
+    }
+}
+
+/// POST /management/settings/chat-webhooks - Add a chat webhook
+pub async fn chat_webhook_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<ChatWebhookForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match create_chat_webhook_validated(
+        &state.db,
+        &form.name,
+        &form.kind,
+        &form.url,
+        form.notify_results,
+        form.notify_milestones,
+    )
+    .await
+    {
+        Ok(_) => render_chat_webhooks(&state, &session, &t, None).await,
+        Err(Ok(validation_error)) => {
+            render_chat_webhooks(&state, &session, &t, Some(validation_error.message())).await
+        }
+        Err(Err(e)) => {
+            tracing::error!("Failed to create chat webhook: {}", e);
+            toast(
+                "hx-toast-error",
+                t.messages.error_failed_to_save_settings().to_string(),
+            )
+        }
+    }
+}
+
+/// POST /management/settings/chat-webhooks/:id - Choose the notifications of a webhook
+pub async fn chat_webhook_update(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<ChatWebhookNotificationsForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match chat_webhooks::update_chat_webhook_notifications(
+        &state.db,
+        id,
+        form.notify_results,
+        form.notify_milestones,
+    )
+    .await
+    {
+        Ok(_) => {
+            let mut response = render_chat_webhooks(&state, &session, &t, None).await;
+            if let Ok(message) = t.messages.settings_saved().to_string().parse() {
+                response.headers_mut().insert("hx-toast-success", message);
+            }
+            response
+        }
+        Err(e) => {
+            tracing::error!("Failed to update chat webhook {}: {}", id, e);
+            toast(
+                "hx-toast-error",
+                t.messages.error_failed_to_save_settings().to_string(),
+            )
+        }
+    }
+}
+
+/// POST /management/settings/chat-webhooks/:id/delete - Remove a chat webhook
+pub async fn chat_webhook_delete(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<CsrfForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match chat_webhooks::delete_chat_webhook(&state.db, id).await {
+        Ok(_) => {
+            let mut response = render_chat_webhooks(&state, &session, &t, None).await;
+            if let Ok(message) = t.messages.chat_webhooks_deleted().to_string().parse() {
+                response.headers_mut().insert("hx-toast-success", message);
+            }
+            response
+        }
+        Err(e) => {
+            tracing::error!("Failed to delete chat webhook {}: {}", id, e);
+            toast(
+                "hx-toast-error",
+                t.messages.error_failed_to_save_settings().to_string(),
+            )
+        }
+    }
+}
+
+/// POST /management/settings/chat-webhooks/:id/test - Post a test message
+pub async fn chat_webhook_test(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<CsrfForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let webhook = match chat_webhooks::get_chat_webhook_by_id(&state.db, id).await {
+        Ok(Some(webhook)) => webhook,
+        Ok(None) => {
+            return toast(
+                "hx-toast-error",
+                t.messages.chat_webhooks_test_failed().to_string(),
+            )
+        }
+        Err(e) => {
+            tracing::error!("Failed to load chat webhook {}: {}", id, e);
+            return toast(
+                "hx-toast-error",
+                t.messages.chat_webhooks_test_failed().to_string(),
+            );
+        }
+    };
+
+    let text = t.messages.chat_webhooks_test_message().to_string();
+    match state.chat.send(&webhook, &text).await {
+        Ok(()) => toast(
+            "hx-toast-success",
+            t.messages.chat_webhooks_test_sent().to_string(),
+        ),
+        Err(e) => {
+            tracing::warn!(
+                "Test message to chat webhook '{}' failed: {}",
+                webhook.name,
+                e
+            );
+            toast(
+                "hx-toast-error",
+                t.messages.chat_webhooks_test_failed().to_string(),
+            )
+        }
+    }
+}
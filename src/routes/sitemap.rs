@@ -0,0 +1,36 @@
+use axum::{
+    extract::State,
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+};
+
+use crate::app_state::AppState;
+use crate::service::sitemap;
+use crate::views::pages::sitemap::sitemap_xml;
+
+/// Cache lifetime of the sitemap, in seconds
+const SITEMAP_MAX_AGE: u32 = 3600;
+
+/// GET /sitemap.xml - Pages of the public site for search engines
+pub async fn sitemap_get(State(state): State<AppState>) -> Response {
+    match sitemap::get_sitemap_entries(&state.db).await {
+        Ok(entries) => (
+            [
+                (
+                    header::CONTENT_TYPE,
+                    "application/xml; charset=utf-8".to_string(),
+                ),
+                (
+                    header::CACHE_CONTROL,
+                    format!("public, max-age={}", SITEMAP_MAX_AGE),
+                ),
+            ],
+            sitemap_xml(&state.public_url, &entries).into_string(),
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to build sitemap: {}", e);
+            (StatusCode::INTERNAL_SERVER_ERROR, "Failed to build sitemap").into_response()
+        }
+    }
+}
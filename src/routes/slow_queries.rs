@@ -0,0 +1,69 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::views::{
+    layout::admin_layout,
+    pages::slow_queries::{slow_queries_content, slow_queries_page},
+};
+
+/// Individual slow queries listed below the summary
+const RECENT_LIMIT: usize = 50;
+
+#[derive(Debug, Deserialize)]
+pub struct ClearSlowQueriesForm {
+    csrf_token: String,
+}
+
+/// GET /management/slow-queries - Statements over the slow query threshold
+pub async fn slow_queries_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let content = slow_queries_page(
+        &session,
+        &t,
+        state.slow_query_ms,
+        &state.slow_queries.summary(),
+        &state.slow_queries.recent(RECENT_LIMIT),
+    );
+    Html(admin_layout("Slow Queries", &session, "/management", &t, content).into_string())
+}
+
+/// POST /management/slow-queries/clear - Forget the recorded slow queries
+pub async fn slow_queries_clear(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<ClearSlowQueriesForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    state.slow_queries.clear();
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("hx-toast-success"),
+        t.messages
+            .slow_queries_cleared()
+            .to_string()
+            .parse()
+            .expect("Toast message should be a valid header value"),
+    );
+
+    (
+        headers,
+        Html(slow_queries_content(&t, &[], &[]).into_string()),
+    )
+        .into_response()
+}
@@ -0,0 +1,38 @@
+//! Detail pages addressed by numeric ID or by slug
+
+use axum::response::{IntoResponse, Redirect, Response};
+use sqlx::SqlitePool;
+
+use crate::service::slugs::{self, SlugEntity, SlugTarget};
+
+/// Record ID behind a `/teams/{key}` style path segment
+///
+/// The key is either a numeric ID or a slug. A slug given up after a rename
+/// answers with a permanent redirect to `{base}/{current slug}`.
+///
+/// # Returns
+/// * `Ok(Some(id))` - The record to show
+/// * `Ok(None)` - No record uses the slug
+/// * `Err(Ok(Response))` - Redirect to the current slug
+/// * `Err(Err(sqlx::Error))` - If database operation fails
+pub async fn resolve_entity_path(
+    db: &SqlitePool,
+    entity: SlugEntity,
+    base: &str,
+    key: &str,
+) -> Result<Option<i64>, Result<Response, sqlx::Error>> {
+    if let Ok(id) = key.parse::<i64>() {
+        return Ok(Some(id));
+    }
+
+    match slugs::resolve_slug(db, entity, key).await.map_err(Err)? {
+        Some(SlugTarget::Current(id)) => Ok(Some(id)),
+        Some(SlugTarget::Moved(slug)) => Err(Ok(Redirect::permanent(&format!(
+            "{}/{}",
+            base,
+            urlencoding::encode(&slug)
+        ))
+        .into_response())),
+        None => Ok(None),
+    }
+}
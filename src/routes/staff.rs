@@ -0,0 +1,582 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::routes::player_contracts::redirect_to_roster;
+use crate::service::{
+    countries,
+    staff::{
+        self, CreateStaffMemberEntity, SortField, SortOrder, StaffFilters, StaffRole,
+        UpdateStaffMemberEntity,
+    },
+};
+use crate::validation::validate_name;
+use crate::views::{
+    components::{error::error_message, htmx::htmx_reload_table},
+    layout::admin_layout,
+    pages::{
+        roster::add_staff_modal,
+        staff::{
+            staff_list_content, staff_member_create_modal, staff_member_edit_modal, staff_page,
+        },
+    },
+};
+
+#[derive(Debug, Deserialize)]
+pub struct StaffQuery {
+    #[serde(default = "default_page")]
+    page: usize,
+    #[serde(default = "default_page_size")]
+    page_size: usize,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none")]
+    name: Option<String>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    country_id: Option<i64>,
+    #[serde(default = "default_sort")]
+    sort: String,
+    #[serde(default = "default_order")]
+    order: String,
+}
+
+fn default_page() -> usize {
+    1
+}
+
+fn default_page_size() -> usize {
+    20
+}
+
+fn default_sort() -> String {
+    "name".to_string()
+}
+
+fn default_order() -> String {
+    "asc".to_string()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateStaffMemberForm {
+    name: String,
+    country_id: Option<i64>,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateStaffMemberForm {
+    name: String,
+    country_id: Option<i64>,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteStaffMemberForm {
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AddStaffForm {
+    staff_member_id: i64,
+    role: String,
+}
+
+/// GET /staff - Staff list page
+pub async fn staff_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<StaffQuery>,
+) -> impl IntoResponse {
+    // Build filters
+    let filters = StaffFilters {
+        name: query.name.clone(),
+        country_id: query.country_id,
+    };
+
+    // Parse sort parameters
+    let sort_field = SortField::from_str(&query.sort);
+    let sort_order = SortOrder::from_str(&query.order);
+
+    // Get staff
+    let result = match staff::get_staff_members(
+        &state.db,
+        &filters,
+        &sort_field,
+        &sort_order,
+        query.page,
+        query.page_size,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            tracing::error!("Failed to fetch staff: {}", e);
+            return Html(
+                admin_layout(
+                    "Staff",
+                    &session,
+                    "/staff",
+                    &t,
+                    crate::views::components::error::error_message(
+                        &t,
+                        t.messages.error_failed_to_load_staff(),
+                    ),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    // Get countries for filter
+    let countries = match countries::get_countries_simple(&state.db).await {
+        Ok(countries) => countries,
+        Err(e) => {
+            tracing::warn!("Failed to load countries for filter dropdown: {}", e);
+            Vec::new()
+        }
+    };
+
+    let content = staff_page(
+        &session,
+        &t,
+        &result,
+        &filters,
+        &sort_field,
+        &sort_order,
+        &countries,
+    );
+    Html(admin_layout("Staff", &session, "/staff", &t, content).into_string())
+}
+
+/// GET /staff/list - HTMX endpoint for table updates
+pub async fn staff_list_partial(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<StaffQuery>,
+) -> impl IntoResponse {
+    let filters = StaffFilters {
+        name: query.name.clone(),
+        country_id: query.country_id,
+    };
+
+    // Parse sort parameters
+    let sort_field = SortField::from_str(&query.sort);
+    let sort_order = SortOrder::from_str(&query.order);
+
+    let result = match staff::get_staff_members(
+        &state.db,
+        &filters,
+        &sort_field,
+        &sort_order,
+        query.page,
+        query.page_size,
+    )
+    .await
+    {
+        Ok(result) => result,
+        Err(e) => {
+            tracing::error!("Failed to fetch staff: {}", e);
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_staff(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    Html(
+        staff_list_content(&session, &t, &result, &filters, &sort_field, &sort_order).into_string(),
+    )
+}
+
+/// GET /staff/new - Show create modal
+pub async fn staff_member_create_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+) -> impl IntoResponse {
+    Html(staff_member_create_modal(&session, &t, None).into_string())
+}
+
+/// POST /staff - Create new staff member
+pub async fn staff_member_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<CreateStaffMemberForm>,
+) -> impl IntoResponse {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    // Validation
+    let name = match validate_name(&form.name) {
+        Ok(n) => n,
+        Err(error) => {
+            return Html(staff_member_create_modal(&session, &t, Some(error)).into_string())
+                .into_response()
+        }
+    };
+
+    // Create staff member
+    match staff::create_staff_member(
+        &state.db,
+        CreateStaffMemberEntity {
+            name: name.to_string(),
+            country_id: form.country_id,
+        },
+    )
+    .await
+    {
+        Ok(_) => {
+            use axum::http::header::{HeaderMap, HeaderName};
+
+            // Return HTMX response to close modal and reload table
+            // Trigger entity-created event for dashboard stats update
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-trigger"),
+                "entity-created".parse().unwrap(),
+            );
+            headers.insert(
+                HeaderName::from_static("hx-toast-success"),
+                t.messages.staff_created().to_string().parse().unwrap(),
+            );
+            (headers, htmx_reload_table("/staff/list", "staff-table")).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to create staff member: {}", e);
+            Html(
+                staff_member_create_modal(&session, &t, Some("Failed to create staff member"))
+                    .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
+
+/// GET /staff/{id}/edit - Show edit modal
+pub async fn staff_member_edit_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let member = match staff::get_staff_member_by_id(&state.db, id).await {
+        Ok(Some(member)) => member,
+        Ok(None) => {
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_staff_member_not_found(),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch staff member: {}", e);
+            return Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_load_staff_member(),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    Html(staff_member_edit_modal(&session, &t, &member, None).into_string())
+}
+
+/// POST /staff/{id} - Update staff member
+pub async fn staff_member_update(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<UpdateStaffMemberForm>,
+) -> impl IntoResponse {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    // Validation
+    let name = match validate_name(&form.name) {
+        Ok(n) => n,
+        Err(error) => {
+            let member = match staff::get_staff_member_by_id(&state.db, id).await {
+                Ok(Some(member)) => member,
+                Ok(None) => {
+                    return Html(
+                        error_message(&t, t.messages.error_staff_member_not_found()).into_string(),
+                    )
+                    .into_response();
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "Database error fetching staff member {} for form re-render: {}",
+                        id,
+                        e
+                    );
+                    return Html(
+                        error_message(&t, t.messages.error_failed_to_load_staff_member())
+                            .into_string(),
+                    )
+                    .into_response();
+                }
+            };
+            return Html(staff_member_edit_modal(&session, &t, &member, Some(error)).into_string())
+                .into_response();
+        }
+    };
+
+    // Update staff member
+    match staff::update_staff_member(
+        &state.db,
+        id,
+        UpdateStaffMemberEntity {
+            name: name.to_string(),
+            country_id: form.country_id,
+        },
+    )
+    .await
+    {
+        Ok(true) => {
+            // Return HTMX response to close modal and reload table
+            use axum::http::header::{HeaderMap, HeaderName};
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-toast-success"),
+                t.messages.staff_updated().to_string().parse().unwrap(),
+            );
+            (headers, htmx_reload_table("/staff/list", "staff-table")).into_response()
+        }
+        Ok(false) => {
+            Html(error_message(&t, t.messages.error_staff_member_not_found()).into_string())
+                .into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to update staff member: {}", e);
+            let member = match staff::get_staff_member_by_id(&state.db, id).await {
+                Ok(Some(member)) => member,
+                Ok(None) => {
+                    return Html(
+                        error_message(&t, t.messages.error_staff_member_not_found()).into_string(),
+                    )
+                    .into_response();
+                }
+                Err(e2) => {
+                    tracing::error!(
+                        "Database error fetching staff member {} for form re-render: {}",
+                        id,
+                        e2
+                    );
+                    return Html(
+                        error_message(&t, t.messages.error_failed_to_load_staff_member())
+                            .into_string(),
+                    )
+                    .into_response();
+                }
+            };
+            Html(
+                staff_member_edit_modal(
+                    &session,
+                    &t,
+                    &member,
+                    Some("Failed to update staff member"),
+                )
+                .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
+
+/// POST /staff/{id}/delete - Delete staff member
+pub async fn staff_member_delete(
+    Extension(session): Extension<Session>,
+    State(state): State<AppState>,
+    Extension(t): Extension<TranslationContext>,
+    Path(id): Path<i64>,
+    Query(query): Query<StaffQuery>,
+    Form(form): Form<DeleteStaffMemberForm>,
+) -> impl IntoResponse {
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match staff::delete_staff_member(&state.db, id).await {
+        Ok(true) => {
+            // Build URL to reload table with current filters and sorting
+            let mut reload_url = format!(
+                "/staff/list?page={}&page_size={}&sort={}&order={}",
+                query.page, query.page_size, query.sort, query.order
+            );
+
+            if let Some(name) = &query.name {
+                reload_url.push_str(&format!("&name={}", urlencoding::encode(name)));
+            }
+
+            if let Some(country_id) = query.country_id {
+                reload_url.push_str(&format!("&country_id={}", country_id));
+            }
+
+            // Return HTMX response to reload table with filters
+            use axum::http::header::{HeaderMap, HeaderName};
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-toast-success"),
+                t.messages.staff_deleted().to_string().parse().unwrap(),
+            );
+            (
+                headers,
+                Html(format!(
+                    "<div hx-get=\"{}\" hx-target=\"#staff-table\" hx-trigger=\"load\" hx-swap=\"outerHTML\"></div>",
+                    reload_url
+                )),
+            )
+                .into_response()
+        }
+        Ok(false) => Html(
+            crate::views::components::error::error_message(
+                &t,
+                t.messages.error_staff_member_not_found(),
+            )
+            .into_string(),
+        )
+        .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to delete staff member: {}", e);
+            Html(
+                crate::views::components::error::error_message(
+                    &t,
+                    t.messages.error_failed_to_delete_staff_member(),
+                )
+                .into_string(),
+            )
+            .into_response()
+        }
+    }
+}
+
+/// GET /team-participations/{id}/staff/add - Form/modal to contract a staff member
+pub async fn roster_add_staff_form(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(team_participation_id): Path<i64>,
+) -> impl IntoResponse {
+    let staff_members = match staff::get_staff_members_simple(&state.db).await {
+        Ok(members) => members,
+        Err(e) => {
+            tracing::warn!("Failed to load staff members: {}", e);
+            Vec::new()
+        }
+    };
+
+    Html(add_staff_modal(&t, team_participation_id, None, &staff_members).into_string())
+}
+
+/// POST /team-participations/{id}/staff - Contract a staff member to the team participation
+pub async fn roster_add_staff(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(team_participation_id): Path<i64>,
+    Form(form): Form<AddStaffForm>,
+) -> axum::response::Response {
+    // Get staff members for form re-render on error
+    let staff_members = match staff::get_staff_members_simple(&state.db).await {
+        Ok(members) => members,
+        Err(e) => {
+            tracing::warn!("Failed to load staff members: {}", e);
+            Vec::new()
+        }
+    };
+
+    let render_error = |error: &str| {
+        Html(add_staff_modal(&t, team_participation_id, Some(error), &staff_members).into_string())
+            .into_response()
+    };
+
+    let Some(role) = StaffRole::from_str(&form.role) else {
+        return render_error("Please select a valid role");
+    };
+
+    match staff::is_staff_contracted(&state.db, team_participation_id, form.staff_member_id, role)
+        .await
+    {
+        Ok(true) => {
+            return render_error("This staff member already holds this role in the team");
+        }
+        Err(e) => {
+            tracing::error!("Failed to check staff contract: {}", e);
+            return render_error("Failed to check staff status");
+        }
+        _ => {}
+    }
+
+    match staff::add_staff_to_participation(
+        &state.db,
+        team_participation_id,
+        form.staff_member_id,
+        role,
+    )
+    .await
+    {
+        Ok(_) => redirect_to_roster(team_participation_id),
+        Err(e) => {
+            tracing::error!("Failed to add staff member to team participation: {}", e);
+            render_error("Failed to add staff member. Please try again.")
+        }
+    }
+}
+
+/// POST /staff-contracts/{id}/delete - Remove a staff member from a team participation
+pub async fn staff_contract_delete(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(staff_contract_id): Path<i64>,
+) -> axum::response::Response {
+    // Get team_participation_id before deleting for redirect
+    let team_participation_id =
+        match staff::get_team_participation_id_for_staff_contract(&state.db, staff_contract_id)
+            .await
+        {
+            Ok(Some(id)) => id,
+            Ok(None) => {
+                return Html(
+                    error_message(&t, t.messages.error_staff_contract_not_found()).into_string(),
+                )
+                .into_response();
+            }
+            Err(e) => {
+                tracing::error!("Failed to fetch staff contract: {}", e);
+                return Html(
+                    error_message(&t, t.messages.error_failed_to_load_staff()).into_string(),
+                )
+                .into_response();
+            }
+        };
+
+    match staff::remove_staff_contract(&state.db, staff_contract_id).await {
+        Ok(true) => redirect_to_roster(team_participation_id),
+        Ok(false) => {
+            Html(error_message(&t, t.messages.error_staff_contract_not_found()).into_string())
+                .into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to remove staff contract: {}", e);
+            Html(error_message(&t, t.messages.error_failed_to_load_staff()).into_string())
+                .into_response()
+        }
+    }
+}
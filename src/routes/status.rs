@@ -0,0 +1,146 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, HeaderName, StatusCode},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use chrono::Utc;
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::common::file_download::{file_download, SQLITE_CONTENT_TYPE};
+use crate::i18n::TranslationContext;
+use crate::service::maintenance;
+use crate::service::users::{self, UserRole};
+use crate::views::{
+    layout::admin_layout,
+    pages::status::{maintenance_runs_table, status_page},
+};
+
+/// Maintenance task results shown on the status page
+const RECENT_RUNS_LIMIT: i64 = 30;
+
+#[derive(Debug, Deserialize)]
+pub struct RunMaintenanceForm {
+    csrf_token: String,
+}
+
+/// GET /management/status - Database status and maintenance history
+pub async fn status_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let info = match maintenance::get_database_info(&state.db).await {
+        Ok(info) => info,
+        Err(e) => {
+            tracing::error!("Failed to read database status: {}", e);
+            return Html(
+                admin_layout(
+                    "System Status",
+                    &session,
+                    "/management",
+                    &t,
+                    crate::views::components::error::error_message(
+                        &t,
+                        t.messages.error_failed_to_load_status(),
+                    ),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let runs = match maintenance::get_recent_runs(&state.db, RECENT_RUNS_LIMIT).await {
+        Ok(runs) => runs,
+        Err(e) => {
+            tracing::warn!("Failed to load maintenance runs: {}", e);
+            Vec::new()
+        }
+    };
+
+    // Snapshots and manual runs are admin routes, so editors don't get the buttons
+    let is_admin = match users::get_user_by_id(&state.db, session.user_id).await {
+        Ok(user) => user.is_some_and(|user| user.role() == Some(UserRole::Admin)),
+        Err(e) => {
+            tracing::warn!("Failed to check user role: {}", e);
+            false
+        }
+    };
+
+    let next_run = state.maintenance.next_run(Utc::now());
+    let content = status_page(
+        &session,
+        &t,
+        &info,
+        &state.maintenance,
+        next_run,
+        &runs,
+        is_admin,
+    );
+    Html(admin_layout("System Status", &session, "/management", &t, content).into_string())
+}
+
+/// POST /management/status/maintenance - Run the maintenance job now
+pub async fn status_run_maintenance(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<RunMaintenanceForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let outcomes = crate::maintenance::run(&state.db, &state.maintenance).await;
+
+    let runs = match maintenance::get_recent_runs(&state.db, RECENT_RUNS_LIMIT).await {
+        Ok(runs) => runs,
+        Err(e) => {
+            tracing::warn!("Failed to load maintenance runs: {}", e);
+            Vec::new()
+        }
+    };
+
+    let mut headers = HeaderMap::new();
+    let (header, message) = if outcomes.iter().all(|outcome| outcome.success) {
+        ("hx-toast-success", t.messages.status_run_succeeded())
+    } else {
+        ("hx-toast-error", t.messages.status_run_failed())
+    };
+    headers.insert(
+        HeaderName::from_static(header),
+        message
+            .to_string()
+            .parse()
+            .expect("Toast message should be a valid header value"),
+    );
+
+    (
+        headers,
+        Html(maintenance_runs_table(&t, &runs).into_string()),
+    )
+        .into_response()
+}
+
+/// GET /management/status/snapshot - Download a consistent copy of the database
+pub async fn status_snapshot(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> axum::response::Response {
+    let file = match crate::maintenance::snapshot(&state.db).await {
+        Ok(file) => file,
+        Err(e) => {
+            tracing::error!("Failed to write database snapshot: {}", e);
+            return (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                t.messages.status_snapshot_failed().to_string(),
+            )
+                .into_response();
+        }
+    };
+
+    let filename = format!("hockey-{}.db", Utc::now().format("%Y%m%d-%H%M%S"));
+    file_download(file, &filename, SQLITE_CONTENT_TYPE).await
+}
@@ -0,0 +1,467 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::tasks::{self, CreateTaskEntity, TaskEntityType, TaskScope, UpdateTaskEntity};
+use crate::validation::{validate_due_date, validate_name};
+use crate::views::{
+    components::error::error_message,
+    layout::admin_layout,
+    pages::tasks::{
+        task_create_modal, task_edit_modal, tasks_list, tasks_page, TaskFormValues,
+        TASKS_CHANGED_EVENT,
+    },
+};
+
+/// Longest accepted task notes, in characters
+const MAX_NOTES_LENGTH: usize = 2000;
+
+#[derive(Debug, Deserialize)]
+pub struct TasksQuery {
+    #[serde(default)]
+    scope: Option<String>,
+    #[serde(default)]
+    entity_type: Option<String>,
+    #[serde(default)]
+    entity_id: Option<i64>,
+}
+
+impl TasksQuery {
+    fn scope(&self) -> TaskScope {
+        self.scope
+            .as_deref()
+            .map(TaskScope::from_str)
+            .unwrap_or_default()
+    }
+
+    fn entity(&self) -> Option<(TaskEntityType, i64)> {
+        let entity_type = TaskEntityType::from_str(self.entity_type.as_deref()?)?;
+        Some((entity_type, self.entity_id?))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TaskForm {
+    title: String,
+    #[serde(default)]
+    notes: String,
+    #[serde(default)]
+    assignee_id: String,
+    #[serde(default)]
+    due_date: String,
+    #[serde(default)]
+    entity_type: Option<String>,
+    #[serde(default)]
+    entity_id: Option<i64>,
+    csrf_token: String,
+}
+
+impl TaskForm {
+    fn values(&self) -> TaskFormValues<'_> {
+        TaskFormValues {
+            title: &self.title,
+            notes: Some(self.notes.as_str()).filter(|n| !n.is_empty()),
+            assignee_id: self.assignee_id.parse().ok(),
+            due_date: Some(self.due_date.as_str()).filter(|d| !d.is_empty()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CompleteTaskForm {
+    completed: bool,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CsrfForm {
+    csrf_token: String,
+}
+
+/// Validated fields shared by the create and edit forms
+struct ValidatedTask {
+    title: String,
+    notes: Option<String>,
+    assignee_id: Option<i64>,
+    due_date: Option<String>,
+}
+
+fn validate_task_form(form: &TaskForm) -> Result<ValidatedTask, &'static str> {
+    let title = validate_name(&form.title).map_err(|_| "Task cannot be empty")?;
+    let notes = form.notes.trim();
+    if notes.chars().count() > MAX_NOTES_LENGTH {
+        return Err("Notes cannot exceed 2000 characters");
+    }
+    let assignee_id = match form.assignee_id.trim() {
+        "" => None,
+        id => Some(id.parse().map_err(|_| "Invalid assignee")?),
+    };
+    let due_date = validate_due_date(Some(&form.due_date))?;
+
+    Ok(ValidatedTask {
+        title,
+        notes: Some(notes.to_string()).filter(|n| !n.is_empty()),
+        assignee_id,
+        due_date,
+    })
+}
+
+/// Today's date as stored in `task.due_date`
+fn today() -> String {
+    chrono::Local::now().format("%Y-%m-%d").to_string()
+}
+
+/// Headers closing the modal flow: a toast plus the event reloading task lists
+fn tasks_changed_headers(message: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static("hx-toast-success"),
+        message
+            .parse()
+            .expect("Toast message should be a valid header value"),
+    );
+    headers.insert(
+        HeaderName::from_static("hx-trigger"),
+        TASKS_CHANGED_EVENT
+            .parse()
+            .expect("Event name should be a valid header value"),
+    );
+    headers
+}
+
+/// GET /tasks - Task list page
+pub async fn tasks_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<TasksQuery>,
+) -> impl IntoResponse {
+    let scope = query.scope();
+    let content = match tasks::get_tasks(&state.db, scope, session.user_id, None).await {
+        Ok(tasks) => tasks_page(&session, &t, &tasks, scope, &today()),
+        Err(e) => {
+            tracing::error!("Failed to fetch tasks: {}", e);
+            error_message(&t, t.messages.tasks_load_failed())
+        }
+    };
+    Html(admin_layout("Tasks", &session, "/tasks", &t, content).into_string())
+}
+
+/// GET /tasks/list - HTMX endpoint for a task table, optionally of one record
+pub async fn tasks_list_partial(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<TasksQuery>,
+) -> impl IntoResponse {
+    let scope = query.scope();
+    let entity = query.entity();
+    match tasks::get_tasks(&state.db, scope, session.user_id, entity).await {
+        Ok(tasks) => Html(tasks_list(&session, &t, &tasks, scope, entity, &today()).into_string()),
+        Err(e) => {
+            tracing::error!("Failed to fetch tasks: {}", e);
+            Html(error_message(&t, t.messages.tasks_load_failed()).into_string())
+        }
+    }
+}
+
+/// Render the create modal, resolving the attached record's name
+async fn create_modal(
+    state: &AppState,
+    session: &Session,
+    t: &TranslationContext,
+    entity: Option<(TaskEntityType, i64)>,
+    values: &TaskFormValues<'_>,
+    error: Option<&str>,
+) -> Html<String> {
+    let users = match tasks::get_assignable_users(&state.db).await {
+        Ok(users) => users,
+        Err(e) => {
+            tracing::error!("Failed to fetch assignable users: {}", e);
+            return Html(error_message(t, t.messages.tasks_load_failed()).into_string());
+        }
+    };
+
+    let label = match entity {
+        Some((entity_type, id)) => {
+            match tasks::get_entity_label(&state.db, entity_type, id).await {
+                Ok(Some(label)) => Some((entity_type, id, label)),
+                Ok(None) => {
+                    return Html(
+                        error_message(t, t.messages.tasks_entity_not_found()).into_string(),
+                    )
+                }
+                Err(e) => {
+                    tracing::error!(
+                        "Failed to fetch task record {:?} {}: {}",
+                        entity_type,
+                        id,
+                        e
+                    );
+                    return Html(error_message(t, t.messages.tasks_load_failed()).into_string());
+                }
+            }
+        }
+        None => None,
+    };
+
+    Html(
+        task_create_modal(
+            session,
+            t,
+            &users,
+            label
+                .as_ref()
+                .map(|(entity_type, id, label)| (*entity_type, *id, label.as_str())),
+            values,
+            error,
+        )
+        .into_string(),
+    )
+}
+
+/// GET /tasks/new - Show create modal, attached to a record when given
+pub async fn task_create_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<TasksQuery>,
+) -> impl IntoResponse {
+    // New tasks are assigned to their author until someone changes it
+    let values = TaskFormValues {
+        title: "",
+        notes: None,
+        assignee_id: Some(session.user_id),
+        due_date: None,
+    };
+    create_modal(&state, &session, &t, query.entity(), &values, None).await
+}
+
+/// POST /tasks - Create task
+pub async fn task_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<TaskForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let entity = form
+        .entity_type
+        .as_deref()
+        .and_then(TaskEntityType::from_str)
+        .zip(form.entity_id);
+
+    let task = match validate_task_form(&form) {
+        Ok(task) => task,
+        Err(error) => {
+            return create_modal(&state, &session, &t, entity, &form.values(), Some(error))
+                .await
+                .into_response()
+        }
+    };
+
+    match tasks::create_task(
+        &state.db,
+        CreateTaskEntity {
+            title: task.title,
+            notes: task.notes,
+            entity,
+            assignee_id: task.assignee_id,
+            due_date: task.due_date,
+            created_by: session.user_id,
+        },
+    )
+    .await
+    {
+        Ok(_) => (
+            tasks_changed_headers(&t.messages.tasks_created_toast().to_string()),
+            Html(String::new()),
+        )
+            .into_response(),
+        Err(e) => {
+            tracing::error!("Failed to create task: {}", e);
+            create_modal(
+                &state,
+                &session,
+                &t,
+                entity,
+                &form.values(),
+                Some(&t.messages.tasks_save_failed().to_string()),
+            )
+            .await
+            .into_response()
+        }
+    }
+}
+
+/// Render the edit modal with the assignable users
+async fn edit_modal(
+    state: &AppState,
+    session: &Session,
+    t: &TranslationContext,
+    id: i64,
+    values: &TaskFormValues<'_>,
+    error: Option<&str>,
+) -> Html<String> {
+    match tasks::get_assignable_users(&state.db).await {
+        Ok(users) => Html(task_edit_modal(session, t, &users, id, values, error).into_string()),
+        Err(e) => {
+            tracing::error!("Failed to fetch assignable users: {}", e);
+            Html(error_message(t, t.messages.tasks_load_failed()).into_string())
+        }
+    }
+}
+
+/// GET /tasks/{id}/edit - Show edit modal
+pub async fn task_edit_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    match tasks::get_task_by_id(&state.db, id).await {
+        Ok(Some(task)) => {
+            let values = TaskFormValues {
+                title: &task.title,
+                notes: task.notes.as_deref(),
+                assignee_id: task.assignee_id,
+                due_date: task.due_date.as_deref(),
+            };
+            edit_modal(&state, &session, &t, id, &values, None).await
+        }
+        Ok(None) => Html(error_message(&t, t.messages.tasks_not_found()).into_string()),
+        Err(e) => {
+            tracing::error!("Failed to fetch task {}: {}", id, e);
+            Html(error_message(&t, t.messages.tasks_load_failed()).into_string())
+        }
+    }
+}
+
+/// POST /tasks/{id} - Update task
+pub async fn task_update(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<TaskForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let task = match validate_task_form(&form) {
+        Ok(task) => task,
+        Err(error) => {
+            return edit_modal(&state, &session, &t, id, &form.values(), Some(error))
+                .await
+                .into_response()
+        }
+    };
+
+    match tasks::update_task(
+        &state.db,
+        id,
+        UpdateTaskEntity {
+            title: task.title,
+            notes: task.notes,
+            assignee_id: task.assignee_id,
+            due_date: task.due_date,
+        },
+    )
+    .await
+    {
+        Ok(true) => (
+            tasks_changed_headers(&t.messages.tasks_updated_toast().to_string()),
+            Html(String::new()),
+        )
+            .into_response(),
+        Ok(false) => {
+            Html(error_message(&t, t.messages.tasks_not_found()).into_string()).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to update task {}: {}", id, e);
+            edit_modal(
+                &state,
+                &session,
+                &t,
+                id,
+                &form.values(),
+                Some(&t.messages.tasks_save_failed().to_string()),
+            )
+            .await
+            .into_response()
+        }
+    }
+}
+
+/// POST /tasks/{id}/complete - Mark a task done or reopen it
+pub async fn task_complete(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<CompleteTaskForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let toast = if form.completed {
+        t.messages.tasks_completed_toast()
+    } else {
+        t.messages.tasks_reopened_toast()
+    };
+
+    match tasks::set_task_completed(&state.db, id, form.completed).await {
+        Ok(true) => (
+            tasks_changed_headers(&toast.to_string()),
+            Html(String::new()),
+        )
+            .into_response(),
+        Ok(false) => {
+            Html(error_message(&t, t.messages.tasks_not_found()).into_string()).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to update task {}: {}", id, e);
+            Html(error_message(&t, t.messages.tasks_save_failed()).into_string()).into_response()
+        }
+    }
+}
+
+/// POST /tasks/{id}/delete - Delete task
+pub async fn task_delete(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<CsrfForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match tasks::delete_task(&state.db, id).await {
+        Ok(true) => (
+            tasks_changed_headers(&t.messages.tasks_deleted_toast().to_string()),
+            Html(String::new()),
+        )
+            .into_response(),
+        Ok(false) => {
+            Html(error_message(&t, t.messages.tasks_not_found()).into_string()).into_response()
+        }
+        Err(e) => {
+            tracing::error!("Failed to delete task {}: {}", id, e);
+            Html(error_message(&t, t.messages.tasks_delete_failed()).into_string()).into_response()
+        }
+    }
+}
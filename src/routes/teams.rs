@@ -1,23 +1,33 @@
 use axum::{
-    extract::{Path, Query, State},
-    response::{Html, IntoResponse},
+    extract::{Multipart, Path, Query, State},
+    http::{header, HeaderMap},
+    response::{Html, IntoResponse, Json, Response},
     Extension, Form,
 };
 use serde::Deserialize;
 
 use crate::app_state::AppState;
 use crate::auth::Session;
+use crate::common::csv_export::csv_download;
 use crate::i18n::TranslationContext;
 use crate::service::{
-    countries,
+    calendar_subscriptions, countries, goal_types, roster_diff,
+    slugs::SlugEntity,
+    splits, team_form, team_history, team_merge, team_participations, team_stats,
     teams::{self, CreateTeamEntity, SortField, SortOrder, TeamFilters, UpdateTeamEntity},
 };
 use crate::validation::validate_name;
 use crate::views::{
     components::{error::error_message, htmx::htmx_reload_table},
     layout::admin_layout,
+    pages::roster_diff::roster_diff_page,
+    pages::splits::team_splits_page,
     pages::team_detail::team_detail_page,
-    pages::teams::{team_create_modal, team_edit_modal, team_list_content, teams_page},
+    pages::team_merge::team_merge_page,
+    pages::teams::{
+        team_create_modal, team_edit_modal, team_import_modal, team_import_result_modal,
+        team_list_content, teams_page,
+    },
 };
 
 #[derive(Debug, Deserialize)]
@@ -63,6 +73,8 @@ pub struct CreateTeamForm {
 pub struct UpdateTeamForm {
     name: String,
     country_id: Option<i64>,
+    #[serde(default)]
+    slug: Option<String>,
     csrf_token: String,
 }
 
@@ -181,6 +193,24 @@ pub async fn teams_list_partial(
     Html(team_list_content(&session, &t, &result, &filters, &sort_field, &sort_order).into_string())
 }
 
+/// GET /teams/export, /api/teams/export - Teams of the current list as CSV
+pub async fn teams_export(
+    State(state): State<AppState>,
+    Query(query): Query<TeamsQuery>,
+) -> Response {
+    let filters = TeamFilters {
+        name: query.name,
+        country_id: query.country_id,
+    };
+    let sort_field = SortField::from_str(&query.sort);
+    let sort_order = SortOrder::from_str(&query.order);
+
+    csv_download("teams.csv", move |mut sink| async move {
+        teams::export_teams_csv(&state.db, &filters, &sort_field, &sort_order, &mut sink).await?;
+        Ok(sink)
+    })
+}
+
 /// GET /teams/new - Show create modal
 pub async fn team_create_form(
     Extension(session): Extension<Session>,
@@ -290,8 +320,26 @@ pub async fn team_update(
     }
 
     // Validation
-    let name = match validate_name(&form.name) {
-        Ok(n) => n,
+    let validated = match validate_name(&form.name) {
+        Ok(name) => crate::business::slugs::validate_requested_slug(
+            &state.db,
+            SlugEntity::Team,
+            id,
+            form.slug.as_deref(),
+        )
+        .await
+        .map(|slug| (name, slug))
+        .map_err(|error| match error {
+            Ok(error) => error.message(),
+            Err(e) => {
+                tracing::error!("Failed to check slug of team {}: {}", id, e);
+                "Failed to check the slug"
+            }
+        }),
+        Err(error) => Err(error),
+    };
+    let (name, slug) = match validated {
+        Ok(validated) => validated,
         Err(error) => {
             let team = match teams::get_team_by_id(&state.db, id).await {
                 Ok(Some(team)) => team,
@@ -325,6 +373,7 @@ pub async fn team_update(
         UpdateTeamEntity {
             name: name.to_string(),
             country_id: form.country_id,
+            slug,
         },
     )
     .await
@@ -435,14 +484,23 @@ pub async fn team_delete(
     }
 }
 
-/// GET /teams/{id} - Team detail page
+/// GET /teams/{id} - Team detail page, also reachable by the team's slug
 pub async fn team_detail(
     Extension(session): Extension<Session>,
     Extension(t): Extension<TranslationContext>,
     State(state): State<AppState>,
-    Path(id): Path<i64>,
-) -> impl IntoResponse {
-    let detail = match teams::get_team_detail(&state.db, id).await {
+    Path(key): Path<String>,
+) -> Response {
+    let resolved =
+        crate::routes::slugs::resolve_entity_path(&state.db, SlugEntity::Team, "/teams", &key)
+            .await;
+    let detail = match resolved {
+        Ok(Some(id)) => teams::get_team_detail(&state.db, id).await,
+        Ok(None) => Ok(None),
+        Err(Ok(redirect)) => return redirect,
+        Err(Err(e)) => Err(e),
+    };
+    let detail = match detail {
         Ok(Some(detail)) => detail,
         Ok(None) => {
             return Html(
@@ -457,7 +515,8 @@ pub async fn team_detail(
                     ),
                 )
                 .into_string(),
-            );
+            )
+            .into_response();
         }
         Err(e) => {
             tracing::error!("Failed to fetch team detail: {}", e);
@@ -473,17 +532,454 @@ pub async fn team_detail(
                     ),
                 )
                 .into_string(),
+            )
+            .into_response();
+        }
+    };
+
+    let id = detail.team_info.id;
+
+    let subscriptions = calendar_subscriptions::get_team_subscriptions(&state.db, id)
+        .await
+        .unwrap_or_default();
+    let season_stats = match team_stats::get_team_season_stats(&state.db, id).await {
+        Ok(stats) => stats,
+        Err(e) => {
+            tracing::warn!("Failed to load season stats for team {}: {}", id, e);
+            Vec::new()
+        }
+    };
+
+    let goal_types = match goal_types::get_team_goal_types(&state.db, id).await {
+        Ok(goal_types) => goal_types,
+        Err(e) => {
+            tracing::warn!("Failed to load goal types for team {}: {}", id, e);
+            Vec::new()
+        }
+    };
+
+    let form = match team_form::get_team_form(&state.db, id).await {
+        Ok(form) => form,
+        Err(e) => {
+            tracing::warn!("Failed to load form for team {}: {}", id, e);
+            Vec::new()
+        }
+    };
+
+    let content = team_detail_page(
+        &session,
+        &t,
+        &detail,
+        &form,
+        &season_stats,
+        &goal_types,
+        &subscriptions,
+        &state.public_url,
+    );
+    Html(admin_layout("Team Detail", &session, "/teams", &t, content).into_string()).into_response()
+}
+
+/// GET /api/teams/:id/stats - JSON goal statistics of a team per season
+pub async fn team_stats_api(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    match teams::get_team_by_id(&state.db, id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return (axum::http::StatusCode::NOT_FOUND, "Team not found").into_response(),
+        Err(e) => {
+            tracing::error!("Failed to fetch team: {}", e);
+            return (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to fetch team",
+            )
+                .into_response();
+        }
+    }
+
+    match team_stats::get_team_season_stats(&state.db, id).await {
+        Ok(stats) => Json(stats).into_response(),
+        Err(e) => {
+            tracing::error!("Failed to fetch team season stats: {}", e);
+            (
+                axum::http::StatusCode::INTERNAL_SERVER_ERROR,
+                "Failed to fetch team stats",
+            )
+                .into_response()
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RosterDiffQuery {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    from: Option<i64>,
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    to: Option<i64>,
+}
+
+/// GET /teams/{id}/roster-diff - Compare the team's rosters of two seasons
+///
+/// Without `from` and `to`, the two most recent seasons are compared.
+pub async fn team_roster_diff_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Query(query): Query<RosterDiffQuery>,
+) -> impl IntoResponse {
+    let detail = match teams::get_team_detail(&state.db, id).await {
+        Ok(Some(detail)) => detail,
+        Ok(None) => {
+            return Html(
+                admin_layout(
+                    "Team Not Found",
+                    &session,
+                    "/teams",
+                    &t,
+                    error_message(&t, t.messages.error_team_not_found()),
+                )
+                .into_string(),
             );
         }
+        Err(e) => {
+            tracing::error!("Failed to fetch team detail: {}", e);
+            return Html(
+                admin_layout(
+                    "Error",
+                    &session,
+                    "/teams",
+                    &t,
+                    error_message(&t, t.messages.error_failed_to_load_team()),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    // Participations are listed newest first
+    let seasons: Vec<i64> = detail.participations.iter().map(|p| p.season_id).collect();
+    let to = query.to.or_else(|| seasons.first().copied());
+    let from = query.from.or_else(|| seasons.get(1).copied());
+
+    let diff = match (from, to) {
+        (Some(from), Some(to)) if from != to => {
+            match roster_diff::get_roster_diff(&state.db, id, from, to).await {
+                Ok(diff) => Some(diff),
+                Err(e) => {
+                    tracing::warn!("Failed to compare rosters of team {}: {}", id, e);
+                    None
+                }
+            }
+        }
+        _ => None,
     };
 
-    let content = team_detail_page(&session, &t, &detail);
-    Html(admin_layout("Team Detail", &session, "/teams", &t, content).into_string())
+    let content = roster_diff_page(
+        &t,
+        &detail.team_info,
+        &detail.participations,
+        from,
+        to,
+        diff.as_ref(),
+    );
+    Html(admin_layout("Roster Comparison", &session, "/teams", &t, content).into_string())
+}
+
+/// GET /teams/{id}/splits - Home and away records of the team per season
+pub async fn team_splits_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let team = match teams::get_team_by_id(&state.db, id).await {
+        Ok(Some(team)) => team,
+        Ok(None) => {
+            return Html(
+                admin_layout(
+                    "Team Not Found",
+                    &session,
+                    "/teams",
+                    &t,
+                    error_message(&t, t.messages.error_team_not_found()),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch team: {}", e);
+            return Html(
+                admin_layout(
+                    "Error",
+                    &session,
+                    "/teams",
+                    &t,
+                    error_message(&t, t.messages.error_failed_to_load_team()),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let content = match splits::get_team_splits(&state.db, id).await {
+        Ok(splits) => team_splits_page(&t, &team, &splits),
+        Err(e) => {
+            tracing::error!("Failed to load splits for team {}: {}", id, e);
+            error_message(&t, t.messages.splits_load_failed())
+        }
+    };
+    Html(admin_layout("Home/Away Splits", &session, "/teams", &t, content).into_string())
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TeamMergeQuery {
+    #[serde(default, deserialize_with = "crate::utils::empty_string_as_none_i64")]
+    into: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TeamMergeForm {
+    into: i64,
+    csrf_token: String,
+}
+
+/// GET /teams/{id}/merge - Pick the team to merge this one into and preview the merge
+pub async fn team_merge_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Query(query): Query<TeamMergeQuery>,
+) -> impl IntoResponse {
+    let team = match teams::get_team_by_id(&state.db, id).await {
+        Ok(Some(team)) => team,
+        Ok(None) => {
+            return Html(
+                admin_layout(
+                    "Team Not Found",
+                    &session,
+                    "/teams",
+                    &t,
+                    error_message(&t, t.messages.error_team_not_found()),
+                )
+                .into_string(),
+            );
+        }
+        Err(e) => {
+            tracing::error!("Failed to fetch team: {}", e);
+            return Html(
+                admin_layout(
+                    "Error",
+                    &session,
+                    "/teams",
+                    &t,
+                    error_message(&t, t.messages.error_failed_to_load_team()),
+                )
+                .into_string(),
+            );
+        }
+    };
+
+    let all_teams = team_participations::get_all_teams_for_dropdown(&state.db)
+        .await
+        .unwrap_or_default();
+
+    let mut error = None;
+    let mut into = None;
+    let mut preview = None;
+    if let Some(into_id) = query.into.filter(|into_id| *into_id != id) {
+        match teams::get_team_by_id(&state.db, into_id).await {
+            Ok(Some(canonical)) => {
+                match team_merge::get_merge_preview(&state.db, into_id, id).await {
+                    Ok(found) => {
+                        into = Some(canonical);
+                        preview = Some(found);
+                    }
+                    Err(e) => {
+                        tracing::error!("Failed to preview merge of team {}: {}", id, e);
+                        error = Some(t.messages.team_merge_failed().to_string());
+                    }
+                }
+            }
+            Ok(None) => error = Some(t.messages.error_team_not_found().to_string()),
+            Err(e) => {
+                tracing::error!("Failed to fetch team: {}", e);
+                error = Some(t.messages.error_failed_to_load_team().to_string());
+            }
+        }
+    }
+
+    let content = team_merge_page(
+        &session,
+        &t,
+        &team,
+        &all_teams,
+        into.as_ref(),
+        preview.as_ref(),
+        error.as_deref(),
+    );
+    Html(admin_layout("Merge Team", &session, "/teams", &t, content).into_string())
+}
+
+/// POST /teams/{id}/merge - Merge this team into another one and delete it
+pub async fn team_merge_post(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<TeamMergeForm>,
+) -> impl IntoResponse {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match crate::business::team_merge::merge_teams_validated(&state.db, form.into, id).await {
+        Ok(()) => {
+            use axum::http::header::HeaderName;
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-redirect"),
+                format!("/teams/{}", form.into)
+                    .parse()
+                    .expect("Valid redirect URL should parse"),
+            );
+            (headers, Html("".to_string())).into_response()
+        }
+        Err(Ok(validation_error)) => {
+            Html(error_message(&t, validation_error.message()).into_string()).into_response()
+        }
+        Err(Err(e)) => {
+            tracing::error!("Failed to merge team {} into {}: {}", id, form.into, e);
+            Html(error_message(&t, t.messages.team_merge_failed()).into_string()).into_response()
+        }
+    }
+}
+
+/// Upload limit for team history files, which can exceed axum's 2 MB default
+pub const TEAM_IMPORT_MAX_BYTES: usize = 20 * 1024 * 1024;
+
+/// GET /teams/{id}/export - Download the team's history as a portable JSON file
+pub async fn team_export(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> impl IntoResponse {
+    let file = match team_history::export_team_history(&state.db, id).await {
+        Ok(Some(file)) => file,
+        Ok(None) => {
+            return Html(error_message(&t, t.messages.error_team_not_found()).into_string())
+                .into_response();
+        }
+        Err(e) => {
+            tracing::error!("Failed to export team {}: {}", id, e);
+            return Html(error_message(&t, t.messages.error_failed_to_load_team()).into_string())
+                .into_response();
+        }
+    };
+
+    let body = match serde_json::to_string_pretty(&file) {
+        Ok(body) => body,
+        Err(e) => {
+            tracing::error!("Failed to serialize team {} history: {}", id, e);
+            return Html(error_message(&t, t.messages.error_failed_to_load_team()).into_string())
+                .into_response();
+        }
+    };
+
+    let filename = format!("team-{}-history.json", id);
+    (
+        [
+            (header::CONTENT_TYPE, "application/json".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            ),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+/// GET /teams/import - Show import modal
+pub async fn team_import_form(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+) -> impl IntoResponse {
+    Html(team_import_modal(&session, &t, None).into_string())
+}
+
+/// POST /teams/import - Import a team history file
+pub async fn team_import(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    mut multipart: Multipart,
+) -> impl IntoResponse {
+    let mut csrf_token = String::new();
+    let mut mode = team_history::ImportMode::AddMissing;
+    let mut data = Vec::new();
+
+    while let Ok(Some(field)) = multipart.next_field().await {
+        match field.name().unwrap_or("") {
+            "csrf_token" => csrf_token = field.text().await.unwrap_or_default(),
+            "mode" => {
+                mode = team_history::ImportMode::from_str(&field.text().await.unwrap_or_default())
+            }
+            "file" => match field.bytes().await {
+                Ok(bytes) => data = bytes.to_vec(),
+                Err(e) => {
+                    tracing::warn!("Failed to read team history upload: {}", e);
+                    return Html(
+                        team_import_modal(&session, &t, Some("Failed to read uploaded file"))
+                            .into_string(),
+                    )
+                    .into_response();
+                }
+            },
+            _ => {}
+        }
+    }
+
+    // Validate CSRF token
+    if let Err(response) = crate::auth::validate_csrf_token(&csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match crate::business::team_history::import_team_history_validated(&state.db, &data, mode).await
+    {
+        Ok(summary) => {
+            use axum::http::header::{HeaderMap, HeaderName};
+
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                HeaderName::from_static("hx-trigger"),
+                "entity-created".parse().unwrap(),
+            );
+            (
+                headers,
+                Html(team_import_result_modal(&t, &summary).into_string()),
+            )
+                .into_response()
+        }
+        Err(Ok(validation_error)) => {
+            Html(team_import_modal(&session, &t, Some(validation_error.message())).into_string())
+                .into_response()
+        }
+        Err(Err(e)) => {
+            tracing::error!("Failed to import team history: {}", e);
+            Html(
+                team_import_modal(&session, &t, Some("Failed to import team history"))
+                    .into_string(),
+            )
+            .into_response()
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::test_utils::{create_test_app, create_test_session, session_cookie};
+    use crate::test_support::{create_test_app, create_test_session, session_cookie};
     use axum_test::TestServer;
     use sqlx::SqlitePool;
 
@@ -731,4 +1227,26 @@ mod tests {
         let body = response.text();
         assert!(body.contains("not found") || body.contains("Not found"));
     }
+
+    #[sqlx::test(migrations = "./migrations", fixtures("users", "teams"))]
+    async fn test_team_export_downloads_json(pool: SqlitePool) {
+        let app = create_test_app(pool.clone());
+        let server = TestServer::new(app).unwrap();
+        let session = create_test_session(&pool).await;
+
+        let response = server
+            .get("/teams/1/export")
+            .add_cookie(session_cookie(&session))
+            .await;
+
+        response.assert_status_ok();
+        assert!(response
+            .headers()
+            .get("content-disposition")
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.starts_with("attachment")));
+        let body = response.text();
+        assert!(body.contains("\"format\": \"hockey-team-history\""));
+        assert!(body.contains("Team Canada"));
+    }
 }
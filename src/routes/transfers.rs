@@ -0,0 +1,120 @@
+use axum::{
+    extract::{Path, State},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::business::transfers::record_transfer_validated;
+use crate::i18n::TranslationContext;
+use crate::routes::player_contracts::redirect_to_roster;
+use crate::service::transfers::{self, TransferSourceEntity};
+use crate::views::pages::roster::transfer_player_modal;
+
+#[derive(Debug, Deserialize)]
+pub struct TransferForm {
+    to_participation_id: i64,
+    transfer_date: String,
+    #[serde(default)]
+    note: Option<String>,
+}
+
+/// Load the roster entry being transferred, or the error fragment to show instead
+async fn load_source(
+    t: &TranslationContext,
+    state: &AppState,
+    player_contract_id: i64,
+) -> Result<TransferSourceEntity, Html<String>> {
+    match transfers::get_transfer_source(&state.db, player_contract_id).await {
+        Ok(Some(source)) => Ok(source),
+        Ok(None) => Err(Html(
+            crate::views::components::error::error_message(
+                t,
+                t.messages.error_player_contract_not_found(),
+            )
+            .into_string(),
+        )),
+        Err(e) => {
+            tracing::error!(
+                "Failed to fetch player contract {}: {}",
+                player_contract_id,
+                e
+            );
+            Err(Html(
+                crate::views::components::error::error_message(
+                    t,
+                    t.messages.error_failed_to_load_roster(),
+                )
+                .into_string(),
+            ))
+        }
+    }
+}
+
+/// Other teams of the season, logging failures
+async fn load_destinations(state: &AppState, team_participation_id: i64) -> Vec<(i64, String)> {
+    match transfers::get_transfer_destinations(&state.db, team_participation_id).await {
+        Ok(destinations) => destinations,
+        Err(e) => {
+            tracing::warn!(
+                "Failed to load transfer destinations for team participation {}: {}",
+                team_participation_id,
+                e
+            );
+            Vec::new()
+        }
+    }
+}
+
+/// GET /player-contracts/{id}/transfer - Form/modal to transfer a player
+pub async fn transfer_form(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(player_contract_id): Path<i64>,
+) -> impl IntoResponse {
+    let source = match load_source(&t, &state, player_contract_id).await {
+        Ok(source) => source,
+        Err(error) => return error,
+    };
+
+    let destinations = load_destinations(&state, source.team_participation_id).await;
+    Html(transfer_player_modal(&t, &source, &destinations, None).into_string())
+}
+
+/// POST /player-contracts/{id}/transfer - Record a transfer
+pub async fn transfer_create(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(player_contract_id): Path<i64>,
+    Form(form): Form<TransferForm>,
+) -> axum::response::Response {
+    let source = match load_source(&t, &state, player_contract_id).await {
+        Ok(source) => source,
+        Err(error) => return error.into_response(),
+    };
+
+    match record_transfer_validated(
+        &state.db,
+        player_contract_id,
+        form.to_participation_id,
+        &form.transfer_date,
+        form.note.as_deref(),
+    )
+    .await
+    {
+        Ok(_) => redirect_to_roster(source.team_participation_id),
+        Err(result) => {
+            let error = match result {
+                Ok(validation_error) => validation_error.message(),
+                Err(e) => {
+                    tracing::error!("Failed to record transfer: {}", e);
+                    "Failed to record transfer. Please try again."
+                }
+            };
+            let destinations = load_destinations(&state, source.team_participation_id).await;
+            Html(transfer_player_modal(&t, &source, &destinations, Some(error)).into_string())
+                .into_response()
+        }
+    }
+}
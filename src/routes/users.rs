@@ -0,0 +1,354 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::audit;
+use crate::auth::lockout;
+use crate::auth::Session;
+use crate::business::users::{self as users_business, UserValidationError};
+use crate::i18n::TranslationContext;
+use crate::service::settings::{self, RegistrationSettings};
+use crate::service::users;
+use crate::views::components::error::error_message;
+use crate::views::{
+    layout::admin_layout,
+    pages::users::{sign_in_activity_page, users_list, users_page, IssuedPassword},
+};
+
+/// Number of sign-in attempts listed on the activity page
+const ACTIVITY_LIMIT: i64 = 200;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateUserForm {
+    csrf_token: String,
+    #[serde(default)]
+    email: String,
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    role: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserRoleForm {
+    csrf_token: String,
+    #[serde(default)]
+    role: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserActiveForm {
+    csrf_token: String,
+    active: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UserActionForm {
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ActivityQuery {
+    #[serde(default)]
+    failed: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RegistrationSettingsForm {
+    csrf_token: String,
+    #[serde(default)]
+    enabled: bool,
+    #[serde(default)]
+    requires_approval: bool,
+}
+
+/// GET /users - User management (admins only)
+pub async fn users_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let registration = settings::get_registration_settings(&state.db)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to load registration settings: {}", e);
+            RegistrationSettings::default()
+        });
+
+    let content = match users::get_users(&state.db).await {
+        Ok(list) => users_page(&t, &session, &list, &registration),
+        Err(e) => {
+            tracing::error!("Failed to load users: {}", e);
+            error_message(&t, t.messages.error_server_error())
+        }
+    };
+
+    Html(admin_layout("Users", &session, "/management", &t, content).into_string())
+}
+
+/// Re-render the user list with an optional temporary password, error or toast
+async fn users_response(
+    state: &AppState,
+    session: &Session,
+    t: &TranslationContext,
+    issued: Option<IssuedPassword<'_>>,
+    error: Option<&str>,
+    toast: Option<String>,
+) -> axum::response::Response {
+    let list = match users::get_users(&state.db).await {
+        Ok(list) => list,
+        Err(e) => {
+            tracing::error!("Failed to load users: {}", e);
+            return Html(error_message(t, t.messages.error_server_error()).into_string())
+                .into_response();
+        }
+    };
+
+    let mut headers = HeaderMap::new();
+    if let Some(toast) = toast {
+        headers.insert(
+            HeaderName::from_static("hx-toast-success"),
+            toast
+                .parse()
+                .expect("Toast message should be a valid header value"),
+        );
+    }
+
+    (
+        headers,
+        Html(users_list(t, session, &list, issued, error).into_string()),
+    )
+        .into_response()
+}
+
+/// Message for a failed user action; database errors are logged
+fn failure_message(result: Result<UserValidationError, sqlx::Error>, action: &str) -> String {
+    match result {
+        Ok(error) => error.message().to_string(),
+        Err(e) => {
+            tracing::error!("Failed to {}: {}", action, e);
+            format!("Failed to {}. Please try again.", action)
+        }
+    }
+}
+
+/// POST /users - Add a user with a temporary password
+pub async fn user_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<CreateUserForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match users_business::create_user_validated(&state.db, &form.email, &form.name, &form.role)
+        .await
+    {
+        Ok((_, password)) => {
+            let email = form.email.trim();
+            users_response(
+                &state,
+                &session,
+                &t,
+                Some(IssuedPassword {
+                    email,
+                    password: &password,
+                }),
+                None,
+                Some(t.messages.users_created().to_string()),
+            )
+            .await
+        }
+        Err(e) => {
+            let error = failure_message(e, "add user");
+            users_response(&state, &session, &t, None, Some(&error), None).await
+        }
+    }
+}
+
+/// POST /users/{id}/role - Change a user's role
+pub async fn user_role_update(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<UserRoleForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match users_business::set_user_role_validated(&state.db, session.user_id, id, &form.role).await
+    {
+        Ok(()) => {
+            let toast = t.messages.users_updated().to_string();
+            users_response(&state, &session, &t, None, None, Some(toast)).await
+        }
+        Err(e) => {
+            let error = failure_message(e, "change role");
+            users_response(&state, &session, &t, None, Some(&error), None).await
+        }
+    }
+}
+
+/// POST /users/{id}/active - Deactivate or reactivate a user
+pub async fn user_active_update(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<UserActiveForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match users_business::set_user_active_validated(&state.db, session.user_id, id, form.active)
+        .await
+    {
+        Ok(()) => {
+            let toast = t.messages.users_updated().to_string();
+            users_response(&state, &session, &t, None, None, Some(toast)).await
+        }
+        Err(e) => {
+            let error = failure_message(e, "update user");
+            users_response(&state, &session, &t, None, Some(&error), None).await
+        }
+    }
+}
+
+/// POST /users/{id}/reset-password - Give a user a new temporary password
+pub async fn user_password_reset(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<UserActionForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let email = match users::get_user_by_id(&state.db, id).await {
+        Ok(Some(user)) => user.email,
+        Ok(None) => {
+            let error = UserValidationError::NotFound.message();
+            return users_response(&state, &session, &t, None, Some(error), None).await;
+        }
+        Err(e) => {
+            let error = failure_message(Err(e), "reset password");
+            return users_response(&state, &session, &t, None, Some(&error), None).await;
+        }
+    };
+
+    match users_business::reset_user_password(&state.db, id).await {
+        Ok(password) => {
+            users_response(
+                &state,
+                &session,
+                &t,
+                Some(IssuedPassword {
+                    email: &email,
+                    password: &password,
+                }),
+                None,
+                Some(t.messages.users_password_was_reset().to_string()),
+            )
+            .await
+        }
+        Err(e) => {
+            let error = failure_message(e, "reset password");
+            users_response(&state, &session, &t, None, Some(&error), None).await
+        }
+    }
+}
+
+/// POST /users/{id}/unlock - Let a locked-out user sign in again right away
+pub async fn user_unlock(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<UserActionForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match lockout::unlock_user(&state.db, id).await {
+        Ok(true) => {
+            let toast = t.messages.users_unlocked().to_string();
+            users_response(&state, &session, &t, None, None, Some(toast)).await
+        }
+        Ok(false) => {
+            let error = UserValidationError::NotFound.message();
+            users_response(&state, &session, &t, None, Some(error), None).await
+        }
+        Err(e) => {
+            let error = failure_message(Err(e), "unlock user");
+            users_response(&state, &session, &t, None, Some(&error), None).await
+        }
+    }
+}
+
+/// GET /users/activity - Recent sign-in attempts of all accounts
+pub async fn users_activity_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<ActivityQuery>,
+) -> impl IntoResponse {
+    let content = match audit::get_recent_activity(&state.db, query.failed, ACTIVITY_LIMIT).await {
+        Ok(entries) => sign_in_activity_page(&t, &entries, query.failed),
+        Err(e) => {
+            tracing::error!("Failed to load sign-in activity: {}", e);
+            error_message(&t, t.messages.error_failed_to_load_logins())
+        }
+    };
+
+    Html(admin_layout("Sign-in activity", &session, "/management", &t, content).into_string())
+}
+
+/// POST /users/registration - Open or close self-service sign-up
+pub async fn registration_settings_update(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<RegistrationSettingsForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let registration = RegistrationSettings {
+        enabled: form.enabled,
+        requires_approval: form.requires_approval,
+    };
+    let (kind, message) =
+        match settings::update_registration_settings(&state.db, &registration).await {
+            Ok(()) => ("hx-toast-success", t.messages.settings_saved().to_string()),
+            Err(e) => {
+                tracing::error!("Failed to save registration settings: {}", e);
+                (
+                    "hx-toast-error",
+                    t.messages.error_failed_to_save_settings().to_string(),
+                )
+            }
+        };
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static(kind),
+        message
+            .parse()
+            .expect("Toast message should be a valid header value"),
+    );
+    (headers, Html(String::new())).into_response()
+}
@@ -0,0 +1,260 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, HeaderName},
+    response::{Html, IntoResponse},
+    Extension, Form,
+};
+use serde::Deserialize;
+
+use crate::app_state::AppState;
+use crate::auth::Session;
+use crate::business::webhooks::create_webhook_subscription_validated;
+use crate::i18n::TranslationContext;
+use crate::service::webhooks;
+use crate::views::{
+    layout::admin_layout,
+    pages::webhooks::{webhook_deliveries, webhook_subscriptions, webhooks_page},
+};
+
+/// Deliveries listed on the page
+const RECENT_DELIVERIES: i64 = 50;
+
+#[derive(Debug, Deserialize)]
+pub struct CreateSubscriptionForm {
+    url: String,
+    #[serde(default)]
+    on_matches: bool,
+    #[serde(default)]
+    on_score_events: bool,
+    #[serde(default)]
+    on_players: bool,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateSubscriptionForm {
+    #[serde(default)]
+    on_matches: bool,
+    #[serde(default)]
+    on_score_events: bool,
+    #[serde(default)]
+    on_players: bool,
+    #[serde(default)]
+    active: bool,
+    csrf_token: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CsrfForm {
+    csrf_token: String,
+}
+
+/// Toast header for a response
+fn toast(kind: &'static str, message: String) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static(kind),
+        message
+            .parse()
+            .expect("Toast message should be a valid header value"),
+    );
+    headers
+}
+
+/// Subscriptions section after a change, with an optional form error
+async fn render_subscriptions(
+    state: &AppState,
+    session: &Session,
+    t: &TranslationContext,
+    error: Option<&str>,
+) -> Html<String> {
+    let subscriptions = webhooks::get_webhook_subscriptions(&state.db)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::error!("Failed to load webhook subscriptions: {}", e);
+            Vec::new()
+        });
+    Html(webhook_subscriptions(session, t, &subscriptions, error).into_string())
+}
+
+/// GET /management/webhooks - Webhook subscriptions and recent deliveries
+pub async fn webhooks_get(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+) -> impl IntoResponse {
+    let subscriptions = webhooks::get_webhook_subscriptions(&state.db)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::error!("Failed to load webhook subscriptions: {}", e);
+            Vec::new()
+        });
+    let deliveries = webhooks::get_recent_deliveries(&state.db, RECENT_DELIVERIES)
+        .await
+        .unwrap_or_else(|e| {
+            tracing::error!("Failed to load webhook deliveries: {}", e);
+            Vec::new()
+        });
+
+    let content = webhooks_page(&session, &t, &subscriptions, &deliveries);
+    Html(admin_layout("Webhooks", &session, "/management", &t, content).into_string())
+}
+
+/// POST /management/webhooks - Add a subscription
+pub async fn webhook_create(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Form(form): Form<CreateSubscriptionForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    match create_webhook_subscription_validated(
+        &state.db,
+        &form.url,
+        form.on_matches,
+        form.on_score_events,
+        form.on_players,
+    )
+    .await
+    {
+        Ok(_) => (
+            toast(
+                "hx-toast-success",
+                t.messages.webhooks_created().to_string(),
+            ),
+            render_subscriptions(&state, &session, &t, None).await,
+        )
+            .into_response(),
+        Err(Ok(validation_error)) => {
+            render_subscriptions(&state, &session, &t, Some(validation_error.message()))
+                .await
+                .into_response()
+        }
+        Err(Err(e)) => {
+            tracing::error!("Failed to create webhook subscription: {}", e);
+            render_subscriptions(
+                &state,
+                &session,
+                &t,
+                Some(&t.messages.webhooks_save_failed().to_string()),
+            )
+            .await
+            .into_response()
+        }
+    }
+}
+
+/// POST /management/webhooks/:id - Change what a subscription follows
+pub async fn webhook_update(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<UpdateSubscriptionForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let headers = match webhooks::update_webhook_subscription(
+        &state.db,
+        id,
+        form.on_matches,
+        form.on_score_events,
+        form.on_players,
+        form.active,
+    )
+    .await
+    {
+        Ok(_) => toast("hx-toast-success", t.messages.webhooks_saved().to_string()),
+        Err(e) => {
+            tracing::error!("Failed to update webhook subscription {}: {}", id, e);
+            toast(
+                "hx-toast-error",
+                t.messages.webhooks_save_failed().to_string(),
+            )
+        }
+    };
+    (
+        headers,
+        render_subscriptions(&state, &session, &t, None).await,
+    )
+        .into_response()
+}
+
+/// POST /management/webhooks/:id/delete - Remove a subscription and its deliveries
+pub async fn webhook_delete(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<CsrfForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let headers = match webhooks::delete_webhook_subscription(&state.db, id).await {
+        Ok(_) => toast(
+            "hx-toast-success",
+            t.messages.webhooks_deleted().to_string(),
+        ),
+        Err(e) => {
+            tracing::error!("Failed to delete webhook subscription {}: {}", id, e);
+            toast(
+                "hx-toast-error",
+                t.messages.webhooks_save_failed().to_string(),
+            )
+        }
+    };
+    (
+        headers,
+        render_subscriptions(&state, &session, &t, None).await,
+    )
+        .into_response()
+}
+
+/// POST /management/webhooks/deliveries/:id/retry - Queue a failed delivery again
+pub async fn webhook_delivery_retry(
+    Extension(session): Extension<Session>,
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Form(form): Form<CsrfForm>,
+) -> axum::response::Response {
+    if let Err(response) = crate::auth::validate_csrf_token(&form.csrf_token, &session) {
+        return response.into_response();
+    }
+
+    let headers = match webhooks::retry_delivery(&state.db, id).await {
+        Ok(_) => {
+            state.webhooks.wake();
+            toast(
+                "hx-toast-success",
+                t.messages.webhooks_retry_queued().to_string(),
+            )
+        }
+        Err(e) => {
+            tracing::error!("Failed to retry webhook delivery {}: {}", id, e);
+            toast(
+                "hx-toast-error",
+                t.messages.webhooks_save_failed().to_string(),
+            )
+        }
+    };
+
+    let subscriptions = webhooks::get_webhook_subscriptions(&state.db)
+        .await
+        .unwrap_or_default();
+    let deliveries = webhooks::get_recent_deliveries(&state.db, RECENT_DELIVERIES)
+        .await
+        .unwrap_or_default();
+    (
+        headers,
+        Html(webhook_deliveries(&session, &t, &deliveries, &subscriptions).into_string()),
+    )
+        .into_response()
+}
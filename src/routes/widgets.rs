@@ -0,0 +1,186 @@
+//! Embeddable widgets for other sites
+//!
+//! Each widget renders as a bare HTML document for an iframe, or as JSON with
+//! `?format=json`. Any origin may fetch them (see the CORS layer in `app.rs`).
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{Html, IntoResponse, Response},
+    Extension, Json,
+};
+use maud::Markup;
+use serde::{Deserialize, Serialize};
+
+use crate::app_state::AppState;
+use crate::i18n::TranslationContext;
+use crate::service::public_site::public_season_path;
+use crate::service::season_export::{self, ScheduleMatchEntity};
+use crate::service::slugs::{self, SlugEntity};
+use crate::service::widgets::WidgetStandings;
+use crate::service::{live_scores, season_groups, seasons, standings};
+use crate::views::layout::widget_layout;
+use crate::views::pages::public_site::season_name;
+use crate::views::pages::widgets::{schedule_widget, scoreboard_widget, standings_widget};
+
+/// Cache lifetime of the season widgets, in seconds
+const SEASON_MAX_AGE: u32 = 60;
+
+/// Cache lifetime and reload interval of the scoreboard, in seconds
+const SCOREBOARD_MAX_AGE: u32 = 30;
+
+#[derive(Debug, Deserialize)]
+pub struct WidgetQuery {
+    format: Option<String>,
+}
+
+impl WidgetQuery {
+    fn json(&self) -> bool {
+        self.format.as_deref() == Some("json")
+    }
+}
+
+/// Schedule widget as JSON
+#[derive(Debug, Serialize)]
+pub struct WidgetSchedule {
+    season_id: i64,
+    season_name: String,
+    matches: Vec<ScheduleMatchEntity>,
+}
+
+/// The widget as JSON or as an HTML document, cacheable for `max_age`
+///
+/// The HTML is rendered in the visitor's language, so caches keep a copy per
+/// locale cookie and `Accept-Language`.
+fn widget_response<T: Serialize>(
+    query: &WidgetQuery,
+    max_age: u32,
+    data: T,
+    html: impl FnOnce(&T) -> Markup,
+) -> Response {
+    let cache_control = [(
+        header::CACHE_CONTROL,
+        format!("public, max-age={}", max_age),
+    )];
+    if query.json() {
+        (cache_control, Json(data)).into_response()
+    } else {
+        (
+            cache_control,
+            [(header::VARY, "Cookie, Accept-Language")],
+            Html(html(&data).into_string()),
+        )
+            .into_response()
+    }
+}
+
+fn widget_error(e: sqlx::Error) -> Response {
+    tracing::error!("Failed to load widget: {}", e);
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        [(header::CACHE_CONTROL, "no-store")],
+        "Failed to load widget",
+    )
+        .into_response()
+}
+
+/// Name of a season and the path of its public page, or the response to send instead
+async fn load_season_name(state: &AppState, season_id: i64) -> Result<(String, String), Response> {
+    let season = match seasons::get_season_by_id(&state.db, season_id).await {
+        Ok(Some(season)) => season,
+        Ok(None) => return Err((StatusCode::NOT_FOUND, "Season not found").into_response()),
+        Err(e) => return Err(widget_error(e)),
+    };
+    match slugs::get_slug(&state.db, SlugEntity::Season, season_id).await {
+        Ok(slug) => Ok((
+            season_name(&season),
+            public_season_path(season_id, slug.as_deref()),
+        )),
+        Err(e) => Err(widget_error(e)),
+    }
+}
+
+/// GET /widgets/season/:id/standings - Standings per group
+pub async fn standings_widget_get(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(season_id): Path<i64>,
+    Query(query): Query<WidgetQuery>,
+) -> Response {
+    let (name, season_path) = match load_season_name(&state, season_id).await {
+        Ok(loaded) => loaded,
+        Err(response) => return response,
+    };
+    let data = async {
+        Ok::<_, sqlx::Error>((
+            season_groups::get_season_groups(&state.db, season_id).await?,
+            standings::get_season_standings(&state.db, season_id).await?,
+        ))
+    };
+    match data.await {
+        Ok((groups, standings)) => widget_response(
+            &query,
+            SEASON_MAX_AGE,
+            WidgetStandings::new(season_id, name, &groups, &standings),
+            |standings| {
+                widget_layout(
+                    &standings.season_name,
+                    None,
+                    standings_widget(&t, standings, &season_path),
+                )
+            },
+        ),
+        Err(e) => widget_error(e),
+    }
+}
+
+/// GET /widgets/season/:id/schedule - Every match of a season
+pub async fn schedule_widget_get(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Path(season_id): Path<i64>,
+    Query(query): Query<WidgetQuery>,
+) -> Response {
+    let (name, season_path) = match load_season_name(&state, season_id).await {
+        Ok(loaded) => loaded,
+        Err(response) => return response,
+    };
+    match season_export::get_season_schedule(&state.db, season_id).await {
+        Ok(matches) => widget_response(
+            &query,
+            SEASON_MAX_AGE,
+            WidgetSchedule {
+                season_id,
+                season_name: name,
+                matches,
+            },
+            |schedule| {
+                widget_layout(
+                    &schedule.season_name,
+                    None,
+                    schedule_widget(&t, &season_path, &schedule.season_name, &schedule.matches),
+                )
+            },
+        ),
+        Err(e) => widget_error(e),
+    }
+}
+
+/// GET /widgets/scoreboard - The day's matches, reloading itself
+pub async fn scoreboard_widget_get(
+    Extension(t): Extension<TranslationContext>,
+    State(state): State<AppState>,
+    Query(query): Query<WidgetQuery>,
+) -> Response {
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    match live_scores::get_matches_on_date(&state.db, &today).await {
+        Ok(matches) => widget_response(&query, SCOREBOARD_MAX_AGE, matches, |matches| {
+            widget_layout(
+                &t.messages.widgets_scoreboard_title().to_string(),
+                Some(SCOREBOARD_MAX_AGE),
+                scoreboard_widget(&t, matches),
+            )
+        }),
+        Err(e) => widget_error(e),
+    }
+}
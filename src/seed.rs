@@ -0,0 +1,625 @@
+//! Demo dataset for local development and demos
+//!
+//! `hockey --seed` and `create_admin --seed` fill an empty database with
+//! national teams and their players, two events with three seasons, rosters
+//! and round-robin matches with goals. Everything is generated from a fixed
+//! seed, so every run produces the same dataset; only the dates of the newest
+//! season follow the current day, so it has both results and upcoming games.
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Utc};
+use sqlx::{SqliteConnection, SqlitePool};
+
+use crate::service::matches::refresh_match_result;
+use crate::service::slugs::assign_missing_slugs;
+
+/// Seed of the generator, fixed so the dataset is reproducible
+const SEED: u64 = 0x5eed_2024_4ecc_e7a1;
+
+/// A national team with the names its players are made up from
+struct TeamSeed {
+    name: &'static str,
+    short_name: &'static str,
+    /// ISO 3166-1 alpha-2 code of the country
+    iso2: &'static str,
+    city: &'static str,
+    first_names: [&'static str; 4],
+    last_names: [&'static str; 6],
+}
+
+const TEAMS: [TeamSeed; 8] = [
+    TeamSeed {
+        name: "Canada",
+        short_name: "CAN",
+        iso2: "CA",
+        city: "Toronto",
+        first_names: ["Connor", "Tyler", "Ryan", "Brayden"],
+        last_names: [
+            "Mackenzie",
+            "Tremblay",
+            "Gauthier",
+            "Fraser",
+            "Boyle",
+            "Lindell",
+        ],
+    },
+    TeamSeed {
+        name: "United States",
+        short_name: "USA",
+        iso2: "US",
+        city: "Boston",
+        first_names: ["Jack", "Matthew", "Cole", "Trevor"],
+        last_names: [
+            "Harrington",
+            "Walsh",
+            "Donovan",
+            "Keller",
+            "Brooks",
+            "Mercer",
+        ],
+    },
+    TeamSeed {
+        name: "Sweden",
+        short_name: "SWE",
+        iso2: "SE",
+        city: "Gothenburg",
+        first_names: ["Erik", "Oskar", "Lucas", "Viktor"],
+        last_names: [
+            "Lindqvist",
+            "Bergström",
+            "Håkansson",
+            "Nyberg",
+            "Sjölund",
+            "Ekman",
+        ],
+    },
+    TeamSeed {
+        name: "Finland",
+        short_name: "FIN",
+        iso2: "FI",
+        city: "Tampere",
+        first_names: ["Mikko", "Aleksi", "Juho", "Eetu"],
+        last_names: [
+            "Koivisto",
+            "Lehtinen",
+            "Salminen",
+            "Heikkilä",
+            "Rantanen",
+            "Virta",
+        ],
+    },
+    TeamSeed {
+        name: "Czechia",
+        short_name: "CZE",
+        iso2: "CZ",
+        city: "Brno",
+        first_names: ["Jakub", "Ondřej", "Lukáš", "Tomáš"],
+        last_names: [
+            "Novotný",
+            "Dvořák",
+            "Kratochvíl",
+            "Procházka",
+            "Veselý",
+            "Sýkora",
+        ],
+    },
+    TeamSeed {
+        name: "Switzerland",
+        short_name: "SUI",
+        iso2: "CH",
+        city: "Bern",
+        first_names: ["Nico", "Timo", "Luca", "Dominik"],
+        last_names: ["Brunner", "Meier", "Gerber", "Hofmann", "Baumann", "Frei"],
+    },
+    TeamSeed {
+        name: "Germany",
+        short_name: "GER",
+        iso2: "DE",
+        city: "Cologne",
+        first_names: ["Leon", "Moritz", "Tobias", "Maximilian"],
+        last_names: ["Seidel", "Krüger", "Hartmann", "Wagner", "Lorenz", "Engel"],
+    },
+    TeamSeed {
+        name: "Latvia",
+        short_name: "LAT",
+        iso2: "LV",
+        city: "Riga",
+        first_names: ["Kristers", "Rihards", "Artūrs", "Edgars"],
+        last_names: ["Ozols", "Kalniņš", "Bērziņš", "Liepa", "Zariņš", "Krūmiņš"],
+    },
+];
+
+/// Roster of every team: goalies, defensemen, forwards
+const GOALIES: usize = 2;
+const DEFENSEMEN: usize = 6;
+const FORWARDS: usize = 10;
+
+/// What was created
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SeedSummary {
+    pub teams: usize,
+    pub players: usize,
+    pub events: usize,
+    pub seasons: usize,
+    pub matches: usize,
+    pub score_events: usize,
+}
+
+/// A season to create: its teams (indexes into [`TEAMS`]) play a round robin
+/// starting on `start`, one round per day
+struct SeasonSeed {
+    year: i32,
+    display_name: Option<String>,
+    host: Option<&'static str>,
+    teams: Vec<usize>,
+    start: NaiveDate,
+}
+
+/// Seeded players of a team, by position
+struct Roster {
+    goalies: Vec<i64>,
+    skaters: Vec<i64>,
+}
+
+/// Small deterministic generator (xorshift64*)
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A number in `0..n`
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    /// A number in `low..=high`
+    fn between(&mut self, low: i64, high: i64) -> i64 {
+        low + self.below((high - low + 1) as usize) as i64
+    }
+}
+
+/// Fill an empty database with the demo dataset
+///
+/// Returns `None` without changing anything when the database already has
+/// teams, players or events, so the seed never mixes with real data.
+pub async fn seed_demo_data(db: &SqlitePool) -> Result<Option<SeedSummary>, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let existing = sqlx::query_scalar!(
+        r#"
+        SELECT (SELECT COUNT(*) FROM team) + (SELECT COUNT(*) FROM player)
+            + (SELECT COUNT(*) FROM event) as "count!: i64"
+        "#
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+    if existing > 0 {
+        return Ok(None);
+    }
+
+    let mut rng = Rng(SEED);
+    let mut summary = SeedSummary::default();
+
+    let mut team_ids = Vec::new();
+    let mut rosters = Vec::new();
+    for team in &TEAMS {
+        let country_id = country(&mut tx, team.iso2).await?;
+        let team_id = sqlx::query!(
+            "INSERT INTO team (name, short_name, country_id) VALUES (?, ?, ?)",
+            team.name,
+            team.short_name,
+            country_id
+        )
+        .execute(&mut *tx)
+        .await?
+        .last_insert_rowid();
+        summary.teams += 1;
+
+        let mut roster = Roster {
+            goalies: Vec::new(),
+            skaters: Vec::new(),
+        };
+        for index in 0..GOALIES + DEFENSEMEN + FORWARDS {
+            // Unique name pairs for up to 24 players
+            let name = format!(
+                "{} {}",
+                team.first_names[(index / 6 + index) % 4],
+                team.last_names[index % 6]
+            );
+            let position = match index {
+                i if i < GOALIES => "Goalie",
+                i if i < GOALIES + DEFENSEMEN => "Defense",
+                _ => "Forward",
+            };
+            let birth_date = format!(
+                "{}-{:02}-{:02}",
+                rng.between(1992, 2005),
+                rng.between(1, 12),
+                rng.between(1, 28)
+            );
+            let height = rng.between(175, 198);
+            let weight = rng.between(76, 102);
+            let shoots = if rng.below(3) == 0 { "Right" } else { "Left" };
+
+            let player_id = sqlx::query!(
+                r#"
+                INSERT INTO player (name, country_id, birth_date, birth_place, height_cm, weight_kg, position, shoots)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+                name,
+                country_id,
+                birth_date,
+                team.city,
+                height,
+                weight,
+                position,
+                shoots
+            )
+            .execute(&mut *tx)
+            .await?
+            .last_insert_rowid();
+            summary.players += 1;
+
+            if position == "Goalie" {
+                roster.goalies.push(player_id);
+            } else {
+                roster.skaters.push(player_id);
+            }
+        }
+
+        team_ids.push(team_id);
+        rosters.push(roster);
+    }
+
+    let today = Utc::now().date_naive();
+    let all_teams: Vec<usize> = (0..TEAMS.len()).collect();
+    let events = [
+        (
+            "World Championship",
+            "iihf",
+            vec![
+                SeasonSeed {
+                    year: 2024,
+                    display_name: Some("2024 World Championship".to_string()),
+                    host: Some("CZ"),
+                    teams: all_teams.clone(),
+                    start: NaiveDate::from_ymd_opt(2024, 5, 10).expect("Valid date"),
+                },
+                SeasonSeed {
+                    year: 2025,
+                    display_name: Some("2025 World Championship".to_string()),
+                    host: Some("SE"),
+                    teams: all_teams,
+                    start: NaiveDate::from_ymd_opt(2025, 5, 9).expect("Valid date"),
+                },
+            ],
+        ),
+        (
+            "Spring Cup",
+            "classic",
+            vec![SeasonSeed {
+                year: today.year(),
+                display_name: None,
+                host: Some("CH"),
+                teams: vec![0, 2, 4, 5],
+                // The first rounds are played, the last one is upcoming
+                start: today - Duration::days(2),
+            }],
+        ),
+    ];
+
+    let now = Utc::now().naive_utc();
+    for (event_name, point_system, seasons) in events {
+        let event_id = sqlx::query!(
+            "INSERT INTO event (name, point_system) VALUES (?, ?)",
+            event_name,
+            point_system
+        )
+        .execute(&mut *tx)
+        .await?
+        .last_insert_rowid();
+        summary.events += 1;
+
+        for season in seasons {
+            let host_id = match season.host {
+                Some(iso2) => Some(country(&mut tx, iso2).await?),
+                None => None,
+            };
+            let season_id = sqlx::query!(
+                "INSERT INTO season (year, display_name, event_id, country_id) VALUES (?, ?, ?, ?)",
+                season.year,
+                season.display_name,
+                event_id,
+                host_id
+            )
+            .execute(&mut *tx)
+            .await?
+            .last_insert_rowid();
+            summary.seasons += 1;
+
+            for &team in &season.teams {
+                roster_contracts(&mut tx, team_ids[team], season_id, event_id, &rosters[team])
+                    .await?;
+            }
+
+            for (round, pairs) in round_robin(season.teams.len()).into_iter().enumerate() {
+                let day = season.start + Duration::days(round as i64);
+                for (slot, (home, away)) in pairs.into_iter().enumerate() {
+                    let (home, away) = (season.teams[home], season.teams[away]);
+                    let kickoff = day
+                        .and_hms_opt(if slot % 2 == 0 { 16 } else { 20 }, 20, 0)
+                        .expect("Valid time");
+                    let goals = seed_match(
+                        &mut tx,
+                        &mut rng,
+                        SeedMatch {
+                            season_id,
+                            home: (team_ids[home], &rosters[home]),
+                            away: (team_ids[away], &rosters[away]),
+                            kickoff,
+                            played: kickoff < now,
+                            overtime: point_system == "iihf",
+                        },
+                    )
+                    .await?;
+                    summary.matches += 1;
+                    summary.score_events += goals;
+                }
+            }
+        }
+    }
+
+    assign_missing_slugs(&mut tx).await?;
+    tx.commit().await?;
+
+    Ok(Some(summary))
+}
+
+/// Country by ISO code, enabled so it shows up in pickers
+async fn country(conn: &mut SqliteConnection, iso2: &str) -> Result<i64, sqlx::Error> {
+    let id = sqlx::query_scalar!(
+        r#"SELECT id as "id!" FROM country WHERE iso2Code = ? ORDER BY isHistorical, id LIMIT 1"#,
+        iso2
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+
+    sqlx::query!("UPDATE country SET enabled = 1 WHERE id = ?", id)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(id)
+}
+
+/// Enter a team into a season with its whole roster
+async fn roster_contracts(
+    conn: &mut SqliteConnection,
+    team_id: i64,
+    season_id: i64,
+    event_id: i64,
+    roster: &Roster,
+) -> Result<(), sqlx::Error> {
+    let participation_id = sqlx::query!(
+        "INSERT INTO team_participation (team_id, season_id, event_id) VALUES (?, ?, ?)",
+        team_id,
+        season_id,
+        event_id
+    )
+    .execute(&mut *conn)
+    .await?
+    .last_insert_rowid();
+
+    // Goalies wear 1 and 30, skaters 2-29 (skipping the goalies' numbers)
+    let numbers = [1, 30].into_iter().chain(2..30);
+    let players = roster.goalies.iter().chain(&roster.skaters);
+    for (position, (player_id, jersey)) in players.zip(numbers).enumerate() {
+        // The first forward captains the team, the next two are alternates
+        let captaincy = match position.checked_sub(GOALIES + DEFENSEMEN) {
+            Some(0) => Some("C"),
+            Some(1 | 2) => Some("A"),
+            _ => None,
+        };
+        sqlx::query!(
+            r#"
+            INSERT INTO player_contract (player_id, team_participation_id, jersey_number, captaincy)
+            VALUES (?, ?, ?, ?)
+            "#,
+            player_id,
+            participation_id,
+            jersey,
+            captaincy
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
+struct SeedMatch<'a> {
+    season_id: i64,
+    home: (i64, &'a Roster),
+    away: (i64, &'a Roster),
+    kickoff: NaiveDateTime,
+    /// Finished with goals, or still scheduled
+    played: bool,
+    /// Ties are decided in overtime rather than left as draws
+    overtime: bool,
+}
+
+/// Create a match and, once played, its goals; returns the number of goals
+async fn seed_match(
+    conn: &mut SqliteConnection,
+    rng: &mut Rng,
+    game: SeedMatch<'_>,
+) -> Result<usize, sqlx::Error> {
+    let status = if game.played { "finished" } else { "scheduled" };
+    let match_date = game.kickoff.format("%Y-%m-%dT%H:%M").to_string();
+    let match_id = sqlx::query!(
+        "INSERT INTO match (season_id, home_team_id, away_team_id, match_date, status) VALUES (?, ?, ?, ?, ?)",
+        game.season_id,
+        game.home.0,
+        game.away.0,
+        match_date,
+        status
+    )
+    .execute(&mut *conn)
+    .await?
+    .last_insert_rowid();
+
+    if !game.played {
+        return Ok(0);
+    }
+
+    // Goals of each side in regulation, in order of time
+    let mut goals: Vec<(i64, i64, i64)> = Vec::new();
+    for side in [0, 1] {
+        for _ in 0..rng.between(0, 5) {
+            goals.push((side, rng.between(1, 3), rng.between(0, 19 * 60 + 59)));
+        }
+    }
+    let home_goals = goals.iter().filter(|(side, _, _)| *side == 0).count();
+    if game.overtime && home_goals * 2 == goals.len() {
+        goals.push((rng.between(0, 1), 4, rng.between(0, 4 * 60 + 59)));
+    }
+    goals.sort_by_key(|&(_, period, time)| (period, time));
+
+    for &(side, period, time) in &goals {
+        let (team_id, roster) = if side == 0 { game.home } else { game.away };
+        let skaters = &roster.skaters;
+        // Forwards score most of the goals
+        let scorer = if rng.below(5) == 0 {
+            skaters[rng.below(DEFENSEMEN)]
+        } else {
+            skaters[DEFENSEMEN + rng.below(FORWARDS)]
+        };
+        let mut assists = Vec::new();
+        for _ in 0..rng.below(3) {
+            let assist = skaters[rng.below(skaters.len())];
+            if assist != scorer && !assists.contains(&assist) {
+                assists.push(assist);
+            }
+        }
+        let goal_type = match rng.below(10) {
+            0..=6 => "even_strength",
+            7 | 8 => "power_play",
+            _ => "short_handed",
+        };
+        let (assist1, assist2) = (assists.first().copied(), assists.get(1).copied());
+        let (minutes, seconds) = (time / 60, time % 60);
+        sqlx::query!(
+            r#"
+            INSERT INTO score_event (match_id, team_id, scorer_id, assist1_id, assist2_id, period, time_minutes, time_seconds, goal_type)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+            match_id,
+            team_id,
+            scorer,
+            assist1,
+            assist2,
+            period,
+            minutes,
+            seconds,
+            goal_type
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    refresh_match_result(conn, match_id).await?;
+    Ok(goals.len())
+}
+
+/// Rounds of a single round robin between `teams` teams (circle method)
+///
+/// Pairs are `(home, away)` indexes; with an odd count one team rests each
+/// round.
+fn round_robin(teams: usize) -> Vec<Vec<(usize, usize)>> {
+    let slots = teams + teams % 2;
+    let mut order: Vec<usize> = (0..slots).collect();
+    let mut rounds = Vec::new();
+    for round in 0..slots.saturating_sub(1) {
+        let mut pairs = Vec::new();
+        for i in 0..slots / 2 {
+            let (a, b) = (order[i], order[slots - 1 - i]);
+            if a < teams && b < teams {
+                // Alternate home ice between rounds
+                pairs.push(if round % 2 == 0 { (a, b) } else { (b, a) });
+            }
+        }
+        rounds.push(pairs);
+        // Keep the first team in place and rotate the rest
+        order[1..].rotate_right(1);
+    }
+    rounds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_robin() {
+        for teams in [4, 5, 8] {
+            let rounds = round_robin(teams);
+            let mut pairs: Vec<(usize, usize)> = rounds
+                .iter()
+                .flatten()
+                .map(|&(a, b)| (a.min(b), a.max(b)))
+                .collect();
+            pairs.sort();
+            pairs.dedup();
+            assert_eq!(pairs.len(), teams * (teams - 1) / 2);
+
+            // Nobody plays twice in a round
+            for round in &rounds {
+                let mut playing: Vec<usize> = round.iter().flat_map(|&(a, b)| [a, b]).collect();
+                playing.sort();
+                playing.dedup();
+                assert_eq!(playing.len(), round.len() * 2);
+            }
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_seed_demo_data(pool: SqlitePool) {
+        let summary = seed_demo_data(&pool).await.unwrap().unwrap();
+        assert_eq!(summary.teams, 8);
+        assert_eq!(summary.players, 8 * 18);
+        assert_eq!(summary.events, 2);
+        assert_eq!(summary.seasons, 3);
+        // Two 8-team and one 4-team round robin
+        assert_eq!(summary.matches, 28 + 28 + 6);
+        assert!(summary.score_events > 0);
+
+        // Ties of the IIHF event go to overtime
+        let draws = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*) as "count!: i64"
+            FROM match m
+            INNER JOIN season s ON s.id = m.season_id
+            INNER JOIN event e ON e.id = s.event_id
+            WHERE e.point_system = 'iihf' AND (m.result IS NULL OR m.result = 'draw')
+            "#
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(draws, 0);
+
+        let unslugged = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!: i64" FROM player WHERE slug IS NULL"#
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(unslugged, 0);
+
+        // A second run leaves the data alone
+        assert_eq!(seed_demo_data(&pool).await.unwrap(), None);
+    }
+}
@@ -0,0 +1,226 @@
+use std::time::Duration;
+
+use axum::{
+    extract::{ConnectInfo, Request},
+    Router,
+};
+use hyper::{body::Incoming, server::conn::http1};
+use hyper_util::{
+    rt::{TokioExecutor, TokioIo, TokioTimer},
+    server::conn::auto,
+    service::TowerToHyperService,
+};
+use tokio::net::TcpListener;
+use tower::ServiceExt;
+
+/// HTTP connection settings
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    /// Serve HTTP/2 in addition to HTTP/1.1
+    ///
+    /// Plain-text connections are detected by the HTTP/2 preface (h2c with
+    /// prior knowledge), which is what TLS-terminating proxies speak to the
+    /// backend after negotiating `h2` with the browser.
+    pub http2: bool,
+    /// How long an idle connection is kept open (`None` disables keep-alive)
+    ///
+    /// For HTTP/1.1 this bounds the wait for the next request's headers; for
+    /// HTTP/2 it is the ping interval used to detect dead connections.
+    pub keep_alive: Option<Duration>,
+    /// Streams (parallel requests) allowed on one HTTP/2 connection
+    pub http2_max_concurrent_streams: u32,
+}
+
+/// How long to wait for an HTTP/2 ping acknowledgement before dropping the connection
+const HTTP2_KEEP_ALIVE_TIMEOUT: Duration = Duration::from_secs(20);
+
+impl ServerConfig {
+    /// Connection builder accepting HTTP/1.1 and, by its preface, HTTP/2
+    fn auto_builder(&self) -> auto::Builder<TokioExecutor> {
+        let mut builder = auto::Builder::new(TokioExecutor::new());
+
+        builder
+            .http1()
+            .timer(TokioTimer::new())
+            .keep_alive(self.keep_alive.is_some())
+            .header_read_timeout(self.keep_alive);
+
+        builder
+            .http2()
+            .timer(TokioTimer::new())
+            .keep_alive_interval(self.keep_alive)
+            .keep_alive_timeout(HTTP2_KEEP_ALIVE_TIMEOUT)
+            .max_concurrent_streams(self.http2_max_concurrent_streams);
+
+        builder
+    }
+
+    /// Connection builder accepting HTTP/1.1 only
+    ///
+    /// `auto::Builder::http1_only` is ignored once upgrades are enabled, so the
+    /// HTTP/1 builder is used directly.
+    fn http1_builder(&self) -> http1::Builder {
+        let mut builder = http1::Builder::new();
+        builder
+            .timer(TokioTimer::new())
+            .keep_alive(self.keep_alive.is_some())
+            .header_read_timeout(self.keep_alive);
+        builder
+    }
+}
+
+/// Serve the application on the listener until the process exits
+///
+/// Replaces `axum::serve` so connection-level settings can be tuned. Each
+/// request carries `ConnectInfo<SocketAddr>` like
+/// `into_make_service_with_connect_info` provides.
+pub async fn serve(listener: TcpListener, app: Router, config: &ServerConfig) {
+    let auto_builder = config.auto_builder();
+    let http1_builder = config.http1_builder();
+
+    loop {
+        let (stream, remote_addr) = match listener.accept().await {
+            Ok(connection) => connection,
+            Err(e) => {
+                tracing::warn!("Failed to accept connection: {}", e);
+                continue;
+            }
+        };
+
+        if let Err(e) = stream.set_nodelay(true) {
+            tracing::debug!("Failed to set TCP_NODELAY for {}: {}", remote_addr, e);
+        }
+
+        let service = app
+            .clone()
+            .map_request(move |mut request: Request<Incoming>| {
+                request.extensions_mut().insert(ConnectInfo(remote_addr));
+                request
+            });
+        let service = TowerToHyperService::new(service);
+        let io = TokioIo::new(stream);
+
+        let http2 = config.http2;
+        let auto_builder = auto_builder.clone();
+        let http1_builder = http1_builder.clone();
+
+        tokio::spawn(async move {
+            let result = if http2 {
+                auto_builder
+                    .serve_connection_with_upgrades(io, service)
+                    .await
+            } else {
+                http1_builder
+                    .serve_connection(io, service)
+                    .with_upgrades()
+                    .await
+                    .map_err(Into::into)
+            };
+
+            if let Err(e) = result {
+                tracing::debug!("Connection from {} closed with error: {}", remote_addr, e);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::routing::get;
+    use std::net::SocketAddr;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
+
+    async fn start(config: ServerConfig) -> SocketAddr {
+        let app = Router::new().route(
+            "/peer",
+            get(|ConnectInfo(addr): ConnectInfo<SocketAddr>| async move { addr.ip().to_string() }),
+        );
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { serve(listener, app, &config).await });
+        addr
+    }
+
+    fn config(http2: bool) -> ServerConfig {
+        ServerConfig {
+            http2,
+            keep_alive: Some(Duration::from_secs(5)),
+            http2_max_concurrent_streams: 100,
+        }
+    }
+
+    /// Read one HTTP/1.1 response with a `content-length` body
+    async fn read_response(stream: &mut TcpStream) -> String {
+        let mut response = Vec::new();
+        let mut buf = [0u8; 1024];
+        loop {
+            let n = stream.read(&mut buf).await.unwrap();
+            assert!(n > 0, "connection closed before the response completed");
+            response.extend_from_slice(&buf[..n]);
+            let text = String::from_utf8_lossy(&response).to_string();
+            if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                let length: usize = head
+                    .lines()
+                    .find_map(|line| {
+                        line.to_lowercase()
+                            .strip_prefix("content-length: ")
+                            .map(str::to_string)
+                    })
+                    .and_then(|value| value.trim().parse().ok())
+                    .unwrap_or(0);
+                if body.len() >= length {
+                    return text;
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_http1_keep_alive_reuses_connection() {
+        let addr = start(config(true)).await;
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+
+        for _ in 0..2 {
+            stream
+                .write_all(b"GET /peer HTTP/1.1\r\nHost: localhost\r\n\r\n")
+                .await
+                .unwrap();
+            let response = read_response(&mut stream).await;
+            assert!(response.starts_with("HTTP/1.1 200"));
+            assert!(response.ends_with("127.0.0.1"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_h2c_prior_knowledge() {
+        const PREFACE: &[u8] = b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n";
+        // Empty SETTINGS frame: length 0, type 0x4, no flags, stream 0
+        const SETTINGS: &[u8] = &[0, 0, 0, 4, 0, 0, 0, 0, 0];
+
+        let addr = start(config(true)).await;
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(PREFACE).await.unwrap();
+        stream.write_all(SETTINGS).await.unwrap();
+
+        // The server answers with its own SETTINGS frame
+        let mut header = [0u8; 9];
+        stream.read_exact(&mut header).await.unwrap();
+        assert_eq!(header[3], 0x4);
+    }
+
+    #[tokio::test]
+    async fn test_http1_only_rejects_h2c() {
+        let addr = start(config(false)).await;
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n")
+            .await
+            .unwrap();
+
+        let mut response = Vec::new();
+        let _ = stream.read_to_end(&mut response).await;
+        assert!(!response.starts_with(&[0, 0]));
+    }
+}
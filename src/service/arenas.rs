@@ -0,0 +1,377 @@
+use sqlx::{Row, SqliteConnection, SqlitePool};
+
+use crate::common::pagination::PagedResult;
+
+pub use crate::common::pagination::SortOrder;
+
+#[derive(Debug, Clone)]
+pub struct ArenaEntity {
+    pub id: i64,
+    pub name: String,
+    pub city: Option<String>,
+    pub country_id: Option<i64>,
+    pub country_name: Option<String>,
+    pub country_iso2_code: Option<String>,
+    pub capacity: Option<i64>,
+    /// Matches played at this arena
+    pub match_count: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateArenaEntity {
+    pub name: String,
+    pub city: Option<String>,
+    pub country_id: Option<i64>,
+    pub capacity: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateArenaEntity {
+    pub name: String,
+    pub city: Option<String>,
+    pub country_id: Option<i64>,
+    pub capacity: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ArenaFilters {
+    /// Matches arena name or city
+    pub name: Option<String>,
+    pub country_id: Option<i64>,
+}
+
+/// Sortable fields for arenas
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SortField {
+    Id,
+    Name,
+    City,
+    Country,
+    Capacity,
+    Matches,
+}
+
+impl SortField {
+    pub fn from_str(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "id" => Self::Id,
+            "city" => Self::City,
+            "country" => Self::Country,
+            "capacity" => Self::Capacity,
+            "matches" => Self::Matches,
+            _ => Self::Name,
+        }
+    }
+
+    pub fn to_sql(&self) -> &'static str {
+        match self {
+            Self::Id => "a.id",
+            Self::Name => "a.name",
+            Self::City => "a.city",
+            Self::Country => "c.name",
+            Self::Capacity => "a.capacity",
+            Self::Matches => "match_count",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Name => "name",
+            Self::City => "city",
+            Self::Country => "country",
+            Self::Capacity => "capacity",
+            Self::Matches => "matches",
+        }
+    }
+}
+
+/// Create a new arena
+pub async fn create_arena(db: &SqlitePool, arena: CreateArenaEntity) -> Result<i64, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO arena (name, city, country_id, capacity)
+        VALUES (?, ?, ?, ?)
+        "#,
+        arena.name,
+        arena.city,
+        arena.country_id,
+        arena.capacity
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Get arenas with filters, sorting, and pagination
+pub async fn get_arenas(
+    db: &SqlitePool,
+    filters: &ArenaFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+    page: usize,
+    page_size: usize,
+) -> Result<PagedResult<ArenaEntity>, sqlx::Error> {
+    // Build count query
+    let mut count_query = sqlx::QueryBuilder::new(
+        "SELECT COUNT(*) as count FROM arena a LEFT JOIN country c ON a.country_id = c.id WHERE 1=1",
+    );
+    apply_filters(&mut count_query, filters);
+
+    let total: i64 = count_query.build().fetch_one(db).await?.get("count");
+
+    // Build data query
+    let mut data_query = sqlx::QueryBuilder::new(
+        "SELECT a.id, a.name, a.city, a.country_id, c.name as country_name, c.iso2Code as country_iso2_code, a.capacity,
+                (SELECT COUNT(*) FROM match m WHERE m.arena_id = a.id) as match_count
+         FROM arena a
+         LEFT JOIN country c ON a.country_id = c.id
+         WHERE 1=1",
+    );
+    apply_filters(&mut data_query, filters);
+
+    // Apply sorting
+    // SECURITY: Using .push() method to safely append enum values.
+    // This prevents SQL injection as values come from trusted enum matches.
+    data_query
+        .push(" ORDER BY ")
+        .push(sort_field.to_sql())
+        .push(" ")
+        .push(sort_order.to_sql())
+        .push(", a.name ASC");
+
+    // Apply pagination
+    let offset = (page - 1) * page_size;
+    data_query.push(" LIMIT ").push_bind(page_size as i64);
+    data_query.push(" OFFSET ").push_bind(offset as i64);
+
+    let rows = data_query.build().fetch_all(db).await?;
+
+    let items = rows
+        .into_iter()
+        .map(|row| ArenaEntity {
+            id: row.get("id"),
+            name: row.get("name"),
+            city: row.get("city"),
+            country_id: row.get("country_id"),
+            country_name: row.get("country_name"),
+            country_iso2_code: row.get("country_iso2_code"),
+            capacity: row.get("capacity"),
+            match_count: row.get("match_count"),
+        })
+        .collect();
+
+    Ok(PagedResult::new(items, total as usize, page, page_size))
+}
+
+/// Get a single arena by ID
+pub async fn get_arena_by_id(db: &SqlitePool, id: i64) -> Result<Option<ArenaEntity>, sqlx::Error> {
+    let row = sqlx::query_as!(
+        ArenaEntity,
+        r#"
+        SELECT
+            a.id,
+            a.name,
+            a.city,
+            a.country_id,
+            c.name as country_name,
+            c.iso2Code as country_iso2_code,
+            a.capacity,
+            (SELECT COUNT(*) FROM match m WHERE m.arena_id = a.id) as "match_count!: i64"
+        FROM arena a
+        LEFT JOIN country c ON a.country_id = c.id
+        WHERE a.id = ?
+        "#,
+        id
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(row)
+}
+
+/// Get all arenas for dropdowns, labelled with their city when known
+pub async fn get_arenas_simple(db: &SqlitePool) -> Result<Vec<(i64, String)>, sqlx::Error> {
+    let rows = sqlx::query!("SELECT id, name, city FROM arena ORDER BY name ASC")
+        .fetch_all(db)
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| match row.city {
+            Some(city) => (row.id, format!("{} ({})", row.name, city)),
+            None => (row.id, row.name),
+        })
+        .collect())
+}
+
+/// Update an arena
+pub async fn update_arena(
+    db: &SqlitePool,
+    id: i64,
+    arena: UpdateArenaEntity,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE arena
+        SET name = ?, city = ?, country_id = ?, capacity = ?, updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        arena.name,
+        arena.city,
+        arena.country_id,
+        arena.capacity,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete an arena (matches played there are left without one)
+pub async fn delete_arena(db: &SqlitePool, id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        DELETE FROM arena
+        WHERE id = ?
+        "#,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Find an arena by name (case-insensitive), creating a bare one if missing
+///
+/// Used when importing matches that only carry a venue name.
+pub async fn find_or_create_arena(
+    conn: &mut SqliteConnection,
+    name: &str,
+) -> Result<i64, sqlx::Error> {
+    let name = name.trim();
+    let existing = sqlx::query_scalar!(
+        "SELECT id FROM arena WHERE name = ? COLLATE NOCASE ORDER BY id LIMIT 1",
+        name
+    )
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    if let Some(id) = existing {
+        return Ok(id);
+    }
+
+    let result = sqlx::query!("INSERT INTO arena (name) VALUES (?)", name)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Helper function to apply filters to a query
+fn apply_filters<'a>(
+    query_builder: &mut sqlx::QueryBuilder<'a, sqlx::Sqlite>,
+    filters: &'a ArenaFilters,
+) {
+    if let Some(name) = &filters.name {
+        query_builder
+            .push(" AND (a.name LIKE '%' || ")
+            .push_bind(name)
+            .push(" || '%' OR a.city LIKE '%' || ")
+            .push_bind(name)
+            .push(" || '%')");
+    }
+
+    if let Some(country_id) = filters.country_id {
+        query_builder
+            .push(" AND a.country_id = ")
+            .push_bind(country_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arena(name: &str, city: Option<&str>) -> CreateArenaEntity {
+        CreateArenaEntity {
+            name: name.to_string(),
+            city: city.map(str::to_string),
+            country_id: None,
+            capacity: Some(17_000),
+        }
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_arena_crud(pool: SqlitePool) {
+        let id = create_arena(&pool, arena("O2 Arena", Some("Prague")))
+            .await
+            .unwrap();
+
+        let found = get_arena_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!(found.name, "O2 Arena");
+        assert_eq!(found.capacity, Some(17_000));
+        assert_eq!(found.match_count, 0);
+
+        let updated = update_arena(
+            &pool,
+            id,
+            UpdateArenaEntity {
+                name: "O2 Arena".to_string(),
+                city: Some("Praha".to_string()),
+                country_id: None,
+                capacity: None,
+            },
+        )
+        .await
+        .unwrap();
+        assert!(updated);
+        let found = get_arena_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!(found.city.as_deref(), Some("Praha"));
+        assert!(found.capacity.is_none());
+
+        assert_eq!(
+            get_arenas_simple(&pool).await.unwrap(),
+            vec![(id, "O2 Arena (Praha)".to_string())]
+        );
+
+        assert!(delete_arena(&pool, id).await.unwrap());
+        assert!(get_arena_by_id(&pool, id).await.unwrap().is_none());
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_get_arenas_filters_by_name_or_city(pool: SqlitePool) {
+        create_arena(&pool, arena("O2 Arena", Some("Prague")))
+            .await
+            .unwrap();
+        create_arena(&pool, arena("Hartwall Arena", Some("Helsinki")))
+            .await
+            .unwrap();
+
+        let filters = ArenaFilters {
+            name: Some("helsinki".to_string()),
+            country_id: None,
+        };
+        let result = get_arenas(&pool, &filters, &SortField::Name, &SortOrder::Asc, 1, 20)
+            .await
+            .unwrap();
+        assert_eq!(result.total, 1);
+        assert_eq!(result.items[0].name, "Hartwall Arena");
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_find_or_create_arena(pool: SqlitePool) {
+        let id = create_arena(&pool, arena("O2 Arena", None)).await.unwrap();
+        let mut conn = pool.acquire().await.unwrap();
+
+        assert_eq!(
+            find_or_create_arena(&mut conn, " o2 arena ").await.unwrap(),
+            id
+        );
+        let new_id = find_or_create_arena(&mut conn, "Ice Palace").await.unwrap();
+        assert_ne!(new_id, id);
+    }
+}
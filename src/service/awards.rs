@@ -0,0 +1,319 @@
+use sqlx::SqlitePool;
+
+/// Players on the All-Star Team of a season (goalie, two defensemen, three forwards)
+pub const ALL_STAR_TEAM_SIZE: i64 = 6;
+
+/// Individual award given at the end of a season
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AwardType {
+    Mvp,
+    BestForward,
+    BestDefenseman,
+    BestGoalie,
+    AllStar,
+}
+
+impl AwardType {
+    pub const ALL: [AwardType; 5] = [
+        AwardType::Mvp,
+        AwardType::BestForward,
+        AwardType::BestDefenseman,
+        AwardType::BestGoalie,
+        AwardType::AllStar,
+    ];
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "mvp" => Some(Self::Mvp),
+            "best_forward" => Some(Self::BestForward),
+            "best_defenseman" => Some(Self::BestDefenseman),
+            "best_goalie" => Some(Self::BestGoalie),
+            "all_star" => Some(Self::AllStar),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mvp => "mvp",
+            Self::BestForward => "best_forward",
+            Self::BestDefenseman => "best_defenseman",
+            Self::BestGoalie => "best_goalie",
+            Self::AllStar => "all_star",
+        }
+    }
+
+    /// How many players a season can give the award to
+    pub fn max_winners(&self) -> i64 {
+        match self {
+            Self::AllStar => ALL_STAR_TEAM_SIZE,
+            _ => 1,
+        }
+    }
+}
+
+/// An award of a season with the winning player
+#[derive(Debug, Clone)]
+pub struct SeasonAwardEntity {
+    pub id: i64,
+    pub season_id: i64,
+    pub player_id: i64,
+    pub player_name: String,
+    pub award: String,
+}
+
+impl SeasonAwardEntity {
+    pub fn award_type(&self) -> Option<AwardType> {
+        AwardType::from_str(&self.award)
+    }
+}
+
+/// An award won by a player, with its season
+#[derive(Debug, Clone)]
+pub struct PlayerAwardEntity {
+    pub id: i64,
+    pub season_id: i64,
+    pub season_year: i64,
+    pub season_display_name: Option<String>,
+    pub event_name: String,
+    pub award: String,
+}
+
+impl PlayerAwardEntity {
+    pub fn award_type(&self) -> Option<AwardType> {
+        AwardType::from_str(&self.award)
+    }
+}
+
+/// Get the awards of a season, in award order then by player name
+pub async fn get_season_awards(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<Vec<SeasonAwardEntity>, sqlx::Error> {
+    let mut awards = sqlx::query_as!(
+        SeasonAwardEntity,
+        r#"
+        SELECT
+            sa.id as "id!",
+            sa.season_id,
+            sa.player_id,
+            p.name as player_name,
+            sa.award
+        FROM season_award sa
+        INNER JOIN player p ON sa.player_id = p.id
+        WHERE sa.season_id = ?
+        ORDER BY p.name ASC
+        "#,
+        season_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    awards.sort_by_key(|a| {
+        a.award_type()
+            .and_then(|award| AwardType::ALL.iter().position(|t| *t == award))
+    });
+
+    Ok(awards)
+}
+
+/// Get the awards a player has won, most recent season first
+pub async fn get_player_awards(
+    db: &SqlitePool,
+    player_id: i64,
+) -> Result<Vec<PlayerAwardEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        PlayerAwardEntity,
+        r#"
+        SELECT
+            sa.id as "id!",
+            sa.season_id,
+            s.year as season_year,
+            s.display_name as season_display_name,
+            e.name as event_name,
+            sa.award
+        FROM season_award sa
+        INNER JOIN season s ON sa.season_id = s.id
+        INNER JOIN event e ON s.event_id = e.id
+        WHERE sa.player_id = ?
+        ORDER BY s.year DESC, e.name ASC
+        "#,
+        player_id
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Get a single award by ID
+pub async fn get_award_by_id(
+    db: &SqlitePool,
+    id: i64,
+) -> Result<Option<SeasonAwardEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        SeasonAwardEntity,
+        r#"
+        SELECT
+            sa.id as "id!",
+            sa.season_id,
+            sa.player_id,
+            p.name as player_name,
+            sa.award
+        FROM season_award sa
+        INNER JOIN player p ON sa.player_id = p.id
+        WHERE sa.id = ?
+        "#,
+        id
+    )
+    .fetch_optional(db)
+    .await
+}
+
+/// Players on the season's rosters (for the award dropdown), labelled with their team
+pub async fn get_season_award_candidates(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<Vec<(i64, String)>, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            p.id as "id!",
+            p.name || ' (' || GROUP_CONCAT(DISTINCT t.name) || ')' as "label!: String"
+        FROM player_contract pc
+        INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+        INNER JOIN team t ON tp.team_id = t.id
+        INNER JOIN player p ON pc.player_id = p.id
+        WHERE tp.season_id = ?
+        GROUP BY p.id
+        ORDER BY p.name ASC
+        "#,
+        season_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows.into_iter().map(|row| (row.id, row.label)).collect())
+}
+
+/// Count the players holding an award in a season
+pub async fn count_award_winners(
+    db: &SqlitePool,
+    season_id: i64,
+    award: AwardType,
+) -> Result<i64, sqlx::Error> {
+    let award = award.as_str();
+    let row = sqlx::query!(
+        r#"SELECT COUNT(*) as "count!: i64" FROM season_award WHERE season_id = ? AND award = ?"#,
+        season_id,
+        award
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(row.count)
+}
+
+/// Check whether a player already holds an award in a season
+pub async fn player_has_award(
+    db: &SqlitePool,
+    season_id: i64,
+    award: AwardType,
+    player_id: i64,
+) -> Result<bool, sqlx::Error> {
+    let award = award.as_str();
+    let row = sqlx::query!(
+        r#"
+        SELECT COUNT(*) as "count!: i64"
+        FROM season_award
+        WHERE season_id = ? AND award = ? AND player_id = ?
+        "#,
+        season_id,
+        award,
+        player_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(row.count > 0)
+}
+
+/// Give an award to a player
+pub async fn create_award(
+    db: &SqlitePool,
+    season_id: i64,
+    award: AwardType,
+    player_id: i64,
+) -> Result<i64, sqlx::Error> {
+    let award = award.as_str();
+    let result = sqlx::query!(
+        "INSERT INTO season_award (season_id, award, player_id) VALUES (?, ?, ?)",
+        season_id,
+        award,
+        player_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Remove an award
+pub async fn delete_award(db: &SqlitePool, id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!("DELETE FROM season_award WHERE id = ?", id)
+        .execute(db)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_season_awards(pool: SqlitePool) {
+        sqlx::query(
+            "INSERT INTO player (id, name, country_id) VALUES (1, 'Zed Skater', 34), (2, 'Abe Goalie', 187)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO player_contract (team_participation_id, player_id) VALUES (1, 1), (2, 2)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let candidates = get_season_award_candidates(&pool, 1).await.unwrap();
+        assert_eq!(candidates[0], (2, "Abe Goalie (Team USA)".to_string()));
+
+        create_award(&pool, 1, AwardType::AllStar, 2).await.unwrap();
+        create_award(&pool, 1, AwardType::AllStar, 1).await.unwrap();
+        let mvp = create_award(&pool, 1, AwardType::Mvp, 1).await.unwrap();
+
+        // MVP first, then the All-Star Team by name
+        let awards = get_season_awards(&pool, 1).await.unwrap();
+        assert_eq!(awards.len(), 3);
+        assert_eq!(awards[0].award_type(), Some(AwardType::Mvp));
+        assert_eq!(awards[1].player_name, "Abe Goalie");
+
+        assert_eq!(
+            count_award_winners(&pool, 1, AwardType::AllStar)
+                .await
+                .unwrap(),
+            2
+        );
+        assert!(player_has_award(&pool, 1, AwardType::Mvp, 1).await.unwrap());
+        assert!(!player_has_award(&pool, 1, AwardType::Mvp, 2).await.unwrap());
+
+        let player_awards = get_player_awards(&pool, 1).await.unwrap();
+        assert_eq!(player_awards.len(), 2);
+        assert_eq!(player_awards[0].event_name, "Winter Olympics");
+
+        assert!(delete_award(&pool, mvp).await.unwrap());
+        assert!(get_award_by_id(&pool, mvp).await.unwrap().is_none());
+    }
+}
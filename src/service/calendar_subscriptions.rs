@@ -0,0 +1,244 @@
+use sqlx::SqlitePool;
+
+/// A tokenized calendar feed of one team's matches
+#[derive(Debug, Clone)]
+pub struct CalendarSubscriptionEntity {
+    pub id: i64,
+    #[allow(dead_code)]
+    pub team_id: i64,
+    pub token: String,
+    pub label: Option<String>,
+    pub created_at: String,
+    pub last_used_at: Option<String>,
+}
+
+/// A dated match as it appears in a calendar feed
+#[derive(Debug, Clone)]
+pub struct CalendarMatchEntity {
+    pub id: i64,
+    pub match_date: String,
+    pub status: String,
+    pub home_team_name: String,
+    pub away_team_name: String,
+    pub home_score: i64,
+    pub away_score: i64,
+    pub event_name: String,
+    pub season_name: String,
+    pub arena_name: Option<String>,
+    pub arena_city: Option<String>,
+    pub updated_at: String,
+}
+
+/// Get the calendar subscriptions of a team, newest first
+pub async fn get_team_subscriptions(
+    db: &SqlitePool,
+    team_id: i64,
+) -> Result<Vec<CalendarSubscriptionEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        CalendarSubscriptionEntity,
+        r#"
+        SELECT id as "id!", team_id, token, label, created_at, last_used_at
+        FROM team_calendar_subscription
+        WHERE team_id = ?
+        ORDER BY created_at DESC, id DESC
+        "#,
+        team_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Create a calendar subscription with a fresh random token
+pub async fn create_subscription(
+    db: &SqlitePool,
+    team_id: i64,
+    label: Option<&str>,
+) -> Result<i64, sqlx::Error> {
+    let token = uuid::Uuid::new_v4().simple().to_string();
+    let result = sqlx::query!(
+        "INSERT INTO team_calendar_subscription (team_id, token, label) VALUES (?, ?, ?)",
+        team_id,
+        token,
+        label
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Revoke (delete) a subscription of a team; its feed URL stops working
+pub async fn revoke_subscription(
+    db: &SqlitePool,
+    team_id: i64,
+    subscription_id: i64,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        "DELETE FROM team_calendar_subscription WHERE id = ? AND team_id = ?",
+        subscription_id,
+        team_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Resolve a feed token to its team (ID and name) and record the access
+pub async fn use_subscription_token(
+    db: &SqlitePool,
+    token: &str,
+) -> Result<Option<(i64, String)>, sqlx::Error> {
+    let row = sqlx::query!(
+        r#"
+        SELECT t.id as "team_id!", t.name as team_name
+        FROM team_calendar_subscription tcs
+        INNER JOIN team t ON tcs.team_id = t.id
+        WHERE tcs.token = ?
+        "#,
+        token
+    )
+    .fetch_optional(db)
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(None);
+    };
+
+    sqlx::query!(
+        "UPDATE team_calendar_subscription SET last_used_at = CURRENT_TIMESTAMP WHERE token = ?",
+        token
+    )
+    .execute(db)
+    .await?;
+
+    Ok(Some((row.team_id, row.team_name)))
+}
+
+/// Get all dated matches of a team across seasons, oldest first
+///
+/// Scores include unidentified goals.
+pub async fn get_team_calendar_matches(
+    db: &SqlitePool,
+    team_id: i64,
+) -> Result<Vec<CalendarMatchEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        CalendarMatchEntity,
+        r#"
+        SELECT
+            m.id as "id!",
+            m.match_date as "match_date!",
+            m.status,
+            ht.name as home_team_name,
+            at.name as away_team_name,
+            m.home_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id) as "home_score!: i64",
+            m.away_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.away_team_id) as "away_score!: i64",
+            e.name as event_name,
+            COALESCE(s.display_name, CAST(s.year AS TEXT)) as "season_name!: String",
+            ar.name as "arena_name?",
+            ar.city as "arena_city?",
+            m.updated_at
+        FROM match m
+        INNER JOIN team ht ON m.home_team_id = ht.id
+        INNER JOIN team at ON m.away_team_id = at.id
+        INNER JOIN season s ON m.season_id = s.id
+        INNER JOIN event e ON s.event_id = e.id
+        LEFT JOIN arena ar ON m.arena_id = ar.id
+        WHERE (m.home_team_id = ?1 OR m.away_team_id = ?1) AND m.match_date IS NOT NULL
+        ORDER BY m.match_date ASC, m.id ASC
+        "#,
+        team_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{create_match, CreateMatchEntity};
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_subscription_lifecycle(pool: SqlitePool) {
+        let id = create_subscription(&pool, 1, Some("Fan club"))
+            .await
+            .unwrap();
+        create_subscription(&pool, 1, None).await.unwrap();
+
+        let subscriptions = get_team_subscriptions(&pool, 1).await.unwrap();
+        assert_eq!(subscriptions.len(), 2);
+        assert_ne!(subscriptions[0].token, subscriptions[1].token);
+        let token = subscriptions
+            .iter()
+            .find(|s| s.id == id)
+            .unwrap()
+            .token
+            .clone();
+        assert_eq!(token.len(), 32);
+
+        let (team_id, _) = use_subscription_token(&pool, &token)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(team_id, 1);
+        let used = get_team_subscriptions(&pool, 1).await.unwrap();
+        assert!(used
+            .iter()
+            .find(|s| s.id == id)
+            .unwrap()
+            .last_used_at
+            .is_some());
+
+        // Another team cannot revoke the subscription
+        assert!(!revoke_subscription(&pool, 2, id).await.unwrap());
+        assert!(revoke_subscription(&pool, 1, id).await.unwrap());
+        assert!(use_subscription_token(&pool, &token)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_team_calendar_matches(pool: SqlitePool) {
+        for match_date in [Some("2024-02-01T19:30"), None, Some("2024-01-15")] {
+            create_match(
+                &pool,
+                CreateMatchEntity {
+                    season_id: 1,
+                    home_team_id: 1,
+                    away_team_id: 2,
+                    home_score_unidentified: 2,
+                    away_score_unidentified: 1,
+                    match_date: match_date.map(str::to_string),
+                    status: "finished".to_string(),
+                    arena_id: None,
+                    attendance: None,
+                    game_type: "group".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        }
+
+        // Undated matches cannot be placed on a calendar
+        let matches = get_team_calendar_matches(&pool, 2).await.unwrap();
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[0].match_date, "2024-01-15");
+        assert_eq!((matches[0].home_score, matches[0].away_score), (2, 1));
+
+        assert!(get_team_calendar_matches(&pool, 3)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
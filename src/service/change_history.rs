@@ -0,0 +1,386 @@
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+
+use crate::service::matches::{MatchEntity, ScoreEventEntity};
+use crate::service::players::PlayerEntity;
+
+/// Kind of record a history entry belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryEntityType {
+    Match,
+    ScoreEvent,
+    Player,
+}
+
+impl HistoryEntityType {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "match" => Some(Self::Match),
+            "score_event" => Some(Self::ScoreEvent),
+            "player" => Some(Self::Player),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Match => "match",
+            Self::ScoreEvent => "score_event",
+            Self::Player => "player",
+        }
+    }
+}
+
+/// What happened to the record
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryAction {
+    Create,
+    Update,
+    Delete,
+}
+
+impl HistoryAction {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "create" => Some(Self::Create),
+            "update" => Some(Self::Update),
+            "delete" => Some(Self::Delete),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Delete => "delete",
+        }
+    }
+}
+
+/// One changed field; `None` means the field was empty (or the record did
+/// not exist yet / any more)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// Display values of a record's tracked fields, in display order
+pub type Snapshot = Vec<(&'static str, Option<String>)>;
+
+/// Tracked fields of a match; teams, season and arena by name
+pub fn match_snapshot(m: &MatchEntity) -> Snapshot {
+    vec![
+        ("season", m.season_name.clone()),
+        ("home_team", Some(m.home_team_name.clone())),
+        ("away_team", Some(m.away_team_name.clone())),
+        (
+            "home_score_unidentified",
+            Some(m.home_score_unidentified.to_string()),
+        ),
+        (
+            "away_score_unidentified",
+            Some(m.away_score_unidentified.to_string()),
+        ),
+        ("match_date", m.match_date.clone()),
+        ("status", Some(m.status.clone())),
+        ("game_type", Some(m.game_type.clone())),
+        ("arena", m.arena_name.clone()),
+        ("attendance", m.attendance.map(|a| a.to_string())),
+    ]
+}
+
+/// Tracked fields of a goal; players by name, time as `mm:ss`
+pub fn score_event_snapshot(e: &ScoreEventEntity) -> Snapshot {
+    let time = e
+        .time_minutes
+        .map(|minutes| format!("{:02}:{:02}", minutes, e.time_seconds.unwrap_or_default()));
+
+    vec![
+        ("team", Some(e.team_name.clone())),
+        ("scorer", e.scorer_name.clone()),
+        ("assist1", e.assist1_name.clone()),
+        ("assist2", e.assist2_name.clone()),
+        ("period", Some(e.period.to_string())),
+        ("time", time),
+        ("goal_type", e.goal_type.clone()),
+        ("video", e.video_link()),
+    ]
+}
+
+/// Tracked fields of a player
+pub fn player_snapshot(p: &PlayerEntity) -> Snapshot {
+    vec![
+        ("name", Some(p.name.clone())),
+        ("country", Some(p.country_name.clone())),
+        ("birth_date", p.birth_date.clone()),
+        ("birth_place", p.birth_place.clone()),
+        ("height_cm", p.height_cm.map(|h| h.to_string())),
+        ("weight_kg", p.weight_kg.map(|w| w.to_string())),
+        ("position", p.position.clone()),
+        ("shoots", p.shoots.clone()),
+        ("photo", p.photo_path.clone()),
+    ]
+}
+
+/// Fields that differ between two snapshots of the same kind of record
+///
+/// Pass `None` as `old` for a created record and as `new` for a deleted one;
+/// empty fields are then left out.
+pub fn diff_snapshots(old: Option<&Snapshot>, new: Option<&Snapshot>) -> Vec<FieldChange> {
+    let fields = old.or(new).map(|s| s.as_slice()).unwrap_or_default();
+    let value = |snapshot: Option<&Snapshot>, index: usize| {
+        snapshot
+            .and_then(|s| s.get(index))
+            .and_then(|(_, v)| v.clone())
+    };
+
+    fields
+        .iter()
+        .enumerate()
+        .filter_map(|(index, (field, _))| {
+            let (old, new) = (value(old, index), value(new, index));
+            (old != new).then(|| FieldChange {
+                field: field.to_string(),
+                old,
+                new,
+            })
+        })
+        .collect()
+}
+
+/// A change to be written to the history
+#[derive(Debug, Clone)]
+pub struct NewChangeEntity {
+    pub entity_type: HistoryEntityType,
+    pub entity_id: i64,
+    /// Match the record belongs to (the match itself, or a goal's match)
+    pub match_id: Option<i64>,
+    pub user_id: Option<i64>,
+    pub action: HistoryAction,
+    pub changes: Vec<FieldChange>,
+}
+
+/// A stored history entry with the name of the user who made the change
+#[derive(Debug, Clone)]
+pub struct ChangeHistoryEntity {
+    pub id: i64,
+    pub entity_type: String,
+    pub entity_id: i64,
+    pub user_name: Option<String>,
+    pub action: String,
+    pub changes: Vec<FieldChange>,
+    pub created_at: String,
+}
+
+impl ChangeHistoryEntity {
+    pub fn entity_type(&self) -> Option<HistoryEntityType> {
+        HistoryEntityType::from_str(&self.entity_type)
+    }
+
+    pub fn action(&self) -> Option<HistoryAction> {
+        HistoryAction::from_str(&self.action)
+    }
+}
+
+/// Write a change to the history
+///
+/// Updates that changed no tracked field are not recorded; returns whether
+/// an entry was written.
+pub async fn record_change(db: &SqlitePool, change: NewChangeEntity) -> Result<bool, sqlx::Error> {
+    if change.action == HistoryAction::Update && change.changes.is_empty() {
+        return Ok(false);
+    }
+
+    let entity_type = change.entity_type.as_str();
+    let action = change.action.as_str();
+    let changes = serde_json::to_string(&change.changes).unwrap_or_else(|_| "[]".to_string());
+
+    sqlx::query!(
+        r#"
+        INSERT INTO change_history (entity_type, entity_id, match_id, user_id, action, changes)
+        VALUES (?, ?, ?, ?, ?, ?)
+        "#,
+        entity_type,
+        change.entity_id,
+        change.match_id,
+        change.user_id,
+        action,
+        changes
+    )
+    .execute(db)
+    .await?;
+
+    Ok(true)
+}
+
+/// History of a match and its goals, newest first
+pub async fn get_match_history(
+    db: &SqlitePool,
+    match_id: i64,
+) -> Result<Vec<ChangeHistoryEntity>, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            h.id as "id!",
+            h.entity_type,
+            h.entity_id,
+            u.name as "user_name?",
+            h.action,
+            h.changes,
+            h.created_at
+        FROM change_history h
+        LEFT JOIN users u ON h.user_id = u.id
+        WHERE h.match_id = ?
+        ORDER BY h.created_at DESC, h.id DESC
+        "#,
+        match_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| ChangeHistoryEntity {
+            id: row.id,
+            entity_type: row.entity_type,
+            entity_id: row.entity_id,
+            user_name: row.user_name,
+            action: row.action,
+            changes: serde_json::from_str(&row.changes).unwrap_or_default(),
+            created_at: row.created_at,
+        })
+        .collect())
+}
+
+/// History of a player, newest first
+pub async fn get_player_history(
+    db: &SqlitePool,
+    player_id: i64,
+) -> Result<Vec<ChangeHistoryEntity>, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            h.id as "id!",
+            h.entity_type,
+            h.entity_id,
+            u.name as "user_name?",
+            h.action,
+            h.changes,
+            h.created_at
+        FROM change_history h
+        LEFT JOIN users u ON h.user_id = u.id
+        WHERE h.entity_type = 'player' AND h.entity_id = ?
+        ORDER BY h.created_at DESC, h.id DESC
+        "#,
+        player_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| ChangeHistoryEntity {
+            id: row.id,
+            entity_type: row.entity_type,
+            entity_id: row.entity_id,
+            user_name: row.user_name,
+            action: row.action,
+            changes: serde_json::from_str(&row.changes).unwrap_or_default(),
+            created_at: row.created_at,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{create_match, get_match_by_id, CreateMatchEntity};
+
+    #[test]
+    fn test_diff_snapshots() {
+        let old: Snapshot = vec![("name", Some("Jágr".to_string())), ("position", None)];
+        let new: Snapshot = vec![
+            ("name", Some("Jaromír Jágr".to_string())),
+            ("position", None),
+        ];
+
+        assert_eq!(
+            diff_snapshots(Some(&old), Some(&new)),
+            vec![FieldChange {
+                field: "name".to_string(),
+                old: Some("Jágr".to_string()),
+                new: Some("Jaromír Jágr".to_string()),
+            }]
+        );
+        assert!(diff_snapshots(Some(&old), Some(&old)).is_empty());
+        // Created records list their non-empty fields only
+        assert_eq!(diff_snapshots(None, Some(&new)).len(), 1);
+        assert_eq!(
+            diff_snapshots(Some(&old), None)[0].old.as_deref(),
+            Some("Jágr")
+        );
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_match_history(pool: SqlitePool) {
+        let match_id = create_match(
+            &pool,
+            CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: 0,
+                away_score_unidentified: 0,
+                match_date: None,
+                status: "scheduled".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        let before = get_match_by_id(&pool, match_id).await.unwrap().unwrap();
+        let mut after = before.clone();
+        after.status = "finished".to_string();
+
+        let change = |changes| NewChangeEntity {
+            entity_type: HistoryEntityType::Match,
+            entity_id: match_id,
+            match_id: Some(match_id),
+            user_id: None,
+            action: HistoryAction::Update,
+            changes,
+        };
+        let changes = diff_snapshots(
+            Some(&match_snapshot(&before)),
+            Some(&match_snapshot(&after)),
+        );
+        assert!(record_change(&pool, change(changes)).await.unwrap());
+        // Nothing changed, nothing recorded
+        assert!(!record_change(&pool, change(Vec::new())).await.unwrap());
+
+        let history = get_match_history(&pool, match_id).await.unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].action(), Some(HistoryAction::Update));
+        assert_eq!(history[0].user_name, None);
+        assert_eq!(
+            history[0].changes,
+            vec![FieldChange {
+                field: "status".to_string(),
+                old: Some("scheduled".to_string()),
+                new: Some("finished".to_string()),
+            }]
+        );
+        assert!(get_player_history(&pool, match_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
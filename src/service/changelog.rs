@@ -0,0 +1,239 @@
+use sqlx::SqlitePool;
+
+/// Entry of the in-app "What's new" panel
+#[derive(Debug, Clone)]
+pub struct ChangelogEntryEntity {
+    pub id: i64,
+    pub version: String,
+    pub title: String,
+    pub body: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateChangelogEntryEntity {
+    pub version: String,
+    pub title: String,
+    pub body: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateChangelogEntryEntity {
+    pub version: String,
+    pub title: String,
+    pub body: String,
+}
+
+/// Get all changelog entries, newest first
+pub async fn get_changelog_entries(
+    db: &SqlitePool,
+) -> Result<Vec<ChangelogEntryEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        ChangelogEntryEntity,
+        r#"
+        SELECT id as "id!", version, title, body, created_at
+        FROM changelog_entry
+        ORDER BY id DESC
+        "#
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Get a changelog entry by ID
+pub async fn get_changelog_entry_by_id(
+    db: &SqlitePool,
+    id: i64,
+) -> Result<Option<ChangelogEntryEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        ChangelogEntryEntity,
+        r#"
+        SELECT id as "id!", version, title, body, created_at
+        FROM changelog_entry
+        WHERE id = ?
+        "#,
+        id
+    )
+    .fetch_optional(db)
+    .await
+}
+
+/// Entries a user has not dismissed yet, newest first
+///
+/// Entries written before the user's account was created are skipped, so new
+/// users are not greeted with the whole history.
+pub async fn get_unseen_changelog_entries(
+    db: &SqlitePool,
+    user_id: i64,
+    limit: i64,
+) -> Result<Vec<ChangelogEntryEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        ChangelogEntryEntity,
+        r#"
+        SELECT ce.id as "id!", ce.version, ce.title, ce.body, ce.created_at
+        FROM changelog_entry ce
+        INNER JOIN users u ON u.id = ?
+        WHERE ce.id > u.changelog_seen_id AND ce.created_at >= u.created_at
+        ORDER BY ce.id DESC
+        LIMIT ?
+        "#,
+        user_id,
+        limit
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Mark every current entry as seen by a user
+pub async fn mark_changelog_seen(db: &SqlitePool, user_id: i64) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE users
+        SET changelog_seen_id = (SELECT COALESCE(MAX(id), 0) FROM changelog_entry)
+        WHERE id = ?
+        "#,
+        user_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+/// Create a changelog entry
+pub async fn create_changelog_entry(
+    db: &SqlitePool,
+    entity: CreateChangelogEntryEntity,
+) -> Result<i64, sqlx::Error> {
+    let result = sqlx::query!(
+        "INSERT INTO changelog_entry (version, title, body) VALUES (?, ?, ?)",
+        entity.version,
+        entity.title,
+        entity.body
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Update a changelog entry, returning whether it existed
+///
+/// Edits do not bring the entry back for users who already dismissed it.
+pub async fn update_changelog_entry(
+    db: &SqlitePool,
+    id: i64,
+    entity: UpdateChangelogEntryEntity,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE changelog_entry
+        SET version = ?, title = ?, body = ?, updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        entity.version,
+        entity.title,
+        entity.body,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete a changelog entry, returning whether it existed
+pub async fn delete_changelog_entry(db: &SqlitePool, id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!("DELETE FROM changelog_entry WHERE id = ?", id)
+        .execute(db)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(version: &str, title: &str) -> CreateChangelogEntryEntity {
+        CreateChangelogEntryEntity {
+            version: version.to_string(),
+            title: title.to_string(),
+            body: "Details".to_string(),
+        }
+    }
+
+    async fn unseen_titles(pool: &SqlitePool, user_id: i64) -> Vec<String> {
+        get_unseen_changelog_entries(pool, user_id, 10)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|e| e.title)
+            .collect()
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_changelog_entries(pool: SqlitePool) {
+        let id = create_changelog_entry(&pool, entry("0.1.0", "Slugs"))
+            .await
+            .unwrap();
+        assert!(update_changelog_entry(
+            &pool,
+            id,
+            UpdateChangelogEntryEntity {
+                version: "0.2.0".to_string(),
+                title: "URL slugs".to_string(),
+                body: "Teams have readable addresses".to_string(),
+            },
+        )
+        .await
+        .unwrap());
+
+        let updated = get_changelog_entry_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!(updated.version, "0.2.0");
+        assert_eq!(updated.title, "URL slugs");
+
+        assert!(delete_changelog_entry(&pool, id).await.unwrap());
+        assert!(!delete_changelog_entry(&pool, id).await.unwrap());
+        assert!(get_changelog_entries(&pool).await.unwrap().is_empty());
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_unseen_changelog_entries(pool: SqlitePool) {
+        let user_id = sqlx::query(
+            "INSERT INTO users (email, name, password_hash, created_at)
+             VALUES ('a@b.c', 'A', 'x', '2026-01-01 00:00:00')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap()
+        .last_insert_rowid();
+        let old = create_changelog_entry(&pool, entry("0.1.0", "Before account"))
+            .await
+            .unwrap();
+        sqlx::query("UPDATE changelog_entry SET created_at = '2025-12-31 00:00:00' WHERE id = ?")
+            .bind(old)
+            .execute(&pool)
+            .await
+            .unwrap();
+        create_changelog_entry(&pool, entry("0.2.0", "Slugs"))
+            .await
+            .unwrap();
+        create_changelog_entry(&pool, entry("0.2.0", "Goal flags"))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            unseen_titles(&pool, user_id).await,
+            vec!["Goal flags", "Slugs"]
+        );
+
+        mark_changelog_seen(&pool, user_id).await.unwrap();
+        assert!(unseen_titles(&pool, user_id).await.is_empty());
+
+        create_changelog_entry(&pool, entry("0.3.0", "Report builder"))
+            .await
+            .unwrap();
+        assert_eq!(unseen_titles(&pool, user_id).await, vec!["Report builder"]);
+    }
+}
@@ -0,0 +1,248 @@
+use sqlx::SqlitePool;
+
+/// Chat service a webhook posts to; decides the payload format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatWebhookKind {
+    Slack,
+    Discord,
+}
+
+impl ChatWebhookKind {
+    pub const ALL: [ChatWebhookKind; 2] = [ChatWebhookKind::Slack, ChatWebhookKind::Discord];
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "slack" => Some(Self::Slack),
+            "discord" => Some(Self::Discord),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Slack => "slack",
+            Self::Discord => "discord",
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Slack => "Slack",
+            Self::Discord => "Discord",
+        }
+    }
+}
+
+/// What happened, to pick the webhooks that want to hear about it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatNotification {
+    /// A match was marked finished
+    MatchResult,
+    /// A player reached a milestone
+    Milestone,
+}
+
+#[derive(Debug, Clone)]
+pub struct ChatWebhookEntity {
+    pub id: i64,
+    pub name: String,
+    pub kind: String,
+    /// Contains the secret token of the webhook, never shown in full
+    pub url: String,
+    pub notify_results: bool,
+    pub notify_milestones: bool,
+    pub created_at: String,
+}
+
+impl ChatWebhookEntity {
+    pub fn kind(&self) -> Option<ChatWebhookKind> {
+        ChatWebhookKind::from_str(&self.kind)
+    }
+
+    /// Scheme and host only, e.g. `https://hooks.slack.com/…`
+    pub fn masked_url(&self) -> String {
+        let host_end = self
+            .url
+            .find("://")
+            .map(|scheme_end| {
+                let host_start = scheme_end + 3;
+                self.url[host_start..]
+                    .find('/')
+                    .map_or(self.url.len(), |i| host_start + i)
+            })
+            .unwrap_or(0);
+        format!("{}/…", &self.url[..host_end])
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateChatWebhookEntity {
+    pub name: String,
+    pub kind: ChatWebhookKind,
+    pub url: String,
+    pub notify_results: bool,
+    pub notify_milestones: bool,
+}
+
+/// All webhooks in creation order
+pub async fn get_chat_webhooks(db: &SqlitePool) -> Result<Vec<ChatWebhookEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        ChatWebhookEntity,
+        r#"
+        SELECT
+            id as "id!",
+            name,
+            kind,
+            url,
+            notify_results as "notify_results: bool",
+            notify_milestones as "notify_milestones: bool",
+            created_at
+        FROM chat_webhook
+        ORDER BY id
+        "#
+    )
+    .fetch_all(db)
+    .await
+}
+
+pub async fn get_chat_webhook_by_id(
+    db: &SqlitePool,
+    id: i64,
+) -> Result<Option<ChatWebhookEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        ChatWebhookEntity,
+        r#"
+        SELECT
+            id as "id!",
+            name,
+            kind,
+            url,
+            notify_results as "notify_results: bool",
+            notify_milestones as "notify_milestones: bool",
+            created_at
+        FROM chat_webhook
+        WHERE id = ?
+        "#,
+        id
+    )
+    .fetch_optional(db)
+    .await
+}
+
+/// Webhooks that receive a kind of notification
+pub async fn get_chat_webhooks_for(
+    db: &SqlitePool,
+    notification: ChatNotification,
+) -> Result<Vec<ChatWebhookEntity>, sqlx::Error> {
+    Ok(get_chat_webhooks(db)
+        .await?
+        .into_iter()
+        .filter(|webhook| match notification {
+            ChatNotification::MatchResult => webhook.notify_results,
+            ChatNotification::Milestone => webhook.notify_milestones,
+        })
+        .collect())
+}
+
+pub async fn create_chat_webhook(
+    db: &SqlitePool,
+    webhook: &CreateChatWebhookEntity,
+) -> Result<i64, sqlx::Error> {
+    let kind = webhook.kind.as_str();
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO chat_webhook (name, kind, url, notify_results, notify_milestones)
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+        webhook.name,
+        kind,
+        webhook.url,
+        webhook.notify_results,
+        webhook.notify_milestones
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Choose which notifications a webhook receives
+pub async fn update_chat_webhook_notifications(
+    db: &SqlitePool,
+    id: i64,
+    notify_results: bool,
+    notify_milestones: bool,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE chat_webhook
+        SET notify_results = ?, notify_milestones = ?, updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        notify_results,
+        notify_milestones,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn delete_chat_webhook(db: &SqlitePool, id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!("DELETE FROM chat_webhook WHERE id = ?", id)
+        .execute(db)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_chat_webhooks(pool: SqlitePool) {
+        let id = create_chat_webhook(
+            &pool,
+            &CreateChatWebhookEntity {
+                name: "Results channel".to_string(),
+                kind: ChatWebhookKind::Discord,
+                url: "https://discord.com/api/webhooks/1/secret".to_string(),
+                notify_results: true,
+                notify_milestones: false,
+            },
+        )
+        .await
+        .unwrap();
+
+        let webhook = get_chat_webhook_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!(webhook.kind(), Some(ChatWebhookKind::Discord));
+        assert_eq!(webhook.masked_url(), "https://discord.com/…");
+        assert_eq!(
+            get_chat_webhooks_for(&pool, ChatNotification::MatchResult)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(get_chat_webhooks_for(&pool, ChatNotification::Milestone)
+            .await
+            .unwrap()
+            .is_empty());
+
+        assert!(update_chat_webhook_notifications(&pool, id, false, true)
+            .await
+            .unwrap());
+        assert!(get_chat_webhooks_for(&pool, ChatNotification::MatchResult)
+            .await
+            .unwrap()
+            .is_empty());
+
+        assert!(delete_chat_webhook(&pool, id).await.unwrap());
+        assert!(get_chat_webhooks(&pool).await.unwrap().is_empty());
+        assert!(!update_chat_webhook_notifications(&pool, id, true, true)
+            .await
+            .unwrap());
+    }
+}
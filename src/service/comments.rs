@@ -0,0 +1,373 @@
+use sqlx::SqlitePool;
+
+/// Kind of record a comment is attached to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentEntityType {
+    Match,
+    Player,
+    Season,
+}
+
+impl CommentEntityType {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "match" => Some(Self::Match),
+            "player" => Some(Self::Player),
+            "season" => Some(Self::Season),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Match => "match",
+            Self::Player => "player",
+            Self::Season => "season",
+        }
+    }
+
+    /// Detail page of a record of this kind
+    pub fn url(&self, id: i64) -> String {
+        match self {
+            Self::Match => format!("/matches/{}", id),
+            Self::Player => format!("/players/{}", id),
+            Self::Season => format!("/seasons/{}", id),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CommentEntity {
+    pub id: i64,
+    pub parent_id: Option<i64>,
+    pub author_id: Option<i64>,
+    /// `None` when the author's account was deleted
+    pub author_name: Option<String>,
+    /// Markdown source
+    pub body: String,
+    pub created_at: String,
+}
+
+/// A top-level comment with its replies, oldest first
+#[derive(Debug, Clone)]
+pub struct CommentThread {
+    pub comment: CommentEntity,
+    pub replies: Vec<CommentEntity>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateCommentEntity {
+    pub entity_type: CommentEntityType,
+    pub entity_id: i64,
+    /// Comment being replied to; replies to replies join the same thread
+    pub parent_id: Option<i64>,
+    pub author_id: i64,
+    pub body: String,
+}
+
+/// Result of posting a comment
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedComment {
+    pub id: i64,
+    /// Number of users notified about a mention
+    pub notified: usize,
+}
+
+/// Mention handles (`@jane.doe` or `@jane.doe@example.com`) in a comment, lowercased
+///
+/// An `@` only starts a mention at the start of the text or after a character
+/// that cannot be part of an email address, so addresses written out in the
+/// text are not mistaken for mentions.
+pub fn parse_mentions(body: &str) -> Vec<String> {
+    fn is_handle_char(c: char) -> bool {
+        c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '%' | '+' | '-' | '@')
+    }
+
+    let mut mentions: Vec<String> = Vec::new();
+    let mut previous: Option<char> = None;
+    let mut chars = body.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let starts_mention = c == '@' && !previous.is_some_and(is_handle_char);
+        previous = Some(c);
+        if !starts_mention {
+            continue;
+        }
+
+        let mut end = start + 1;
+        while let Some(&(i, next)) = chars.peek() {
+            if !is_handle_char(next) {
+                break;
+            }
+            end = i + next.len_utf8();
+            previous = Some(next);
+            chars.next();
+        }
+
+        // Sentence punctuation right after a handle is not part of it
+        let handle = body[start + 1..end]
+            .trim_end_matches(['.', '-', '@'])
+            .to_lowercase();
+        if !handle.is_empty() && !mentions.contains(&handle) {
+            mentions.push(handle);
+        }
+    }
+    mentions
+}
+
+/// Comment threads of a record, oldest first
+pub async fn get_comment_threads(
+    db: &SqlitePool,
+    entity_type: CommentEntityType,
+    entity_id: i64,
+) -> Result<Vec<CommentThread>, sqlx::Error> {
+    let entity_type = entity_type.as_str();
+    let comments = sqlx::query_as!(
+        CommentEntity,
+        r#"
+        SELECT
+            c.id as "id!",
+            c.parent_id,
+            c.author_id,
+            COALESCE(u.name, u.email) as "author_name: String",
+            c.body,
+            c.created_at
+        FROM comment c
+        LEFT JOIN users u ON u.id = c.author_id
+        WHERE c.entity_type = ? AND c.entity_id = ?
+        ORDER BY c.created_at ASC, c.id ASC
+        "#,
+        entity_type,
+        entity_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    let (roots, replies): (Vec<_>, Vec<_>) =
+        comments.into_iter().partition(|c| c.parent_id.is_none());
+    let mut threads: Vec<CommentThread> = roots
+        .into_iter()
+        .map(|comment| CommentThread {
+            comment,
+            replies: Vec::new(),
+        })
+        .collect();
+    for reply in replies {
+        if let Some(thread) = threads
+            .iter_mut()
+            .find(|thread| Some(thread.comment.id) == reply.parent_id)
+        {
+            thread.replies.push(reply);
+        }
+    }
+    Ok(threads)
+}
+
+/// Post a comment and notify the users it mentions
+///
+/// The author is never notified about their own mention. Returns `None` when
+/// the replied-to comment does not belong to the same record.
+pub async fn create_comment(
+    db: &SqlitePool,
+    entity: CreateCommentEntity,
+) -> Result<Option<CreatedComment>, sqlx::Error> {
+    let entity_type = entity.entity_type.as_str();
+    let mut tx = db.begin().await?;
+
+    let parent_id = match entity.parent_id {
+        Some(parent_id) => {
+            let parent = sqlx::query!(
+                r#"
+                SELECT COALESCE(parent_id, id) as "root_id!: i64"
+                FROM comment
+                WHERE id = ? AND entity_type = ? AND entity_id = ?
+                "#,
+                parent_id,
+                entity_type,
+                entity.entity_id
+            )
+            .fetch_optional(&mut *tx)
+            .await?;
+            match parent {
+                Some(parent) => Some(parent.root_id),
+                None => return Ok(None),
+            }
+        }
+        None => None,
+    };
+
+    let id = sqlx::query!(
+        r#"
+        INSERT INTO comment (entity_type, entity_id, parent_id, author_id, body)
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+        entity_type,
+        entity.entity_id,
+        parent_id,
+        entity.author_id,
+        entity.body
+    )
+    .execute(&mut *tx)
+    .await?
+    .last_insert_rowid();
+
+    let mut notified = 0;
+    for handle in parse_mentions(&entity.body) {
+        let result = sqlx::query!(
+            r#"
+            INSERT OR IGNORE INTO notification (user_id, comment_id, actor_id)
+            SELECT id, ?1, ?2
+            FROM users
+            WHERE id != ?2
+              AND (lower(email) = ?3 OR lower(substr(email, 1, instr(email, '@') - 1)) = ?3)
+            "#,
+            id,
+            entity.author_id,
+            handle
+        )
+        .execute(&mut *tx)
+        .await?;
+        notified += result.rows_affected() as usize;
+    }
+
+    tx.commit().await?;
+    Ok(Some(CreatedComment { id, notified }))
+}
+
+/// Delete a comment written by `author_id`, with its replies
+///
+/// Returns whether a comment was deleted.
+pub async fn delete_comment(db: &SqlitePool, id: i64, author_id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        "DELETE FROM comment WHERE id = ? AND author_id = ?",
+        id,
+        author_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn user(pool: &SqlitePool, email: &str, name: &str) -> i64 {
+        sqlx::query("INSERT INTO users (email, password_hash, name) VALUES (?, 'x', ?)")
+            .bind(email)
+            .bind(name)
+            .execute(pool)
+            .await
+            .unwrap()
+            .last_insert_rowid()
+    }
+
+    async fn post(
+        pool: &SqlitePool,
+        author_id: i64,
+        parent_id: Option<i64>,
+        body: &str,
+    ) -> Option<CreatedComment> {
+        create_comment(
+            pool,
+            CreateCommentEntity {
+                entity_type: CommentEntityType::Player,
+                entity_id: 1,
+                parent_id,
+                author_id,
+                body: body.to_string(),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn notification_count(pool: &SqlitePool, user_id: i64) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM notification WHERE user_id = ?")
+            .bind(user_id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_mentions() {
+        assert_eq!(
+            parse_mentions("@jane.doe, can you check this with @Bob@example.com?"),
+            vec!["jane.doe", "bob@example.com"]
+        );
+        assert_eq!(
+            parse_mentions("Thanks @jane. And @jane again."),
+            vec!["jane"]
+        );
+        assert!(parse_mentions("mail me at jane@example.com or @ someone").is_empty());
+        assert_eq!(parse_mentions("(@bob)"), vec!["bob"]);
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_comment_threads_and_mentions(pool: SqlitePool) {
+        let jane = user(&pool, "jane.doe@example.com", "Jane Doe").await;
+        let bob = user(&pool, "bob@example.com", "Bob").await;
+
+        let root = post(
+            &pool,
+            jane,
+            None,
+            "Birth date differs from **IIHF** records, @bob",
+        )
+        .await
+        .unwrap();
+        assert_eq!(root.notified, 1);
+        assert_eq!(notification_count(&pool, bob).await, 1);
+
+        // Replying to a reply joins the top-level thread; self-mentions are ignored
+        let reply = post(&pool, bob, Some(root.id), "Checked, @jane.doe is right")
+            .await
+            .unwrap();
+        let nested = post(&pool, bob, Some(reply.id), "Fixed @bob")
+            .await
+            .unwrap();
+        assert_eq!(nested.notified, 0);
+        assert_eq!(notification_count(&pool, jane).await, 1);
+
+        let threads = get_comment_threads(&pool, CommentEntityType::Player, 1)
+            .await
+            .unwrap();
+        assert_eq!(threads.len(), 1);
+        assert_eq!(threads[0].comment.author_name.as_deref(), Some("Jane Doe"));
+        assert_eq!(threads[0].replies.len(), 2);
+        assert!(threads[0]
+            .replies
+            .iter()
+            .all(|r| r.parent_id == Some(root.id)));
+        assert!(get_comment_threads(&pool, CommentEntityType::Match, 1)
+            .await
+            .unwrap()
+            .is_empty());
+
+        // Replies must stay on the same record
+        let other = create_comment(
+            &pool,
+            CreateCommentEntity {
+                entity_type: CommentEntityType::Match,
+                entity_id: 1,
+                parent_id: Some(root.id),
+                author_id: bob,
+                body: "Wrong thread".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        assert!(other.is_none());
+
+        // Only the author can delete, and replies go with the thread
+        assert!(!delete_comment(&pool, root.id, bob).await.unwrap());
+        assert!(delete_comment(&pool, root.id, jane).await.unwrap());
+        assert!(get_comment_threads(&pool, CommentEntityType::Player, 1)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(notification_count(&pool, bob).await, 0);
+    }
+}
@@ -1,6 +1,6 @@
 use sqlx::{Row, SqlitePool};
 
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CountryEntity {
     pub id: i64,
@@ -13,13 +13,21 @@ pub struct CountryEntity {
     pub enabled: bool,
 }
 
-#[derive(Debug, Clone, Default)]
+/// Filters for the country list; also the query string of `GET /api/countries`
+#[derive(Debug, Clone, Default, serde::Serialize)]
 pub struct CountryFilters {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub search: Option<String>,
     pub iihf_only: bool,
     pub enabled_only: bool,
 }
 
+/// Response of `POST /api/countries/:id/toggle`
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+pub struct CountryToggleEntity {
+    pub enabled: bool,
+}
+
 /// Get all countries with optional filters
 pub async fn get_countries(
     db: &SqlitePool,
@@ -147,6 +155,23 @@ pub async fn toggle_country_enabled(db: &SqlitePool, id: i64) -> Result<Option<b
     Ok(Some(row.enabled))
 }
 
+/// Look up a country by ISO 3166 alpha-2 or IOC code, ignoring case
+pub async fn find_country_by_code(db: &SqlitePool, code: &str) -> Result<Option<i64>, sqlx::Error> {
+    let id = sqlx::query_scalar!(
+        r#"
+        SELECT id as "id!" FROM country
+        WHERE UPPER(iso2Code) = UPPER(?1) OR UPPER(iocCode) = UPPER(?1)
+        ORDER BY isHistorical, id
+        LIMIT 1
+        "#,
+        code
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
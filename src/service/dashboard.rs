@@ -8,6 +8,27 @@ pub struct DashboardStats {
     pub events_count: i64,
     pub seasons_count: i64,
     pub matches_count: i64,
+    pub identified_goals_count: i64,
+    /// Players added in the last 7 days vs the 7 days before
+    pub new_players: WeekDelta,
+    /// Matches entered in the last 7 days vs the 7 days before
+    pub new_matches: WeekDelta,
+    /// Goals with a known scorer entered in the last 7 days vs the 7 days before
+    pub identified_goals: WeekDelta,
+}
+
+/// A count for the last 7 days compared with the 7 days before
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WeekDelta {
+    pub this_week: i64,
+    pub last_week: i64,
+}
+
+impl WeekDelta {
+    /// Difference to the previous week (positive when growing)
+    pub fn change(&self) -> i64 {
+        self.this_week - self.last_week
+    }
 }
 
 /// Recent activity item
@@ -21,8 +42,8 @@ pub struct RecentActivity {
 
 /// Get dashboard statistics (counts of all entities)
 pub async fn get_dashboard_stats(db: &SqlitePool) -> Result<DashboardStats, sqlx::Error> {
-    // Optimize: Single query with subqueries instead of 5 separate queries
-    // Reduces database round trips from 5 to 1
+    // Optimize: Single query with subqueries instead of separate queries
+    // Reduces database round trips to 1, including the week-over-week deltas
     let row = sqlx::query!(
         r#"
         SELECT
@@ -30,7 +51,25 @@ pub async fn get_dashboard_stats(db: &SqlitePool) -> Result<DashboardStats, sqlx
             (SELECT COUNT(*) FROM player) as players_count,
             (SELECT COUNT(*) FROM event) as events_count,
             (SELECT COUNT(*) FROM season) as seasons_count,
-            (SELECT COUNT(*) FROM match) as matches_count
+            (SELECT COUNT(*) FROM match) as matches_count,
+            (SELECT COUNT(*) FROM score_event WHERE scorer_id IS NOT NULL) as identified_goals_count,
+            (SELECT COUNT(*) FROM player
+                WHERE created_at >= datetime('now', '-7 days')) as "players_this_week!: i64",
+            (SELECT COUNT(*) FROM player
+                WHERE created_at >= datetime('now', '-14 days')
+                  AND created_at < datetime('now', '-7 days')) as "players_last_week!: i64",
+            (SELECT COUNT(*) FROM match
+                WHERE created_at >= datetime('now', '-7 days')) as "matches_this_week!: i64",
+            (SELECT COUNT(*) FROM match
+                WHERE created_at >= datetime('now', '-14 days')
+                  AND created_at < datetime('now', '-7 days')) as "matches_last_week!: i64",
+            (SELECT COUNT(*) FROM score_event
+                WHERE scorer_id IS NOT NULL
+                  AND created_at >= datetime('now', '-7 days')) as "goals_this_week!: i64",
+            (SELECT COUNT(*) FROM score_event
+                WHERE scorer_id IS NOT NULL
+                  AND created_at >= datetime('now', '-14 days')
+                  AND created_at < datetime('now', '-7 days')) as "goals_last_week!: i64"
         "#
     )
     .fetch_one(db)
@@ -42,6 +81,19 @@ pub async fn get_dashboard_stats(db: &SqlitePool) -> Result<DashboardStats, sqlx
         events_count: row.events_count,
         seasons_count: row.seasons_count,
         matches_count: row.matches_count,
+        identified_goals_count: row.identified_goals_count,
+        new_players: WeekDelta {
+            this_week: row.players_this_week,
+            last_week: row.players_last_week,
+        },
+        new_matches: WeekDelta {
+            this_week: row.matches_this_week,
+            last_week: row.matches_last_week,
+        },
+        identified_goals: WeekDelta {
+            this_week: row.goals_this_week,
+            last_week: row.goals_last_week,
+        },
     })
 }
 
@@ -122,6 +174,34 @@ mod tests {
         assert_eq!(stats.matches_count, 0); // No matches fixture
     }
 
+    #[sqlx::test(migrations = "./migrations", fixtures("players"))]
+    async fn test_get_dashboard_stats_week_deltas(pool: SqlitePool) {
+        // Fixture players were created now; move two into last week and one further back
+        sqlx::query!(
+            "UPDATE player SET created_at = datetime('now', '-10 days') WHERE id IN (1, 2)"
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query!("UPDATE player SET created_at = datetime('now', '-30 days') WHERE id = 3")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let stats = get_dashboard_stats(&pool).await.unwrap();
+
+        assert_eq!(
+            stats.new_players,
+            WeekDelta {
+                this_week: 7,
+                last_week: 2,
+            }
+        );
+        assert_eq!(stats.new_players.change(), 5);
+        assert_eq!(stats.new_matches, WeekDelta::default());
+        assert_eq!(stats.identified_goals.change(), 0);
+    }
+
     #[sqlx::test(migrations = "./migrations")]
     async fn test_get_recent_activity_empty(pool: SqlitePool) {
         let activities = get_recent_activity(&pool).await.unwrap();
@@ -0,0 +1,290 @@
+//! Records that are incomplete or look wrong, for the data quality dashboard
+
+use sqlx::SqlitePool;
+
+use crate::service::reports::{self, DuplicatePair};
+
+/// Most records listed per kind of issue; totals are always exact
+pub const ISSUE_LIMIT: i64 = 100;
+
+/// Records with one kind of issue
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssueList<T> {
+    /// All records with the issue, may exceed the listed `items`
+    pub total: i64,
+    pub items: Vec<T>,
+}
+
+impl<T> IssueList<T> {
+    pub fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+}
+
+/// Match without a date
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UndatedMatchEntity {
+    pub id: i64,
+    pub season_name: String,
+    pub home_team_name: String,
+    pub away_team_name: String,
+    pub status: String,
+}
+
+/// Identified goal without a scorer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnscoredGoalEntity {
+    pub id: i64,
+    pub match_id: i64,
+    pub match_date: Option<String>,
+    pub home_team_name: String,
+    pub away_team_name: String,
+    pub team_name: String,
+    pub period: Option<i32>,
+}
+
+/// Team whose name is blank
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnnamedTeamEntity {
+    pub id: i64,
+    pub short_name: Option<String>,
+    pub country_name: Option<String>,
+}
+
+/// Season participation without any players on the roster
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmptyRosterEntity {
+    pub participation_id: i64,
+    pub team_id: i64,
+    pub team_name: String,
+    pub season_id: i64,
+    pub season_name: String,
+}
+
+/// Everything the data quality dashboard lists
+#[derive(Debug, Clone)]
+pub struct DataQualityReport {
+    pub undated_matches: IssueList<UndatedMatchEntity>,
+    pub unscored_goals: IssueList<UnscoredGoalEntity>,
+    pub unnamed_teams: IssueList<UnnamedTeamEntity>,
+    pub duplicate_players: Vec<DuplicatePair>,
+    pub empty_rosters: IssueList<EmptyRosterEntity>,
+}
+
+impl DataQualityReport {
+    /// Records with an issue across all kinds
+    pub fn total(&self) -> i64 {
+        self.undated_matches.total
+            + self.unscored_goals.total
+            + self.unnamed_teams.total
+            + self.duplicate_players.len() as i64
+            + self.empty_rosters.total
+    }
+}
+
+async fn undated_matches(db: &SqlitePool) -> Result<IssueList<UndatedMatchEntity>, sqlx::Error> {
+    let total = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!: i64" FROM match WHERE match_date IS NULL"#
+    )
+    .fetch_one(db)
+    .await?;
+
+    let items = sqlx::query_as!(
+        UndatedMatchEntity,
+        r#"
+        SELECT
+            m.id as "id!",
+            COALESCE(s.display_name, e.name || ' ' || s.year) as "season_name!: String",
+            ht.name as home_team_name,
+            at.name as away_team_name,
+            m.status
+        FROM match m
+        INNER JOIN season s ON s.id = m.season_id
+        INNER JOIN event e ON e.id = s.event_id
+        INNER JOIN team ht ON ht.id = m.home_team_id
+        INNER JOIN team at ON at.id = m.away_team_id
+        WHERE m.match_date IS NULL
+        ORDER BY s.year DESC, m.id
+        LIMIT ?
+        "#,
+        ISSUE_LIMIT
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(IssueList { total, items })
+}
+
+async fn unscored_goals(db: &SqlitePool) -> Result<IssueList<UnscoredGoalEntity>, sqlx::Error> {
+    let total = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!: i64" FROM score_event WHERE scorer_id IS NULL"#
+    )
+    .fetch_one(db)
+    .await?;
+
+    let items = sqlx::query_as!(
+        UnscoredGoalEntity,
+        r#"
+        SELECT
+            se.id as "id!",
+            se.match_id,
+            m.match_date,
+            ht.name as home_team_name,
+            at.name as away_team_name,
+            t.name as team_name,
+            se.period as "period: i32"
+        FROM score_event se
+        INNER JOIN match m ON m.id = se.match_id
+        INNER JOIN team ht ON ht.id = m.home_team_id
+        INNER JOIN team at ON at.id = m.away_team_id
+        INNER JOIN team t ON t.id = se.team_id
+        WHERE se.scorer_id IS NULL
+        ORDER BY m.match_date DESC, se.match_id, se.id
+        LIMIT ?
+        "#,
+        ISSUE_LIMIT
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(IssueList { total, items })
+}
+
+async fn unnamed_teams(db: &SqlitePool) -> Result<IssueList<UnnamedTeamEntity>, sqlx::Error> {
+    let items = sqlx::query_as!(
+        UnnamedTeamEntity,
+        r#"
+        SELECT t.id as "id!", t.short_name, c.name as "country_name?"
+        FROM team t
+        LEFT JOIN country c ON c.id = t.country_id
+        WHERE TRIM(t.name) = ''
+        ORDER BY t.id
+        "#
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(IssueList {
+        total: items.len() as i64,
+        items,
+    })
+}
+
+async fn empty_rosters(db: &SqlitePool) -> Result<IssueList<EmptyRosterEntity>, sqlx::Error> {
+    let total = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) as "count!: i64"
+        FROM team_participation tp
+        WHERE NOT EXISTS (SELECT 1 FROM player_contract pc WHERE pc.team_participation_id = tp.id)
+        "#
+    )
+    .fetch_one(db)
+    .await?;
+
+    let items = sqlx::query_as!(
+        EmptyRosterEntity,
+        r#"
+        SELECT
+            tp.id as "participation_id!",
+            t.id as "team_id!",
+            t.name as team_name,
+            s.id as "season_id!",
+            COALESCE(s.display_name, e.name || ' ' || s.year) as "season_name!: String"
+        FROM team_participation tp
+        INNER JOIN team t ON t.id = tp.team_id
+        INNER JOIN season s ON s.id = tp.season_id
+        INNER JOIN event e ON e.id = s.event_id
+        WHERE NOT EXISTS (SELECT 1 FROM player_contract pc WHERE pc.team_participation_id = tp.id)
+        ORDER BY s.year DESC, e.name, t.name
+        LIMIT ?
+        "#,
+        ISSUE_LIMIT
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(IssueList { total, items })
+}
+
+/// All data quality issues
+pub async fn get_data_quality_report(db: &SqlitePool) -> Result<DataQualityReport, sqlx::Error> {
+    Ok(DataQualityReport {
+        undated_matches: undated_matches(db).await?,
+        unscored_goals: unscored_goals(db).await?,
+        unnamed_teams: unnamed_teams(db).await?,
+        duplicate_players: reports::get_duplicate_report(db).await?.players,
+        empty_rosters: empty_rosters(db).await?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_data_quality_report(pool: SqlitePool) {
+        let participations =
+            sqlx::query_scalar!(r#"SELECT COUNT(*) as "count!: i64" FROM team_participation"#)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+
+        sqlx::query(
+            "INSERT INTO player_contract (team_participation_id, player_id)
+             SELECT id, 1 FROM team_participation WHERE season_id = 1 AND team_id = 1",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO match (id, season_id, home_team_id, away_team_id, match_date, status) VALUES
+                (1, 1, 1, 2, '2024-01-01', 'finished'),
+                (2, 1, 1, 2, NULL, 'finished')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO score_event (match_id, team_id, scorer_id, period) VALUES
+                (1, 1, 1, 1),
+                (1, 2, NULL, 2)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO team (id, name, country_id) VALUES (100, '  ', NULL)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO player (id, name, country_id, birth_date) VALUES (100, 'Gretzky Wayne', 34, NULL)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let report = get_data_quality_report(&pool).await.unwrap();
+
+        assert_eq!(report.undated_matches.total, 1);
+        assert_eq!(report.undated_matches.items[0].id, 2);
+
+        assert_eq!(report.unscored_goals.total, 1);
+        assert_eq!(report.unscored_goals.items[0].period, Some(2));
+
+        assert_eq!(report.unnamed_teams.total, 1);
+        assert_eq!(report.unnamed_teams.items[0].id, 100);
+
+        assert_eq!(report.duplicate_players.len(), 1);
+        assert_eq!(report.duplicate_players[0].second.id, 100);
+
+        assert_eq!(report.empty_rosters.total, participations - 1);
+        assert!(report
+            .empty_rosters
+            .items
+            .iter()
+            .all(|r| !(r.season_id == 1 && r.team_id == 1)));
+    }
+}
@@ -1,5 +1,9 @@
 use sqlx::{Row, SqlitePool};
 
+use crate::service::roster_rules::{self, RosterRulesEntity};
+use crate::service::slugs::{self, SlugEntity};
+use crate::service::standings::PointSystem;
+
 #[derive(Debug, Clone)]
 pub struct EventEntity {
     pub id: i64,
@@ -7,18 +11,31 @@ pub struct EventEntity {
     pub country_id: Option<i64>,
     pub country_name: Option<String>,
     pub country_iso2_code: Option<String>,
+    pub point_system: String,
+    /// URL slug, see [`crate::service::slugs`]
+    pub slug: Option<String>,
+}
+
+impl EventEntity {
+    pub fn point_system(&self) -> PointSystem {
+        PointSystem::from_str(&self.point_system).unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct CreateEventEntity {
     pub name: String,
     pub country_id: Option<i64>,
+    pub point_system: String,
 }
 
 #[derive(Debug, Clone)]
 pub struct UpdateEventEntity {
     pub name: String,
     pub country_id: Option<i64>,
+    pub point_system: String,
+    /// Slug from the edit form, `None` generates it from the name
+    pub slug: Option<String>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -58,18 +75,24 @@ impl<T> PagedResult<T> {
 
 /// Create a new event
 pub async fn create_event(db: &SqlitePool, event: CreateEventEntity) -> Result<i64, sqlx::Error> {
+    let mut tx = db.begin().await?;
     let result = sqlx::query!(
         r#"
-        INSERT INTO event (name, country_id)
-        VALUES (?, ?)
+        INSERT INTO event (name, country_id, point_system)
+        VALUES (?, ?, ?)
         "#,
         event.name,
-        event.country_id
+        event.country_id,
+        event.point_system
     )
-    .execute(db)
+    .execute(&mut *tx)
     .await?;
 
-    Ok(result.last_insert_rowid())
+    let id = result.last_insert_rowid();
+    slugs::assign_slug(&mut tx, SlugEntity::Event, id, &event.name).await?;
+    tx.commit().await?;
+
+    Ok(id)
 }
 
 /// Get events with filters and pagination
@@ -89,7 +112,7 @@ pub async fn get_events(
 
     // Build data query
     let mut data_query = sqlx::QueryBuilder::new(
-        "SELECT e.id, e.name, e.country_id, c.name as country_name, c.iso2Code as country_iso2_code
+        "SELECT e.id, e.name, e.country_id, c.name as country_name, c.iso2Code as country_iso2_code, e.point_system, e.slug
          FROM event e
          LEFT JOIN country c ON e.country_id = c.id
          WHERE 1=1",
@@ -112,6 +135,8 @@ pub async fn get_events(
             country_id: row.get("country_id"),
             country_name: row.get("country_name"),
             country_iso2_code: row.get("country_iso2_code"),
+            point_system: row.get("point_system"),
+            slug: row.get("slug"),
         })
         .collect();
 
@@ -128,7 +153,9 @@ pub async fn get_event_by_id(db: &SqlitePool, id: i64) -> Result<Option<EventEnt
             e.name as "name!",
             e.country_id,
             c.name as country_name,
-            c.iso2Code as country_iso2_code
+            c.iso2Code as country_iso2_code,
+            e.point_system,
+            e.slug
         FROM event e
         LEFT JOIN country c ON e.country_id = c.id
         WHERE e.id = ?
@@ -152,6 +179,7 @@ pub struct SeasonEntity {
 pub struct EventDetailEntity {
     pub event_info: EventEntity,
     pub seasons: Vec<SeasonEntity>,
+    pub roster_rules: RosterRulesEntity,
 }
 
 /// Get event detail with all seasons
@@ -188,9 +216,14 @@ pub async fn get_event_detail(
     //     })
     //     .collect();
 
+    let roster_rules = roster_rules::get_event_roster_rules(db, event_id)
+        .await?
+        .unwrap_or_default();
+
     Ok(Some(EventDetailEntity {
         event_info,
         seasons,
+        roster_rules,
     }))
 }
 
@@ -200,20 +233,39 @@ pub async fn update_event(
     id: i64,
     event: UpdateEventEntity,
 ) -> Result<bool, sqlx::Error> {
-    let result = sqlx::query!(
+    let mut tx = db.begin().await?;
+    let Some(previous_name) = sqlx::query_scalar!("SELECT name FROM event WHERE id = ?", id)
+        .fetch_optional(&mut *tx)
+        .await?
+    else {
+        return Ok(false);
+    };
+
+    sqlx::query!(
         r#"
         UPDATE event
-        SET name = ?, country_id = ?
+        SET name = ?, country_id = ?, point_system = ?
         WHERE id = ?
         "#,
         event.name,
         event.country_id,
+        event.point_system,
         id
     )
-    .execute(db)
+    .execute(&mut *tx)
     .await?;
 
-    Ok(result.rows_affected() > 0)
+    slugs::update_slug(
+        &mut tx,
+        SlugEntity::Event,
+        id,
+        &previous_name,
+        event.slug.as_deref(),
+    )
+    .await?;
+    tx.commit().await?;
+
+    Ok(true)
 }
 
 /// Delete an event
@@ -260,6 +312,7 @@ mod tests {
         let event = CreateEventEntity {
             name: "Test Tournament".to_string(),
             country_id: Some(1), // Canada from migrations
+            point_system: "classic".to_string(),
         };
 
         let id = create_event(&pool, event).await.unwrap();
@@ -269,6 +322,7 @@ mod tests {
         assert!(result.is_some());
         let event = result.unwrap();
         assert_eq!(event.name, "Test Tournament");
+        assert_eq!(event.point_system(), PointSystem::Classic);
     }
 
     #[sqlx::test(migrations = "./migrations", fixtures("events"))]
@@ -317,6 +371,8 @@ mod tests {
         let update = UpdateEventEntity {
             name: "Updated Olympics".to_string(),
             country_id: Some(1),
+            point_system: "iihf".to_string(),
+            slug: None,
         };
 
         let success = update_event(&pool, 1, update).await.unwrap();
@@ -324,6 +380,7 @@ mod tests {
 
         let event = get_event_by_id(&pool, 1).await.unwrap().unwrap();
         assert_eq!(event.name, "Updated Olympics");
+        assert_eq!(event.point_system(), PointSystem::Iihf);
     }
 
     #[sqlx::test(migrations = "./migrations", fixtures("events"))]
@@ -0,0 +1,153 @@
+//! Stable external identifiers (aliases) for records exchanged between instances
+//!
+//! Local IDs differ between databases, so exported records carry an external
+//! ID instead. A record gets its own ID the first time it is exported and
+//! keeps every ID it was imported under, letting later imports find it again.
+
+use sqlx::SqliteConnection;
+
+/// Kind of record an external ID points to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalEntity {
+    Team,
+    Player,
+    Match,
+}
+
+impl ExternalEntity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExternalEntity::Team => "team",
+            ExternalEntity::Player => "player",
+            ExternalEntity::Match => "match",
+        }
+    }
+}
+
+/// Find the local record an external ID was linked to
+pub async fn find_by_external_id(
+    conn: &mut SqliteConnection,
+    entity: ExternalEntity,
+    external_id: &str,
+) -> Result<Option<i64>, sqlx::Error> {
+    let entity_type = entity.as_str();
+    let id = sqlx::query_scalar!(
+        "SELECT entity_id FROM external_alias WHERE entity_type = ? AND external_id = ?",
+        entity_type,
+        external_id
+    )
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    Ok(id)
+}
+
+/// Link an external ID to a local record, re-pointing it if it was linked elsewhere
+pub async fn link_external_id(
+    conn: &mut SqliteConnection,
+    entity: ExternalEntity,
+    external_id: &str,
+    entity_id: i64,
+) -> Result<(), sqlx::Error> {
+    let entity_type = entity.as_str();
+    sqlx::query!(
+        r#"
+        INSERT INTO external_alias (entity_type, external_id, entity_id)
+        VALUES (?, ?, ?)
+        ON CONFLICT (entity_type, external_id) DO UPDATE SET entity_id = excluded.entity_id
+        "#,
+        entity_type,
+        external_id,
+        entity_id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Get the external ID of a local record, assigning a new one on first use
+///
+/// When a record has several aliases the oldest is used, so an imported
+/// record keeps exporting under the ID it originally came with.
+pub async fn external_id_for(
+    conn: &mut SqliteConnection,
+    entity: ExternalEntity,
+    entity_id: i64,
+) -> Result<String, sqlx::Error> {
+    let entity_type = entity.as_str();
+    let existing = sqlx::query_scalar!(
+        r#"
+        SELECT external_id FROM external_alias
+        WHERE entity_type = ? AND entity_id = ?
+        ORDER BY id
+        LIMIT 1
+        "#,
+        entity_type,
+        entity_id
+    )
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    if let Some(external_id) = existing {
+        return Ok(external_id);
+    }
+
+    let external_id = uuid::Uuid::new_v4().to_string();
+    link_external_id(conn, entity, &external_id, entity_id).await?;
+
+    Ok(external_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::SqlitePool;
+
+    #[sqlx::test(migrations = "./migrations", fixtures("teams"))]
+    async fn test_external_ids_are_stable_and_cleaned_up(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+
+        let first = external_id_for(&mut conn, ExternalEntity::Team, 1)
+            .await
+            .unwrap();
+        let second = external_id_for(&mut conn, ExternalEntity::Team, 1)
+            .await
+            .unwrap();
+        assert_eq!(first, second);
+
+        // A second alias resolves too, but the original stays the export ID
+        link_external_id(&mut conn, ExternalEntity::Team, "other-instance:7", 1)
+            .await
+            .unwrap();
+        assert_eq!(
+            find_by_external_id(&mut conn, ExternalEntity::Team, "other-instance:7")
+                .await
+                .unwrap(),
+            Some(1)
+        );
+        assert_eq!(
+            external_id_for(&mut conn, ExternalEntity::Team, 1)
+                .await
+                .unwrap(),
+            first
+        );
+        assert_eq!(
+            find_by_external_id(&mut conn, ExternalEntity::Player, &first)
+                .await
+                .unwrap(),
+            None
+        );
+
+        sqlx::query("DELETE FROM team WHERE id = 1")
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+        assert_eq!(
+            find_by_external_id(&mut conn, ExternalEntity::Team, &first)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+}
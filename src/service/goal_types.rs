@@ -0,0 +1,284 @@
+use sqlx::SqlitePool;
+
+/// Situation a goal was scored in, as stored in `score_event.goal_type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GoalType {
+    EvenStrength,
+    PowerPlay,
+    ShortHanded,
+    PenaltyShot,
+    EmptyNet,
+}
+
+impl GoalType {
+    pub const ALL: [GoalType; 5] = [
+        GoalType::EvenStrength,
+        GoalType::PowerPlay,
+        GoalType::ShortHanded,
+        GoalType::PenaltyShot,
+        GoalType::EmptyNet,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::EvenStrength => "even_strength",
+            Self::PowerPlay => "power_play",
+            Self::ShortHanded => "short_handed",
+            Self::PenaltyShot => "penalty_shot",
+            Self::EmptyNet => "empty_net",
+        }
+    }
+}
+
+/// Identified goals split by goal type
+///
+/// Goals recorded without a type are counted as `unspecified`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GoalTypeBreakdown {
+    pub even_strength: i64,
+    pub power_play: i64,
+    pub short_handed: i64,
+    pub penalty_shot: i64,
+    pub empty_net: i64,
+    pub unspecified: i64,
+}
+
+impl GoalTypeBreakdown {
+    pub fn count(&self, goal_type: GoalType) -> i64 {
+        match goal_type {
+            GoalType::EvenStrength => self.even_strength,
+            GoalType::PowerPlay => self.power_play,
+            GoalType::ShortHanded => self.short_handed,
+            GoalType::PenaltyShot => self.penalty_shot,
+            GoalType::EmptyNet => self.empty_net,
+        }
+    }
+
+    pub fn total(&self) -> i64 {
+        GoalType::ALL.iter().map(|&gt| self.count(gt)).sum::<i64>() + self.unspecified
+    }
+
+    /// Share of all goals in percent, `None` when there are no goals
+    pub fn percentage(&self, count: i64) -> Option<f64> {
+        let total = self.total();
+        (total > 0).then(|| count as f64 * 100.0 / total as f64)
+    }
+}
+
+/// Goal types of one team in a season
+#[derive(Debug, Clone)]
+pub struct TeamGoalTypesEntity {
+    pub team_id: i64,
+    pub team_name: String,
+    pub breakdown: GoalTypeBreakdown,
+}
+
+/// Goal types of a team in one season
+#[derive(Debug, Clone)]
+pub struct SeasonGoalTypesEntity {
+    pub season_id: i64,
+    pub season_year: i64,
+    pub season_display_name: Option<String>,
+    pub event_name: String,
+    pub breakdown: GoalTypeBreakdown,
+}
+
+/// Goal types per team of a season, most goals first
+///
+/// Only identified goals (score events) carry a type, so unidentified goals
+/// are not counted. Exhibition games are left out.
+pub async fn get_season_goal_types(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<Vec<TeamGoalTypesEntity>, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            t.id as "team_id!",
+            t.name as "team_name!",
+            SUM(se.goal_type = 'even_strength') as "even_strength!: i64",
+            SUM(se.goal_type = 'power_play') as "power_play!: i64",
+            SUM(se.goal_type = 'short_handed') as "short_handed!: i64",
+            SUM(se.goal_type = 'penalty_shot') as "penalty_shot!: i64",
+            SUM(se.goal_type = 'empty_net') as "empty_net!: i64",
+            SUM(se.goal_type IS NULL) as "unspecified!: i64"
+        FROM score_event se
+        INNER JOIN match m ON m.id = se.match_id
+        INNER JOIN team t ON t.id = se.team_id
+        WHERE m.season_id = ? AND m.game_type != 'exhibition'
+        GROUP BY t.id, t.name
+        ORDER BY COUNT(*) DESC, t.name ASC
+        "#,
+        season_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| TeamGoalTypesEntity {
+            team_id: row.team_id,
+            team_name: row.team_name,
+            breakdown: GoalTypeBreakdown {
+                even_strength: row.even_strength,
+                power_play: row.power_play,
+                short_handed: row.short_handed,
+                penalty_shot: row.penalty_shot,
+                empty_net: row.empty_net,
+                unspecified: row.unspecified,
+            },
+        })
+        .collect())
+}
+
+/// Goal types of a team's goals per season, newest first
+///
+/// Seasons without an identified goal of the team are left out. Exhibition
+/// games are not counted.
+pub async fn get_team_goal_types(
+    db: &SqlitePool,
+    team_id: i64,
+) -> Result<Vec<SeasonGoalTypesEntity>, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            s.id as "season_id!",
+            s.year as "season_year!",
+            s.display_name as season_display_name,
+            e.name as "event_name!",
+            SUM(se.goal_type = 'even_strength') as "even_strength!: i64",
+            SUM(se.goal_type = 'power_play') as "power_play!: i64",
+            SUM(se.goal_type = 'short_handed') as "short_handed!: i64",
+            SUM(se.goal_type = 'penalty_shot') as "penalty_shot!: i64",
+            SUM(se.goal_type = 'empty_net') as "empty_net!: i64",
+            SUM(se.goal_type IS NULL) as "unspecified!: i64"
+        FROM score_event se
+        INNER JOIN match m ON m.id = se.match_id
+        INNER JOIN season s ON s.id = m.season_id
+        INNER JOIN event e ON e.id = s.event_id
+        WHERE se.team_id = ? AND m.game_type != 'exhibition'
+        GROUP BY s.id, s.year, s.display_name, e.name
+        ORDER BY s.year DESC, s.id DESC
+        "#,
+        team_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| SeasonGoalTypesEntity {
+            season_id: row.season_id,
+            season_year: row.season_year,
+            season_display_name: row.season_display_name,
+            event_name: row.event_name,
+            breakdown: GoalTypeBreakdown {
+                even_strength: row.even_strength,
+                power_play: row.power_play,
+                short_handed: row.short_handed,
+                penalty_shot: row.penalty_shot,
+                empty_net: row.empty_net,
+                unspecified: row.unspecified,
+            },
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{
+        create_match, create_score_event, CreateMatchEntity, CreateScoreEventEntity,
+    };
+
+    async fn finished_match(pool: &SqlitePool, season_id: i64, game_type: &str) -> i64 {
+        create_match(
+            pool,
+            CreateMatchEntity {
+                season_id,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: 0,
+                away_score_unidentified: 0,
+                match_date: None,
+                status: "finished".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: game_type.to_string(),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn goal(pool: &SqlitePool, match_id: i64, team_id: i64, goal_type: Option<&str>) {
+        create_score_event(
+            pool,
+            CreateScoreEventEntity {
+                match_id,
+                team_id,
+                scorer_id: None,
+                assist1_id: None,
+                assist2_id: None,
+                period: 1,
+                time_minutes: None,
+                time_seconds: None,
+                goal_type: goal_type.map(str::to_string),
+                video_url: None,
+                video_timestamp: None,
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    #[test]
+    fn test_goal_type_breakdown() {
+        let breakdown = GoalTypeBreakdown {
+            even_strength: 5,
+            power_play: 2,
+            short_handed: 1,
+            penalty_shot: 0,
+            empty_net: 1,
+            unspecified: 1,
+        };
+        assert_eq!(breakdown.total(), 10);
+        assert_eq!(breakdown.count(GoalType::PowerPlay), 2);
+        assert_eq!(breakdown.percentage(breakdown.even_strength), Some(50.0));
+        assert_eq!(GoalTypeBreakdown::default().percentage(0), None);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_goal_types_per_team_and_season(pool: SqlitePool) {
+        let group = finished_match(&pool, 1, "group").await;
+        goal(&pool, group, 1, Some("even_strength")).await;
+        goal(&pool, group, 1, Some("power_play")).await;
+        goal(&pool, group, 1, None).await;
+        goal(&pool, group, 2, Some("short_handed")).await;
+        let exhibition = finished_match(&pool, 1, "exhibition").await;
+        goal(&pool, exhibition, 1, Some("empty_net")).await;
+
+        let season = get_season_goal_types(&pool, 1).await.unwrap();
+        assert_eq!(season.len(), 2);
+        assert_eq!(season[0].team_name, "Team Canada");
+        assert_eq!(
+            season[0].breakdown,
+            GoalTypeBreakdown {
+                even_strength: 1,
+                power_play: 1,
+                unspecified: 1,
+                ..Default::default()
+            }
+        );
+        assert_eq!(season[1].breakdown.short_handed, 1);
+
+        let team = get_team_goal_types(&pool, 2).await.unwrap();
+        assert_eq!(team.len(), 1);
+        assert_eq!(team[0].event_name, "Winter Olympics");
+        assert_eq!(team[0].breakdown.total(), 1);
+        assert!(get_team_goal_types(&pool, 3).await.unwrap().is_empty());
+    }
+}
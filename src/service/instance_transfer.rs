@@ -0,0 +1,613 @@
+//! Portable export/import of all data of an instance
+//!
+//! The file nests events, their seasons, the teams taking part with their
+//! rosters, and each season's matches with their goals. Records reference
+//! each other by name and external ID like team history files (see
+//! [`crate::service::team_history`]), and imports go through the same
+//! importer, so the file can move data between instances whose IDs differ.
+
+use std::collections::{BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqlitePool};
+
+use super::external_ids::{self, ExternalEntity};
+use super::slugs;
+use super::standings::PointSystem;
+use super::team_history::{
+    ImportCounts, ImportMode, Importer, MatchRecord, Outcome, PlayerRecord, RosterRecord,
+    ScoreEventRecord, SeasonRef, TeamRef,
+};
+
+/// Value of the `format` field identifying an instance export
+pub const INSTANCE_EXPORT_FORMAT: &str = "hockey-instance";
+/// Current version of the instance export layout
+pub const INSTANCE_EXPORT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceFile {
+    pub format: String,
+    pub version: u32,
+    #[serde(default)]
+    pub exported_at: Option<String>,
+    /// Every player, referenced by rosters and goals through their `key`
+    #[serde(default)]
+    pub players: Vec<PlayerRecord>,
+    /// Every team, including those that never took part in a season
+    #[serde(default)]
+    pub teams: Vec<TeamRef>,
+    #[serde(default)]
+    pub events: Vec<EventRecord>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventRecord {
+    pub name: String,
+    /// IOC country code
+    #[serde(default)]
+    pub country: Option<String>,
+    /// `classic` or `iihf`; the instance default when missing
+    #[serde(default)]
+    pub point_system: Option<String>,
+    #[serde(default)]
+    pub seasons: Vec<SeasonRecord>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonRecord {
+    pub year: i64,
+    #[serde(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    pub participations: Vec<SeasonParticipationRecord>,
+    #[serde(default)]
+    pub matches: Vec<SeasonMatchRecord>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonParticipationRecord {
+    pub team: TeamRef,
+    #[serde(default)]
+    pub roster: Vec<RosterRecord>,
+}
+
+/// A match of a season; the season is the one it is listed under
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonMatchRecord {
+    #[serde(default)]
+    pub external_id: Option<String>,
+    pub home_team: TeamRef,
+    pub away_team: TeamRef,
+    #[serde(default)]
+    pub home_score_unidentified: i64,
+    #[serde(default)]
+    pub away_score_unidentified: i64,
+    #[serde(default)]
+    pub match_date: Option<String>,
+    pub status: String,
+    #[serde(default)]
+    pub game_type: Option<String>,
+    /// Arena name, matched to a local arena on import
+    #[serde(default)]
+    pub venue: Option<String>,
+    #[serde(default)]
+    pub score_events: Vec<ScoreEventRecord>,
+}
+
+impl SeasonMatchRecord {
+    fn to_match_record(&self, season: &SeasonRef) -> MatchRecord {
+        MatchRecord {
+            external_id: self.external_id.clone(),
+            season: season.clone(),
+            home_team: self.home_team.clone(),
+            away_team: self.away_team.clone(),
+            home_score_unidentified: self.home_score_unidentified,
+            away_score_unidentified: self.away_score_unidentified,
+            match_date: self.match_date.clone(),
+            status: self.status.clone(),
+            game_type: self.game_type.clone(),
+            venue: self.venue.clone(),
+            score_events: self.score_events.clone(),
+        }
+    }
+}
+
+impl EventRecord {
+    fn season_ref(&self, season: &SeasonRecord) -> SeasonRef {
+        SeasonRef {
+            event: self.name.clone(),
+            event_country: self.country.clone(),
+            year: season.year,
+            display_name: season.display_name.clone(),
+        }
+    }
+}
+
+impl InstanceFile {
+    fn seasons(&self) -> impl Iterator<Item = &SeasonRecord> {
+        self.events.iter().flat_map(|e| e.seasons.iter())
+    }
+
+    fn team_refs(&self) -> impl Iterator<Item = &TeamRef> {
+        let participants = self
+            .seasons()
+            .flat_map(|s| s.participations.iter().map(|p| &p.team));
+        let opponents = self
+            .seasons()
+            .flat_map(|s| s.matches.iter())
+            .flat_map(|m| [&m.home_team, &m.away_team]);
+
+        self.teams.iter().chain(participants).chain(opponents)
+    }
+
+    /// All IOC country codes referenced by the file
+    pub fn country_codes(&self) -> BTreeSet<&str> {
+        self.team_refs()
+            .filter_map(|t| t.country.as_deref())
+            .chain(self.events.iter().filter_map(|e| e.country.as_deref()))
+            .chain(self.players.iter().map(|p| p.country.as_str()))
+            .collect()
+    }
+
+    /// All player keys referenced by rosters and score events
+    pub fn referenced_player_keys(&self) -> BTreeSet<i64> {
+        let roster = self
+            .seasons()
+            .flat_map(|s| s.participations.iter())
+            .flat_map(|p| p.roster.iter().map(|r| r.player));
+        let scoring = self
+            .seasons()
+            .flat_map(|s| s.matches.iter())
+            .flat_map(|m| m.score_events.iter())
+            .flat_map(|se| [se.scorer, se.assist1, se.assist2])
+            .flatten();
+
+        roster.chain(scoring).collect()
+    }
+
+    /// Whether every event, team and player has a name
+    pub fn has_names(&self) -> bool {
+        self.events.iter().all(|e| !e.name.trim().is_empty())
+            && self.team_refs().all(|t| !t.name.trim().is_empty())
+            && self.players.iter().all(|p| !p.name.trim().is_empty())
+    }
+}
+
+/// What an instance import created, updated or left unchanged, per entity type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstanceImportSummary {
+    pub mode: ImportMode,
+    pub teams: ImportCounts,
+    pub events: ImportCounts,
+    pub seasons: ImportCounts,
+    pub participations: ImportCounts,
+    pub players: ImportCounts,
+    pub contracts: ImportCounts,
+    pub matches: ImportCounts,
+    pub score_events: ImportCounts,
+}
+
+/// Build the export of every event, season, team, player and match
+///
+/// Teams, players and matches without an external ID get one assigned.
+pub async fn export_instance(db: &SqlitePool) -> Result<InstanceFile, sqlx::Error> {
+    let mut conn = db.acquire().await?;
+
+    let mut team_refs: HashMap<i64, TeamRef> = HashMap::new();
+    let mut teams = Vec::new();
+    for row in sqlx::query(
+        r#"
+        SELECT t.id, t.name, t.short_name, c.iocCode as country
+        FROM team t
+        LEFT JOIN country c ON t.country_id = c.id
+        ORDER BY t.id
+        "#,
+    )
+    .fetch_all(db)
+    .await?
+    {
+        let id: i64 = row.get("id");
+        let team = TeamRef {
+            external_id: Some(
+                external_ids::external_id_for(&mut conn, ExternalEntity::Team, id).await?,
+            ),
+            name: row.get("name"),
+            short_name: row.get("short_name"),
+            country: row.get("country"),
+        };
+        team_refs.insert(id, team.clone());
+        teams.push(team);
+    }
+
+    let mut players = Vec::new();
+    for row in sqlx::query(
+        r#"
+        SELECT p.id, p.name, c.iocCode as country, p.birth_date, p.birth_place,
+               p.height_cm, p.weight_kg, p.position, p.shoots
+        FROM player p
+        INNER JOIN country c ON p.country_id = c.id
+        ORDER BY p.id
+        "#,
+    )
+    .fetch_all(db)
+    .await?
+    {
+        let id: i64 = row.get("id");
+        players.push(PlayerRecord {
+            key: id,
+            external_id: Some(
+                external_ids::external_id_for(&mut conn, ExternalEntity::Player, id).await?,
+            ),
+            name: row.get("name"),
+            country: row.get::<Option<String>, _>("country").unwrap_or_default(),
+            birth_date: row.get("birth_date"),
+            birth_place: row.get("birth_place"),
+            height_cm: row.get("height_cm"),
+            weight_kg: row.get("weight_kg"),
+            position: row.get("position"),
+            shoots: row.get("shoots"),
+        });
+    }
+
+    let mut events = Vec::new();
+    for event_row in sqlx::query(
+        r#"
+        SELECT e.id, e.name, c.iocCode as country, e.point_system
+        FROM event e
+        LEFT JOIN country c ON e.country_id = c.id
+        ORDER BY e.id
+        "#,
+    )
+    .fetch_all(db)
+    .await?
+    {
+        let season_rows = sqlx::query(
+            "SELECT id, year, display_name FROM season WHERE event_id = ? ORDER BY year, id",
+        )
+        .bind(event_row.get::<i64, _>("id"))
+        .fetch_all(db)
+        .await?;
+
+        let mut seasons = Vec::with_capacity(season_rows.len());
+        for season_row in season_rows {
+            let season_id: i64 = season_row.get("id");
+            seasons.push(SeasonRecord {
+                year: season_row.get("year"),
+                display_name: season_row.get("display_name"),
+                participations: season_participations(db, season_id, &team_refs).await?,
+                matches: season_matches(db, &mut conn, season_id, &team_refs).await?,
+            });
+        }
+
+        events.push(EventRecord {
+            name: event_row.get("name"),
+            country: event_row.get("country"),
+            point_system: event_row.get("point_system"),
+            seasons,
+        });
+    }
+
+    Ok(InstanceFile {
+        format: INSTANCE_EXPORT_FORMAT.to_string(),
+        version: INSTANCE_EXPORT_VERSION,
+        exported_at: Some(chrono::Utc::now().to_rfc3339()),
+        players,
+        teams,
+        events,
+    })
+}
+
+/// Teams of a season with their rosters
+async fn season_participations(
+    db: &SqlitePool,
+    season_id: i64,
+    team_refs: &HashMap<i64, TeamRef>,
+) -> Result<Vec<SeasonParticipationRecord>, sqlx::Error> {
+    let rows =
+        sqlx::query("SELECT id, team_id FROM team_participation WHERE season_id = ? ORDER BY id")
+            .bind(season_id)
+            .fetch_all(db)
+            .await?;
+
+    let mut participations = Vec::with_capacity(rows.len());
+    for row in rows {
+        let roster = sqlx::query(
+            r#"
+            SELECT player_id, jersey_number
+            FROM player_contract
+            WHERE team_participation_id = ?
+            ORDER BY jersey_number IS NULL, jersey_number, player_id
+            "#,
+        )
+        .bind(row.get::<i64, _>("id"))
+        .fetch_all(db)
+        .await?
+        .into_iter()
+        .map(|r| RosterRecord {
+            player: r.get("player_id"),
+            jersey_number: r.get("jersey_number"),
+        })
+        .collect();
+
+        participations.push(SeasonParticipationRecord {
+            team: team_refs[&row.get::<i64, _>("team_id")].clone(),
+            roster,
+        });
+    }
+
+    Ok(participations)
+}
+
+/// Matches of a season with their goals, in date order
+async fn season_matches(
+    db: &SqlitePool,
+    conn: &mut sqlx::SqliteConnection,
+    season_id: i64,
+    team_refs: &HashMap<i64, TeamRef>,
+) -> Result<Vec<SeasonMatchRecord>, sqlx::Error> {
+    let rows = sqlx::query(
+        r#"
+        SELECT m.id, m.home_team_id, m.away_team_id, m.home_score_unidentified,
+               m.away_score_unidentified, m.match_date, m.status, m.game_type, ar.name as venue
+        FROM match m
+        LEFT JOIN arena ar ON m.arena_id = ar.id
+        WHERE m.season_id = ?
+        ORDER BY m.match_date IS NULL, m.match_date, m.id
+        "#,
+    )
+    .bind(season_id)
+    .fetch_all(db)
+    .await?;
+
+    let mut matches = Vec::with_capacity(rows.len());
+    for row in rows {
+        let match_id: i64 = row.get("id");
+        let home_team_id: i64 = row.get("home_team_id");
+
+        let score_events = sqlx::query(
+            r#"
+            SELECT team_id, scorer_id, assist1_id, assist2_id, period, time_minutes, time_seconds, goal_type
+            FROM score_event
+            WHERE match_id = ?
+            ORDER BY period, time_minutes, time_seconds, id
+            "#,
+        )
+        .bind(match_id)
+        .fetch_all(db)
+        .await?
+        .into_iter()
+        .map(|se| ScoreEventRecord {
+            home: se.get::<i64, _>("team_id") == home_team_id,
+            scorer: se.get("scorer_id"),
+            assist1: se.get("assist1_id"),
+            assist2: se.get("assist2_id"),
+            period: se.get("period"),
+            time_minutes: se.get("time_minutes"),
+            time_seconds: se.get("time_seconds"),
+            goal_type: se.get("goal_type"),
+        })
+        .collect();
+
+        matches.push(SeasonMatchRecord {
+            external_id: Some(
+                external_ids::external_id_for(conn, ExternalEntity::Match, match_id).await?,
+            ),
+            home_team: team_refs[&home_team_id].clone(),
+            away_team: team_refs[&row.get::<i64, _>("away_team_id")].clone(),
+            home_score_unidentified: row.get("home_score_unidentified"),
+            away_score_unidentified: row.get("away_score_unidentified"),
+            match_date: row.get("match_date"),
+            status: row.get("status"),
+            game_type: row.get("game_type"),
+            venue: row.get("venue"),
+            score_events,
+        });
+    }
+
+    Ok(matches)
+}
+
+/// Import an instance export in a single transaction
+///
+/// Records are resolved the same way as in team history imports (see
+/// [`crate::service::team_history::import_team_history`]), with every ID of
+/// the file remapped to the local one. The event's point system is set on
+/// events the import creates, and on existing ones when merging.
+///
+/// The file must be validated first: every country code must exist and every
+/// player reference must point to a listed player.
+pub async fn import_instance(
+    db: &SqlitePool,
+    file: &InstanceFile,
+    countries: &HashMap<String, i64>,
+    mode: ImportMode,
+) -> Result<InstanceImportSummary, sqlx::Error> {
+    let mut tx = db.begin().await?;
+    let mut importer = Importer::new(&mut tx, mode, countries);
+
+    let mut player_ids: HashMap<i64, i64> = HashMap::new();
+    for player in &file.players {
+        let id = importer.player(player).await?;
+        player_ids.insert(player.key, id);
+    }
+
+    for team in &file.teams {
+        importer.team(team).await?;
+    }
+
+    for event in &file.events {
+        let (event_id, outcome) = importer
+            .event(&event.name, event.country.as_deref())
+            .await?;
+        let point_system = event
+            .point_system
+            .as_deref()
+            .and_then(PointSystem::from_str);
+        if let Some(point_system) = point_system {
+            if outcome == Outcome::Created || mode == ImportMode::Merge {
+                sqlx::query("UPDATE event SET point_system = ? WHERE id = ?")
+                    .bind(point_system.as_str())
+                    .bind(event_id)
+                    .execute(importer.conn())
+                    .await?;
+            }
+        }
+
+        for season in &event.seasons {
+            let season_ref = event.season_ref(season);
+            let (season_id, event_id) = importer.season(&season_ref).await?;
+
+            for participation in &season.participations {
+                let (team_id, _) = importer.team(&participation.team).await?;
+                let tp_id = importer.participation(team_id, season_id, event_id).await?;
+
+                for entry in &participation.roster {
+                    if let Some(&player_id) = player_ids.get(&entry.player) {
+                        importer
+                            .contract(tp_id, player_id, entry.jersey_number)
+                            .await?;
+                    }
+                }
+            }
+
+            for record in &season.matches {
+                importer
+                    .game(&record.to_match_record(&season_ref), &player_ids)
+                    .await?;
+            }
+        }
+    }
+
+    let counts = importer.counts;
+    let summary = InstanceImportSummary {
+        mode,
+        teams: counts.teams,
+        events: counts.events,
+        seasons: counts.seasons,
+        participations: counts.participations,
+        players: counts.players,
+        contracts: counts.contracts,
+        matches: counts.matches,
+        score_events: counts.score_events,
+    };
+
+    // Rows above are inserted directly, so give new records their slugs
+    slugs::assign_missing_slugs(&mut tx).await?;
+    tx.commit().await?;
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::player_contracts::add_player_to_roster;
+
+    async fn seed_instance(pool: &SqlitePool) {
+        add_player_to_roster(pool, 1, 1, Some(97)).await.unwrap();
+        add_player_to_roster(pool, 2, 9, None).await.unwrap();
+
+        sqlx::query(
+            "UPDATE event SET point_system = 'iihf' WHERE id = 1;
+             INSERT INTO match (id, season_id, home_team_id, away_team_id, home_score_unidentified, match_date, status, game_type)
+                VALUES (1, 1, 1, 2, 1, '2022-02-10', 'finished', 'playoff');
+             INSERT INTO score_event (match_id, team_id, scorer_id, assist1_id, period)
+                VALUES (1, 1, 1, NULL, 1), (1, 2, 9, NULL, 2);",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn countries_for(pool: &SqlitePool, file: &InstanceFile) -> HashMap<String, i64> {
+        let mut countries = HashMap::new();
+        for code in file.country_codes() {
+            let id = super::super::team_history::find_country_by_code(pool, code)
+                .await
+                .unwrap()
+                .unwrap();
+            countries.insert(code.to_string(), id);
+        }
+        countries
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_export_instance(pool: SqlitePool) {
+        seed_instance(&pool).await;
+
+        let file = export_instance(&pool).await.unwrap();
+        assert_eq!(file.format, INSTANCE_EXPORT_FORMAT);
+        assert_eq!(file.players.len(), 10);
+        assert!(file.teams.iter().any(|t| t.name == "Team Canada"));
+
+        let olympics = &file.events[0];
+        assert_eq!(olympics.point_system.as_deref(), Some("iihf"));
+        let season = &olympics.seasons[0];
+        assert_eq!(season.year, 2022);
+        assert_eq!(season.participations.len(), 2);
+        assert_eq!(season.participations[0].team.name, "Team Canada");
+        assert_eq!(season.participations[0].roster[0].jersey_number, Some(97));
+        assert_eq!(season.matches.len(), 1);
+        assert_eq!(season.matches[0].game_type.as_deref(), Some("playoff"));
+        assert!(season.matches[0].score_events[0].home);
+        assert_eq!(season.matches[0].score_events[0].scorer, Some(1));
+        assert!(file.referenced_player_keys().contains(&9));
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_import_instance_remaps_ids(pool: SqlitePool) {
+        seed_instance(&pool).await;
+        let file = export_instance(&pool).await.unwrap();
+        let countries = countries_for(&pool, &file).await;
+
+        // Importing an instance into itself finds everything
+        let summary = import_instance(&pool, &file, &countries, ImportMode::AddMissing)
+            .await
+            .unwrap();
+        assert_eq!(summary.events.created, 0);
+        assert_eq!(summary.players.created, 0);
+        assert_eq!(summary.contracts.created, 0);
+        assert_eq!(summary.matches.created, 0);
+        assert_eq!(summary.matches.unchanged, 1);
+
+        // A renamed event is created with its seasons, rosters and matches,
+        // pointing at the existing teams and players
+        let mut renamed = file.clone();
+        renamed.events.truncate(1);
+        renamed.events[0].name = "Olympic Archive".to_string();
+        renamed.events[0].seasons[0].matches[0].external_id = None;
+        let summary = import_instance(&pool, &renamed, &countries, ImportMode::AddMissing)
+            .await
+            .unwrap();
+        assert_eq!(summary.events.created, 1);
+        assert_eq!(summary.seasons.created, 1);
+        assert_eq!(summary.participations.created, 2);
+        assert_eq!(summary.contracts.created, 2);
+        assert_eq!(summary.matches.created, 1);
+        assert_eq!(summary.score_events.created, 2);
+        assert_eq!(summary.teams.created, 0);
+
+        let event =
+            sqlx::query("SELECT id, point_system FROM event WHERE name = 'Olympic Archive'")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(event.get::<String, _>("point_system"), "iihf");
+        let game = sqlx::query(
+            "SELECT m.game_type, (SELECT scorer_id FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id) as scorer
+             FROM match m INNER JOIN season s ON m.season_id = s.id WHERE s.event_id = ?",
+        )
+        .bind(event.get::<i64, _>("id"))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(game.get::<String, _>("game_type"), "playoff");
+        assert_eq!(game.get::<Option<i64>, _>("scorer"), Some(1));
+    }
+}
@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+
+/// Default number of players listed per leaderboard
+pub const DEFAULT_LEADERS_LIMIT: usize = 10;
+
+/// Scoring totals of a player in a season
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LeaderEntity {
+    pub player_id: i64,
+    pub player_name: String,
+    /// Teams the player recorded points for, comma separated
+    pub team_names: String,
+    pub goals: i64,
+    pub assists: i64,
+    pub points: i64,
+}
+
+/// Top goal scorers, assist leaders and point leaders of a season
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeasonLeadersEntity {
+    pub goals: Vec<LeaderEntity>,
+    pub assists: Vec<LeaderEntity>,
+    pub points: Vec<LeaderEntity>,
+}
+
+impl SeasonLeadersEntity {
+    /// Rank players by each category, keeping the top `limit`
+    ///
+    /// Players with nothing in a category are left out of it. Ties are broken
+    /// by the other categories, then by name.
+    pub fn from_players(players: &[LeaderEntity], limit: usize) -> Self {
+        let rank = |key: fn(&LeaderEntity) -> (i64, i64)| {
+            let mut ranked: Vec<LeaderEntity> = players
+                .iter()
+                .filter(|player| key(player).0 > 0)
+                .cloned()
+                .collect();
+            ranked.sort_by(|a, b| {
+                key(b)
+                    .cmp(&key(a))
+                    .then_with(|| a.player_name.cmp(&b.player_name))
+            });
+            ranked.truncate(limit);
+            ranked
+        };
+
+        Self {
+            goals: rank(|p| (p.goals, p.assists)),
+            assists: rank(|p| (p.assists, p.goals)),
+            points: rank(|p| (p.points, p.goals)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+}
+
+/// Goals and assists of every player who recorded a point in the season
+///
+/// Exhibition games are left out, as in the players' season stats.
+pub async fn get_season_player_points(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<Vec<LeaderEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        LeaderEntity,
+        r#"
+        SELECT
+            pts.player_id as "player_id!: i64",
+            p.name as player_name,
+            GROUP_CONCAT(DISTINCT t.name) as "team_names!: String",
+            SUM(pts.is_goal) as "goals!: i64",
+            SUM(1 - pts.is_goal) as "assists!: i64",
+            COUNT(*) as "points!: i64"
+        FROM (
+            SELECT se.scorer_id as player_id, se.team_id, 1 as is_goal
+            FROM score_event se
+            INNER JOIN match m ON se.match_id = m.id
+            WHERE m.season_id = ?1 AND m.game_type != 'exhibition' AND se.scorer_id IS NOT NULL
+            UNION ALL
+            SELECT se.assist1_id, se.team_id, 0
+            FROM score_event se
+            INNER JOIN match m ON se.match_id = m.id
+            WHERE m.season_id = ?1 AND m.game_type != 'exhibition' AND se.assist1_id IS NOT NULL
+            UNION ALL
+            SELECT se.assist2_id, se.team_id, 0
+            FROM score_event se
+            INNER JOIN match m ON se.match_id = m.id
+            WHERE m.season_id = ?1 AND m.game_type != 'exhibition' AND se.assist2_id IS NOT NULL
+        ) pts
+        INNER JOIN player p ON pts.player_id = p.id
+        INNER JOIN team t ON pts.team_id = t.id
+        GROUP BY pts.player_id, p.name
+        ORDER BY pts.player_id
+        "#,
+        season_id
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Leaderboards of a season, `limit` players per category
+pub async fn get_season_leaders(
+    db: &SqlitePool,
+    season_id: i64,
+    limit: usize,
+) -> Result<SeasonLeadersEntity, sqlx::Error> {
+    let players = get_season_player_points(db, season_id).await?;
+    Ok(SeasonLeadersEntity::from_players(&players, limit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_get_season_leaders(pool: SqlitePool) {
+        sqlx::query(
+            "INSERT INTO player (id, name, country_id) VALUES
+                (1, 'Sniper', 34), (2, 'Setup', 34), (3, 'Grinder', 34);
+             INSERT INTO match (id, season_id, home_team_id, away_team_id)
+                VALUES (1, 1, 1, 2), (2, 2, 1, 2);
+             INSERT INTO score_event (match_id, team_id, scorer_id, assist1_id, assist2_id, period) VALUES
+                (1, 1, 1, 2, 3, 1), (1, 1, 1, 2, NULL, 2), (1, 1, 2, 3, NULL, 3),
+                (1, 1, NULL, NULL, NULL, 3), (2, 1, 3, NULL, NULL, 1);",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let leaders = get_season_leaders(&pool, 1, DEFAULT_LEADERS_LIMIT)
+            .await
+            .unwrap();
+        let ids = |leaders: &[LeaderEntity]| -> Vec<i64> {
+            leaders.iter().map(|leader| leader.player_id).collect()
+        };
+
+        // Sniper 2G, Setup 1G 2A, Grinder 0G 2A; the other season's goal is ignored
+        assert_eq!(ids(&leaders.goals), vec![1, 2]);
+        assert_eq!(ids(&leaders.assists), vec![2, 3]);
+        assert_eq!(ids(&leaders.points), vec![2, 1, 3]);
+        assert_eq!(leaders.points[0].points, 3);
+
+        // An exhibition goal changes no totals
+        sqlx::query(
+            "INSERT INTO match (id, season_id, home_team_id, away_team_id, game_type)
+                VALUES (3, 1, 1, 2, 'exhibition');
+             INSERT INTO score_event (match_id, team_id, scorer_id, assist1_id, period)
+                VALUES (3, 1, 1, 2, 1);",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let after = get_season_leaders(&pool, 1, DEFAULT_LEADERS_LIMIT)
+            .await
+            .unwrap();
+        assert_eq!(after, leaders);
+
+        let top = get_season_leaders(&pool, 1, 1).await.unwrap();
+        assert_eq!(ids(&top.points), vec![2]);
+
+        assert!(get_season_leaders(&pool, 3, DEFAULT_LEADERS_LIMIT)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,299 @@
+use sqlx::SqlitePool;
+
+use crate::service::player_contracts::Captaincy;
+
+/// Unit a roster player can be assigned to on the lines chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineUnit {
+    Forward,
+    Defense,
+    Goalie,
+}
+
+impl LineUnit {
+    pub const ALL: [LineUnit; 3] = [LineUnit::Forward, LineUnit::Defense, LineUnit::Goalie];
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "forward" => Some(LineUnit::Forward),
+            "defense" => Some(LineUnit::Defense),
+            "goalie" => Some(LineUnit::Goalie),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineUnit::Forward => "forward",
+            LineUnit::Defense => "defense",
+            LineUnit::Goalie => "goalie",
+        }
+    }
+
+    /// Number of lines (forward lines, defense pairs, goalie depth)
+    pub fn line_count(&self) -> i64 {
+        match self {
+            LineUnit::Forward => 4,
+            LineUnit::Defense => 3,
+            LineUnit::Goalie => 3,
+        }
+    }
+
+    /// Number of players per line (LW/C/RW, LD/RD, G)
+    pub fn slot_count(&self) -> i64 {
+        match self {
+            LineUnit::Forward => 3,
+            LineUnit::Defense => 2,
+            LineUnit::Goalie => 1,
+        }
+    }
+
+    pub fn is_valid_slot(&self, line_number: i64, slot: i64) -> bool {
+        (1..=self.line_count()).contains(&line_number) && (1..=self.slot_count()).contains(&slot)
+    }
+}
+
+/// A roster player placed on the lines chart
+#[derive(Debug, Clone)]
+pub struct LineAssignmentEntity {
+    pub player_contract_id: i64,
+    pub unit: String,
+    pub line_number: i64,
+    pub slot: i64,
+    pub player_name: String,
+    pub jersey_number: Option<i64>,
+    pub captaincy: Option<String>,
+}
+
+impl LineAssignmentEntity {
+    pub fn unit(&self) -> Option<LineUnit> {
+        LineUnit::from_str(&self.unit)
+    }
+
+    pub fn captaincy(&self) -> Option<Captaincy> {
+        self.captaincy.as_deref().and_then(Captaincy::from_str)
+    }
+
+    pub fn is_at(&self, unit: LineUnit, line_number: i64, slot: i64) -> bool {
+        self.unit() == Some(unit) && self.line_number == line_number && self.slot == slot
+    }
+}
+
+/// Get the lines chart of a team participation
+pub async fn get_line_assignments(
+    db: &SqlitePool,
+    team_participation_id: i64,
+) -> Result<Vec<LineAssignmentEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        LineAssignmentEntity,
+        r#"
+        SELECT
+            la.player_contract_id,
+            la.unit,
+            la.line_number,
+            la.slot,
+            p.name as player_name,
+            pc.jersey_number,
+            pc.captaincy
+        FROM line_assignment la
+        INNER JOIN player_contract pc ON la.player_contract_id = pc.id
+        INNER JOIN player p ON pc.player_id = p.id
+        WHERE la.team_participation_id = ?
+        ORDER BY la.unit, la.line_number, la.slot
+        "#,
+        team_participation_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Place a roster player into a slot of the lines chart
+///
+/// The player leaves their previous slot. If the target slot is taken, its
+/// occupant swaps into the player's previous slot, or becomes unassigned when
+/// the player came from outside the chart.
+pub async fn assign_player_to_slot(
+    db: &SqlitePool,
+    team_participation_id: i64,
+    player_contract_id: i64,
+    unit: LineUnit,
+    line_number: i64,
+    slot: i64,
+) -> Result<(), sqlx::Error> {
+    let unit = unit.as_str();
+    let mut tx = db.begin().await?;
+
+    let previous = sqlx::query!(
+        "SELECT unit, line_number, slot FROM line_assignment WHERE player_contract_id = ?",
+        player_contract_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let occupant = sqlx::query_scalar!(
+        r#"
+        SELECT player_contract_id
+        FROM line_assignment
+        WHERE team_participation_id = ? AND unit = ? AND line_number = ? AND slot = ?
+        "#,
+        team_participation_id,
+        unit,
+        line_number,
+        slot
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    if occupant == Some(player_contract_id) {
+        return Ok(());
+    }
+
+    sqlx::query!(
+        "DELETE FROM line_assignment WHERE player_contract_id = ?",
+        player_contract_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if let Some(occupant_id) = occupant {
+        sqlx::query!(
+            "DELETE FROM line_assignment WHERE player_contract_id = ?",
+            occupant_id
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if let Some(prev) = &previous {
+            sqlx::query!(
+                r#"
+                INSERT INTO line_assignment (team_participation_id, player_contract_id, unit, line_number, slot)
+                VALUES (?, ?, ?, ?, ?)
+                "#,
+                team_participation_id,
+                occupant_id,
+                prev.unit,
+                prev.line_number,
+                prev.slot
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+    }
+
+    sqlx::query!(
+        r#"
+        INSERT INTO line_assignment (team_participation_id, player_contract_id, unit, line_number, slot)
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+        team_participation_id,
+        player_contract_id,
+        unit,
+        line_number,
+        slot
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Take a roster player off the lines chart
+pub async fn unassign_player(
+    db: &SqlitePool,
+    team_participation_id: i64,
+    player_contract_id: i64,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        "DELETE FROM line_assignment WHERE team_participation_id = ? AND player_contract_id = ?",
+        team_participation_id,
+        player_contract_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::player_contracts::{add_player_to_roster, remove_player_from_roster};
+
+    async fn slot_of(pool: &SqlitePool, contract_id: i64) -> Option<(String, i64, i64)> {
+        get_line_assignments(pool, 1)
+            .await
+            .unwrap()
+            .into_iter()
+            .find(|a| a.player_contract_id == contract_id)
+            .map(|a| (a.unit, a.line_number, a.slot))
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_assign_swaps_occupied_slot(pool: SqlitePool) {
+        let first = add_player_to_roster(&pool, 1, 1, None).await.unwrap();
+        let second = add_player_to_roster(&pool, 1, 2, None).await.unwrap();
+        let third = add_player_to_roster(&pool, 1, 3, None).await.unwrap();
+
+        assign_player_to_slot(&pool, 1, first, LineUnit::Forward, 1, 2)
+            .await
+            .unwrap();
+        assign_player_to_slot(&pool, 1, second, LineUnit::Forward, 2, 2)
+            .await
+            .unwrap();
+
+        // Moving onto an occupied slot swaps the two players
+        assign_player_to_slot(&pool, 1, second, LineUnit::Forward, 1, 2)
+            .await
+            .unwrap();
+        assert_eq!(
+            slot_of(&pool, second).await,
+            Some(("forward".to_string(), 1, 2))
+        );
+        assert_eq!(
+            slot_of(&pool, first).await,
+            Some(("forward".to_string(), 2, 2))
+        );
+
+        // A player from outside the chart pushes the occupant off
+        assign_player_to_slot(&pool, 1, third, LineUnit::Forward, 1, 2)
+            .await
+            .unwrap();
+        assert_eq!(slot_of(&pool, second).await, None);
+        assert_eq!(get_line_assignments(&pool, 1).await.unwrap().len(), 2);
+
+        assert!(!unassign_player(&pool, 2, third).await.unwrap());
+        assert!(unassign_player(&pool, 1, third).await.unwrap());
+        assert_eq!(slot_of(&pool, third).await, None);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_removing_player_from_roster_clears_slot(pool: SqlitePool) {
+        let contract = add_player_to_roster(&pool, 1, 1, None).await.unwrap();
+        assign_player_to_slot(&pool, 1, contract, LineUnit::Goalie, 1, 1)
+            .await
+            .unwrap();
+
+        remove_player_from_roster(&pool, contract).await.unwrap();
+
+        assert!(get_line_assignments(&pool, 1).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_valid_slots() {
+        assert!(LineUnit::Forward.is_valid_slot(4, 3));
+        assert!(!LineUnit::Forward.is_valid_slot(5, 1));
+        assert!(!LineUnit::Defense.is_valid_slot(1, 3));
+        assert!(LineUnit::Goalie.is_valid_slot(3, 1));
+        assert!(!LineUnit::Goalie.is_valid_slot(0, 1));
+    }
+}
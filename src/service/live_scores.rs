@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+
+/// One team of a scoreboard entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreboardTeam {
+    pub name: String,
+    pub country_iso2: Option<String>,
+    /// Identified plus unidentified goals
+    pub score: i64,
+}
+
+/// Minimal match data for public scoreboards
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreboardMatch {
+    pub id: i64,
+    pub event_name: Option<String>,
+    pub match_date: Option<String>,
+    pub status: String,
+    pub home: ScoreboardTeam,
+    pub away: ScoreboardTeam,
+}
+
+struct ScoreboardRow {
+    id: i64,
+    event_name: Option<String>,
+    match_date: Option<String>,
+    status: String,
+    home_team_name: String,
+    home_country_iso2: Option<String>,
+    home_score: i64,
+    away_team_name: String,
+    away_country_iso2: Option<String>,
+    away_score: i64,
+}
+
+impl From<ScoreboardRow> for ScoreboardMatch {
+    fn from(row: ScoreboardRow) -> Self {
+        Self {
+            id: row.id,
+            event_name: row.event_name,
+            match_date: row.match_date,
+            status: row.status,
+            home: ScoreboardTeam {
+                name: row.home_team_name,
+                country_iso2: row.home_country_iso2,
+                score: row.home_score,
+            },
+            away: ScoreboardTeam {
+                name: row.away_team_name,
+                country_iso2: row.away_country_iso2,
+                score: row.away_score,
+            },
+        }
+    }
+}
+
+/// Matches currently in progress, earliest start first
+pub async fn get_live_matches(db: &SqlitePool) -> Result<Vec<ScoreboardMatch>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        ScoreboardRow,
+        r#"
+        SELECT
+            m.id as "id!",
+            e.name as "event_name?",
+            m.match_date,
+            m.status,
+            ht.name as home_team_name,
+            hc.iso2Code as "home_country_iso2?",
+            m.home_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id) as "home_score!: i64",
+            at.name as away_team_name,
+            ac.iso2Code as "away_country_iso2?",
+            m.away_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.away_team_id) as "away_score!: i64"
+        FROM match m
+        INNER JOIN team ht ON m.home_team_id = ht.id
+        INNER JOIN team at ON m.away_team_id = at.id
+        LEFT JOIN country hc ON ht.country_id = hc.id
+        LEFT JOIN country ac ON at.country_id = ac.id
+        LEFT JOIN season s ON m.season_id = s.id
+        LEFT JOIN event e ON s.event_id = e.id
+        WHERE m.status = 'in_progress'
+        ORDER BY m.match_date, m.id
+        "#
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows.into_iter().map(ScoreboardMatch::from).collect())
+}
+
+/// All matches on a day (`YYYY-MM-DD`) regardless of status, by start time
+pub async fn get_matches_on_date(
+    db: &SqlitePool,
+    date: &str,
+) -> Result<Vec<ScoreboardMatch>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        ScoreboardRow,
+        r#"
+        SELECT
+            m.id as "id!",
+            e.name as "event_name?",
+            m.match_date,
+            m.status,
+            ht.name as home_team_name,
+            hc.iso2Code as "home_country_iso2?",
+            m.home_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id) as "home_score!: i64",
+            at.name as away_team_name,
+            ac.iso2Code as "away_country_iso2?",
+            m.away_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.away_team_id) as "away_score!: i64"
+        FROM match m
+        INNER JOIN team ht ON m.home_team_id = ht.id
+        INNER JOIN team at ON m.away_team_id = at.id
+        LEFT JOIN country hc ON ht.country_id = hc.id
+        LEFT JOIN country ac ON at.country_id = ac.id
+        LEFT JOIN season s ON m.season_id = s.id
+        LEFT JOIN event e ON s.event_id = e.id
+        WHERE substr(m.match_date, 1, 10) = ?
+        ORDER BY m.match_date, m.id
+        "#,
+        date
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows.into_iter().map(ScoreboardMatch::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{
+        create_match, create_score_event, CreateMatchEntity, CreateScoreEventEntity,
+    };
+
+    async fn scheduled_match(pool: &SqlitePool, date: &str, status: &str) -> i64 {
+        create_match(
+            pool,
+            CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: 1,
+                away_score_unidentified: 0,
+                match_date: Some(date.to_string()),
+                status: status.to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_live_and_daily_scoreboards(pool: SqlitePool) {
+        let live = scheduled_match(&pool, "2024-02-10T18:00", "in_progress").await;
+        create_score_event(
+            &pool,
+            CreateScoreEventEntity {
+                match_id: live,
+                team_id: 2,
+                scorer_id: None,
+                assist1_id: None,
+                assist2_id: None,
+                period: 1,
+                time_minutes: None,
+                time_seconds: None,
+                goal_type: None,
+                video_url: None,
+                video_timestamp: None,
+            },
+        )
+        .await
+        .unwrap();
+        let earlier = scheduled_match(&pool, "2024-02-10T12:00", "finished").await;
+        scheduled_match(&pool, "2024-02-11T12:00", "scheduled").await;
+
+        let matches = get_live_matches(&pool).await.unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].id, live);
+        assert_eq!((matches[0].home.score, matches[0].away.score), (1, 1));
+
+        let day: Vec<i64> = get_matches_on_date(&pool, "2024-02-10")
+            .await
+            .unwrap()
+            .iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(day, vec![earlier, live]);
+    }
+}
@@ -0,0 +1,228 @@
+use sqlx::SqlitePool;
+
+/// A maintenance task run by the scheduled job
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MaintenanceTask {
+    /// Copy the WAL back into the database file and truncate it
+    Checkpoint,
+    /// `PRAGMA optimize` (refreshes query planner statistics)
+    Optimize,
+    /// `VACUUM INTO` a backup file
+    Backup,
+}
+
+impl MaintenanceTask {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MaintenanceTask::Checkpoint => "checkpoint",
+            MaintenanceTask::Optimize => "optimize",
+            MaintenanceTask::Backup => "backup",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "checkpoint" => Some(MaintenanceTask::Checkpoint),
+            "optimize" => Some(MaintenanceTask::Optimize),
+            "backup" => Some(MaintenanceTask::Backup),
+            _ => None,
+        }
+    }
+}
+
+/// A recorded maintenance task result
+#[derive(Debug, Clone)]
+pub struct MaintenanceRunEntity {
+    #[allow(dead_code)]
+    pub id: i64,
+    pub task: String,
+    pub success: bool,
+    pub detail: Option<String>,
+    pub duration_ms: i64,
+    pub started_at: String,
+}
+
+impl MaintenanceRunEntity {
+    pub fn task(&self) -> Option<MaintenanceTask> {
+        MaintenanceTask::from_str(&self.task)
+    }
+}
+
+/// Outcome of `PRAGMA wal_checkpoint`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CheckpointResult {
+    /// A reader or writer prevented the checkpoint from completing
+    pub busy: bool,
+    /// Frames in the WAL before the checkpoint (-1 when not in WAL mode)
+    pub log_frames: i64,
+    /// Frames copied back into the database file
+    pub checkpointed_frames: i64,
+}
+
+/// Storage figures shown on the status page
+#[derive(Debug, Clone, Default)]
+pub struct DatabaseInfo {
+    pub journal_mode: String,
+    pub page_size: i64,
+    pub page_count: i64,
+    pub freelist_count: i64,
+}
+
+impl DatabaseInfo {
+    pub fn size_bytes(&self) -> i64 {
+        self.page_size * self.page_count
+    }
+
+    /// Space held by free pages, which only `VACUUM` gives back
+    pub fn free_bytes(&self) -> i64 {
+        self.page_size * self.freelist_count
+    }
+}
+
+/// Checkpoint the WAL and truncate it to zero bytes
+pub async fn checkpoint_wal(db: &SqlitePool) -> Result<CheckpointResult, sqlx::Error> {
+    let (busy, log_frames, checkpointed_frames): (i64, i64, i64) =
+        sqlx::query_as("PRAGMA wal_checkpoint(TRUNCATE)")
+            .fetch_one(db)
+            .await?;
+
+    Ok(CheckpointResult {
+        busy: busy != 0,
+        log_frames,
+        checkpointed_frames,
+    })
+}
+
+/// Let SQLite refresh statistics for tables whose contents changed
+pub async fn optimize(db: &SqlitePool) -> Result<(), sqlx::Error> {
+    sqlx::query("PRAGMA optimize").execute(db).await?;
+    Ok(())
+}
+
+/// Write a compacted copy of the database to `path`
+///
+/// The target file must not exist yet.
+pub async fn vacuum_into(db: &SqlitePool, path: &str) -> Result<(), sqlx::Error> {
+    sqlx::query("VACUUM INTO ?").bind(path).execute(db).await?;
+    Ok(())
+}
+
+/// Get journal mode and page figures of the database
+pub async fn get_database_info(db: &SqlitePool) -> Result<DatabaseInfo, sqlx::Error> {
+    let journal_mode: String = sqlx::query_scalar("PRAGMA journal_mode")
+        .fetch_one(db)
+        .await?;
+    let page_size: i64 = sqlx::query_scalar("PRAGMA page_size").fetch_one(db).await?;
+    let page_count: i64 = sqlx::query_scalar("PRAGMA page_count")
+        .fetch_one(db)
+        .await?;
+    let freelist_count: i64 = sqlx::query_scalar("PRAGMA freelist_count")
+        .fetch_one(db)
+        .await?;
+
+    Ok(DatabaseInfo {
+        journal_mode,
+        page_size,
+        page_count,
+        freelist_count,
+    })
+}
+
+/// Record the result of a maintenance task
+pub async fn record_run(
+    db: &SqlitePool,
+    task: MaintenanceTask,
+    success: bool,
+    detail: Option<&str>,
+    duration_ms: i64,
+) -> Result<i64, sqlx::Error> {
+    let task = task.as_str();
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO maintenance_run (task, success, detail, duration_ms)
+        VALUES (?, ?, ?, ?)
+        "#,
+        task,
+        success,
+        detail,
+        duration_ms
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Get the most recent maintenance task results
+pub async fn get_recent_runs(
+    db: &SqlitePool,
+    limit: i64,
+) -> Result<Vec<MaintenanceRunEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        MaintenanceRunEntity,
+        r#"
+        SELECT
+            id as "id!",
+            task,
+            success as "success: bool",
+            detail,
+            duration_ms,
+            started_at
+        FROM maintenance_run
+        ORDER BY started_at DESC, id DESC
+        LIMIT ?
+        "#,
+        limit
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_maintenance_tasks_and_history(pool: SqlitePool) {
+        // Test databases are not in WAL mode, so there is nothing to copy back
+        let checkpoint = checkpoint_wal(&pool).await.unwrap();
+        assert!(!checkpoint.busy);
+        optimize(&pool).await.unwrap();
+
+        let info = get_database_info(&pool).await.unwrap();
+        assert!(info.page_count > 0);
+        assert!(info.size_bytes() >= info.free_bytes());
+
+        record_run(&pool, MaintenanceTask::Checkpoint, true, None, 3)
+            .await
+            .unwrap();
+        record_run(&pool, MaintenanceTask::Backup, false, Some("disk full"), 10)
+            .await
+            .unwrap();
+
+        let runs = get_recent_runs(&pool, 10).await.unwrap();
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].task(), Some(MaintenanceTask::Backup));
+        assert!(!runs[0].success);
+        assert_eq!(runs[0].detail.as_deref(), Some("disk full"));
+        assert!(runs[1].success);
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_vacuum_into_writes_backup(pool: SqlitePool) {
+        let dir = std::env::temp_dir().join(format!("hockey-vacuum-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("backup.db");
+        let _ = std::fs::remove_file(&path);
+
+        vacuum_into(&pool, path.to_str().unwrap()).await.unwrap();
+        assert!(std::fs::metadata(&path).unwrap().len() > 0);
+
+        // VACUUM INTO refuses to overwrite an existing file
+        assert!(vacuum_into(&pool, path.to_str().unwrap()).await.is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
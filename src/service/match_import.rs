@@ -0,0 +1,468 @@
+//! Creating finished matches in bulk from rows of season, teams, date and
+//! final score
+//!
+//! Seasons are named by ID or by their label ("2024 World Championship" or
+//! "IIHF World Championship 2024"), teams by name or external alias among the
+//! teams taking part in the season, ignoring case, accents and word order.
+//! Importing is all or nothing: [`plan_match_import`] checks every row and
+//! [`apply_match_import`] creates the matches of a plan without errors in a
+//! single transaction. The final score is recorded as unidentified goals, to
+//! be broken down into goals later.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::SqlitePool;
+
+use crate::service::matches::{refresh_match_result, GameType};
+use crate::service::reports::normalize_name;
+
+/// Highest score accepted for one team
+const MAX_SCORE: i64 = 99;
+
+/// A match row, as written in the file or request
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MatchImportRow {
+    /// Line in the file counting the header as line 1, or position in the
+    /// request counting from 1
+    pub line: usize,
+    pub season: String,
+    pub home_team: String,
+    pub away_team: String,
+    pub date: String,
+    pub home_score: String,
+    pub away_score: String,
+}
+
+/// Why a row cannot be imported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchRowError {
+    MissingSeason,
+    UnknownSeason,
+    /// Several seasons have the label
+    AmbiguousSeason,
+    MissingTeam,
+    UnknownHomeTeam,
+    UnknownAwayTeam,
+    SameTeams,
+    InvalidDate,
+    InvalidScore,
+    /// The teams already played each other on that day in the season
+    AlreadyExists,
+    /// The same match appears on an earlier row
+    DuplicateRow,
+}
+
+impl MatchRowError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            MatchRowError::MissingSeason => "Season is missing",
+            MatchRowError::UnknownSeason => "Unknown season",
+            MatchRowError::AmbiguousSeason => "Several seasons have this name",
+            MatchRowError::MissingTeam => "Both teams are required",
+            MatchRowError::UnknownHomeTeam => "Home team does not take part in the season",
+            MatchRowError::UnknownAwayTeam => "Away team does not take part in the season",
+            MatchRowError::SameTeams => "Home and away teams must be different",
+            MatchRowError::InvalidDate => {
+                "Date must be written as YYYY-MM-DD, optionally with HH:MM"
+            }
+            MatchRowError::InvalidScore => "Scores must be numbers between 0 and 99",
+            MatchRowError::AlreadyExists => "The match already exists",
+            MatchRowError::DuplicateRow => "Match is listed more than once",
+        }
+    }
+}
+
+/// A match ready to be created
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewMatch {
+    pub season_id: i64,
+    pub home_team_id: i64,
+    pub away_team_id: i64,
+    /// `YYYY-MM-DD`, or `YYYY-MM-DDTHH:MM` when the row has a time
+    pub match_date: String,
+    pub home_score: i64,
+    pub away_score: i64,
+}
+
+/// A row with the match it resolves to, or why it does not
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedMatch {
+    pub row: MatchImportRow,
+    pub result: Result<NewMatch, MatchRowError>,
+}
+
+/// Checked rows of a match import
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MatchImportPlan {
+    pub rows: Vec<PlannedMatch>,
+}
+
+impl MatchImportPlan {
+    /// Rows that cannot be imported, with the reason
+    pub fn errors(&self) -> impl Iterator<Item = (&MatchImportRow, MatchRowError)> {
+        self.rows
+            .iter()
+            .filter_map(|planned| planned.result.as_ref().err().map(|e| (&planned.row, *e)))
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.errors().next().is_some()
+    }
+}
+
+/// Match date as stored, from a date with an optional time
+pub fn parse_match_date(date: &str) -> Option<String> {
+    let date = date.trim();
+    for format in ["%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M", "%d.%m.%Y %H:%M"] {
+        if let Ok(datetime) = NaiveDateTime::parse_from_str(date, format) {
+            return Some(datetime.format("%Y-%m-%dT%H:%M").to_string());
+        }
+    }
+    ["%Y-%m-%d", "%d.%m.%Y"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(date, format).ok())
+        .map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+/// Check every row and resolve its season and teams
+pub async fn plan_match_import(
+    db: &SqlitePool,
+    rows: Vec<MatchImportRow>,
+) -> Result<MatchImportPlan, sqlx::Error> {
+    let mut seasons: HashMap<String, Vec<i64>> = HashMap::new();
+    for season in sqlx::query!(
+        r#"
+        SELECT s.id as "id!", s.display_name, e.name as "event_name!", s.year
+        FROM season s
+        INNER JOIN event e ON e.id = s.event_id
+        ORDER BY s.id
+        "#
+    )
+    .fetch_all(db)
+    .await?
+    {
+        let mut labels = HashSet::from([normalize_name(&format!(
+            "{} {}",
+            season.event_name, season.year
+        ))]);
+        labels.extend(season.display_name.as_deref().map(normalize_name));
+        for label in labels {
+            seasons.entry(label).or_default().push(season.id);
+        }
+    }
+
+    // Names and aliases of the teams taking part, per season
+    let mut teams: HashMap<(i64, String), i64> = HashMap::new();
+    for team in sqlx::query!(
+        r#"
+        SELECT tp.season_id, t.id as "team_id!", t.name as "name!"
+        FROM team_participation tp
+        INNER JOIN team t ON t.id = tp.team_id
+        UNION ALL
+        SELECT tp.season_id, t.id, ea.external_id
+        FROM team_participation tp
+        INNER JOIN team t ON t.id = tp.team_id
+        INNER JOIN external_alias ea ON ea.entity_type = 'team' AND ea.entity_id = t.id
+        "#
+    )
+    .fetch_all(db)
+    .await?
+    {
+        teams
+            .entry((team.season_id, normalize_name(&team.name)))
+            .or_insert(team.team_id);
+    }
+
+    let existing: HashSet<(i64, i64, i64, String)> = sqlx::query!(
+        r#"
+        SELECT season_id, home_team_id, away_team_id, substr(match_date, 1, 10) as "day!: String"
+        FROM match
+        WHERE match_date IS NOT NULL
+        "#
+    )
+    .fetch_all(db)
+    .await?
+    .into_iter()
+    .map(|m| (m.season_id, m.home_team_id, m.away_team_id, m.day))
+    .collect();
+
+    let mut seen = HashSet::new();
+    let mut planned = Vec::with_capacity(rows.len());
+    for row in rows {
+        let result = resolve_row(&row, &seasons, &teams).and_then(|new| {
+            let key = (
+                new.season_id,
+                new.home_team_id,
+                new.away_team_id,
+                new.match_date[..10].to_string(),
+            );
+            if existing.contains(&key) {
+                Err(MatchRowError::AlreadyExists)
+            } else if !seen.insert(key) {
+                Err(MatchRowError::DuplicateRow)
+            } else {
+                Ok(new)
+            }
+        });
+        planned.push(PlannedMatch { row, result });
+    }
+
+    Ok(MatchImportPlan { rows: planned })
+}
+
+fn resolve_row(
+    row: &MatchImportRow,
+    seasons: &HashMap<String, Vec<i64>>,
+    teams: &HashMap<(i64, String), i64>,
+) -> Result<NewMatch, MatchRowError> {
+    let season = row.season.trim();
+    if season.is_empty() {
+        return Err(MatchRowError::MissingSeason);
+    }
+    let season_id = match season.parse::<i64>() {
+        Ok(id) => seasons
+            .values()
+            .flatten()
+            .find(|season_id| **season_id == id)
+            .copied()
+            .ok_or(MatchRowError::UnknownSeason)?,
+        Err(_) => match seasons.get(&normalize_name(season)).map(Vec::as_slice) {
+            Some([season_id]) => *season_id,
+            Some([_, _, ..]) => return Err(MatchRowError::AmbiguousSeason),
+            _ => return Err(MatchRowError::UnknownSeason),
+        },
+    };
+
+    if row.home_team.trim().is_empty() || row.away_team.trim().is_empty() {
+        return Err(MatchRowError::MissingTeam);
+    }
+    let team = |name: &str| teams.get(&(season_id, normalize_name(name))).copied();
+    let home_team_id = team(&row.home_team).ok_or(MatchRowError::UnknownHomeTeam)?;
+    let away_team_id = team(&row.away_team).ok_or(MatchRowError::UnknownAwayTeam)?;
+    if home_team_id == away_team_id {
+        return Err(MatchRowError::SameTeams);
+    }
+
+    let match_date = parse_match_date(&row.date).ok_or(MatchRowError::InvalidDate)?;
+    let score = |score: &str| {
+        score
+            .trim()
+            .parse::<i64>()
+            .ok()
+            .filter(|score| (0..=MAX_SCORE).contains(score))
+            .ok_or(MatchRowError::InvalidScore)
+    };
+
+    Ok(NewMatch {
+        season_id,
+        home_team_id,
+        away_team_id,
+        match_date,
+        home_score: score(&row.home_score)?,
+        away_score: score(&row.away_score)?,
+    })
+}
+
+/// Create the matches of a plan without errors in a single transaction
+///
+/// Returns the IDs of the created matches in row order.
+pub async fn apply_match_import(
+    db: &SqlitePool,
+    plan: &MatchImportPlan,
+) -> Result<Vec<i64>, sqlx::Error> {
+    let mut tx = db.begin().await?;
+    let game_type = GameType::Group.as_str();
+
+    let mut ids = Vec::with_capacity(plan.rows.len());
+    for new in plan
+        .rows
+        .iter()
+        .filter_map(|planned| planned.result.as_ref().ok())
+    {
+        let id = sqlx::query!(
+            r#"
+            INSERT INTO match (season_id, home_team_id, away_team_id, home_score_unidentified,
+                               away_score_unidentified, match_date, status, game_type)
+            VALUES (?, ?, ?, ?, ?, ?, 'finished', ?)
+            "#,
+            new.season_id,
+            new.home_team_id,
+            new.away_team_id,
+            new.home_score,
+            new.away_score,
+            new.match_date,
+            game_type
+        )
+        .execute(&mut *tx)
+        .await?
+        .last_insert_rowid();
+
+        refresh_match_result(&mut tx, id).await?;
+        ids.push(id);
+    }
+
+    tx.commit().await?;
+
+    Ok(ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(
+        line: usize,
+        season: &str,
+        home: &str,
+        away: &str,
+        date: &str,
+        score: (&str, &str),
+    ) -> MatchImportRow {
+        MatchImportRow {
+            line,
+            season: season.to_string(),
+            home_team: home.to_string(),
+            away_team: away.to_string(),
+            date: date.to_string(),
+            home_score: score.0.to_string(),
+            away_score: score.1.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_match_date() {
+        assert_eq!(
+            parse_match_date("2024-05-10"),
+            Some("2024-05-10".to_string())
+        );
+        assert_eq!(
+            parse_match_date("2024-05-10 20:15"),
+            Some("2024-05-10T20:15".to_string())
+        );
+        assert_eq!(
+            parse_match_date("10.5.2024"),
+            Some("2024-05-10".to_string())
+        );
+        assert_eq!(parse_match_date("2024-13-10"), None);
+        assert_eq!(parse_match_date(""), None);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_match_import(pool: SqlitePool) {
+        sqlx::query("INSERT INTO external_alias (entity_type, external_id, entity_id) VALUES ('team', 'CAN', 1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO match (season_id, home_team_id, away_team_id, match_date) VALUES (2, 3, 4, '2023-05-12T16:20')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let rows = vec![
+            row(
+                2,
+                "Winter Olympics 2022",
+                "team canada",
+                "Team USA",
+                "2022-02-16 12:10",
+                ("4", "2"),
+            ),
+            row(3, "1", "can", "USA Team", "2022-02-20", ("3", "2")),
+        ];
+        let plan = plan_match_import(&pool, rows.clone()).await.unwrap();
+        assert!(!plan.has_errors());
+        assert_eq!(
+            plan.rows[0].result,
+            Ok(NewMatch {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                match_date: "2022-02-16T12:10".to_string(),
+                home_score: 4,
+                away_score: 2,
+            })
+        );
+
+        let invalid = plan_match_import(
+            &pool,
+            vec![
+                row(2, "", "Team Canada", "Team USA", "2022-02-16", ("1", "0")),
+                row(
+                    3,
+                    "Winter Olympics 2030",
+                    "Team Canada",
+                    "Team USA",
+                    "2022-02-16",
+                    ("1", "0"),
+                ),
+                row(4, "1", "Team Russia", "Team USA", "2022-02-16", ("1", "0")),
+                row(5, "1", "Team Canada", "CAN", "2022-02-16", ("1", "0")),
+                row(6, "1", "Team Canada", "Team USA", "16/02/2022", ("1", "0")),
+                row(7, "1", "Team Canada", "Team USA", "2022-02-16", ("-1", "0")),
+                row(
+                    8,
+                    "2023 World Championship",
+                    "Team Russia",
+                    "Team Finland",
+                    "2023-05-12",
+                    ("1", "0"),
+                ),
+                row(9, "1", "Team Canada", "Team USA", "2022-02-17", ("1", "0")),
+                row(
+                    10,
+                    "1",
+                    "Team Canada",
+                    "Team USA",
+                    "2022-02-17 20:00",
+                    ("2", "0"),
+                ),
+            ],
+        )
+        .await
+        .unwrap();
+        let errors: Vec<(usize, MatchRowError)> = invalid
+            .errors()
+            .map(|(row, error)| (row.line, error))
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                (2, MatchRowError::MissingSeason),
+                (3, MatchRowError::UnknownSeason),
+                (4, MatchRowError::UnknownHomeTeam),
+                (5, MatchRowError::SameTeams),
+                (6, MatchRowError::InvalidDate),
+                (7, MatchRowError::InvalidScore),
+                (8, MatchRowError::AlreadyExists),
+                (10, MatchRowError::DuplicateRow),
+            ]
+        );
+
+        let ids = apply_match_import(&pool, &plan).await.unwrap();
+        assert_eq!(ids.len(), 2);
+        let created = sqlx::query!(
+            "SELECT status, home_score_unidentified, away_score_unidentified, game_type FROM match WHERE id = ?",
+            ids[1]
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(created.status, "finished");
+        assert_eq!(
+            (
+                created.home_score_unidentified,
+                created.away_score_unidentified
+            ),
+            (3, 2)
+        );
+        assert_eq!(created.game_type, "group");
+
+        // Importing the same rows again finds the matches
+        let again = plan_match_import(&pool, rows).await.unwrap();
+        assert_eq!(again.errors().count(), 2);
+    }
+}
@@ -0,0 +1,128 @@
+use sqlx::{Row, SqlitePool};
+
+use super::entities::AttendanceEntity;
+
+/// Attendance of a season broken down by arena, best attended first
+pub async fn get_season_attendance(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<Vec<AttendanceEntity>, sqlx::Error> {
+    let rows = sqlx::query(
+        r#"
+        SELECT
+            m.arena_id as group_id,
+            ar.name as group_name,
+            COUNT(*) as matches,
+            SUM(m.attendance) as total
+        FROM match m
+        LEFT JOIN arena ar ON m.arena_id = ar.id
+        WHERE m.season_id = ? AND m.attendance IS NOT NULL
+        GROUP BY m.arena_id
+        ORDER BY total DESC, ar.name ASC
+        "#,
+    )
+    .bind(season_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows.into_iter().map(attendance_from_row).collect())
+}
+
+/// Attendance at an arena broken down by season, most recent first
+pub async fn get_arena_attendance(
+    db: &SqlitePool,
+    arena_id: i64,
+) -> Result<Vec<AttendanceEntity>, sqlx::Error> {
+    let rows = sqlx::query(
+        r#"
+        SELECT
+            s.id as group_id,
+            e.name || ' ' || COALESCE(s.display_name, CAST(s.year AS TEXT)) as group_name,
+            COUNT(*) as matches,
+            SUM(m.attendance) as total
+        FROM match m
+        INNER JOIN season s ON m.season_id = s.id
+        INNER JOIN event e ON s.event_id = e.id
+        WHERE m.arena_id = ? AND m.attendance IS NOT NULL
+        GROUP BY s.id
+        ORDER BY s.year DESC, e.name ASC
+        "#,
+    )
+    .bind(arena_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows.into_iter().map(attendance_from_row).collect())
+}
+
+fn attendance_from_row(row: sqlx::sqlite::SqliteRow) -> AttendanceEntity {
+    AttendanceEntity {
+        group_id: row.get("group_id"),
+        group_name: row.get("group_name"),
+        matches: row.get("matches"),
+        total: row.get("total"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{create_match, CreateMatchEntity};
+
+    async fn create_test_match(
+        pool: &SqlitePool,
+        arena_id: Option<i64>,
+        attendance: Option<i64>,
+    ) -> i64 {
+        create_match(
+            pool,
+            CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: 0,
+                away_score_unidentified: 0,
+                match_date: Some("2024-01-15".to_string()),
+                status: "finished".to_string(),
+                arena_id,
+                attendance,
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_season_and_arena_attendance(pool: SqlitePool) {
+        let arena_id = sqlx::query("INSERT INTO arena (name) VALUES ('Test Arena')")
+            .execute(&pool)
+            .await
+            .unwrap()
+            .last_insert_rowid();
+
+        create_test_match(&pool, Some(arena_id), Some(10_000)).await;
+        create_test_match(&pool, Some(arena_id), Some(5_001)).await;
+        // Unknown attendance must not drag the average down
+        create_test_match(&pool, Some(arena_id), None).await;
+        create_test_match(&pool, None, Some(300)).await;
+
+        let season = get_season_attendance(&pool, 1).await.unwrap();
+        assert_eq!(season.len(), 2);
+        assert_eq!(season[0].group_id, Some(arena_id));
+        assert_eq!((season[0].matches, season[0].total), (2, 15_001));
+        assert_eq!(season[0].average(), Some(7_501));
+        assert_eq!(season[1].group_id, None);
+        assert_eq!(season[1].total, 300);
+
+        let arena = get_arena_attendance(&pool, arena_id).await.unwrap();
+        assert_eq!(arena.len(), 1);
+        assert_eq!(arena[0].group_id, Some(1));
+        assert_eq!(arena[0].matches, 2);
+
+        assert!(get_season_attendance(&pool, 2).await.unwrap().is_empty());
+    }
+}
@@ -1,3 +1,11 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::common::pagination::SortableField;
+use crate::service::referees::MatchOfficialEntity;
+use crate::service::staff::MatchStaffEntity;
+
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
 pub struct MatchEntity {
@@ -13,12 +21,248 @@ pub struct MatchEntity {
     pub away_team_country_iso2: Option<String>,
     pub home_score_unidentified: i32,
     pub away_score_unidentified: i32,
+    pub home_score_identified: i32,
+    pub away_score_identified: i32,
     pub match_date: Option<String>,
     pub status: String,
-    pub venue: Option<String>,
+    pub arena_id: Option<i64>,
+    pub arena_name: Option<String>,
+    pub arena_city: Option<String>,
+    /// Number of spectators, if known
+    pub attendance: Option<i64>,
+    pub game_type: String,
+    /// Stored result, see [`MatchResult`]
+    pub result: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+impl MatchEntity {
+    pub fn home_score_total(&self) -> i32 {
+        self.home_score_identified + self.home_score_unidentified
+    }
+
+    pub fn away_score_total(&self) -> i32 {
+        self.away_score_identified + self.away_score_unidentified
+    }
+
+    /// How much of the final score is backed by recorded score events
+    pub fn score_coverage(&self) -> ScoreCoverage {
+        ScoreCoverage::from_counts(
+            self.home_score_identified + self.away_score_identified,
+            self.home_score_unidentified + self.away_score_unidentified,
+        )
+    }
+
+    pub fn game_type(&self) -> GameType {
+        GameType::from_str(&self.game_type).unwrap_or(GameType::Group)
+    }
+
+    pub fn result(&self) -> Option<MatchResult> {
+        self.result.as_deref().and_then(MatchResult::from_str)
+    }
+}
+
+/// Stage of competition a match belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameType {
+    Group,
+    Playoff,
+    Relegation,
+    /// Friendly game; left out of standings and player statistics
+    Exhibition,
+}
+
+impl GameType {
+    pub const ALL: [GameType; 4] = [
+        GameType::Group,
+        GameType::Playoff,
+        GameType::Relegation,
+        GameType::Exhibition,
+    ];
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "group" => Some(Self::Group),
+            "playoff" => Some(Self::Playoff),
+            "relegation" => Some(Self::Relegation),
+            "exhibition" => Some(Self::Exhibition),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Group => "group",
+            Self::Playoff => "playoff",
+            Self::Relegation => "relegation",
+            Self::Exhibition => "exhibition",
+        }
+    }
+}
+
+/// Period number of overtime goals
+pub const OVERTIME_PERIOD: i32 = 4;
+/// Period number of shootout goals
+pub const SHOOTOUT_PERIOD: i32 = 5;
+
+/// How a decided match was won
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    Regulation,
+    Overtime,
+    Shootout,
+}
+
+impl Decision {
+    /// Decision implied by the period of the last goal
+    pub fn from_period(period: Option<i32>) -> Self {
+        match period {
+            Some(SHOOTOUT_PERIOD) => Self::Shootout,
+            Some(OVERTIME_PERIOD) => Self::Overtime,
+            _ => Self::Regulation,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Regulation => "regulation",
+            Self::Overtime => "overtime",
+            Self::Shootout => "shootout",
+        }
+    }
+}
+
+/// Result of a finished match, stored in `match.result`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchResult {
+    HomeWin(Decision),
+    AwayWin(Decision),
+    Draw,
+}
+
+impl MatchResult {
+    /// Derive the result from the status, final score and the period of the
+    /// last goal; `None` while the match is not finished
+    ///
+    /// Without recorded goals (only unidentified totals) a win counts as a
+    /// regulation win.
+    pub fn derive(
+        status: &str,
+        home_score: i32,
+        away_score: i32,
+        last_goal_period: Option<i32>,
+    ) -> Option<Self> {
+        if status != "finished" {
+            return None;
+        }
+        let decision = Decision::from_period(last_goal_period);
+        Some(match home_score.cmp(&away_score) {
+            std::cmp::Ordering::Greater => Self::HomeWin(decision),
+            std::cmp::Ordering::Less => Self::AwayWin(decision),
+            std::cmp::Ordering::Equal => Self::Draw,
+        })
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        if s == "draw" {
+            return Some(Self::Draw);
+        }
+        let (side, decision) = s.split_once('_')?;
+        let decision = match decision {
+            "regulation" => Decision::Regulation,
+            "overtime" => Decision::Overtime,
+            "shootout" => Decision::Shootout,
+            _ => return None,
+        };
+        match side {
+            "home" => Some(Self::HomeWin(decision)),
+            "away" => Some(Self::AwayWin(decision)),
+            _ => None,
+        }
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            Self::HomeWin(decision) => format!("home_{}", decision.as_str()),
+            Self::AwayWin(decision) => format!("away_{}", decision.as_str()),
+            Self::Draw => "draw".to_string(),
+        }
+    }
+
+    /// How the match was decided, `None` for a draw
+    pub fn decision(&self) -> Option<Decision> {
+        match self {
+            Self::HomeWin(decision) | Self::AwayWin(decision) => Some(*decision),
+            Self::Draw => None,
+        }
+    }
+}
+
+/// Goal coverage level of a match, derived from identified vs. unidentified goals
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreCoverage {
+    /// Every goal has a score event
+    Full,
+    /// Some goals have score events, the rest are unidentified
+    Partial,
+    /// Only aggregate (unidentified) totals are known
+    AggregateOnly,
+    /// No goals recorded at all
+    NoGoals,
+}
+
+impl ScoreCoverage {
+    pub fn from_counts(identified: i32, unidentified: i32) -> Self {
+        match (identified > 0, unidentified > 0) {
+            (true, false) => Self::Full,
+            (true, true) => Self::Partial,
+            (false, true) => Self::AggregateOnly,
+            (false, false) => Self::NoGoals,
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "full" => Some(Self::Full),
+            "partial" => Some(Self::Partial),
+            "aggregate" => Some(Self::AggregateOnly),
+            "none" => Some(Self::NoGoals),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::Partial => "partial",
+            Self::AggregateOnly => "aggregate",
+            Self::NoGoals => "none",
+        }
+    }
+
+    /// SQL condition selecting matches with this coverage level (expects `m` alias)
+    pub fn to_sql(self) -> &'static str {
+        match self {
+            Self::Full => {
+                "EXISTS (SELECT 1 FROM score_event se WHERE se.match_id = m.id) \
+                 AND m.home_score_unidentified + m.away_score_unidentified = 0"
+            }
+            Self::Partial => {
+                "EXISTS (SELECT 1 FROM score_event se WHERE se.match_id = m.id) \
+                 AND m.home_score_unidentified + m.away_score_unidentified > 0"
+            }
+            Self::AggregateOnly => {
+                "NOT EXISTS (SELECT 1 FROM score_event se WHERE se.match_id = m.id) \
+                 AND m.home_score_unidentified + m.away_score_unidentified > 0"
+            }
+            Self::NoGoals => {
+                "NOT EXISTS (SELECT 1 FROM score_event se WHERE se.match_id = m.id) \
+                 AND m.home_score_unidentified + m.away_score_unidentified = 0"
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct ScoreEventEntity {
     pub id: i64,
@@ -35,6 +279,52 @@ pub struct ScoreEventEntity {
     pub time_minutes: Option<i32>,
     pub time_seconds: Option<i32>,
     pub goal_type: Option<String>,
+    /// Letters worn by the scorer and assistants for the team this season
+    pub scorer_captaincy: Option<String>,
+    pub assist1_captaincy: Option<String>,
+    pub assist2_captaincy: Option<String>,
+    /// Highlight video of the goal and the offset into it in seconds
+    pub video_url: Option<String>,
+    pub video_timestamp: Option<i32>,
+}
+
+impl ScoreEventEntity {
+    /// Video URL that starts playback at the timestamp
+    ///
+    /// Uses the `t` query parameter understood by YouTube and most players.
+    pub fn video_link(&self) -> Option<String> {
+        let url = self.video_url.as_deref()?;
+        let Some(seconds) = self.video_timestamp else {
+            return Some(url.to_string());
+        };
+
+        let (base, fragment) = match url.split_once('#') {
+            Some((base, fragment)) => (base, format!("#{}", fragment)),
+            None => (url, String::new()),
+        };
+        let separator = if base.contains('?') { '&' } else { '?' };
+        Some(format!("{}{}t={}s{}", base, separator, seconds, fragment))
+    }
+
+    /// Video timestamp as `h:mm:ss` or `m:ss`
+    pub fn video_timestamp_label(&self) -> Option<String> {
+        self.video_timestamp.map(|seconds| {
+            let (h, m, s) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+            if h > 0 {
+                format!("{}:{:02}:{:02}", h, m, s)
+            } else {
+                format!("{}:{:02}", m, s)
+            }
+        })
+    }
+
+    /// Players credited with the goal or an assist
+    pub fn credited_player_ids(&self) -> Vec<i64> {
+        [self.scorer_id, self.assist1_id, self.assist2_id]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -45,8 +335,220 @@ pub struct MatchDetailEntity {
     pub away_score_identified: i32,
     pub home_score_total: i32,
     pub away_score_total: i32,
+    pub shots: Vec<MatchShotsEntity>,
+    /// Dressed players of both teams
+    pub lineup: Vec<LineupPlayerEntity>,
+    /// Referees and linesmen, referees first
+    pub officials: Vec<MatchOfficialEntity>,
+    /// Staff of both teams for the match's season, head coaches first
+    pub staff: Vec<MatchStaffEntity>,
+    /// Faceoffs of both teams' players
+    pub faceoffs: Vec<MatchFaceoffsEntity>,
+    /// Flags derived from the final score, keyed by score event ID
+    pub goal_flags: HashMap<i64, GoalFlags>,
+}
+
+impl MatchDetailEntity {
+    /// Shots on goal recorded for a team in a period
+    pub fn team_period_shots(&self, team_id: i64, period: i32) -> Option<i32> {
+        self.shots
+            .iter()
+            .find(|s| s.team_id == team_id && s.period == period)
+            .map(|s| s.shots)
+    }
+
+    /// Total shots on goal for a team, or `None` when no shots were recorded
+    pub fn team_shots_total(&self, team_id: i64) -> Option<i32> {
+        let mut team_shots = self
+            .shots
+            .iter()
+            .filter(|s| s.team_id == team_id)
+            .peekable();
+        team_shots.peek()?;
+        Some(team_shots.map(|s| s.shots).sum())
+    }
+
+    /// Dressed players of a team, empty when no lineup was recorded
+    pub fn team_lineup(&self, team_id: i64) -> Vec<&LineupPlayerEntity> {
+        self.lineup
+            .iter()
+            .filter(|p| p.team_id == team_id)
+            .collect()
+    }
+
+    /// Faceoffs of a team's players, empty when none were recorded
+    pub fn team_faceoffs(&self, team_id: i64) -> Vec<&MatchFaceoffsEntity> {
+        self.faceoffs
+            .iter()
+            .filter(|f| f.team_id == team_id)
+            .collect()
+    }
+
+    pub fn goal_flags(&self, score_event_id: i64) -> GoalFlags {
+        self.goal_flags
+            .get(&score_event_id)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// Badges shown next to a goal in the goals list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GoalFlags {
+    /// The goal that put the winner one ahead of the loser's final total
+    pub game_winning: bool,
+    /// Why an empty-net goal does not fit the score, if it does not
+    pub empty_net_issue: Option<EmptyNetIssue>,
+}
+
+/// Inconsistency of a goal marked as scored into an empty net
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmptyNetIssue {
+    /// Shootout attempts are always taken against a goaltender
+    Shootout,
+    /// The scoring team was behind, so the opponent had no reason to pull
+    /// their goaltender
+    ScoredWhileTrailing,
+}
+
+/// Shots on goal for one team in one period (1-3, 4 = overtime)
+#[derive(Debug, Clone)]
+pub struct MatchShotsEntity {
+    pub team_id: i64,
+    pub period: i32,
+    pub shots: i32,
+}
+
+/// Faceoffs won and lost by one player in a match
+#[derive(Debug, Clone)]
+pub struct MatchFaceoffsEntity {
+    pub team_id: i64,
+    pub player_id: i64,
+    pub player_name: String,
+    pub won: i32,
+    pub lost: i32,
+}
+
+impl MatchFaceoffsEntity {
+    pub fn percentage(&self) -> Option<f64> {
+        faceoff_percentage(self.won.into(), self.lost.into())
+    }
+}
+
+/// Faceoffs won as a percentage of faceoffs taken, `None` when none were taken
+pub fn faceoff_percentage(won: i64, lost: i64) -> Option<f64> {
+    let taken = won + lost;
+    if taken <= 0 {
+        return None;
+    }
+    Some(won as f64 * 100.0 / taken as f64)
+}
+
+/// Roster player of a team in a match's season, and whether they dressed
+#[derive(Debug, Clone)]
+pub struct LineupPlayerEntity {
+    pub player_contract_id: i64,
+    pub player_id: i64,
+    pub team_id: i64,
+    pub player_name: String,
+    pub jersey_number: Option<i64>,
+    pub dressed: bool,
+}
+
+/// Group a player is listed under in the scorer and assist dropdowns, in
+/// display order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ScoringGroup {
+    /// Forward line on the lines chart
+    ForwardLine(i64),
+    /// Defense pair on the lines chart
+    DefensePair(i64),
+    /// Forwards not on a line
+    Forwards,
+    /// Defensemen not on a pair
+    Defense,
+    /// Players with neither a line nor a position
+    Other,
+    /// Goalies, hidden from scorer lists unless explicitly included
+    Goalies,
+}
+
+/// Player a goal or assist can be credited to
+#[derive(Debug, Clone)]
+pub struct ScoringPlayerEntity {
+    pub player_id: i64,
+    /// Name, prefixed with the season's jersey number when one is set
+    pub label: String,
+    pub group: ScoringGroup,
+}
+
+impl ScoringPlayerEntity {
+    pub fn is_goalie(&self) -> bool {
+        self.group == ScoringGroup::Goalies
+    }
+}
+
+/// Season shooting totals of a team, over matches with shots recorded
+#[derive(Debug, Clone)]
+pub struct TeamShootingEntity {
+    pub team_id: i64,
+    pub games: i64,
+    pub shots: i64,
+    pub goals: i64,
+}
+
+impl TeamShootingEntity {
+    pub fn shooting_percentage(&self) -> Option<f64> {
+        shooting_percentage(self.goals, self.shots)
+    }
 }
 
+/// Goals as a percentage of shots on goal, `None` when there are no shots
+pub fn shooting_percentage(goals: i64, shots: i64) -> Option<f64> {
+    if shots <= 0 {
+        return None;
+    }
+    Some(goals as f64 * 100.0 / shots as f64)
+}
+
+/// Attendance totals of one group of matches (an arena or a season)
+///
+/// Only matches with a recorded attendance are counted.
+#[derive(Debug, Clone)]
+pub struct AttendanceEntity {
+    /// Arena or season ID; `None` groups matches without an arena
+    pub group_id: Option<i64>,
+    pub group_name: Option<String>,
+    pub matches: i64,
+    pub total: i64,
+}
+
+impl AttendanceEntity {
+    pub fn average(&self) -> Option<i64> {
+        average_attendance(self.total, self.matches)
+    }
+}
+
+/// Rounded average attendance per match, `None` when no match has attendance
+pub fn average_attendance(total: i64, matches: i64) -> Option<i64> {
+    if matches <= 0 {
+        return None;
+    }
+    Some((total as f64 / matches as f64).round() as i64)
+}
+
+/// Status filter value matching every match that was not cancelled
+pub const STATUS_NOT_CANCELLED: &str = "not_cancelled";
+
+/// Values accepted by the status filter of the matches list
+pub const STATUS_FILTERS: [&str; 5] = [
+    STATUS_NOT_CANCELLED,
+    "scheduled",
+    "in_progress",
+    "finished",
+    "cancelled",
+];
+
 #[derive(Debug, Clone)]
 pub struct MatchFilters {
     pub season_id: Option<i64>,
@@ -54,6 +556,13 @@ pub struct MatchFilters {
     pub status: Option<String>,
     pub date_from: Option<String>,
     pub date_to: Option<String>,
+    pub coverage: Option<ScoreCoverage>,
+    pub arena_id: Option<i64>,
+    pub game_type: Option<GameType>,
+    /// Only matches between two teams of this season group
+    pub group_id: Option<i64>,
+    /// Only matches without a date (e.g. imported historical results)
+    pub date_unknown: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -89,6 +598,24 @@ impl SortField {
     }
 }
 
+impl SortableField for SortField {
+    fn from_str(s: &str) -> Self {
+        SortField::from_str(s)
+    }
+
+    fn to_sql(&self) -> &'static str {
+        SortField::to_sql(self)
+    }
+
+    fn as_str(&self) -> &'static str {
+        SortField::as_str(self)
+    }
+
+    fn nulls_last(&self) -> bool {
+        matches!(self, SortField::Date)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CreateMatchEntity {
     pub season_id: i64,
@@ -98,7 +625,9 @@ pub struct CreateMatchEntity {
     pub away_score_unidentified: i32,
     pub match_date: Option<String>,
     pub status: String,
-    pub venue: Option<String>,
+    pub arena_id: Option<i64>,
+    pub attendance: Option<i64>,
+    pub game_type: String,
 }
 
 #[derive(Debug, Clone)]
@@ -110,7 +639,9 @@ pub struct UpdateMatchEntity {
     pub away_score_unidentified: i32,
     pub match_date: Option<String>,
     pub status: String,
-    pub venue: Option<String>,
+    pub arena_id: Option<i64>,
+    pub attendance: Option<i64>,
+    pub game_type: String,
 }
 
 #[derive(Debug, Clone)]
@@ -124,6 +655,8 @@ pub struct CreateScoreEventEntity {
     pub time_minutes: Option<i32>,
     pub time_seconds: Option<i32>,
     pub goal_type: Option<String>,
+    pub video_url: Option<String>,
+    pub video_timestamp: Option<i32>,
 }
 
 #[derive(Debug, Clone)]
@@ -136,4 +669,6 @@ pub struct UpdateScoreEventEntity {
     pub time_minutes: Option<i32>,
     pub time_seconds: Option<i32>,
     pub goal_type: Option<String>,
+    pub video_url: Option<String>,
+    pub video_timestamp: Option<i32>,
 }
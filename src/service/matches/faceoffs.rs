@@ -0,0 +1,122 @@
+use sqlx::SqlitePool;
+
+use super::entities::MatchFaceoffsEntity;
+
+/// Get the faceoffs recorded for a match, ordered by team and most taken
+pub async fn get_match_faceoffs(
+    db: &SqlitePool,
+    match_id: i64,
+) -> Result<Vec<MatchFaceoffsEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        MatchFaceoffsEntity,
+        r#"
+        SELECT
+            f.team_id,
+            f.player_id,
+            p.name as player_name,
+            f.won as "won: i32",
+            f.lost as "lost: i32"
+        FROM match_faceoffs f
+        INNER JOIN player p ON f.player_id = p.id
+        WHERE f.match_id = ?
+        ORDER BY f.team_id, f.won + f.lost DESC, p.name
+        "#,
+        match_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Replace the faceoffs of one team in a match
+///
+/// Entries are `(player_id, won, lost)`; players missing from `entries` are
+/// cleared, so the form can unset values. Teams that did not play in the
+/// match are ignored.
+pub async fn save_team_faceoffs(
+    db: &SqlitePool,
+    match_id: i64,
+    team_id: i64,
+    entries: &[(i64, i32, i32)],
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "DELETE FROM match_faceoffs WHERE match_id = ? AND team_id = ?",
+        match_id,
+        team_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    for (player_id, won, lost) in entries {
+        sqlx::query!(
+            r#"
+            INSERT OR IGNORE INTO match_faceoffs (match_id, player_id, team_id, won, lost)
+            SELECT m.id, ?, ?, ?, ?
+            FROM match m
+            WHERE m.id = ? AND ? IN (m.home_team_id, m.away_team_id)
+            "#,
+            player_id,
+            team_id,
+            won,
+            lost,
+            match_id,
+            team_id
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_save_team_faceoffs(pool: SqlitePool) {
+        sqlx::query(
+            "INSERT INTO match (id, season_id, home_team_id, away_team_id) VALUES (1, 1, 1, 2)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        save_team_faceoffs(&pool, 1, 1, &[(1, 8, 4), (2, 3, 5)])
+            .await
+            .unwrap();
+        save_team_faceoffs(&pool, 1, 2, &[(3, 9, 11)])
+            .await
+            .unwrap();
+        // Team 3 did not play in the match
+        save_team_faceoffs(&pool, 1, 3, &[(4, 1, 1)]).await.unwrap();
+
+        let faceoffs = get_match_faceoffs(&pool, 1).await.unwrap();
+        assert_eq!(faceoffs.len(), 3);
+        assert_eq!(faceoffs[0].player_id, 1);
+        assert_eq!(faceoffs[0].percentage(), Some(8.0 * 100.0 / 12.0));
+
+        // A season with faceoffs only is listed in the player's season stats
+        let stats = crate::service::players::get_player_season_stats(&pool, 3)
+            .await
+            .unwrap();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].points, 0);
+        assert_eq!(stats[0].faceoffs_won, 9);
+        assert_eq!(stats[0].faceoff_percentage(), Some(45.0));
+
+        // Replacing one team leaves the other alone
+        save_team_faceoffs(&pool, 1, 1, &[]).await.unwrap();
+        let faceoffs = get_match_faceoffs(&pool, 1).await.unwrap();
+        assert_eq!(faceoffs.len(), 1);
+        assert_eq!(faceoffs[0].player_id, 3);
+    }
+}
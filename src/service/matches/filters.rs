@@ -51,24 +51,43 @@ pub async fn get_teams_for_season(
     Ok(rows.into_iter().map(|row| (row.id, row.name)).collect())
 }
 
-/// Get players for a specific team participation (for dropdowns)
+/// Get players who have played for a team (for dropdowns), labelled with
+/// their jersey number in the given season when one is set
 pub async fn get_players_for_team(
     db: &SqlitePool,
     team_id: i64,
+    season_id: i64,
 ) -> Result<Vec<(i64, String)>, sqlx::Error> {
     let rows = sqlx::query!(
         r#"
-        SELECT DISTINCT p.id as "id!", p.name
+        SELECT
+            p.id as "id!",
+            p.name,
+            (SELECT pc2.jersey_number
+             FROM player_contract pc2
+             INNER JOIN team_participation tp2 ON pc2.team_participation_id = tp2.id
+             WHERE pc2.player_id = p.id AND tp2.team_id = ?1 AND tp2.season_id = ?2) as "jersey_number: i64"
         FROM player p
-        INNER JOIN player_contract pc ON p.id = pc.player_id
-        INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
-        WHERE tp.team_id = ?
+        WHERE p.id IN (
+            SELECT pc.player_id
+            FROM player_contract pc
+            INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+            WHERE tp.team_id = ?1
+        )
         ORDER BY p.name ASC
         "#,
-        team_id
+        team_id,
+        season_id
     )
     .fetch_all(db)
     .await?;
 
-    Ok(rows.into_iter().map(|row| (row.id, row.name)).collect())
+    // Prefix the season's jersey number so scorers can be picked by number
+    Ok(rows
+        .into_iter()
+        .map(|row| match row.jersey_number {
+            Some(number) => (row.id, format!("#{} {}", number, row.name)),
+            None => (row.id, row.name),
+        })
+        .collect())
 }
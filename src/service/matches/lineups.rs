@@ -0,0 +1,394 @@
+use sqlx::SqlitePool;
+
+use super::entities::{LineupPlayerEntity, MatchEntity, ScoringGroup, ScoringPlayerEntity};
+use super::filters::get_players_for_team;
+use crate::service::lines::LineUnit;
+use crate::service::roster_rules::GOALIE_POSITION;
+
+/// Get a team's season roster for a match, flagging the players who dressed
+///
+/// Players who joined after the match or were transferred out before it are
+/// left out.
+pub async fn get_lineup_candidates(
+    db: &SqlitePool,
+    match_id: i64,
+    team_id: i64,
+) -> Result<Vec<LineupPlayerEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        LineupPlayerEntity,
+        r#"
+        SELECT
+            pc.id as "player_contract_id!",
+            p.id as "player_id!",
+            tp.team_id,
+            p.name as player_name,
+            pc.jersey_number,
+            EXISTS(
+                SELECT 1 FROM match_lineup ml
+                WHERE ml.match_id = m.id AND ml.player_contract_id = pc.id
+            ) as "dressed!: bool"
+        FROM match m
+        INNER JOIN team_participation tp ON tp.season_id = m.season_id
+        INNER JOIN player_contract pc ON pc.team_participation_id = tp.id
+        INNER JOIN player p ON pc.player_id = p.id
+        WHERE m.id = ? AND tp.team_id = ?
+          AND (pc.joined_on IS NULL OR m.match_date IS NULL OR date(m.match_date) >= pc.joined_on)
+          AND (pc.left_on IS NULL OR m.match_date IS NULL OR date(m.match_date) < pc.left_on)
+        ORDER BY pc.jersey_number IS NULL, pc.jersey_number, p.name
+        "#,
+        match_id,
+        team_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Get the dressed players of both teams in a match
+pub async fn get_match_lineup(
+    db: &SqlitePool,
+    match_id: i64,
+) -> Result<Vec<LineupPlayerEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        LineupPlayerEntity,
+        r#"
+        SELECT
+            pc.id as "player_contract_id!",
+            p.id as "player_id!",
+            tp.team_id,
+            p.name as player_name,
+            pc.jersey_number,
+            1 as "dressed!: bool"
+        FROM match_lineup ml
+        INNER JOIN player_contract pc ON ml.player_contract_id = pc.id
+        INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+        INNER JOIN player p ON pc.player_id = p.id
+        WHERE ml.match_id = ?
+        ORDER BY tp.team_id, pc.jersey_number IS NULL, pc.jersey_number, p.name
+        "#,
+        match_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Replace the dressed lineup of one team in a match
+///
+/// Contracts that are not on the team's roster for the match's season, or
+/// whose transfer dates exclude the match, are ignored. An empty list clears
+/// the lineup.
+pub async fn save_team_lineup(
+    db: &SqlitePool,
+    match_id: i64,
+    team_id: i64,
+    player_contract_ids: &[i64],
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        r#"
+        DELETE FROM match_lineup
+        WHERE match_id = ? AND player_contract_id IN (
+            SELECT pc.id
+            FROM player_contract pc
+            INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+            WHERE tp.team_id = ?
+        )
+        "#,
+        match_id,
+        team_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    for player_contract_id in player_contract_ids {
+        sqlx::query!(
+            r#"
+            INSERT OR IGNORE INTO match_lineup (match_id, player_contract_id)
+            SELECT m.id, pc.id
+            FROM match m
+            INNER JOIN team_participation tp ON tp.season_id = m.season_id
+            INNER JOIN player_contract pc ON pc.team_participation_id = tp.id
+            WHERE m.id = ? AND tp.team_id = ? AND pc.id = ?
+              AND (pc.joined_on IS NULL OR m.match_date IS NULL OR date(m.match_date) >= pc.joined_on)
+              AND (pc.left_on IS NULL OR m.match_date IS NULL OR date(m.match_date) < pc.left_on)
+            "#,
+            match_id,
+            team_id,
+            player_contract_id
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Get the players a goal or assist can be credited to (for dropdowns)
+///
+/// Once a lineup is recorded for the team, only dressed players are offered;
+/// otherwise everyone who has played for the team is. Players in `keep` stay
+/// in the list even when not dressed, so editing an existing goal does not
+/// silently drop its scorer or assists.
+///
+/// Players are ordered by their line on the season's lines chart, then by
+/// position, with goalies last.
+pub async fn get_scoring_players(
+    db: &SqlitePool,
+    match_info: &MatchEntity,
+    team_id: i64,
+    keep: &[i64],
+) -> Result<Vec<ScoringPlayerEntity>, sqlx::Error> {
+    let all_players = get_players_for_team(db, team_id, match_info.season_id).await?;
+
+    let dressed: Vec<LineupPlayerEntity> = get_match_lineup(db, match_info.id)
+        .await?
+        .into_iter()
+        .filter(|p| p.team_id == team_id)
+        .collect();
+
+    let mut players: Vec<(i64, String)> =
+        if dressed.is_empty() {
+            all_players
+        } else {
+            let mut players: Vec<(i64, String)> = dressed
+                .iter()
+                .map(|p| match p.jersey_number {
+                    Some(number) => (p.player_id, format!("#{} {}", number, p.player_name)),
+                    None => (p.player_id, p.player_name.clone()),
+                })
+                .collect();
+
+            players.extend(all_players.into_iter().filter(|(id, _)| {
+                keep.contains(id) && !dressed.iter().any(|p| p.player_id == *id)
+            }));
+            players
+        };
+
+    let placements = get_scoring_placements(db, team_id, match_info.season_id).await?;
+    let placement = |player_id: i64| {
+        placements
+            .iter()
+            .find(|p| p.player_id == player_id)
+            .map(|p| (p.group, p.slot))
+            .unwrap_or((ScoringGroup::Other, None))
+    };
+
+    // Stable sort keeps the jersey/name order within a group
+    players.sort_by_key(|(id, _)| placement(*id));
+
+    Ok(players
+        .into_iter()
+        .map(|(player_id, label)| ScoringPlayerEntity {
+            player_id,
+            label,
+            group: placement(player_id).0,
+        })
+        .collect())
+}
+
+/// Where a player of the team sits on the season's lines chart
+struct ScoringPlacement {
+    player_id: i64,
+    group: ScoringGroup,
+    slot: Option<i64>,
+}
+
+/// Line and position of everyone who has played for the team
+///
+/// The lines chart of the given season wins over the player's position.
+async fn get_scoring_placements(
+    db: &SqlitePool,
+    team_id: i64,
+    season_id: i64,
+) -> Result<Vec<ScoringPlacement>, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            p.id as "player_id!",
+            p.position,
+            la.unit as "unit?",
+            la.line_number as "line_number?",
+            la.slot as "slot?"
+        FROM player p
+        LEFT JOIN (
+            SELECT pc.player_id, la.unit, la.line_number, la.slot
+            FROM line_assignment la
+            INNER JOIN player_contract pc ON la.player_contract_id = pc.id
+            INNER JOIN team_participation tp ON la.team_participation_id = tp.id
+            WHERE tp.team_id = ?1 AND tp.season_id = ?2
+        ) la ON la.player_id = p.id
+        WHERE p.id IN (
+            SELECT pc.player_id
+            FROM player_contract pc
+            INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+            WHERE tp.team_id = ?1
+        )
+        "#,
+        team_id,
+        season_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let unit = row.unit.as_deref().and_then(LineUnit::from_str);
+            let group = match (unit, row.line_number, row.position.as_deref()) {
+                (Some(LineUnit::Forward), Some(line), _) => ScoringGroup::ForwardLine(line),
+                (Some(LineUnit::Defense), Some(line), _) => ScoringGroup::DefensePair(line),
+                (Some(LineUnit::Goalie), _, _) | (_, _, Some(GOALIE_POSITION)) => {
+                    ScoringGroup::Goalies
+                }
+                (_, _, Some("Forward")) => ScoringGroup::Forwards,
+                (_, _, Some("Defense")) => ScoringGroup::Defense,
+                _ => ScoringGroup::Other,
+            };
+            ScoringPlacement {
+                player_id: row.player_id,
+                group,
+                slot: row.slot,
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{create_match, get_match_by_id, CreateMatchEntity};
+    use crate::service::player_contracts::add_player_to_roster;
+
+    async fn create_test_match(pool: &SqlitePool) -> i64 {
+        create_match(
+            pool,
+            CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: 0,
+                away_score_unidentified: 0,
+                match_date: Some("2022-02-10".to_string()),
+                status: "scheduled".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_save_team_lineup(pool: SqlitePool) {
+        let match_id = create_test_match(&pool).await;
+        let home_a = add_player_to_roster(&pool, 1, 1, Some(9)).await.unwrap();
+        let home_b = add_player_to_roster(&pool, 1, 2, None).await.unwrap();
+        let away = add_player_to_roster(&pool, 2, 3, None).await.unwrap();
+
+        save_team_lineup(&pool, match_id, 2, &[away]).await.unwrap();
+        // The away contract does not belong to the home roster and is ignored
+        save_team_lineup(&pool, match_id, 1, &[home_a, away])
+            .await
+            .unwrap();
+
+        let candidates = get_lineup_candidates(&pool, match_id, 1).await.unwrap();
+        assert_eq!(candidates.len(), 2);
+        assert!(candidates
+            .iter()
+            .any(|c| c.player_contract_id == home_a && c.dressed));
+        assert!(candidates
+            .iter()
+            .any(|c| c.player_contract_id == home_b && !c.dressed));
+
+        let lineup = get_match_lineup(&pool, match_id).await.unwrap();
+        assert_eq!(lineup.len(), 2);
+
+        // Replacing the home lineup leaves the away lineup alone
+        save_team_lineup(&pool, match_id, 1, &[]).await.unwrap();
+        let lineup = get_match_lineup(&pool, match_id).await.unwrap();
+        assert_eq!(lineup.len(), 1);
+        assert_eq!(lineup[0].player_contract_id, away);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_scoring_players_follow_lineup(pool: SqlitePool) {
+        let match_id = create_test_match(&pool).await;
+        let dressed = add_player_to_roster(&pool, 1, 1, Some(9)).await.unwrap();
+        add_player_to_roster(&pool, 1, 2, None).await.unwrap();
+        let match_info = get_match_by_id(&pool, match_id).await.unwrap().unwrap();
+
+        // Without a lineup the whole roster is offered
+        let players = get_scoring_players(&pool, &match_info, 1, &[])
+            .await
+            .unwrap();
+        assert_eq!(players.len(), 2);
+
+        save_team_lineup(&pool, match_id, 1, &[dressed])
+            .await
+            .unwrap();
+        let players = get_scoring_players(&pool, &match_info, 1, &[])
+            .await
+            .unwrap();
+        assert_eq!(players.len(), 1);
+        assert_eq!(players[0].player_id, 1);
+        assert!(players[0].label.starts_with("#9 "));
+
+        // Already credited players stay selectable
+        let players = get_scoring_players(&pool, &match_info, 1, &[2])
+            .await
+            .unwrap();
+        assert_eq!(players.len(), 2);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_scoring_players_grouped_by_line(pool: SqlitePool) {
+        let match_id = create_test_match(&pool).await;
+        sqlx::query(
+            "UPDATE player SET position = CASE id WHEN 1 THEN 'Goalie' WHEN 3 THEN 'Defense' ELSE position END",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        add_player_to_roster(&pool, 1, 1, None).await.unwrap();
+        let winger = add_player_to_roster(&pool, 1, 2, None).await.unwrap();
+        add_player_to_roster(&pool, 1, 3, None).await.unwrap();
+        add_player_to_roster(&pool, 1, 4, None).await.unwrap();
+        crate::service::lines::assign_player_to_slot(&pool, 1, winger, LineUnit::Forward, 2, 1)
+            .await
+            .unwrap();
+        let match_info = get_match_by_id(&pool, match_id).await.unwrap().unwrap();
+
+        let players = get_scoring_players(&pool, &match_info, 1, &[])
+            .await
+            .unwrap();
+        let groups: Vec<(i64, ScoringGroup)> =
+            players.iter().map(|p| (p.player_id, p.group)).collect();
+        assert_eq!(
+            groups,
+            vec![
+                (2, ScoringGroup::ForwardLine(2)),
+                (3, ScoringGroup::Defense),
+                // 'D' is not a position the player form uses
+                (4, ScoringGroup::Other),
+                (1, ScoringGroup::Goalies),
+            ]
+        );
+        assert!(players[3].is_goalie());
+    }
+}
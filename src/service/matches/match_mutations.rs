@@ -1,12 +1,14 @@
-use sqlx::SqlitePool;
+use sqlx::{SqliteConnection, SqlitePool};
 
-use super::entities::{CreateMatchEntity, UpdateMatchEntity};
+use super::entities::{CreateMatchEntity, MatchResult, UpdateMatchEntity};
 
 /// Create a new match
 pub async fn create_match(db: &SqlitePool, entity: CreateMatchEntity) -> Result<i64, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
     let result = sqlx::query!(
-        "INSERT INTO match (season_id, home_team_id, away_team_id, home_score_unidentified, away_score_unidentified, match_date, status, venue) \
-         VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        "INSERT INTO match (season_id, home_team_id, away_team_id, home_score_unidentified, away_score_unidentified, match_date, status, arena_id, attendance, game_type) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         entity.season_id,
         entity.home_team_id,
         entity.away_team_id,
@@ -14,12 +16,18 @@ pub async fn create_match(db: &SqlitePool, entity: CreateMatchEntity) -> Result<
         entity.away_score_unidentified,
         entity.match_date,
         entity.status,
-        entity.venue
+        entity.arena_id,
+        entity.attendance,
+        entity.game_type
     )
-    .execute(db)
+    .execute(&mut *tx)
     .await?;
+    let id = result.last_insert_rowid();
+
+    refresh_match_result(&mut tx, id).await?;
+    tx.commit().await?;
 
-    Ok(result.last_insert_rowid())
+    Ok(id)
 }
 
 /// Update an existing match
@@ -28,11 +36,13 @@ pub async fn update_match(
     id: i64,
     entity: UpdateMatchEntity,
 ) -> Result<bool, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
     let result = sqlx::query!(
         "UPDATE match \
          SET season_id = ?, home_team_id = ?, away_team_id = ?, \
              home_score_unidentified = ?, away_score_unidentified = ?, \
-             match_date = ?, status = ?, venue = ?, \
+             match_date = ?, status = ?, arena_id = ?, attendance = ?, game_type = ?, \
              updated_at = CURRENT_TIMESTAMP \
          WHERE id = ?",
         entity.season_id,
@@ -42,13 +52,22 @@ pub async fn update_match(
         entity.away_score_unidentified,
         entity.match_date,
         entity.status,
-        entity.venue,
+        entity.arena_id,
+        entity.attendance,
+        entity.game_type,
         id
     )
-    .execute(db)
+    .execute(&mut *tx)
     .await?;
 
-    Ok(result.rows_affected() > 0)
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    refresh_match_result(&mut tx, id).await?;
+    tx.commit().await?;
+
+    Ok(true)
 }
 
 /// Delete a match (cascades to score events)
@@ -59,3 +78,181 @@ pub async fn delete_match(db: &SqlitePool, id: i64) -> Result<bool, sqlx::Error>
 
     Ok(result.rows_affected() > 0)
 }
+
+/// Derive a match's result from its status and goals and store it
+///
+/// Called after every change to a match's status, scores or score events so
+/// standings and form can read `match.result` directly.
+pub async fn refresh_match_result(
+    conn: &mut SqliteConnection,
+    match_id: i64,
+) -> Result<(), sqlx::Error> {
+    let row = sqlx::query!(
+        r#"
+        SELECT
+            m.status,
+            m.home_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id) as "home_score!: i32",
+            m.away_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.away_team_id) as "away_score!: i32",
+            (SELECT MAX(se.period) FROM score_event se WHERE se.match_id = m.id) as "last_goal_period: i32"
+        FROM match m
+        WHERE m.id = ?
+        "#,
+        match_id
+    )
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(());
+    };
+    let result = MatchResult::derive(
+        &row.status,
+        row.home_score,
+        row.away_score,
+        row.last_goal_period,
+    )
+    .map(|result| result.as_string());
+
+    sqlx::query!("UPDATE match SET result = ? WHERE id = ?", result, match_id)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{
+        create_score_event, delete_score_event, get_match_by_id, update_score_event,
+        CreateScoreEventEntity, Decision, UpdateScoreEventEntity, SHOOTOUT_PERIOD,
+    };
+
+    fn goal(match_id: i64, team_id: i64, period: i32) -> CreateScoreEventEntity {
+        CreateScoreEventEntity {
+            match_id,
+            team_id,
+            scorer_id: None,
+            assist1_id: None,
+            assist2_id: None,
+            period,
+            time_minutes: None,
+            time_seconds: None,
+            goal_type: None,
+            video_url: None,
+            video_timestamp: None,
+        }
+    }
+
+    async fn stored_result(pool: &SqlitePool, id: i64) -> Option<MatchResult> {
+        get_match_by_id(pool, id).await.unwrap().unwrap().result()
+    }
+
+    #[test]
+    fn test_derive_match_result() {
+        assert_eq!(MatchResult::derive("scheduled", 2, 1, None), None);
+        assert_eq!(
+            MatchResult::derive("finished", 2, 1, None),
+            Some(MatchResult::HomeWin(Decision::Regulation))
+        );
+        assert_eq!(
+            MatchResult::derive("finished", 2, 3, Some(4)),
+            Some(MatchResult::AwayWin(Decision::Overtime))
+        );
+        assert_eq!(
+            MatchResult::derive("finished", 3, 2, Some(5)),
+            Some(MatchResult::HomeWin(Decision::Shootout))
+        );
+        assert_eq!(
+            MatchResult::derive("finished", 1, 1, Some(3)),
+            Some(MatchResult::Draw)
+        );
+
+        for result in [
+            MatchResult::HomeWin(Decision::Shootout),
+            MatchResult::AwayWin(Decision::Regulation),
+            MatchResult::Draw,
+        ] {
+            assert_eq!(MatchResult::from_str(&result.as_string()), Some(result));
+        }
+        assert_eq!(MatchResult::from_str("home_penalties"), None);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_match_result_follows_changes(pool: SqlitePool) {
+        let id = create_match(
+            &pool,
+            CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: 0,
+                away_score_unidentified: 0,
+                match_date: None,
+                status: "in_progress".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(stored_result(&pool, id).await, None);
+
+        let finished = UpdateMatchEntity {
+            season_id: 1,
+            home_team_id: 1,
+            away_team_id: 2,
+            home_score_unidentified: 0,
+            away_score_unidentified: 0,
+            match_date: None,
+            status: "finished".to_string(),
+            arena_id: None,
+            attendance: None,
+            game_type: "group".to_string(),
+        };
+        assert!(update_match(&pool, id, finished).await.unwrap());
+        assert_eq!(stored_result(&pool, id).await, Some(MatchResult::Draw));
+
+        create_score_event(&pool, goal(id, 1, 1)).await.unwrap();
+        create_score_event(&pool, goal(id, 2, 2)).await.unwrap();
+        assert_eq!(stored_result(&pool, id).await, Some(MatchResult::Draw));
+
+        let winner = create_score_event(&pool, goal(id, 2, 4)).await.unwrap();
+        assert_eq!(
+            stored_result(&pool, id).await,
+            Some(MatchResult::AwayWin(Decision::Overtime))
+        );
+
+        let in_shootout = UpdateScoreEventEntity {
+            team_id: 2,
+            scorer_id: None,
+            assist1_id: None,
+            assist2_id: None,
+            period: SHOOTOUT_PERIOD,
+            time_minutes: None,
+            time_seconds: None,
+            goal_type: None,
+            video_url: None,
+            video_timestamp: None,
+        };
+        assert!(update_score_event(&pool, winner, in_shootout)
+            .await
+            .unwrap());
+        assert_eq!(
+            stored_result(&pool, id).await,
+            Some(MatchResult::AwayWin(Decision::Shootout))
+        );
+
+        // The deleted goal stays counted as unidentified, but no longer
+        // decides the match after regulation
+        assert!(delete_score_event(&pool, winner).await.unwrap());
+        assert_eq!(
+            stored_result(&pool, id).await,
+            Some(MatchResult::AwayWin(Decision::Regulation))
+        );
+    }
+}
@@ -1,7 +1,15 @@
-use crate::common::pagination::{PagedResult, SortOrder};
+use std::collections::HashMap;
+
+use crate::common::csv_export::CsvSink;
+use crate::common::pagination::{
+    push_secondary_sort, push_sort_key, Cursor, PagedResult, SortOrder,
+};
+use futures::TryStreamExt;
 use sqlx::{QueryBuilder, Row, SqlitePool};
 
-use super::entities::{MatchEntity, MatchFilters, ScoreEventEntity, SortField};
+use super::entities::{
+    MatchEntity, MatchFilters, ScoreEventEntity, SortField, STATUS_NOT_CANCELLED,
+};
 
 /// Get a single match by ID with all related details
 pub async fn get_match_by_id(db: &SqlitePool, id: i64) -> Result<Option<MatchEntity>, sqlx::Error> {
@@ -21,9 +29,16 @@ pub async fn get_match_by_id(db: &SqlitePool, id: i64) -> Result<Option<MatchEnt
             ac.iso2Code as away_team_country_iso2,
             m.home_score_unidentified as "home_score_unidentified: i32",
             m.away_score_unidentified as "away_score_unidentified: i32",
+            (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id) as "home_score_identified!: i32",
+            (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.away_team_id) as "away_score_identified!: i32",
             m.match_date,
             m.status,
-            m.venue
+            m.arena_id,
+            ar.name as "arena_name?",
+            ar.city as arena_city,
+            m.attendance,
+            m.game_type,
+            m.result
         FROM match m
         INNER JOIN team ht ON m.home_team_id = ht.id
         INNER JOIN team at ON m.away_team_id = at.id
@@ -31,6 +46,7 @@ pub async fn get_match_by_id(db: &SqlitePool, id: i64) -> Result<Option<MatchEnt
         LEFT JOIN country ac ON at.country_id = ac.id
         LEFT JOIN season s ON m.season_id = s.id
         LEFT JOIN event e ON s.event_id = e.id
+        LEFT JOIN arena ar ON m.arena_id = ar.id
         WHERE m.id = ?
         "#,
         id
@@ -55,16 +71,31 @@ pub async fn get_score_events(
             se.team_id as "team_id!",
             t.name as "team_name!",
             se.scorer_id,
-            scorer.name as scorer_name,
+            scorer.name as "scorer_name?",
             se.assist1_id,
-            assist1.name as assist1_name,
+            assist1.name as "assist1_name?",
             se.assist2_id,
-            assist2.name as assist2_name,
+            assist2.name as "assist2_name?",
             se.period as "period!: i32",
             se.time_minutes as "time_minutes: i32",
             se.time_seconds as "time_seconds: i32",
-            se.goal_type
+            se.goal_type,
+            (SELECT pc.captaincy
+             FROM player_contract pc
+             INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+             WHERE pc.player_id = se.scorer_id AND tp.team_id = se.team_id AND tp.season_id = m.season_id) as "scorer_captaincy: String",
+            (SELECT pc.captaincy
+             FROM player_contract pc
+             INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+             WHERE pc.player_id = se.assist1_id AND tp.team_id = se.team_id AND tp.season_id = m.season_id) as "assist1_captaincy: String",
+            (SELECT pc.captaincy
+             FROM player_contract pc
+             INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+             WHERE pc.player_id = se.assist2_id AND tp.team_id = se.team_id AND tp.season_id = m.season_id) as "assist2_captaincy: String",
+            se.video_url,
+            se.video_timestamp as "video_timestamp: i32"
         FROM score_event se
+        INNER JOIN match m ON se.match_id = m.id
         INNER JOIN team t ON se.team_id = t.id
         LEFT JOIN player scorer ON se.scorer_id = scorer.id
         LEFT JOIN player assist1 ON se.assist1_id = assist1.id
@@ -80,6 +111,92 @@ pub async fn get_score_events(
     Ok(rows)
 }
 
+/// Goals in insertion order, read after a cursor instead of at an offset
+///
+/// `match_id` limits the goals to one match. The page number is derived from
+/// the cursor's position, so it stays meaningful alongside `total`.
+pub async fn get_score_events_after(
+    db: &SqlitePool,
+    match_id: Option<i64>,
+    after: Option<Cursor>,
+    limit: usize,
+) -> Result<PagedResult<ScoreEventEntity>, sqlx::Error> {
+    let after_id = after.map(|cursor| cursor.after_id).unwrap_or(0);
+    let fetch = (limit + 1) as i64;
+    let mut rows = sqlx::query_as!(
+        ScoreEventEntity,
+        r#"
+        SELECT
+            se.id as "id!",
+            se.match_id as "match_id!",
+            se.team_id as "team_id!",
+            t.name as "team_name!",
+            se.scorer_id,
+            scorer.name as "scorer_name?",
+            se.assist1_id,
+            assist1.name as "assist1_name?",
+            se.assist2_id,
+            assist2.name as "assist2_name?",
+            se.period as "period!: i32",
+            se.time_minutes as "time_minutes: i32",
+            se.time_seconds as "time_seconds: i32",
+            se.goal_type,
+            (SELECT pc.captaincy
+             FROM player_contract pc
+             INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+             WHERE pc.player_id = se.scorer_id AND tp.team_id = se.team_id AND tp.season_id = m.season_id) as "scorer_captaincy: String",
+            (SELECT pc.captaincy
+             FROM player_contract pc
+             INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+             WHERE pc.player_id = se.assist1_id AND tp.team_id = se.team_id AND tp.season_id = m.season_id) as "assist1_captaincy: String",
+            (SELECT pc.captaincy
+             FROM player_contract pc
+             INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+             WHERE pc.player_id = se.assist2_id AND tp.team_id = se.team_id AND tp.season_id = m.season_id) as "assist2_captaincy: String",
+            se.video_url,
+            se.video_timestamp as "video_timestamp: i32"
+        FROM score_event se
+        INNER JOIN match m ON se.match_id = m.id
+        INNER JOIN team t ON se.team_id = t.id
+        LEFT JOIN player scorer ON se.scorer_id = scorer.id
+        LEFT JOIN player assist1 ON se.assist1_id = assist1.id
+        LEFT JOIN player assist2 ON se.assist2_id = assist2.id
+        WHERE (?1 IS NULL OR se.match_id = ?1) AND se.id > ?2
+        ORDER BY se.id
+        LIMIT ?3
+        "#,
+        match_id,
+        after_id,
+        fetch
+    )
+    .fetch_all(db)
+    .await?;
+
+    let counts = sqlx::query!(
+        r#"
+        SELECT
+            COUNT(*) as "total!: i64",
+            COALESCE(SUM(id <= ?2), 0) as "before!: i64"
+        FROM score_event
+        WHERE ?1 IS NULL OR match_id = ?1
+        "#,
+        match_id,
+        after_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    let next = if rows.len() > limit {
+        rows.truncate(limit);
+        rows.last().map(|row| Cursor::after(row.id))
+    } else {
+        None
+    };
+    let page = (counts.before as usize).div_ceil(limit) + 1;
+
+    Ok(PagedResult::new(rows, counts.total as usize, page, limit).with_next_cursor(next))
+}
+
 /// Get match detail with calculated scores
 pub async fn get_match_detail(
     db: &SqlitePool,
@@ -91,6 +208,11 @@ pub async fn get_match_detail(
     };
 
     let score_events = get_score_events(db, id).await?;
+    let shots = super::shots::get_match_shots(db, id).await?;
+    let lineup = super::lineups::get_match_lineup(db, id).await?;
+    let officials = crate::service::referees::get_match_officials(db, id).await?;
+    let staff = crate::service::staff::get_match_staff(db, id).await?;
+    let faceoffs = super::faceoffs::get_match_faceoffs(db, id).await?;
 
     // Calculate identified scores from score events
     let home_score_identified = score_events
@@ -113,17 +235,34 @@ pub async fn get_match_detail(
         away_score_identified,
         home_score_total,
         away_score_total,
+        shots,
+        lineup,
+        officials,
+        staff,
+        faceoffs,
+        goal_flags: HashMap::new(),
     }))
 }
 
 /// Get matches with filtering, sorting, and pagination
+///
+/// `then_by` holds tie-breaking sort keys applied after the primary sort.
+///
+/// Sorted by date alone, the list is paged by keyset on (match_date, id):
+/// `after` is the `next_cursor` of the previous page, which then replaces
+/// the OFFSET so late pages don't scan every earlier match. `page` only
+/// labels the result in that case. Other sorts, and jumps to a page without
+/// a cursor, fall back to OFFSET.
+#[allow(clippy::too_many_arguments)]
 pub async fn get_matches(
     db: &SqlitePool,
     filters: &MatchFilters,
     sort_field: &SortField,
     sort_order: &SortOrder,
+    then_by: &[(SortField, SortOrder)],
     page: usize,
     page_size: usize,
+    after: Option<&Cursor>,
 ) -> Result<PagedResult<MatchEntity>, sqlx::Error> {
     // Build the base query
     let mut count_query = QueryBuilder::new(
@@ -138,7 +277,9 @@ pub async fn get_matches(
             m.home_team_id, ht.name as home_team_name, hc.iso2Code as home_team_country_iso2, \
             m.away_team_id, at.name as away_team_name, ac.iso2Code as away_team_country_iso2, \
             m.home_score_unidentified, m.away_score_unidentified, \
-            m.match_date, m.status, m.venue \
+            (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id) as home_score_identified, \
+            (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.away_team_id) as away_score_identified, \
+            m.match_date, m.status, m.arena_id, ar.name as arena_name, ar.city as arena_city, m.attendance, m.game_type, m.result \
          FROM match m \
          INNER JOIN team ht ON m.home_team_id = ht.id \
          INNER JOIN team at ON m.away_team_id = at.id \
@@ -146,70 +287,41 @@ pub async fn get_matches(
          LEFT JOIN country ac ON at.country_id = ac.id \
          LEFT JOIN season s ON m.season_id = s.id \
          LEFT JOIN event e ON s.event_id = e.id \
+         LEFT JOIN arena ar ON m.arena_id = ar.id \
          WHERE 1=1"
     );
 
-    // Apply filters
-    if let Some(season_id) = filters.season_id {
-        count_query.push(" AND m.season_id = ").push_bind(season_id);
-        data_query.push(" AND m.season_id = ").push_bind(season_id);
-    }
-
-    if let Some(team_id) = filters.team_id {
-        count_query
-            .push(" AND (m.home_team_id = ")
-            .push_bind(team_id)
-            .push(" OR m.away_team_id = ")
-            .push_bind(team_id)
-            .push(")");
-        data_query
-            .push(" AND (m.home_team_id = ")
-            .push_bind(team_id)
-            .push(" OR m.away_team_id = ")
-            .push_bind(team_id)
-            .push(")");
-    }
-
-    if let Some(status) = &filters.status {
-        count_query.push(" AND m.status = ").push_bind(status);
-        data_query.push(" AND m.status = ").push_bind(status);
-    }
-
-    if let Some(date_from) = &filters.date_from {
-        count_query
-            .push(" AND m.match_date >= ")
-            .push_bind(date_from);
-        data_query
-            .push(" AND m.match_date >= ")
-            .push_bind(date_from);
-    }
-
-    if let Some(date_to) = &filters.date_to {
-        count_query.push(" AND m.match_date <= ").push_bind(date_to);
-        data_query.push(" AND m.match_date <= ").push_bind(date_to);
-    }
+    apply_filters(&mut count_query, filters);
+    apply_filters(&mut data_query, filters);
 
     // Get total count
     let count_row = count_query.build().fetch_one(db).await?;
     let total: i64 = count_row.get("total");
 
+    let keyset = matches!(sort_field, SortField::Date) && then_by.is_empty();
+    let after = after.filter(|_| keyset);
+    if let Some(cursor) = after {
+        push_date_keyset(&mut data_query, sort_order, cursor);
+    }
+
     // Add sorting
-    data_query
-        .push(" ORDER BY ")
-        .push(sort_field.to_sql())
-        .push(" ")
-        .push(sort_order.to_sql());
-
-    // Add pagination
-    let offset = (page - 1) * page_size;
-    data_query
-        .push(" LIMIT ")
-        .push_bind(page_size as i64)
-        .push(" OFFSET ")
-        .push_bind(offset as i64);
+    data_query.push(" ORDER BY ");
+    push_sort_key(&mut data_query, sort_field, sort_order);
+    push_secondary_sort(&mut data_query, then_by);
+    // Matches equal on every key would otherwise move between pages
+    data_query.push(", m.id ").push(sort_order.to_sql());
+
+    // Add pagination; one extra row tells whether a next page exists
+    data_query.push(" LIMIT ").push_bind(page_size as i64 + 1);
+    if after.is_none() {
+        let offset = (page - 1) * page_size;
+        data_query.push(" OFFSET ").push_bind(offset as i64);
+    }
 
     // Execute data query
-    let rows = data_query.build().fetch_all(db).await?;
+    let mut rows = data_query.build().fetch_all(db).await?;
+    let more = rows.len() > page_size;
+    rows.truncate(page_size);
 
     let items: Vec<MatchEntity> = rows
         .into_iter()
@@ -226,13 +338,315 @@ pub async fn get_matches(
             away_team_country_iso2: row.get("away_team_country_iso2"),
             home_score_unidentified: row.get("home_score_unidentified"),
             away_score_unidentified: row.get("away_score_unidentified"),
+            home_score_identified: row.get("home_score_identified"),
+            away_score_identified: row.get("away_score_identified"),
             match_date: row.get("match_date"),
             status: row.get("status"),
-            venue: row.get("venue"),
+            arena_id: row.get("arena_id"),
+            arena_name: row.get("arena_name"),
+            arena_city: row.get("arena_city"),
+            attendance: row.get("attendance"),
+            game_type: row.get("game_type"),
+            result: row.get("result"),
         })
-        .collect();
+        .collect::<Vec<_>>();
 
-    Ok(PagedResult::new(items, total as usize, page, page_size))
+    let result = PagedResult::new(items, total as usize, page, page_size);
+    if !keyset {
+        return Ok(result);
+    }
+    let next = result
+        .items
+        .last()
+        .filter(|_| more)
+        .map(|last| Cursor::after_key(last.match_date.clone(), last.id));
+    Ok(result.with_next_cursor(next))
+}
+
+/// Restrict a date-sorted match query to the rows after the cursor
+///
+/// Mirrors `ORDER BY m.match_date {order} NULLS LAST, m.id {order}`: undated
+/// matches come after every dated one in either direction.
+fn push_date_keyset(
+    query: &mut QueryBuilder<'_, sqlx::Sqlite>,
+    sort_order: &SortOrder,
+    cursor: &Cursor,
+) {
+    let beyond = match sort_order {
+        SortOrder::Asc => " > ",
+        SortOrder::Desc => " < ",
+    };
+    match &cursor.after_key {
+        Some(date) => {
+            query
+                .push(" AND (m.match_date")
+                .push(beyond)
+                .push_bind(date.clone())
+                .push(" OR (m.match_date = ")
+                .push_bind(date.clone())
+                .push(" AND m.id")
+                .push(beyond)
+                .push_bind(cursor.after_id)
+                .push(") OR m.match_date IS NULL)");
+        }
+        None => {
+            query
+                .push(" AND m.match_date IS NULL AND m.id")
+                .push(beyond)
+                .push_bind(cursor.after_id);
+        }
+    }
+}
+
+/// Season label of a match in exports, as the match import reads it back
+const EXPORT_SEASON_LABEL: &str = "COALESCE(s.display_name, e.name || ' ' || s.year)";
+
+/// Write every match matching the filters to a CSV export, in list order
+///
+/// Rows are read from the database one at a time, so the export never holds
+/// the whole list. The leading columns match what the match import reads.
+pub async fn export_matches_csv(
+    db: &SqlitePool,
+    filters: &MatchFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+    then_by: &[(SortField, SortOrder)],
+    sink: &mut CsvSink,
+) -> Result<(), sqlx::Error> {
+    let mut query = QueryBuilder::new("SELECT m.id, ");
+    query.push(EXPORT_SEASON_LABEL).push(
+        " as season, ht.name as home_team_name, at.name as away_team_name, m.match_date, \
+            m.home_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id) as home_score, \
+            m.away_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.away_team_id) as away_score, \
+            m.status, m.game_type, m.result, ar.name as arena_name, m.attendance \
+         FROM match m \
+         INNER JOIN team ht ON m.home_team_id = ht.id \
+         INNER JOIN team at ON m.away_team_id = at.id \
+         LEFT JOIN season s ON m.season_id = s.id \
+         LEFT JOIN event e ON s.event_id = e.id \
+         LEFT JOIN arena ar ON m.arena_id = ar.id \
+         WHERE 1=1",
+    );
+    apply_filters(&mut query, filters);
+    query.push(" ORDER BY ");
+    push_sort_key(&mut query, sort_field, sort_order);
+    push_secondary_sort(&mut query, then_by);
+    query.push(", m.id ").push(sort_order.to_sql());
+
+    if !sink
+        .write([
+            "id",
+            "season",
+            "home_team",
+            "away_team",
+            "date",
+            "home_score",
+            "away_score",
+            "status",
+            "game_type",
+            "result",
+            "arena",
+            "attendance",
+        ])
+        .await
+    {
+        return Ok(());
+    }
+
+    let mut rows = query.build().fetch(db);
+    while let Some(row) = rows.try_next().await? {
+        let optional_text = |column: &str| row.get::<Option<String>, _>(column).unwrap_or_default();
+        let record = [
+            row.get::<i64, _>("id").to_string(),
+            optional_text("season"),
+            row.get("home_team_name"),
+            row.get("away_team_name"),
+            optional_text("match_date"),
+            row.get::<i64, _>("home_score").to_string(),
+            row.get::<i64, _>("away_score").to_string(),
+            row.get("status"),
+            optional_text("game_type"),
+            optional_text("result"),
+            optional_text("arena_name"),
+            row.get::<Option<i64>, _>("attendance")
+                .map(|attendance| attendance.to_string())
+                .unwrap_or_default(),
+        ];
+        if !sink.write(record).await {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Write the goals of every match matching the filters to a CSV export
+///
+/// Matches come in list order, their goals in the order they were scored.
+/// Goals recorded only as a score, without a score event, are not included.
+pub async fn export_score_events_csv(
+    db: &SqlitePool,
+    filters: &MatchFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+    then_by: &[(SortField, SortOrder)],
+    sink: &mut CsvSink,
+) -> Result<(), sqlx::Error> {
+    let mut query = QueryBuilder::new("SELECT se.id, se.match_id, m.match_date, ");
+    query.push(EXPORT_SEASON_LABEL).push(
+        " as season, ht.name as home_team_name, at.name as away_team_name, t.name as team_name, \
+            se.period, se.time_minutes, se.time_seconds, se.goal_type, \
+            scorer.name as scorer_name, assist1.name as assist1_name, assist2.name as assist2_name \
+         FROM score_event se \
+         INNER JOIN match m ON se.match_id = m.id \
+         INNER JOIN team t ON se.team_id = t.id \
+         INNER JOIN team ht ON m.home_team_id = ht.id \
+         INNER JOIN team at ON m.away_team_id = at.id \
+         LEFT JOIN player scorer ON se.scorer_id = scorer.id \
+         LEFT JOIN player assist1 ON se.assist1_id = assist1.id \
+         LEFT JOIN player assist2 ON se.assist2_id = assist2.id \
+         LEFT JOIN season s ON m.season_id = s.id \
+         LEFT JOIN event e ON s.event_id = e.id \
+         WHERE 1=1",
+    );
+    apply_filters(&mut query, filters);
+    query.push(" ORDER BY ");
+    push_sort_key(&mut query, sort_field, sort_order);
+    push_secondary_sort(&mut query, then_by);
+    query
+        .push(", m.id ")
+        .push(sort_order.to_sql())
+        .push(", se.period, se.time_minutes, se.time_seconds, se.id");
+
+    if !sink
+        .write([
+            "id",
+            "match_id",
+            "date",
+            "season",
+            "home_team",
+            "away_team",
+            "team",
+            "period",
+            "time",
+            "goal_type",
+            "scorer",
+            "assist1",
+            "assist2",
+        ])
+        .await
+    {
+        return Ok(());
+    }
+
+    let mut rows = query.build().fetch(db);
+    while let Some(row) = rows.try_next().await? {
+        let optional_text = |column: &str| row.get::<Option<String>, _>(column).unwrap_or_default();
+        let time = match (
+            row.get::<Option<i64>, _>("time_minutes"),
+            row.get::<Option<i64>, _>("time_seconds"),
+        ) {
+            (Some(minutes), seconds) => format!("{}:{:02}", minutes, seconds.unwrap_or(0)),
+            (None, _) => String::new(),
+        };
+        let record = [
+            row.get::<i64, _>("id").to_string(),
+            row.get::<i64, _>("match_id").to_string(),
+            optional_text("match_date"),
+            optional_text("season"),
+            row.get("home_team_name"),
+            row.get("away_team_name"),
+            row.get("team_name"),
+            row.get::<Option<i64>, _>("period")
+                .map(|period| period.to_string())
+                .unwrap_or_default(),
+            time,
+            optional_text("goal_type"),
+            optional_text("scorer_name"),
+            optional_text("assist1_name"),
+            optional_text("assist2_name"),
+        ];
+        if !sink.write(record).await {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Append the WHERE conditions of the match filters to a query over `match m`
+fn apply_filters<'a>(
+    query_builder: &mut QueryBuilder<'a, sqlx::Sqlite>,
+    filters: &'a MatchFilters,
+) {
+    if let Some(season_id) = filters.season_id {
+        query_builder
+            .push(" AND m.season_id = ")
+            .push_bind(season_id);
+    }
+
+    if let Some(team_id) = filters.team_id {
+        query_builder
+            .push(" AND (m.home_team_id = ")
+            .push_bind(team_id)
+            .push(" OR m.away_team_id = ")
+            .push_bind(team_id)
+            .push(")");
+    }
+
+    if filters.status.as_deref() == Some(STATUS_NOT_CANCELLED) {
+        query_builder.push(" AND m.status != 'cancelled'");
+    } else if let Some(status) = &filters.status {
+        query_builder.push(" AND m.status = ").push_bind(status);
+    }
+
+    if let Some(date_from) = &filters.date_from {
+        query_builder
+            .push(" AND m.match_date >= ")
+            .push_bind(date_from);
+    }
+
+    if let Some(date_to) = &filters.date_to {
+        query_builder
+            .push(" AND m.match_date <= ")
+            .push_bind(date_to);
+    }
+
+    if filters.date_unknown {
+        query_builder.push(" AND m.match_date IS NULL");
+    }
+
+    if let Some(arena_id) = filters.arena_id {
+        query_builder.push(" AND m.arena_id = ").push_bind(arena_id);
+    }
+
+    if let Some(game_type) = filters.game_type {
+        query_builder
+            .push(" AND m.game_type = ")
+            .push_bind(game_type.as_str());
+    }
+
+    if let Some(group_id) = filters.group_id {
+        // Both teams must be assigned to the group in the match's season
+        let in_group = |side: &str| {
+            format!(
+                " AND EXISTS (SELECT 1 FROM team_participation tp \
+                 WHERE tp.season_id = m.season_id AND tp.team_id = m.{}_team_id AND tp.group_id = ",
+                side
+            )
+        };
+        query_builder
+            .push(in_group("home"))
+            .push_bind(group_id)
+            .push(")")
+            .push(in_group("away"))
+            .push_bind(group_id)
+            .push(")");
+    }
+
+    if let Some(coverage) = &filters.coverage {
+        query_builder.push(" AND ").push(coverage.to_sql());
+    }
 }
 
 /// Check if both teams participate in the given season (for validation)
@@ -262,7 +676,7 @@ pub async fn validate_teams_in_season(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::service::matches::CreateMatchEntity;
+    use crate::service::matches::{CreateMatchEntity, GameType, ScoreCoverage};
     use sqlx::SqlitePool;
 
     #[sqlx::test(
@@ -270,6 +684,13 @@ mod tests {
         fixtures("events", "seasons", "teams", "team_participations")
     )]
     async fn test_get_match_by_id_found(pool: SqlitePool) {
+        let arena_id =
+            sqlx::query("INSERT INTO arena (name, city) VALUES ('Test Arena', 'Prague')")
+                .execute(&pool)
+                .await
+                .unwrap()
+                .last_insert_rowid();
+
         // Create a match first
         let create_match = CreateMatchEntity {
             season_id: 1,
@@ -279,14 +700,17 @@ mod tests {
             away_score_unidentified: 0,
             match_date: Some("2024-01-15".to_string()),
             status: "scheduled".to_string(),
-            venue: Some("Test Arena".to_string()),
+            arena_id: Some(arena_id),
+            attendance: Some(12_000),
+            game_type: "group".to_string(),
         };
         let id = crate::service::matches::create_match(&pool, create_match)
             .await
             .unwrap();
 
-        let result = get_match_by_id(&pool, id).await.unwrap();
-        assert!(result.is_some());
+        let result = get_match_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!(result.arena_name.as_deref(), Some("Test Arena"));
+        assert_eq!(result.attendance, Some(12_000));
     }
 
     #[sqlx::test(migrations = "./migrations")]
@@ -306,15 +730,435 @@ mod tests {
             status: None,
             date_from: None,
             date_to: None,
+            coverage: None,
+            arena_id: None,
+            game_type: None,
+            group_id: None,
+            date_unknown: false,
         };
-        let result = get_matches(&pool, &filters, &SortField::Date, &SortOrder::Desc, 1, 20)
-            .await
-            .unwrap();
+        let result = get_matches(
+            &pool,
+            &filters,
+            &SortField::Date,
+            &SortOrder::Desc,
+            &[],
+            1,
+            20,
+            None,
+        )
+        .await
+        .unwrap();
 
         // Just verify the query executes successfully - total is usize so always >= 0
         assert!(result.items.len() <= result.total);
     }
 
+    async fn create_test_match(pool: &SqlitePool, home_unidentified: i32) -> i64 {
+        crate::service::matches::create_match(
+            pool,
+            CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: home_unidentified,
+                away_score_unidentified: 0,
+                match_date: Some("2024-01-15".to_string()),
+                status: "finished".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn add_home_goal(pool: &SqlitePool, match_id: i64) {
+        crate::service::matches::create_score_event(
+            pool,
+            crate::service::matches::CreateScoreEventEntity {
+                match_id,
+                team_id: 1,
+                scorer_id: None,
+                assist1_id: None,
+                assist2_id: None,
+                period: 1,
+                time_minutes: Some(5),
+                time_seconds: Some(0),
+                goal_type: None,
+                video_url: None,
+                video_timestamp: None,
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_get_matches_score_coverage(pool: SqlitePool) {
+        // 2 goals, both identified
+        let full = create_test_match(&pool, 2).await;
+        add_home_goal(&pool, full).await;
+        add_home_goal(&pool, full).await;
+        // 3 goals, one identified
+        let partial = create_test_match(&pool, 3).await;
+        add_home_goal(&pool, partial).await;
+        // 2 goals, none identified
+        let aggregate = create_test_match(&pool, 2).await;
+        // 0:0
+        let no_goals = create_test_match(&pool, 0).await;
+
+        let expected = [
+            (full, ScoreCoverage::Full),
+            (partial, ScoreCoverage::Partial),
+            (aggregate, ScoreCoverage::AggregateOnly),
+            (no_goals, ScoreCoverage::NoGoals),
+        ];
+
+        for (id, coverage) in expected {
+            let found = get_match_by_id(&pool, id).await.unwrap().unwrap();
+            assert_eq!(found.score_coverage(), coverage);
+
+            let filters = MatchFilters {
+                season_id: None,
+                team_id: None,
+                status: None,
+                date_from: None,
+                date_to: None,
+                coverage: Some(coverage),
+                arena_id: None,
+                game_type: None,
+                group_id: None,
+                date_unknown: false,
+            };
+            let result = get_matches(
+                &pool,
+                &filters,
+                &SortField::Date,
+                &SortOrder::Desc,
+                &[],
+                1,
+                20,
+                None,
+            )
+            .await
+            .unwrap();
+            assert_eq!(result.total, 1);
+            assert_eq!(result.items[0].id, id);
+            assert_eq!(result.items[0].score_coverage(), coverage);
+        }
+
+        let partial_match = get_match_by_id(&pool, partial).await.unwrap().unwrap();
+        assert_eq!(partial_match.home_score_identified, 1);
+        assert_eq!(partial_match.home_score_total(), 3);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_get_matches_secondary_sort(pool: SqlitePool) {
+        let first = create_test_match(&pool, 0).await;
+        let second = create_test_match(&pool, 0).await;
+        sqlx::query!(
+            "UPDATE match SET status = 'scheduled', match_date = '2024-02-01' WHERE id = ?",
+            second
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let third = create_test_match(&pool, 0).await;
+        sqlx::query!(
+            "UPDATE match SET match_date = '2024-03-01' WHERE id = ?",
+            third
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let filters = MatchFilters {
+            season_id: None,
+            team_id: None,
+            status: None,
+            date_from: None,
+            date_to: None,
+            coverage: None,
+            arena_id: None,
+            game_type: None,
+            group_id: None,
+            date_unknown: false,
+        };
+
+        // Status first, newest date breaks the tie between finished matches
+        let result = get_matches(
+            &pool,
+            &filters,
+            &SortField::Status,
+            &SortOrder::Asc,
+            &[(SortField::Date, SortOrder::Desc)],
+            1,
+            20,
+            None,
+        )
+        .await
+        .unwrap();
+        let ids: Vec<i64> = result.items.iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec![third, first, second]);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_get_matches_not_cancelled(pool: SqlitePool) {
+        let played = create_test_match(&pool, 0).await;
+        let cancelled = create_test_match(&pool, 0).await;
+        sqlx::query!(
+            "UPDATE match SET status = 'cancelled' WHERE id = ?",
+            cancelled
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let filters = MatchFilters {
+            season_id: None,
+            team_id: None,
+            status: Some(STATUS_NOT_CANCELLED.to_string()),
+            date_from: None,
+            date_to: None,
+            coverage: None,
+            arena_id: None,
+            game_type: None,
+            group_id: None,
+            date_unknown: false,
+        };
+        let result = get_matches(
+            &pool,
+            &filters,
+            &SortField::Date,
+            &SortOrder::Desc,
+            &[],
+            1,
+            20,
+            None,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.total, 1);
+        assert_eq!(result.items[0].id, played);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_get_matches_undated_sort_last_and_stable(pool: SqlitePool) {
+        let dated = create_test_match(&pool, 0).await;
+        let mut undated = Vec::new();
+        for _ in 0..3 {
+            let id = create_test_match(&pool, 0).await;
+            sqlx::query!("UPDATE match SET match_date = NULL WHERE id = ?", id)
+                .execute(&pool)
+                .await
+                .unwrap();
+            undated.push(id);
+        }
+
+        let mut filters = MatchFilters {
+            season_id: None,
+            team_id: None,
+            status: None,
+            date_from: None,
+            date_to: None,
+            coverage: None,
+            arena_id: None,
+            game_type: None,
+            group_id: None,
+            date_unknown: false,
+        };
+
+        // Undated matches come last in both directions, ordered by ID
+        for (order, expected) in [
+            (
+                SortOrder::Desc,
+                vec![dated, undated[2], undated[1], undated[0]],
+            ),
+            (
+                SortOrder::Asc,
+                vec![dated, undated[0], undated[1], undated[2]],
+            ),
+        ] {
+            let mut ids = Vec::new();
+            for page in 1..=2 {
+                let result = get_matches(
+                    &pool,
+                    &filters,
+                    &SortField::Date,
+                    &order,
+                    &[],
+                    page,
+                    2,
+                    None,
+                )
+                .await
+                .unwrap();
+                ids.extend(result.items.iter().map(|m| m.id));
+            }
+            assert_eq!(ids, expected);
+        }
+
+        filters.date_unknown = true;
+        let result = get_matches(
+            &pool,
+            &filters,
+            &SortField::Date,
+            &SortOrder::Desc,
+            &[],
+            1,
+            20,
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.total, 3);
+        assert!(result.items.iter().all(|m| m.match_date.is_none()));
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_get_matches_keyset_pages(pool: SqlitePool) {
+        for date in [
+            Some("2024-01-10"),
+            Some("2024-01-15"),
+            Some("2024-01-15"),
+            None,
+            None,
+        ] {
+            let id = create_test_match(&pool, 0).await;
+            sqlx::query!("UPDATE match SET match_date = ? WHERE id = ?", date, id)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+        let filters = MatchFilters {
+            season_id: None,
+            team_id: None,
+            status: None,
+            date_from: None,
+            date_to: None,
+            coverage: None,
+            arena_id: None,
+            game_type: None,
+            group_id: None,
+            date_unknown: false,
+        };
+
+        for order in [SortOrder::Asc, SortOrder::Desc] {
+            let all = get_matches(&pool, &filters, &SortField::Date, &order, &[], 1, 20, None)
+                .await
+                .unwrap();
+            assert!(all.next_cursor.is_none());
+            let expected: Vec<i64> = all.items.iter().map(|m| m.id).collect();
+
+            let mut ids = Vec::new();
+            let mut after = None;
+            for page in 1..=3 {
+                let result = get_matches(
+                    &pool,
+                    &filters,
+                    &SortField::Date,
+                    &order,
+                    &[],
+                    page,
+                    2,
+                    after.as_ref(),
+                )
+                .await
+                .unwrap();
+                ids.extend(result.items.iter().map(|m| m.id));
+                assert_eq!(result.has_next, page < 3);
+                after = result.next_cursor.as_deref().and_then(Cursor::decode);
+            }
+            assert_eq!(ids, expected);
+            assert!(after.is_none());
+        }
+
+        // Other sorts page by offset and hand out no cursor
+        let result = get_matches(
+            &pool,
+            &filters,
+            &SortField::Status,
+            &SortOrder::Asc,
+            &[],
+            1,
+            2,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(result.has_next);
+        assert!(result.next_cursor.is_none());
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_get_matches_game_type(pool: SqlitePool) {
+        let group = create_test_match(&pool, 0).await;
+        let exhibition = create_test_match(&pool, 0).await;
+        sqlx::query!(
+            "UPDATE match SET game_type = 'exhibition' WHERE id = ?",
+            exhibition
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let found = get_match_by_id(&pool, group).await.unwrap().unwrap();
+        assert_eq!(found.game_type(), GameType::Group);
+
+        for (game_type, expected) in [
+            (GameType::Group, vec![group]),
+            (GameType::Exhibition, vec![exhibition]),
+            (GameType::Playoff, vec![]),
+        ] {
+            let filters = MatchFilters {
+                season_id: None,
+                team_id: None,
+                status: None,
+                date_from: None,
+                date_to: None,
+                coverage: None,
+                arena_id: None,
+                game_type: Some(game_type),
+                group_id: None,
+                date_unknown: false,
+            };
+            let result = get_matches(
+                &pool,
+                &filters,
+                &SortField::Date,
+                &SortOrder::Desc,
+                &[],
+                1,
+                20,
+                None,
+            )
+            .await
+            .unwrap();
+            let ids: Vec<i64> = result.items.iter().map(|m| m.id).collect();
+            assert_eq!(ids, expected);
+        }
+    }
+
     #[sqlx::test(
         migrations = "./migrations",
         fixtures("events", "seasons", "teams", "team_participations")
@@ -334,4 +1178,199 @@ mod tests {
         let result = validate_teams_in_season(&pool, 1, 1, 999).await.unwrap();
         assert!(!result);
     }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_score_event_video_link(pool: SqlitePool) {
+        let match_id = create_test_match(&pool, 0).await;
+        add_home_goal(&pool, match_id).await;
+        let goal = &get_score_events(&pool, match_id).await.unwrap()[0];
+        assert_eq!(goal.video_link(), None);
+
+        crate::service::matches::update_score_event(
+            &pool,
+            goal.id,
+            crate::service::matches::UpdateScoreEventEntity {
+                team_id: 1,
+                scorer_id: None,
+                assist1_id: None,
+                assist2_id: None,
+                period: 1,
+                time_minutes: Some(5),
+                time_seconds: Some(0),
+                goal_type: None,
+                video_url: Some("https://www.youtube.com/watch?v=abc".to_string()),
+                video_timestamp: Some(5025),
+            },
+        )
+        .await
+        .unwrap();
+
+        let goal = crate::service::matches::get_score_event_by_id(&pool, goal.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            goal.video_link().as_deref(),
+            Some("https://www.youtube.com/watch?v=abc&t=5025s")
+        );
+        assert_eq!(goal.video_timestamp_label().as_deref(), Some("1:23:45"));
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_export_matches_and_goals_csv(pool: SqlitePool) {
+        // 2 goals; recording one identifies it
+        let match_id = create_test_match(&pool, 2).await;
+        crate::service::matches::create_score_event(
+            &pool,
+            crate::service::matches::CreateScoreEventEntity {
+                match_id,
+                team_id: 1,
+                scorer_id: Some(1),
+                assist1_id: Some(2),
+                assist2_id: None,
+                period: 2,
+                time_minutes: Some(7),
+                time_seconds: Some(5),
+                goal_type: None,
+                video_url: None,
+                video_timestamp: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let filters = MatchFilters {
+            season_id: Some(1),
+            team_id: None,
+            status: None,
+            date_from: None,
+            date_to: None,
+            coverage: None,
+            arena_id: None,
+            game_type: None,
+            group_id: None,
+            date_unknown: false,
+        };
+        let (db, filters) = (&pool, &filters);
+        let matches_csv = crate::common::csv_export::collect_csv(|mut sink| async move {
+            export_matches_csv(
+                db,
+                filters,
+                &SortField::Date,
+                &SortOrder::Desc,
+                &[],
+                &mut sink,
+            )
+            .await?;
+            Ok(sink)
+        })
+        .await;
+        let goals_csv = crate::common::csv_export::collect_csv(|mut sink| async move {
+            export_score_events_csv(
+                db,
+                filters,
+                &SortField::Date,
+                &SortOrder::Desc,
+                &[],
+                &mut sink,
+            )
+            .await?;
+            Ok(sink)
+        })
+        .await;
+
+        assert_eq!(
+            matches_csv,
+            format!(
+                "id,season,home_team,away_team,date,home_score,away_score,status,game_type,result,arena,attendance\n\
+                 {},2022 Winter Olympics,Team Canada,Team USA,2024-01-15,2,0,finished,group,home_regulation,,\n",
+                match_id
+            )
+        );
+        let goals: Vec<&str> = goals_csv.lines().collect();
+        assert_eq!(goals.len(), 2);
+        assert!(goals[1].ends_with(",Team Canada,2,7:05,,Connor McDavid,Wayne Gretzky,"));
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_get_score_events_after(pool: SqlitePool) {
+        let match_id = crate::service::matches::create_match(
+            &pool,
+            CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: 0,
+                away_score_unidentified: 0,
+                match_date: None,
+                status: "finished".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        let mut ids = Vec::new();
+        for period in 1..=5 {
+            let goal = crate::service::matches::CreateScoreEventEntity {
+                match_id,
+                team_id: 1,
+                scorer_id: None,
+                assist1_id: None,
+                assist2_id: None,
+                period,
+                time_minutes: None,
+                time_seconds: None,
+                goal_type: None,
+                video_url: None,
+                video_timestamp: None,
+            };
+            ids.push(
+                crate::service::matches::create_score_event(&pool, goal)
+                    .await
+                    .unwrap(),
+            );
+        }
+
+        let first = get_score_events_after(&pool, Some(match_id), None, 2)
+            .await
+            .unwrap();
+        assert_eq!(
+            first.items.iter().map(|e| e.id).collect::<Vec<_>>(),
+            ids[..2]
+        );
+        assert_eq!((first.total, first.page, first.total_pages), (5, 1, 3));
+        let cursor = Cursor::decode(first.next_cursor.as_deref().unwrap()).unwrap();
+        assert_eq!(cursor, Cursor::after(ids[1]));
+
+        // Removing an earlier goal does not shift the next page
+        crate::service::matches::delete_score_event(&pool, ids[0])
+            .await
+            .unwrap();
+        let second = get_score_events_after(&pool, Some(match_id), Some(cursor), 2)
+            .await
+            .unwrap();
+        assert_eq!(
+            second.items.iter().map(|e| e.id).collect::<Vec<_>>(),
+            ids[2..4]
+        );
+        assert_eq!(second.page, 2);
+
+        let last = get_score_events_after(&pool, None, Some(Cursor::after(ids[3])), 2)
+            .await
+            .unwrap();
+        assert_eq!(last.items.len(), 1);
+        assert!(last.next_cursor.is_none());
+        assert!(!last.has_next);
+    }
 }
@@ -1,11 +1,21 @@
+mod attendance;
 mod entities;
+mod faceoffs;
 mod filters;
+mod lineups;
 mod match_mutations;
 mod match_queries;
+mod on_ice;
 mod scoring;
+mod shots;
 
+pub use self::attendance::*;
 pub use self::entities::*;
+pub use self::faceoffs::*;
 pub use self::filters::*;
+pub use self::lineups::*;
 pub use self::match_mutations::*;
 pub use self::match_queries::*;
+pub use self::on_ice::*;
 pub use self::scoring::*;
+pub use self::shots::*;
@@ -0,0 +1,143 @@
+use sqlx::SqlitePool;
+
+/// Get the players recorded on the ice for a goal, of both teams
+pub async fn get_on_ice_player_ids(
+    db: &SqlitePool,
+    score_event_id: i64,
+) -> Result<Vec<i64>, sqlx::Error> {
+    let rows = sqlx::query_scalar!(
+        r#"
+        SELECT player_id
+        FROM score_event_on_ice
+        WHERE score_event_id = ?
+        ORDER BY team_id, player_id
+        "#,
+        score_event_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Replace the players of one team on the ice for a goal
+///
+/// Only the two teams of the goal's match are accepted; other teams are
+/// ignored. An empty list clears the team's entry.
+pub async fn save_on_ice_players(
+    db: &SqlitePool,
+    score_event_id: i64,
+    team_id: i64,
+    player_ids: &[i64],
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "DELETE FROM score_event_on_ice WHERE score_event_id = ? AND team_id = ?",
+        score_event_id,
+        team_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    for player_id in player_ids {
+        sqlx::query!(
+            r#"
+            INSERT OR IGNORE INTO score_event_on_ice (score_event_id, player_id, team_id)
+            SELECT se.id, ?, ?
+            FROM score_event se
+            INNER JOIN match m ON se.match_id = m.id
+            WHERE se.id = ? AND ? IN (m.home_team_id, m.away_team_id)
+            "#,
+            player_id,
+            team_id,
+            score_event_id,
+            team_id
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{
+        create_match, create_score_event, CreateMatchEntity, CreateScoreEventEntity,
+    };
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_save_on_ice_players(pool: SqlitePool) {
+        let match_id = create_match(
+            &pool,
+            CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: 0,
+                away_score_unidentified: 0,
+                match_date: Some("2022-02-10".to_string()),
+                status: "finished".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+        let goal_id = create_score_event(
+            &pool,
+            CreateScoreEventEntity {
+                match_id,
+                team_id: 1,
+                scorer_id: Some(1),
+                assist1_id: None,
+                assist2_id: None,
+                period: 1,
+                time_minutes: Some(5),
+                time_seconds: Some(0),
+                goal_type: Some("even_strength".to_string()),
+                video_url: None,
+                video_timestamp: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        save_on_ice_players(&pool, goal_id, 1, &[1, 2])
+            .await
+            .unwrap();
+        save_on_ice_players(&pool, goal_id, 2, &[3]).await.unwrap();
+        // Team 3 did not play in the match
+        save_on_ice_players(&pool, goal_id, 3, &[4]).await.unwrap();
+        assert_eq!(
+            get_on_ice_player_ids(&pool, goal_id).await.unwrap(),
+            vec![1, 2, 3]
+        );
+
+        let scorer = crate::service::players::get_player_season_stats(&pool, 1)
+            .await
+            .unwrap();
+        assert_eq!(scorer[0].plus_minus, Some(1));
+        let defender = crate::service::players::get_player_season_stats(&pool, 3)
+            .await
+            .unwrap();
+        assert_eq!(defender.len(), 1);
+        assert_eq!(defender[0].points, 0);
+        assert_eq!(defender[0].plus_minus, Some(-1));
+
+        // Replacing one team leaves the other alone
+        save_on_ice_players(&pool, goal_id, 1, &[]).await.unwrap();
+        assert_eq!(
+            get_on_ice_player_ids(&pool, goal_id).await.unwrap(),
+            vec![3]
+        );
+    }
+}
@@ -15,16 +15,31 @@ pub async fn get_score_event_by_id(
             se.team_id as "team_id!",
             t.name as "team_name!",
             se.scorer_id,
-            scorer.name as scorer_name,
+            scorer.name as "scorer_name?",
             se.assist1_id,
-            assist1.name as assist1_name,
+            assist1.name as "assist1_name?",
             se.assist2_id,
-            assist2.name as assist2_name,
+            assist2.name as "assist2_name?",
             se.period as "period!: i32",
             se.time_minutes as "time_minutes: i32",
             se.time_seconds as "time_seconds: i32",
-            se.goal_type
+            se.goal_type,
+            (SELECT pc.captaincy
+             FROM player_contract pc
+             INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+             WHERE pc.player_id = se.scorer_id AND tp.team_id = se.team_id AND tp.season_id = m.season_id) as "scorer_captaincy: String",
+            (SELECT pc.captaincy
+             FROM player_contract pc
+             INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+             WHERE pc.player_id = se.assist1_id AND tp.team_id = se.team_id AND tp.season_id = m.season_id) as "assist1_captaincy: String",
+            (SELECT pc.captaincy
+             FROM player_contract pc
+             INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+             WHERE pc.player_id = se.assist2_id AND tp.team_id = se.team_id AND tp.season_id = m.season_id) as "assist2_captaincy: String",
+            se.video_url,
+            se.video_timestamp as "video_timestamp: i32"
         FROM score_event se
+        INNER JOIN match m ON se.match_id = m.id
         INNER JOIN team t ON se.team_id = t.id
         LEFT JOIN player scorer ON se.scorer_id = scorer.id
         LEFT JOIN player assist1 ON se.assist1_id = assist1.id
@@ -51,6 +66,11 @@ pub async fn get_score_event_by_id(
         time_minutes: row.time_minutes,
         time_seconds: row.time_seconds,
         goal_type: row.goal_type,
+        scorer_captaincy: row.scorer_captaincy,
+        assist1_captaincy: row.assist1_captaincy,
+        assist2_captaincy: row.assist2_captaincy,
+        video_url: row.video_url,
+        video_timestamp: row.video_timestamp,
     }))
 }
 
@@ -64,8 +84,8 @@ pub async fn create_score_event(
 
     // Insert the score event
     let result = sqlx::query!(
-        "INSERT INTO score_event (match_id, team_id, scorer_id, assist1_id, assist2_id, period, time_minutes, time_seconds, goal_type) \
-         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        "INSERT INTO score_event (match_id, team_id, scorer_id, assist1_id, assist2_id, period, time_minutes, time_seconds, goal_type, video_url, video_timestamp) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         entity.match_id,
         entity.team_id,
         entity.scorer_id,
@@ -74,7 +94,9 @@ pub async fn create_score_event(
         entity.period,
         entity.time_minutes,
         entity.time_seconds,
-        entity.goal_type
+        entity.goal_type,
+        entity.video_url,
+        entity.video_timestamp
     )
     .execute(&mut *tx)
     .await?;
@@ -110,6 +132,8 @@ pub async fn create_score_event(
         .await?;
     }
 
+    super::refresh_match_result(&mut tx, entity.match_id).await?;
+
     // Commit the transaction
     tx.commit().await?;
 
@@ -122,10 +146,13 @@ pub async fn update_score_event(
     id: i64,
     entity: UpdateScoreEventEntity,
 ) -> Result<bool, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
     let result = sqlx::query!(
         "UPDATE score_event \
          SET team_id = ?, scorer_id = ?, assist1_id = ?, assist2_id = ?, \
-             period = ?, time_minutes = ?, time_seconds = ?, goal_type = ? \
+             period = ?, time_minutes = ?, time_seconds = ?, goal_type = ?, \
+             video_url = ?, video_timestamp = ? \
          WHERE id = ?",
         entity.team_id,
         entity.scorer_id,
@@ -135,12 +162,24 @@ pub async fn update_score_event(
         entity.time_minutes,
         entity.time_seconds,
         entity.goal_type,
+        entity.video_url,
+        entity.video_timestamp,
         id
     )
-    .execute(db)
+    .execute(&mut *tx)
     .await?;
 
-    Ok(result.rows_affected() > 0)
+    if result.rows_affected() == 0 {
+        return Ok(false);
+    }
+
+    let match_id = sqlx::query_scalar!("SELECT match_id FROM score_event WHERE id = ?", id)
+        .fetch_one(&mut *tx)
+        .await?;
+    super::refresh_match_result(&mut tx, match_id).await?;
+    tx.commit().await?;
+
+    Ok(true)
 }
 
 /// Delete a score event and increment unidentified goal count
@@ -192,6 +231,8 @@ pub async fn delete_score_event(db: &SqlitePool, id: i64) -> Result<bool, sqlx::
         .await?;
     }
 
+    super::refresh_match_result(&mut tx, match_id).await?;
+
     // Commit the transaction
     tx.commit().await?;
 
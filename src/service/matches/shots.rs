@@ -0,0 +1,184 @@
+use sqlx::{Row, SqlitePool};
+
+use super::entities::{MatchShotsEntity, TeamShootingEntity};
+
+/// Get all recorded shots on goal for a match, ordered by team and period
+pub async fn get_match_shots(
+    db: &SqlitePool,
+    match_id: i64,
+) -> Result<Vec<MatchShotsEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        MatchShotsEntity,
+        r#"
+        SELECT team_id, period as "period: i32", shots as "shots: i32"
+        FROM match_shots
+        WHERE match_id = ?
+        ORDER BY team_id, period
+        "#,
+        match_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Replace all shots on goal of a match
+///
+/// Periods missing from `shots` are cleared, so the form can unset values.
+pub async fn save_match_shots(
+    db: &SqlitePool,
+    match_id: i64,
+    shots: &[MatchShotsEntity],
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!("DELETE FROM match_shots WHERE match_id = ?", match_id)
+        .execute(&mut *tx)
+        .await?;
+
+    for entry in shots {
+        sqlx::query!(
+            "INSERT INTO match_shots (match_id, team_id, period, shots) VALUES (?, ?, ?, ?)",
+            match_id,
+            entry.team_id,
+            entry.period,
+            entry.shots
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Aggregate shots and goals per team for a season
+///
+/// Only matches where the team has shots recorded are counted, so the
+/// shooting percentage is not diluted by games without shot data.
+/// Exhibition games are left out.
+pub async fn get_season_team_shooting(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<Vec<TeamShootingEntity>, sqlx::Error> {
+    let rows = sqlx::query(
+        r#"
+        SELECT team_id, COUNT(*) as games, SUM(shots) as shots, SUM(goals) as goals
+        FROM team_match_shooting
+        WHERE season_id = ?
+        GROUP BY team_id
+        "#,
+    )
+    .bind(season_id)
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| TeamShootingEntity {
+            team_id: row.get("team_id"),
+            games: row.get("games"),
+            shots: row.get("shots"),
+            goals: row.get("goals"),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{create_match, CreateMatchEntity};
+
+    async fn create_test_match(pool: &SqlitePool, home_unidentified: i32) -> i64 {
+        create_match(
+            pool,
+            CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: home_unidentified,
+                away_score_unidentified: 1,
+                match_date: Some("2024-01-15".to_string()),
+                status: "finished".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    fn shots(team_id: i64, period: i32, shots: i32) -> MatchShotsEntity {
+        MatchShotsEntity {
+            team_id,
+            period,
+            shots,
+        }
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_save_match_shots_replaces_existing(pool: SqlitePool) {
+        let match_id = create_test_match(&pool, 2).await;
+
+        save_match_shots(
+            &pool,
+            match_id,
+            &[shots(1, 1, 10), shots(1, 2, 8), shots(2, 1, 5)],
+        )
+        .await
+        .unwrap();
+        assert_eq!(get_match_shots(&pool, match_id).await.unwrap().len(), 3);
+
+        save_match_shots(&pool, match_id, &[shots(1, 1, 12)])
+            .await
+            .unwrap();
+        let saved = get_match_shots(&pool, match_id).await.unwrap();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].shots, 12);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_season_team_shooting(pool: SqlitePool) {
+        let with_shots = create_test_match(&pool, 2).await;
+        // No shots recorded: must not count towards the shooting totals
+        create_test_match(&pool, 5).await;
+        // Exhibition games are left out as well
+        let exhibition = create_test_match(&pool, 4).await;
+        sqlx::query("UPDATE match SET game_type = 'exhibition' WHERE id = ?")
+            .bind(exhibition)
+            .execute(&pool)
+            .await
+            .unwrap();
+        save_match_shots(&pool, exhibition, &[shots(1, 1, 30)])
+            .await
+            .unwrap();
+
+        save_match_shots(
+            &pool,
+            with_shots,
+            &[shots(1, 1, 10), shots(1, 2, 10), shots(2, 3, 8)],
+        )
+        .await
+        .unwrap();
+
+        let shooting = get_season_team_shooting(&pool, 1).await.unwrap();
+        let home = shooting.iter().find(|s| s.team_id == 1).unwrap();
+        assert_eq!(home.games, 1);
+        assert_eq!(home.shots, 20);
+        assert_eq!(home.goals, 2);
+        assert_eq!(home.shooting_percentage(), Some(10.0));
+
+        let away = shooting.iter().find(|s| s.team_id == 2).unwrap();
+        assert_eq!(away.shots, 8);
+        assert_eq!(away.goals, 1);
+    }
+}
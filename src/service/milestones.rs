@@ -0,0 +1,422 @@
+use sqlx::SqlitePool;
+
+/// Goals in one match for a hat trick
+pub const HAT_TRICK_GOALS: i64 = 3;
+/// Points (goals + assists) in one match for a big game
+pub const BIG_GAME_POINTS: i64 = 4;
+/// Career goal that counts as a milestone
+pub const CAREER_GOAL_MILESTONE: i64 = 100;
+
+/// Kind of milestone
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MilestoneKind {
+    HatTrick,
+    FourPointGame,
+    HundredthGoal,
+}
+
+impl MilestoneKind {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "hat_trick" => Some(Self::HatTrick),
+            "four_point_game" => Some(Self::FourPointGame),
+            "hundredth_goal" => Some(Self::HundredthGoal),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::HatTrick => "hat_trick",
+            Self::FourPointGame => "four_point_game",
+            Self::HundredthGoal => "hundredth_goal",
+        }
+    }
+}
+
+/// A milestone reached in a match, with player and match names for display
+#[derive(Debug, Clone)]
+pub struct MilestoneEntity {
+    pub id: i64,
+    pub player_id: i64,
+    pub player_name: String,
+    pub match_id: i64,
+    pub home_team_name: String,
+    pub away_team_name: String,
+    pub match_date: Option<String>,
+    pub kind: String,
+    pub value: i64,
+    pub created_at: String,
+}
+
+impl MilestoneEntity {
+    pub fn kind(&self) -> Option<MilestoneKind> {
+        MilestoneKind::from_str(&self.kind)
+    }
+}
+
+/// A milestone to be stored for a match
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NewMilestone {
+    pub player_id: i64,
+    pub kind: MilestoneKind,
+    pub value: i64,
+}
+
+/// Goals and assists of one player in a match
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MatchPlayerPointsEntity {
+    pub player_id: i64,
+    pub goals: i64,
+    pub assists: i64,
+}
+
+impl MatchPlayerPointsEntity {
+    pub fn points(&self) -> i64 {
+        self.goals + self.assists
+    }
+}
+
+/// Goals and assists of every credited player in a match
+pub async fn get_match_player_points(
+    db: &SqlitePool,
+    match_id: i64,
+) -> Result<Vec<MatchPlayerPointsEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        MatchPlayerPointsEntity,
+        r#"
+        SELECT
+            player_id as "player_id!: i64",
+            SUM(is_goal) as "goals!: i64",
+            SUM(1 - is_goal) as "assists!: i64"
+        FROM (
+            SELECT scorer_id as player_id, 1 as is_goal FROM score_event
+            WHERE match_id = ?1 AND scorer_id IS NOT NULL
+            UNION ALL
+            SELECT assist1_id, 0 FROM score_event
+            WHERE match_id = ?1 AND assist1_id IS NOT NULL
+            UNION ALL
+            SELECT assist2_id, 0 FROM score_event
+            WHERE match_id = ?1 AND assist2_id IS NOT NULL
+        )
+        GROUP BY player_id
+        ORDER BY player_id
+        "#,
+        match_id
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Match in which a player scored their `nth` career goal, if they have scored that many
+///
+/// Goals are ordered by match date (undated matches last), then by time in the match.
+pub async fn get_career_goal_match(
+    db: &SqlitePool,
+    player_id: i64,
+    nth: i64,
+) -> Result<Option<i64>, sqlx::Error> {
+    let offset = nth - 1;
+    let row = sqlx::query!(
+        r#"
+        SELECT se.match_id
+        FROM score_event se
+        INNER JOIN match m ON se.match_id = m.id
+        WHERE se.scorer_id = ?
+        ORDER BY m.match_date IS NULL, m.match_date, m.id,
+                 se.period, se.time_minutes, se.time_seconds, se.id
+        LIMIT 1 OFFSET ?
+        "#,
+        player_id,
+        offset
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(row.map(|row| row.match_id))
+}
+
+/// Replace the single-match milestones (hat tricks, big games) of a match
+///
+/// Milestones that are still reached keep their original detection time.
+/// Returns the ids of the milestones that were not reached before.
+pub async fn replace_match_milestones(
+    db: &SqlitePool,
+    match_id: i64,
+    milestones: &[NewMilestone],
+) -> Result<Vec<i64>, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let existing = sqlx::query!(
+        r#"
+        SELECT id as "id!", player_id, kind
+        FROM milestone
+        WHERE match_id = ? AND kind IN ('hat_trick', 'four_point_game')
+        "#,
+        match_id
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    for row in &existing {
+        let still_reached = milestones
+            .iter()
+            .any(|m| m.player_id == row.player_id && m.kind.as_str() == row.kind);
+        if !still_reached {
+            sqlx::query!("DELETE FROM milestone WHERE id = ?", row.id)
+                .execute(&mut *tx)
+                .await?;
+        }
+    }
+
+    let mut reached = Vec::new();
+    for milestone in milestones {
+        let kind = milestone.kind.as_str();
+        let id = sqlx::query_scalar!(
+            r#"
+            INSERT INTO milestone (player_id, match_id, kind, value)
+            VALUES (?, ?, ?, ?)
+            ON CONFLICT (player_id, match_id, kind) DO UPDATE SET value = excluded.value
+            RETURNING id as "id!"
+            "#,
+            milestone.player_id,
+            match_id,
+            kind,
+            milestone.value
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        let existed = existing
+            .iter()
+            .any(|row| row.player_id == milestone.player_id && row.kind == kind);
+        if !existed {
+            reached.push(id);
+        }
+    }
+
+    tx.commit().await?;
+    Ok(reached)
+}
+
+/// Set the match of a player's career goal milestone (`None` removes it)
+///
+/// Returns the id of the milestone when it was not recorded for that match before.
+pub async fn set_career_goal_milestone(
+    db: &SqlitePool,
+    player_id: i64,
+    match_id: Option<i64>,
+) -> Result<Option<i64>, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        r#"
+        DELETE FROM milestone
+        WHERE player_id = ? AND kind = 'hundredth_goal' AND match_id IS NOT ?
+        "#,
+        player_id,
+        match_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let mut reached = None;
+    if let Some(match_id) = match_id {
+        reached = sqlx::query_scalar!(
+            r#"
+            INSERT INTO milestone (player_id, match_id, kind, value)
+            VALUES (?, ?, 'hundredth_goal', ?)
+            ON CONFLICT (player_id, match_id, kind) DO NOTHING
+            RETURNING id as "id!"
+            "#,
+            player_id,
+            match_id,
+            CAREER_GOAL_MILESTONE
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(reached)
+}
+
+/// A milestone by id with player and match names
+pub async fn get_milestone_by_id(
+    db: &SqlitePool,
+    id: i64,
+) -> Result<Option<MilestoneEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        MilestoneEntity,
+        r#"
+        SELECT
+            ms.id as "id!",
+            ms.player_id,
+            p.name as player_name,
+            ms.match_id,
+            ht.name as home_team_name,
+            at.name as away_team_name,
+            m.match_date,
+            ms.kind,
+            ms.value,
+            ms.created_at
+        FROM milestone ms
+        INNER JOIN player p ON ms.player_id = p.id
+        INNER JOIN match m ON ms.match_id = m.id
+        INNER JOIN team ht ON m.home_team_id = ht.id
+        INNER JOIN team at ON m.away_team_id = at.id
+        WHERE ms.id = ?
+        "#,
+        id
+    )
+    .fetch_optional(db)
+    .await
+}
+
+/// Most recently detected milestones across all players
+pub async fn get_recent_milestones(
+    db: &SqlitePool,
+    limit: i64,
+) -> Result<Vec<MilestoneEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        MilestoneEntity,
+        r#"
+        SELECT
+            ms.id as "id!",
+            ms.player_id,
+            p.name as player_name,
+            ms.match_id,
+            ht.name as home_team_name,
+            at.name as away_team_name,
+            m.match_date,
+            ms.kind,
+            ms.value,
+            ms.created_at
+        FROM milestone ms
+        INNER JOIN player p ON ms.player_id = p.id
+        INNER JOIN match m ON ms.match_id = m.id
+        INNER JOIN team ht ON m.home_team_id = ht.id
+        INNER JOIN team at ON m.away_team_id = at.id
+        ORDER BY ms.created_at DESC, ms.id DESC
+        LIMIT ?
+        "#,
+        limit
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Milestones of a player, most recent match first
+pub async fn get_player_milestones(
+    db: &SqlitePool,
+    player_id: i64,
+) -> Result<Vec<MilestoneEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        MilestoneEntity,
+        r#"
+        SELECT
+            ms.id as "id!",
+            ms.player_id,
+            p.name as player_name,
+            ms.match_id,
+            ht.name as home_team_name,
+            at.name as away_team_name,
+            m.match_date,
+            ms.kind,
+            ms.value,
+            ms.created_at
+        FROM milestone ms
+        INNER JOIN player p ON ms.player_id = p.id
+        INNER JOIN match m ON ms.match_id = m.id
+        INNER JOIN team ht ON m.home_team_id = ht.id
+        INNER JOIN team at ON m.away_team_id = at.id
+        WHERE ms.player_id = ?
+        ORDER BY m.match_date IS NULL, m.match_date DESC, m.id DESC, ms.kind
+        "#,
+        player_id
+    )
+    .fetch_all(db)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_milestone_queries(pool: SqlitePool) {
+        sqlx::query(
+            "INSERT INTO player (id, name, country_id) VALUES (1, 'Sniper', 34), (2, 'Setup', 34);
+             INSERT INTO match (id, season_id, home_team_id, away_team_id, match_date)
+                VALUES (1, 1, 1, 2, '2024-02-01'), (2, 1, 1, 2, '2024-01-01');
+             INSERT INTO score_event (match_id, team_id, scorer_id, assist1_id, period) VALUES
+                (1, 1, 1, 2, 1), (1, 1, 1, 2, 2), (1, 1, 1, NULL, 3), (2, 1, 1, NULL, 1);",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let points = get_match_player_points(&pool, 1).await.unwrap();
+        assert_eq!(
+            points,
+            vec![
+                MatchPlayerPointsEntity {
+                    player_id: 1,
+                    goals: 3,
+                    assists: 0,
+                },
+                MatchPlayerPointsEntity {
+                    player_id: 2,
+                    goals: 0,
+                    assists: 2,
+                },
+            ]
+        );
+
+        // Match 2 is older, so its goal is the first of the career
+        assert_eq!(get_career_goal_match(&pool, 1, 1).await.unwrap(), Some(2));
+        assert_eq!(get_career_goal_match(&pool, 1, 2).await.unwrap(), Some(1));
+        assert_eq!(get_career_goal_match(&pool, 1, 5).await.unwrap(), None);
+
+        let hat_trick = NewMilestone {
+            player_id: 1,
+            kind: MilestoneKind::HatTrick,
+            value: 3,
+        };
+        let reached = replace_match_milestones(&pool, 1, &[hat_trick])
+            .await
+            .unwrap();
+        assert_eq!(reached.len(), 1);
+        let career = set_career_goal_milestone(&pool, 1, Some(1)).await.unwrap();
+        assert!(career.is_some());
+        assert_eq!(get_player_milestones(&pool, 1).await.unwrap().len(), 2);
+        let hat_trick_entity = get_milestone_by_id(&pool, reached[0])
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(hat_trick_entity.player_name, "Sniper");
+
+        // Detecting again reports nothing new
+        assert!(replace_match_milestones(&pool, 1, &[hat_trick])
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            set_career_goal_milestone(&pool, 1, Some(1)).await.unwrap(),
+            None
+        );
+
+        // Recomputing without the hat trick removes it; the career milestone moves
+        replace_match_milestones(&pool, 1, &[]).await.unwrap();
+        set_career_goal_milestone(&pool, 1, Some(2)).await.unwrap();
+        let milestones = get_player_milestones(&pool, 1).await.unwrap();
+        assert_eq!(milestones.len(), 1);
+        assert_eq!(milestones[0].kind(), Some(MilestoneKind::HundredthGoal));
+        assert_eq!(milestones[0].match_id, 2);
+
+        set_career_goal_milestone(&pool, 1, None).await.unwrap();
+        assert!(get_recent_milestones(&pool, 10).await.unwrap().is_empty());
+    }
+}
@@ -1,9 +1,61 @@
+pub mod arenas;
+pub mod awards;
+pub mod calendar_subscriptions;
+pub mod change_history;
+pub mod changelog;
+pub mod chat_webhooks;
+pub mod comments;
 pub mod countries;
 pub mod dashboard;
+pub mod data_quality;
 pub mod events;
+pub mod external_ids;
+pub mod goal_types;
+pub mod instance_transfer;
+pub mod leaders;
+pub mod lines;
+pub mod live_scores;
+pub mod maintenance;
+pub mod match_import;
 pub mod matches;
+pub mod milestones;
+pub mod notifications;
+pub mod password_reset;
+pub mod period_distribution;
+pub mod placements;
 pub mod player_contracts;
+pub mod player_import;
 pub mod players;
+pub mod playoffs;
+pub mod predictions;
+pub mod public_site;
+pub mod referees;
+pub mod registration;
+pub mod reports;
+pub mod roster_diff;
+pub mod roster_import;
+pub mod roster_rules;
+pub mod season_clone;
+pub mod season_export;
+pub mod season_groups;
+pub mod season_stats;
 pub mod seasons;
+pub mod settings;
+pub mod sitemap;
+pub mod slugs;
+pub mod splits;
+pub mod staff;
+pub mod standings;
+pub mod streaks;
+pub mod tasks;
+pub mod team_form;
+pub mod team_history;
+pub mod team_merge;
 pub mod team_participations;
+pub mod team_stats;
 pub mod teams;
+pub mod transfers;
+pub mod user_identities;
+pub mod users;
+pub mod webhooks;
+pub mod widgets;
@@ -0,0 +1,205 @@
+use sqlx::SqlitePool;
+
+use crate::service::comments::CommentEntityType;
+
+/// A mention of the user in a comment
+#[derive(Debug, Clone)]
+pub struct NotificationEntity {
+    pub id: i64,
+    pub comment_id: i64,
+    pub entity_type: String,
+    pub entity_id: i64,
+    /// `None` when the mentioning user's account was deleted
+    pub actor_name: Option<String>,
+    /// Markdown source of the comment
+    pub comment_body: String,
+    pub created_at: String,
+    pub read_at: Option<String>,
+}
+
+impl NotificationEntity {
+    pub fn is_read(&self) -> bool {
+        self.read_at.is_some()
+    }
+
+    /// Link to the comment on its record's detail page
+    pub fn url(&self) -> Option<String> {
+        let entity_type = CommentEntityType::from_str(&self.entity_type)?;
+        Some(format!(
+            "{}#comment-{}",
+            entity_type.url(self.entity_id),
+            self.comment_id
+        ))
+    }
+}
+
+/// A user's notifications, newest first
+pub async fn get_notifications(
+    db: &SqlitePool,
+    user_id: i64,
+    limit: i64,
+) -> Result<Vec<NotificationEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        NotificationEntity,
+        r#"
+        SELECT
+            n.id as "id!",
+            n.comment_id,
+            c.entity_type,
+            c.entity_id,
+            COALESCE(u.name, u.email) as "actor_name: String",
+            c.body as comment_body,
+            n.created_at,
+            n.read_at
+        FROM notification n
+        INNER JOIN comment c ON c.id = n.comment_id
+        LEFT JOIN users u ON u.id = n.actor_id
+        WHERE n.user_id = ?
+        ORDER BY n.created_at DESC, n.id DESC
+        LIMIT ?
+        "#,
+        user_id,
+        limit
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Number of unread notifications of a user
+pub async fn count_unread_notifications(db: &SqlitePool, user_id: i64) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!: i64" FROM notification WHERE user_id = ? AND read_at IS NULL"#,
+        user_id
+    )
+    .fetch_one(db)
+    .await
+}
+
+/// Mark one of the user's notifications as read
+///
+/// Returns the notification, `None` when it does not exist or belongs to
+/// another user.
+pub async fn mark_notification_read(
+    db: &SqlitePool,
+    id: i64,
+    user_id: i64,
+) -> Result<Option<NotificationEntity>, sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE notification
+        SET read_at = COALESCE(read_at, CURRENT_TIMESTAMP)
+        WHERE id = ? AND user_id = ?
+        "#,
+        id,
+        user_id
+    )
+    .execute(db)
+    .await?;
+
+    sqlx::query_as!(
+        NotificationEntity,
+        r#"
+        SELECT
+            n.id as "id!",
+            n.comment_id,
+            c.entity_type,
+            c.entity_id,
+            COALESCE(u.name, u.email) as "actor_name: String",
+            c.body as comment_body,
+            n.created_at,
+            n.read_at
+        FROM notification n
+        INNER JOIN comment c ON c.id = n.comment_id
+        LEFT JOIN users u ON u.id = n.actor_id
+        WHERE n.id = ? AND n.user_id = ?
+        "#,
+        id,
+        user_id
+    )
+    .fetch_optional(db)
+    .await
+}
+
+/// Mark all of a user's notifications as read, returning how many changed
+pub async fn mark_all_notifications_read(
+    db: &SqlitePool,
+    user_id: i64,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query!(
+        "UPDATE notification SET read_at = CURRENT_TIMESTAMP WHERE user_id = ? AND read_at IS NULL",
+        user_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::comments::{create_comment, CreateCommentEntity};
+
+    async fn user(pool: &SqlitePool, email: &str) -> i64 {
+        sqlx::query("INSERT INTO users (email, password_hash) VALUES (?, 'x')")
+            .bind(email)
+            .execute(pool)
+            .await
+            .unwrap()
+            .last_insert_rowid()
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_mention_notifications(pool: SqlitePool) {
+        let jane = user(&pool, "jane@example.com").await;
+        let bob = user(&pool, "bob@example.com").await;
+
+        for body in ["@bob please verify", "@bob and the second period too"] {
+            create_comment(
+                &pool,
+                CreateCommentEntity {
+                    entity_type: CommentEntityType::Match,
+                    entity_id: 7,
+                    parent_id: None,
+                    author_id: jane,
+                    body: body.to_string(),
+                },
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        }
+
+        let notifications = get_notifications(&pool, bob, 10).await.unwrap();
+        assert_eq!(notifications.len(), 2);
+        assert_eq!(
+            notifications[0].actor_name.as_deref(),
+            Some("jane@example.com")
+        );
+        assert_eq!(
+            notifications[0].url(),
+            Some(format!(
+                "/matches/7#comment-{}",
+                notifications[0].comment_id
+            ))
+        );
+        assert_eq!(count_unread_notifications(&pool, bob).await.unwrap(), 2);
+
+        // Other users cannot mark someone else's notification
+        let id = notifications[0].id;
+        assert!(mark_notification_read(&pool, id, jane)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(mark_notification_read(&pool, id, bob)
+            .await
+            .unwrap()
+            .unwrap()
+            .is_read());
+        assert_eq!(count_unread_notifications(&pool, bob).await.unwrap(), 1);
+
+        assert_eq!(mark_all_notifications_read(&pool, bob).await.unwrap(), 1);
+        assert_eq!(count_unread_notifications(&pool, bob).await.unwrap(), 0);
+        assert!(get_notifications(&pool, jane, 10).await.unwrap().is_empty());
+    }
+}
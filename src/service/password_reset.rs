@@ -0,0 +1,263 @@
+use sqlx::SqlitePool;
+
+use crate::service::registration::{hash_token, new_token};
+
+/// How long an emailed password reset link works
+pub const RESET_TOKEN_TTL_MINUTES: i64 = 60;
+
+/// State of a password reset link
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResetTokenStatus {
+    /// Unused link of an active user
+    Valid { user_id: i64 },
+    /// The link is older than `RESET_TOKEN_TTL_MINUTES`
+    Expired,
+    /// Unknown, used or replaced link, or the user was deactivated
+    Invalid,
+}
+
+/// ID of the active user with this email, who may reset their password
+pub async fn get_resettable_user_id(
+    db: &SqlitePool,
+    email: &str,
+) -> Result<Option<i64>, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"SELECT id as "id!" FROM users WHERE email = ? COLLATE NOCASE AND active = 1"#,
+        email
+    )
+    .fetch_optional(db)
+    .await
+}
+
+/// Issue a reset token for a user; earlier links of the user stop working
+pub async fn create_reset_token(db: &SqlitePool, user_id: i64) -> Result<String, sqlx::Error> {
+    let token = new_token();
+    let token_hash = hash_token(&token);
+    let ttl = format!("+{} minutes", RESET_TOKEN_TTL_MINUTES);
+
+    let mut tx = db.begin().await?;
+    sqlx::query!(
+        "UPDATE password_reset_token SET used_at = CURRENT_TIMESTAMP WHERE user_id = ? AND used_at IS NULL",
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        r#"
+        INSERT INTO password_reset_token (user_id, token_hash, expires_at)
+        VALUES (?, ?, datetime('now', ?))
+        "#,
+        user_id,
+        token_hash,
+        ttl
+    )
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok(token)
+}
+
+/// Look a reset token up without using it, e.g. before showing the form
+pub async fn check_reset_token(
+    db: &SqlitePool,
+    token: &str,
+) -> Result<ResetTokenStatus, sqlx::Error> {
+    let token_hash = hash_token(token);
+    let row = sqlx::query!(
+        r#"
+        SELECT t.user_id, t.expires_at < datetime('now') as "expired!: bool"
+        FROM password_reset_token t
+        INNER JOIN users u ON u.id = t.user_id
+        WHERE t.token_hash = ? AND t.used_at IS NULL AND u.active = 1
+        "#,
+        token_hash
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(match row {
+        None => ResetTokenStatus::Invalid,
+        Some(row) if row.expired => ResetTokenStatus::Expired,
+        Some(row) => ResetTokenStatus::Valid {
+            user_id: row.user_id,
+        },
+    })
+}
+
+/// Set a new password with a reset token
+///
+/// Uses up the token and every other open link of the user, clears a pending
+/// forced password change and a lockout, and signs the user out everywhere. Returns the
+/// token's state before it was used; the password changed only if it was valid.
+pub async fn reset_password(
+    db: &SqlitePool,
+    token: &str,
+    password_hash: &str,
+) -> Result<ResetTokenStatus, sqlx::Error> {
+    let token_hash = hash_token(token);
+    let mut tx = db.begin().await?;
+
+    let row = sqlx::query!(
+        r#"
+        SELECT t.user_id, t.expires_at < datetime('now') as "expired!: bool"
+        FROM password_reset_token t
+        INNER JOIN users u ON u.id = t.user_id
+        WHERE t.token_hash = ? AND t.used_at IS NULL AND u.active = 1
+        "#,
+        token_hash
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(ResetTokenStatus::Invalid);
+    };
+    if row.expired {
+        return Ok(ResetTokenStatus::Expired);
+    }
+
+    sqlx::query!(
+        "UPDATE password_reset_token SET used_at = CURRENT_TIMESTAMP WHERE user_id = ? AND used_at IS NULL",
+        row.user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        r#"
+        UPDATE users
+        SET password_hash = ?, must_change_password = 0, failed_logins = 0, locked_until = NULL,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        password_hash,
+        row.user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!("DELETE FROM sessions WHERE user_id = ?", row.user_id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    Ok(ResetTokenStatus::Valid {
+        user_id: row.user_id,
+    })
+}
+
+/// Delete reset tokens that expired more than a day ago
+pub async fn purge_expired_reset_tokens(db: &SqlitePool) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query!(
+        "DELETE FROM password_reset_token WHERE expires_at < datetime('now', '-1 days')"
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::users::{self, UserRole};
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_reset_password(pool: SqlitePool) {
+        let id = users::create_user(&pool, "coach@x.cz", "Coach", UserRole::Editor, "hash")
+            .await
+            .unwrap();
+        assert_eq!(
+            get_resettable_user_id(&pool, "COACH@x.cz").await.unwrap(),
+            Some(id)
+        );
+        sqlx::query(
+            "INSERT INTO sessions (id, user_id, user_email, user_name, csrf_token, created_at, expires_at) VALUES ('s1', ?, 'coach@x.cz', 'Coach', 'csrf', datetime('now'), datetime('now', '+1 days'))",
+        )
+        .bind(id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query("UPDATE users SET locked_until = datetime('now', '+15 minutes')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        // A new link replaces the previous one
+        let old = create_reset_token(&pool, id).await.unwrap();
+        let token = create_reset_token(&pool, id).await.unwrap();
+        assert_eq!(
+            check_reset_token(&pool, &old).await.unwrap(),
+            ResetTokenStatus::Invalid
+        );
+        assert_eq!(
+            check_reset_token(&pool, &token).await.unwrap(),
+            ResetTokenStatus::Valid { user_id: id }
+        );
+
+        assert_eq!(
+            reset_password(&pool, &token, "new-hash").await.unwrap(),
+            ResetTokenStatus::Valid { user_id: id }
+        );
+        assert_eq!(
+            users::get_password_hash(&pool, id)
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("new-hash")
+        );
+        assert!(!users::must_change_password(&pool, id).await.unwrap());
+        assert!(
+            !users::get_user_by_id(&pool, id)
+                .await
+                .unwrap()
+                .unwrap()
+                .locked
+        );
+        let sessions: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sessions")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(sessions, 0);
+
+        // Links work once
+        assert_eq!(
+            reset_password(&pool, &token, "other-hash").await.unwrap(),
+            ResetTokenStatus::Invalid
+        );
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_expired_and_deactivated(pool: SqlitePool) {
+        let id = users::create_user(&pool, "coach@x.cz", "Coach", UserRole::Editor, "hash")
+            .await
+            .unwrap();
+        let token = create_reset_token(&pool, id).await.unwrap();
+        sqlx::query("UPDATE password_reset_token SET expires_at = datetime('now', '-1 minutes')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            reset_password(&pool, &token, "new-hash").await.unwrap(),
+            ResetTokenStatus::Expired
+        );
+        assert_eq!(purge_expired_reset_tokens(&pool).await.unwrap(), 0);
+        sqlx::query("UPDATE password_reset_token SET expires_at = datetime('now', '-2 days')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(purge_expired_reset_tokens(&pool).await.unwrap(), 1);
+
+        // Deactivated users can neither request nor use a link
+        let token = create_reset_token(&pool, id).await.unwrap();
+        users::set_user_active(&pool, id, false).await.unwrap();
+        assert_eq!(
+            get_resettable_user_id(&pool, "coach@x.cz").await.unwrap(),
+            None
+        );
+        assert_eq!(
+            check_reset_token(&pool, &token).await.unwrap(),
+            ResetTokenStatus::Invalid
+        );
+    }
+}
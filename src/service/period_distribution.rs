@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+
+use crate::service::matches::{OVERTIME_PERIOD, SHOOTOUT_PERIOD};
+
+/// Goals scored in one period across a season
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PeriodGoalsEntity {
+    /// 1-3 for regulation periods, 4 for overtime
+    pub period: i32,
+    pub goals: i64,
+    pub home_goals: i64,
+    pub away_goals: i64,
+}
+
+/// Goals per period of a season
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PeriodDistributionEntity {
+    pub season_id: i64,
+    /// Finished matches the averages are based on
+    pub matches: i64,
+    pub total_goals: i64,
+    /// Regulation periods are always listed, overtime only when it saw a goal
+    pub periods: Vec<PeriodGoalsEntity>,
+}
+
+impl PeriodDistributionEntity {
+    /// Share of the season's goals scored in a period, in percent
+    pub fn percentage(&self, period: &PeriodGoalsEntity) -> Option<f64> {
+        (self.total_goals > 0).then(|| period.goals as f64 * 100.0 / self.total_goals as f64)
+    }
+
+    /// Average goals per finished match in a period
+    pub fn per_match(&self, period: &PeriodGoalsEntity) -> Option<f64> {
+        (self.matches > 0).then(|| period.goals as f64 / self.matches as f64)
+    }
+
+    /// Most goals in any one period, for scaling charts
+    pub fn max_goals(&self) -> i64 {
+        self.periods.iter().map(|p| p.goals).max().unwrap_or(0)
+    }
+}
+
+/// Identified goals per period of a season
+///
+/// Shootout goals do not count as goals and are left out, as are exhibition
+/// games. Unidentified goals have no period and cannot be included.
+pub async fn get_season_period_distribution(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<PeriodDistributionEntity, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            se.period as "period!: i32",
+            COUNT(*) as "goals!: i64",
+            SUM(se.team_id = m.home_team_id) as "home_goals!: i64",
+            SUM(se.team_id = m.away_team_id) as "away_goals!: i64"
+        FROM score_event se
+        INNER JOIN match m ON m.id = se.match_id
+        WHERE m.season_id = ? AND m.game_type != 'exhibition' AND se.period != ?
+        GROUP BY se.period
+        ORDER BY se.period
+        "#,
+        season_id,
+        SHOOTOUT_PERIOD
+    )
+    .fetch_all(db)
+    .await?;
+
+    let matches = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) as "count!: i64"
+        FROM match
+        WHERE season_id = ? AND status = 'finished' AND game_type != 'exhibition'
+        "#,
+        season_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    let mut periods: Vec<PeriodGoalsEntity> = (1..OVERTIME_PERIOD)
+        .map(|period| PeriodGoalsEntity {
+            period,
+            goals: 0,
+            home_goals: 0,
+            away_goals: 0,
+        })
+        .collect();
+    for row in rows {
+        let goals = PeriodGoalsEntity {
+            period: row.period,
+            goals: row.goals,
+            home_goals: row.home_goals,
+            away_goals: row.away_goals,
+        };
+        match periods.iter_mut().find(|p| p.period == row.period) {
+            Some(period) => *period = goals,
+            None => periods.push(goals),
+        }
+    }
+
+    Ok(PeriodDistributionEntity {
+        season_id,
+        matches,
+        total_goals: periods.iter().map(|p| p.goals).sum(),
+        periods,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{
+        create_match, create_score_event, CreateMatchEntity, CreateScoreEventEntity,
+    };
+
+    async fn finished_match(pool: &SqlitePool, game_type: &str) -> i64 {
+        create_match(
+            pool,
+            CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: 0,
+                away_score_unidentified: 0,
+                match_date: None,
+                status: "finished".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: game_type.to_string(),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn goal(pool: &SqlitePool, match_id: i64, team_id: i64, period: i32) {
+        create_score_event(
+            pool,
+            CreateScoreEventEntity {
+                match_id,
+                team_id,
+                scorer_id: None,
+                assist1_id: None,
+                assist2_id: None,
+                period,
+                time_minutes: None,
+                time_seconds: None,
+                goal_type: None,
+                video_url: None,
+                video_timestamp: None,
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_season_period_distribution(pool: SqlitePool) {
+        let empty = get_season_period_distribution(&pool, 1).await.unwrap();
+        assert_eq!(empty.total_goals, 0);
+        assert_eq!(empty.periods.len(), 3);
+        assert_eq!(empty.percentage(&empty.periods[0]), None);
+
+        let first = finished_match(&pool, "group").await;
+        goal(&pool, first, 1, 1).await;
+        goal(&pool, first, 1, 3).await;
+        goal(&pool, first, 2, 3).await;
+        let second = finished_match(&pool, "group").await;
+        goal(&pool, second, 2, 3).await;
+        goal(&pool, second, 2, OVERTIME_PERIOD).await;
+        goal(&pool, second, 1, SHOOTOUT_PERIOD).await;
+        let exhibition = finished_match(&pool, "exhibition").await;
+        goal(&pool, exhibition, 1, 2).await;
+
+        let distribution = get_season_period_distribution(&pool, 1).await.unwrap();
+        assert_eq!(distribution.matches, 2);
+        assert_eq!(distribution.total_goals, 5);
+        let goals: Vec<(i32, i64)> = distribution
+            .periods
+            .iter()
+            .map(|p| (p.period, p.goals))
+            .collect();
+        assert_eq!(goals, vec![(1, 1), (2, 0), (3, 3), (4, 1)]);
+
+        let third = &distribution.periods[2];
+        assert_eq!((third.home_goals, third.away_goals), (1, 2));
+        assert_eq!(distribution.percentage(third), Some(60.0));
+        assert_eq!(distribution.per_match(third), Some(1.5));
+        assert_eq!(distribution.max_goals(), 3);
+    }
+}
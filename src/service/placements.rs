@@ -0,0 +1,259 @@
+use sqlx::SqlitePool;
+
+/// Lowest final placement that can be recorded
+pub const MAX_FINAL_RANK: i64 = 16;
+/// Lowest playoff seed that can be recorded
+pub const MAX_SEED: i64 = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Medal {
+    Gold,
+    Silver,
+    Bronze,
+}
+
+impl Medal {
+    /// Medal awarded for a final placement, if any
+    pub fn from_rank(rank: i64) -> Option<Self> {
+        match rank {
+            1 => Some(Self::Gold),
+            2 => Some(Self::Silver),
+            3 => Some(Self::Bronze),
+            _ => None,
+        }
+    }
+
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            Self::Gold => "🥇",
+            Self::Silver => "🥈",
+            Self::Bronze => "🥉",
+        }
+    }
+}
+
+/// Medals a team won across all seasons of an event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MedalTableEntity {
+    pub team_id: i64,
+    pub team_name: String,
+    pub country_iso2_code: Option<String>,
+    pub gold: i64,
+    pub silver: i64,
+    pub bronze: i64,
+}
+
+impl MedalTableEntity {
+    pub fn total(&self) -> i64 {
+        self.gold + self.silver + self.bronze
+    }
+}
+
+/// Set (or clear) the final placement of a team participation
+pub async fn set_final_rank(
+    db: &SqlitePool,
+    participation_id: i64,
+    final_rank: Option<i64>,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE team_participation
+        SET final_rank = ?, updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        final_rank,
+        participation_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Check whether another team of the season already holds a placement
+pub async fn is_final_rank_taken(
+    db: &SqlitePool,
+    participation_id: i64,
+    final_rank: i64,
+) -> Result<bool, sqlx::Error> {
+    let row = sqlx::query!(
+        r#"
+        SELECT COUNT(*) as "count!: i64"
+        FROM team_participation other
+        INNER JOIN team_participation tp ON tp.season_id = other.season_id
+        WHERE tp.id = ?1 AND other.id != ?1 AND other.final_rank = ?2
+        "#,
+        participation_id,
+        final_rank
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(row.count > 0)
+}
+
+/// A seeded team of a season
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeededTeamEntity {
+    pub team_id: i64,
+    pub seed: i64,
+}
+
+/// Set (or clear) the playoff seed of a team participation
+pub async fn set_seed(
+    db: &SqlitePool,
+    participation_id: i64,
+    seed: Option<i64>,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE team_participation
+        SET seed = ?, updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        seed,
+        participation_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Check whether another team of the season already holds a seed
+pub async fn is_seed_taken(
+    db: &SqlitePool,
+    participation_id: i64,
+    seed: i64,
+) -> Result<bool, sqlx::Error> {
+    let row = sqlx::query!(
+        r#"
+        SELECT COUNT(*) as "count!: i64"
+        FROM team_participation other
+        INNER JOIN team_participation tp ON tp.season_id = other.season_id
+        WHERE tp.id = ?1 AND other.id != ?1 AND other.seed = ?2
+        "#,
+        participation_id,
+        seed
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(row.count > 0)
+}
+
+/// Seeded teams of a season, top seed first
+pub async fn get_seeded_teams(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<Vec<SeededTeamEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        SeededTeamEntity,
+        r#"
+        SELECT team_id, seed as "seed!: i64"
+        FROM team_participation
+        WHERE season_id = ? AND seed IS NOT NULL
+        ORDER BY seed
+        "#,
+        season_id
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Medal table of an event: medals per team summed over all its seasons
+///
+/// Ordered by gold, then silver, then bronze medals; teams without a medal
+/// are left out.
+pub async fn get_event_medal_table(
+    db: &SqlitePool,
+    event_id: i64,
+) -> Result<Vec<MedalTableEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        MedalTableEntity,
+        r#"
+        SELECT
+            t.id as "team_id!",
+            t.name as team_name,
+            c.iso2Code as country_iso2_code,
+            SUM(tp.final_rank = 1) as "gold!: i64",
+            SUM(tp.final_rank = 2) as "silver!: i64",
+            SUM(tp.final_rank = 3) as "bronze!: i64"
+        FROM team_participation tp
+        INNER JOIN season s ON tp.season_id = s.id
+        INNER JOIN team t ON tp.team_id = t.id
+        LEFT JOIN country c ON t.country_id = c.id
+        WHERE s.event_id = ? AND tp.final_rank <= 3
+        GROUP BY t.id
+        ORDER BY 4 DESC, 5 DESC, 6 DESC, t.name ASC
+        "#,
+        event_id
+    )
+    .fetch_all(db)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_event_medal_table(pool: SqlitePool) {
+        assert!(set_final_rank(&pool, 1, Some(2)).await.unwrap());
+        assert!(set_final_rank(&pool, 2, Some(1)).await.unwrap());
+        assert!(!set_final_rank(&pool, 999, Some(1)).await.unwrap());
+
+        assert!(is_final_rank_taken(&pool, 1, 1).await.unwrap());
+        assert!(!is_final_rank_taken(&pool, 2, 1).await.unwrap());
+        // Placements are per season
+        assert!(!is_final_rank_taken(&pool, 3, 1).await.unwrap());
+
+        let table = get_event_medal_table(&pool, 1).await.unwrap();
+        assert_eq!(table.len(), 2);
+        assert_eq!(table[0].team_name, "Team USA");
+        assert_eq!((table[0].gold, table[0].silver), (1, 0));
+        assert_eq!(table[1].silver, 1);
+        assert_eq!(table[1].total(), 1);
+
+        // 4th place and below win no medal
+        set_final_rank(&pool, 2, Some(4)).await.unwrap();
+        assert_eq!(get_event_medal_table(&pool, 1).await.unwrap().len(), 1);
+        assert!(get_event_medal_table(&pool, 2).await.unwrap().is_empty());
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_seeds(pool: SqlitePool) {
+        assert!(set_seed(&pool, 2, Some(1)).await.unwrap());
+        assert!(set_seed(&pool, 1, Some(2)).await.unwrap());
+        assert!(set_seed(&pool, 3, Some(1)).await.unwrap());
+        assert!(!set_seed(&pool, 999, Some(1)).await.unwrap());
+
+        assert!(is_seed_taken(&pool, 1, 1).await.unwrap());
+        assert!(!is_seed_taken(&pool, 2, 1).await.unwrap());
+        // Seeds are per season
+        assert!(!is_seed_taken(&pool, 4, 2).await.unwrap());
+
+        assert_eq!(
+            get_seeded_teams(&pool, 1).await.unwrap(),
+            vec![
+                SeededTeamEntity {
+                    team_id: 2,
+                    seed: 1
+                },
+                SeededTeamEntity {
+                    team_id: 1,
+                    seed: 2
+                },
+            ]
+        );
+
+        set_seed(&pool, 2, None).await.unwrap();
+        assert_eq!(get_seeded_teams(&pool, 1).await.unwrap().len(), 1);
+    }
+}
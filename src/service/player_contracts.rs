@@ -1,5 +1,33 @@
 use sqlx::SqlitePool;
 
+/// Captaincy of a player within a team participation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Captaincy {
+    Captain,
+    Alternate,
+}
+
+impl Captaincy {
+    /// Most alternate captains a team can name
+    pub const MAX_ALTERNATES: i64 = 2;
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "C" => Some(Self::Captain),
+            "A" => Some(Self::Alternate),
+            _ => None,
+        }
+    }
+
+    /// Stored value, also the letter shown next to the player's name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Captain => "C",
+            Self::Alternate => "A",
+        }
+    }
+}
+
 /// Player in a roster with additional details
 #[derive(Debug, Clone)]
 pub struct PlayerInRoster {
@@ -7,11 +35,41 @@ pub struct PlayerInRoster {
     #[allow(dead_code)]
     pub player_id: i64,
     pub player_name: String,
+    pub jersey_number: Option<i64>,
     #[allow(dead_code)]
     pub country_id: i64,
     pub country_name: String,
     pub country_iso2_code: String,
     pub photo_path: Option<String>,
+    /// Date the player joined mid-season (transfer in), `None` for the whole season
+    pub joined_on: Option<String>,
+    /// Date the player left mid-season (transfer out)
+    pub left_on: Option<String>,
+    /// `C` for the captain, `A` for an alternate
+    pub captaincy: Option<String>,
+}
+
+impl PlayerInRoster {
+    pub fn captaincy(&self) -> Option<Captaincy> {
+        self.captaincy.as_deref().and_then(Captaincy::from_str)
+    }
+}
+
+/// A single roster entry, used by the edit form
+#[derive(Debug, Clone)]
+pub struct PlayerContractEntity {
+    pub id: i64,
+    pub team_participation_id: i64,
+    pub player_name: String,
+    pub jersey_number: Option<i64>,
+    pub captaincy: Option<String>,
+    pub left_on: Option<String>,
+}
+
+impl PlayerContractEntity {
+    pub fn captaincy(&self) -> Option<Captaincy> {
+        self.captaincy.as_deref().and_then(Captaincy::from_str)
+    }
 }
 
 /// Team participation context for roster page header
@@ -42,15 +100,19 @@ pub async fn get_roster(
             pc.id as "player_contract_id!",
             p.id as "player_id!",
             p.name as player_name,
+            pc.jersey_number,
             p.photo_path,
             c.id as "country_id!",
             c.name as country_name,
-            c.iso2Code as "country_iso2_code!"
+            c.iso2Code as "country_iso2_code!",
+            pc.joined_on,
+            pc.left_on,
+            pc.captaincy
         FROM player_contract pc
         INNER JOIN player p ON pc.player_id = p.id
         INNER JOIN country c ON p.country_id = c.id
         WHERE pc.team_participation_id = ?
-        ORDER BY p.name ASC
+        ORDER BY pc.left_on IS NOT NULL, pc.jersey_number IS NULL, pc.jersey_number ASC, p.name ASC
         "#,
         team_participation_id
     )
@@ -140,16 +202,41 @@ pub async fn is_player_in_roster(
     Ok(row.count > 0)
 }
 
+/// Check if a jersey number is already worn by another player in the roster
+pub async fn is_jersey_number_taken(
+    db: &SqlitePool,
+    team_participation_id: i64,
+    jersey_number: i64,
+    exclude_contract_id: Option<i64>,
+) -> Result<bool, sqlx::Error> {
+    let row = sqlx::query!(
+        r#"
+        SELECT COUNT(*) as count
+        FROM player_contract
+        WHERE team_participation_id = ? AND jersey_number = ? AND id != COALESCE(?, -1)
+        "#,
+        team_participation_id,
+        jersey_number,
+        exclude_contract_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(row.count > 0)
+}
+
 /// Add a player to a roster (create player_contract)
 pub async fn add_player_to_roster(
     db: &SqlitePool,
     team_participation_id: i64,
     player_id: i64,
+    jersey_number: Option<i64>,
 ) -> Result<i64, sqlx::Error> {
     let result = sqlx::query!(
-        "INSERT INTO player_contract (team_participation_id, player_id) VALUES (?, ?)",
+        "INSERT INTO player_contract (team_participation_id, player_id, jersey_number) VALUES (?, ?, ?)",
         team_participation_id,
-        player_id
+        player_id,
+        jersey_number
     )
     .execute(db)
     .await?;
@@ -157,6 +244,93 @@ pub async fn add_player_to_roster(
     Ok(result.last_insert_rowid())
 }
 
+/// Get a single roster entry by contract ID
+pub async fn get_player_contract(
+    db: &SqlitePool,
+    player_contract_id: i64,
+) -> Result<Option<PlayerContractEntity>, sqlx::Error> {
+    let row = sqlx::query_as!(
+        PlayerContractEntity,
+        r#"
+        SELECT
+            pc.id as "id!",
+            pc.team_participation_id,
+            p.name as player_name,
+            pc.jersey_number,
+            pc.captaincy,
+            pc.left_on
+        FROM player_contract pc
+        INNER JOIN player p ON pc.player_id = p.id
+        WHERE pc.id = ?
+        "#,
+        player_contract_id
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(row)
+}
+
+/// Update the jersey number of a roster entry
+pub async fn update_jersey_number(
+    db: &SqlitePool,
+    player_contract_id: i64,
+    jersey_number: Option<i64>,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        "UPDATE player_contract SET jersey_number = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+        jersey_number,
+        player_contract_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Count the captain or alternates of a team participation, optionally
+/// ignoring one roster entry (the one being edited)
+pub async fn count_captaincy(
+    db: &SqlitePool,
+    team_participation_id: i64,
+    captaincy: Captaincy,
+    exclude_contract_id: Option<i64>,
+) -> Result<i64, sqlx::Error> {
+    let captaincy = captaincy.as_str();
+    let row = sqlx::query!(
+        r#"
+        SELECT COUNT(*) as count
+        FROM player_contract
+        WHERE team_participation_id = ? AND captaincy = ? AND id != COALESCE(?, -1)
+        "#,
+        team_participation_id,
+        captaincy,
+        exclude_contract_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(row.count)
+}
+
+/// Update the captaincy of a roster entry
+pub async fn update_captaincy(
+    db: &SqlitePool,
+    player_contract_id: i64,
+    captaincy: Option<Captaincy>,
+) -> Result<bool, sqlx::Error> {
+    let captaincy = captaincy.map(|c| c.as_str());
+    let result = sqlx::query!(
+        "UPDATE player_contract SET captaincy = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+        captaincy,
+        player_contract_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
 /// Remove a player from a roster (delete player_contract)
 pub async fn remove_player_from_roster(
     db: &SqlitePool,
@@ -305,10 +479,10 @@ mod tests {
         .unwrap();
 
         // Add to roster
-        add_player_to_roster(&pool, participation_id, player1_id)
+        add_player_to_roster(&pool, participation_id, player1_id, None)
             .await
             .unwrap();
-        add_player_to_roster(&pool, participation_id, player2_id)
+        add_player_to_roster(&pool, participation_id, player2_id, None)
             .await
             .unwrap();
 
@@ -449,7 +623,7 @@ mod tests {
             .unwrap();
 
         // Add player1 to roster
-        add_player_to_roster(&pool, participation_id, player1_id)
+        add_player_to_roster(&pool, participation_id, player1_id, None)
             .await
             .unwrap();
 
@@ -525,7 +699,7 @@ mod tests {
         .unwrap();
 
         // Add player1 to roster
-        add_player_to_roster(&pool, participation_id, player1_id)
+        add_player_to_roster(&pool, participation_id, player1_id, None)
             .await
             .unwrap();
 
@@ -590,7 +764,7 @@ mod tests {
         .await
         .unwrap();
 
-        let contract_id = add_player_to_roster(&pool, participation_id, player_id)
+        let contract_id = add_player_to_roster(&pool, participation_id, player_id, None)
             .await
             .unwrap();
 
@@ -658,7 +832,7 @@ mod tests {
         .await
         .unwrap();
 
-        let contract_id = add_player_to_roster(&pool, participation_id, player_id)
+        let contract_id = add_player_to_roster(&pool, participation_id, player_id, None)
             .await
             .unwrap();
 
@@ -734,7 +908,7 @@ mod tests {
         .await
         .unwrap();
 
-        let contract_id = add_player_to_roster(&pool, participation_id, player_id)
+        let contract_id = add_player_to_roster(&pool, participation_id, player_id, None)
             .await
             .unwrap();
 
@@ -753,4 +927,88 @@ mod tests {
             .unwrap();
         assert!(result.is_none());
     }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_jersey_number_unique_within_roster(pool: SqlitePool) {
+        let first = add_player_to_roster(&pool, 1, 1, Some(97)).await.unwrap();
+        let second = add_player_to_roster(&pool, 1, 2, None).await.unwrap();
+
+        assert!(is_jersey_number_taken(&pool, 1, 97, None).await.unwrap());
+        assert!(is_jersey_number_taken(&pool, 1, 97, Some(second))
+            .await
+            .unwrap());
+        // The owner of the number can keep it when editing
+        assert!(!is_jersey_number_taken(&pool, 1, 97, Some(first))
+            .await
+            .unwrap());
+        // Other rosters are independent
+        assert!(!is_jersey_number_taken(&pool, 2, 97, None).await.unwrap());
+
+        // The database enforces uniqueness as well
+        assert!(update_jersey_number(&pool, second, Some(97)).await.is_err());
+
+        assert!(update_jersey_number(&pool, second, Some(99)).await.unwrap());
+        let contract = get_player_contract(&pool, second).await.unwrap().unwrap();
+        assert_eq!(contract.jersey_number, Some(99));
+
+        // Numbered players are listed first, by number
+        let roster = get_roster(&pool, 1).await.unwrap();
+        assert_eq!(roster[0].jersey_number, Some(97));
+        assert_eq!(roster[1].jersey_number, Some(99));
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_captaincy(pool: SqlitePool) {
+        let captain = add_player_to_roster(&pool, 1, 1, None).await.unwrap();
+        let alternate = add_player_to_roster(&pool, 1, 2, None).await.unwrap();
+
+        assert!(update_captaincy(&pool, captain, Some(Captaincy::Captain))
+            .await
+            .unwrap());
+        assert!(
+            update_captaincy(&pool, alternate, Some(Captaincy::Alternate))
+                .await
+                .unwrap()
+        );
+
+        assert_eq!(
+            count_captaincy(&pool, 1, Captaincy::Captain, None)
+                .await
+                .unwrap(),
+            1
+        );
+        // The captain being edited does not count against themselves
+        assert_eq!(
+            count_captaincy(&pool, 1, Captaincy::Captain, Some(captain))
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            count_captaincy(&pool, 2, Captaincy::Alternate, None)
+                .await
+                .unwrap(),
+            0
+        );
+
+        // The database allows a single captain per team
+        assert!(update_captaincy(&pool, alternate, Some(Captaincy::Captain))
+            .await
+            .is_err());
+
+        let roster = get_roster(&pool, 1).await.unwrap();
+        let letters: Vec<Option<Captaincy>> = roster.iter().map(|p| p.captaincy()).collect();
+        assert!(letters.contains(&Some(Captaincy::Captain)));
+        assert!(letters.contains(&Some(Captaincy::Alternate)));
+
+        assert!(update_captaincy(&pool, captain, None).await.unwrap());
+        let contract = get_player_contract(&pool, captain).await.unwrap().unwrap();
+        assert_eq!(contract.captaincy(), None);
+    }
 }
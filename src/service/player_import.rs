@@ -0,0 +1,391 @@
+//! Creating players in bulk from a CSV of names, countries, birth dates and
+//! positions
+//!
+//! Every row is checked on its own and the valid ones are created in a single
+//! transaction, so one bad row does not block the rest of the file. A row is a
+//! duplicate when a player with the same name (ignoring case, accents and word
+//! order) exists already: with the same birth date when both have one, with
+//! the same country otherwise. Duplicates are reported, not created.
+
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use sqlx::SqlitePool;
+
+use crate::service::countries;
+use crate::service::reports::normalize_name;
+use crate::service::slugs;
+
+/// A row of a player CSV, as written in the file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerCsvRow {
+    /// Line in the file, counting the header as line 1
+    pub line: usize,
+    pub name: String,
+    pub country: String,
+    pub birth_date: String,
+    pub position: String,
+}
+
+/// Why a row was not imported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerRowError {
+    MissingName,
+    NameTooLong,
+    MissingCountry,
+    UnknownCountry,
+    InvalidBirthDate,
+    InvalidPosition,
+    /// The same player appears on an earlier row
+    DuplicateRow,
+}
+
+impl PlayerRowError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            PlayerRowError::MissingName => "Name is missing",
+            PlayerRowError::NameTooLong => "Name cannot exceed 255 characters",
+            PlayerRowError::MissingCountry => "Country is missing",
+            PlayerRowError::UnknownCountry => "Unknown country code",
+            PlayerRowError::InvalidBirthDate => {
+                "Birth date must be written as YYYY-MM-DD or DD.MM.YYYY"
+            }
+            PlayerRowError::InvalidPosition => "Position must be Forward, Defense or Goalie",
+            PlayerRowError::DuplicateRow => "Player is listed more than once",
+        }
+    }
+}
+
+/// What importing a row did
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlayerRowOutcome {
+    Created {
+        player_id: i64,
+    },
+    /// Skipped, the player exists already
+    Duplicate {
+        player_id: i64,
+        player_name: String,
+    },
+    /// Skipped, the row is invalid
+    Invalid(PlayerRowError),
+}
+
+/// A CSV row with what importing it did
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerImportRow {
+    pub row: PlayerCsvRow,
+    pub outcome: PlayerRowOutcome,
+}
+
+/// Per-row report of a player import
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerImportReport {
+    pub rows: Vec<PlayerImportRow>,
+}
+
+impl PlayerImportReport {
+    pub fn created(&self) -> usize {
+        self.count(|outcome| matches!(outcome, PlayerRowOutcome::Created { .. }))
+    }
+
+    pub fn duplicates(&self) -> usize {
+        self.count(|outcome| matches!(outcome, PlayerRowOutcome::Duplicate { .. }))
+    }
+
+    pub fn invalid(&self) -> usize {
+        self.count(|outcome| matches!(outcome, PlayerRowOutcome::Invalid(_)))
+    }
+
+    fn count(&self, f: impl Fn(&PlayerRowOutcome) -> bool) -> usize {
+        self.rows.iter().filter(|row| f(&row.outcome)).count()
+    }
+}
+
+/// Position as stored on players, from the full name or a common abbreviation
+pub fn parse_position(position: &str) -> Option<&'static str> {
+    match position.trim().to_uppercase().as_str() {
+        "FORWARD" | "F" | "C" | "LW" | "RW" | "W" => Some("Forward"),
+        "DEFENSE" | "DEFENCE" | "D" | "LD" | "RD" => Some("Defense"),
+        "GOALIE" | "GOALTENDER" | "G" => Some("Goalie"),
+        _ => None,
+    }
+}
+
+/// Birth date in ISO form, from ISO or the day-first form used in Czech files
+pub fn parse_birth_date(birth_date: &str) -> Option<String> {
+    ["%Y-%m-%d", "%d.%m.%Y", "%d. %m. %Y"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(birth_date.trim(), format).ok())
+        .map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+/// A row that passed validation, ready to be created
+struct ValidRow {
+    name: String,
+    country_id: i64,
+    birth_date: Option<String>,
+    position: Option<&'static str>,
+}
+
+/// Existing player a new one is compared against
+struct KnownPlayer {
+    id: i64,
+    name: String,
+    country_id: i64,
+    birth_date: Option<String>,
+    /// Created by an earlier row of the same file
+    imported: bool,
+}
+
+impl KnownPlayer {
+    fn is_same_as(&self, row: &ValidRow) -> bool {
+        match (&self.birth_date, &row.birth_date) {
+            (Some(known), Some(new)) => known == new,
+            _ => self.country_id == row.country_id,
+        }
+    }
+}
+
+/// Check every row and create the valid, new players in a single transaction
+pub async fn import_players(
+    db: &SqlitePool,
+    rows: Vec<PlayerCsvRow>,
+) -> Result<PlayerImportReport, sqlx::Error> {
+    let mut known: HashMap<String, Vec<KnownPlayer>> = HashMap::new();
+    for player in
+        sqlx::query!(r#"SELECT id as "id!", name, country_id, birth_date FROM player ORDER BY id"#)
+            .fetch_all(db)
+            .await?
+    {
+        known
+            .entry(normalize_name(&player.name))
+            .or_default()
+            .push(KnownPlayer {
+                id: player.id,
+                name: player.name,
+                country_id: player.country_id,
+                birth_date: player.birth_date,
+                imported: false,
+            });
+    }
+
+    // Rows are checked before the transaction starts, so the lookups do not
+    // wait for the connection the transaction holds
+    let mut countries: HashMap<String, Option<i64>> = HashMap::new();
+    let mut checked = Vec::with_capacity(rows.len());
+    for row in rows {
+        let valid = validate_row(db, &row, &mut countries).await?;
+        checked.push((row, valid));
+    }
+
+    let mut tx = db.begin().await?;
+    let mut report = PlayerImportReport::default();
+    for (row, valid) in checked {
+        let valid = match valid {
+            Ok(valid) => valid,
+            Err(error) => {
+                report.rows.push(PlayerImportRow {
+                    row,
+                    outcome: PlayerRowOutcome::Invalid(error),
+                });
+                continue;
+            }
+        };
+
+        let normalized = normalize_name(&valid.name);
+        let namesakes = known.entry(normalized).or_default();
+        let outcome = match namesakes.iter().find(|player| player.is_same_as(&valid)) {
+            Some(player) if player.imported => {
+                PlayerRowOutcome::Invalid(PlayerRowError::DuplicateRow)
+            }
+            Some(player) => PlayerRowOutcome::Duplicate {
+                player_id: player.id,
+                player_name: player.name.clone(),
+            },
+            None => {
+                let player_id = sqlx::query!(
+                    "INSERT INTO player (name, country_id, birth_date, position) VALUES (?, ?, ?, ?)",
+                    valid.name,
+                    valid.country_id,
+                    valid.birth_date,
+                    valid.position
+                )
+                .execute(&mut *tx)
+                .await?
+                .last_insert_rowid();
+                namesakes.push(KnownPlayer {
+                    id: player_id,
+                    name: valid.name,
+                    country_id: valid.country_id,
+                    birth_date: valid.birth_date,
+                    imported: true,
+                });
+                PlayerRowOutcome::Created { player_id }
+            }
+        };
+        report.rows.push(PlayerImportRow { row, outcome });
+    }
+
+    // Players above are inserted directly, so give them their slugs
+    slugs::assign_missing_slugs(&mut tx).await?;
+    tx.commit().await?;
+
+    Ok(report)
+}
+
+async fn validate_row(
+    db: &SqlitePool,
+    row: &PlayerCsvRow,
+    countries: &mut HashMap<String, Option<i64>>,
+) -> Result<Result<ValidRow, PlayerRowError>, sqlx::Error> {
+    if row.name.is_empty() {
+        return Ok(Err(PlayerRowError::MissingName));
+    }
+    if row.name.len() > 255 {
+        return Ok(Err(PlayerRowError::NameTooLong));
+    }
+    if row.country.is_empty() {
+        return Ok(Err(PlayerRowError::MissingCountry));
+    }
+
+    let code = row.country.to_uppercase();
+    let country_id = match countries.get(&code) {
+        Some(id) => *id,
+        None => {
+            let id = countries::find_country_by_code(db, &code).await?;
+            countries.insert(code, id);
+            id
+        }
+    };
+    let Some(country_id) = country_id else {
+        return Ok(Err(PlayerRowError::UnknownCountry));
+    };
+
+    let birth_date = match row.birth_date.as_str() {
+        "" => None,
+        birth_date => match parse_birth_date(birth_date) {
+            Some(birth_date) => Some(birth_date),
+            None => return Ok(Err(PlayerRowError::InvalidBirthDate)),
+        },
+    };
+    let position = match row.position.as_str() {
+        "" => None,
+        position => match parse_position(position) {
+            Some(position) => Some(position),
+            None => return Ok(Err(PlayerRowError::InvalidPosition)),
+        },
+    };
+
+    Ok(Ok(ValidRow {
+        name: row.name.clone(),
+        country_id,
+        birth_date,
+        position,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(
+        line: usize,
+        name: &str,
+        country: &str,
+        birth_date: &str,
+        position: &str,
+    ) -> PlayerCsvRow {
+        PlayerCsvRow {
+            line,
+            name: name.to_string(),
+            country: country.to_string(),
+            birth_date: birth_date.to_string(),
+            position: position.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_player_fields() {
+        assert_eq!(parse_position("lw"), Some("Forward"));
+        assert_eq!(parse_position("Defence"), Some("Defense"));
+        assert_eq!(parse_position("G"), Some("Goalie"));
+        assert_eq!(parse_position("Coach"), None);
+        assert_eq!(
+            parse_birth_date("1997-01-13"),
+            Some("1997-01-13".to_string())
+        );
+        assert_eq!(
+            parse_birth_date("15.2.1972"),
+            Some("1972-02-15".to_string())
+        );
+        assert_eq!(parse_birth_date("1997-02-30"), None);
+    }
+
+    #[sqlx::test(migrations = "./migrations", fixtures("players"))]
+    async fn test_import_players(pool: SqlitePool) {
+        let report = import_players(
+            &pool,
+            vec![
+                // Same name and birth date as player 1
+                row(2, "McDavid Connor", "CAN", "1997-01-13", "C"),
+                // Same name, no birth date, same country as player 2
+                row(3, "wayne gretzky", "ca", "", ""),
+                row(4, "Jaromír Jágr", "CZE", "15.2.1972", "RW"),
+                row(5, "Jaromir Jagr", "CZ", "1972-02-15", ""),
+                // A namesake from another country is a different player
+                row(6, "Sidney Crosby", "USA", "", "Goalie"),
+                row(7, "", "CZE", "", ""),
+                row(8, "Petr Nedvěd", "", "", ""),
+                row(9, "Petr Nedvěd", "XX", "", ""),
+                row(10, "Petr Nedvěd", "CZE", "9/12/1971", ""),
+                row(11, "Petr Nedvěd", "CZE", "", "Coach"),
+            ],
+        )
+        .await
+        .unwrap();
+
+        let outcomes: Vec<&PlayerRowOutcome> = report.rows.iter().map(|r| &r.outcome).collect();
+        assert_eq!(
+            outcomes[0],
+            &PlayerRowOutcome::Duplicate {
+                player_id: 1,
+                player_name: "Connor McDavid".to_string()
+            }
+        );
+        assert!(matches!(
+            outcomes[1],
+            PlayerRowOutcome::Duplicate { player_id: 2, .. }
+        ));
+        assert!(matches!(outcomes[2], PlayerRowOutcome::Created { .. }));
+        assert_eq!(
+            outcomes[3],
+            &PlayerRowOutcome::Invalid(PlayerRowError::DuplicateRow)
+        );
+        assert!(matches!(outcomes[4], PlayerRowOutcome::Created { .. }));
+        assert_eq!(
+            &outcomes[5..],
+            &[
+                &PlayerRowOutcome::Invalid(PlayerRowError::MissingName),
+                &PlayerRowOutcome::Invalid(PlayerRowError::MissingCountry),
+                &PlayerRowOutcome::Invalid(PlayerRowError::UnknownCountry),
+                &PlayerRowOutcome::Invalid(PlayerRowError::InvalidBirthDate),
+                &PlayerRowOutcome::Invalid(PlayerRowError::InvalidPosition),
+            ]
+        );
+        assert_eq!(
+            (report.created(), report.duplicates(), report.invalid()),
+            (2, 2, 6)
+        );
+
+        let jagr = sqlx::query!(
+            "SELECT birth_date, position, slug FROM player WHERE name = 'Jaromír Jágr'"
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(jagr.birth_date.as_deref(), Some("1972-02-15"));
+        assert_eq!(jagr.position.as_deref(), Some("Forward"));
+        assert!(jagr.slug.is_some());
+    }
+}
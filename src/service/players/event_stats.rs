@@ -16,6 +16,8 @@ pub struct PlayerEventStatsEntity {
 }
 
 /// Get all event stats for a player (with identified counts)
+///
+/// Identified counts leave out exhibition games.
 pub async fn get_player_event_stats(
     db: &SqlitePool,
     player_id: i64,
@@ -37,7 +39,7 @@ pub async fn get_player_event_stats(
         FROM player_event_stats pes
         INNER JOIN event e ON pes.event_id = e.id
         LEFT JOIN season s ON s.event_id = e.id
-        LEFT JOIN match m ON m.season_id = s.id
+        LEFT JOIN match m ON m.season_id = s.id AND m.game_type != 'exhibition'
         LEFT JOIN score_event se ON se.match_id = m.id
         WHERE pes.player_id = ?
         GROUP BY pes.id, pes.event_id, e.name, pes.goals_total, pes.assists_total
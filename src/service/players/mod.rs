@@ -8,9 +8,9 @@ mod scoring_queries;
 
 // Re-export everything from player_ops module
 pub use player_ops::{
-    create_player, delete_player, get_player_by_id, get_player_detail, get_players, update_player,
-    CreatePlayerEntity, PagedResult, PlayerContractWithTeamEntity, PlayerDetailEntity,
-    PlayerEntity, PlayerFilters, SortField, SortOrder, UpdatePlayerEntity,
+    create_player, delete_player, export_players_csv, get_player_by_id, get_player_detail,
+    get_players, update_player, CreatePlayerEntity, PagedResult, PlayerContractWithTeamEntity,
+    PlayerDetailEntity, PlayerEntity, PlayerFilters, SortField, SortOrder, UpdatePlayerEntity,
 };
 
 // Re-export scoring entities and queries
@@ -1,5 +1,9 @@
+use futures::TryStreamExt;
 use sqlx::{Row, SqlitePool};
 
+use crate::common::csv_export::CsvSink;
+use crate::service::slugs::{self, SlugEntity};
+
 // Re-export common pagination types for convenience
 pub use crate::common::pagination::{PagedResult, SortOrder};
 
@@ -17,6 +21,8 @@ pub struct PlayerEntity {
     pub weight_kg: Option<i64>,
     pub position: Option<String>,
     pub shoots: Option<String>,
+    /// URL slug, see [`crate::service::slugs`]
+    pub slug: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -60,9 +66,11 @@ pub struct UpdatePlayerEntity {
     pub weight_kg: Option<i64>,
     pub position: Option<String>,
     pub shoots: Option<String>,
+    /// Slug from the edit form, `None` generates it from the name
+    pub slug: Option<String>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, serde::Serialize)]
 pub struct PlayerFilters {
     pub name: Option<String>,
     pub country_id: Option<i64>,
@@ -107,6 +115,7 @@ pub async fn create_player(
     db: &SqlitePool,
     player: CreatePlayerEntity,
 ) -> Result<i64, sqlx::Error> {
+    let mut tx = db.begin().await?;
     let result = sqlx::query!(
         r#"
         INSERT INTO player (name, country_id, photo_path, birth_date, birth_place, height_cm, weight_kg, position, shoots)
@@ -122,10 +131,14 @@ pub async fn create_player(
         player.position,
         player.shoots
     )
-    .execute(db)
+    .execute(&mut *tx)
     .await?;
 
-    Ok(result.last_insert_rowid())
+    let id = result.last_insert_rowid();
+    slugs::assign_slug(&mut tx, SlugEntity::Player, id, &player.name).await?;
+    tx.commit().await?;
+
+    Ok(id)
 }
 
 /// Get players with filters and pagination
@@ -148,7 +161,7 @@ pub async fn get_players(
     // Build data query
     let mut data_query = sqlx::QueryBuilder::new(
         "SELECT p.id, p.name, p.country_id, p.photo_path, p.birth_date, p.birth_place, p.height_cm, p.weight_kg, p.position, p.shoots,
-         c.name as country_name, c.iso2Code as country_iso2_code
+         c.name as country_name, c.iso2Code as country_iso2_code, p.slug
          FROM player p
          INNER JOIN country c ON p.country_id = c.id
          WHERE 1=1",
@@ -183,12 +196,81 @@ pub async fn get_players(
             weight_kg: row.get("weight_kg"),
             position: row.get("position"),
             shoots: row.get("shoots"),
+            slug: row.get("slug"),
         })
         .collect();
 
     Ok(PagedResult::new(items, total as usize, page, page_size))
 }
 
+/// Write every player matching the filters to a CSV export, in list order
+///
+/// Rows are read from the database one at a time, so the export never holds
+/// the whole list. The columns match what the player import reads.
+pub async fn export_players_csv(
+    db: &SqlitePool,
+    filters: &PlayerFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+    sink: &mut CsvSink,
+) -> Result<(), sqlx::Error> {
+    let mut query = sqlx::QueryBuilder::new(
+        "SELECT p.id, p.name, c.iso2Code as country, p.birth_date, p.birth_place, p.height_cm, p.weight_kg, p.position, p.shoots
+         FROM player p
+         INNER JOIN country c ON p.country_id = c.id
+         WHERE 1=1",
+    );
+    apply_filters(&mut query, filters);
+    query
+        .push(" ORDER BY ")
+        .push(sort_field.to_sql())
+        .push(" ")
+        .push(sort_order.to_sql());
+
+    if !sink
+        .write([
+            "id",
+            "name",
+            "country",
+            "birth_date",
+            "birth_place",
+            "height_cm",
+            "weight_kg",
+            "position",
+            "shoots",
+        ])
+        .await
+    {
+        return Ok(());
+    }
+
+    let mut rows = query.build().fetch(db);
+    while let Some(row) = rows.try_next().await? {
+        let optional_number = |column: &str| {
+            row.get::<Option<i64>, _>(column)
+                .map(|value| value.to_string())
+                .unwrap_or_default()
+        };
+        let optional_text = |column: &str| row.get::<Option<String>, _>(column).unwrap_or_default();
+        let record = [
+            row.get::<i64, _>("id").to_string(),
+            row.get("name"),
+            row.get("country"),
+            optional_text("birth_date"),
+            optional_text("birth_place"),
+            optional_number("height_cm"),
+            optional_number("weight_kg"),
+            optional_text("position"),
+            optional_text("shoots"),
+        ];
+        if !sink.write(record).await {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
 /// Get a single player by ID
 pub async fn get_player_by_id(
     db: &SqlitePool,
@@ -209,7 +291,8 @@ pub async fn get_player_by_id(
             p.position,
             p.shoots,
             c.name as country_name,
-            c.iso2Code as "country_iso2_code!"
+            c.iso2Code as "country_iso2_code!",
+            p.slug
         FROM player p
         INNER JOIN country c ON p.country_id = c.id
         WHERE p.id = ?
@@ -228,7 +311,15 @@ pub async fn update_player(
     id: i64,
     player: UpdatePlayerEntity,
 ) -> Result<bool, sqlx::Error> {
-    let result = sqlx::query!(
+    let mut tx = db.begin().await?;
+    let Some(previous_name) = sqlx::query_scalar!("SELECT name FROM player WHERE id = ?", id)
+        .fetch_optional(&mut *tx)
+        .await?
+    else {
+        return Ok(false);
+    };
+
+    sqlx::query!(
         r#"
         UPDATE player
         SET name = ?, country_id = ?, photo_path = ?, birth_date = ?, birth_place = ?,
@@ -246,10 +337,20 @@ pub async fn update_player(
         player.shoots,
         id
     )
-    .execute(db)
+    .execute(&mut *tx)
     .await?;
 
-    Ok(result.rows_affected() > 0)
+    slugs::update_slug(
+        &mut tx,
+        SlugEntity::Player,
+        id,
+        &previous_name,
+        player.slug.as_deref(),
+    )
+    .await?;
+    tx.commit().await?;
+
+    Ok(true)
 }
 
 /// Delete a player
@@ -508,6 +609,7 @@ mod tests {
             weight_kg: Some(89),  // Updated weight
             position: Some("C".to_string()),
             shoots: Some("L".to_string()),
+            slug: None,
         };
 
         let updated = update_player(&pool, 1, update).await.unwrap();
@@ -538,6 +640,7 @@ mod tests {
             weight_kg: None,
             position: None,
             shoots: None,
+            slug: None,
         };
 
         let updated = update_player(&pool, 999, update).await.unwrap();
@@ -618,4 +721,24 @@ mod tests {
         assert_eq!(contract.team_name, "Team Canada");
         assert_eq!(contract.season_year, 2022);
     }
+
+    #[sqlx::test(migrations = "./migrations", fixtures("players"))]
+    async fn test_export_players_csv(pool: SqlitePool) {
+        let filters = PlayerFilters {
+            name: Some("McDavid".to_string()),
+            ..Default::default()
+        };
+        let db = &pool;
+        let csv = crate::common::csv_export::collect_csv(|mut sink| async move {
+            export_players_csv(db, &filters, &SortField::Name, &SortOrder::Asc, &mut sink).await?;
+            Ok(sink)
+        })
+        .await;
+
+        assert_eq!(
+            csv,
+            "id,name,country,birth_date,birth_place,height_cm,weight_kg,position,shoots\n\
+             1,Connor McDavid,CA,1997-01-13,,,,C,L\n"
+        );
+    }
 }
@@ -91,4 +91,18 @@ pub struct PlayerSeasonStats {
     pub goals: i32,
     pub assists: i32,
     pub points: i32,
+    /// `None` when no goal of the season has the player's on-ice entry
+    pub plus_minus: Option<i32>,
+    pub faceoffs_won: i32,
+    pub faceoffs_lost: i32,
+}
+
+impl PlayerSeasonStats {
+    /// Faceoff win percentage, `None` when no faceoffs were recorded
+    pub fn faceoff_percentage(&self) -> Option<f64> {
+        crate::service::matches::faceoff_percentage(
+            self.faceoffs_won.into(),
+            self.faceoffs_lost.into(),
+        )
+    }
 }
@@ -10,6 +10,10 @@ use super::scoring_entities::{
 };
 
 /// Get player statistics grouped by season
+///
+/// Exhibition games are not counted. Plus/minus counts the even-strength,
+/// short-handed and empty-net goals the player was recorded on the ice for.
+/// Seasons where the player only took faceoffs are listed too.
 pub async fn get_player_season_stats(
     db: &SqlitePool,
     player_id: i64,
@@ -22,23 +26,34 @@ pub async fn get_player_season_stats(
             s.display_name as season_display_name,
             e.id as "event_id!",
             e.name as "event_name!",
-            COALESCE(SUM(CASE WHEN se.scorer_id = ? THEN 1 ELSE 0 END), 0) as "goals!: i32",
-            COALESCE(SUM(CASE WHEN se.assist1_id = ? OR se.assist2_id = ? THEN 1 ELSE 0 END), 0) as "assists!: i32"
+            COALESCE(SUM(CASE WHEN se.scorer_id = ?1 THEN 1 ELSE 0 END), 0) as "goals!: i32",
+            COALESCE(SUM(CASE WHEN se.assist1_id = ?1 OR se.assist2_id = ?1 THEN 1 ELSE 0 END), 0) as "assists!: i32",
+            SUM(
+                CASE
+                    WHEN oi.team_id IS NULL
+                      OR se.goal_type IN ('power_play', 'penalty_shot')
+                      OR se.period = 5 THEN NULL
+                    WHEN oi.team_id = se.team_id THEN 1
+                    ELSE -1
+                END
+            ) as "plus_minus: i32",
+            COUNT(oi.id) as "on_ice_goals!: i32"
         FROM season s
         INNER JOIN event e ON s.event_id = e.id
         INNER JOIN match m ON m.season_id = s.id
         INNER JOIN score_event se ON se.match_id = m.id
-        WHERE se.scorer_id = ? OR se.assist1_id = ? OR se.assist2_id = ?
+        LEFT JOIN score_event_on_ice oi ON oi.score_event_id = se.id AND oi.player_id = ?1
+        WHERE (se.scorer_id = ?1 OR se.assist1_id = ?1 OR se.assist2_id = ?1 OR oi.id IS NOT NULL)
+          AND m.game_type != 'exhibition'
         GROUP BY s.id, s.year, s.display_name, e.id, e.name
         ORDER BY s.year DESC
         "#,
-        player_id, player_id, player_id,
-        player_id, player_id, player_id
+        player_id
     )
     .fetch_all(db)
     .await?;
 
-    Ok(rows
+    let mut stats: Vec<PlayerSeasonStats> = rows
         .into_iter()
         .map(|row| PlayerSeasonStats {
             season_id: row.season_id,
@@ -49,8 +64,60 @@ pub async fn get_player_season_stats(
             goals: row.goals,
             assists: row.assists,
             points: row.goals + row.assists,
+            // Goals that do not count (power play, penalty shot, shootout) still
+            // mean the player's on-ice entry is being kept
+            plus_minus: (row.on_ice_goals > 0).then(|| row.plus_minus.unwrap_or(0)),
+            faceoffs_won: 0,
+            faceoffs_lost: 0,
         })
-        .collect())
+        .collect();
+
+    let faceoffs = sqlx::query!(
+        r#"
+        SELECT
+            s.id as "season_id!",
+            s.year as "season_year!",
+            s.display_name as season_display_name,
+            e.id as "event_id!",
+            e.name as "event_name!",
+            SUM(f.won) as "won!: i32",
+            SUM(f.lost) as "lost!: i32"
+        FROM match_faceoffs f
+        INNER JOIN match m ON f.match_id = m.id
+        INNER JOIN season s ON m.season_id = s.id
+        INNER JOIN event e ON s.event_id = e.id
+        WHERE f.player_id = ? AND m.game_type != 'exhibition'
+        GROUP BY s.id, s.year, s.display_name, e.id, e.name
+        "#,
+        player_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    for row in faceoffs {
+        match stats.iter_mut().find(|s| s.season_id == row.season_id) {
+            Some(season) => {
+                season.faceoffs_won = row.won;
+                season.faceoffs_lost = row.lost;
+            }
+            None => stats.push(PlayerSeasonStats {
+                season_id: row.season_id,
+                season_year: row.season_year,
+                season_display_name: row.season_display_name,
+                event_id: row.event_id,
+                event_name: row.event_name,
+                goals: 0,
+                assists: 0,
+                points: 0,
+                plus_minus: None,
+                faceoffs_won: row.won,
+                faceoffs_lost: row.lost,
+            }),
+        }
+    }
+    stats.sort_by_key(|s| std::cmp::Reverse(s.season_year));
+
+    Ok(stats)
 }
 
 /// Get player scoring events with pagination and filters
@@ -0,0 +1,366 @@
+use sqlx::{Row, SqlitePool};
+
+/// A best-of-N playoff series between two teams of a season
+#[derive(Debug, Clone)]
+pub struct PlayoffSeriesEntity {
+    pub id: i64,
+    pub season_id: i64,
+    pub round: i64,
+    pub home_team_id: i64,
+    pub home_team_name: String,
+    pub home_team_country_iso2: Option<String>,
+    pub away_team_id: i64,
+    pub away_team_name: String,
+    pub away_team_country_iso2: Option<String>,
+    pub home_seed: Option<i64>,
+    pub away_seed: Option<i64>,
+    pub best_of: i64,
+    pub home_wins: i64,
+    pub away_wins: i64,
+    pub games: Vec<SeriesGameEntity>,
+}
+
+impl PlayoffSeriesEntity {
+    /// Number of wins required to take the series
+    pub fn wins_needed(&self) -> i64 {
+        self.best_of / 2 + 1
+    }
+
+    /// Winner of the series once one team reached the required number of wins
+    pub fn winner_team_id(&self) -> Option<i64> {
+        if self.home_wins >= self.wins_needed() {
+            Some(self.home_team_id)
+        } else if self.away_wins >= self.wins_needed() {
+            Some(self.away_team_id)
+        } else {
+            None
+        }
+    }
+
+    /// Whether another game can still be scheduled in this series
+    pub fn can_add_game(&self) -> bool {
+        self.winner_team_id().is_none() && (self.games.len() as i64) < self.best_of
+    }
+}
+
+/// A single match belonging to a playoff series
+#[derive(Debug, Clone)]
+pub struct SeriesGameEntity {
+    pub match_id: i64,
+    pub home_team_id: i64,
+    pub home_score: i64,
+    pub away_score: i64,
+    pub match_date: Option<String>,
+    pub status: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreatePlayoffSeriesEntity {
+    pub season_id: i64,
+    pub round: i64,
+    pub home_team_id: i64,
+    pub away_team_id: i64,
+    pub home_seed: Option<i64>,
+    pub away_seed: Option<i64>,
+    pub best_of: i64,
+}
+
+/// Create a new playoff series
+pub async fn create_series(
+    db: &SqlitePool,
+    series: CreatePlayoffSeriesEntity,
+) -> Result<i64, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO playoff_series (season_id, round, home_team_id, away_team_id, home_seed, away_seed, best_of)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#,
+        series.season_id,
+        series.round,
+        series.home_team_id,
+        series.away_team_id,
+        series.home_seed,
+        series.away_seed,
+        series.best_of
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Get all series of a season ordered by round, with games and series score
+pub async fn get_series_for_season(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<Vec<PlayoffSeriesEntity>, sqlx::Error> {
+    let rows = sqlx::query(
+        r#"
+        SELECT
+            ps.id, ps.season_id, ps.round,
+            ps.home_team_id, ht.name as home_team_name, hc.iso2Code as home_team_country_iso2,
+            ps.away_team_id, at.name as away_team_name, ac.iso2Code as away_team_country_iso2,
+            ps.home_seed, ps.away_seed, ps.best_of
+        FROM playoff_series ps
+        INNER JOIN team ht ON ps.home_team_id = ht.id
+        INNER JOIN team at ON ps.away_team_id = at.id
+        LEFT JOIN country hc ON ht.country_id = hc.id
+        LEFT JOIN country ac ON at.country_id = ac.id
+        WHERE ps.season_id = ?
+        ORDER BY ps.round ASC, COALESCE(ps.home_seed, 999) ASC, ps.id ASC
+        "#,
+    )
+    .bind(season_id)
+    .fetch_all(db)
+    .await?;
+
+    let mut series: Vec<PlayoffSeriesEntity> = rows
+        .into_iter()
+        .map(|row| PlayoffSeriesEntity {
+            id: row.get("id"),
+            season_id: row.get("season_id"),
+            round: row.get("round"),
+            home_team_id: row.get("home_team_id"),
+            home_team_name: row.get("home_team_name"),
+            home_team_country_iso2: row.get("home_team_country_iso2"),
+            away_team_id: row.get("away_team_id"),
+            away_team_name: row.get("away_team_name"),
+            away_team_country_iso2: row.get("away_team_country_iso2"),
+            home_seed: row.get("home_seed"),
+            away_seed: row.get("away_seed"),
+            best_of: row.get("best_of"),
+            home_wins: 0,
+            away_wins: 0,
+            games: Vec::new(),
+        })
+        .collect();
+
+    // Scores are totals: identified score events plus unidentified goals
+    let game_rows = sqlx::query(
+        r#"
+        SELECT
+            m.id, m.series_id, m.home_team_id, m.match_date, m.status,
+            m.home_score_unidentified
+                + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id)
+                as home_score,
+            m.away_score_unidentified
+                + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.away_team_id)
+                as away_score
+        FROM match m
+        INNER JOIN playoff_series ps ON m.series_id = ps.id
+        WHERE ps.season_id = ?
+        ORDER BY m.match_date IS NULL, m.match_date ASC, m.id ASC
+        "#,
+    )
+    .bind(season_id)
+    .fetch_all(db)
+    .await?;
+
+    for row in game_rows {
+        let series_id: i64 = row.get("series_id");
+        let Some(entry) = series.iter_mut().find(|s| s.id == series_id) else {
+            continue;
+        };
+
+        let game = SeriesGameEntity {
+            match_id: row.get("id"),
+            home_team_id: row.get("home_team_id"),
+            home_score: row.get("home_score"),
+            away_score: row.get("away_score"),
+            match_date: row.get("match_date"),
+            status: row.get("status"),
+        };
+
+        // Only finished games count towards the series score
+        if game.status == "finished" && game.home_score != game.away_score {
+            let winner = if game.home_score > game.away_score {
+                game.home_team_id
+            } else if game.home_team_id == entry.home_team_id {
+                entry.away_team_id
+            } else {
+                entry.home_team_id
+            };
+
+            if winner == entry.home_team_id {
+                entry.home_wins += 1;
+            } else {
+                entry.away_wins += 1;
+            }
+        }
+
+        entry.games.push(game);
+    }
+
+    Ok(series)
+}
+
+/// Get a single series by ID (including its games)
+pub async fn get_series_by_id(
+    db: &SqlitePool,
+    id: i64,
+) -> Result<Option<PlayoffSeriesEntity>, sqlx::Error> {
+    let season_id = sqlx::query_scalar!("SELECT season_id FROM playoff_series WHERE id = ?", id)
+        .fetch_optional(db)
+        .await?;
+
+    let Some(season_id) = season_id else {
+        return Ok(None);
+    };
+
+    Ok(get_series_for_season(db, season_id)
+        .await?
+        .into_iter()
+        .find(|s| s.id == id))
+}
+
+/// Delete a series (its matches are kept and unlinked)
+pub async fn delete_series(db: &SqlitePool, id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!("DELETE FROM playoff_series WHERE id = ?", id)
+        .execute(db)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Schedule the next game of a series
+///
+/// Home ice follows the 2-2-1-1-1 format: the series home team (higher seed)
+/// hosts games 1, 2, 5 and 7.
+pub async fn create_series_game(
+    db: &SqlitePool,
+    series: &PlayoffSeriesEntity,
+    match_date: Option<String>,
+) -> Result<i64, sqlx::Error> {
+    let game_number = series.games.len() + 1;
+    let (home_team_id, away_team_id) = if matches!(game_number, 3 | 4 | 6) {
+        (series.away_team_id, series.home_team_id)
+    } else {
+        (series.home_team_id, series.away_team_id)
+    };
+
+    let result = sqlx::query!(
+        "INSERT INTO match (season_id, home_team_id, away_team_id, match_date, status, series_id) \
+         VALUES (?, ?, ?, ?, 'scheduled', ?)",
+        series.season_id,
+        home_team_id,
+        away_team_id,
+        match_date,
+        series.id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::SqlitePool;
+
+    async fn create_test_series(pool: &SqlitePool, best_of: i64) -> i64 {
+        create_series(
+            pool,
+            CreatePlayoffSeriesEntity {
+                season_id: 1,
+                round: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_seed: Some(1),
+                away_seed: Some(8),
+                best_of,
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn finish_game(pool: &SqlitePool, match_id: i64, home: i64, away: i64) {
+        sqlx::query!(
+            "UPDATE match SET status = 'finished', home_score_unidentified = ?, away_score_unidentified = ? WHERE id = ?",
+            home,
+            away,
+            match_id
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_create_and_list_series(pool: SqlitePool) {
+        let id = create_test_series(&pool, 7).await;
+
+        let series = get_series_for_season(&pool, 1).await.unwrap();
+        assert_eq!(series.len(), 1);
+        assert_eq!(series[0].id, id);
+        assert_eq!(series[0].wins_needed(), 4);
+        assert_eq!(series[0].home_wins, 0);
+        assert!(series[0].games.is_empty());
+        assert!(series[0].can_add_game());
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_series_games_alternate_home_ice(pool: SqlitePool) {
+        let id = create_test_series(&pool, 7).await;
+
+        for _ in 0..3 {
+            let series = get_series_by_id(&pool, id).await.unwrap().unwrap();
+            create_series_game(&pool, &series, None).await.unwrap();
+        }
+
+        let series = get_series_by_id(&pool, id).await.unwrap().unwrap();
+        let hosts: Vec<i64> = series.games.iter().map(|g| g.home_team_id).collect();
+        assert_eq!(hosts, vec![1, 1, 2]);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_series_score_updates_from_results(pool: SqlitePool) {
+        let id = create_test_series(&pool, 3).await;
+
+        let series = get_series_by_id(&pool, id).await.unwrap().unwrap();
+        let game1 = create_series_game(&pool, &series, None).await.unwrap();
+        let series = get_series_by_id(&pool, id).await.unwrap().unwrap();
+        let game2 = create_series_game(&pool, &series, None).await.unwrap();
+
+        // Game 1: series home team wins at home; game 2 still scheduled
+        finish_game(&pool, game1, 3, 1).await;
+        let series = get_series_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!((series.home_wins, series.away_wins), (1, 0));
+        assert_eq!(series.winner_team_id(), None);
+
+        // Game 2: series home team wins again and clinches
+        finish_game(&pool, game2, 2, 0).await;
+        let series = get_series_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!((series.home_wins, series.away_wins), (2, 0));
+        assert_eq!(series.winner_team_id(), Some(1));
+        assert!(!series.can_add_game());
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_delete_series_keeps_matches(pool: SqlitePool) {
+        let id = create_test_series(&pool, 7).await;
+        let series = get_series_by_id(&pool, id).await.unwrap().unwrap();
+        let match_id = create_series_game(&pool, &series, None).await.unwrap();
+
+        assert!(delete_series(&pool, id).await.unwrap());
+        assert!(get_series_by_id(&pool, id).await.unwrap().is_none());
+
+        let remaining = crate::service::matches::get_match_by_id(&pool, match_id)
+            .await
+            .unwrap();
+        assert!(remaining.is_some());
+    }
+}
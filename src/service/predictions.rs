@@ -0,0 +1,318 @@
+use sqlx::SqlitePool;
+
+/// Team a user expects to win a match
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PredictedWinner {
+    Home,
+    Away,
+}
+
+impl PredictedWinner {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "home" => Some(Self::Home),
+            "away" => Some(Self::Away),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Home => "home",
+            Self::Away => "away",
+        }
+    }
+}
+
+/// A scheduled match together with the current user's pick
+#[derive(Debug, Clone)]
+pub struct UpcomingMatchPickEntity {
+    pub match_id: i64,
+    pub match_date: Option<String>,
+    pub season_name: String,
+    pub home_team_name: String,
+    pub away_team_name: String,
+    pub predicted_winner: Option<String>,
+}
+
+impl UpcomingMatchPickEntity {
+    pub fn pick(&self) -> Option<PredictedWinner> {
+        self.predicted_winner
+            .as_deref()
+            .and_then(PredictedWinner::from_str)
+    }
+}
+
+/// A user's prediction record in one season
+#[derive(Debug, Clone)]
+pub struct PredictorStandingEntity {
+    pub user_id: i64,
+    pub user_name: String,
+    /// Picks on finished matches
+    pub picks: i64,
+    /// Picks that named the actual winner
+    pub correct: i64,
+}
+
+impl PredictorStandingEntity {
+    pub fn accuracy(&self) -> Option<f64> {
+        if self.picks <= 0 {
+            return None;
+        }
+        Some(self.correct as f64 * 100.0 / self.picks as f64)
+    }
+}
+
+/// Get scheduled matches open for predictions with the user's picks
+///
+/// Undated matches are listed last.
+pub async fn get_upcoming_matches(
+    db: &SqlitePool,
+    user_id: i64,
+    season_id: Option<i64>,
+) -> Result<Vec<UpcomingMatchPickEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        UpcomingMatchPickEntity,
+        r#"
+        SELECT
+            m.id as "match_id!",
+            m.match_date,
+            e.name || ' ' || COALESCE(s.display_name, CAST(s.year AS TEXT)) as "season_name!: String",
+            ht.name as home_team_name,
+            at.name as away_team_name,
+            mp.predicted_winner as "predicted_winner?"
+        FROM match m
+        INNER JOIN team ht ON m.home_team_id = ht.id
+        INNER JOIN team at ON m.away_team_id = at.id
+        INNER JOIN season s ON m.season_id = s.id
+        INNER JOIN event e ON s.event_id = e.id
+        LEFT JOIN match_prediction mp ON mp.match_id = m.id AND mp.user_id = ?1
+        WHERE m.status = 'scheduled' AND (?2 IS NULL OR m.season_id = ?2)
+        ORDER BY m.match_date ASC NULLS LAST, m.id ASC
+        "#,
+        user_id,
+        season_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Get one scheduled match with the user's pick, `None` once picks are closed
+pub async fn get_upcoming_match(
+    db: &SqlitePool,
+    user_id: i64,
+    match_id: i64,
+) -> Result<Option<UpcomingMatchPickEntity>, sqlx::Error> {
+    let row = sqlx::query_as!(
+        UpcomingMatchPickEntity,
+        r#"
+        SELECT
+            m.id as "match_id!",
+            m.match_date,
+            e.name || ' ' || COALESCE(s.display_name, CAST(s.year AS TEXT)) as "season_name!: String",
+            ht.name as home_team_name,
+            at.name as away_team_name,
+            mp.predicted_winner as "predicted_winner?"
+        FROM match m
+        INNER JOIN team ht ON m.home_team_id = ht.id
+        INNER JOIN team at ON m.away_team_id = at.id
+        INNER JOIN season s ON m.season_id = s.id
+        INNER JOIN event e ON s.event_id = e.id
+        LEFT JOIN match_prediction mp ON mp.match_id = m.id AND mp.user_id = ?1
+        WHERE m.id = ?2 AND m.status = 'scheduled'
+        "#,
+        user_id,
+        match_id
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(row)
+}
+
+/// Save or change a user's pick
+///
+/// Only scheduled matches accept picks; returns `false` when the match does
+/// not exist or has already started.
+pub async fn save_prediction(
+    db: &SqlitePool,
+    user_id: i64,
+    match_id: i64,
+    winner: PredictedWinner,
+) -> Result<bool, sqlx::Error> {
+    let winner = winner.as_str();
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO match_prediction (user_id, match_id, predicted_winner)
+        SELECT ?, id, ? FROM match WHERE id = ? AND status = 'scheduled'
+        ON CONFLICT (user_id, match_id) DO UPDATE
+        SET predicted_winner = excluded.predicted_winner, updated_at = CURRENT_TIMESTAMP
+        "#,
+        user_id,
+        winner,
+        match_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Rank predictors of a season by correct picks
+///
+/// Picks are scored once their match is finished, against the final score
+/// including unidentified goals; a tied final score has no winner, so every
+/// pick on it counts as missed. Fewer picks rank ahead on equal correct picks.
+pub async fn get_leaderboard(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<Vec<PredictorStandingEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        PredictorStandingEntity,
+        r#"
+        WITH finished AS (
+            SELECT
+                m.id,
+                m.home_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id) as home_score,
+                m.away_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.away_team_id) as away_score
+            FROM match m
+            WHERE m.season_id = ? AND m.status = 'finished'
+        )
+        SELECT
+            u.id as "user_id!",
+            COALESCE(u.name, u.email) as "user_name!: String",
+            COUNT(*) as "picks!: i64",
+            SUM(CASE
+                WHEN mp.predicted_winner = 'home' AND f.home_score > f.away_score THEN 1
+                WHEN mp.predicted_winner = 'away' AND f.away_score > f.home_score THEN 1
+                ELSE 0
+            END) as "correct!: i64"
+        FROM match_prediction mp
+        INNER JOIN finished f ON mp.match_id = f.id
+        INNER JOIN users u ON mp.user_id = u.id
+        GROUP BY u.id
+        ORDER BY 4 DESC, 3 ASC, 2 ASC
+        "#,
+        season_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{create_match, CreateMatchEntity};
+
+    async fn create_user(pool: &SqlitePool, email: &str) -> i64 {
+        sqlx::query("INSERT INTO users (email, name, password_hash) VALUES (?, ?, 'x')")
+            .bind(email)
+            .bind(email)
+            .execute(pool)
+            .await
+            .unwrap()
+            .last_insert_rowid()
+    }
+
+    async fn create_test_match(pool: &SqlitePool, home: i32, away: i32) -> i64 {
+        create_match(
+            pool,
+            CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: home,
+                away_score_unidentified: away,
+                match_date: Some("2024-01-15".to_string()),
+                status: "scheduled".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn finish_match(pool: &SqlitePool, match_id: i64) {
+        sqlx::query("UPDATE match SET status = 'finished' WHERE id = ?")
+            .bind(match_id)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_picks_close_when_match_starts(pool: SqlitePool) {
+        let user = create_user(&pool, "fan@example.com").await;
+        let match_id = create_test_match(&pool, 3, 1).await;
+
+        assert!(
+            save_prediction(&pool, user, match_id, PredictedWinner::Away)
+                .await
+                .unwrap()
+        );
+        // Changing the pick updates it in place
+        assert!(
+            save_prediction(&pool, user, match_id, PredictedWinner::Home)
+                .await
+                .unwrap()
+        );
+        let upcoming = get_upcoming_matches(&pool, user, Some(1)).await.unwrap();
+        assert_eq!(upcoming.len(), 1);
+        assert_eq!(upcoming[0].pick(), Some(PredictedWinner::Home));
+
+        finish_match(&pool, match_id).await;
+        assert!(
+            !save_prediction(&pool, user, match_id, PredictedWinner::Away)
+                .await
+                .unwrap()
+        );
+        assert!(get_upcoming_match(&pool, user, match_id)
+            .await
+            .unwrap()
+            .is_none());
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_leaderboard(pool: SqlitePool) {
+        let alice = create_user(&pool, "alice@example.com").await;
+        let bob = create_user(&pool, "bob@example.com").await;
+        let home_win = create_test_match(&pool, 3, 1).await;
+        let tie = create_test_match(&pool, 2, 2).await;
+        let not_played = create_test_match(&pool, 0, 0).await;
+
+        for (user, match_id, winner) in [
+            (alice, home_win, PredictedWinner::Home),
+            (alice, tie, PredictedWinner::Home),
+            (bob, home_win, PredictedWinner::Away),
+            (bob, not_played, PredictedWinner::Home),
+        ] {
+            save_prediction(&pool, user, match_id, winner)
+                .await
+                .unwrap();
+        }
+        finish_match(&pool, home_win).await;
+        finish_match(&pool, tie).await;
+
+        let leaderboard = get_leaderboard(&pool, 1).await.unwrap();
+        assert_eq!(leaderboard.len(), 2);
+        assert_eq!(leaderboard[0].user_id, alice);
+        assert_eq!((leaderboard[0].correct, leaderboard[0].picks), (1, 2));
+        assert_eq!(leaderboard[0].accuracy(), Some(50.0));
+        // The unplayed match is not scored yet
+        assert_eq!((leaderboard[1].correct, leaderboard[1].picks), (0, 1));
+
+        assert!(get_leaderboard(&pool, 2).await.unwrap().is_empty());
+    }
+}
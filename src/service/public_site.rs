@@ -0,0 +1,297 @@
+//! Read-only queries of the public site
+
+use std::collections::HashMap;
+
+use sqlx::SqlitePool;
+
+use super::roster_rules::GOALIE_POSITION;
+
+/// Path segment of a record on the public site: its slug, or its ID until it has one
+fn path_key(slug: Option<&str>, id: i64) -> String {
+    slug.map(|slug| urlencoding::encode(slug).into_owned())
+        .unwrap_or_else(|| id.to_string())
+}
+
+/// Path of a season's public page
+pub fn public_season_path(season_id: i64, slug: Option<&str>) -> String {
+    format!("/public/seasons/{}", path_key(slug, season_id))
+}
+
+/// Path of a team's roster page in a season
+pub fn public_roster_path(
+    season_id: i64,
+    season_slug: Option<&str>,
+    team_id: i64,
+    team_slug: Option<&str>,
+) -> String {
+    format!(
+        "{}/teams/{}",
+        public_season_path(season_id, season_slug),
+        path_key(team_slug, team_id)
+    )
+}
+
+/// Slug paths of a season's public pages
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicSeasonPaths {
+    season: String,
+    /// Path segments of the participating teams by team ID
+    teams: HashMap<i64, String>,
+}
+
+impl PublicSeasonPaths {
+    /// Standings and schedule
+    pub fn season(&self) -> String {
+        self.season.clone()
+    }
+
+    /// Scoring of every player
+    pub fn players(&self) -> String {
+        format!("{}/players", self.season)
+    }
+
+    /// Roster of a team
+    pub fn roster(&self, team_id: i64) -> String {
+        let team = self
+            .teams
+            .get(&team_id)
+            .cloned()
+            .unwrap_or_else(|| team_id.to_string());
+        format!("{}/teams/{}", self.season, team)
+    }
+}
+
+/// A season listed on the public home page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicSeasonEntity {
+    pub id: i64,
+    pub year: i64,
+    pub display_name: Option<String>,
+    pub slug: Option<String>,
+    pub event_name: String,
+    pub teams: i64,
+    pub finished_matches: i64,
+    pub matches: i64,
+}
+
+impl PublicSeasonEntity {
+    /// Display name, or event name and year when the season has none
+    pub fn name(&self) -> String {
+        self.display_name
+            .clone()
+            .unwrap_or_else(|| format!("{} {}", self.event_name, self.year))
+    }
+
+    /// Path of the season's public page
+    pub fn path(&self) -> String {
+        public_season_path(self.id, self.slug.as_deref())
+    }
+}
+
+/// A player on a team's season roster with the points scored for that team
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublicRosterPlayerEntity {
+    pub player_id: i64,
+    pub player_name: String,
+    pub jersey_number: Option<i64>,
+    pub position: Option<String>,
+    pub country_iso2_code: Option<String>,
+    /// `C` for the captain, `A` for an alternate
+    pub captaincy: Option<String>,
+    pub goals: i64,
+    pub assists: i64,
+}
+
+impl PublicRosterPlayerEntity {
+    pub fn points(&self) -> i64 {
+        self.goals + self.assists
+    }
+}
+
+/// Every season, newest first
+pub async fn get_public_seasons(db: &SqlitePool) -> Result<Vec<PublicSeasonEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        PublicSeasonEntity,
+        r#"
+        SELECT
+            s.id as "id!",
+            s.year,
+            s.display_name,
+            s.slug,
+            e.name as event_name,
+            (SELECT COUNT(*) FROM team_participation tp WHERE tp.season_id = s.id) as "teams!: i64",
+            (SELECT COUNT(*) FROM match m WHERE m.season_id = s.id AND m.status = 'finished') as "finished_matches!: i64",
+            (SELECT COUNT(*) FROM match m WHERE m.season_id = s.id AND m.status != 'cancelled') as "matches!: i64"
+        FROM season s
+        INNER JOIN event e ON s.event_id = e.id
+        ORDER BY s.year DESC, e.name ASC, s.id DESC
+        "#
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Paths of a season's public pages by the current slugs of the season and its teams
+pub async fn get_public_season_paths(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<PublicSeasonPaths, sqlx::Error> {
+    let season_slug = sqlx::query_scalar!("SELECT slug FROM season WHERE id = ?", season_id)
+        .fetch_optional(db)
+        .await?
+        .flatten();
+    let teams = sqlx::query!(
+        r#"
+        SELECT t.id as "id!", t.slug
+        FROM team_participation tp
+        INNER JOIN team t ON t.id = tp.team_id
+        WHERE tp.season_id = ?
+        "#,
+        season_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(PublicSeasonPaths {
+        season: public_season_path(season_id, season_slug.as_deref()),
+        teams: teams
+            .into_iter()
+            .map(|team| (team.id, path_key(team.slug.as_deref(), team.id)))
+            .collect(),
+    })
+}
+
+/// Current roster of a team in a season: goalies, defense, forwards
+///
+/// Players who left mid-season are not listed.
+pub async fn get_public_roster(
+    db: &SqlitePool,
+    season_id: i64,
+    team_id: i64,
+) -> Result<Vec<PublicRosterPlayerEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        PublicRosterPlayerEntity,
+        r#"
+        SELECT
+            p.id as "player_id!",
+            p.name as player_name,
+            pc.jersey_number,
+            p.position,
+            c.iso2Code as country_iso2_code,
+            pc.captaincy,
+            (SELECT COUNT(*) FROM score_event se INNER JOIN match m ON se.match_id = m.id
+             WHERE m.season_id = tp.season_id AND se.team_id = tp.team_id AND se.scorer_id = p.id) as "goals!: i64",
+            (SELECT COUNT(*) FROM score_event se INNER JOIN match m ON se.match_id = m.id
+             WHERE m.season_id = tp.season_id AND se.team_id = tp.team_id
+               AND (se.assist1_id = p.id OR se.assist2_id = p.id)) as "assists!: i64"
+        FROM player_contract pc
+        INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+        INNER JOIN player p ON pc.player_id = p.id
+        LEFT JOIN country c ON p.country_id = c.id
+        WHERE tp.season_id = ?1 AND tp.team_id = ?2 AND pc.left_on IS NULL
+        ORDER BY
+            CASE p.position WHEN ?3 THEN 0 WHEN 'Defense' THEN 1 WHEN 'Forward' THEN 2 ELSE 3 END,
+            pc.jersey_number IS NULL, pc.jersey_number, p.name
+        "#,
+        season_id,
+        team_id,
+        GOALIE_POSITION
+    )
+    .fetch_all(db)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_public_seasons_and_roster(pool: SqlitePool) {
+        let seasons = get_public_seasons(&pool).await.unwrap();
+        assert_eq!(seasons.len(), 3);
+        // Newest first
+        assert!(seasons[0].year >= seasons[1].year);
+        let olympics = seasons.iter().find(|s| s.id == 1).unwrap();
+        assert_eq!(olympics.teams, 2);
+
+        // Team Canada in the 2022 Winter Olympics
+        let participation_id = 1;
+        for (player_id, number, position, left_on) in [
+            (1, 97, "Forward", None),
+            (2, 1, "Goalie", None),
+            (3, 44, "Defense", Some("2022-02-10")),
+        ] {
+            sqlx::query("UPDATE player SET position = ? WHERE id = ?")
+                .bind(position)
+                .bind(player_id)
+                .execute(&pool)
+                .await
+                .unwrap();
+            sqlx::query(
+                "INSERT INTO player_contract (player_id, team_participation_id, jersey_number, left_on) VALUES (?, ?, ?, ?)",
+            )
+            .bind(player_id)
+            .bind(participation_id)
+            .bind(number)
+            .bind(left_on)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        let match_id = sqlx::query(
+            "INSERT INTO match (season_id, home_team_id, away_team_id, status) VALUES (1, 1, 2, 'finished')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap()
+        .last_insert_rowid();
+        sqlx::query(
+            "INSERT INTO score_event (match_id, team_id, scorer_id, assist1_id) VALUES (?, 1, 1, 2)",
+        )
+        .bind(match_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let roster = get_public_roster(&pool, 1, 1).await.unwrap();
+        let ids: Vec<i64> = roster.iter().map(|p| p.player_id).collect();
+        // Goalie first, the player who left is not listed
+        assert_eq!(ids, vec![2, 1]);
+        assert_eq!((roster[1].goals, roster[1].assists), (1, 0));
+        assert_eq!(roster[0].points(), 1);
+
+        assert!(get_public_roster(&pool, 2, 1).await.unwrap().is_empty());
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_public_season_paths(pool: SqlitePool) {
+        // IDs stand in until slugs are assigned
+        let paths = get_public_season_paths(&pool, 1).await.unwrap();
+        assert_eq!(paths.roster(1), "/public/seasons/1/teams/1");
+
+        let mut conn = pool.acquire().await.unwrap();
+        crate::service::slugs::assign_missing_slugs(&mut conn)
+            .await
+            .unwrap();
+        let paths = get_public_season_paths(&pool, 1).await.unwrap();
+        assert_eq!(paths.season(), "/public/seasons/2022-winter-olympics");
+        assert_eq!(
+            paths.players(),
+            "/public/seasons/2022-winter-olympics/players"
+        );
+        assert_eq!(
+            paths.roster(1),
+            "/public/seasons/2022-winter-olympics/teams/team-canada"
+        );
+
+        let seasons = get_public_seasons(&pool).await.unwrap();
+        let olympics = seasons.iter().find(|s| s.id == 1).unwrap();
+        assert_eq!(olympics.path(), paths.season());
+    }
+}
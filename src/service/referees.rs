@@ -0,0 +1,458 @@
+use sqlx::{Row, SqlitePool};
+
+use crate::common::pagination::PagedResult;
+
+pub use crate::common::pagination::SortOrder;
+
+#[derive(Debug, Clone)]
+pub struct RefereeEntity {
+    pub id: i64,
+    pub name: String,
+    pub country_id: Option<i64>,
+    pub country_name: Option<String>,
+    pub country_iso2_code: Option<String>,
+    /// Matches this official is assigned to, in any role
+    pub match_count: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateRefereeEntity {
+    pub name: String,
+    pub country_id: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateRefereeEntity {
+    pub name: String,
+    pub country_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RefereeFilters {
+    pub name: Option<String>,
+    pub country_id: Option<i64>,
+}
+
+/// Sortable fields for referees
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SortField {
+    Id,
+    Name,
+    Country,
+    Matches,
+}
+
+impl SortField {
+    pub fn from_str(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "id" => Self::Id,
+            "country" => Self::Country,
+            "matches" => Self::Matches,
+            _ => Self::Name,
+        }
+    }
+
+    pub fn to_sql(&self) -> &'static str {
+        match self {
+            Self::Id => "r.id",
+            Self::Name => "r.name",
+            Self::Country => "c.name",
+            Self::Matches => "match_count",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Name => "name",
+            Self::Country => "country",
+            Self::Matches => "matches",
+        }
+    }
+}
+
+/// Role of an official in a match
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OfficialRole {
+    Referee,
+    Linesman,
+}
+
+impl OfficialRole {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "referee" => Some(Self::Referee),
+            "linesman" => Some(Self::Linesman),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Referee => "referee",
+            Self::Linesman => "linesman",
+        }
+    }
+
+    /// Officials of this role per match (IIHF four-official system)
+    pub fn max_per_match(&self) -> usize {
+        2
+    }
+}
+
+/// An official assigned to a match
+#[derive(Debug, Clone)]
+pub struct MatchOfficialEntity {
+    pub referee_id: i64,
+    pub name: String,
+    pub role: String,
+    pub country_iso2_code: Option<String>,
+}
+
+impl MatchOfficialEntity {
+    pub fn role(&self) -> Option<OfficialRole> {
+        OfficialRole::from_str(&self.role)
+    }
+}
+
+/// Create a new referee
+pub async fn create_referee(
+    db: &SqlitePool,
+    referee: CreateRefereeEntity,
+) -> Result<i64, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO referee (name, country_id)
+        VALUES (?, ?)
+        "#,
+        referee.name,
+        referee.country_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Get referees with filters, sorting, and pagination
+pub async fn get_referees(
+    db: &SqlitePool,
+    filters: &RefereeFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+    page: usize,
+    page_size: usize,
+) -> Result<PagedResult<RefereeEntity>, sqlx::Error> {
+    // Build count query
+    let mut count_query = sqlx::QueryBuilder::new(
+        "SELECT COUNT(*) as count FROM referee r LEFT JOIN country c ON r.country_id = c.id WHERE 1=1",
+    );
+    apply_filters(&mut count_query, filters);
+
+    let total: i64 = count_query.build().fetch_one(db).await?.get("count");
+
+    // Build data query
+    let mut data_query = sqlx::QueryBuilder::new(
+        "SELECT r.id, r.name, r.country_id, c.name as country_name, c.iso2Code as country_iso2_code,
+                (SELECT COUNT(DISTINCT mo.match_id) FROM match_official mo WHERE mo.referee_id = r.id) as match_count
+         FROM referee r
+         LEFT JOIN country c ON r.country_id = c.id
+         WHERE 1=1",
+    );
+    apply_filters(&mut data_query, filters);
+
+    // Apply sorting
+    // SECURITY: Using .push() method to safely append enum values.
+    // This prevents SQL injection as values come from trusted enum matches.
+    data_query
+        .push(" ORDER BY ")
+        .push(sort_field.to_sql())
+        .push(" ")
+        .push(sort_order.to_sql())
+        .push(", r.name ASC");
+
+    // Apply pagination
+    let offset = (page - 1) * page_size;
+    data_query.push(" LIMIT ").push_bind(page_size as i64);
+    data_query.push(" OFFSET ").push_bind(offset as i64);
+
+    let rows = data_query.build().fetch_all(db).await?;
+
+    let items = rows
+        .into_iter()
+        .map(|row| RefereeEntity {
+            id: row.get("id"),
+            name: row.get("name"),
+            country_id: row.get("country_id"),
+            country_name: row.get("country_name"),
+            country_iso2_code: row.get("country_iso2_code"),
+            match_count: row.get("match_count"),
+        })
+        .collect();
+
+    Ok(PagedResult::new(items, total as usize, page, page_size))
+}
+
+/// Get a single referee by ID
+pub async fn get_referee_by_id(
+    db: &SqlitePool,
+    id: i64,
+) -> Result<Option<RefereeEntity>, sqlx::Error> {
+    let row = sqlx::query_as!(
+        RefereeEntity,
+        r#"
+        SELECT
+            r.id,
+            r.name,
+            r.country_id,
+            c.name as country_name,
+            c.iso2Code as country_iso2_code,
+            (SELECT COUNT(DISTINCT mo.match_id) FROM match_official mo WHERE mo.referee_id = r.id) as "match_count!: i64"
+        FROM referee r
+        LEFT JOIN country c ON r.country_id = c.id
+        WHERE r.id = ?
+        "#,
+        id
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(row)
+}
+
+/// Get all referees for dropdowns
+pub async fn get_referees_simple(db: &SqlitePool) -> Result<Vec<(i64, String)>, sqlx::Error> {
+    let rows = sqlx::query!("SELECT id, name FROM referee ORDER BY name ASC")
+        .fetch_all(db)
+        .await?;
+
+    Ok(rows.into_iter().map(|row| (row.id, row.name)).collect())
+}
+
+/// Update a referee
+pub async fn update_referee(
+    db: &SqlitePool,
+    id: i64,
+    referee: UpdateRefereeEntity,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE referee
+        SET name = ?, country_id = ?, updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        referee.name,
+        referee.country_id,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete a referee (also removes their match assignments)
+pub async fn delete_referee(db: &SqlitePool, id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        DELETE FROM referee
+        WHERE id = ?
+        "#,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Get the officials assigned to a match, referees first
+pub async fn get_match_officials(
+    db: &SqlitePool,
+    match_id: i64,
+) -> Result<Vec<MatchOfficialEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        MatchOfficialEntity,
+        r#"
+        SELECT
+            r.id as "referee_id!",
+            r.name,
+            mo.role,
+            c.iso2Code as country_iso2_code
+        FROM match_official mo
+        INNER JOIN referee r ON mo.referee_id = r.id
+        LEFT JOIN country c ON r.country_id = c.id
+        WHERE mo.match_id = ?
+        ORDER BY mo.role DESC, mo.id
+        "#,
+        match_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Replace the officials of a match
+pub async fn set_match_officials(
+    db: &SqlitePool,
+    match_id: i64,
+    officials: &[(i64, OfficialRole)],
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!("DELETE FROM match_official WHERE match_id = ?", match_id)
+        .execute(&mut *tx)
+        .await?;
+
+    for (referee_id, role) in officials {
+        let role = role.as_str();
+        sqlx::query!(
+            "INSERT INTO match_official (match_id, referee_id, role) VALUES (?, ?, ?)",
+            match_id,
+            referee_id,
+            role
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+/// Helper function to apply filters to a query
+fn apply_filters<'a>(
+    query_builder: &mut sqlx::QueryBuilder<'a, sqlx::Sqlite>,
+    filters: &'a RefereeFilters,
+) {
+    if let Some(name) = &filters.name {
+        query_builder
+            .push(" AND r.name LIKE '%' || ")
+            .push_bind(name)
+            .push(" || '%'");
+    }
+
+    if let Some(country_id) = filters.country_id {
+        query_builder
+            .push(" AND r.country_id = ")
+            .push_bind(country_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn create_test_referee(pool: &SqlitePool, name: &str) -> i64 {
+        create_referee(
+            pool,
+            CreateRefereeEntity {
+                name: name.to_string(),
+                country_id: Some(34),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_referee_crud(pool: SqlitePool) {
+        let id = create_test_referee(&pool, "Anna Referee").await;
+
+        let referee = get_referee_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!(referee.name, "Anna Referee");
+        assert_eq!(referee.match_count, 0);
+
+        let updated = update_referee(
+            &pool,
+            id,
+            UpdateRefereeEntity {
+                name: "Anna Official".to_string(),
+                country_id: None,
+            },
+        )
+        .await
+        .unwrap();
+        assert!(updated);
+        let referee = get_referee_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!(referee.name, "Anna Official");
+        assert!(referee.country_id.is_none());
+
+        assert!(delete_referee(&pool, id).await.unwrap());
+        assert!(!delete_referee(&pool, id).await.unwrap());
+        assert!(get_referee_by_id(&pool, id).await.unwrap().is_none());
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_get_referees_filters(pool: SqlitePool) {
+        create_test_referee(&pool, "Anna Referee").await;
+        create_test_referee(&pool, "Bob Linesman").await;
+
+        let filters = RefereeFilters {
+            name: Some("bob".to_string()),
+            country_id: None,
+        };
+        let result = get_referees(&pool, &filters, &SortField::Name, &SortOrder::Asc, 1, 20)
+            .await
+            .unwrap();
+        assert_eq!(result.total, 1);
+        assert_eq!(result.items[0].name, "Bob Linesman");
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_match_officials_and_counts(pool: SqlitePool) {
+        let anna = create_test_referee(&pool, "Anna Referee").await;
+        let bob = create_test_referee(&pool, "Bob Linesman").await;
+
+        let mut match_ids = Vec::new();
+        for _ in 0..2 {
+            let id = sqlx::query(
+                "INSERT INTO match (season_id, home_team_id, away_team_id, status) VALUES (1, 1, 2, 'scheduled')",
+            )
+            .execute(&pool)
+            .await
+            .unwrap()
+            .last_insert_rowid();
+            match_ids.push(id);
+        }
+
+        set_match_officials(
+            &pool,
+            match_ids[0],
+            &[(anna, OfficialRole::Referee), (bob, OfficialRole::Linesman)],
+        )
+        .await
+        .unwrap();
+        set_match_officials(&pool, match_ids[1], &[(anna, OfficialRole::Referee)])
+            .await
+            .unwrap();
+
+        let officials = get_match_officials(&pool, match_ids[0]).await.unwrap();
+        assert_eq!(officials.len(), 2);
+        assert_eq!(officials[0].role(), Some(OfficialRole::Referee));
+
+        let result = get_referees(
+            &pool,
+            &RefereeFilters::default(),
+            &SortField::Matches,
+            &SortOrder::Desc,
+            1,
+            20,
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.items[0].id, anna);
+        assert_eq!(result.items[0].match_count, 2);
+        assert_eq!(result.items[1].match_count, 1);
+
+        // Replacing the officials drops the previous assignments
+        set_match_officials(&pool, match_ids[0], &[]).await.unwrap();
+        let bob_entity = get_referee_by_id(&pool, bob).await.unwrap().unwrap();
+        assert_eq!(bob_entity.match_count, 0);
+    }
+}
@@ -0,0 +1,258 @@
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+
+/// How long an emailed verification link works
+pub const VERIFICATION_TOKEN_TTL_HOURS: i64 = 24;
+
+/// Days after the last link expired before an unconfirmed registration is deleted
+pub const UNVERIFIED_RETENTION_DAYS: i64 = 7;
+
+/// Result of following a verification link
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationOutcome {
+    /// Email confirmed and the account is active
+    Activated,
+    /// Email confirmed, an admin still has to approve the account
+    AwaitingApproval,
+    /// The link is older than `VERIFICATION_TOKEN_TTL_HOURS`
+    Expired,
+    /// Unknown or already used link
+    Invalid,
+}
+
+/// Hex SHA-256 of a token, as stored in the database
+pub(crate) fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+/// Random token for an emailed link
+pub(crate) fn new_token() -> String {
+    format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    )
+}
+
+/// Create an inactive, unconfirmed editor account
+pub async fn create_registration(
+    db: &SqlitePool,
+    email: &str,
+    name: &str,
+    password_hash: &str,
+) -> Result<i64, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO users (email, name, password_hash, role, active, email_verified_at, registered_via_signup)
+        VALUES (?, ?, ?, 'editor', 0, NULL, 1)
+        "#,
+        email,
+        name,
+        password_hash
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// ID of the unconfirmed registration with this email, if any
+///
+/// Only sign-ups match; a deactivated account is never an unconfirmed registration.
+pub async fn get_unverified_user_id(
+    db: &SqlitePool,
+    email: &str,
+) -> Result<Option<i64>, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"
+        SELECT id as "id!" FROM users
+        WHERE email = ? COLLATE NOCASE AND registered_via_signup = 1
+          AND email_verified_at IS NULL AND active = 0
+        "#,
+        email
+    )
+    .fetch_optional(db)
+    .await
+}
+
+/// Issue a verification token for a user; earlier links of the user stop working
+pub async fn create_verification_token(
+    db: &SqlitePool,
+    user_id: i64,
+) -> Result<String, sqlx::Error> {
+    let token = new_token();
+    let token_hash = hash_token(&token);
+    let ttl = format!("+{} hours", VERIFICATION_TOKEN_TTL_HOURS);
+
+    let mut tx = db.begin().await?;
+    sqlx::query!(
+        "UPDATE email_verification_token SET used_at = CURRENT_TIMESTAMP WHERE user_id = ? AND used_at IS NULL",
+        user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        r#"
+        INSERT INTO email_verification_token (user_id, token_hash, expires_at)
+        VALUES (?, ?, datetime('now', ?))
+        "#,
+        user_id,
+        token_hash,
+        ttl
+    )
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok(token)
+}
+
+/// Confirm the email of the token's user
+///
+/// With `requires_approval` the account stays inactive and is flagged for an
+/// admin; otherwise it is activated right away. Each token works once.
+pub async fn verify_email(
+    db: &SqlitePool,
+    token: &str,
+    requires_approval: bool,
+) -> Result<VerificationOutcome, sqlx::Error> {
+    let token_hash = hash_token(token);
+    let mut tx = db.begin().await?;
+
+    let row = sqlx::query!(
+        r#"
+        SELECT id as "id!", user_id, expires_at < datetime('now') as "expired!: bool"
+        FROM email_verification_token
+        WHERE token_hash = ? AND used_at IS NULL
+        "#,
+        token_hash
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    let Some(row) = row else {
+        return Ok(VerificationOutcome::Invalid);
+    };
+    if row.expired {
+        return Ok(VerificationOutcome::Expired);
+    }
+
+    sqlx::query!(
+        "UPDATE email_verification_token SET used_at = CURRENT_TIMESTAMP WHERE id = ?",
+        row.id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        r#"
+        UPDATE users
+        SET email_verified_at = CURRENT_TIMESTAMP,
+            active = NOT ?1,
+            approval_pending = ?1,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?2
+        "#,
+        requires_approval,
+        row.user_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok(if requires_approval {
+        VerificationOutcome::AwaitingApproval
+    } else {
+        VerificationOutcome::Activated
+    })
+}
+
+/// Delete registrations whose links all expired `UNVERIFIED_RETENTION_DAYS` ago
+///
+/// Frees the email address for a new sign-up; returns the number of deleted users.
+pub async fn purge_unverified_registrations(db: &SqlitePool) -> Result<u64, sqlx::Error> {
+    let retention = format!("-{} days", UNVERIFIED_RETENTION_DAYS);
+    let result = sqlx::query!(
+        r#"
+        DELETE FROM users
+        WHERE registered_via_signup = 1 AND email_verified_at IS NULL AND active = 0
+          AND id IN (
+            SELECT user_id FROM email_verification_token
+            GROUP BY user_id
+            HAVING MAX(expires_at) < datetime('now', ?)
+          )
+        "#,
+        retention
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::users;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_verify_email(pool: SqlitePool) {
+        let id = create_registration(&pool, "fan@x.cz", "Fan", "hash")
+            .await
+            .unwrap();
+        assert_eq!(
+            get_unverified_user_id(&pool, "FAN@x.cz").await.unwrap(),
+            Some(id)
+        );
+        let user = users::get_user_by_id(&pool, id).await.unwrap().unwrap();
+        assert!(!user.active && user.email_unverified);
+
+        // A new link replaces the previous one
+        let old = create_verification_token(&pool, id).await.unwrap();
+        let token = create_verification_token(&pool, id).await.unwrap();
+        assert_eq!(
+            verify_email(&pool, &old, false).await.unwrap(),
+            VerificationOutcome::Invalid
+        );
+
+        assert_eq!(
+            verify_email(&pool, &token, true).await.unwrap(),
+            VerificationOutcome::AwaitingApproval
+        );
+        let user = users::get_user_by_id(&pool, id).await.unwrap().unwrap();
+        assert!(!user.active && !user.email_unverified && user.approval_pending);
+        assert_eq!(
+            get_unverified_user_id(&pool, "fan@x.cz").await.unwrap(),
+            None
+        );
+
+        // Links work once
+        assert_eq!(
+            verify_email(&pool, &token, true).await.unwrap(),
+            VerificationOutcome::Invalid
+        );
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_expired_registrations(pool: SqlitePool) {
+        let id = create_registration(&pool, "late@x.cz", "Late", "hash")
+            .await
+            .unwrap();
+        let token = create_verification_token(&pool, id).await.unwrap();
+        sqlx::query("UPDATE email_verification_token SET expires_at = datetime('now', '-1 hours')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            verify_email(&pool, &token, false).await.unwrap(),
+            VerificationOutcome::Expired
+        );
+        assert_eq!(purge_unverified_registrations(&pool).await.unwrap(), 0);
+
+        sqlx::query("UPDATE email_verification_token SET expires_at = datetime('now', '-8 days')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(purge_unverified_registrations(&pool).await.unwrap(), 1);
+        assert!(users::get_user_by_id(&pool, id).await.unwrap().is_none());
+    }
+}
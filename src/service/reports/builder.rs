@@ -0,0 +1,620 @@
+//! Custom reports composed from a whitelisted set of fields
+//!
+//! A report picks a source (goals, matches or roster contracts), the
+//! dimensions to group by and the measures to compute. Only the SQL
+//! fragments listed here ever reach the query; user input is limited to
+//! field keys, which are looked up in these lists, and filter values, which
+//! are bound as parameters.
+
+use serde::{Deserialize, Serialize};
+use sqlx::{QueryBuilder, Row, Sqlite, SqlitePool};
+
+use crate::common::csv_export::CsvSink;
+
+/// Most rows a report returns; anything beyond is cut off
+pub const MAX_REPORT_ROWS: i64 = 5000;
+
+/// A dimension or measure a report can select
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReportField {
+    pub key: &'static str,
+    sql: &'static str,
+}
+
+const SEASON_NAME: &str = "COALESCE(s.display_name, e.name || ' ' || s.year)";
+
+const GOAL_DIMENSIONS: &[ReportField] = &[
+    ReportField {
+        key: "season",
+        sql: SEASON_NAME,
+    },
+    ReportField {
+        key: "event",
+        sql: "e.name",
+    },
+    ReportField {
+        key: "team",
+        sql: "t.name",
+    },
+    ReportField {
+        key: "scorer",
+        sql: "p.name",
+    },
+    ReportField {
+        key: "period",
+        sql: "se.period",
+    },
+    ReportField {
+        key: "goal_type",
+        sql: "se.goal_type",
+    },
+    ReportField {
+        key: "game_type",
+        sql: "m.game_type",
+    },
+    ReportField {
+        key: "month",
+        sql: "strftime('%Y-%m', m.match_date)",
+    },
+];
+
+const GOAL_MEASURES: &[ReportField] = &[
+    ReportField {
+        key: "goals",
+        sql: "COUNT(*)",
+    },
+    ReportField {
+        key: "matches",
+        sql: "COUNT(DISTINCT se.match_id)",
+    },
+    ReportField {
+        key: "scorers",
+        sql: "COUNT(DISTINCT se.scorer_id)",
+    },
+    ReportField {
+        key: "assisted_goals",
+        sql: "SUM(se.assist1_id IS NOT NULL)",
+    },
+];
+
+const MATCH_DIMENSIONS: &[ReportField] = &[
+    ReportField {
+        key: "season",
+        sql: SEASON_NAME,
+    },
+    ReportField {
+        key: "event",
+        sql: "e.name",
+    },
+    ReportField {
+        key: "home_team",
+        sql: "ht.name",
+    },
+    ReportField {
+        key: "away_team",
+        sql: "at.name",
+    },
+    ReportField {
+        key: "arena",
+        sql: "a.name",
+    },
+    ReportField {
+        key: "status",
+        sql: "m.status",
+    },
+    ReportField {
+        key: "game_type",
+        sql: "m.game_type",
+    },
+    ReportField {
+        key: "month",
+        sql: "strftime('%Y-%m', m.match_date)",
+    },
+];
+
+const MATCH_MEASURES: &[ReportField] = &[
+    ReportField { key: "matches", sql: "COUNT(*)" },
+    ReportField {
+        key: "goals",
+        sql: "SUM((SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id) + m.home_score_unidentified + m.away_score_unidentified)",
+    },
+    ReportField { key: "total_attendance", sql: "SUM(m.attendance)" },
+    ReportField { key: "avg_attendance", sql: "ROUND(AVG(m.attendance), 1)" },
+];
+
+const ROSTER_DIMENSIONS: &[ReportField] = &[
+    ReportField {
+        key: "season",
+        sql: SEASON_NAME,
+    },
+    ReportField {
+        key: "event",
+        sql: "e.name",
+    },
+    ReportField {
+        key: "team",
+        sql: "t.name",
+    },
+    ReportField {
+        key: "position",
+        sql: "p.position",
+    },
+    ReportField {
+        key: "country",
+        sql: "c.name",
+    },
+];
+
+const ROSTER_MEASURES: &[ReportField] = &[
+    ReportField {
+        key: "players",
+        sql: "COUNT(DISTINCT pc.player_id)",
+    },
+    ReportField {
+        key: "contracts",
+        sql: "COUNT(*)",
+    },
+];
+
+/// What a report counts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportSource {
+    /// One row per recorded score event
+    #[default]
+    Goals,
+    /// One row per match
+    Matches,
+    /// One row per player contract
+    Rosters,
+}
+
+impl ReportSource {
+    pub const ALL: [ReportSource; 3] = [
+        ReportSource::Goals,
+        ReportSource::Matches,
+        ReportSource::Rosters,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportSource::Goals => "goals",
+            ReportSource::Matches => "matches",
+            ReportSource::Rosters => "rosters",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|source| source.as_str() == s)
+    }
+
+    pub fn dimensions(&self) -> &'static [ReportField] {
+        match self {
+            ReportSource::Goals => GOAL_DIMENSIONS,
+            ReportSource::Matches => MATCH_DIMENSIONS,
+            ReportSource::Rosters => ROSTER_DIMENSIONS,
+        }
+    }
+
+    pub fn measures(&self) -> &'static [ReportField] {
+        match self {
+            ReportSource::Goals => GOAL_MEASURES,
+            ReportSource::Matches => MATCH_MEASURES,
+            ReportSource::Rosters => ROSTER_MEASURES,
+        }
+    }
+
+    pub fn dimension(&self, key: &str) -> Option<&'static ReportField> {
+        self.dimensions().iter().find(|f| f.key == key)
+    }
+
+    pub fn measure(&self, key: &str) -> Option<&'static ReportField> {
+        self.measures().iter().find(|f| f.key == key)
+    }
+
+    /// Whether game type and date filters apply (roster contracts have neither)
+    pub fn has_match_filters(&self) -> bool {
+        !matches!(self, ReportSource::Rosters)
+    }
+
+    fn tables_sql(&self) -> &'static str {
+        match self {
+            ReportSource::Goals => {
+                " FROM score_event se
+                INNER JOIN match m ON se.match_id = m.id
+                INNER JOIN season s ON m.season_id = s.id
+                INNER JOIN event e ON s.event_id = e.id
+                INNER JOIN team t ON se.team_id = t.id
+                LEFT JOIN player p ON se.scorer_id = p.id"
+            }
+            ReportSource::Matches => {
+                " FROM match m
+                INNER JOIN season s ON m.season_id = s.id
+                INNER JOIN event e ON s.event_id = e.id
+                INNER JOIN team ht ON m.home_team_id = ht.id
+                INNER JOIN team at ON m.away_team_id = at.id
+                LEFT JOIN arena a ON m.arena_id = a.id"
+            }
+            ReportSource::Rosters => {
+                " FROM player_contract pc
+                INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+                INNER JOIN season s ON tp.season_id = s.id
+                INNER JOIN event e ON s.event_id = e.id
+                INNER JOIN team t ON tp.team_id = t.id
+                INNER JOIN player p ON pc.player_id = p.id
+                LEFT JOIN country c ON p.country_id = c.id"
+            }
+        }
+    }
+
+    fn season_column(&self) -> &'static str {
+        match self {
+            ReportSource::Goals | ReportSource::Matches => "m.season_id",
+            ReportSource::Rosters => "tp.season_id",
+        }
+    }
+
+    /// Team columns, any of which must equal the team filter
+    fn team_columns(&self) -> &'static str {
+        match self {
+            ReportSource::Goals => "se.team_id",
+            ReportSource::Matches => "m.home_team_id, m.away_team_id",
+            ReportSource::Rosters => "tp.team_id",
+        }
+    }
+}
+
+/// Values the report rows are narrowed down by
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportFilters {
+    #[serde(default)]
+    pub season_id: Option<i64>,
+    #[serde(default)]
+    pub team_id: Option<i64>,
+    #[serde(default)]
+    pub game_type: Option<String>,
+    /// Earliest match date (YYYY-MM-DD), inclusive
+    #[serde(default)]
+    pub date_from: Option<String>,
+    /// Latest match date (YYYY-MM-DD), inclusive
+    #[serde(default)]
+    pub date_to: Option<String>,
+}
+
+/// Everything that makes up a report, as stored for saved reports
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportDefinition {
+    pub source: ReportSource,
+    #[serde(default)]
+    pub dimensions: Vec<String>,
+    #[serde(default)]
+    pub measures: Vec<String>,
+    #[serde(default)]
+    pub filters: ReportFilters,
+    /// Key of a selected field to sort by; dimensions sort ascending,
+    /// measures descending. Defaults to the dimensions in order.
+    #[serde(default)]
+    pub sort: Option<String>,
+}
+
+impl ReportDefinition {
+    /// Whether any dimension or measure is selected
+    pub fn has_columns(&self) -> bool {
+        !self.dimensions.is_empty() || !self.measures.is_empty()
+    }
+
+    /// The definition as `(name, value)` form fields, as read back by the
+    /// report builder (repeated `dimension` and `measure` fields)
+    pub fn form_fields(&self) -> Vec<(&'static str, String)> {
+        let mut fields = vec![("source", self.source.as_str().to_string())];
+        fields.extend(self.dimensions.iter().map(|d| ("dimension", d.clone())));
+        fields.extend(self.measures.iter().map(|m| ("measure", m.clone())));
+        let filters = &self.filters;
+        fields.extend(filters.season_id.map(|id| ("season_id", id.to_string())));
+        fields.extend(filters.team_id.map(|id| ("team_id", id.to_string())));
+        fields.extend(filters.game_type.clone().map(|g| ("game_type", g)));
+        fields.extend(filters.date_from.clone().map(|d| ("date_from", d)));
+        fields.extend(filters.date_to.clone().map(|d| ("date_to", d)));
+        fields.extend(self.sort.clone().map(|s| ("sort", s)));
+        fields
+    }
+
+    /// Read a definition from form or query fields; unknown names are ignored
+    pub fn from_form_fields(fields: &[(String, String)]) -> Self {
+        let mut definition = ReportDefinition::default();
+        for (name, value) in fields {
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            let filters = &mut definition.filters;
+            match name.as_str() {
+                "source" => definition.source = ReportSource::from_str(value).unwrap_or_default(),
+                "dimension" => definition.dimensions.push(value.to_string()),
+                "measure" => definition.measures.push(value.to_string()),
+                "season_id" => filters.season_id = value.parse().ok(),
+                "team_id" => filters.team_id = value.parse().ok(),
+                "game_type" => filters.game_type = Some(value.to_string()),
+                "date_from" => filters.date_from = Some(value.to_string()),
+                "date_to" => filters.date_to = Some(value.to_string()),
+                "sort" => definition.sort = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        definition
+    }
+
+    /// URL query string of the definition
+    pub fn query_string(&self) -> String {
+        self.form_fields()
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, urlencoding::encode(value)))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+}
+
+/// Output of a report: one column per selected dimension, then measure
+#[derive(Debug, Clone, Default)]
+pub struct ReportResult {
+    /// Field keys in column order
+    pub columns: Vec<&'static str>,
+    pub rows: Vec<Vec<Option<String>>>,
+    /// Whether rows beyond the limit were cut off
+    pub truncated: bool,
+}
+
+impl ReportResult {
+    /// Write as CSV with the given header labels
+    pub async fn write_csv(&self, headers: &[String], sink: &mut CsvSink) {
+        if !sink.write(headers).await {
+            return;
+        }
+        for row in &self.rows {
+            if !sink
+                .write(row.iter().map(|v| v.as_deref().unwrap_or("")))
+                .await
+            {
+                return;
+            }
+        }
+    }
+}
+
+/// Run a report, returning at most `limit` rows
+///
+/// Field keys not whitelisted for the source are skipped; validate the
+/// definition first to report them instead.
+pub async fn run_report(
+    db: &SqlitePool,
+    definition: &ReportDefinition,
+    limit: i64,
+) -> Result<ReportResult, sqlx::Error> {
+    let source = definition.source;
+    let dimensions: Vec<&ReportField> = definition
+        .dimensions
+        .iter()
+        .filter_map(|key| source.dimension(key))
+        .collect();
+    let measures: Vec<&ReportField> = definition
+        .measures
+        .iter()
+        .filter_map(|key| source.measure(key))
+        .collect();
+    if dimensions.is_empty() && measures.is_empty() {
+        return Ok(ReportResult::default());
+    }
+
+    let mut query: QueryBuilder<Sqlite> = QueryBuilder::new("SELECT ");
+    {
+        let mut select = query.separated(", ");
+        for field in dimensions.iter().chain(measures.iter()) {
+            select.push(format!("CAST({} AS TEXT)", field.sql));
+        }
+    }
+    query.push(source.tables_sql());
+    push_filters(&mut query, source, &definition.filters);
+
+    if !dimensions.is_empty() {
+        query.push(" GROUP BY ");
+        let mut group = query.separated(", ");
+        for field in &dimensions {
+            group.push(field.sql);
+        }
+    }
+
+    let sort = definition.sort.as_deref();
+    let order_by: Vec<String> =
+        if let Some(field) = sort.and_then(|key| dimensions.iter().find(|f| f.key == key)) {
+            vec![format!("{} ASC", field.sql)]
+        } else if let Some(field) = sort.and_then(|key| measures.iter().find(|f| f.key == key)) {
+            vec![format!("{} DESC", field.sql)]
+        } else {
+            dimensions
+                .iter()
+                .map(|f| format!("{} ASC", f.sql))
+                .collect()
+        };
+    if !order_by.is_empty() {
+        query.push(" ORDER BY ");
+        query.push(order_by.join(", "));
+    }
+
+    query.push(" LIMIT ");
+    query.push_bind(limit + 1);
+
+    let mut rows = query.build().fetch_all(db).await?;
+    let truncated = rows.len() as i64 > limit;
+    rows.truncate(limit as usize);
+
+    let width = dimensions.len() + measures.len();
+    let rows = rows
+        .iter()
+        .map(|row| {
+            (0..width)
+                .map(|i| row.try_get::<Option<String>, _>(i))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ReportResult {
+        columns: dimensions
+            .iter()
+            .chain(measures.iter())
+            .map(|f| f.key)
+            .collect(),
+        rows,
+        truncated,
+    })
+}
+
+fn push_filters(query: &mut QueryBuilder<Sqlite>, source: ReportSource, filters: &ReportFilters) {
+    query.push(" WHERE 1 = 1");
+    if let Some(season_id) = filters.season_id {
+        query.push(format!(" AND {} = ", source.season_column()));
+        query.push_bind(season_id);
+    }
+    if let Some(team_id) = filters.team_id {
+        query.push(" AND ");
+        query.push_bind(team_id);
+        query.push(format!(" IN ({})", source.team_columns()));
+    }
+    if source.has_match_filters() {
+        if let Some(game_type) = &filters.game_type {
+            query.push(" AND m.game_type = ");
+            query.push_bind(game_type.clone());
+        }
+        if let Some(date_from) = &filters.date_from {
+            query.push(" AND date(m.match_date) >= ");
+            query.push_bind(date_from.clone());
+        }
+        if let Some(date_to) = &filters.date_to {
+            query.push(" AND date(m.match_date) <= ");
+            query.push_bind(date_to.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn insert_goals(pool: &SqlitePool) {
+        sqlx::query(
+            "INSERT INTO match (id, season_id, home_team_id, away_team_id, match_date, attendance, game_type)
+                VALUES (1, 1, 1, 2, '2022-02-10', 1000, 'group'),
+                       (2, 1, 2, 1, '2022-02-12', 3000, 'playoff');
+             INSERT INTO score_event (match_id, team_id, scorer_id, assist1_id, period, goal_type) VALUES
+                (1, 1, 1, 2, 1, 'power_play'), (1, 1, 1, NULL, 2, NULL),
+                (1, 2, 3, NULL, 3, NULL), (2, 1, 2, 1, 1, NULL);",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_run_report(pool: SqlitePool) {
+        insert_goals(&pool).await;
+
+        let definition = ReportDefinition {
+            source: ReportSource::Goals,
+            dimensions: vec!["team".to_string(), "injected; DROP TABLE match".to_string()],
+            measures: vec!["goals".to_string(), "matches".to_string()],
+            sort: Some("goals".to_string()),
+            ..Default::default()
+        };
+        let result = run_report(&pool, &definition, 100).await.unwrap();
+        assert_eq!(result.columns, vec!["team", "goals", "matches"]);
+        assert_eq!(
+            result.rows,
+            vec![
+                vec![
+                    Some("Team Canada".into()),
+                    Some("3".into()),
+                    Some("2".into())
+                ],
+                vec![Some("Team USA".into()), Some("1".into()), Some("1".into())],
+            ]
+        );
+
+        // Filters are bound; the team filter matches either side of a match
+        let definition = ReportDefinition {
+            source: ReportSource::Matches,
+            measures: vec!["matches".to_string(), "avg_attendance".to_string()],
+            filters: ReportFilters {
+                team_id: Some(1),
+                game_type: Some("group".to_string()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let result = run_report(&pool, &definition, 100).await.unwrap();
+        assert_eq!(
+            result.rows,
+            vec![vec![Some("1".into()), Some("1000.0".into())]]
+        );
+
+        let definition = ReportDefinition {
+            source: ReportSource::Goals,
+            dimensions: vec!["period".to_string()],
+            ..Default::default()
+        };
+        let result = run_report(&pool, &definition, 2).await.unwrap();
+        assert_eq!(result.rows.len(), 2);
+        assert!(result.truncated);
+    }
+
+    #[test]
+    fn test_form_fields_round_trip() {
+        let definition = ReportDefinition {
+            source: ReportSource::Matches,
+            dimensions: vec!["season".to_string()],
+            measures: vec!["goals".to_string()],
+            filters: ReportFilters {
+                date_from: Some("2024-01-01".to_string()),
+                ..Default::default()
+            },
+            sort: None,
+        };
+        let fields: Vec<(String, String)> = definition
+            .form_fields()
+            .into_iter()
+            .map(|(name, value)| (name.to_string(), value))
+            .collect();
+        assert_eq!(ReportDefinition::from_form_fields(&fields), definition);
+        assert_eq!(
+            definition.query_string(),
+            "source=matches&dimension=season&measure=goals&date_from=2024-01-01"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_write_csv_escapes_values() {
+        let result = ReportResult {
+            columns: vec!["team", "goals"],
+            rows: vec![
+                vec![Some("Team \"A\", B".to_string()), None],
+                vec![
+                    Some("=HYPERLINK(\"x\")".to_string()),
+                    Some("-1".to_string()),
+                ],
+            ],
+            truncated: false,
+        };
+        let csv = crate::common::csv_export::collect_csv(|mut sink| async move {
+            result
+                .write_csv(&["Team".to_string(), "Goals".to_string()], &mut sink)
+                .await;
+            Ok(sink)
+        })
+        .await;
+        assert_eq!(
+            csv,
+            "Team,Goals\n\"Team \"\"A\"\", B\",\n\"'=HYPERLINK(\"\"x\"\")\",-1\n"
+        );
+    }
+}
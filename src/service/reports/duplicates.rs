@@ -0,0 +1,354 @@
+//! Probable duplicate players, teams and arenas
+//!
+//! Names are compared by the Jaccard similarity of their trigrams after
+//! lowercasing, transliterating accented letters and sorting the words, so
+//! "Jágr Jaromír" and "Jaromir Jagr" are an exact match and small spelling
+//! differences still score high. Records that are known to differ are never
+//! paired: players born in different years, teams of different countries and
+//! arenas in different cities.
+
+use std::collections::{HashMap, HashSet};
+
+use sqlx::{Row, SqlitePool};
+
+use crate::service::slugs::transliterate;
+
+/// Lowest name similarity (0-1) reported as a probable duplicate
+pub const SIMILARITY_THRESHOLD: f64 = 0.5;
+
+/// Most pairs listed per kind of record
+pub const MAX_PAIRS: usize = 200;
+
+/// One side of a probable duplicate
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicateRecord {
+    pub id: i64,
+    pub name: String,
+    /// Birth year of a player, short name of a team or city of an arena
+    pub detail: Option<String>,
+    pub country_name: Option<String>,
+}
+
+/// Two records whose names are similar enough to be the same
+#[derive(Debug, Clone, PartialEq)]
+pub struct DuplicatePair {
+    pub first: DuplicateRecord,
+    pub second: DuplicateRecord,
+    /// Trigram similarity of the names, between the threshold and 1
+    pub similarity: f64,
+    /// Names are the same apart from case, accents, punctuation and word order
+    pub exact: bool,
+}
+
+/// Probable duplicates of every kind, most similar first
+#[derive(Debug, Clone, Default)]
+pub struct DuplicateReport {
+    pub players: Vec<DuplicatePair>,
+    pub teams: Vec<DuplicatePair>,
+    pub arenas: Vec<DuplicatePair>,
+}
+
+impl DuplicateReport {
+    pub fn total(&self) -> usize {
+        self.players.len() + self.teams.len() + self.arenas.len()
+    }
+}
+
+/// Record prepared for comparison
+struct Candidate {
+    record: DuplicateRecord,
+    normalized: String,
+    trigrams: HashSet<String>,
+    /// Records with different known blocks are never paired
+    block: Option<String>,
+}
+
+/// Lowercase ASCII words of a name in alphabetical order
+pub fn normalize_name(name: &str) -> String {
+    let mut folded = String::with_capacity(name.len());
+    for c in name.to_lowercase().chars() {
+        match c {
+            'a'..='z' | '0'..='9' => folded.push(c),
+            _ => match transliterate(c) {
+                Some(ascii) => folded.push_str(ascii),
+                None => folded.push(' '),
+            },
+        }
+    }
+
+    let mut words: Vec<&str> = folded.split_whitespace().collect();
+    words.sort_unstable();
+    words.join(" ")
+}
+
+/// Trigrams of a normalized name, each word padded like PostgreSQL's pg_trgm
+fn trigrams(normalized: &str) -> HashSet<String> {
+    let mut trigrams = HashSet::new();
+    for word in normalized.split_whitespace() {
+        let padded: Vec<char> = format!("  {} ", word).chars().collect();
+        for window in padded.windows(3) {
+            trigrams.insert(window.iter().collect());
+        }
+    }
+    trigrams
+}
+
+/// Trigram similarity of two names, between 0 and 1
+pub fn name_similarity(a: &str, b: &str) -> f64 {
+    let (a, b) = (trigrams(&normalize_name(a)), trigrams(&normalize_name(b)));
+    jaccard(a.intersection(&b).count(), a.len(), b.len())
+}
+
+fn jaccard(shared: usize, a: usize, b: usize) -> f64 {
+    let union = a + b - shared;
+    if union == 0 {
+        0.0
+    } else {
+        shared as f64 / union as f64
+    }
+}
+
+fn candidate(record: DuplicateRecord, block: Option<String>) -> Candidate {
+    let normalized = normalize_name(&record.name);
+    Candidate {
+        trigrams: trigrams(&normalized),
+        normalized,
+        record,
+        block,
+    }
+}
+
+/// Pairs of candidates at or above the similarity threshold
+///
+/// Only candidates sharing at least one trigram are compared, which keeps
+/// the work far below comparing every pair.
+fn find_pairs(candidates: &[Candidate]) -> Vec<DuplicatePair> {
+    let mut postings: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (index, candidate) in candidates.iter().enumerate() {
+        for trigram in &candidate.trigrams {
+            postings.entry(trigram.as_str()).or_default().push(index);
+        }
+    }
+
+    let mut pairs = Vec::new();
+    for (index, candidate) in candidates.iter().enumerate() {
+        let mut shared: HashMap<usize, usize> = HashMap::new();
+        for trigram in &candidate.trigrams {
+            for &other in &postings[trigram.as_str()] {
+                if other > index {
+                    *shared.entry(other).or_default() += 1;
+                }
+            }
+        }
+
+        for (other, count) in shared {
+            let other_candidate = &candidates[other];
+            if let (Some(a), Some(b)) = (&candidate.block, &other_candidate.block) {
+                if a != b {
+                    continue;
+                }
+            }
+            let similarity = jaccard(
+                count,
+                candidate.trigrams.len(),
+                other_candidate.trigrams.len(),
+            );
+            if similarity >= SIMILARITY_THRESHOLD {
+                pairs.push(DuplicatePair {
+                    first: candidate.record.clone(),
+                    second: other_candidate.record.clone(),
+                    similarity,
+                    exact: candidate.normalized == other_candidate.normalized,
+                });
+            }
+        }
+    }
+
+    pairs.sort_by(|a, b| {
+        b.exact
+            .cmp(&a.exact)
+            .then_with(|| b.similarity.total_cmp(&a.similarity))
+            .then_with(|| a.first.name.cmp(&b.first.name))
+            .then_with(|| a.first.id.cmp(&b.first.id))
+            .then_with(|| a.second.id.cmp(&b.second.id))
+    });
+    pairs.truncate(MAX_PAIRS);
+    pairs
+}
+
+async fn player_candidates(db: &SqlitePool) -> Result<Vec<Candidate>, sqlx::Error> {
+    let rows = sqlx::query(
+        r#"
+        SELECT p.id, p.name, substr(p.birth_date, 1, 4) as birth_year, c.name as country_name
+        FROM player p
+        LEFT JOIN country c ON c.id = p.country_id
+        "#,
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let birth_year: Option<String> = row.get("birth_year");
+            candidate(
+                DuplicateRecord {
+                    id: row.get("id"),
+                    name: row.get("name"),
+                    detail: birth_year.clone(),
+                    country_name: row.get("country_name"),
+                },
+                birth_year,
+            )
+        })
+        .collect())
+}
+
+async fn team_candidates(db: &SqlitePool) -> Result<Vec<Candidate>, sqlx::Error> {
+    let rows = sqlx::query(
+        r#"
+        SELECT t.id, t.name, t.short_name, t.country_id, c.name as country_name
+        FROM team t
+        LEFT JOIN country c ON c.id = t.country_id
+        "#,
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let country_id: Option<i64> = row.get("country_id");
+            candidate(
+                DuplicateRecord {
+                    id: row.get("id"),
+                    name: row.get("name"),
+                    detail: row.get("short_name"),
+                    country_name: row.get("country_name"),
+                },
+                country_id.map(|id| id.to_string()),
+            )
+        })
+        .collect())
+}
+
+async fn arena_candidates(db: &SqlitePool) -> Result<Vec<Candidate>, sqlx::Error> {
+    let rows = sqlx::query(
+        r#"
+        SELECT a.id, a.name, a.city, c.name as country_name
+        FROM arena a
+        LEFT JOIN country c ON c.id = a.country_id
+        "#,
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let city: Option<String> = row.get("city");
+            let block = city
+                .as_deref()
+                .map(normalize_name)
+                .filter(|city| !city.is_empty());
+            candidate(
+                DuplicateRecord {
+                    id: row.get("id"),
+                    name: row.get("name"),
+                    detail: city,
+                    country_name: row.get("country_name"),
+                },
+                block,
+            )
+        })
+        .collect())
+}
+
+/// Probable duplicate players, teams and arenas
+pub async fn get_duplicate_report(db: &SqlitePool) -> Result<DuplicateReport, sqlx::Error> {
+    Ok(DuplicateReport {
+        players: find_pairs(&player_candidates(db).await?),
+        teams: find_pairs(&team_candidates(db).await?),
+        arenas: find_pairs(&arena_candidates(db).await?),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("Jágr, Jaromír"), "jagr jaromir");
+        assert_eq!(normalize_name("Jaromir JAGR"), "jagr jaromir");
+        assert_eq!(normalize_name("HC Škoda Plzeň"), "hc plzen skoda");
+    }
+
+    #[test]
+    fn test_name_similarity() {
+        assert_eq!(name_similarity("Dominik Hašek", "Hasek Dominik"), 1.0);
+        assert!(name_similarity("Petr Novák", "Petr Nowak") >= SIMILARITY_THRESHOLD);
+        assert!(name_similarity("Petr Novák", "Pavel Datsyuk") < SIMILARITY_THRESHOLD);
+        assert_eq!(name_similarity("", ""), 0.0);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_duplicate_report(pool: SqlitePool) {
+        sqlx::query(
+            "INSERT INTO player (id, name, country_id, birth_date) VALUES
+                (101, 'McDavid Connor', 34, '1997-05-01'),
+                (102, 'Wayne Gretzky', 34, '1962-01-26'),
+                (103, 'Sidney Crosby', 34, NULL)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO team (id, name, country_id) VALUES
+                (101, 'Team Canáda', 34),
+                (102, 'Team Canada', 187)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO arena (id, name, city) VALUES
+                (1, 'O2 Arena', 'Praha'),
+                (2, 'O2 arena', 'Praha'),
+                (3, 'O2 Arena', 'London')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let report = get_duplicate_report(&pool).await.unwrap();
+
+        // Gretzky is born a year apart, the undated Crosby can still be a duplicate
+        let players: Vec<(i64, i64)> = report
+            .players
+            .iter()
+            .map(|p| (p.first.id, p.second.id))
+            .collect();
+        assert_eq!(players, vec![(1, 101), (8, 103)]);
+        assert!(report.players.iter().all(|p| p.exact));
+
+        // Same name in another country is a different team
+        let teams: Vec<(i64, i64)> = report
+            .teams
+            .iter()
+            .map(|p| (p.first.id, p.second.id))
+            .collect();
+        assert_eq!(teams, vec![(1, 101)]);
+
+        let arenas: Vec<(i64, i64)> = report
+            .arenas
+            .iter()
+            .map(|p| (p.first.id, p.second.id))
+            .collect();
+        assert_eq!(arenas, vec![(1, 2)]);
+        assert_eq!(report.total(), 4);
+    }
+}
@@ -0,0 +1,12 @@
+// Reports service module
+mod builder;
+mod duplicates;
+mod saved;
+mod stats_reconciliation;
+mod unidentified_goals;
+
+pub use self::builder::*;
+pub use self::duplicates::*;
+pub use self::saved::*;
+pub use self::stats_reconciliation::*;
+pub use self::unidentified_goals::*;
@@ -0,0 +1,126 @@
+use sqlx::SqlitePool;
+
+use super::builder::ReportDefinition;
+
+/// A report saved from the report builder
+#[derive(Debug, Clone)]
+pub struct SavedReportEntity {
+    pub id: i64,
+    pub name: String,
+    pub definition: ReportDefinition,
+    pub created_at: String,
+}
+
+struct SavedReportRow {
+    id: i64,
+    name: String,
+    definition: String,
+    created_at: String,
+}
+
+impl TryFrom<SavedReportRow> for SavedReportEntity {
+    type Error = sqlx::Error;
+
+    fn try_from(row: SavedReportRow) -> Result<Self, Self::Error> {
+        let definition =
+            serde_json::from_str(&row.definition).map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        Ok(SavedReportEntity {
+            id: row.id,
+            name: row.name,
+            definition,
+            created_at: row.created_at,
+        })
+    }
+}
+
+/// Get all saved reports, by name
+pub async fn get_saved_reports(db: &SqlitePool) -> Result<Vec<SavedReportEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        SavedReportRow,
+        r#"
+        SELECT id as "id!", name, definition, created_at
+        FROM saved_report
+        ORDER BY name COLLATE NOCASE
+        "#
+    )
+    .fetch_all(db)
+    .await?;
+
+    rows.into_iter().map(SavedReportEntity::try_from).collect()
+}
+
+/// Get a saved report by ID
+pub async fn get_saved_report(
+    db: &SqlitePool,
+    id: i64,
+) -> Result<Option<SavedReportEntity>, sqlx::Error> {
+    let row = sqlx::query_as!(
+        SavedReportRow,
+        r#"
+        SELECT id as "id!", name, definition, created_at
+        FROM saved_report
+        WHERE id = ?
+        "#,
+        id
+    )
+    .fetch_optional(db)
+    .await?;
+
+    row.map(SavedReportEntity::try_from).transpose()
+}
+
+/// Save a report definition under a name
+pub async fn create_saved_report(
+    db: &SqlitePool,
+    name: &str,
+    definition: &ReportDefinition,
+) -> Result<i64, sqlx::Error> {
+    let definition =
+        serde_json::to_string(definition).map_err(|e| sqlx::Error::Encode(Box::new(e)))?;
+
+    let result = sqlx::query!(
+        "INSERT INTO saved_report (name, definition) VALUES (?, ?)",
+        name,
+        definition
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Delete a saved report, returning whether it existed
+pub async fn delete_saved_report(db: &SqlitePool, id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!("DELETE FROM saved_report WHERE id = ?", id)
+        .execute(db)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::reports::ReportSource;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_saved_reports(pool: SqlitePool) {
+        let definition = ReportDefinition {
+            source: ReportSource::Rosters,
+            dimensions: vec!["team".to_string()],
+            measures: vec!["players".to_string()],
+            ..Default::default()
+        };
+        let id = create_saved_report(&pool, "Roster sizes", &definition)
+            .await
+            .unwrap();
+
+        let saved = get_saved_report(&pool, id).await.unwrap().unwrap();
+        assert_eq!(saved.name, "Roster sizes");
+        assert_eq!(saved.definition, definition);
+        assert_eq!(get_saved_reports(&pool).await.unwrap().len(), 1);
+
+        assert!(delete_saved_report(&pool, id).await.unwrap());
+        assert!(get_saved_report(&pool, id).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+
+use sqlx::{Row, SqlitePool};
+
+/// Comparison of a player's manually entered event stats with the totals
+/// derived from recorded score events
+#[derive(Debug, Clone)]
+pub struct StatsReconciliationRow {
+    pub player_id: i64,
+    pub player_name: String,
+    pub event_id: i64,
+    pub event_name: String,
+    /// ID of the manual `player_event_stats` entry, if one exists
+    pub stats_id: Option<i64>,
+    pub manual_goals: Option<i32>,
+    pub manual_assists: Option<i32>,
+    pub derived_goals: i32,
+    pub derived_assists: i32,
+    /// Score-event-derived totals broken down by season
+    pub seasons: Vec<ReconciliationSeasonEntity>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ReconciliationSeasonEntity {
+    pub season_id: i64,
+    pub season_name: String,
+    pub goals: i32,
+    pub assists: i32,
+}
+
+/// Outcome of comparing manual and derived totals
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconciliationStatus {
+    /// Manual totals equal the derived totals
+    Matching,
+    /// Manual totals exceed derived ones; the gap is unidentified scoring
+    Unidentified,
+    /// Score events credit more than the manual totals allow
+    Conflict,
+    /// Score events exist but no manual entry was made
+    MissingManual,
+}
+
+impl StatsReconciliationRow {
+    pub fn status(&self) -> ReconciliationStatus {
+        match (self.manual_goals, self.manual_assists) {
+            (Some(goals), Some(assists)) => {
+                if self.derived_goals > goals || self.derived_assists > assists {
+                    ReconciliationStatus::Conflict
+                } else if self.derived_goals == goals && self.derived_assists == assists {
+                    ReconciliationStatus::Matching
+                } else {
+                    ReconciliationStatus::Unidentified
+                }
+            }
+            _ => ReconciliationStatus::MissingManual,
+        }
+    }
+
+    /// Whether the row needs attention (anything other than an exact match)
+    pub fn has_discrepancy(&self) -> bool {
+        self.status() != ReconciliationStatus::Matching
+    }
+}
+
+/// Build the stats reconciliation report
+///
+/// Rows are keyed by player and event, because manual stats are entered per
+/// event. Derived totals are aggregated from every season of that event.
+pub async fn get_stats_reconciliation(
+    db: &SqlitePool,
+    only_discrepancies: bool,
+) -> Result<Vec<StatsReconciliationRow>, sqlx::Error> {
+    let mut rows: HashMap<(i64, i64), StatsReconciliationRow> = HashMap::new();
+
+    let manual_rows = sqlx::query(
+        r#"
+        SELECT pes.id, pes.player_id, p.name as player_name, pes.event_id, e.name as event_name,
+               pes.goals_total, pes.assists_total
+        FROM player_event_stats pes
+        INNER JOIN player p ON pes.player_id = p.id
+        INNER JOIN event e ON pes.event_id = e.id
+        "#,
+    )
+    .fetch_all(db)
+    .await?;
+
+    for row in manual_rows {
+        let player_id: i64 = row.get("player_id");
+        let event_id: i64 = row.get("event_id");
+        rows.insert(
+            (player_id, event_id),
+            StatsReconciliationRow {
+                player_id,
+                player_name: row.get("player_name"),
+                event_id,
+                event_name: row.get("event_name"),
+                stats_id: Some(row.get("id")),
+                manual_goals: Some(row.get("goals_total")),
+                manual_assists: Some(row.get("assists_total")),
+                derived_goals: 0,
+                derived_assists: 0,
+                seasons: Vec::new(),
+            },
+        );
+    }
+
+    // One line per goal or assist credit, aggregated per player and season;
+    // exhibition games do not count towards player stats
+    let derived_rows = sqlx::query(
+        r#"
+        SELECT
+            credit.player_id, p.name as player_name,
+            e.id as event_id, e.name as event_name,
+            s.id as season_id, COALESCE(s.display_name, CAST(s.year AS TEXT)) as season_name,
+            SUM(credit.is_goal) as goals,
+            SUM(1 - credit.is_goal) as assists
+        FROM (
+            SELECT scorer_id as player_id, match_id, 1 as is_goal FROM score_event WHERE scorer_id IS NOT NULL
+            UNION ALL
+            SELECT assist1_id, match_id, 0 FROM score_event WHERE assist1_id IS NOT NULL
+            UNION ALL
+            SELECT assist2_id, match_id, 0 FROM score_event WHERE assist2_id IS NOT NULL
+        ) credit
+        INNER JOIN player p ON credit.player_id = p.id
+        INNER JOIN match m ON credit.match_id = m.id
+        INNER JOIN season s ON m.season_id = s.id
+        INNER JOIN event e ON s.event_id = e.id
+        WHERE m.game_type != 'exhibition'
+        GROUP BY credit.player_id, p.name, e.id, e.name, s.id
+        ORDER BY s.year ASC
+        "#,
+    )
+    .fetch_all(db)
+    .await?;
+
+    for row in derived_rows {
+        let player_id: i64 = row.get("player_id");
+        let event_id: i64 = row.get("event_id");
+        let goals: i32 = row.get("goals");
+        let assists: i32 = row.get("assists");
+
+        let entry = rows
+            .entry((player_id, event_id))
+            .or_insert_with(|| StatsReconciliationRow {
+                player_id,
+                player_name: row.get("player_name"),
+                event_id,
+                event_name: row.get("event_name"),
+                stats_id: None,
+                manual_goals: None,
+                manual_assists: None,
+                derived_goals: 0,
+                derived_assists: 0,
+                seasons: Vec::new(),
+            });
+
+        entry.derived_goals += goals;
+        entry.derived_assists += assists;
+        entry.seasons.push(ReconciliationSeasonEntity {
+            season_id: row.get("season_id"),
+            season_name: row.get("season_name"),
+            goals,
+            assists,
+        });
+    }
+
+    let mut report: Vec<StatsReconciliationRow> = rows
+        .into_values()
+        .filter(|row| !only_discrepancies || row.has_discrepancy())
+        .collect();
+
+    report.sort_by(|a, b| {
+        a.player_name
+            .cmp(&b.player_name)
+            .then_with(|| a.event_name.cmp(&b.event_name))
+    });
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::SqlitePool;
+
+    async fn insert_goal(pool: &SqlitePool, match_id: i64, scorer: i64, assist: Option<i64>) {
+        sqlx::query!(
+            "INSERT INTO score_event (match_id, team_id, scorer_id, assist1_id, period) VALUES (?, 1, ?, ?, 1)",
+            match_id,
+            scorer,
+            assist
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn insert_match(pool: &SqlitePool) -> i64 {
+        sqlx::query!(
+            "INSERT INTO match (season_id, home_team_id, away_team_id, status) VALUES (1, 1, 2, 'finished')"
+        )
+        .execute(pool)
+        .await
+        .unwrap()
+        .last_insert_rowid()
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_reconciliation_statuses(pool: SqlitePool) {
+        let match_id = insert_match(&pool).await;
+        // Player 1: 2 goals identified, 1 assist for player 2
+        insert_goal(&pool, match_id, 1, Some(2)).await;
+        insert_goal(&pool, match_id, 1, None).await;
+        // Player 3: 1 goal identified, no manual entry
+        insert_goal(&pool, match_id, 3, None).await;
+
+        sqlx::query!(
+            "INSERT INTO player_event_stats (player_id, event_id, goals_total, assists_total) VALUES \
+             (1, 1, 2, 0), (2, 1, 0, 0), (4, 1, 5, 5)"
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let report = get_stats_reconciliation(&pool, false).await.unwrap();
+        let status_of = |player_id: i64| {
+            report
+                .iter()
+                .find(|r| r.player_id == player_id)
+                .map(|r| r.status())
+        };
+
+        assert_eq!(status_of(1), Some(ReconciliationStatus::Matching));
+        assert_eq!(status_of(2), Some(ReconciliationStatus::Conflict));
+        assert_eq!(status_of(3), Some(ReconciliationStatus::MissingManual));
+        assert_eq!(status_of(4), Some(ReconciliationStatus::Unidentified));
+
+        let player1 = report.iter().find(|r| r.player_id == 1).unwrap();
+        assert_eq!(player1.seasons.len(), 1);
+        assert_eq!(player1.seasons[0].goals, 2);
+
+        let discrepancies = get_stats_reconciliation(&pool, true).await.unwrap();
+        assert_eq!(discrepancies.len(), 3);
+        assert!(discrepancies.iter().all(|r| r.player_id != 1));
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_reconciliation_empty(pool: SqlitePool) {
+        let report = get_stats_reconciliation(&pool, false).await.unwrap();
+        assert!(report.is_empty());
+    }
+}
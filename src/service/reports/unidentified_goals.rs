@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use sqlx::SqlitePool;
+
+/// A match whose score still includes goals without a recorded score event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnidentifiedMatchRow {
+    pub match_id: i64,
+    pub match_date: Option<String>,
+    pub season_id: i64,
+    pub season_name: String,
+    pub home_team_id: i64,
+    pub home_team_name: String,
+    pub away_team_id: i64,
+    pub away_team_name: String,
+    pub home_unidentified: i64,
+    pub away_unidentified: i64,
+    /// Goals already identified, for judging how much of the match is done
+    pub identified: i64,
+}
+
+impl UnidentifiedMatchRow {
+    pub fn unidentified(&self) -> i64 {
+        self.home_unidentified + self.away_unidentified
+    }
+}
+
+/// Unidentified goals of one season or one team
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnidentifiedTotal {
+    pub id: i64,
+    pub name: String,
+    /// Matches with at least one unidentified goal
+    pub matches: i64,
+    pub goals: i64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UnidentifiedGoalsFilters {
+    pub season_id: Option<i64>,
+    /// Matches of this team, home or away
+    pub team_id: Option<i64>,
+}
+
+/// Backlog of goal identification work with totals per season and team
+#[derive(Debug, Clone, Default)]
+pub struct UnidentifiedGoalsReport {
+    /// Most unidentified goals first
+    pub matches: Vec<UnidentifiedMatchRow>,
+    /// Most unidentified goals first
+    pub seasons: Vec<UnidentifiedTotal>,
+    /// Most unidentified goals first; a team counts only its own goals
+    pub teams: Vec<UnidentifiedTotal>,
+}
+
+impl UnidentifiedGoalsReport {
+    pub fn total_goals(&self) -> i64 {
+        self.matches.iter().map(|m| m.unidentified()).sum()
+    }
+}
+
+/// Add a match to a running total
+fn add_to(totals: &mut HashMap<i64, UnidentifiedTotal>, id: i64, name: &str, goals: i64) {
+    if goals == 0 {
+        return;
+    }
+    let total = totals.entry(id).or_insert_with(|| UnidentifiedTotal {
+        id,
+        name: name.to_string(),
+        matches: 0,
+        goals: 0,
+    });
+    total.matches += 1;
+    total.goals += goals;
+}
+
+fn sorted(totals: HashMap<i64, UnidentifiedTotal>) -> Vec<UnidentifiedTotal> {
+    let mut totals: Vec<UnidentifiedTotal> = totals.into_values().collect();
+    totals.sort_by(|a, b| b.goals.cmp(&a.goals).then_with(|| a.name.cmp(&b.name)));
+    totals
+}
+
+/// Matches still carrying unidentified goals, cancelled matches excluded
+pub async fn get_unidentified_goals_report(
+    db: &SqlitePool,
+    filters: &UnidentifiedGoalsFilters,
+) -> Result<UnidentifiedGoalsReport, sqlx::Error> {
+    let matches = sqlx::query_as!(
+        UnidentifiedMatchRow,
+        r#"
+        SELECT
+            m.id as "match_id!",
+            m.match_date,
+            m.season_id,
+            COALESCE(s.display_name, e.name || ' ' || s.year) as "season_name!: String",
+            m.home_team_id,
+            ht.name as home_team_name,
+            m.away_team_id,
+            at.name as away_team_name,
+            m.home_score_unidentified as home_unidentified,
+            m.away_score_unidentified as away_unidentified,
+            (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id) as "identified!: i64"
+        FROM match m
+        INNER JOIN season s ON s.id = m.season_id
+        INNER JOIN event e ON e.id = s.event_id
+        INNER JOIN team ht ON ht.id = m.home_team_id
+        INNER JOIN team at ON at.id = m.away_team_id
+        WHERE (m.home_score_unidentified > 0 OR m.away_score_unidentified > 0)
+          AND m.status != 'cancelled'
+          AND (?1 IS NULL OR m.season_id = ?1)
+          AND (?2 IS NULL OR m.home_team_id = ?2 OR m.away_team_id = ?2)
+        ORDER BY m.home_score_unidentified + m.away_score_unidentified DESC, m.match_date, m.id
+        "#,
+        filters.season_id,
+        filters.team_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    let mut seasons = HashMap::new();
+    let mut teams = HashMap::new();
+    for row in &matches {
+        add_to(
+            &mut seasons,
+            row.season_id,
+            &row.season_name,
+            row.unidentified(),
+        );
+        add_to(
+            &mut teams,
+            row.home_team_id,
+            &row.home_team_name,
+            row.home_unidentified,
+        );
+        add_to(
+            &mut teams,
+            row.away_team_id,
+            &row.away_team_name,
+            row.away_unidentified,
+        );
+    }
+
+    Ok(UnidentifiedGoalsReport {
+        matches,
+        seasons: sorted(seasons),
+        teams: sorted(teams),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_unidentified_goals_report(pool: SqlitePool) {
+        sqlx::query(
+            "INSERT INTO match (id, season_id, home_team_id, away_team_id, home_score_unidentified, away_score_unidentified, status) VALUES
+                (1, 1, 1, 2, 3, 1, 'finished'),
+                (2, 1, 2, 3, 0, 2, 'finished'),
+                (3, 1, 1, 3, 0, 0, 'finished'),
+                (4, 1, 1, 2, 5, 0, 'cancelled')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let report = get_unidentified_goals_report(&pool, &UnidentifiedGoalsFilters::default())
+            .await
+            .unwrap();
+        let matches: Vec<i64> = report.matches.iter().map(|m| m.match_id).collect();
+        assert_eq!(matches, vec![1, 2]);
+        assert_eq!(report.total_goals(), 6);
+
+        assert_eq!(report.seasons.len(), 1);
+        assert_eq!((report.seasons[0].matches, report.seasons[0].goals), (2, 6));
+
+        let teams: Vec<(i64, i64, i64)> = report
+            .teams
+            .iter()
+            .map(|t| (t.id, t.matches, t.goals))
+            .collect();
+        assert_eq!(teams, vec![(1, 1, 3), (3, 1, 2), (2, 1, 1)]);
+
+        let filtered = get_unidentified_goals_report(
+            &pool,
+            &UnidentifiedGoalsFilters {
+                season_id: Some(1),
+                team_id: Some(3),
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(filtered.matches.len(), 1);
+        assert_eq!(filtered.matches[0].match_id, 2);
+    }
+}
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+
+use sqlx::SqlitePool;
+
+/// Player on one or both of the compared rosters
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RosterDiffPlayerEntity {
+    pub player_id: i64,
+    pub player_name: String,
+    pub position: Option<String>,
+    /// Jersey number in the earlier season
+    pub from_jersey_number: Option<i64>,
+    /// Jersey number in the later season
+    pub to_jersey_number: Option<i64>,
+    /// Where an added player played in the earlier season, or where a
+    /// departed player plays in the later one
+    pub other_teams: Option<String>,
+}
+
+/// Players added, departed and retained between two seasons of a team
+#[derive(Debug, Clone, Default)]
+pub struct RosterDiffEntity {
+    pub added: Vec<RosterDiffPlayerEntity>,
+    pub departed: Vec<RosterDiffPlayerEntity>,
+    pub retained: Vec<RosterDiffPlayerEntity>,
+}
+
+struct RosterRow {
+    player_id: i64,
+    player_name: String,
+    position: Option<String>,
+    jersey_number: Option<i64>,
+}
+
+/// Compare a team's rosters of two seasons, using its player contracts
+///
+/// Each list is ordered by player name.
+pub async fn get_roster_diff(
+    db: &SqlitePool,
+    team_id: i64,
+    from_season_id: i64,
+    to_season_id: i64,
+) -> Result<RosterDiffEntity, sqlx::Error> {
+    let from = get_season_roster(db, team_id, from_season_id).await?;
+    let to = get_season_roster(db, team_id, to_season_id).await?;
+    let from_elsewhere = get_other_teams(db, team_id, from_season_id).await?;
+    let to_elsewhere = get_other_teams(db, team_id, to_season_id).await?;
+
+    let mut diff = RosterDiffEntity::default();
+
+    for row in &to {
+        match from.iter().find(|f| f.player_id == row.player_id) {
+            Some(earlier) => diff.retained.push(RosterDiffPlayerEntity {
+                player_id: row.player_id,
+                player_name: row.player_name.clone(),
+                position: row.position.clone(),
+                from_jersey_number: earlier.jersey_number,
+                to_jersey_number: row.jersey_number,
+                other_teams: None,
+            }),
+            None => diff.added.push(RosterDiffPlayerEntity {
+                player_id: row.player_id,
+                player_name: row.player_name.clone(),
+                position: row.position.clone(),
+                from_jersey_number: None,
+                to_jersey_number: row.jersey_number,
+                other_teams: from_elsewhere.get(&row.player_id).cloned(),
+            }),
+        }
+    }
+
+    for row in from
+        .into_iter()
+        .filter(|f| !to.iter().any(|t| t.player_id == f.player_id))
+    {
+        diff.departed.push(RosterDiffPlayerEntity {
+            other_teams: to_elsewhere.get(&row.player_id).cloned(),
+            player_id: row.player_id,
+            player_name: row.player_name,
+            position: row.position,
+            from_jersey_number: row.jersey_number,
+            to_jersey_number: None,
+        });
+    }
+
+    Ok(diff)
+}
+
+/// Players contracted to the team in a season, one row per player
+async fn get_season_roster(
+    db: &SqlitePool,
+    team_id: i64,
+    season_id: i64,
+) -> Result<Vec<RosterRow>, sqlx::Error> {
+    sqlx::query_as!(
+        RosterRow,
+        r#"
+        SELECT
+            p.id as "player_id!",
+            p.name as "player_name!",
+            p.position,
+            MAX(pc.jersey_number) as "jersey_number: i64"
+        FROM player_contract pc
+        INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+        INNER JOIN player p ON pc.player_id = p.id
+        WHERE tp.team_id = ? AND tp.season_id = ?
+        GROUP BY p.id, p.name, p.position
+        ORDER BY p.name
+        "#,
+        team_id,
+        season_id
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Other teams each player was contracted to in a season, comma separated
+async fn get_other_teams(
+    db: &SqlitePool,
+    team_id: i64,
+    season_id: i64,
+) -> Result<HashMap<i64, String>, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            pc.player_id as "player_id!",
+            GROUP_CONCAT(DISTINCT t.name) as "team_names!: String"
+        FROM player_contract pc
+        INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+        INNER JOIN team t ON tp.team_id = t.id
+        WHERE tp.season_id = ? AND tp.team_id <> ?
+        GROUP BY pc.player_id
+        "#,
+        season_id,
+        team_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| (row.player_id, row.team_names))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_get_roster_diff(pool: SqlitePool) {
+        // Team 1 plays seasons 1 and 2; team 3 plays season 2
+        sqlx::query(
+            "INSERT INTO team_participation (id, season_id, team_id, event_id) VALUES (5, 2, 1, 2);
+             INSERT INTO player_contract (player_id, team_participation_id, jersey_number) VALUES
+                (1, 1, 97), (2, 1, 99), (3, 2, 66),
+                (1, 5, 9), (3, 5, NULL), (2, 3, 99);",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let diff = get_roster_diff(&pool, 1, 1, 2).await.unwrap();
+
+        assert_eq!(diff.retained.len(), 1);
+        assert_eq!(diff.retained[0].player_id, 1);
+        assert_eq!(diff.retained[0].from_jersey_number, Some(97));
+        assert_eq!(diff.retained[0].to_jersey_number, Some(9));
+
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].player_id, 3);
+        assert_eq!(diff.added[0].other_teams.as_deref(), Some("Team USA"));
+
+        assert_eq!(diff.departed.len(), 1);
+        assert_eq!(diff.departed[0].player_id, 2);
+        assert_eq!(diff.departed[0].other_teams.as_deref(), Some("Team Russia"));
+    }
+}
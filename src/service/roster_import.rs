@@ -0,0 +1,438 @@
+//! Importing a roster from a CSV of player names and jersey numbers
+//!
+//! Rows are matched to existing players by name, ignoring case, accents and
+//! word order. A row without a match creates a new player of the row's
+//! country, or of the team's country when the row has none. Importing is a
+//! two-step process: [`plan_roster_import`] decides what happens to every row
+//! for the review step, [`apply_roster_import`] carries out a plan.
+
+use std::collections::{HashMap, HashSet};
+
+use sqlx::SqlitePool;
+
+use crate::service::countries;
+use crate::service::reports::normalize_name;
+use crate::service::slugs;
+
+/// A player row of a roster CSV, as written in the file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RosterCsvRow {
+    /// Line in the file, counting the header as line 1
+    pub line: usize,
+    pub name: String,
+    pub number: String,
+    pub country: String,
+}
+
+/// Why a row cannot be imported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RosterRowError {
+    MissingName,
+    InvalidNumber,
+    /// Jersey number used on the roster or by an earlier row
+    NumberTaken,
+    UnknownCountry,
+    /// New player without a country in the row or on the team
+    MissingCountry,
+    /// Several existing players match the name
+    Ambiguous,
+    /// The same player appears on an earlier row
+    DuplicateRow,
+}
+
+impl RosterRowError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            RosterRowError::MissingName => "Name is missing",
+            RosterRowError::InvalidNumber => "Jersey number must be between 0 and 99",
+            RosterRowError::NumberTaken => "Jersey number is already taken",
+            RosterRowError::UnknownCountry => "Unknown country code",
+            RosterRowError::MissingCountry => "Country is required for new players",
+            RosterRowError::Ambiguous => "Several players have this name",
+            RosterRowError::DuplicateRow => "Player is listed more than once",
+        }
+    }
+}
+
+/// What importing a row does
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RosterRowAction {
+    /// Adds an existing player to the roster
+    Existing { player_id: i64, player_name: String },
+    /// Creates a player and adds them to the roster
+    New { country_id: i64 },
+    /// Skipped, the player is on the roster already
+    OnRoster { player_id: i64 },
+    /// Skipped, the row is invalid
+    Invalid(RosterRowError),
+}
+
+/// A CSV row with its planned action
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RosterImportRow {
+    pub row: RosterCsvRow,
+    pub jersey_number: Option<i64>,
+    pub action: RosterRowAction,
+}
+
+/// Planned import of a roster CSV, shown for review before it is applied
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RosterImportPlan {
+    pub rows: Vec<RosterImportRow>,
+}
+
+impl RosterImportPlan {
+    /// Existing players that will be added
+    pub fn matched(&self) -> usize {
+        self.count(|action| matches!(action, RosterRowAction::Existing { .. }))
+    }
+
+    /// Players that will be created and added
+    pub fn created(&self) -> usize {
+        self.count(|action| matches!(action, RosterRowAction::New { .. }))
+    }
+
+    /// Rows that will not change anything
+    pub fn skipped(&self) -> usize {
+        self.rows.len() - self.matched() - self.created()
+    }
+
+    fn count(&self, f: impl Fn(&RosterRowAction) -> bool) -> usize {
+        self.rows.iter().filter(|row| f(&row.action)).count()
+    }
+}
+
+/// Players added by an applied import
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RosterImportSummary {
+    pub added: usize,
+    pub created: usize,
+}
+
+/// Decide what importing each row into the roster would do
+pub async fn plan_roster_import(
+    db: &SqlitePool,
+    team_participation_id: i64,
+    rows: Vec<RosterCsvRow>,
+) -> Result<RosterImportPlan, sqlx::Error> {
+    let team_country_id = sqlx::query_scalar!(
+        r#"
+        SELECT t.country_id
+        FROM team_participation tp
+        INNER JOIN team t ON t.id = tp.team_id
+        WHERE tp.id = ?
+        "#,
+        team_participation_id
+    )
+    .fetch_optional(db)
+    .await?
+    .flatten();
+
+    let contracts = sqlx::query!(
+        "SELECT player_id, jersey_number FROM player_contract WHERE team_participation_id = ?",
+        team_participation_id
+    )
+    .fetch_all(db)
+    .await?;
+    let on_roster: HashSet<i64> = contracts.iter().map(|c| c.player_id).collect();
+    let mut numbers: HashSet<i64> = contracts.iter().filter_map(|c| c.jersey_number).collect();
+
+    let mut players: HashMap<String, Vec<(i64, String, i64)>> = HashMap::new();
+    for player in sqlx::query!(r#"SELECT id as "id!", name, country_id FROM player ORDER BY id"#)
+        .fetch_all(db)
+        .await?
+    {
+        players
+            .entry(normalize_name(&player.name))
+            .or_default()
+            .push((player.id, player.name, player.country_id));
+    }
+
+    let mut countries: HashMap<String, Option<i64>> = HashMap::new();
+    let mut seen_players = HashSet::new();
+    let mut seen_names = HashSet::new();
+    let mut planned = Vec::with_capacity(rows.len());
+    for row in rows {
+        let jersey_number = row.number.parse::<i64>().ok();
+        let plan = |action| RosterImportRow {
+            row: row.clone(),
+            jersey_number,
+            action,
+        };
+
+        if row.name.is_empty() {
+            planned.push(plan(RosterRowAction::Invalid(RosterRowError::MissingName)));
+            continue;
+        }
+        if !row.number.is_empty() && !jersey_number.is_some_and(|n| (0..=99).contains(&n)) {
+            planned.push(plan(RosterRowAction::Invalid(
+                RosterRowError::InvalidNumber,
+            )));
+            continue;
+        }
+
+        let country_id = if row.country.is_empty() {
+            None
+        } else {
+            let code = row.country.to_uppercase();
+            let id = match countries.get(&code) {
+                Some(id) => *id,
+                None => {
+                    let id = countries::find_country_by_code(db, &code).await?;
+                    countries.insert(code, id);
+                    id
+                }
+            };
+            if id.is_none() {
+                planned.push(plan(RosterRowAction::Invalid(
+                    RosterRowError::UnknownCountry,
+                )));
+                continue;
+            }
+            id
+        };
+
+        let normalized = normalize_name(&row.name);
+        let candidates: Vec<&(i64, String, i64)> = players
+            .get(&normalized)
+            .map(|found| {
+                found
+                    .iter()
+                    .filter(|(_, _, country)| country_id.is_none_or(|id| id == *country))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let chosen = match candidates.as_slice() {
+            [] => None,
+            [only] => Some(*only),
+            several => {
+                // Prefer the namesake from the team's country
+                let local: Vec<_> = several
+                    .iter()
+                    .filter(|(_, _, country)| Some(*country) == team_country_id)
+                    .collect();
+                match local.as_slice() {
+                    [only] => Some(**only),
+                    _ => {
+                        planned.push(plan(RosterRowAction::Invalid(RosterRowError::Ambiguous)));
+                        continue;
+                    }
+                }
+            }
+        };
+
+        if let Some((player_id, _, _)) = chosen.filter(|(id, _, _)| on_roster.contains(id)) {
+            planned.push(plan(RosterRowAction::OnRoster {
+                player_id: *player_id,
+            }));
+            continue;
+        }
+        let duplicate = match chosen {
+            Some((player_id, _, _)) => seen_players.contains(player_id),
+            None => seen_names.contains(&normalized),
+        };
+        if duplicate {
+            planned.push(plan(RosterRowAction::Invalid(RosterRowError::DuplicateRow)));
+            continue;
+        }
+        if jersey_number.is_some_and(|number| numbers.contains(&number)) {
+            planned.push(plan(RosterRowAction::Invalid(RosterRowError::NumberTaken)));
+            continue;
+        }
+
+        let action = match chosen {
+            Some((player_id, player_name, _)) => {
+                seen_players.insert(*player_id);
+                RosterRowAction::Existing {
+                    player_id: *player_id,
+                    player_name: player_name.clone(),
+                }
+            }
+            None => match country_id.or(team_country_id) {
+                Some(country_id) => {
+                    seen_names.insert(normalized);
+                    RosterRowAction::New { country_id }
+                }
+                None => RosterRowAction::Invalid(RosterRowError::MissingCountry),
+            },
+        };
+        if matches!(
+            action,
+            RosterRowAction::Existing { .. } | RosterRowAction::New { .. }
+        ) {
+            numbers.extend(jersey_number);
+        }
+        planned.push(plan(action));
+    }
+
+    Ok(RosterImportPlan { rows: planned })
+}
+
+/// Create the planned players and contracts in a single transaction
+pub async fn apply_roster_import(
+    db: &SqlitePool,
+    team_participation_id: i64,
+    plan: &RosterImportPlan,
+) -> Result<RosterImportSummary, sqlx::Error> {
+    let mut tx = db.begin().await?;
+    let mut summary = RosterImportSummary::default();
+
+    for row in &plan.rows {
+        let player_id = match &row.action {
+            RosterRowAction::Existing { player_id, .. } => *player_id,
+            RosterRowAction::New { country_id } => {
+                summary.created += 1;
+                sqlx::query!(
+                    "INSERT INTO player (name, country_id) VALUES (?, ?)",
+                    row.row.name,
+                    country_id
+                )
+                .execute(&mut *tx)
+                .await?
+                .last_insert_rowid()
+            }
+            RosterRowAction::OnRoster { .. } | RosterRowAction::Invalid(_) => continue,
+        };
+
+        sqlx::query!(
+            "INSERT INTO player_contract (team_participation_id, player_id, jersey_number) VALUES (?, ?, ?)",
+            team_participation_id,
+            player_id,
+            row.jersey_number
+        )
+        .execute(&mut *tx)
+        .await?;
+        summary.added += 1;
+    }
+
+    // Players above are inserted directly, so give them their slugs
+    slugs::assign_missing_slugs(&mut tx).await?;
+    tx.commit().await?;
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(line: usize, name: &str, number: &str, country: &str) -> RosterCsvRow {
+        RosterCsvRow {
+            line,
+            name: name.to_string(),
+            number: number.to_string(),
+            country: country.to_string(),
+        }
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_roster_import(pool: SqlitePool) {
+        let participation = sqlx::query_scalar!(
+            r#"SELECT id as "id!" FROM team_participation WHERE season_id = 1 AND team_id = 1"#
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        // Player 2 is on the roster already and wears 29
+        sqlx::query("INSERT INTO player_contract (team_participation_id, player_id, jersey_number) VALUES (?, 2, 29)")
+            .bind(participation)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let player_2 = sqlx::query_scalar!("SELECT name FROM player WHERE id = 2")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        let plan = plan_roster_import(
+            &pool,
+            participation,
+            vec![
+                row(2, "mcdavid connor", "97", ""),
+                row(3, &player_2, "", ""),
+                row(4, "Jan Novák", "29", "cz"),
+                row(5, "Jan Novák", "12", "CZE"),
+                row(6, "Marie Dvořáková", "99", ""),
+                row(7, "", "1", ""),
+                row(8, "Mario Lemieux", "100", ""),
+                row(9, "Jaromír Jágr", "68", "XX"),
+            ],
+        )
+        .await
+        .unwrap();
+
+        let actions: Vec<&RosterRowAction> = plan.rows.iter().map(|r| &r.action).collect();
+        assert_eq!(
+            actions[0],
+            &RosterRowAction::Existing {
+                player_id: 1,
+                player_name: "Connor McDavid".to_string()
+            }
+        );
+        assert_eq!(actions[1], &RosterRowAction::OnRoster { player_id: 2 });
+        assert_eq!(
+            actions[2],
+            &RosterRowAction::Invalid(RosterRowError::NumberTaken)
+        );
+        assert!(matches!(actions[3], RosterRowAction::New { .. }));
+        // Team Canada's country is used for new players without one
+        assert_eq!(actions[4], &RosterRowAction::New { country_id: 34 });
+        assert_eq!(
+            actions[5],
+            &RosterRowAction::Invalid(RosterRowError::MissingName)
+        );
+        assert_eq!(
+            actions[6],
+            &RosterRowAction::Invalid(RosterRowError::InvalidNumber)
+        );
+        assert_eq!(
+            actions[7],
+            &RosterRowAction::Invalid(RosterRowError::UnknownCountry)
+        );
+        assert_eq!((plan.matched(), plan.created(), plan.skipped()), (1, 2, 5));
+
+        let summary = apply_roster_import(&pool, participation, &plan)
+            .await
+            .unwrap();
+        assert_eq!(
+            summary,
+            RosterImportSummary {
+                added: 3,
+                created: 2
+            }
+        );
+
+        let roster = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) as "count!: i64" FROM player_contract WHERE team_participation_id = ?"#,
+            participation
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(roster, 4);
+        let slug = sqlx::query_scalar!("SELECT slug FROM player WHERE name = 'Marie Dvořáková'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert!(slug.is_some());
+
+        // Importing the same rows again only finds them on the roster
+        let again = plan_roster_import(
+            &pool,
+            participation,
+            vec![
+                row(2, "Connor McDavid", "97", ""),
+                row(3, "Jan Novák", "12", "CZ"),
+            ],
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            (again.matched(), again.created(), again.skipped()),
+            (0, 0, 2)
+        );
+    }
+}
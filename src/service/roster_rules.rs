@@ -0,0 +1,288 @@
+use sqlx::SqlitePool;
+
+/// Position value marking a goalie (see the player form)
+pub const GOALIE_POSITION: &str = "Goalie";
+
+/// Roster limits configured on an event; `None` means no limit
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RosterRulesEntity {
+    pub max_players: Option<i64>,
+    pub min_goalies: Option<i64>,
+    pub max_imports: Option<i64>,
+}
+
+impl RosterRulesEntity {
+    pub fn is_empty(&self) -> bool {
+        self.max_players.is_none() && self.min_goalies.is_none() && self.max_imports.is_none()
+    }
+
+    /// Check a roster against every configured rule
+    pub fn checks(&self, composition: &RosterCompositionEntity) -> Vec<RosterRuleCheck> {
+        [
+            (
+                RosterRule::MaxPlayers,
+                self.max_players,
+                composition.players,
+            ),
+            (
+                RosterRule::MinGoalies,
+                self.min_goalies,
+                composition.goalies,
+            ),
+            (
+                RosterRule::MaxImports,
+                self.max_imports,
+                composition.imports,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(rule, limit, current)| {
+            limit.map(|limit| RosterRuleCheck {
+                rule,
+                limit,
+                current,
+            })
+        })
+        .collect()
+    }
+}
+
+/// Counts of active players (not transferred away) in a roster
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RosterCompositionEntity {
+    pub players: i64,
+    pub goalies: i64,
+    /// Players from a country other than the team's
+    pub imports: i64,
+}
+
+impl RosterCompositionEntity {
+    /// Composition after adding the players of `other`
+    pub fn with(&self, other: &Self) -> Self {
+        Self {
+            players: self.players + other.players,
+            goalies: self.goalies + other.goalies,
+            imports: self.imports + other.imports,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RosterRule {
+    MaxPlayers,
+    MinGoalies,
+    MaxImports,
+}
+
+/// A rule compared with a roster
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RosterRuleCheck {
+    pub rule: RosterRule,
+    pub limit: i64,
+    pub current: i64,
+}
+
+impl RosterRuleCheck {
+    pub fn is_met(&self) -> bool {
+        match self.rule {
+            RosterRule::MinGoalies => self.current >= self.limit,
+            RosterRule::MaxPlayers | RosterRule::MaxImports => self.current <= self.limit,
+        }
+    }
+}
+
+/// Get the roster rules of an event
+pub async fn get_event_roster_rules(
+    db: &SqlitePool,
+    event_id: i64,
+) -> Result<Option<RosterRulesEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        RosterRulesEntity,
+        r#"
+        SELECT
+            roster_max_players as max_players,
+            roster_min_goalies as min_goalies,
+            roster_max_imports as max_imports
+        FROM event
+        WHERE id = ?
+        "#,
+        event_id
+    )
+    .fetch_optional(db)
+    .await
+}
+
+/// Get the roster rules that apply to a team participation (from its event)
+pub async fn get_participation_roster_rules(
+    db: &SqlitePool,
+    team_participation_id: i64,
+) -> Result<Option<RosterRulesEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        RosterRulesEntity,
+        r#"
+        SELECT
+            e.roster_max_players as max_players,
+            e.roster_min_goalies as min_goalies,
+            e.roster_max_imports as max_imports
+        FROM team_participation tp
+        INNER JOIN season s ON tp.season_id = s.id
+        INNER JOIN event e ON s.event_id = e.id
+        WHERE tp.id = ?
+        "#,
+        team_participation_id
+    )
+    .fetch_optional(db)
+    .await
+}
+
+/// Replace the roster rules of an event
+pub async fn update_event_roster_rules(
+    db: &SqlitePool,
+    event_id: i64,
+    rules: &RosterRulesEntity,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE event
+        SET roster_max_players = ?, roster_min_goalies = ?, roster_max_imports = ?
+        WHERE id = ?
+        "#,
+        rules.max_players,
+        rules.min_goalies,
+        rules.max_imports,
+        event_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Count the active players, goalies and imports of a roster
+pub async fn get_roster_composition(
+    db: &SqlitePool,
+    team_participation_id: i64,
+) -> Result<RosterCompositionEntity, sqlx::Error> {
+    sqlx::query_as!(
+        RosterCompositionEntity,
+        r#"
+        SELECT
+            COUNT(*) as "players!: i64",
+            COALESCE(SUM(p.position = ?2), 0) as "goalies!: i64",
+            COALESCE(SUM(t.country_id IS NOT NULL AND p.country_id != t.country_id), 0) as "imports!: i64"
+        FROM player_contract pc
+        INNER JOIN player p ON pc.player_id = p.id
+        INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+        INNER JOIN team t ON tp.team_id = t.id
+        WHERE pc.team_participation_id = ?1 AND pc.left_on IS NULL
+        "#,
+        team_participation_id,
+        GOALIE_POSITION
+    )
+    .fetch_one(db)
+    .await
+}
+
+/// What a single player would add to a roster's composition
+///
+/// Returns `None` if the player or team participation does not exist.
+pub async fn get_player_composition(
+    db: &SqlitePool,
+    team_participation_id: i64,
+    player_id: i64,
+) -> Result<Option<RosterCompositionEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        RosterCompositionEntity,
+        r#"
+        SELECT
+            1 as "players!: i64",
+            (p.position IS NOT NULL AND p.position = ?3) as "goalies!: i64",
+            (t.country_id IS NOT NULL AND p.country_id != t.country_id) as "imports!: i64"
+        FROM player p, team_participation tp
+        INNER JOIN team t ON tp.team_id = t.id
+        WHERE p.id = ?2 AND tp.id = ?1
+        "#,
+        team_participation_id,
+        player_id,
+        GOALIE_POSITION
+    )
+    .fetch_optional(db)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rule_checks() {
+        let rules = RosterRulesEntity {
+            max_players: Some(2),
+            min_goalies: Some(1),
+            max_imports: None,
+        };
+        let composition = RosterCompositionEntity {
+            players: 3,
+            goalies: 1,
+            imports: 2,
+        };
+
+        let checks = rules.checks(&composition);
+        assert_eq!(checks.len(), 2);
+        assert!(!checks[0].is_met());
+        assert!(checks[1].is_met());
+        assert!(RosterRulesEntity::default().is_empty());
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_roster_composition(pool: SqlitePool) {
+        // Team 1 is Canadian (34); one home goalie and one import skater
+        sqlx::query(
+            "INSERT INTO player (id, name, country_id, position) VALUES
+                (1, 'Home Goalie', 34, 'Goalie'),
+                (2, 'Import Skater', 187, 'Forward'),
+                (3, 'Candidate', 153, NULL)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO player_contract (team_participation_id, player_id) VALUES (1, 1), (1, 2)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let composition = get_roster_composition(&pool, 1).await.unwrap();
+        assert_eq!(
+            composition,
+            RosterCompositionEntity {
+                players: 2,
+                goalies: 1,
+                imports: 1,
+            }
+        );
+
+        let candidate = get_player_composition(&pool, 1, 3).await.unwrap().unwrap();
+        assert_eq!(composition.with(&candidate).imports, 2);
+        assert_eq!(candidate.goalies, 0);
+        assert!(get_player_composition(&pool, 1, 999)
+            .await
+            .unwrap()
+            .is_none());
+
+        let rules = RosterRulesEntity {
+            max_players: Some(20),
+            min_goalies: None,
+            max_imports: Some(1),
+        };
+        assert!(update_event_roster_rules(&pool, 1, &rules).await.unwrap());
+        assert_eq!(
+            get_participation_roster_rules(&pool, 1).await.unwrap(),
+            Some(rules)
+        );
+    }
+}
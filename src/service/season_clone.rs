@@ -0,0 +1,255 @@
+//! Cloning a season as the starting point of the next one
+//!
+//! The clone belongs to the same event and host country and gets the same
+//! groups and participating teams. Results of the source season (seeds, final
+//! ranks, matches, playoffs, awards) are not copied. Rosters are copied on
+//! request, leaving out players transferred away during the season; jersey
+//! numbers and captaincies come along, join and leave dates do not.
+
+use sqlx::SqlitePool;
+
+use crate::service::slugs::{self, SlugEntity};
+
+#[derive(Debug, Clone)]
+pub struct CloneSeasonEntity {
+    pub year: i64,
+    pub display_name: Option<String>,
+    pub copy_rosters: bool,
+}
+
+/// What a clone of the season would copy
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SeasonCloneSummary {
+    pub teams: i64,
+    /// Players on the copied rosters when rosters are copied
+    pub players: i64,
+}
+
+/// Teams and roster players a clone of the season would copy
+pub async fn get_clone_summary(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<SeasonCloneSummary, sqlx::Error> {
+    let teams = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!: i64" FROM team_participation WHERE season_id = ?"#,
+        season_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    let players = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) as "count!: i64"
+        FROM player_contract pc
+        INNER JOIN team_participation tp ON tp.id = pc.team_participation_id
+        WHERE tp.season_id = ? AND pc.left_on IS NULL
+        "#,
+        season_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(SeasonCloneSummary { teams, players })
+}
+
+/// Whether the event already has a season in the given year
+pub async fn season_year_exists(
+    db: &SqlitePool,
+    event_id: i64,
+    year: i64,
+) -> Result<bool, sqlx::Error> {
+    let exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM season WHERE event_id = ? AND year = ?) as "exists!: bool""#,
+        event_id,
+        year
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(exists)
+}
+
+/// Create a new season from an existing one in a single transaction
+///
+/// Returns the new season's ID, `None` when the source season does not exist.
+pub async fn clone_season(
+    db: &SqlitePool,
+    source_id: i64,
+    clone: CloneSeasonEntity,
+) -> Result<Option<i64>, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    let Some(source) = sqlx::query!(
+        "SELECT event_id, country_id FROM season WHERE id = ?",
+        source_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    let id = sqlx::query!(
+        "INSERT INTO season (year, display_name, event_id, country_id) VALUES (?, ?, ?, ?)",
+        clone.year,
+        clone.display_name,
+        source.event_id,
+        source.country_id
+    )
+    .execute(&mut *tx)
+    .await?
+    .last_insert_rowid();
+    if let Some(name) = slugs::slug_name(&mut tx, SlugEntity::Season, id).await? {
+        slugs::assign_slug(&mut tx, SlugEntity::Season, id, &name).await?;
+    }
+
+    sqlx::query!(
+        r#"
+        INSERT INTO season_group (season_id, name)
+        SELECT ?1, name FROM season_group WHERE season_id = ?2 ORDER BY id
+        "#,
+        id,
+        source_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    // Teams keep their group, matched by name in the new season
+    sqlx::query!(
+        r#"
+        INSERT INTO team_participation (team_id, season_id, event_id, group_id)
+        SELECT tp.team_id, ?1, tp.event_id, new_group.id
+        FROM team_participation tp
+        LEFT JOIN season_group old_group ON old_group.id = tp.group_id
+        LEFT JOIN season_group new_group
+            ON new_group.season_id = ?1 AND new_group.name = old_group.name
+        WHERE tp.season_id = ?2
+        ORDER BY tp.id
+        "#,
+        id,
+        source_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if clone.copy_rosters {
+        sqlx::query!(
+            r#"
+            INSERT INTO player_contract (player_id, team_participation_id, jersey_number, captaincy)
+            SELECT pc.player_id, new_tp.id, pc.jersey_number, pc.captaincy
+            FROM player_contract pc
+            INNER JOIN team_participation old_tp ON old_tp.id = pc.team_participation_id
+            INNER JOIN team_participation new_tp
+                ON new_tp.season_id = ?1 AND new_tp.team_id = old_tp.team_id
+            WHERE old_tp.season_id = ?2 AND pc.left_on IS NULL
+            ORDER BY pc.id
+            "#,
+            id,
+            source_id
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
+    Ok(Some(id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_clone_season(pool: SqlitePool) {
+        sqlx::query("INSERT INTO season_group (id, season_id, name) VALUES (1, 1, 'Group A')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE team_participation SET group_id = 1, seed = 1 WHERE season_id = 1 AND team_id = 1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        // Player 2 was transferred away and is not copied
+        sqlx::query(
+            "INSERT INTO player_contract (team_participation_id, player_id, jersey_number, captaincy, left_on)
+             SELECT id, 1, 97, 'C', NULL FROM team_participation WHERE season_id = 1 AND team_id = 1
+             UNION ALL
+             SELECT id, 2, 29, NULL, '2022-02-10' FROM team_participation WHERE season_id = 1 AND team_id = 1",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let source_teams = get_clone_summary(&pool, 1).await.unwrap();
+        assert_eq!(source_teams.players, 1);
+
+        let without_rosters = clone_season(
+            &pool,
+            1,
+            CloneSeasonEntity {
+                year: 2026,
+                display_name: Some("2026 Winter Olympics".to_string()),
+                copy_rosters: false,
+            },
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        let summary = get_clone_summary(&pool, without_rosters).await.unwrap();
+        assert_eq!(summary.teams, source_teams.teams);
+        assert_eq!(summary.players, 0);
+
+        let with_rosters = clone_season(
+            &pool,
+            1,
+            CloneSeasonEntity {
+                year: 2030,
+                display_name: None,
+                copy_rosters: true,
+            },
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        let copied = sqlx::query!(
+            r#"
+            SELECT pc.player_id, pc.jersey_number, pc.captaincy, sg.name as "group_name?", tp.seed
+            FROM player_contract pc
+            INNER JOIN team_participation tp ON tp.id = pc.team_participation_id
+            LEFT JOIN season_group sg ON sg.id = tp.group_id
+            WHERE tp.season_id = ?
+            "#,
+            with_rosters
+        )
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(copied.len(), 1);
+        assert_eq!(copied[0].player_id, 1);
+        assert_eq!(copied[0].jersey_number, Some(97));
+        assert_eq!(copied[0].captaincy.as_deref(), Some("C"));
+        assert_eq!(copied[0].group_name.as_deref(), Some("Group A"));
+        assert_eq!(copied[0].seed, None);
+
+        assert!(season_year_exists(&pool, 1, 2030).await.unwrap());
+        assert_eq!(
+            clone_season(
+                &pool,
+                999,
+                CloneSeasonEntity {
+                    year: 2031,
+                    display_name: None,
+                    copy_rosters: false,
+                },
+            )
+            .await
+            .unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use serde::Serialize;
+use sqlx::SqlitePool;
+
+/// A match of a season's schedule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ScheduleMatchEntity {
+    pub id: i64,
+    pub match_date: Option<String>,
+    pub home_team_name: String,
+    pub away_team_name: String,
+    /// Goals including unidentified ones
+    pub home_score: i64,
+    pub away_score: i64,
+    pub status: String,
+    pub game_type: String,
+    pub result: Option<String>,
+    pub arena_name: Option<String>,
+}
+
+/// Every match of a season in date order, undated matches last
+pub async fn get_season_schedule(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<Vec<ScheduleMatchEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        ScheduleMatchEntity,
+        r#"
+        SELECT
+            m.id as "id!",
+            m.match_date,
+            ht.name as home_team_name,
+            at.name as away_team_name,
+            m.home_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id) as "home_score!: i64",
+            m.away_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.away_team_id) as "away_score!: i64",
+            m.status,
+            m.game_type,
+            m.result,
+            ar.name as "arena_name?"
+        FROM match m
+        INNER JOIN team ht ON m.home_team_id = ht.id
+        INNER JOIN team at ON m.away_team_id = at.id
+        LEFT JOIN arena ar ON m.arena_id = ar.id
+        WHERE m.season_id = ?
+        ORDER BY m.match_date IS NULL, m.match_date, m.id
+        "#,
+        season_id
+    )
+    .fetch_all(db)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_get_season_schedule(pool: SqlitePool) {
+        sqlx::query(
+            "INSERT INTO match (id, season_id, home_team_id, away_team_id, home_score_unidentified, match_date, status) VALUES
+                (1, 1, 1, 2, 1, NULL, 'scheduled'),
+                (2, 1, 2, 1, 0, '2022-02-10', 'finished'),
+                (3, 1, 1, 2, 2, '2022-02-05T12:10', 'finished'),
+                (4, 2, 3, 4, 0, '2023-05-01', 'finished');
+             INSERT INTO score_event (match_id, team_id, period) VALUES (2, 1, 1), (2, 1, 2);",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let schedule = get_season_schedule(&pool, 1).await.unwrap();
+
+        let ids: Vec<i64> = schedule.iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec![3, 2, 1]);
+        assert_eq!(schedule[0].home_score, 2);
+        assert_eq!(schedule[1].home_team_name, "Team USA");
+        assert_eq!((schedule[1].home_score, schedule[1].away_score), (0, 2));
+    }
+}
@@ -0,0 +1,178 @@
+use sqlx::SqlitePool;
+
+/// A group (Group A, a division, ...) within a season
+#[derive(Debug, Clone)]
+pub struct SeasonGroupEntity {
+    pub id: i64,
+    pub season_id: i64,
+    pub name: String,
+    /// Teams assigned to the group
+    pub team_count: i64,
+}
+
+/// Get the groups of a season ordered by name
+pub async fn get_season_groups(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<Vec<SeasonGroupEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        SeasonGroupEntity,
+        r#"
+        SELECT
+            sg.id as "id!",
+            sg.season_id,
+            sg.name,
+            (SELECT COUNT(*) FROM team_participation tp WHERE tp.group_id = sg.id) as "team_count!: i64"
+        FROM season_group sg
+        WHERE sg.season_id = ?
+        ORDER BY sg.name ASC
+        "#,
+        season_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Get a single group by ID
+pub async fn get_group_by_id(
+    db: &SqlitePool,
+    id: i64,
+) -> Result<Option<SeasonGroupEntity>, sqlx::Error> {
+    let row = sqlx::query_as!(
+        SeasonGroupEntity,
+        r#"
+        SELECT
+            sg.id as "id!",
+            sg.season_id,
+            sg.name,
+            (SELECT COUNT(*) FROM team_participation tp WHERE tp.group_id = sg.id) as "team_count!: i64"
+        FROM season_group sg
+        WHERE sg.id = ?
+        "#,
+        id
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(row)
+}
+
+/// Get all groups as (id, "Event Season - Group") pairs for dropdowns
+pub async fn get_groups_simple(db: &SqlitePool) -> Result<Vec<(i64, String)>, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            sg.id as "id!",
+            e.name || ' ' || COALESCE(s.display_name, CAST(s.year AS TEXT)) || ' - ' || sg.name as "name!: String"
+        FROM season_group sg
+        INNER JOIN season s ON sg.season_id = s.id
+        INNER JOIN event e ON s.event_id = e.id
+        ORDER BY s.year DESC, e.name ASC, sg.name ASC
+        "#
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows.into_iter().map(|row| (row.id, row.name)).collect())
+}
+
+/// Check whether a season already has a group with this name
+pub async fn group_name_exists(
+    db: &SqlitePool,
+    season_id: i64,
+    name: &str,
+) -> Result<bool, sqlx::Error> {
+    let row = sqlx::query!(
+        r#"SELECT COUNT(*) as "count!: i64" FROM season_group WHERE season_id = ? AND name = ?"#,
+        season_id,
+        name
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(row.count > 0)
+}
+
+/// Create a group in a season
+pub async fn create_group(db: &SqlitePool, season_id: i64, name: &str) -> Result<i64, sqlx::Error> {
+    let result = sqlx::query!(
+        "INSERT INTO season_group (season_id, name) VALUES (?, ?)",
+        season_id,
+        name
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Delete a group; its teams stay in the season without a group
+pub async fn delete_group(db: &SqlitePool, id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!("DELETE FROM season_group WHERE id = ?", id)
+        .execute(db)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Assign a team participation to a group of its season, or clear it with `None`
+///
+/// Returns `false` when the participation does not exist or the group belongs
+/// to another season.
+pub async fn assign_team_to_group(
+    db: &SqlitePool,
+    participation_id: i64,
+    group_id: Option<i64>,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE team_participation
+        SET group_id = ?1, updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?2
+          AND (?1 IS NULL OR ?1 IN (SELECT id FROM season_group WHERE season_id = team_participation.season_id))
+        "#,
+        group_id,
+        participation_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_group_assignment(pool: SqlitePool) {
+        let group_a = create_group(&pool, 1, "Group A").await.unwrap();
+        let other_season = create_group(&pool, 2, "Group A").await.unwrap();
+        assert!(group_name_exists(&pool, 1, "Group A").await.unwrap());
+        assert!(!group_name_exists(&pool, 1, "Group B").await.unwrap());
+
+        assert!(assign_team_to_group(&pool, 1, Some(group_a)).await.unwrap());
+        // Participation 2 is in season 1, the group in season 2
+        assert!(!assign_team_to_group(&pool, 2, Some(other_season))
+            .await
+            .unwrap());
+
+        let groups = get_season_groups(&pool, 1).await.unwrap();
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].team_count, 1);
+
+        // Deleting the group keeps the team in the season, unassigned
+        assert!(delete_group(&pool, group_a).await.unwrap());
+        let group_id: Option<i64> =
+            sqlx::query_scalar("SELECT group_id FROM team_participation WHERE id = 1")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(group_id, None);
+    }
+}
@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use sqlx::SqlitePool;
+
+use super::players::PlayerSeasonStats;
+
+/// How long computed season stats are reused before being recomputed
+const CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// Games and scoring of a player in one season
+///
+/// Games played are the finished, non-exhibition matches of the player's team;
+/// when the team has a lineup recorded for a match, only dressed players get
+/// the game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeasonPlayerStatsEntity {
+    pub player_id: i64,
+    pub games_played: i64,
+    pub goals: i64,
+    pub assists: i64,
+    pub points: i64,
+}
+
+impl SeasonPlayerStatsEntity {
+    pub fn points_per_game(&self) -> Option<f64> {
+        (self.games_played > 0).then(|| self.points as f64 / self.games_played as f64)
+    }
+}
+
+/// Where a player ranks among all players of a season, as "top N%"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatPercentiles {
+    /// Players the ranks are computed against
+    pub players_count: usize,
+    pub goals: u32,
+    pub assists: u32,
+    pub points: u32,
+    /// `None` when the player has not played a game
+    pub points_per_game: Option<u32>,
+}
+
+/// Where a player ranks within one of their seasons
+#[derive(Debug, Clone)]
+pub struct PlayerSeasonPercentiles {
+    pub season: PlayerSeasonStats,
+    pub percentiles: StatPercentiles,
+}
+
+/// Share of players at or above `value`, rounded up to a whole percent
+///
+/// Tied players share the better rank, so the leader of a 200 player season
+/// is in the top 1%.
+pub fn top_percent(values: &[f64], value: f64) -> u32 {
+    if values.is_empty() {
+        return 100;
+    }
+    let better = values.iter().filter(|other| **other > value).count();
+    let percent = ((better + 1) as f64 * 100.0 / values.len() as f64).ceil() as u32;
+    percent.clamp(1, 100)
+}
+
+/// Rank a player against the stats of the season, `None` if they are not part of it
+pub fn player_percentiles(
+    stats: &[SeasonPlayerStatsEntity],
+    player_id: i64,
+) -> Option<StatPercentiles> {
+    let player = stats.iter().find(|s| s.player_id == player_id)?;
+    let column = |value: fn(&SeasonPlayerStatsEntity) -> i64| -> Vec<f64> {
+        stats.iter().map(|s| value(s) as f64).collect()
+    };
+    let per_game: Vec<f64> = stats.iter().filter_map(|s| s.points_per_game()).collect();
+
+    Some(StatPercentiles {
+        players_count: stats.len(),
+        goals: top_percent(&column(|s| s.goals), player.goals as f64),
+        assists: top_percent(&column(|s| s.assists), player.assists as f64),
+        points: top_percent(&column(|s| s.points), player.points as f64),
+        points_per_game: player
+            .points_per_game()
+            .map(|ppg| top_percent(&per_game, ppg)),
+    })
+}
+
+/// Stats of every player of a season: rostered players and anyone who scored
+///
+/// Exhibition games are not counted.
+pub async fn get_season_player_stats(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<Vec<SeasonPlayerStatsEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        SeasonPlayerStatsEntity,
+        r#"
+        WITH roster AS (
+            SELECT pc.id as contract_id, pc.player_id, tp.team_id
+            FROM player_contract pc
+            INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+            WHERE tp.season_id = ?1
+        ),
+        games AS (
+            SELECT DISTINCT r.player_id, m.id as match_id
+            FROM roster r
+            INNER JOIN match m
+                ON m.season_id = ?1 AND (m.home_team_id = r.team_id OR m.away_team_id = r.team_id)
+            WHERE m.status = 'finished' AND m.game_type != 'exhibition'
+              AND (
+                EXISTS (
+                    SELECT 1 FROM match_lineup ml
+                    WHERE ml.match_id = m.id AND ml.player_contract_id = r.contract_id
+                )
+                OR NOT EXISTS (
+                    SELECT 1 FROM match_lineup ml
+                    INNER JOIN roster teammate ON ml.player_contract_id = teammate.contract_id
+                    WHERE ml.match_id = m.id AND teammate.team_id = r.team_id
+                )
+              )
+        ),
+        scoring AS (
+            SELECT se.scorer_id as player_id, 1 as is_goal
+            FROM score_event se
+            INNER JOIN match m ON se.match_id = m.id
+            WHERE m.season_id = ?1 AND m.game_type != 'exhibition' AND se.scorer_id IS NOT NULL
+            UNION ALL
+            SELECT se.assist1_id, 0
+            FROM score_event se
+            INNER JOIN match m ON se.match_id = m.id
+            WHERE m.season_id = ?1 AND m.game_type != 'exhibition' AND se.assist1_id IS NOT NULL
+            UNION ALL
+            SELECT se.assist2_id, 0
+            FROM score_event se
+            INNER JOIN match m ON se.match_id = m.id
+            WHERE m.season_id = ?1 AND m.game_type != 'exhibition' AND se.assist2_id IS NOT NULL
+        ),
+        players AS (
+            SELECT player_id FROM roster
+            UNION
+            SELECT player_id FROM scoring
+        )
+        SELECT
+            p.player_id as "player_id!: i64",
+            (SELECT COUNT(*) FROM games g WHERE g.player_id = p.player_id) as "games_played!: i64",
+            (SELECT COUNT(*) FROM scoring s WHERE s.player_id = p.player_id AND s.is_goal = 1)
+                as "goals!: i64",
+            (SELECT COUNT(*) FROM scoring s WHERE s.player_id = p.player_id AND s.is_goal = 0)
+                as "assists!: i64",
+            (SELECT COUNT(*) FROM scoring s WHERE s.player_id = p.player_id) as "points!: i64"
+        FROM players p
+        ORDER BY p.player_id
+        "#,
+        season_id
+    )
+    .fetch_all(db)
+    .await
+}
+
+struct CachedSeason {
+    computed_at: Instant,
+    stats: Arc<Vec<SeasonPlayerStatsEntity>>,
+}
+
+/// In-memory cache of season player stats, shared by all requests
+///
+/// Entries expire after a few minutes; saving a goal clears the cache so that
+/// rankings catch up right away.
+#[derive(Clone, Default)]
+pub struct SeasonStatsCache {
+    entries: Arc<Mutex<HashMap<i64, CachedSeason>>>,
+}
+
+impl SeasonStatsCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stats of a season, computed on first use or once the entry expired
+    pub async fn get(
+        &self,
+        db: &SqlitePool,
+        season_id: i64,
+    ) -> Result<Arc<Vec<SeasonPlayerStatsEntity>>, sqlx::Error> {
+        {
+            let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+            if let Some(cached) = entries.get(&season_id) {
+                if cached.computed_at.elapsed() < CACHE_TTL {
+                    return Ok(cached.stats.clone());
+                }
+            }
+        }
+
+        let stats = Arc::new(get_season_player_stats(db, season_id).await?);
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.insert(
+            season_id,
+            CachedSeason {
+                computed_at: Instant::now(),
+                stats: stats.clone(),
+            },
+        );
+        Ok(stats)
+    }
+
+    /// Drop all cached seasons
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_top_percent() {
+        let values: Vec<f64> = (1..=200).map(f64::from).collect();
+        assert_eq!(top_percent(&values, 200.0), 1);
+        assert_eq!(top_percent(&values, 191.0), 5);
+        assert_eq!(top_percent(&values, 1.0), 100);
+        // Ties share the better rank
+        assert_eq!(top_percent(&[3.0, 3.0, 1.0, 0.0], 3.0), 25);
+        assert_eq!(top_percent(&[], 1.0), 100);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_season_player_stats(pool: SqlitePool) {
+        sqlx::query(
+            "INSERT INTO player (id, name, country_id) VALUES
+                (1, 'Sniper', 34), (2, 'Setup', 34), (3, 'Bench', 34), (4, 'Guest', 34);
+             INSERT INTO player_contract (id, player_id, team_participation_id) VALUES
+                (1, 1, 1), (2, 2, 1), (3, 3, 1);
+             INSERT INTO match (id, season_id, home_team_id, away_team_id, status, game_type) VALUES
+                (1, 1, 1, 2, 'finished', 'group'), (2, 1, 1, 2, 'finished', 'group'),
+                (3, 1, 1, 2, 'scheduled', 'group'), (4, 1, 1, 2, 'finished', 'exhibition');
+             INSERT INTO match_lineup (match_id, player_contract_id) VALUES (2, 1), (2, 2);
+             INSERT INTO score_event (match_id, team_id, scorer_id, assist1_id, period) VALUES
+                (1, 1, 1, 2, 1), (2, 1, 1, NULL, 1), (1, 2, 4, NULL, 2), (4, 1, 3, NULL, 1);",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let stats = get_season_player_stats(&pool, 1).await.unwrap();
+        let by_id = |id: i64| stats.iter().find(|s| s.player_id == id).unwrap();
+
+        assert_eq!(stats.len(), 4);
+        // Dressed for both finished games
+        assert_eq!((by_id(1).games_played, by_id(1).goals), (2, 2));
+        assert_eq!((by_id(2).games_played, by_id(2).assists), (2, 1));
+        // Left out of match 2's lineup; the exhibition goal does not count
+        assert_eq!((by_id(3).games_played, by_id(3).points), (1, 0));
+        // Scored without a contract
+        assert_eq!((by_id(4).games_played, by_id(4).goals), (0, 1));
+
+        let percentiles = player_percentiles(&stats, 1).unwrap();
+        assert_eq!(percentiles.players_count, 4);
+        assert_eq!(percentiles.goals, 25);
+        assert_eq!(percentiles.points, 25);
+        assert_eq!(percentiles.points_per_game, Some(34));
+        assert_eq!(player_percentiles(&stats, 4).unwrap().points_per_game, None);
+        assert!(player_percentiles(&stats, 5).is_none());
+
+        let cache = SeasonStatsCache::new();
+        assert_eq!(*cache.get(&pool, 1).await.unwrap(), stats);
+    }
+}
@@ -1,5 +1,7 @@
 use sqlx::{Row, SqlitePool};
 
+use super::slugs::{self, SlugEntity};
+
 // Re-export common pagination types for convenience
 pub use crate::common::pagination::{PagedResult, SortOrder};
 
@@ -104,17 +106,24 @@ pub async fn create_season(
     db: &SqlitePool,
     season: CreateSeasonEntity,
 ) -> Result<i64, sqlx::Error> {
-    let result = sqlx::query!(
+    let mut tx = db.begin().await?;
+    let id = sqlx::query!(
         "INSERT INTO season (year, display_name, event_id, country_id) VALUES (?, ?, ?, ?)",
         season.year,
         season.display_name,
         season.event_id,
         season.country_id
     )
-    .execute(db)
-    .await?;
+    .execute(&mut *tx)
+    .await?
+    .last_insert_rowid();
 
-    Ok(result.last_insert_rowid())
+    if let Some(name) = slugs::slug_name(&mut tx, SlugEntity::Season, id).await? {
+        slugs::assign_slug(&mut tx, SlugEntity::Season, id, &name).await?;
+    }
+    tx.commit().await?;
+
+    Ok(id)
 }
 
 /// Get seasons with filters and pagination
@@ -236,7 +245,12 @@ pub async fn update_season(
     id: i64,
     season: UpdateSeasonEntity,
 ) -> Result<bool, sqlx::Error> {
-    let result = sqlx::query!(
+    let mut tx = db.begin().await?;
+    let Some(previous_name) = slugs::slug_name(&mut tx, SlugEntity::Season, id).await? else {
+        return Ok(false);
+    };
+
+    sqlx::query!(
         "UPDATE season SET year = ?, display_name = ?, event_id = ?, country_id = ? WHERE id = ?",
         season.year,
         season.display_name,
@@ -244,10 +258,14 @@ pub async fn update_season(
         season.country_id,
         id
     )
-    .execute(db)
+    .execute(&mut *tx)
     .await?;
 
-    Ok(result.rows_affected() > 0)
+    // Seasons have no slug field, so the slug follows the name
+    slugs::update_slug(&mut tx, SlugEntity::Season, id, &previous_name, None).await?;
+    tx.commit().await?;
+
+    Ok(true)
 }
 
 /// Delete a season
@@ -288,11 +306,32 @@ pub async fn get_events(db: &SqlitePool) -> Result<Vec<(i64, String)>, sqlx::Err
     Ok(rows.into_iter().map(|row| (row.id, row.name)).collect())
 }
 
+/// Get the current season: the one with the most recent year
+pub async fn get_current_season_id(db: &SqlitePool) -> Result<Option<i64>, sqlx::Error> {
+    let row = sqlx::query!(
+        r#"
+        SELECT id as "id!"
+        FROM season
+        ORDER BY year DESC, id DESC
+        LIMIT 1
+        "#
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(row.map(|row| row.id))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use sqlx::SqlitePool;
 
+    #[sqlx::test(migrations = "./migrations", fixtures("events", "seasons"))]
+    async fn test_get_current_season_id(pool: SqlitePool) {
+        assert_eq!(get_current_season_id(&pool).await.unwrap(), Some(3));
+    }
+
     #[sqlx::test(migrations = "./migrations", fixtures("events"))]
     async fn test_create_season(pool: SqlitePool) {
         let season = CreateSeasonEntity {
@@ -0,0 +1,197 @@
+use sqlx::SqlitePool;
+
+/// Default season filter of the matches list
+pub const MATCHES_DEFAULT_SEASON: &str = "matches.default_season";
+/// Default status filter of the matches list
+pub const MATCHES_DEFAULT_STATUS: &str = "matches.default_status";
+/// Whether visitors may sign up on the login page
+pub const REGISTRATION_ENABLED: &str = "registration.enabled";
+/// Whether an admin has to approve accounts after sign-up
+pub const REGISTRATION_REQUIRES_APPROVAL: &str = "registration.requires_approval";
+
+/// A season preset: the current (most recent) season or a fixed one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefaultSeason {
+    Current,
+    Season(i64),
+}
+
+impl DefaultSeason {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "current" => Some(Self::Current),
+            id => id.parse().ok().map(Self::Season),
+        }
+    }
+
+    pub fn as_string(&self) -> String {
+        match self {
+            Self::Current => "current".to_string(),
+            Self::Season(id) => id.to_string(),
+        }
+    }
+}
+
+/// Filters applied to the matches list when it is opened without query parameters
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MatchListDefaults {
+    pub season: Option<DefaultSeason>,
+    pub status: Option<String>,
+}
+
+impl MatchListDefaults {
+    pub fn is_empty(&self) -> bool {
+        self.season.is_none() && self.status.is_none()
+    }
+}
+
+/// Self-service registration switches, managed on the users page
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegistrationSettings {
+    pub enabled: bool,
+    pub requires_approval: bool,
+}
+
+impl Default for RegistrationSettings {
+    /// Closed, and approval required once opened
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            requires_approval: true,
+        }
+    }
+}
+
+/// Get a setting, `None` if it was never set
+pub async fn get_setting(db: &SqlitePool, key: &str) -> Result<Option<String>, sqlx::Error> {
+    let row = sqlx::query!("SELECT value FROM app_setting WHERE key = ?", key)
+        .fetch_optional(db)
+        .await?;
+
+    Ok(row.map(|row| row.value))
+}
+
+/// Store a setting; `None` removes it
+pub async fn set_setting(
+    db: &SqlitePool,
+    key: &str,
+    value: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    match value {
+        Some(value) => {
+            sqlx::query!(
+                r#"
+                INSERT INTO app_setting (key, value) VALUES (?, ?)
+                ON CONFLICT (key) DO UPDATE
+                SET value = excluded.value, updated_at = CURRENT_TIMESTAMP
+                "#,
+                key,
+                value
+            )
+            .execute(db)
+            .await?;
+        }
+        None => {
+            sqlx::query!("DELETE FROM app_setting WHERE key = ?", key)
+                .execute(db)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Get the default filters of the matches list
+pub async fn get_match_list_defaults(db: &SqlitePool) -> Result<MatchListDefaults, sqlx::Error> {
+    let season = get_setting(db, MATCHES_DEFAULT_SEASON).await?;
+    let status = get_setting(db, MATCHES_DEFAULT_STATUS).await?;
+
+    Ok(MatchListDefaults {
+        season: season.as_deref().and_then(DefaultSeason::from_str),
+        status,
+    })
+}
+
+/// Replace the default filters of the matches list
+pub async fn update_match_list_defaults(
+    db: &SqlitePool,
+    defaults: &MatchListDefaults,
+) -> Result<(), sqlx::Error> {
+    let season = defaults.season.map(|season| season.as_string());
+    set_setting(db, MATCHES_DEFAULT_SEASON, season.as_deref()).await?;
+    set_setting(db, MATCHES_DEFAULT_STATUS, defaults.status.as_deref()).await
+}
+
+/// Get the registration switches
+pub async fn get_registration_settings(
+    db: &SqlitePool,
+) -> Result<RegistrationSettings, sqlx::Error> {
+    let defaults = RegistrationSettings::default();
+    let enabled = get_setting(db, REGISTRATION_ENABLED).await?;
+    let requires_approval = get_setting(db, REGISTRATION_REQUIRES_APPROVAL).await?;
+
+    Ok(RegistrationSettings {
+        enabled: enabled.map_or(defaults.enabled, |value| value == "true"),
+        requires_approval: requires_approval
+            .map_or(defaults.requires_approval, |value| value == "true"),
+    })
+}
+
+/// Replace the registration switches
+pub async fn update_registration_settings(
+    db: &SqlitePool,
+    registration: &RegistrationSettings,
+) -> Result<(), sqlx::Error> {
+    let enabled = registration.enabled.to_string();
+    let requires_approval = registration.requires_approval.to_string();
+    set_setting(db, REGISTRATION_ENABLED, Some(&enabled)).await?;
+    set_setting(db, REGISTRATION_REQUIRES_APPROVAL, Some(&requires_approval)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_match_list_defaults(pool: SqlitePool) {
+        assert!(get_match_list_defaults(&pool).await.unwrap().is_empty());
+
+        let defaults = MatchListDefaults {
+            season: Some(DefaultSeason::Current),
+            status: Some("not_cancelled".to_string()),
+        };
+        update_match_list_defaults(&pool, &defaults).await.unwrap();
+        assert_eq!(get_match_list_defaults(&pool).await.unwrap(), defaults);
+
+        let defaults = MatchListDefaults {
+            season: Some(DefaultSeason::Season(3)),
+            status: None,
+        };
+        update_match_list_defaults(&pool, &defaults).await.unwrap();
+        assert_eq!(get_match_list_defaults(&pool).await.unwrap(), defaults);
+        assert_eq!(
+            get_setting(&pool, MATCHES_DEFAULT_STATUS).await.unwrap(),
+            None
+        );
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_registration_settings(pool: SqlitePool) {
+        assert_eq!(
+            get_registration_settings(&pool).await.unwrap(),
+            RegistrationSettings::default()
+        );
+
+        let registration = RegistrationSettings {
+            enabled: true,
+            requires_approval: false,
+        };
+        update_registration_settings(&pool, &registration)
+            .await
+            .unwrap();
+        assert_eq!(
+            get_registration_settings(&pool).await.unwrap(),
+            registration
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! Pages of the public site listed in `/sitemap.xml`
+
+use sqlx::SqlitePool;
+
+use super::public_site::{public_roster_path, public_season_path};
+
+/// A public page and the date its content last changed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SitemapEntry {
+    /// Path below the site root, e.g. `/public/seasons/2025-world-championship`
+    pub path: String,
+    /// `YYYY-MM-DD`
+    pub last_modified: Option<String>,
+}
+
+struct SeasonRow {
+    id: i64,
+    slug: Option<String>,
+    last_modified: Option<String>,
+}
+
+struct RosterRow {
+    season_id: i64,
+    season_slug: Option<String>,
+    team_id: i64,
+    team_slug: Option<String>,
+    last_modified: Option<String>,
+}
+
+/// Date part of an SQLite timestamp
+fn date_of(timestamp: Option<String>) -> Option<String> {
+    timestamp
+        .filter(|ts| ts.len() >= 10)
+        .map(|ts| ts[..10].to_string())
+}
+
+/// Every page of the public site, home page first
+///
+/// A season page changes with its event, season and matches; a roster page
+/// with its team, participation, contracts and players. The player statistics
+/// of a season share the season's date, and the home page takes the latest.
+pub async fn get_sitemap_entries(db: &SqlitePool) -> Result<Vec<SitemapEntry>, sqlx::Error> {
+    let seasons = sqlx::query_as!(
+        SeasonRow,
+        r#"
+        SELECT
+            s.id as "id!",
+            s.slug,
+            MAX(
+                s.updated_at,
+                e.updated_at,
+                COALESCE((SELECT MAX(m.updated_at) FROM match m WHERE m.season_id = s.id), ''),
+                COALESCE((SELECT MAX(se.updated_at) FROM score_event se
+                          INNER JOIN match m ON se.match_id = m.id WHERE m.season_id = s.id), '')
+            ) as "last_modified: String"
+        FROM season s
+        INNER JOIN event e ON s.event_id = e.id
+        ORDER BY s.year DESC, s.id DESC
+        "#
+    )
+    .fetch_all(db)
+    .await?;
+
+    let rosters = sqlx::query_as!(
+        RosterRow,
+        r#"
+        SELECT
+            tp.season_id as "season_id!",
+            s.slug as season_slug,
+            tp.team_id as "team_id!",
+            t.slug as team_slug,
+            MAX(
+                tp.updated_at,
+                t.updated_at,
+                COALESCE((SELECT MAX(MAX(pc.updated_at, p.updated_at)) FROM player_contract pc
+                          INNER JOIN player p ON pc.player_id = p.id
+                          WHERE pc.team_participation_id = tp.id), '')
+            ) as "last_modified: String"
+        FROM team_participation tp
+        INNER JOIN season s ON tp.season_id = s.id
+        INNER JOIN team t ON tp.team_id = t.id
+        ORDER BY tp.season_id, t.name
+        "#
+    )
+    .fetch_all(db)
+    .await?;
+
+    let latest = seasons
+        .iter()
+        .filter_map(|s| s.last_modified.clone())
+        .chain(rosters.iter().filter_map(|r| r.last_modified.clone()))
+        .max();
+
+    let mut entries = vec![SitemapEntry {
+        path: "/public".to_string(),
+        last_modified: date_of(latest),
+    }];
+    for season in seasons {
+        let path = public_season_path(season.id, season.slug.as_deref());
+        let last_modified = date_of(season.last_modified);
+        entries.push(SitemapEntry {
+            path: path.clone(),
+            last_modified: last_modified.clone(),
+        });
+        entries.push(SitemapEntry {
+            path: format!("{}/players", path),
+            last_modified,
+        });
+    }
+    entries.extend(rosters.into_iter().map(|roster| SitemapEntry {
+        path: public_roster_path(
+            roster.season_id,
+            roster.season_slug.as_deref(),
+            roster.team_id,
+            roster.team_slug.as_deref(),
+        ),
+        last_modified: date_of(roster.last_modified),
+    }));
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_get_sitemap_entries(pool: SqlitePool) {
+        sqlx::query("UPDATE season SET updated_at = '2030-01-02 10:00:00' WHERE id = 1")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let mut conn = pool.acquire().await.unwrap();
+        crate::service::slugs::assign_missing_slugs(&mut conn)
+            .await
+            .unwrap();
+        drop(conn);
+
+        let entries = get_sitemap_entries(&pool).await.unwrap();
+        let paths: Vec<&str> = entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths[0], "/public");
+        assert!(paths.contains(&"/public/seasons/2022-winter-olympics"));
+        assert!(paths.contains(&"/public/seasons/2022-winter-olympics/players"));
+        assert!(paths.contains(&"/public/seasons/2022-winter-olympics/teams/team-canada"));
+        // 1 home page, 2 pages per season, 1 per participating team
+        assert_eq!(entries.len(), 1 + 3 * 2 + 4);
+
+        let season = entries
+            .iter()
+            .find(|e| e.path == "/public/seasons/2022-winter-olympics")
+            .unwrap();
+        assert_eq!(season.last_modified.as_deref(), Some("2030-01-02"));
+        assert_eq!(entries[0].last_modified.as_deref(), Some("2030-01-02"));
+    }
+}
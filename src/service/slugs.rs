@@ -0,0 +1,603 @@
+//! URL slugs of teams, players, events and seasons
+//!
+//! A slug is generated from the name when a record is created and follows
+//! later renames until it is edited by hand. Every slug a record gives up is
+//! kept in `slug_redirect`, so old links keep working after a rename.
+
+use sqlx::{Row, SqliteConnection, SqlitePool};
+
+/// Generated slugs stay well below the 80 characters accepted from users
+const MAX_GENERATED_LENGTH: usize = 60;
+
+/// Words taken by static routes like `/teams/new` or `/players/streaks`,
+/// which win over the `/teams/{slug}` pages
+pub const RESERVED_SLUGS: [&str; 5] = ["export", "import", "list", "new", "streaks"];
+
+/// Whether a slug would be shadowed by a static route
+pub fn is_reserved_slug(slug: &str) -> bool {
+    RESERVED_SLUGS.contains(&slug)
+}
+
+/// Kind of record a slug points to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlugEntity {
+    Team,
+    Player,
+    Event,
+    Season,
+}
+
+impl SlugEntity {
+    pub const ALL: [SlugEntity; 4] = [
+        SlugEntity::Team,
+        SlugEntity::Player,
+        SlugEntity::Event,
+        SlugEntity::Season,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SlugEntity::Team => "team",
+            SlugEntity::Player => "player",
+            SlugEntity::Event => "event",
+            SlugEntity::Season => "season",
+        }
+    }
+
+    /// Table holding the records (whitelisted, safe to format into SQL)
+    fn table(&self) -> &'static str {
+        match self {
+            SlugEntity::Team => "team",
+            SlugEntity::Player => "player",
+            SlugEntity::Event => "event",
+            SlugEntity::Season => "season",
+        }
+    }
+
+    /// Name the slug is generated from, as an SQL expression over the table
+    ///
+    /// Seasons are named like on their pages: the display name, or the event
+    /// name and year when they have none.
+    fn name_sql(&self) -> &'static str {
+        match self {
+            SlugEntity::Season => {
+                "COALESCE(display_name, (SELECT name FROM event WHERE event.id = season.event_id) || ' ' || year)"
+            }
+            _ => "name",
+        }
+    }
+}
+
+/// Where a slug leads
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlugTarget {
+    /// The record currently using the slug
+    Current(i64),
+    /// The slug was renamed, the record now uses this one
+    Moved(String),
+}
+
+/// ASCII spelling of a lowercase accented Latin letter, e.g. 'ř' -> "r"
+pub(crate) fn transliterate(c: char) -> Option<&'static str> {
+    match c {
+        'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' | 'ā' | 'ą' => Some("a"),
+        'æ' => Some("ae"),
+        'č' | 'ç' | 'ć' => Some("c"),
+        'ď' | 'đ' => Some("d"),
+        'é' | 'è' | 'ê' | 'ë' | 'ě' | 'ē' | 'ę' => Some("e"),
+        'í' | 'ì' | 'î' | 'ï' | 'ī' => Some("i"),
+        'ľ' | 'ĺ' | 'ł' => Some("l"),
+        'ň' | 'ñ' | 'ń' => Some("n"),
+        'ó' | 'ò' | 'ô' | 'ö' | 'õ' | 'ø' | 'ő' | 'ō' => Some("o"),
+        'œ' => Some("oe"),
+        'ř' | 'ŕ' => Some("r"),
+        'š' | 'ś' | 'ş' => Some("s"),
+        'ß' => Some("ss"),
+        'ť' | 'ţ' => Some("t"),
+        'ú' | 'ù' | 'û' | 'ü' | 'ů' | 'ű' | 'ū' => Some("u"),
+        'ý' | 'ÿ' => Some("y"),
+        'ž' | 'ź' | 'ż' => Some("z"),
+        _ => None,
+    }
+}
+
+/// Lowercase ASCII slug of a name, e.g. "HC Dynamo Pardubice" -> "hc-dynamo-pardubice"
+///
+/// Accented Latin letters are transliterated, anything else separates words.
+/// Names without letters fall back to the entity type, purely numeric slugs
+/// get it as a prefix so they cannot be mistaken for IDs, and reserved words
+/// get it as a suffix so they cannot be mistaken for static routes.
+pub fn slugify(entity: SlugEntity, name: &str) -> String {
+    let mut slug = String::with_capacity(name.len());
+    for c in name.to_lowercase().chars() {
+        let mapped = match c {
+            'a'..='z' | '0'..='9' => Some(c.to_string()),
+            _ => transliterate(c).map(str::to_string),
+        };
+        match mapped {
+            Some(part) => slug.push_str(&part),
+            None if !slug.is_empty() && !slug.ends_with('-') => slug.push('-'),
+            None => {}
+        }
+    }
+
+    slug.truncate(MAX_GENERATED_LENGTH);
+    let slug = slug.trim_matches('-');
+
+    if slug.is_empty() {
+        entity.as_str().to_string()
+    } else if slug.chars().all(|c| c.is_ascii_digit()) {
+        format!("{}-{}", entity.as_str(), slug)
+    } else if is_reserved_slug(slug) {
+        format!("{}-{}", slug, entity.as_str())
+    } else {
+        slug.to_string()
+    }
+}
+
+/// Whether a slug is the one generated from a name, possibly with a numeric suffix
+fn is_generated_from(entity: SlugEntity, slug: &str, name: &str) -> bool {
+    let base = slugify(entity, name);
+    slug == base
+        || slug
+            .strip_prefix(&base)
+            .and_then(|rest| rest.strip_prefix('-'))
+            .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Whether another record of the type already uses the slug
+pub async fn is_slug_taken(
+    db: &SqlitePool,
+    entity: SlugEntity,
+    slug: &str,
+    exclude_id: Option<i64>,
+) -> Result<bool, sqlx::Error> {
+    let mut conn = db.acquire().await?;
+    slug_in_use(&mut conn, entity, slug, exclude_id.unwrap_or(0)).await
+}
+
+async fn slug_in_use(
+    conn: &mut SqliteConnection,
+    entity: SlugEntity,
+    slug: &str,
+    exclude_id: i64,
+) -> Result<bool, sqlx::Error> {
+    let sql = format!(
+        "SELECT EXISTS(SELECT 1 FROM {} WHERE slug = ? AND id != ?)",
+        entity.table()
+    );
+    let taken: bool = sqlx::query_scalar(&sql)
+        .bind(slug)
+        .bind(exclude_id)
+        .fetch_one(&mut *conn)
+        .await?;
+
+    Ok(taken)
+}
+
+/// Free slug generated from a name, adding `-2`, `-3`, ... when needed
+async fn unique_slug(
+    conn: &mut SqliteConnection,
+    entity: SlugEntity,
+    id: i64,
+    name: &str,
+) -> Result<String, sqlx::Error> {
+    let base = slugify(entity, name);
+    let mut candidate = base.clone();
+    let mut n = 2;
+    while slug_in_use(conn, entity, &candidate, id).await? {
+        candidate = format!("{}-{}", base, n);
+        n += 1;
+    }
+
+    Ok(candidate)
+}
+
+/// Set a record's slug, keeping the previous one as a redirect
+async fn set_slug(
+    conn: &mut SqliteConnection,
+    entity: SlugEntity,
+    id: i64,
+    previous: Option<&str>,
+    slug: &str,
+) -> Result<(), sqlx::Error> {
+    let entity_type = entity.as_str();
+
+    // The slug is current again, so it no longer redirects anywhere
+    sqlx::query!(
+        "DELETE FROM slug_redirect WHERE entity_type = ? AND slug = ?",
+        entity_type,
+        slug
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    if let Some(previous) = previous {
+        sqlx::query!(
+            r#"
+            INSERT INTO slug_redirect (entity_type, slug, entity_id)
+            VALUES (?, ?, ?)
+            ON CONFLICT (entity_type, slug) DO UPDATE SET entity_id = excluded.entity_id
+            "#,
+            entity_type,
+            previous,
+            id
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    let sql = format!("UPDATE {} SET slug = ? WHERE id = ?", entity.table());
+    sqlx::query(&sql)
+        .bind(slug)
+        .bind(id)
+        .execute(&mut *conn)
+        .await?;
+
+    Ok(())
+}
+
+/// Give a new record a slug generated from its name
+pub async fn assign_slug(
+    conn: &mut SqliteConnection,
+    entity: SlugEntity,
+    id: i64,
+    name: &str,
+) -> Result<String, sqlx::Error> {
+    let slug = unique_slug(conn, entity, id, name).await?;
+    set_slug(conn, entity, id, None, &slug).await?;
+
+    Ok(slug)
+}
+
+/// Update a record's slug after it was saved
+///
+/// `requested` is the validated slug from the edit form, its uniqueness must
+/// be checked beforehand. A changed slug is taken as is, an empty one is
+/// generated from the name again. An unchanged slug that was generated from
+/// the previous name follows a rename, while a hand-edited one is kept.
+pub async fn update_slug(
+    conn: &mut SqliteConnection,
+    entity: SlugEntity,
+    id: i64,
+    previous_name: &str,
+    requested: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    let sql = format!(
+        "SELECT {} AS name, slug FROM {} WHERE id = ?",
+        entity.name_sql(),
+        entity.table()
+    );
+    let Some(row) = sqlx::query(&sql)
+        .bind(id)
+        .fetch_optional(&mut *conn)
+        .await?
+    else {
+        return Ok(());
+    };
+    let name: String = row.get("name");
+    let current: Option<String> = row.get("slug");
+
+    let generated_from = |name: &str| {
+        current
+            .as_deref()
+            .is_some_and(|c| is_generated_from(entity, c, name))
+    };
+    let slug = match requested {
+        Some(requested) if current.as_deref() != Some(requested) => requested.to_string(),
+        Some(_) if !generated_from(previous_name) => return Ok(()),
+        _ if generated_from(&name) => return Ok(()),
+        _ => unique_slug(conn, entity, id, &name).await?,
+    };
+
+    if current.as_deref() != Some(slug.as_str()) {
+        set_slug(conn, entity, id, current.as_deref(), &slug).await?;
+    }
+
+    Ok(())
+}
+
+/// Point the slugs of a record that is going away at another record
+///
+/// The record's current slug becomes a redirect together with the ones it
+/// already had; call this before deleting it, which drops its redirects.
+pub async fn redirect_slugs(
+    conn: &mut SqliteConnection,
+    entity: SlugEntity,
+    from_id: i64,
+    to_id: i64,
+) -> Result<(), sqlx::Error> {
+    let entity_type = entity.as_str();
+    sqlx::query!(
+        "UPDATE slug_redirect SET entity_id = ? WHERE entity_type = ? AND entity_id = ?",
+        to_id,
+        entity_type,
+        from_id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    let sql = format!("SELECT slug FROM {} WHERE id = ?", entity.table());
+    let slug: Option<String> = sqlx::query_scalar(&sql)
+        .bind(from_id)
+        .fetch_optional(&mut *conn)
+        .await?
+        .flatten();
+    if let Some(slug) = slug {
+        sqlx::query!(
+            r#"
+            INSERT INTO slug_redirect (entity_type, slug, entity_id)
+            VALUES (?, ?, ?)
+            ON CONFLICT (entity_type, slug) DO UPDATE SET entity_id = excluded.entity_id
+            "#,
+            entity_type,
+            slug,
+            to_id
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Generate slugs for records created without one (imports, older data)
+pub async fn assign_missing_slugs(conn: &mut SqliteConnection) -> Result<u64, sqlx::Error> {
+    let mut assigned = 0;
+    for entity in SlugEntity::ALL {
+        let sql = format!(
+            "SELECT id, {} AS name FROM {} WHERE slug IS NULL ORDER BY id",
+            entity.name_sql(),
+            entity.table()
+        );
+        let rows = sqlx::query(&sql).fetch_all(&mut *conn).await?;
+        for row in rows {
+            let id: i64 = row.get("id");
+            let name: String = row.get("name");
+            assign_slug(conn, entity, id, &name).await?;
+            assigned += 1;
+        }
+    }
+
+    Ok(assigned)
+}
+
+/// Find the record behind a slug, following redirects from renamed slugs
+pub async fn resolve_slug(
+    db: &SqlitePool,
+    entity: SlugEntity,
+    slug: &str,
+) -> Result<Option<SlugTarget>, sqlx::Error> {
+    let sql = format!("SELECT id FROM {} WHERE slug = ?", entity.table());
+    let current: Option<i64> = sqlx::query_scalar(&sql)
+        .bind(slug)
+        .fetch_optional(db)
+        .await?;
+    if let Some(id) = current {
+        return Ok(Some(SlugTarget::Current(id)));
+    }
+
+    let entity_type = entity.as_str();
+    let redirect = sqlx::query_scalar!(
+        "SELECT entity_id FROM slug_redirect WHERE entity_type = ? AND slug = ?",
+        entity_type,
+        slug
+    )
+    .fetch_optional(db)
+    .await?;
+    let Some(id) = redirect else {
+        return Ok(None);
+    };
+
+    let sql = format!("SELECT slug FROM {} WHERE id = ?", entity.table());
+    let moved: Option<String> = sqlx::query_scalar(&sql)
+        .bind(id)
+        .fetch_optional(db)
+        .await?
+        .flatten();
+
+    Ok(moved.map(SlugTarget::Moved))
+}
+
+/// Name a record's slug is generated from, e.g. before renaming it
+pub async fn slug_name(
+    conn: &mut SqliteConnection,
+    entity: SlugEntity,
+    id: i64,
+) -> Result<Option<String>, sqlx::Error> {
+    let sql = format!(
+        "SELECT {} FROM {} WHERE id = ?",
+        entity.name_sql(),
+        entity.table()
+    );
+    sqlx::query_scalar(&sql)
+        .bind(id)
+        .fetch_optional(&mut *conn)
+        .await
+}
+
+/// Current slug of a record
+pub async fn get_slug(
+    db: &SqlitePool,
+    entity: SlugEntity,
+    id: i64,
+) -> Result<Option<String>, sqlx::Error> {
+    let sql = format!("SELECT slug FROM {} WHERE id = ?", entity.table());
+    let slug: Option<Option<String>> = sqlx::query_scalar(&sql).bind(id).fetch_optional(db).await?;
+
+    Ok(slug.flatten())
+}
+
+/// Record behind a path segment: a numeric ID, its slug or one it gave up
+pub async fn resolve_key(
+    db: &SqlitePool,
+    entity: SlugEntity,
+    key: &str,
+) -> Result<Option<i64>, sqlx::Error> {
+    if let Ok(id) = key.parse::<i64>() {
+        return Ok(Some(id));
+    }
+
+    let sql = format!("SELECT id FROM {} WHERE slug = ?", entity.table());
+    let current: Option<i64> = sqlx::query_scalar(&sql)
+        .bind(key)
+        .fetch_optional(db)
+        .await?;
+    if current.is_some() {
+        return Ok(current);
+    }
+
+    let entity_type = entity.as_str();
+    sqlx::query_scalar!(
+        "SELECT entity_id FROM slug_redirect WHERE entity_type = ? AND slug = ?",
+        entity_type,
+        key
+    )
+    .fetch_optional(db)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(
+            slugify(SlugEntity::Team, "HC Dynamo Pardubice"),
+            "hc-dynamo-pardubice"
+        );
+        assert_eq!(slugify(SlugEntity::Player, "Jaromír Jágr"), "jaromir-jagr");
+        assert_eq!(
+            slugify(SlugEntity::Player, "  Teemu Selänne (Jr.) "),
+            "teemu-selanne-jr"
+        );
+        assert_eq!(slugify(SlugEntity::Event, "1980"), "event-1980");
+        assert_eq!(slugify(SlugEntity::Team, "ЦСКА"), "team");
+    }
+
+    #[test]
+    fn test_slugify_avoids_static_routes() {
+        for (entity, name, path) in [
+            (SlugEntity::Team, "New", "/teams/new"),
+            (SlugEntity::Team, "Import", "/teams/import"),
+            (SlugEntity::Team, "Export", "/teams/export"),
+            (SlugEntity::Player, "New", "/players/new"),
+            (SlugEntity::Player, "Import", "/players/import"),
+            (SlugEntity::Player, "Export", "/players/export"),
+            (SlugEntity::Player, "Streaks", "/players/streaks"),
+            (SlugEntity::Event, "New", "/events/new"),
+        ] {
+            let slug = slugify(entity, name);
+            assert!(!path.ends_with(&format!("/{}", slug)), "{path}");
+            assert_eq!(slug, format!("{}-{}", name.to_lowercase(), entity.as_str()));
+            assert!(
+                crate::validation::validate_slug(Some(name)).is_err(),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_is_generated_from() {
+        assert!(is_generated_from(SlugEntity::Team, "canada", "Canada"));
+        assert!(is_generated_from(SlugEntity::Team, "canada-3", "Canada"));
+        assert!(!is_generated_from(SlugEntity::Team, "canada-u20", "Canada"));
+        assert!(!is_generated_from(
+            SlugEntity::Team,
+            "team-canada",
+            "Canada"
+        ));
+    }
+
+    #[sqlx::test(migrations = "./migrations", fixtures("teams"))]
+    async fn test_slugs_follow_renames_and_redirect(pool: SqlitePool) {
+        let mut conn = pool.acquire().await.unwrap();
+        assign_missing_slugs(&mut conn).await.unwrap();
+        assert_eq!(
+            resolve_slug(&pool, SlugEntity::Team, "team-canada")
+                .await
+                .unwrap(),
+            Some(SlugTarget::Current(1))
+        );
+
+        // Same name gets a suffix
+        sqlx::query("INSERT INTO team (id, name) VALUES (10, 'Team Canada')")
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+        assert_eq!(
+            assign_slug(&mut conn, SlugEntity::Team, 10, "Team Canada")
+                .await
+                .unwrap(),
+            "team-canada-2"
+        );
+
+        // A generated slug follows a rename, the old one redirects
+        rename(&mut conn, 1, "Canada").await;
+        update_slug(
+            &mut conn,
+            SlugEntity::Team,
+            1,
+            "Team Canada",
+            Some("team-canada"),
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            resolve_slug(&pool, SlugEntity::Team, "team-canada")
+                .await
+                .unwrap(),
+            Some(SlugTarget::Moved("canada".to_string()))
+        );
+
+        // A hand-edited slug stays through renames
+        update_slug(&mut conn, SlugEntity::Team, 1, "Canada", Some("can"))
+            .await
+            .unwrap();
+        rename(&mut conn, 1, "Kanada").await;
+        update_slug(&mut conn, SlugEntity::Team, 1, "Canada", Some("can"))
+            .await
+            .unwrap();
+        assert_eq!(
+            resolve_slug(&pool, SlugEntity::Team, "can").await.unwrap(),
+            Some(SlugTarget::Current(1))
+        );
+        assert_eq!(
+            resolve_slug(&pool, SlugEntity::Team, "canada")
+                .await
+                .unwrap(),
+            Some(SlugTarget::Moved("can".to_string()))
+        );
+        assert!(is_slug_taken(&pool, SlugEntity::Team, "can", Some(2))
+            .await
+            .unwrap());
+        assert!(!is_slug_taken(&pool, SlugEntity::Team, "can", Some(1))
+            .await
+            .unwrap());
+
+        // Clearing the slug generates it from the name again
+        update_slug(&mut conn, SlugEntity::Team, 1, "Kanada", None)
+            .await
+            .unwrap();
+        assert_eq!(
+            resolve_slug(&pool, SlugEntity::Team, "can").await.unwrap(),
+            Some(SlugTarget::Moved("kanada".to_string()))
+        );
+        assert_eq!(
+            resolve_slug(&pool, SlugEntity::Team, "missing")
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    async fn rename(conn: &mut SqliteConnection, id: i64, name: &str) {
+        sqlx::query("UPDATE team SET name = ? WHERE id = ?")
+            .bind(name)
+            .bind(id)
+            .execute(&mut *conn)
+            .await
+            .unwrap();
+    }
+}
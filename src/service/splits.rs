@@ -0,0 +1,354 @@
+use sqlx::SqlitePool;
+
+/// A team's record in one venue (home or away)
+///
+/// `wins` and `losses` include games decided in overtime or a shootout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VenueRecord {
+    pub games: i64,
+    pub wins: i64,
+    pub ties: i64,
+    pub losses: i64,
+    pub goals_for: i64,
+    pub goals_against: i64,
+}
+
+impl VenueRecord {
+    pub fn goal_difference(&self) -> i64 {
+        self.goals_for - self.goals_against
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.games += other.games;
+        self.wins += other.wins;
+        self.ties += other.ties;
+        self.losses += other.losses;
+        self.goals_for += other.goals_for;
+        self.goals_against += other.goals_against;
+    }
+}
+
+/// Home and away records of a team in one season
+#[derive(Debug, Clone)]
+pub struct TeamSeasonSplitEntity {
+    pub season_id: i64,
+    pub season_year: i64,
+    pub season_display_name: Option<String>,
+    pub event_name: String,
+    pub home: VenueRecord,
+    pub away: VenueRecord,
+}
+
+/// A player's scoring in one venue (home or away)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PointsSplit {
+    pub goals: i64,
+    pub assists: i64,
+}
+
+impl PointsSplit {
+    pub fn points(&self) -> i64 {
+        self.goals + self.assists
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.goals += other.goals;
+        self.assists += other.assists;
+    }
+}
+
+/// Home and away scoring of a player in one season
+#[derive(Debug, Clone)]
+pub struct PlayerSeasonSplitEntity {
+    pub season_id: i64,
+    pub season_year: i64,
+    pub season_display_name: Option<String>,
+    pub event_name: String,
+    pub home: PointsSplit,
+    pub away: PointsSplit,
+}
+
+/// Career totals of team splits
+pub fn team_split_totals(splits: &[TeamSeasonSplitEntity]) -> (VenueRecord, VenueRecord) {
+    splits.iter().fold(
+        (VenueRecord::default(), VenueRecord::default()),
+        |(mut home, mut away), split| {
+            home.add(&split.home);
+            away.add(&split.away);
+            (home, away)
+        },
+    )
+}
+
+/// Career totals of player splits
+pub fn player_split_totals(splits: &[PlayerSeasonSplitEntity]) -> (PointsSplit, PointsSplit) {
+    splits.iter().fold(
+        (PointsSplit::default(), PointsSplit::default()),
+        |(mut home, mut away), split| {
+            home.add(&split.home);
+            away.add(&split.away);
+            (home, away)
+        },
+    )
+}
+
+/// Home and away records of a team per season, newest first
+///
+/// Only finished matches with a stored result count, and exhibition games are
+/// left out. Scores include unidentified goals.
+pub async fn get_team_splits(
+    db: &SqlitePool,
+    team_id: i64,
+) -> Result<Vec<TeamSeasonSplitEntity>, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        WITH games AS (
+            SELECT
+                m.season_id,
+                m.home_team_id = ?1 as is_home,
+                CASE WHEN m.home_team_id = ?1 THEN 'home_' ELSE 'away_' END as side,
+                m.result,
+                m.home_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id) as home_score,
+                m.away_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.away_team_id) as away_score
+            FROM match m
+            WHERE (m.home_team_id = ?1 OR m.away_team_id = ?1)
+              AND m.result IS NOT NULL
+              AND m.game_type != 'exhibition'
+        )
+        SELECT
+            s.id as "season_id!",
+            s.year as "season_year!",
+            s.display_name as season_display_name,
+            e.name as "event_name!",
+            g.is_home as "is_home!: bool",
+            COUNT(*) as "games!: i64",
+            SUM(g.result LIKE g.side || '%') as "wins!: i64",
+            SUM(g.result = 'draw') as "ties!: i64",
+            SUM(g.result <> 'draw' AND g.result NOT LIKE g.side || '%') as "losses!: i64",
+            SUM(CASE WHEN g.is_home THEN g.home_score ELSE g.away_score END) as "goals_for!: i64",
+            SUM(CASE WHEN g.is_home THEN g.away_score ELSE g.home_score END) as "goals_against!: i64"
+        FROM games g
+        INNER JOIN season s ON s.id = g.season_id
+        INNER JOIN event e ON e.id = s.event_id
+        GROUP BY s.id, s.year, s.display_name, e.name, g.is_home
+        ORDER BY s.year DESC, e.name ASC, s.id
+        "#,
+        team_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    let mut splits: Vec<TeamSeasonSplitEntity> = Vec::new();
+    for row in rows {
+        let record = VenueRecord {
+            games: row.games,
+            wins: row.wins,
+            ties: row.ties,
+            losses: row.losses,
+            goals_for: row.goals_for,
+            goals_against: row.goals_against,
+        };
+        let split = match splits.last_mut() {
+            Some(split) if split.season_id == row.season_id => split,
+            _ => {
+                splits.push(TeamSeasonSplitEntity {
+                    season_id: row.season_id,
+                    season_year: row.season_year,
+                    season_display_name: row.season_display_name,
+                    event_name: row.event_name,
+                    home: VenueRecord::default(),
+                    away: VenueRecord::default(),
+                });
+                splits.last_mut().expect("split was just pushed")
+            }
+        };
+        if row.is_home {
+            split.home = record;
+        } else {
+            split.away = record;
+        }
+    }
+    Ok(splits)
+}
+
+/// Goals and assists of a player at home and away per season, newest first
+///
+/// The venue follows the team the goal was scored for. Exhibition games are
+/// not counted, matching the player's season statistics.
+pub async fn get_player_splits(
+    db: &SqlitePool,
+    player_id: i64,
+) -> Result<Vec<PlayerSeasonSplitEntity>, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            s.id as "season_id!",
+            s.year as "season_year!",
+            s.display_name as season_display_name,
+            e.name as "event_name!",
+            COALESCE(SUM(se.scorer_id = ?1 AND se.team_id = m.home_team_id), 0) as "home_goals!: i64",
+            COALESCE(SUM((se.assist1_id = ?1 OR se.assist2_id = ?1) AND se.team_id = m.home_team_id), 0) as "home_assists!: i64",
+            COALESCE(SUM(se.scorer_id = ?1 AND se.team_id = m.away_team_id), 0) as "away_goals!: i64",
+            COALESCE(SUM((se.assist1_id = ?1 OR se.assist2_id = ?1) AND se.team_id = m.away_team_id), 0) as "away_assists!: i64"
+        FROM score_event se
+        INNER JOIN match m ON m.id = se.match_id
+        INNER JOIN season s ON s.id = m.season_id
+        INNER JOIN event e ON e.id = s.event_id
+        WHERE (se.scorer_id = ?1 OR se.assist1_id = ?1 OR se.assist2_id = ?1)
+          AND m.game_type != 'exhibition'
+        GROUP BY s.id, s.year, s.display_name, e.name
+        ORDER BY s.year DESC, e.name ASC
+        "#,
+        player_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| PlayerSeasonSplitEntity {
+            season_id: row.season_id,
+            season_year: row.season_year,
+            season_display_name: row.season_display_name,
+            event_name: row.event_name,
+            home: PointsSplit {
+                goals: row.home_goals,
+                assists: row.home_assists,
+            },
+            away: PointsSplit {
+                goals: row.away_goals,
+                assists: row.away_assists,
+            },
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{
+        create_match, create_score_event, CreateMatchEntity, CreateScoreEventEntity,
+    };
+
+    async fn finished_match(
+        pool: &SqlitePool,
+        season_id: i64,
+        home: (i64, i32),
+        away: (i64, i32),
+        game_type: &str,
+    ) -> i64 {
+        create_match(
+            pool,
+            CreateMatchEntity {
+                season_id,
+                home_team_id: home.0,
+                away_team_id: away.0,
+                home_score_unidentified: home.1,
+                away_score_unidentified: away.1,
+                match_date: None,
+                status: "finished".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: game_type.to_string(),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    async fn goal(pool: &SqlitePool, match_id: i64, team_id: i64, scorer: i64, assist: i64) {
+        create_score_event(
+            pool,
+            CreateScoreEventEntity {
+                match_id,
+                team_id,
+                scorer_id: Some(scorer),
+                assist1_id: Some(assist),
+                assist2_id: None,
+                period: 1,
+                time_minutes: None,
+                time_seconds: None,
+                goal_type: None,
+                video_url: None,
+                video_timestamp: None,
+            },
+        )
+        .await
+        .unwrap();
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_team_splits(pool: SqlitePool) {
+        finished_match(&pool, 1, (1, 4), (2, 1), "group").await;
+        finished_match(&pool, 1, (1, 2), (2, 2), "group").await;
+        finished_match(&pool, 1, (2, 3), (1, 2), "playoff").await;
+        finished_match(&pool, 1, (2, 0), (1, 9), "exhibition").await;
+
+        let splits = get_team_splits(&pool, 1).await.unwrap();
+        assert_eq!(splits.len(), 1);
+        let split = &splits[0];
+        assert_eq!(split.event_name, "Winter Olympics");
+        assert_eq!(
+            split.home,
+            VenueRecord {
+                games: 2,
+                wins: 1,
+                ties: 1,
+                losses: 0,
+                goals_for: 6,
+                goals_against: 3,
+            }
+        );
+        assert_eq!(
+            split.away,
+            VenueRecord {
+                games: 1,
+                wins: 0,
+                ties: 0,
+                losses: 1,
+                goals_for: 2,
+                goals_against: 3,
+            }
+        );
+        assert_eq!(team_split_totals(&splits).0.goal_difference(), 3);
+
+        let usa = get_team_splits(&pool, 2).await.unwrap();
+        assert_eq!(usa[0].home.wins, 1);
+        assert_eq!(usa[0].away.losses, 1);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_player_splits(pool: SqlitePool) {
+        let home = finished_match(&pool, 1, (1, 0), (2, 0), "group").await;
+        let away = finished_match(&pool, 1, (2, 0), (1, 0), "group").await;
+        let exhibition = finished_match(&pool, 1, (2, 0), (1, 0), "exhibition").await;
+        goal(&pool, home, 1, 1, 2).await;
+        goal(&pool, home, 1, 2, 1).await;
+        goal(&pool, away, 1, 1, 2).await;
+        goal(&pool, exhibition, 1, 1, 2).await;
+
+        let splits = get_player_splits(&pool, 1).await.unwrap();
+        assert_eq!(splits.len(), 1);
+        assert_eq!(
+            splits[0].home,
+            PointsSplit {
+                goals: 1,
+                assists: 1
+            }
+        );
+        assert_eq!(
+            splits[0].away,
+            PointsSplit {
+                goals: 1,
+                assists: 0
+            }
+        );
+        assert_eq!(player_split_totals(&splits).0.points(), 2);
+    }
+}
@@ -0,0 +1,578 @@
+use sqlx::{Row, SqlitePool};
+
+use crate::common::pagination::PagedResult;
+
+pub use crate::common::pagination::SortOrder;
+
+#[derive(Debug, Clone)]
+pub struct StaffMemberEntity {
+    pub id: i64,
+    pub name: String,
+    pub country_id: Option<i64>,
+    pub country_name: Option<String>,
+    pub country_iso2_code: Option<String>,
+    /// Team participations this person is contracted to, in any role
+    pub contract_count: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateStaffMemberEntity {
+    pub name: String,
+    pub country_id: Option<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct UpdateStaffMemberEntity {
+    pub name: String,
+    pub country_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StaffFilters {
+    pub name: Option<String>,
+    pub country_id: Option<i64>,
+}
+
+/// Sortable fields for staff members
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SortField {
+    Id,
+    Name,
+    Country,
+    Contracts,
+}
+
+impl SortField {
+    pub fn from_str(s: &str) -> Self {
+        match s.to_lowercase().as_str() {
+            "id" => Self::Id,
+            "country" => Self::Country,
+            "contracts" => Self::Contracts,
+            _ => Self::Name,
+        }
+    }
+
+    pub fn to_sql(&self) -> &'static str {
+        match self {
+            Self::Id => "sm.id",
+            Self::Name => "sm.name",
+            Self::Country => "c.name",
+            Self::Contracts => "contract_count",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Id => "id",
+            Self::Name => "name",
+            Self::Country => "country",
+            Self::Contracts => "contracts",
+        }
+    }
+}
+
+/// Role of a staff member within a team participation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StaffRole {
+    HeadCoach,
+    AssistantCoach,
+    GeneralManager,
+}
+
+impl StaffRole {
+    pub const ALL: [StaffRole; 3] = [
+        StaffRole::HeadCoach,
+        StaffRole::AssistantCoach,
+        StaffRole::GeneralManager,
+    ];
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "head_coach" => Some(Self::HeadCoach),
+            "assistant_coach" => Some(Self::AssistantCoach),
+            "general_manager" => Some(Self::GeneralManager),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::HeadCoach => "head_coach",
+            Self::AssistantCoach => "assistant_coach",
+            Self::GeneralManager => "general_manager",
+        }
+    }
+}
+
+/// A staff contract within a team participation
+#[derive(Debug, Clone)]
+pub struct StaffContractEntity {
+    pub staff_contract_id: i64,
+    #[allow(dead_code)]
+    pub team_participation_id: i64,
+    #[allow(dead_code)]
+    pub staff_member_id: i64,
+    pub name: String,
+    pub role: String,
+    pub country_name: Option<String>,
+    pub country_iso2_code: Option<String>,
+}
+
+impl StaffContractEntity {
+    pub fn role(&self) -> Option<StaffRole> {
+        StaffRole::from_str(&self.role)
+    }
+}
+
+/// Bench staff of one side of a match
+#[derive(Debug, Clone)]
+pub struct MatchStaffEntity {
+    pub team_id: i64,
+    pub name: String,
+    pub role: String,
+    pub country_iso2_code: Option<String>,
+}
+
+impl MatchStaffEntity {
+    pub fn role(&self) -> Option<StaffRole> {
+        StaffRole::from_str(&self.role)
+    }
+}
+
+/// Create a new staff member
+pub async fn create_staff_member(
+    db: &SqlitePool,
+    staff_member: CreateStaffMemberEntity,
+) -> Result<i64, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO staff_member (name, country_id)
+        VALUES (?, ?)
+        "#,
+        staff_member.name,
+        staff_member.country_id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Get staff members with filters, sorting, and pagination
+pub async fn get_staff_members(
+    db: &SqlitePool,
+    filters: &StaffFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+    page: usize,
+    page_size: usize,
+) -> Result<PagedResult<StaffMemberEntity>, sqlx::Error> {
+    // Build count query
+    let mut count_query = sqlx::QueryBuilder::new(
+        "SELECT COUNT(*) as count FROM staff_member sm LEFT JOIN country c ON sm.country_id = c.id WHERE 1=1",
+    );
+    apply_filters(&mut count_query, filters);
+
+    let total: i64 = count_query.build().fetch_one(db).await?.get("count");
+
+    // Build data query
+    let mut data_query = sqlx::QueryBuilder::new(
+        "SELECT sm.id, sm.name, sm.country_id, c.name as country_name, c.iso2Code as country_iso2_code,
+                (SELECT COUNT(DISTINCT sc.team_participation_id) FROM staff_contract sc WHERE sc.staff_member_id = sm.id) as contract_count
+         FROM staff_member sm
+         LEFT JOIN country c ON sm.country_id = c.id
+         WHERE 1=1",
+    );
+    apply_filters(&mut data_query, filters);
+
+    // Apply sorting
+    // SECURITY: Using .push() method to safely append enum values.
+    // This prevents SQL injection as values come from trusted enum matches.
+    data_query
+        .push(" ORDER BY ")
+        .push(sort_field.to_sql())
+        .push(" ")
+        .push(sort_order.to_sql())
+        .push(", sm.name ASC");
+
+    // Apply pagination
+    let offset = (page - 1) * page_size;
+    data_query.push(" LIMIT ").push_bind(page_size as i64);
+    data_query.push(" OFFSET ").push_bind(offset as i64);
+
+    let rows = data_query.build().fetch_all(db).await?;
+
+    let items = rows
+        .into_iter()
+        .map(|row| StaffMemberEntity {
+            id: row.get("id"),
+            name: row.get("name"),
+            country_id: row.get("country_id"),
+            country_name: row.get("country_name"),
+            country_iso2_code: row.get("country_iso2_code"),
+            contract_count: row.get("contract_count"),
+        })
+        .collect();
+
+    Ok(PagedResult::new(items, total as usize, page, page_size))
+}
+
+/// Get a single staff member by ID
+pub async fn get_staff_member_by_id(
+    db: &SqlitePool,
+    id: i64,
+) -> Result<Option<StaffMemberEntity>, sqlx::Error> {
+    let row = sqlx::query_as!(
+        StaffMemberEntity,
+        r#"
+        SELECT
+            sm.id,
+            sm.name,
+            sm.country_id,
+            c.name as country_name,
+            c.iso2Code as country_iso2_code,
+            (SELECT COUNT(DISTINCT sc.team_participation_id) FROM staff_contract sc WHERE sc.staff_member_id = sm.id) as "contract_count!: i64"
+        FROM staff_member sm
+        LEFT JOIN country c ON sm.country_id = c.id
+        WHERE sm.id = ?
+        "#,
+        id
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(row)
+}
+
+/// Get all staff members for dropdowns
+pub async fn get_staff_members_simple(db: &SqlitePool) -> Result<Vec<(i64, String)>, sqlx::Error> {
+    let rows = sqlx::query!("SELECT id, name FROM staff_member ORDER BY name ASC")
+        .fetch_all(db)
+        .await?;
+
+    Ok(rows.into_iter().map(|row| (row.id, row.name)).collect())
+}
+
+/// Update a staff member
+pub async fn update_staff_member(
+    db: &SqlitePool,
+    id: i64,
+    staff_member: UpdateStaffMemberEntity,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE staff_member
+        SET name = ?, country_id = ?, updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        staff_member.name,
+        staff_member.country_id,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete a staff member (also removes their contracts)
+pub async fn delete_staff_member(db: &SqlitePool, id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        DELETE FROM staff_member
+        WHERE id = ?
+        "#,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Get the staff of a team participation, head coach first
+pub async fn get_participation_staff(
+    db: &SqlitePool,
+    team_participation_id: i64,
+) -> Result<Vec<StaffContractEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        StaffContractEntity,
+        r#"
+        SELECT
+            sc.id as "staff_contract_id!",
+            sc.team_participation_id,
+            sm.id as "staff_member_id!",
+            sm.name,
+            sc.role,
+            c.name as country_name,
+            c.iso2Code as country_iso2_code
+        FROM staff_contract sc
+        INNER JOIN staff_member sm ON sc.staff_member_id = sm.id
+        LEFT JOIN country c ON sm.country_id = c.id
+        WHERE sc.team_participation_id = ?
+        ORDER BY
+            CASE sc.role
+                WHEN 'head_coach' THEN 0
+                WHEN 'assistant_coach' THEN 1
+                ELSE 2
+            END,
+            sm.name ASC
+        "#,
+        team_participation_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Get the staff of both teams in a match, from their participations in the match's season
+pub async fn get_match_staff(
+    db: &SqlitePool,
+    match_id: i64,
+) -> Result<Vec<MatchStaffEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        MatchStaffEntity,
+        r#"
+        SELECT
+            tp.team_id as "team_id!",
+            sm.name,
+            sc.role,
+            c.iso2Code as country_iso2_code
+        FROM match m
+        INNER JOIN team_participation tp
+            ON tp.season_id = m.season_id
+            AND tp.team_id IN (m.home_team_id, m.away_team_id)
+        INNER JOIN staff_contract sc ON sc.team_participation_id = tp.id
+        INNER JOIN staff_member sm ON sc.staff_member_id = sm.id
+        LEFT JOIN country c ON sm.country_id = c.id
+        WHERE m.id = ?
+        ORDER BY
+            CASE sc.role
+                WHEN 'head_coach' THEN 0
+                WHEN 'assistant_coach' THEN 1
+                ELSE 2
+            END,
+            sm.name ASC
+        "#,
+        match_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Check if a staff member already holds a role in a team participation
+pub async fn is_staff_contracted(
+    db: &SqlitePool,
+    team_participation_id: i64,
+    staff_member_id: i64,
+    role: StaffRole,
+) -> Result<bool, sqlx::Error> {
+    let role = role.as_str();
+    let row = sqlx::query!(
+        r#"
+        SELECT COUNT(*) as "count!: i64"
+        FROM staff_contract
+        WHERE team_participation_id = ? AND staff_member_id = ? AND role = ?
+        "#,
+        team_participation_id,
+        staff_member_id,
+        role
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(row.count > 0)
+}
+
+/// Add a staff member to a team participation (create staff_contract)
+pub async fn add_staff_to_participation(
+    db: &SqlitePool,
+    team_participation_id: i64,
+    staff_member_id: i64,
+    role: StaffRole,
+) -> Result<i64, sqlx::Error> {
+    let role = role.as_str();
+    let result = sqlx::query!(
+        "INSERT INTO staff_contract (team_participation_id, staff_member_id, role) VALUES (?, ?, ?)",
+        team_participation_id,
+        staff_member_id,
+        role
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Remove a staff member from a team participation (delete staff_contract)
+pub async fn remove_staff_contract(
+    db: &SqlitePool,
+    staff_contract_id: i64,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!("DELETE FROM staff_contract WHERE id = ?", staff_contract_id)
+        .execute(db)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Get team_participation_id for a staff_contract (useful for redirects after delete)
+pub async fn get_team_participation_id_for_staff_contract(
+    db: &SqlitePool,
+    staff_contract_id: i64,
+) -> Result<Option<i64>, sqlx::Error> {
+    let row = sqlx::query!(
+        "SELECT team_participation_id FROM staff_contract WHERE id = ?",
+        staff_contract_id
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(row.map(|r| r.team_participation_id))
+}
+
+/// Helper function to apply filters to a query
+fn apply_filters<'a>(
+    query_builder: &mut sqlx::QueryBuilder<'a, sqlx::Sqlite>,
+    filters: &'a StaffFilters,
+) {
+    if let Some(name) = &filters.name {
+        query_builder
+            .push(" AND sm.name LIKE '%' || ")
+            .push_bind(name)
+            .push(" || '%'");
+    }
+
+    if let Some(country_id) = filters.country_id {
+        query_builder
+            .push(" AND sm.country_id = ")
+            .push_bind(country_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn create_test_staff_member(pool: &SqlitePool, name: &str) -> i64 {
+        create_staff_member(
+            pool,
+            CreateStaffMemberEntity {
+                name: name.to_string(),
+                country_id: Some(34),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_staff_member_crud(pool: SqlitePool) {
+        let id = create_test_staff_member(&pool, "Carl Coach").await;
+
+        let member = get_staff_member_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!(member.name, "Carl Coach");
+        assert_eq!(member.contract_count, 0);
+
+        let updated = update_staff_member(
+            &pool,
+            id,
+            UpdateStaffMemberEntity {
+                name: "Carl Manager".to_string(),
+                country_id: None,
+            },
+        )
+        .await
+        .unwrap();
+        assert!(updated);
+        let member = get_staff_member_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!(member.name, "Carl Manager");
+        assert!(member.country_id.is_none());
+
+        let result = get_staff_members(
+            &pool,
+            &StaffFilters {
+                name: Some("manager".to_string()),
+                country_id: None,
+            },
+            &SortField::Name,
+            &SortOrder::Asc,
+            1,
+            20,
+        )
+        .await
+        .unwrap();
+        assert_eq!(result.total, 1);
+
+        assert!(delete_staff_member(&pool, id).await.unwrap());
+        assert!(!delete_staff_member(&pool, id).await.unwrap());
+        assert!(get_staff_member_by_id(&pool, id).await.unwrap().is_none());
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_participation_and_match_staff(pool: SqlitePool) {
+        let coach = create_test_staff_member(&pool, "Carl Coach").await;
+        let assistant = create_test_staff_member(&pool, "Alice Assistant").await;
+
+        // Canada (home) and USA (away) in season 1
+        let (home_tp, away_tp) = (1, 2);
+
+        add_staff_to_participation(&pool, home_tp, assistant, StaffRole::AssistantCoach)
+            .await
+            .unwrap();
+        let contract_id = add_staff_to_participation(&pool, home_tp, coach, StaffRole::HeadCoach)
+            .await
+            .unwrap();
+        add_staff_to_participation(&pool, away_tp, coach, StaffRole::GeneralManager)
+            .await
+            .unwrap();
+
+        assert!(
+            is_staff_contracted(&pool, home_tp, coach, StaffRole::HeadCoach)
+                .await
+                .unwrap()
+        );
+        assert!(
+            !is_staff_contracted(&pool, home_tp, coach, StaffRole::GeneralManager)
+                .await
+                .unwrap()
+        );
+
+        let staff = get_participation_staff(&pool, home_tp).await.unwrap();
+        assert_eq!(staff.len(), 2);
+        assert_eq!(staff[0].role(), Some(StaffRole::HeadCoach));
+        assert_eq!(staff[1].name, "Alice Assistant");
+
+        let match_id = sqlx::query(
+            "INSERT INTO match (season_id, home_team_id, away_team_id, status) VALUES (1, 1, 2, 'scheduled')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap()
+        .last_insert_rowid();
+        let match_staff = get_match_staff(&pool, match_id).await.unwrap();
+        assert_eq!(match_staff.len(), 3);
+        assert_eq!(match_staff.iter().filter(|s| s.team_id == 2).count(), 1);
+
+        let member = get_staff_member_by_id(&pool, coach).await.unwrap().unwrap();
+        assert_eq!(member.contract_count, 2);
+
+        assert_eq!(
+            get_team_participation_id_for_staff_contract(&pool, contract_id)
+                .await
+                .unwrap(),
+            Some(home_tp)
+        );
+        assert!(remove_staff_contract(&pool, contract_id).await.unwrap());
+        assert_eq!(
+            get_participation_staff(&pool, home_tp).await.unwrap().len(),
+            1
+        );
+    }
+}
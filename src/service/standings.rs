@@ -0,0 +1,269 @@
+use std::cmp::Ordering;
+
+use sqlx::SqlitePool;
+
+/// How standings award points, configured per event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PointSystem {
+    /// 2 points for a win, 1 for a tie
+    #[default]
+    Classic,
+    /// 3 points for a regulation win, 2 for an overtime or shootout win,
+    /// 1 for an overtime or shootout loss
+    Iihf,
+}
+
+impl PointSystem {
+    pub const ALL: [PointSystem; 2] = [PointSystem::Classic, PointSystem::Iihf];
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "classic" => Some(Self::Classic),
+            "iihf" => Some(Self::Iihf),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Classic => "classic",
+            Self::Iihf => "iihf",
+        }
+    }
+
+    /// Points for a record under this system
+    pub fn points(&self, record: &TeamStandingEntity) -> i64 {
+        match self {
+            Self::Classic => record.wins * 2 + record.ties,
+            Self::Iihf => {
+                (record.wins - record.overtime_wins) * 3
+                    + record.overtime_wins * 2
+                    + record.overtime_losses
+                    + record.ties
+            }
+        }
+    }
+}
+
+/// A team's record in the group stage of a season
+///
+/// `overtime_wins` and `overtime_losses` are the part of `wins` and `losses`
+/// decided in overtime or a shootout.
+#[derive(Debug, Clone)]
+pub struct TeamStandingEntity {
+    pub team_id: i64,
+    pub team_name: String,
+    pub country_iso2_code: Option<String>,
+    pub group_id: Option<i64>,
+    pub point_system: String,
+    pub games: i64,
+    pub wins: i64,
+    pub overtime_wins: i64,
+    pub ties: i64,
+    pub overtime_losses: i64,
+    pub losses: i64,
+    pub goals_for: i64,
+    pub goals_against: i64,
+}
+
+impl TeamStandingEntity {
+    pub fn point_system(&self) -> PointSystem {
+        PointSystem::from_str(&self.point_system).unwrap_or_default()
+    }
+
+    pub fn points(&self) -> i64 {
+        self.point_system().points(self)
+    }
+
+    pub fn goal_difference(&self) -> i64 {
+        self.goals_for - self.goals_against
+    }
+
+    /// Table order: points, then goal difference, then goals scored
+    fn rank_cmp(&self, other: &Self) -> Ordering {
+        other
+            .points()
+            .cmp(&self.points())
+            .then_with(|| other.goal_difference().cmp(&self.goal_difference()))
+            .then_with(|| other.goals_for.cmp(&self.goals_for))
+            .then_with(|| self.team_name.cmp(&other.team_name))
+    }
+}
+
+/// Compute the standings of every team in a season, best first
+///
+/// Only finished group-stage matches count; playoff, relegation and
+/// exhibition games are left out. Scores include unidentified goals. Wins,
+/// losses and overtime or shootout decisions come from the stored match
+/// result (see `matches::MatchResult`). Points follow the point system of the
+/// season's event. Teams without a finished game are listed with an empty
+/// record. Callers split the table by `group_id` for group-scoped standings.
+pub async fn get_season_standings(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<Vec<TeamStandingEntity>, sqlx::Error> {
+    let mut rows = sqlx::query_as!(
+        TeamStandingEntity,
+        r#"
+        WITH results AS (
+            SELECT
+                m.home_team_id,
+                m.away_team_id,
+                m.home_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id) as home_score,
+                m.away_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.away_team_id) as away_score,
+                m.result,
+                m.result IN ('home_overtime', 'home_shootout', 'away_overtime', 'away_shootout') as overtime
+            FROM match m
+            WHERE m.season_id = ?1 AND m.status = 'finished' AND m.game_type = 'group'
+        ),
+        team_games AS (
+            SELECT home_team_id as team_id, home_score as goals_for, away_score as goals_against,
+                   result LIKE 'home_%' as won, result LIKE 'away_%' as lost, result = 'draw' as drawn, overtime
+            FROM results
+            UNION ALL
+            SELECT away_team_id, away_score, home_score,
+                   result LIKE 'away_%', result LIKE 'home_%', result = 'draw', overtime
+            FROM results
+        )
+        SELECT
+            tp.team_id as "team_id!",
+            t.name as "team_name!",
+            c.iso2Code as "country_iso2_code?",
+            tp.group_id as "group_id?",
+            e.point_system as "point_system!",
+            COUNT(tg.team_id) as "games!: i64",
+            COALESCE(SUM(tg.won), 0) as "wins!: i64",
+            COALESCE(SUM(tg.won AND tg.overtime), 0) as "overtime_wins!: i64",
+            COALESCE(SUM(tg.drawn), 0) as "ties!: i64",
+            COALESCE(SUM(tg.lost AND tg.overtime), 0) as "overtime_losses!: i64",
+            COALESCE(SUM(tg.lost), 0) as "losses!: i64",
+            COALESCE(SUM(tg.goals_for), 0) as "goals_for!: i64",
+            COALESCE(SUM(tg.goals_against), 0) as "goals_against!: i64"
+        FROM team_participation tp
+        INNER JOIN team t ON tp.team_id = t.id
+        INNER JOIN season s ON tp.season_id = s.id
+        INNER JOIN event e ON s.event_id = e.id
+        LEFT JOIN country c ON t.country_id = c.id
+        LEFT JOIN team_games tg ON tg.team_id = tp.team_id
+        WHERE tp.season_id = ?1
+        GROUP BY tp.id
+        "#,
+        season_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    rows.sort_by(TeamStandingEntity::rank_cmp);
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{
+        create_match, create_score_event, CreateMatchEntity, CreateScoreEventEntity,
+    };
+
+    async fn create_test_match(
+        pool: &SqlitePool,
+        home_team_id: i64,
+        away_team_id: i64,
+        score: (i32, i32),
+        game_type: &str,
+    ) -> i64 {
+        create_match(
+            pool,
+            CreateMatchEntity {
+                season_id: 1,
+                home_team_id,
+                away_team_id,
+                home_score_unidentified: score.0,
+                away_score_unidentified: score.1,
+                match_date: Some("2024-01-15".to_string()),
+                status: "finished".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: game_type.to_string(),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_season_standings(pool: SqlitePool) {
+        create_test_match(&pool, 1, 2, (2, 2), "group").await;
+        create_test_match(&pool, 2, 1, (3, 1), "group").await;
+        // Neither counts towards the group-stage table
+        create_test_match(&pool, 1, 2, (5, 0), "exhibition").await;
+        create_test_match(&pool, 1, 2, (5, 0), "playoff").await;
+
+        let standings = get_season_standings(&pool, 1).await.unwrap();
+        assert_eq!(standings.len(), 2);
+
+        let leader = &standings[0];
+        assert_eq!(leader.team_id, 2);
+        assert_eq!(
+            (leader.games, leader.wins, leader.ties, leader.losses),
+            (2, 1, 1, 0)
+        );
+        assert_eq!((leader.goals_for, leader.goals_against), (5, 3));
+        assert_eq!(leader.points(), 3);
+
+        assert_eq!(standings[1].points(), 1);
+        assert_eq!(standings[1].goal_difference(), -2);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_season_standings_iihf_points(pool: SqlitePool) {
+        sqlx::query("UPDATE event SET point_system = 'iihf' WHERE id = 1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        create_test_match(&pool, 1, 2, (3, 1), "group").await;
+        // Tied after regulation, decided by an overtime goal
+        let overtime = create_test_match(&pool, 1, 2, (2, 3), "group").await;
+        create_score_event(
+            &pool,
+            CreateScoreEventEntity {
+                match_id: overtime,
+                team_id: 2,
+                scorer_id: None,
+                assist1_id: None,
+                assist2_id: None,
+                period: 4,
+                time_minutes: None,
+                time_seconds: None,
+                goal_type: None,
+                video_url: None,
+                video_timestamp: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        let standings = get_season_standings(&pool, 1).await.unwrap();
+        let (first, second) = (&standings[0], &standings[1]);
+        assert_eq!(first.team_id, 1);
+        assert_eq!(first.point_system(), PointSystem::Iihf);
+        assert_eq!(
+            (first.wins, first.overtime_wins, first.overtime_losses),
+            (1, 0, 1)
+        );
+        assert_eq!(first.points(), 4);
+        assert_eq!(
+            (second.wins, second.overtime_wins, second.losses),
+            (1, 1, 1)
+        );
+        assert_eq!(second.points(), 2);
+
+        // The same record under the classic system
+        assert_eq!(PointSystem::Classic.points(first), 2);
+    }
+}
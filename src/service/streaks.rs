@@ -0,0 +1,268 @@
+//! Point and goal streaks of players
+//!
+//! A streak is a run of consecutive games played with at least one point (or
+//! goal). Games are the finished, non-exhibition matches of the player's teams
+//! in date order, counted like season stats: when a team has a lineup recorded
+//! for a match, only dressed players get the game. Undated matches cannot be
+//! ordered and are left out. Unidentified goals credit nobody, so streaks are
+//! a lower bound where scoring is incomplete.
+
+use std::collections::BTreeMap;
+
+use sqlx::SqlitePool;
+
+/// A player's scoring in one game they played
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerGameEntity {
+    pub player_id: i64,
+    pub match_id: i64,
+    pub match_date: String,
+    pub goals: i64,
+    pub points: i64,
+}
+
+/// Current and longest streaks of a player, in games
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerStreaksEntity {
+    pub games_played: i64,
+    /// Games with a point up to and including the latest game
+    pub current_points: i64,
+    pub longest_points: i64,
+    pub current_goals: i64,
+    pub longest_goals: i64,
+}
+
+/// A player on a streaks leaderboard
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StreakLeaderEntity {
+    pub player_id: i64,
+    pub player_name: String,
+    pub games: i64,
+}
+
+/// Players with the longest streaks, one list per kind of streak
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StreakLeadersEntity {
+    pub current_points: Vec<StreakLeaderEntity>,
+    pub longest_points: Vec<StreakLeaderEntity>,
+    pub current_goals: Vec<StreakLeaderEntity>,
+    pub longest_goals: Vec<StreakLeaderEntity>,
+}
+
+/// Streaks from a player's games in the order they were played
+pub fn compute_streaks(games: &[PlayerGameEntity]) -> PlayerStreaksEntity {
+    let mut streaks = PlayerStreaksEntity {
+        games_played: games.len() as i64,
+        ..Default::default()
+    };
+    for game in games {
+        streaks.current_points = if game.points > 0 {
+            streaks.current_points + 1
+        } else {
+            0
+        };
+        streaks.current_goals = if game.goals > 0 {
+            streaks.current_goals + 1
+        } else {
+            0
+        };
+        streaks.longest_points = streaks.longest_points.max(streaks.current_points);
+        streaks.longest_goals = streaks.longest_goals.max(streaks.current_goals);
+    }
+    streaks
+}
+
+/// Games of one player, or of every player when `player_id` is `None`,
+/// ordered by player and then by date
+pub async fn get_player_games(
+    db: &SqlitePool,
+    player_id: Option<i64>,
+) -> Result<Vec<PlayerGameEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        PlayerGameEntity,
+        r#"
+        WITH roster AS (
+            SELECT pc.id as contract_id, pc.player_id, tp.team_id, tp.season_id
+            FROM player_contract pc
+            INNER JOIN team_participation tp ON pc.team_participation_id = tp.id
+            WHERE ?1 IS NULL OR pc.player_id = ?1
+        ),
+        games AS (
+            SELECT DISTINCT r.player_id, m.id as match_id, m.match_date
+            FROM roster r
+            INNER JOIN match m
+                ON m.season_id = r.season_id
+                AND (m.home_team_id = r.team_id OR m.away_team_id = r.team_id)
+            WHERE m.status = 'finished' AND m.game_type != 'exhibition'
+              AND m.match_date IS NOT NULL
+              AND (
+                EXISTS (
+                    SELECT 1 FROM match_lineup ml
+                    WHERE ml.match_id = m.id AND ml.player_contract_id = r.contract_id
+                )
+                OR NOT EXISTS (
+                    SELECT 1 FROM match_lineup ml
+                    INNER JOIN player_contract teammate ON ml.player_contract_id = teammate.id
+                    INNER JOIN team_participation ttp ON teammate.team_participation_id = ttp.id
+                    WHERE ml.match_id = m.id AND ttp.team_id = r.team_id
+                )
+              )
+        )
+        SELECT
+            g.player_id as "player_id!: i64",
+            g.match_id as "match_id!: i64",
+            g.match_date as "match_date!: String",
+            (SELECT COUNT(*) FROM score_event se
+                WHERE se.match_id = g.match_id AND se.scorer_id = g.player_id) as "goals!: i64",
+            (SELECT COUNT(*) FROM score_event se
+                WHERE se.match_id = g.match_id
+                  AND (se.scorer_id = g.player_id OR se.assist1_id = g.player_id
+                       OR se.assist2_id = g.player_id)) as "points!: i64"
+        FROM games g
+        ORDER BY g.player_id, g.match_date, g.match_id
+        "#,
+        player_id
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Current and longest streaks of one player
+pub async fn get_player_streaks(
+    db: &SqlitePool,
+    player_id: i64,
+) -> Result<PlayerStreaksEntity, sqlx::Error> {
+    let games = get_player_games(db, Some(player_id)).await?;
+    Ok(compute_streaks(&games))
+}
+
+/// Top `limit` players of each kind of streak, ties broken by name
+///
+/// Players without a streak of at least one game are not listed.
+pub async fn get_streak_leaders(
+    db: &SqlitePool,
+    limit: usize,
+) -> Result<StreakLeadersEntity, sqlx::Error> {
+    let games = get_player_games(db, None).await?;
+    let names: BTreeMap<i64, String> = sqlx::query!(r#"SELECT id as "id!", name FROM player"#)
+        .fetch_all(db)
+        .await?
+        .into_iter()
+        .map(|row| (row.id, row.name))
+        .collect();
+
+    let streaks: Vec<(i64, PlayerStreaksEntity)> = games
+        .chunk_by(|a, b| a.player_id == b.player_id)
+        .map(|games| (games[0].player_id, compute_streaks(games)))
+        .collect();
+
+    let top = |games: fn(&PlayerStreaksEntity) -> i64| -> Vec<StreakLeaderEntity> {
+        let mut leaders: Vec<StreakLeaderEntity> = streaks
+            .iter()
+            .filter(|(_, streaks)| games(streaks) > 0)
+            .map(|(player_id, streaks)| StreakLeaderEntity {
+                player_id: *player_id,
+                player_name: names.get(player_id).cloned().unwrap_or_default(),
+                games: games(streaks),
+            })
+            .collect();
+        leaders.sort_by(|a, b| {
+            b.games
+                .cmp(&a.games)
+                .then_with(|| a.player_name.cmp(&b.player_name))
+        });
+        leaders.truncate(limit);
+        leaders
+    };
+
+    Ok(StreakLeadersEntity {
+        current_points: top(|s| s.current_points),
+        longest_points: top(|s| s.longest_points),
+        current_goals: top(|s| s.current_goals),
+        longest_goals: top(|s| s.longest_goals),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game(goals: i64, points: i64) -> PlayerGameEntity {
+        PlayerGameEntity {
+            player_id: 1,
+            match_id: 0,
+            match_date: String::new(),
+            goals,
+            points,
+        }
+    }
+
+    #[test]
+    fn test_compute_streaks() {
+        let streaks = compute_streaks(&[
+            game(1, 1),
+            game(0, 2),
+            game(1, 1),
+            game(0, 0),
+            game(1, 1),
+            game(2, 3),
+        ]);
+        assert_eq!(
+            streaks,
+            PlayerStreaksEntity {
+                games_played: 6,
+                current_points: 2,
+                longest_points: 3,
+                current_goals: 2,
+                longest_goals: 2,
+            }
+        );
+        assert_eq!(compute_streaks(&[]), PlayerStreaksEntity::default());
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_player_streaks(pool: SqlitePool) {
+        // Player 1 plays for team 1 in season 1
+        sqlx::query(
+            "INSERT INTO player_contract (team_participation_id, player_id)
+             SELECT id, 1 FROM team_participation WHERE season_id = 1 AND team_id = 1",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO match (id, season_id, home_team_id, away_team_id, match_date, status, game_type) VALUES
+                (1, 1, 1, 2, '2024-01-03', 'finished', 'group'),
+                (2, 1, 2, 1, '2024-01-01', 'finished', 'group'),
+                (3, 1, 1, 2, '2024-01-02', 'finished', 'group'),
+                (4, 1, 1, 2, '2024-01-04', 'scheduled', 'group'),
+                (5, 1, 1, 2, NULL, 'finished', 'group')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        // Goal on Jan 1, assist on Jan 2, nothing on Jan 3
+        sqlx::query(
+            "INSERT INTO score_event (match_id, team_id, scorer_id, assist1_id, period) VALUES
+                (2, 1, 1, NULL, 1),
+                (3, 1, 2, 1, 1)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let streaks = get_player_streaks(&pool, 1).await.unwrap();
+        assert_eq!(streaks.games_played, 3);
+        assert_eq!((streaks.current_points, streaks.longest_points), (0, 2));
+        assert_eq!((streaks.current_goals, streaks.longest_goals), (0, 1));
+
+        let leaders = get_streak_leaders(&pool, 10).await.unwrap();
+        assert_eq!(leaders.longest_points.len(), 1);
+        assert_eq!(leaders.longest_points[0].player_name, "Connor McDavid");
+        assert_eq!(leaders.longest_points[0].games, 2);
+        assert!(leaders.current_points.is_empty());
+    }
+}
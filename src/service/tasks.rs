@@ -0,0 +1,577 @@
+use sqlx::{QueryBuilder, Row, Sqlite, SqlitePool};
+
+/// Kind of record a task is attached to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskEntityType {
+    Team,
+    Player,
+    Event,
+    Season,
+    Match,
+}
+
+impl TaskEntityType {
+    pub const ALL: [TaskEntityType; 5] = [
+        TaskEntityType::Team,
+        TaskEntityType::Player,
+        TaskEntityType::Event,
+        TaskEntityType::Season,
+        TaskEntityType::Match,
+    ];
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "team" => Some(Self::Team),
+            "player" => Some(Self::Player),
+            "event" => Some(Self::Event),
+            "season" => Some(Self::Season),
+            "match" => Some(Self::Match),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Team => "team",
+            Self::Player => "player",
+            Self::Event => "event",
+            Self::Season => "season",
+            Self::Match => "match",
+        }
+    }
+
+    /// Detail page of a record of this kind
+    pub fn url(&self, id: i64) -> String {
+        match self {
+            Self::Team => format!("/teams/{}", id),
+            Self::Player => format!("/players/{}", id),
+            Self::Event => format!("/events/{}", id),
+            Self::Season => format!("/seasons/{}", id),
+            Self::Match => format!("/matches/{}", id),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TaskEntity {
+    pub id: i64,
+    pub title: String,
+    pub notes: Option<String>,
+    pub entity_type: Option<String>,
+    pub entity_id: Option<i64>,
+    /// Name of the attached record, `None` when it was deleted
+    pub entity_label: Option<String>,
+    pub assignee_id: Option<i64>,
+    pub assignee_name: Option<String>,
+    pub due_date: Option<String>,
+    pub completed_at: Option<String>,
+    pub created_at: String,
+}
+
+impl TaskEntity {
+    /// Attached record, if any
+    pub fn entity(&self) -> Option<(TaskEntityType, i64)> {
+        let entity_type = TaskEntityType::from_str(self.entity_type.as_deref()?)?;
+        Some((entity_type, self.entity_id?))
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.completed_at.is_some()
+    }
+
+    /// Whether an open task's due date (`YYYY-MM-DD`) lies before `today`
+    pub fn is_overdue(&self, today: &str) -> bool {
+        !self.is_completed() && self.due_date.as_deref().is_some_and(|due| due < today)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateTaskEntity {
+    pub title: String,
+    pub notes: Option<String>,
+    pub entity: Option<(TaskEntityType, i64)>,
+    pub assignee_id: Option<i64>,
+    pub due_date: Option<String>,
+    pub created_by: i64,
+}
+
+/// Editable fields; the attached record stays as created
+#[derive(Debug, Clone)]
+pub struct UpdateTaskEntity {
+    pub title: String,
+    pub notes: Option<String>,
+    pub assignee_id: Option<i64>,
+    pub due_date: Option<String>,
+}
+
+/// Which tasks to list
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TaskScope {
+    /// Open tasks assigned to the current user
+    #[default]
+    Mine,
+    /// All open tasks
+    Open,
+    /// Completed tasks
+    Completed,
+    All,
+}
+
+impl TaskScope {
+    pub const ALL: [TaskScope; 4] = [
+        TaskScope::Mine,
+        TaskScope::Open,
+        TaskScope::Completed,
+        TaskScope::All,
+    ];
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "open" => Self::Open,
+            "completed" => Self::Completed,
+            "all" => Self::All,
+            _ => Self::Mine,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Mine => "mine",
+            Self::Open => "open",
+            Self::Completed => "completed",
+            Self::All => "all",
+        }
+    }
+}
+
+/// User a task can be assigned to
+#[derive(Debug, Clone)]
+pub struct AssignableUserEntity {
+    pub id: i64,
+    pub name: String,
+}
+
+const TASK_SELECT: &str = r#"
+    SELECT
+        t.id, t.title, t.notes, t.entity_type, t.entity_id,
+        CASE t.entity_type
+            WHEN 'team' THEN (SELECT name FROM team WHERE id = t.entity_id)
+            WHEN 'player' THEN (SELECT name FROM player WHERE id = t.entity_id)
+            WHEN 'event' THEN (SELECT name FROM event WHERE id = t.entity_id)
+            WHEN 'season' THEN (
+                SELECT COALESCE(s.display_name, e.name || ' ' || s.year)
+                FROM season s INNER JOIN event e ON e.id = s.event_id
+                WHERE s.id = t.entity_id
+            )
+            WHEN 'match' THEN (
+                SELECT ht.name || ' – ' || at.name
+                FROM match m
+                INNER JOIN team ht ON ht.id = m.home_team_id
+                INNER JOIN team at ON at.id = m.away_team_id
+                WHERE m.id = t.entity_id
+            )
+        END as entity_label,
+        t.assignee_id,
+        COALESCE(u.name, u.email) as assignee_name,
+        t.due_date, t.completed_at, t.created_at
+    FROM task t
+    LEFT JOIN users u ON u.id = t.assignee_id
+    WHERE 1=1
+"#;
+
+/// Open tasks first (earliest due date first, undated last), then completed ones
+const TASK_ORDER: &str =
+    " ORDER BY t.completed_at IS NOT NULL, t.due_date IS NULL, t.due_date, t.completed_at DESC, t.id";
+
+fn task_from_row(row: sqlx::sqlite::SqliteRow) -> TaskEntity {
+    TaskEntity {
+        id: row.get("id"),
+        title: row.get("title"),
+        notes: row.get("notes"),
+        entity_type: row.get("entity_type"),
+        entity_id: row.get("entity_id"),
+        entity_label: row.get("entity_label"),
+        assignee_id: row.get("assignee_id"),
+        assignee_name: row.get("assignee_name"),
+        due_date: row.get("due_date"),
+        completed_at: row.get("completed_at"),
+        created_at: row.get("created_at"),
+    }
+}
+
+/// List tasks in a scope, optionally only those attached to one record
+///
+/// `user_id` is the current user, used by [`TaskScope::Mine`].
+pub async fn get_tasks(
+    db: &SqlitePool,
+    scope: TaskScope,
+    user_id: i64,
+    entity: Option<(TaskEntityType, i64)>,
+) -> Result<Vec<TaskEntity>, sqlx::Error> {
+    let mut query_builder: QueryBuilder<Sqlite> = QueryBuilder::new(TASK_SELECT);
+
+    match scope {
+        TaskScope::Mine => {
+            query_builder
+                .push(" AND t.completed_at IS NULL AND t.assignee_id = ")
+                .push_bind(user_id);
+        }
+        TaskScope::Open => {
+            query_builder.push(" AND t.completed_at IS NULL");
+        }
+        TaskScope::Completed => {
+            query_builder.push(" AND t.completed_at IS NOT NULL");
+        }
+        TaskScope::All => {}
+    }
+
+    if let Some((entity_type, entity_id)) = entity {
+        query_builder
+            .push(" AND t.entity_type = ")
+            .push_bind(entity_type.as_str())
+            .push(" AND t.entity_id = ")
+            .push_bind(entity_id);
+    }
+
+    query_builder.push(TASK_ORDER);
+
+    let rows = query_builder.build().fetch_all(db).await?;
+    Ok(rows.into_iter().map(task_from_row).collect())
+}
+
+/// Open tasks assigned to a user, most urgent first
+pub async fn get_open_tasks_for_user(
+    db: &SqlitePool,
+    user_id: i64,
+    limit: i64,
+) -> Result<Vec<TaskEntity>, sqlx::Error> {
+    let mut query_builder: QueryBuilder<Sqlite> = QueryBuilder::new(TASK_SELECT);
+    query_builder
+        .push(" AND t.completed_at IS NULL AND t.assignee_id = ")
+        .push_bind(user_id)
+        .push(TASK_ORDER)
+        .push(" LIMIT ")
+        .push_bind(limit);
+
+    let rows = query_builder.build().fetch_all(db).await?;
+    Ok(rows.into_iter().map(task_from_row).collect())
+}
+
+/// Get a task by ID
+pub async fn get_task_by_id(db: &SqlitePool, id: i64) -> Result<Option<TaskEntity>, sqlx::Error> {
+    let mut query_builder: QueryBuilder<Sqlite> = QueryBuilder::new(TASK_SELECT);
+    query_builder.push(" AND t.id = ").push_bind(id);
+
+    let row = query_builder.build().fetch_optional(db).await?;
+    Ok(row.map(task_from_row))
+}
+
+/// Name of the record a new task would be attached to, `None` if it does not exist
+pub async fn get_entity_label(
+    db: &SqlitePool,
+    entity_type: TaskEntityType,
+    entity_id: i64,
+) -> Result<Option<String>, sqlx::Error> {
+    let label = match entity_type {
+        TaskEntityType::Team => {
+            sqlx::query_scalar!("SELECT name FROM team WHERE id = ?", entity_id)
+                .fetch_optional(db)
+                .await?
+        }
+        TaskEntityType::Player => {
+            sqlx::query_scalar!("SELECT name FROM player WHERE id = ?", entity_id)
+                .fetch_optional(db)
+                .await?
+        }
+        TaskEntityType::Event => {
+            sqlx::query_scalar!("SELECT name FROM event WHERE id = ?", entity_id)
+                .fetch_optional(db)
+                .await?
+        }
+        TaskEntityType::Season => {
+            sqlx::query_scalar!(
+                r#"
+            SELECT COALESCE(s.display_name, e.name || ' ' || s.year) as "label!: String"
+            FROM season s INNER JOIN event e ON e.id = s.event_id
+            WHERE s.id = ?
+            "#,
+                entity_id
+            )
+            .fetch_optional(db)
+            .await?
+        }
+        TaskEntityType::Match => {
+            sqlx::query_scalar!(
+                r#"
+            SELECT ht.name || ' – ' || at.name as "label!: String"
+            FROM match m
+            INNER JOIN team ht ON ht.id = m.home_team_id
+            INNER JOIN team at ON at.id = m.away_team_id
+            WHERE m.id = ?
+            "#,
+                entity_id
+            )
+            .fetch_optional(db)
+            .await?
+        }
+    };
+    Ok(label)
+}
+
+/// Users tasks can be assigned to, by name
+pub async fn get_assignable_users(
+    db: &SqlitePool,
+) -> Result<Vec<AssignableUserEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        AssignableUserEntity,
+        r#"
+        SELECT id as "id!", COALESCE(name, email) as "name!: String"
+        FROM users
+        ORDER BY COALESCE(name, email)
+        "#
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// Create a task
+pub async fn create_task(db: &SqlitePool, entity: CreateTaskEntity) -> Result<i64, sqlx::Error> {
+    let entity_type = entity.entity.map(|(entity_type, _)| entity_type.as_str());
+    let entity_id = entity.entity.map(|(_, id)| id);
+
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO task (title, notes, entity_type, entity_id, assignee_id, due_date, created_by)
+        VALUES (?, ?, ?, ?, ?, ?, ?)
+        "#,
+        entity.title,
+        entity.notes,
+        entity_type,
+        entity_id,
+        entity.assignee_id,
+        entity.due_date,
+        entity.created_by
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Update a task, returning whether it existed
+pub async fn update_task(
+    db: &SqlitePool,
+    id: i64,
+    entity: UpdateTaskEntity,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE task
+        SET title = ?, notes = ?, assignee_id = ?, due_date = ?, updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        entity.title,
+        entity.notes,
+        entity.assignee_id,
+        entity.due_date,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Mark a task done or reopen it, returning whether it existed
+pub async fn set_task_completed(
+    db: &SqlitePool,
+    id: i64,
+    completed: bool,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE task
+        SET completed_at = CASE WHEN ? THEN COALESCE(completed_at, CURRENT_TIMESTAMP) END,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        completed,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete a task, returning whether it existed
+pub async fn delete_task(db: &SqlitePool, id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!("DELETE FROM task WHERE id = ?", id)
+        .execute(db)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn user(pool: &SqlitePool, email: &str) -> i64 {
+        sqlx::query("INSERT INTO users (email, name, password_hash) VALUES (?, ?, 'x')")
+            .bind(email)
+            .bind(email.split('@').next())
+            .execute(pool)
+            .await
+            .unwrap()
+            .last_insert_rowid()
+    }
+
+    fn task(
+        title: &str,
+        entity: Option<(TaskEntityType, i64)>,
+        assignee_id: Option<i64>,
+        due_date: Option<&str>,
+        created_by: i64,
+    ) -> CreateTaskEntity {
+        CreateTaskEntity {
+            title: title.to_string(),
+            notes: None,
+            entity,
+            assignee_id,
+            due_date: due_date.map(str::to_string),
+            created_by,
+        }
+    }
+
+    fn titles(tasks: &[TaskEntity]) -> Vec<&str> {
+        tasks.iter().map(|t| t.title.as_str()).collect()
+    }
+
+    #[sqlx::test(migrations = "./migrations", fixtures("events", "seasons", "teams"))]
+    async fn test_tasks_by_scope(pool: SqlitePool) {
+        let anna = user(&pool, "anna@example.com").await;
+        let ben = user(&pool, "ben@example.com").await;
+        let season = Some((TaskEntityType::Season, 1));
+
+        create_task(&pool, task("Undated", None, Some(anna), None, ben))
+            .await
+            .unwrap();
+        let roster = create_task(
+            &pool,
+            task("Verify roster", season, Some(anna), Some("2026-03-01"), ben),
+        )
+        .await
+        .unwrap();
+        create_task(
+            &pool,
+            task("Scan programme", None, Some(ben), Some("2026-02-01"), anna),
+        )
+        .await
+        .unwrap();
+        let done = create_task(
+            &pool,
+            task("Add arena", None, None, Some("2026-01-01"), anna),
+        )
+        .await
+        .unwrap();
+        assert!(set_task_completed(&pool, done, true).await.unwrap());
+
+        let mine = get_tasks(&pool, TaskScope::Mine, anna, None).await.unwrap();
+        assert_eq!(titles(&mine), vec!["Verify roster", "Undated"]);
+        assert_eq!(
+            mine[0].entity_label.as_deref(),
+            Some("2022 Winter Olympics")
+        );
+        assert_eq!(mine[0].entity(), season);
+        assert_eq!(mine[0].assignee_name.as_deref(), Some("anna"));
+        assert!(mine[0].is_overdue("2026-03-02"));
+        assert!(!mine[0].is_overdue("2026-03-01"));
+
+        let open = get_tasks(&pool, TaskScope::Open, anna, None).await.unwrap();
+        assert_eq!(
+            titles(&open),
+            vec!["Scan programme", "Verify roster", "Undated"]
+        );
+        let all = get_tasks(&pool, TaskScope::All, anna, None).await.unwrap();
+        assert_eq!(all.last().unwrap().title, "Add arena");
+        assert!(!all.last().unwrap().is_overdue("2026-03-02"));
+
+        let for_season = get_tasks(&pool, TaskScope::All, anna, season)
+            .await
+            .unwrap();
+        assert_eq!(titles(&for_season), vec!["Verify roster"]);
+
+        assert_eq!(
+            titles(&get_open_tasks_for_user(&pool, anna, 1).await.unwrap()),
+            vec!["Verify roster"]
+        );
+
+        assert!(set_task_completed(&pool, roster, true).await.unwrap());
+        assert!(set_task_completed(&pool, done, false).await.unwrap());
+        let completed = get_tasks(&pool, TaskScope::Completed, anna, None)
+            .await
+            .unwrap();
+        assert_eq!(titles(&completed), vec!["Verify roster"]);
+    }
+
+    #[sqlx::test(migrations = "./migrations", fixtures("events", "seasons", "teams"))]
+    async fn test_task_update_and_labels(pool: SqlitePool) {
+        let anna = user(&pool, "anna@example.com").await;
+        assert_eq!(
+            get_entity_label(&pool, TaskEntityType::Team, 1)
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("Team Canada")
+        );
+        assert_eq!(
+            get_entity_label(&pool, TaskEntityType::Team, 999)
+                .await
+                .unwrap(),
+            None
+        );
+
+        let id = create_task(
+            &pool,
+            task(
+                "Check logo",
+                Some((TaskEntityType::Team, 1)),
+                None,
+                None,
+                anna,
+            ),
+        )
+        .await
+        .unwrap();
+        assert!(update_task(
+            &pool,
+            id,
+            UpdateTaskEntity {
+                title: "Check jersey colours".to_string(),
+                notes: Some("Compare with the 1998 photos".to_string()),
+                assignee_id: Some(anna),
+                due_date: Some("2026-04-01".to_string()),
+            },
+        )
+        .await
+        .unwrap());
+
+        let updated = get_task_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!(updated.title, "Check jersey colours");
+        assert_eq!(updated.assignee_id, Some(anna));
+        assert_eq!(updated.entity_label.as_deref(), Some("Team Canada"));
+
+        // Deleting the record keeps the task without a label
+        sqlx::query("DELETE FROM team WHERE id = 1")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let orphaned = get_task_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!(orphaned.entity_label, None);
+        assert!(orphaned.entity().is_some());
+
+        assert!(delete_task(&pool, id).await.unwrap());
+        assert!(!delete_task(&pool, id).await.unwrap());
+    }
+}
@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use sqlx::SqlitePool;
+
+use crate::service::matches::{Decision, MatchResult};
+
+/// Number of matches in a form strip
+pub const FORM_LENGTH: i64 = 5;
+
+/// Outcome of one match from a team's point of view
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormOutcome {
+    Win,
+    /// Won in overtime or a shootout
+    OvertimeWin,
+    Draw,
+    /// Lost in overtime or a shootout
+    OvertimeLoss,
+    Loss,
+}
+
+impl FormOutcome {
+    /// Outcome for the home (`is_home`) or away team of a match result
+    pub fn from_result(result: MatchResult, is_home: bool) -> Self {
+        let (won, decision) = match result {
+            MatchResult::HomeWin(decision) => (is_home, decision),
+            MatchResult::AwayWin(decision) => (!is_home, decision),
+            MatchResult::Draw => return Self::Draw,
+        };
+        match (won, decision) {
+            (true, Decision::Regulation) => Self::Win,
+            (true, _) => Self::OvertimeWin,
+            (false, Decision::Regulation) => Self::Loss,
+            (false, _) => Self::OvertimeLoss,
+        }
+    }
+}
+
+/// One finished match in a team's form strip
+#[derive(Debug, Clone)]
+pub struct FormMatchEntity {
+    pub match_id: i64,
+    pub match_date: Option<String>,
+    pub opponent_name: String,
+    pub goals_for: i64,
+    pub goals_against: i64,
+    pub outcome: FormOutcome,
+}
+
+/// A team's last `FORM_LENGTH` finished matches across all seasons, oldest first
+pub async fn get_team_form(
+    db: &SqlitePool,
+    team_id: i64,
+) -> Result<Vec<FormMatchEntity>, sqlx::Error> {
+    Ok(fetch_form(db, None, Some(team_id))
+        .await?
+        .remove(&team_id)
+        .unwrap_or_default())
+}
+
+/// Form strips of every team that finished a match in a season, by team ID
+///
+/// All game types count, so late-season playoff games show up in the form of
+/// the standings rows.
+pub async fn get_season_form(
+    db: &SqlitePool,
+    season_id: i64,
+) -> Result<HashMap<i64, Vec<FormMatchEntity>>, sqlx::Error> {
+    fetch_form(db, Some(season_id), None).await
+}
+
+/// Last finished matches per team, optionally narrowed to a season or a team
+///
+/// Matches are ordered by date, undated ones count as the oldest. The outcome
+/// comes from the stored match result (see `matches::MatchResult`).
+async fn fetch_form(
+    db: &SqlitePool,
+    season_id: Option<i64>,
+    team_id: Option<i64>,
+) -> Result<HashMap<i64, Vec<FormMatchEntity>>, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        WITH results AS (
+            SELECT
+                m.id,
+                m.home_team_id,
+                m.away_team_id,
+                m.match_date,
+                m.result,
+                m.home_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.home_team_id) as home_score,
+                m.away_score_unidentified + (SELECT COUNT(*) FROM score_event se WHERE se.match_id = m.id AND se.team_id = m.away_team_id) as away_score
+            FROM match m
+            WHERE m.result IS NOT NULL AND (?1 IS NULL OR m.season_id = ?1)
+        ),
+        team_games AS (
+            SELECT id, match_date, result, home_team_id as team_id, away_team_id as opponent_id,
+                   1 as is_home, home_score as goals_for, away_score as goals_against
+            FROM results
+            UNION ALL
+            SELECT id, match_date, result, away_team_id, home_team_id,
+                   0, away_score, home_score
+            FROM results
+        ),
+        ranked AS (
+            SELECT
+                tg.*,
+                ROW_NUMBER() OVER (
+                    PARTITION BY tg.team_id
+                    ORDER BY tg.match_date IS NULL, tg.match_date DESC, tg.id DESC
+                ) as recency
+            FROM team_games tg
+            WHERE ?2 IS NULL OR tg.team_id = ?2
+        )
+        SELECT
+            r.team_id as "team_id!: i64",
+            r.id as "match_id!: i64",
+            r.match_date,
+            r.result as "result!: String",
+            r.is_home as "is_home!: bool",
+            r.goals_for as "goals_for!: i64",
+            r.goals_against as "goals_against!: i64",
+            t.name as "opponent_name!"
+        FROM ranked r
+        INNER JOIN team t ON t.id = r.opponent_id
+        WHERE r.recency <= ?3
+        ORDER BY r.team_id, r.recency DESC
+        "#,
+        season_id,
+        team_id,
+        FORM_LENGTH
+    )
+    .fetch_all(db)
+    .await?;
+
+    let mut form: HashMap<i64, Vec<FormMatchEntity>> = HashMap::new();
+    for row in rows {
+        let Some(result) = MatchResult::from_str(&row.result) else {
+            continue;
+        };
+        form.entry(row.team_id).or_default().push(FormMatchEntity {
+            match_id: row.match_id,
+            match_date: row.match_date,
+            opponent_name: row.opponent_name,
+            goals_for: row.goals_for,
+            goals_against: row.goals_against,
+            outcome: FormOutcome::from_result(result, row.is_home),
+        });
+    }
+    Ok(form)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{create_match, CreateMatchEntity};
+
+    async fn finished_match(
+        pool: &SqlitePool,
+        season_id: i64,
+        home: (i64, i32),
+        away: (i64, i32),
+        date: &str,
+    ) -> i64 {
+        create_match(
+            pool,
+            CreateMatchEntity {
+                season_id,
+                home_team_id: home.0,
+                away_team_id: away.0,
+                home_score_unidentified: home.1,
+                away_score_unidentified: away.1,
+                match_date: Some(date.to_string()),
+                status: "finished".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap()
+    }
+
+    fn outcomes(form: &[FormMatchEntity]) -> Vec<FormOutcome> {
+        form.iter().map(|m| m.outcome).collect()
+    }
+
+    #[test]
+    fn test_form_outcome_from_result() {
+        let overtime = MatchResult::HomeWin(Decision::Overtime);
+        assert_eq!(
+            FormOutcome::from_result(overtime, true),
+            FormOutcome::OvertimeWin
+        );
+        assert_eq!(
+            FormOutcome::from_result(overtime, false),
+            FormOutcome::OvertimeLoss
+        );
+        let regulation = MatchResult::AwayWin(Decision::Regulation);
+        assert_eq!(
+            FormOutcome::from_result(regulation, true),
+            FormOutcome::Loss
+        );
+        assert_eq!(
+            FormOutcome::from_result(regulation, false),
+            FormOutcome::Win
+        );
+        assert_eq!(
+            FormOutcome::from_result(MatchResult::Draw, true),
+            FormOutcome::Draw
+        );
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_team_form_keeps_last_matches(pool: SqlitePool) {
+        for day in 1..=6 {
+            // Team Canada wins at home on odd days and draws away on even days
+            let date = format!("2026-02-0{}", day);
+            if day % 2 == 1 {
+                finished_match(&pool, 1, (1, 3), (2, 1), &date).await;
+            } else {
+                finished_match(&pool, 1, (2, 2), (1, 2), &date).await;
+            }
+        }
+        let scheduled = create_match(
+            &pool,
+            CreateMatchEntity {
+                season_id: 1,
+                home_team_id: 1,
+                away_team_id: 2,
+                home_score_unidentified: 0,
+                away_score_unidentified: 0,
+                match_date: Some("2026-02-10".to_string()),
+                status: "scheduled".to_string(),
+                arena_id: None,
+                attendance: None,
+                game_type: "group".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+        let form = get_team_form(&pool, 1).await.unwrap();
+        assert_eq!(form.len(), FORM_LENGTH as usize);
+        assert!(form.iter().all(|m| m.match_id != scheduled));
+        assert_eq!(form[0].match_date.as_deref(), Some("2026-02-02"));
+        assert_eq!(
+            outcomes(&form),
+            vec![
+                FormOutcome::Draw,
+                FormOutcome::Win,
+                FormOutcome::Draw,
+                FormOutcome::Win,
+                FormOutcome::Draw,
+            ]
+        );
+        assert_eq!(form[1].opponent_name, "Team USA");
+        assert_eq!((form[1].goals_for, form[1].goals_against), (3, 1));
+
+        let season = get_season_form(&pool, 1).await.unwrap();
+        assert_eq!(season[&2][1].outcome, FormOutcome::Loss);
+        assert!(get_season_form(&pool, 2).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,1349 @@
+//! Portable export/import of a single team's history
+//!
+//! The file references countries by IOC code and everything else by name
+//! (events, seasons by event and year, teams, players by name and birth
+//! date), so it can be moved between instances with different IDs.
+//! Players are listed once and referenced by a file-local `key`.
+//!
+//! Teams, players and matches also carry a stable `external_id` (see
+//! [`crate::service::external_ids`]), which merge imports use to find and
+//! update records imported earlier instead of duplicating them.
+
+use std::collections::{BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+use sqlx::{Row, SqliteConnection, SqlitePool};
+
+use super::arenas;
+use super::external_ids::{self, ExternalEntity};
+use super::matches;
+use super::slugs;
+
+/// Value of the `format` field identifying a team history file
+pub const TEAM_HISTORY_FORMAT: &str = "hockey-team-history";
+/// Current version of the team history file layout
+pub const TEAM_HISTORY_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamHistoryFile {
+    pub format: String,
+    pub version: u32,
+    #[serde(default)]
+    pub exported_at: Option<String>,
+    pub team: TeamRef,
+    #[serde(default)]
+    pub players: Vec<PlayerRecord>,
+    #[serde(default)]
+    pub participations: Vec<ParticipationRecord>,
+    #[serde(default)]
+    pub matches: Vec<MatchRecord>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TeamRef {
+    #[serde(default)]
+    pub external_id: Option<String>,
+    pub name: String,
+    #[serde(default)]
+    pub short_name: Option<String>,
+    /// IOC country code
+    #[serde(default)]
+    pub country: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeasonRef {
+    pub event: String,
+    /// IOC country code of the event
+    #[serde(default)]
+    pub event_country: Option<String>,
+    pub year: i64,
+    #[serde(default)]
+    pub display_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerRecord {
+    /// File-local reference used by rosters and score events
+    pub key: i64,
+    #[serde(default)]
+    pub external_id: Option<String>,
+    pub name: String,
+    /// IOC country code
+    pub country: String,
+    #[serde(default)]
+    pub birth_date: Option<String>,
+    #[serde(default)]
+    pub birth_place: Option<String>,
+    #[serde(default)]
+    pub height_cm: Option<i64>,
+    #[serde(default)]
+    pub weight_kg: Option<i64>,
+    #[serde(default)]
+    pub position: Option<String>,
+    #[serde(default)]
+    pub shoots: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParticipationRecord {
+    pub season: SeasonRef,
+    #[serde(default)]
+    pub roster: Vec<RosterRecord>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RosterRecord {
+    pub player: i64,
+    #[serde(default)]
+    pub jersey_number: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchRecord {
+    #[serde(default)]
+    pub external_id: Option<String>,
+    pub season: SeasonRef,
+    pub home_team: TeamRef,
+    pub away_team: TeamRef,
+    #[serde(default)]
+    pub home_score_unidentified: i64,
+    #[serde(default)]
+    pub away_score_unidentified: i64,
+    #[serde(default)]
+    pub match_date: Option<String>,
+    pub status: String,
+    /// `group`, `playoff`, `relegation` or `exhibition`; group stage when missing
+    #[serde(default)]
+    pub game_type: Option<String>,
+    /// Arena name, matched to a local arena on import
+    #[serde(default)]
+    pub venue: Option<String>,
+    #[serde(default)]
+    pub score_events: Vec<ScoreEventRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScoreEventRecord {
+    /// Whether the goal was scored by the home team
+    pub home: bool,
+    #[serde(default)]
+    pub scorer: Option<i64>,
+    #[serde(default)]
+    pub assist1: Option<i64>,
+    #[serde(default)]
+    pub assist2: Option<i64>,
+    #[serde(default)]
+    pub period: Option<i64>,
+    #[serde(default)]
+    pub time_minutes: Option<i64>,
+    #[serde(default)]
+    pub time_seconds: Option<i64>,
+    #[serde(default)]
+    pub goal_type: Option<String>,
+}
+
+impl TeamHistoryFile {
+    /// All IOC country codes referenced by the file
+    pub fn country_codes(&self) -> BTreeSet<&str> {
+        let team_refs = std::iter::once(&self.team).chain(
+            self.matches
+                .iter()
+                .flat_map(|m| [&m.home_team, &m.away_team]),
+        );
+        let seasons = self
+            .participations
+            .iter()
+            .map(|p| &p.season)
+            .chain(self.matches.iter().map(|m| &m.season));
+
+        team_refs
+            .filter_map(|t| t.country.as_deref())
+            .chain(seasons.filter_map(|s| s.event_country.as_deref()))
+            .chain(self.players.iter().map(|p| p.country.as_str()))
+            .collect()
+    }
+
+    /// All player keys referenced by rosters and score events
+    pub fn referenced_player_keys(&self) -> BTreeSet<i64> {
+        let roster = self
+            .participations
+            .iter()
+            .flat_map(|p| p.roster.iter().map(|r| r.player));
+        let scoring = self
+            .matches
+            .iter()
+            .flat_map(|m| m.score_events.iter())
+            .flat_map(|se| [se.scorer, se.assist1, se.assist2])
+            .flatten();
+
+        roster.chain(scoring).collect()
+    }
+}
+
+/// Build the portable history of a team
+///
+/// Teams, players and matches without an external ID get one assigned.
+pub async fn export_team_history(
+    db: &SqlitePool,
+    team_id: i64,
+) -> Result<Option<TeamHistoryFile>, sqlx::Error> {
+    let Some(team_row) = sqlx::query(
+        r#"
+        SELECT t.name, t.short_name, c.iocCode as country
+        FROM team t
+        LEFT JOIN country c ON t.country_id = c.id
+        WHERE t.id = ?
+        "#,
+    )
+    .bind(team_id)
+    .fetch_optional(db)
+    .await?
+    else {
+        return Ok(None);
+    };
+
+    let mut conn = db.acquire().await?;
+
+    let team = TeamRef {
+        external_id: Some(
+            external_ids::external_id_for(&mut conn, ExternalEntity::Team, team_id).await?,
+        ),
+        name: team_row.get("name"),
+        short_name: team_row.get("short_name"),
+        country: team_row.get("country"),
+    };
+
+    let mut player_ids: BTreeSet<i64> = BTreeSet::new();
+
+    let participation_rows = sqlx::query(
+        r#"
+        SELECT tp.id, e.name as event, ec.iocCode as event_country, s.year, s.display_name
+        FROM team_participation tp
+        INNER JOIN season s ON tp.season_id = s.id
+        INNER JOIN event e ON s.event_id = e.id
+        LEFT JOIN country ec ON e.country_id = ec.id
+        WHERE tp.team_id = ?
+        ORDER BY s.year, e.name
+        "#,
+    )
+    .bind(team_id)
+    .fetch_all(db)
+    .await?;
+
+    let mut participations = Vec::with_capacity(participation_rows.len());
+    for row in participation_rows {
+        let roster: Vec<RosterRecord> = sqlx::query(
+            r#"
+            SELECT player_id, jersey_number
+            FROM player_contract
+            WHERE team_participation_id = ?
+            ORDER BY jersey_number IS NULL, jersey_number, player_id
+            "#,
+        )
+        .bind(row.get::<i64, _>("id"))
+        .fetch_all(db)
+        .await?
+        .into_iter()
+        .map(|r| RosterRecord {
+            player: r.get("player_id"),
+            jersey_number: r.get("jersey_number"),
+        })
+        .collect();
+
+        player_ids.extend(roster.iter().map(|r| r.player));
+        participations.push(ParticipationRecord {
+            season: season_ref_from_row(&row),
+            roster,
+        });
+    }
+
+    let match_rows = sqlx::query(
+        r#"
+        SELECT
+            m.id, m.home_team_id, m.away_team_id, m.home_score_unidentified, m.away_score_unidentified,
+            m.match_date, m.status, m.game_type, ar.name as venue,
+            e.name as event, ec.iocCode as event_country, s.year, s.display_name,
+            ht.name as home_name, ht.short_name as home_short_name, hc.iocCode as home_country,
+            at.name as away_name, at.short_name as away_short_name, ac.iocCode as away_country
+        FROM match m
+        INNER JOIN season s ON m.season_id = s.id
+        INNER JOIN event e ON s.event_id = e.id
+        LEFT JOIN country ec ON e.country_id = ec.id
+        INNER JOIN team ht ON m.home_team_id = ht.id
+        LEFT JOIN country hc ON ht.country_id = hc.id
+        INNER JOIN team at ON m.away_team_id = at.id
+        LEFT JOIN country ac ON at.country_id = ac.id
+        LEFT JOIN arena ar ON m.arena_id = ar.id
+        WHERE m.home_team_id = ? OR m.away_team_id = ?
+        ORDER BY m.match_date IS NULL, m.match_date, m.id
+        "#,
+    )
+    .bind(team_id)
+    .bind(team_id)
+    .fetch_all(db)
+    .await?;
+
+    let mut matches = Vec::with_capacity(match_rows.len());
+    for row in match_rows {
+        let match_id: i64 = row.get("id");
+        let home_team_id: i64 = row.get("home_team_id");
+        let away_team_id: i64 = row.get("away_team_id");
+
+        let score_events: Vec<ScoreEventRecord> = sqlx::query(
+            r#"
+            SELECT team_id, scorer_id, assist1_id, assist2_id, period, time_minutes, time_seconds, goal_type
+            FROM score_event
+            WHERE match_id = ?
+            ORDER BY period, time_minutes, time_seconds, id
+            "#,
+        )
+        .bind(match_id)
+        .fetch_all(db)
+        .await?
+        .into_iter()
+        .map(|se| ScoreEventRecord {
+            home: se.get::<i64, _>("team_id") == home_team_id,
+            scorer: se.get("scorer_id"),
+            assist1: se.get("assist1_id"),
+            assist2: se.get("assist2_id"),
+            period: se.get("period"),
+            time_minutes: se.get("time_minutes"),
+            time_seconds: se.get("time_seconds"),
+            goal_type: se.get("goal_type"),
+        })
+        .collect();
+
+        player_ids.extend(
+            score_events
+                .iter()
+                .flat_map(|se| [se.scorer, se.assist1, se.assist2])
+                .flatten(),
+        );
+
+        matches.push(MatchRecord {
+            external_id: Some(
+                external_ids::external_id_for(&mut conn, ExternalEntity::Match, match_id).await?,
+            ),
+            season: season_ref_from_row(&row),
+            home_team: TeamRef {
+                external_id: Some(
+                    external_ids::external_id_for(&mut conn, ExternalEntity::Team, home_team_id)
+                        .await?,
+                ),
+                name: row.get("home_name"),
+                short_name: row.get("home_short_name"),
+                country: row.get("home_country"),
+            },
+            away_team: TeamRef {
+                external_id: Some(
+                    external_ids::external_id_for(&mut conn, ExternalEntity::Team, away_team_id)
+                        .await?,
+                ),
+                name: row.get("away_name"),
+                short_name: row.get("away_short_name"),
+                country: row.get("away_country"),
+            },
+            home_score_unidentified: row.get("home_score_unidentified"),
+            away_score_unidentified: row.get("away_score_unidentified"),
+            match_date: row.get("match_date"),
+            status: row.get("status"),
+            game_type: row.get("game_type"),
+            venue: row.get("venue"),
+            score_events,
+        });
+    }
+
+    let mut players = Vec::with_capacity(player_ids.len());
+    for player_id in player_ids {
+        let row = sqlx::query(
+            r#"
+            SELECT p.id, p.name, c.iocCode as country, p.birth_date, p.birth_place,
+                   p.height_cm, p.weight_kg, p.position, p.shoots
+            FROM player p
+            INNER JOIN country c ON p.country_id = c.id
+            WHERE p.id = ?
+            "#,
+        )
+        .bind(player_id)
+        .fetch_one(db)
+        .await?;
+
+        players.push(PlayerRecord {
+            key: player_id,
+            external_id: Some(
+                external_ids::external_id_for(&mut conn, ExternalEntity::Player, player_id).await?,
+            ),
+            name: row.get("name"),
+            country: row.get::<Option<String>, _>("country").unwrap_or_default(),
+            birth_date: row.get("birth_date"),
+            birth_place: row.get("birth_place"),
+            height_cm: row.get("height_cm"),
+            weight_kg: row.get("weight_kg"),
+            position: row.get("position"),
+            shoots: row.get("shoots"),
+        });
+    }
+
+    Ok(Some(TeamHistoryFile {
+        format: TEAM_HISTORY_FORMAT.to_string(),
+        version: TEAM_HISTORY_VERSION,
+        exported_at: Some(chrono::Utc::now().to_rfc3339()),
+        team,
+        players,
+        participations,
+        matches,
+    }))
+}
+
+fn season_ref_from_row(row: &sqlx::sqlite::SqliteRow) -> SeasonRef {
+    SeasonRef {
+        event: row.get("event"),
+        event_country: row.get("event_country"),
+        year: row.get("year"),
+        display_name: row.get("display_name"),
+    }
+}
+
+/// How an import treats records that already exist
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Match existing records by name and only add what is missing
+    AddMissing,
+    /// Match records by external ID first (falling back to names) and update
+    /// them to the file's values
+    Merge,
+}
+
+impl ImportMode {
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "merge" => ImportMode::Merge,
+            _ => ImportMode::AddMissing,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportMode::AddMissing => "add",
+            ImportMode::Merge => "merge",
+        }
+    }
+}
+
+/// Created/updated/unchanged counts for one entity type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportCounts {
+    pub created: i64,
+    pub updated: i64,
+    pub unchanged: i64,
+}
+
+impl ImportCounts {
+    fn record(&mut self, outcome: Outcome) {
+        match outcome {
+            Outcome::Created => self.created += 1,
+            Outcome::Updated => self.updated += 1,
+            Outcome::Unchanged => self.unchanged += 1,
+        }
+    }
+}
+
+/// What happened to a single record during an import
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Outcome {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+/// What an import created, updated or left unchanged, per entity type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeamImportSummary {
+    pub team_id: i64,
+    pub team_created: bool,
+    pub mode: ImportMode,
+    pub teams: ImportCounts,
+    pub events: ImportCounts,
+    pub seasons: ImportCounts,
+    pub participations: ImportCounts,
+    pub players: ImportCounts,
+    pub contracts: ImportCounts,
+    pub matches: ImportCounts,
+    pub score_events: ImportCounts,
+}
+
+/// Look up a country by IOC code
+pub async fn find_country_by_code(db: &SqlitePool, code: &str) -> Result<Option<i64>, sqlx::Error> {
+    let id = sqlx::query_scalar::<_, i64>("SELECT id FROM country WHERE iocCode = ?")
+        .bind(code)
+        .fetch_optional(db)
+        .await?;
+
+    Ok(id)
+}
+
+/// Import a team history file in a single transaction
+///
+/// In [`ImportMode::AddMissing`] existing records are reused when they match
+/// by name (and birth date for players), so importing the same file twice
+/// does not duplicate anything. [`ImportMode::Merge`] additionally resolves
+/// teams, players and matches by external ID and overwrites their fields with
+/// the file's values.
+///
+/// The file must be validated first: every country code must exist and every
+/// player reference must point to a listed player.
+pub async fn import_team_history(
+    db: &SqlitePool,
+    file: &TeamHistoryFile,
+    countries: &HashMap<String, i64>,
+    mode: ImportMode,
+) -> Result<TeamImportSummary, sqlx::Error> {
+    let mut tx = db.begin().await?;
+    let mut importer = Importer::new(&mut tx, mode, countries);
+
+    let (team_id, outcome) = importer.team(&file.team).await?;
+    importer.counts.team_id = team_id;
+    importer.counts.team_created = outcome == Outcome::Created;
+
+    let mut player_ids: HashMap<i64, i64> = HashMap::new();
+    for player in &file.players {
+        let id = importer.player(player).await?;
+        player_ids.insert(player.key, id);
+    }
+
+    for participation in &file.participations {
+        let (season_id, event_id) = importer.season(&participation.season).await?;
+        let tp_id = importer.participation(team_id, season_id, event_id).await?;
+
+        for entry in &participation.roster {
+            if let Some(&player_id) = player_ids.get(&entry.player) {
+                importer
+                    .contract(tp_id, player_id, entry.jersey_number)
+                    .await?;
+            }
+        }
+    }
+
+    for record in &file.matches {
+        importer.game(record, &player_ids).await?;
+    }
+
+    let summary = importer.counts;
+
+    // Rows above are inserted directly, so give new records their slugs
+    slugs::assign_missing_slugs(&mut tx).await?;
+    tx.commit().await?;
+
+    Ok(summary)
+}
+
+/// Import state shared across the records of one file
+///
+/// Teams, events, seasons and participations are cached so that each is
+/// resolved (and counted) once, no matter how many matches reference it.
+/// Also used by whole-instance imports (see
+/// [`crate::service::instance_transfer`]).
+pub(super) struct Importer<'a> {
+    conn: &'a mut SqliteConnection,
+    mode: ImportMode,
+    countries: &'a HashMap<String, i64>,
+    teams: HashMap<(Option<String>, String, Option<String>), i64>,
+    events: HashMap<String, i64>,
+    seasons: HashMap<(String, i64), (i64, i64)>,
+    participations: HashMap<(i64, i64), i64>,
+    pub(super) counts: TeamImportSummary,
+}
+
+impl<'a> Importer<'a> {
+    pub(super) fn new(
+        conn: &'a mut SqliteConnection,
+        mode: ImportMode,
+        countries: &'a HashMap<String, i64>,
+    ) -> Self {
+        Self {
+            conn,
+            mode,
+            countries,
+            teams: HashMap::new(),
+            events: HashMap::new(),
+            seasons: HashMap::new(),
+            participations: HashMap::new(),
+            counts: TeamImportSummary {
+                team_id: 0,
+                team_created: false,
+                mode,
+                teams: ImportCounts::default(),
+                events: ImportCounts::default(),
+                seasons: ImportCounts::default(),
+                participations: ImportCounts::default(),
+                players: ImportCounts::default(),
+                contracts: ImportCounts::default(),
+                matches: ImportCounts::default(),
+                score_events: ImportCounts::default(),
+            },
+        }
+    }
+
+    /// Connection of the import's transaction
+    pub(super) fn conn(&mut self) -> &mut SqliteConnection {
+        self.conn
+    }
+
+    fn country(&self, code: Option<&str>) -> Option<i64> {
+        code.and_then(|c| self.countries.get(c).copied())
+    }
+
+    /// Resolve a record through its external ID when merging
+    async fn by_external_id(
+        &mut self,
+        entity: ExternalEntity,
+        external_id: Option<&str>,
+    ) -> Result<Option<i64>, sqlx::Error> {
+        match (self.mode, external_id) {
+            (ImportMode::Merge, Some(external_id)) => {
+                external_ids::find_by_external_id(self.conn, entity, external_id).await
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Remember the external ID of a resolved record when merging
+    async fn link(
+        &mut self,
+        entity: ExternalEntity,
+        external_id: Option<&str>,
+        entity_id: i64,
+    ) -> Result<(), sqlx::Error> {
+        match (self.mode, external_id) {
+            (ImportMode::Merge, Some(external_id)) => {
+                external_ids::link_external_id(self.conn, entity, external_id, entity_id).await
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Find a team by external ID or name and country, creating it when missing
+    pub(super) async fn team(&mut self, team: &TeamRef) -> Result<(i64, Outcome), sqlx::Error> {
+        let key = (
+            team.external_id.clone(),
+            team.name.clone(),
+            team.country.clone(),
+        );
+        if let Some(&id) = self.teams.get(&key) {
+            return Ok((id, Outcome::Unchanged));
+        }
+
+        let country_id = self.country(team.country.as_deref());
+        let mut existing = self
+            .by_external_id(ExternalEntity::Team, team.external_id.as_deref())
+            .await?;
+        if existing.is_none() {
+            existing = sqlx::query_scalar::<_, i64>(
+                "SELECT id FROM team WHERE name = ? AND country_id IS ? ORDER BY id LIMIT 1",
+            )
+            .bind(&team.name)
+            .bind(country_id)
+            .fetch_optional(&mut *self.conn)
+            .await?;
+        }
+
+        let (id, outcome) = match existing {
+            Some(id) if self.mode == ImportMode::Merge => {
+                let changed = sqlx::query(
+                    r#"
+                    UPDATE team SET name = ?, short_name = ?, country_id = ?, updated_at = CURRENT_TIMESTAMP
+                    WHERE id = ? AND (name IS NOT ? OR short_name IS NOT ? OR country_id IS NOT ?)
+                    "#,
+                )
+                .bind(&team.name)
+                .bind(&team.short_name)
+                .bind(country_id)
+                .bind(id)
+                .bind(&team.name)
+                .bind(&team.short_name)
+                .bind(country_id)
+                .execute(&mut *self.conn)
+                .await?
+                .rows_affected()
+                    > 0;
+                (
+                    id,
+                    if changed {
+                        Outcome::Updated
+                    } else {
+                        Outcome::Unchanged
+                    },
+                )
+            }
+            Some(id) => (id, Outcome::Unchanged),
+            None => {
+                let id =
+                    sqlx::query("INSERT INTO team (name, short_name, country_id) VALUES (?, ?, ?)")
+                        .bind(&team.name)
+                        .bind(&team.short_name)
+                        .bind(country_id)
+                        .execute(&mut *self.conn)
+                        .await?
+                        .last_insert_rowid();
+                (id, Outcome::Created)
+            }
+        };
+
+        self.link(ExternalEntity::Team, team.external_id.as_deref(), id)
+            .await?;
+        self.teams.insert(key, id);
+        self.counts.teams.record(outcome);
+
+        Ok((id, outcome))
+    }
+
+    /// Find a player by external ID or name and birth date, creating it when missing
+    pub(super) async fn player(&mut self, player: &PlayerRecord) -> Result<i64, sqlx::Error> {
+        let country_id = self.country(Some(&player.country));
+        let mut existing = self
+            .by_external_id(ExternalEntity::Player, player.external_id.as_deref())
+            .await?;
+        if existing.is_none() {
+            existing = sqlx::query_scalar::<_, i64>(
+                "SELECT id FROM player WHERE name = ? AND birth_date IS ? ORDER BY id LIMIT 1",
+            )
+            .bind(&player.name)
+            .bind(&player.birth_date)
+            .fetch_optional(&mut *self.conn)
+            .await?;
+        }
+
+        let (id, outcome) = match existing {
+            Some(id) if self.mode == ImportMode::Merge => {
+                let changed = sqlx::query(
+                    r#"
+                    UPDATE player
+                    SET name = ?1, country_id = ?2, birth_date = ?3, birth_place = ?4,
+                        height_cm = ?5, weight_kg = ?6, position = ?7, shoots = ?8,
+                        updated_at = CURRENT_TIMESTAMP
+                    WHERE id = ?9 AND (
+                        name IS NOT ?1 OR country_id IS NOT ?2 OR birth_date IS NOT ?3
+                        OR birth_place IS NOT ?4 OR height_cm IS NOT ?5 OR weight_kg IS NOT ?6
+                        OR position IS NOT ?7 OR shoots IS NOT ?8
+                    )
+                    "#,
+                )
+                .bind(&player.name)
+                .bind(country_id)
+                .bind(&player.birth_date)
+                .bind(&player.birth_place)
+                .bind(player.height_cm)
+                .bind(player.weight_kg)
+                .bind(&player.position)
+                .bind(&player.shoots)
+                .bind(id)
+                .execute(&mut *self.conn)
+                .await?
+                .rows_affected()
+                    > 0;
+                (
+                    id,
+                    if changed {
+                        Outcome::Updated
+                    } else {
+                        Outcome::Unchanged
+                    },
+                )
+            }
+            Some(id) => (id, Outcome::Unchanged),
+            None => {
+                let id = sqlx::query(
+                    r#"
+                    INSERT INTO player (name, country_id, birth_date, birth_place, height_cm, weight_kg, position, shoots)
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+                    "#,
+                )
+                .bind(&player.name)
+                .bind(country_id)
+                .bind(&player.birth_date)
+                .bind(&player.birth_place)
+                .bind(player.height_cm)
+                .bind(player.weight_kg)
+                .bind(&player.position)
+                .bind(&player.shoots)
+                .execute(&mut *self.conn)
+                .await?
+                .last_insert_rowid();
+                (id, Outcome::Created)
+            }
+        };
+
+        self.link(ExternalEntity::Player, player.external_id.as_deref(), id)
+            .await?;
+        self.counts.players.record(outcome);
+
+        Ok(id)
+    }
+
+    /// Find an event by name, preferring one in the given country, creating it when missing
+    pub(super) async fn event(
+        &mut self,
+        name: &str,
+        country: Option<&str>,
+    ) -> Result<(i64, Outcome), sqlx::Error> {
+        if let Some(&id) = self.events.get(name) {
+            return Ok((id, Outcome::Unchanged));
+        }
+
+        let country_id = self.country(country);
+        let (id, outcome) = match sqlx::query_scalar::<_, i64>(
+            "SELECT id FROM event WHERE name = ? ORDER BY country_id IS NOT ?, id LIMIT 1",
+        )
+        .bind(name)
+        .bind(country_id)
+        .fetch_optional(&mut *self.conn)
+        .await?
+        {
+            Some(id) => (id, Outcome::Unchanged),
+            None => {
+                let id = sqlx::query("INSERT INTO event (name, country_id) VALUES (?, ?)")
+                    .bind(name)
+                    .bind(country_id)
+                    .execute(&mut *self.conn)
+                    .await?
+                    .last_insert_rowid();
+                (id, Outcome::Created)
+            }
+        };
+
+        self.events.insert(name.to_string(), id);
+        self.counts.events.record(outcome);
+
+        Ok((id, outcome))
+    }
+
+    /// Find an event by name and its season by year, creating either when missing
+    ///
+    /// Returns `(season_id, event_id)`.
+    pub(super) async fn season(&mut self, season: &SeasonRef) -> Result<(i64, i64), sqlx::Error> {
+        let key = (season.event.clone(), season.year);
+        if let Some(&ids) = self.seasons.get(&key) {
+            return Ok(ids);
+        }
+
+        let (event_id, _) = self
+            .event(&season.event, season.event_country.as_deref())
+            .await?;
+
+        let season_id = match sqlx::query_scalar::<_, i64>(
+            "SELECT id FROM season WHERE event_id = ? AND year = ? ORDER BY id LIMIT 1",
+        )
+        .bind(event_id)
+        .bind(season.year)
+        .fetch_optional(&mut *self.conn)
+        .await?
+        {
+            Some(id) => {
+                self.counts.seasons.record(Outcome::Unchanged);
+                id
+            }
+            None => {
+                self.counts.seasons.record(Outcome::Created);
+                sqlx::query("INSERT INTO season (year, display_name, event_id) VALUES (?, ?, ?)")
+                    .bind(season.year)
+                    .bind(&season.display_name)
+                    .bind(event_id)
+                    .execute(&mut *self.conn)
+                    .await?
+                    .last_insert_rowid()
+            }
+        };
+
+        self.seasons.insert(key, (season_id, event_id));
+
+        Ok((season_id, event_id))
+    }
+
+    /// Find or create the participation of a team in a season
+    pub(super) async fn participation(
+        &mut self,
+        team_id: i64,
+        season_id: i64,
+        event_id: i64,
+    ) -> Result<i64, sqlx::Error> {
+        if let Some(&id) = self.participations.get(&(team_id, season_id)) {
+            return Ok(id);
+        }
+
+        let existing = sqlx::query_scalar::<_, i64>(
+            "SELECT id FROM team_participation WHERE team_id = ? AND season_id = ?",
+        )
+        .bind(team_id)
+        .bind(season_id)
+        .fetch_optional(&mut *self.conn)
+        .await?;
+
+        let id = match existing {
+            Some(id) => {
+                self.counts.participations.record(Outcome::Unchanged);
+                id
+            }
+            None => {
+                self.counts.participations.record(Outcome::Created);
+                sqlx::query(
+                    "INSERT INTO team_participation (team_id, season_id, event_id) VALUES (?, ?, ?)",
+                )
+                .bind(team_id)
+                .bind(season_id)
+                .bind(event_id)
+                .execute(&mut *self.conn)
+                .await?
+                .last_insert_rowid()
+            }
+        };
+
+        self.participations.insert((team_id, season_id), id);
+
+        Ok(id)
+    }
+
+    /// Add a player to a roster, keeping the jersey number only when it is free
+    pub(super) async fn contract(
+        &mut self,
+        tp_id: i64,
+        player_id: i64,
+        jersey_number: Option<i64>,
+    ) -> Result<(), sqlx::Error> {
+        let existing = sqlx::query(
+            "SELECT id, jersey_number FROM player_contract WHERE team_participation_id = ? AND player_id = ?",
+        )
+        .bind(tp_id)
+        .bind(player_id)
+        .fetch_optional(&mut *self.conn)
+        .await?;
+
+        let number_is_free = match jersey_number {
+            Some(number) => {
+                let taken = sqlx::query_scalar::<_, i64>(
+                    "SELECT COUNT(*) FROM player_contract WHERE team_participation_id = ? AND jersey_number = ? AND player_id != ?",
+                )
+                .bind(tp_id)
+                .bind(number)
+                .bind(player_id)
+                .fetch_one(&mut *self.conn)
+                .await?;
+                taken == 0
+            }
+            None => true,
+        };
+
+        let outcome = match existing {
+            Some(row) => {
+                let current: Option<i64> = row.get("jersey_number");
+                if self.mode == ImportMode::Merge && current != jersey_number && number_is_free {
+                    sqlx::query("UPDATE player_contract SET jersey_number = ? WHERE id = ?")
+                        .bind(jersey_number)
+                        .bind(row.get::<i64, _>("id"))
+                        .execute(&mut *self.conn)
+                        .await?;
+                    Outcome::Updated
+                } else {
+                    Outcome::Unchanged
+                }
+            }
+            None => {
+                sqlx::query(
+                    "INSERT INTO player_contract (team_participation_id, player_id, jersey_number) VALUES (?, ?, ?)",
+                )
+                .bind(tp_id)
+                .bind(player_id)
+                .bind(jersey_number.filter(|_| number_is_free))
+                .execute(&mut *self.conn)
+                .await?;
+                Outcome::Created
+            }
+        };
+
+        self.counts.contracts.record(outcome);
+
+        Ok(())
+    }
+
+    /// Import a match and its goals
+    ///
+    /// Existing matches are found by external ID (when merging) or by season,
+    /// teams and date. When merging, their fields are overwritten and their
+    /// goals replaced if they differ from the file.
+    pub(super) async fn game(
+        &mut self,
+        record: &MatchRecord,
+        player_ids: &HashMap<i64, i64>,
+    ) -> Result<(), sqlx::Error> {
+        let (season_id, event_id) = self.season(&record.season).await?;
+        let (home_team_id, _) = self.team(&record.home_team).await?;
+        let (away_team_id, _) = self.team(&record.away_team).await?;
+        self.participation(home_team_id, season_id, event_id)
+            .await?;
+        self.participation(away_team_id, season_id, event_id)
+            .await?;
+
+        // Unknown game types fall back to the group stage, like the column default
+        let game_type = record
+            .game_type
+            .as_deref()
+            .and_then(matches::GameType::from_str)
+            .unwrap_or(matches::GameType::Group)
+            .as_str();
+
+        let arena_id = match record.venue.as_deref().map(str::trim) {
+            Some(name) if !name.is_empty() => {
+                Some(arenas::find_or_create_arena(&mut *self.conn, name).await?)
+            }
+            _ => None,
+        };
+
+        let mut existing = self
+            .by_external_id(ExternalEntity::Match, record.external_id.as_deref())
+            .await?;
+        if existing.is_none() {
+            existing = sqlx::query_scalar::<_, i64>(
+                r#"
+                SELECT id FROM match
+                WHERE season_id = ? AND home_team_id = ? AND away_team_id = ? AND match_date IS ?
+                LIMIT 1
+                "#,
+            )
+            .bind(season_id)
+            .bind(home_team_id)
+            .bind(away_team_id)
+            .bind(&record.match_date)
+            .fetch_optional(&mut *self.conn)
+            .await?;
+        }
+
+        let goals: Vec<ScoreEventRecord> = record
+            .score_events
+            .iter()
+            .map(|se| {
+                let player = |key: Option<i64>| key.and_then(|k| player_ids.get(&k).copied());
+                ScoreEventRecord {
+                    scorer: player(se.scorer),
+                    assist1: player(se.assist1),
+                    assist2: player(se.assist2),
+                    ..se.clone()
+                }
+            })
+            .collect();
+
+        let match_id = match existing {
+            Some(id) if self.mode == ImportMode::Merge => {
+                let changed = sqlx::query(
+                    r#"
+                    UPDATE match
+                    SET season_id = ?1, home_team_id = ?2, away_team_id = ?3,
+                        home_score_unidentified = ?4, away_score_unidentified = ?5,
+                        match_date = ?6, status = ?7, arena_id = ?8, game_type = ?10,
+                        updated_at = CURRENT_TIMESTAMP
+                    WHERE id = ?9 AND (
+                        season_id IS NOT ?1 OR home_team_id IS NOT ?2 OR away_team_id IS NOT ?3
+                        OR home_score_unidentified IS NOT ?4 OR away_score_unidentified IS NOT ?5
+                        OR match_date IS NOT ?6 OR status IS NOT ?7 OR arena_id IS NOT ?8
+                        OR game_type IS NOT ?10
+                    )
+                    "#,
+                )
+                .bind(season_id)
+                .bind(home_team_id)
+                .bind(away_team_id)
+                .bind(record.home_score_unidentified)
+                .bind(record.away_score_unidentified)
+                .bind(&record.match_date)
+                .bind(&record.status)
+                .bind(arena_id)
+                .bind(id)
+                .bind(game_type)
+                .execute(&mut *self.conn)
+                .await?
+                .rows_affected()
+                    > 0;
+
+                let current = self.goals_of(id, home_team_id).await?;
+                let goals_changed = current != goals;
+                if goals_changed {
+                    sqlx::query("DELETE FROM score_event WHERE match_id = ?")
+                        .bind(id)
+                        .execute(&mut *self.conn)
+                        .await?;
+                    self.insert_goals(id, home_team_id, away_team_id, &goals)
+                        .await?;
+                    self.counts.score_events.updated += goals.len() as i64;
+                } else {
+                    self.counts.score_events.unchanged += goals.len() as i64;
+                }
+
+                self.counts.matches.record(if changed || goals_changed {
+                    Outcome::Updated
+                } else {
+                    Outcome::Unchanged
+                });
+                id
+            }
+            Some(id) => {
+                self.counts.matches.record(Outcome::Unchanged);
+                id
+            }
+            None => {
+                let id = sqlx::query(
+                    r#"
+                    INSERT INTO match (season_id, home_team_id, away_team_id, home_score_unidentified,
+                                       away_score_unidentified, match_date, status, arena_id, game_type)
+                    VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                    "#,
+                )
+                .bind(season_id)
+                .bind(home_team_id)
+                .bind(away_team_id)
+                .bind(record.home_score_unidentified)
+                .bind(record.away_score_unidentified)
+                .bind(&record.match_date)
+                .bind(&record.status)
+                .bind(arena_id)
+                .bind(game_type)
+                .execute(&mut *self.conn)
+                .await?
+                .last_insert_rowid();
+
+                self.insert_goals(id, home_team_id, away_team_id, &goals)
+                    .await?;
+                self.counts.score_events.created += goals.len() as i64;
+                self.counts.matches.record(Outcome::Created);
+                id
+            }
+        };
+        matches::refresh_match_result(self.conn, match_id).await?;
+
+        self.link(
+            ExternalEntity::Match,
+            record.external_id.as_deref(),
+            match_id,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Goals of a match in file order, with local player IDs
+    async fn goals_of(
+        &mut self,
+        match_id: i64,
+        home_team_id: i64,
+    ) -> Result<Vec<ScoreEventRecord>, sqlx::Error> {
+        let rows = sqlx::query(
+            r#"
+            SELECT team_id, scorer_id, assist1_id, assist2_id, period, time_minutes, time_seconds, goal_type
+            FROM score_event
+            WHERE match_id = ?
+            ORDER BY period, time_minutes, time_seconds, id
+            "#,
+        )
+        .bind(match_id)
+        .fetch_all(&mut *self.conn)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|se| ScoreEventRecord {
+                home: se.get::<i64, _>("team_id") == home_team_id,
+                scorer: se.get("scorer_id"),
+                assist1: se.get("assist1_id"),
+                assist2: se.get("assist2_id"),
+                period: se.get("period"),
+                time_minutes: se.get("time_minutes"),
+                time_seconds: se.get("time_seconds"),
+                goal_type: se.get("goal_type"),
+            })
+            .collect())
+    }
+
+    async fn insert_goals(
+        &mut self,
+        match_id: i64,
+        home_team_id: i64,
+        away_team_id: i64,
+        goals: &[ScoreEventRecord],
+    ) -> Result<(), sqlx::Error> {
+        for se in goals {
+            sqlx::query(
+                r#"
+                INSERT INTO score_event (match_id, team_id, scorer_id, assist1_id, assist2_id,
+                                         period, time_minutes, time_seconds, goal_type)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(match_id)
+            .bind(if se.home { home_team_id } else { away_team_id })
+            .bind(se.scorer)
+            .bind(se.assist1)
+            .bind(se.assist2)
+            .bind(se.period)
+            .bind(se.time_minutes)
+            .bind(se.time_seconds)
+            .bind(&se.goal_type)
+            .execute(&mut *self.conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::player_contracts::add_player_to_roster;
+
+    async fn seed_history(pool: &SqlitePool) {
+        add_player_to_roster(pool, 1, 1, Some(97)).await.unwrap();
+        add_player_to_roster(pool, 1, 8, Some(87)).await.unwrap();
+        add_player_to_roster(pool, 2, 9, None).await.unwrap();
+
+        let match_id = sqlx::query(
+            "INSERT INTO match (season_id, home_team_id, away_team_id, match_date, status) VALUES (1, 1, 2, '2022-02-10', 'finished')",
+        )
+        .execute(pool)
+        .await
+        .unwrap()
+        .last_insert_rowid();
+
+        sqlx::query(
+            "INSERT INTO score_event (match_id, team_id, scorer_id, assist1_id, period) VALUES (?, 1, 1, 8, 1), (?, 2, 9, NULL, 2)",
+        )
+        .bind(match_id)
+        .bind(match_id)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn countries_for(pool: &SqlitePool, file: &TeamHistoryFile) -> HashMap<String, i64> {
+        let mut countries = HashMap::new();
+        for code in file.country_codes() {
+            let id = find_country_by_code(pool, code).await.unwrap().unwrap();
+            countries.insert(code.to_string(), id);
+        }
+        countries
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_export_team_history(pool: SqlitePool) {
+        seed_history(&pool).await;
+
+        let file = export_team_history(&pool, 1).await.unwrap().unwrap();
+        assert_eq!(file.format, TEAM_HISTORY_FORMAT);
+        assert_eq!(file.team.name, "Team Canada");
+        assert_eq!(file.team.country.as_deref(), Some("CAN"));
+        assert_eq!(file.participations.len(), 1);
+        assert_eq!(file.participations[0].roster.len(), 2);
+        assert_eq!(file.matches.len(), 1);
+        assert_eq!(file.matches[0].score_events.len(), 2);
+        // Opponent scorer is listed even though not on this team's roster
+        assert!(file.players.iter().any(|p| p.key == 9));
+        assert_eq!(
+            file.referenced_player_keys(),
+            file.players.iter().map(|p| p.key).collect()
+        );
+
+        // External IDs are assigned once and reused by later exports
+        let again = export_team_history(&pool, 1).await.unwrap().unwrap();
+        assert!(file.team.external_id.is_some());
+        assert_eq!(file.team.external_id, again.team.external_id);
+        assert_eq!(file.matches[0].external_id, again.matches[0].external_id);
+        assert_eq!(file.players[0].external_id, again.players[0].external_id);
+
+        assert!(export_team_history(&pool, 999).await.unwrap().is_none());
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_import_team_history_is_idempotent(pool: SqlitePool) {
+        seed_history(&pool).await;
+
+        let mut file = export_team_history(&pool, 1).await.unwrap().unwrap();
+        file.team.name = "Canada Archive".to_string();
+        file.matches[0].home_team.name = "Canada Archive".to_string();
+        file.matches[0].season.event = "Olympic Archive".to_string();
+        let countries = countries_for(&pool, &file).await;
+
+        let summary = import_team_history(&pool, &file, &countries, ImportMode::AddMissing)
+            .await
+            .unwrap();
+        assert!(summary.team_created);
+        assert_eq!(summary.players.created, 0);
+        assert_eq!(summary.players.unchanged, 3);
+        assert_eq!(summary.contracts.created, 2);
+        assert_eq!(summary.events.created, 1);
+        assert_eq!(summary.matches.created, 1);
+        assert_eq!(summary.score_events.created, 2);
+
+        let imported = export_team_history(&pool, summary.team_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(imported.matches.len(), 1);
+        assert_eq!(imported.participations.len(), 2);
+
+        let again = import_team_history(&pool, &file, &countries, ImportMode::AddMissing)
+            .await
+            .unwrap();
+        assert_eq!(again.team_id, summary.team_id);
+        assert!(!again.team_created);
+        assert_eq!(again.contracts.created, 0);
+        assert_eq!(again.matches.created, 0);
+        assert_eq!(again.matches.unchanged, 1);
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_merge_import_updates_by_external_id(pool: SqlitePool) {
+        seed_history(&pool).await;
+
+        let mut file = export_team_history(&pool, 1).await.unwrap().unwrap();
+        // Renamed records are still found through their external IDs
+        file.team.name = "Canada Renamed".to_string();
+        file.matches[0].home_team.name = "Canada Renamed".to_string();
+        file.players[0].height_cm = Some(199);
+        file.matches[0].venue = Some("Main Arena".to_string());
+        file.matches[0].score_events.pop();
+        let countries = countries_for(&pool, &file).await;
+
+        let summary = import_team_history(&pool, &file, &countries, ImportMode::Merge)
+            .await
+            .unwrap();
+        assert_eq!(summary.team_id, 1);
+        assert!(!summary.team_created);
+        assert_eq!(summary.teams.updated, 1);
+        assert_eq!(summary.teams.unchanged, 1);
+        assert_eq!(summary.players.updated, 1);
+        assert_eq!(summary.players.unchanged, 2);
+        assert_eq!(summary.matches.updated, 1);
+        assert_eq!(summary.score_events.updated, 1);
+        assert_eq!(summary.contracts.unchanged, 2);
+
+        let merged = export_team_history(&pool, 1).await.unwrap().unwrap();
+        assert_eq!(merged.team.name, "Canada Renamed");
+        assert_eq!(merged.matches.len(), 1);
+        assert_eq!(merged.matches[0].venue.as_deref(), Some("Main Arena"));
+        assert_eq!(merged.matches[0].score_events.len(), 1);
+
+        let again = import_team_history(&pool, &file, &countries, ImportMode::Merge)
+            .await
+            .unwrap();
+        assert_eq!(again.teams.updated, 0);
+        assert_eq!(again.players.updated, 0);
+        assert_eq!(again.matches.unchanged, 1);
+        assert_eq!(again.score_events.unchanged, 1);
+    }
+}
@@ -0,0 +1,311 @@
+//! Merging a duplicate team into the canonical one
+//!
+//! Everything recorded for the duplicate (season participations with their
+//! rosters, matches, score events, playoff series, shots, faceoffs, calendar
+//! subscriptions, external ids and tasks) moves to the canonical team, then
+//! the duplicate is deleted. Its slugs keep redirecting to the canonical team.
+//!
+//! Two teams can only be merged when their histories do not overlap: a season
+//! both took part in would leave the canonical team with two participations,
+//! and a match between the two would be played against itself.
+
+use sqlx::SqlitePool;
+
+use crate::service::slugs::{self, SlugEntity};
+
+/// Season in which both teams took part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedSeasonEntity {
+    pub season_id: i64,
+    pub season_name: String,
+}
+
+/// What a merge would move, and what prevents it
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TeamMergePreview {
+    pub participations: i64,
+    pub matches: i64,
+    pub score_events: i64,
+    pub shared_seasons: Vec<SharedSeasonEntity>,
+    /// Matches and playoff series between the two teams
+    pub head_to_head: i64,
+}
+
+impl TeamMergePreview {
+    pub fn has_conflicts(&self) -> bool {
+        !self.shared_seasons.is_empty() || self.head_to_head > 0
+    }
+}
+
+/// Records of the duplicate team that a merge into the canonical one would move
+pub async fn get_merge_preview(
+    db: &SqlitePool,
+    canonical_id: i64,
+    duplicate_id: i64,
+) -> Result<TeamMergePreview, sqlx::Error> {
+    let participations = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!: i64" FROM team_participation WHERE team_id = ?"#,
+        duplicate_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    let matches = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) as "count!: i64" FROM match
+        WHERE home_team_id = ?1 OR away_team_id = ?1
+        "#,
+        duplicate_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    let score_events = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!: i64" FROM score_event WHERE team_id = ?"#,
+        duplicate_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    let shared_seasons = sqlx::query_as!(
+        SharedSeasonEntity,
+        r#"
+        SELECT DISTINCT
+            s.id as "season_id!",
+            COALESCE(s.display_name, e.name || ' ' || s.year) as "season_name!: String"
+        FROM team_participation canonical
+        INNER JOIN team_participation duplicate ON duplicate.season_id = canonical.season_id
+        INNER JOIN season s ON s.id = canonical.season_id
+        INNER JOIN event e ON e.id = s.event_id
+        WHERE canonical.team_id = ?1 AND duplicate.team_id = ?2
+        ORDER BY s.year DESC, e.name
+        "#,
+        canonical_id,
+        duplicate_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    let head_to_head = sqlx::query_scalar!(
+        r#"
+        SELECT
+            (SELECT COUNT(*) FROM match
+                WHERE (home_team_id = ?1 AND away_team_id = ?2)
+                   OR (home_team_id = ?2 AND away_team_id = ?1))
+            + (SELECT COUNT(*) FROM playoff_series
+                WHERE (home_team_id = ?1 AND away_team_id = ?2)
+                   OR (home_team_id = ?2 AND away_team_id = ?1)) as "count!: i64"
+        "#,
+        canonical_id,
+        duplicate_id
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(TeamMergePreview {
+        participations,
+        matches,
+        score_events,
+        shared_seasons,
+        head_to_head,
+    })
+}
+
+/// Move everything of the duplicate team to the canonical one and delete it
+///
+/// Conflicts must be checked beforehand with [`get_merge_preview`]; an
+/// overlapping history makes the merge fail on a constraint and roll back.
+pub async fn merge_teams(
+    db: &SqlitePool,
+    canonical_id: i64,
+    duplicate_id: i64,
+) -> Result<(), sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "UPDATE team_participation SET team_id = ? WHERE team_id = ?",
+        canonical_id,
+        duplicate_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        "UPDATE match SET home_team_id = ? WHERE home_team_id = ?",
+        canonical_id,
+        duplicate_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        "UPDATE match SET away_team_id = ? WHERE away_team_id = ?",
+        canonical_id,
+        duplicate_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        "UPDATE score_event SET team_id = ? WHERE team_id = ?",
+        canonical_id,
+        duplicate_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        "UPDATE score_event_on_ice SET team_id = ? WHERE team_id = ?",
+        canonical_id,
+        duplicate_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        "UPDATE playoff_series SET home_team_id = ? WHERE home_team_id = ?",
+        canonical_id,
+        duplicate_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        "UPDATE playoff_series SET away_team_id = ? WHERE away_team_id = ?",
+        canonical_id,
+        duplicate_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        "UPDATE match_shots SET team_id = ? WHERE team_id = ?",
+        canonical_id,
+        duplicate_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        "UPDATE match_faceoffs SET team_id = ? WHERE team_id = ?",
+        canonical_id,
+        duplicate_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        "UPDATE team_calendar_subscription SET team_id = ? WHERE team_id = ?",
+        canonical_id,
+        duplicate_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        "UPDATE external_alias SET entity_id = ? WHERE entity_type = 'team' AND entity_id = ?",
+        canonical_id,
+        duplicate_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        "UPDATE task SET entity_id = ? WHERE entity_type = 'team' AND entity_id = ?",
+        canonical_id,
+        duplicate_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    slugs::redirect_slugs(&mut tx, SlugEntity::Team, duplicate_id, canonical_id).await?;
+
+    sqlx::query!("DELETE FROM team WHERE id = ?", duplicate_id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_merge_teams(pool: SqlitePool) {
+        // Team 100 duplicates Team Canada in a season Canada did not play
+        sqlx::query("INSERT INTO season (id, year, event_id) VALUES (100, 1990, 1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "UPDATE team SET slug = 'team-canada' WHERE id = 1;
+             INSERT INTO team (id, name, country_id, slug) VALUES (100, 'Kanada', 34, 'kanada')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO team_participation (team_id, season_id, event_id) VALUES (100, 100, 1), (2, 100, 1)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO match (id, season_id, home_team_id, away_team_id, status) VALUES
+                (1, 100, 100, 2, 'finished')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO score_event (match_id, team_id, period) VALUES (1, 100, 1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let preview = get_merge_preview(&pool, 1, 100).await.unwrap();
+        assert_eq!(
+            (
+                preview.participations,
+                preview.matches,
+                preview.score_events
+            ),
+            (1, 1, 1)
+        );
+        assert!(!preview.has_conflicts());
+
+        // Both USA and the duplicate played season 100, and against each other
+        let conflicting = get_merge_preview(&pool, 2, 100).await.unwrap();
+        assert_eq!(conflicting.shared_seasons.len(), 1);
+        assert_eq!(conflicting.shared_seasons[0].season_id, 100);
+        assert_eq!(conflicting.head_to_head, 1);
+
+        merge_teams(&pool, 1, 100).await.unwrap();
+
+        let home_team = sqlx::query_scalar!("SELECT home_team_id FROM match WHERE id = 1")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(home_team, 1);
+        let scoring_team =
+            sqlx::query_scalar!("SELECT team_id FROM score_event WHERE match_id = 1")
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(scoring_team, 1);
+        let participant = sqlx::query_scalar!(
+            "SELECT team_id FROM team_participation WHERE season_id = 100 AND team_id != 2"
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(participant, 1);
+
+        let remaining =
+            sqlx::query_scalar!(r#"SELECT COUNT(*) as "count!: i64" FROM team WHERE id = 100"#)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(remaining, 0);
+        assert_eq!(
+            slugs::resolve_slug(&pool, SlugEntity::Team, "kanada")
+                .await
+                .unwrap(),
+            Some(slugs::SlugTarget::Moved("team-canada".to_string()))
+        );
+    }
+}
@@ -3,7 +3,6 @@ use sqlx::SqlitePool;
 #[derive(Debug, Clone)]
 pub struct TeamParticipationEntity {
     pub id: i64,
-    #[allow(dead_code)]
     pub team_id: i64,
     pub team_name: String,
     #[allow(dead_code)]
@@ -11,6 +10,11 @@ pub struct TeamParticipationEntity {
     pub country_iso2_code: Option<String>,
     #[allow(dead_code)]
     pub season_id: i64,
+    pub group_id: Option<i64>,
+    /// Final placement in the season (1 = gold)
+    pub final_rank: Option<i64>,
+    /// Playoff seed (1 = top seed)
+    pub seed: Option<i64>,
 }
 
 #[derive(Debug, Clone)]
@@ -40,7 +44,10 @@ pub async fn get_teams_for_season(
             t.name as team_name,
             t.country_id,
             c.iso2Code as country_iso2_code,
-            tp.season_id as season_id
+            tp.season_id as season_id,
+            tp.group_id,
+            tp.final_rank,
+            tp.seed
         FROM team_participation tp
         INNER JOIN team t ON tp.team_id = t.id
         LEFT JOIN country c ON t.country_id = c.id
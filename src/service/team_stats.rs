@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+
+/// Goal totals of a team in one season, from its score events
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TeamSeasonStatsEntity {
+    pub season_id: i64,
+    pub season_year: i64,
+    pub season_display_name: Option<String>,
+    pub event_name: String,
+    pub goals_for: i64,
+    pub goals_against: i64,
+    pub goal_differential: i64,
+    /// Power play goals scored by the team
+    pub power_play_goals: i64,
+    /// Shorthanded goals scored by the team
+    pub short_handed_goals: i64,
+    pub faceoffs_won: i64,
+    pub faceoffs_lost: i64,
+    /// Shots on goal in matches with shots recorded
+    pub shots: i64,
+    /// Goals in those same matches, the base of the shooting percentage
+    pub shooting_goals: i64,
+}
+
+impl TeamSeasonStatsEntity {
+    /// Faceoff win percentage, `None` when no faceoffs were recorded
+    pub fn faceoff_percentage(&self) -> Option<f64> {
+        crate::service::matches::faceoff_percentage(self.faceoffs_won, self.faceoffs_lost)
+    }
+
+    /// Shooting percentage, `None` when no shots were recorded
+    pub fn shooting_percentage(&self) -> Option<f64> {
+        crate::service::matches::shooting_percentage(self.shooting_goals, self.shots)
+    }
+}
+
+/// Goal statistics of a team for every season it takes part in, newest first
+///
+/// Seasons without any recorded goal are listed with zeros. Faceoffs add up
+/// the team's players' recorded faceoffs, and shooting counts the matches
+/// with shots recorded like the season cards do. Exhibition games are left
+/// out, as in the standings.
+pub async fn get_team_season_stats(
+    db: &SqlitePool,
+    team_id: i64,
+) -> Result<Vec<TeamSeasonStatsEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        TeamSeasonStatsEntity,
+        r#"
+        SELECT
+            s.id as "season_id!: i64",
+            s.year as season_year,
+            s.display_name as season_display_name,
+            e.name as event_name,
+            COALESCE(SUM(se.team_id = ?1), 0) as "goals_for!: i64",
+            COALESCE(SUM(se.team_id <> ?1), 0) as "goals_against!: i64",
+            COALESCE(SUM(se.team_id = ?1), 0) - COALESCE(SUM(se.team_id <> ?1), 0)
+                as "goal_differential!: i64",
+            COALESCE(SUM(se.team_id = ?1 AND se.goal_type = 'power_play'), 0)
+                as "power_play_goals!: i64",
+            COALESCE(SUM(se.team_id = ?1 AND se.goal_type = 'short_handed'), 0)
+                as "short_handed_goals!: i64",
+            (
+                SELECT COALESCE(SUM(f.won), 0)
+                FROM match_faceoffs f
+                INNER JOIN match fm ON f.match_id = fm.id
+                WHERE f.team_id = ?1 AND fm.season_id = s.id AND fm.game_type != 'exhibition'
+            ) as "faceoffs_won!: i64",
+            (
+                SELECT COALESCE(SUM(f.lost), 0)
+                FROM match_faceoffs f
+                INNER JOIN match fm ON f.match_id = fm.id
+                WHERE f.team_id = ?1 AND fm.season_id = s.id AND fm.game_type != 'exhibition'
+            ) as "faceoffs_lost!: i64",
+            (
+                SELECT COALESCE(SUM(tms.shots), 0)
+                FROM team_match_shooting tms
+                WHERE tms.team_id = ?1 AND tms.season_id = s.id
+            ) as "shots!: i64",
+            (
+                SELECT COALESCE(SUM(tms.goals), 0)
+                FROM team_match_shooting tms
+                WHERE tms.team_id = ?1 AND tms.season_id = s.id
+            ) as "shooting_goals!: i64"
+        FROM season s
+        INNER JOIN event e ON s.event_id = e.id
+        LEFT JOIN match m
+            ON m.season_id = s.id AND (m.home_team_id = ?1 OR m.away_team_id = ?1)
+            AND m.game_type != 'exhibition'
+        LEFT JOIN score_event se ON se.match_id = m.id
+        WHERE s.id IN (SELECT season_id FROM team_participation WHERE team_id = ?1)
+        GROUP BY s.id, s.year, s.display_name, e.name
+        ORDER BY s.year DESC, s.id DESC
+        "#,
+        team_id
+    )
+    .fetch_all(db)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations")
+    )]
+    async fn test_get_team_season_stats(pool: SqlitePool) {
+        sqlx::query(
+            "INSERT INTO team_participation (season_id, team_id, event_id) VALUES (2, 1, 2);
+             INSERT INTO match (id, season_id, home_team_id, away_team_id)
+                VALUES (1, 1, 1, 2), (2, 1, 3, 4);
+             INSERT INTO score_event (match_id, team_id, period, goal_type) VALUES
+                (1, 1, 1, 'power_play'), (1, 1, 2, 'short_handed'), (1, 1, 3, NULL),
+                (1, 2, 3, 'power_play'), (2, 3, 1, 'power_play');
+             INSERT INTO player (id, name, country_id) VALUES (1, 'Center', 34), (2, 'Other', 34);
+             INSERT INTO match_faceoffs (match_id, player_id, team_id, won, lost) VALUES
+                (1, 1, 1, 12, 8), (1, 2, 2, 8, 12);
+             INSERT INTO match_shots (match_id, team_id, period, shots) VALUES
+                (1, 1, 1, 10), (1, 1, 2, 10);
+             INSERT INTO match (id, season_id, home_team_id, away_team_id, game_type)
+                VALUES (3, 1, 1, 2, 'exhibition');
+             INSERT INTO score_event (match_id, team_id, period) VALUES (3, 1, 1), (3, 2, 1);
+             INSERT INTO match_shots (match_id, team_id, period, shots) VALUES (3, 1, 1, 40);",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let stats = get_team_season_stats(&pool, 1).await.unwrap();
+        assert_eq!(stats.len(), 2);
+
+        // Newest season first, without any goal
+        assert_eq!(stats[0].season_id, 2);
+        assert_eq!(stats[0].goals_for, 0);
+        assert_eq!(stats[0].goals_against, 0);
+
+        // Goals of matches without the team are ignored
+        let season = &stats[1];
+        assert_eq!(season.season_id, 1);
+        assert_eq!(season.goals_for, 3);
+        assert_eq!(season.goals_against, 1);
+        assert_eq!(season.goal_differential, 2);
+        assert_eq!(season.power_play_goals, 1);
+        assert_eq!(season.short_handed_goals, 1);
+        assert_eq!(season.faceoff_percentage(), Some(60.0));
+        // Exhibition goals and shots are left out
+        assert_eq!(season.shots, 20);
+        assert_eq!(season.shooting_percentage(), Some(15.0));
+        assert_eq!(stats[0].shooting_percentage(), None);
+        assert_eq!(stats[0].faceoff_percentage(), None);
+    }
+}
@@ -1,6 +1,9 @@
+use futures::TryStreamExt;
 use sqlx::{Row, SqlitePool};
 
+use crate::common::csv_export::CsvSink;
 use crate::common::pagination::PagedResult;
+use crate::service::slugs::{self, SlugEntity};
 
 // Re-export SortOrder for backwards compatibility
 pub use crate::common::pagination::SortOrder;
@@ -12,6 +15,8 @@ pub struct TeamEntity {
     pub country_id: Option<i64>,
     pub country_name: Option<String>,
     pub country_iso2_code: Option<String>,
+    /// URL slug, see [`crate::service::slugs`]
+    pub slug: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -22,6 +27,12 @@ pub struct TeamParticipationWithSeasonEntity {
     pub season_display_name: Option<String>,
     pub event_name: String,
     pub player_count: i64,
+    /// Head coaches contracted for this participation, comma separated
+    pub head_coach: Option<String>,
+    /// Final placement in the season (1 = gold)
+    pub final_rank: Option<i64>,
+    /// Playoff seed (1 = top seed)
+    pub seed: Option<i64>,
 }
 
 #[derive(Debug, Clone)]
@@ -40,9 +51,11 @@ pub struct CreateTeamEntity {
 pub struct UpdateTeamEntity {
     pub name: String,
     pub country_id: Option<i64>,
+    /// Slug from the edit form, `None` generates it from the name
+    pub slug: Option<String>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, serde::Serialize)]
 pub struct TeamFilters {
     pub name: Option<String>,
     pub country_id: Option<i64>,
@@ -86,6 +99,7 @@ impl SortField {
 
 /// Create a new team
 pub async fn create_team(db: &SqlitePool, team: CreateTeamEntity) -> Result<i64, sqlx::Error> {
+    let mut tx = db.begin().await?;
     let result = sqlx::query!(
         r#"
         INSERT INTO team (name, country_id)
@@ -94,10 +108,14 @@ pub async fn create_team(db: &SqlitePool, team: CreateTeamEntity) -> Result<i64,
         team.name,
         team.country_id
     )
-    .execute(db)
+    .execute(&mut *tx)
     .await?;
 
-    Ok(result.last_insert_rowid())
+    let id = result.last_insert_rowid();
+    slugs::assign_slug(&mut tx, SlugEntity::Team, id, &team.name).await?;
+    tx.commit().await?;
+
+    Ok(id)
 }
 
 /// Get teams with filters, sorting, and pagination
@@ -119,7 +137,7 @@ pub async fn get_teams(
 
     // Build data query
     let mut data_query = sqlx::QueryBuilder::new(
-        "SELECT t.id, t.name, t.country_id, c.name as country_name, c.iso2Code as country_iso2_code
+        "SELECT t.id, t.name, t.country_id, c.name as country_name, c.iso2Code as country_iso2_code, t.slug
          FROM team t
          LEFT JOIN country c ON t.country_id = c.id
          WHERE 1=1",
@@ -150,12 +168,58 @@ pub async fn get_teams(
             country_id: row.get("country_id"),
             country_name: row.get("country_name"),
             country_iso2_code: row.get("country_iso2_code"),
+            slug: row.get("slug"),
         })
         .collect();
 
     Ok(PagedResult::new(items, total as usize, page, page_size))
 }
 
+/// Write every team matching the filters to a CSV export, in list order
+///
+/// Rows are read from the database one at a time, so the export never holds
+/// the whole list.
+pub async fn export_teams_csv(
+    db: &SqlitePool,
+    filters: &TeamFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+    sink: &mut CsvSink,
+) -> Result<(), sqlx::Error> {
+    let mut query = sqlx::QueryBuilder::new(
+        "SELECT t.id, t.name, c.name as country_name, c.iso2Code as country
+         FROM team t
+         LEFT JOIN country c ON t.country_id = c.id
+         WHERE 1=1",
+    );
+    apply_filters(&mut query, filters);
+    query
+        .push(" ORDER BY ")
+        .push(sort_field.to_sql())
+        .push(" ")
+        .push(sort_order.to_sql());
+
+    if !sink.write(["id", "name", "country", "country_name"]).await {
+        return Ok(());
+    }
+
+    let mut rows = query.build().fetch(db);
+    while let Some(row) = rows.try_next().await? {
+        let record = [
+            row.get::<i64, _>("id").to_string(),
+            row.get("name"),
+            row.get::<Option<String>, _>("country").unwrap_or_default(),
+            row.get::<Option<String>, _>("country_name")
+                .unwrap_or_default(),
+        ];
+        if !sink.write(record).await {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
 /// Get a single team by ID
 pub async fn get_team_by_id(db: &SqlitePool, id: i64) -> Result<Option<TeamEntity>, sqlx::Error> {
     let row = sqlx::query_as!(
@@ -166,7 +230,8 @@ pub async fn get_team_by_id(db: &SqlitePool, id: i64) -> Result<Option<TeamEntit
             t.name as name,
             t.country_id,
             c.name as country_name,
-            c.iso2Code as country_iso2_code
+            c.iso2Code as country_iso2_code,
+            t.slug
         FROM team t
         LEFT JOIN country c ON t.country_id = c.id
         WHERE t.id = ?
@@ -185,7 +250,15 @@ pub async fn update_team(
     id: i64,
     team: UpdateTeamEntity,
 ) -> Result<bool, sqlx::Error> {
-    let result = sqlx::query!(
+    let mut tx = db.begin().await?;
+    let Some(previous_name) = sqlx::query_scalar!("SELECT name FROM team WHERE id = ?", id)
+        .fetch_optional(&mut *tx)
+        .await?
+    else {
+        return Ok(false);
+    };
+
+    sqlx::query!(
         r#"
         UPDATE team
         SET name = ?, country_id = ?
@@ -195,10 +268,20 @@ pub async fn update_team(
         team.country_id,
         id
     )
-    .execute(db)
+    .execute(&mut *tx)
     .await?;
 
-    Ok(result.rows_affected() > 0)
+    slugs::update_slug(
+        &mut tx,
+        SlugEntity::Team,
+        id,
+        &previous_name,
+        team.slug.as_deref(),
+    )
+    .await?;
+    tx.commit().await?;
+
+    Ok(true)
 }
 
 /// Delete a team
@@ -259,7 +342,15 @@ pub async fn get_team_detail(
             COALESCE(
                 (SELECT COUNT(*) FROM player_contract pc WHERE pc.team_participation_id = tp.id),
                 0
-            ) as "player_count!: i64"
+            ) as "player_count!: i64",
+            (
+                SELECT GROUP_CONCAT(sm.name, ', ')
+                FROM staff_contract sc
+                INNER JOIN staff_member sm ON sc.staff_member_id = sm.id
+                WHERE sc.team_participation_id = tp.id AND sc.role = 'head_coach'
+            ) as "head_coach?: String",
+            tp.final_rank,
+            tp.seed
         FROM team_participation tp
         INNER JOIN season s ON tp.season_id = s.id
         INNER JOIN event e ON s.event_id = e.id
@@ -300,6 +391,7 @@ mod tests {
         let team = result.unwrap();
         assert_eq!(team.name, "Test Team");
         assert_eq!(team.country_id, Some(1));
+        assert_eq!(team.slug.as_deref(), Some("test-team"));
     }
 
     #[sqlx::test(migrations = "./migrations", fixtures("teams"))]
@@ -392,6 +484,7 @@ mod tests {
         let update = UpdateTeamEntity {
             name: "Updated Team Canada".to_string(),
             country_id: Some(1),
+            slug: None,
         };
 
         let success = update_team(&pool, 1, update).await.unwrap();
@@ -400,6 +493,7 @@ mod tests {
         // Verify update
         let team = get_team_by_id(&pool, 1).await.unwrap().unwrap();
         assert_eq!(team.name, "Updated Team Canada");
+        assert_eq!(team.slug.as_deref(), Some("updated-team-canada"));
     }
 
     #[sqlx::test(migrations = "./migrations")]
@@ -407,6 +501,7 @@ mod tests {
         let update = UpdateTeamEntity {
             name: "Non-existent Team".to_string(),
             country_id: Some(1),
+            slug: None,
         };
 
         let success = update_team(&pool, 999, update).await.unwrap();
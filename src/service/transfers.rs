@@ -0,0 +1,282 @@
+use sqlx::SqlitePool;
+
+/// A player's move between two team participations of the same season
+#[derive(Debug, Clone)]
+pub struct TransferEntity {
+    #[allow(dead_code)]
+    pub id: i64,
+    #[allow(dead_code)]
+    pub player_id: i64,
+    pub transfer_date: String,
+    pub note: Option<String>,
+    #[allow(dead_code)]
+    pub from_participation_id: i64,
+    pub from_team_id: i64,
+    pub from_team_name: String,
+    #[allow(dead_code)]
+    pub to_participation_id: i64,
+    pub to_team_id: i64,
+    pub to_team_name: String,
+    pub season_id: i64,
+    pub season_year: i64,
+    pub season_display_name: Option<String>,
+    pub event_name: String,
+}
+
+/// The contract a transfer starts from
+#[derive(Debug, Clone)]
+pub struct TransferSourceEntity {
+    pub player_contract_id: i64,
+    pub player_id: i64,
+    pub player_name: String,
+    pub team_participation_id: i64,
+    pub joined_on: Option<String>,
+    pub left_on: Option<String>,
+}
+
+/// Get a player's transfers, most recent first
+pub async fn get_player_transfers(
+    db: &SqlitePool,
+    player_id: i64,
+) -> Result<Vec<TransferEntity>, sqlx::Error> {
+    let rows = sqlx::query_as!(
+        TransferEntity,
+        r#"
+        SELECT
+            pt.id as "id!",
+            pt.player_id,
+            pt.transfer_date,
+            pt.note,
+            pt.from_participation_id,
+            ft.id as "from_team_id!",
+            ft.name as from_team_name,
+            pt.to_participation_id,
+            tt.id as "to_team_id!",
+            tt.name as to_team_name,
+            s.id as "season_id!",
+            s.year as season_year,
+            s.display_name as season_display_name,
+            e.name as event_name
+        FROM player_transfer pt
+        INNER JOIN team_participation ftp ON pt.from_participation_id = ftp.id
+        INNER JOIN team ft ON ftp.team_id = ft.id
+        INNER JOIN team_participation ttp ON pt.to_participation_id = ttp.id
+        INNER JOIN team tt ON ttp.team_id = tt.id
+        INNER JOIN season s ON ttp.season_id = s.id
+        INNER JOIN event e ON s.event_id = e.id
+        WHERE pt.player_id = ?
+        ORDER BY pt.transfer_date DESC, pt.id DESC
+        "#,
+        player_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows)
+}
+
+/// Get the roster entry a transfer would start from
+pub async fn get_transfer_source(
+    db: &SqlitePool,
+    player_contract_id: i64,
+) -> Result<Option<TransferSourceEntity>, sqlx::Error> {
+    let row = sqlx::query_as!(
+        TransferSourceEntity,
+        r#"
+        SELECT
+            pc.id as "player_contract_id!",
+            pc.player_id,
+            p.name as player_name,
+            pc.team_participation_id,
+            pc.joined_on,
+            pc.left_on
+        FROM player_contract pc
+        INNER JOIN player p ON pc.player_id = p.id
+        WHERE pc.id = ?
+        "#,
+        player_contract_id
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(row)
+}
+
+/// Get the other participations of a season a player can transfer to (for dropdowns)
+pub async fn get_transfer_destinations(
+    db: &SqlitePool,
+    team_participation_id: i64,
+) -> Result<Vec<(i64, String)>, sqlx::Error> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT tp.id as "id!", t.name
+        FROM team_participation tp
+        INNER JOIN team t ON tp.team_id = t.id
+        WHERE tp.season_id = (SELECT season_id FROM team_participation WHERE id = ?1)
+          AND tp.id != ?1
+        ORDER BY t.name ASC
+        "#,
+        team_participation_id
+    )
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows.into_iter().map(|row| (row.id, row.name)).collect())
+}
+
+/// Record a transfer
+///
+/// Ends the source contract on the transfer date, starts the player's
+/// contract with the destination participation on the same date (reusing an
+/// existing roster entry if there is one) and drops the player from the old
+/// team's lines and captaincy.
+pub async fn record_transfer(
+    db: &SqlitePool,
+    source: &TransferSourceEntity,
+    to_participation_id: i64,
+    transfer_date: &str,
+    note: Option<&str>,
+) -> Result<i64, sqlx::Error> {
+    let mut tx = db.begin().await?;
+
+    sqlx::query!(
+        "UPDATE player_contract SET left_on = ?, captaincy = NULL, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+        transfer_date,
+        source.player_contract_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        "DELETE FROM line_assignment WHERE player_contract_id = ?",
+        source.player_contract_id
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let existing = sqlx::query_scalar!(
+        "SELECT id FROM player_contract WHERE team_participation_id = ? AND player_id = ?",
+        to_participation_id,
+        source.player_id
+    )
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    match existing {
+        Some(contract_id) => {
+            sqlx::query!(
+                "UPDATE player_contract SET joined_on = ?, left_on = NULL, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+                transfer_date,
+                contract_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+        None => {
+            sqlx::query!(
+                "INSERT INTO player_contract (team_participation_id, player_id, joined_on) VALUES (?, ?, ?)",
+                to_participation_id,
+                source.player_id,
+                transfer_date
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+    }
+
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO player_transfer (player_id, from_participation_id, to_participation_id, transfer_date, note)
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+        source.player_id,
+        source.team_participation_id,
+        to_participation_id,
+        transfer_date,
+        note
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::matches::{get_lineup_candidates, save_team_lineup};
+    use crate::service::player_contracts::{add_player_to_roster, get_roster};
+
+    async fn create_match(pool: &SqlitePool, date: &str) -> i64 {
+        sqlx::query(
+            "INSERT INTO match (season_id, home_team_id, away_team_id, match_date, status) VALUES (1, 1, 2, ?, 'finished')",
+        )
+        .bind(date)
+        .execute(pool)
+        .await
+        .unwrap()
+        .last_insert_rowid()
+    }
+
+    #[sqlx::test(
+        migrations = "./migrations",
+        fixtures("events", "seasons", "teams", "team_participations", "players")
+    )]
+    async fn test_transfer_moves_player_between_rosters(pool: SqlitePool) {
+        // Canada (participation 1) to USA (participation 2) in season 1
+        let contract_id = add_player_to_roster(&pool, 1, 1, Some(87)).await.unwrap();
+        let before = create_match(&pool, "2022-02-10T12:00").await;
+        let after = create_match(&pool, "2022-02-20T12:00").await;
+
+        assert_eq!(get_transfer_destinations(&pool, 1).await.unwrap().len(), 1);
+
+        let source = get_transfer_source(&pool, contract_id)
+            .await
+            .unwrap()
+            .unwrap();
+        record_transfer(&pool, &source, 2, "2022-02-15", Some("Loan"))
+            .await
+            .unwrap();
+
+        let old_roster = get_roster(&pool, 1).await.unwrap();
+        assert_eq!(old_roster[0].left_on.as_deref(), Some("2022-02-15"));
+        let new_roster = get_roster(&pool, 2).await.unwrap();
+        assert_eq!(new_roster.len(), 1);
+        assert_eq!(new_roster[0].joined_on.as_deref(), Some("2022-02-15"));
+
+        // Before the transfer the player dresses for Canada, after it for USA
+        assert_eq!(
+            get_lineup_candidates(&pool, before, 1).await.unwrap().len(),
+            1
+        );
+        assert!(get_lineup_candidates(&pool, before, 2)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(get_lineup_candidates(&pool, after, 1)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            get_lineup_candidates(&pool, after, 2).await.unwrap().len(),
+            1
+        );
+
+        // Contracts outside their dates are not saved into a lineup
+        save_team_lineup(&pool, after, 1, &[contract_id])
+            .await
+            .unwrap();
+        assert!(get_lineup_candidates(&pool, after, 1)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let transfers = get_player_transfers(&pool, 1).await.unwrap();
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].from_participation_id, 1);
+        assert_eq!(transfers[0].to_participation_id, 2);
+        assert_eq!(transfers[0].note.as_deref(), Some("Loan"));
+    }
+}
@@ -0,0 +1,187 @@
+use sqlx::SqlitePool;
+
+/// External account linked to a user
+#[derive(Debug, Clone)]
+pub struct UserIdentityEntity {
+    pub id: i64,
+    /// `google` or `github`
+    pub provider: String,
+    pub email: Option<String>,
+    pub created_at: String,
+    pub last_used_at: Option<String>,
+}
+
+/// Accounts linked to a user, by provider
+pub async fn get_user_identities(
+    db: &SqlitePool,
+    user_id: i64,
+) -> Result<Vec<UserIdentityEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        UserIdentityEntity,
+        r#"
+        SELECT id as "id!", provider, email, created_at, last_used_at
+        FROM user_identity
+        WHERE user_id = ?
+        ORDER BY provider
+        "#,
+        user_id
+    )
+    .fetch_all(db)
+    .await
+}
+
+/// User the external account is linked to
+pub async fn get_linked_user_id(
+    db: &SqlitePool,
+    provider: &str,
+    subject: &str,
+) -> Result<Option<i64>, sqlx::Error> {
+    sqlx::query_scalar!(
+        "SELECT user_id FROM user_identity WHERE provider = ? AND subject = ?",
+        provider,
+        subject
+    )
+    .fetch_optional(db)
+    .await
+}
+
+/// ID of the active user signing in with this email
+pub async fn get_active_user_id_by_email(
+    db: &SqlitePool,
+    email: &str,
+) -> Result<Option<i64>, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"SELECT id as "id!" FROM users WHERE email = ? COLLATE NOCASE AND active = 1"#,
+        email
+    )
+    .fetch_optional(db)
+    .await
+}
+
+/// Whether the user already has an account of this provider linked
+pub async fn has_provider(
+    db: &SqlitePool,
+    user_id: i64,
+    provider: &str,
+) -> Result<bool, sqlx::Error> {
+    let count = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM user_identity WHERE user_id = ? AND provider = ?",
+        user_id,
+        provider
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(count > 0)
+}
+
+/// Link an external account to a user
+pub async fn link_identity(
+    db: &SqlitePool,
+    user_id: i64,
+    provider: &str,
+    subject: &str,
+    email: Option<&str>,
+) -> Result<i64, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO user_identity (user_id, provider, subject, email)
+        VALUES (?, ?, ?, ?)
+        "#,
+        user_id,
+        provider,
+        subject,
+        email
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Note a sign-in with the external account
+pub async fn record_identity_use(
+    db: &SqlitePool,
+    provider: &str,
+    subject: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE user_identity SET last_used_at = CURRENT_TIMESTAMP
+        WHERE provider = ? AND subject = ?
+        "#,
+        provider,
+        subject
+    )
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+/// Remove the user's linked account of a provider
+pub async fn unlink_identity(
+    db: &SqlitePool,
+    user_id: i64,
+    provider: &str,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        "DELETE FROM user_identity WHERE user_id = ? AND provider = ?",
+        user_id,
+        provider
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::users::{self, UserRole};
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_link_identity(pool: SqlitePool) {
+        let id = users::create_user(&pool, "coach@x.cz", "Coach", UserRole::Editor, "hash")
+            .await
+            .unwrap();
+        assert_eq!(
+            get_active_user_id_by_email(&pool, "Coach@x.cz")
+                .await
+                .unwrap(),
+            Some(id)
+        );
+        assert!(!has_provider(&pool, id, "github").await.unwrap());
+
+        link_identity(&pool, id, "github", "42", Some("coach@x.cz"))
+            .await
+            .unwrap();
+        assert_eq!(
+            get_linked_user_id(&pool, "github", "42").await.unwrap(),
+            Some(id)
+        );
+        assert_eq!(
+            get_linked_user_id(&pool, "google", "42").await.unwrap(),
+            None
+        );
+        assert!(has_provider(&pool, id, "github").await.unwrap());
+
+        // One account per provider and user
+        assert!(link_identity(&pool, id, "github", "43", None)
+            .await
+            .is_err());
+
+        record_identity_use(&pool, "github", "42").await.unwrap();
+        let identities = get_user_identities(&pool, id).await.unwrap();
+        assert_eq!(identities.len(), 1);
+        assert!(identities[0].last_used_at.is_some());
+
+        assert!(unlink_identity(&pool, id, "github").await.unwrap());
+        assert!(!unlink_identity(&pool, id, "github").await.unwrap());
+        assert_eq!(
+            get_linked_user_id(&pool, "github", "42").await.unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,377 @@
+use sqlx::SqlitePool;
+
+/// What a user may do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UserRole {
+    /// Everything, including managing users
+    Admin,
+    /// All hockey data, but not users
+    Editor,
+}
+
+impl UserRole {
+    pub const ALL: [UserRole; 2] = [UserRole::Admin, UserRole::Editor];
+
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "admin" => Some(Self::Admin),
+            "editor" => Some(Self::Editor),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Admin => "admin",
+            Self::Editor => "editor",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UserEntity {
+    pub id: i64,
+    pub email: String,
+    pub name: Option<String>,
+    pub role: String,
+    pub active: bool,
+    /// The user has to choose a new password at the next sign-in
+    pub must_change_password: bool,
+    /// Registered user who has not confirmed their email yet
+    pub email_unverified: bool,
+    /// Registered user waiting for an admin to approve the account
+    pub approval_pending: bool,
+    /// Too many wrong passwords; password sign-in is refused for now
+    pub locked: bool,
+    pub created_at: String,
+    /// Latest successful sign-in from the audit log
+    pub last_login_at: Option<String>,
+}
+
+impl UserEntity {
+    pub fn role(&self) -> Option<UserRole> {
+        UserRole::from_str(&self.role)
+    }
+
+    pub fn is_admin(&self) -> bool {
+        self.role() == Some(UserRole::Admin)
+    }
+}
+
+/// All users, active ones first, then by name
+pub async fn get_users(db: &SqlitePool) -> Result<Vec<UserEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        UserEntity,
+        r#"
+        SELECT
+            u.id as "id!",
+            u.email,
+            u.name,
+            u.role,
+            u.active as "active: bool",
+            u.must_change_password as "must_change_password: bool",
+            u.email_verified_at IS NULL as "email_unverified: bool",
+            u.approval_pending as "approval_pending: bool",
+            COALESCE(u.locked_until > datetime('now'), 0) as "locked!: bool",
+            u.created_at,
+            (SELECT MAX(la.created_at) FROM login_audit la
+             WHERE la.user_id = u.id AND la.success = 1) as "last_login_at: String"
+        FROM users u
+        ORDER BY u.active DESC, COALESCE(u.name, u.email) COLLATE NOCASE
+        "#
+    )
+    .fetch_all(db)
+    .await
+}
+
+pub async fn get_user_by_id(db: &SqlitePool, id: i64) -> Result<Option<UserEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        UserEntity,
+        r#"
+        SELECT
+            u.id as "id!",
+            u.email,
+            u.name,
+            u.role,
+            u.active as "active: bool",
+            u.must_change_password as "must_change_password: bool",
+            u.email_verified_at IS NULL as "email_unverified: bool",
+            u.approval_pending as "approval_pending: bool",
+            COALESCE(u.locked_until > datetime('now'), 0) as "locked!: bool",
+            u.created_at,
+            (SELECT MAX(la.created_at) FROM login_audit la
+             WHERE la.user_id = u.id AND la.success = 1) as "last_login_at: String"
+        FROM users u
+        WHERE u.id = ?
+        "#,
+        id
+    )
+    .fetch_optional(db)
+    .await
+}
+
+pub async fn email_exists(db: &SqlitePool, email: &str) -> Result<bool, sqlx::Error> {
+    let count = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!: i64" FROM users WHERE email = ? COLLATE NOCASE"#,
+        email
+    )
+    .fetch_one(db)
+    .await?;
+
+    Ok(count > 0)
+}
+
+/// Active admins; the last one cannot be demoted or deactivated
+pub async fn count_active_admins(db: &SqlitePool) -> Result<i64, sqlx::Error> {
+    sqlx::query_scalar!(
+        r#"SELECT COUNT(*) as "count!: i64" FROM users WHERE role = 'admin' AND active = 1"#
+    )
+    .fetch_one(db)
+    .await
+}
+
+/// Create a user who has to replace the given password at the first sign-in
+pub async fn create_user(
+    db: &SqlitePool,
+    email: &str,
+    name: &str,
+    role: UserRole,
+    password_hash: &str,
+) -> Result<i64, sqlx::Error> {
+    let role = role.as_str();
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO users (email, name, password_hash, role, must_change_password, email_verified_at)
+        VALUES (?, ?, ?, ?, 1, CURRENT_TIMESTAMP)
+        "#,
+        email,
+        name,
+        password_hash,
+        role
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+pub async fn set_user_role(db: &SqlitePool, id: i64, role: UserRole) -> Result<bool, sqlx::Error> {
+    let role = role.as_str();
+    let result = sqlx::query!(
+        "UPDATE users SET role = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ?",
+        role,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Deactivate or reactivate a user; deactivating also ends their sessions
+///
+/// Activating a registered user approves the account and confirms the email.
+pub async fn set_user_active(db: &SqlitePool, id: i64, active: bool) -> Result<bool, sqlx::Error> {
+    let mut tx = db.begin().await?;
+    let result = sqlx::query!(
+        r#"
+        UPDATE users
+        SET active = ?1,
+            approval_pending = CASE WHEN ?1 THEN 0 ELSE approval_pending END,
+            email_verified_at = CASE WHEN ?1 THEN COALESCE(email_verified_at, CURRENT_TIMESTAMP)
+                                     ELSE email_verified_at END,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?2
+        "#,
+        active,
+        id
+    )
+    .execute(&mut *tx)
+    .await?;
+    if !active {
+        sqlx::query!("DELETE FROM sessions WHERE user_id = ?", id)
+            .execute(&mut *tx)
+            .await?;
+    }
+    tx.commit().await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Replace a user's password with a temporary one they must change
+///
+/// Ends the user's sessions and lifts a lockout.
+pub async fn reset_user_password(
+    db: &SqlitePool,
+    id: i64,
+    password_hash: &str,
+) -> Result<bool, sqlx::Error> {
+    let mut tx = db.begin().await?;
+    let result = sqlx::query!(
+        r#"
+        UPDATE users
+        SET password_hash = ?, must_change_password = 1, failed_logins = 0, locked_until = NULL,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        password_hash,
+        id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!("DELETE FROM sessions WHERE user_id = ?", id)
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Set the password the user chose, clearing a pending forced change
+///
+/// Signs the user out of every session except `keep_session_id`, the one
+/// the change was made from.
+pub async fn change_password(
+    db: &SqlitePool,
+    id: i64,
+    password_hash: &str,
+    keep_session_id: &str,
+) -> Result<bool, sqlx::Error> {
+    let mut tx = db.begin().await?;
+    let result = sqlx::query!(
+        r#"
+        UPDATE users
+        SET password_hash = ?, must_change_password = 0, updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        password_hash,
+        id
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        "DELETE FROM sessions WHERE user_id = ? AND id != ?",
+        id,
+        keep_session_id
+    )
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Replace a hash of the same password, e.g. one made with older parameters
+///
+/// Does nothing if the password changed since `old_hash` was read.
+pub async fn upgrade_password_hash(
+    db: &SqlitePool,
+    id: i64,
+    old_hash: &str,
+    new_hash: &str,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        "UPDATE users SET password_hash = ? WHERE id = ? AND password_hash = ?",
+        new_hash,
+        id,
+        old_hash
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn get_password_hash(db: &SqlitePool, id: i64) -> Result<Option<String>, sqlx::Error> {
+    sqlx::query_scalar!("SELECT password_hash FROM users WHERE id = ?", id)
+        .fetch_optional(db)
+        .await
+}
+
+/// Whether the user still has to replace a temporary password
+pub async fn must_change_password(db: &SqlitePool, id: i64) -> Result<bool, sqlx::Error> {
+    let required = sqlx::query_scalar!(
+        r#"SELECT must_change_password as "must_change_password: bool" FROM users WHERE id = ?"#,
+        id
+    )
+    .fetch_optional(db)
+    .await?;
+
+    Ok(required.unwrap_or(false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_user_management(pool: SqlitePool) {
+        let id = create_user(&pool, "editor@x.cz", "Eddie", UserRole::Editor, "hash")
+            .await
+            .unwrap();
+        assert!(email_exists(&pool, "EDITOR@x.cz").await.unwrap());
+        let user = get_user_by_id(&pool, id).await.unwrap().unwrap();
+        assert_eq!(user.role(), Some(UserRole::Editor));
+        assert!(user.active);
+        assert!(user.must_change_password);
+        assert!(user.last_login_at.is_none());
+
+        for session_id in ["current", "other"] {
+            sqlx::query(
+                "INSERT INTO sessions (id, user_id, user_email, user_name, csrf_token, created_at, expires_at) VALUES (?, ?, 'editor@x.cz', 'Eddie', 'csrf', datetime('now'), datetime('now', '+1 days'))",
+            )
+            .bind(session_id)
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        assert!(change_password(&pool, id, "new-hash", "current")
+            .await
+            .unwrap());
+        assert!(!must_change_password(&pool, id).await.unwrap());
+        let sessions: Vec<String> = sqlx::query_scalar("SELECT id FROM sessions")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(sessions, vec!["current".to_string()]);
+        assert_eq!(
+            get_password_hash(&pool, id).await.unwrap().as_deref(),
+            Some("new-hash")
+        );
+        assert!(!upgrade_password_hash(&pool, id, "old-hash", "rehashed")
+            .await
+            .unwrap());
+        assert!(upgrade_password_hash(&pool, id, "new-hash", "rehashed")
+            .await
+            .unwrap());
+        assert_eq!(
+            get_password_hash(&pool, id).await.unwrap().as_deref(),
+            Some("rehashed")
+        );
+
+        assert_eq!(count_active_admins(&pool).await.unwrap(), 0);
+        set_user_role(&pool, id, UserRole::Admin).await.unwrap();
+        assert_eq!(count_active_admins(&pool).await.unwrap(), 1);
+
+        sqlx::query(
+            "INSERT INTO sessions (id, user_id, user_email, user_name, csrf_token, created_at, expires_at) \
+             VALUES ('s1', ?, 'editor@x.cz', 'Eddie', 'c', '2026-01-01', '2099-01-01')",
+        )
+        .bind(id)
+        .execute(&pool)
+        .await
+        .unwrap();
+        assert!(set_user_active(&pool, id, false).await.unwrap());
+        let sessions: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sessions")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(sessions, 0);
+        assert_eq!(count_active_admins(&pool).await.unwrap(), 0);
+
+        assert!(reset_user_password(&pool, id, "temp-hash").await.unwrap());
+        assert!(must_change_password(&pool, id).await.unwrap());
+        assert_eq!(get_users(&pool).await.unwrap().len(), 1);
+    }
+}
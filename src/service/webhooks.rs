@@ -0,0 +1,436 @@
+use sqlx::SqlitePool;
+
+use crate::service::change_history::HistoryEntityType;
+
+#[derive(Debug, Clone)]
+pub struct WebhookSubscriptionEntity {
+    pub id: i64,
+    pub url: String,
+    /// Key of the `X-Hockey-Signature` HMAC, shared with the receiver
+    pub secret: String,
+    pub on_matches: bool,
+    pub on_score_events: bool,
+    pub on_players: bool,
+    pub active: bool,
+    pub created_at: String,
+}
+
+impl WebhookSubscriptionEntity {
+    /// Whether changes to this kind of record are sent to the subscription
+    pub fn follows(&self, entity_type: HistoryEntityType) -> bool {
+        match entity_type {
+            HistoryEntityType::Match => self.on_matches,
+            HistoryEntityType::ScoreEvent => self.on_score_events,
+            HistoryEntityType::Player => self.on_players,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CreateWebhookSubscriptionEntity {
+    pub url: String,
+    pub on_matches: bool,
+    pub on_score_events: bool,
+    pub on_players: bool,
+}
+
+/// Delivery state of a queued event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeliveryStatus {
+    /// Waiting for its first attempt or for a retry
+    Pending,
+    Delivered,
+    /// Gave up after the last retry
+    Failed,
+}
+
+impl DeliveryStatus {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(Self::Pending),
+            "delivered" => Some(Self::Delivered),
+            "failed" => Some(Self::Failed),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Delivered => "delivered",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WebhookDeliveryEntity {
+    pub id: i64,
+    pub subscription_id: i64,
+    pub event: String,
+    pub payload: String,
+    pub status: String,
+    pub attempts: i64,
+    pub next_attempt_at: String,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<String>,
+    pub created_at: String,
+}
+
+impl WebhookDeliveryEntity {
+    pub fn status(&self) -> Option<DeliveryStatus> {
+        DeliveryStatus::from_str(&self.status)
+    }
+}
+
+/// A delivery due for an attempt, with where and how to send it
+#[derive(Debug, Clone)]
+pub struct DueDeliveryEntity {
+    pub id: i64,
+    pub event: String,
+    pub payload: String,
+    pub attempts: i64,
+    pub url: String,
+    pub secret: String,
+}
+
+/// All subscriptions in creation order
+pub async fn get_webhook_subscriptions(
+    db: &SqlitePool,
+) -> Result<Vec<WebhookSubscriptionEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        WebhookSubscriptionEntity,
+        r#"
+        SELECT
+            id as "id!",
+            url,
+            secret,
+            on_matches as "on_matches: bool",
+            on_score_events as "on_score_events: bool",
+            on_players as "on_players: bool",
+            active as "active: bool",
+            created_at
+        FROM webhook_subscription
+        ORDER BY id
+        "#
+    )
+    .fetch_all(db)
+    .await
+}
+
+pub async fn get_webhook_subscription_by_id(
+    db: &SqlitePool,
+    id: i64,
+) -> Result<Option<WebhookSubscriptionEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        WebhookSubscriptionEntity,
+        r#"
+        SELECT
+            id as "id!",
+            url,
+            secret,
+            on_matches as "on_matches: bool",
+            on_score_events as "on_score_events: bool",
+            on_players as "on_players: bool",
+            active as "active: bool",
+            created_at
+        FROM webhook_subscription
+        WHERE id = ?
+        "#,
+        id
+    )
+    .fetch_optional(db)
+    .await
+}
+
+/// Create a subscription with a new random secret
+pub async fn create_webhook_subscription(
+    db: &SqlitePool,
+    subscription: &CreateWebhookSubscriptionEntity,
+) -> Result<i64, sqlx::Error> {
+    let secret = format!(
+        "{}{}",
+        uuid::Uuid::new_v4().simple(),
+        uuid::Uuid::new_v4().simple()
+    );
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO webhook_subscription (url, secret, on_matches, on_score_events, on_players)
+        VALUES (?, ?, ?, ?, ?)
+        "#,
+        subscription.url,
+        secret,
+        subscription.on_matches,
+        subscription.on_score_events,
+        subscription.on_players
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// Choose the followed records and pause or resume a subscription
+pub async fn update_webhook_subscription(
+    db: &SqlitePool,
+    id: i64,
+    on_matches: bool,
+    on_score_events: bool,
+    on_players: bool,
+    active: bool,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE webhook_subscription
+        SET on_matches = ?, on_score_events = ?, on_players = ?, active = ?,
+            updated_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        on_matches,
+        on_score_events,
+        on_players,
+        active,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Delete a subscription together with its deliveries
+pub async fn delete_webhook_subscription(db: &SqlitePool, id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!("DELETE FROM webhook_subscription WHERE id = ?", id)
+        .execute(db)
+        .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Queue an event for every active subscription following the record kind
+///
+/// Returns the number of deliveries queued.
+pub async fn enqueue_webhook_event(
+    db: &SqlitePool,
+    entity_type: HistoryEntityType,
+    event: &str,
+    payload: &str,
+) -> Result<usize, sqlx::Error> {
+    let subscriptions: Vec<_> = get_webhook_subscriptions(db)
+        .await?
+        .into_iter()
+        .filter(|subscription| subscription.active && subscription.follows(entity_type))
+        .collect();
+
+    let mut tx = db.begin().await?;
+    for subscription in &subscriptions {
+        sqlx::query!(
+            "INSERT INTO webhook_delivery (subscription_id, event, payload) VALUES (?, ?, ?)",
+            subscription.id,
+            event,
+            payload
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+    tx.commit().await?;
+
+    Ok(subscriptions.len())
+}
+
+/// Pending deliveries of active subscriptions whose next attempt is due,
+/// oldest first
+pub async fn get_due_deliveries(
+    db: &SqlitePool,
+    limit: i64,
+) -> Result<Vec<DueDeliveryEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        DueDeliveryEntity,
+        r#"
+        SELECT
+            d.id as "id!",
+            d.event,
+            d.payload,
+            d.attempts,
+            s.url,
+            s.secret
+        FROM webhook_delivery d
+        INNER JOIN webhook_subscription s ON d.subscription_id = s.id
+        WHERE d.status = 'pending' AND d.next_attempt_at <= CURRENT_TIMESTAMP AND s.active = 1
+        ORDER BY d.id
+        LIMIT ?
+        "#,
+        limit
+    )
+    .fetch_all(db)
+    .await
+}
+
+pub async fn mark_delivery_delivered(db: &SqlitePool, id: i64) -> Result<(), sqlx::Error> {
+    sqlx::query!(
+        r#"
+        UPDATE webhook_delivery
+        SET status = 'delivered', attempts = attempts + 1, last_error = NULL,
+            delivered_at = CURRENT_TIMESTAMP
+        WHERE id = ?
+        "#,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+/// Record a failed attempt; retry after `retry_in_seconds`, or give up when `None`
+pub async fn mark_delivery_attempt_failed(
+    db: &SqlitePool,
+    id: i64,
+    error: &str,
+    retry_in_seconds: Option<i64>,
+) -> Result<(), sqlx::Error> {
+    let status = match retry_in_seconds {
+        Some(_) => DeliveryStatus::Pending,
+        None => DeliveryStatus::Failed,
+    }
+    .as_str();
+    let offset = format!("+{} seconds", retry_in_seconds.unwrap_or_default());
+    sqlx::query!(
+        r#"
+        UPDATE webhook_delivery
+        SET status = ?, attempts = attempts + 1, last_error = ?,
+            next_attempt_at = datetime('now', ?)
+        WHERE id = ?
+        "#,
+        status,
+        error,
+        offset,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(())
+}
+
+/// Queue a failed delivery again for an immediate attempt
+pub async fn retry_delivery(db: &SqlitePool, id: i64) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE webhook_delivery
+        SET status = 'pending', attempts = 0, next_attempt_at = CURRENT_TIMESTAMP
+        WHERE id = ? AND status = 'failed'
+        "#,
+        id
+    )
+    .execute(db)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Latest deliveries across all subscriptions, newest first
+pub async fn get_recent_deliveries(
+    db: &SqlitePool,
+    limit: i64,
+) -> Result<Vec<WebhookDeliveryEntity>, sqlx::Error> {
+    sqlx::query_as!(
+        WebhookDeliveryEntity,
+        r#"
+        SELECT
+            id as "id!",
+            subscription_id,
+            event,
+            payload,
+            status,
+            attempts,
+            next_attempt_at,
+            last_error,
+            delivered_at,
+            created_at
+        FROM webhook_delivery
+        ORDER BY id DESC
+        LIMIT ?
+        "#,
+        limit
+    )
+    .fetch_all(db)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_webhook_deliveries(pool: SqlitePool) {
+        let id = create_webhook_subscription(
+            &pool,
+            &CreateWebhookSubscriptionEntity {
+                url: "https://example.com/hooks/hockey".to_string(),
+                on_matches: true,
+                on_score_events: false,
+                on_players: true,
+            },
+        )
+        .await
+        .unwrap();
+        let subscription = get_webhook_subscription_by_id(&pool, id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(subscription.secret.len(), 64);
+        assert!(subscription.active);
+
+        let queued = enqueue_webhook_event(
+            &pool,
+            HistoryEntityType::ScoreEvent,
+            "score_event.created",
+            "{}",
+        )
+        .await
+        .unwrap();
+        assert_eq!(queued, 0);
+        let queued = enqueue_webhook_event(&pool, HistoryEntityType::Match, "match.created", "{}")
+            .await
+            .unwrap();
+        assert_eq!(queued, 1);
+
+        let due = get_due_deliveries(&pool, 10).await.unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].event, "match.created");
+        assert_eq!(due[0].secret, subscription.secret);
+
+        // A retry in the future is not due yet
+        mark_delivery_attempt_failed(&pool, due[0].id, "HTTP 500", Some(60))
+            .await
+            .unwrap();
+        assert!(get_due_deliveries(&pool, 10).await.unwrap().is_empty());
+
+        mark_delivery_attempt_failed(&pool, due[0].id, "HTTP 500", None)
+            .await
+            .unwrap();
+        let delivery = &get_recent_deliveries(&pool, 10).await.unwrap()[0];
+        assert_eq!(delivery.status(), Some(DeliveryStatus::Failed));
+        assert_eq!(delivery.attempts, 2);
+
+        assert!(retry_delivery(&pool, delivery.id).await.unwrap());
+        mark_delivery_delivered(&pool, delivery.id).await.unwrap();
+        let delivery = &get_recent_deliveries(&pool, 10).await.unwrap()[0];
+        assert_eq!(delivery.status(), Some(DeliveryStatus::Delivered));
+        assert!(delivery.last_error.is_none());
+
+        // Paused subscriptions receive nothing
+        update_webhook_subscription(&pool, id, true, true, true, false)
+            .await
+            .unwrap();
+        let queued =
+            enqueue_webhook_event(&pool, HistoryEntityType::Player, "player.updated", "{}")
+                .await
+                .unwrap();
+        assert_eq!(queued, 0);
+
+        assert!(delete_webhook_subscription(&pool, id).await.unwrap());
+        assert!(get_recent_deliveries(&pool, 10).await.unwrap().is_empty());
+    }
+}
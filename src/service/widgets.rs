@@ -0,0 +1,174 @@
+//! Data of the embeddable widgets, shaped for JSON consumers
+
+use serde::Serialize;
+
+use super::season_groups::SeasonGroupEntity;
+use super::standings::TeamStandingEntity;
+
+/// A team's line in a widget standings table
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WidgetStandingRow {
+    pub rank: usize,
+    pub team_id: i64,
+    pub team_name: String,
+    pub country_iso2: Option<String>,
+    pub games: i64,
+    pub wins: i64,
+    pub overtime_wins: i64,
+    pub ties: i64,
+    pub overtime_losses: i64,
+    pub losses: i64,
+    pub goals_for: i64,
+    pub goals_against: i64,
+    pub points: i64,
+}
+
+/// Standings of one group, or of the whole season when `group` is empty
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WidgetStandingTable {
+    pub group: Option<String>,
+    pub rows: Vec<WidgetStandingRow>,
+}
+
+/// Standings widget of a season
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WidgetStandings {
+    pub season_id: i64,
+    pub season_name: String,
+    pub point_system: &'static str,
+    pub tables: Vec<WidgetStandingTable>,
+}
+
+impl WidgetStandings {
+    /// Split ranked standings into one table per group
+    ///
+    /// Teams outside every group go to a last table without a name. A season
+    /// without groups has a single table.
+    pub fn new(
+        season_id: i64,
+        season_name: String,
+        groups: &[SeasonGroupEntity],
+        standings: &[TeamStandingEntity],
+    ) -> Self {
+        let table = |group: Option<String>, rows: Vec<&TeamStandingEntity>| WidgetStandingTable {
+            group,
+            rows: rows
+                .into_iter()
+                .enumerate()
+                .map(|(index, row)| WidgetStandingRow {
+                    rank: index + 1,
+                    team_id: row.team_id,
+                    team_name: row.team_name.clone(),
+                    country_iso2: row.country_iso2_code.clone(),
+                    games: row.games,
+                    wins: row.wins,
+                    overtime_wins: row.overtime_wins,
+                    ties: row.ties,
+                    overtime_losses: row.overtime_losses,
+                    losses: row.losses,
+                    goals_for: row.goals_for,
+                    goals_against: row.goals_against,
+                    points: row.points(),
+                })
+                .collect(),
+        };
+
+        let mut tables: Vec<WidgetStandingTable> = groups
+            .iter()
+            .map(|g| {
+                table(
+                    Some(g.name.clone()),
+                    standings
+                        .iter()
+                        .filter(|s| s.group_id == Some(g.id))
+                        .collect(),
+                )
+            })
+            .collect();
+        let unassigned: Vec<&TeamStandingEntity> = standings
+            .iter()
+            .filter(|s| !groups.iter().any(|g| s.group_id == Some(g.id)))
+            .collect();
+        if groups.is_empty() || !unassigned.is_empty() {
+            tables.push(table(None, unassigned));
+        }
+
+        Self {
+            season_id,
+            season_name,
+            point_system: standings
+                .first()
+                .map(TeamStandingEntity::point_system)
+                .unwrap_or_default()
+                .as_str(),
+            tables,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn standing(team_id: i64, group_id: Option<i64>, wins: i64) -> TeamStandingEntity {
+        TeamStandingEntity {
+            team_id,
+            team_name: format!("Team {}", team_id),
+            country_iso2_code: None,
+            group_id,
+            point_system: "classic".to_string(),
+            games: 3,
+            wins,
+            overtime_wins: 0,
+            ties: 0,
+            overtime_losses: 0,
+            losses: 3 - wins,
+            goals_for: wins,
+            goals_against: 3 - wins,
+        }
+    }
+
+    #[test]
+    fn test_widget_standings_split_by_group() {
+        let groups = vec![
+            SeasonGroupEntity {
+                id: 1,
+                season_id: 1,
+                name: "Group A".to_string(),
+                team_count: 2,
+            },
+            SeasonGroupEntity {
+                id: 2,
+                season_id: 1,
+                name: "Group B".to_string(),
+                team_count: 1,
+            },
+        ];
+        let standings = vec![
+            standing(1, Some(1), 3),
+            standing(2, Some(2), 2),
+            standing(3, Some(1), 1),
+            standing(4, None, 0),
+        ];
+
+        let widget = WidgetStandings::new(1, "Cup".to_string(), &groups, &standings);
+        assert_eq!(widget.point_system, "classic");
+        let groups: Vec<Option<&str>> = widget.tables.iter().map(|t| t.group.as_deref()).collect();
+        assert_eq!(groups, vec![Some("Group A"), Some("Group B"), None]);
+        let ranks: Vec<Vec<(usize, i64)>> = widget
+            .tables
+            .iter()
+            .map(|t| t.rows.iter().map(|r| (r.rank, r.team_id)).collect())
+            .collect();
+        assert_eq!(
+            ranks,
+            vec![vec![(1, 1), (2, 3)], vec![(1, 2)], vec![(1, 4)]]
+        );
+        assert_eq!(widget.tables[0].rows[0].points, 6);
+
+        // Without groups every team shares one table
+        let widget = WidgetStandings::new(1, "Cup".to_string(), &[], &standings);
+        assert_eq!(widget.tables.len(), 1);
+        assert_eq!(widget.tables[0].rows.len(), 4);
+    }
+}
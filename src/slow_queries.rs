@@ -0,0 +1,314 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Metadata, Subscriber};
+use tracing_subscriber::layer::{Context, Filter, Layer};
+use tracing_subscriber::registry::LookupSpan;
+
+/// Target sqlx logs executed statements under
+const SQLX_QUERY_TARGET: &str = "sqlx::query";
+
+/// Name of the span `tower_http::trace::TraceLayer` opens for each request
+const REQUEST_SPAN_NAME: &str = "request";
+
+/// Slow queries kept in memory; older ones are dropped first
+const CAPACITY: usize = 500;
+
+/// A statement that exceeded the slow query threshold
+#[derive(Debug, Clone)]
+pub struct SlowQuery {
+    pub recorded_at: DateTime<Utc>,
+    pub statement: String,
+    pub elapsed_ms: f64,
+    pub rows_returned: u64,
+    pub rows_affected: u64,
+    /// Request the query ran for, e.g. `GET /matches/list?page=2`; `None`
+    /// for background jobs
+    pub route: Option<String>,
+}
+
+/// Slow queries grouped by statement
+#[derive(Debug, Clone)]
+pub struct SlowQuerySummary {
+    pub statement: String,
+    pub count: usize,
+    pub total_ms: f64,
+    pub max_ms: f64,
+    pub last_seen: DateTime<Utc>,
+    pub last_route: Option<String>,
+}
+
+impl SlowQuerySummary {
+    pub fn avg_ms(&self) -> f64 {
+        self.total_ms / self.count as f64
+    }
+}
+
+/// In-memory log of recent slow queries, shared between the tracing layer
+/// and the admin panel
+#[derive(Debug, Clone, Default)]
+pub struct SlowQueryLog {
+    entries: Arc<Mutex<VecDeque<SlowQuery>>>,
+}
+
+impl SlowQueryLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(&self, query: SlowQuery) {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if entries.len() == CAPACITY {
+            entries.pop_front();
+        }
+        entries.push_back(query);
+    }
+
+    /// Recorded slow queries, most recent first
+    pub fn recent(&self, limit: usize) -> Vec<SlowQuery> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.iter().rev().take(limit).cloned().collect()
+    }
+
+    /// Slow queries grouped by statement, the most total time first
+    pub fn summary(&self) -> Vec<SlowQuerySummary> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let mut groups: HashMap<&str, SlowQuerySummary> = HashMap::new();
+
+        for query in entries.iter() {
+            let group =
+                groups
+                    .entry(query.statement.as_str())
+                    .or_insert_with(|| SlowQuerySummary {
+                        statement: query.statement.clone(),
+                        count: 0,
+                        total_ms: 0.0,
+                        max_ms: 0.0,
+                        last_seen: query.recorded_at,
+                        last_route: None,
+                    });
+            group.count += 1;
+            group.total_ms += query.elapsed_ms;
+            group.max_ms = group.max_ms.max(query.elapsed_ms);
+            // Entries are in insertion order, so the last one wins
+            group.last_seen = query.recorded_at;
+            group.last_route = query.route.clone();
+        }
+
+        let mut summary: Vec<SlowQuerySummary> = groups.into_values().collect();
+        summary.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms));
+        summary
+    }
+
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+/// Tracing layer recording sqlx's slow statement events into a [`SlowQueryLog`]
+///
+/// sqlx emits the event inside the span of the code that ran the query, so
+/// the request span opened by `TraceLayer` gives the route context.
+pub struct SlowQueryLayer {
+    log: SlowQueryLog,
+}
+
+impl SlowQueryLayer {
+    pub fn new(log: SlowQueryLog) -> Self {
+        Self { log }
+    }
+
+    /// Per-layer filter: sqlx statements and request spans only
+    ///
+    /// Keeps the layer independent of `RUST_LOG`, which only applies to the
+    /// console output.
+    pub fn filter<S>() -> impl Filter<S> {
+        tracing_subscriber::filter::filter_fn(|metadata: &Metadata<'_>| {
+            (metadata.is_event() && metadata.target() == SQLX_QUERY_TARGET)
+                || (metadata.is_span() && metadata.name() == REQUEST_SPAN_NAME)
+        })
+    }
+}
+
+/// Method and URI of a request span, stored in its extensions
+struct RequestContext(String);
+
+#[derive(Default)]
+struct RequestVisitor {
+    method: Option<String>,
+    uri: Option<String>,
+}
+
+impl Visit for RequestVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "method" => self.method = Some(format!("{:?}", value)),
+            "uri" => self.uri = Some(format!("{:?}", value)),
+            _ => {}
+        }
+    }
+}
+
+#[derive(Default)]
+struct StatementVisitor {
+    summary: Option<String>,
+    statement: Option<String>,
+    elapsed_secs: Option<f64>,
+    rows_returned: u64,
+    rows_affected: u64,
+    slow: bool,
+}
+
+impl Visit for StatementVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "summary" => self.summary = Some(value.to_string()),
+            "db.statement" => self.statement = Some(value.trim().to_string()),
+            _ => {}
+        }
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        if field.name() == "elapsed_secs" {
+            self.elapsed_secs = Some(value);
+        }
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        match field.name() {
+            "rows_returned" => self.rows_returned = value,
+            "rows_affected" => self.rows_affected = value,
+            _ => {}
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            // Only present on events for statements over the threshold
+            "slow_threshold" => self.slow = true,
+            "summary" => self.summary = Some(format!("{:?}", value)),
+            _ => {}
+        }
+    }
+}
+
+impl<S> Layer<S> for SlowQueryLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        if attrs.metadata().name() != REQUEST_SPAN_NAME {
+            return;
+        }
+
+        let mut visitor = RequestVisitor::default();
+        attrs.record(&mut visitor);
+        if let (Some(method), Some(uri), Some(span)) = (visitor.method, visitor.uri, ctx.span(id)) {
+            span.extensions_mut()
+                .insert(RequestContext(format!("{} {}", method, uri)));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        if event.metadata().target() != SQLX_QUERY_TARGET {
+            return;
+        }
+
+        let mut visitor = StatementVisitor::default();
+        event.record(&mut visitor);
+        if !visitor.slow {
+            return;
+        }
+
+        // Short statements are logged as the summary only
+        let statement = match visitor.statement.filter(|s| !s.is_empty()) {
+            Some(statement) => statement,
+            None => visitor.summary.unwrap_or_default(),
+        };
+
+        let route = ctx.event_scope(event).and_then(|scope| {
+            scope.from_root().find_map(|span| {
+                span.extensions()
+                    .get::<RequestContext>()
+                    .map(|r| r.0.clone())
+            })
+        });
+
+        self.log.push(SlowQuery {
+            recorded_at: Utc::now(),
+            statement,
+            elapsed_ms: visitor.elapsed_secs.unwrap_or_default() * 1000.0,
+            rows_returned: visitor.rows_returned,
+            rows_affected: visitor.rows_affected,
+            route,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    fn slow_event(statement: &str, elapsed_secs: f64) {
+        tracing::warn!(
+            target: "sqlx::query",
+            summary = "SELECT * FROM …",
+            db.statement = statement,
+            rows_affected = 0u64,
+            rows_returned = 3u64,
+            elapsed_secs,
+            slow_threshold = ?std::time::Duration::from_millis(100),
+            "slow statement: execution time exceeded alert threshold"
+        );
+    }
+
+    #[test]
+    fn test_layer_records_slow_statements_with_route() {
+        let log = SlowQueryLog::new();
+        let subscriber = tracing_subscriber::registry()
+            .with(SlowQueryLayer::new(log.clone()).with_filter(SlowQueryLayer::filter()));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::debug_span!(
+                "request",
+                method = ?axum::http::Method::GET,
+                uri = ?"/matches/list?page=2".parse::<axum::http::Uri>().unwrap(),
+            );
+            span.in_scope(|| {
+                slow_event("SELECT * FROM match", 0.25);
+                // Statements under the threshold carry no `slow_threshold`
+                tracing::debug!(target: "sqlx::query", summary = "SELECT 1", elapsed_secs = 0.001);
+            });
+            slow_event("SELECT * FROM match", 0.5);
+            slow_event("SELECT * FROM player", 0.2);
+        });
+
+        let recent = log.recent(10);
+        assert_eq!(recent.len(), 3);
+        assert_eq!(recent[0].statement, "SELECT * FROM player");
+        assert_eq!(recent[0].route, None);
+        assert_eq!(recent[2].route.as_deref(), Some("GET /matches/list?page=2"));
+        assert_eq!(recent[2].rows_returned, 3);
+        assert!((recent[2].elapsed_ms - 250.0).abs() < 1e-9);
+
+        let summary = log.summary();
+        assert_eq!(summary.len(), 2);
+        assert_eq!(summary[0].statement, "SELECT * FROM match");
+        assert_eq!(summary[0].count, 2);
+        assert!((summary[0].max_ms - 500.0).abs() < 1e-9);
+        assert!((summary[0].avg_ms() - 375.0).abs() < 1e-9);
+        // The most recent occurrence ran outside a request
+        assert_eq!(summary[0].last_route, None);
+
+        log.clear();
+        assert!(log.recent(10).is_empty());
+    }
+}
@@ -0,0 +1,524 @@
+//! Test harness for unit, route and integration tests
+//!
+//! Public so integration tests under `tests/` (and other crates) can drive the
+//! real router the way a browser would:
+//! - In-memory database with migrations applied
+//! - Seed data functions
+//! - Test session management and signed session cookies
+//! - [`TestHarness`], a client that sends requests through the full app
+//!
+//! ```ignore
+//! let mut harness = TestHarness::with_seed_data().await;
+//! harness.sign_in().await;
+//! let response = harness.get("/teams").await;
+//! assert_eq!(response.status, StatusCode::OK);
+//! assert!(response.body.contains("Team Canada"));
+//! ```
+
+use std::str::FromStr;
+
+use crate::app_state::AppState;
+use crate::auth::{sign_session_id, Session, SessionStore};
+use axum::{
+    body::Body,
+    http::{header, HeaderMap, Method, Request, StatusCode},
+    Router,
+};
+use axum_extra::extract::cookie::Cookie;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::SqlitePool;
+use tower::ServiceExt;
+
+/// Test session secret (32+ characters as required)
+pub const TEST_SESSION_SECRET: &str = "test-secret-key-DO-NOT-USE-IN-PRODUCTION-32chars";
+
+/// Largest response body the harness reads
+const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+/// Create an in-memory database with all migrations applied
+///
+/// The pool holds a single connection that is never recycled, since an
+/// in-memory database disappears with its connection.
+pub async fn create_test_pool() -> SqlitePool {
+    let options = SqliteConnectOptions::from_str("sqlite::memory:")
+        .expect("In-memory database URL should parse")
+        .foreign_keys(true);
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect_with(options)
+        .await
+        .expect("Failed to open in-memory database");
+
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("Failed to run migrations");
+
+    pool
+}
+
+/// Create a test application with routes for integration testing
+///
+/// This is the production router (`app::router`) with all routes and
+/// middleware, using the provided database pool. Use with axum-test or
+/// [`TestHarness`] for route testing.
+pub fn create_test_app(pool: SqlitePool) -> Router {
+    crate::app::router(create_test_state(pool))
+}
+
+/// Application state the test app runs with, before any `with_*` settings
+pub fn create_test_state(pool: SqlitePool) -> AppState {
+    let session_store = SessionStore::new(pool.clone());
+    AppState::new(pool, session_store, TEST_SESSION_SECRET.to_string(), false)
+}
+
+/// Create a test session for authenticated route testing
+///
+/// Creates a session for a test user and stores it in the database.
+/// Returns the Session object which can be used with `session_cookie()`.
+pub async fn create_test_session(pool: &SqlitePool) -> Session {
+    let store = SessionStore::new(pool.clone());
+
+    // Create or get test user
+    let user = sqlx::query!(
+        r#"
+        INSERT INTO users (email, name, password_hash, email_verified_at)
+        VALUES ('test@example.com', 'Test User', 'hash', CURRENT_TIMESTAMP)
+        ON CONFLICT(email) DO UPDATE SET email = email
+        RETURNING id, email, name
+        "#
+    )
+    .fetch_one(pool)
+    .await
+    .expect("Failed to create test user");
+
+    store
+        .create_session(
+            user.id,
+            user.email,
+            user.name.unwrap_or_else(|| "Test User".to_string()),
+        )
+        .await
+        .expect("Failed to create test session")
+}
+
+/// Generate a session cookie from a Session
+///
+/// Creates a Cookie that can be used with axum-test's `add_cookie()` method
+/// to authenticate requests in route tests. The session ID is signed using
+/// the TEST_SESSION_SECRET to match production behavior.
+pub fn session_cookie(session: &Session) -> Cookie<'static> {
+    // Sign the session ID using the test secret
+    let signed_session_id = sign_session_id(&session.id, TEST_SESSION_SECRET);
+
+    Cookie::build(("hockey_session", signed_session_id))
+        .http_only(true)
+        .secure(false) // Not secure in tests
+        .same_site(axum_extra::extract::cookie::SameSite::Lax)
+        .path("/")
+        .into()
+}
+
+/// Seed test countries into the database
+///
+/// Inserts a standard set of countries for testing, including:
+/// - IIHF members and non-members
+/// - Historical countries (Soviet Union, East Germany)
+/// - Countries with various attribute combinations
+pub async fn seed_test_countries(pool: &SqlitePool) {
+    sqlx::query!(
+        r#"
+        INSERT OR IGNORE INTO country (id, name, iihf, iocCode, iso2Code, isHistorical, years, enabled)
+        VALUES
+            (1, 'Canada', 1, 'CAN', 'ca', 0, NULL, 1),
+            (2, 'United States', 1, 'USA', 'us', 0, NULL, 1),
+            (3, 'Russia', 1, 'RUS', 'ru', 0, NULL, 1),
+            (4, 'Finland', 1, 'FIN', 'fi', 0, NULL, 1),
+            (5, 'Sweden', 1, 'SWE', 'se', 0, NULL, 1),
+            (6, 'Czech Republic', 1, 'CZE', 'cz', 0, NULL, 1),
+            (7, 'Slovakia', 1, 'SVK', 'sk', 0, NULL, 1),
+            (8, 'Switzerland', 1, 'SUI', 'ch', 0, NULL, 1),
+            (9, 'Germany', 1, 'GER', 'de', 0, NULL, 1),
+            (10, 'Austria', 1, 'AUT', 'at', 0, NULL, 1),
+            (11, 'Latvia', 1, 'LAT', 'lv', 0, NULL, 1),
+            (12, 'Norway', 1, 'NOR', 'no', 0, NULL, 1),
+            (13, 'Denmark', 1, 'DEN', 'dk', 0, NULL, 1),
+            (14, 'France', 1, 'FRA', 'fr', 0, NULL, 1),
+            (15, 'Belarus', 1, 'BLR', 'by', 0, NULL, 1),
+            (16, 'Soviet Union', 1, 'URS', 'su', 1, '1922-1991', 0),
+            (17, 'East Germany', 1, 'GDR', NULL, 1, '1949-1990', 0),
+            (18, 'Czechoslovakia', 1, 'TCH', NULL, 1, '1920-1992', 0),
+            (19, 'Japan', 1, 'JPN', 'jp', 0, NULL, 1),
+            (20, 'South Korea', 1, 'KOR', 'kr', 0, NULL, 1)
+        "#
+    )
+    .execute(pool)
+    .await
+    .expect("Failed to seed countries");
+}
+
+/// Seed test teams into the database
+///
+/// Inserts a set of hockey teams for testing. Requires countries to be seeded first.
+pub async fn seed_test_teams(pool: &SqlitePool) {
+    // Ensure countries exist first
+    seed_test_countries(pool).await;
+
+    sqlx::query!(
+        r#"
+        INSERT OR IGNORE INTO team (id, name, country_id)
+        VALUES
+            (1, 'Team Canada', 1),
+            (2, 'Team USA', 2),
+            (3, 'Team Russia', 3),
+            (4, 'Team Finland', 4),
+            (5, 'Team Sweden', 5)
+        "#
+    )
+    .execute(pool)
+    .await
+    .expect("Failed to seed teams");
+}
+
+/// Seed test players into the database
+///
+/// Inserts a set of players for testing. Requires countries to be seeded first.
+pub async fn seed_test_players(pool: &SqlitePool) {
+    // Ensure countries exist first
+    seed_test_countries(pool).await;
+
+    sqlx::query!(
+        r#"
+        INSERT OR IGNORE INTO player (id, name, country_id, birth_date, position)
+        VALUES
+            (1, 'Wayne Gretzky', 1, '1961-01-26', 'Forward'),
+            (2, 'Mario Lemieux', 1, '1965-10-05', 'Forward'),
+            (3, 'Bobby Orr', 1, '1948-03-20', 'Defense'),
+            (4, 'Gordie Howe', 1, '1928-03-31', 'Forward'),
+            (5, 'Pavel Datsyuk', 3, '1978-07-20', 'Forward')
+        "#
+    )
+    .execute(pool)
+    .await
+    .expect("Failed to seed players");
+}
+
+/// Seed test events into the database
+///
+/// Inserts hockey events (tournaments) for testing. Requires countries to be seeded first.
+pub async fn seed_test_events(pool: &SqlitePool) {
+    // Ensure countries exist first
+    seed_test_countries(pool).await;
+
+    sqlx::query!(
+        r#"
+        INSERT OR IGNORE INTO event (id, name, country_id)
+        VALUES
+            (1, 'Winter Olympics', 1),
+            (2, 'World Championship', 4),
+            (3, 'World Cup', 2)
+        "#
+    )
+    .execute(pool)
+    .await
+    .expect("Failed to seed events");
+}
+
+/// Response collected by [`TestHarness`]
+#[derive(Debug)]
+pub struct TestResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+impl TestResponse {
+    /// Value of a response header, if present and valid UTF-8
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+}
+
+/// In-memory database plus the full application router
+///
+/// Requests carry the session cookie once [`TestHarness::sign_in`] has been
+/// called, and form posts and uploads get the session's CSRF token added
+/// automatically.
+pub struct TestHarness {
+    pub pool: SqlitePool,
+    app: Router,
+    session: Option<Session>,
+}
+
+impl TestHarness {
+    /// Fresh database with migrations only
+    pub async fn new() -> Self {
+        let pool = create_test_pool().await;
+        Self {
+            app: create_test_app(pool.clone()),
+            pool,
+            session: None,
+        }
+    }
+
+    /// Fresh database with migrations only, and app settings adjusted by
+    /// `configure`, e.g. `|state| state.with_oauth(...)`
+    pub async fn with_state(configure: impl FnOnce(AppState) -> AppState) -> Self {
+        let pool = create_test_pool().await;
+        Self {
+            app: crate::app::router(configure(create_test_state(pool.clone()))),
+            pool,
+            session: None,
+        }
+    }
+
+    /// Fresh database seeded with test countries, teams, players and events
+    pub async fn with_seed_data() -> Self {
+        let harness = Self::new().await;
+        seed_test_teams(&harness.pool).await;
+        seed_test_players(&harness.pool).await;
+        seed_test_events(&harness.pool).await;
+        harness
+    }
+
+    /// Sign in as the test user; later requests are authenticated
+    pub async fn sign_in(&mut self) -> &Session {
+        let session = create_test_session(&self.pool).await;
+        self.session.insert(session)
+    }
+
+    /// Current session, if signed in
+    pub fn session(&self) -> Option<&Session> {
+        self.session.as_ref()
+    }
+
+    /// Send a GET request for a full page
+    pub async fn get(&self, path: &str) -> TestResponse {
+        self.send(Method::GET, path, false, &[], None).await
+    }
+
+    /// Send a GET request as HTMX does, for partials
+    pub async fn htmx_get(&self, path: &str) -> TestResponse {
+        self.send(Method::GET, path, true, &[], None).await
+    }
+
+    /// Send a GET request with extra headers, e.g. an `X-Api-Key`
+    pub async fn get_with_headers(&self, path: &str, headers: &[(&str, &str)]) -> TestResponse {
+        self.send(Method::GET, path, false, headers, None).await
+    }
+
+    /// Submit a form as HTMX does, adding the CSRF token when signed in
+    pub async fn post_form(&self, path: &str, fields: &[(&str, &str)]) -> TestResponse {
+        let mut pairs: Vec<(&str, &str)> = fields.to_vec();
+        if let Some(session) = &self.session {
+            if !fields.iter().any(|(name, _)| *name == "csrf_token") {
+                pairs.push(("csrf_token", &session.csrf_token));
+            }
+        }
+        let body = pairs
+            .iter()
+            .map(|(name, value)| {
+                format!(
+                    "{}={}",
+                    urlencoding::encode(name),
+                    urlencoding::encode(value)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("&");
+
+        self.send(
+            Method::POST,
+            path,
+            true,
+            &[],
+            Some(("application/x-www-form-urlencoded".to_string(), body)),
+        )
+        .await
+    }
+
+    /// Send a JSON body, as API clients do
+    pub async fn post_json(&self, path: &str, json: &str) -> TestResponse {
+        self.send(
+            Method::POST,
+            path,
+            false,
+            &[],
+            Some(("application/json".to_string(), json.to_string())),
+        )
+        .await
+    }
+
+    /// Upload a file in the `file` field of a multipart form, adding the
+    /// CSRF token when signed in
+    pub async fn post_file(&self, path: &str, file_name: &str, data: &str) -> TestResponse {
+        let boundary = "hockey-test-boundary";
+        let mut body = String::new();
+        if let Some(session) = &self.session {
+            body.push_str(&format!(
+                "--{boundary}\r\nContent-Disposition: form-data; name=\"csrf_token\"\r\n\r\n{}\r\n",
+                session.csrf_token
+            ));
+        }
+        body.push_str(&format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"{file_name}\"\r\n\r\n{data}\r\n--{boundary}--\r\n"
+        ));
+
+        self.send(
+            Method::POST,
+            path,
+            true,
+            &[],
+            Some((format!("multipart/form-data; boundary={}", boundary), body)),
+        )
+        .await
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        htmx: bool,
+        headers: &[(&str, &str)],
+        body: Option<(String, String)>,
+    ) -> TestResponse {
+        let mut request = Request::builder().method(method).uri(path);
+        if let Some(session) = &self.session {
+            let cookie = session_cookie(session);
+            request = request.header(
+                header::COOKIE,
+                format!("{}={}", cookie.name(), cookie.value()),
+            );
+        }
+        if htmx {
+            request = request.header("HX-Request", "true");
+        }
+        for (name, value) in headers {
+            request = request.header(*name, *value);
+        }
+        let body = match body {
+            Some((content_type, body)) => {
+                request = request.header(header::CONTENT_TYPE, content_type);
+                Body::from(body)
+            }
+            None => Body::empty(),
+        };
+
+        let response = self
+            .app
+            .clone()
+            .oneshot(request.body(body).expect("Test request should be valid"))
+            .await
+            .expect("Router is infallible");
+
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = axum::body::to_bytes(response.into_body(), MAX_BODY_BYTES)
+            .await
+            .expect("Failed to read response body");
+
+        TestResponse {
+            status,
+            headers,
+            body: String::from_utf8_lossy(&bytes).into_owned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_seed_countries(pool: SqlitePool) {
+        seed_test_countries(&pool).await;
+
+        let count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM country")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        assert!(count >= 20, "Should have at least 20 countries seeded");
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_seed_teams(pool: SqlitePool) {
+        seed_test_teams(&pool).await;
+
+        let count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM team")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(count, 5, "Should have 5 teams seeded");
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_create_session(pool: SqlitePool) {
+        let session = create_test_session(&pool).await;
+
+        assert_eq!(session.user_email, "test@example.com");
+        assert_eq!(session.user_name, "Test User");
+        assert!(!session.is_expired());
+    }
+
+    #[sqlx::test(migrations = "./migrations")]
+    async fn test_session_cookie(pool: SqlitePool) {
+        use crate::auth::verify_signed_session_id;
+
+        let session = create_test_session(&pool).await;
+        let cookie = session_cookie(&session);
+
+        assert_eq!(cookie.name(), "hockey_session");
+
+        // Cookie value should be a signed session ID, not plain session ID
+        assert_ne!(cookie.value(), session.id);
+
+        // Verify that the cookie value is properly signed
+        let verified_session_id = verify_signed_session_id(cookie.value(), TEST_SESSION_SECRET);
+        assert_eq!(verified_session_id, Some(session.id.clone()));
+
+        assert!(cookie.http_only().unwrap());
+        assert_eq!(cookie.path(), Some("/"));
+    }
+
+    #[tokio::test]
+    async fn test_harness_requires_sign_in() {
+        let mut harness = TestHarness::with_seed_data().await;
+
+        let anonymous = harness.get("/teams").await;
+        assert_eq!(anonymous.status, StatusCode::SEE_OTHER);
+
+        harness.sign_in().await;
+        let page = harness.get("/teams").await;
+        assert_eq!(page.status, StatusCode::OK);
+        assert!(page.body.contains("<html"));
+
+        let partial = harness.htmx_get("/teams/list").await;
+        assert_eq!(partial.status, StatusCode::OK);
+        assert!(!partial.body.contains("<html"));
+        assert!(partial.body.contains("Team Canada"));
+    }
+
+    #[tokio::test]
+    async fn test_harness_post_form_adds_csrf_token() {
+        let mut harness = TestHarness::new().await;
+        harness.sign_in().await;
+
+        let response = harness
+            .post_form("/arenas", &[("name", "Harness Arena"), ("city", "Brno")])
+            .await;
+        assert_eq!(response.status, StatusCode::OK);
+        assert!(response.header("hx-toast-success").is_some());
+
+        let count =
+            sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM arena WHERE name = 'Harness Arena'")
+                .fetch_one(&harness.pool)
+                .await
+                .unwrap();
+        assert_eq!(count, 1);
+    }
+}
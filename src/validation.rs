@@ -118,6 +118,280 @@ pub fn validate_score_event_time(
     Ok(())
 }
 
+/// Validates shots on goal for a single team and period
+///
+/// # Validation Rules
+/// * Shots must be between 0 and 200 (inclusive) if provided
+///
+/// # Examples
+/// ```
+/// let result = validate_shots_on_goal(Some(12));
+/// assert!(result.is_ok());
+///
+/// let result = validate_shots_on_goal(Some(-1));
+/// assert!(result.is_err());
+/// ```
+pub fn validate_shots_on_goal(shots: Option<i32>) -> Result<(), &'static str> {
+    if let Some(shots) = shots {
+        if !(0..=200).contains(&shots) {
+            return Err("Shots on goal must be between 0 and 200");
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates a player's faceoffs won or lost in one match
+///
+/// # Validation Rules
+/// * Count must be between 0 and 99 (inclusive) if provided
+///
+/// # Examples
+/// ```
+/// let result = validate_faceoffs(Some(14));
+/// assert!(result.is_ok());
+///
+/// let result = validate_faceoffs(Some(100));
+/// assert!(result.is_err());
+/// ```
+pub fn validate_faceoffs(faceoffs: Option<i32>) -> Result<(), &'static str> {
+    if let Some(faceoffs) = faceoffs {
+        if !(0..=99).contains(&faceoffs) {
+            return Err("Faceoffs must be between 0 and 99");
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates a goal's video link and parses its timestamp
+///
+/// # Validation Rules
+/// * URL must start with `http://` or `https://`, contain no whitespace and
+///   be at most 500 characters long
+/// * Timestamp is `h:mm:ss`, `m:ss` or plain seconds and needs a URL
+///
+/// # Returns
+/// * `Ok((url, seconds))` - Trimmed URL and timestamp in seconds
+/// * `Err(&'static str)` - Error message if validation fails
+///
+/// # Examples
+/// ```
+/// let result = validate_video_link(Some("https://youtu.be/abc"), Some("1:23:45"));
+/// assert_eq!(result, Ok((Some("https://youtu.be/abc".to_string()), Some(5025))));
+///
+/// let result = validate_video_link(None, Some("1:23"));
+/// assert!(result.is_err());
+/// ```
+pub fn validate_video_link(
+    url: Option<&str>,
+    timestamp: Option<&str>,
+) -> Result<(Option<String>, Option<i32>), &'static str> {
+    let url = url.map(str::trim).filter(|u| !u.is_empty());
+    let timestamp = timestamp.map(str::trim).filter(|ts| !ts.is_empty());
+
+    let Some(url) = url else {
+        return match timestamp {
+            Some(_) => Err("Video timestamp requires a video URL"),
+            None => Ok((None, None)),
+        };
+    };
+
+    if !(url.starts_with("http://") || url.starts_with("https://"))
+        || url.chars().any(char::is_whitespace)
+    {
+        return Err("Video URL must be a web address starting with http:// or https://");
+    }
+    if url.len() > 500 {
+        return Err("Video URL must be at most 500 characters");
+    }
+
+    let seconds = match timestamp {
+        Some(ts) => {
+            Some(parse_video_timestamp(ts).ok_or("Video timestamp must look like 1:23:45")?)
+        }
+        None => None,
+    };
+
+    Ok((Some(url.to_string()), seconds))
+}
+
+/// Seconds of an `h:mm:ss`, `m:ss` or plain seconds timestamp
+fn parse_video_timestamp(ts: &str) -> Option<i32> {
+    let parts: Vec<&str> = ts.split(':').collect();
+    if parts.len() > 3 {
+        return None;
+    }
+
+    let mut seconds: i32 = 0;
+    for (i, part) in parts.iter().enumerate() {
+        if part.is_empty() || !part.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let value: i32 = part.parse().ok()?;
+        // Only the leading component may exceed 59
+        if i > 0 && value > 59 {
+            return None;
+        }
+        seconds = seconds.checked_mul(60)?.checked_add(value)?;
+    }
+
+    Some(seconds)
+}
+
+/// Validates a URL slug entered by hand
+///
+/// # Validation Rules
+/// * Empty input means the slug is generated from the name
+/// * Only lowercase letters, digits and single hyphens between them
+/// * At most 80 characters and not a plain number (those are IDs)
+/// * Not a word taken by a static route, such as `new` or `export`
+///
+/// # Examples
+/// ```
+/// let result = validate_slug(Some(" HC-Sparta "));
+/// assert_eq!(result, Ok(Some("hc-sparta".to_string())));
+///
+/// let result = validate_slug(Some("2024"));
+/// assert!(result.is_err());
+/// ```
+pub fn validate_slug(slug: Option<&str>) -> Result<Option<String>, &'static str> {
+    let Some(slug) = slug
+        .map(|s| s.trim().to_lowercase())
+        .filter(|s| !s.is_empty())
+    else {
+        return Ok(None);
+    };
+
+    if slug.len() > 80 {
+        return Err("Slug must be at most 80 characters");
+    }
+    let valid_chars = slug
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !valid_chars || slug.starts_with('-') || slug.ends_with('-') || slug.contains("--") {
+        return Err("Slug may only contain letters a-z, digits and single hyphens");
+    }
+    if slug.chars().all(|c| c.is_ascii_digit()) {
+        return Err("Slug cannot be only a number");
+    }
+    if crate::service::slugs::is_reserved_slug(&slug) {
+        return Err("Slug is reserved for another page");
+    }
+
+    Ok(Some(slug))
+}
+
+/// Validates the version label of a changelog entry
+///
+/// # Validation Rules
+/// * Version cannot be empty or contain whitespace
+/// * Version must be at most 32 characters
+///
+/// # Examples
+/// ```
+/// let result = validate_changelog_version(" 0.2.0 ");
+/// assert_eq!(result, Ok("0.2.0".to_string()));
+///
+/// let result = validate_changelog_version("0.2 beta");
+/// assert!(result.is_err());
+/// ```
+pub fn validate_changelog_version(version: &str) -> Result<String, &'static str> {
+    let version = version.trim();
+
+    if version.is_empty() {
+        return Err("Version cannot be empty");
+    }
+    if version.len() > 32 {
+        return Err("Version must be at most 32 characters");
+    }
+    if version.chars().any(char::is_whitespace) {
+        return Err("Version cannot contain spaces");
+    }
+
+    Ok(version.to_string())
+}
+
+/// Validates the text of a changelog entry
+///
+/// # Validation Rules
+/// * Text cannot be empty (after trimming)
+/// * Text must be at most 5000 characters
+pub fn validate_changelog_body(body: &str) -> Result<String, &'static str> {
+    let body = body.trim();
+
+    if body.is_empty() {
+        return Err("Text cannot be empty");
+    }
+    if body.chars().count() > 5000 {
+        return Err("Text must be at most 5000 characters");
+    }
+
+    Ok(body.to_string())
+}
+
+/// Validates the markdown text of a comment
+///
+/// # Validation Rules
+/// * Text cannot be empty (after trimming)
+/// * Text must be at most 5000 characters
+pub fn validate_comment_body(body: &str) -> Result<String, &'static str> {
+    let body = body.trim();
+
+    if body.is_empty() {
+        return Err("Comment cannot be empty");
+    }
+    if body.chars().count() > 5000 {
+        return Err("Comment must be at most 5000 characters");
+    }
+
+    Ok(body.to_string())
+}
+
+/// Validates an optional task due date
+///
+/// # Validation Rules
+/// * Empty input means no due date
+/// * Otherwise the date must be a valid `YYYY-MM-DD` date
+///
+/// # Examples
+/// ```
+/// let result = validate_due_date(Some("2026-03-01"));
+/// assert_eq!(result, Ok(Some("2026-03-01".to_string())));
+///
+/// let result = validate_due_date(Some("2026-02-30"));
+/// assert!(result.is_err());
+/// ```
+pub fn validate_due_date(date: Option<&str>) -> Result<Option<String>, &'static str> {
+    let Some(date) = date.map(str::trim).filter(|d| !d.is_empty()) else {
+        return Ok(None);
+    };
+
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map(|d| Some(d.format("%Y-%m-%d").to_string()))
+        .map_err(|_| "Due date must be a valid date (YYYY-MM-DD)")
+}
+
+/// Validates a jersey number
+///
+/// # Validation Rules
+/// * Number must be between 0 and 99 (inclusive) if provided
+///
+/// # Examples
+/// ```
+/// let result = validate_jersey_number(Some(87));
+/// assert!(result.is_ok());
+///
+/// let result = validate_jersey_number(Some(100));
+/// assert!(result.is_err());
+/// ```
+pub fn validate_jersey_number(number: Option<i64>) -> Result<Option<i64>, &'static str> {
+    match number {
+        Some(n) if !(0..=99).contains(&n) => Err("Jersey number must be between 0 and 99"),
+        _ => Ok(number),
+    }
+}
+
 /// Validates player height in centimeters
 ///
 /// Ensures height is within reasonable human biological limits.
@@ -192,6 +466,28 @@ pub fn validate_weight_kg(weight: Option<i64>) -> Result<Option<i64>, &'static s
     }
 }
 
+/// Validates arena seating capacity
+///
+/// # Arguments
+/// * `capacity` - Optional number of seats
+///
+/// # Returns
+/// * `Ok(Some(i64))` - If capacity is valid
+/// * `Ok(None)` - If capacity is None
+/// * `Err(&'static str)` - Error message if validation fails
+///
+/// # Valid Range
+/// * Minimum: 1
+/// * Maximum: 200,000 - well above the largest outdoor games
+pub fn validate_arena_capacity(capacity: Option<i64>) -> Result<Option<i64>, &'static str> {
+    match capacity {
+        None => Ok(None),
+        Some(c) if c < 1 => Err("Capacity must be a positive number"),
+        Some(c) if c > 200_000 => Err("Capacity must be at most 200,000"),
+        Some(c) => Ok(Some(c)),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -214,6 +510,139 @@ mod tests {
         assert!(validate_name(&long_name).is_err());
     }
 
+    #[test]
+    fn test_validate_jersey_number() {
+        assert_eq!(validate_jersey_number(None).unwrap(), None);
+        assert_eq!(validate_jersey_number(Some(0)).unwrap(), Some(0));
+        assert_eq!(validate_jersey_number(Some(99)).unwrap(), Some(99));
+        assert!(validate_jersey_number(Some(-1)).is_err());
+        assert!(validate_jersey_number(Some(100)).is_err());
+    }
+
+    #[test]
+    fn test_validate_arena_capacity() {
+        assert_eq!(validate_arena_capacity(None).unwrap(), None);
+        assert_eq!(validate_arena_capacity(Some(17_000)).unwrap(), Some(17_000));
+        assert!(validate_arena_capacity(Some(0)).is_err());
+        assert!(validate_arena_capacity(Some(250_000)).is_err());
+    }
+
+    #[test]
+    fn test_validate_shots_on_goal() {
+        assert!(validate_shots_on_goal(None).is_ok());
+        assert!(validate_shots_on_goal(Some(0)).is_ok());
+        assert!(validate_shots_on_goal(Some(45)).is_ok());
+        assert!(validate_shots_on_goal(Some(-1)).is_err());
+        assert!(validate_shots_on_goal(Some(201)).is_err());
+    }
+
+    #[test]
+    fn test_validate_faceoffs() {
+        assert!(validate_faceoffs(None).is_ok());
+        assert!(validate_faceoffs(Some(0)).is_ok());
+        assert!(validate_faceoffs(Some(99)).is_ok());
+        assert!(validate_faceoffs(Some(-1)).is_err());
+        assert!(validate_faceoffs(Some(100)).is_err());
+    }
+
+    #[test]
+    fn test_validate_video_link() {
+        assert_eq!(validate_video_link(None, None), Ok((None, None)));
+        assert_eq!(validate_video_link(Some("  "), Some("")), Ok((None, None)));
+        assert_eq!(
+            validate_video_link(Some(" https://youtu.be/abc "), None),
+            Ok((Some("https://youtu.be/abc".to_string()), None))
+        );
+        assert_eq!(
+            validate_video_link(Some("https://youtu.be/abc"), Some("1:23:45")),
+            Ok((Some("https://youtu.be/abc".to_string()), Some(5025)))
+        );
+        assert_eq!(
+            validate_video_link(Some("https://youtu.be/abc"), Some("83:45")),
+            Ok((Some("https://youtu.be/abc".to_string()), Some(5025)))
+        );
+        assert_eq!(
+            validate_video_link(Some("https://youtu.be/abc"), Some("90")),
+            Ok((Some("https://youtu.be/abc".to_string()), Some(90)))
+        );
+    }
+
+    #[test]
+    fn test_validate_video_link_invalid() {
+        assert!(validate_video_link(None, Some("1:23")).is_err());
+        assert!(validate_video_link(Some("javascript:alert(1)"), None).is_err());
+        assert!(validate_video_link(Some("https://a b"), None).is_err());
+        let long = format!("https://example.com/{}", "a".repeat(500));
+        assert!(validate_video_link(Some(&long), None).is_err());
+        for ts in ["1:60", "1:2:3:4", "a:bc", "1::2", "-5"] {
+            assert!(
+                validate_video_link(Some("https://youtu.be/abc"), Some(ts)).is_err(),
+                "{ts}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_slug() {
+        assert_eq!(validate_slug(None), Ok(None));
+        assert_eq!(validate_slug(Some("  ")), Ok(None));
+        assert_eq!(
+            validate_slug(Some(" HC-Sparta ")),
+            Ok(Some("hc-sparta".to_string()))
+        );
+        assert_eq!(
+            validate_slug(Some("team-2024")),
+            Ok(Some("team-2024".to_string()))
+        );
+        for invalid in [
+            "2024", "-a", "a-", "a--b", "a b", "jágr", "a/b", "new", " Export ",
+        ] {
+            assert!(validate_slug(Some(invalid)).is_err(), "{invalid}");
+        }
+        assert!(validate_slug(Some(&"a".repeat(81))).is_err());
+    }
+
+    #[test]
+    fn test_validate_changelog_entry() {
+        assert_eq!(
+            validate_changelog_version(" 0.2.0 "),
+            Ok("0.2.0".to_string())
+        );
+        assert!(validate_changelog_version("").is_err());
+        assert!(validate_changelog_version("0.2 beta").is_err());
+        assert!(validate_changelog_version(&"1".repeat(33)).is_err());
+
+        assert_eq!(
+            validate_changelog_body("  New:\nstuff  "),
+            Ok("New:\nstuff".to_string())
+        );
+        assert!(validate_changelog_body(" \n ").is_err());
+        assert!(validate_changelog_body(&"x".repeat(5001)).is_err());
+    }
+
+    #[test]
+    fn test_validate_comment_body() {
+        assert_eq!(
+            validate_comment_body("  @bob see **here**\n "),
+            Ok("@bob see **here**".to_string())
+        );
+        assert!(validate_comment_body("   ").is_err());
+        assert!(validate_comment_body(&"é".repeat(5000)).is_ok());
+        assert!(validate_comment_body(&"x".repeat(5001)).is_err());
+    }
+
+    #[test]
+    fn test_validate_due_date() {
+        assert_eq!(validate_due_date(None), Ok(None));
+        assert_eq!(validate_due_date(Some("  ")), Ok(None));
+        assert_eq!(
+            validate_due_date(Some(" 2026-03-01 ")),
+            Ok(Some("2026-03-01".to_string()))
+        );
+        assert!(validate_due_date(Some("2026-02-30")).is_err());
+        assert!(validate_due_date(Some("01.03.2026")).is_err());
+    }
+
     #[test]
     fn test_validate_event_stats_success() {
         assert!(validate_event_stats(0, 0).is_ok());
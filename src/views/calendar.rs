@@ -0,0 +1,174 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime, Utc};
+
+use crate::service::calendar_subscriptions::CalendarMatchEntity;
+
+/// Length assumed for matches with a start time; calendars need an end
+const MATCH_DURATION_MINUTES: i64 = 150;
+
+/// Render a team's matches as an iCalendar (RFC 5545) feed
+///
+/// Match dates carry no time zone, so timed matches are written as floating
+/// times shown as-is in every subscriber's zone; date-only matches become
+/// all-day events.
+pub fn team_calendar(team_name: &str, matches: &[CalendarMatchEntity]) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Hockey//Team Calendar//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        format!("X-WR-CALNAME:{}", escape_text(team_name)),
+    ];
+
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ").to_string();
+    for m in matches {
+        let Some((start, end)) = event_times(&m.match_date) else {
+            continue;
+        };
+
+        let summary = if m.status == "finished" {
+            format!(
+                "{} {}:{} {}",
+                m.home_team_name, m.home_score, m.away_score, m.away_team_name
+            )
+        } else {
+            format!("{} vs {}", m.home_team_name, m.away_team_name)
+        };
+        let location = match (&m.arena_name, &m.arena_city) {
+            (Some(name), Some(city)) => Some(format!("{}, {}", name, city)),
+            (Some(name), None) => Some(name.clone()),
+            _ => None,
+        };
+
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:match-{}@hockey", m.id));
+        lines.push(format!("DTSTAMP:{}", stamp));
+        lines.push(start);
+        lines.push(end);
+        lines.push(format!("SUMMARY:{}", escape_text(&summary)));
+        lines.push(format!(
+            "DESCRIPTION:{}",
+            escape_text(&format!("{} {}", m.event_name, m.season_name))
+        ));
+        if let Some(location) = location {
+            lines.push(format!("LOCATION:{}", escape_text(&location)));
+        }
+        if m.status == "cancelled" {
+            lines.push("STATUS:CANCELLED".to_string());
+        }
+        if let Ok(updated) = NaiveDateTime::parse_from_str(&m.updated_at, "%Y-%m-%d %H:%M:%S") {
+            lines.push(format!(
+                "LAST-MODIFIED:{}",
+                updated.format("%Y%m%dT%H%M%SZ")
+            ));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut out = String::new();
+    for line in lines {
+        out.push_str(&fold_line(&line));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+/// `DTSTART` and `DTEND` lines for a match date (`YYYY-MM-DD` or `YYYY-MM-DDTHH:MM`)
+fn event_times(match_date: &str) -> Option<(String, String)> {
+    for format in ["%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M:%S"] {
+        if let Ok(start) = NaiveDateTime::parse_from_str(match_date, format) {
+            let end = start + Duration::minutes(MATCH_DURATION_MINUTES);
+            return Some((
+                format!("DTSTART:{}", start.format("%Y%m%dT%H%M%S")),
+                format!("DTEND:{}", end.format("%Y%m%dT%H%M%S")),
+            ));
+        }
+    }
+
+    let day = NaiveDate::parse_from_str(match_date, "%Y-%m-%d").ok()?;
+    Some((
+        format!("DTSTART;VALUE=DATE:{}", day.format("%Y%m%d")),
+        format!(
+            "DTEND;VALUE=DATE:{}",
+            (day + Duration::days(1)).format("%Y%m%d")
+        ),
+    ))
+}
+
+/// Escape a TEXT property value
+fn escape_text(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold lines longer than 75 octets, never splitting a UTF-8 character
+fn fold_line(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn calendar_match(id: i64, match_date: &str, status: &str) -> CalendarMatchEntity {
+        CalendarMatchEntity {
+            id,
+            match_date: match_date.to_string(),
+            status: status.to_string(),
+            home_team_name: "Team Canada".to_string(),
+            away_team_name: "Team USA".to_string(),
+            home_score: 3,
+            away_score: 2,
+            event_name: "Winter Olympics".to_string(),
+            season_name: "2022".to_string(),
+            arena_name: Some("National Stadium".to_string()),
+            arena_city: Some("Beijing".to_string()),
+            updated_at: "2024-01-10 12:00:00".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_team_calendar() {
+        let ics = team_calendar(
+            "Team Canada",
+            &[
+                calendar_match(1, "2024-02-01T19:30", "scheduled"),
+                calendar_match(2, "2024-01-15", "finished"),
+                calendar_match(3, "soon", "scheduled"),
+            ],
+        );
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ics.contains("DTSTART:20240201T193000\r\nDTEND:20240201T220000\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20240115\r\nDTEND;VALUE=DATE:20240116\r\n"));
+        assert!(ics.contains("SUMMARY:Team Canada vs Team USA\r\n"));
+        assert!(ics.contains("SUMMARY:Team Canada 3:2 Team USA\r\n"));
+        assert!(ics.contains("LOCATION:National Stadium\\, Beijing\r\n"));
+        assert!(ics.contains("UID:match-2@hockey\r\n"));
+    }
+
+    #[test]
+    fn test_fold_line() {
+        let line = format!("SUMMARY:{}", "á".repeat(50));
+        let folded = fold_line(&line);
+        assert!(folded.split("\r\n").all(|part| part.len() <= 75));
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+}
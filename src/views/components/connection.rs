@@ -0,0 +1,135 @@
+use maud::{html, Markup, PreEscaped};
+
+use crate::i18n::TranslationContext;
+
+/// Seconds between health checks while the connection is down (doubles up to the max)
+const RETRY_INTERVAL_SECONDS: u32 = 5;
+const MAX_RETRY_INTERVAL_SECONDS: u32 = 60;
+
+/// Banner shown while the browser is offline or the server is unreachable
+///
+/// Failed HTMX swaps (network errors and 502/503/504 responses) and the
+/// browser's `offline` event open the banner. While it is open the page polls
+/// `/readiness`; once that succeeds the banner closes and the GET requests
+/// that failed in the meantime are issued again. Other requests are not
+/// retried because they may not be idempotent.
+pub fn connection_banner(t: &TranslationContext) -> Markup {
+    html! {
+        div
+            id="connection-banner"
+            class="connection-banner"
+            role="alert"
+            aria-live="assertive"
+            hidden
+            data-offline=(t.messages.connection_offline())
+            data-unavailable=(t.messages.connection_server_unavailable())
+            data-restored=(t.messages.connection_restored())
+            data-retry-interval=(RETRY_INTERVAL_SECONDS)
+            data-max-retry-interval=(MAX_RETRY_INTERVAL_SECONDS)
+        {
+            span class="connection-banner-message" {}
+            button type="button" class="btn btn-sm btn-secondary" {
+                (t.messages.connection_retry_now())
+            }
+        }
+        script {
+            (PreEscaped(r#"
+(function() {
+    var banner = document.getElementById('connection-banner');
+    if (!banner) return;
+    var message = banner.querySelector('.connection-banner-message');
+    var baseDelay = Number(banner.dataset.retryInterval) * 1000;
+    var maxDelay = Number(banner.dataset.maxRetryInterval) * 1000;
+    var delay = baseDelay;
+    var timer;
+    var checking = false;
+    // Failed GET requests to replay, keyed by the element that issued them
+    var pending = new Map();
+
+    function open(text) {
+        message.textContent = text;
+        if (banner.hidden) {
+            banner.hidden = false;
+            delay = baseDelay;
+            schedule();
+        }
+    }
+
+    function schedule() {
+        clearTimeout(timer);
+        timer = setTimeout(check, delay);
+        delay = Math.min(delay * 2, maxDelay);
+    }
+
+    function check() {
+        if (checking || banner.hidden) return;
+        if (!navigator.onLine) {
+            message.textContent = banner.dataset.offline;
+            schedule();
+            return;
+        }
+        checking = true;
+        fetch('/readiness', { cache: 'no-store' })
+            .then(function(response) {
+                if (response.ok) {
+                    restore();
+                } else {
+                    open(banner.dataset.unavailable);
+                    schedule();
+                }
+            })
+            .catch(function() {
+                open(banner.dataset.unavailable);
+                schedule();
+            })
+            .finally(function() { checking = false; });
+    }
+
+    function restore() {
+        clearTimeout(timer);
+        banner.hidden = true;
+        var toasts = document.querySelector('hockey-toast-container');
+        if (toasts) toasts.success(banner.dataset.restored);
+        var requests = Array.from(pending.entries());
+        pending.clear();
+        requests.forEach(function(entry) {
+            var elt = entry[0];
+            if (!document.body.contains(elt)) return;
+            htmx.ajax('GET', entry[1].path, { source: elt, target: entry[1].target });
+        });
+    }
+
+    function remember(evt) {
+        var config = evt.detail.requestConfig;
+        if (config && config.verb === 'get' && evt.detail.elt) {
+            pending.set(evt.detail.elt, { path: config.path, target: evt.detail.target });
+        }
+    }
+
+    document.body.addEventListener('htmx:sendError', function(evt) {
+        remember(evt);
+        open(navigator.onLine ? banner.dataset.unavailable : banner.dataset.offline);
+    });
+    document.body.addEventListener('htmx:responseError', function(evt) {
+        var status = evt.detail.xhr ? evt.detail.xhr.status : 0;
+        if (status === 502 || status === 503 || status === 504) {
+            remember(evt);
+            open(banner.dataset.unavailable);
+        }
+    });
+    document.body.addEventListener('htmx:afterRequest', function(evt) {
+        if (evt.detail.successful && evt.detail.elt) pending.delete(evt.detail.elt);
+    });
+    window.addEventListener('offline', function() { open(banner.dataset.offline); });
+    window.addEventListener('online', function() {
+        if (!banner.hidden) { delay = baseDelay; check(); }
+    });
+    banner.querySelector('button').addEventListener('click', function() {
+        delay = baseDelay;
+        check();
+    });
+})();
+            "#))
+        }
+    }
+}
@@ -51,7 +51,7 @@ pub fn table_actions(
 }
 
 /// Table actions with i18n support and custom confirmation dialog
-#[allow(dead_code, clippy::too_many_arguments)]
+#[allow(clippy::too_many_arguments)]
 pub fn table_actions_i18n(
     edit_url: &str,
     delete_url: &str,
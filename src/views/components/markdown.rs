@@ -0,0 +1,73 @@
+use maud::{Markup, PreEscaped};
+use pulldown_cmark::{html, CowStr, Event, Options, Parser, Tag, TagEnd};
+
+/// Link schemes allowed in rendered markdown; anything else becomes `#`
+const SAFE_SCHEMES: [&str; 3] = ["http:", "https:", "mailto:"];
+
+fn safe_url(url: CowStr<'_>) -> CowStr<'_> {
+    let lower = url.trim().to_ascii_lowercase();
+    let has_scheme = lower
+        .split(['/', '?', '#'])
+        .next()
+        .is_some_and(|first| first.contains(':'));
+    if !has_scheme || SAFE_SCHEMES.iter().any(|s| lower.starts_with(s)) {
+        url
+    } else {
+        CowStr::Borrowed("#")
+    }
+}
+
+/// Render user-written markdown (comments) to HTML
+///
+/// Raw HTML in the source is shown as text and links may only use http(s)
+/// and mailto, so the output is safe to embed in a page. Images are rendered
+/// as links.
+pub fn render_markdown(source: &str) -> Markup {
+    let options = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TABLES;
+    let events = Parser::new_ext(source, options).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+        Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            title,
+            id,
+        })
+        | Event::Start(Tag::Image {
+            link_type,
+            dest_url,
+            title,
+            id,
+        }) => Event::Start(Tag::Link {
+            link_type,
+            dest_url: safe_url(dest_url),
+            title,
+            id,
+        }),
+        Event::End(TagEnd::Image) => Event::End(TagEnd::Link),
+        other => other,
+    });
+
+    let mut output = String::new();
+    html::push_html(&mut output, events);
+    PreEscaped(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_markdown_escapes_html_and_unsafe_links() {
+        let html = render_markdown("**Birth date** <script>alert(1)</script>").into_string();
+        assert!(html.contains("<strong>Birth date</strong>"));
+        assert!(html.contains("&lt;script&gt;"));
+
+        let html =
+            render_markdown("[source](https://iihf.com) [x](javascript:alert(1))").into_string();
+        assert!(html.contains(r#"href="https://iihf.com""#));
+        assert!(html.contains(r##"href="#""##));
+
+        let html = render_markdown("![scan](/static/scan.png)").into_string();
+        assert!(html.contains(r#"<a href="/static/scan.png">scan</a>"#));
+    }
+}
@@ -1,10 +1,12 @@
 pub mod confirm;
+pub mod connection;
 pub mod crud;
 pub mod empty_state;
 pub mod error;
 pub mod forms;
 pub mod htmx;
 pub mod loading;
+pub mod markdown;
 pub mod sidebar;
 pub mod table;
 pub mod toast;
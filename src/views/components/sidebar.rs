@@ -8,6 +8,8 @@ pub struct NavItem {
     pub path: String,
     pub label: String,
     pub icon: &'static str,
+    /// Endpoint rendering a counter badge next to the label
+    pub badge_url: Option<&'static str>,
 }
 
 impl NavItem {
@@ -16,9 +18,15 @@ impl NavItem {
             path: path.into(),
             label: label.into(),
             icon,
+            badge_url: None,
         }
     }
 
+    pub fn with_badge(mut self, badge_url: &'static str) -> Self {
+        self.badge_url = Some(badge_url);
+        self
+    }
+
     pub fn is_active(&self, current_path: &str) -> bool {
         if self.path == "/" {
             current_path == "/"
@@ -33,9 +41,19 @@ pub fn sidebar(session: &Session, current_path: &str, t: &TranslationContext) ->
         NavItem::new("/", t.messages.nav_dashboard().to_string(), "📊"),
         NavItem::new("/teams", t.messages.nav_teams().to_string(), "🏒"),
         NavItem::new("/players", t.messages.nav_players().to_string(), "👤"),
+        NavItem::new("/staff", t.messages.nav_staff().to_string(), "📋"),
         NavItem::new("/events", t.messages.nav_events().to_string(), "🏆"),
         NavItem::new("/seasons", t.messages.nav_seasons().to_string(), "📅"),
         NavItem::new("/matches", t.messages.nav_matches().to_string(), "🎯"),
+        NavItem::new("/arenas", t.messages.nav_arenas().to_string(), "🏟️"),
+        NavItem::new("/referees", t.messages.nav_referees().to_string(), "🦓"),
+        NavItem::new("/tasks", t.messages.nav_tasks().to_string(), "✅"),
+        NavItem::new(
+            "/notifications",
+            t.messages.nav_notifications().to_string(),
+            "🔔",
+        )
+        .with_badge("/notifications/unread-count"),
         NavItem::new("/management", t.messages.nav_management().to_string(), "⚙️"),
     ];
 
@@ -79,13 +97,16 @@ fn nav_link(item: &NavItem, current_path: &str) -> Markup {
         {
             span class="nav-icon" { (item.icon) }
             span class="nav-label" { (item.label) }
+            @if let Some(badge_url) = item.badge_url {
+                span hx-get=(badge_url) hx-trigger="load" hx-swap="outerHTML" {}
+            }
         }
     }
 }
 
 fn user_info(session: &Session) -> Markup {
     html! {
-        div class="user-info" {
+        a href="/profile" class="user-info" {
             div class="user-avatar" {
                 (session.user_name.chars().next().unwrap_or('U').to_uppercase())
             }
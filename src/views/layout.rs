@@ -1,10 +1,41 @@
 use maud::{html, Markup, DOCTYPE};
 
+use super::components::connection::connection_banner;
 use super::components::sidebar;
 use super::components::toast::htmx_toast_event_handler;
+use super::pages::changelog::whats_new_loader;
+use crate::assets;
 use crate::auth::Session;
 use crate::i18n::TranslationContext;
 
+/// Web component modules, built into `static/js/components`
+const COMPONENT_SCRIPTS: &[&str] = &[
+    "js/components/country-selector.js",
+    "js/components/badge.js",
+    "js/components/flag-icon.js",
+    "js/components/toggle-switch.js",
+    "js/components/client-data-table.js",
+    "js/components/countries-table.js",
+    "js/components/loading-spinner.js",
+    "js/components/loading-state.js",
+    "js/components/toast.js",
+    "js/components/confirm-dialog.js",
+    "js/components/modal.js",
+];
+
+/// Stylesheets in cascade order
+const STYLESHEETS: &[&str] = &[
+    "css/theme.css",
+    "css/reset.css",
+    "css/layout.css",
+    "css/components.css",
+    "css/forms.css",
+    "css/tables.css",
+    "css/modals.css",
+    "css/utils.css",
+    "css/pages.css",
+];
+
 pub fn base_layout(title: &str, content: Markup) -> Markup {
     html! {
         (DOCTYPE)
@@ -13,18 +44,17 @@ pub fn base_layout(title: &str, content: Markup) -> Markup {
                 meta charset="utf-8";
                 meta name="viewport" content="width=device-width, initial-scale=1.0";
                 title { (title) " - Hockey Management" }
+                // Preload critical assets so they download in parallel
+                link rel="preconnect" href=(assets::FLAG_ORIGIN);
+                @for (url, kind) in assets::preload_assets() {
+                    link rel="preload" href=(url) as=(kind);
+                }
                 // CSS Files
-                link rel="stylesheet" href="/static/css/theme.css";
-                link rel="stylesheet" href="/static/css/reset.css";
-                link rel="stylesheet" href="/static/css/layout.css";
-                link rel="stylesheet" href="/static/css/components.css";
-                link rel="stylesheet" href="/static/css/forms.css";
-                link rel="stylesheet" href="/static/css/tables.css";
-                link rel="stylesheet" href="/static/css/modals.css";
-                link rel="stylesheet" href="/static/css/utils.css";
-                link rel="stylesheet" href="/static/css/pages.css";
+                @for path in STYLESHEETS {
+                    link rel="stylesheet" href=(assets::asset_url(path));
+                }
                 // HTMX library for dynamic HTML updates
-                script src="https://unpkg.com/htmx.org@2.0.4" {}
+                script src=(assets::HTMX_SCRIPT_URL) {}
                 // Import map for Lit web components
                 script type="importmap" {
                     (maud::PreEscaped(r#"
@@ -43,17 +73,9 @@ pub fn base_layout(title: &str, content: Markup) -> Markup {
                     "#))
                 }
                 // Web Components
-                script type="module" src="/static/js/components/country-selector.js" {}
-                script type="module" src="/static/js/components/badge.js" {}
-                script type="module" src="/static/js/components/flag-icon.js" {}
-                script type="module" src="/static/js/components/toggle-switch.js" {}
-                script type="module" src="/static/js/components/client-data-table.js" {}
-                script type="module" src="/static/js/components/countries-table.js" {}
-                script type="module" src="/static/js/components/loading-spinner.js" {}
-                script type="module" src="/static/js/components/loading-state.js" {}
-                script type="module" src="/static/js/components/toast.js" {}
-                script type="module" src="/static/js/components/confirm-dialog.js" {}
-                script type="module" src="/static/js/components/modal.js" {}
+                @for path in COMPONENT_SCRIPTS {
+                    script type="module" src=(assets::asset_url(path)) {}
+                }
             }
             body {
                 (content)
@@ -103,6 +125,8 @@ pub fn admin_layout(
                 {}
                 (sidebar(session, current_path, t))
                 main class="main-content" {
+                    (connection_banner(t))
+                    (session_expiry_banner(session, t))
                     div class="content-wrapper" {
                         (content)
                     }
@@ -110,10 +134,133 @@ pub fn admin_layout(
             }
             // Modal container for HTMX modal loading
             div id="modal-container" {}
+            (whats_new_loader())
+        },
+    )
+}
+
+/// Layout of the public read-only site: a plain header instead of the sidebar
+pub fn public_layout(title: &str, t: &TranslationContext, content: Markup) -> Markup {
+    base_layout(
+        title,
+        html! {
+            header style="background: white; border-bottom: 1px solid var(--gray-200);" {
+                div class="container" style="display: flex; justify-content: space-between; align-items: center; padding-top: 1rem; padding-bottom: 1rem;" {
+                    a href="/public" style="font-size: 1.25rem; font-weight: 700; color: inherit; text-decoration: none;" {
+                        "🏒 " (t.messages.public_site_name())
+                    }
+                    a href="/auth/login" class="btn btn-secondary btn-sm" {
+                        (t.messages.public_sign_in())
+                    }
+                }
+            }
+            main class="container" style="padding-top: 2rem; padding-bottom: 2rem;" {
+                (content)
+            }
         },
     )
 }
 
+/// Styles of the embeddable widgets, inlined so a widget is a single request
+const WIDGET_STYLE: &str = r#"
+body { margin: 0; font: 14px/1.4 system-ui, sans-serif; color: #1f2937; background: transparent; }
+h1 { font-size: 1rem; margin: 0 0 0.5rem; }
+h2 { font-size: 0.875rem; margin: 0.75rem 0 0.25rem; color: #4b5563; }
+table { width: 100%; border-collapse: collapse; }
+th, td { padding: 0.25rem 0.5rem; border-bottom: 1px solid #e5e7eb; text-align: left; }
+th { font-size: 0.75rem; color: #6b7280; text-transform: uppercase; }
+.num { text-align: right; }
+.score { text-align: center; font-weight: 600; white-space: nowrap; }
+.muted { color: #6b7280; }
+footer { margin-top: 0.5rem; font-size: 0.75rem; }
+a { color: #2563eb; text-decoration: none; }
+"#;
+
+/// Bare document for widgets embedded in other sites via iframe
+///
+/// No scripts and no navigation. Links open outside the frame. With `refresh`
+/// set the page reloads itself every that many seconds.
+pub fn widget_layout(title: &str, refresh: Option<u32>, content: Markup) -> Markup {
+    html! {
+        (DOCTYPE)
+        html lang="en" {
+            head {
+                meta charset="utf-8";
+                meta name="viewport" content="width=device-width, initial-scale=1.0";
+                @if let Some(seconds) = refresh {
+                    meta http-equiv="refresh" content=(seconds);
+                }
+                title { (title) }
+                base target="_blank";
+                style { (maud::PreEscaped(WIDGET_STYLE)) }
+            }
+            body { (content) }
+        }
+    }
+}
+
+/// Seconds before session expiry at which the warning banner appears
+const SESSION_EXPIRY_WARNING_SECONDS: i64 = 5 * 60;
+
+/// Hidden banner that warns the user shortly before their session expires
+///
+/// Expiry is tracked client-side from relative offsets so clock skew does not
+/// matter. HTMX requests renew the session server-side, so each completed
+/// request pushes the idle deadline forward again (up to the absolute limit).
+fn session_expiry_banner(session: &Session, t: &TranslationContext) -> Markup {
+    let now = chrono::Utc::now();
+    let expires_in = (session.expires_at - now).num_seconds().max(0);
+    let absolute_in = (session.absolute_expires_at - now).num_seconds().max(0);
+
+    html! {
+        div
+            id="session-expiry-banner"
+            class="session-expiry-banner"
+            role="alert"
+            hidden
+            data-expires-in=(expires_in)
+            data-absolute-in=(absolute_in)
+            data-warning=(SESSION_EXPIRY_WARNING_SECONDS)
+        {
+            span { (t.messages.session_expiry_warning()) }
+            button
+                type="button"
+                class="btn btn-sm btn-primary"
+                hx-get="/auth/keepalive"
+                hx-swap="none"
+            {
+                (t.messages.session_stay_signed_in())
+            }
+        }
+        script {
+            (maud::PreEscaped(r#"
+(function() {
+    var banner = document.getElementById('session-expiry-banner');
+    if (!banner) return;
+    var loadedAt = Date.now();
+    var idleMs = Number(banner.dataset.expiresIn) * 1000;
+    var absoluteAt = loadedAt + Number(banner.dataset.absoluteIn) * 1000;
+    var warningMs = Number(banner.dataset.warning) * 1000;
+    var timer;
+
+    function schedule(expiresAt) {
+        clearTimeout(timer);
+        banner.hidden = true;
+        timer = setTimeout(function() { banner.hidden = false; }, Math.max(0, expiresAt - warningMs - Date.now()));
+    }
+
+    schedule(Math.min(loadedAt + idleMs, absoluteAt));
+    document.body.addEventListener('htmx:afterRequest', function(evt) {
+        if (evt.detail.successful) {
+            schedule(Math.min(Date.now() + idleMs, absoluteAt));
+        }
+    });
+})();
+            "#))
+        }
+    }
+}
+
 pub fn auth_layout(title: &str, content: Markup) -> Markup {
     base_layout(
         title,
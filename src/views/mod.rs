@@ -1,3 +1,4 @@
+pub mod calendar;
 pub mod components;
 pub mod layout;
 pub mod pages;
@@ -0,0 +1,89 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::arenas::ArenaEntity;
+use crate::service::matches::AttendanceEntity;
+use crate::views::pages::attendance::attendance_section;
+
+/// Arena detail page with attendance per season
+pub fn arena_detail_page(
+    t: &TranslationContext,
+    arena: &ArenaEntity,
+    attendance: &[AttendanceEntity],
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="display: flex; align-items: center; gap: 1rem; margin-bottom: 1.5rem;" {
+                a href="/arenas" class="btn btn-secondary" {
+                    (format!("← {}", t.messages.arenas_back_to_list()))
+                }
+                h1 style="font-size: 2rem; font-weight: 700; margin: 0;" { (arena.name) }
+            }
+
+            (arena_info_card(t, arena))
+
+            (attendance_section(
+                t,
+                &t.messages.attendance_season().to_string(),
+                attendance,
+                |season_id| format!("/seasons/{}", season_id),
+            ))
+        }
+    }
+}
+
+/// Arena info card with location, capacity, and match count
+fn arena_info_card(t: &TranslationContext, arena: &ArenaEntity) -> Markup {
+    html! {
+        div style="padding: 1.5rem; background: var(--gray-50); border-radius: 8px;" {
+            div style="display: grid; grid-template-columns: repeat(auto-fit, minmax(200px, 1fr)); gap: 1rem;" {
+                div {
+                    div style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 0.25rem;" {
+                        (t.messages.arenas_city())
+                    }
+                    div style="font-weight: 600;" {
+                        @if let Some(city) = &arena.city {
+                            (city)
+                        } @else {
+                            span class="no-value-text" { "-" }
+                        }
+                    }
+                }
+                div {
+                    div style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 0.25rem;" {
+                        (t.messages.form_country())
+                    }
+                    div style="font-weight: 600;" {
+                        @if let Some(country_name) = &arena.country_name {
+                            (country_name)
+                        } @else {
+                            span class="no-value-text" { (t.messages.common_no_country()) }
+                        }
+                    }
+                }
+                div {
+                    div style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 0.25rem;" {
+                        (t.messages.arenas_capacity())
+                    }
+                    div style="font-weight: 600;" {
+                        @if let Some(capacity) = arena.capacity {
+                            (capacity)
+                        } @else {
+                            span class="no-value-text" { "-" }
+                        }
+                    }
+                }
+                div {
+                    div style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 0.25rem;" {
+                        (t.messages.arenas_matches())
+                    }
+                    div style="font-weight: 600;" {
+                        a href=(format!("/matches?arena_id={}", arena.id)) class="primary-link" {
+                            (arena.match_count)
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
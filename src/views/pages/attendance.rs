@@ -0,0 +1,74 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::matches::{average_attendance, AttendanceEntity};
+
+/// Attendance table with a totals row, shown on season and arena detail pages
+///
+/// `group_label` heads the first column and `group_href` builds the link of
+/// each group; groups without an ID (matches without an arena) are not linked.
+pub fn attendance_section(
+    t: &TranslationContext,
+    group_label: &str,
+    rows: &[AttendanceEntity],
+    group_href: impl Fn(i64) -> String,
+) -> Markup {
+    let matches: i64 = rows.iter().map(|r| r.matches).sum();
+    let total: i64 = rows.iter().map(|r| r.total).sum();
+
+    html! {
+        div style="margin-top: 2rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin-bottom: 1rem;" {
+                (t.messages.attendance_title())
+            }
+
+            @if rows.is_empty() {
+                div style="padding: 1rem; background: var(--gray-100); border-radius: 4px; color: var(--gray-600); text-align: center;" {
+                    (t.messages.attendance_empty())
+                }
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (group_label) }
+                            th style="text-align: right;" { (t.messages.attendance_matches()) }
+                            th style="text-align: right;" { (t.messages.attendance_total()) }
+                            th style="text-align: right;" { (t.messages.attendance_average()) }
+                        }
+                    }
+                    tbody {
+                        @for row in rows {
+                            tr {
+                                td {
+                                    @match (row.group_id, &row.group_name) {
+                                        (Some(id), Some(name)) => {
+                                            a href=(group_href(id)) class="primary-link" { (name) }
+                                        }
+                                        _ => {
+                                            span style="color: var(--gray-400); font-style: italic;" {
+                                                (t.messages.matches_no_arena())
+                                            }
+                                        }
+                                    }
+                                }
+                                td style="text-align: right;" { (row.matches) }
+                                td style="text-align: right;" { (row.total) }
+                                td style="text-align: right;" {
+                                    @if let Some(average) = row.average() { (average) }
+                                }
+                            }
+                        }
+                        tr style="font-weight: 600;" {
+                            td { (t.messages.attendance_overall()) }
+                            td style="text-align: right;" { (matches) }
+                            td style="text-align: right;" { (total) }
+                            td style="text-align: right;" {
+                                @if let Some(average) = average_attendance(total, matches) { (average) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,9 +1,22 @@
 use maud::{html, Markup};
 
+use crate::auth::oauth::OAuthProvider;
 use crate::i18n::TranslationContext;
 use crate::views::layout::auth_layout;
 
-pub fn login_page(t: &TranslationContext, error: Option<String>) -> Markup {
+/// What the sign-in page offers besides email and password
+#[derive(Debug, Clone, Default)]
+pub struct LoginOptions {
+    /// Button for the shared read-only demo account
+    pub demo_mode: bool,
+    /// Link to the sign-up form
+    pub registration_open: bool,
+    /// "Sign in with ..." buttons
+    pub oauth_providers: Vec<OAuthProvider>,
+}
+
+/// Sign-in page
+pub fn login_page(t: &TranslationContext, options: &LoginOptions, error: Option<String>) -> Markup {
     auth_layout(
         &t.messages.signin_title().to_string(),
         html! {
@@ -44,7 +57,219 @@ pub fn login_page(t: &TranslationContext, error: Option<String>) -> Markup {
                         (t.messages.signin_button())
                     }
                 }
+
+                p style="margin-top: 1rem; text-align: center; font-size: 0.875rem;" {
+                    a href="/auth/forgot-password" { (t.messages.reset_forgot_link()) }
+                }
+
+                @if !options.oauth_providers.is_empty() {
+                    div style="margin-top: 1.5rem; padding-top: 1.5rem; border-top: 1px solid var(--gray-200); display: flex; flex-direction: column; gap: 0.5rem;" {
+                        @for provider in &options.oauth_providers {
+                            a class="btn btn-secondary"
+                                href=(format!("/auth/oauth/{}", provider.as_str()))
+                                style="width: 100%; box-sizing: border-box; text-decoration: none;" {
+                                (t.messages.signin_with()) " " (provider.label())
+                            }
+                        }
+                    }
+                }
+
+                @if options.registration_open {
+                    p style="margin-top: 1rem; text-align: center; font-size: 0.875rem; color: var(--gray-600);" {
+                        (t.messages.register_prompt()) " "
+                        a href="/auth/register" { (t.messages.register_link()) }
+                    }
+                }
+
+                @if options.demo_mode {
+                    form method="POST" action="/auth/demo" style="margin-top: 1.5rem; padding-top: 1.5rem; border-top: 1px solid var(--gray-200);" {
+                        p style="margin-bottom: 0.75rem; color: var(--gray-600); font-size: 0.875rem;" {
+                            (t.messages.signin_demo_hint())
+                        }
+                        button type="submit" class="btn-secondary" style="width: 100%;" {
+                            (t.messages.signin_demo_button())
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+/// Sign-up form; `email` and `name` refill the form after an error
+pub fn register_page(
+    t: &TranslationContext,
+    email: &str,
+    name: &str,
+    error: Option<&str>,
+) -> Markup {
+    auth_layout(
+        &t.messages.register_title().to_string(),
+        html! {
+            div.card {
+                h1 style="margin-bottom: 1.5rem; font-size: 1.875rem; font-weight: 700;" {
+                    (t.messages.register_title())
+                }
+
+                @if let Some(err) = error {
+                    div.error {
+                        (err)
+                    }
+                }
+
+                form method="POST" action="/auth/register" {
+                    div.form-group {
+                        label for="email" { (t.messages.signin_email()) }
+                        input
+                            type="email"
+                            id="email"
+                            name="email"
+                            value=(email)
+                            required
+                            autofocus
+                            maxlength="254"
+                            placeholder="your@email.com";
+                    }
+
+                    div.form-group {
+                        label for="name" { (t.messages.register_name()) }
+                        input type="text" id="name" name="name" value=(name) required maxlength="100";
+                    }
+
+                    div.form-group {
+                        label for="password" { (t.messages.password_new()) }
+                        input type="password" id="password" name="password" required minlength="8" autocomplete="new-password";
+                    }
+
+                    div.form-group {
+                        label for="confirm_password" { (t.messages.password_confirm()) }
+                        input type="password" id="confirm_password" name="confirm_password" required minlength="8" autocomplete="new-password";
+                    }
+
+                    button type="submit" style="width: 100%; margin-top: 0.5rem;" {
+                        (t.messages.register_button())
+                    }
+                }
+
+                p style="margin-top: 1rem; text-align: center; font-size: 0.875rem;" {
+                    a href="/auth/login" { (t.messages.register_back_to_signin()) }
+                }
+            }
+        },
+    )
+}
+
+/// Message page of the sign-up and password reset flows (check your inbox,
+/// link confirmed, ...)
+pub fn auth_notice_page(t: &TranslationContext, title: &str, message: &str) -> Markup {
+    auth_layout(
+        title,
+        html! {
+            div.card {
+                h1 style="margin-bottom: 1rem; font-size: 1.875rem; font-weight: 700;" {
+                    (title)
+                }
+                p style="margin-bottom: 1.5rem; color: var(--gray-600);" {
+                    (message)
+                }
+                a href="/auth/login" { (t.messages.register_back_to_signin()) }
+            }
+        },
+    )
+}
+
+/// Form asking for the email address to send a password reset link to
+pub fn forgot_password_page(t: &TranslationContext) -> Markup {
+    auth_layout(
+        &t.messages.reset_request_title().to_string(),
+        html! {
+            div.card {
+                h1 style="margin-bottom: 1rem; font-size: 1.875rem; font-weight: 700;" {
+                    (t.messages.reset_request_title())
+                }
+                p style="margin-bottom: 1.5rem; color: var(--gray-600);" {
+                    (t.messages.reset_request_hint())
+                }
+
+                form method="POST" action="/auth/forgot-password" {
+                    div.form-group {
+                        label for="email" { (t.messages.signin_email()) }
+                        input
+                            type="email"
+                            id="email"
+                            name="email"
+                            required
+                            autofocus
+                            maxlength="254"
+                            placeholder="your@email.com";
+                    }
+
+                    button type="submit" style="width: 100%; margin-top: 0.5rem;" {
+                        (t.messages.reset_request_button())
+                    }
+                }
+
+                p style="margin-top: 1rem; text-align: center; font-size: 0.875rem;" {
+                    a href="/auth/login" { (t.messages.register_back_to_signin()) }
+                }
             }
         },
     )
 }
+
+/// Form choosing a new password with the emailed `token`
+pub fn reset_password_page(t: &TranslationContext, token: &str, error: Option<&str>) -> Markup {
+    auth_layout(
+        &t.messages.reset_title().to_string(),
+        html! {
+            div.card {
+                h1 style="margin-bottom: 1.5rem; font-size: 1.875rem; font-weight: 700;" {
+                    (t.messages.reset_title())
+                }
+
+                @if let Some(err) = error {
+                    div.error {
+                        (err)
+                    }
+                }
+
+                form method="POST" action=(format!("/auth/reset-password/{}", token)) {
+                    div.form-group {
+                        label for="password" { (t.messages.password_new()) }
+                        input type="password" id="password" name="password" required autofocus minlength="8" autocomplete="new-password";
+                    }
+
+                    div.form-group {
+                        label for="confirm_password" { (t.messages.password_confirm()) }
+                        input type="password" id="confirm_password" name="confirm_password" required minlength="8" autocomplete="new-password";
+                    }
+
+                    button type="submit" style="width: 100%; margin-top: 0.5rem;" {
+                        (t.messages.reset_button())
+                    }
+                }
+            }
+        },
+    )
+}
+
+/// Continue to `url` from a page of this site
+///
+/// Used after the provider sends the user back: the `SameSite=Strict`
+/// session cookie set by the callback would not be sent on a redirect that
+/// is still part of the navigation coming from the provider.
+pub fn continue_page(url: &str) -> Markup {
+    html! {
+        (maud::DOCTYPE)
+        html {
+            head {
+                meta charset="utf-8";
+                meta http-equiv="refresh" content=(format!("0;url={}", url));
+                title { "Hockey" }
+            }
+            body {
+                a href=(url) { "Continue" }
+            }
+        }
+    }
+}
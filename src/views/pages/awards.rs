@@ -0,0 +1,179 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::awards::{AwardType, PlayerAwardEntity, SeasonAwardEntity};
+use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
+use crate::views::components::crud::modal_form_i18n;
+use crate::views::components::forms::csrf_token_field;
+
+/// Translated name of an award
+pub fn award_label(t: &TranslationContext, award: AwardType) -> String {
+    match award {
+        AwardType::Mvp => t.messages.awards_mvp().to_string(),
+        AwardType::BestForward => t.messages.awards_best_forward().to_string(),
+        AwardType::BestDefenseman => t.messages.awards_best_defenseman().to_string(),
+        AwardType::BestGoalie => t.messages.awards_best_goalie().to_string(),
+        AwardType::AllStar => t.messages.awards_all_star().to_string(),
+    }
+}
+
+/// Awards section for the season detail page, one row per award
+pub fn awards_section(
+    session: &Session,
+    t: &TranslationContext,
+    season_id: i64,
+    awards: &[SeasonAwardEntity],
+) -> Markup {
+    html! {
+        div style="margin-top: 2rem;" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1rem;" {
+                h2 style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
+                    (t.messages.awards_title())
+                }
+                button
+                    class="btn btn-primary"
+                    hx-get=(format!("/seasons/{}/awards/new", season_id))
+                    hx-target="#modal-container"
+                    hx-swap="innerHTML"
+                {
+                    (format!("+ {}", t.messages.awards_add()))
+                }
+            }
+
+            @if awards.is_empty() {
+                p style="color: var(--gray-600);" { (t.messages.awards_empty()) }
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.awards_award()) }
+                            th { (t.messages.awards_player()) }
+                            th style="width: 1%;" {}
+                        }
+                    }
+                    tbody {
+                        @for award in awards {
+                            tr {
+                                td style="font-weight: 600;" {
+                                    @if let Some(award_type) = award.award_type() {
+                                        (award_label(t, award_type))
+                                    } @else {
+                                        (award.award)
+                                    }
+                                }
+                                td {
+                                    a href=(format!("/players/{}", award.player_id)) class="primary-link" {
+                                        (award.player_name)
+                                    }
+                                }
+                                td {
+                                    form style="display: inline;" {
+                                        (csrf_token_field(&session.csrf_token))
+                                        button
+                                            type="submit"
+                                            class="btn btn-sm btn-danger"
+                                            hx-post=(format!("/season-awards/{}/delete", award.id))
+                                            hx-confirm-custom=(confirm_attrs(
+                                                &format!("{} \"{}\"", t.messages.common_delete(), award.player_name),
+                                                &t.messages.awards_confirm_delete().to_string(),
+                                                ConfirmVariant::Danger,
+                                                Some(&t.messages.common_delete().to_string()),
+                                                Some(&t.messages.common_cancel().to_string())
+                                            ))
+                                        {
+                                            (t.messages.common_delete())
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Modal form to give an award to a player of the season
+///
+/// `candidates` are the players on the season's rosters as `(id, label)`.
+pub fn award_create_modal(
+    session: &Session,
+    t: &TranslationContext,
+    season_id: i64,
+    candidates: &[(i64, String)],
+    error: Option<&str>,
+) -> Markup {
+    let form_fields = html! {
+        (csrf_token_field(&session.csrf_token))
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.awards_award())
+                span class="required-indicator" { " *" }
+            }
+            select name="award" required {
+                @for award in AwardType::ALL {
+                    option value=(award.as_str()) { (award_label(t, award)) }
+                }
+            }
+        }
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.awards_player())
+                span class="required-indicator" { " *" }
+            }
+            @if candidates.is_empty() {
+                p style="color: var(--gray-600);" { (t.messages.awards_no_candidates()) }
+            } @else {
+                select name="player_id" required {
+                    @for (player_id, label) in candidates {
+                        option value=(player_id) { (label) }
+                    }
+                }
+            }
+        }
+    };
+
+    modal_form_i18n(
+        "award-modal",
+        &t.messages.awards_add().to_string(),
+        error,
+        &format!("/seasons/{}/awards", season_id),
+        form_fields,
+        &t.messages.common_add().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Awards a player has won, for the player detail page
+pub fn player_awards_section(t: &TranslationContext, awards: &[PlayerAwardEntity]) -> Markup {
+    html! {
+        div style="margin-top: 2rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin: 0 0 1.5rem 0;" {
+                (t.messages.awards_player_title())
+            }
+            div style="display: flex; flex-wrap: wrap; gap: 0.75rem;" {
+                @for award in awards {
+                    div style="padding: 0.75rem 1rem; border: 1px solid var(--gray-200); border-radius: 8px; background: white;" {
+                        div style="font-weight: 600;" {
+                            "🏆 "
+                            @if let Some(award_type) = award.award_type() {
+                                (award_label(t, award_type))
+                            } @else {
+                                (award.award)
+                            }
+                        }
+                        a
+                            href=(format!("/seasons/{}", award.season_id))
+                            style="color: var(--gray-600); font-size: 0.875rem; text-decoration: none;"
+                        {
+                            (award.event_name) " "
+                            (award.season_display_name.clone().unwrap_or_else(|| award.season_year.to_string()))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
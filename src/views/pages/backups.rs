@@ -0,0 +1,127 @@
+use chrono::{DateTime, Utc};
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::maintenance::{BackupFile, MaintenanceConfig};
+use crate::views::components::crud::empty_state_i18n;
+use crate::views::components::forms::csrf_token_field;
+use crate::views::pages::status::{format_bytes, status_tile};
+
+/// Backups page: schedule, retention and the backups on disk
+pub fn backups_page(
+    session: &Session,
+    t: &TranslationContext,
+    maintenance: &MaintenanceConfig,
+    next_backup: Option<DateTime<Utc>>,
+    backups: &[BackupFile],
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
+                div {
+                    h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                        (t.messages.backups_title())
+                    }
+                    p style="color: var(--gray-600);" {
+                        (t.messages.backups_description())
+                    }
+                }
+                @if maintenance.backup_dir.is_some() {
+                    form style="display: inline;" {
+                        (csrf_token_field(&session.csrf_token))
+                        button
+                            type="submit"
+                            class="btn btn-primary"
+                            hx-post="/management/backups"
+                            hx-target="#backup-list"
+                            hx-swap="outerHTML"
+                            hx-disabled-elt="this"
+                        {
+                            (t.messages.backups_run_now())
+                        }
+                    }
+                }
+            }
+
+            @match &maintenance.backup_dir {
+                None => {
+                    (empty_state_i18n(
+                        &t.messages.backups_disabled_title().to_string(),
+                        &t.messages.backups_disabled_message().to_string(),
+                        false
+                    ))
+                }
+                Some(dir) => {
+                    div style="display: grid; grid-template-columns: repeat(auto-fit, minmax(220px, 1fr)); gap: 1rem; margin-bottom: 2rem;" {
+                        (status_tile(&t.messages.backups_directory().to_string(), &dir.display().to_string()))
+                        (status_tile(
+                            &t.messages.backups_interval().to_string(),
+                            &format!("{} h", maintenance.backup_interval_hours)
+                        ))
+                        (status_tile(
+                            &t.messages.backups_keep().to_string(),
+                            &if maintenance.backup_keep > 0 {
+                                maintenance.backup_keep.to_string()
+                            } else {
+                                t.messages.backups_keep_all().to_string()
+                            }
+                        ))
+                        @if let Some(next_backup) = next_backup {
+                            (status_tile(
+                                &t.messages.backups_next().to_string(),
+                                &next_backup.format("%Y-%m-%d %H:%M UTC").to_string()
+                            ))
+                        }
+                    }
+
+                    (backup_list(t, backups))
+                }
+            }
+        }
+    }
+}
+
+/// Backups on disk, newest first (also returned after a manual backup)
+pub fn backup_list(t: &TranslationContext, backups: &[BackupFile]) -> Markup {
+    html! {
+        div id="backup-list" {
+            @if backups.is_empty() {
+                (empty_state_i18n(
+                    &t.messages.backups_empty_title().to_string(),
+                    &t.messages.backups_empty_message().to_string(),
+                    false
+                ))
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.backups_file()) }
+                            th { (t.messages.backups_created()) }
+                            th style="text-align: right;" { (t.messages.backups_size()) }
+                            th {}
+                        }
+                    }
+                    tbody {
+                        @for backup in backups {
+                            tr {
+                                td style="font-family: monospace;" { (backup.name) }
+                                td style="white-space: nowrap;" {
+                                    (backup.modified_at.format("%Y-%m-%d %H:%M UTC"))
+                                }
+                                td style="text-align: right; white-space: nowrap;" {
+                                    (format_bytes(backup.size_bytes as i64))
+                                }
+                                td style="text-align: right;" {
+                                    a href=(format!("/management/backups/{}", backup.name)) class="btn btn-sm btn-secondary" download {
+                                        (t.messages.backups_download())
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::calendar_subscriptions::CalendarSubscriptionEntity;
+use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
+use crate::views::components::forms::csrf_token_field;
+
+/// Calendar subscriptions of a team: create and revoke feed URLs
+///
+/// Rendered as a partial so creating or revoking swaps in the updated list.
+pub fn calendar_subscriptions_section(
+    session: &Session,
+    t: &TranslationContext,
+    team_id: i64,
+    subscriptions: &[CalendarSubscriptionEntity],
+    base_url: &str,
+    error: Option<&str>,
+) -> Markup {
+    let input_style = "padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
+
+    html! {
+        div id="calendar-subscriptions" style="margin-top: 2rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                (t.messages.calendar_subscriptions_title())
+            }
+            p style="color: var(--gray-600); margin-bottom: 1rem;" {
+                (t.messages.calendar_subscriptions_description())
+            }
+
+            @if let Some(error) = error {
+                div class="error" style="padding: 0.75rem 1rem; margin-bottom: 1rem;" { (error) }
+            }
+
+            form
+                hx-post=(format!("/teams/{}/calendar-subscriptions", team_id))
+                hx-target="#calendar-subscriptions"
+                hx-swap="outerHTML"
+                style="display: flex; gap: 0.5rem; margin-bottom: 1rem;"
+            {
+                (csrf_token_field(&session.csrf_token))
+                input
+                    type="text"
+                    name="label"
+                    maxlength="100"
+                    placeholder=(t.messages.calendar_subscription_label_placeholder())
+                    style=(format!("flex: 1; {}", input_style));
+                button type="submit" class="btn btn-primary" {
+                    (t.messages.calendar_subscription_create())
+                }
+            }
+
+            @if subscriptions.is_empty() {
+                div style="padding: 1rem; background: var(--gray-100); border-radius: 4px; color: var(--gray-600); text-align: center;" {
+                    (t.messages.calendar_subscriptions_empty())
+                }
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.calendar_subscription_label()) }
+                            th { (t.messages.calendar_subscription_url()) }
+                            th { (t.messages.calendar_subscription_created_at()) }
+                            th { (t.messages.calendar_subscription_last_used()) }
+                            th style="text-align: right;" { "Actions" }
+                        }
+                    }
+                    tbody {
+                        @for subscription in subscriptions {
+                            @let url = format!("{}/calendar/{}.ics", base_url, subscription.token);
+                            tr {
+                                td style="font-weight: 500;" {
+                                    @if let Some(label) = &subscription.label {
+                                        (label)
+                                    } @else {
+                                        span style="color: var(--gray-400);" { "-" }
+                                    }
+                                }
+                                td style="min-width: 16rem;" {
+                                    input
+                                        type="text"
+                                        readonly
+                                        value=(url)
+                                        onclick="this.select()"
+                                        style=(format!("width: 100%; font-family: monospace; font-size: 0.8125rem; {}", input_style));
+                                }
+                                td style="white-space: nowrap;" { (subscription.created_at) }
+                                td style="white-space: nowrap;" {
+                                    @if let Some(last_used) = &subscription.last_used_at {
+                                        (last_used)
+                                    } @else {
+                                        span style="color: var(--gray-400);" { (t.messages.calendar_subscription_never_used()) }
+                                    }
+                                }
+                                td style="text-align: right; white-space: nowrap;" {
+                                    a
+                                        class="btn btn-sm"
+                                        style="margin-right: 0.5rem;"
+                                        href=(url.replacen("https://", "webcal://", 1).replacen("http://", "webcal://", 1))
+                                    {
+                                        (t.messages.calendar_subscription_subscribe())
+                                    }
+                                    form style="display: inline;" {
+                                        (csrf_token_field(&session.csrf_token))
+                                        button
+                                            type="submit"
+                                            class="btn btn-sm btn-danger"
+                                            hx-post=(format!("/teams/{}/calendar-subscriptions/{}/revoke", team_id, subscription.id))
+                                            hx-target="#calendar-subscriptions"
+                                            hx-swap="outerHTML"
+                                            hx-confirm-custom=(confirm_attrs(
+                                                &t.messages.calendar_subscription_revoke().to_string(),
+                                                &t.messages.calendar_subscription_confirm_revoke().to_string(),
+                                                ConfirmVariant::Danger,
+                                                Some(&t.messages.calendar_subscription_revoke().to_string()),
+                                                Some(&t.messages.common_cancel().to_string())
+                                            ))
+                                        {
+                                            (t.messages.calendar_subscription_revoke())
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::change_history::{
+    ChangeHistoryEntity, FieldChange, HistoryAction, HistoryEntityType,
+};
+use crate::service::matches::{GameType, MatchEntity};
+use crate::service::players::PlayerEntity;
+use crate::views::pages::matches::game_type_label;
+
+/// History page of a match, including changes to its goals
+pub fn match_history_page(
+    t: &TranslationContext,
+    match_entity: &MatchEntity,
+    history: &[ChangeHistoryEntity],
+) -> Markup {
+    let title = format!(
+        "{} vs {}",
+        match_entity.home_team_name, match_entity.away_team_name
+    );
+    history_page(
+        t,
+        &format!("/matches/{}", match_entity.id),
+        &t.messages.history_back_to_match().to_string(),
+        &title,
+        history,
+    )
+}
+
+/// History page of a player
+pub fn player_history_page(
+    t: &TranslationContext,
+    player: &PlayerEntity,
+    history: &[ChangeHistoryEntity],
+) -> Markup {
+    history_page(
+        t,
+        &format!("/players/{}", player.id),
+        &t.messages.players_back_to_detail().to_string(),
+        &player.name,
+        history,
+    )
+}
+
+fn history_page(
+    t: &TranslationContext,
+    back_url: &str,
+    back_label: &str,
+    title: &str,
+    history: &[ChangeHistoryEntity],
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="display: flex; align-items: center; gap: 1rem; margin-bottom: 1.5rem;" {
+                a href=(back_url) class="btn btn-secondary" {
+                    (format!("← {}", back_label))
+                }
+                h1 style="font-size: 2rem; font-weight: 700; margin: 0;" {
+                    (title)
+                    " - "
+                    (t.messages.history_title())
+                }
+            }
+
+            @if history.is_empty() {
+                p style="color: var(--gray-600);" { (t.messages.history_empty()) }
+            } @else {
+                div style="display: flex; flex-direction: column; gap: 1rem;" {
+                    @for entry in history {
+                        (history_entry(t, entry))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One change: who, when, what, and a before/after table of the fields
+fn history_entry(t: &TranslationContext, entry: &ChangeHistoryEntity) -> Markup {
+    html! {
+        div style="padding: 1rem; background: var(--gray-50); border-radius: 8px;" {
+            div style="display: flex; justify-content: space-between; gap: 1rem; margin-bottom: 0.75rem;" {
+                div style="font-weight: 600;" {
+                    (entry_title(t, entry))
+                    @if entry.entity_type() == Some(HistoryEntityType::ScoreEvent) {
+                        span style="color: var(--gray-500); font-weight: 400;" {
+                            (format!(" #{}", entry.entity_id))
+                        }
+                    }
+                }
+                div style="color: var(--gray-600); font-size: 0.875rem; white-space: nowrap;" {
+                    @if let Some(user_name) = &entry.user_name {
+                        (user_name)
+                    } @else {
+                        (t.messages.history_unknown_user())
+                    }
+                    " · "
+                    (entry.created_at)
+                }
+            }
+            @if !entry.changes.is_empty() {
+                table class="table" {
+                    thead {
+                        tr {
+                            th style="width: 30%;" { (t.messages.history_field()) }
+                            th { (t.messages.history_before()) }
+                            th { (t.messages.history_after()) }
+                        }
+                    }
+                    tbody {
+                        @for change in &entry.changes {
+                            (change_row(t, change))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn change_row(t: &TranslationContext, change: &FieldChange) -> Markup {
+    let value = |value: &Option<String>| match value {
+        Some(value) => html! { (value_label(t, &change.field, value)) },
+        None => html! { span style="color: var(--gray-400);" { "—" } },
+    };
+
+    html! {
+        tr {
+            td { (field_label(t, &change.field)) }
+            td style="color: var(--gray-600);" { (value(&change.old)) }
+            td style="font-weight: 600;" { (value(&change.new)) }
+        }
+    }
+}
+
+fn entry_title(t: &TranslationContext, entry: &ChangeHistoryEntity) -> String {
+    match (entry.entity_type(), entry.action()) {
+        (Some(HistoryEntityType::Match), _) => t.messages.history_match_updated().to_string(),
+        (Some(HistoryEntityType::Player), _) => t.messages.history_player_updated().to_string(),
+        (_, Some(HistoryAction::Create)) => t.messages.history_goal_created().to_string(),
+        (_, Some(HistoryAction::Delete)) => t.messages.history_goal_deleted().to_string(),
+        _ => t.messages.history_goal_updated().to_string(),
+    }
+}
+
+/// Translated name of a tracked field (see the snapshots in `service::change_history`)
+fn field_label(t: &TranslationContext, field: &str) -> String {
+    match field {
+        "season" => t.messages.history_field_season().to_string(),
+        "home_team" => t.messages.history_field_home_team().to_string(),
+        "away_team" => t.messages.history_field_away_team().to_string(),
+        "home_score_unidentified" => t.messages.history_field_home_score().to_string(),
+        "away_score_unidentified" => t.messages.history_field_away_score().to_string(),
+        "match_date" => t.messages.history_field_match_date().to_string(),
+        "status" => t.messages.history_field_status().to_string(),
+        "game_type" => t.messages.history_field_game_type().to_string(),
+        "arena" => t.messages.history_field_arena().to_string(),
+        "attendance" => t.messages.history_field_attendance().to_string(),
+        "team" => t.messages.history_field_team().to_string(),
+        "scorer" => t.messages.history_field_scorer().to_string(),
+        "assist1" => t.messages.history_field_first_assist().to_string(),
+        "assist2" => t.messages.history_field_second_assist().to_string(),
+        "period" => t.messages.history_field_period().to_string(),
+        "time" => t.messages.history_field_time().to_string(),
+        "goal_type" => t.messages.history_field_goal_type().to_string(),
+        "video" => t.messages.history_field_video().to_string(),
+        "name" => t.messages.history_field_name().to_string(),
+        "country" => t.messages.history_field_country().to_string(),
+        "birth_date" => t.messages.history_field_birth_date().to_string(),
+        "birth_place" => t.messages.history_field_birth_place().to_string(),
+        "height_cm" => t.messages.history_field_height().to_string(),
+        "weight_kg" => t.messages.history_field_weight().to_string(),
+        "position" => t.messages.history_field_position().to_string(),
+        "shoots" => t.messages.history_field_shoots().to_string(),
+        "photo" => t.messages.history_field_photo().to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Translated value for fields stored as codes; other values are shown as stored
+fn value_label(t: &TranslationContext, field: &str, value: &str) -> String {
+    match (field, value) {
+        ("status", "scheduled") => t.messages.matches_status_scheduled().to_string(),
+        ("status", "in_progress") => t.messages.matches_status_in_progress().to_string(),
+        ("status", "finished") => t.messages.matches_status_finished().to_string(),
+        ("status", "cancelled") => t.messages.matches_status_cancelled().to_string(),
+        ("game_type", code) => GameType::from_str(code)
+            .map(|game_type| game_type_label(t, game_type))
+            .unwrap_or_else(|| code.to_string()),
+        _ => value.to_string(),
+    }
+}
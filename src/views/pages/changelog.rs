@@ -0,0 +1,242 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::changelog::ChangelogEntryEntity;
+use crate::views::components::crud::{empty_state_i18n, modal_form_i18n, table_actions_i18n};
+use crate::views::components::forms::{csrf_token_field, form_field, form_textarea, InputType};
+
+const ENTRY_BODY_STYLE: &str = "white-space: pre-line; color: var(--gray-700); margin: 0;";
+
+/// Management page for editing the "What's new" entries
+pub fn changelog_page(
+    session: &Session,
+    t: &TranslationContext,
+    entries: &[ChangelogEntryEntity],
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
+                div {
+                    h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                        (t.messages.changelog_title())
+                    }
+                    p style="color: var(--gray-600);" {
+                        (t.messages.changelog_description())
+                    }
+                }
+                button
+                    class="btn btn-primary"
+                    hx-get="/management/whats-new/new"
+                    hx-target="#modal-container"
+                    hx-swap="innerHTML"
+                {
+                    "+ " (t.messages.changelog_create())
+                }
+            }
+
+            (changelog_list(session, t, entries))
+        }
+    }
+}
+
+/// Table of all entries, newest first (reloaded after changes)
+pub fn changelog_list(
+    session: &Session,
+    t: &TranslationContext,
+    entries: &[ChangelogEntryEntity],
+) -> Markup {
+    html! {
+        div id="changelog-entries" {
+            @if entries.is_empty() {
+                (empty_state_i18n(
+                    &t.messages.changelog_empty_title().to_string(),
+                    &t.messages.changelog_empty_message().to_string(),
+                    false
+                ))
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.changelog_version()) }
+                            th { (t.messages.changelog_entry_title()) }
+                            th { (t.messages.changelog_created()) }
+                            th style="text-align: right;" { (t.messages.common_actions()) }
+                        }
+                    }
+                    tbody {
+                        @for entry in entries {
+                            tr {
+                                td { code { (entry.version) } }
+                                td {
+                                    div style="font-weight: 600;" { (entry.title) }
+                                    p style=(format!("{} font-size: 0.875rem;", ENTRY_BODY_STYLE)) { (entry.body) }
+                                }
+                                td style="white-space: nowrap;" { (entry.created_at) }
+                                (table_actions_i18n(
+                                    &format!("/management/whats-new/{}/edit", entry.id),
+                                    &format!("/management/whats-new/{}/delete", entry.id),
+                                    "changelog-entries",
+                                    &t.messages.common_edit().to_string(),
+                                    &t.messages.common_delete().to_string(),
+                                    &t.messages.changelog_delete_title().to_string(),
+                                    &t.messages.changelog_delete_confirm().to_string(),
+                                    &session.csrf_token
+                                ))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn changelog_form_fields(
+    session: &Session,
+    t: &TranslationContext,
+    version: &str,
+    entry: Option<&ChangelogEntryEntity>,
+) -> Markup {
+    html! {
+        (csrf_token_field(&session.csrf_token))
+        (form_field(
+            "version",
+            &t.messages.changelog_version().to_string(),
+            InputType::Text,
+            Some(version),
+            Some("0.2.0"),
+            true,
+            None,
+            None
+        ))
+        (form_field(
+            "title",
+            &t.messages.changelog_entry_title().to_string(),
+            InputType::Text,
+            entry.map(|e| e.title.as_str()),
+            None,
+            true,
+            None,
+            None
+        ))
+        (form_textarea(
+            "body",
+            &t.messages.changelog_body().to_string(),
+            entry.map(|e| e.body.as_str()),
+            None,
+            6,
+            true,
+            None,
+            Some(&t.messages.changelog_body_help().to_string())
+        ))
+    }
+}
+
+/// Create entry modal, with the version prefilled
+pub fn changelog_create_modal(
+    session: &Session,
+    t: &TranslationContext,
+    version: &str,
+    error: Option<&str>,
+) -> Markup {
+    modal_form_i18n(
+        "changelog-modal",
+        &t.messages.changelog_create().to_string(),
+        error,
+        "/management/whats-new",
+        changelog_form_fields(session, t, version, None),
+        &t.messages.common_create().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Edit entry modal
+pub fn changelog_edit_modal(
+    session: &Session,
+    t: &TranslationContext,
+    entry: &ChangelogEntryEntity,
+    error: Option<&str>,
+) -> Markup {
+    modal_form_i18n(
+        "changelog-modal",
+        &t.messages.changelog_edit().to_string(),
+        error,
+        &format!("/management/whats-new/{}", entry.id),
+        changelog_form_fields(session, t, &entry.version, Some(entry)),
+        &t.messages.common_save().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Placeholder in the admin layout that loads the "What's new" panel
+pub fn whats_new_loader() -> Markup {
+    html! {
+        div hx-get="/whats-new" hx-trigger="load" hx-swap="outerHTML" {}
+    }
+}
+
+/// "What's new" panel with the entries a user has not dismissed yet
+///
+/// Closing the panel dismisses the entries, so it shows once per user.
+pub fn whats_new_panel(
+    session: &Session,
+    t: &TranslationContext,
+    entries: &[ChangelogEntryEntity],
+) -> Markup {
+    html! {
+        div
+            id="whats-new-modal"
+            class="modal-backdrop"
+            style="position: fixed; top: 0; left: 0; right: 0; bottom: 0; background: rgba(0, 0, 0, 0.5); display: flex; align-items: center; justify-content: center; z-index: 1000;"
+        {
+            div
+                class="modal"
+                role="dialog"
+                aria-labelledby="whats-new-title"
+                style="background: white; border-radius: 12px; padding: 2rem; max-width: 560px; width: 90%; max-height: 90vh; overflow-y: auto;"
+            {
+                div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
+                    h2 id="whats-new-title" style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
+                        "✨ " (t.messages.changelog_whats_new())
+                    }
+                    form hx-post="/whats-new/dismiss" hx-target="#whats-new-modal" hx-swap="outerHTML" {
+                        (csrf_token_field(&session.csrf_token))
+                        button
+                            type="submit"
+                            class="modal-close-btn"
+                            style="background: none; border: none; font-size: 1.5rem; cursor: pointer; color: var(--gray-500); padding: 0.25rem;"
+                            title=(t.messages.common_close())
+                        {
+                            "×"
+                        }
+                    }
+                }
+
+                @for (i, entry) in entries.iter().enumerate() {
+                    @if i == 0 || entries[i - 1].version != entry.version {
+                        div style="font-size: 0.75rem; font-weight: 600; text-transform: uppercase; color: var(--gray-500); margin: 1rem 0 0.5rem;" {
+                            (t.messages.changelog_version()) " " (entry.version)
+                        }
+                    }
+                    div style="margin-bottom: 1rem;" {
+                        div style="font-weight: 600; margin-bottom: 0.25rem;" { (entry.title) }
+                        p style=(ENTRY_BODY_STYLE) { (entry.body) }
+                    }
+                }
+
+                form
+                    hx-post="/whats-new/dismiss"
+                    hx-target="#whats-new-modal"
+                    hx-swap="outerHTML"
+                    style="display: flex; justify-content: flex-end; margin-top: 1.5rem;"
+                {
+                    (csrf_token_field(&session.csrf_token))
+                    button type="submit" class="btn btn-primary" {
+                        (t.messages.changelog_dismiss())
+                    }
+                }
+            }
+        }
+    }
+}
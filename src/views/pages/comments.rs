@@ -0,0 +1,155 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::comments::{CommentEntity, CommentEntityType, CommentThread};
+use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
+use crate::views::components::forms::csrf_token_field;
+use crate::views::components::markdown::render_markdown;
+
+/// Event sent by comment mutations so the thread list reloads
+pub const COMMENTS_CHANGED_EVENT: &str = "comments-changed";
+
+fn thread_url(entity_type: CommentEntityType, entity_id: i64) -> String {
+    format!(
+        "/comments?entity_type={}&entity_id={}",
+        entity_type.as_str(),
+        entity_id
+    )
+}
+
+/// "Comments" section on a detail page, loaded once the page is shown
+pub fn entity_comments_section(
+    t: &TranslationContext,
+    entity_type: CommentEntityType,
+    entity_id: i64,
+) -> Markup {
+    html! {
+        div style="margin-top: 2rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin: 0 0 0.5rem 0;" {
+                (t.messages.comments_title())
+            }
+            p style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 1rem;" {
+                (t.messages.comments_hint())
+            }
+            div id="comments-thread" hx-get=(thread_url(entity_type, entity_id)) hx-trigger="load" hx-swap="outerHTML" {}
+        }
+    }
+}
+
+/// Comment threads of a record with the form for a new comment
+pub fn comment_threads(
+    session: &Session,
+    t: &TranslationContext,
+    entity_type: CommentEntityType,
+    entity_id: i64,
+    threads: &[CommentThread],
+) -> Markup {
+    html! {
+        div
+            id="comments-thread"
+            hx-get=(thread_url(entity_type, entity_id))
+            hx-trigger=(format!("{} from:body", COMMENTS_CHANGED_EVENT))
+            hx-swap="outerHTML"
+        {
+            @if threads.is_empty() {
+                p style="color: var(--gray-500); margin-bottom: 1rem;" {
+                    (t.messages.comments_empty())
+                }
+            }
+            @for thread in threads {
+                div class="comment-thread" {
+                    (comment(session, t, &thread.comment))
+                    @if !thread.replies.is_empty() {
+                        div class="comment-replies" {
+                            @for reply in &thread.replies {
+                                (comment(session, t, reply))
+                            }
+                        }
+                    }
+                    details class="comment-reply" {
+                        summary { (t.messages.comments_reply()) }
+                        (comment_form(session, t, entity_type, entity_id, Some(thread.comment.id)))
+                    }
+                }
+            }
+            (comment_form(session, t, entity_type, entity_id, None))
+        }
+    }
+}
+
+fn comment(session: &Session, t: &TranslationContext, comment: &CommentEntity) -> Markup {
+    html! {
+        div id=(format!("comment-{}", comment.id)) class="comment" {
+            div class="comment-header" {
+                strong {
+                    @if let Some(author) = &comment.author_name {
+                        (author)
+                    } @else {
+                        span style="color: var(--gray-500);" { (t.messages.comments_deleted_user()) }
+                    }
+                }
+                span class="comment-date" { (comment.created_at) }
+                @if comment.author_id == Some(session.user_id) {
+                    form style="display: inline; margin-left: auto;" {
+                        (csrf_token_field(&session.csrf_token))
+                        button
+                            type="submit"
+                            class="btn btn-sm btn-danger"
+                            hx-post=(format!("/comments/{}/delete", comment.id))
+                            hx-swap="none"
+                            hx-confirm-custom=(confirm_attrs(
+                                &t.messages.comments_delete_title().to_string(),
+                                &t.messages.comments_delete_confirm().to_string(),
+                                ConfirmVariant::Danger,
+                                Some(&t.messages.common_delete().to_string()),
+                                None
+                            ))
+                        {
+                            (t.messages.common_delete())
+                        }
+                    }
+                }
+            }
+            div class="comment-body" {
+                (render_markdown(&comment.body))
+            }
+        }
+    }
+}
+
+fn comment_form(
+    session: &Session,
+    t: &TranslationContext,
+    entity_type: CommentEntityType,
+    entity_id: i64,
+    parent_id: Option<i64>,
+) -> Markup {
+    let placeholder = if parent_id.is_some() {
+        t.messages.comments_reply_placeholder()
+    } else {
+        t.messages.comments_placeholder()
+    };
+
+    html! {
+        form class="comment-form" hx-post="/comments" hx-swap="none" {
+            (csrf_token_field(&session.csrf_token))
+            input type="hidden" name="entity_type" value=(entity_type.as_str());
+            input type="hidden" name="entity_id" value=(entity_id);
+            @if let Some(parent_id) = parent_id {
+                input type="hidden" name="parent_id" value=(parent_id);
+            }
+            textarea name="body" rows="3" required placeholder=(placeholder) aria-label=(placeholder) {}
+            div style="display: flex; justify-content: space-between; align-items: center; gap: 1rem;" {
+                span class="field-help" { (t.messages.comments_markdown_help()) }
+                button type="submit" class="btn btn-sm btn-primary" {
+                    @if parent_id.is_some() {
+                        (t.messages.comments_reply())
+                    } @else {
+                        (t.messages.comments_post())
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,12 +1,25 @@
 use maud::{html, Markup};
 
 use crate::i18n::TranslationContext;
-use crate::service::dashboard::{DashboardStats, RecentActivity};
+use crate::service::dashboard::{DashboardStats, RecentActivity, WeekDelta};
+use crate::service::leaders::SeasonLeadersEntity;
+use crate::service::milestones::MilestoneEntity;
+use crate::service::seasons::SeasonEntity;
+use crate::service::tasks::TaskEntity;
+use crate::views::pages::leaders::dashboard_leaders_section;
+use crate::views::pages::milestones::recent_milestones_section;
+use crate::views::pages::tasks::my_tasks_widget;
 
+#[allow(clippy::too_many_arguments)]
 pub fn dashboard_page(
     t: &TranslationContext,
     stats: &DashboardStats,
     recent_activity: &[RecentActivity],
+    milestones: &[MilestoneEntity],
+    leaders: Option<&(SeasonEntity, SeasonLeadersEntity)>,
+    my_tasks: &[TaskEntity],
+    today: &str,
+    predictions_enabled: bool,
 ) -> Markup {
     html! {
         div class="card" {
@@ -18,17 +31,7 @@ pub fn dashboard_page(
             }
 
             // Stats cards
-            div id="dashboard-stats" class="stats-grid"
-                hx-get="/dashboard/stats"
-                hx-trigger="entity-created from:body"
-                hx-swap="outerHTML"
-            {
-                (stat_card(&t.messages.nav_teams().to_string(), &stats.teams_count.to_string(), "/teams"))
-                (stat_card(&t.messages.nav_players().to_string(), &stats.players_count.to_string(), "/players"))
-                (stat_card(&t.messages.nav_events().to_string(), &stats.events_count.to_string(), "/events"))
-                (stat_card(&t.messages.nav_seasons().to_string(), &stats.seasons_count.to_string(), "/seasons"))
-                (stat_card(&t.messages.nav_matches().to_string(), &stats.matches_count.to_string(), "/matches"))
-            }
+            (dashboard_stats_partial(t, stats))
 
             // Quick actions section
             div class="dashboard-section" {
@@ -41,9 +44,27 @@ pub fn dashboard_page(
                     (quick_action_button(&t.messages.dashboard_add_event().to_string(), "/events/new"))
                     (quick_action_button(&t.messages.dashboard_add_season().to_string(), "/seasons/new"))
                     (quick_action_button(&t.messages.dashboard_add_match().to_string(), "/matches/new"))
+                    @if predictions_enabled {
+                        a href="/predictions" class="quick-action-btn" {
+                            span { (t.messages.predictions_title()) }
+                        }
+                    }
                 }
             }
 
+            // Open tasks assigned to the current user
+            (my_tasks_widget(t, my_tasks, today))
+
+            // Scoring leaders of the current season
+            @if let Some((season, leaders)) = leaders {
+                (dashboard_leaders_section(t, season, leaders))
+            }
+
+            // Recent milestones (hat tricks, big games, career goals)
+            @if !milestones.is_empty() {
+                (recent_milestones_section(t, milestones))
+            }
+
             // Recent activity section
             div class="dashboard-section" {
                 h2 class="section-heading" {
@@ -100,26 +121,54 @@ pub fn dashboard_stats_partial(t: &TranslationContext, stats: &DashboardStats) -
             hx-trigger="entity-created from:body"
             hx-swap="outerHTML"
         {
-            (stat_card(&t.messages.nav_teams().to_string(), &stats.teams_count.to_string(), "/teams"))
-            (stat_card(&t.messages.nav_players().to_string(), &stats.players_count.to_string(), "/players"))
-            (stat_card(&t.messages.nav_events().to_string(), &stats.events_count.to_string(), "/events"))
-            (stat_card(&t.messages.nav_seasons().to_string(), &stats.seasons_count.to_string(), "/seasons"))
-            (stat_card(&t.messages.nav_matches().to_string(), &stats.matches_count.to_string(), "/matches"))
+            (stat_card(t, &t.messages.nav_teams().to_string(), &stats.teams_count.to_string(), "/teams", None))
+            (stat_card(t, &t.messages.nav_players().to_string(), &stats.players_count.to_string(), "/players", Some(stats.new_players)))
+            (stat_card(t, &t.messages.nav_events().to_string(), &stats.events_count.to_string(), "/events", None))
+            (stat_card(t, &t.messages.nav_seasons().to_string(), &stats.seasons_count.to_string(), "/seasons", None))
+            (stat_card(t, &t.messages.nav_matches().to_string(), &stats.matches_count.to_string(), "/matches", Some(stats.new_matches)))
+            (stat_card(t, &t.messages.dashboard_stats_identified_goals().to_string(), &stats.identified_goals_count.to_string(), "/matches", Some(stats.identified_goals)))
         }
     }
 }
 
-fn stat_card(title: &str, value: &str, link: &str) -> Markup {
+fn stat_card(
+    t: &TranslationContext,
+    title: &str,
+    value: &str,
+    link: &str,
+    delta: Option<WeekDelta>,
+) -> Markup {
     html! {
         a href=(link) class="stat-card" {
             div class="stat-card-header" {
                 div class="stat-card-value" { (value) }
+                @if let Some(delta) = delta {
+                    (week_delta_badge(t, delta))
+                }
             }
             div class="stat-card-title" { (title) }
         }
     }
 }
 
+/// Arrow with this week's count; the tooltip shows last week's
+fn week_delta_badge(t: &TranslationContext, delta: WeekDelta) -> Markup {
+    let (arrow, class) = match delta.change() {
+        change if change > 0 => ("▲", "stat-card-delta stat-card-delta-up"),
+        change if change < 0 => ("▼", "stat-card-delta stat-card-delta-down"),
+        _ => ("▶", "stat-card-delta"),
+    };
+
+    html! {
+        div
+            class=(class)
+            title=(format!("{}: {}", t.messages.dashboard_last_week(), delta.last_week))
+        {
+            (format!("{} {} {}", arrow, delta.this_week, t.messages.dashboard_this_week()))
+        }
+    }
+}
+
 fn quick_action_button(label: &str, href: &str) -> Markup {
     html! {
         a
@@ -0,0 +1,202 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::data_quality::{DataQualityReport, IssueList};
+use crate::views::components::crud::empty_state_i18n;
+use crate::views::pages::matches::{shot_period_label, status_filter_label};
+
+/// Data quality dashboard: one section per kind of issue, each row linking to where it is fixed
+pub fn data_quality_page(t: &TranslationContext, report: &DataQualityReport) -> Markup {
+    let fix = t.messages.data_quality_fix().to_string();
+
+    html! {
+        div class="card" {
+            div style="margin-bottom: 1.5rem;" {
+                h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                    (t.messages.data_quality_title())
+                }
+                p style="color: var(--gray-600);" {
+                    (t.messages.data_quality_description())
+                }
+            }
+
+            @if report.total() == 0 {
+                (empty_state_i18n(
+                    &t.messages.data_quality_empty_title().to_string(),
+                    &t.messages.data_quality_empty_message().to_string(),
+                    false
+                ))
+            } @else {
+                // Matches without a date
+                @if !report.undated_matches.is_empty() {
+                    (section_header(
+                        t,
+                        &t.messages.data_quality_undated_matches().to_string(),
+                        &report.undated_matches,
+                        Some(("/matches?date_unknown=true", t.messages.data_quality_view_all().to_string())),
+                    ))
+                    table class="table" {
+                        tbody {
+                            @for item in &report.undated_matches.items {
+                                tr {
+                                    td { (item.home_team_name) " – " (item.away_team_name) }
+                                    td style="color: var(--gray-600);" { (item.season_name) }
+                                    td style="color: var(--gray-600);" { (status_filter_label(t, &item.status)) }
+                                    td style="text-align: right;" {
+                                        a href=(format!("/matches/{}", item.id)) class="btn btn-sm" { (fix) }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Goals without a scorer
+                @if !report.unscored_goals.is_empty() {
+                    (section_header(
+                        t,
+                        &t.messages.data_quality_unscored_goals().to_string(),
+                        &report.unscored_goals,
+                        None,
+                    ))
+                    table class="table" {
+                        tbody {
+                            @for item in &report.unscored_goals.items {
+                                tr {
+                                    td { (item.home_team_name) " – " (item.away_team_name) }
+                                    td style="color: var(--gray-600);" {
+                                        (item.match_date.as_deref().unwrap_or("-"))
+                                    }
+                                    td { (item.team_name) }
+                                    td style="color: var(--gray-600);" {
+                                        @if let Some(period) = item.period {
+                                            (shot_period_label(t, period))
+                                        }
+                                    }
+                                    td style="text-align: right;" {
+                                        a href=(format!("/matches/{}", item.match_id)) class="btn btn-sm" { (fix) }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Teams without a name
+                @if !report.unnamed_teams.is_empty() {
+                    (section_header(
+                        t,
+                        &t.messages.data_quality_unnamed_teams().to_string(),
+                        &report.unnamed_teams,
+                        None,
+                    ))
+                    table class="table" {
+                        tbody {
+                            @for item in &report.unnamed_teams.items {
+                                tr {
+                                    td { "#" (item.id) }
+                                    td style="color: var(--gray-600);" {
+                                        (item.short_name.as_deref().unwrap_or("-"))
+                                    }
+                                    td style="color: var(--gray-600);" {
+                                        (item.country_name.as_deref().unwrap_or("-"))
+                                    }
+                                    td style="text-align: right;" {
+                                        a href=(format!("/teams/{}", item.id)) class="btn btn-sm" { (fix) }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Players that look like duplicates
+                @if !report.duplicate_players.is_empty() {
+                    div style="display: flex; justify-content: space-between; align-items: center; margin: 1.5rem 0 0.75rem 0;" {
+                        h2 style="font-size: 1.25rem; font-weight: 600; margin: 0;" {
+                            (t.messages.data_quality_duplicate_players()) " (" (report.duplicate_players.len()) ")"
+                        }
+                        a href="/reports/duplicates" class="primary-link" { (t.messages.data_quality_view_all()) }
+                    }
+                    table class="table" {
+                        tbody {
+                            @for pair in &report.duplicate_players {
+                                tr {
+                                    td {
+                                        a href=(format!("/players/{}", pair.first.id)) { (pair.first.name) }
+                                    }
+                                    td {
+                                        a href=(format!("/players/{}", pair.second.id)) { (pair.second.name) }
+                                    }
+                                    td style="text-align: right; color: var(--gray-600);" {
+                                        @if pair.exact {
+                                            (t.messages.reports_duplicates_exact())
+                                        } @else {
+                                            (format!("{:.0}%", pair.similarity * 100.0))
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Season participations with nobody on the roster
+                @if !report.empty_rosters.is_empty() {
+                    (section_header(
+                        t,
+                        &t.messages.data_quality_empty_rosters().to_string(),
+                        &report.empty_rosters,
+                        None,
+                    ))
+                    table class="table" {
+                        tbody {
+                            @for item in &report.empty_rosters.items {
+                                tr {
+                                    td {
+                                        a href=(format!("/teams/{}", item.team_id)) { (item.team_name) }
+                                    }
+                                    td {
+                                        a href=(format!("/seasons/{}", item.season_id)) { (item.season_name) }
+                                    }
+                                    td style="text-align: right;" {
+                                        a
+                                            href=(format!("/team-participations/{}/roster", item.participation_id))
+                                            class="btn btn-sm"
+                                        {
+                                            (fix)
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Section title with the total, noting when only part of the records is listed
+fn section_header<T>(
+    t: &TranslationContext,
+    title: &str,
+    issues: &IssueList<T>,
+    view_all: Option<(&str, String)>,
+) -> Markup {
+    html! {
+        div style="display: flex; justify-content: space-between; align-items: center; margin: 1.5rem 0 0.75rem 0;" {
+            h2 style="font-size: 1.25rem; font-weight: 600; margin: 0;" {
+                (title) " (" (issues.total) ")"
+            }
+            @if let Some((href, label)) = view_all {
+                a href=(href) class="primary-link" { (label) }
+            }
+        }
+        @if issues.total > issues.items.len() as i64 {
+            p style="color: var(--gray-500); font-size: 0.875rem; margin: 0 0 0.5rem 0;" {
+                (t.messages.data_quality_truncated())
+            }
+        }
+    }
+}
@@ -2,10 +2,21 @@ use maud::{html, Markup};
 
 use crate::i18n::TranslationContext;
 use crate::service::events::{EventDetailEntity, EventEntity, SeasonEntity};
+use crate::service::placements::MedalTableEntity;
+use crate::service::roster_rules::RosterRulesEntity;
+use crate::service::tasks::TaskEntityType;
 use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
+use crate::views::components::crud::modal_form_i18n;
+use crate::views::pages::events::point_system_label;
+use crate::views::pages::placements::medal_table_section;
+use crate::views::pages::tasks::entity_tasks_section;
 
-/// Event detail page with seasons list
-pub fn event_detail_page(t: &TranslationContext, detail: &EventDetailEntity) -> Markup {
+/// Event detail page with seasons list and medal table
+pub fn event_detail_page(
+    t: &TranslationContext,
+    detail: &EventDetailEntity,
+    medal_table: &[MedalTableEntity],
+) -> Markup {
     let event = &detail.event_info;
 
     html! {
@@ -51,6 +62,9 @@ pub fn event_detail_page(t: &TranslationContext, detail: &EventDetailEntity) ->
             // Event Info Card
             (event_info_card(t, event))
 
+            // Roster Rules Section
+            (roster_rules_card(t, event.id, &detail.roster_rules))
+
             // Seasons Section
             div style="margin-top: 2rem;" {
                 div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
@@ -74,6 +88,14 @@ pub fn event_detail_page(t: &TranslationContext, detail: &EventDetailEntity) ->
                 }
             }
 
+            // Medal Table
+            @if !detail.seasons.is_empty() {
+                (medal_table_section(t, medal_table))
+            }
+
+            // Open tasks attached to this event
+            (entity_tasks_section(t, TaskEntityType::Event, event.id))
+
             // Modal container
             div id="modal-container" {}
         }
@@ -113,11 +135,114 @@ fn event_info_card(t: &TranslationContext, event: &EventEntity) -> Markup {
                         }
                     }
                 }
+                div {
+                    div style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 0.25rem;" {
+                        (t.messages.events_point_system())
+                    }
+                    div style="font-weight: 600;" {
+                        (point_system_label(t, event.point_system()))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Roster rules applied to every team of the event
+fn roster_rules_card(t: &TranslationContext, event_id: i64, rules: &RosterRulesEntity) -> Markup {
+    let limit = |value: Option<i64>| match value {
+        Some(value) => html! { (value) },
+        None => html! {
+            span style="color: var(--gray-400); font-style: italic;" { (t.messages.roster_rules_no_limit()) }
+        },
+    };
+
+    html! {
+        div style="margin-top: 2rem;" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1rem;" {
+                h2 style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
+                    (t.messages.roster_rules_title())
+                }
+                button
+                    class="btn btn-secondary"
+                    hx-get=(format!("/events/{}/roster-rules/edit", event_id))
+                    hx-target="#modal-container"
+                    hx-swap="innerHTML"
+                {
+                    (t.messages.roster_rules_edit())
+                }
+            }
+            div style="padding: 1.5rem; background: var(--gray-50); border-radius: 8px;" {
+                div style="display: grid; grid-template-columns: repeat(auto-fit, minmax(200px, 1fr)); gap: 1rem;" {
+                    div {
+                        div style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 0.25rem;" {
+                            (t.messages.roster_rules_max_players())
+                        }
+                        div style="font-weight: 600;" { (limit(rules.max_players)) }
+                    }
+                    div {
+                        div style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 0.25rem;" {
+                            (t.messages.roster_rules_min_goalies())
+                        }
+                        div style="font-weight: 600;" { (limit(rules.min_goalies)) }
+                    }
+                    div {
+                        div style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 0.25rem;" {
+                            (t.messages.roster_rules_max_imports())
+                        }
+                        div style="font-weight: 600;" { (limit(rules.max_imports)) }
+                    }
+                }
+                p style="color: var(--gray-600); font-size: 0.875rem; margin: 1rem 0 0;" {
+                    (t.messages.roster_rules_hint())
+                }
             }
         }
     }
 }
 
+/// Modal form to edit an event's roster rules; empty fields mean no limit
+pub fn roster_rules_modal(
+    t: &TranslationContext,
+    event_id: i64,
+    rules: &RosterRulesEntity,
+    error: Option<&str>,
+) -> Markup {
+    let field = |name: &str, label: String, value: Option<i64>| {
+        html! {
+            div class="form-group" {
+                label class="form-label" for=(name) { (label) }
+                input
+                    type="number"
+                    id=(name)
+                    name=(name)
+                    min="0"
+                    value=[value]
+                    placeholder=(t.messages.roster_rules_no_limit());
+            }
+        }
+    };
+
+    let form_fields = html! {
+        (field("max_players", t.messages.roster_rules_max_players().to_string(), rules.max_players))
+        (field("min_goalies", t.messages.roster_rules_min_goalies().to_string(), rules.min_goalies))
+        (field("max_imports", t.messages.roster_rules_max_imports().to_string(), rules.max_imports))
+        p style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 1rem;" {
+            (t.messages.roster_rules_hint())
+        }
+    };
+
+    modal_form_i18n(
+        "roster-rules-modal",
+        &t.messages.roster_rules_edit().to_string(),
+        error,
+        &format!("/events/{}/roster-rules", event_id),
+        form_fields,
+        &t.messages.common_save().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
 /// Empty state when no seasons exist
 fn empty_seasons_state(t: &TranslationContext) -> Markup {
     html! {
@@ -2,8 +2,10 @@ use maud::{html, Markup, PreEscaped};
 
 use crate::i18n::TranslationContext;
 use crate::service::events::{EventEntity, EventFilters, PagedResult};
+use crate::service::standings::PointSystem;
 use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
 use crate::views::components::empty_state::{empty_state_enhanced, EmptyStateIcon};
+use crate::views::components::forms::{form_field, InputType};
 
 /// Main events page with table and filters
 pub fn events_page(
@@ -320,6 +322,32 @@ fn pagination_pages(current_page: usize, total_pages: usize) -> Vec<usize> {
     pages
 }
 
+/// Translated name of a point system
+pub fn point_system_label(t: &TranslationContext, point_system: PointSystem) -> String {
+    match point_system {
+        PointSystem::Classic => t.messages.events_point_system_classic().to_string(),
+        PointSystem::Iihf => t.messages.events_point_system_iihf().to_string(),
+    }
+}
+
+/// Point system dropdown shared by the create and edit modals
+fn point_system_field(t: &TranslationContext, selected: PointSystem) -> Markup {
+    html! {
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.events_point_system())
+            }
+            select name="point_system" {
+                @for point_system in PointSystem::ALL {
+                    option value=(point_system.as_str()) selected[point_system == selected] {
+                        (point_system_label(t, point_system))
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Create event modal
 pub fn event_create_modal(
     t: &TranslationContext,
@@ -368,6 +396,8 @@ pub fn event_create_modal(
                             enabled-only;
                     }
 
+                    (point_system_field(t, PointSystem::default()))
+
                     div class="modal-actions" {
                         button
                             type="button"
@@ -435,6 +465,17 @@ pub fn event_edit_modal(
                             autofocus;
                     }
 
+                    (form_field(
+                        "slug",
+                        &t.messages.form_slug().to_string(),
+                        InputType::Text,
+                        event.slug.as_deref(),
+                        None,
+                        false,
+                        None,
+                        Some(&t.messages.form_slug_help().to_string()),
+                    ))
+
                     div class="form-group" {
                         label class="form-label" {
                             (t.messages.events_host_country())
@@ -453,6 +494,8 @@ pub fn event_edit_modal(
                         }
                     }
 
+                    (point_system_field(t, event.point_system()))
+
                     div class="modal-actions" {
                         button
                             type="button"
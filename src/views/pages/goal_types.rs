@@ -0,0 +1,163 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::goal_types::{
+    GoalType, GoalTypeBreakdown, SeasonGoalTypesEntity, TeamGoalTypesEntity,
+};
+
+const NUMBER_CELL: &str = "text-align: right;";
+
+/// Short label (EV/PP/SH/PS/EN) of a goal type
+fn short_label(t: &TranslationContext, goal_type: GoalType) -> String {
+    match goal_type {
+        GoalType::EvenStrength => t.messages.goal_types_even_strength_short(),
+        GoalType::PowerPlay => t.messages.goal_types_power_play_short(),
+        GoalType::ShortHanded => t.messages.goal_types_short_handed_short(),
+        GoalType::PenaltyShot => t.messages.goal_types_penalty_shot_short(),
+        GoalType::EmptyNet => t.messages.goal_types_empty_net_short(),
+    }
+    .to_string()
+}
+
+fn full_label(t: &TranslationContext, goal_type: GoalType) -> String {
+    match goal_type {
+        GoalType::EvenStrength => t.messages.player_scoring_even_strength(),
+        GoalType::PowerPlay => t.messages.player_scoring_power_play(),
+        GoalType::ShortHanded => t.messages.player_scoring_short_handed(),
+        GoalType::PenaltyShot => t.messages.player_scoring_penalty_shot(),
+        GoalType::EmptyNet => t.messages.player_scoring_empty_net(),
+    }
+    .to_string()
+}
+
+fn legend(t: &TranslationContext) -> Markup {
+    html! {
+        div class="goal-type-legend" {
+            @for goal_type in GoalType::ALL {
+                span class="goal-type-legend-item" {
+                    span class=(format!("goal-type-swatch goal-type-{}", goal_type.as_str())) {}
+                    (format!("{} – {}", short_label(t, goal_type), full_label(t, goal_type)))
+                }
+            }
+            span class="goal-type-legend-item" {
+                span class="goal-type-swatch goal-type-unspecified" {}
+                (t.messages.goal_types_unspecified())
+            }
+        }
+    }
+}
+
+fn segment(class: &str, title: String, count: i64, breakdown: &GoalTypeBreakdown) -> Markup {
+    html! {
+        @if let Some(percentage) = breakdown.percentage(count).filter(|_| count > 0) {
+            span
+                class=(format!("goal-type-segment goal-type-{}", class))
+                style=(format!("width: {:.2}%;", percentage))
+                title=(format!("{}: {} ({:.0}%)", title, count, percentage))
+            {}
+        }
+    }
+}
+
+/// Stacked bar of one breakdown, one segment per goal type
+fn stacked_bar(t: &TranslationContext, breakdown: &GoalTypeBreakdown) -> Markup {
+    html! {
+        div class="goal-type-bar" {
+            @for goal_type in GoalType::ALL {
+                (segment(goal_type.as_str(), full_label(t, goal_type), breakdown.count(goal_type), breakdown))
+            }
+            (segment("unspecified", t.messages.goal_types_unspecified().to_string(), breakdown.unspecified, breakdown))
+        }
+    }
+}
+
+/// Chart section: a stacked bar and the counts per goal type for every row
+fn goal_types_section(
+    t: &TranslationContext,
+    row_label: &str,
+    rows: Vec<(Markup, &GoalTypeBreakdown)>,
+) -> Markup {
+    html! {
+        div style="margin-top: 2rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin: 0 0 0.5rem 0;" {
+                (t.messages.goal_types_title())
+            }
+            p style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 1rem;" {
+                (t.messages.goal_types_hint())
+            }
+
+            @if rows.is_empty() {
+                div style="padding: 1rem; background: var(--gray-100); border-radius: 4px; color: var(--gray-600); text-align: center;" {
+                    (t.messages.goal_types_empty())
+                }
+            } @else {
+                (legend(t))
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (row_label) }
+                            th style="width: 40%;" {}
+                            @for goal_type in GoalType::ALL {
+                                th style=(NUMBER_CELL) title=(full_label(t, goal_type)) {
+                                    (short_label(t, goal_type))
+                                }
+                            }
+                            th style=(NUMBER_CELL) title=(t.messages.goal_types_unspecified()) { "?" }
+                            th style=(NUMBER_CELL) { (t.messages.goal_types_total()) }
+                        }
+                    }
+                    tbody {
+                        @for (label, breakdown) in &rows {
+                            tr {
+                                td { (label) }
+                                td { (stacked_bar(t, breakdown)) }
+                                @for goal_type in GoalType::ALL {
+                                    td style=(NUMBER_CELL) { (breakdown.count(goal_type)) }
+                                }
+                                td style=(NUMBER_CELL) { (breakdown.unspecified) }
+                                td style=(format!("{} font-weight: 600;", NUMBER_CELL)) { (breakdown.total()) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Goal type distribution of every team in a season (season detail page)
+pub fn season_goal_types_section(t: &TranslationContext, teams: &[TeamGoalTypesEntity]) -> Markup {
+    let rows = teams
+        .iter()
+        .map(|team| {
+            let label = html! {
+                a href=(format!("/teams/{}", team.team_id)) class="primary-link" { (team.team_name) }
+            };
+            (label, &team.breakdown)
+        })
+        .collect();
+
+    goal_types_section(t, &t.messages.goal_types_team().to_string(), rows)
+}
+
+/// Goal type distribution of a team per season (team detail page)
+pub fn team_goal_types_section(
+    t: &TranslationContext,
+    seasons: &[SeasonGoalTypesEntity],
+) -> Markup {
+    let rows = seasons
+        .iter()
+        .map(|season| {
+            let name = season
+                .season_display_name
+                .clone()
+                .unwrap_or_else(|| format!("{} {}", season.event_name, season.season_year));
+            let label = html! {
+                a href=(format!("/seasons/{}", season.season_id)) class="primary-link" { (name) }
+            };
+            (label, &season.breakdown)
+        })
+        .collect();
+
+    goal_types_section(t, &t.messages.team_stats_season().to_string(), rows)
+}
@@ -0,0 +1,134 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::instance_transfer::InstanceImportSummary;
+use crate::service::team_history::ImportMode;
+use crate::views::components::forms::csrf_token_field;
+
+/// Data transfer page: download of all data and upload of an export
+pub fn instance_transfer_page(session: &Session, t: &TranslationContext) -> Markup {
+    html! {
+        div class="card" {
+            div style="margin-bottom: 1.5rem;" {
+                h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                    (t.messages.instance_transfer_title())
+                }
+                p style="color: var(--gray-600);" {
+                    (t.messages.instance_transfer_description())
+                }
+            }
+
+            div style="margin-bottom: 2rem;" {
+                h2 style="font-size: 1.25rem; font-weight: 600; margin-bottom: 0.5rem;" {
+                    (t.messages.instance_transfer_export())
+                }
+                p style="color: var(--gray-600); margin-bottom: 1rem;" {
+                    (t.messages.instance_transfer_export_help())
+                }
+                a href="/management/instance-transfer/export" class="btn btn-secondary" download {
+                    (t.messages.instance_transfer_download())
+                }
+            }
+
+            h2 style="font-size: 1.25rem; font-weight: 600; margin-bottom: 0.5rem;" {
+                (t.messages.instance_transfer_import())
+            }
+            p style="color: var(--gray-600); margin-bottom: 1rem;" {
+                (t.messages.instance_transfer_import_help())
+            }
+            form
+                hx-post="/management/instance-transfer"
+                hx-encoding="multipart/form-data"
+                hx-target="#instance-import-result"
+                hx-swap="innerHTML"
+                style="margin-bottom: 1.5rem;"
+            {
+                (csrf_token_field(&session.csrf_token))
+                div class="form-group" style="margin-bottom: 1rem;" {
+                    label class="form-label" for="instance-import-file" {
+                        (t.messages.instance_transfer_file())
+                    }
+                    input
+                        id="instance-import-file"
+                        type="file"
+                        name="file"
+                        accept="application/json,.json"
+                        required;
+                }
+                div style="margin-bottom: 1rem;" {
+                    label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                        (t.messages.teams_import_mode())
+                    }
+                    @for (value, label, checked) in [
+                        (ImportMode::AddMissing, t.messages.teams_import_mode_add(), true),
+                        (ImportMode::Merge, t.messages.teams_import_mode_merge(), false),
+                    ] {
+                        label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer; padding: 0.25rem 0;" {
+                            input type="radio" name="mode" value=(value.as_str()) checked[checked];
+                            span { (label) }
+                        }
+                    }
+                }
+                button type="submit" class="btn btn-primary" { (t.messages.instance_transfer_submit()) }
+            }
+
+            div id="instance-import-result" {}
+        }
+    }
+}
+
+/// Result of an uploaded instance export: counts per record type, or why nothing was imported
+pub fn instance_import_result(
+    t: &TranslationContext,
+    outcome: Result<&InstanceImportSummary, &str>,
+) -> Markup {
+    let summary = match outcome {
+        Ok(summary) => summary,
+        Err(error) => {
+            return html! {
+                div class="error" style="padding: 1rem;" { (error) }
+            }
+        }
+    };
+
+    let rows = [
+        (t.messages.teams_import_row_teams(), summary.teams),
+        (t.messages.teams_import_row_events(), summary.events),
+        (t.messages.teams_import_row_seasons(), summary.seasons),
+        (
+            t.messages.teams_import_row_participations(),
+            summary.participations,
+        ),
+        (t.messages.teams_import_row_players(), summary.players),
+        (t.messages.teams_import_row_contracts(), summary.contracts),
+        (t.messages.teams_import_row_matches(), summary.matches),
+        (t.messages.teams_import_row_goals(), summary.score_events),
+    ];
+
+    html! {
+        div style="padding: 1rem; background: var(--green-50); color: var(--green-700); border-radius: 4px; margin-bottom: 1rem;" {
+            (t.messages.instance_transfer_done())
+        }
+        table class="table" {
+            thead {
+                tr {
+                    th { (t.messages.teams_import_entity()) }
+                    th style="text-align: right;" { (t.messages.teams_import_created()) }
+                    th style="text-align: right;" { (t.messages.teams_import_updated()) }
+                    th style="text-align: right;" { (t.messages.teams_import_unchanged()) }
+                }
+            }
+            tbody {
+                @for (label, counts) in &rows {
+                    tr {
+                        td { (label) }
+                        td style="text-align: right; font-weight: 600;" { (counts.created) }
+                        td style="text-align: right; font-weight: 600;" { (counts.updated) }
+                        td style="text-align: right; color: var(--gray-500);" { (counts.unchanged) }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::leaders::{LeaderEntity, SeasonLeadersEntity};
+use crate::service::seasons::SeasonEntity;
+
+/// Players per category in the compact panel of season detail and dashboard
+pub const PANEL_LEADERS_LIMIT: usize = 5;
+/// Players per category on the full leaders page
+pub const PAGE_LEADERS_LIMIT: usize = 25;
+
+/// Season name as shown outside the season page, e.g. "World Championship 2024"
+fn season_title(season: &SeasonEntity) -> String {
+    season
+        .display_name
+        .clone()
+        .unwrap_or_else(|| format!("{} {}", season.event_name, season.year))
+}
+
+/// Full leaderboards of a season
+pub fn season_leaders_page(
+    t: &TranslationContext,
+    season: &SeasonEntity,
+    leaders: &SeasonLeadersEntity,
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="display: flex; align-items: center; gap: 1rem; margin-bottom: 1.5rem;" {
+                a href=(format!("/seasons/{}", season.id)) class="btn btn-secondary" {
+                    (format!("← {}", season_title(season)))
+                }
+                h1 style="font-size: 2rem; font-weight: 700; margin: 0;" {
+                    (t.messages.leaders_title())
+                }
+            }
+
+            (leaders_tables(t, leaders))
+        }
+    }
+}
+
+/// Compact leaderboards for the season detail page
+pub fn leaders_panel(
+    t: &TranslationContext,
+    season_id: i64,
+    leaders: &SeasonLeadersEntity,
+) -> Markup {
+    html! {
+        div style="margin-top: 2rem;" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1rem;" {
+                h2 style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
+                    (t.messages.leaders_title())
+                }
+                @if !leaders.is_empty() {
+                    a href=(format!("/seasons/{}/leaders", season_id)) class="btn btn-secondary" {
+                        (t.messages.leaders_view_all())
+                    }
+                }
+            }
+            (leaders_tables(t, leaders))
+        }
+    }
+}
+
+/// Leaders of the current season for the dashboard
+pub fn dashboard_leaders_section(
+    t: &TranslationContext,
+    season: &SeasonEntity,
+    leaders: &SeasonLeadersEntity,
+) -> Markup {
+    html! {
+        div class="dashboard-section" {
+            h2 class="section-heading" {
+                (format!("{} · {}", t.messages.leaders_title(), season_title(season)))
+            }
+            (leaders_tables(t, leaders))
+            a href=(format!("/seasons/{}/leaders", season.id)) class="primary-link" {
+                (t.messages.leaders_view_all())
+            }
+        }
+    }
+}
+
+fn leaders_tables(t: &TranslationContext, leaders: &SeasonLeadersEntity) -> Markup {
+    html! {
+        @if leaders.is_empty() {
+            p style="color: var(--gray-600);" { (t.messages.leaders_empty()) }
+        } @else {
+            div style="display: grid; grid-template-columns: repeat(auto-fit, minmax(16rem, 1fr)); gap: 1rem;" {
+                (leader_table(
+                    &t.messages.leaders_goals().to_string(),
+                    &t.messages.leaders_goals_short().to_string(),
+                    &leaders.goals,
+                    |leader| leader.goals,
+                ))
+                (leader_table(
+                    &t.messages.leaders_assists().to_string(),
+                    &t.messages.leaders_assists_short().to_string(),
+                    &leaders.assists,
+                    |leader| leader.assists,
+                ))
+                (leader_table(
+                    &t.messages.leaders_points().to_string(),
+                    &t.messages.leaders_points_short().to_string(),
+                    &leaders.points,
+                    |leader| leader.points,
+                ))
+            }
+        }
+    }
+}
+
+/// One ranked category; players tied on `value` share the rank
+fn leader_table(
+    title: &str,
+    column: &str,
+    leaders: &[LeaderEntity],
+    value: fn(&LeaderEntity) -> i64,
+) -> Markup {
+    let mut rank = 0;
+    let ranks: Vec<usize> = leaders
+        .iter()
+        .enumerate()
+        .map(|(index, leader)| {
+            if index == 0 || value(&leaders[index - 1]) != value(leader) {
+                rank = index + 1;
+            }
+            rank
+        })
+        .collect();
+
+    html! {
+        div {
+            h3 style="font-size: 1.125rem; font-weight: 600; margin: 0 0 0.5rem 0;" { (title) }
+            table class="table" {
+                tbody {
+                    @for (leader, rank) in leaders.iter().zip(ranks) {
+                        tr {
+                            td style="width: 1%; color: var(--gray-600);" { (rank) "." }
+                            td {
+                                a href=(format!("/players/{}", leader.player_id)) class="primary-link" {
+                                    (leader.player_name)
+                                }
+                                div style="color: var(--gray-600); font-size: 0.875rem;" {
+                                    (leader.team_names)
+                                }
+                            }
+                            td style="text-align: right; font-weight: 700;" title=(column) {
+                                (value(leader))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
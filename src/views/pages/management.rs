@@ -23,22 +23,130 @@ pub fn management_page(t: &TranslationContext) -> Markup {
                     true
                 ))
 
-                // Future: Users card (placeholder)
+                // Stats reconciliation report
+                (management_card(
+                    "📋",
+                    &t.messages.reports_reconciliation_title().to_string(),
+                    &t.messages.reports_reconciliation_card_description().to_string(),
+                    "/reports/stats-reconciliation",
+                    true
+                ))
+
+                // Incomplete and suspicious records
+                (management_card(
+                    "🧹",
+                    &t.messages.data_quality_title().to_string(),
+                    &t.messages.data_quality_card_description().to_string(),
+                    "/management/data-quality",
+                    true
+                ))
+
+                // Bulk match import
+                (management_card(
+                    "📥",
+                    &t.messages.match_import_title().to_string(),
+                    &t.messages.match_import_card_description().to_string(),
+                    "/management/match-import",
+                    true
+                ))
+
+                // Moving all data between instances
+                (management_card(
+                    "🔁",
+                    &t.messages.instance_transfer_title().to_string(),
+                    &t.messages.instance_transfer_card_description().to_string(),
+                    "/management/instance-transfer",
+                    true
+                ))
+
+                // Goal identification backlog
+                (management_card(
+                    "❓",
+                    &t.messages.reports_unidentified_title().to_string(),
+                    &t.messages.reports_unidentified_card_description().to_string(),
+                    "/reports/unidentified-goals",
+                    true
+                ))
+
+                // Probable duplicate records
+                (management_card(
+                    "👯",
+                    &t.messages.reports_duplicates_title().to_string(),
+                    &t.messages.reports_duplicates_card_description().to_string(),
+                    "/reports/duplicates",
+                    true
+                ))
+
+                // Custom report builder
+                (management_card(
+                    "📊",
+                    &t.messages.report_builder_title().to_string(),
+                    &t.messages.report_builder_card_description().to_string(),
+                    "/reports/builder",
+                    true
+                ))
+
+                // Database status and maintenance
+                (management_card(
+                    "🩺",
+                    &t.messages.status_title().to_string(),
+                    &t.messages.status_card_description().to_string(),
+                    "/management/status",
+                    true
+                ))
+
+                // Scheduled backups
+                (management_card(
+                    "💾",
+                    &t.messages.backups_title().to_string(),
+                    &t.messages.backups_card_description().to_string(),
+                    "/management/backups",
+                    true
+                ))
+
+                // Outgoing webhooks for external systems
+                (management_card(
+                    "🔗",
+                    &t.messages.webhooks_title().to_string(),
+                    &t.messages.webhooks_card_description().to_string(),
+                    "/management/webhooks",
+                    true
+                ))
+
+                // Slow query panel
+                (management_card(
+                    "🐢",
+                    &t.messages.slow_queries_title().to_string(),
+                    &t.messages.slow_queries_card_description().to_string(),
+                    "/management/slow-queries",
+                    true
+                ))
+
+                // "What's new" entries shown to users after upgrades
+                (management_card(
+                    "✨",
+                    &t.messages.changelog_title().to_string(),
+                    &t.messages.changelog_card_description().to_string(),
+                    "/management/whats-new",
+                    true
+                ))
+
+                // Accounts and roles (admins only)
                 (management_card(
                     "👥",
-                    "Users",
-                    "Manage user accounts and permissions",
-                    "#",
-                    false
+                    &t.messages.users_title().to_string(),
+                    &t.messages.users_card_description().to_string(),
+                    "/users",
+                    true
                 ))
 
-                // Future: System settings (placeholder)
+                // Instance-wide settings (default list filters)
                 (management_card(
                     "⚙️",
-                    "Settings",
-                    "Configure system preferences and options",
-                    "#",
-                    false
+                    &t.messages.settings_title().to_string(),
+                    &t.messages.settings_card_description().to_string(),
+                    "/management/settings",
+                    true
                 ))
             }
         }
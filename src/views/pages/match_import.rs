@@ -0,0 +1,111 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::business::match_import::MatchImportOutcome;
+use crate::i18n::TranslationContext;
+use crate::views::components::forms::csrf_token_field;
+
+/// Example rows shown on the page, one per accepted format
+const CSV_EXAMPLE: &str = "season,home_team,away_team,date,home_score,away_score\n2024 World Championship,Czechia,Switzerland,2024-05-26 20:20,2,0";
+const JSON_EXAMPLE: &str = r#"[{"season": "2024 World Championship", "home_team": "Czechia", "away_team": "Switzerland", "date": "2024-05-26 20:20", "home_score": 2, "away_score": 0}]"#;
+
+/// Bulk match import: upload form, with the result shown below it
+pub fn match_import_page(session: &Session, t: &TranslationContext) -> Markup {
+    html! {
+        div class="card" {
+            div style="margin-bottom: 1.5rem;" {
+                h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                    (t.messages.match_import_title())
+                }
+                p style="color: var(--gray-600);" {
+                    (t.messages.match_import_description())
+                }
+            }
+
+            div style="margin-bottom: 1.5rem;" {
+                p style="font-weight: 500; margin-bottom: 0.5rem;" { "CSV" }
+                pre style="background: var(--gray-50); padding: 0.75rem; border-radius: 4px; overflow-x: auto; font-size: 0.875rem;" {
+                    (CSV_EXAMPLE)
+                }
+                p style="font-weight: 500; margin: 1rem 0 0.5rem 0;" { "JSON" }
+                pre style="background: var(--gray-50); padding: 0.75rem; border-radius: 4px; overflow-x: auto; font-size: 0.875rem;" {
+                    (JSON_EXAMPLE)
+                }
+                p style="color: var(--gray-600); margin-top: 0.5rem;" {
+                    (t.messages.match_import_api_hint()) " "
+                    code { "POST /api/matches/import" }
+                }
+            }
+
+            form
+                hx-post="/management/match-import"
+                hx-encoding="multipart/form-data"
+                hx-target="#match-import-result"
+                hx-swap="innerHTML"
+                style="display: flex; gap: 1rem; align-items: flex-end; margin-bottom: 1.5rem; flex-wrap: wrap;"
+            {
+                (csrf_token_field(&session.csrf_token))
+                div class="form-group" {
+                    label class="form-label" for="match-import-file" { (t.messages.match_import_file()) }
+                    input
+                        id="match-import-file"
+                        type="file"
+                        name="file"
+                        accept="text/csv,.csv,application/json,.json"
+                        required;
+                }
+                div class="form-group" {
+                    button type="submit" class="btn btn-primary" { (t.messages.match_import_submit()) }
+                }
+            }
+
+            div id="match-import-result" {}
+        }
+    }
+}
+
+/// Result of an uploaded match file: the created matches, or why nothing was created
+pub fn match_import_result(
+    t: &TranslationContext,
+    outcome: Result<&MatchImportOutcome, &str>,
+) -> Markup {
+    html! {
+        @match outcome {
+            Err(error) => {
+                div class="error" style="padding: 1rem;" { (error) }
+            }
+            Ok(MatchImportOutcome::Created(ids)) => {
+                div style="padding: 1rem; background: var(--green-50); color: var(--green-700); border-radius: 4px;" {
+                    (t.messages.match_import_created()) ": " strong { (ids.len()) }
+                    " · "
+                    a href="/matches" { (t.messages.match_import_view_matches()) }
+                }
+            }
+            Ok(MatchImportOutcome::Rejected(plan)) => {
+                div class="error" style="padding: 1rem; margin-bottom: 1rem;" {
+                    (t.messages.match_import_rejected())
+                }
+                table class="table" {
+                    thead {
+                        tr {
+                            th style="text-align: right;" { (t.messages.match_import_line()) }
+                            th { (t.messages.match_import_season()) }
+                            th { (t.messages.match_import_match()) }
+                            th { (t.messages.match_import_error()) }
+                        }
+                    }
+                    tbody {
+                        @for (row, error) in plan.errors() {
+                            tr {
+                                td style="text-align: right; color: var(--gray-500);" { (row.line) }
+                                td { (row.season) }
+                                td { (row.home_team) " – " (row.away_team) }
+                                td style="color: #b91c1c;" { (error.message()) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,293 @@
+use maud::{html, Markup, PreEscaped};
+
+use crate::i18n::TranslationContext;
+use crate::service::matches::{MatchEntity, ScoringPlayerEntity};
+
+use super::detail_page::status_badge;
+use super::scoring_components::team_player_options;
+
+/// Full-screen live console for scoring a match as it is played
+///
+/// Goals are posted to the regular score event endpoint; the inherited
+/// `return_to` value brings the browser back here after a save. The period
+/// clock only runs in the browser and is kept in local storage, so reloads
+/// don't reset it.
+pub fn match_console_page(
+    t: &TranslationContext,
+    match_info: &MatchEntity,
+    home_players: &[ScoringPlayerEntity],
+    away_players: &[ScoringPlayerEntity],
+) -> Markup {
+    let return_to =
+        serde_json::json!({ "return_to": format!("/matches/{}/console", match_info.id) });
+    let period_labels = serde_json::json!([
+        "1st",
+        "2nd",
+        "3rd",
+        t.messages.matches_overtime().to_string(),
+        t.messages.matches_shootout().to_string(),
+    ]);
+
+    html! {
+        div
+            id="match-console"
+            data-match-id=(match_info.id)
+            data-status=(match_info.status)
+            data-period-labels=(period_labels)
+            hx-vals=(return_to)
+            style="min-height: 100vh; padding: 1.5rem 2rem; background: var(--gray-900); color: white; display: flex; flex-direction: column; gap: 1.5rem;"
+        {
+            div style="display: flex; justify-content: space-between; align-items: center; gap: 1rem;" {
+                a href=(format!("/matches/{}", match_info.id)) class="btn btn-secondary" {
+                    (format!("← {}", t.messages.matches_console_back()))
+                }
+                (status_badge(&match_info.status))
+                span style="color: var(--gray-400); font-size: 0.875rem;" {
+                    (t.messages.matches_console_keys())
+                }
+            }
+
+            // Scoreboard
+            div style="display: grid; grid-template-columns: 1fr auto 1fr; align-items: center; gap: 2rem; text-align: center;" {
+                div style="font-size: 2.5rem; font-weight: 700;" { (match_info.home_team_name) }
+                div style="font-size: 8rem; font-weight: 800; line-height: 1; font-variant-numeric: tabular-nums;" {
+                    span id="console-home-score" { (match_info.home_score_total()) }
+                    " : "
+                    span id="console-away-score" { (match_info.away_score_total()) }
+                }
+                div style="font-size: 2.5rem; font-weight: 700;" { (match_info.away_team_name) }
+            }
+
+            // Period clock
+            div style="display: flex; justify-content: center; align-items: center; gap: 1.5rem;" {
+                span id="console-period" style="font-size: 2rem; font-weight: 600; color: var(--gray-300);" { "1st" }
+                span id="console-clock" style="font-size: 4rem; font-weight: 700; font-variant-numeric: tabular-nums;" { "00:00" }
+                button type="button" class="btn btn-primary" data-clock-action="toggle" {
+                    (t.messages.matches_console_clock_toggle()) " (Space)"
+                }
+                button type="button" class="btn btn-secondary" data-clock-action="next" {
+                    (t.messages.matches_console_next_period()) " (N)"
+                }
+                button type="button" class="btn btn-secondary" data-clock-action="reset" {
+                    (t.messages.matches_console_reset_clock())
+                }
+            }
+
+            div style="display: grid; grid-template-columns: 1fr 1fr; gap: 2rem;" {
+                (console_goal_form(t, match_info, "home", match_info.home_team_id, &match_info.home_team_name, home_players))
+                (console_goal_form(t, match_info, "away", match_info.away_team_id, &match_info.away_team_name, away_players))
+            }
+
+            div id="modal-container" style="color: var(--gray-900);" {}
+        }
+        (console_script())
+    }
+}
+
+/// Goal form of one team; the period and time are stamped from the clock
+/// when the form is opened
+fn console_goal_form(
+    t: &TranslationContext,
+    match_info: &MatchEntity,
+    side: &str,
+    team_id: i64,
+    team_name: &str,
+    players: &[ScoringPlayerEntity],
+) -> Markup {
+    let key = if side == "home" { "H" } else { "A" };
+    let field_style = "width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px; color: var(--gray-900);";
+
+    html! {
+        form
+            id=(format!("console-{}-goal", side))
+            class="console-goal-form"
+            hx-post=(format!("/matches/{}/score-events", match_info.id))
+            hx-target="#modal-container"
+            hx-swap="innerHTML"
+            style="padding: 1.5rem; background: var(--gray-800); border-radius: 8px; display: flex; flex-direction: column; gap: 0.75rem;"
+        {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
+                (t.messages.matches_console_goal()) " – " (team_name) " (" (key) ")"
+            }
+            input type="hidden" name="team_id" value=(team_id);
+
+            div style="display: grid; grid-template-columns: 1fr 1fr 1fr; gap: 0.75rem;" {
+                label {
+                    (t.messages.matches_period())
+                    select name="period" style=(field_style) {
+                        option value="1" { "1st" }
+                        option value="2" { "2nd" }
+                        option value="3" { "3rd" }
+                        option value="4" { (t.messages.matches_overtime()) }
+                        option value="5" { (t.messages.matches_shootout()) }
+                    }
+                }
+                label {
+                    (t.messages.matches_minutes())
+                    input type="number" name="time_minutes" min="0" max="60" style=(field_style);
+                }
+                label {
+                    (t.messages.matches_seconds())
+                    input type="number" name="time_seconds" min="0" max="59" style=(field_style);
+                }
+            }
+
+            @for (name, label) in [
+                ("scorer_id", t.messages.matches_goal_scorer().to_string()),
+                ("assist1_id", t.messages.matches_goal_assist_1().to_string()),
+                ("assist2_id", t.messages.matches_goal_assist_2().to_string()),
+            ] {
+                label {
+                    (label)
+                    select name=(name) style=(field_style) {
+                        option value="" { "---" }
+                        (team_player_options(t, team_name, players, None, false))
+                    }
+                }
+            }
+
+            label {
+                (t.messages.matches_goal_type())
+                select name="goal_type" style=(field_style) {
+                    option value="" { "---" }
+                    option value="even_strength" { (t.messages.matches_regular()) }
+                    option value="power_play" { (t.messages.matches_power_play()) }
+                    option value="short_handed" { (t.messages.matches_short_handed()) }
+                    option value="penalty_shot" { (t.messages.matches_penalty_shot()) }
+                    option value="empty_net" { (t.messages.matches_empty_net()) }
+                }
+            }
+
+            button type="submit" class="btn btn-primary" style="font-size: 1.25rem;" {
+                (t.messages.matches_console_save_goal()) " (Enter)"
+            }
+        }
+    }
+}
+
+/// Period clock and keyboard shortcuts
+///
+/// The clock counts up to 20:00 within a period. H and A open the home and
+/// away goal form stamped with the current period and time (with Shift as a
+/// penalty shot), Enter saves and Escape leaves the form. The score follows
+/// the match's live WebSocket.
+fn console_script() -> Markup {
+    html! {
+        script {
+            (PreEscaped(r#"
+(function() {
+    var page = document.getElementById('match-console');
+    if (!page) return;
+    var matchId = page.dataset.matchId;
+    var labels = JSON.parse(page.dataset.periodLabels);
+    var storageKey = 'match-console-' + matchId;
+    var periodSeconds = 20 * 60;
+    var clock = JSON.parse(localStorage.getItem(storageKey) || 'null')
+        || { period: 1, elapsed: 0, startedAt: null };
+
+    function elapsed() {
+        var seconds = clock.elapsed;
+        if (clock.startedAt) seconds += (Date.now() - clock.startedAt) / 1000;
+        return Math.min(Math.floor(seconds), periodSeconds);
+    }
+    function save() { localStorage.setItem(storageKey, JSON.stringify(clock)); }
+    function pad(n) { return (n < 10 ? '0' : '') + n; }
+    function render() {
+        var seconds = elapsed();
+        if (clock.startedAt && seconds >= periodSeconds) {
+            clock.elapsed = periodSeconds;
+            clock.startedAt = null;
+            save();
+        }
+        document.getElementById('console-period').textContent = labels[clock.period - 1] || clock.period;
+        document.getElementById('console-clock').textContent = pad(Math.floor(seconds / 60)) + ':' + pad(seconds % 60);
+    }
+    function toggle() {
+        if (clock.startedAt) {
+            clock.elapsed = elapsed();
+            clock.startedAt = null;
+        } else if (elapsed() < periodSeconds) {
+            clock.startedAt = Date.now();
+        }
+        save();
+        render();
+    }
+    function nextPeriod() {
+        clock = { period: Math.min(clock.period + 1, labels.length), elapsed: 0, startedAt: null };
+        save();
+        render();
+    }
+    function reset() {
+        clock = { period: 1, elapsed: 0, startedAt: null };
+        save();
+        render();
+    }
+    function openForm(side, penaltyShot) {
+        var form = document.getElementById('console-' + side + '-goal');
+        var seconds = elapsed();
+        form.elements.period.value = String(clock.period);
+        form.elements.time_minutes.value = Math.floor(seconds / 60);
+        form.elements.time_seconds.value = seconds % 60;
+        form.elements.goal_type.value = penaltyShot ? 'penalty_shot' : '';
+        form.elements.scorer_id.focus();
+    }
+
+    page.querySelectorAll('[data-clock-action]').forEach(function(button) {
+        button.addEventListener('click', function() {
+            ({ toggle: toggle, next: nextPeriod, reset: reset })[button.dataset.clockAction]();
+            button.blur();
+        });
+    });
+
+    document.addEventListener('keydown', function(evt) {
+        if (evt.ctrlKey || evt.metaKey || evt.altKey) return;
+        var form = evt.target.closest && evt.target.closest('.console-goal-form');
+        if (form) {
+            if (evt.key === 'Enter' && evt.target.tagName === 'SELECT') {
+                evt.preventDefault();
+                form.requestSubmit();
+            } else if (evt.key === 'Escape') {
+                evt.target.blur();
+            }
+            return;
+        }
+        if (evt.target.closest && evt.target.closest('#modal-container input, #modal-container select, #modal-container textarea')) return;
+        switch (evt.key.toLowerCase()) {
+            case ' ': evt.preventDefault(); toggle(); break;
+            case 'n': nextPeriod(); break;
+            case 'h': evt.preventDefault(); openForm('home', evt.shiftKey); break;
+            case 'a': evt.preventDefault(); openForm('away', evt.shiftKey); break;
+        }
+    });
+
+    render();
+    setInterval(render, 250);
+
+    if (!window.WebSocket) return;
+    var url = (location.protocol === 'https:' ? 'wss://' : 'ws://') + location.host
+        + '/public/api/matches/' + matchId + '/live';
+    var delay = 1000;
+    function connect() {
+        var socket = new WebSocket(url);
+        socket.onopen = function() { delay = 1000; };
+        socket.onmessage = function(evt) {
+            var update = JSON.parse(evt.data);
+            if (update.kind === 'deleted') {
+                window.location.href = '/matches';
+                return;
+            }
+            document.getElementById('console-home-score').textContent = update.home_score;
+            document.getElementById('console-away-score').textContent = update.away_score;
+            if (update.status !== page.dataset.status) window.location.reload();
+        };
+        socket.onclose = function() {
+            setTimeout(connect, delay);
+            delay = Math.min(delay * 2, 30000);
+        };
+    }
+    connect();
+})();
+            "#))
+        }
+    }
+}
@@ -1,15 +1,34 @@
-use maud::{html, Markup};
+use maud::{html, Markup, PreEscaped};
 
+use crate::assets;
 use crate::i18n::TranslationContext;
-use crate::service::matches::{MatchDetailEntity, ScoreEventEntity};
+use crate::service::comments::CommentEntityType;
+use crate::service::matches::{
+    faceoff_percentage, shooting_percentage, Decision, EmptyNetIssue, GameType, GoalFlags,
+    MatchDetailEntity, MatchResult,
+};
+use crate::service::player_contracts::Captaincy;
+use crate::service::referees::OfficialRole;
+use crate::service::tasks::TaskEntityType;
 use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
+use crate::views::pages::comments::entity_comments_section;
+use crate::views::pages::roster::captaincy_letter;
+use crate::views::pages::staff::staff_role_label;
+use crate::views::pages::tasks::entity_tasks_section;
 
 /// Match detail page with score tracking
 pub fn match_detail_page(t: &TranslationContext, detail: &MatchDetailEntity) -> Markup {
     let match_info = &detail.match_info;
 
     html! {
-        div class="card" {
+        div
+            class="card"
+            id="match-detail"
+            data-match-id=(match_info.id)
+            data-status=(match_info.status)
+            data-home-score=(detail.home_score_total)
+            data-away-score=(detail.away_score_total)
+        {
             // Header with back button and action buttons
             div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
                 div style="display: flex; align-items: center; gap: 1rem;" {
@@ -24,6 +43,26 @@ pub fn match_detail_page(t: &TranslationContext, detail: &MatchDetailEntity) ->
                     }
                 }
                 div style="display: flex; gap: 0.5rem;" {
+                    a
+                        href=(format!("/matches/{}/console", match_info.id))
+                        class="btn btn-secondary"
+                    {
+                        (t.messages.matches_console())
+                    }
+                    a
+                        href=(format!("/matches/{}/card.svg", match_info.id))
+                        class="btn btn-secondary"
+                        target="_blank"
+                        download=(format!("match-{}.svg", match_info.id))
+                    {
+                        (t.messages.matches_share_image())
+                    }
+                    a
+                        href=(format!("/matches/{}/history", match_info.id))
+                        class="btn btn-secondary"
+                    {
+                        (t.messages.history_view())
+                    }
                     button
                         class="btn btn-primary"
                         hx-get=(format!("/matches/{}/edit", match_info.id))
@@ -49,7 +88,7 @@ pub fn match_detail_page(t: &TranslationContext, detail: &MatchDetailEntity) ->
             }
 
             // Match Info Card
-            div style="margin-bottom: 2rem; padding: 1.5rem; background: var(--gray-50); border-radius: 8px;" {
+            div id="match-score" data-live-region style="margin-bottom: 2rem; padding: 1.5rem; background: var(--gray-50); border-radius: 8px;" {
                 // Match Score
                 div style="text-align: center; margin-bottom: 1.5rem;" {
                     div style="display: flex; justify-content: center; align-items: center; gap: 2rem; margin-bottom: 1rem;" {
@@ -73,6 +112,7 @@ pub fn match_detail_page(t: &TranslationContext, detail: &MatchDetailEntity) ->
                             (detail.home_score_total)
                             " : "
                             (detail.away_score_total)
+                            (decision_suffix(t, match_info.result()))
                         }
 
                         // Away Team
@@ -140,18 +180,47 @@ pub fn match_detail_page(t: &TranslationContext, detail: &MatchDetailEntity) ->
                             "Venue"
                         }
                         div style="font-weight: 500;" {
-                            @if let Some(venue) = &match_info.venue {
-                                (venue)
+                            @if let (Some(arena_id), Some(arena_name)) = (match_info.arena_id, &match_info.arena_name) {
+                                a
+                                    href=(format!("/matches?arena_id={}", arena_id))
+                                    class="primary-link"
+                                {
+                                    (arena_name)
+                                }
+                                @if let Some(city) = &match_info.arena_city {
+                                    span style="color: var(--gray-500);" { (format!(", {}", city)) }
+                                }
                             } @else {
                                 span style="color: var(--gray-400); font-style: italic;" { "TBD" }
                             }
                         }
                     }
+                    div {
+                        div style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 0.25rem;" {
+                            (t.messages.matches_game_type())
+                        }
+                        div style="font-weight: 500;" {
+                            a
+                                href=(format!("/matches?game_type={}", match_info.game_type().as_str()))
+                                class="primary-link"
+                            {
+                                (game_type_label(t, match_info.game_type()))
+                            }
+                        }
+                    }
+                    @if let Some(attendance) = match_info.attendance {
+                        div {
+                            div style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 0.25rem;" {
+                                (t.messages.matches_attendance())
+                            }
+                            div style="font-weight: 500;" { (attendance) }
+                        }
+                    }
                 }
             }
 
             // Score Breakdown
-            div style="margin-bottom: 2rem;" {
+            div id="match-score-breakdown" data-live-region style="margin-bottom: 2rem;" {
                 h2 style="font-size: 1.5rem; font-weight: 700; margin-bottom: 1rem;" {
                     "Score Breakdown"
                 }
@@ -173,6 +242,7 @@ pub fn match_detail_page(t: &TranslationContext, detail: &MatchDetailEntity) ->
                             span style="font-weight: 600;" { "Total" }
                             span style="font-weight: 700;" { (detail.home_score_total) }
                         }
+                        (shooting_rows(t, detail.team_shots_total(match_info.home_team_id), detail.home_score_total))
                     }
 
                     // Away Team Breakdown
@@ -192,53 +262,466 @@ pub fn match_detail_page(t: &TranslationContext, detail: &MatchDetailEntity) ->
                             span style="font-weight: 600;" { "Total" }
                             span style="font-weight: 700;" { (detail.away_score_total) }
                         }
+                        (shooting_rows(t, detail.team_shots_total(match_info.away_team_id), detail.away_score_total))
                     }
                 }
             }
 
-            // Score Events (Goals)
-            div {
-                div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1rem;" {
-                    h2 style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
-                        "Goals"
+            // Shots on goal per period
+            (shots_by_period(t, detail))
+
+            // Dressed lineups
+            (lineups(t, detail))
+
+            // Faceoffs per player
+            (faceoffs(t, detail))
+
+            // Coaches and managers of both teams
+            (bench_staff(t, detail))
+
+            // Referees and linesmen
+            (officials(t, detail))
+
+            // Score Events (Goals); the server pushes a fresh list when
+            // another editor changes them
+            div
+                id="match-goals"
+                hx-ext="sse"
+                sse-connect=(format!("/matches/{}/events", match_info.id))
+                sse-swap="goals"
+                hx-swap="innerHTML"
+            {
+                (match_goals(t, detail))
+            }
+
+            // Internal discussion about this match
+            (entity_comments_section(t, CommentEntityType::Match, match_info.id))
+
+            // Open tasks attached to this match
+            (entity_tasks_section(t, TaskEntityType::Match, match_info.id))
+
+            // Modal container
+            div id="modal-container" {}
+        }
+        script src=(assets::HTMX_SSE_SCRIPT_URL) {}
+        (live_updates_script())
+    }
+}
+
+/// Goals heading and list, also pushed to open pages over server-sent events
+pub fn match_goals(t: &TranslationContext, detail: &MatchDetailEntity) -> Markup {
+    let match_info = &detail.match_info;
+
+    html! {
+        div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
+                "Goals"
+            }
+            button
+                class="btn btn-primary"
+                hx-get=(format!("/matches/{}/score-events/new", match_info.id))
+                hx-target="#modal-container"
+                hx-swap="innerHTML"
+            {
+                "+ Identify Goal"
+            }
+        }
+
+        @if detail.score_events.is_empty() {
+            div style="padding: 3rem; text-align: center; color: var(--gray-500); background: var(--gray-50); border-radius: 8px;" {
+                p { "No goals identified yet." }
+                p style="margin-top: 0.5rem; font-size: 0.875rem;" {
+                    "Click 'Identify Goal' to assign goals to players."
+                }
+            }
+        } @else {
+            (score_events_list(t, detail, match_info.home_team_id, match_info.away_team_id))
+        }
+    }
+}
+
+/// Keep the score in sync with changes made in other browsers
+///
+/// Listens on the match's live WebSocket and re-renders the
+/// `data-live-region` sections from a fresh copy of the page; the rest of the
+/// page (open modals, comment drafts, the goals pushed over the event stream)
+/// is left alone. Reconnects with backoff.
+fn live_updates_script() -> Markup {
+    html! {
+        script {
+            (PreEscaped(r#"
+(function() {
+    var page = document.getElementById('match-detail');
+    if (!page || !window.WebSocket) return;
+    var url = (location.protocol === 'https:' ? 'wss://' : 'ws://') + location.host
+        + '/public/api/matches/' + page.dataset.matchId + '/live';
+    var shown = {
+        status: page.dataset.status,
+        home_score: Number(page.dataset.homeScore),
+        away_score: Number(page.dataset.awayScore)
+    };
+    var delay = 1000;
+
+    function refresh() {
+        fetch(location.pathname, { cache: 'no-store' })
+            .then(function(response) { return response.text(); })
+            .then(function(html) {
+                var fresh = new DOMParser().parseFromString(html, 'text/html');
+                page.querySelectorAll('[data-live-region]').forEach(function(region) {
+                    var replacement = fresh.getElementById(region.id);
+                    if (!replacement) return;
+                    region.replaceWith(replacement);
+                    htmx.process(replacement);
+                });
+            });
+    }
+
+    function connect() {
+        var socket = new WebSocket(url);
+        socket.onopen = function() { delay = 1000; };
+        socket.onmessage = function(evt) {
+            var update = JSON.parse(evt.data);
+            if (update.kind === 'deleted') {
+                window.location.href = '/matches';
+                return;
+            }
+            var changed = update.status !== shown.status
+                || update.home_score !== shown.home_score
+                || update.away_score !== shown.away_score;
+            shown = update;
+            // Edited goals keep the score, so they always refresh
+            if (changed || update.kind === 'score_event') refresh();
+        };
+        socket.onclose = function() {
+            setTimeout(connect, delay);
+            delay = Math.min(delay * 2, 30000);
+        };
+    }
+    connect();
+})();
+            "#))
+        }
+    }
+}
+
+/// Shots on goal and shooting percentage rows for a team's score breakdown
+fn shooting_rows(t: &TranslationContext, shots: Option<i32>, goals: i32) -> Markup {
+    html! {
+        div style="display: flex; justify-content: space-between; padding: 0.5rem 0; border-top: 1px solid var(--gray-200);" {
+            span { (t.messages.matches_shots_on_goal()) }
+            span style="font-weight: 600;" {
+                @match shots {
+                    Some(shots) => (shots),
+                    None => span style="color: var(--gray-400);" { "-" },
+                }
+            }
+        }
+        div style="display: flex; justify-content: space-between; padding: 0.5rem 0;" {
+            span { (t.messages.matches_shooting_percentage()) }
+            span style="font-weight: 600;" {
+                @match shots.and_then(|shots| shooting_percentage(goals.into(), shots.into())) {
+                    Some(pct) => (format!("{:.1}%", pct)),
+                    None => span style="color: var(--gray-400);" { "-" },
+                }
+            }
+        }
+    }
+}
+
+/// Shots on goal table broken down by period, with edit button
+fn shots_by_period(t: &TranslationContext, detail: &MatchDetailEntity) -> Markup {
+    let match_info = &detail.match_info;
+
+    html! {
+        div style="margin-bottom: 2rem;" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1rem;" {
+                h2 style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
+                    (t.messages.matches_shots_on_goal())
+                }
+                button
+                    class="btn btn-secondary"
+                    hx-get=(format!("/matches/{}/shots/edit", match_info.id))
+                    hx-target="#modal-container"
+                    hx-swap="innerHTML"
+                {
+                    (t.messages.matches_shots_edit())
+                }
+            }
+            @if detail.shots.is_empty() {
+                div style="padding: 1.5rem; text-align: center; color: var(--gray-500); background: var(--gray-50); border-radius: 8px;" {
+                    (t.messages.matches_shots_empty())
+                }
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.matches_period()) }
+                            th style="text-align: center;" { (match_info.home_team_name) }
+                            th style="text-align: center;" { (match_info.away_team_name) }
+                        }
                     }
-                    button
-                        class="btn btn-primary"
-                        hx-get=(format!("/matches/{}/score-events/new", match_info.id))
-                        hx-target="#modal-container"
-                        hx-swap="innerHTML"
-                    {
-                        "+ Identify Goal"
+                    tbody {
+                        @for period in super::modals::SHOT_PERIODS {
+                            @let home = detail.team_period_shots(match_info.home_team_id, period);
+                            @let away = detail.team_period_shots(match_info.away_team_id, period);
+                            @if home.is_some() || away.is_some() {
+                                tr {
+                                    td { (super::modals::shot_period_label(t, period)) }
+                                    td style="text-align: center;" { (home.map(|s| s.to_string()).unwrap_or_else(|| "-".to_string())) }
+                                    td style="text-align: center;" { (away.map(|s| s.to_string()).unwrap_or_else(|| "-".to_string())) }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Staff of both teams, taken from their participations in the match's season
+fn bench_staff(t: &TranslationContext, detail: &MatchDetailEntity) -> Markup {
+    let match_info = &detail.match_info;
+    let sides = [
+        (match_info.home_team_id, &match_info.home_team_name),
+        (match_info.away_team_id, &match_info.away_team_name),
+    ];
+
+    html! {
+        div style="margin-bottom: 2rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin: 0 0 1rem 0;" {
+                (t.messages.matches_staff())
+            }
+            div style="display: grid; grid-template-columns: 1fr 1fr; gap: 1.5rem;" {
+                @for (team_id, team_name) in sides {
+                    div style="padding: 1rem; background: var(--gray-50); border-radius: 8px;" {
+                        h3 style="font-size: 1rem; font-weight: 600; margin: 0 0 0.75rem 0;" { (team_name) }
+                        @let members: Vec<_> = detail.staff.iter().filter(|s| s.team_id == team_id).collect();
+                        @if members.is_empty() {
+                            div style="color: var(--gray-500); font-size: 0.875rem;" {
+                                (t.messages.matches_staff_empty())
+                            }
+                        } @else {
+                            @for member in members {
+                                div style="display: flex; justify-content: space-between; padding: 0.25rem 0;" {
+                                    span style="display: flex; align-items: center; gap: 0.375rem; font-weight: 500;" {
+                                        @if let Some(iso2) = &member.country_iso2_code {
+                                            flag-icon
+                                                country-code=(iso2.to_lowercase())
+                                                country-name=(member.name)
+                                                size="sm";
+                                        }
+                                        (member.name)
+                                    }
+                                    @if let Some(role) = member.role() {
+                                        span style="color: var(--gray-500); font-size: 0.875rem;" {
+                                            (staff_role_label(t, role))
+                                        }
+                                    }
+                                }
+                            }
+                        }
                     }
                 }
+            }
+        }
+    }
+}
 
-                @if detail.score_events.is_empty() {
-                    div style="padding: 3rem; text-align: center; color: var(--gray-500); background: var(--gray-50); border-radius: 8px;" {
-                        p { "No goals identified yet." }
-                        p style="margin-top: 0.5rem; font-size: 0.875rem;" {
-                            "Click 'Identify Goal' to assign goals to players."
+/// Referees and linesmen assigned to the match
+fn officials(t: &TranslationContext, detail: &MatchDetailEntity) -> Markup {
+    html! {
+        div style="margin-bottom: 2rem;" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1rem;" {
+                h2 style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
+                    (t.messages.matches_officials())
+                }
+                button
+                    class="btn btn-secondary btn-sm"
+                    hx-get=(format!("/matches/{}/officials/edit", detail.match_info.id))
+                    hx-target="#modal-container"
+                    hx-swap="innerHTML"
+                {
+                    (t.messages.matches_officials_edit())
+                }
+            }
+            @if detail.officials.is_empty() {
+                div style="color: var(--gray-500); font-size: 0.875rem;" {
+                    (t.messages.matches_officials_empty())
+                }
+            } @else {
+                div style="display: flex; flex-wrap: wrap; gap: 0.75rem;" {
+                    @for official in &detail.officials {
+                        div style="padding: 0.5rem 1rem; background: var(--gray-50); border-radius: 8px;" {
+                            div style="color: var(--gray-500); font-size: 0.75rem; text-transform: uppercase;" {
+                                @match official.role() {
+                                    Some(OfficialRole::Linesman) => (t.messages.matches_officials_linesman()),
+                                    _ => (t.messages.matches_officials_referee()),
+                                }
+                            }
+                            div style="display: flex; align-items: center; gap: 0.375rem; font-weight: 600;" {
+                                @if let Some(iso2) = &official.country_iso2_code {
+                                    flag-icon
+                                        country-code=(iso2.to_lowercase())
+                                        country-name=(official.name)
+                                        size="sm";
+                                }
+                                (official.name)
+                            }
                         }
                     }
-                } @else {
-                    (score_events_list(&detail.score_events, match_info.home_team_id, match_info.away_team_id))
                 }
             }
+        }
+    }
+}
 
-            // Modal container
-            div id="modal-container" {}
+/// Dressed lineups of both teams, each with an edit button
+fn lineups(t: &TranslationContext, detail: &MatchDetailEntity) -> Markup {
+    let match_info = &detail.match_info;
+    let sides = [
+        (match_info.home_team_id, &match_info.home_team_name),
+        (match_info.away_team_id, &match_info.away_team_name),
+    ];
+
+    html! {
+        div style="margin-bottom: 2rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin: 0 0 1rem 0;" {
+                (t.messages.matches_lineups())
+            }
+            div style="display: grid; grid-template-columns: 1fr 1fr; gap: 1.5rem;" {
+                @for (team_id, team_name) in sides {
+                    @let players = detail.team_lineup(team_id);
+                    div style="padding: 1rem; background: var(--gray-50); border-radius: 8px;" {
+                        div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 0.75rem;" {
+                            div {
+                                span style="font-weight: 600;" { (team_name) }
+                                @if !players.is_empty() {
+                                    span style="color: var(--gray-500); font-size: 0.875rem; margin-left: 0.5rem;" {
+                                        (format!("{} {}", players.len(), t.messages.matches_lineup_dressed()))
+                                    }
+                                }
+                            }
+                            button
+                                class="btn btn-secondary btn-sm"
+                                hx-get=(format!("/matches/{}/lineup/{}/edit", match_info.id, team_id))
+                                hx-target="#modal-container"
+                                hx-swap="innerHTML"
+                            {
+                                (t.messages.matches_lineup_edit())
+                            }
+                        }
+                        @if players.is_empty() {
+                            div style="color: var(--gray-500); font-size: 0.875rem;" {
+                                (t.messages.matches_lineup_empty())
+                            }
+                        } @else {
+                            ul style="list-style: none; padding: 0; margin: 0; columns: 2; font-size: 0.875rem;" {
+                                @for player in players {
+                                    li style="padding: 0.125rem 0;" {
+                                        @if let Some(number) = player.jersey_number {
+                                            span style="color: var(--gray-500); display: inline-block; min-width: 2rem;" { (format!("#{}", number)) }
+                                        }
+                                        (player.player_name)
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Faceoffs of both teams' players, each team with an edit button
+fn faceoffs(t: &TranslationContext, detail: &MatchDetailEntity) -> Markup {
+    let match_info = &detail.match_info;
+    let sides = [
+        (match_info.home_team_id, &match_info.home_team_name),
+        (match_info.away_team_id, &match_info.away_team_name),
+    ];
+
+    html! {
+        div style="margin-bottom: 2rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin: 0 0 1rem 0;" {
+                (t.messages.matches_faceoffs())
+            }
+            div style="display: grid; grid-template-columns: 1fr 1fr; gap: 1.5rem;" {
+                @for (team_id, team_name) in sides {
+                    @let players = detail.team_faceoffs(team_id);
+                    @let won: i64 = players.iter().map(|f| i64::from(f.won)).sum();
+                    @let lost: i64 = players.iter().map(|f| i64::from(f.lost)).sum();
+                    div style="padding: 1rem; background: var(--gray-50); border-radius: 8px;" {
+                        div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 0.75rem;" {
+                            div {
+                                span style="font-weight: 600;" { (team_name) }
+                                @if let Some(pct) = faceoff_percentage(won, lost) {
+                                    span style="color: var(--gray-500); font-size: 0.875rem; margin-left: 0.5rem;" {
+                                        (format!("{}–{} ({:.1}%)", won, lost, pct))
+                                    }
+                                }
+                            }
+                            button
+                                class="btn btn-secondary btn-sm"
+                                hx-get=(format!("/matches/{}/faceoffs/{}/edit", match_info.id, team_id))
+                                hx-target="#modal-container"
+                                hx-swap="innerHTML"
+                            {
+                                (t.messages.matches_faceoffs_edit())
+                            }
+                        }
+                        @if players.is_empty() {
+                            div style="color: var(--gray-500); font-size: 0.875rem;" {
+                                (t.messages.matches_faceoffs_empty())
+                            }
+                        } @else {
+                            table style="width: 100%; font-size: 0.875rem;" {
+                                thead {
+                                    tr style="color: var(--gray-500);" {
+                                        th style="text-align: left; font-weight: 500;" { (t.messages.matches_faceoffs_player()) }
+                                        th style="text-align: right; font-weight: 500;" { (t.messages.matches_faceoffs_won_short()) }
+                                        th style="text-align: right; font-weight: 500;" { (t.messages.matches_faceoffs_lost_short()) }
+                                        th style="text-align: right; font-weight: 500;" { (t.messages.matches_faceoffs_percentage()) }
+                                    }
+                                }
+                                tbody {
+                                    @for player in players {
+                                        tr {
+                                            td style="padding: 0.125rem 0;" {
+                                                a href=(format!("/players/{}", player.player_id)) { (player.player_name) }
+                                            }
+                                            td style="text-align: right;" { (player.won) }
+                                            td style="text-align: right;" { (player.lost) }
+                                            td style="text-align: right;" {
+                                                @match player.percentage() {
+                                                    Some(pct) => (format!("{:.1}%", pct)),
+                                                    None => span style="color: var(--gray-400);" { "-" },
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
         }
     }
 }
 
 /// Render score events list
 pub fn score_events_list(
-    events: &[ScoreEventEntity],
+    t: &TranslationContext,
+    detail: &MatchDetailEntity,
     home_team_id: i64,
     _away_team_id: i64,
 ) -> Markup {
     html! {
         div style="border: 1px solid var(--gray-200); border-radius: 8px; overflow: hidden;" {
-            @for event in events {
+            @for event in &detail.score_events {
+                @let flags = detail.goal_flags(event.id);
                 div style=(format!(
                     "display: flex; justify-content: space-between; align-items: center; padding: 1rem; border-bottom: 1px solid var(--gray-200); {}",
                     if event.team_id == home_team_id {
@@ -257,6 +740,20 @@ pub fn score_events_list(
                                 (format!("{}:{:02}", min, sec))
                             }
                         }
+                        @if let Some(link) = event.video_link() {
+                            a
+                                href=(link)
+                                target="_blank"
+                                rel="noopener noreferrer"
+                                title=(t.messages.matches_video_play())
+                                style="display: inline-block; margin-top: 0.25rem; font-size: 0.875rem; color: var(--primary-color); text-decoration: none;"
+                            {
+                                "▶"
+                                @if let Some(at) = event.video_timestamp_label() {
+                                    " " (at)
+                                }
+                            }
+                        }
                     }
 
                     // Team
@@ -269,6 +766,7 @@ pub fn score_events_list(
                                 (format_goal_type(goal_type))
                             }
                         }
+                        (goal_flag_badges(t, flags))
                     }
 
                     // Scorer and Assists
@@ -277,6 +775,7 @@ pub fn score_events_list(
                             span style="font-weight: 600;" { "Goal: " }
                             @if let Some(scorer_name) = &event.scorer_name {
                                 span { (scorer_name) }
+                                (score_event_captaincy(t, event.scorer_captaincy.as_deref()))
                             } @else {
                                 span style="color: var(--gray-400); font-style: italic;" { "Unknown" }
                             }
@@ -286,12 +785,14 @@ pub fn score_events_list(
                                 span { "Assists: " }
                                 @if let Some(assist1_name) = &event.assist1_name {
                                     span { (assist1_name) }
+                                    (score_event_captaincy(t, event.assist1_captaincy.as_deref()))
                                     @if event.assist2_name.is_some() {
                                         span { ", " }
                                     }
                                 }
                                 @if let Some(assist2_name) = &event.assist2_name {
                                     span { (assist2_name) }
+                                    (score_event_captaincy(t, event.assist2_captaincy.as_deref()))
                                 }
                             }
                         }
@@ -308,6 +809,15 @@ pub fn score_events_list(
                         {
                             "Edit"
                         }
+                        button
+                            class="btn btn-sm"
+                            hx-get=(format!("/matches/score-events/{}/on-ice/edit", event.id))
+                            hx-target="#modal-container"
+                            hx-swap="innerHTML"
+                            style="margin-right: 0.5rem;"
+                        {
+                            (t.messages.matches_on_ice())
+                        }
                         button
                             class="btn btn-sm btn-danger"
                             hx-post=(format!("/matches/score-events/{}/delete", event.id))
@@ -328,6 +838,42 @@ pub fn score_events_list(
     }
 }
 
+/// Game-winning and empty-net warning badges of a goal
+fn goal_flag_badges(t: &TranslationContext, flags: GoalFlags) -> Markup {
+    let badge = "display: inline-block; margin-top: 0.25rem; margin-right: 0.25rem; padding: 0.125rem 0.5rem; border-radius: 9999px; font-size: 0.75rem; font-weight: 600;";
+    html! {
+        @if flags.game_winning {
+            span
+                style=(format!("{} color: #15803d; background: #dcfce7;", badge))
+                title=(t.messages.matches_game_winning_goal())
+            {
+                (t.messages.matches_game_winning_goal_short())
+            }
+        }
+        @if let Some(issue) = flags.empty_net_issue {
+            span
+                style=(format!("{} color: #b45309; background: #fef3c7;", badge))
+                title=(match issue {
+                    EmptyNetIssue::Shootout => t.messages.matches_empty_net_in_shootout(),
+                    EmptyNetIssue::ScoredWhileTrailing => t.messages.matches_empty_net_while_trailing(),
+                })
+            {
+                "⚠ " (t.messages.matches_empty_net())
+            }
+        }
+    }
+}
+
+/// Captaincy letter after a scorer's or assistant's name
+fn score_event_captaincy(t: &TranslationContext, captaincy: Option<&str>) -> Markup {
+    html! {
+        @if let Some(captaincy) = captaincy.and_then(Captaincy::from_str) {
+            " "
+            (captaincy_letter(t, captaincy))
+        }
+    }
+}
+
 /// Format match status as a badge
 pub fn status_badge(status: &str) -> Markup {
     let text = match status {
@@ -356,6 +902,30 @@ pub fn status_badge(status: &str) -> Markup {
     }
 }
 
+/// "OT" or "SO" after the score of a match decided after regulation
+pub fn decision_suffix(t: &TranslationContext, result: Option<MatchResult>) -> Markup {
+    let label = match result.and_then(|r| r.decision()) {
+        Some(Decision::Overtime) => t.messages.matches_result_overtime(),
+        Some(Decision::Shootout) => t.messages.matches_result_shootout(),
+        Some(Decision::Regulation) | None => return html! {},
+    };
+    html! {
+        span style="font-size: 0.5em; font-weight: 600; color: var(--gray-500); margin-left: 0.35em;" {
+            (label)
+        }
+    }
+}
+
+/// Translated name of a game type
+pub fn game_type_label(t: &TranslationContext, game_type: GameType) -> String {
+    match game_type {
+        GameType::Group => t.messages.matches_game_type_group().to_string(),
+        GameType::Playoff => t.messages.matches_game_type_playoff().to_string(),
+        GameType::Relegation => t.messages.matches_game_type_relegation().to_string(),
+        GameType::Exhibition => t.messages.matches_game_type_exhibition().to_string(),
+    }
+}
+
 /// Format period number to readable name
 pub fn period_name(period: i32) -> &'static str {
     match period {
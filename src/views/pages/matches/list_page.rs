@@ -1,22 +1,42 @@
 use maud::{html, Markup};
 
-use crate::common::pagination::{PagedResult, SortOrder};
+use crate::common::pagination::{format_sort_keys, rerank_sort_keys, PagedResult, SortOrder};
 use crate::i18n::TranslationContext;
-use crate::service::matches::{MatchEntity, MatchFilters, SortField};
+use crate::service::matches::{
+    GameType, MatchEntity, MatchFilters, ScoreCoverage, SortField, STATUS_FILTERS,
+    STATUS_NOT_CANCELLED,
+};
 use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
 use crate::views::components::crud::{empty_state_i18n, page_header_i18n, pagination};
 
-use super::detail_page::{format_date, status_badge};
+use super::detail_page::{decision_suffix, format_date, game_type_label, status_badge};
+
+/// Translated option of the status filter
+pub fn status_filter_label(t: &TranslationContext, status: &str) -> String {
+    match status {
+        STATUS_NOT_CANCELLED => t.messages.matches_status_not_cancelled().to_string(),
+        "scheduled" => t.messages.matches_status_scheduled().to_string(),
+        "in_progress" => t.messages.matches_status_in_progress().to_string(),
+        "finished" => t.messages.matches_status_finished().to_string(),
+        "cancelled" => t.messages.matches_status_cancelled().to_string(),
+        other => other.to_string(),
+    }
+}
 
 /// Main matches page with table and filters
+#[allow(clippy::too_many_arguments)]
 pub fn matches_page(
     t: &TranslationContext,
     result: &PagedResult<MatchEntity>,
     filters: &MatchFilters,
     sort_field: &SortField,
     sort_order: &SortOrder,
+    then_by: &[(SortField, SortOrder)],
     seasons: &[(i64, String)],
     teams: &[(i64, String)],
+    arenas: &[(i64, String)],
+    groups: &[(i64, String)],
+    defaults_applied: bool,
 ) -> Markup {
     html! {
         div class="card" {
@@ -28,10 +48,18 @@ pub fn matches_page(
                 &t.messages.matches_new().to_string()
             ))
 
+            // Default filters from the settings page were used
+            @if defaults_applied {
+                div class="info" style="margin-bottom: 1rem; display: flex; justify-content: space-between; align-items: center; gap: 1rem;" {
+                    span { (t.messages.matches_defaults_applied()) }
+                    a href="/matches?page=1" class="btn btn-sm btn-secondary" { (t.messages.matches_show_all()) }
+                }
+            }
+
             // Filters
             div style="margin-bottom: 1.5rem; padding: 1rem; background: var(--gray-50); border-radius: 8px;" {
                 form hx-get="/matches/list" hx-target="#matches-table" hx-swap="outerHTML" hx-trigger="submit, change delay:300ms" {
-                    div style="display: grid; grid-template-columns: repeat(3, 1fr) auto; gap: 1rem; align-items: end;" {
+                    div style="display: grid; grid-template-columns: repeat(4, 1fr) auto; gap: 1rem; align-items: end;" {
                         // Season filter
                         div {
                             label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
@@ -84,29 +112,34 @@ pub fn matches_page(
                                 style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
                             {
                                 option value="" { (t.messages.matches_all_statuses()) }
-                                option
-                                    value="scheduled"
-                                    selected[filters.status.as_deref() == Some("scheduled")]
-                                {
-                                    (t.messages.matches_status_scheduled())
-                                }
-                                option
-                                    value="in_progress"
-                                    selected[filters.status.as_deref() == Some("in_progress")]
-                                {
-                                    (t.messages.matches_status_in_progress())
-                                }
-                                option
-                                    value="finished"
-                                    selected[filters.status.as_deref() == Some("finished")]
-                                {
-                                    (t.messages.matches_status_finished())
+                                @for status in STATUS_FILTERS {
+                                    option
+                                        value=(status)
+                                        selected[filters.status.as_deref() == Some(status)]
+                                    {
+                                        (status_filter_label(t, status))
+                                    }
                                 }
-                                option
-                                    value="cancelled"
-                                    selected[filters.status.as_deref() == Some("cancelled")]
-                                {
-                                    (t.messages.matches_status_cancelled())
+                            }
+                        }
+
+                        // Group filter
+                        div {
+                            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                                (t.messages.matches_filter_group())
+                            }
+                            select
+                                name="group_id"
+                                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
+                            {
+                                option value="" { (t.messages.matches_all_groups()) }
+                                @for (id, name) in groups {
+                                    option
+                                        value=(id)
+                                        selected[filters.group_id == Some(*id)]
+                                    {
+                                        (name)
+                                    }
                                 }
                             }
                         }
@@ -125,8 +158,8 @@ pub fn matches_page(
                         }
                     }
 
-                    // Date range filters (second row)
-                    div style="display: grid; grid-template-columns: repeat(2, 1fr); gap: 1rem; margin-top: 1rem;" {
+                    // Date range, coverage, arena and game type filters (second row)
+                    div style="display: grid; grid-template-columns: repeat(5, 1fr); gap: 1rem; margin-top: 1rem;" {
                         div {
                             label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
                                 (t.messages.matches_filter_date_from())
@@ -148,12 +181,81 @@ pub fn matches_page(
                                 value=[filters.date_to.as_ref()]
                                 style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
                         }
+
+                        div {
+                            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                                (t.messages.matches_filter_coverage())
+                            }
+                            select
+                                name="coverage"
+                                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
+                            {
+                                option value="" { (t.messages.matches_all_coverage()) }
+                                @for coverage in [ScoreCoverage::Full, ScoreCoverage::Partial, ScoreCoverage::AggregateOnly, ScoreCoverage::NoGoals] {
+                                    option
+                                        value=(coverage.as_str())
+                                        selected[filters.coverage == Some(coverage)]
+                                    {
+                                        (coverage_label(t, coverage))
+                                    }
+                                }
+                            }
+                        }
+
+                        div {
+                            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                                (t.messages.matches_filter_arena())
+                            }
+                            select
+                                name="arena_id"
+                                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
+                            {
+                                option value="" { (t.messages.matches_all_arenas()) }
+                                @for (id, name) in arenas {
+                                    option
+                                        value=(id)
+                                        selected[filters.arena_id == Some(*id)]
+                                    {
+                                        (name)
+                                    }
+                                }
+                            }
+                        }
+
+                        div {
+                            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                                (t.messages.matches_game_type())
+                            }
+                            select
+                                name="game_type"
+                                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
+                            {
+                                option value="" { (t.messages.matches_all_game_types()) }
+                                @for game_type in GameType::ALL {
+                                    option
+                                        value=(game_type.as_str())
+                                        selected[filters.game_type == Some(game_type)]
+                                    {
+                                        (game_type_label(t, game_type))
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    label style="display: inline-flex; align-items: center; gap: 0.5rem; cursor: pointer; margin-top: 1rem;" {
+                        input
+                            type="checkbox"
+                            name="date_unknown"
+                            value="true"
+                            checked[filters.date_unknown];
+                        span { (t.messages.matches_filter_date_unknown()) }
                     }
                 }
             }
 
             // Table
-            (match_list_content(t, result, filters, sort_field, sort_order))
+            (match_list_content(t, result, filters, sort_field, sort_order, then_by))
 
             // Modal container
             div id="modal-container" {}
@@ -168,6 +270,7 @@ pub fn match_list_content(
     filters: &MatchFilters,
     sort_field: &SortField,
     sort_order: &SortOrder,
+    then_by: &[(SortField, SortOrder)],
 ) -> Markup {
     html! {
         div id="matches-table" class="loading-overlay" {
@@ -180,9 +283,25 @@ pub fn match_list_content(
                 (empty_state_i18n(
                     &t.messages.matches_empty_title().to_string(),
                     &t.messages.matches_empty_message().to_string(),
-                    filters.season_id.is_some() || filters.team_id.is_some() || filters.status.is_some() || filters.date_from.is_some() || filters.date_to.is_some()
+                    filters.season_id.is_some() || filters.team_id.is_some() || filters.status.is_some() || filters.date_from.is_some() || filters.date_to.is_some() || filters.coverage.is_some() || filters.arena_id.is_some() || filters.game_type.is_some() || filters.group_id.is_some() || filters.date_unknown
                 ))
             } @else {
+                div style="display: flex; justify-content: flex-end; gap: 0.5rem; margin-bottom: 0.75rem;" {
+                    a
+                        href=(build_list_url("/matches/export", sort_field, sort_order, then_by, filters))
+                        class="btn btn-secondary btn-sm"
+                        download
+                    {
+                        (t.messages.list_export_csv())
+                    }
+                    a
+                        href=(build_list_url("/matches/score-events/export", sort_field, sort_order, then_by, filters))
+                        class="btn btn-secondary btn-sm"
+                        download
+                    {
+                        (t.messages.matches_export_goals())
+                    }
+                }
                 table class="table" {
                     thead {
                         tr {
@@ -192,6 +311,7 @@ pub fn match_list_content(
                                     &SortField::Date,
                                     sort_field,
                                     sort_order,
+                                    then_by,
                                     filters,
                                 ))
                             }
@@ -201,6 +321,7 @@ pub fn match_list_content(
                                     &SortField::Event,
                                     sort_field,
                                     sort_order,
+                                    then_by,
                                     filters,
                                 ))
                             }
@@ -212,6 +333,7 @@ pub fn match_list_content(
                                     &SortField::Status,
                                     sort_field,
                                     sort_order,
+                                    then_by,
                                     filters,
                                 ))
                             }
@@ -225,8 +347,10 @@ pub fn match_list_content(
                                 td {
                                     @if let Some(date) = &match_item.match_date {
                                         (format_date(date))
-                                    } @else {
+                                    } @else if match_item.status == "scheduled" {
                                         span style="color: var(--gray-400); font-style: italic;" { "TBD" }
+                                    } @else {
+                                        span style="color: var(--gray-400); font-style: italic;" { (t.messages.matches_date_unknown()) }
                                     }
                                 }
 
@@ -270,15 +394,22 @@ pub fn match_list_content(
                                 // Score
                                 td {
                                     div style="font-weight: 600; font-size: 1.1rem;" {
-                                        (match_item.home_score_unidentified)
+                                        (match_item.home_score_total())
                                         " : "
-                                        (match_item.away_score_unidentified)
+                                        (match_item.away_score_total())
+                                        (decision_suffix(t, match_item.result()))
                                     }
+                                    (coverage_badge(t, match_item.score_coverage()))
                                 }
 
                                 // Status
                                 td {
                                     (status_badge(&match_item.status))
+                                    @if match_item.game_type() != GameType::Group {
+                                        div style="color: var(--gray-600); font-size: 0.75rem; margin-top: 0.25rem;" {
+                                            (game_type_label(t, match_item.game_type()))
+                                        }
+                                    }
                                 }
 
                                 // Actions
@@ -301,7 +432,7 @@ pub fn match_list_content(
                                     }
                                     button
                                         class="btn btn-sm btn-danger"
-                                        hx-post=(build_delete_url(match_item.id, filters, sort_field, sort_order))
+                                        hx-post=(build_delete_url(match_item.id, filters, sort_field, sort_order, then_by))
                                         hx-target="#matches-table"
                                         hx-swap="outerHTML"
                                         hx-confirm-custom=(confirm_attrs(
@@ -329,7 +460,14 @@ pub fn match_list_content(
                 (pagination(
                     result,
                     "matches",
-                    |page| build_pagination_url(page, result.page_size, filters, sort_field, sort_order),
+                    |page| {
+                        let url = build_pagination_url(page, result.page_size, filters, sort_field, sort_order, then_by);
+                        // "Next" continues after the last row instead of skipping an offset
+                        match &result.next_cursor {
+                            Some(cursor) if page == result.page + 1 => format!("{}&after={}", url, cursor),
+                            _ => url,
+                        }
+                    },
                     "matches-table"
                 ))
             }
@@ -337,21 +475,55 @@ pub fn match_list_content(
     }
 }
 
+fn coverage_label(t: &TranslationContext, coverage: ScoreCoverage) -> String {
+    match coverage {
+        ScoreCoverage::Full => t.messages.matches_coverage_full().to_string(),
+        ScoreCoverage::Partial => t.messages.matches_coverage_partial().to_string(),
+        ScoreCoverage::AggregateOnly => t.messages.matches_coverage_aggregate().to_string(),
+        ScoreCoverage::NoGoals => t.messages.matches_coverage_none().to_string(),
+    }
+}
+
+/// Small pill showing how much of the score is backed by score events
+fn coverage_badge(t: &TranslationContext, coverage: ScoreCoverage) -> Markup {
+    let colors = match coverage {
+        ScoreCoverage::Full => "color: #15803d; background: #dcfce7;",
+        ScoreCoverage::Partial => "color: #a16207; background: #fef9c3;",
+        ScoreCoverage::AggregateOnly => "color: #374151; background: #f3f4f6;",
+        // Nothing to cover yet, keep the row clean
+        ScoreCoverage::NoGoals => return html! {},
+    };
+
+    html! {
+        span
+            title=(t.messages.matches_coverage_hint())
+            style=(format!(
+                "display: inline-block; margin-top: 0.25rem; padding: 0.125rem 0.5rem; border-radius: 9999px; font-size: 0.75rem; font-weight: 500; {}",
+                colors
+            ))
+        {
+            (coverage_label(t, coverage))
+        }
+    }
+}
+
 /// Sortable table header
+///
+/// Clicking an inactive column makes it the primary sort and demotes the
+/// current primary sort to a tie-breaker.
 fn sortable_header(
     label: &str,
     field: &SortField,
     current_sort: &SortField,
     current_order: &SortOrder,
+    then_by: &[(SortField, SortOrder)],
     filters: &MatchFilters,
 ) -> Markup {
     // Determine if this column is currently sorted
-    let is_active = matches!(
-        (field, current_sort),
-        (SortField::Date, SortField::Date)
-            | (SortField::Status, SortField::Status)
-            | (SortField::Event, SortField::Event)
-    );
+    let is_active = field.as_str() == current_sort.as_str();
+    let secondary_rank = then_by
+        .iter()
+        .position(|(f, _)| f.as_str() == field.as_str());
 
     // If this column is active, toggle the order; otherwise default to DESC for date, ASC for others
     let next_order = if is_active {
@@ -362,18 +534,23 @@ fn sortable_header(
             _ => SortOrder::Asc,
         }
     };
+    let next_then = rerank_sort_keys(field, current_sort, current_order, then_by);
 
     // Build the URL
-    let url = build_sort_url(field, &next_order, filters);
+    let url = build_sort_url(field, &next_order, &next_then, filters);
 
     // Choose the indicator
+    let arrow = |order: &SortOrder| match order {
+        SortOrder::Asc => "↑",
+        SortOrder::Desc => "↓",
+    };
     let indicator = if is_active {
-        match current_order {
-            SortOrder::Asc => "↑",
-            SortOrder::Desc => "↓",
-        }
+        arrow(current_order).to_string()
+    } else if let Some(rank) = secondary_rank {
+        // Tie-breakers show their position in the sort order
+        format!("{}{}", arrow(&then_by[rank].1), rank + 2)
     } else {
-        "↕"
+        "↕".to_string()
     };
 
     html! {
@@ -385,7 +562,7 @@ fn sortable_header(
             style="background: none; border: none; cursor: pointer; padding: 0; font-weight: 600; display: flex; align-items: center; gap: 0.25rem;"
         {
             (label)
-            span style=(if is_active { "font-size: 0.75rem; color: var(--primary-color);" } else { "font-size: 0.75rem;" }) {
+            span style=(if is_active { "font-size: 0.75rem; color: var(--primary-color);" } else if secondary_rank.is_some() { "font-size: 0.75rem; color: var(--gray-500);" } else { "font-size: 0.75rem;" }) {
                 (indicator)
             }
         }
@@ -393,12 +570,28 @@ fn sortable_header(
 }
 
 /// Helper to build sort URLs
-fn build_sort_url(field: &SortField, order: &SortOrder, filters: &MatchFilters) -> String {
-    let mut url = format!(
-        "/matches/list?sort={}&order={}",
-        field.as_str(),
-        order.as_str()
-    );
+fn build_sort_url(
+    field: &SortField,
+    order: &SortOrder,
+    then_by: &[(SortField, SortOrder)],
+    filters: &MatchFilters,
+) -> String {
+    build_list_url("/matches/list", field, order, then_by, filters)
+}
+
+/// URL of `path` carrying the list's sorting and filters
+fn build_list_url(
+    path: &str,
+    field: &SortField,
+    order: &SortOrder,
+    then_by: &[(SortField, SortOrder)],
+    filters: &MatchFilters,
+) -> String {
+    let mut url = format!("{}?sort={}&order={}", path, field.as_str(), order.as_str());
+
+    if !then_by.is_empty() {
+        url.push_str(&format!("&then={}", format_sort_keys(then_by)));
+    }
 
     if let Some(season_id) = filters.season_id {
         url.push_str(&format!("&season_id={}", season_id));
@@ -420,6 +613,26 @@ fn build_sort_url(field: &SortField, order: &SortOrder, filters: &MatchFilters)
         url.push_str(&format!("&date_to={}", urlencoding::encode(date_to)));
     }
 
+    if let Some(coverage) = &filters.coverage {
+        url.push_str(&format!("&coverage={}", coverage.as_str()));
+    }
+
+    if let Some(arena_id) = filters.arena_id {
+        url.push_str(&format!("&arena_id={}", arena_id));
+    }
+
+    if let Some(game_type) = filters.game_type {
+        url.push_str(&format!("&game_type={}", game_type.as_str()));
+    }
+
+    if let Some(group_id) = filters.group_id {
+        url.push_str(&format!("&group_id={}", group_id));
+    }
+
+    if filters.date_unknown {
+        url.push_str("&date_unknown=true");
+    }
+
     url
 }
 
@@ -430,6 +643,7 @@ pub fn build_pagination_url(
     filters: &MatchFilters,
     sort_field: &SortField,
     sort_order: &SortOrder,
+    then_by: &[(SortField, SortOrder)],
 ) -> String {
     let mut url = format!(
         "/matches/list?page={}&page_size={}&sort={}&order={}",
@@ -439,6 +653,10 @@ pub fn build_pagination_url(
         sort_order.as_str()
     );
 
+    if !then_by.is_empty() {
+        url.push_str(&format!("&then={}", format_sort_keys(then_by)));
+    }
+
     if let Some(season_id) = filters.season_id {
         url.push_str(&format!("&season_id={}", season_id));
     }
@@ -459,6 +677,26 @@ pub fn build_pagination_url(
         url.push_str(&format!("&date_to={}", urlencoding::encode(date_to)));
     }
 
+    if let Some(coverage) = &filters.coverage {
+        url.push_str(&format!("&coverage={}", coverage.as_str()));
+    }
+
+    if let Some(arena_id) = filters.arena_id {
+        url.push_str(&format!("&arena_id={}", arena_id));
+    }
+
+    if let Some(game_type) = filters.game_type {
+        url.push_str(&format!("&game_type={}", game_type.as_str()));
+    }
+
+    if let Some(group_id) = filters.group_id {
+        url.push_str(&format!("&group_id={}", group_id));
+    }
+
+    if filters.date_unknown {
+        url.push_str("&date_unknown=true");
+    }
+
     url
 }
 
@@ -468,6 +706,7 @@ fn build_delete_url(
     filters: &MatchFilters,
     sort_field: &SortField,
     sort_order: &SortOrder,
+    then_by: &[(SortField, SortOrder)],
 ) -> String {
     let mut url = format!(
         "/matches/{}/delete?sort={}&order={}",
@@ -476,6 +715,10 @@ fn build_delete_url(
         sort_order.as_str()
     );
 
+    if !then_by.is_empty() {
+        url.push_str(&format!("&then={}", format_sort_keys(then_by)));
+    }
+
     if let Some(season_id) = filters.season_id {
         url.push_str(&format!("&season_id={}", season_id));
     }
@@ -496,5 +739,25 @@ fn build_delete_url(
         url.push_str(&format!("&date_to={}", urlencoding::encode(date_to)));
     }
 
+    if let Some(coverage) = &filters.coverage {
+        url.push_str(&format!("&coverage={}", coverage.as_str()));
+    }
+
+    if let Some(arena_id) = filters.arena_id {
+        url.push_str(&format!("&arena_id={}", arena_id));
+    }
+
+    if let Some(game_type) = filters.game_type {
+        url.push_str(&format!("&game_type={}", game_type.as_str()));
+    }
+
+    if let Some(group_id) = filters.group_id {
+        url.push_str(&format!("&group_id={}", group_id));
+    }
+
+    if filters.date_unknown {
+        url.push_str("&date_unknown=true");
+    }
+
     url
 }
@@ -1,9 +1,15 @@
+mod console;
 mod detail_page;
 mod list_page;
 mod modals;
+mod quick_create;
 mod scoring_components;
+mod share_card;
 
+pub use self::console::*;
 pub use self::detail_page::*;
 pub use self::list_page::*;
 pub use self::modals::*;
+pub use self::quick_create::*;
 pub use self::scoring_components::*;
+pub use self::share_card::*;
@@ -1,37 +1,36 @@
 use maud::{html, Markup};
 
 use crate::i18n::TranslationContext;
-use crate::service::matches::MatchEntity;
+use crate::service::matches::{
+    GameType, LineupPlayerEntity, MatchEntity, MatchFaceoffsEntity, MatchShotsEntity,
+    ScoringPlayerEntity,
+};
+use crate::service::referees::OfficialRole;
 use crate::views::components::crud::modal_form_i18n;
 use crate::views::components::loading::htmx_loading_indicator;
 
+use super::detail_page::game_type_label;
+use super::quick_create::{match_season_select, match_team_select, quick_create_button, MatchSide};
+
 /// Create match modal
 pub fn match_create_modal(
     t: &TranslationContext,
     error: Option<&str>,
     seasons: &[(i64, String)],
     teams: &[(i64, String)],
+    arenas: &[(i64, String)],
 ) -> Markup {
     let form_fields = html! {
         div class="form-row" style="margin-bottom: 1rem;" {
             div class="form-group" {
-                label class="form-label" {
-                    (t.messages.matches_season())
-                    span class="required-indicator" { " *" }
-                }
-                select
-                    name="season_id"
-                    required
-                    hx-get="/matches/teams-for-season"
-                    hx-swap="none"
-                    hx-trigger="change"
-                    hx-indicator="#season-loading-create"
-                {
-                    option value="" { (t.messages.matches_select_season()) }
-                    @for (id, name) in seasons {
-                        option value=(id) { (name) }
+                div style="display: flex; justify-content: space-between; align-items: baseline;" {
+                    label class="form-label" {
+                        (t.messages.matches_season())
+                        span class="required-indicator" { " *" }
                     }
+                    (quick_create_button(t, "/matches/quick-create/season", None))
                 }
+                (match_season_select(t, seasons, None, false))
                 (htmx_loading_indicator("season-loading-create", Some(&t.messages.matches_loading_teams().to_string())))
             }
 
@@ -51,39 +50,25 @@ pub fn match_create_modal(
 
         div class="form-row" style="margin-bottom: 1rem;" {
             div class="form-group" {
-                label class="form-label" {
-                    (t.messages.matches_home_team())
-                    span class="required-indicator" { " *" }
-                }
-                select
-                    name="home_team_id"
-                    id="home_team_id"
-                    class="team-select"
-                    required
-                {
-                    option value="" { (t.messages.matches_select_team()) }
-                    @for (id, name) in teams {
-                        option value=(id) { (name) }
+                div style="display: flex; justify-content: space-between; align-items: baseline;" {
+                    label class="form-label" {
+                        (t.messages.matches_home_team())
+                        span class="required-indicator" { " *" }
                     }
+                    (quick_create_button(t, "/matches/quick-create/team", Some(MatchSide::Home)))
                 }
+                (match_team_select(t, MatchSide::Home, teams, None, false))
             }
 
             div class="form-group" {
-                label class="form-label" {
-                    (t.messages.matches_away_team())
-                    span class="required-indicator" { " *" }
-                }
-                select
-                    name="away_team_id"
-                    id="away_team_id"
-                    class="team-select"
-                    required
-                {
-                    option value="" { (t.messages.matches_select_team()) }
-                    @for (id, name) in teams {
-                        option value=(id) { (name) }
+                div style="display: flex; justify-content: space-between; align-items: baseline;" {
+                    label class="form-label" {
+                        (t.messages.matches_away_team())
+                        span class="required-indicator" { " *" }
                     }
+                    (quick_create_button(t, "/matches/quick-create/team", Some(MatchSide::Away)))
                 }
+                (match_team_select(t, MatchSide::Away, teams, None, false))
             }
         }
 
@@ -120,14 +105,40 @@ pub fn match_create_modal(
                 name="match_date";
         }
 
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.matches_game_type())
+            }
+            select name="game_type" {
+                @for game_type in GameType::ALL {
+                    option value=(game_type.as_str()) selected[game_type == GameType::Group] {
+                        (game_type_label(t, game_type))
+                    }
+                }
+            }
+        }
+
         div class="form-group" {
             label class="form-label" {
                 (t.messages.matches_location())
             }
+            select name="arena_id" {
+                option value="" { (t.messages.matches_no_arena()) }
+                @for (id, name) in arenas {
+                    option value=(id) { (name) }
+                }
+            }
+        }
+
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.matches_attendance())
+            }
             input
-                type="text"
-                name="venue"
-                placeholder=(t.messages.matches_location_placeholder());
+                type="number"
+                name="attendance"
+                min="0"
+                max="200000";
         }
     };
 
@@ -149,6 +160,7 @@ pub fn match_edit_modal(
     error: Option<&str>,
     seasons: &[(i64, String)],
     teams: &[(i64, String)],
+    arenas: &[(i64, String)],
 ) -> Markup {
     let form_fields = html! {
         div class="form-row" style="margin-bottom: 1rem;" {
@@ -271,15 +283,43 @@ pub fn match_edit_modal(
                 value=[match_entity.match_date.as_ref()];
         }
 
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.matches_game_type())
+            }
+            select name="game_type" {
+                @for game_type in GameType::ALL {
+                    option value=(game_type.as_str()) selected[match_entity.game_type() == game_type] {
+                        (game_type_label(t, game_type))
+                    }
+                }
+            }
+        }
+
         div class="form-group" {
             label class="form-label" {
                 (t.messages.matches_location())
             }
+            select name="arena_id" {
+                option value="" { (t.messages.matches_no_arena()) }
+                @for (id, name) in arenas {
+                    option value=(id) selected[match_entity.arena_id == Some(*id)] {
+                        (name)
+                    }
+                }
+            }
+        }
+
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.matches_attendance())
+            }
             input
-                type="text"
-                name="venue"
-                value=[match_entity.venue.as_ref()]
-                placeholder=(t.messages.matches_location_placeholder());
+                type="number"
+                name="attendance"
+                min="0"
+                max="200000"
+                value=[match_entity.attendance];
         }
     };
 
@@ -293,3 +333,271 @@ pub fn match_edit_modal(
         &t.messages.common_cancel().to_string(),
     )
 }
+
+/// Periods that can have shots on goal recorded (4 = overtime)
+pub const SHOT_PERIODS: [i32; 4] = [1, 2, 3, 4];
+
+/// Edit shots on goal modal (per team and period)
+pub fn match_shots_modal(
+    t: &TranslationContext,
+    error: Option<&str>,
+    match_entity: &MatchEntity,
+    shots: &[MatchShotsEntity],
+) -> Markup {
+    let value_for = |team_id: i64, period: i32| {
+        shots
+            .iter()
+            .find(|s| s.team_id == team_id && s.period == period)
+            .map(|s| s.shots)
+    };
+
+    let form_fields = html! {
+        p style="margin-bottom: 1rem; color: var(--gray-600); font-size: 0.875rem;" {
+            (t.messages.matches_shots_hint())
+        }
+        table class="table" style="margin-bottom: 1rem;" {
+            thead {
+                tr {
+                    th { (t.messages.matches_period()) }
+                    th { (match_entity.home_team_name) }
+                    th { (match_entity.away_team_name) }
+                }
+            }
+            tbody {
+                @for period in SHOT_PERIODS {
+                    tr {
+                        td { (shot_period_label(t, period)) }
+                        @for (prefix, team_id) in [("home", match_entity.home_team_id), ("away", match_entity.away_team_id)] {
+                            td {
+                                input
+                                    type="number"
+                                    name=(format!("{}_{}", prefix, period))
+                                    min="0"
+                                    max="200"
+                                    value=[value_for(team_id, period)]
+                                    style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    modal_form_i18n(
+        "match-shots-modal",
+        &t.messages.matches_shots_edit_title().to_string(),
+        error,
+        &format!("/matches/{}/shots", match_entity.id),
+        form_fields,
+        &t.messages.common_save().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Short period label used in the shots table and modal
+pub fn shot_period_label(t: &TranslationContext, period: i32) -> String {
+    match period {
+        4 => t.messages.matches_overtime().to_string(),
+        _ => period.to_string(),
+    }
+}
+
+/// Edit one team's dressed lineup for a match
+pub fn match_lineup_modal(
+    t: &TranslationContext,
+    error: Option<&str>,
+    match_entity: &MatchEntity,
+    team_id: i64,
+    team_name: &str,
+    candidates: &[LineupPlayerEntity],
+) -> Markup {
+    let form_fields = html! {
+        p style="margin-bottom: 1rem; color: var(--gray-600); font-size: 0.875rem;" {
+            (t.messages.matches_lineup_hint())
+        }
+        @if candidates.is_empty() {
+            div style="padding: 1.5rem; text-align: center; color: var(--gray-500); background: var(--gray-50); border-radius: 8px; margin-bottom: 1rem;" {
+                (t.messages.matches_lineup_no_roster())
+            }
+        } @else {
+            label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer; margin-bottom: 0.75rem; font-weight: 600;" {
+                input
+                    type="checkbox"
+                    onchange="this.closest('form').querySelectorAll('input[name=player_contract_id]').forEach(c => c.checked = this.checked)";
+                span { (t.messages.matches_lineup_select_all()) }
+            }
+            div style="display: grid; grid-template-columns: 1fr 1fr; gap: 0.25rem 1rem; margin-bottom: 1rem;" {
+                @for player in candidates {
+                    label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer; padding: 0.25rem 0;" {
+                        input
+                            type="checkbox"
+                            name="player_contract_id"
+                            value=(player.player_contract_id)
+                            checked[player.dressed];
+                        span {
+                            @if let Some(number) = player.jersey_number {
+                                span style="color: var(--gray-500); margin-right: 0.25rem;" { (format!("#{}", number)) }
+                            }
+                            (player.player_name)
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    modal_form_i18n(
+        "match-lineup-modal",
+        &format!("{} – {}", t.messages.matches_lineup_edit_title(), team_name),
+        error,
+        &format!("/matches/{}/lineup/{}", match_entity.id, team_id),
+        form_fields,
+        &t.messages.common_save().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Faceoffs won and lost by each skater of one team in a match
+///
+/// Rows left empty are not saved.
+pub fn match_faceoffs_modal(
+    t: &TranslationContext,
+    error: Option<&str>,
+    match_entity: &MatchEntity,
+    team_id: i64,
+    team_name: &str,
+    players: &[ScoringPlayerEntity],
+    faceoffs: &[MatchFaceoffsEntity],
+) -> Markup {
+    let recorded = |player_id: i64| faceoffs.iter().find(|f| f.player_id == player_id);
+
+    let form_fields = html! {
+        p style="margin-bottom: 1rem; color: var(--gray-600); font-size: 0.875rem;" {
+            (t.messages.matches_faceoffs_hint())
+        }
+        @if players.is_empty() {
+            div style="padding: 1.5rem; text-align: center; color: var(--gray-500); background: var(--gray-50); border-radius: 8px; margin-bottom: 1rem;" {
+                (t.messages.matches_lineup_no_roster())
+            }
+        } @else {
+            table class="table" style="margin-bottom: 1rem;" {
+                thead {
+                    tr {
+                        th { (t.messages.matches_faceoffs_player()) }
+                        th style="width: 6rem;" { (t.messages.matches_faceoffs_won()) }
+                        th style="width: 6rem;" { (t.messages.matches_faceoffs_lost()) }
+                    }
+                }
+                tbody {
+                    @for player in players.iter().filter(|p| !p.is_goalie()) {
+                        @let entry = recorded(player.player_id);
+                        tr {
+                            td { (player.label) }
+                            td {
+                                input
+                                    type="number"
+                                    name=(format!("won_{}", player.player_id))
+                                    min="0"
+                                    max="99"
+                                    value=[entry.map(|f| f.won)]
+                                    style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
+                            }
+                            td {
+                                input
+                                    type="number"
+                                    name=(format!("lost_{}", player.player_id))
+                                    min="0"
+                                    max="99"
+                                    value=[entry.map(|f| f.lost)]
+                                    style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    modal_form_i18n(
+        "match-faceoffs-modal",
+        &format!(
+            "{} – {}",
+            t.messages.matches_faceoffs_edit_title(),
+            team_name
+        ),
+        error,
+        &format!("/matches/{}/faceoffs/{}", match_entity.id, team_id),
+        form_fields,
+        &t.messages.common_save().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Assign referees and linesmen to a match
+///
+/// Each role has two slots; `selected` pre-fills them in order.
+pub fn match_officials_modal(
+    t: &TranslationContext,
+    error: Option<&str>,
+    match_entity: &MatchEntity,
+    referees: &[(i64, String)],
+    selected: &[(i64, OfficialRole)],
+) -> Markup {
+    let slots = [
+        (
+            OfficialRole::Referee,
+            t.messages.matches_officials_referee(),
+        ),
+        (
+            OfficialRole::Linesman,
+            t.messages.matches_officials_linesman(),
+        ),
+    ];
+
+    let form_fields = html! {
+        p style="margin-bottom: 1rem; color: var(--gray-600); font-size: 0.875rem;" {
+            (t.messages.matches_officials_hint())
+        }
+        @if referees.is_empty() {
+            div style="padding: 1.5rem; text-align: center; color: var(--gray-500); background: var(--gray-50); border-radius: 8px; margin-bottom: 1rem;" {
+                (t.messages.matches_officials_no_referees())
+            }
+        } @else {
+            @for (role, label) in &slots {
+                @let current: Vec<i64> = selected
+                    .iter()
+                    .filter(|(_, r)| r == role)
+                    .map(|(id, _)| *id)
+                    .collect();
+                div class="form-row" style="margin-bottom: 1rem;" {
+                    @for slot in 0..role.max_per_match() {
+                        div class="form-group" {
+                            label class="form-label" {
+                                (format!("{} {}", label, slot + 1))
+                            }
+                            select name=(format!("{}_{}", role.as_str(), slot + 1)) {
+                                option value="" { (t.messages.matches_officials_none()) }
+                                @for (id, name) in referees {
+                                    option value=(id) selected[current.get(slot) == Some(id)] {
+                                        (name)
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    modal_form_i18n(
+        "match-officials-modal",
+        &t.messages.matches_officials_edit_title().to_string(),
+        error,
+        &format!("/matches/{}/officials", match_entity.id),
+        form_fields,
+        &t.messages.common_save().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
@@ -0,0 +1,241 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::views::components::crud::modal_form_i18n;
+use crate::views::components::forms::csrf_token_field;
+
+/// Side of the match a quick-created team is selected for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchSide {
+    Home,
+    Away,
+}
+
+impl MatchSide {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "home" => Some(Self::Home),
+            "away" => Some(Self::Away),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Home => "home",
+            Self::Away => "away",
+        }
+    }
+}
+
+/// Season dropdown of the create match modal; picking a season reloads the
+/// team dropdowns
+///
+/// `oob` renders it as an out-of-band swap replacing the dropdown in place.
+pub fn match_season_select(
+    t: &TranslationContext,
+    seasons: &[(i64, String)],
+    selected: Option<i64>,
+    oob: bool,
+) -> Markup {
+    html! {
+        select
+            name="season_id"
+            id="season_id"
+            hx-swap-oob=[oob.then_some("true")]
+            required
+            hx-get="/matches/teams-for-season"
+            hx-swap="none"
+            hx-trigger="change"
+            hx-indicator="#season-loading-create"
+        {
+            option value="" { (t.messages.matches_select_season()) }
+            @for (id, name) in seasons {
+                option value=(id) selected[selected == Some(*id)] { (name) }
+            }
+        }
+    }
+}
+
+/// Home or away team dropdown of the create match modal
+pub fn match_team_select(
+    t: &TranslationContext,
+    side: MatchSide,
+    teams: &[(i64, String)],
+    selected: Option<i64>,
+    oob: bool,
+) -> Markup {
+    let name = format!("{}_team_id", side.as_str());
+
+    html! {
+        select
+            name=(name)
+            id=(name)
+            hx-swap-oob=[oob.then_some("true")]
+            class="team-select"
+            required
+        {
+            option value="" { (t.messages.matches_select_team()) }
+            @for (id, team_name) in teams {
+                option value=(id) selected[selected == Some(*id)] { (team_name) }
+            }
+        }
+    }
+}
+
+/// "+ New" button next to a create match dropdown, opening a quick-create
+/// modal on top of the match modal (appended to the page body)
+pub fn quick_create_button(t: &TranslationContext, url: &str, side: Option<MatchSide>) -> Markup {
+    html! {
+        button
+            type="button"
+            class="btn btn-sm btn-secondary"
+            hx-get=(url)
+            hx-target="body"
+            hx-swap="beforeend"
+            hx-include="#match-modal [name='season_id'], #match-modal [name='home_team_id'], #match-modal [name='away_team_id']"
+            hx-vals=[side.map(|side| format!(r#"{{"side": "{}"}}"#, side.as_str()))]
+        {
+            (format!("+ {}", t.messages.matches_quick_create_new()))
+        }
+    }
+}
+
+/// Quick-create season modal, shown over the create match modal
+pub fn quick_create_season_modal(
+    session: &Session,
+    t: &TranslationContext,
+    error: Option<&str>,
+    events: &[(i64, String)],
+) -> Markup {
+    let form_fields = html! {
+        (csrf_token_field(&session.csrf_token))
+
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.seasons_event())
+                span class="required-indicator" { " *" }
+            }
+            select name="event_id" required autofocus {
+                option value="" { (t.messages.seasons_select_event()) }
+                @for (id, name) in events {
+                    option value=(id) { (name) }
+                }
+            }
+        }
+
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.seasons_year_label())
+                span class="required-indicator" { " *" }
+            }
+            input
+                type="number"
+                name="year"
+                required
+                min="1900"
+                max="2100"
+                placeholder=(t.messages.seasons_year_placeholder());
+        }
+
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.seasons_display_name_label())
+            }
+            input
+                type="text"
+                name="display_name"
+                placeholder=(t.messages.seasons_display_name_placeholder());
+        }
+    };
+
+    modal_form_i18n(
+        "quick-create-modal",
+        &t.messages.seasons_create_title().to_string(),
+        error,
+        "/matches/quick-create/season",
+        form_fields,
+        &t.messages.common_create().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Quick-create team modal; the team joins the season picked in the match
+/// modal and is selected on `side`
+///
+/// The match modal's current team selection is carried along so it survives
+/// the team dropdowns being replaced.
+#[allow(clippy::too_many_arguments)]
+pub fn quick_create_team_modal(
+    session: &Session,
+    t: &TranslationContext,
+    error: Option<&str>,
+    season_id: i64,
+    side: MatchSide,
+    home_team_id: Option<i64>,
+    away_team_id: Option<i64>,
+) -> Markup {
+    let form_fields = html! {
+        (csrf_token_field(&session.csrf_token))
+        input type="hidden" name="season_id" value=(season_id);
+        input type="hidden" name="side" value=(side.as_str());
+        input type="hidden" name="home_team_id" value=[home_team_id];
+        input type="hidden" name="away_team_id" value=[away_team_id];
+
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.teams_name_label())
+                span class="required-indicator" { " *" }
+            }
+            input
+                type="text"
+                name="name"
+                required
+                autofocus;
+        }
+
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.form_country())
+            }
+            country-selector
+                name="country_id"
+                placeholder=(t.messages.teams_select_country())
+                enabled-only;
+        }
+
+        p style="font-size: 0.875rem; color: var(--gray-600); margin-bottom: 1rem;" {
+            (t.messages.matches_quick_create_team_hint())
+        }
+    };
+
+    modal_form_i18n(
+        "quick-create-modal",
+        &t.messages.teams_create_title().to_string(),
+        error,
+        "/matches/quick-create/team",
+        form_fields,
+        &t.messages.common_create().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Response to a successful quick-create: closes the quick-create modal and
+/// swaps the updated dropdowns into the match modal, leaving the rest of the
+/// match form as filled in
+pub fn quick_create_result(
+    t: &TranslationContext,
+    season_select: Option<Markup>,
+    teams: &[(i64, String)],
+    home_team_id: Option<i64>,
+    away_team_id: Option<i64>,
+) -> Markup {
+    html! {
+        @if let Some(season_select) = season_select {
+            (season_select)
+        }
+        (match_team_select(t, MatchSide::Home, teams, home_team_id, true))
+        (match_team_select(t, MatchSide::Away, teams, away_team_id, true))
+    }
+}
@@ -1,7 +1,7 @@
 use maud::{html, Markup};
 
 use crate::i18n::TranslationContext;
-use crate::service::matches::{MatchEntity, ScoreEventEntity};
+use crate::service::matches::{MatchEntity, ScoreEventEntity, ScoringGroup, ScoringPlayerEntity};
 use crate::views::components::crud::modal_form_i18n;
 
 /// Create score event modal
@@ -9,8 +9,8 @@ pub fn score_event_create_modal(
     t: &TranslationContext,
     error: Option<&str>,
     match_info: &MatchEntity,
-    home_players: &[(i64, String)],
-    away_players: &[(i64, String)],
+    home_players: &[ScoringPlayerEntity],
+    away_players: &[ScoringPlayerEntity],
 ) -> Markup {
     let form_fields = html! {
         div style="margin-bottom: 1rem;" {
@@ -91,71 +91,37 @@ pub fn score_event_create_modal(
             }
         }
 
-        div style="margin-bottom: 1rem;" {
-            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
-                (t.messages.matches_goal_scorer())
-            }
-            select
-                name="scorer_id"
-                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
-            {
-                option value="" { "---" }
-                optgroup label=(match_info.home_team_name) {
-                    @for (id, name) in home_players {
-                        option value=(id) { (name) }
-                    }
-                }
-                optgroup label=(match_info.away_team_name) {
-                    @for (id, name) in away_players {
-                        option value=(id) { (name) }
-                    }
-                }
-            }
-        }
+        (scoring_player_select(
+            t,
+            "scorer_id",
+            &t.messages.matches_goal_scorer().to_string(),
+            match_info,
+            home_players,
+            away_players,
+            None,
+        ))
 
-        div style="margin-bottom: 1rem;" {
-            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
-                (t.messages.matches_goal_assist_1())
-            }
-            select
-                name="assist1_id"
-                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
-            {
-                option value="" { "---" }
-                optgroup label=(match_info.home_team_name) {
-                    @for (id, name) in home_players {
-                        option value=(id) { (name) }
-                    }
-                }
-                optgroup label=(match_info.away_team_name) {
-                    @for (id, name) in away_players {
-                        option value=(id) { (name) }
-                    }
-                }
-            }
-        }
+        (scoring_player_select(
+            t,
+            "assist1_id",
+            &t.messages.matches_goal_assist_1().to_string(),
+            match_info,
+            home_players,
+            away_players,
+            None,
+        ))
 
-        div style="margin-bottom: 1.5rem;" {
-            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
-                (t.messages.matches_goal_assist_2())
-            }
-            select
-                name="assist2_id"
-                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
-            {
-                option value="" { "---" }
-                optgroup label=(match_info.home_team_name) {
-                    @for (id, name) in home_players {
-                        option value=(id) { (name) }
-                    }
-                }
-                optgroup label=(match_info.away_team_name) {
-                    @for (id, name) in away_players {
-                        option value=(id) { (name) }
-                    }
-                }
-            }
-        }
+        (scoring_player_select(
+            t,
+            "assist2_id",
+            &t.messages.matches_goal_assist_2().to_string(),
+            match_info,
+            home_players,
+            away_players,
+            None,
+        ))
+
+        (video_link_fields(t, None, None))
     };
 
     modal_form_i18n(
@@ -175,8 +141,8 @@ pub fn score_event_edit_modal(
     error: Option<&str>,
     score_event: &ScoreEventEntity,
     match_info: &MatchEntity,
-    home_players: &[(i64, String)],
-    away_players: &[(i64, String)],
+    home_players: &[ScoringPlayerEntity],
+    away_players: &[ScoringPlayerEntity],
 ) -> Markup {
     let form_fields = html! {
         div style="margin-bottom: 1rem;" {
@@ -269,67 +235,131 @@ pub fn score_event_edit_modal(
             }
         }
 
-        div style="margin-bottom: 1rem;" {
-            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
-                (t.messages.matches_goal_scorer())
-            }
-            select
-                name="scorer_id"
-                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
-            {
-                option value="" selected[score_event.scorer_id.is_none()] { "---" }
-                optgroup label=(match_info.home_team_name) {
-                    @for (id, name) in home_players {
-                        option value=(id) selected[score_event.scorer_id == Some(*id)] { (name) }
-                    }
-                }
-                optgroup label=(match_info.away_team_name) {
-                    @for (id, name) in away_players {
-                        option value=(id) selected[score_event.scorer_id == Some(*id)] { (name) }
-                    }
+        (scoring_player_select(
+            t,
+            "scorer_id",
+            &t.messages.matches_goal_scorer().to_string(),
+            match_info,
+            home_players,
+            away_players,
+            score_event.scorer_id,
+        ))
+
+        (scoring_player_select(
+            t,
+            "assist1_id",
+            &t.messages.matches_goal_assist_1().to_string(),
+            match_info,
+            home_players,
+            away_players,
+            score_event.assist1_id,
+        ))
+
+        (scoring_player_select(
+            t,
+            "assist2_id",
+            &t.messages.matches_goal_assist_2().to_string(),
+            match_info,
+            home_players,
+            away_players,
+            score_event.assist2_id,
+        ))
+
+        (video_link_fields(
+            t,
+            score_event.video_url.as_deref(),
+            score_event.video_timestamp_label().as_deref(),
+        ))
+    };
+
+    modal_form_i18n(
+        "score-event-modal",
+        &t.messages.matches_edit_score_event().to_string(),
+        error,
+        &format!("/matches/score-events/{}", score_event.id),
+        form_fields,
+        &t.messages.common_save().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Optional highlight video URL and timestamp of a goal
+fn video_link_fields(t: &TranslationContext, url: Option<&str>, timestamp: Option<&str>) -> Markup {
+    html! {
+        div style="display: grid; grid-template-columns: 2fr 1fr; gap: 1rem; margin-bottom: 1rem;" {
+            div {
+                label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                    (t.messages.matches_video_url())
                 }
+                input
+                    type="url"
+                    name="video_url"
+                    value=[url]
+                    maxlength="500"
+                    placeholder="https://"
+                    style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
             }
-        }
 
-        div style="margin-bottom: 1rem;" {
-            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
-                (t.messages.matches_goal_assist_1())
-            }
-            select
-                name="assist1_id"
-                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
-            {
-                option value="" selected[score_event.assist1_id.is_none()] { "---" }
-                optgroup label=(match_info.home_team_name) {
-                    @for (id, name) in home_players {
-                        option value=(id) selected[score_event.assist1_id == Some(*id)] { (name) }
-                    }
-                }
-                optgroup label=(match_info.away_team_name) {
-                    @for (id, name) in away_players {
-                        option value=(id) selected[score_event.assist1_id == Some(*id)] { (name) }
-                    }
+            div {
+                label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                    (t.messages.matches_video_timestamp())
                 }
+                input
+                    type="text"
+                    name="video_timestamp"
+                    value=[timestamp]
+                    pattern="[0-9]+(:[0-5][0-9]){0,2}"
+                    placeholder="1:23:45"
+                    style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
             }
         }
+    }
+}
 
-        div style="margin-bottom: 1.5rem;" {
-            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
-                (t.messages.matches_goal_assist_2())
-            }
-            select
-                name="assist2_id"
-                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
-            {
-                option value="" selected[score_event.assist2_id.is_none()] { "---" }
-                optgroup label=(match_info.home_team_name) {
-                    @for (id, name) in home_players {
-                        option value=(id) selected[score_event.assist2_id == Some(*id)] { (name) }
+/// Pick the players of both teams who were on the ice for a goal
+///
+/// With nothing recorded yet, the scorer and assistants start checked.
+pub fn on_ice_modal(
+    t: &TranslationContext,
+    error: Option<&str>,
+    score_event: &ScoreEventEntity,
+    match_info: &MatchEntity,
+    home_players: &[ScoringPlayerEntity],
+    away_players: &[ScoringPlayerEntity],
+    on_ice: &[i64],
+) -> Markup {
+    let checked: Vec<i64> = if on_ice.is_empty() {
+        score_event.credited_player_ids()
+    } else {
+        on_ice.to_vec()
+    };
+    let sides = [
+        ("home_player_id", &match_info.home_team_name, home_players),
+        ("away_player_id", &match_info.away_team_name, away_players),
+    ];
+
+    let form_fields = html! {
+        p style="margin-bottom: 1rem; color: var(--gray-600); font-size: 0.875rem;" {
+            (t.messages.matches_on_ice_hint())
+        }
+        div style="display: grid; grid-template-columns: 1fr 1fr; gap: 1rem; margin-bottom: 1rem;" {
+            @for (field, team_name, players) in sides {
+                div {
+                    div style="font-weight: 600; margin-bottom: 0.5rem;" { (team_name) }
+                    @if players.is_empty() {
+                        div style="color: var(--gray-500); font-size: 0.875rem;" {
+                            (t.messages.matches_on_ice_no_players())
+                        }
                     }
-                }
-                optgroup label=(match_info.away_team_name) {
-                    @for (id, name) in away_players {
-                        option value=(id) selected[score_event.assist2_id == Some(*id)] { (name) }
+                    @for player in players {
+                        label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer; padding: 0.25rem 0;" {
+                            input
+                                type="checkbox"
+                                name=(field)
+                                value=(player.player_id)
+                                checked[checked.contains(&player.player_id)];
+                            span { (player.label) }
+                        }
                     }
                 }
             }
@@ -337,12 +367,110 @@ pub fn score_event_edit_modal(
     };
 
     modal_form_i18n(
-        "score-event-modal",
-        &t.messages.matches_edit_score_event().to_string(),
+        "on-ice-modal",
+        &t.messages.matches_on_ice_title().to_string(),
         error,
-        &format!("/matches/score-events/{}", score_event.id),
+        &format!("/matches/score-events/{}/on-ice", score_event.id),
         form_fields,
         &t.messages.common_save().to_string(),
         &t.messages.common_cancel().to_string(),
     )
 }
+
+/// Scorer or assist dropdown with both teams' players, grouped by line and
+/// position
+///
+/// Goalies are hidden from the scorer dropdown until "Include goalies" is
+/// checked, unless the goal is already credited to one.
+fn scoring_player_select(
+    t: &TranslationContext,
+    name: &str,
+    label: &str,
+    match_info: &MatchEntity,
+    home_players: &[ScoringPlayerEntity],
+    away_players: &[ScoringPlayerEntity],
+    selected: Option<i64>,
+) -> Markup {
+    let is_scorer = name == "scorer_id";
+    let goalie_selected = home_players
+        .iter()
+        .chain(away_players)
+        .any(|p| p.is_goalie() && Some(p.player_id) == selected);
+    let hide_goalies = is_scorer && !goalie_selected;
+    let has_goalies = home_players
+        .iter()
+        .chain(away_players)
+        .any(|p| p.is_goalie());
+
+    html! {
+        div style="margin-bottom: 1rem;" {
+            div style="display: flex; justify-content: space-between; align-items: baseline; margin-bottom: 0.5rem;" {
+                label style="font-weight: 500;" { (label) }
+                @if is_scorer && has_goalies {
+                    label style="display: flex; align-items: center; gap: 0.25rem; font-size: 0.875rem; color: var(--gray-600);" {
+                        input
+                            type="checkbox"
+                            checked[!hide_goalies]
+                            onchange="this.closest('form').querySelectorAll('select[name=scorer_id] option[data-goalie]').forEach(o => { o.hidden = !this.checked; o.disabled = !this.checked; })";
+                        (t.messages.matches_include_goalies())
+                    }
+                }
+            }
+            select
+                name=(name)
+                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
+            {
+                option value="" selected[selected.is_none()] { "---" }
+                (team_player_options(t, &match_info.home_team_name, home_players, selected, hide_goalies))
+                (team_player_options(t, &match_info.away_team_name, away_players, selected, hide_goalies))
+            }
+        }
+    }
+}
+
+/// One optgroup per line/position group of a team; a single optgroup named
+/// after the team when nothing is known about lines or positions
+pub(super) fn team_player_options(
+    t: &TranslationContext,
+    team_name: &str,
+    players: &[ScoringPlayerEntity],
+    selected: Option<i64>,
+    hide_goalies: bool,
+) -> Markup {
+    let grouped = players.iter().any(|p| p.group != ScoringGroup::Other);
+    let groups = players.chunk_by(|a, b| !grouped || a.group == b.group);
+
+    html! {
+        @for group in groups {
+            optgroup label=(if grouped {
+                format!("{} · {}", team_name, scoring_group_label(t, group[0].group))
+            } else {
+                team_name.to_string()
+            }) {
+                @for player in group {
+                    @let hidden = hide_goalies && player.is_goalie();
+                    option
+                        value=(player.player_id)
+                        selected[selected == Some(player.player_id)]
+                        data-goalie=[player.is_goalie().then_some("true")]
+                        hidden[hidden]
+                        disabled[hidden]
+                    {
+                        (player.label)
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn scoring_group_label(t: &TranslationContext, group: ScoringGroup) -> String {
+    match group {
+        ScoringGroup::ForwardLine(line) => format!("{} {}", t.messages.roster_lines_line(), line),
+        ScoringGroup::DefensePair(pair) => format!("{} {}", t.messages.roster_lines_pair(), pair),
+        ScoringGroup::Forwards => t.messages.roster_lines_forwards().to_string(),
+        ScoringGroup::Defense => t.messages.roster_lines_defense().to_string(),
+        ScoringGroup::Other => t.messages.matches_scoring_group_other().to_string(),
+        ScoringGroup::Goalies => t.messages.roster_lines_goalies().to_string(),
+    }
+}
@@ -0,0 +1,146 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::matches::{MatchDetailEntity, ScoreEventEntity};
+
+use super::detail_page::format_date;
+use super::list_page::status_filter_label;
+
+/// Size of the card, the common link preview ratio of social networks
+const CARD_WIDTH: i32 = 1200;
+const CARD_HEIGHT: i32 = 630;
+/// Scorers listed per team before the rest is summarised as "+N"
+const MAX_SCORERS: usize = 7;
+
+/// Match result card as a standalone SVG image for sharing
+///
+/// Built from the same detail data as the match page: teams with flags, the
+/// score and each team's scorers.
+pub fn match_share_card(t: &TranslationContext, detail: &MatchDetailEntity) -> Markup {
+    let m = &detail.match_info;
+    let title = [m.event_name.as_deref(), m.season_name.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" · ");
+    let subtitle = [
+        m.match_date.as_deref().map(format_date),
+        m.arena_name.clone(),
+        Some(status_filter_label(t, &m.status)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>()
+    .join(" · ");
+
+    html! {
+        svg
+            xmlns="http://www.w3.org/2000/svg"
+            xmlns:xlink="http://www.w3.org/1999/xlink"
+            width=(CARD_WIDTH)
+            height=(CARD_HEIGHT)
+            viewBox=(format!("0 0 {} {}", CARD_WIDTH, CARD_HEIGHT))
+            font-family="Inter, Segoe UI, Helvetica, Arial, sans-serif"
+        {
+            defs {
+                linearGradient id="bg" x1="0" y1="0" x2="1" y2="1" {
+                    stop offset="0%" stop-color="#1e3a8a" {}
+                    stop offset="100%" stop-color="#0f172a" {}
+                }
+            }
+            rect width=(CARD_WIDTH) height=(CARD_HEIGHT) fill="url(#bg)" {}
+
+            text x=(CARD_WIDTH / 2) y="70" text-anchor="middle" fill="#bfdbfe" font-size="30" font-weight="600" {
+                (title)
+            }
+            text x=(CARD_WIDTH / 2) y="112" text-anchor="middle" fill="#94a3b8" font-size="24" {
+                (subtitle)
+            }
+
+            (team_block(m.home_team_name.as_str(), m.home_team_country_iso2.as_deref(), 300))
+            (team_block(m.away_team_name.as_str(), m.away_team_country_iso2.as_deref(), 900))
+
+            text x=(CARD_WIDTH / 2) y="290" text-anchor="middle" fill="white" font-size="120" font-weight="800" {
+                (format!("{} : {}", detail.home_score_total, detail.away_score_total))
+            }
+
+            (scorers_block(t, &detail.score_events, m.home_team_id, 300))
+            (scorers_block(t, &detail.score_events, m.away_team_id, 900))
+        }
+    }
+}
+
+/// Flag and name of a team, centred on `x`
+fn team_block(name: &str, iso2: Option<&str>, x: i32) -> Markup {
+    html! {
+        @if let Some(iso2) = iso2 {
+            image
+                x=(x - 60)
+                y="150"
+                width="120"
+                height="80"
+                preserveAspectRatio="xMidYMid slice"
+                href=(flag_url(iso2))
+                xlink:href=(flag_url(iso2))
+            {}
+        }
+        text x=(x) y="290" text-anchor="middle" fill="white" font-size="44" font-weight="700" {
+            (name)
+        }
+    }
+}
+
+/// A team's goals in order, e.g. "2. 12:30 Jágr", centred on `x`
+fn scorers_block(
+    t: &TranslationContext,
+    events: &[ScoreEventEntity],
+    team_id: i64,
+    x: i32,
+) -> Markup {
+    let goals: Vec<&ScoreEventEntity> = events.iter().filter(|e| e.team_id == team_id).collect();
+    let hidden = goals.len().saturating_sub(MAX_SCORERS);
+
+    html! {
+        @for (index, goal) in goals.iter().take(MAX_SCORERS).enumerate() {
+            text
+                x=(x)
+                y=(360 + index as i32 * 34)
+                text-anchor="middle"
+                fill="#e2e8f0"
+                font-size="26"
+            {
+                (goal_line(t, goal))
+            }
+        }
+        @if hidden > 0 {
+            text
+                x=(x)
+                y=(360 + MAX_SCORERS as i32 * 34)
+                text-anchor="middle"
+                fill="#94a3b8"
+                font-size="24"
+            {
+                (format!("+{}", hidden))
+            }
+        }
+    }
+}
+
+fn goal_line(t: &TranslationContext, goal: &ScoreEventEntity) -> String {
+    let time = match (goal.time_minutes, goal.time_seconds) {
+        (Some(minutes), seconds) => {
+            format!("{}. {}:{:02}", goal.period, minutes, seconds.unwrap_or(0))
+        }
+        (None, _) => format!("{}.", goal.period),
+    };
+    let scorer = goal
+        .scorer_name
+        .clone()
+        .unwrap_or_else(|| t.messages.player_scoring_unidentified().to_string());
+    format!("{} {}", time, scorer)
+}
+
+/// Flag image; linked in both SVG 2 and SVG 1.1 syntax for older renderers
+fn flag_url(iso2: &str) -> String {
+    format!("https://flagcdn.com/w160/{}.png", iso2.to_lowercase())
+}
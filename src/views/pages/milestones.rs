@@ -0,0 +1,100 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::milestones::{MilestoneEntity, MilestoneKind};
+
+/// Translated milestone with its goal or point count, e.g. "Hat trick (4 goals)"
+pub fn milestone_label(t: &TranslationContext, milestone: &MilestoneEntity) -> String {
+    match milestone.kind() {
+        Some(MilestoneKind::HatTrick) => format!(
+            "{} ({} {})",
+            t.messages.milestones_hat_trick(),
+            milestone.value,
+            t.messages.milestones_goals()
+        ),
+        Some(MilestoneKind::FourPointGame) => format!(
+            "{} ({} {})",
+            t.messages.milestones_four_point_game(),
+            milestone.value,
+            t.messages.milestones_points()
+        ),
+        Some(MilestoneKind::HundredthGoal) => t.messages.milestones_hundredth_goal().to_string(),
+        None => milestone.kind.clone(),
+    }
+}
+
+pub fn milestone_icon(kind: Option<MilestoneKind>) -> &'static str {
+    match kind {
+        Some(MilestoneKind::HatTrick) => "🎩",
+        Some(MilestoneKind::FourPointGame) => "⭐",
+        Some(MilestoneKind::HundredthGoal) => "💯",
+        None => "🏅",
+    }
+}
+
+fn match_title(milestone: &MilestoneEntity) -> String {
+    format!(
+        "{} vs {}",
+        milestone.home_team_name, milestone.away_team_name
+    )
+}
+
+/// Recently detected milestones for the dashboard
+pub fn recent_milestones_section(t: &TranslationContext, milestones: &[MilestoneEntity]) -> Markup {
+    html! {
+        div class="dashboard-section" {
+            h2 class="section-heading" {
+                (t.messages.milestones_recent())
+            }
+            div class="activity-feed" {
+                @for milestone in milestones {
+                    div class="activity-item" {
+                        span class="activity-icon" { (milestone_icon(milestone.kind())) }
+                        div class="activity-details" {
+                            a href=(format!("/players/{}", milestone.player_id)) class="activity-name primary-link" {
+                                (milestone.player_name)
+                            }
+                            span class="activity-action" { (milestone_label(t, milestone)) }
+                            a href=(format!("/matches/{}", milestone.match_id)) class="activity-action" {
+                                (match_title(milestone))
+                            }
+                        }
+                        @if let Some(date) = &milestone.match_date {
+                            span class="activity-timestamp" { (date) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Milestones of a player for the player detail page
+pub fn player_milestones_section(t: &TranslationContext, milestones: &[MilestoneEntity]) -> Markup {
+    html! {
+        div style="margin-top: 2rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin: 0 0 1.5rem 0;" {
+                (t.messages.milestones_title())
+            }
+            div style="display: flex; flex-direction: column; gap: 0.5rem;" {
+                @for milestone in milestones {
+                    div style="display: flex; align-items: center; gap: 0.75rem; padding: 0.75rem 1rem; border: 1px solid var(--gray-200); border-radius: 8px; background: white;" {
+                        span style="font-size: 1.25rem;" { (milestone_icon(milestone.kind())) }
+                        span style="font-weight: 600;" { (milestone_label(t, milestone)) }
+                        a
+                            href=(format!("/matches/{}", milestone.match_id))
+                            style="color: var(--primary-color); text-decoration: none;"
+                        {
+                            (match_title(milestone))
+                        }
+                        @if let Some(date) = &milestone.match_date {
+                            span style="margin-left: auto; color: var(--gray-600); font-size: 0.875rem;" {
+                                (date)
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,18 +1,57 @@
+pub mod arena_detail;
+pub mod arenas;
+pub mod attendance;
 pub mod auth;
+pub mod awards;
+pub mod backups;
+pub mod calendar_subscriptions;
+pub mod change_history;
+pub mod changelog;
+pub mod comments;
 pub mod countries;
 pub mod dashboard;
+pub mod data_quality;
 pub mod event_detail;
 pub mod events;
+pub mod goal_types;
+pub mod instance_transfer;
+pub mod leaders;
 pub mod management;
+pub mod match_import;
 pub mod matches;
+pub mod milestones;
+pub mod notifications;
+pub mod period_distribution;
+pub mod placements;
 pub mod player_detail;
 pub mod player_event_stats;
 pub mod player_property_changes;
 pub mod player_scoring;
 pub mod players;
+pub mod playoffs;
+pub mod predictions;
+pub mod profile;
+pub mod public_site;
+pub mod referees;
+pub mod reports;
 pub mod roster;
+pub mod roster_diff;
 pub mod season_detail;
+pub mod season_groups;
 pub mod seasons;
+pub mod settings;
+pub mod sitemap;
+pub mod slow_queries;
+pub mod splits;
+pub mod staff;
+pub mod status;
+pub mod streaks;
+pub mod tasks;
 pub mod team_detail;
+pub mod team_form;
+pub mod team_merge;
 pub mod team_participations;
 pub mod teams;
+pub mod users;
+pub mod webhooks;
+pub mod widgets;
@@ -0,0 +1,87 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::notifications::NotificationEntity;
+use crate::views::components::forms::csrf_token_field;
+
+/// Characters of the comment shown in a notification
+const EXCERPT_LENGTH: usize = 160;
+
+fn excerpt(body: &str) -> String {
+    let mut excerpt: String = body.chars().take(EXCERPT_LENGTH).collect();
+    if body.chars().count() > EXCERPT_LENGTH {
+        excerpt.push('…');
+    }
+    excerpt
+}
+
+/// List of the current user's notifications
+pub fn notifications_page(
+    session: &Session,
+    t: &TranslationContext,
+    notifications: &[NotificationEntity],
+) -> Markup {
+    let has_unread = notifications.iter().any(|n| !n.is_read());
+
+    html! {
+        div class="card" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
+                h1 style="font-size: 2rem; font-weight: 700; margin: 0;" {
+                    (t.messages.notifications_title())
+                }
+                @if has_unread {
+                    form method="post" action="/notifications/read-all" {
+                        (csrf_token_field(&session.csrf_token))
+                        button type="submit" class="btn btn-secondary" {
+                            (t.messages.notifications_mark_all_read())
+                        }
+                    }
+                }
+            }
+
+            @if notifications.is_empty() {
+                p style="color: var(--gray-500);" { (t.messages.notifications_empty()) }
+            } @else {
+                ul style="list-style: none; padding: 0; margin: 0;" {
+                    @for notification in notifications {
+                        li
+                            style=(format!(
+                                "padding: 0.75rem 1rem; border-bottom: 1px solid var(--gray-200);{}",
+                                if notification.is_read() { "" } else { " background: var(--gray-50); border-left: 3px solid var(--primary-color);" }
+                            ))
+                        {
+                            div style="display: flex; justify-content: space-between; gap: 1rem;" {
+                                a href=(format!("/notifications/{}/open", notification.id)) class="primary-link" {
+                                    strong {
+                                        @if let Some(actor) = &notification.actor_name {
+                                            (actor)
+                                        } @else {
+                                            (t.messages.comments_deleted_user())
+                                        }
+                                    }
+                                    " " (t.messages.notifications_mentioned_you())
+                                }
+                                span style="color: var(--gray-500); font-size: 0.875rem; white-space: nowrap;" {
+                                    (notification.created_at)
+                                }
+                            }
+                            p style="margin: 0.25rem 0 0 0; color: var(--gray-600); font-size: 0.875rem;" {
+                                (excerpt(&notification.comment_body))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Unread counter next to the "Notifications" navigation link, empty when all are read
+pub fn notifications_badge(unread: i64) -> Markup {
+    html! {
+        @if unread > 0 {
+            span class="nav-badge" { (unread) }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::period_distribution::PeriodDistributionEntity;
+use crate::views::pages::matches::shot_period_label;
+
+const NUMBER_CELL: &str = "text-align: right;";
+
+/// Goals per period of a season as bars scaled to the busiest period
+pub fn period_distribution_section(
+    t: &TranslationContext,
+    distribution: &PeriodDistributionEntity,
+) -> Markup {
+    let max_goals = distribution.max_goals();
+
+    html! {
+        div style="margin-top: 2rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin: 0 0 0.5rem 0;" {
+                (t.messages.period_distribution_title())
+            }
+            p style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 1rem;" {
+                (t.messages.period_distribution_hint())
+            }
+
+            @if distribution.total_goals == 0 {
+                div style="padding: 1rem; background: var(--gray-100); border-radius: 4px; color: var(--gray-600); text-align: center;" {
+                    (t.messages.period_distribution_empty())
+                }
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.matches_period()) }
+                            th style="width: 40%;" {}
+                            th style=(NUMBER_CELL) { (t.messages.period_distribution_goals()) }
+                            th style=(NUMBER_CELL) { (t.messages.period_distribution_home_away()) }
+                            th style=(NUMBER_CELL) { (t.messages.period_distribution_share()) }
+                            th style=(NUMBER_CELL) { (t.messages.period_distribution_per_match()) }
+                        }
+                    }
+                    tbody {
+                        @for period in &distribution.periods {
+                            tr {
+                                td style="font-weight: 600;" { (shot_period_label(t, period.period)) }
+                                td {
+                                    div class="period-bar" {
+                                        @if max_goals > 0 {
+                                            div
+                                                class="period-bar-fill"
+                                                style=(format!("width: {:.2}%;", period.goals as f64 * 100.0 / max_goals as f64))
+                                            {}
+                                        }
+                                    }
+                                }
+                                td style=(NUMBER_CELL) { (period.goals) }
+                                td style=(NUMBER_CELL) { (format!("{}:{}", period.home_goals, period.away_goals)) }
+                                td style=(NUMBER_CELL) {
+                                    @if let Some(percentage) = distribution.percentage(period) {
+                                        (format!("{:.1}%", percentage))
+                                    }
+                                }
+                                td style=(NUMBER_CELL) {
+                                    @if let Some(per_match) = distribution.per_match(period) {
+                                        (format!("{:.2}", per_match))
+                                    } @else {
+                                        "-"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::placements::{Medal, MedalTableEntity, MAX_FINAL_RANK, MAX_SEED};
+use crate::service::team_participations::TeamParticipationEntity;
+use crate::views::components::forms::csrf_token_field;
+
+/// Placement label: the place, with a medal for the first three
+pub fn placement_label(rank: i64) -> String {
+    match Medal::from_rank(rank) {
+        Some(medal) => format!("{} {}.", medal.emoji(), rank),
+        None => format!("{}.", rank),
+    }
+}
+
+/// Final placement dropdown for a team card on the season detail page
+pub fn placement_select(
+    session: &Session,
+    t: &TranslationContext,
+    team: &TeamParticipationEntity,
+) -> Markup {
+    html! {
+        form
+            hx-post=(format!("/team-participations/{}/placement", team.id))
+            hx-trigger="change"
+            style="display: flex; align-items: center; gap: 0.5rem; font-size: 0.875rem;"
+        {
+            (csrf_token_field(&session.csrf_token))
+            label style="color: var(--gray-600);" for=(format!("placement-{}", team.id)) {
+                (t.messages.placements_final_placement())
+            }
+            select
+                id=(format!("placement-{}", team.id))
+                name="final_rank"
+                style="flex: 1; padding: 0.25rem 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
+            {
+                option value="" { (t.messages.placements_none()) }
+                @for rank in 1..=MAX_FINAL_RANK {
+                    option value=(rank) selected[team.final_rank == Some(rank)] { (placement_label(rank)) }
+                }
+            }
+        }
+    }
+}
+
+/// Playoff seed dropdown for a team card on the season detail page
+pub fn seed_select(
+    session: &Session,
+    t: &TranslationContext,
+    team: &TeamParticipationEntity,
+) -> Markup {
+    html! {
+        form
+            hx-post=(format!("/team-participations/{}/seed", team.id))
+            hx-trigger="change"
+            style="display: flex; align-items: center; gap: 0.5rem; font-size: 0.875rem;"
+        {
+            (csrf_token_field(&session.csrf_token))
+            label style="color: var(--gray-600);" for=(format!("seed-{}", team.id)) {
+                (t.messages.playoffs_seed())
+            }
+            select
+                id=(format!("seed-{}", team.id))
+                name="seed"
+                style="flex: 1; padding: 0.25rem 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
+            {
+                option value="" { (t.messages.placements_not_seeded()) }
+                @for seed in 1..=MAX_SEED {
+                    option value=(seed) selected[team.seed == Some(seed)] { (seed) }
+                }
+            }
+        }
+    }
+}
+
+/// Medal table of an event, summed over all its seasons
+pub fn medal_table_section(t: &TranslationContext, medal_table: &[MedalTableEntity]) -> Markup {
+    html! {
+        div style="margin-top: 2rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                (t.messages.placements_medal_table())
+            }
+            p style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 1rem;" {
+                (t.messages.placements_medal_table_hint())
+            }
+
+            @if medal_table.is_empty() {
+                p style="color: var(--gray-500);" { (t.messages.placements_no_medals()) }
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th style="width: 3rem;" { "#" }
+                            th { (t.messages.standings_team()) }
+                            th style="text-align: right;" title=(t.messages.placements_gold()) { (Medal::Gold.emoji()) }
+                            th style="text-align: right;" title=(t.messages.placements_silver()) { (Medal::Silver.emoji()) }
+                            th style="text-align: right;" title=(t.messages.placements_bronze()) { (Medal::Bronze.emoji()) }
+                            th style="text-align: right;" { (t.messages.placements_total()) }
+                        }
+                    }
+                    tbody {
+                        @for (index, row) in medal_table.iter().enumerate() {
+                            tr {
+                                td { (index + 1) }
+                                td {
+                                    div style="display: flex; align-items: center; gap: 0.5rem;" {
+                                        @if let Some(iso2) = &row.country_iso2_code {
+                                            flag-icon
+                                                country-code=(iso2.to_lowercase())
+                                                country-name=(row.team_name)
+                                                size="sm";
+                                        }
+                                        a href=(format!("/teams/{}", row.team_id)) class="primary-link" {
+                                            (row.team_name)
+                                        }
+                                    }
+                                }
+                                td style="text-align: right;" { (row.gold) }
+                                td style="text-align: right;" { (row.silver) }
+                                td style="text-align: right;" { (row.bronze) }
+                                td style="text-align: right; font-weight: 600;" { (row.total()) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -2,21 +2,39 @@ use maud::{html, Markup};
 
 use crate::auth::Session;
 use crate::i18n::TranslationContext;
+use crate::service::awards::PlayerAwardEntity;
+use crate::service::comments::CommentEntityType;
+use crate::service::milestones::MilestoneEntity;
 use crate::service::players::{
     PlayerContractWithTeamEntity, PlayerDetailEntity, PlayerEntity, PlayerEventStatsEntity,
     PlayerSeasonStats, PropertyChangeEntity,
 };
+use crate::service::season_stats::PlayerSeasonPercentiles;
+use crate::service::streaks::PlayerStreaksEntity;
+use crate::service::tasks::TaskEntityType;
+use crate::service::transfers::TransferEntity;
 use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
 use crate::views::components::forms::csrf_token_field;
+use crate::views::pages::awards::player_awards_section;
+use crate::views::pages::comments::entity_comments_section;
+use crate::views::pages::milestones::player_milestones_section;
+use crate::views::pages::streaks::player_streaks_badges;
+use crate::views::pages::tasks::entity_tasks_section;
 
 /// Player detail page with career history and scoring
+#[allow(clippy::too_many_arguments)]
 pub fn player_detail_page(
     session: &Session,
     t: &TranslationContext,
     detail: &PlayerDetailEntity,
     season_stats: &[PlayerSeasonStats],
+    percentiles: &[PlayerSeasonPercentiles],
     event_stats: &[PlayerEventStatsEntity],
     property_changes: &[PropertyChangeEntity],
+    transfers: &[TransferEntity],
+    awards: &[PlayerAwardEntity],
+    milestones: &[MilestoneEntity],
+    streaks: &PlayerStreaksEntity,
 ) -> Markup {
     let player = &detail.player_info;
 
@@ -44,6 +62,18 @@ pub fn player_detail_page(
                             (t.messages.player_view_scoring())
                         }
                     }
+                    a
+                        href=(format!("/players/{}/splits", player.id))
+                        class="btn btn-secondary"
+                    {
+                        (t.messages.splits_view())
+                    }
+                    a
+                        href=(format!("/players/{}/history", player.id))
+                        class="btn btn-secondary"
+                    {
+                        (t.messages.history_view())
+                    }
                     button
                         class="btn btn-primary"
                         hx-get=(format!("/players/{}/edit", player.id))
@@ -72,15 +102,38 @@ pub fn player_detail_page(
                 }
             }
 
+            // Current and longest scoring streaks
+            (player_streaks_badges(t, streaks))
+
             // Player Info Card
             (player_info_card(t, player))
 
             // Career Statistics Summary by Event
             (career_stats_by_event(t, player, event_stats))
 
+            // Rankings within recent seasons
+            @if !percentiles.is_empty() {
+                (season_percentiles_section(t, percentiles))
+            }
+
             // Property Changes Timeline
             (property_changes_timeline(t, player, property_changes))
 
+            // Transfers Timeline
+            @if !transfers.is_empty() {
+                (transfers_timeline(t, transfers))
+            }
+
+            // Season awards
+            @if !awards.is_empty() {
+                (player_awards_section(t, awards))
+            }
+
+            // Milestones
+            @if !milestones.is_empty() {
+                (player_milestones_section(t, milestones))
+            }
+
             // Career History Section
             div style="margin-top: 2rem;" {
                 div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
@@ -96,6 +149,12 @@ pub fn player_detail_page(
                 }
             }
 
+            // Internal discussion about this player
+            (entity_comments_section(t, CommentEntityType::Player, player.id))
+
+            // Open tasks attached to this player
+            (entity_tasks_section(t, TaskEntityType::Player, player.id))
+
             // Modal container
             div id="modal-container" {}
         }
@@ -280,6 +339,62 @@ fn empty_contracts_state(t: &TranslationContext) -> Markup {
 }
 
 /// Career statistics by event (competition-specific totals)
+/// "Top N%" rankings of the player within their recent seasons
+fn season_percentiles_section(
+    t: &TranslationContext,
+    percentiles: &[PlayerSeasonPercentiles],
+) -> Markup {
+    html! {
+        div style="margin-top: 1.5rem;" {
+            h3 style="font-size: 1.125rem; font-weight: 700; margin-bottom: 0.25rem;" {
+                (t.messages.players_season_rankings())
+            }
+            p style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 1rem;" {
+                (t.messages.players_season_rankings_hint())
+            }
+            div style="display: grid; gap: 0.75rem;" {
+                @for entry in percentiles {
+                    div style="padding: 0.75rem 1rem; border: 1px solid var(--gray-200); border-radius: 8px; background: white;" {
+                        div style="display: flex; justify-content: space-between; margin-bottom: 0.5rem;" {
+                            span style="font-weight: 600;" {
+                                (entry.season.event_name)
+                                " "
+                                (entry.season.season_display_name.clone().unwrap_or_else(|| entry.season.season_year.to_string()))
+                            }
+                            span style="color: var(--gray-600); font-size: 0.875rem;" {
+                                (format!("{} {}", entry.percentiles.players_count, t.messages.players_season_rankings_players()))
+                            }
+                        }
+                        div style="display: flex; flex-wrap: wrap; gap: 0.5rem;" {
+                            @if let Some(top) = entry.percentiles.points_per_game {
+                                (percentile_chip(t, top, &t.messages.players_season_rankings_points_per_game().to_string()))
+                            }
+                            (percentile_chip(t, entry.percentiles.points, &t.messages.players_season_rankings_points().to_string()))
+                            (percentile_chip(t, entry.percentiles.goals, &t.messages.players_season_rankings_goals().to_string()))
+                            (percentile_chip(t, entry.percentiles.assists, &t.messages.players_season_rankings_assists().to_string()))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A ranking such as "Top 5% in points per game", highlighted for the top tenth
+fn percentile_chip(t: &TranslationContext, top: u32, in_stat: &str) -> Markup {
+    let style = if top <= 10 {
+        "background: var(--primary-color); color: white;"
+    } else {
+        "background: var(--gray-100); color: var(--gray-700);"
+    };
+
+    html! {
+        span style=(format!("font-size: 0.875rem; padding: 0.25rem 0.75rem; border-radius: 9999px; {}", style)) {
+            (format!("{} {}% {}", t.messages.players_season_rankings_top(), top, in_stat))
+        }
+    }
+}
+
 fn career_stats_by_event(
     t: &TranslationContext,
     player: &PlayerEntity,
@@ -511,6 +626,49 @@ fn property_change_card(
     }
 }
 
+/// Transfers timeline (moves between teams within a season)
+fn transfers_timeline(t: &TranslationContext, transfers: &[TransferEntity]) -> Markup {
+    html! {
+        div style="margin-top: 2rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin: 0 0 1.5rem 0;" {
+                (t.messages.player_transfers_title())
+            }
+            div style="display: flex; flex-direction: column; gap: 1rem;" {
+                @for transfer in transfers {
+                    div style="padding: 1.25rem; border: 1px solid var(--gray-200); border-radius: 8px; background: white;" {
+                        div style="display: flex; align-items: center; gap: 0.75rem; flex-wrap: wrap; margin-bottom: 0.75rem;" {
+                            span style="color: var(--gray-600); font-size: 0.875rem;" {
+                                (transfer.transfer_date)
+                            }
+                            a
+                                href=(format!("/seasons/{}", transfer.season_id))
+                                style="padding: 0.25rem 0.5rem; background: var(--gray-100); color: var(--gray-700); border-radius: 4px; font-size: 0.75rem; text-decoration: none;"
+                            {
+                                (transfer.event_name) " "
+                                (transfer.season_display_name.clone().unwrap_or_else(|| transfer.season_year.to_string()))
+                            }
+                        }
+                        div style="display: flex; align-items: center; gap: 0.75rem; font-weight: 500; flex-wrap: wrap;" {
+                            a href=(format!("/teams/{}", transfer.from_team_id)) style="color: var(--primary-color); text-decoration: none;" {
+                                (transfer.from_team_name)
+                            }
+                            span style="color: var(--gray-400);" { "→" }
+                            a href=(format!("/teams/{}", transfer.to_team_id)) style="color: var(--primary-color); text-decoration: none;" {
+                                (transfer.to_team_name)
+                            }
+                        }
+                        @if let Some(note) = &transfer.note {
+                            p style="color: var(--gray-700); line-height: 1.5; margin: 0.75rem 0 0 0;" {
+                                (note)
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Empty state when no property changes exist
 fn empty_property_changes_state(t: &TranslationContext) -> Markup {
     html! {
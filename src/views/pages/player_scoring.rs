@@ -76,7 +76,9 @@ fn season_stats_summary(t: &TranslationContext, season_stats: &[PlayerSeasonStat
                         }
 
                         // Stats grid
-                        div style="display: grid; grid-template-columns: repeat(3, 1fr); gap: 1rem; margin-top: 1rem;" {
+                        @let faceoff_pct = stats.faceoff_percentage();
+                        @let columns = 3 + usize::from(stats.plus_minus.is_some()) + usize::from(faceoff_pct.is_some());
+                        div style=(format!("display: grid; grid-template-columns: repeat({}, 1fr); gap: 1rem; margin-top: 1rem;", columns)) {
                             // Goals
                             div {
                                 div style="font-size: 0.75rem; opacity: 0.8;" {
@@ -106,6 +108,33 @@ fn season_stats_summary(t: &TranslationContext, season_stats: &[PlayerSeasonStat
                                     (stats.points)
                                 }
                             }
+
+                            // Plus/minus, once on-ice players are recorded
+                            @if let Some(plus_minus) = stats.plus_minus {
+                                div {
+                                    div style="font-size: 0.75rem; opacity: 0.8;" {
+                                        (t.messages.player_scoring_plus_minus())
+                                    }
+                                    div style="font-size: 2rem; font-weight: 700;" {
+                                        (format_plus_minus(plus_minus))
+                                    }
+                                }
+                            }
+
+                            // Faceoff percentage, once faceoffs are recorded
+                            @if let Some(pct) = faceoff_pct {
+                                div {
+                                    div style="font-size: 0.75rem; opacity: 0.8;" {
+                                        (t.messages.player_scoring_faceoff_percentage())
+                                    }
+                                    div style="font-size: 2rem; font-weight: 700;" {
+                                        (format!("{:.1}%", pct))
+                                    }
+                                    div style="font-size: 0.75rem; opacity: 0.8;" {
+                                        (format!("{}–{}", stats.faceoffs_won, stats.faceoffs_lost))
+                                    }
+                                }
+                            }
                         }
                     }
                 }
@@ -114,6 +143,15 @@ fn season_stats_summary(t: &TranslationContext, season_stats: &[PlayerSeasonStat
     }
 }
 
+/// Plus/minus with an explicit sign for positive values
+fn format_plus_minus(plus_minus: i32) -> String {
+    if plus_minus > 0 {
+        format!("+{}", plus_minus)
+    } else {
+        plus_minus.to_string()
+    }
+}
+
 /// Filters section
 fn scoring_filters(
     t: &TranslationContext,
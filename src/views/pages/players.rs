@@ -2,11 +2,12 @@ use maud::{html, Markup};
 
 use crate::auth::Session;
 use crate::i18n::TranslationContext;
+use crate::service::player_import::{PlayerImportReport, PlayerRowOutcome};
 use crate::service::players::{PagedResult, PlayerEntity, PlayerFilters, SortField, SortOrder};
 use crate::views::components::crud::{
-    empty_state, modal_form_multipart, page_header, pagination, table_actions,
+    empty_state, modal_form_multipart, pagination, table_actions,
 };
-use crate::views::components::forms::csrf_token_field;
+use crate::views::components::forms::{csrf_token_field, form_field, InputType};
 
 /// Main players page with table and filters
 pub fn players_page(
@@ -20,13 +21,41 @@ pub fn players_page(
 ) -> Markup {
     html! {
         div class="card" {
-            // Header with title and create button
-            (page_header(
-                &t.messages.players_title().to_string(),
-                &t.messages.players_description().to_string(),
-                "/players/new",
-                &t.messages.players_create().to_string()
-            ))
+            // Header with title, import and create buttons
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
+                div {
+                    h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                        (t.messages.players_title())
+                    }
+                    p style="color: var(--gray-600);" {
+                        (t.messages.players_description())
+                    }
+                }
+                div style="display: flex; gap: 0.5rem;" {
+                    button
+                        class="btn btn-secondary"
+                        hx-get="/players/import"
+                        hx-target="#modal-container"
+                        hx-swap="innerHTML"
+                    {
+                        (t.messages.players_import())
+                    }
+                    button
+                        class="btn btn-primary"
+                        hx-get="/players/new"
+                        hx-target="#modal-container"
+                        hx-swap="innerHTML"
+                    {
+                        (t.messages.players_create())
+                    }
+                }
+            }
+
+            div style="margin-bottom: 1rem;" {
+                a href="/players/streaks" class="primary-link" {
+                    (t.messages.streaks_leaderboard_link())
+                }
+            }
 
             // Filters
             div style="margin-bottom: 1.5rem; padding: 1rem; background: var(--gray-50); border-radius: 8px;" {
@@ -115,6 +144,15 @@ pub fn player_list_content(
                     Some(&t.messages.players_create().to_string()),
                 ))
             } @else {
+                div style="display: flex; justify-content: flex-end; margin-bottom: 0.75rem;" {
+                    a
+                        href=(build_list_url("/players/export", sort_field, sort_order, filters))
+                        class="btn btn-secondary btn-sm"
+                        download
+                    {
+                        (t.messages.list_export_csv())
+                    }
+                }
                 table class="table" {
                     thead {
                         tr {
@@ -264,11 +302,17 @@ fn sortable_header(
 
 /// Helper to build sort URLs
 fn build_sort_url(field: &SortField, order: &SortOrder, filters: &PlayerFilters) -> String {
-    let mut url = format!(
-        "/players/list?sort={}&order={}",
-        field.as_str(),
-        order.as_str()
-    );
+    build_list_url("/players/list", field, order, filters)
+}
+
+/// URL of `path` carrying the list's sorting and filters
+fn build_list_url(
+    path: &str,
+    field: &SortField,
+    order: &SortOrder,
+    filters: &PlayerFilters,
+) -> String {
+    let mut url = format!("{}?sort={}&order={}", path, field.as_str(), order.as_str());
 
     if let Some(name) = &filters.name {
         url.push_str(&format!("&name={}", urlencoding::encode(name)));
@@ -514,6 +558,17 @@ pub fn player_edit_modal(
                 style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
         }
 
+        (form_field(
+            "slug",
+            &t.messages.form_slug().to_string(),
+            InputType::Text,
+            player.slug.as_deref(),
+            None,
+            false,
+            None,
+            Some(&t.messages.form_slug_help().to_string()),
+        ))
+
         div style="margin-bottom: 1rem;" {
             label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
                 (t.messages.form_country())
@@ -667,3 +722,111 @@ pub fn player_edit_modal(
         &t.messages.common_save().to_string(),
     )
 }
+
+/// Import players from a CSV modal
+pub fn player_import_modal(
+    session: &Session,
+    t: &TranslationContext,
+    error: Option<&str>,
+) -> Markup {
+    let form_fields = html! {
+        (csrf_token_field(&session.csrf_token))
+
+        p style="color: var(--gray-600); margin-bottom: 1rem;" {
+            (t.messages.players_import_help())
+        }
+
+        div style="margin-bottom: 1.5rem;" {
+            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                (t.messages.players_import_file())
+                span style="color: red;" { "*" }
+            }
+            input
+                type="file"
+                name="file"
+                accept="text/csv,.csv"
+                required
+                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
+        }
+    };
+
+    modal_form_multipart(
+        "player-modal",
+        &t.messages.players_import_title().to_string(),
+        error,
+        "/players/import",
+        form_fields,
+        &t.messages.players_import_submit().to_string(),
+    )
+}
+
+/// Per-row result of a player import, replacing the import modal
+pub fn player_import_result_modal(t: &TranslationContext, report: &PlayerImportReport) -> Markup {
+    html! {
+        div
+            class="modal-backdrop"
+            style="position: fixed; top: 0; left: 0; right: 0; bottom: 0; background: rgba(0, 0, 0, 0.5); display: flex; align-items: center; justify-content: center; z-index: 1000;"
+            id="player-modal"
+        {
+            div
+                class="modal"
+                style="background: white; border-radius: 12px; padding: 2rem; max-width: 720px; width: 90%; max-height: 90vh; overflow-y: auto;"
+            {
+                h2 style="font-size: 1.5rem; font-weight: 700; margin: 0 0 0.5rem 0;" {
+                    (t.messages.players_import_done())
+                }
+                p style="color: var(--gray-600); margin-bottom: 1rem;" {
+                    (t.messages.players_import_created()) ": " strong { (report.created()) }
+                    " · "
+                    (t.messages.players_import_duplicates()) ": " strong { (report.duplicates()) }
+                    " · "
+                    (t.messages.players_import_invalid()) ": " strong { (report.invalid()) }
+                }
+                table class="table" style="margin-bottom: 1.5rem;" {
+                    thead {
+                        tr {
+                            th style="text-align: right;" { (t.messages.players_import_line()) }
+                            th { (t.messages.form_name()) }
+                            th { (t.messages.players_import_result()) }
+                        }
+                    }
+                    tbody {
+                        @for row in &report.rows {
+                            tr {
+                                td style="text-align: right; color: var(--gray-500);" { (row.row.line) }
+                                td { (row.row.name) }
+                                td {
+                                    @match &row.outcome {
+                                        PlayerRowOutcome::Created { player_id } => {
+                                            a href=(format!("/players/{}", player_id)) style="color: #15803d;" {
+                                                (t.messages.players_import_created())
+                                            }
+                                        }
+                                        PlayerRowOutcome::Duplicate { player_id, player_name } => {
+                                            span style="color: var(--gray-600);" {
+                                                (t.messages.players_import_duplicate_of()) " "
+                                                a href=(format!("/players/{}", player_id)) { (player_name) }
+                                            }
+                                        }
+                                        PlayerRowOutcome::Invalid(error) => {
+                                            span style="color: #b91c1c;" { (error.message()) }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                div style="display: flex; justify-content: flex-end;" {
+                    button
+                        type="button"
+                        class="btn btn-secondary"
+                        onclick="document.getElementById('player-modal').remove()"
+                    {
+                        (t.messages.common_close())
+                    }
+                }
+            }
+        }
+    }
+}
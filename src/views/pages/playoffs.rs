@@ -0,0 +1,307 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::playoffs::PlayoffSeriesEntity;
+use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
+use crate::views::components::crud::modal_form_i18n;
+use crate::views::components::forms::csrf_token_field;
+
+/// Playoff bracket section for the season detail page
+///
+/// Series are laid out in one column per round, each card showing seeds,
+/// the running series score and links to the individual games.
+pub fn playoff_bracket(
+    session: &Session,
+    t: &TranslationContext,
+    season_id: i64,
+    series: &[PlayoffSeriesEntity],
+) -> Markup {
+    let mut rounds: Vec<i64> = series.iter().map(|s| s.round).collect();
+    rounds.dedup();
+
+    html! {
+        div id="playoff-bracket" style="margin-top: 2rem;" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
+                h2 style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
+                    (t.messages.playoffs_title())
+                }
+                button
+                    class="btn btn-primary"
+                    hx-get=(format!("/seasons/{}/playoffs/new", season_id))
+                    hx-target="#modal-container"
+                    hx-swap="innerHTML"
+                {
+                    (format!("+ {}", t.messages.playoffs_add_series()))
+                }
+            }
+
+            @if series.is_empty() {
+                div style="padding: 2rem; text-align: center; background: var(--gray-50); border-radius: 8px; border: 2px dashed var(--gray-300); color: var(--gray-600);" {
+                    (t.messages.playoffs_empty())
+                    (generate_bracket_form(session, t, season_id))
+                }
+            } @else {
+                div style="display: flex; gap: 1.5rem; overflow-x: auto; align-items: stretch;" {
+                    @for round in &rounds {
+                        div style="display: flex; flex-direction: column; justify-content: space-around; gap: 1rem; min-width: 260px;" {
+                            div style="font-weight: 600; color: var(--gray-600); text-align: center;" {
+                                (format!("{} {}", t.messages.playoffs_round(), round))
+                            }
+                            @for item in series.iter().filter(|s| s.round == *round) {
+                                (series_card(session, t, item))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Form creating round 1 from the team seeds, shown while the bracket is empty
+fn generate_bracket_form(session: &Session, t: &TranslationContext, season_id: i64) -> Markup {
+    html! {
+        form
+            hx-post=(format!("/seasons/{}/playoffs/generate", season_id))
+            style="display: flex; justify-content: center; align-items: center; gap: 0.5rem; margin-top: 1rem;"
+        {
+            (csrf_token_field(&session.csrf_token))
+            label for="generate-best-of" { (t.messages.playoffs_best_of()) }
+            select id="generate-best-of" name="best_of" style="padding: 0.25rem 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;" {
+                @for best_of in [7, 5, 3, 1] {
+                    option value=(best_of) { (best_of) }
+                }
+            }
+            button type="submit" class="btn btn-sm btn-primary" {
+                (t.messages.playoffs_generate_from_seeds())
+            }
+        }
+        p style="font-size: 0.875rem; margin-top: 0.5rem;" {
+            (t.messages.playoffs_generate_hint())
+        }
+    }
+}
+
+/// Single series card with both teams, series score and games
+fn series_card(session: &Session, t: &TranslationContext, series: &PlayoffSeriesEntity) -> Markup {
+    let winner = series.winner_team_id();
+
+    html! {
+        div style="padding: 0.75rem; border: 1px solid var(--gray-200); border-radius: 8px; background: white;" {
+            (series_team_row(
+                &series.home_team_name,
+                series.home_team_country_iso2.as_deref(),
+                series.home_seed,
+                series.home_wins,
+                winner == Some(series.home_team_id),
+            ))
+            (series_team_row(
+                &series.away_team_name,
+                series.away_team_country_iso2.as_deref(),
+                series.away_seed,
+                series.away_wins,
+                winner == Some(series.away_team_id),
+            ))
+
+            div style="font-size: 0.75rem; color: var(--gray-500); margin-top: 0.5rem;" {
+                (format!("{} {}", t.messages.playoffs_best_of(), series.best_of))
+            }
+
+            @if !series.games.is_empty() {
+                div style="display: flex; flex-wrap: wrap; gap: 0.25rem; margin-top: 0.5rem;" {
+                    @for (index, game) in series.games.iter().enumerate() {
+                        a
+                            href=(format!("/matches/{}", game.match_id))
+                            title=[game.match_date.as_ref()]
+                            style="font-size: 0.75rem; padding: 0.125rem 0.5rem; border-radius: 9999px; background: var(--gray-100); color: var(--gray-700); text-decoration: none;"
+                        {
+                            (format!("G{}", index + 1))
+                            @if game.status == "finished" {
+                                (format!(" {}:{}", game.home_score, game.away_score))
+                            }
+                        }
+                    }
+                }
+            }
+
+            div style="display: flex; gap: 0.5rem; margin-top: 0.75rem;" {
+                @if series.can_add_game() {
+                    button
+                        class="btn btn-sm"
+                        hx-get=(format!("/playoffs/{}/games/new", series.id))
+                        hx-target="#modal-container"
+                        hx-swap="innerHTML"
+                    {
+                        (format!("+ {}", t.messages.playoffs_add_game()))
+                    }
+                }
+                form style="display: inline; margin-left: auto;" {
+                    (csrf_token_field(&session.csrf_token))
+                    button
+                        type="submit"
+                        class="btn btn-sm btn-danger"
+                        hx-post=(format!("/playoffs/{}/delete", series.id))
+                        hx-confirm-custom=(confirm_attrs(
+                            &format!(
+                                "{} \"{} {} {}\"",
+                                t.messages.common_delete(),
+                                series.home_team_name,
+                                t.messages.matches_vs(),
+                                series.away_team_name
+                            ),
+                            &t.messages.playoffs_confirm_delete().to_string(),
+                            ConfirmVariant::Danger,
+                            Some(&t.messages.common_delete().to_string()),
+                            Some(&t.messages.common_cancel().to_string())
+                        ))
+                    {
+                        (t.messages.common_delete())
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn series_team_row(
+    name: &str,
+    iso2: Option<&str>,
+    seed: Option<i64>,
+    wins: i64,
+    is_winner: bool,
+) -> Markup {
+    html! {
+        div style=(format!(
+            "display: flex; align-items: center; gap: 0.5rem; padding: 0.25rem 0; {}",
+            if is_winner { "font-weight: 700;" } else { "" }
+        )) {
+            span style="width: 1.5rem; font-size: 0.75rem; color: var(--gray-500); text-align: right;" {
+                @if let Some(seed) = seed {
+                    (seed)
+                }
+            }
+            @if let Some(iso2) = iso2 {
+                flag-icon country-code=(iso2.to_lowercase()) country-name=(name) size="sm" {}
+            }
+            span style="flex: 1;" { (name) }
+            span style="font-weight: 600; min-width: 1.5rem; text-align: right;" { (wins) }
+        }
+    }
+}
+
+/// Modal form to create a playoff series
+pub fn series_create_modal(
+    session: &Session,
+    t: &TranslationContext,
+    season_id: i64,
+    error: Option<&str>,
+    teams: &[(i64, String)],
+) -> Markup {
+    let form_fields = html! {
+        (csrf_token_field(&session.csrf_token))
+        div class="form-row" style="margin-bottom: 1rem;" {
+            div class="form-group" {
+                label class="form-label" {
+                    (t.messages.playoffs_round())
+                    span class="required-indicator" { " *" }
+                }
+                input type="number" name="round" min="1" value="1" required;
+            }
+            div class="form-group" {
+                label class="form-label" {
+                    (t.messages.playoffs_best_of())
+                    span class="required-indicator" { " *" }
+                }
+                select name="best_of" required {
+                    @for best_of in [7, 5, 3, 1] {
+                        option value=(best_of) { (best_of) }
+                    }
+                }
+            }
+        }
+
+        div class="form-row" style="margin-bottom: 1rem;" {
+            div class="form-group" {
+                label class="form-label" {
+                    (t.messages.playoffs_higher_seed())
+                    span class="required-indicator" { " *" }
+                }
+                select name="home_team_id" required {
+                    option value="" { (t.messages.matches_select_team()) }
+                    @for (id, name) in teams {
+                        option value=(id) { (name) }
+                    }
+                }
+            }
+            div class="form-group" {
+                label class="form-label" { (t.messages.playoffs_seed()) }
+                input type="number" name="home_seed" min="1";
+            }
+        }
+
+        div class="form-row" style="margin-bottom: 1rem;" {
+            div class="form-group" {
+                label class="form-label" {
+                    (t.messages.playoffs_lower_seed())
+                    span class="required-indicator" { " *" }
+                }
+                select name="away_team_id" required {
+                    option value="" { (t.messages.matches_select_team()) }
+                    @for (id, name) in teams {
+                        option value=(id) { (name) }
+                    }
+                }
+            }
+            div class="form-group" {
+                label class="form-label" { (t.messages.playoffs_seed()) }
+                input type="number" name="away_seed" min="1";
+            }
+        }
+    };
+
+    modal_form_i18n(
+        "series-modal",
+        &t.messages.playoffs_create_title().to_string(),
+        error,
+        &format!("/seasons/{}/playoffs", season_id),
+        form_fields,
+        &t.messages.common_create().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Modal form to schedule the next game of a series
+pub fn series_game_modal(
+    session: &Session,
+    t: &TranslationContext,
+    series: &PlayoffSeriesEntity,
+    error: Option<&str>,
+) -> Markup {
+    let form_fields = html! {
+        (csrf_token_field(&session.csrf_token))
+        p style="margin-bottom: 1rem; color: var(--gray-600);" {
+            (format!(
+                "G{}: {} {} {}",
+                series.games.len() + 1,
+                series.home_team_name,
+                t.messages.matches_vs(),
+                series.away_team_name
+            ))
+        }
+        div class="form-group" style="margin-bottom: 1rem;" {
+            label class="form-label" { (t.messages.matches_date()) }
+            input type="date" name="match_date";
+        }
+    };
+
+    modal_form_i18n(
+        "series-game-modal",
+        &t.messages.playoffs_add_game().to_string(),
+        error,
+        &format!("/playoffs/{}/games", series.id),
+        form_fields,
+        &t.messages.common_add().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
@@ -0,0 +1,217 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::predictions::{
+    PredictedWinner, PredictorStandingEntity, UpcomingMatchPickEntity,
+};
+use crate::views::components::crud::empty_state_i18n;
+use crate::views::components::forms::csrf_token_field;
+use crate::views::pages::matches::format_date;
+
+/// Upcoming matches with pick buttons for the signed-in user
+pub fn predictions_page(
+    session: &Session,
+    t: &TranslationContext,
+    seasons: &[(i64, String)],
+    season_id: Option<i64>,
+    matches: &[UpcomingMatchPickEntity],
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
+                div {
+                    h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                        (t.messages.predictions_title())
+                    }
+                    p style="color: var(--gray-600);" {
+                        (t.messages.predictions_description())
+                    }
+                }
+                a
+                    href=(match season_id {
+                        Some(id) => format!("/predictions/leaderboard?season_id={}", id),
+                        None => "/predictions/leaderboard".to_string(),
+                    })
+                    class="btn btn-secondary"
+                {
+                    (t.messages.predictions_leaderboard())
+                }
+            }
+
+            (season_filter(t, "/predictions", seasons, season_id, true))
+
+            @if matches.is_empty() {
+                (empty_state_i18n(
+                    &t.messages.predictions_empty_title().to_string(),
+                    &t.messages.predictions_empty_message().to_string(),
+                    false
+                ))
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.matches_date()) }
+                            th { (t.messages.matches_season()) }
+                            th { (t.messages.predictions_match()) }
+                            th style="text-align: right;" { (t.messages.predictions_your_pick()) }
+                        }
+                    }
+                    tbody {
+                        @for m in matches {
+                            (prediction_row(session, m))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// One match row; swapped in place after a pick is saved
+pub fn prediction_row(session: &Session, m: &UpcomingMatchPickEntity) -> Markup {
+    let pick = m.pick();
+
+    html! {
+        tr id=(format!("prediction-{}", m.match_id)) {
+            td style="white-space: nowrap;" {
+                @if let Some(date) = &m.match_date {
+                    (format_date(date))
+                } @else {
+                    span style="color: var(--gray-400); font-style: italic;" { "TBD" }
+                }
+            }
+            td { (m.season_name) }
+            td {
+                a href=(format!("/matches/{}", m.match_id)) class="primary-link" {
+                    (format!("{} vs {}", m.home_team_name, m.away_team_name))
+                }
+            }
+            td style="text-align: right; white-space: nowrap;" {
+                form style="display: inline-flex; gap: 0.5rem;" {
+                    (csrf_token_field(&session.csrf_token))
+                    @for (winner, team_name) in [
+                        (PredictedWinner::Home, &m.home_team_name),
+                        (PredictedWinner::Away, &m.away_team_name),
+                    ] {
+                        button
+                            type="submit"
+                            name="winner"
+                            value=(winner.as_str())
+                            class=(if pick == Some(winner) { "btn btn-sm btn-primary" } else { "btn btn-sm btn-secondary" })
+                            aria-pressed=(pick == Some(winner))
+                            hx-post=(format!("/predictions/{}", m.match_id))
+                            hx-target=(format!("#prediction-{}", m.match_id))
+                            hx-swap="outerHTML"
+                        {
+                            (team_name)
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Season leaderboard of predictors
+pub fn leaderboard_page(
+    session: &Session,
+    t: &TranslationContext,
+    seasons: &[(i64, String)],
+    season_id: Option<i64>,
+    standings: &[PredictorStandingEntity],
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="display: flex; align-items: center; gap: 1rem; margin-bottom: 1.5rem;" {
+                a href="/predictions" class="btn btn-secondary" {
+                    (format!("← {}", t.messages.predictions_back()))
+                }
+                h1 style="font-size: 2rem; font-weight: 700; margin: 0;" {
+                    (t.messages.predictions_leaderboard())
+                }
+            }
+            p style="color: var(--gray-600); margin-bottom: 1rem;" {
+                (t.messages.predictions_leaderboard_description())
+            }
+
+            (season_filter(t, "/predictions/leaderboard", seasons, season_id, false))
+
+            @if standings.is_empty() {
+                (empty_state_i18n(
+                    &t.messages.predictions_leaderboard_empty_title().to_string(),
+                    &t.messages.predictions_leaderboard_empty_message().to_string(),
+                    false
+                ))
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th style="width: 4rem;" { "#" }
+                            th { (t.messages.predictions_predictor()) }
+                            th style="text-align: right;" { (t.messages.predictions_correct()) }
+                            th style="text-align: right;" { (t.messages.predictions_picks()) }
+                            th style="text-align: right;" { (t.messages.predictions_accuracy()) }
+                        }
+                    }
+                    tbody {
+                        @for (standing, rank) in standings.iter().zip(ranks(standings)) {
+                            tr style=(if standing.user_id == session.user_id { "font-weight: 600; background: var(--gray-50);" } else { "" }) {
+                                td { (rank) }
+                                td { (standing.user_name) }
+                                td style="text-align: right;" { (standing.correct) }
+                                td style="text-align: right;" { (standing.picks) }
+                                td style="text-align: right;" {
+                                    @if let Some(accuracy) = standing.accuracy() {
+                                        (format!("{:.1}%", accuracy))
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Standard competition ranks ("1, 2, 2, 4"); equal records share a rank
+fn ranks(standings: &[PredictorStandingEntity]) -> Vec<usize> {
+    let mut ranks: Vec<usize> = Vec::with_capacity(standings.len());
+    for (i, standing) in standings.iter().enumerate() {
+        let rank = match i.checked_sub(1).map(|prev| &standings[prev]) {
+            Some(prev) if prev.correct == standing.correct && prev.picks == standing.picks => {
+                ranks[i - 1]
+            }
+            _ => i + 1,
+        };
+        ranks.push(rank);
+    }
+    ranks
+}
+
+/// Season dropdown submitting a GET to `action`
+fn season_filter(
+    t: &TranslationContext,
+    action: &str,
+    seasons: &[(i64, String)],
+    season_id: Option<i64>,
+    allow_all: bool,
+) -> Markup {
+    html! {
+        form method="get" action=(action) style="margin-bottom: 1rem;" {
+            select
+                name="season_id"
+                onchange="this.form.submit()"
+                style="padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
+            {
+                @if allow_all {
+                    option value="" { (t.messages.matches_all_seasons()) }
+                }
+                @for (id, name) in seasons {
+                    option value=(id) selected[season_id == Some(*id)] { (name) }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use maud::{html, Markup};
+
+use crate::auth::api_keys::{ApiKeyEntry, ApiScope};
+use crate::auth::audit::{LoginAuditEntry, LoginFailure};
+use crate::auth::oauth::OAuthProvider;
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::user_identities::UserIdentityEntity;
+use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
+use crate::views::components::crud::empty_state_i18n;
+use crate::views::components::forms::csrf_token_field;
+
+/// Profile page: account details, linked accounts, API keys and recent
+/// sign-in attempts
+pub fn profile_page(
+    t: &TranslationContext,
+    session: &Session,
+    logins: &[LoginAuditEntry],
+    api_keys: &[ApiKeyEntry],
+    providers: &[OAuthProvider],
+    identities: &[UserIdentityEntity],
+) -> Markup {
+    html! {
+        div class="card" {
+            h1 class="page-title" { (t.messages.profile_title()) }
+            p class="page-description" { (t.messages.profile_description()) }
+
+            dl style="display: grid; grid-template-columns: max-content 1fr; gap: 0.5rem 1.5rem; margin-bottom: 2rem;" {
+                dt style="color: var(--gray-600);" { (t.messages.profile_name()) }
+                dd style="font-weight: 500;" { (session.user_name) }
+                dt style="color: var(--gray-600);" { (t.messages.profile_email()) }
+                dd style="font-weight: 500;" { (session.user_email) }
+            }
+
+            div style="margin-bottom: 2rem;" {
+                a href="/profile/password" class="btn btn-secondary" { (t.messages.password_change()) }
+            }
+
+            @if !providers.is_empty() || !identities.is_empty() {
+                (identities_section(t, session, providers, identities))
+            }
+
+            (api_keys_section(t, session, api_keys, None, None))
+
+            h2 style="font-size: 1.25rem; font-weight: 600; margin-bottom: 0.25rem;" {
+                (t.messages.profile_recent_logins())
+            }
+            p style="color: var(--gray-600); margin-bottom: 1rem;" {
+                (t.messages.profile_recent_logins_hint())
+            }
+
+            @if logins.is_empty() {
+                (empty_state_i18n(
+                    &t.messages.profile_no_logins_title().to_string(),
+                    &t.messages.profile_no_logins_message().to_string(),
+                    false
+                ))
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.profile_login_time()) }
+                            th { (t.messages.profile_login_result()) }
+                            th { (t.messages.profile_login_ip()) }
+                            th { (t.messages.profile_login_user_agent()) }
+                        }
+                    }
+                    tbody {
+                        @for login in logins {
+                            tr {
+                                td style="white-space: nowrap;" { (login.created_at) }
+                                td { (login_result_badge(t, login)) }
+                                td style="white-space: nowrap;" {
+                                    (login.ip_address.as_deref().unwrap_or("-"))
+                                    @if login.new_ip {
+                                        " "
+                                        span style="display: inline-block; padding: 0.125rem 0.5rem; border-radius: 9999px; font-size: 0.75rem; font-weight: 500; color: #a16207; background: #fef9c3;" {
+                                            (t.messages.profile_login_new_ip())
+                                        }
+                                    }
+                                }
+                                td style="font-size: 0.875rem; color: var(--gray-600); max-width: 24rem; overflow: hidden; text-overflow: ellipsis; white-space: nowrap;"
+                                    title=(login.user_agent.as_deref().unwrap_or(""))
+                                {
+                                    (login.user_agent.as_deref().unwrap_or("-"))
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Google / GitHub accounts the user can sign in with
+///
+/// Linked accounts of providers no longer configured are still listed so
+/// they can be removed.
+fn identities_section(
+    t: &TranslationContext,
+    session: &Session,
+    providers: &[OAuthProvider],
+    identities: &[UserIdentityEntity],
+) -> Markup {
+    let mut shown: Vec<OAuthProvider> = providers.to_vec();
+    for identity in identities {
+        if let Some(provider) = OAuthProvider::from_str(&identity.provider) {
+            if !shown.contains(&provider) {
+                shown.push(provider);
+            }
+        }
+    }
+
+    html! {
+        div style="margin-bottom: 2rem;" {
+            h2 style="font-size: 1.25rem; font-weight: 600; margin-bottom: 0.25rem;" {
+                (t.messages.identities_title())
+            }
+            p style="color: var(--gray-600); margin-bottom: 1rem;" {
+                (t.messages.identities_hint())
+            }
+
+            table class="table" {
+                tbody {
+                    @for provider in &shown {
+                        @let identity = identities.iter().find(|i| i.provider == provider.as_str());
+                        tr {
+                            td style="font-weight: 500;" { (provider.label()) }
+                            td {
+                                @match identity {
+                                    Some(identity) => (identity.email.as_deref().unwrap_or("-")),
+                                    None => span style="color: var(--gray-400);" { (t.messages.identities_not_linked()) },
+                                }
+                            }
+                            td style="text-align: right; white-space: nowrap;" {
+                                @if identity.is_some() {
+                                    form method="POST" action=(format!("/profile/identities/{}/unlink", provider.as_str())) style="display: inline;" {
+                                        (csrf_token_field(&session.csrf_token))
+                                        button type="submit" class="btn btn-sm btn-secondary" {
+                                            (t.messages.identities_unlink())
+                                        }
+                                    }
+                                } @else if providers.contains(provider) {
+                                    form method="POST" action=(format!("/profile/identities/{}", provider.as_str())) style="display: inline;" {
+                                        (csrf_token_field(&session.csrf_token))
+                                        button type="submit" class="btn btn-sm btn-primary" {
+                                            (t.messages.identities_link())
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn login_result_badge(t: &TranslationContext, login: &LoginAuditEntry) -> Markup {
+    let (label, colors) = if login.success {
+        (
+            t.messages.profile_login_success().to_string(),
+            "color: #15803d; background: #dcfce7;",
+        )
+    } else if login.failure_reason.as_deref() == Some(LoginFailure::InvalidPassword.as_str()) {
+        (
+            t.messages.profile_login_wrong_password().to_string(),
+            "color: #b91c1c; background: #fee2e2;",
+        )
+    } else if login.failure_reason.as_deref() == Some(LoginFailure::LockedOut.as_str()) {
+        (
+            t.messages.profile_login_locked().to_string(),
+            "color: #b91c1c; background: #fee2e2;",
+        )
+    } else {
+        (
+            t.messages.profile_login_failed().to_string(),
+            "color: #b91c1c; background: #fee2e2;",
+        )
+    };
+
+    html! {
+        span style=(format!(
+            "display: inline-block; padding: 0.25rem 0.75rem; border-radius: 9999px; font-size: 0.875rem; font-weight: 500; {}",
+            colors
+        )) {
+            (label)
+        }
+    }
+}
+
+fn scope_label(t: &TranslationContext, scope: ApiScope) -> String {
+    match scope {
+        ApiScope::MatchesRead => t.messages.api_scope_matches_read().to_string(),
+        ApiScope::StatsRead => t.messages.api_scope_stats_read().to_string(),
+        ApiScope::TeamsRead => t.messages.api_scope_teams_read().to_string(),
+        ApiScope::PlayersRead => t.messages.api_scope_players_read().to_string(),
+    }
+}
+
+/// API keys of the current user: create and revoke keys
+///
+/// Rendered as a partial so creating or revoking swaps in the updated list;
+/// `new_key` is the key just created, the only time it is shown.
+pub fn api_keys_section(
+    t: &TranslationContext,
+    session: &Session,
+    api_keys: &[ApiKeyEntry],
+    new_key: Option<&str>,
+    error: Option<&str>,
+) -> Markup {
+    let input_style = "padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
+
+    html! {
+        div id="api-keys" style="margin-bottom: 2rem;" {
+            h2 style="font-size: 1.25rem; font-weight: 600; margin-bottom: 0.25rem;" {
+                (t.messages.profile_api_keys())
+            }
+            p style="color: var(--gray-600); margin-bottom: 1rem;" {
+                (t.messages.profile_api_keys_hint())
+            }
+
+            @if let Some(error) = error {
+                div class="error" style="padding: 0.75rem 1rem; margin-bottom: 1rem;" { (error) }
+            }
+
+            @if let Some(key) = new_key {
+                div style="padding: 0.75rem 1rem; margin-bottom: 1rem; background: #dcfce7; border-radius: 4px;" {
+                    p style="margin-bottom: 0.5rem; color: #15803d; font-weight: 500;" {
+                        (t.messages.profile_api_key_new())
+                    }
+                    input
+                        type="text"
+                        readonly
+                        value=(key)
+                        onclick="this.select()"
+                        style=(format!("width: 100%; font-family: monospace; font-size: 0.8125rem; {}", input_style));
+                }
+            }
+
+            form
+                hx-post="/profile/api-keys"
+                hx-target="#api-keys"
+                hx-swap="outerHTML"
+                style="margin-bottom: 1rem;"
+            {
+                (csrf_token_field(&session.csrf_token))
+                div style="display: flex; gap: 0.5rem; margin-bottom: 0.75rem;" {
+                    input
+                        type="text"
+                        name="name"
+                        required
+                        maxlength="100"
+                        placeholder=(t.messages.profile_api_key_name_placeholder())
+                        style=(format!("flex: 1; {}", input_style));
+                    button type="submit" class="btn btn-primary" {
+                        (t.messages.profile_api_key_create())
+                    }
+                }
+                fieldset style="border: 1px solid var(--gray-200); border-radius: 4px; padding: 0.75rem 1rem;" {
+                    legend style="font-size: 0.875rem; font-weight: 500; padding: 0 0.25rem;" {
+                        (t.messages.profile_api_key_scopes())
+                    }
+                    p style="font-size: 0.875rem; color: var(--gray-600); margin-bottom: 0.5rem;" {
+                        (t.messages.profile_api_key_scopes_hint())
+                    }
+                    div style="display: flex; gap: 1rem; flex-wrap: wrap;" {
+                        @for scope in ApiScope::ALL {
+                            label style="display: flex; align-items: center; gap: 0.375rem; font-size: 0.875rem;" {
+                                input type="checkbox" name=(scope.as_str()) value="true";
+                                (scope_label(t, scope))
+                            }
+                        }
+                    }
+                }
+            }
+
+            @if api_keys.is_empty() {
+                div style="padding: 1rem; background: var(--gray-100); border-radius: 4px; color: var(--gray-600); text-align: center;" {
+                    (t.messages.profile_api_keys_empty())
+                }
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.profile_api_key_name()) }
+                            th { (t.messages.profile_api_key_prefix()) }
+                            th { (t.messages.profile_api_key_access()) }
+                            th { (t.messages.profile_api_key_created_at()) }
+                            th { (t.messages.profile_api_key_last_used()) }
+                            th style="text-align: right;" { "Actions" }
+                        }
+                    }
+                    tbody {
+                        @for api_key in api_keys {
+                            tr {
+                                td style="font-weight: 500;" { (api_key.name) }
+                                td style="font-family: monospace;" { (api_key.key_prefix) "…" }
+                                td style="font-size: 0.875rem;" {
+                                    @match api_key.scopes() {
+                                        Some(scopes) => {
+                                            @for (i, scope) in scopes.iter().enumerate() {
+                                                @if i > 0 { ", " }
+                                                span title=(scope.as_str()) { (scope_label(t, *scope)) }
+                                            }
+                                        }
+                                        None => span style="color: var(--gray-600);" { (t.messages.profile_api_key_full_access()) },
+                                    }
+                                }
+                                td style="white-space: nowrap;" { (api_key.created_at) }
+                                td style="white-space: nowrap;" {
+                                    @if let Some(last_used) = &api_key.last_used_at {
+                                        (last_used)
+                                    } @else {
+                                        span style="color: var(--gray-400);" { (t.messages.profile_api_key_never_used()) }
+                                    }
+                                }
+                                td style="text-align: right; white-space: nowrap;" {
+                                    form style="display: inline;" {
+                                        (csrf_token_field(&session.csrf_token))
+                                        button
+                                            type="submit"
+                                            class="btn btn-sm btn-danger"
+                                            hx-post=(format!("/profile/api-keys/{}/revoke", api_key.id))
+                                            hx-target="#api-keys"
+                                            hx-swap="outerHTML"
+                                            hx-confirm-custom=(confirm_attrs(
+                                                &t.messages.profile_api_key_revoke().to_string(),
+                                                &t.messages.profile_api_key_confirm_revoke().to_string(),
+                                                ConfirmVariant::Danger,
+                                                Some(&t.messages.profile_api_key_revoke().to_string()),
+                                                Some(&t.messages.common_cancel().to_string())
+                                            ))
+                                        {
+                                            (t.messages.profile_api_key_revoke())
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,376 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::leaders::LeaderEntity;
+use crate::service::public_site::{
+    PublicRosterPlayerEntity, PublicSeasonEntity, PublicSeasonPaths,
+};
+use crate::service::season_export::ScheduleMatchEntity;
+use crate::service::season_groups::SeasonGroupEntity;
+use crate::service::seasons::SeasonEntity;
+use crate::service::standings::{PointSystem, TeamStandingEntity};
+use crate::service::team_participations::TeamParticipationEntity;
+use crate::views::components::crud::empty_state_i18n;
+use crate::views::pages::matches::status_filter_label;
+
+/// Display name, or event name and year when the season has none
+pub fn season_name(season: &SeasonEntity) -> String {
+    season
+        .display_name
+        .clone()
+        .unwrap_or_else(|| format!("{} {}", season.event_name, season.year))
+}
+
+fn page_header(title: &str, subtitle: Option<&str>, back: Option<(String, &str)>) -> Markup {
+    html! {
+        div style="margin-bottom: 1.5rem;" {
+            @if let Some((href, label)) = back {
+                a href=(href) class="primary-link" style="font-size: 0.875rem;" { "← " (label) }
+            }
+            h1 style="font-size: 2rem; font-weight: 700; margin: 0.5rem 0;" { (title) }
+            @if let Some(subtitle) = subtitle {
+                p style="color: var(--gray-600);" { (subtitle) }
+            }
+        }
+    }
+}
+
+fn flag(iso2: Option<&str>, name: &str) -> Markup {
+    html! {
+        @if let Some(iso2) = iso2 {
+            flag-icon country-code=(iso2.to_lowercase()) country-name=(name) size="sm";
+            " "
+        }
+    }
+}
+
+/// Public home page: every season with links to its schedule and standings
+pub fn public_home_page(t: &TranslationContext, seasons: &[PublicSeasonEntity]) -> Markup {
+    html! {
+        div class="card" {
+            (page_header(
+                &t.messages.public_seasons_title().to_string(),
+                Some(&t.messages.public_seasons_description().to_string()),
+                None,
+            ))
+            @if seasons.is_empty() {
+                (empty_state_i18n(
+                    &t.messages.public_no_seasons_title().to_string(),
+                    &t.messages.public_no_seasons_message().to_string(),
+                    false,
+                ))
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.public_season()) }
+                            th { (t.messages.public_event()) }
+                            th style="text-align: right;" { (t.messages.public_teams()) }
+                            th style="text-align: right;" { (t.messages.public_played()) }
+                        }
+                    }
+                    tbody {
+                        @for season in seasons {
+                            tr {
+                                td {
+                                    a href=(season.path()) class="primary-link" {
+                                        (season.name())
+                                    }
+                                }
+                                td { (season.event_name) }
+                                td style="text-align: right;" { (season.teams) }
+                                td style="text-align: right;" {
+                                    (format!("{}/{}", season.finished_matches, season.matches))
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Public season page: standings per group and the full schedule
+pub fn public_season_page(
+    t: &TranslationContext,
+    season: &SeasonEntity,
+    paths: &PublicSeasonPaths,
+    groups: &[SeasonGroupEntity],
+    standings: &[TeamStandingEntity],
+    schedule: &[ScheduleMatchEntity],
+) -> Markup {
+    html! {
+        div class="card" {
+            (page_header(
+                &season_name(season),
+                Some(&season.event_name),
+                Some(("/public".to_string(), &t.messages.public_back_to_seasons().to_string())),
+            ))
+            a href=(paths.players()) class="btn btn-secondary" {
+                (t.messages.public_player_stats())
+            }
+            (public_standings(t, paths, groups, standings))
+            (public_schedule(t, schedule))
+        }
+    }
+}
+
+fn public_standings(
+    t: &TranslationContext,
+    paths: &PublicSeasonPaths,
+    groups: &[SeasonGroupEntity],
+    standings: &[TeamStandingEntity],
+) -> Markup {
+    let mut tables: Vec<(Option<&str>, Vec<&TeamStandingEntity>)> = groups
+        .iter()
+        .map(|g| {
+            (
+                Some(g.name.as_str()),
+                standings
+                    .iter()
+                    .filter(|s| s.group_id == Some(g.id))
+                    .collect(),
+            )
+        })
+        .collect();
+    let unassigned: Vec<&TeamStandingEntity> = standings
+        .iter()
+        .filter(|s| !groups.iter().any(|g| s.group_id == Some(g.id)))
+        .collect();
+    if !unassigned.is_empty() {
+        tables.push((None, unassigned));
+    }
+    let overtime_columns = standings
+        .first()
+        .is_some_and(|s| s.point_system() == PointSystem::Iihf);
+
+    html! {
+        h2 style="font-size: 1.5rem; font-weight: 700; margin: 2rem 0 1rem;" {
+            (t.messages.standings_title())
+        }
+        @if standings.is_empty() {
+            p style="color: var(--gray-500);" { (t.messages.seasons_no_teams()) }
+        }
+        @for (name, rows) in &tables {
+            @if let Some(name) = name {
+                h3 style="font-size: 1.125rem; font-weight: 600; margin: 1rem 0 0.5rem;" { (name) }
+            }
+            table class="table" {
+                thead {
+                    tr {
+                        th style="width: 3rem;" { "#" }
+                        th { (t.messages.standings_team()) }
+                        th style="text-align: right;" { (t.messages.standings_games()) }
+                        th style="text-align: right;" { (t.messages.standings_wins()) }
+                        @if overtime_columns {
+                            th style="text-align: right;" { (t.messages.standings_overtime_wins()) }
+                        }
+                        th style="text-align: right;" { (t.messages.standings_ties()) }
+                        @if overtime_columns {
+                            th style="text-align: right;" { (t.messages.standings_overtime_losses()) }
+                        }
+                        th style="text-align: right;" { (t.messages.standings_losses()) }
+                        th style="text-align: right;" { (t.messages.standings_goals()) }
+                        th style="text-align: right;" { (t.messages.standings_points()) }
+                    }
+                }
+                tbody {
+                    @for (index, row) in rows.iter().enumerate() {
+                        tr {
+                            td { (index + 1) }
+                            td {
+                                (flag(row.country_iso2_code.as_deref(), &row.team_name))
+                                a href=(paths.roster(row.team_id)) class="primary-link" {
+                                    (row.team_name)
+                                }
+                            }
+                            td style="text-align: right;" { (row.games) }
+                            td style="text-align: right;" { (row.wins) }
+                            @if overtime_columns {
+                                td style="text-align: right;" { (row.overtime_wins) }
+                            }
+                            td style="text-align: right;" { (row.ties) }
+                            @if overtime_columns {
+                                td style="text-align: right;" { (row.overtime_losses) }
+                            }
+                            td style="text-align: right;" { (row.losses) }
+                            td style="text-align: right;" { (format!("{}:{}", row.goals_for, row.goals_against)) }
+                            td style="text-align: right; font-weight: 700;" { (row.points()) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Score with an OT or SO suffix; a dash until the match starts
+pub fn schedule_score(game: &ScheduleMatchEntity) -> String {
+    if !matches!(game.status.as_str(), "in_progress" | "finished") {
+        return "–".to_string();
+    }
+    let suffix = match game.result.as_deref() {
+        Some(result) if result.ends_with("_overtime") => " OT",
+        Some(result) if result.ends_with("_shootout") => " SO",
+        _ => "",
+    };
+    format!("{}:{}{}", game.home_score, game.away_score, suffix)
+}
+
+fn public_schedule(t: &TranslationContext, schedule: &[ScheduleMatchEntity]) -> Markup {
+    html! {
+        h2 style="font-size: 1.5rem; font-weight: 700; margin: 2rem 0 1rem;" {
+            (t.messages.public_schedule())
+        }
+        @if schedule.is_empty() {
+            p style="color: var(--gray-500);" { (t.messages.public_no_matches()) }
+        } @else {
+            table class="table" {
+                thead {
+                    tr {
+                        th { (t.messages.matches_date()) }
+                        th { (t.messages.matches_home_team()) }
+                        th style="text-align: center;" { (t.messages.matches_score()) }
+                        th { (t.messages.matches_away_team()) }
+                        th { (t.messages.matches_status()) }
+                    }
+                }
+                tbody {
+                    @for game in schedule {
+                        tr {
+                            td {
+                                @if let Some(date) = &game.match_date {
+                                    (date.replace('T', " "))
+                                } @else {
+                                    (t.messages.matches_date_unknown())
+                                }
+                            }
+                            td { (game.home_team_name) }
+                            td style="text-align: center; font-weight: 600;" { (schedule_score(game)) }
+                            td { (game.away_team_name) }
+                            td { (status_filter_label(t, &game.status)) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Public roster of one team in a season with the points scored for it
+pub fn public_roster_page(
+    t: &TranslationContext,
+    season: &SeasonEntity,
+    paths: &PublicSeasonPaths,
+    team: &TeamParticipationEntity,
+    roster: &[PublicRosterPlayerEntity],
+) -> Markup {
+    let season_name = season_name(season);
+    html! {
+        div class="card" {
+            (page_header(
+                &team.team_name,
+                Some(&season_name),
+                Some((paths.season(), &t.messages.public_back_to_season().to_string())),
+            ))
+            @if roster.is_empty() {
+                p style="color: var(--gray-500);" { (t.messages.public_no_roster()) }
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th style="width: 3rem;" { (t.messages.roster_jersey_number_short()) }
+                            th { (t.messages.public_player()) }
+                            th { (t.messages.players_position()) }
+                            th style="text-align: right;" { (t.messages.leaders_goals_short()) }
+                            th style="text-align: right;" { (t.messages.leaders_assists_short()) }
+                            th style="text-align: right;" { (t.messages.leaders_points_short()) }
+                        }
+                    }
+                    tbody {
+                        @for player in roster {
+                            tr {
+                                td { @if let Some(number) = player.jersey_number { (number) } }
+                                td {
+                                    (flag(player.country_iso2_code.as_deref(), &player.player_name))
+                                    (player.player_name)
+                                    @if let Some(captaincy) = &player.captaincy {
+                                        " (" (captaincy) ")"
+                                    }
+                                }
+                                td { (player.position.as_deref().unwrap_or("")) }
+                                td style="text-align: right;" { (player.goals) }
+                                td style="text-align: right;" { (player.assists) }
+                                td style="text-align: right; font-weight: 700;" { (player.points()) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Public scoring table of a season, best first
+pub fn public_players_page(
+    t: &TranslationContext,
+    season: &SeasonEntity,
+    paths: &PublicSeasonPaths,
+    players: &[LeaderEntity],
+) -> Markup {
+    let season_name = season_name(season);
+    html! {
+        div class="card" {
+            (page_header(
+                &t.messages.public_player_stats().to_string(),
+                Some(&season_name),
+                Some((paths.season(), &t.messages.public_back_to_season().to_string())),
+            ))
+            @if players.is_empty() {
+                p style="color: var(--gray-500);" { (t.messages.public_no_points()) }
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th style="width: 3rem;" { "#" }
+                            th { (t.messages.public_player()) }
+                            th { (t.messages.public_teams()) }
+                            th style="text-align: right;" { (t.messages.leaders_goals_short()) }
+                            th style="text-align: right;" { (t.messages.leaders_assists_short()) }
+                            th style="text-align: right;" { (t.messages.leaders_points_short()) }
+                        }
+                    }
+                    tbody {
+                        @for (index, player) in players.iter().enumerate() {
+                            tr {
+                                td { (index + 1) }
+                                td { (player.player_name) }
+                                td { (player.team_names) }
+                                td style="text-align: right;" { (player.goals) }
+                                td style="text-align: right;" { (player.assists) }
+                                td style="text-align: right; font-weight: 700;" { (player.points) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Shown for unknown seasons and teams
+pub fn public_not_found(t: &TranslationContext) -> Markup {
+    html! {
+        div class="card" {
+            (empty_state_i18n(
+                &t.messages.public_not_found_title().to_string(),
+                &t.messages.public_not_found_message().to_string(),
+                false,
+            ))
+            p style="text-align: center;" {
+                a href="/public" class="primary-link" { (t.messages.public_back_to_seasons()) }
+            }
+        }
+    }
+}
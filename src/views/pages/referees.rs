@@ -0,0 +1,383 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::common::pagination::PagedResult;
+use crate::i18n::TranslationContext;
+use crate::service::referees::{RefereeEntity, RefereeFilters, SortField, SortOrder};
+use crate::views::components::crud::{
+    empty_state, modal_form, page_header, pagination, table_actions,
+};
+use crate::views::components::forms::csrf_token_field;
+
+/// Main referees page with table and filters
+pub fn referees_page(
+    session: &Session,
+    t: &TranslationContext,
+    result: &PagedResult<RefereeEntity>,
+    filters: &RefereeFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+    countries: &[(i64, String)],
+) -> Markup {
+    html! {
+        div class="card" {
+            (page_header(
+                &t.messages.referees_title().to_string(),
+                &t.messages.referees_description().to_string(),
+                "/referees/new",
+                &t.messages.referees_create().to_string(),
+            ))
+
+            // Filters
+            div class="filters-container" {
+                form hx-get="/referees/list" hx-target="#referees-table" hx-swap="outerHTML" hx-trigger="submit, change delay:300ms" {
+                    div class="filters-grid" {
+                        // Name filter
+                        div {
+                            label class="filter-label" {
+                                (t.messages.common_search_by_name())
+                            }
+                            input
+                                type="text"
+                                name="name"
+                                value=[filters.name.as_ref()]
+                                placeholder=(t.messages.referees_name_placeholder())
+                                class="filter-input";
+                        }
+
+                        // Country filter
+                        div {
+                            label class="filter-label" {
+                                (t.messages.common_filter_by_country())
+                            }
+                            select
+                                name="country_id"
+                                class="filter-select"
+                            {
+                                option value="" { (t.messages.common_all_countries()) }
+                                @for (id, name) in countries {
+                                    option
+                                        value=(id)
+                                        selected[filters.country_id == Some(*id)]
+                                    {
+                                        (name)
+                                    }
+                                }
+                            }
+                        }
+
+                        // Clear button
+                        div {
+                            button
+                                type="button"
+                                class="btn btn-secondary"
+                                hx-get="/referees/list"
+                                hx-target="#referees-table"
+                                hx-swap="outerHTML"
+                            {
+                                (t.messages.common_clear())
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Table
+            (referee_list_content(session, t, result, filters, sort_field, sort_order))
+
+            // Modal container
+            div id="modal-container" {}
+        }
+    }
+}
+
+/// Referees table content (for HTMX updates)
+pub fn referee_list_content(
+    session: &Session,
+    t: &TranslationContext,
+    result: &PagedResult<RefereeEntity>,
+    filters: &RefereeFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+) -> Markup {
+    html! {
+        div id="referees-table" class="loading-overlay" {
+            // Loading spinner overlay
+            div class="loading-spinner-overlay" {
+                hockey-loading-spinner size="lg" {}
+            }
+
+            @if result.items.is_empty() {
+                (empty_state(
+                    &t.messages.referees_entity().to_string(),
+                    filters.name.is_some() || filters.country_id.is_some(),
+                    Some("/referees/new"),
+                    Some(&t.messages.referees_create().to_string()),
+                ))
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th {
+                                (sortable_header(
+                                    &t.messages.common_id().to_string(),
+                                    &SortField::Id,
+                                    sort_field,
+                                    sort_order,
+                                    filters,
+                                ))
+                            }
+                            th {
+                                (sortable_header(
+                                    &t.messages.form_name().to_string(),
+                                    &SortField::Name,
+                                    sort_field,
+                                    sort_order,
+                                    filters,
+                                ))
+                            }
+                            th {
+                                (sortable_header(
+                                    &t.messages.form_country().to_string(),
+                                    &SortField::Country,
+                                    sort_field,
+                                    sort_order,
+                                    filters,
+                                ))
+                            }
+                            th {
+                                (sortable_header(
+                                    &t.messages.referees_matches().to_string(),
+                                    &SortField::Matches,
+                                    sort_field,
+                                    sort_order,
+                                    filters,
+                                ))
+                            }
+                            th class="text-right" { (t.messages.common_actions()) }
+                        }
+                    }
+                    tbody {
+                        @for referee in &result.items {
+                            tr {
+                                td { (referee.id) }
+                                td { (referee.name) }
+                                td {
+                                    @if let Some(country_name) = &referee.country_name {
+                                        @if let Some(iso2) = &referee.country_iso2_code {
+                                            span class="flag-display" {
+                                                img
+                                                    src=(format!("https://flagcdn.com/w40/{}.png", iso2.to_lowercase()))
+                                                    alt=(country_name)
+                                                    class="flag-image"
+                                                    onerror="this.style.display='none'";
+                                                (country_name)
+                                            }
+                                        } @else {
+                                            (country_name)
+                                        }
+                                    } @else {
+                                        span class="no-value-text" { (t.messages.common_no_country()) }
+                                    }
+                                }
+                                td { (referee.match_count) }
+                                (table_actions(
+                                    &format!("/referees/{}/edit", referee.id),
+                                    &build_delete_url(referee.id, filters, sort_field, sort_order),
+                                    "referees-table",
+                                    &t.messages.referees_entity().to_string(),
+                                    &session.csrf_token
+                                ))
+                            }
+                        }
+                    }
+                }
+
+                // Pagination
+                (pagination(
+                    result,
+                    "referees",
+                    |page| build_pagination_url(page, result.page_size, filters, sort_field, sort_order),
+                    "referees-table"
+                ))
+            }
+        }
+    }
+}
+
+/// Sortable table header
+fn sortable_header(
+    label: &str,
+    field: &SortField,
+    current_sort: &SortField,
+    current_order: &SortOrder,
+    filters: &RefereeFilters,
+) -> Markup {
+    let is_active = field == current_sort;
+
+    // If this column is active, toggle the order; otherwise default to ASC
+    let next_order = if is_active {
+        current_order.toggle()
+    } else {
+        SortOrder::Asc
+    };
+
+    let url = build_sort_url(field, &next_order, filters);
+
+    let indicator = if is_active {
+        match current_order {
+            SortOrder::Asc => "↑",
+            SortOrder::Desc => "↓",
+        }
+    } else {
+        "↕"
+    };
+
+    html! {
+        button
+            class="sort-button"
+            hx-get=(url)
+            hx-target="#referees-table"
+            hx-swap="outerHTML"
+        {
+            (label)
+            span class=(if is_active { "sort-indicator active" } else { "sort-indicator" }) {
+                (indicator)
+            }
+        }
+    }
+}
+
+/// Append the active filters to a list URL
+fn push_filters(url: &mut String, filters: &RefereeFilters) {
+    if let Some(name) = &filters.name {
+        url.push_str(&format!("&name={}", urlencoding::encode(name)));
+    }
+
+    if let Some(country_id) = filters.country_id {
+        url.push_str(&format!("&country_id={}", country_id));
+    }
+}
+
+/// Helper to build sort URLs
+fn build_sort_url(field: &SortField, order: &SortOrder, filters: &RefereeFilters) -> String {
+    let mut url = format!(
+        "/referees/list?sort={}&order={}",
+        field.as_str(),
+        order.as_str()
+    );
+    push_filters(&mut url, filters);
+    url
+}
+
+/// Helper to build pagination URLs with filters and sorting
+fn build_pagination_url(
+    page: usize,
+    page_size: usize,
+    filters: &RefereeFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+) -> String {
+    let mut url = format!(
+        "/referees/list?page={}&page_size={}&sort={}&order={}",
+        page,
+        page_size,
+        sort_field.as_str(),
+        sort_order.as_str()
+    );
+    push_filters(&mut url, filters);
+    url
+}
+
+/// Helper to build delete URL with current filters and sorting
+fn build_delete_url(
+    referee_id: i64,
+    filters: &RefereeFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+) -> String {
+    let mut url = format!(
+        "/referees/{}/delete?sort={}&order={}",
+        referee_id,
+        sort_field.as_str(),
+        sort_order.as_str()
+    );
+    push_filters(&mut url, filters);
+    url
+}
+
+/// Shared fields of the create and edit modals
+fn referee_form_fields(
+    session: &Session,
+    t: &TranslationContext,
+    referee: Option<&RefereeEntity>,
+) -> Markup {
+    html! {
+        (csrf_token_field(&session.csrf_token))
+
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.referees_name_label())
+                span class="required-indicator" { "*" }
+            }
+            input
+                type="text"
+                name="name"
+                value=[referee.map(|r| r.name.as_str())]
+                placeholder=(t.messages.referees_name_placeholder())
+                required
+                autofocus;
+        }
+
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.form_country())
+            }
+            @if let Some(country_id) = referee.and_then(|r| r.country_id) {
+                country-selector
+                    name="country_id"
+                    placeholder=(t.messages.teams_select_country())
+                    value=(country_id)
+                    enabled-only;
+            } @else {
+                country-selector
+                    name="country_id"
+                    placeholder=(t.messages.teams_select_country())
+                    enabled-only;
+            }
+        }
+    }
+}
+
+/// Create referee modal
+pub fn referee_create_modal(
+    session: &Session,
+    t: &TranslationContext,
+    error: Option<&str>,
+) -> Markup {
+    modal_form(
+        "referee-modal",
+        &t.messages.referees_create_title().to_string(),
+        error,
+        "/referees",
+        referee_form_fields(session, t, None),
+        &t.messages.referees_create_submit().to_string(),
+    )
+}
+
+/// Edit referee modal
+pub fn referee_edit_modal(
+    session: &Session,
+    t: &TranslationContext,
+    referee: &RefereeEntity,
+    error: Option<&str>,
+) -> Markup {
+    modal_form(
+        "referee-modal",
+        &t.messages.referees_edit_title().to_string(),
+        error,
+        &format!("/referees/{}", referee.id),
+        referee_form_fields(session, t, Some(referee)),
+        &t.messages.common_save().to_string(),
+    )
+}
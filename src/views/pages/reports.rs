@@ -0,0 +1,736 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::matches::GameType;
+use crate::service::reports::{
+    DuplicatePair, DuplicateRecord, DuplicateReport, ReconciliationStatus, ReportDefinition,
+    ReportResult, ReportSource, SavedReportEntity, StatsReconciliationRow,
+    UnidentifiedGoalsFilters, UnidentifiedGoalsReport, UnidentifiedTotal,
+};
+use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
+use crate::views::components::crud::empty_state_i18n;
+use crate::views::components::forms::csrf_token_field;
+use crate::views::pages::matches::game_type_label;
+
+/// Stats reconciliation report: manual event stats vs. score-event-derived totals
+pub fn stats_reconciliation_page(
+    t: &TranslationContext,
+    rows: &[StatsReconciliationRow],
+    show_all: bool,
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
+                div {
+                    h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                        (t.messages.reports_reconciliation_title())
+                    }
+                    p style="color: var(--gray-600);" {
+                        (t.messages.reports_reconciliation_description())
+                    }
+                }
+                div style="display: flex; gap: 0.5rem;" {
+                    a
+                        href="/reports/stats-reconciliation"
+                        class=(if show_all { "btn btn-secondary" } else { "btn btn-primary" })
+                    {
+                        (t.messages.reports_show_discrepancies())
+                    }
+                    a
+                        href="/reports/stats-reconciliation?show=all"
+                        class=(if show_all { "btn btn-primary" } else { "btn btn-secondary" })
+                    {
+                        (t.messages.reports_show_all())
+                    }
+                }
+            }
+
+            @if rows.is_empty() {
+                (empty_state_i18n(
+                    &t.messages.reports_reconciliation_empty_title().to_string(),
+                    &t.messages.reports_reconciliation_empty_message().to_string(),
+                    !show_all
+                ))
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.players_name()) }
+                            th { (t.messages.players_event_stats_competition()) }
+                            th style="text-align: center;" { (t.messages.reports_manual()) }
+                            th style="text-align: center;" { (t.messages.reports_derived()) }
+                            th { (t.messages.matches_status()) }
+                            th style="text-align: right;" { (t.messages.common_actions()) }
+                        }
+                    }
+                    tbody {
+                        @for row in rows {
+                            tr {
+                                td {
+                                    a href=(format!("/players/{}", row.player_id)) { (row.player_name) }
+                                }
+                                td {
+                                    a href=(format!("/events/{}", row.event_id)) { (row.event_name) }
+                                }
+                                td style="text-align: center;" {
+                                    @match (row.manual_goals, row.manual_assists) {
+                                        (Some(goals), Some(assists)) => {
+                                            (format!("{} G / {} A", goals, assists))
+                                        }
+                                        _ => {
+                                            span style="color: var(--gray-400);" { "-" }
+                                        }
+                                    }
+                                }
+                                td style="text-align: center;" {
+                                    div { (format!("{} G / {} A", row.derived_goals, row.derived_assists)) }
+                                    @for season in &row.seasons {
+                                        div style="font-size: 0.75rem; color: var(--gray-500);" {
+                                            a href=(format!("/seasons/{}", season.season_id)) { (season.season_name) }
+                                            (format!(": {} G / {} A", season.goals, season.assists))
+                                        }
+                                    }
+                                }
+                                td { (reconciliation_badge(t, row.status())) }
+                                td style="text-align: right; white-space: nowrap;" {
+                                    button
+                                        class="btn btn-sm"
+                                        hx-get=(match row.stats_id {
+                                            Some(stats_id) => format!("/players/{}/event-stats/{}/edit", row.player_id, stats_id),
+                                            None => format!("/players/{}/event-stats/new", row.player_id),
+                                        })
+                                        hx-target="#modal-container"
+                                        hx-swap="innerHTML"
+                                        style="margin-right: 0.5rem;"
+                                    {
+                                        (t.messages.reports_fix_manual())
+                                    }
+                                    a
+                                        href=(format!("/players/{}/scoring", row.player_id))
+                                        class="btn btn-sm"
+                                    {
+                                        (t.messages.reports_fix_derived())
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn reconciliation_badge(t: &TranslationContext, status: ReconciliationStatus) -> Markup {
+    let (label, colors) = match status {
+        ReconciliationStatus::Matching => (
+            t.messages.reports_status_matching().to_string(),
+            "color: #15803d; background: #dcfce7;",
+        ),
+        ReconciliationStatus::Unidentified => (
+            t.messages.reports_status_unidentified().to_string(),
+            "color: #1e40af; background: #dbeafe;",
+        ),
+        ReconciliationStatus::Conflict => (
+            t.messages.reports_status_conflict().to_string(),
+            "color: #b91c1c; background: #fee2e2;",
+        ),
+        ReconciliationStatus::MissingManual => (
+            t.messages.reports_status_missing_manual().to_string(),
+            "color: #a16207; background: #fef9c3;",
+        ),
+    };
+
+    html! {
+        span style=(format!(
+            "display: inline-block; padding: 0.25rem 0.75rem; border-radius: 9999px; font-size: 0.875rem; font-weight: 500; {}",
+            colors
+        )) {
+            (label)
+        }
+    }
+}
+
+/// Duplicate report: probable duplicate players, teams and arenas
+pub fn duplicates_page(t: &TranslationContext, report: &DuplicateReport) -> Markup {
+    html! {
+        div class="card" {
+            div style="margin-bottom: 1.5rem;" {
+                h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                    (t.messages.reports_duplicates_title())
+                }
+                p style="color: var(--gray-600);" {
+                    (t.messages.reports_duplicates_description())
+                }
+            }
+
+            @if report.total() == 0 {
+                (empty_state_i18n(
+                    &t.messages.reports_duplicates_empty_title().to_string(),
+                    &t.messages.reports_duplicates_empty_message().to_string(),
+                    false
+                ))
+            } @else {
+                (duplicate_section(
+                    t,
+                    &t.messages.reports_duplicates_players().to_string(),
+                    &t.messages.reports_duplicates_birth_year().to_string(),
+                    "/players",
+                    &report.players,
+                    false,
+                ))
+                (duplicate_section(
+                    t,
+                    &t.messages.reports_duplicates_teams().to_string(),
+                    &t.messages.reports_duplicates_short_name().to_string(),
+                    "/teams",
+                    &report.teams,
+                    true,
+                ))
+                (duplicate_section(
+                    t,
+                    &t.messages.reports_duplicates_arenas().to_string(),
+                    &t.messages.arenas_city().to_string(),
+                    "/arenas",
+                    &report.arenas,
+                    false,
+                ))
+            }
+        }
+    }
+}
+
+/// Pairs of one kind of record, each side linking to its detail page
+///
+/// With `mergeable`, each pair also links to the merge tool, proposing to merge
+/// the possible duplicate into the first record.
+fn duplicate_section(
+    t: &TranslationContext,
+    title: &str,
+    detail_label: &str,
+    base_url: &str,
+    pairs: &[DuplicatePair],
+    mergeable: bool,
+) -> Markup {
+    html! {
+        @if !pairs.is_empty() {
+            h2 style="font-size: 1.25rem; font-weight: 600; margin: 1.5rem 0 0.75rem 0;" {
+                (title) " (" (pairs.len()) ")"
+            }
+            table class="table" {
+                thead {
+                    tr {
+                        th { (t.messages.reports_duplicates_record()) }
+                        th { (t.messages.reports_duplicates_possible_duplicate()) }
+                        th style="text-align: right;" { (t.messages.reports_duplicates_similarity()) }
+                        @if mergeable {
+                            th {}
+                        }
+                    }
+                }
+                tbody {
+                    @for pair in pairs {
+                        tr {
+                            td { (duplicate_record(detail_label, base_url, &pair.first)) }
+                            td { (duplicate_record(detail_label, base_url, &pair.second)) }
+                            td style="text-align: right; white-space: nowrap;" {
+                                @if pair.exact {
+                                    span style="display: inline-block; padding: 0.25rem 0.75rem; border-radius: 9999px; font-size: 0.875rem; font-weight: 500; color: #b91c1c; background: #fee2e2;" {
+                                        (t.messages.reports_duplicates_exact())
+                                    }
+                                } @else {
+                                    (format!("{:.0}%", pair.similarity * 100.0))
+                                }
+                            }
+                            @if mergeable {
+                                td style="text-align: right;" {
+                                    a
+                                        href=(format!("{}/{}/merge?into={}", base_url, pair.second.id, pair.first.id))
+                                        class="btn btn-sm"
+                                    {
+                                        (t.messages.team_merge_action())
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn duplicate_record(detail_label: &str, base_url: &str, record: &DuplicateRecord) -> Markup {
+    html! {
+        a href=(format!("{}/{}", base_url, record.id)) { (record.name) }
+        span style="color: var(--gray-400); font-size: 0.75rem;" { " #" (record.id) }
+        @if record.detail.is_some() || record.country_name.is_some() {
+            div style="font-size: 0.75rem; color: var(--gray-500);" {
+                @if let Some(detail) = &record.detail {
+                    (detail_label) ": " (detail)
+                    @if record.country_name.is_some() { " · " }
+                }
+                @if let Some(country) = &record.country_name {
+                    (country)
+                }
+            }
+        }
+    }
+}
+
+/// Unidentified goals backlog: matches still missing score events, with totals
+pub fn unidentified_goals_page(
+    t: &TranslationContext,
+    report: &UnidentifiedGoalsReport,
+    filters: &UnidentifiedGoalsFilters,
+    seasons: &[(i64, String)],
+    teams: &[(i64, String)],
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="margin-bottom: 1.5rem;" {
+                h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                    (t.messages.reports_unidentified_title())
+                }
+                p style="color: var(--gray-600);" {
+                    (t.messages.reports_unidentified_description())
+                }
+            }
+
+            form method="get" action="/reports/unidentified-goals" style="margin-bottom: 1.5rem;" {
+                div style="display: grid; grid-template-columns: repeat(auto-fit, minmax(180px, 1fr)); gap: 1rem; align-items: end;" {
+                    div class="form-group" {
+                        label class="form-label" { (t.messages.report_builder_season()) }
+                        select name="season_id" {
+                            option value="" { (t.messages.report_builder_any()) }
+                            @for (id, name) in seasons {
+                                option value=(id) selected[filters.season_id == Some(*id)] { (name) }
+                            }
+                        }
+                    }
+                    div class="form-group" {
+                        label class="form-label" { (t.messages.report_builder_team()) }
+                        select name="team_id" {
+                            option value="" { (t.messages.report_builder_any()) }
+                            @for (id, name) in teams {
+                                option value=(id) selected[filters.team_id == Some(*id)] { (name) }
+                            }
+                        }
+                    }
+                    div class="form-group" {
+                        button type="submit" class="btn btn-primary" { (t.messages.common_filter()) }
+                    }
+                }
+            }
+
+            @if report.matches.is_empty() {
+                (empty_state_i18n(
+                    &t.messages.reports_unidentified_empty_title().to_string(),
+                    &t.messages.reports_unidentified_empty_message().to_string(),
+                    filters.season_id.is_some() || filters.team_id.is_some()
+                ))
+            } @else {
+                div style="display: flex; gap: 2rem; margin-bottom: 1.5rem;" {
+                    div {
+                        div style="font-size: 2rem; font-weight: 700;" { (report.total_goals()) }
+                        div style="color: var(--gray-600);" { (t.messages.reports_unidentified_goals()) }
+                    }
+                    div {
+                        div style="font-size: 2rem; font-weight: 700;" { (report.matches.len()) }
+                        div style="color: var(--gray-600);" { (t.messages.reports_unidentified_matches()) }
+                    }
+                }
+
+                div style="display: grid; grid-template-columns: repeat(auto-fit, minmax(20rem, 1fr)); gap: 1.5rem; margin-bottom: 1.5rem;" {
+                    (unidentified_totals_table(t, &t.messages.reports_unidentified_by_season().to_string(), "/seasons", &report.seasons))
+                    (unidentified_totals_table(t, &t.messages.reports_unidentified_by_team().to_string(), "/teams", &report.teams))
+                }
+
+                h2 style="font-size: 1.25rem; font-weight: 600; margin: 0 0 0.75rem 0;" {
+                    (t.messages.reports_unidentified_matches())
+                }
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.matches_date()) }
+                            th { (t.messages.report_builder_season()) }
+                            th { (t.messages.reports_unidentified_match()) }
+                            th style="text-align: center;" { (t.messages.reports_unidentified_goals()) }
+                            th style="text-align: center;" { (t.messages.reports_unidentified_identified()) }
+                            th style="text-align: right;" { (t.messages.common_actions()) }
+                        }
+                    }
+                    tbody {
+                        @for row in &report.matches {
+                            tr {
+                                td { (row.match_date.as_deref().unwrap_or("-")) }
+                                td {
+                                    a href=(format!("/seasons/{}", row.season_id)) { (row.season_name) }
+                                }
+                                td { (row.home_team_name) " – " (row.away_team_name) }
+                                td style="text-align: center; font-weight: 700;" {
+                                    (row.unidentified())
+                                    span style="font-weight: 400; color: var(--gray-500); font-size: 0.875rem;" {
+                                        (format!(" ({}:{})", row.home_unidentified, row.away_unidentified))
+                                    }
+                                }
+                                td style="text-align: center;" { (row.identified) }
+                                td style="text-align: right;" {
+                                    a href=(format!("/matches/{}", row.match_id)) class="btn btn-sm" {
+                                        (t.messages.reports_unidentified_identify())
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn unidentified_totals_table(
+    t: &TranslationContext,
+    title: &str,
+    base_url: &str,
+    totals: &[UnidentifiedTotal],
+) -> Markup {
+    html! {
+        div {
+            h2 style="font-size: 1.25rem; font-weight: 600; margin: 0 0 0.75rem 0;" { (title) }
+            table class="table" {
+                thead {
+                    tr {
+                        th {}
+                        th style="text-align: right;" { (t.messages.reports_unidentified_matches()) }
+                        th style="text-align: right;" { (t.messages.reports_unidentified_goals()) }
+                    }
+                }
+                tbody {
+                    @for total in totals {
+                        tr {
+                            td {
+                                a href=(format!("{}/{}", base_url, total.id)) { (total.name) }
+                            }
+                            td style="text-align: right;" { (total.matches) }
+                            td style="text-align: right; font-weight: 700;" { (total.goals) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Custom report builder: source, fields and filters, then the result table
+#[allow(clippy::too_many_arguments)]
+pub fn report_builder_page(
+    session: &Session,
+    t: &TranslationContext,
+    definition: &ReportDefinition,
+    result: Option<&ReportResult>,
+    error: Option<&str>,
+    saved_reports: &[SavedReportEntity],
+    saved: Option<&SavedReportEntity>,
+    seasons: &[(i64, String)],
+    teams: &[(i64, String)],
+) -> Markup {
+    let source = definition.source;
+    let filters = &definition.filters;
+
+    html! {
+        div class="card" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
+                div {
+                    h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                        @if let Some(saved) = saved {
+                            (saved.name)
+                        } @else {
+                            (t.messages.report_builder_title())
+                        }
+                    }
+                    p style="color: var(--gray-600);" {
+                        (t.messages.report_builder_description())
+                    }
+                }
+                @if let Some(saved) = saved {
+                    div style="display: flex; gap: 0.5rem;" {
+                        a href="/reports/builder" class="btn btn-secondary" {
+                            (t.messages.report_builder_new())
+                        }
+                        form style="display: inline;" {
+                            (csrf_token_field(&session.csrf_token))
+                            button
+                                type="submit"
+                                class="btn btn-danger"
+                                hx-post=(format!("/reports/saved/{}/delete", saved.id))
+                                hx-target="#report-save-result"
+                                hx-confirm-custom=(confirm_attrs(
+                                    &format!("{} \"{}\"", t.messages.common_delete(), saved.name),
+                                    &t.messages.report_builder_confirm_delete().to_string(),
+                                    ConfirmVariant::Danger,
+                                    Some(&t.messages.common_delete().to_string()),
+                                    Some(&t.messages.common_cancel().to_string())
+                                ))
+                            {
+                                (t.messages.common_delete())
+                            }
+                        }
+                    }
+                }
+            }
+
+            @if !saved_reports.is_empty() {
+                div style="display: flex; flex-wrap: wrap; gap: 0.5rem; align-items: center; margin-bottom: 1.5rem;" {
+                    span style="font-weight: 600; margin-right: 0.25rem;" { (t.messages.report_builder_saved()) }
+                    @for report in saved_reports {
+                        a
+                            href=(format!("/reports/saved/{}", report.id))
+                            class=(if saved.map(|s| s.id) == Some(report.id) { "btn btn-sm btn-primary" } else { "btn btn-sm btn-secondary" })
+                        {
+                            (report.name)
+                        }
+                    }
+                }
+            }
+
+            form method="get" action="/reports/builder" style="margin-bottom: 1.5rem; padding: 1rem; background: var(--gray-50); border-radius: 8px;" {
+                div class="form-group" style="max-width: 20rem;" {
+                    label class="form-label" for="report-source" { (t.messages.report_builder_source()) }
+                    // Fields differ per source, so switching starts a new report
+                    select
+                        id="report-source"
+                        name="source"
+                        onchange="window.location = '/reports/builder?source=' + this.value"
+                    {
+                        @for option in ReportSource::ALL {
+                            option value=(option.as_str()) selected[option == source] {
+                                (report_source_label(t, option))
+                            }
+                        }
+                    }
+                }
+
+                div style="display: grid; grid-template-columns: 1fr 1fr; gap: 1rem; margin: 1rem 0;" {
+                    div {
+                        div style="font-weight: 600; margin-bottom: 0.5rem;" { (t.messages.report_builder_dimensions()) }
+                        @for field in source.dimensions() {
+                            label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer; padding: 0.125rem 0;" {
+                                input
+                                    type="checkbox"
+                                    name="dimension"
+                                    value=(field.key)
+                                    checked[definition.dimensions.iter().any(|d| d == field.key)];
+                                span { (report_field_label(t, field.key)) }
+                            }
+                        }
+                    }
+                    div {
+                        div style="font-weight: 600; margin-bottom: 0.5rem;" { (t.messages.report_builder_measures()) }
+                        @for field in source.measures() {
+                            label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer; padding: 0.125rem 0;" {
+                                input
+                                    type="checkbox"
+                                    name="measure"
+                                    value=(field.key)
+                                    checked[definition.measures.iter().any(|m| m == field.key)];
+                                span { (report_field_label(t, field.key)) }
+                            }
+                        }
+                    }
+                }
+
+                div style="display: grid; grid-template-columns: repeat(auto-fit, minmax(180px, 1fr)); gap: 1rem; align-items: end;" {
+                    div class="form-group" {
+                        label class="form-label" { (t.messages.report_builder_season()) }
+                        select name="season_id" {
+                            option value="" { (t.messages.report_builder_any()) }
+                            @for (id, name) in seasons {
+                                option value=(id) selected[filters.season_id == Some(*id)] { (name) }
+                            }
+                        }
+                    }
+                    div class="form-group" {
+                        label class="form-label" { (t.messages.report_builder_team()) }
+                        select name="team_id" {
+                            option value="" { (t.messages.report_builder_any()) }
+                            @for (id, name) in teams {
+                                option value=(id) selected[filters.team_id == Some(*id)] { (name) }
+                            }
+                        }
+                    }
+                    @if source.has_match_filters() {
+                        div class="form-group" {
+                            label class="form-label" { (t.messages.report_builder_game_type()) }
+                            select name="game_type" {
+                                option value="" { (t.messages.report_builder_any()) }
+                                @for game_type in GameType::ALL {
+                                    option
+                                        value=(game_type.as_str())
+                                        selected[filters.game_type.as_deref() == Some(game_type.as_str())]
+                                    {
+                                        (game_type_label(t, game_type))
+                                    }
+                                }
+                            }
+                        }
+                        div class="form-group" {
+                            label class="form-label" { (t.messages.report_builder_date_from()) }
+                            input type="date" name="date_from" value=[filters.date_from.as_deref()];
+                        }
+                        div class="form-group" {
+                            label class="form-label" { (t.messages.report_builder_date_to()) }
+                            input type="date" name="date_to" value=[filters.date_to.as_deref()];
+                        }
+                    }
+                    div class="form-group" {
+                        label class="form-label" { (t.messages.report_builder_sort()) }
+                        select name="sort" {
+                            option value="" { (t.messages.report_builder_sort_default()) }
+                            @for field in source.dimensions().iter().chain(source.measures()) {
+                                option value=(field.key) selected[definition.sort.as_deref() == Some(field.key)] {
+                                    (report_field_label(t, field.key))
+                                }
+                            }
+                        }
+                    }
+                    div class="form-group" {
+                        button type="submit" class="btn btn-primary" { (t.messages.report_builder_run()) }
+                    }
+                }
+            }
+
+            @if let Some(error) = error {
+                div class="error" style="padding: 1rem; margin-bottom: 1rem;" { (error) }
+            }
+
+            @if let Some(result) = result {
+                (report_result(session, t, definition, result, saved.is_none()))
+            } @else if error.is_none() {
+                div style="padding: 1.5rem; text-align: center; color: var(--gray-500); background: var(--gray-50); border-radius: 8px;" {
+                    (t.messages.report_builder_pick_fields())
+                }
+            }
+            div id="report-save-result" {}
+        }
+    }
+}
+
+/// Result table with CSV export and, for unsaved reports, a save form
+fn report_result(
+    session: &Session,
+    t: &TranslationContext,
+    definition: &ReportDefinition,
+    result: &ReportResult,
+    can_save: bool,
+) -> Markup {
+    // Measures follow the dimensions and are right-aligned
+    let first_measure = definition.dimensions.len();
+
+    html! {
+        div style="display: flex; justify-content: space-between; align-items: flex-end; gap: 1rem; margin-bottom: 1rem; flex-wrap: wrap;" {
+            @if can_save {
+                form
+                    hx-post="/reports/saved"
+                    hx-target="#report-save-result"
+                    style="display: flex; gap: 0.5rem; align-items: flex-end;"
+                {
+                    (csrf_token_field(&session.csrf_token))
+                    @for (name, value) in definition.form_fields() {
+                        input type="hidden" name=(name) value=(value);
+                    }
+                    div class="form-group" style="margin: 0;" {
+                        label class="form-label" { (t.messages.report_builder_name()) }
+                        input type="text" name="name" required maxlength="100";
+                    }
+                    button type="submit" class="btn btn-secondary" { (t.messages.report_builder_save()) }
+                }
+            } @else {
+                div {}
+            }
+            a
+                href=(format!("/reports/builder/export?{}", definition.query_string()))
+                class="btn btn-secondary"
+                download
+            {
+                (t.messages.report_builder_export_csv())
+            }
+        }
+
+        @if result.rows.is_empty() {
+            div style="padding: 1.5rem; text-align: center; color: var(--gray-500); background: var(--gray-50); border-radius: 8px;" {
+                (t.messages.report_builder_no_rows())
+            }
+        } @else {
+            table class="table" {
+                thead {
+                    tr {
+                        @for (i, key) in result.columns.iter().enumerate() {
+                            th style=[(i >= first_measure).then_some("text-align: right;")] {
+                                (report_field_label(t, key))
+                            }
+                        }
+                    }
+                }
+                tbody {
+                    @for row in &result.rows {
+                        tr {
+                            @for (i, value) in row.iter().enumerate() {
+                                td style=[(i >= first_measure).then_some("text-align: right;")] {
+                                    @if let Some(value) = value {
+                                        (value)
+                                    } @else {
+                                        span style="color: var(--gray-400);" { "-" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            @if result.truncated {
+                p style="margin-top: 0.75rem; color: var(--gray-500); font-size: 0.875rem;" {
+                    (t.messages.report_builder_truncated())
+                }
+            }
+        }
+    }
+}
+
+fn report_source_label(t: &TranslationContext, source: ReportSource) -> String {
+    match source {
+        ReportSource::Goals => t.messages.report_builder_source_goals().to_string(),
+        ReportSource::Matches => t.messages.report_builder_source_matches().to_string(),
+        ReportSource::Rosters => t.messages.report_builder_source_rosters().to_string(),
+    }
+}
+
+/// Column header of a report field
+pub fn report_field_label(t: &TranslationContext, key: &str) -> String {
+    match key {
+        "season" => t.messages.report_field_season().to_string(),
+        "event" => t.messages.report_field_event().to_string(),
+        "team" => t.messages.report_field_team().to_string(),
+        "scorer" => t.messages.report_field_scorer().to_string(),
+        "period" => t.messages.report_field_period().to_string(),
+        "goal_type" => t.messages.report_field_goal_type().to_string(),
+        "game_type" => t.messages.report_field_game_type().to_string(),
+        "month" => t.messages.report_field_month().to_string(),
+        "home_team" => t.messages.report_field_home_team().to_string(),
+        "away_team" => t.messages.report_field_away_team().to_string(),
+        "arena" => t.messages.report_field_arena().to_string(),
+        "status" => t.messages.report_field_status().to_string(),
+        "position" => t.messages.report_field_position().to_string(),
+        "country" => t.messages.report_field_country().to_string(),
+        "goals" => t.messages.report_field_goals().to_string(),
+        "matches" => t.messages.report_field_matches().to_string(),
+        "scorers" => t.messages.report_field_scorers().to_string(),
+        "assisted_goals" => t.messages.report_field_assisted_goals().to_string(),
+        "total_attendance" => t.messages.report_field_total_attendance().to_string(),
+        "avg_attendance" => t.messages.report_field_avg_attendance().to_string(),
+        "players" => t.messages.report_field_players().to_string(),
+        "contracts" => t.messages.report_field_contracts().to_string(),
+        _ => key.to_string(),
+    }
+}
@@ -1,15 +1,28 @@
 use maud::{html, Markup};
 
+use crate::auth::Session;
 use crate::i18n::TranslationContext;
-use crate::service::player_contracts::{PlayerInRoster, TeamParticipationContext};
+use crate::service::lines::{LineAssignmentEntity, LineUnit};
+use crate::service::player_contracts::{
+    Captaincy, PlayerContractEntity, PlayerInRoster, TeamParticipationContext,
+};
+use crate::service::roster_import::{RosterImportPlan, RosterRowAction};
+use crate::service::roster_rules::{RosterRule, RosterRuleCheck};
+use crate::service::staff::{StaffContractEntity, StaffRole};
+use crate::service::transfers::TransferSourceEntity;
 use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
-use crate::views::components::crud::modal_form_i18n;
+use crate::views::components::crud::{modal_form_i18n, modal_form_multipart_i18n};
+use crate::views::components::forms::csrf_token_field;
+use crate::views::pages::staff::staff_role_label;
 
 /// Main roster management page
 pub fn roster_page(
-    _t: &TranslationContext,
+    t: &TranslationContext,
     context: &TeamParticipationContext,
     roster: &[PlayerInRoster],
+    assignments: &[LineAssignmentEntity],
+    staff: &[StaffContractEntity],
+    rule_checks: &[RosterRuleCheck],
 ) -> Markup {
     html! {
         div class="card" {
@@ -31,26 +44,80 @@ pub fn roster_page(
             // Context info card
             (context_card(context))
 
+            // Compliance with the event's roster rules
+            @if !rule_checks.is_empty() {
+                (roster_rules_panel(t, rule_checks))
+            }
+
             // Roster section
             div style="margin-top: 2rem;" {
                 div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
                     h2 style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
                         (format!("Players ({} total)", roster.len()))
                     }
+                    div style="display: flex; gap: 0.5rem;" {
+                        button
+                            class="btn btn-secondary"
+                            hx-get=(format!("/team-participations/{}/roster/import", context.team_participation_id))
+                            hx-target="#modal-container"
+                            hx-swap="innerHTML"
+                        {
+                            (t.messages.roster_import_open())
+                        }
+                        button
+                            class="btn btn-primary"
+                            hx-get=(format!("/team-participations/{}/roster/add-player", context.team_participation_id))
+                            hx-target="#modal-container"
+                            hx-swap="innerHTML"
+                        {
+                            "+ Add Player"
+                        }
+                    }
+                }
+
+                @if roster.is_empty() {
+                    (empty_roster_state())
+                } @else {
+                    (roster_table(t, roster))
+                }
+            }
+
+            // Lines section
+            @if !roster.is_empty() {
+                div style="margin-top: 2rem;" {
+                    h2 style="font-size: 1.5rem; font-weight: 700; margin: 0 0 0.25rem 0;" {
+                        (t.messages.roster_lines_title())
+                    }
+                    p style="color: var(--gray-600); margin-bottom: 1rem;" {
+                        (t.messages.roster_lines_hint())
+                    }
+                    (line_editor(t, context.team_participation_id, roster, assignments, None))
+                    (line_editor_script())
+                }
+            }
+
+            // Staff section
+            div style="margin-top: 2rem;" {
+                div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
+                    h2 style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
+                        (t.messages.roster_staff_title())
+                    }
                     button
                         class="btn btn-primary"
-                        hx-get=(format!("/team-participations/{}/roster/add-player", context.team_participation_id))
+                        hx-get=(format!("/team-participations/{}/staff/add", context.team_participation_id))
                         hx-target="#modal-container"
                         hx-swap="innerHTML"
                     {
-                        "+ Add Player"
+                        (t.messages.roster_staff_add())
                     }
                 }
 
-                @if roster.is_empty() {
-                    (empty_roster_state())
+                @if staff.is_empty() {
+                    p style="color: var(--gray-500); font-size: 0.875rem;" {
+                        (t.messages.roster_staff_empty())
+                    }
                 } @else {
-                    (roster_table(roster))
+                    (staff_table(t, staff))
                 }
             }
 
@@ -60,7 +127,241 @@ pub fn roster_page(
     }
 }
 
+/// Drag-and-drop lines chart: unassigned roster players plus forward lines,
+/// defense pairs and goalie depth chart
+///
+/// Rendered as a partial so every change swaps in the updated chart.
+pub fn line_editor(
+    t: &TranslationContext,
+    team_participation_id: i64,
+    roster: &[PlayerInRoster],
+    assignments: &[LineAssignmentEntity],
+    error: Option<&str>,
+) -> Markup {
+    // Players transferred out can no longer be placed on a line
+    let unassigned: Vec<&PlayerInRoster> = roster
+        .iter()
+        .filter(|p| p.left_on.is_none())
+        .filter(|p| {
+            !assignments
+                .iter()
+                .any(|a| a.player_contract_id == p.player_contract_id)
+        })
+        .collect();
+
+    html! {
+        div
+            id="line-editor"
+            class="line-editor"
+            data-assign-url=(format!("/team-participations/{}/lines/assign", team_participation_id))
+            data-unassign-url=(format!("/team-participations/{}/lines/unassign", team_participation_id))
+        {
+            @if let Some(error) = error {
+                div class="error" style="padding: 0.75rem 1rem; margin-bottom: 1rem;" { (error) }
+            }
+
+            div class="line-pool" data-drop="unassign" {
+                div class="line-unit-title" { (t.messages.roster_lines_unassigned()) }
+                div class="line-pool-players" {
+                    @for player in &unassigned {
+                        (line_chip(t, player.player_contract_id, player.jersey_number, &player.player_name, player.captaincy(), None))
+                    }
+                    @if unassigned.is_empty() {
+                        span style="color: var(--gray-400); font-size: 0.875rem;" {
+                            (t.messages.roster_lines_all_assigned())
+                        }
+                    }
+                }
+            }
+
+            div class="line-units" {
+                @for unit in LineUnit::ALL {
+                    div class="line-unit" {
+                        div class="line-unit-title" { (unit_title(t, unit)) }
+                        @for line_number in 1..=unit.line_count() {
+                            div class="line-row" {
+                                span class="line-row-label" { (line_label(t, unit, line_number)) }
+                                @for slot in 1..=unit.slot_count() {
+                                    div
+                                        class="line-slot"
+                                        data-drop="assign"
+                                        data-unit=(unit.as_str())
+                                        data-line=(line_number)
+                                        data-slot=(slot)
+                                    {
+                                        span class="line-slot-label" { (slot_label(unit, slot)) }
+                                        @if let Some(a) = assignments.iter().find(|a| a.is_at(unit, line_number, slot)) {
+                                            (line_chip(t, a.player_contract_id, a.jersey_number, &a.player_name, a.captaincy(), Some(team_participation_id)))
+                                        } @else {
+                                            span class="line-slot-empty" { (t.messages.roster_lines_empty_slot()) }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Draggable player chip; assigned chips get a remove button as a non-drag fallback
+fn line_chip(
+    t: &TranslationContext,
+    player_contract_id: i64,
+    jersey_number: Option<i64>,
+    player_name: &str,
+    captaincy: Option<Captaincy>,
+    removable: Option<i64>,
+) -> Markup {
+    html! {
+        div class="line-chip" draggable="true" data-contract-id=(player_contract_id) {
+            @if let Some(number) = jersey_number {
+                span class="line-chip-number" { (format!("#{}", number)) }
+            }
+            span { (player_name) }
+            @if let Some(captaincy) = captaincy {
+                (captaincy_letter(t, captaincy))
+            }
+            @if let Some(team_participation_id) = removable {
+                button
+                    type="button"
+                    class="line-chip-remove"
+                    title=(t.messages.roster_lines_remove())
+                    hx-post=(format!("/team-participations/{}/lines/unassign", team_participation_id))
+                    hx-vals=(format!(r#"{{"player_contract_id": {}}}"#, player_contract_id))
+                    hx-target="#line-editor"
+                    hx-swap="outerHTML"
+                {
+                    "×"
+                }
+            }
+        }
+    }
+}
+
+fn unit_title(t: &TranslationContext, unit: LineUnit) -> String {
+    match unit {
+        LineUnit::Forward => t.messages.roster_lines_forwards().to_string(),
+        LineUnit::Defense => t.messages.roster_lines_defense().to_string(),
+        LineUnit::Goalie => t.messages.roster_lines_goalies().to_string(),
+    }
+}
+
+fn line_label(t: &TranslationContext, unit: LineUnit, line_number: i64) -> String {
+    match unit {
+        LineUnit::Forward => format!("{} {}", t.messages.roster_lines_line(), line_number),
+        LineUnit::Defense => format!("{} {}", t.messages.roster_lines_pair(), line_number),
+        LineUnit::Goalie => format!("G{}", line_number),
+    }
+}
+
+fn slot_label(unit: LineUnit, slot: i64) -> &'static str {
+    match (unit, slot) {
+        (LineUnit::Forward, 1) => "LW",
+        (LineUnit::Forward, 2) => "C",
+        (LineUnit::Forward, _) => "RW",
+        (LineUnit::Defense, 1) => "LD",
+        (LineUnit::Defense, _) => "RD",
+        (LineUnit::Goalie, _) => "G",
+    }
+}
+
+/// Drag-and-drop wiring for the line editor
+///
+/// Listeners are delegated from the document so they keep working after the
+/// editor partial is swapped.
+fn line_editor_script() -> Markup {
+    html! {
+        script {
+            (maud::PreEscaped(r#"
+(function () {
+    if (window.lineEditorBound) return;
+    window.lineEditorBound = true;
+
+    document.addEventListener('dragstart', function (e) {
+        var chip = e.target.closest && e.target.closest('.line-chip');
+        if (!chip) return;
+        e.dataTransfer.setData('text/plain', chip.dataset.contractId);
+        e.dataTransfer.effectAllowed = 'move';
+    });
+
+    document.addEventListener('dragover', function (e) {
+        var zone = e.target.closest && e.target.closest('#line-editor [data-drop]');
+        if (!zone) return;
+        e.preventDefault();
+        zone.classList.add('drag-over');
+    });
+
+    document.addEventListener('dragleave', function (e) {
+        var zone = e.target.closest && e.target.closest('#line-editor [data-drop]');
+        if (zone && !zone.contains(e.relatedTarget)) zone.classList.remove('drag-over');
+    });
+
+    document.addEventListener('drop', function (e) {
+        var zone = e.target.closest && e.target.closest('#line-editor [data-drop]');
+        if (!zone) return;
+        e.preventDefault();
+        zone.classList.remove('drag-over');
+
+        var contractId = e.dataTransfer.getData('text/plain');
+        if (!contractId) return;
+
+        var editor = document.getElementById('line-editor');
+        var values = { player_contract_id: contractId };
+        var url = editor.dataset.unassignUrl;
+        if (zone.dataset.drop === 'assign') {
+            url = editor.dataset.assignUrl;
+            values.unit = zone.dataset.unit;
+            values.line_number = zone.dataset.line;
+            values.slot = zone.dataset.slot;
+        }
+
+        htmx.ajax('POST', url, { target: '#line-editor', swap: 'outerHTML', values: values });
+    });
+})();
+"#))
+        }
+    }
+}
+
 /// Context card showing team, event, and season info
+/// Roster rules of the event with the roster's current standing; highlighted
+/// when any rule is broken
+fn roster_rules_panel(t: &TranslationContext, checks: &[RosterRuleCheck]) -> Markup {
+    let compliant = checks.iter().all(RosterRuleCheck::is_met);
+    let style = if compliant {
+        "margin-top: 1rem; padding: 1rem 1.5rem; background: var(--gray-50); border-radius: 8px;"
+    } else {
+        "margin-top: 1rem; padding: 1rem 1.5rem; background-color: #fef3c7; border: 1px solid #f59e0b; border-radius: 8px;"
+    };
+
+    html! {
+        div style=(style) {
+            div style="font-weight: 600; margin-bottom: 0.5rem;" {
+                @if compliant {
+                    (t.messages.roster_rules_compliant())
+                } @else {
+                    (format!("⚠ {}", t.messages.roster_rules_not_compliant()))
+                }
+            }
+            ul style="margin: 0; padding-left: 1.25rem;" {
+                @for check in checks {
+                    li style=(if check.is_met() { "color: var(--gray-700);" } else { "color: #b45309; font-weight: 600;" }) {
+                        (match check.rule {
+                            RosterRule::MaxPlayers => t.messages.roster_rules_max_players().to_string(),
+                            RosterRule::MinGoalies => t.messages.roster_rules_min_goalies().to_string(),
+                            RosterRule::MaxImports => t.messages.roster_rules_max_imports().to_string(),
+                        })
+                        (format!(": {} / {}", check.current, check.limit))
+                    }
+                }
+            }
+        }
+    }
+}
+
 fn context_card(context: &TeamParticipationContext) -> Markup {
     html! {
         div style="padding: 1.5rem; background: var(--gray-50); border-radius: 8px;" {
@@ -105,11 +406,12 @@ fn context_card(context: &TeamParticipationContext) -> Markup {
 }
 
 /// Roster table showing all players
-fn roster_table(roster: &[PlayerInRoster]) -> Markup {
+fn roster_table(t: &TranslationContext, roster: &[PlayerInRoster]) -> Markup {
     html! {
         table class="table" {
             thead {
                 tr {
+                    th style="width: 4rem; text-align: center;" { (t.messages.roster_jersey_number_short()) }
                     th { "Player" }
                     th { "Nationality" }
                     th style="text-align: right;" { "Actions" }
@@ -117,7 +419,14 @@ fn roster_table(roster: &[PlayerInRoster]) -> Markup {
             }
             tbody {
                 @for player in roster {
-                    tr {
+                    tr style=[player.left_on.is_some().then_some("opacity: 0.6;")] {
+                        td style="text-align: center; font-weight: 700;" {
+                            @if let Some(number) = player.jersey_number {
+                                (number)
+                            } @else {
+                                span style="color: var(--gray-400);" { "-" }
+                            }
+                        }
                         td {
                             div style="display: flex; align-items: center; gap: 0.5rem;" {
                                 @if let Some(photo) = &player.photo_path {
@@ -130,6 +439,15 @@ fn roster_table(roster: &[PlayerInRoster]) -> Markup {
                                 span style="font-weight: 500;" {
                                     (player.player_name)
                                 }
+                                @if let Some(captaincy) = player.captaincy() {
+                                    (captaincy_letter(t, captaincy))
+                                }
+                                @if let Some(date) = &player.joined_on {
+                                    (contract_date_badge(&format!("{} {}", t.messages.roster_joined(), date), "color: #15803d; background: #dcfce7;"))
+                                }
+                                @if let Some(date) = &player.left_on {
+                                    (contract_date_badge(&format!("{} {}", t.messages.roster_left(), date), "color: #b91c1c; background: #fee2e2;"))
+                                }
                             }
                         }
                         td {
@@ -141,7 +459,27 @@ fn roster_table(roster: &[PlayerInRoster]) -> Markup {
                                 (player.country_name)
                             }
                         }
-                        td style="text-align: right;" {
+                        td style="text-align: right; white-space: nowrap;" {
+                            @if player.left_on.is_none() {
+                                button
+                                    class="btn btn-sm"
+                                    style="margin-right: 0.5rem;"
+                                    hx-get=(format!("/player-contracts/{}/transfer", player.player_contract_id))
+                                    hx-target="#modal-container"
+                                    hx-swap="innerHTML"
+                                {
+                                    (t.messages.roster_transfer())
+                                }
+                            }
+                            button
+                                class="btn btn-sm"
+                                style="margin-right: 0.5rem;"
+                                hx-get=(format!("/player-contracts/{}/edit", player.player_contract_id))
+                                hx-target="#modal-container"
+                                hx-swap="innerHTML"
+                            {
+                                (t.messages.common_edit())
+                            }
                             button
                                 class="btn btn-sm btn-danger"
                                 hx-post=(format!("/player-contracts/{}/delete", player.player_contract_id))
@@ -163,6 +501,86 @@ fn roster_table(roster: &[PlayerInRoster]) -> Markup {
     }
 }
 
+/// Boxed `C` or `A` shown next to a captain's or alternate's name
+pub fn captaincy_letter(t: &TranslationContext, captaincy: Captaincy) -> Markup {
+    let title = match captaincy {
+        Captaincy::Captain => t.messages.roster_captain().to_string(),
+        Captaincy::Alternate => t.messages.roster_alternate_captain().to_string(),
+    };
+
+    html! {
+        span class="captaincy-letter" title=(title) { (captaincy.as_str()) }
+    }
+}
+
+/// Small pill showing when a player joined or left the team
+fn contract_date_badge(label: &str, colors: &str) -> Markup {
+    html! {
+        span style=(format!(
+            "display: inline-block; padding: 0.125rem 0.5rem; border-radius: 9999px; font-size: 0.75rem; font-weight: 500; {}",
+            colors
+        )) {
+            (label)
+        }
+    }
+}
+
+/// Staff table showing coaches and managers of the team participation
+fn staff_table(t: &TranslationContext, staff: &[StaffContractEntity]) -> Markup {
+    html! {
+        table class="table" {
+            thead {
+                tr {
+                    th { (t.messages.roster_staff_member()) }
+                    th { (t.messages.roster_staff_role()) }
+                    th { "Nationality" }
+                    th style="text-align: right;" { "Actions" }
+                }
+            }
+            tbody {
+                @for member in staff {
+                    tr {
+                        td style="font-weight: 500;" { (member.name) }
+                        td {
+                            @if let Some(role) = member.role() {
+                                (staff_role_label(t, role))
+                            }
+                        }
+                        td {
+                            @if let (Some(iso2), Some(country_name)) = (&member.country_iso2_code, &member.country_name) {
+                                div style="display: flex; align-items: center; gap: 0.5rem;" {
+                                    flag-icon
+                                        country-code=(iso2.to_lowercase())
+                                        country-name=(country_name)
+                                        size="sm";
+                                    (country_name)
+                                }
+                            } @else {
+                                span style="color: var(--gray-400);" { "-" }
+                            }
+                        }
+                        td style="text-align: right; white-space: nowrap;" {
+                            button
+                                class="btn btn-sm btn-danger"
+                                hx-post=(format!("/staff-contracts/{}/delete", member.staff_contract_id))
+                                hx-confirm-custom=(confirm_attrs(
+                                    &t.messages.roster_staff_remove_title().to_string(),
+                                    &t.messages.roster_staff_confirm_remove().to_string(),
+                                    ConfirmVariant::Danger,
+                                    Some(&t.messages.roster_staff_remove().to_string()),
+                                    Some(&t.messages.common_cancel().to_string())
+                                ))
+                            {
+                                (t.messages.roster_staff_remove())
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Empty state when no players in roster
 fn empty_roster_state() -> Markup {
     html! {
@@ -214,6 +632,7 @@ pub fn add_player_modal(
                 }
             }
         }
+        (jersey_number_field(t, None))
     };
 
     modal_form_i18n(
@@ -226,3 +645,300 @@ pub fn add_player_modal(
         &t.messages.common_cancel().to_string(),
     )
 }
+
+/// Modal form to upload a roster CSV for review
+pub fn roster_import_modal(
+    session: &Session,
+    t: &TranslationContext,
+    team_participation_id: i64,
+    error: Option<&str>,
+) -> Markup {
+    let form_fields = html! {
+        (csrf_token_field(&session.csrf_token))
+
+        p style="color: var(--gray-600); margin-bottom: 1rem;" {
+            (t.messages.roster_import_help())
+        }
+        pre style="background: var(--gray-100); padding: 0.75rem; border-radius: 4px; font-size: 0.875rem; margin-bottom: 1rem;" {
+            "name,number,country\nConnor McDavid,97,CAN\nLeon Draisaitl,29,GER"
+        }
+
+        div style="margin-bottom: 1.5rem;" {
+            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                (t.messages.roster_import_file())
+                span style="color: red; margin-left: 0.25rem;" { "*" }
+            }
+            input
+                type="file"
+                name="file"
+                accept="text/csv,.csv"
+                required
+                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
+        }
+    };
+
+    modal_form_multipart_i18n(
+        "roster-import-modal",
+        &t.messages.roster_import_title().to_string(),
+        error,
+        &format!(
+            "/team-participations/{}/roster/import/review",
+            team_participation_id
+        ),
+        form_fields,
+        &t.messages.roster_import_review().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Review step of a roster import: what happens to every row of the file
+///
+/// The file's content travels with the confirmation, which plans the import
+/// again before applying it.
+pub fn roster_import_review_modal(
+    session: &Session,
+    t: &TranslationContext,
+    team_participation_id: i64,
+    csv: &str,
+    plan: &RosterImportPlan,
+) -> Markup {
+    let form_fields = html! {
+        (csrf_token_field(&session.csrf_token))
+        textarea name="csv" hidden { (csv) }
+
+        div style="display: flex; gap: 1rem; margin-bottom: 1rem; font-size: 0.875rem;" {
+            span { (t.messages.roster_import_matched()) ": " strong { (plan.matched()) } }
+            span { (t.messages.roster_import_new()) ": " strong { (plan.created()) } }
+            span { (t.messages.roster_import_skipped()) ": " strong { (plan.skipped()) } }
+        }
+
+        div style="max-height: 50vh; overflow-y: auto; margin-bottom: 1.5rem;" {
+            table class="table" style="font-size: 0.875rem;" {
+                tbody {
+                    @for row in &plan.rows {
+                        tr {
+                            td style="color: var(--gray-500);" { (row.row.line) }
+                            td { (row.row.name) }
+                            td { (row.row.number) }
+                            td { (roster_import_action(t, &row.action)) }
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    let submit = format!(
+        "{} ({})",
+        t.messages.roster_import_submit(),
+        plan.matched() + plan.created()
+    );
+    modal_form_i18n(
+        "roster-import-modal",
+        &t.messages.roster_import_title().to_string(),
+        None,
+        &format!(
+            "/team-participations/{}/roster/import",
+            team_participation_id
+        ),
+        form_fields,
+        &submit,
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+fn roster_import_action(t: &TranslationContext, action: &RosterRowAction) -> Markup {
+    html! {
+        @match action {
+            RosterRowAction::Existing { player_id, player_name } => {
+                span style="color: #15803d;" { (t.messages.roster_import_matched()) ": " }
+                a href=(format!("/players/{}", player_id)) target="_blank" { (player_name) }
+            }
+            RosterRowAction::New { .. } => {
+                span style="color: #1d4ed8;" { (t.messages.roster_import_new()) }
+            }
+            RosterRowAction::OnRoster { .. } => {
+                span style="color: var(--gray-500);" { (t.messages.roster_import_on_roster()) }
+            }
+            RosterRowAction::Invalid(error) => {
+                span style="color: #b91c1c;" { (error.message()) }
+            }
+        }
+    }
+}
+
+/// Modal form to contract a staff member to the team participation
+pub fn add_staff_modal(
+    t: &TranslationContext,
+    team_participation_id: i64,
+    error: Option<&str>,
+    staff_members: &[(i64, String)],
+) -> Markup {
+    let select_style = "width: 100%; padding: 0.75rem; border: 1px solid var(--gray-300); border-radius: 4px; font-size: 1rem;";
+    let form_fields = html! {
+        @if staff_members.is_empty() {
+            div style="padding: 1rem; background: var(--gray-100); border-radius: 4px; color: var(--gray-600); text-align: center;" {
+                (t.messages.roster_staff_none_registered())
+            }
+        } @else {
+            div style="margin-bottom: 1.5rem;" {
+                label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                    (t.messages.roster_staff_member())
+                    span style="color: red; margin-left: 0.25rem;" { "*" }
+                }
+                select name="staff_member_id" required autofocus style=(select_style) {
+                    option value="" { (t.messages.roster_staff_select_member()) }
+                    @for (id, name) in staff_members {
+                        option value=(id) { (name) }
+                    }
+                }
+            }
+            div style="margin-bottom: 1.5rem;" {
+                label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                    (t.messages.roster_staff_role())
+                    span style="color: red; margin-left: 0.25rem;" { "*" }
+                }
+                select name="role" required style=(select_style) {
+                    @for role in StaffRole::ALL {
+                        option value=(role.as_str()) { (staff_role_label(t, role)) }
+                    }
+                }
+            }
+        }
+    };
+
+    modal_form_i18n(
+        "add-staff-modal",
+        &t.messages.roster_staff_add_title().to_string(),
+        error,
+        &format!("/team-participations/{}/staff", team_participation_id),
+        form_fields,
+        &t.messages.roster_staff_add_title().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Modal form to edit a roster entry (jersey number and captaincy)
+pub fn edit_player_contract_modal(
+    t: &TranslationContext,
+    contract: &PlayerContractEntity,
+    error: Option<&str>,
+) -> Markup {
+    let form_fields = html! {
+        p style="margin-bottom: 1rem; font-weight: 500;" { (contract.player_name) }
+        (jersey_number_field(t, contract.jersey_number))
+        @if contract.left_on.is_none() {
+            (captaincy_field(t, contract.captaincy()))
+        }
+    };
+
+    modal_form_i18n(
+        "edit-player-contract-modal",
+        &t.messages.roster_edit_player_title().to_string(),
+        error,
+        &format!("/player-contracts/{}", contract.id),
+        form_fields,
+        &t.messages.common_save().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Modal form to transfer a player to another team of the same season
+pub fn transfer_player_modal(
+    t: &TranslationContext,
+    source: &TransferSourceEntity,
+    destinations: &[(i64, String)],
+    error: Option<&str>,
+) -> Markup {
+    let input_style = "width: 100%; padding: 0.75rem; border: 1px solid var(--gray-300); border-radius: 4px; font-size: 1rem;";
+    let form_fields = html! {
+        p style="margin-bottom: 1rem; font-weight: 500;" { (source.player_name) }
+        @if destinations.is_empty() {
+            div style="padding: 1rem; background: var(--gray-100); border-radius: 4px; color: var(--gray-600); text-align: center;" {
+                (t.messages.roster_transfer_no_destinations())
+            }
+        } @else {
+            div style="margin-bottom: 1.5rem;" {
+                label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                    (t.messages.roster_transfer_destination())
+                    span style="color: red; margin-left: 0.25rem;" { "*" }
+                }
+                select name="to_participation_id" required autofocus style=(input_style) {
+                    option value="" { (t.messages.roster_transfer_select_destination()) }
+                    @for (id, name) in destinations {
+                        option value=(id) { (name) }
+                    }
+                }
+            }
+            div style="margin-bottom: 1.5rem;" {
+                label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                    (t.messages.roster_transfer_date())
+                    span style="color: red; margin-left: 0.25rem;" { "*" }
+                }
+                input type="date" name="transfer_date" required min=[source.joined_on.as_deref()] style=(input_style);
+            }
+            div style="margin-bottom: 1.5rem;" {
+                label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                    (t.messages.roster_transfer_note())
+                }
+                input type="text" name="note" maxlength="500" style=(input_style);
+            }
+        }
+    };
+
+    modal_form_i18n(
+        "transfer-player-modal",
+        &t.messages.roster_transfer_title().to_string(),
+        error,
+        &format!("/player-contracts/{}/transfer", source.player_contract_id),
+        form_fields,
+        &t.messages.roster_transfer().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Optional jersey number input shared by the add and edit forms
+fn jersey_number_field(t: &TranslationContext, value: Option<i64>) -> Markup {
+    html! {
+        div style="margin-bottom: 1.5rem;" {
+            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                (t.messages.roster_jersey_number())
+            }
+            input
+                type="number"
+                name="jersey_number"
+                min="0"
+                max="99"
+                value=[value]
+                style="width: 100%; padding: 0.75rem; border: 1px solid var(--gray-300); border-radius: 4px; font-size: 1rem;";
+            p style="margin-top: 0.25rem; font-size: 0.875rem; color: var(--gray-500);" {
+                (t.messages.roster_jersey_number_help())
+            }
+        }
+    }
+}
+
+fn captaincy_field(t: &TranslationContext, value: Option<Captaincy>) -> Markup {
+    html! {
+        div style="margin-bottom: 1.5rem;" {
+            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                (t.messages.roster_captaincy())
+            }
+            select
+                name="captaincy"
+                style="width: 100%; padding: 0.75rem; border: 1px solid var(--gray-300); border-radius: 4px; font-size: 1rem;"
+            {
+                option value="" selected[value.is_none()] { (t.messages.roster_captaincy_none()) }
+                option value=(Captaincy::Captain.as_str()) selected[value == Some(Captaincy::Captain)] {
+                    (t.messages.roster_captain())
+                }
+                option value=(Captaincy::Alternate.as_str()) selected[value == Some(Captaincy::Alternate)] {
+                    (t.messages.roster_alternate_captain())
+                }
+            }
+            p style="margin-top: 0.25rem; font-size: 0.875rem; color: var(--gray-500);" {
+                (t.messages.roster_captaincy_help())
+            }
+        }
+    }
+}
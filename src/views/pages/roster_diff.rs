@@ -0,0 +1,156 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::roster_diff::{RosterDiffEntity, RosterDiffPlayerEntity};
+use crate::service::teams::{TeamEntity, TeamParticipationWithSeasonEntity};
+
+/// Which side of the comparison a player list shows
+#[derive(Clone, Copy)]
+enum DiffColumn {
+    Added,
+    Departed,
+    Retained,
+}
+
+fn participation_title(participation: &TeamParticipationWithSeasonEntity) -> String {
+    participation
+        .season_display_name
+        .clone()
+        .unwrap_or_else(|| format!("{} {}", participation.event_name, participation.season_year))
+}
+
+/// Compare a team's rosters of two seasons
+///
+/// `diff` is `None` until two different seasons are picked.
+pub fn roster_diff_page(
+    t: &TranslationContext,
+    team: &TeamEntity,
+    participations: &[TeamParticipationWithSeasonEntity],
+    from_season_id: Option<i64>,
+    to_season_id: Option<i64>,
+    diff: Option<&RosterDiffEntity>,
+) -> Markup {
+    let season_select = |name: &str, label: String, selected: Option<i64>| {
+        html! {
+            div class="form-group" {
+                label class="form-label" for=(name) { (label) }
+                select id=(name) name=(name) {
+                    @for participation in participations {
+                        option
+                            value=(participation.season_id)
+                            selected[selected == Some(participation.season_id)]
+                        {
+                            (participation_title(participation))
+                        }
+                    }
+                }
+            }
+        }
+    };
+
+    html! {
+        div class="card" {
+            div style="display: flex; align-items: center; gap: 1rem; margin-bottom: 1.5rem;" {
+                a href=(format!("/teams/{}", team.id)) class="btn btn-secondary" {
+                    (format!("← {}", team.name))
+                }
+                h1 style="font-size: 2rem; font-weight: 700; margin: 0;" {
+                    (t.messages.roster_diff_title())
+                }
+            }
+
+            form
+                method="get"
+                action=(format!("/teams/{}/roster-diff", team.id))
+                style="display: flex; gap: 1rem; align-items: flex-end; margin-bottom: 1.5rem; flex-wrap: wrap;"
+            {
+                (season_select("from", t.messages.roster_diff_from().to_string(), from_season_id))
+                (season_select("to", t.messages.roster_diff_to().to_string(), to_season_id))
+                div class="form-group" {
+                    button type="submit" class="btn btn-primary" { (t.messages.roster_diff_compare()) }
+                }
+            }
+
+            @if let Some(diff) = diff {
+                div style="display: grid; grid-template-columns: repeat(auto-fit, minmax(250px, 1fr)); gap: 1.5rem;" {
+                    (diff_column(t, DiffColumn::Added, &diff.added))
+                    (diff_column(t, DiffColumn::Departed, &diff.departed))
+                    (diff_column(t, DiffColumn::Retained, &diff.retained))
+                }
+            } @else {
+                div style="padding: 1.5rem; text-align: center; color: var(--gray-500); background: var(--gray-50); border-radius: 8px;" {
+                    (t.messages.roster_diff_pick_seasons())
+                }
+            }
+        }
+    }
+}
+
+fn diff_column(
+    t: &TranslationContext,
+    column: DiffColumn,
+    players: &[RosterDiffPlayerEntity],
+) -> Markup {
+    let (title, color) = match column {
+        DiffColumn::Added => (t.messages.roster_diff_added(), "var(--success-color)"),
+        DiffColumn::Departed => (t.messages.roster_diff_departed(), "var(--danger-color)"),
+        DiffColumn::Retained => (t.messages.roster_diff_retained(), "var(--gray-600)"),
+    };
+
+    html! {
+        div {
+            h2 style=(format!("font-size: 1.25rem; font-weight: 700; margin: 0 0 0.75rem 0; color: {};", color)) {
+                (title) " (" (players.len()) ")"
+            }
+            @if players.is_empty() {
+                div style="color: var(--gray-500); font-size: 0.875rem;" { (t.messages.roster_diff_none()) }
+            } @else {
+                ul style="list-style: none; margin: 0; padding: 0;" {
+                    @for player in players {
+                        li style="padding: 0.5rem 0; border-bottom: 1px solid var(--gray-100);" {
+                            div {
+                                @if let Some(number) = player.to_jersey_number.or(player.from_jersey_number) {
+                                    span style="color: var(--gray-500); margin-right: 0.25rem;" { (format!("#{}", number)) }
+                                }
+                                a href=(format!("/players/{}", player.player_id)) class="primary-link" {
+                                    (player.player_name)
+                                }
+                                @if let Some(position) = &player.position {
+                                    span style="color: var(--gray-500); font-size: 0.875rem;" { " · " (position) }
+                                }
+                            }
+                            (diff_note(t, column, player))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Where a player came from or went to, or a jersey number change
+fn diff_note(
+    t: &TranslationContext,
+    column: DiffColumn,
+    player: &RosterDiffPlayerEntity,
+) -> Markup {
+    let note = match (column, &player.other_teams) {
+        (DiffColumn::Added, Some(teams)) => {
+            Some(format!("{} {}", t.messages.roster_diff_came_from(), teams))
+        }
+        (DiffColumn::Departed, Some(teams)) => {
+            Some(format!("{} {}", t.messages.roster_diff_went_to(), teams))
+        }
+        (DiffColumn::Retained, _) => match (player.from_jersey_number, player.to_jersey_number) {
+            (Some(from), Some(to)) if from != to => Some(format!("#{} → #{}", from, to)),
+            _ => None,
+        },
+        _ => None,
+    };
+
+    html! {
+        @if let Some(note) = note {
+            div style="font-size: 0.75rem; color: var(--gray-500); margin-top: 0.125rem;" { (note) }
+        }
+    }
+}
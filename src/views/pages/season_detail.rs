@@ -1,20 +1,63 @@
+use std::collections::HashMap;
+
 use maud::{html, Markup};
 
 use crate::auth::Session;
 use crate::i18n::TranslationContext;
+use crate::service::awards::SeasonAwardEntity;
+use crate::service::comments::CommentEntityType;
+use crate::service::goal_types::TeamGoalTypesEntity;
+use crate::service::leaders::SeasonLeadersEntity;
+use crate::service::matches::{AttendanceEntity, TeamShootingEntity};
+use crate::service::period_distribution::PeriodDistributionEntity;
+use crate::service::playoffs::PlayoffSeriesEntity;
+use crate::service::season_groups::SeasonGroupEntity;
 use crate::service::seasons::SeasonDetailEntity;
+use crate::service::standings::TeamStandingEntity;
+use crate::service::tasks::TaskEntityType;
+use crate::service::team_form::FormMatchEntity;
 use crate::service::team_participations::TeamParticipationEntity;
 use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
 use crate::views::components::crud::modal_form_i18n;
 use crate::views::components::forms::csrf_token_field;
+use crate::views::pages::attendance::attendance_section;
+use crate::views::pages::awards::awards_section;
+use crate::views::pages::comments::entity_comments_section;
+use crate::views::pages::goal_types::season_goal_types_section;
+use crate::views::pages::leaders::leaders_panel;
+use crate::views::pages::period_distribution::period_distribution_section;
+use crate::views::pages::placements::{placement_select, seed_select};
+use crate::views::pages::playoffs::playoff_bracket;
+use crate::views::pages::season_groups::{groups_section, standings_section, team_group_select};
+use crate::views::pages::tasks::entity_tasks_section;
 
 /// Season detail page with team participation management
+///
+/// `selected_group` narrows the teams list and standings to one group.
+#[allow(clippy::too_many_arguments)]
 pub fn season_detail_page(
     session: &Session,
     t: &TranslationContext,
     detail: &SeasonDetailEntity,
+    groups: &[SeasonGroupEntity],
+    selected_group: Option<i64>,
+    standings: &[TeamStandingEntity],
+    form: &HashMap<i64, Vec<FormMatchEntity>>,
+    series: &[PlayoffSeriesEntity],
+    shooting: &[TeamShootingEntity],
+    attendance: &[AttendanceEntity],
+    goal_types: &[TeamGoalTypesEntity],
+    period_distribution: &PeriodDistributionEntity,
+    awards: &[SeasonAwardEntity],
+    leaders: &SeasonLeadersEntity,
 ) -> Markup {
     let season = &detail.season_info;
+    let teams: Vec<TeamParticipationEntity> = detail
+        .participating_teams
+        .iter()
+        .filter(|team| selected_group.is_none_or(|id| team.group_id == Some(id)))
+        .cloned()
+        .collect();
 
     html! {
         div class="card" {
@@ -36,6 +79,21 @@ pub fn season_detail_page(
                     }
                 }
                 div style="display: flex; gap: 0.5rem;" {
+                    a
+                        href=(format!("/seasons/{}/export/xlsx", season.id))
+                        class="btn btn-secondary"
+                        download
+                    {
+                        (t.messages.season_export_xlsx())
+                    }
+                    button
+                        class="btn btn-secondary"
+                        hx-get=(format!("/seasons/{}/clone", season.id))
+                        hx-target="#modal-container"
+                        hx-swap="innerHTML"
+                    {
+                        (t.messages.seasons_clone())
+                    }
                     button
                         class="btn btn-primary"
                         hx-get=(format!("/seasons/{}/edit", season.id))
@@ -71,6 +129,9 @@ pub fn season_detail_page(
             // Season Info Card
             (season_info_card(t, season))
 
+            // Groups (Group A/B, divisions)
+            (groups_section(session, t, season.id, groups, selected_group))
+
             // Participating Teams Section
             div style="margin-top: 2rem;" {
                 div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
@@ -87,13 +148,47 @@ pub fn season_detail_page(
                     }
                 }
 
-                @if detail.participating_teams.is_empty() {
+                @if teams.is_empty() {
                     (empty_teams_state(t))
                 } @else {
-                    (teams_list(session, t, &detail.participating_teams))
+                    (teams_list(session, t, &teams, groups, shooting))
                 }
             }
 
+            // Standings
+            @if !detail.participating_teams.is_empty() {
+                (standings_section(t, groups, standings, form, selected_group))
+            }
+
+            // Playoff bracket
+            (playoff_bracket(session, t, season.id, series))
+
+            // Attendance per arena
+            (attendance_section(
+                t,
+                &t.messages.attendance_arena().to_string(),
+                attendance,
+                |arena_id| format!("/arenas/{}", arena_id),
+            ))
+
+            // Goal type distribution per team
+            (season_goal_types_section(t, goal_types))
+
+            // Goals per period
+            (period_distribution_section(t, period_distribution))
+
+            // Scoring leaders
+            (leaders_panel(t, season.id, leaders))
+
+            // Season awards
+            (awards_section(session, t, season.id, awards))
+
+            // Internal discussion about this season
+            (entity_comments_section(t, CommentEntityType::Season, season.id))
+
+            // Open tasks attached to this season
+            (entity_tasks_section(t, TaskEntityType::Season, season.id))
+
             // Modal container
             div id="modal-container" {}
         }
@@ -144,6 +239,8 @@ fn teams_list(
     session: &Session,
     t: &TranslationContext,
     teams: &[TeamParticipationEntity],
+    groups: &[SeasonGroupEntity],
+    shooting: &[TeamShootingEntity],
 ) -> Markup {
     html! {
         div style="display: grid; grid-template-columns: repeat(auto-fill, minmax(320px, 1fr)); gap: 1rem;" {
@@ -164,6 +261,23 @@ fn teams_list(
                             (team.team_name)
                         }
                     }
+                    @if let Some(stats) = shooting.iter().find(|s| s.team_id == team.team_id) {
+                        div
+                            style="display: flex; gap: 1rem; font-size: 0.875rem; color: var(--gray-600);"
+                            title=(format!("{} {}", stats.games, t.messages.matches_shots_games_recorded()))
+                        {
+                            span { (format!("{}: {}", t.messages.matches_shots_on_goal(), stats.shots)) }
+                            span { (format!("{}: {}", t.messages.matches_goals(), stats.goals)) }
+                            @if let Some(pct) = stats.shooting_percentage() {
+                                span { (format!("{}: {:.1}%", t.messages.matches_shooting_percentage(), pct)) }
+                            }
+                        }
+                    }
+                    @if !groups.is_empty() {
+                        (team_group_select(session, t, team, groups))
+                    }
+                    (seed_select(session, t, team))
+                    (placement_select(session, t, team))
                     div style="display: flex; gap: 0.5rem;" {
                         a
                             href=(format!("/team-participations/{}/roster", team.id))
@@ -0,0 +1,283 @@
+use std::collections::HashMap;
+
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::season_groups::SeasonGroupEntity;
+use crate::service::standings::{PointSystem, TeamStandingEntity};
+use crate::service::team_form::FormMatchEntity;
+use crate::service::team_participations::TeamParticipationEntity;
+use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
+use crate::views::components::crud::modal_form_i18n;
+use crate::views::components::forms::csrf_token_field;
+use crate::views::pages::team_form::form_strip;
+
+/// Groups section for the season detail page
+///
+/// Each group doubles as a filter for the teams list and standings below.
+pub fn groups_section(
+    session: &Session,
+    t: &TranslationContext,
+    season_id: i64,
+    groups: &[SeasonGroupEntity],
+    selected_group: Option<i64>,
+) -> Markup {
+    html! {
+        div style="margin-top: 2rem;" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1rem;" {
+                h2 style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
+                    (t.messages.groups_title())
+                }
+                button
+                    class="btn btn-primary"
+                    hx-get=(format!("/seasons/{}/groups/new", season_id))
+                    hx-target="#modal-container"
+                    hx-swap="innerHTML"
+                {
+                    (format!("+ {}", t.messages.groups_add()))
+                }
+            }
+
+            @if groups.is_empty() {
+                p style="color: var(--gray-600);" { (t.messages.groups_empty()) }
+            } @else {
+                div style="display: flex; flex-wrap: wrap; gap: 0.5rem; align-items: center;" {
+                    a
+                        href=(format!("/seasons/{}", season_id))
+                        class=(if selected_group.is_none() { "btn btn-sm btn-primary" } else { "btn btn-sm btn-secondary" })
+                    {
+                        (t.messages.groups_all_teams())
+                    }
+                    @for group in groups {
+                        a
+                            href=(format!("/seasons/{}?group_id={}", season_id, group.id))
+                            class=(if selected_group == Some(group.id) { "btn btn-sm btn-primary" } else { "btn btn-sm btn-secondary" })
+                        {
+                            (format!("{} ({})", group.name, group.team_count))
+                        }
+                    }
+                }
+
+                @if let Some(group) = groups.iter().find(|g| Some(g.id) == selected_group) {
+                    div style="display: flex; gap: 0.5rem; margin-top: 1rem;" {
+                        a
+                            href=(format!("/matches?season_id={}&group_id={}", season_id, group.id))
+                            class="btn btn-sm"
+                        {
+                            (t.messages.groups_view_matches())
+                        }
+                        form style="display: inline;" {
+                            (csrf_token_field(&session.csrf_token))
+                            button
+                                type="submit"
+                                class="btn btn-sm btn-danger"
+                                hx-post=(format!("/season-groups/{}/delete", group.id))
+                                hx-confirm-custom=(confirm_attrs(
+                                    &format!("{} \"{}\"", t.messages.common_delete(), group.name),
+                                    &t.messages.groups_confirm_delete().to_string(),
+                                    ConfirmVariant::Danger,
+                                    Some(&t.messages.common_delete().to_string()),
+                                    Some(&t.messages.common_cancel().to_string())
+                                ))
+                            {
+                                (t.messages.common_delete())
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Group dropdown on a team card; changing it moves the team
+pub fn team_group_select(
+    session: &Session,
+    t: &TranslationContext,
+    team: &TeamParticipationEntity,
+    groups: &[SeasonGroupEntity],
+) -> Markup {
+    html! {
+        form
+            hx-post=(format!("/team-participations/{}/group", team.id))
+            hx-trigger="change"
+            style="display: flex; align-items: center; gap: 0.5rem; font-size: 0.875rem;"
+        {
+            (csrf_token_field(&session.csrf_token))
+            label style="color: var(--gray-600);" for=(format!("group-{}", team.id)) {
+                (t.messages.matches_filter_group())
+            }
+            select
+                id=(format!("group-{}", team.id))
+                name="group_id"
+                style="flex: 1; padding: 0.25rem 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;"
+            {
+                option value="" { (t.messages.groups_none()) }
+                @for group in groups {
+                    option value=(group.id) selected[team.group_id == Some(group.id)] { (group.name) }
+                }
+            }
+        }
+    }
+}
+
+/// Standings tables: one per group, or a single table when the season has no groups
+///
+/// `form` holds each team's last matches of the season, shown next to the points.
+pub fn standings_section(
+    t: &TranslationContext,
+    groups: &[SeasonGroupEntity],
+    standings: &[TeamStandingEntity],
+    form: &HashMap<i64, Vec<FormMatchEntity>>,
+    selected_group: Option<i64>,
+) -> Markup {
+    let tables: Vec<(Option<&str>, Vec<&TeamStandingEntity>)> = if groups.is_empty() {
+        vec![(None, standings.iter().collect())]
+    } else {
+        let mut tables: Vec<(Option<&str>, Vec<&TeamStandingEntity>)> = groups
+            .iter()
+            .filter(|g| selected_group.is_none_or(|id| id == g.id))
+            .map(|g| {
+                (
+                    Some(g.name.as_str()),
+                    standings
+                        .iter()
+                        .filter(|s| s.group_id == Some(g.id))
+                        .collect(),
+                )
+            })
+            .collect();
+        let unassigned: Vec<&TeamStandingEntity> =
+            standings.iter().filter(|s| s.group_id.is_none()).collect();
+        if selected_group.is_none() && !unassigned.is_empty() {
+            tables.push((None, unassigned));
+        }
+        tables
+    };
+    // Every team of a season shares its event's point system
+    let point_system = standings
+        .first()
+        .map(TeamStandingEntity::point_system)
+        .unwrap_or_default();
+    let overtime_columns = point_system == PointSystem::Iihf;
+
+    html! {
+        div style="margin-top: 2rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                (t.messages.standings_title())
+            }
+            p style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 1rem;" {
+                (t.messages.standings_hint())
+                " "
+                @match point_system {
+                    PointSystem::Classic => (t.messages.standings_points_classic()),
+                    PointSystem::Iihf => (t.messages.standings_points_iihf()),
+                }
+            }
+
+            @for (name, rows) in &tables {
+                @if !groups.is_empty() {
+                    h3 style="font-size: 1.125rem; font-weight: 600; margin: 1rem 0 0.5rem;" {
+                        @if let Some(name) = name {
+                            (name)
+                        } @else {
+                            (t.messages.groups_none())
+                        }
+                    }
+                }
+                @if rows.is_empty() {
+                    p style="color: var(--gray-500);" { (t.messages.seasons_no_teams()) }
+                } @else {
+                    table class="table" {
+                        thead {
+                            tr {
+                                th style="width: 3rem;" { "#" }
+                                th { (t.messages.standings_team()) }
+                                th style="text-align: right;" { (t.messages.standings_games()) }
+                                th style="text-align: right;" { (t.messages.standings_wins()) }
+                                @if overtime_columns {
+                                    th style="text-align: right;" { (t.messages.standings_overtime_wins()) }
+                                }
+                                th style="text-align: right;" { (t.messages.standings_ties()) }
+                                @if overtime_columns {
+                                    th style="text-align: right;" { (t.messages.standings_overtime_losses()) }
+                                }
+                                th style="text-align: right;" { (t.messages.standings_losses()) }
+                                th style="text-align: right;" { (t.messages.standings_goals()) }
+                                th style="text-align: right;" { (t.messages.standings_goal_difference()) }
+                                th style="text-align: right;" { (t.messages.standings_points()) }
+                                th { (t.messages.team_form_title()) }
+                            }
+                        }
+                        tbody {
+                            @for (index, row) in rows.iter().enumerate() {
+                                tr {
+                                    td { (index + 1) }
+                                    td {
+                                        a href=(format!("/teams/{}", row.team_id)) class="primary-link" {
+                                            (row.team_name)
+                                        }
+                                    }
+                                    td style="text-align: right;" { (row.games) }
+                                    td style="text-align: right;" { (row.wins) }
+                                    @if overtime_columns {
+                                        td style="text-align: right;" { (row.overtime_wins) }
+                                    }
+                                    td style="text-align: right;" { (row.ties) }
+                                    @if overtime_columns {
+                                        td style="text-align: right;" { (row.overtime_losses) }
+                                    }
+                                    td style="text-align: right;" { (row.losses) }
+                                    td style="text-align: right;" { (format!("{}:{}", row.goals_for, row.goals_against)) }
+                                    td style="text-align: right;" { (format!("{:+}", row.goal_difference())) }
+                                    td style="text-align: right; font-weight: 700;" { (row.points()) }
+                                    td {
+                                        @if let Some(team_form) = form.get(&row.team_id) {
+                                            (form_strip(t, team_form))
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Modal form to create a group in a season
+pub fn group_create_modal(
+    session: &Session,
+    t: &TranslationContext,
+    season_id: i64,
+    error: Option<&str>,
+) -> Markup {
+    let form_fields = html! {
+        (csrf_token_field(&session.csrf_token))
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.groups_name())
+                span class="required-indicator" { " *" }
+            }
+            input
+                type="text"
+                name="name"
+                required
+                autofocus
+                maxlength="255"
+                placeholder=(t.messages.groups_name_placeholder());
+        }
+    };
+
+    modal_form_i18n(
+        "group-modal",
+        &t.messages.groups_create_title().to_string(),
+        error,
+        &format!("/seasons/{}/groups", season_id),
+        form_fields,
+        &t.messages.common_add().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
@@ -2,6 +2,7 @@ use maud::{html, Markup};
 
 use crate::auth::Session;
 use crate::i18n::TranslationContext;
+use crate::service::season_clone::SeasonCloneSummary;
 use crate::service::seasons::{PagedResult, SeasonEntity, SeasonFilters, SortField, SortOrder};
 use crate::views::components::crud::{
     empty_state, modal_form, page_header, pagination, table_actions,
@@ -564,3 +565,76 @@ pub fn season_edit_modal(
         &t.messages.seasons_edit_submit().to_string(),
     )
 }
+
+/// Clone season modal: the new season's year and name, and whether rosters come along
+#[allow(clippy::too_many_arguments)]
+pub fn season_clone_modal(
+    session: &Session,
+    t: &TranslationContext,
+    season: &SeasonEntity,
+    summary: &SeasonCloneSummary,
+    year: i64,
+    display_name: Option<&str>,
+    copy_rosters: bool,
+    error: Option<&str>,
+) -> Markup {
+    let form_fields = html! {
+        (csrf_token_field(&session.csrf_token))
+
+        p style="color: var(--gray-600); margin-bottom: 1rem;" {
+            (t.messages.seasons_clone_description())
+        }
+
+        div style="margin-bottom: 1rem;" {
+            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                (t.messages.seasons_year_label())
+                span style="color: red;" { "*" }
+            }
+            input
+                type="number"
+                name="year"
+                value=(year)
+                required
+                autofocus
+                min="1900"
+                max="2100"
+                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
+        }
+
+        div style="margin-bottom: 1rem;" {
+            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                (t.messages.seasons_display_name_label())
+            }
+            input
+                type="text"
+                name="display_name"
+                value=[display_name]
+                placeholder=(t.messages.seasons_display_name_placeholder())
+                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
+        }
+
+        div style="margin-bottom: 1rem; padding: 0.75rem; background: var(--gray-50); border-radius: 4px;" {
+            div { (t.messages.seasons_clone_teams()) ": " strong { (summary.teams) } }
+            label style="display: flex; align-items: center; gap: 0.5rem; margin-top: 0.5rem; cursor: pointer;" {
+                input type="checkbox" name="copy_rosters" value="true" checked[copy_rosters];
+                span {
+                    (t.messages.seasons_clone_copy_rosters())
+                    " (" (summary.players) ")"
+                }
+            }
+        }
+
+        p style="font-size: 0.75rem; color: var(--gray-500); margin-bottom: 1.5rem;" {
+            (t.messages.seasons_clone_not_copied())
+        }
+    };
+
+    modal_form(
+        "season-modal",
+        &t.messages.seasons_clone_title().to_string(),
+        error,
+        &format!("/seasons/{}/clone", season.id),
+        form_fields,
+        &t.messages.seasons_clone_submit().to_string(),
+    )
+}
@@ -0,0 +1,234 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::chat_webhooks::{ChatWebhookEntity, ChatWebhookKind};
+use crate::service::matches::STATUS_FILTERS;
+use crate::service::settings::{DefaultSeason, MatchListDefaults};
+use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
+use crate::views::components::forms::csrf_token_field;
+use crate::views::pages::matches::status_filter_label;
+
+const SELECT_STYLE: &str =
+    "width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
+
+/// Instance-wide settings: default filters of list views and chat webhooks
+pub fn settings_page(
+    session: &Session,
+    t: &TranslationContext,
+    match_defaults: &MatchListDefaults,
+    seasons: &[(i64, String)],
+    webhooks: &[ChatWebhookEntity],
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="display: flex; align-items: center; gap: 1rem; margin-bottom: 1.5rem;" {
+                a href="/management" class="btn btn-secondary" {
+                    (format!("← {}", t.messages.management_title()))
+                }
+                h1 style="font-size: 2rem; font-weight: 700; margin: 0;" {
+                    (t.messages.settings_title())
+                }
+            }
+
+            form hx-post="/management/settings" hx-swap="none" {
+                (csrf_token_field(&session.csrf_token))
+
+                h2 style="font-size: 1.25rem; font-weight: 600; margin-bottom: 0.5rem;" {
+                    (t.messages.settings_match_defaults())
+                }
+                p style="color: var(--gray-600); margin-bottom: 1rem;" {
+                    (t.messages.settings_match_defaults_hint())
+                }
+
+                div style="display: grid; grid-template-columns: repeat(2, minmax(0, 20rem)); gap: 1rem; margin-bottom: 1.5rem;" {
+                    div class="form-group" {
+                        label class="form-label" for="matches_default_season" {
+                            (t.messages.matches_filter_season())
+                        }
+                        select id="matches_default_season" name="matches_default_season" style=(SELECT_STYLE) {
+                            option value="" { (t.messages.matches_all_seasons()) }
+                            option
+                                value=(DefaultSeason::Current.as_string())
+                                selected[match_defaults.season == Some(DefaultSeason::Current)]
+                            {
+                                (t.messages.settings_current_season())
+                            }
+                            @for (id, name) in seasons {
+                                option
+                                    value=(id)
+                                    selected[match_defaults.season == Some(DefaultSeason::Season(*id))]
+                                {
+                                    (name)
+                                }
+                            }
+                        }
+                    }
+                    div class="form-group" {
+                        label class="form-label" for="matches_default_status" {
+                            (t.messages.matches_filter_status())
+                        }
+                        select id="matches_default_status" name="matches_default_status" style=(SELECT_STYLE) {
+                            option value="" { (t.messages.matches_all_statuses()) }
+                            @for status in STATUS_FILTERS {
+                                option
+                                    value=(status)
+                                    selected[match_defaults.status.as_deref() == Some(status)]
+                                {
+                                    (status_filter_label(t, status))
+                                }
+                            }
+                        }
+                    }
+                }
+
+                button type="submit" class="btn btn-primary" {
+                    (t.messages.common_save())
+                }
+            }
+
+            (chat_webhooks_section(session, t, webhooks, None))
+        }
+    }
+}
+
+/// Chat webhooks with their notification toggles and a form to add one
+pub fn chat_webhooks_section(
+    session: &Session,
+    t: &TranslationContext,
+    webhooks: &[ChatWebhookEntity],
+    error: Option<&str>,
+) -> Markup {
+    html! {
+        section id="chat-webhooks" style="margin-top: 2.5rem;" {
+            h2 style="font-size: 1.25rem; font-weight: 600; margin-bottom: 0.5rem;" {
+                (t.messages.chat_webhooks_title())
+            }
+            p style="color: var(--gray-600); margin-bottom: 1rem;" {
+                (t.messages.chat_webhooks_hint())
+            }
+
+            @if webhooks.is_empty() {
+                p style="color: var(--gray-500); margin-bottom: 1rem;" {
+                    (t.messages.chat_webhooks_empty())
+                }
+            } @else {
+                table class="table" style="margin-bottom: 1.5rem;" {
+                    thead {
+                        tr {
+                            th { (t.messages.chat_webhooks_name()) }
+                            th { (t.messages.chat_webhooks_service()) }
+                            th { (t.messages.chat_webhooks_notifications()) }
+                            th { (t.messages.common_actions()) }
+                        }
+                    }
+                    tbody {
+                        @for webhook in webhooks {
+                            tr {
+                                td {
+                                    div style="font-weight: 500;" { (webhook.name) }
+                                    div style="color: var(--gray-500); font-size: 0.75rem;" { (webhook.masked_url()) }
+                                }
+                                td { (webhook.kind().map_or(webhook.kind.as_str(), |kind| kind.label())) }
+                                td {
+                                    form
+                                        hx-post=(format!("/management/settings/chat-webhooks/{}", webhook.id))
+                                        hx-trigger="change"
+                                        hx-target="#chat-webhooks"
+                                        hx-swap="outerHTML"
+                                        style="display: flex; gap: 1rem;"
+                                    {
+                                        (csrf_token_field(&session.csrf_token))
+                                        label class="checkbox-label" {
+                                            input type="checkbox" name="notify_results" value="true" checked[webhook.notify_results];
+                                            span class="checkbox-text" { (t.messages.chat_webhooks_results()) }
+                                        }
+                                        label class="checkbox-label" {
+                                            input type="checkbox" name="notify_milestones" value="true" checked[webhook.notify_milestones];
+                                            span class="checkbox-text" { (t.messages.chat_webhooks_milestones()) }
+                                        }
+                                    }
+                                }
+                                td {
+                                    form style="display: inline-flex; gap: 0.5rem;" {
+                                        (csrf_token_field(&session.csrf_token))
+                                        button
+                                            type="submit"
+                                            class="btn btn-sm btn-secondary"
+                                            hx-post=(format!("/management/settings/chat-webhooks/{}/test", webhook.id))
+                                            hx-swap="none"
+                                            hx-disabled-elt="this"
+                                        {
+                                            (t.messages.chat_webhooks_send_test())
+                                        }
+                                        button
+                                            type="submit"
+                                            class="btn btn-sm btn-danger"
+                                            hx-post=(format!("/management/settings/chat-webhooks/{}/delete", webhook.id))
+                                            hx-target="#chat-webhooks"
+                                            hx-swap="outerHTML"
+                                            hx-confirm-custom=(confirm_attrs(
+                                                &format!("{} \"{}\"", t.messages.common_delete(), webhook.name),
+                                                &t.messages.chat_webhooks_confirm_delete().to_string(),
+                                                ConfirmVariant::Danger,
+                                                Some(&t.messages.common_delete().to_string()),
+                                                Some(&t.messages.common_cancel().to_string())
+                                            ))
+                                        {
+                                            (t.messages.common_delete())
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            h3 style="font-size: 1rem; font-weight: 600; margin-bottom: 0.5rem;" {
+                (t.messages.chat_webhooks_add())
+            }
+            @if let Some(error) = error {
+                div class="error" style="padding: 0.75rem; margin-bottom: 1rem;" { (error) }
+            }
+            form
+                hx-post="/management/settings/chat-webhooks"
+                hx-target="#chat-webhooks"
+                hx-swap="outerHTML"
+            {
+                (csrf_token_field(&session.csrf_token))
+                div style="display: grid; grid-template-columns: minmax(0, 14rem) minmax(0, 10rem) minmax(0, 1fr); gap: 1rem; margin-bottom: 1rem;" {
+                    div class="form-group" {
+                        label class="form-label" for="webhook_name" { (t.messages.chat_webhooks_name()) }
+                        input type="text" id="webhook_name" name="name" required maxlength="100";
+                    }
+                    div class="form-group" {
+                        label class="form-label" for="webhook_kind" { (t.messages.chat_webhooks_service()) }
+                        select id="webhook_kind" name="kind" style=(SELECT_STYLE) {
+                            @for kind in ChatWebhookKind::ALL {
+                                option value=(kind.as_str()) { (kind.label()) }
+                            }
+                        }
+                    }
+                    div class="form-group" {
+                        label class="form-label" for="webhook_url" { (t.messages.chat_webhooks_url()) }
+                        input type="url" id="webhook_url" name="url" required placeholder="https://";
+                    }
+                }
+                div style="display: flex; gap: 1.5rem; align-items: center; margin-bottom: 1rem;" {
+                    label class="checkbox-label" {
+                        input type="checkbox" name="notify_results" value="true" checked;
+                        span class="checkbox-text" { (t.messages.chat_webhooks_results()) }
+                    }
+                    label class="checkbox-label" {
+                        input type="checkbox" name="notify_milestones" value="true" checked;
+                        span class="checkbox-text" { (t.messages.chat_webhooks_milestones()) }
+                    }
+                }
+                button type="submit" class="btn btn-primary" {
+                    (t.messages.chat_webhooks_add())
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use maud::{html, Markup, PreEscaped};
+
+use crate::service::sitemap::SitemapEntry;
+
+/// Sitemap protocol document with absolute URLs below `base_url`
+pub fn sitemap_xml(base_url: &str, entries: &[SitemapEntry]) -> Markup {
+    html! {
+        (PreEscaped(r#"<?xml version="1.0" encoding="UTF-8"?>"#))
+        urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" {
+            @for entry in entries {
+                url {
+                    loc { (base_url) (entry.path) }
+                    @if let Some(last_modified) = &entry.last_modified {
+                        lastmod { (last_modified) }
+                    }
+                }
+            }
+        }
+    }
+}
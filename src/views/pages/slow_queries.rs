@@ -0,0 +1,142 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::slow_queries::{SlowQuery, SlowQuerySummary};
+use crate::views::components::crud::empty_state_i18n;
+use crate::views::components::forms::csrf_token_field;
+
+const STATEMENT_STYLE: &str = "font-family: monospace; font-size: 0.8125rem; white-space: pre-wrap; word-break: break-word; margin: 0; max-height: 8rem; overflow: auto;";
+
+/// Slow query panel: statements over the threshold, grouped and recent
+pub fn slow_queries_page(
+    session: &Session,
+    t: &TranslationContext,
+    threshold_ms: u64,
+    summary: &[SlowQuerySummary],
+    recent: &[SlowQuery],
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
+                div {
+                    h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                        (t.messages.slow_queries_title())
+                    }
+                    p style="color: var(--gray-600);" {
+                        (t.messages.slow_queries_description())
+                        " "
+                        @if threshold_ms > 0 {
+                            strong { (format!("{} {} ms", t.messages.slow_queries_threshold(), threshold_ms)) }
+                        } @else {
+                            strong { (t.messages.slow_queries_disabled()) }
+                        }
+                    }
+                }
+                form style="display: inline;" {
+                    (csrf_token_field(&session.csrf_token))
+                    button
+                        type="submit"
+                        class="btn btn-secondary"
+                        hx-post="/management/slow-queries/clear"
+                        hx-target="#slow-queries"
+                        hx-swap="outerHTML"
+                    {
+                        (t.messages.slow_queries_clear())
+                    }
+                }
+            }
+
+            (slow_queries_content(t, summary, recent))
+        }
+    }
+}
+
+/// Summary and recent tables (also returned after clearing)
+pub fn slow_queries_content(
+    t: &TranslationContext,
+    summary: &[SlowQuerySummary],
+    recent: &[SlowQuery],
+) -> Markup {
+    html! {
+        div id="slow-queries" {
+            @if summary.is_empty() {
+                (empty_state_i18n(
+                    &t.messages.slow_queries_empty_title().to_string(),
+                    &t.messages.slow_queries_empty_message().to_string(),
+                    false
+                ))
+            } @else {
+                h2 style="font-size: 1.5rem; font-weight: 700; margin-bottom: 1rem;" {
+                    (t.messages.slow_queries_by_statement())
+                }
+                table class="table" style="margin-bottom: 2rem;" {
+                    thead {
+                        tr {
+                            th { (t.messages.slow_queries_statement()) }
+                            th style="text-align: right;" { (t.messages.slow_queries_count()) }
+                            th style="text-align: right;" { (t.messages.slow_queries_avg()) }
+                            th style="text-align: right;" { (t.messages.slow_queries_max()) }
+                            th { (t.messages.slow_queries_last_route()) }
+                        }
+                    }
+                    tbody {
+                        @for group in summary {
+                            tr {
+                                td { pre style=(STATEMENT_STYLE) { (group.statement) } }
+                                td style="text-align: right;" { (group.count) }
+                                td style="text-align: right; white-space: nowrap;" { (format_ms(group.avg_ms())) }
+                                td style="text-align: right; white-space: nowrap;" { (format_ms(group.max_ms)) }
+                                td style="font-size: 0.875rem;" {
+                                    (route_label(t, group.last_route.as_deref()))
+                                    div style="color: var(--gray-500); font-size: 0.75rem;" {
+                                        (group.last_seen.format("%Y-%m-%d %H:%M:%S UTC"))
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                h2 style="font-size: 1.5rem; font-weight: 700; margin-bottom: 1rem;" {
+                    (t.messages.slow_queries_recent())
+                }
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.slow_queries_time()) }
+                            th { (t.messages.slow_queries_route()) }
+                            th style="text-align: right;" { (t.messages.slow_queries_duration()) }
+                            th style="text-align: right;" { (t.messages.slow_queries_rows()) }
+                            th { (t.messages.slow_queries_statement()) }
+                        }
+                    }
+                    tbody {
+                        @for query in recent {
+                            tr {
+                                td style="white-space: nowrap;" { (query.recorded_at.format("%H:%M:%S")) }
+                                td style="font-size: 0.875rem;" { (route_label(t, query.route.as_deref())) }
+                                td style="text-align: right; white-space: nowrap;" { (format_ms(query.elapsed_ms)) }
+                                td style="text-align: right;" { (query.rows_returned.max(query.rows_affected)) }
+                                td { pre style=(STATEMENT_STYLE) { (query.statement) } }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn route_label(t: &TranslationContext, route: Option<&str>) -> Markup {
+    html! {
+        @match route {
+            Some(route) => code { (route) },
+            None => span style="color: var(--gray-500);" { (t.messages.slow_queries_background()) },
+        }
+    }
+}
+
+fn format_ms(ms: f64) -> String {
+    format!("{:.1} ms", ms)
+}
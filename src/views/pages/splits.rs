@@ -0,0 +1,197 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::players::PlayerEntity;
+use crate::service::splits::{
+    player_split_totals, team_split_totals, PlayerSeasonSplitEntity, PointsSplit,
+    TeamSeasonSplitEntity, VenueRecord,
+};
+use crate::service::teams::TeamEntity;
+
+const NUMBER_CELL: &str = "text-align: right;";
+const TOTAL_ROW: &str = "font-weight: 700; border-top: 2px solid var(--gray-300);";
+
+fn season_title(display_name: &Option<String>, event_name: &str, year: i64) -> String {
+    display_name
+        .clone()
+        .unwrap_or_else(|| format!("{} {}", event_name, year))
+}
+
+fn page_header(t: &TranslationContext, back_url: &str, back_label: &str, hint: String) -> Markup {
+    html! {
+        div style="display: flex; align-items: center; gap: 1rem; margin-bottom: 0.5rem;" {
+            a href=(back_url) class="btn btn-secondary" {
+                (format!("← {}", back_label))
+            }
+            h1 style="font-size: 2rem; font-weight: 700; margin: 0;" {
+                (t.messages.splits_title())
+            }
+        }
+        p style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 1.5rem;" {
+            (hint)
+        }
+    }
+}
+
+fn venue_record_cells(record: &VenueRecord) -> Markup {
+    html! {
+        td style=(NUMBER_CELL) { (record.games) }
+        td style=(NUMBER_CELL) { (record.wins) }
+        td style=(NUMBER_CELL) { (record.ties) }
+        td style=(NUMBER_CELL) { (record.losses) }
+        td style=(NUMBER_CELL) { (format!("{}:{}", record.goals_for, record.goals_against)) }
+        td style=(NUMBER_CELL) { (format!("{:+}", record.goal_difference())) }
+    }
+}
+
+fn venue_rows(
+    t: &TranslationContext,
+    title: Markup,
+    home: &VenueRecord,
+    away: &VenueRecord,
+    row_style: Option<&str>,
+) -> Markup {
+    html! {
+        tr style=[row_style] {
+            td rowspan="2" style="vertical-align: top;" { (title) }
+            td { (t.messages.splits_home()) }
+            (venue_record_cells(home))
+        }
+        tr {
+            td { (t.messages.splits_away()) }
+            (venue_record_cells(away))
+        }
+    }
+}
+
+/// Home and away records of a team per season
+pub fn team_splits_page(
+    t: &TranslationContext,
+    team: &TeamEntity,
+    splits: &[TeamSeasonSplitEntity],
+) -> Markup {
+    let (home_total, away_total) = team_split_totals(splits);
+
+    html! {
+        div class="card" {
+            (page_header(
+                t,
+                &format!("/teams/{}", team.id),
+                &team.name,
+                t.messages.splits_team_hint().to_string()
+            ))
+
+            @if splits.is_empty() {
+                p style="color: var(--gray-500);" { (t.messages.splits_team_empty()) }
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.team_stats_season()) }
+                            th { (t.messages.splits_venue()) }
+                            th style=(NUMBER_CELL) { (t.messages.standings_games()) }
+                            th style=(NUMBER_CELL) { (t.messages.standings_wins()) }
+                            th style=(NUMBER_CELL) { (t.messages.standings_ties()) }
+                            th style=(NUMBER_CELL) { (t.messages.standings_losses()) }
+                            th style=(NUMBER_CELL) { (t.messages.standings_goals()) }
+                            th style=(NUMBER_CELL) { (t.messages.standings_goal_difference()) }
+                        }
+                    }
+                    tbody {
+                        @for split in splits {
+                            (venue_rows(
+                                t,
+                                html! {
+                                    a href=(format!("/seasons/{}", split.season_id)) class="primary-link" {
+                                        (season_title(&split.season_display_name, &split.event_name, split.season_year))
+                                    }
+                                },
+                                &split.home,
+                                &split.away,
+                                None
+                            ))
+                        }
+                        @if splits.len() > 1 {
+                            (venue_rows(
+                                t,
+                                html! { (t.messages.splits_total()) },
+                                &home_total,
+                                &away_total,
+                                Some(TOTAL_ROW)
+                            ))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn points_cells(split: &PointsSplit) -> Markup {
+    html! {
+        td style=(NUMBER_CELL) { (split.goals) }
+        td style=(NUMBER_CELL) { (split.assists) }
+        td style=(format!("{} font-weight: 600;", NUMBER_CELL)) { (split.points()) }
+    }
+}
+
+/// Goals, assists and points of a player at home and away per season
+pub fn player_splits_page(
+    t: &TranslationContext,
+    player: &PlayerEntity,
+    splits: &[PlayerSeasonSplitEntity],
+) -> Markup {
+    let (home_total, away_total) = player_split_totals(splits);
+
+    html! {
+        div class="card" {
+            (page_header(
+                t,
+                &format!("/players/{}", player.id),
+                &player.name,
+                t.messages.splits_player_hint().to_string()
+            ))
+
+            @if splits.is_empty() {
+                p style="color: var(--gray-500);" { (t.messages.splits_player_empty()) }
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th rowspan="2" { (t.messages.team_stats_season()) }
+                            th colspan="3" style="text-align: center;" { (t.messages.splits_home()) }
+                            th colspan="3" style="text-align: center;" { (t.messages.splits_away()) }
+                        }
+                        tr {
+                            @for _ in 0..2 {
+                                th style=(NUMBER_CELL) { (t.messages.leaders_goals_short()) }
+                                th style=(NUMBER_CELL) { (t.messages.leaders_assists_short()) }
+                                th style=(NUMBER_CELL) { (t.messages.leaders_points_short()) }
+                            }
+                        }
+                    }
+                    tbody {
+                        @for split in splits {
+                            tr {
+                                td {
+                                    a href=(format!("/seasons/{}", split.season_id)) class="primary-link" {
+                                        (season_title(&split.season_display_name, &split.event_name, split.season_year))
+                                    }
+                                }
+                                (points_cells(&split.home))
+                                (points_cells(&split.away))
+                            }
+                        }
+                        @if splits.len() > 1 {
+                            tr style=(TOTAL_ROW) {
+                                td { (t.messages.splits_total()) }
+                                (points_cells(&home_total))
+                                (points_cells(&away_total))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,392 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::common::pagination::PagedResult;
+use crate::i18n::TranslationContext;
+use crate::service::staff::{SortField, SortOrder, StaffFilters, StaffMemberEntity, StaffRole};
+use crate::views::components::crud::{
+    empty_state, modal_form, page_header, pagination, table_actions,
+};
+use crate::views::components::forms::csrf_token_field;
+
+/// Main staff page with table and filters
+pub fn staff_page(
+    session: &Session,
+    t: &TranslationContext,
+    result: &PagedResult<StaffMemberEntity>,
+    filters: &StaffFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+    countries: &[(i64, String)],
+) -> Markup {
+    html! {
+        div class="card" {
+            (page_header(
+                &t.messages.staff_title().to_string(),
+                &t.messages.staff_description().to_string(),
+                "/staff/new",
+                &t.messages.staff_create().to_string(),
+            ))
+
+            // Filters
+            div class="filters-container" {
+                form hx-get="/staff/list" hx-target="#staff-table" hx-swap="outerHTML" hx-trigger="submit, change delay:300ms" {
+                    div class="filters-grid" {
+                        // Name filter
+                        div {
+                            label class="filter-label" {
+                                (t.messages.common_search_by_name())
+                            }
+                            input
+                                type="text"
+                                name="name"
+                                value=[filters.name.as_ref()]
+                                placeholder=(t.messages.staff_name_placeholder())
+                                class="filter-input";
+                        }
+
+                        // Country filter
+                        div {
+                            label class="filter-label" {
+                                (t.messages.common_filter_by_country())
+                            }
+                            select
+                                name="country_id"
+                                class="filter-select"
+                            {
+                                option value="" { (t.messages.common_all_countries()) }
+                                @for (id, name) in countries {
+                                    option
+                                        value=(id)
+                                        selected[filters.country_id == Some(*id)]
+                                    {
+                                        (name)
+                                    }
+                                }
+                            }
+                        }
+
+                        // Clear button
+                        div {
+                            button
+                                type="button"
+                                class="btn btn-secondary"
+                                hx-get="/staff/list"
+                                hx-target="#staff-table"
+                                hx-swap="outerHTML"
+                            {
+                                (t.messages.common_clear())
+                            }
+                        }
+                    }
+                }
+            }
+
+            // Table
+            (staff_list_content(session, t, result, filters, sort_field, sort_order))
+
+            // Modal container
+            div id="modal-container" {}
+        }
+    }
+}
+
+/// Staff table content (for HTMX updates)
+pub fn staff_list_content(
+    session: &Session,
+    t: &TranslationContext,
+    result: &PagedResult<StaffMemberEntity>,
+    filters: &StaffFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+) -> Markup {
+    html! {
+        div id="staff-table" class="loading-overlay" {
+            // Loading spinner overlay
+            div class="loading-spinner-overlay" {
+                hockey-loading-spinner size="lg" {}
+            }
+
+            @if result.items.is_empty() {
+                (empty_state(
+                    &t.messages.staff_entity().to_string(),
+                    filters.name.is_some() || filters.country_id.is_some(),
+                    Some("/staff/new"),
+                    Some(&t.messages.staff_create().to_string()),
+                ))
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th {
+                                (sortable_header(
+                                    &t.messages.common_id().to_string(),
+                                    &SortField::Id,
+                                    sort_field,
+                                    sort_order,
+                                    filters,
+                                ))
+                            }
+                            th {
+                                (sortable_header(
+                                    &t.messages.form_name().to_string(),
+                                    &SortField::Name,
+                                    sort_field,
+                                    sort_order,
+                                    filters,
+                                ))
+                            }
+                            th {
+                                (sortable_header(
+                                    &t.messages.form_country().to_string(),
+                                    &SortField::Country,
+                                    sort_field,
+                                    sort_order,
+                                    filters,
+                                ))
+                            }
+                            th {
+                                (sortable_header(
+                                    &t.messages.staff_contracts().to_string(),
+                                    &SortField::Contracts,
+                                    sort_field,
+                                    sort_order,
+                                    filters,
+                                ))
+                            }
+                            th class="text-right" { (t.messages.common_actions()) }
+                        }
+                    }
+                    tbody {
+                        @for member in &result.items {
+                            tr {
+                                td { (member.id) }
+                                td { (member.name) }
+                                td {
+                                    @if let Some(country_name) = &member.country_name {
+                                        @if let Some(iso2) = &member.country_iso2_code {
+                                            span class="flag-display" {
+                                                img
+                                                    src=(format!("https://flagcdn.com/w40/{}.png", iso2.to_lowercase()))
+                                                    alt=(country_name)
+                                                    class="flag-image"
+                                                    onerror="this.style.display='none'";
+                                                (country_name)
+                                            }
+                                        } @else {
+                                            (country_name)
+                                        }
+                                    } @else {
+                                        span class="no-value-text" { (t.messages.common_no_country()) }
+                                    }
+                                }
+                                td { (member.contract_count) }
+                                (table_actions(
+                                    &format!("/staff/{}/edit", member.id),
+                                    &build_delete_url(member.id, filters, sort_field, sort_order),
+                                    "staff-table",
+                                    &t.messages.staff_entity().to_string(),
+                                    &session.csrf_token
+                                ))
+                            }
+                        }
+                    }
+                }
+
+                // Pagination
+                (pagination(
+                    result,
+                    "staff",
+                    |page| build_pagination_url(page, result.page_size, filters, sort_field, sort_order),
+                    "staff-table"
+                ))
+            }
+        }
+    }
+}
+
+/// Sortable table header
+fn sortable_header(
+    label: &str,
+    field: &SortField,
+    current_sort: &SortField,
+    current_order: &SortOrder,
+    filters: &StaffFilters,
+) -> Markup {
+    let is_active = field == current_sort;
+
+    // If this column is active, toggle the order; otherwise default to ASC
+    let next_order = if is_active {
+        current_order.toggle()
+    } else {
+        SortOrder::Asc
+    };
+
+    let url = build_sort_url(field, &next_order, filters);
+
+    let indicator = if is_active {
+        match current_order {
+            SortOrder::Asc => "↑",
+            SortOrder::Desc => "↓",
+        }
+    } else {
+        "↕"
+    };
+
+    html! {
+        button
+            class="sort-button"
+            hx-get=(url)
+            hx-target="#staff-table"
+            hx-swap="outerHTML"
+        {
+            (label)
+            span class=(if is_active { "sort-indicator active" } else { "sort-indicator" }) {
+                (indicator)
+            }
+        }
+    }
+}
+
+/// Append the active filters to a list URL
+fn push_filters(url: &mut String, filters: &StaffFilters) {
+    if let Some(name) = &filters.name {
+        url.push_str(&format!("&name={}", urlencoding::encode(name)));
+    }
+
+    if let Some(country_id) = filters.country_id {
+        url.push_str(&format!("&country_id={}", country_id));
+    }
+}
+
+/// Helper to build sort URLs
+fn build_sort_url(field: &SortField, order: &SortOrder, filters: &StaffFilters) -> String {
+    let mut url = format!(
+        "/staff/list?sort={}&order={}",
+        field.as_str(),
+        order.as_str()
+    );
+    push_filters(&mut url, filters);
+    url
+}
+
+/// Helper to build pagination URLs with filters and sorting
+fn build_pagination_url(
+    page: usize,
+    page_size: usize,
+    filters: &StaffFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+) -> String {
+    let mut url = format!(
+        "/staff/list?page={}&page_size={}&sort={}&order={}",
+        page,
+        page_size,
+        sort_field.as_str(),
+        sort_order.as_str()
+    );
+    push_filters(&mut url, filters);
+    url
+}
+
+/// Helper to build delete URL with current filters and sorting
+fn build_delete_url(
+    staff_member_id: i64,
+    filters: &StaffFilters,
+    sort_field: &SortField,
+    sort_order: &SortOrder,
+) -> String {
+    let mut url = format!(
+        "/staff/{}/delete?sort={}&order={}",
+        staff_member_id,
+        sort_field.as_str(),
+        sort_order.as_str()
+    );
+    push_filters(&mut url, filters);
+    url
+}
+
+/// Shared fields of the create and edit modals
+fn staff_member_form_fields(
+    session: &Session,
+    t: &TranslationContext,
+    member: Option<&StaffMemberEntity>,
+) -> Markup {
+    html! {
+        (csrf_token_field(&session.csrf_token))
+
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.staff_name_label())
+                span class="required-indicator" { "*" }
+            }
+            input
+                type="text"
+                name="name"
+                value=[member.map(|m| m.name.as_str())]
+                placeholder=(t.messages.staff_name_placeholder())
+                required
+                autofocus;
+        }
+
+        div class="form-group" {
+            label class="form-label" {
+                (t.messages.form_country())
+            }
+            @if let Some(country_id) = member.and_then(|m| m.country_id) {
+                country-selector
+                    name="country_id"
+                    placeholder=(t.messages.teams_select_country())
+                    value=(country_id)
+                    enabled-only;
+            } @else {
+                country-selector
+                    name="country_id"
+                    placeholder=(t.messages.teams_select_country())
+                    enabled-only;
+            }
+        }
+    }
+}
+
+/// Create staff member modal
+pub fn staff_member_create_modal(
+    session: &Session,
+    t: &TranslationContext,
+    error: Option<&str>,
+) -> Markup {
+    modal_form(
+        "staff-member-modal",
+        &t.messages.staff_create_title().to_string(),
+        error,
+        "/staff",
+        staff_member_form_fields(session, t, None),
+        &t.messages.staff_create_submit().to_string(),
+    )
+}
+
+/// Edit staff member modal
+pub fn staff_member_edit_modal(
+    session: &Session,
+    t: &TranslationContext,
+    member: &StaffMemberEntity,
+    error: Option<&str>,
+) -> Markup {
+    modal_form(
+        "staff-member-modal",
+        &t.messages.staff_edit_title().to_string(),
+        error,
+        &format!("/staff/{}", member.id),
+        staff_member_form_fields(session, t, Some(member)),
+        &t.messages.common_save().to_string(),
+    )
+}
+
+/// Translated label of a staff role
+pub fn staff_role_label(t: &TranslationContext, role: StaffRole) -> String {
+    match role {
+        StaffRole::HeadCoach => t.messages.staff_role_head_coach().to_string(),
+        StaffRole::AssistantCoach => t.messages.staff_role_assistant_coach().to_string(),
+        StaffRole::GeneralManager => t.messages.staff_role_general_manager().to_string(),
+    }
+}
@@ -0,0 +1,188 @@
+use chrono::{DateTime, Utc};
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::maintenance::MaintenanceConfig;
+use crate::service::maintenance::{DatabaseInfo, MaintenanceRunEntity, MaintenanceTask};
+use crate::views::components::crud::empty_state_i18n;
+use crate::views::components::forms::csrf_token_field;
+
+/// System status page: database storage and maintenance job results
+///
+/// The snapshot download and manual maintenance run are offered to admins only.
+pub fn status_page(
+    session: &Session,
+    t: &TranslationContext,
+    info: &DatabaseInfo,
+    maintenance: &MaintenanceConfig,
+    next_run: DateTime<Utc>,
+    runs: &[MaintenanceRunEntity],
+    is_admin: bool,
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
+                div {
+                    h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                        (t.messages.status_title())
+                    }
+                    p style="color: var(--gray-600);" {
+                        (t.messages.status_description())
+                    }
+                }
+                @if is_admin {
+                    div style="display: flex; gap: 0.5rem;" {
+                        a href="/management/status/snapshot" class="btn btn-secondary" download {
+                            (t.messages.status_download_snapshot())
+                        }
+                        form style="display: inline;" {
+                            (csrf_token_field(&session.csrf_token))
+                            button
+                                type="submit"
+                                class="btn btn-primary"
+                                hx-post="/management/status/maintenance"
+                                hx-target="#maintenance-runs"
+                                hx-swap="outerHTML"
+                                hx-disabled-elt="this"
+                            {
+                                (t.messages.status_run_now())
+                            }
+                        }
+                    }
+                }
+            }
+
+            div style="display: grid; grid-template-columns: repeat(auto-fit, minmax(220px, 1fr)); gap: 1rem; margin-bottom: 2rem;" {
+                (status_tile(&t.messages.status_journal_mode().to_string(), &info.journal_mode.to_uppercase()))
+                (status_tile(&t.messages.status_database_size().to_string(), &format_bytes(info.size_bytes())))
+                (status_tile(&t.messages.status_free_space().to_string(), &format_bytes(info.free_bytes())))
+                (status_tile(
+                    &t.messages.status_next_run().to_string(),
+                    &next_run.format("%Y-%m-%d %H:%M UTC").to_string()
+                ))
+                (status_tile(
+                    &t.messages.status_backups().to_string(),
+                    &match &maintenance.backup_dir {
+                        Some(dir) if maintenance.backup_keep > 0 => {
+                            format!("{} ({} {})", dir.display(), maintenance.backup_keep, t.messages.status_backups_kept())
+                        }
+                        Some(dir) => dir.display().to_string(),
+                        None => t.messages.status_backups_disabled().to_string(),
+                    }
+                ))
+            }
+
+            h2 style="font-size: 1.5rem; font-weight: 700; margin-bottom: 1rem;" {
+                (t.messages.status_recent_runs())
+            }
+            (maintenance_runs_table(t, runs))
+        }
+    }
+}
+
+/// Recent maintenance task results (also returned after a manual run)
+pub fn maintenance_runs_table(t: &TranslationContext, runs: &[MaintenanceRunEntity]) -> Markup {
+    html! {
+        div id="maintenance-runs" {
+            @if runs.is_empty() {
+                (empty_state_i18n(
+                    &t.messages.status_no_runs_title().to_string(),
+                    &t.messages.status_no_runs_message().to_string(),
+                    false
+                ))
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.status_run_started()) }
+                            th { (t.messages.status_run_task()) }
+                            th { (t.messages.status_run_result()) }
+                            th style="text-align: right;" { (t.messages.status_run_duration()) }
+                            th { (t.messages.status_run_detail()) }
+                        }
+                    }
+                    tbody {
+                        @for run in runs {
+                            tr {
+                                td style="white-space: nowrap;" { (run.started_at) }
+                                td {
+                                    @match run.task() {
+                                        Some(task) => { (task_label(t, task)) }
+                                        None => { (run.task) }
+                                    }
+                                }
+                                td { (result_badge(t, run.success)) }
+                                td style="text-align: right; white-space: nowrap;" {
+                                    (format!("{} ms", run.duration_ms))
+                                }
+                                td style="color: var(--gray-600); font-size: 0.875rem;" {
+                                    @if let Some(detail) = &run.detail {
+                                        (detail)
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn status_tile(label: &str, value: &str) -> Markup {
+    html! {
+        div style="padding: 1rem; border: 1px solid var(--gray-200); border-radius: 8px; background: var(--gray-50);" {
+            div style="font-size: 0.875rem; color: var(--gray-600); margin-bottom: 0.25rem;" { (label) }
+            div style="font-size: 1.125rem; font-weight: 600; word-break: break-all;" { (value) }
+        }
+    }
+}
+
+fn task_label(t: &TranslationContext, task: MaintenanceTask) -> String {
+    match task {
+        MaintenanceTask::Checkpoint => t.messages.status_task_checkpoint().to_string(),
+        MaintenanceTask::Optimize => t.messages.status_task_optimize().to_string(),
+        MaintenanceTask::Backup => t.messages.status_task_backup().to_string(),
+    }
+}
+
+fn result_badge(t: &TranslationContext, success: bool) -> Markup {
+    let (label, colors) = if success {
+        (
+            t.messages.status_result_ok().to_string(),
+            "color: #15803d; background: #dcfce7;",
+        )
+    } else {
+        (
+            t.messages.status_result_failed().to_string(),
+            "color: #b91c1c; background: #fee2e2;",
+        )
+    };
+
+    html! {
+        span style=(format!(
+            "display: inline-block; padding: 0.25rem 0.75rem; border-radius: 9999px; font-size: 0.875rem; font-weight: 500; {}",
+            colors
+        )) {
+            (label)
+        }
+    }
+}
+
+/// Human-readable size, e.g. `1.4 MB`
+pub fn format_bytes(bytes: i64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
@@ -0,0 +1,125 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::streaks::{PlayerStreaksEntity, StreakLeaderEntity, StreakLeadersEntity};
+
+/// Players per kind of streak on the leaderboard
+pub const STREAK_LEADERS_LIMIT: usize = 25;
+
+/// Streak badges under the player name, nothing before the first game
+pub fn player_streaks_badges(t: &TranslationContext, streaks: &PlayerStreaksEntity) -> Markup {
+    html! {
+        @if streaks.games_played > 0 {
+            div style="display: flex; flex-wrap: wrap; align-items: center; gap: 0.5rem; margin: -0.75rem 0 1.5rem 0;" {
+                (streak_badge(
+                    &t.messages.streaks_points().to_string(),
+                    streaks.current_points,
+                    streaks.longest_points,
+                    &t.messages.streaks_longest().to_string(),
+                ))
+                (streak_badge(
+                    &t.messages.streaks_goals().to_string(),
+                    streaks.current_goals,
+                    streaks.longest_goals,
+                    &t.messages.streaks_longest().to_string(),
+                ))
+                a href="/players/streaks" class="primary-link" style="font-size: 0.875rem;" {
+                    (t.messages.streaks_leaderboard_link())
+                }
+            }
+        }
+    }
+}
+
+fn streak_badge(label: &str, current: i64, longest: i64, longest_label: &str) -> Markup {
+    let colors = if current > 0 {
+        "color: #c2410c; background: #ffedd5;"
+    } else {
+        "color: var(--gray-600); background: var(--gray-100);"
+    };
+
+    html! {
+        span style=(format!(
+            "display: inline-block; padding: 0.25rem 0.75rem; border-radius: 9999px; font-size: 0.875rem; font-weight: 500; {}",
+            colors
+        )) {
+            @if current > 0 { "🔥 " }
+            (label) ": " strong { (current) }
+            span style="opacity: 0.75;" { " · " (longest_label) " " (longest) }
+        }
+    }
+}
+
+/// Players with the longest current and all-time streaks
+pub fn streak_leaders_page(t: &TranslationContext, leaders: &StreakLeadersEntity) -> Markup {
+    let games = t.messages.streaks_games().to_string();
+
+    html! {
+        div class="card" {
+            div style="display: flex; align-items: center; gap: 1rem; margin-bottom: 0.5rem;" {
+                a href="/players" class="btn btn-secondary" {
+                    (format!("← {}", t.messages.players_back_to_list()))
+                }
+                h1 style="font-size: 2rem; font-weight: 700; margin: 0;" {
+                    (t.messages.streaks_title())
+                }
+            }
+            p style="color: var(--gray-600); margin-bottom: 1.5rem;" {
+                (t.messages.streaks_description())
+            }
+
+            @if leaders.longest_points.is_empty() {
+                p style="color: var(--gray-600);" { (t.messages.streaks_empty()) }
+            } @else {
+                div style="display: grid; grid-template-columns: repeat(auto-fit, minmax(16rem, 1fr)); gap: 1rem;" {
+                    (streak_table(&t.messages.streaks_current_points().to_string(), &games, &leaders.current_points))
+                    (streak_table(&t.messages.streaks_longest_points().to_string(), &games, &leaders.longest_points))
+                    (streak_table(&t.messages.streaks_current_goals().to_string(), &games, &leaders.current_goals))
+                    (streak_table(&t.messages.streaks_longest_goals().to_string(), &games, &leaders.longest_goals))
+                }
+            }
+        }
+    }
+}
+
+/// One ranked streak list; players tied on games share the rank
+fn streak_table(title: &str, column: &str, leaders: &[StreakLeaderEntity]) -> Markup {
+    let mut rank = 0;
+    let ranks: Vec<usize> = leaders
+        .iter()
+        .enumerate()
+        .map(|(index, leader)| {
+            if index == 0 || leaders[index - 1].games != leader.games {
+                rank = index + 1;
+            }
+            rank
+        })
+        .collect();
+
+    html! {
+        div {
+            h3 style="font-size: 1.125rem; font-weight: 600; margin: 0 0 0.5rem 0;" { (title) }
+            @if leaders.is_empty() {
+                p style="color: var(--gray-500); font-size: 0.875rem;" { "-" }
+            } @else {
+                table class="table" {
+                    tbody {
+                        @for (leader, rank) in leaders.iter().zip(ranks) {
+                            tr {
+                                td style="width: 1%; color: var(--gray-600);" { (rank) "." }
+                                td {
+                                    a href=(format!("/players/{}", leader.player_id)) class="primary-link" {
+                                        (leader.player_name)
+                                    }
+                                }
+                                td style="text-align: right; font-weight: 700;" title=(column) {
+                                    (leader.games)
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,422 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::tasks::{AssignableUserEntity, TaskEntity, TaskEntityType, TaskScope};
+use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
+use crate::views::components::crud::{empty_state_i18n, modal_form_i18n};
+use crate::views::components::forms::{
+    csrf_token_field, form_field, form_select, form_textarea, InputType,
+};
+
+/// Event sent by task mutations so every task list on the page reloads
+pub const TASKS_CHANGED_EVENT: &str = "tasks-changed";
+
+fn scope_label(t: &TranslationContext, scope: TaskScope) -> String {
+    match scope {
+        TaskScope::Mine => t.messages.tasks_scope_mine(),
+        TaskScope::Open => t.messages.tasks_scope_open(),
+        TaskScope::Completed => t.messages.tasks_scope_completed(),
+        TaskScope::All => t.messages.tasks_scope_all(),
+    }
+    .to_string()
+}
+
+fn list_url(scope: TaskScope, entity: Option<(TaskEntityType, i64)>) -> String {
+    match entity {
+        Some((entity_type, id)) => format!(
+            "/tasks/list?scope={}&entity_type={}&entity_id={}",
+            scope.as_str(),
+            entity_type.as_str(),
+            id
+        ),
+        None => format!("/tasks/list?scope={}", scope.as_str()),
+    }
+}
+
+fn new_task_url(entity: Option<(TaskEntityType, i64)>) -> String {
+    match entity {
+        Some((entity_type, id)) => format!(
+            "/tasks/new?entity_type={}&entity_id={}",
+            entity_type.as_str(),
+            id
+        ),
+        None => "/tasks/new".to_string(),
+    }
+}
+
+/// Task list page with scope filter
+pub fn tasks_page(
+    session: &Session,
+    t: &TranslationContext,
+    tasks: &[TaskEntity],
+    scope: TaskScope,
+    today: &str,
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
+                div {
+                    h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                        (t.messages.tasks_title())
+                    }
+                    p style="color: var(--gray-600);" {
+                        (t.messages.tasks_description())
+                    }
+                }
+                button
+                    class="btn btn-primary"
+                    hx-get=(new_task_url(None))
+                    hx-target="#modal-container"
+                    hx-swap="innerHTML"
+                {
+                    "+ " (t.messages.tasks_create())
+                }
+            }
+
+            div style="display: flex; gap: 0.5rem; margin-bottom: 1rem;" {
+                @for option in TaskScope::ALL {
+                    a
+                        href=(format!("/tasks?scope={}", option.as_str()))
+                        class=(if option == scope { "btn btn-sm btn-primary" } else { "btn btn-sm btn-secondary" })
+                    {
+                        (scope_label(t, option))
+                    }
+                }
+            }
+
+            (tasks_list(session, t, tasks, scope, None, today))
+        }
+    }
+}
+
+/// Tasks table, reloaded whenever a task changes
+pub fn tasks_list(
+    session: &Session,
+    t: &TranslationContext,
+    tasks: &[TaskEntity],
+    scope: TaskScope,
+    entity: Option<(TaskEntityType, i64)>,
+    today: &str,
+) -> Markup {
+    html! {
+        div
+            id="tasks-list"
+            hx-get=(list_url(scope, entity))
+            hx-trigger=(format!("{} from:body", TASKS_CHANGED_EVENT))
+            hx-swap="outerHTML"
+        {
+            @if tasks.is_empty() {
+                (empty_state_i18n(
+                    &t.messages.tasks_empty_title().to_string(),
+                    &t.messages.tasks_empty_message().to_string(),
+                    false
+                ))
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th style="width: 3rem;" {}
+                            th { (t.messages.tasks_task()) }
+                            @if entity.is_none() {
+                                th { (t.messages.tasks_attached_to()) }
+                            }
+                            th { (t.messages.tasks_assignee()) }
+                            th { (t.messages.tasks_due_date()) }
+                            th style="text-align: right;" { (t.messages.common_actions()) }
+                        }
+                    }
+                    tbody {
+                        @for task in tasks {
+                            tr style=[task.is_completed().then_some("color: var(--gray-500);")] {
+                                td { (complete_toggle(session, t, task)) }
+                                td {
+                                    div style=(if task.is_completed() { "text-decoration: line-through;" } else { "font-weight: 600;" }) {
+                                        (task.title)
+                                    }
+                                    @if let Some(notes) = &task.notes {
+                                        p style="white-space: pre-line; font-size: 0.875rem; color: var(--gray-600); margin: 0;" {
+                                            (notes)
+                                        }
+                                    }
+                                }
+                                @if entity.is_none() {
+                                    td { (entity_link(t, task)) }
+                                }
+                                td {
+                                    @if let Some(name) = &task.assignee_name {
+                                        (name)
+                                    } @else {
+                                        span style="color: var(--gray-500);" { (t.messages.tasks_unassigned()) }
+                                    }
+                                }
+                                td style="white-space: nowrap;" { (due_date(t, task, today)) }
+                                td style="text-align: right; white-space: nowrap;" {
+                                    button
+                                        class="btn btn-sm"
+                                        hx-get=(format!("/tasks/{}/edit", task.id))
+                                        hx-target="#modal-container"
+                                        hx-swap="innerHTML"
+                                        style="margin-right: 0.5rem;"
+                                    {
+                                        (t.messages.common_edit())
+                                    }
+                                    form style="display: inline;" {
+                                        (csrf_token_field(&session.csrf_token))
+                                        button
+                                            type="submit"
+                                            class="btn btn-sm btn-danger"
+                                            hx-post=(format!("/tasks/{}/delete", task.id))
+                                            hx-swap="none"
+                                            hx-confirm-custom=(confirm_attrs(
+                                                &t.messages.tasks_delete_title().to_string(),
+                                                &t.messages.tasks_delete_confirm().to_string(),
+                                                ConfirmVariant::Danger,
+                                                Some(&t.messages.common_delete().to_string()),
+                                                None
+                                            ))
+                                        {
+                                            (t.messages.common_delete())
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Checkbox button that marks a task done or reopens it
+fn complete_toggle(session: &Session, t: &TranslationContext, task: &TaskEntity) -> Markup {
+    let (label, completed) = if task.is_completed() {
+        (t.messages.tasks_reopen(), "false")
+    } else {
+        (t.messages.tasks_complete(), "true")
+    };
+
+    html! {
+        form hx-post=(format!("/tasks/{}/complete", task.id)) hx-swap="none" style="margin: 0;" {
+            (csrf_token_field(&session.csrf_token))
+            input type="hidden" name="completed" value=(completed);
+            button
+                type="submit"
+                class="btn btn-sm btn-secondary"
+                title=(label)
+                aria-label=(label)
+            {
+                @if task.is_completed() { "☑" } @else { "☐" }
+            }
+        }
+    }
+}
+
+fn entity_link(t: &TranslationContext, task: &TaskEntity) -> Markup {
+    html! {
+        @match (task.entity(), &task.entity_label) {
+            (Some((entity_type, id)), Some(label)) => {
+                a href=(entity_type.url(id)) class="primary-link" { (label) }
+            }
+            (Some(_), None) => {
+                span style="color: var(--gray-500);" { (t.messages.tasks_entity_deleted()) }
+            }
+            (None, _) => {}
+        }
+    }
+}
+
+fn due_date(t: &TranslationContext, task: &TaskEntity, today: &str) -> Markup {
+    html! {
+        @if let Some(due) = &task.due_date {
+            @if task.is_overdue(today) {
+                span style="color: var(--danger-color); font-weight: 600;" title=(t.messages.tasks_overdue()) {
+                    (due) " ⚠"
+                }
+            } @else {
+                (due)
+            }
+        }
+    }
+}
+
+/// "Tasks" section on a detail page, listing the open tasks of that record
+pub fn entity_tasks_section(
+    t: &TranslationContext,
+    entity_type: TaskEntityType,
+    entity_id: i64,
+) -> Markup {
+    let entity = Some((entity_type, entity_id));
+
+    html! {
+        div style="margin-top: 2rem;" {
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1rem;" {
+                h2 style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
+                    (t.messages.tasks_title())
+                }
+                button
+                    class="btn btn-secondary"
+                    hx-get=(new_task_url(entity))
+                    hx-target="#modal-container"
+                    hx-swap="innerHTML"
+                {
+                    "+ " (t.messages.tasks_create())
+                }
+            }
+            div id="tasks-list" hx-get=(list_url(TaskScope::Open, entity)) hx-trigger="load" hx-swap="outerHTML" {}
+        }
+    }
+}
+
+/// Submitted or stored values of the task form
+pub struct TaskFormValues<'a> {
+    pub title: &'a str,
+    pub notes: Option<&'a str>,
+    pub assignee_id: Option<i64>,
+    pub due_date: Option<&'a str>,
+}
+
+fn task_form_fields(
+    session: &Session,
+    t: &TranslationContext,
+    users: &[AssignableUserEntity],
+    values: &TaskFormValues,
+) -> Markup {
+    let unassigned = t.messages.tasks_unassigned().to_string();
+    let mut assignee_options: Vec<(String, &str)> = vec![(String::new(), unassigned.as_str())];
+    assignee_options.extend(users.iter().map(|u| (u.id.to_string(), u.name.as_str())));
+    let selected_assignee = values
+        .assignee_id
+        .map(|id| id.to_string())
+        .unwrap_or_default();
+
+    html! {
+        (csrf_token_field(&session.csrf_token))
+        (form_field(
+            "title",
+            &t.messages.tasks_task().to_string(),
+            InputType::Text,
+            Some(values.title),
+            Some(&t.messages.tasks_title_placeholder().to_string()),
+            true,
+            None,
+            None
+        ))
+        (form_textarea(
+            "notes",
+            &t.messages.tasks_notes().to_string(),
+            values.notes,
+            None,
+            3,
+            false,
+            None,
+            None
+        ))
+        (form_select(
+            "assignee_id",
+            &t.messages.tasks_assignee().to_string(),
+            &assignee_options,
+            Some(&selected_assignee),
+            None,
+            false,
+            None
+        ))
+        (form_field(
+            "due_date",
+            &t.messages.tasks_due_date().to_string(),
+            InputType::Date,
+            values.due_date,
+            None,
+            false,
+            None,
+            None
+        ))
+    }
+}
+
+/// Create task modal, optionally attached to a record
+pub fn task_create_modal(
+    session: &Session,
+    t: &TranslationContext,
+    users: &[AssignableUserEntity],
+    entity: Option<(TaskEntityType, i64, &str)>,
+    values: &TaskFormValues,
+    error: Option<&str>,
+) -> Markup {
+    let fields = html! {
+        @if let Some((entity_type, id, label)) = entity {
+            input type="hidden" name="entity_type" value=(entity_type.as_str());
+            input type="hidden" name="entity_id" value=(id);
+            p style="margin-bottom: 1rem; color: var(--gray-600);" {
+                (t.messages.tasks_attached_to()) ": "
+                strong { (label) }
+            }
+        }
+        (task_form_fields(session, t, users, values))
+    };
+
+    modal_form_i18n(
+        "task-modal",
+        &t.messages.tasks_create().to_string(),
+        error,
+        "/tasks",
+        fields,
+        &t.messages.common_create().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Edit task modal
+pub fn task_edit_modal(
+    session: &Session,
+    t: &TranslationContext,
+    users: &[AssignableUserEntity],
+    task_id: i64,
+    values: &TaskFormValues,
+    error: Option<&str>,
+) -> Markup {
+    modal_form_i18n(
+        "task-modal",
+        &t.messages.tasks_edit().to_string(),
+        error,
+        &format!("/tasks/{}", task_id),
+        task_form_fields(session, t, users, values),
+        &t.messages.common_save().to_string(),
+        &t.messages.common_cancel().to_string(),
+    )
+}
+
+/// Dashboard widget with the current user's open tasks
+pub fn my_tasks_widget(t: &TranslationContext, tasks: &[TaskEntity], today: &str) -> Markup {
+    html! {
+        div class="dashboard-section" {
+            div style="display: flex; justify-content: space-between; align-items: center;" {
+                h2 class="section-heading" {
+                    (t.messages.tasks_my_open())
+                }
+                a href="/tasks" class="primary-link" { (t.messages.tasks_view_all()) }
+            }
+            @if tasks.is_empty() {
+                div class="info" style="padding: 1rem;" {
+                    (t.messages.tasks_none_assigned())
+                }
+            } @else {
+                ul style="list-style: none; padding: 0; margin: 0;" {
+                    @for task in tasks {
+                        li style="display: flex; justify-content: space-between; gap: 1rem; padding: 0.5rem 0; border-bottom: 1px solid var(--gray-200);" {
+                            span {
+                                span style="font-weight: 600;" { (task.title) }
+                                @if task.entity().is_some() {
+                                    " · "
+                                    (entity_link(t, task))
+                                }
+                            }
+                            span style="white-space: nowrap;" { (due_date(t, task, today)) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
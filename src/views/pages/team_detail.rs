@@ -2,15 +2,32 @@ use maud::{html, Markup};
 
 use crate::auth::Session;
 use crate::i18n::TranslationContext;
+use crate::service::calendar_subscriptions::CalendarSubscriptionEntity;
+use crate::service::goal_types::SeasonGoalTypesEntity;
+use crate::service::placements::Medal;
+use crate::service::tasks::TaskEntityType;
+use crate::service::team_form::FormMatchEntity;
+use crate::service::team_stats::TeamSeasonStatsEntity;
 use crate::service::teams::{TeamDetailEntity, TeamEntity, TeamParticipationWithSeasonEntity};
 use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
 use crate::views::components::forms::csrf_token_field;
+use crate::views::pages::calendar_subscriptions::calendar_subscriptions_section;
+use crate::views::pages::goal_types::team_goal_types_section;
+use crate::views::pages::placements::placement_label;
+use crate::views::pages::tasks::entity_tasks_section;
+use crate::views::pages::team_form::form_strip;
 
 /// Team detail page with season participation management
+#[allow(clippy::too_many_arguments)]
 pub fn team_detail_page(
     session: &Session,
     t: &TranslationContext,
     detail: &TeamDetailEntity,
+    form: &[FormMatchEntity],
+    season_stats: &[TeamSeasonStatsEntity],
+    goal_types: &[SeasonGoalTypesEntity],
+    subscriptions: &[CalendarSubscriptionEntity],
+    base_url: &str,
 ) -> Markup {
     let team = &detail.team_info;
 
@@ -30,6 +47,25 @@ pub fn team_detail_page(
                     }
                 }
                 div style="display: flex; gap: 0.5rem;" {
+                    a
+                        href=(format!("/teams/{}/splits", team.id))
+                        class="btn btn-secondary"
+                    {
+                        (t.messages.splits_view())
+                    }
+                    a
+                        href=(format!("/teams/{}/merge", team.id))
+                        class="btn btn-secondary"
+                    {
+                        (t.messages.team_merge_open())
+                    }
+                    a
+                        href=(format!("/teams/{}/export", team.id))
+                        class="btn btn-secondary"
+                        download
+                    {
+                        (t.messages.teams_export())
+                    }
                     button
                         class="btn btn-primary"
                         hx-get=(format!("/teams/{}/edit", team.id))
@@ -59,7 +95,7 @@ pub fn team_detail_page(
             }
 
             // Team Info Card
-            (team_info_card(t, team))
+            (team_info_card(t, team, &detail.participations, form))
 
             // Participations Section
             div style="margin-top: 2rem;" {
@@ -67,13 +103,23 @@ pub fn team_detail_page(
                     h2 style="font-size: 1.5rem; font-weight: 700; margin: 0;" {
                         "Season Participations"
                     }
-                    button
-                        class="btn btn-primary"
-                        hx-get=(format!("/team-participations/new?team_id={}&return_to=/teams/{}", team.id, team.id))
-                        hx-target="#modal-container"
-                        hx-swap="innerHTML"
-                    {
-                        (t.messages.teams_add_to_season())
+                    div style="display: flex; gap: 0.5rem;" {
+                        @if detail.participations.len() > 1 {
+                            a
+                                href=(format!("/teams/{}/roster-diff", team.id))
+                                class="btn btn-secondary"
+                            {
+                                (t.messages.roster_diff_open())
+                            }
+                        }
+                        button
+                            class="btn btn-primary"
+                            hx-get=(format!("/team-participations/new?team_id={}&return_to=/teams/{}", team.id, team.id))
+                            hx-target="#modal-container"
+                            hx-swap="innerHTML"
+                        {
+                            (t.messages.teams_add_to_season())
+                        }
                     }
                 }
 
@@ -84,14 +130,43 @@ pub fn team_detail_page(
                 }
             }
 
+            // Goal statistics per season
+            @if !season_stats.is_empty() {
+                (season_stats_section(t, season_stats))
+            }
+
+            // Goal type distribution per season
+            (team_goal_types_section(t, goal_types))
+
+            (calendar_subscriptions_section(session, t, team.id, subscriptions, base_url, None))
+
+            // Open tasks attached to this team
+            (entity_tasks_section(t, TaskEntityType::Team, team.id))
+
             // Modal container
             div id="modal-container" {}
         }
     }
 }
 
-/// Team info card with country
-fn team_info_card(t: &TranslationContext, team: &TeamEntity) -> Markup {
+/// Team info card with country and medals won
+fn team_info_card(
+    t: &TranslationContext,
+    team: &TeamEntity,
+    participations: &[TeamParticipationWithSeasonEntity],
+    form: &[FormMatchEntity],
+) -> Markup {
+    let medals: Vec<(Medal, usize)> = [Medal::Gold, Medal::Silver, Medal::Bronze]
+        .into_iter()
+        .map(|medal| {
+            let count = participations
+                .iter()
+                .filter(|p| p.final_rank.and_then(Medal::from_rank) == Some(medal))
+                .count();
+            (medal, count)
+        })
+        .collect();
+
     html! {
         div style="padding: 1.5rem; background: var(--gray-50); border-radius: 8px;" {
             div style="display: grid; grid-template-columns: repeat(auto-fit, minmax(200px, 1fr)); gap: 1rem;" {
@@ -119,6 +194,92 @@ fn team_info_card(t: &TranslationContext, team: &TeamEntity) -> Markup {
                         }
                     }
                 }
+                @if medals.iter().any(|(_, count)| *count > 0) {
+                    div {
+                        div style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 0.25rem;" {
+                            (t.messages.placements_medals())
+                        }
+                        div style="display: flex; gap: 1rem; font-weight: 600;" {
+                            @for (medal, count) in &medals {
+                                span { (format!("{} {}", medal.emoji(), count)) }
+                            }
+                        }
+                    }
+                }
+                @if !form.is_empty() {
+                    div {
+                        div style="color: var(--gray-600); font-size: 0.875rem; margin-bottom: 0.25rem;" {
+                            (t.messages.team_form_title())
+                        }
+                        (form_strip(t, form))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Goals for and against, power play and shorthanded goals, faceoffs and
+/// shooting per season
+fn season_stats_section(t: &TranslationContext, season_stats: &[TeamSeasonStatsEntity]) -> Markup {
+    html! {
+        div style="margin-top: 2rem;" {
+            h2 style="font-size: 1.5rem; font-weight: 700; margin: 0 0 1.5rem 0;" {
+                (t.messages.team_stats_title())
+            }
+            table class="table" {
+                thead {
+                    tr {
+                        th { (t.messages.team_stats_season()) }
+                        th style="text-align: right;" { (t.messages.team_stats_goals_for()) }
+                        th style="text-align: right;" { (t.messages.team_stats_goals_against()) }
+                        th style="text-align: right;" { (t.messages.standings_goal_difference()) }
+                        th style="text-align: right;" { (t.messages.team_stats_power_play_goals()) }
+                        th style="text-align: right;" { (t.messages.team_stats_short_handed_goals()) }
+                        th style="text-align: right;" { (t.messages.team_stats_faceoff_percentage()) }
+                        th style="text-align: right;" { (t.messages.team_stats_shots()) }
+                        th style="text-align: right;" { (t.messages.team_stats_shooting_percentage()) }
+                    }
+                }
+                tbody {
+                    @for stats in season_stats {
+                        tr {
+                            td {
+                                a href=(format!("/seasons/{}", stats.season_id)) class="primary-link" {
+                                    @if let Some(display_name) = &stats.season_display_name {
+                                        (display_name)
+                                    } @else {
+                                        (format!("{} {}", stats.event_name, stats.season_year))
+                                    }
+                                }
+                            }
+                            td style="text-align: right;" { (stats.goals_for) }
+                            td style="text-align: right;" { (stats.goals_against) }
+                            td style="text-align: right; font-weight: 600;" {
+                                @if stats.goal_differential > 0 {
+                                    (format!("+{}", stats.goal_differential))
+                                } @else {
+                                    (stats.goal_differential)
+                                }
+                            }
+                            td style="text-align: right;" { (stats.power_play_goals) }
+                            td style="text-align: right;" { (stats.short_handed_goals) }
+                            td style="text-align: right;" {
+                                @match stats.faceoff_percentage() {
+                                    Some(pct) => (format!("{:.1}%", pct)),
+                                    None => span style="color: var(--gray-400);" { "-" },
+                                }
+                            }
+                            td style="text-align: right;" { (stats.shots) }
+                            td style="text-align: right;" {
+                                @match stats.shooting_percentage() {
+                                    Some(pct) => (format!("{:.1}%", pct)),
+                                    None => span style="color: var(--gray-400);" { "-" },
+                                }
+                            }
+                        }
+                    }
+                }
             }
         }
     }
@@ -126,7 +287,7 @@ fn team_info_card(t: &TranslationContext, team: &TeamEntity) -> Markup {
 
 /// Participations list in grid layout
 fn participations_list(
-    _t: &TranslationContext,
+    t: &TranslationContext,
     participations: &[TeamParticipationWithSeasonEntity],
 ) -> Markup {
     html! {
@@ -160,6 +321,31 @@ fn participations_list(
                                 "players"
                             }
                         }
+                        @if participation.final_rank.is_some() || participation.seed.is_some() {
+                            div style="display: flex; gap: 1rem; color: var(--gray-500); font-size: 0.875rem; margin-top: 0.25rem;" {
+                                @if let Some(rank) = participation.final_rank {
+                                    span {
+                                        (t.messages.placements_final_placement())
+                                        ": "
+                                        span style="font-weight: 600; color: var(--gray-700);" { (placement_label(rank)) }
+                                    }
+                                }
+                                @if let Some(seed) = participation.seed {
+                                    span {
+                                        (t.messages.playoffs_seed())
+                                        ": "
+                                        span style="font-weight: 500; color: var(--gray-700);" { (seed) }
+                                    }
+                                }
+                            }
+                        }
+                        @if let Some(head_coach) = &participation.head_coach {
+                            div style="color: var(--gray-500); font-size: 0.875rem; margin-top: 0.25rem;" {
+                                (t.messages.teams_head_coach())
+                                ": "
+                                span style="font-weight: 500; color: var(--gray-700);" { (head_coach) }
+                            }
+                        }
                     }
                     div style="display: flex; gap: 0.5rem;" {
                         a
@@ -0,0 +1,51 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::team_form::{FormMatchEntity, FormOutcome};
+
+fn outcome_label(t: &TranslationContext, outcome: FormOutcome) -> String {
+    match outcome {
+        FormOutcome::Win => t.messages.team_form_win(),
+        FormOutcome::OvertimeWin => t.messages.team_form_overtime_win(),
+        FormOutcome::Draw => t.messages.team_form_draw(),
+        FormOutcome::OvertimeLoss => t.messages.team_form_overtime_loss(),
+        FormOutcome::Loss => t.messages.team_form_loss(),
+    }
+    .to_string()
+}
+
+fn outcome_class(outcome: FormOutcome) -> &'static str {
+    match outcome {
+        FormOutcome::Win => "form-badge form-win",
+        FormOutcome::OvertimeWin => "form-badge form-overtime-win",
+        FormOutcome::Draw => "form-badge form-draw",
+        FormOutcome::OvertimeLoss => "form-badge form-overtime-loss",
+        FormOutcome::Loss => "form-badge form-loss",
+    }
+}
+
+/// Last matches as W/L/OTW/OTL badges, oldest on the left
+///
+/// Each badge links to its match and names the opponent and score on hover.
+pub fn form_strip(t: &TranslationContext, form: &[FormMatchEntity]) -> Markup {
+    html! {
+        span class="form-strip" title=(t.messages.team_form_hint()) {
+            @for entry in form {
+                a
+                    href=(format!("/matches/{}", entry.match_id))
+                    class=(outcome_class(entry.outcome))
+                    title=(format!(
+                        "{}{} {} {}:{}",
+                        entry.match_date.as_deref().map(|date| format!("{} · ", date)).unwrap_or_default(),
+                        t.messages.team_form_versus(),
+                        entry.opponent_name,
+                        entry.goals_for,
+                        entry.goals_against
+                    ))
+                {
+                    (outcome_label(t, entry.outcome))
+                }
+            }
+        }
+    }
+}
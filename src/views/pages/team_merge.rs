@@ -0,0 +1,131 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::team_merge::TeamMergePreview;
+use crate::service::teams::TeamEntity;
+use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
+use crate::views::components::forms::csrf_token_field;
+
+/// Merge a duplicate team into another one
+///
+/// `into` and `preview` are set once the canonical team is picked; the merge
+/// can only be confirmed when the preview shows no conflicts. `error` is shown
+/// when the picked team cannot be used.
+pub fn team_merge_page(
+    session: &Session,
+    t: &TranslationContext,
+    team: &TeamEntity,
+    teams: &[(i64, String)],
+    into: Option<&TeamEntity>,
+    preview: Option<&TeamMergePreview>,
+    error: Option<&str>,
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="display: flex; align-items: center; gap: 1rem; margin-bottom: 0.5rem;" {
+                a href=(format!("/teams/{}", team.id)) class="btn btn-secondary" {
+                    (format!("← {}", team.name))
+                }
+                h1 style="font-size: 2rem; font-weight: 700; margin: 0;" {
+                    (t.messages.team_merge_title())
+                }
+            }
+            p style="color: var(--gray-600); margin-bottom: 1.5rem;" {
+                (t.messages.team_merge_description())
+            }
+
+            @if let Some(error) = error {
+                div class="error" style="padding: 1rem; margin-bottom: 1rem;" { (error) }
+            }
+
+            form
+                method="get"
+                action=(format!("/teams/{}/merge", team.id))
+                style="display: flex; gap: 1rem; align-items: flex-end; margin-bottom: 1.5rem; flex-wrap: wrap;"
+            {
+                div class="form-group" {
+                    label class="form-label" for="into" { (t.messages.team_merge_into()) }
+                    select id="into" name="into" required {
+                        option value="" { (t.messages.team_merge_select_team()) }
+                        @for (id, name) in teams.iter().filter(|(id, _)| *id != team.id) {
+                            option value=(id) selected[into.is_some_and(|into| into.id == *id)] {
+                                (name)
+                            }
+                        }
+                    }
+                }
+                div class="form-group" {
+                    button type="submit" class="btn btn-secondary" { (t.messages.team_merge_preview()) }
+                }
+            }
+
+            @if let (Some(into), Some(preview)) = (into, preview) {
+                h2 style="font-size: 1.25rem; font-weight: 600; margin: 0 0 0.75rem 0;" {
+                    (team.name) " → " (into.name)
+                }
+                table class="table" style="max-width: 28rem;" {
+                    tbody {
+                        tr {
+                            td { (t.messages.team_merge_participations()) }
+                            td style="text-align: right; font-weight: 600;" { (preview.participations) }
+                        }
+                        tr {
+                            td { (t.messages.team_merge_matches()) }
+                            td style="text-align: right; font-weight: 600;" { (preview.matches) }
+                        }
+                        tr {
+                            td { (t.messages.team_merge_score_events()) }
+                            td style="text-align: right; font-weight: 600;" { (preview.score_events) }
+                        }
+                    }
+                }
+
+                @if preview.has_conflicts() {
+                    div class="error" style="padding: 1rem; margin-top: 1rem;" {
+                        p style="font-weight: 600; margin: 0 0 0.5rem 0;" {
+                            (t.messages.team_merge_conflicts())
+                        }
+                        @if !preview.shared_seasons.is_empty() {
+                            p style="margin: 0 0 0.25rem 0;" { (t.messages.team_merge_shared_seasons()) }
+                            ul style="margin: 0 0 0.5rem 1.25rem;" {
+                                @for season in &preview.shared_seasons {
+                                    li {
+                                        a href=(format!("/seasons/{}", season.season_id)) { (season.season_name) }
+                                    }
+                                }
+                            }
+                        }
+                        @if preview.head_to_head > 0 {
+                            p style="margin: 0;" {
+                                (t.messages.team_merge_head_to_head()) ": " strong { (preview.head_to_head) }
+                            }
+                        }
+                    }
+                } @else {
+                    div id="team-merge-result" {}
+                    form style="margin-top: 1rem;" {
+                        (csrf_token_field(&session.csrf_token))
+                        input type="hidden" name="into" value=(into.id);
+                        button
+                            type="submit"
+                            class="btn btn-danger"
+                            hx-post=(format!("/teams/{}/merge", team.id))
+                            hx-target="#team-merge-result"
+                            hx-swap="innerHTML"
+                            hx-confirm-custom=(confirm_attrs(
+                                &format!("{} \"{}\"", t.messages.team_merge_title(), team.name),
+                                &t.messages.team_merge_confirm().to_string(),
+                                ConfirmVariant::Danger,
+                                Some(&t.messages.team_merge_action().to_string()),
+                                Some(&t.messages.common_cancel().to_string())
+                            ))
+                        {
+                            (t.messages.team_merge_action())
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
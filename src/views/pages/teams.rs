@@ -3,11 +3,12 @@ use maud::{html, Markup};
 use crate::auth::Session;
 use crate::common::pagination::PagedResult;
 use crate::i18n::TranslationContext;
+use crate::service::team_history::{ImportMode, TeamImportSummary};
 use crate::service::teams::{SortField, SortOrder, TeamEntity, TeamFilters};
 use crate::views::components::crud::{
-    empty_state, modal_form, page_header, pagination, table_actions,
+    empty_state, modal_form, modal_form_multipart, pagination, table_actions,
 };
-use crate::views::components::forms::csrf_token_field;
+use crate::views::components::forms::{csrf_token_field, form_field, InputType};
 
 /// Main teams page with table and filters
 pub fn teams_page(
@@ -21,13 +22,35 @@ pub fn teams_page(
 ) -> Markup {
     html! {
         div class="card" {
-            // Header with title and create button
-            (page_header(
-                &t.messages.teams_title().to_string(),
-                &t.messages.teams_description().to_string(),
-                "/teams/new",
-                &t.messages.teams_create().to_string()
-            ))
+            // Header with title, import and create buttons
+            div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1.5rem;" {
+                div {
+                    h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                        (t.messages.teams_title())
+                    }
+                    p style="color: var(--gray-600);" {
+                        (t.messages.teams_description())
+                    }
+                }
+                div style="display: flex; gap: 0.5rem;" {
+                    button
+                        class="btn btn-secondary"
+                        hx-get="/teams/import"
+                        hx-target="#modal-container"
+                        hx-swap="innerHTML"
+                    {
+                        (t.messages.teams_import())
+                    }
+                    button
+                        class="btn btn-primary"
+                        hx-get="/teams/new"
+                        hx-target="#modal-container"
+                        hx-swap="innerHTML"
+                    {
+                        (t.messages.teams_create())
+                    }
+                }
+            }
 
             // Filters
             div class="filters-container" {
@@ -116,6 +139,15 @@ pub fn team_list_content(
                     Some(&t.messages.teams_create().to_string()),
                 ))
             } @else {
+                div style="display: flex; justify-content: flex-end; margin-bottom: 0.75rem;" {
+                    a
+                        href=(build_list_url("/teams/export", sort_field, sort_order, filters))
+                        class="btn btn-secondary btn-sm"
+                        download
+                    {
+                        (t.messages.list_export_csv())
+                    }
+                }
                 table class="table" {
                     thead {
                         tr {
@@ -262,11 +294,17 @@ fn sortable_header(
 
 /// Helper to build sort URLs
 fn build_sort_url(field: &SortField, order: &SortOrder, filters: &TeamFilters) -> String {
-    let mut url = format!(
-        "/teams/list?sort={}&order={}",
-        field.as_str(),
-        order.as_str()
-    );
+    build_list_url("/teams/list", field, order, filters)
+}
+
+/// URL of `path` carrying the list's sorting and filters
+fn build_list_url(
+    path: &str,
+    field: &SortField,
+    order: &SortOrder,
+    filters: &TeamFilters,
+) -> String {
+    let mut url = format!("{}?sort={}&order={}", path, field.as_str(), order.as_str());
 
     if let Some(name) = &filters.name {
         url.push_str(&format!("&name={}", urlencoding::encode(name)));
@@ -409,6 +447,17 @@ pub fn team_edit_modal(
                     enabled-only;
             }
         }
+
+        (form_field(
+            "slug",
+            &t.messages.form_slug().to_string(),
+            InputType::Text,
+            team.slug.as_deref(),
+            None,
+            false,
+            None,
+            Some(&t.messages.form_slug_help().to_string()),
+        ))
     };
 
     modal_form(
@@ -420,3 +469,124 @@ pub fn team_edit_modal(
         &t.messages.common_save().to_string(),
     )
 }
+
+/// Import team history modal
+pub fn team_import_modal(session: &Session, t: &TranslationContext, error: Option<&str>) -> Markup {
+    let form_fields = html! {
+        (csrf_token_field(&session.csrf_token))
+
+        p style="color: var(--gray-600); margin-bottom: 1rem;" {
+            (t.messages.teams_import_help())
+        }
+
+        div style="margin-bottom: 1.5rem;" {
+            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                (t.messages.teams_import_file())
+                span style="color: red;" { "*" }
+            }
+            input
+                type="file"
+                name="file"
+                accept="application/json,.json"
+                required
+                style="width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
+        }
+
+        div style="margin-bottom: 1.5rem;" {
+            label style="display: block; margin-bottom: 0.5rem; font-weight: 500;" {
+                (t.messages.teams_import_mode())
+            }
+            @for (value, label, checked) in [
+                (ImportMode::AddMissing, t.messages.teams_import_mode_add(), true),
+                (ImportMode::Merge, t.messages.teams_import_mode_merge(), false),
+            ] {
+                label style="display: flex; align-items: center; gap: 0.5rem; cursor: pointer; padding: 0.25rem 0;" {
+                    input type="radio" name="mode" value=(value.as_str()) checked[checked];
+                    span { (label) }
+                }
+            }
+        }
+    };
+
+    modal_form_multipart(
+        "team-modal",
+        &t.messages.teams_import_title().to_string(),
+        error,
+        "/teams/import",
+        form_fields,
+        &t.messages.teams_import_submit().to_string(),
+    )
+}
+
+/// Result of a team history import, replacing the import modal
+pub fn team_import_result_modal(t: &TranslationContext, summary: &TeamImportSummary) -> Markup {
+    let rows = [
+        (t.messages.teams_import_row_teams(), summary.teams),
+        (t.messages.teams_import_row_events(), summary.events),
+        (t.messages.teams_import_row_seasons(), summary.seasons),
+        (
+            t.messages.teams_import_row_participations(),
+            summary.participations,
+        ),
+        (t.messages.teams_import_row_players(), summary.players),
+        (t.messages.teams_import_row_contracts(), summary.contracts),
+        (t.messages.teams_import_row_matches(), summary.matches),
+        (t.messages.teams_import_row_goals(), summary.score_events),
+    ];
+
+    html! {
+        div
+            class="modal-backdrop"
+            style="position: fixed; top: 0; left: 0; right: 0; bottom: 0; background: rgba(0, 0, 0, 0.5); display: flex; align-items: center; justify-content: center; z-index: 1000;"
+            id="team-modal"
+        {
+            div
+                class="modal"
+                style="background: white; border-radius: 12px; padding: 2rem; max-width: 500px; width: 90%; max-height: 90vh; overflow-y: auto;"
+            {
+                h2 style="font-size: 1.5rem; font-weight: 700; margin: 0 0 0.5rem 0;" {
+                    (t.messages.teams_import_done())
+                }
+                p style="color: var(--gray-600); margin-bottom: 1rem;" {
+                    @if summary.team_created {
+                        (t.messages.teams_import_team_created())
+                    } @else {
+                        (t.messages.teams_import_team_matched())
+                    }
+                }
+                table class="table" style="margin-bottom: 1.5rem;" {
+                    thead {
+                        tr {
+                            th { (t.messages.teams_import_entity()) }
+                            th style="text-align: right;" { (t.messages.teams_import_created()) }
+                            th style="text-align: right;" { (t.messages.teams_import_updated()) }
+                            th style="text-align: right;" { (t.messages.teams_import_unchanged()) }
+                        }
+                    }
+                    tbody {
+                        @for (label, counts) in &rows {
+                            tr {
+                                td { (label) }
+                                td style="text-align: right; font-weight: 600;" { (counts.created) }
+                                td style="text-align: right; font-weight: 600;" { (counts.updated) }
+                                td style="text-align: right; color: var(--gray-500);" { (counts.unchanged) }
+                            }
+                        }
+                    }
+                }
+                div style="display: flex; gap: 0.5rem; justify-content: flex-end;" {
+                    button
+                        type="button"
+                        class="btn btn-secondary"
+                        onclick="document.getElementById('team-modal').remove()"
+                    {
+                        (t.messages.common_close())
+                    }
+                    a class="btn btn-primary" href=(format!("/teams/{}", summary.team_id)) {
+                        (t.messages.teams_import_open_team())
+                    }
+                }
+            }
+        }
+    }
+}
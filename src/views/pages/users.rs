@@ -0,0 +1,466 @@
+use maud::{html, Markup};
+
+use crate::auth::audit::{LoginActivityEntry, LoginFailure};
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::settings::RegistrationSettings;
+use crate::service::users::{UserEntity, UserRole};
+use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
+use crate::views::components::crud::empty_state_i18n;
+use crate::views::components::forms::csrf_token_field;
+
+/// A temporary password to show once, with the email it belongs to
+pub struct IssuedPassword<'a> {
+    pub email: &'a str,
+    pub password: &'a str,
+}
+
+/// User management page: add users, manage their access and self-service sign-up
+pub fn users_page(
+    t: &TranslationContext,
+    session: &Session,
+    users: &[UserEntity],
+    registration: &RegistrationSettings,
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="margin-bottom: 1.5rem;" {
+                a href="/management" style="color: var(--primary-color); text-decoration: none;" {
+                    "← " (t.messages.nav_management())
+                }
+            }
+            div style="display: flex; justify-content: space-between; align-items: flex-start; gap: 1rem;" {
+                div {
+                    h1 class="page-title" { (t.messages.users_title()) }
+                    p class="page-description" { (t.messages.users_description()) }
+                }
+                a href="/users/activity" class="btn btn-secondary" { (t.messages.users_activity()) }
+            }
+
+            (users_list(t, session, users, None, None))
+
+            (registration_form(t, session, registration))
+        }
+    }
+}
+
+/// Switches for self-service sign-up on the login page
+fn registration_form(
+    t: &TranslationContext,
+    session: &Session,
+    registration: &RegistrationSettings,
+) -> Markup {
+    html! {
+        section style="margin-top: 2.5rem;" {
+            h2 style="font-size: 1.25rem; font-weight: 600; margin-bottom: 0.5rem;" {
+                (t.messages.users_registration())
+            }
+            p style="color: var(--gray-600); margin-bottom: 1rem;" {
+                (t.messages.users_registration_hint())
+            }
+            form hx-post="/users/registration" hx-swap="none" {
+                (csrf_token_field(&session.csrf_token))
+                label style="display: flex; align-items: center; gap: 0.5rem; margin-bottom: 0.5rem;" {
+                    input type="checkbox" name="enabled" value="true" checked[registration.enabled];
+                    (t.messages.users_registration_enabled())
+                }
+                label style="display: flex; align-items: center; gap: 0.5rem; margin-bottom: 1rem;" {
+                    input type="checkbox" name="requires_approval" value="true" checked[registration.requires_approval];
+                    (t.messages.users_registration_requires_approval())
+                }
+                button type="submit" class="btn btn-primary" { (t.messages.common_save()) }
+            }
+        }
+    }
+}
+
+fn role_label(t: &TranslationContext, role: UserRole) -> String {
+    match role {
+        UserRole::Admin => t.messages.users_role_admin().to_string(),
+        UserRole::Editor => t.messages.users_role_editor().to_string(),
+    }
+}
+
+/// Add form and user table
+///
+/// Rendered as a partial so every action swaps in the updated list;
+/// `issued` is a temporary password just generated, the only time it is shown.
+pub fn users_list(
+    t: &TranslationContext,
+    session: &Session,
+    users: &[UserEntity],
+    issued: Option<IssuedPassword>,
+    error: Option<&str>,
+) -> Markup {
+    let input_style = "padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
+
+    html! {
+        div id="users-list" {
+            @if let Some(error) = error {
+                div class="error" style="padding: 0.75rem 1rem; margin-bottom: 1rem;" { (error) }
+            }
+
+            @if let Some(issued) = issued {
+                div style="padding: 0.75rem 1rem; margin-bottom: 1rem; background: #dcfce7; border-radius: 4px;" {
+                    p style="margin-bottom: 0.5rem; color: #15803d; font-weight: 500;" {
+                        (t.messages.users_temporary_password()) " " (issued.email)
+                    }
+                    input
+                        type="text"
+                        readonly
+                        value=(issued.password)
+                        onclick="this.select()"
+                        style=(format!("width: 100%; font-family: monospace; {}", input_style));
+                }
+            }
+
+            form
+                hx-post="/users"
+                hx-target="#users-list"
+                hx-swap="outerHTML"
+                style="display: flex; gap: 0.5rem; margin-bottom: 1.5rem; flex-wrap: wrap;"
+            {
+                (csrf_token_field(&session.csrf_token))
+                input
+                    type="email"
+                    name="email"
+                    required
+                    maxlength="254"
+                    placeholder=(t.messages.users_email())
+                    style=(format!("flex: 2; min-width: 14rem; {}", input_style));
+                input
+                    type="text"
+                    name="name"
+                    required
+                    maxlength="100"
+                    placeholder=(t.messages.users_name())
+                    style=(format!("flex: 1; min-width: 10rem; {}", input_style));
+                select name="role" style=(input_style) {
+                    @for role in UserRole::ALL {
+                        option value=(role.as_str()) selected[role == UserRole::Editor] {
+                            (role_label(t, role))
+                        }
+                    }
+                }
+                button type="submit" class="btn btn-primary" { (t.messages.users_create()) }
+            }
+
+            table class="table" {
+                thead {
+                    tr {
+                        th { (t.messages.users_name()) }
+                        th { (t.messages.users_email()) }
+                        th { (t.messages.users_role()) }
+                        th { (t.messages.users_status()) }
+                        th { (t.messages.users_last_login()) }
+                        th style="text-align: right;" { "Actions" }
+                    }
+                }
+                tbody {
+                    @for user in users {
+                        (user_row(t, session, user))
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn user_row(t: &TranslationContext, session: &Session, user: &UserEntity) -> Markup {
+    let is_self = user.id == session.user_id;
+    let badge = "display: inline-block; padding: 0.125rem 0.5rem; border-radius: 9999px; font-size: 0.75rem; font-weight: 500;";
+
+    html! {
+        tr style=[(!user.active).then_some("color: var(--gray-500);")] {
+            td style="font-weight: 500;" {
+                (user.name.as_deref().unwrap_or("-"))
+                @if is_self {
+                    " "
+                    span style="color: var(--gray-500); font-weight: 400;" { "(" (t.messages.users_you()) ")" }
+                }
+            }
+            td { (user.email) }
+            td {
+                @if is_self {
+                    (user.role().map(|role| role_label(t, role)).unwrap_or_else(|| user.role.clone()))
+                } @else {
+                    form
+                        hx-post=(format!("/users/{}/role", user.id))
+                        hx-trigger="change"
+                        hx-target="#users-list"
+                        hx-swap="outerHTML"
+                        style="display: inline;"
+                    {
+                        (csrf_token_field(&session.csrf_token))
+                        select name="role" style="padding: 0.25rem; border: 1px solid var(--gray-300); border-radius: 4px;" {
+                            @for role in UserRole::ALL {
+                                option value=(role.as_str()) selected[user.role() == Some(role)] {
+                                    (role_label(t, role))
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            td style="white-space: nowrap;" {
+                @if user.active {
+                    span style=(format!("{} color: #15803d; background: #dcfce7;", badge)) { (t.messages.users_active()) }
+                } @else {
+                    span style=(format!("{} color: #b91c1c; background: #fee2e2;", badge)) { (t.messages.users_inactive()) }
+                }
+                @if user.email_unverified {
+                    " "
+                    span style=(format!("{} color: #a16207; background: #fef9c3;", badge)) { (t.messages.users_email_unverified()) }
+                }
+                @if user.approval_pending {
+                    " "
+                    span style=(format!("{} color: #1d4ed8; background: #dbeafe;", badge)) { (t.messages.users_approval_pending()) }
+                }
+                @if user.must_change_password {
+                    " "
+                    span style=(format!("{} color: #a16207; background: #fef9c3;", badge)) { (t.messages.users_password_pending()) }
+                }
+                @if user.locked {
+                    " "
+                    span style=(format!("{} color: #b91c1c; background: #fee2e2;", badge)) { (t.messages.users_locked()) }
+                }
+            }
+            td style="white-space: nowrap;" {
+                @if let Some(last_login) = &user.last_login_at {
+                    (last_login)
+                } @else {
+                    span style="color: var(--gray-400);" { (t.messages.users_never_signed_in()) }
+                }
+            }
+            td style="text-align: right; white-space: nowrap;" {
+                @if !is_self {
+                    form style="display: inline;" {
+                        (csrf_token_field(&session.csrf_token))
+                        input type="hidden" name="active" value=(if user.active { "false" } else { "true" });
+                        @if user.active {
+                            button
+                                type="submit"
+                                class="btn btn-sm btn-danger"
+                                hx-post=(format!("/users/{}/active", user.id))
+                                hx-target="#users-list"
+                                hx-swap="outerHTML"
+                                hx-confirm-custom=(confirm_attrs(
+                                    &t.messages.users_deactivate().to_string(),
+                                    &t.messages.users_confirm_deactivate().to_string(),
+                                    ConfirmVariant::Danger,
+                                    Some(&t.messages.users_deactivate().to_string()),
+                                    Some(&t.messages.common_cancel().to_string())
+                                ))
+                            {
+                                (t.messages.users_deactivate())
+                            }
+                        } @else {
+                            button
+                                type="submit"
+                                class="btn btn-sm btn-secondary"
+                                hx-post=(format!("/users/{}/active", user.id))
+                                hx-target="#users-list"
+                                hx-swap="outerHTML"
+                            {
+                                @if user.approval_pending {
+                                    (t.messages.users_approve())
+                                } @else {
+                                    (t.messages.users_reactivate())
+                                }
+                            }
+                        }
+                    }
+                    @if user.locked {
+                        " "
+                        form style="display: inline;" {
+                            (csrf_token_field(&session.csrf_token))
+                            button
+                                type="submit"
+                                class="btn btn-sm btn-secondary"
+                                hx-post=(format!("/users/{}/unlock", user.id))
+                                hx-target="#users-list"
+                                hx-swap="outerHTML"
+                            {
+                                (t.messages.users_unlock())
+                            }
+                        }
+                    }
+                    " "
+                    form style="display: inline;" {
+                        (csrf_token_field(&session.csrf_token))
+                        button
+                            type="submit"
+                            class="btn btn-sm btn-secondary"
+                            hx-post=(format!("/users/{}/reset-password", user.id))
+                            hx-target="#users-list"
+                            hx-swap="outerHTML"
+                            hx-confirm-custom=(confirm_attrs(
+                                &t.messages.users_reset_password().to_string(),
+                                &t.messages.users_confirm_reset_password().to_string(),
+                                ConfirmVariant::Warning,
+                                Some(&t.messages.users_reset_password().to_string()),
+                                Some(&t.messages.common_cancel().to_string())
+                            ))
+                        {
+                            (t.messages.users_reset_password())
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Recent sign-in attempts of all accounts, optionally only the failed ones
+pub fn sign_in_activity_page(
+    t: &TranslationContext,
+    entries: &[LoginActivityEntry],
+    failed_only: bool,
+) -> Markup {
+    let filter = |href: &str, label: String, current: bool| {
+        html! {
+            a href=(href) class=(if current { "btn btn-sm btn-primary" } else { "btn btn-sm btn-secondary" }) {
+                (label)
+            }
+        }
+    };
+
+    html! {
+        div class="card" {
+            div style="margin-bottom: 1.5rem;" {
+                a href="/users" style="color: var(--primary-color); text-decoration: none;" {
+                    "← " (t.messages.users_title())
+                }
+            }
+            h1 class="page-title" { (t.messages.users_activity()) }
+            p class="page-description" { (t.messages.users_activity_description()) }
+
+            div style="display: flex; gap: 0.5rem; margin-bottom: 1rem;" {
+                (filter("/users/activity", t.messages.users_activity_all().to_string(), !failed_only))
+                (filter("/users/activity?failed=true", t.messages.users_activity_failed_only().to_string(), failed_only))
+            }
+
+            @if entries.is_empty() {
+                (empty_state_i18n(
+                    &t.messages.users_activity_empty_title().to_string(),
+                    &t.messages.users_activity_empty_message().to_string(),
+                    false
+                ))
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.profile_login_time()) }
+                            th { (t.messages.users_activity_email()) }
+                            th { (t.messages.profile_login_result()) }
+                            th { (t.messages.profile_login_ip()) }
+                            th { (t.messages.profile_login_user_agent()) }
+                        }
+                    }
+                    tbody {
+                        @for entry in entries {
+                            tr {
+                                td style="white-space: nowrap;" { (entry.created_at) }
+                                td style=[entry.user_id.is_none().then_some("color: var(--gray-500);")] { (entry.email) }
+                                td style="white-space: nowrap;" { (activity_result_badge(t, entry)) }
+                                td style="white-space: nowrap;" {
+                                    (entry.ip_address.as_deref().unwrap_or("-"))
+                                    @if entry.new_ip {
+                                        " "
+                                        span style="display: inline-block; padding: 0.125rem 0.5rem; border-radius: 9999px; font-size: 0.75rem; font-weight: 500; color: #a16207; background: #fef9c3;" {
+                                            (t.messages.profile_login_new_ip())
+                                        }
+                                    }
+                                }
+                                td style="font-size: 0.875rem; color: var(--gray-600); max-width: 24rem; overflow: hidden; text-overflow: ellipsis; white-space: nowrap;"
+                                    title=(entry.user_agent.as_deref().unwrap_or(""))
+                                {
+                                    (entry.user_agent.as_deref().unwrap_or("-"))
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn activity_result_badge(t: &TranslationContext, entry: &LoginActivityEntry) -> Markup {
+    let failure = |reason: LoginFailure| entry.failure_reason.as_deref() == Some(reason.as_str());
+    let label = if entry.success {
+        t.messages.profile_login_success().to_string()
+    } else if failure(LoginFailure::UnknownUser) {
+        t.messages.users_activity_unknown_user().to_string()
+    } else if failure(LoginFailure::InvalidPassword) {
+        t.messages.profile_login_wrong_password().to_string()
+    } else if failure(LoginFailure::Deactivated) {
+        t.messages.users_activity_inactive().to_string()
+    } else if failure(LoginFailure::UnlinkedAccount) {
+        t.messages.users_activity_unlinked().to_string()
+    } else if failure(LoginFailure::LockedOut) {
+        t.messages.profile_login_locked().to_string()
+    } else {
+        t.messages.profile_login_failed().to_string()
+    };
+    let colors = if entry.success {
+        "color: #15803d; background: #dcfce7;"
+    } else {
+        "color: #b91c1c; background: #fee2e2;"
+    };
+
+    html! {
+        span style=(format!(
+            "display: inline-block; padding: 0.125rem 0.5rem; border-radius: 9999px; font-size: 0.75rem; font-weight: 500; {}",
+            colors
+        )) {
+            (label)
+        }
+    }
+}
+
+/// Form to change the current user's password
+///
+/// `required` is set when the user signed in with a temporary password and
+/// cannot continue before choosing their own.
+pub fn change_password_page(
+    t: &TranslationContext,
+    session: &Session,
+    required: bool,
+    error: Option<&str>,
+) -> Markup {
+    let input_style =
+        "width: 100%; padding: 0.5rem; border: 1px solid var(--gray-300); border-radius: 4px;";
+
+    html! {
+        div class="card" style="max-width: 32rem;" {
+            h1 class="page-title" { (t.messages.password_change_title()) }
+            @if required {
+                p class="page-description" { (t.messages.password_change_required()) }
+            } @else {
+                p class="page-description" { (t.messages.password_change_description()) }
+            }
+
+            @if let Some(error) = error {
+                div class="error" style="padding: 0.75rem 1rem; margin-bottom: 1rem;" { (error) }
+            }
+
+            form method="post" action="/profile/password" style="display: flex; flex-direction: column; gap: 1rem;" {
+                (csrf_token_field(&session.csrf_token))
+                @for (name, label, autocomplete) in [
+                    ("current_password", t.messages.password_current().to_string(), "current-password"),
+                    ("new_password", t.messages.password_new().to_string(), "new-password"),
+                    ("confirm_password", t.messages.password_confirm().to_string(), "new-password"),
+                ] {
+                    label style="display: flex; flex-direction: column; gap: 0.25rem; font-weight: 500;" {
+                        (label)
+                        input type="password" name=(name) required autocomplete=(autocomplete) style=(input_style);
+                    }
+                }
+                div {
+                    button type="submit" class="btn btn-primary" { (t.messages.password_change_submit()) }
+                }
+            }
+        }
+    }
+}
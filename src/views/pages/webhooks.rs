@@ -0,0 +1,242 @@
+use maud::{html, Markup};
+
+use crate::auth::Session;
+use crate::i18n::TranslationContext;
+use crate::service::webhooks::{DeliveryStatus, WebhookDeliveryEntity, WebhookSubscriptionEntity};
+use crate::views::components::confirm::{confirm_attrs, ConfirmVariant};
+use crate::views::components::crud::empty_state_i18n;
+use crate::views::components::forms::csrf_token_field;
+
+/// Webhooks page: subscriptions and the latest deliveries
+pub fn webhooks_page(
+    session: &Session,
+    t: &TranslationContext,
+    subscriptions: &[WebhookSubscriptionEntity],
+    deliveries: &[WebhookDeliveryEntity],
+) -> Markup {
+    html! {
+        div class="card" {
+            div style="margin-bottom: 1.5rem;" {
+                h1 style="font-size: 2rem; font-weight: 700; margin-bottom: 0.5rem;" {
+                    (t.messages.webhooks_title())
+                }
+                p style="color: var(--gray-600);" {
+                    (t.messages.webhooks_description())
+                }
+            }
+
+            (webhook_subscriptions(session, t, subscriptions, None))
+
+            h2 style="font-size: 1.25rem; font-weight: 600; margin: 2.5rem 0 1rem;" {
+                (t.messages.webhooks_deliveries_title())
+            }
+            (webhook_deliveries(session, t, deliveries, subscriptions))
+        }
+    }
+}
+
+/// Subscriptions with their toggles and a form to add one
+pub fn webhook_subscriptions(
+    session: &Session,
+    t: &TranslationContext,
+    subscriptions: &[WebhookSubscriptionEntity],
+    error: Option<&str>,
+) -> Markup {
+    html! {
+        div id="webhook-subscriptions" {
+            @if subscriptions.is_empty() {
+                (empty_state_i18n(
+                    &t.messages.webhooks_empty_title().to_string(),
+                    &t.messages.webhooks_empty_message().to_string(),
+                    false
+                ))
+            } @else {
+                table class="table" style="margin-bottom: 1.5rem;" {
+                    thead {
+                        tr {
+                            th { (t.messages.webhooks_url()) }
+                            th { (t.messages.webhooks_follows()) }
+                            th {}
+                        }
+                    }
+                    tbody {
+                        @for subscription in subscriptions {
+                            tr {
+                                td {
+                                    div style="font-family: monospace; word-break: break-all;" { (subscription.url) }
+                                    div style="color: var(--gray-500); font-size: 0.75rem;" {
+                                        (t.messages.webhooks_secret()) ": "
+                                        code { (subscription.secret) }
+                                    }
+                                }
+                                td {
+                                    form
+                                        hx-post=(format!("/management/webhooks/{}", subscription.id))
+                                        hx-trigger="change"
+                                        hx-target="#webhook-subscriptions"
+                                        hx-swap="outerHTML"
+                                        style="display: flex; flex-wrap: wrap; gap: 1rem;"
+                                    {
+                                        (csrf_token_field(&session.csrf_token))
+                                        (toggle("on_matches", &t.messages.webhooks_matches().to_string(), subscription.on_matches))
+                                        (toggle("on_score_events", &t.messages.webhooks_score_events().to_string(), subscription.on_score_events))
+                                        (toggle("on_players", &t.messages.webhooks_players().to_string(), subscription.on_players))
+                                        (toggle("active", &t.messages.webhooks_active().to_string(), subscription.active))
+                                    }
+                                }
+                                td style="text-align: right;" {
+                                    form style="display: inline;" {
+                                        (csrf_token_field(&session.csrf_token))
+                                        button
+                                            type="submit"
+                                            class="btn btn-sm btn-danger"
+                                            hx-post=(format!("/management/webhooks/{}/delete", subscription.id))
+                                            hx-target="#webhook-subscriptions"
+                                            hx-swap="outerHTML"
+                                            hx-confirm-custom=(confirm_attrs(
+                                                &t.messages.common_delete().to_string(),
+                                                &t.messages.webhooks_confirm_delete().to_string(),
+                                                ConfirmVariant::Danger,
+                                                Some(&t.messages.common_delete().to_string()),
+                                                Some(&t.messages.common_cancel().to_string())
+                                            ))
+                                        {
+                                            (t.messages.common_delete())
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+
+            h2 style="font-size: 1.25rem; font-weight: 600; margin-bottom: 0.5rem;" {
+                (t.messages.webhooks_add())
+            }
+            @if let Some(error) = error {
+                div class="error" style="padding: 0.75rem; margin-bottom: 1rem;" { (error) }
+            }
+            form
+                hx-post="/management/webhooks"
+                hx-target="#webhook-subscriptions"
+                hx-swap="outerHTML"
+            {
+                (csrf_token_field(&session.csrf_token))
+                div class="form-group" style="max-width: 40rem;" {
+                    label class="form-label" for="webhook_url" { (t.messages.webhooks_url()) }
+                    input type="url" id="webhook_url" name="url" required placeholder="https://";
+                }
+                div style="display: flex; flex-wrap: wrap; gap: 1.5rem; align-items: center; margin-bottom: 1rem;" {
+                    (toggle("on_matches", &t.messages.webhooks_matches().to_string(), true))
+                    (toggle("on_score_events", &t.messages.webhooks_score_events().to_string(), true))
+                    (toggle("on_players", &t.messages.webhooks_players().to_string(), true))
+                }
+                button type="submit" class="btn btn-primary" {
+                    (t.messages.webhooks_add())
+                }
+            }
+        }
+    }
+}
+
+/// Latest deliveries, newest first; failed ones can be queued again
+pub fn webhook_deliveries(
+    session: &Session,
+    t: &TranslationContext,
+    deliveries: &[WebhookDeliveryEntity],
+    subscriptions: &[WebhookSubscriptionEntity],
+) -> Markup {
+    html! {
+        div id="webhook-deliveries" {
+            @if deliveries.is_empty() {
+                p style="color: var(--gray-500);" { (t.messages.webhooks_deliveries_empty()) }
+            } @else {
+                table class="table" {
+                    thead {
+                        tr {
+                            th { (t.messages.webhooks_event()) }
+                            th { (t.messages.webhooks_url()) }
+                            th { (t.messages.webhooks_status()) }
+                            th style="text-align: right;" { (t.messages.webhooks_attempts()) }
+                            th { (t.messages.webhooks_created_at()) }
+                            th {}
+                        }
+                    }
+                    tbody {
+                        @for delivery in deliveries {
+                            tr {
+                                td style="font-family: monospace;" { (delivery.event) }
+                                td style="font-family: monospace; word-break: break-all;" {
+                                    @if let Some(subscription) = subscriptions.iter().find(|s| s.id == delivery.subscription_id) {
+                                        (subscription.url)
+                                    }
+                                }
+                                td {
+                                    (status_badge(t, delivery.status()))
+                                    @if let Some(error) = &delivery.last_error {
+                                        div style="color: var(--gray-500); font-size: 0.75rem;" { (error) }
+                                    }
+                                }
+                                td style="text-align: right;" { (delivery.attempts) }
+                                td style="white-space: nowrap;" { (delivery.created_at) }
+                                td style="text-align: right;" {
+                                    @if delivery.status() == Some(DeliveryStatus::Failed) {
+                                        form style="display: inline;" {
+                                            (csrf_token_field(&session.csrf_token))
+                                            button
+                                                type="submit"
+                                                class="btn btn-sm btn-secondary"
+                                                hx-post=(format!("/management/webhooks/deliveries/{}/retry", delivery.id))
+                                                hx-target="#webhook-deliveries"
+                                                hx-swap="outerHTML"
+                                                hx-disabled-elt="this"
+                                            {
+                                                (t.messages.webhooks_retry())
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn toggle(name: &str, label: &str, checked: bool) -> Markup {
+    html! {
+        label class="checkbox-label" {
+            input type="checkbox" name=(name) value="true" checked[checked];
+            span class="checkbox-text" { (label) }
+        }
+    }
+}
+
+fn status_badge(t: &TranslationContext, status: Option<DeliveryStatus>) -> Markup {
+    let (label, colors) = match status {
+        Some(DeliveryStatus::Delivered) => (
+            t.messages.webhooks_status_delivered().to_string(),
+            "color: #15803d; background: #dcfce7;",
+        ),
+        Some(DeliveryStatus::Failed) => (
+            t.messages.webhooks_status_failed().to_string(),
+            "color: #b91c1c; background: #fee2e2;",
+        ),
+        _ => (
+            t.messages.webhooks_status_pending().to_string(),
+            "color: #a16207; background: #fef9c3;",
+        ),
+    };
+
+    html! {
+        span style=(format!(
+            "display: inline-block; padding: 0.25rem 0.75rem; border-radius: 9999px; font-size: 0.875rem; font-weight: 500; {}",
+            colors
+        )) {
+            (label)
+        }
+    }
+}
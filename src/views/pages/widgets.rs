@@ -0,0 +1,144 @@
+use maud::{html, Markup};
+
+use crate::i18n::TranslationContext;
+use crate::service::live_scores::ScoreboardMatch;
+use crate::service::season_export::ScheduleMatchEntity;
+use crate::service::widgets::WidgetStandings;
+use crate::views::pages::matches::status_filter_label;
+use crate::views::pages::public_site::schedule_score;
+
+/// Link to the full page on the public site
+fn widget_footer(t: &TranslationContext, href: &str) -> Markup {
+    html! {
+        footer {
+            a href=(href) { (t.messages.widgets_view_full()) " →" }
+        }
+    }
+}
+
+fn match_date(t: &TranslationContext, date: Option<&str>) -> String {
+    date.map(|date| date.replace('T', " "))
+        .unwrap_or_else(|| t.messages.matches_date_unknown().to_string())
+}
+
+/// Standings widget: one compact table per group
+pub fn standings_widget(
+    t: &TranslationContext,
+    standings: &WidgetStandings,
+    season_path: &str,
+) -> Markup {
+    let overtime_columns = standings.point_system == "iihf";
+    html! {
+        h1 { (standings.season_name) " – " (t.messages.standings_title()) }
+        @for table in &standings.tables {
+            @if let Some(group) = &table.group {
+                h2 { (group) }
+            }
+            @if table.rows.is_empty() {
+                p class="muted" { (t.messages.seasons_no_teams()) }
+            } @else {
+                table {
+                    thead {
+                        tr {
+                            th class="num" { "#" }
+                            th { (t.messages.standings_team()) }
+                            th class="num" { (t.messages.standings_games()) }
+                            th class="num" { (t.messages.standings_wins()) }
+                            @if overtime_columns {
+                                th class="num" { (t.messages.standings_overtime_wins()) }
+                            }
+                            th class="num" { (t.messages.standings_ties()) }
+                            @if overtime_columns {
+                                th class="num" { (t.messages.standings_overtime_losses()) }
+                            }
+                            th class="num" { (t.messages.standings_losses()) }
+                            th class="num" { (t.messages.standings_goals()) }
+                            th class="num" { (t.messages.standings_points()) }
+                        }
+                    }
+                    tbody {
+                        @for row in &table.rows {
+                            tr {
+                                td class="num" { (row.rank) }
+                                td { (row.team_name) }
+                                td class="num" { (row.games) }
+                                td class="num" { (row.wins) }
+                                @if overtime_columns {
+                                    td class="num" { (row.overtime_wins) }
+                                }
+                                td class="num" { (row.ties) }
+                                @if overtime_columns {
+                                    td class="num" { (row.overtime_losses) }
+                                }
+                                td class="num" { (row.losses) }
+                                td class="num" { (format!("{}:{}", row.goals_for, row.goals_against)) }
+                                td class="num" { strong { (row.points) } }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        (widget_footer(t, season_path))
+    }
+}
+
+/// Schedule widget: every match of a season with scores
+pub fn schedule_widget(
+    t: &TranslationContext,
+    season_path: &str,
+    season_name: &str,
+    schedule: &[ScheduleMatchEntity],
+) -> Markup {
+    html! {
+        h1 { (season_name) " – " (t.messages.public_schedule()) }
+        @if schedule.is_empty() {
+            p class="muted" { (t.messages.public_no_matches()) }
+        } @else {
+            table {
+                tbody {
+                    @for game in schedule {
+                        tr {
+                            td class="muted" { (match_date(t, game.match_date.as_deref())) }
+                            td class="num" { (game.home_team_name) }
+                            td class="score" { (schedule_score(game)) }
+                            td { (game.away_team_name) }
+                        }
+                    }
+                }
+            }
+        }
+        (widget_footer(t, season_path))
+    }
+}
+
+/// Scoreboard widget: the day's matches with live scores
+pub fn scoreboard_widget(t: &TranslationContext, matches: &[ScoreboardMatch]) -> Markup {
+    html! {
+        h1 { (t.messages.widgets_scoreboard_title()) }
+        @if matches.is_empty() {
+            p class="muted" { (t.messages.widgets_no_matches_today()) }
+        } @else {
+            table {
+                tbody {
+                    @for game in matches {
+                        tr {
+                            td class="muted" { (match_date(t, game.match_date.as_deref())) }
+                            td class="num" { (game.home.name) }
+                            td class="score" {
+                                @if game.status == "scheduled" {
+                                    "–"
+                                } @else {
+                                    (format!("{}:{}", game.home.score, game.away.score))
+                                }
+                            }
+                            td { (game.away.name) }
+                            td class="muted" { (status_filter_label(t, &game.status)) }
+                        }
+                    }
+                }
+            }
+        }
+        (widget_footer(t, "/public"))
+    }
+}
@@ -0,0 +1,267 @@
+//! Signed webhook deliveries of record changes
+//!
+//! Handlers queue an event after a match, goal or player was created,
+//! updated or deleted; the worker started by [`spawn`] posts queued
+//! deliveries to their subscriptions (see `service::webhooks`) and retries
+//! failures with exponential backoff. Receivers verify the
+//! `X-Hockey-Signature` header, an HMAC-SHA256 of `"{timestamp}.{body}"`
+//! keyed with the subscription secret.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use hmac::{Hmac, Mac};
+use serde_json::{json, Map, Value};
+use sha2::Sha256;
+use sqlx::SqlitePool;
+use tokio::sync::Notify;
+
+use crate::service::change_history::{HistoryAction, HistoryEntityType, Snapshot};
+use crate::service::webhooks::{self, DueDeliveryEntity};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// How long a receiver may take to answer
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often the worker looks for due retries when nothing wakes it
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Deliveries sent per round of the worker
+const BATCH_SIZE: i64 = 50;
+
+/// Attempts before a delivery is marked failed
+pub const MAX_ATTEMPTS: i64 = 6;
+
+/// Delay before the first retry; doubles with every further attempt
+const FIRST_RETRY_SECONDS: i64 = 30;
+
+/// A change to announce to the subscriptions
+#[derive(Debug, Clone)]
+pub struct EntityChange {
+    pub entity_type: HistoryEntityType,
+    pub action: HistoryAction,
+    pub entity_id: i64,
+    /// Match the record belongs to (the match itself, or a goal's match)
+    pub match_id: Option<i64>,
+    /// Record after the change; `None` for deletes
+    pub snapshot: Option<Snapshot>,
+}
+
+impl EntityChange {
+    /// Event name, e.g. `match.created` or `score_event.deleted`
+    pub fn event(&self) -> String {
+        let action = match self.action {
+            HistoryAction::Create => "created",
+            HistoryAction::Update => "updated",
+            HistoryAction::Delete => "deleted",
+        };
+        format!("{}.{}", self.entity_type.as_str(), action)
+    }
+
+    /// JSON body of the delivery
+    pub fn payload(&self) -> Value {
+        let data = self.snapshot.as_ref().map(|snapshot| {
+            snapshot
+                .iter()
+                .map(|(field, value)| (field.to_string(), json!(value)))
+                .collect::<Map<_, _>>()
+        });
+        json!({
+            "event": self.event(),
+            "entity_type": self.entity_type.as_str(),
+            "entity_id": self.entity_id,
+            "match_id": self.match_id,
+            "occurred_at": chrono::Utc::now().to_rfc3339(),
+            "data": data,
+        })
+    }
+}
+
+/// Queues webhook events and wakes the delivery worker
+#[derive(Clone, Default)]
+pub struct WebhookDispatcher {
+    wake: Arc<Notify>,
+}
+
+impl WebhookDispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a change for every subscription following the record kind
+    ///
+    /// The change itself has already been saved, so a failure is only logged.
+    pub async fn entity_changed(&self, db: &SqlitePool, change: EntityChange) {
+        let event = change.event();
+        let payload = change.payload().to_string();
+        match webhooks::enqueue_webhook_event(db, change.entity_type, &event, &payload).await {
+            Ok(0) => {}
+            Ok(_) => self.wake.notify_one(),
+            Err(e) => tracing::warn!(
+                "Failed to queue webhook {} for {}: {}",
+                event,
+                change.entity_id,
+                e
+            ),
+        }
+    }
+
+    /// Wake the worker, e.g. after a failed delivery was queued again
+    pub fn wake(&self) {
+        self.wake.notify_one();
+    }
+}
+
+/// Start the worker posting queued deliveries
+pub fn spawn(db: SqlitePool, dispatcher: WebhookDispatcher) {
+    let http = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .unwrap_or_default();
+    tokio::spawn(async move {
+        loop {
+            deliver_due(&db, &http).await;
+            tokio::select! {
+                _ = dispatcher.wake.notified() => {}
+                _ = tokio::time::sleep(POLL_INTERVAL) => {}
+            }
+        }
+    });
+}
+
+/// Post every due delivery, in batches until none is left
+async fn deliver_due(db: &SqlitePool, http: &reqwest::Client) {
+    loop {
+        let due = match webhooks::get_due_deliveries(db, BATCH_SIZE).await {
+            Ok(due) => due,
+            Err(e) => {
+                tracing::warn!("Failed to load webhook deliveries: {}", e);
+                return;
+            }
+        };
+        let count = due.len() as i64;
+        for delivery in due {
+            let result = match send(http, &delivery).await {
+                Ok(()) => webhooks::mark_delivery_delivered(db, delivery.id).await,
+                Err(error) => {
+                    let retry_in = retry_delay(delivery.attempts + 1);
+                    tracing::warn!(
+                        "Webhook delivery {} to {} failed (attempt {}): {}",
+                        delivery.id,
+                        delivery.url,
+                        delivery.attempts + 1,
+                        error
+                    );
+                    webhooks::mark_delivery_attempt_failed(db, delivery.id, &error, retry_in).await
+                }
+            };
+            if let Err(e) = result {
+                tracing::warn!("Failed to update webhook delivery {}: {}", delivery.id, e);
+                return;
+            }
+        }
+        if count < BATCH_SIZE {
+            return;
+        }
+    }
+}
+
+/// Post one delivery; any non-2xx answer is a failure
+async fn send(http: &reqwest::Client, delivery: &DueDeliveryEntity) -> Result<(), String> {
+    let timestamp = chrono::Utc::now().timestamp().to_string();
+    let response = http
+        .post(&delivery.url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header("X-Hockey-Event", &delivery.event)
+        .header("X-Hockey-Delivery", delivery.id.to_string())
+        .header("X-Hockey-Timestamp", &timestamp)
+        .header(
+            "X-Hockey-Signature",
+            format!(
+                "sha256={}",
+                sign(&delivery.secret, &timestamp, &delivery.payload)
+            ),
+        )
+        .body(delivery.payload.clone())
+        .send()
+        .await
+        .map_err(|e| e.without_url().to_string())?;
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("HTTP {}", response.status().as_u16()))
+    }
+}
+
+/// Hex HMAC-SHA256 of `"{timestamp}.{body}"`
+pub fn sign(secret: &str, timestamp: &str, body: &str) -> String {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(timestamp.as_bytes());
+    mac.update(b".");
+    mac.update(body.as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Seconds to wait after the given number of failed attempts; `None` once
+/// the delivery should be given up (30 s, 1 min, 2 min, 4 min, 8 min)
+pub fn retry_delay(failed_attempts: i64) -> Option<i64> {
+    (1..MAX_ATTEMPTS)
+        .contains(&failed_attempts)
+        .then(|| FIRST_RETRY_SECONDS << (failed_attempts - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_delay() {
+        assert_eq!(retry_delay(1), Some(30));
+        assert_eq!(retry_delay(2), Some(60));
+        assert_eq!(retry_delay(5), Some(480));
+        assert_eq!(retry_delay(MAX_ATTEMPTS), None);
+    }
+
+    #[test]
+    fn test_sign() {
+        let signature = sign("secret", "1700000000", r#"{"event":"match.created"}"#);
+        assert_eq!(signature.len(), 64);
+        assert_eq!(
+            signature,
+            sign("secret", "1700000000", r#"{"event":"match.created"}"#)
+        );
+        assert_ne!(
+            signature,
+            sign("secret", "1700000001", r#"{"event":"match.created"}"#)
+        );
+    }
+
+    #[test]
+    fn test_payload() {
+        let change = EntityChange {
+            entity_type: HistoryEntityType::ScoreEvent,
+            action: HistoryAction::Create,
+            entity_id: 7,
+            match_id: Some(3),
+            snapshot: Some(vec![
+                ("team", Some("Canada".to_string())),
+                ("assist1", None),
+            ]),
+        };
+        let payload = change.payload();
+        assert_eq!(payload["event"], "score_event.created");
+        assert_eq!(payload["match_id"], 3);
+        assert_eq!(payload["data"]["team"], "Canada");
+        assert!(payload["data"]["assist1"].is_null());
+
+        let deleted = EntityChange {
+            action: HistoryAction::Delete,
+            snapshot: None,
+            ..change
+        };
+        assert_eq!(deleted.event(), "score_event.deleted");
+        assert!(deleted.payload()["data"].is_null());
+    }
+}
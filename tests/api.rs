@@ -0,0 +1,791 @@
+//! Integration tests against the public test harness
+
+use axum::http::StatusCode;
+use hockey::auth::lockout::LockoutPolicy;
+use hockey::auth::oauth::{OAuthClient, OAuthProviders};
+use hockey::auth::{hash_password, verify_password};
+use hockey::service::settings::{self, RegistrationSettings};
+use hockey::service::{password_reset, registration, seasons};
+use hockey::test_support::TestHarness;
+
+#[tokio::test]
+async fn health_check_is_public() {
+    let harness = TestHarness::new().await;
+
+    let response = harness.get("/health").await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body, "OK");
+}
+
+#[tokio::test]
+async fn signed_in_user_sees_dashboard() {
+    let mut harness = TestHarness::with_seed_data().await;
+    harness.sign_in().await;
+
+    let response = harness.get("/").await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert!(response.body.contains("Test User"));
+}
+
+#[tokio::test]
+async fn created_event_is_listed() {
+    let mut harness = TestHarness::with_seed_data().await;
+    harness.sign_in().await;
+
+    let created = harness
+        .post_form("/events", &[("name", "Spengler Cup"), ("country_id", "8")])
+        .await;
+    assert_eq!(created.status, StatusCode::OK);
+
+    let list = harness.htmx_get("/events/list?name=Spengler").await;
+    assert!(list.body.contains("Spengler Cup"));
+}
+
+#[tokio::test]
+async fn live_scores_are_public_and_cacheable() {
+    let harness = TestHarness::new().await;
+
+    let response = harness.get("/public/api/live").await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(response.body, "[]");
+    assert_eq!(response.header("cache-control"), Some("public, max-age=15"));
+}
+
+#[tokio::test]
+async fn public_site_is_readable_without_login() {
+    let harness = TestHarness::new().await;
+    hockey::seed::seed_demo_data(&harness.pool).await.unwrap();
+    let (season_id,): (i64,) =
+        sqlx::query_as("SELECT id FROM season WHERE display_name = '2025 World Championship'")
+            .fetch_one(&harness.pool)
+            .await
+            .unwrap();
+    let (team_id,): (i64,) = sqlx::query_as("SELECT id FROM team WHERE name = 'Canada'")
+        .fetch_one(&harness.pool)
+        .await
+        .unwrap();
+
+    let home = harness.get("/public").await;
+    assert_eq!(home.status, StatusCode::OK);
+    assert!(home.body.contains("2025 World Championship"));
+    assert_eq!(home.header("cache-control"), Some("public, max-age=60"));
+
+    let season = harness.get("/public/seasons/2025-world-championship").await;
+    assert_eq!(season.status, StatusCode::OK);
+    assert!(season.body.contains("Canada"));
+    assert!(season
+        .body
+        .contains("/public/seasons/2025-world-championship/teams/canada"));
+
+    let roster = harness
+        .get("/public/seasons/2025-world-championship/teams/canada")
+        .await;
+    assert_eq!(roster.status, StatusCode::OK);
+
+    let players = harness
+        .get("/public/seasons/2025-world-championship/players")
+        .await;
+    assert_eq!(players.status, StatusCode::OK);
+
+    // Numeric IDs redirect to the slug paths
+    let by_id = harness
+        .get(&format!("/public/seasons/{}/teams/{}", season_id, team_id))
+        .await;
+    assert_eq!(by_id.status, StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(
+        by_id.header("location"),
+        Some("/public/seasons/2025-world-championship/teams/canada")
+    );
+
+    // So do slugs given up after a rename
+    let season_entity = seasons::get_season_by_id(&harness.pool, season_id)
+        .await
+        .unwrap()
+        .unwrap();
+    seasons::update_season(
+        &harness.pool,
+        season_id,
+        seasons::UpdateSeasonEntity {
+            year: season_entity.year,
+            display_name: Some("2025 Worlds".to_string()),
+            event_id: season_entity.event_id,
+            country_id: season_entity.country_id,
+        },
+    )
+    .await
+    .unwrap();
+    let renamed = harness
+        .get("/public/seasons/2025-world-championship/players")
+        .await;
+    assert_eq!(renamed.status, StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(
+        renamed.header("location"),
+        Some("/public/seasons/2025-worlds/players")
+    );
+
+    let missing = harness.get("/public/seasons/9999").await;
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn sitemap_lists_public_pages() {
+    let harness = TestHarness::with_state(|state| {
+        state.with_public_url("https://hockey.example.com".to_string())
+    })
+    .await;
+    hockey::seed::seed_demo_data(&harness.pool).await.unwrap();
+
+    // Links point at the configured address, whatever host the request names
+    let response = harness
+        .get_with_headers("/sitemap.xml", &[("host", "evil.example")])
+        .await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert_eq!(
+        response.header("content-type"),
+        Some("application/xml; charset=utf-8")
+    );
+    assert!(response.body.starts_with("<?xml"));
+    assert!(response
+        .body
+        .contains("<loc>https://hockey.example.com/public</loc>"));
+    assert!(!response.body.contains("evil.example"));
+    // Pages are listed by slug, not by the IDs that redirect to them
+    assert!(!response.body.contains("/public/seasons/1<"));
+    assert!(response.body.contains("/teams/canada</loc>"));
+    // 3 seasons with 2 pages each, 20 rosters and the home page
+    assert_eq!(response.body.matches("<url>").count(), 3 * 2 + 20 + 1);
+}
+
+#[tokio::test]
+async fn widgets_are_embeddable_from_any_origin() {
+    let harness = TestHarness::new().await;
+    hockey::seed::seed_demo_data(&harness.pool).await.unwrap();
+    let (season_id,): (i64,) =
+        sqlx::query_as("SELECT id FROM season WHERE display_name = '2025 World Championship'")
+            .fetch_one(&harness.pool)
+            .await
+            .unwrap();
+
+    let standings = harness
+        .get(&format!("/widgets/season/{}/standings", season_id))
+        .await;
+    assert_eq!(standings.status, StatusCode::OK);
+    assert!(standings.body.contains("Canada"));
+    // No admin chrome or scripts
+    assert!(!standings.body.contains("<script"));
+    assert_eq!(standings.header("access-control-allow-origin"), Some("*"));
+    assert_eq!(standings.header("link"), None);
+    // Rendered in the visitor's language, so cached per locale
+    assert_eq!(standings.header("vary"), Some("Cookie, Accept-Language"));
+
+    let json = harness
+        .get(&format!(
+            "/widgets/season/{}/standings?format=json",
+            season_id
+        ))
+        .await;
+    assert_eq!(json.status, StatusCode::OK);
+    let data: serde_json::Value = serde_json::from_str(&json.body).unwrap();
+    assert_eq!(data["point_system"], "iihf");
+    assert_eq!(data["tables"][0]["rows"].as_array().unwrap().len(), 8);
+
+    let schedule = harness
+        .get(&format!(
+            "/widgets/season/{}/schedule?format=json",
+            season_id
+        ))
+        .await;
+    assert_eq!(schedule.status, StatusCode::OK);
+    assert_eq!(schedule.header("cache-control"), Some("public, max-age=60"));
+
+    let scoreboard = harness.get("/widgets/scoreboard").await;
+    assert_eq!(scoreboard.status, StatusCode::OK);
+
+    let missing = harness.get("/widgets/season/9999/standings").await;
+    assert_eq!(missing.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn match_changes_are_queued_for_webhooks() {
+    let mut harness = TestHarness::new().await;
+    hockey::seed::seed_demo_data(&harness.pool).await.unwrap();
+    harness.sign_in().await;
+
+    let created = harness
+        .post_form(
+            "/management/webhooks",
+            &[("url", "https://example.com/hooks"), ("on_matches", "true")],
+        )
+        .await;
+    assert_eq!(created.status, StatusCode::OK);
+    assert!(created.body.contains("https://example.com/hooks"));
+
+    let (match_id,): (i64,) = sqlx::query_as("SELECT id FROM match ORDER BY id LIMIT 1")
+        .fetch_one(&harness.pool)
+        .await
+        .unwrap();
+    let deleted = harness
+        .post_form(&format!("/matches/{}/delete", match_id), &[])
+        .await;
+    assert_eq!(deleted.status, StatusCode::OK);
+
+    let (event, payload): (String, String) =
+        sqlx::query_as("SELECT event, payload FROM webhook_delivery")
+            .fetch_one(&harness.pool)
+            .await
+            .unwrap();
+    assert_eq!(event, "match.deleted");
+    let payload: serde_json::Value = serde_json::from_str(&payload).unwrap();
+    assert_eq!(payload["entity_id"], match_id);
+
+    let page = harness.get("/management/webhooks").await;
+    assert!(page.body.contains("match.deleted"));
+}
+
+#[tokio::test]
+async fn imported_matches_and_players_are_queued_for_webhooks() {
+    let mut harness = TestHarness::new().await;
+    hockey::seed::seed_demo_data(&harness.pool).await.unwrap();
+    harness.sign_in().await;
+
+    let created = harness
+        .post_form(
+            "/management/webhooks",
+            &[
+                ("url", "https://example.com/hooks"),
+                ("on_matches", "true"),
+                ("on_players", "true"),
+            ],
+        )
+        .await;
+    assert_eq!(created.status, StatusCode::OK);
+
+    let (season_id,): (i64,) =
+        sqlx::query_as("SELECT id FROM season WHERE display_name = '2025 World Championship'")
+            .fetch_one(&harness.pool)
+            .await
+            .unwrap();
+    let imported = harness
+        .post_json(
+            "/api/matches/import",
+            &format!(
+                r#"[{{"season": {}, "home_team": "Canada", "away_team": "Finland",
+                     "date": "2030-01-01", "home_score": 4, "away_score": 1}}]"#,
+                season_id
+            ),
+        )
+        .await;
+    assert_eq!(imported.status, StatusCode::CREATED);
+    let created: serde_json::Value = serde_json::from_str(&imported.body).unwrap();
+
+    let uploaded = harness
+        .post_file(
+            "/players/import",
+            "players.csv",
+            "Name,Country\nJaromir Jagr,CZE\nPatrik Elias,CZE\n",
+        )
+        .await;
+    assert_eq!(uploaded.status, StatusCode::OK);
+
+    let deliveries: Vec<(String, String)> =
+        sqlx::query_as("SELECT event, payload FROM webhook_delivery ORDER BY id")
+            .fetch_all(&harness.pool)
+            .await
+            .unwrap();
+    let events: Vec<&str> = deliveries.iter().map(|(event, _)| event.as_str()).collect();
+    assert_eq!(
+        events,
+        ["match.created", "player.created", "player.created"]
+    );
+    let payload: serde_json::Value = serde_json::from_str(&deliveries[0].1).unwrap();
+    assert_eq!(payload["entity_id"], created["created"][0]);
+    let payload: serde_json::Value = serde_json::from_str(&deliveries[2].1).unwrap();
+    assert_eq!(payload["data"]["name"], "Patrik Elias");
+}
+
+#[tokio::test]
+async fn api_key_authenticates_api_calls() {
+    let mut harness = TestHarness::new().await;
+    harness.sign_in().await;
+
+    let created = harness
+        .post_form("/profile/api-keys", &[("name", "Nightly import")])
+        .await;
+    assert_eq!(created.status, StatusCode::OK);
+    assert!(created.body.contains("Nightly import"));
+    let start = created.body.find("hk_").unwrap();
+    let key = &created.body[start..start + 67];
+
+    let response = harness
+        .get_with_headers("/api/score-events", &[("x-api-key", key)])
+        .await;
+    assert_eq!(response.status, StatusCode::OK);
+    assert!(response.body.contains("\"items\":[]"));
+
+    // The key takes precedence over the session cookie
+    let wrong = harness
+        .get_with_headers("/api/score-events", &[("x-api-key", "hk_wrong")])
+        .await;
+    assert_eq!(wrong.status, StatusCode::UNAUTHORIZED);
+
+    // Pages are not reachable with a key
+    let page = harness
+        .get_with_headers("/matches", &[("x-api-key", key)])
+        .await;
+    assert_eq!(page.status, StatusCode::FORBIDDEN);
+
+    let (key_id,): (i64,) = sqlx::query_as("SELECT id FROM api_key")
+        .fetch_one(&harness.pool)
+        .await
+        .unwrap();
+    let revoked = harness
+        .post_form(&format!("/profile/api-keys/{}/revoke", key_id), &[])
+        .await;
+    assert_eq!(revoked.status, StatusCode::OK);
+    let response = harness
+        .get_with_headers("/api/score-events", &[("x-api-key", key)])
+        .await;
+    assert_eq!(response.status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn scoped_api_key_only_reads_its_scopes() {
+    let mut harness = TestHarness::new().await;
+    harness.sign_in().await;
+
+    let created = harness
+        .post_form(
+            "/profile/api-keys",
+            &[("name", "Scoreboard"), ("matches:read", "true")],
+        )
+        .await;
+    assert_eq!(created.status, StatusCode::OK);
+    assert!(created.body.contains("Matches and score events"));
+    let start = created.body.find("hk_").unwrap();
+    let key = &created.body[start..start + 67];
+
+    let allowed = harness
+        .get_with_headers("/api/score-events", &[("x-api-key", key)])
+        .await;
+    assert_eq!(allowed.status, StatusCode::OK);
+
+    let outside = harness
+        .get_with_headers("/api/players/export", &[("x-api-key", key)])
+        .await;
+    assert_eq!(outside.status, StatusCode::FORBIDDEN);
+    let graphql = harness
+        .get_with_headers("/graphql?query={__typename}", &[("x-api-key", key)])
+        .await;
+    assert_eq!(graphql.status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn users_are_managed_by_admins() {
+    let mut harness = TestHarness::new().await;
+    harness.sign_in().await;
+
+    let created = harness
+        .post_form(
+            "/users",
+            &[
+                ("email", "editor@example.com"),
+                ("name", "Eddie Editor"),
+                ("role", "editor"),
+            ],
+        )
+        .await;
+    assert_eq!(created.status, StatusCode::OK);
+    assert!(created.body.contains("Eddie Editor"));
+    assert!(created.body.contains("id=\"users-list\""));
+
+    let (editor_id, must_change): (i64, bool) = sqlx::query_as(
+        "SELECT id, must_change_password FROM users WHERE email = 'editor@example.com'",
+    )
+    .fetch_one(&harness.pool)
+    .await
+    .unwrap();
+    assert!(must_change);
+
+    let deactivated = harness
+        .post_form(
+            &format!("/users/{}/active", editor_id),
+            &[("active", "false")],
+        )
+        .await;
+    assert_eq!(deactivated.status, StatusCode::OK);
+    let (active,): (bool,) = sqlx::query_as("SELECT active FROM users WHERE id = ?")
+        .bind(editor_id)
+        .fetch_one(&harness.pool)
+        .await
+        .unwrap();
+    assert!(!active);
+
+    // Editors cannot reach user management
+    sqlx::query("UPDATE users SET role = 'editor' WHERE email = 'test@example.com'")
+        .execute(&harness.pool)
+        .await
+        .unwrap();
+    let forbidden = harness.get("/users").await;
+    assert_eq!(forbidden.status, StatusCode::FORBIDDEN);
+
+    // A pending password change sends every page to the change form
+    sqlx::query("UPDATE users SET must_change_password = 1 WHERE email = 'test@example.com'")
+        .execute(&harness.pool)
+        .await
+        .unwrap();
+    let redirected = harness.get("/matches").await;
+    assert!(redirected.status.is_redirection());
+    assert_eq!(redirected.header("location"), Some("/profile/password"));
+    let form = harness.get("/profile/password").await;
+    assert_eq!(form.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn instance_administration_is_limited_to_admins() {
+    let mut harness = TestHarness::new().await;
+    harness.sign_in().await;
+    sqlx::query("UPDATE users SET role = 'editor' WHERE email = 'test@example.com'")
+        .execute(&harness.pool)
+        .await
+        .unwrap();
+
+    for path in [
+        "/management/status/snapshot",
+        "/management/backups",
+        "/management/backups/backup.db",
+        "/management/instance-transfer",
+        "/management/instance-transfer/export",
+        "/management/webhooks",
+        "/management/settings",
+        "/management/slow-queries",
+    ] {
+        let response = harness.get(path).await;
+        assert_eq!(response.status, StatusCode::FORBIDDEN, "GET {}", path);
+    }
+
+    for path in [
+        "/management/backups",
+        "/management/instance-transfer",
+        "/management/webhooks",
+        "/management/webhooks/1",
+        "/management/webhooks/deliveries/1/retry",
+        "/management/settings",
+        "/management/settings/chat-webhooks",
+        "/management/settings/chat-webhooks/1/test",
+        "/management/status/maintenance",
+        "/management/slow-queries/clear",
+    ] {
+        let response = harness.post_form(path, &[]).await;
+        assert_eq!(response.status, StatusCode::FORBIDDEN, "POST {}", path);
+    }
+
+    // The rest of management stays open to editors
+    let status = harness.get("/management/status").await;
+    assert_eq!(status.status, StatusCode::OK);
+    assert!(!status.body.contains("/management/status/snapshot"));
+    assert!(!status.body.contains("/management/status/maintenance"));
+}
+
+#[tokio::test]
+async fn whats_new_editor_is_limited_to_admins() {
+    let mut harness = TestHarness::new().await;
+    harness.sign_in().await;
+    sqlx::query("UPDATE users SET role = 'editor' WHERE email = 'test@example.com'")
+        .execute(&harness.pool)
+        .await
+        .unwrap();
+
+    for path in [
+        "/management/whats-new",
+        "/management/whats-new/list",
+        "/management/whats-new/new",
+        "/management/whats-new/1/edit",
+    ] {
+        let response = harness.get(path).await;
+        assert_eq!(response.status, StatusCode::FORBIDDEN, "GET {}", path);
+    }
+    for path in [
+        "/management/whats-new",
+        "/management/whats-new/1",
+        "/management/whats-new/1/delete",
+    ] {
+        let response = harness.post_form(path, &[]).await;
+        assert_eq!(response.status, StatusCode::FORBIDDEN, "POST {}", path);
+    }
+
+    // Editors still read and dismiss the entries
+    let whats_new = harness.get("/whats-new").await;
+    assert_eq!(whats_new.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn repeated_wrong_passwords_lock_the_account() {
+    let mut harness = TestHarness::with_state(|state| {
+        state.with_lockout(LockoutPolicy {
+            threshold: 2,
+            minutes: 15,
+        })
+    })
+    .await;
+    harness.sign_in().await;
+
+    let user_id: i64 = sqlx::query_scalar(
+        "INSERT INTO users (email, name, password_hash) VALUES ('coach@example.com', 'Coach', ?) RETURNING id",
+    )
+    .bind(hash_password("right-password").unwrap())
+    .fetch_one(&harness.pool)
+    .await
+    .unwrap();
+    let sign_in = |password: &'static str| [("email", "coach@example.com"), ("password", password)];
+
+    let first = harness.post_form("/auth/login", &sign_in("wrong")).await;
+    assert!(first.body.contains("Invalid email or password"));
+    let second = harness.post_form("/auth/login", &sign_in("wrong")).await;
+    assert!(second.body.contains("Too many failed sign-in attempts"));
+
+    // Even the right password is refused until the lockout ends
+    let locked = harness
+        .post_form("/auth/login", &sign_in("right-password"))
+        .await;
+    assert!(locked.body.contains("Too many failed sign-in attempts"));
+
+    let users = harness.get("/users").await;
+    assert!(users.body.contains(&format!("/users/{}/unlock", user_id)));
+    let activity = harness.get("/users/activity?failed=true").await;
+    assert_eq!(activity.status, StatusCode::OK);
+    assert!(activity.body.contains("coach@example.com"));
+    assert!(activity.body.contains("Account locked"));
+    assert!(activity.body.contains("Wrong password"));
+
+    let unlocked = harness
+        .post_form(&format!("/users/{}/unlock", user_id), &[])
+        .await;
+    assert_eq!(
+        unlocked.header("hx-toast-success"),
+        Some("Account unlocked")
+    );
+    let signed_in = harness
+        .post_form("/auth/login", &sign_in("right-password"))
+        .await;
+    assert!(signed_in.status.is_redirection());
+}
+
+#[tokio::test]
+async fn sign_in_ignores_the_case_of_the_email() {
+    let harness = TestHarness::new().await;
+    sqlx::query(
+        "INSERT INTO users (email, name, password_hash) VALUES ('Coach@Example.com', 'Coach', ?)",
+    )
+    .bind(hash_password("right-password").unwrap())
+    .execute(&harness.pool)
+    .await
+    .unwrap();
+
+    let signed_in = harness
+        .post_form(
+            "/auth/login",
+            &[
+                ("email", "coach@example.com"),
+                ("password", "right-password"),
+            ],
+        )
+        .await;
+    assert!(signed_in.status.is_redirection());
+}
+
+#[tokio::test]
+async fn bcrypt_passwords_are_rehashed_at_sign_in() {
+    let harness = TestHarness::new().await;
+    sqlx::query(
+        "INSERT INTO users (email, name, password_hash) VALUES ('coach@example.com', 'Coach', ?)",
+    )
+    .bind(bcrypt::hash("right-password", 4).unwrap())
+    .execute(&harness.pool)
+    .await
+    .unwrap();
+
+    let signed_in = harness
+        .post_form(
+            "/auth/login",
+            &[
+                ("email", "coach@example.com"),
+                ("password", "right-password"),
+            ],
+        )
+        .await;
+    assert!(signed_in.status.is_redirection());
+
+    let hash: String =
+        sqlx::query_scalar("SELECT password_hash FROM users WHERE email = 'coach@example.com'")
+            .fetch_one(&harness.pool)
+            .await
+            .unwrap();
+    assert!(hash.starts_with("$argon2id$"));
+    assert!(verify_password("right-password", &hash).unwrap());
+}
+
+#[tokio::test]
+async fn registration_requires_email_confirmation() {
+    let harness = TestHarness::new().await;
+    let form = [
+        ("email", "fan@example.com"),
+        ("name", "Fan"),
+        ("password", "secret123"),
+        ("confirm_password", "secret123"),
+    ];
+
+    // Closed by default
+    let closed = harness.post_form("/auth/register", &form).await;
+    assert!(closed.status.is_redirection());
+    assert_eq!(closed.header("location"), Some("/auth/login"));
+
+    settings::update_registration_settings(
+        &harness.pool,
+        &RegistrationSettings {
+            enabled: true,
+            requires_approval: false,
+        },
+    )
+    .await
+    .unwrap();
+    let login = harness.get("/auth/login").await;
+    assert!(login.body.contains("/auth/register"));
+
+    let registered = harness.post_form("/auth/register", &form).await;
+    assert_eq!(registered.status, StatusCode::OK);
+    let (user_id, active): (i64, bool) =
+        sqlx::query_as("SELECT id, active FROM users WHERE email = 'fan@example.com'")
+            .fetch_one(&harness.pool)
+            .await
+            .unwrap();
+    assert!(!active);
+
+    let signed_in = harness
+        .post_form(
+            "/auth/login",
+            &[("email", "fan@example.com"), ("password", "secret123")],
+        )
+        .await;
+    assert!(signed_in.body.contains("Confirm your email with the link"));
+
+    let token = registration::create_verification_token(&harness.pool, user_id)
+        .await
+        .unwrap();
+    let verified = harness.get(&format!("/auth/verify/{}", token)).await;
+    assert_eq!(verified.status, StatusCode::OK);
+    assert!(verified.body.contains("You can sign in now"));
+    let again = harness.get(&format!("/auth/verify/{}", token)).await;
+    assert!(again.body.contains("already been used"));
+
+    let signed_in = harness
+        .post_form(
+            "/auth/login",
+            &[("email", "fan@example.com"), ("password", "secret123")],
+        )
+        .await;
+    assert!(signed_in.status.is_redirection());
+}
+
+#[tokio::test]
+async fn forgotten_password_is_reset_by_email_link() {
+    let mut harness = TestHarness::new().await;
+    let user_id = harness.sign_in().await.user_id;
+
+    // Unknown and known addresses get the same answer
+    let unknown = harness
+        .post_form("/auth/forgot-password", &[("email", "nobody@example.com")])
+        .await;
+    let known = harness
+        .post_form("/auth/forgot-password", &[("email", "test@example.com")])
+        .await;
+    assert_eq!(unknown.status, StatusCode::OK);
+    assert_eq!(unknown.body, known.body);
+    let issued: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM password_reset_token")
+        .fetch_one(&harness.pool)
+        .await
+        .unwrap();
+    assert_eq!(issued, 1);
+
+    let token = password_reset::create_reset_token(&harness.pool, user_id)
+        .await
+        .unwrap();
+    let path = format!("/auth/reset-password/{}", token);
+    let form = harness.get(&path).await;
+    assert!(form.body.contains(&format!("action=\"{}\"", path)));
+
+    let mismatch = harness
+        .post_form(
+            &path,
+            &[("password", "new-password"), ("confirm_password", "other")],
+        )
+        .await;
+    assert!(mismatch.body.contains("action="));
+
+    let reset = harness
+        .post_form(
+            &path,
+            &[
+                ("password", "new-password"),
+                ("confirm_password", "new-password"),
+            ],
+        )
+        .await;
+    assert!(reset.body.contains("Your password has been changed"));
+
+    // Every session was signed out and the link is used up
+    let dashboard = harness.get("/").await;
+    assert!(dashboard.status.is_redirection());
+    let again = harness.get(&path).await;
+    assert!(again.body.contains("already been used"));
+}
+
+#[tokio::test]
+async fn configured_providers_offer_external_sign_in() {
+    let mut harness = TestHarness::with_state(|state| {
+        state.with_oauth(OAuthProviders::new(
+            None,
+            Some(OAuthClient {
+                client_id: "github-client".to_string(),
+                client_secret: "github-secret".to_string(),
+            }),
+        ))
+    })
+    .await;
+
+    let login = harness.get("/auth/login").await;
+    assert!(login.body.contains("href=\"/auth/oauth/github\""));
+    assert!(!login.body.contains("/auth/oauth/google"));
+
+    let start = harness
+        .get_with_headers("/auth/oauth/github", &[("host", "evil.example")])
+        .await;
+    assert!(start.status.is_redirection());
+    let location = start.header("location").unwrap();
+    assert!(location.starts_with("https://github.com/login/oauth/authorize?"));
+    assert!(location.contains("client_id=github-client"));
+    // The callback is the configured address, whatever host the request names
+    assert!(location
+        .contains("redirect_uri=http%3A%2F%2Flocalhost%3A8080%2Fauth%2Foauth%2Fgithub%2Fcallback"));
+    assert!(!location.contains("evil.example"));
+    assert!(location.contains("code_challenge_method=S256"));
+    let cookie = start.header("set-cookie").unwrap();
+    assert!(cookie.starts_with("oauth_state="));
+    assert!(cookie.contains("SameSite=Lax"));
+    assert_eq!(
+        harness.get("/auth/oauth/google").await.status,
+        StatusCode::NOT_FOUND
+    );
+
+    // A callback without the state cookie of this browser is refused
+    let callback = harness
+        .get("/auth/oauth/github/callback?code=abc&state=forged")
+        .await;
+    assert_eq!(callback.status, StatusCode::OK);
+    assert!(callback.body.contains("did not work"));
+
+    harness.sign_in().await;
+    let profile = harness.get("/profile").await;
+    assert!(profile
+        .body
+        .contains("action=\"/profile/identities/github\""));
+}
@@ -0,0 +1,237 @@
+//! Typed API client against a server on a local port
+
+#![cfg(feature = "client")]
+
+use hockey::auth::api_keys::{create_api_key, ApiScope};
+use hockey::auth::password::hash_password;
+use hockey::business::match_import::{MatchImportRecord, TextOrNumber};
+use hockey::client::{ClientError, HockeyClient};
+use hockey::service::countries::CountryFilters;
+use hockey::service::matches::MatchFilters;
+use hockey::service::teams::TeamFilters;
+use hockey::test_support::{create_test_app, create_test_pool, seed_test_countries};
+use sqlx::SqlitePool;
+
+/// Start the production router on a random port and return its base URL
+async fn spawn_server() -> String {
+    let pool = create_test_pool().await;
+    seed_test_countries(&pool).await;
+    serve(pool).await
+}
+
+/// Add the API user to `pool` and serve it on a random port
+async fn serve(pool: SqlitePool) -> String {
+    let hash = hash_password("secretpass123").unwrap();
+    sqlx::query(
+        "INSERT INTO users (email, name, password_hash) VALUES ('api@example.com', 'API', ?)",
+    )
+    .bind(hash)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let app = create_test_app(pool);
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    format!("http://{}", addr)
+}
+
+#[tokio::test]
+async fn client_signs_in_and_calls_api() {
+    let client = HockeyClient::new(&spawn_server().await).unwrap();
+    assert!(client.health().await.unwrap());
+    assert!(client.readiness().await.unwrap());
+
+    // API endpoints need a session
+    assert!(matches!(
+        client.countries(&CountryFilters::default()).await,
+        Err(ClientError::Unauthorized)
+    ));
+    assert!(matches!(
+        client.login("api@example.com", "wrong").await,
+        Err(ClientError::InvalidCredentials)
+    ));
+    client
+        .login("api@example.com", "secretpass123")
+        .await
+        .unwrap();
+
+    let all = client.countries(&CountryFilters::default()).await.unwrap();
+    assert!(!all.is_empty());
+    let iihf = client
+        .countries(&CountryFilters {
+            iihf_only: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert!(iihf.iter().all(|c| c.iihf));
+
+    let country = &all[0];
+    let toggled = client.toggle_country(country.id).await.unwrap().unwrap();
+    assert_eq!(toggled.enabled, !country.enabled);
+    assert!(client.toggle_country(999_999).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn client_reads_public_scoreboards_without_sign_in() {
+    let pool = create_test_pool().await;
+    hockey::seed::seed_demo_data(&pool).await.unwrap();
+    let base_url = serve(pool.clone()).await;
+    let today = chrono::Local::now().format("%Y-%m-%d 19:00").to_string();
+    let match_id: i64 = sqlx::query_scalar(
+        "UPDATE match SET status = 'in_progress', match_date = ? WHERE id = (SELECT MIN(id) FROM match) RETURNING id",
+    )
+    .bind(&today)
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+
+    let client = HockeyClient::new(&base_url).unwrap();
+    let live = client.live_matches().await.unwrap();
+    assert_eq!(live.len(), 1);
+    assert_eq!(live[0].id, match_id);
+    assert_eq!(live[0].status, "in_progress");
+    let today = client.today_matches().await.unwrap();
+    assert!(today.iter().any(|m| m.id == match_id));
+}
+
+#[tokio::test]
+async fn client_reads_stats_imports_and_exports_with_api_key() {
+    let pool = create_test_pool().await;
+    hockey::seed::seed_demo_data(&pool).await.unwrap();
+    let base_url = serve(pool.clone()).await;
+    let user_id: i64 = sqlx::query_scalar("SELECT id FROM users WHERE email = 'api@example.com'")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    let (season_id, home, away): (i64, String, String) = sqlx::query_as(
+        r#"
+        SELECT m.season_id, h.name, a.name
+        FROM match m
+        INNER JOIN team h ON m.home_team_id = h.id
+        INNER JOIN team a ON m.away_team_id = a.id
+        WHERE EXISTS (SELECT 1 FROM score_event se WHERE se.match_id = m.id)
+        ORDER BY m.id LIMIT 1
+        "#,
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    let (match_id, team_id): (i64, i64) =
+        sqlx::query_as("SELECT match_id, team_id FROM score_event ORDER BY id LIMIT 1")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+
+    assert!(matches!(
+        HockeyClient::with_api_key(&base_url, "hk_unknown")
+            .unwrap()
+            .team_stats(team_id)
+            .await,
+        Err(ClientError::Unauthorized)
+    ));
+
+    let (_, key) = create_api_key(&pool, user_id, "Full", &[]).await.unwrap();
+    let client = HockeyClient::with_api_key(&base_url, &key).unwrap();
+
+    let stats = client.team_stats(team_id).await.unwrap().unwrap();
+    assert!(!stats.is_empty());
+    assert!(client.team_stats(999_999).await.unwrap().is_none());
+
+    let leaders = client
+        .season_leaders(season_id, Some(3))
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!leaders.points.is_empty() && leaders.points.len() <= 3);
+    let distribution = client
+        .season_period_distribution(season_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(distribution.season_id, season_id);
+    assert!(client
+        .season_leaders(999_999, None)
+        .await
+        .unwrap()
+        .is_none());
+
+    let goals = client.match_score_events(match_id).await.unwrap().unwrap();
+    assert!(!goals.is_empty());
+    let first = client.score_events(None, None, Some(2)).await.unwrap();
+    assert_eq!(first.items.len(), 2);
+    let cursor = first.next_cursor.unwrap();
+    let second = client
+        .score_events(None, Some(&cursor), Some(2))
+        .await
+        .unwrap();
+    assert!(second.items[0].id > first.items[1].id);
+
+    let record = |home_team: &str, away_team: &str| MatchImportRecord {
+        season: TextOrNumber::Number(season_id),
+        home_team: home_team.to_string(),
+        away_team: away_team.to_string(),
+        date: "2031-01-15".to_string(),
+        home_score: TextOrNumber::Number(3),
+        away_score: TextOrNumber::Text("1".to_string()),
+    };
+    let rejected = client
+        .import_matches(&[record(&home, "No Such Team")])
+        .await
+        .unwrap()
+        .unwrap_err();
+    assert_eq!(rejected.errors[0].line, 1);
+    let created = client
+        .import_matches(&[record(&home, &away)])
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(created.created.len(), 1);
+
+    let teams = client.export_teams(&TeamFilters::default()).await.unwrap();
+    assert!(teams.contains(&home));
+    let filters = MatchFilters {
+        season_id: Some(season_id),
+        team_id: None,
+        status: None,
+        date_from: Some("2031-01-01".to_string()),
+        date_to: None,
+        coverage: None,
+        arena_id: None,
+        game_type: None,
+        group_id: None,
+        date_unknown: false,
+    };
+    let matches = client.export_matches(&filters).await.unwrap();
+    assert_eq!(matches.lines().count(), 2);
+    assert!(client.export_score_events(&filters).await.is_ok());
+
+    let team: serde_json::Value = client
+        .graphql(
+            "query($id: Int!) { team(id: $id) { name } }",
+            serde_json::json!({ "id": team_id }),
+        )
+        .await
+        .unwrap();
+    assert!(team["team"]["name"].is_string());
+    assert!(matches!(
+        client
+            .graphql::<serde_json::Value>("{ noSuchField }", serde_json::Value::Null)
+            .await,
+        Err(ClientError::GraphQl(_))
+    ));
+
+    // Scoped keys only read what their scopes cover
+    let (_, key) = create_api_key(&pool, user_id, "Stats", &[ApiScope::StatsRead])
+        .await
+        .unwrap();
+    let stats_only = HockeyClient::with_api_key(&base_url, &key).unwrap();
+    assert!(stats_only.team_stats(team_id).await.unwrap().is_some());
+    assert!(matches!(
+        stats_only.export_teams(&TeamFilters::default()).await,
+        Err(ClientError::Forbidden)
+    ));
+}